@@ -2,6 +2,8 @@
 //!
 //! Auth Service 固有のエラーと、HTTP レスポンスへの変換を定義する。
 
+use std::collections::BTreeMap;
+
 use axum::{
     Json,
     http::StatusCode,
@@ -40,7 +42,8 @@ impl IntoResponse for AuthError {
                     "Authentication Failed",
                     401,
                     "認証に失敗しました",
-                ),
+                )
+                .with_message("authentication-failed-generic", BTreeMap::new()),
             ),
             AuthError::CredentialInactive => (
                 StatusCode::UNAUTHORIZED,
@@ -49,7 +52,8 @@ impl IntoResponse for AuthError {
                     "Credential Inactive",
                     401,
                     "認証情報が無効です",
-                ),
+                )
+                .with_message("credential-inactive", BTreeMap::new()),
             ),
             AuthError::Database(e) => {
                 tracing::error!(
@@ -17,74 +17,137 @@ use ringiflow_bff::{
         AuthState,
         DocumentState,
         FolderState,
+        NotificationLogState,
+        NotificationState,
+        NotificationTemplateState,
+        ProxyGrantState,
         ReadinessState,
+        RealtimeState,
         RoleState,
+        TenantState,
         UserState,
+        WebhookState,
+        WorkflowAdminState,
         WorkflowDefinitionState,
+        WorkflowScheduleState,
         WorkflowState,
         approve_step,
         archive_definition,
+        bulk_decide_tasks,
         confirm_upload,
         create_definition,
         create_folder,
+        create_proxy_grant,
         create_role,
         create_user,
+        create_webhook,
         create_workflow,
+        create_workflow_schedule,
         csrf,
+        delete_comment,
         delete_definition,
         delete_document,
         delete_folder,
+        delete_notification_template,
+        delete_proxy_grant,
         delete_role,
+        delete_webhook,
+        delete_workflow,
+        delete_workflow_schedule,
+        diff_submissions,
+        duplicate_workflow,
+        edit_comment,
+        force_cancel_workflow,
+        force_complete_workflow,
         generate_download_url,
+        get_any_workflow,
         get_dashboard_stats,
+        get_notification_template,
         get_role,
         get_task_by_display_numbers,
+        get_tenant_settings,
+        get_unread_notification_count,
         get_user_detail,
+        get_webhook,
         get_workflow,
         get_workflow_definition,
         health_check,
+        list_activities,
+        list_all_workflows,
         list_audit_logs,
+        list_comment_revisions,
         list_comments,
         list_documents,
+        list_failed_notification_logs,
         list_folders,
+        list_form_data_changes,
         list_my_tasks,
         list_my_workflows,
+        list_notification_preferences,
+        list_notification_templates,
+        list_notifications,
+        list_proxy_grants,
         list_roles,
+        list_submissions,
         list_users,
+        list_watchers,
+        list_webhook_deliveries,
+        list_webhooks,
         list_workflow_attachments,
         list_workflow_definitions,
+        list_workflow_schedules,
         login,
         logout,
+        mark_all_notifications_read,
+        mark_notification_read,
         me,
         post_comment,
+        preview_notification_template,
         publish_definition,
         readiness_check,
+        reassign_current_step,
         reject_step,
         request_changes_step,
         request_upload_url,
+        resend_notification_log,
         resubmit_workflow,
+        retract_approval,
+        save_notification_template,
+        search_workflows,
+        send_test_webhook,
+        stream_events,
         submit_workflow,
+        unwatch_workflow,
         update_definition,
         update_folder,
+        update_my_locale,
+        update_notification_preferences,
         update_role,
+        update_tenant_settings,
         update_user,
         update_user_status,
+        update_webhook,
+        update_workflow_schedule,
         validate_definition,
+        validate_notification_template,
+        watch_workflow,
     },
     middleware::{
         AuthzState,
         CsrfState,
         csrf_middleware,
+        localize_error_response,
         no_cache,
         request_id::store_request_id,
         require_permission,
     },
 };
-use ringiflow_infra::{SessionManager, repository::AuditLogRepository};
+use ringiflow_infra::{SessionManager, realtime::RealtimeMessage, repository::AuditLogRepository};
 use ringiflow_shared::{
     canonical_log::CanonicalLogLineLayer,
     observability::{MakeRequestUuidV7, make_request_span},
 };
+use tokio::sync::broadcast;
 use tower_http::{
     request_id::{PropagateRequestIdLayer, SetRequestIdLayer},
     trace::TraceLayer,
@@ -101,6 +164,7 @@ pub(crate) fn build_app(
     session_manager: Arc<dyn SessionManager>,
     readiness_state: Arc<ReadinessState>,
     audit_log_repository: Arc<dyn AuditLogRepository>,
+    realtime_events: broadcast::Sender<Arc<RealtimeMessage>>,
 ) -> Router {
     // クライアントの初期化
     // 具象型で保持し、各 State 注入時に必要なトレイトオブジェクトへ coerce する
@@ -154,6 +218,65 @@ pub(crate) fn build_app(
         session_manager:     session_manager.clone(),
     });
 
+    // WorkflowScheduleState はワークフローの定期作成設定の CRUD に必要
+    let workflow_schedule_state = Arc::new(WorkflowScheduleState {
+        core_service_client: core_service_client.clone(),
+        session_manager:     session_manager.clone(),
+    });
+
+    // ProxyGrantState は代理申請のための代理権限の付与・取り消しに必要
+    let proxy_grant_state = Arc::new(ProxyGrantState {
+        core_service_client: core_service_client.clone(),
+        session_manager:     session_manager.clone(),
+    });
+
+    // NotificationState はヘッダーのベルに表示するアプリ内通知の参照・既読化に必要
+    let notification_state = Arc::new(NotificationState {
+        core_service_client: core_service_client.clone(),
+        session_manager:     session_manager.clone(),
+    });
+
+    // RealtimeState はリアルタイム通知のストリーム配信に必要（Core Service は呼ばない）
+    let realtime_state = Arc::new(RealtimeState {
+        session_manager: session_manager.clone(),
+        events:          realtime_events,
+    });
+
+    // WebhookState は Webhook 管理の CRUD とテスト送信に必要
+    let webhook_state = Arc::new(WebhookState {
+        core_service_client:  core_service_client.clone(),
+        session_manager:      session_manager.clone(),
+        audit_log_repository: audit_log_repository.clone(),
+    });
+
+    // NotificationTemplateState は通知テンプレートの管理・検証・プレビューに必要
+    let notification_template_state = Arc::new(NotificationTemplateState {
+        core_service_client:  core_service_client.clone(),
+        session_manager:      session_manager.clone(),
+        audit_log_repository: audit_log_repository.clone(),
+    });
+
+    // NotificationLogState は送信に失敗した通知の確認・再送に必要
+    let notification_log_state = Arc::new(NotificationLogState {
+        core_service_client:  core_service_client.clone(),
+        session_manager:      session_manager.clone(),
+        audit_log_repository: audit_log_repository.clone(),
+    });
+
+    // TenantState はテナント設定（既定ロケール）の参照・変更に必要
+    let tenant_state = Arc::new(TenantState {
+        core_service_client:  core_service_client.clone(),
+        session_manager:      session_manager.clone(),
+        audit_log_repository: audit_log_repository.clone(),
+    });
+
+    // WorkflowAdminState はテナント管理者向けのワークフロー閲覧・強制操作に必要
+    let workflow_admin_state = Arc::new(WorkflowAdminState {
+        core_service_client:  core_service_client.clone(),
+        session_manager:      session_manager.clone(),
+        audit_log_repository: audit_log_repository.clone(),
+    });
+
     // DocumentState はドキュメント管理（Upload URL 発行・確認）に必要
     let document_state = Arc::new(DocumentState {
         core_service_client,
@@ -195,6 +318,36 @@ pub(crate) fn build_app(
         required_permission: "workflow_definition:manage".to_string(),
     };
 
+    // Webhook 管理 API 用の認可状態
+    let webhook_manage_authz = AuthzState {
+        session_manager:     session_manager.clone(),
+        required_permission: "webhook:manage".to_string(),
+    };
+
+    // 通知テンプレート管理 API 用の認可状態
+    let notification_template_manage_authz = AuthzState {
+        session_manager:     session_manager.clone(),
+        required_permission: "notification_template:manage".to_string(),
+    };
+
+    // 通知ログ管理 API 用の認可状態
+    let notification_log_manage_authz = AuthzState {
+        session_manager:     session_manager.clone(),
+        required_permission: "notification_log:manage".to_string(),
+    };
+
+    // テナント設定更新 API 用の認可状態
+    let tenant_update_authz = AuthzState {
+        session_manager:     session_manager.clone(),
+        required_permission: "tenant:update".to_string(),
+    };
+
+    // ワークフロー管理 API（テナント管理者向け）用の認可状態
+    let workflow_admin_authz = AuthzState {
+        session_manager:     session_manager.clone(),
+        required_permission: "workflow:admin".to_string(),
+    };
+
     // 監査ログ閲覧 API 用の状態と認可
     let audit_log_state = Arc::new(AuditLogState {
         audit_log_repository,
@@ -218,6 +371,7 @@ pub(crate) fn build_app(
         .route("/api/v1/auth/login", post(login))
         .route("/api/v1/auth/logout", post(logout))
         .route("/api/v1/auth/me", get(me))
+        .route("/api/v1/auth/me/locale", put(update_my_locale))
         .route("/api/v1/auth/csrf", get(csrf))
         .with_state(auth_state)
         // ワークフロー定義 API
@@ -234,7 +388,11 @@ pub(crate) fn build_app(
             "/api/v1/workflows",
             get(list_my_workflows).post(create_workflow),
         )
-        .route("/api/v1/workflows/{display_number}", get(get_workflow))
+        .route("/api/v1/workflows/search", get(search_workflows))
+        .route(
+            "/api/v1/workflows/{display_number}",
+            get(get_workflow).delete(delete_workflow),
+        )
         .route(
             "/api/v1/workflows/{display_number}/submit",
             post(submit_workflow),
@@ -251,17 +409,62 @@ pub(crate) fn build_app(
             "/api/v1/workflows/{display_number}/steps/{step_display_number}/request-changes",
             post(request_changes_step),
         )
+        .route(
+            "/api/v1/workflows/{display_number}/steps/{step_display_number}/retract",
+            post(retract_approval),
+        )
         .route(
             "/api/v1/workflows/{display_number}/resubmit",
             post(resubmit_workflow),
         )
+        .route(
+            "/api/v1/workflows/{display_number}/duplicate",
+            post(duplicate_workflow),
+        )
         // コメント API
         .route(
             "/api/v1/workflows/{display_number}/comments",
             get(list_comments).post(post_comment),
         )
+        .route(
+            "/api/v1/workflows/{display_number}/comments/{comment_id}",
+            patch(edit_comment).delete(delete_comment),
+        )
+        .route(
+            "/api/v1/workflows/{display_number}/comments/{comment_id}/revisions",
+            get(list_comment_revisions),
+        )
+        // ウォッチャー API
+        .route(
+            "/api/v1/workflows/{display_number}/watch",
+            post(watch_workflow).delete(unwatch_workflow),
+        )
+        .route(
+            "/api/v1/workflows/{display_number}/watchers",
+            get(list_watchers),
+        )
+        // フォームデータ変更履歴 API
+        .route(
+            "/api/v1/workflows/{display_number}/form-data-changes",
+            get(list_form_data_changes),
+        )
+        // アクティビティ API
+        .route(
+            "/api/v1/workflows/{display_number}/activities",
+            get(list_activities),
+        )
+        // 申請スナップショット API
+        .route(
+            "/api/v1/workflows/{display_number}/submissions",
+            get(list_submissions),
+        )
+        .route(
+            "/api/v1/workflows/{display_number}/submissions/diff",
+            get(diff_submissions),
+        )
         // タスク API
         .route("/api/v1/tasks/my", get(list_my_tasks))
+        .route("/api/v1/tasks/bulk-decision", post(bulk_decide_tasks))
         .route(
             "/api/v1/workflows/{display_number}/tasks/{step_display_number}",
             get(get_task_by_display_numbers),
@@ -279,6 +482,48 @@ pub(crate) fn build_app(
             put(update_folder).delete(delete_folder),
         )
         .with_state(folder_state)
+        // ワークフロースケジュール API
+        .route(
+            "/api/v1/workflow-schedules",
+            get(list_workflow_schedules).post(create_workflow_schedule),
+        )
+        .route(
+            "/api/v1/workflow-schedules/{schedule_id}",
+            put(update_workflow_schedule).delete(delete_workflow_schedule),
+        )
+        .with_state(workflow_schedule_state)
+        // 代理権限 API
+        .route(
+            "/api/v1/proxy-grants",
+            get(list_proxy_grants).post(create_proxy_grant),
+        )
+        .route(
+            "/api/v1/proxy-grants/{proxy_user_id}",
+            delete(delete_proxy_grant),
+        )
+        .with_state(proxy_grant_state)
+        // アプリ内通知 API
+        .route("/api/v1/notifications", get(list_notifications))
+        .route(
+            "/api/v1/notifications/preferences",
+            get(list_notification_preferences).put(update_notification_preferences),
+        )
+        .route(
+            "/api/v1/notifications/unread-count",
+            get(get_unread_notification_count),
+        )
+        .route(
+            "/api/v1/notifications/read-all",
+            post(mark_all_notifications_read),
+        )
+        .route(
+            "/api/v1/notifications/{id}/read",
+            post(mark_notification_read),
+        )
+        .with_state(notification_state)
+        // リアルタイム通知 API（Server-Sent Events）
+        .route("/api/v1/events", get(stream_events))
+        .with_state(realtime_state)
         // ドキュメント管理 API
         .route(
             "/api/v1/documents",
@@ -385,7 +630,107 @@ pub(crate) fn build_app(
                 .layer(from_fn_with_state(audit_log_read_authz, require_permission))
                 .with_state(audit_log_state),
         )
+        // Webhook 管理 API（認可ミドルウェア適用、webhook:manage 権限）
+        .merge(
+            Router::new()
+                .route("/api/v1/webhooks", get(list_webhooks).post(create_webhook))
+                .route(
+                    "/api/v1/webhooks/{webhook_id}",
+                    get(get_webhook).put(update_webhook).delete(delete_webhook),
+                )
+                .route(
+                    "/api/v1/webhooks/{webhook_id}/deliveries",
+                    get(list_webhook_deliveries),
+                )
+                .route(
+                    "/api/v1/webhooks/{webhook_id}/test",
+                    post(send_test_webhook),
+                )
+                .layer(from_fn_with_state(webhook_manage_authz, require_permission))
+                .with_state(webhook_state),
+        )
+        // 通知テンプレート管理 API（認可ミドルウェア適用、notification_template:manage 権限）
+        .merge(
+            Router::new()
+                .route(
+                    "/api/v1/notification-templates",
+                    get(list_notification_templates),
+                )
+                .route(
+                    "/api/v1/notification-templates/{event_type}",
+                    get(get_notification_template)
+                        .put(save_notification_template)
+                        .delete(delete_notification_template),
+                )
+                .route(
+                    "/api/v1/notification-templates/{event_type}/validate",
+                    post(validate_notification_template),
+                )
+                .route(
+                    "/api/v1/notification-templates/{event_type}/preview",
+                    post(preview_notification_template),
+                )
+                .layer(from_fn_with_state(
+                    notification_template_manage_authz,
+                    require_permission,
+                ))
+                .with_state(notification_template_state),
+        )
+        // 通知ログ管理 API（認可ミドルウェア適用、notification_log:manage 権限）
+        .merge(
+            Router::new()
+                .route(
+                    "/api/v1/notification-logs/failed",
+                    get(list_failed_notification_logs),
+                )
+                .route(
+                    "/api/v1/notification-logs/{id}/resend",
+                    post(resend_notification_log),
+                )
+                .layer(from_fn_with_state(
+                    notification_log_manage_authz,
+                    require_permission,
+                ))
+                .with_state(notification_log_state),
+        )
+        // テナント設定 API（更新のみ認可ミドルウェア適用、tenant:update 権限）
+        .merge(
+            Router::new()
+                .route("/api/v1/tenant/settings", get(get_tenant_settings))
+                .with_state(tenant_state.clone()),
+        )
+        .merge(
+            Router::new()
+                .route("/api/v1/tenant/settings", put(update_tenant_settings))
+                .layer(from_fn_with_state(tenant_update_authz, require_permission))
+                .with_state(tenant_state),
+        )
+        // ワークフロー管理 API（認可ミドルウェア適用、workflow:admin 権限）
+        .merge(
+            Router::new()
+                .route("/api/v1/admin/workflows", get(list_all_workflows))
+                .route(
+                    "/api/v1/admin/workflows/{display_number}",
+                    get(get_any_workflow),
+                )
+                .route(
+                    "/api/v1/admin/workflows/{display_number}/cancel",
+                    post(force_cancel_workflow),
+                )
+                .route(
+                    "/api/v1/admin/workflows/{display_number}/reassign",
+                    post(reassign_current_step),
+                )
+                .route(
+                    "/api/v1/admin/workflows/{display_number}/force-complete",
+                    post(force_complete_workflow),
+                )
+                .layer(from_fn_with_state(workflow_admin_authz, require_permission))
+                .with_state(workflow_admin_state),
+        )
         .layer(from_fn_with_state(csrf_state, csrf_middleware))
+        // エラーメッセージのローカライズ: Accept-Language に合わせて Problem Details の detail を書き換える
+        .layer(from_fn(localize_error_response))
         // キャッシュ制御: 動的 API レスポンスがブラウザにキャッシュされないようにする
         .layer(from_fn(no_cache))
         // Request ID レイヤー（レイヤー順序が重要: 下に書いたものが外側）
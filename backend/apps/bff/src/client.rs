@@ -13,53 +13,109 @@ pub use auth_service::{
     VerifyResponse,
 };
 pub use core_service::{
+    AdminActionCoreRequest,
     ApproveRejectRequest,
+    BulkDecisionCoreRequest,
+    BulkDecisionItemCoreRequest,
+    BulkDecisionItemResultDto,
     CoreServiceClient,
     CoreServiceClientImpl,
     CoreServiceDocumentClient,
     CoreServiceError,
     CoreServiceFolderClient,
+    CoreServiceNotificationClient,
+    CoreServiceNotificationLogClient,
+    CoreServiceNotificationTemplateClient,
+    CoreServiceProxyGrantClient,
     CoreServiceRoleClient,
     CoreServiceTaskClient,
+    CoreServiceTenantClient,
     CoreServiceUserClient,
+    CoreServiceWebhookClient,
+    CoreServiceWorkflowAdminClient,
     CoreServiceWorkflowClient,
+    CoreServiceWorkflowScheduleClient,
     CreateDefinitionCoreRequest,
     CreateFolderCoreRequest,
+    CreateProxyGrantCoreRequest,
     CreateRoleCoreRequest,
     CreateUserCoreRequest,
     CreateUserCoreResponse,
+    CreateWebhookCoreRequest,
     CreateWorkflowRequest,
+    CreateWorkflowScheduleCoreRequest,
     DashboardStatsDto,
     DocumentDetailCoreDto,
     DownloadUrlCoreDto,
+    DuplicateWorkflowCoreRequest,
+    EditCommentCoreRequest,
     FolderItemDto,
+    FormFieldDiffDto,
+    InboxNotificationDto,
+    MarkAllReadDto,
+    NotificationLogDto,
+    NotificationPreferenceDto,
+    NotificationTemplateDto,
+    NotificationTemplatePreviewDto,
+    NotificationTemplateValidationDto,
+    NotificationTemplateValidationErrorDto,
+    NotificationUserCoreRequest,
+    PageCoreQuery,
     PostCommentCoreRequest,
+    PreviewNotificationTemplateCoreRequest,
+    ProxyGrantDto,
+    ProxyGrantsDto,
     PublishArchiveCoreRequest,
+    ReassignStepCoreRequest,
     RequestUploadUrlCoreRequest,
+    ResendNotificationLogCoreRequest,
     ResubmitWorkflowRequest,
+    RetractApprovalCoreRequest,
     RoleDetailDto,
     RoleItemDto,
+    SaveNotificationTemplateCoreRequest,
+    ScheduleRecurrenceDto,
+    ScheduledApproverDto,
+    SearchWorkflowsCoreQuery,
     StepApproverRequest,
+    SubmissionAttachmentDto,
+    SubmissionDiffDto,
     SubmitWorkflowRequest,
     TaskDetailDto,
     TaskItemDto,
     TaskWorkflowSummaryDto,
+    TenantSettingsDto,
     UpdateDefinitionCoreRequest,
     UpdateFolderCoreRequest,
+    UpdateNotificationPreferencesCoreRequest,
     UpdateRoleCoreRequest,
+    UpdateTenantSettingsCoreRequest,
     UpdateUserCoreRequest,
+    UpdateUserLocaleCoreRequest,
     UpdateUserStatusCoreRequest,
+    UpdateWebhookCoreRequest,
+    UpdateWorkflowScheduleCoreRequest,
     UploadUrlCoreDto,
     UserItemDto,
     UserRefDto,
     UserResponse,
     UserWithPermissionsData,
     ValidateDefinitionCoreRequest,
+    ValidateNotificationTemplateCoreRequest,
     ValidationErrorDto,
     ValidationResultDto,
+    WebhookDeliveryDto,
+    WebhookDto,
+    WorkflowActivityDto,
     WorkflowCommentDto,
+    WorkflowCommentRevisionDto,
     WorkflowDefinitionDto,
+    WorkflowFormDataChangeDto,
     WorkflowInstanceDto,
     WorkflowInstanceSummaryDto,
+    WorkflowScheduleDto,
     WorkflowStepDto,
+    WorkflowSubmissionDto,
+    WorkflowViewerQuery,
+    WorkflowWatcherDto,
 };
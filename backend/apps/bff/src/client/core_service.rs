@@ -4,15 +4,24 @@
 //!
 //! ## 構成
 //!
-//! ISP（Interface Segregation Principle）に基づき、5 つのサブトレイトに分割:
+//! ISP（Interface Segregation Principle）に基づき、サブトレイトに分割:
 //!
 //! - [`CoreServiceUserClient`] — ユーザー関連
 //! - [`CoreServiceWorkflowClient`] — ワークフロー関連
 //! - [`CoreServiceTaskClient`] — タスク・ダッシュボード関連
 //! - [`CoreServiceRoleClient`] — ロール管理関連
 //! - [`CoreServiceFolderClient`] — フォルダ管理関連
+//! - [`CoreServiceDocumentClient`] — ドキュメント管理関連
+//! - [`CoreServiceWebhookClient`] — Webhook 管理関連
+//! - [`CoreServiceWorkflowAdminClient`] — テナント管理者向けワークフロー操作関連
+//! - [`CoreServiceWorkflowScheduleClient`] — ワークフロースケジュール関連
+//! - [`CoreServiceProxyGrantClient`] — 代理権限関連
+//! - [`CoreServiceNotificationClient`] — アプリ内通知関連
+//! - [`CoreServiceNotificationTemplateClient`] — 通知テンプレート関連
+//! - [`CoreServiceNotificationLogClient`] — 通知ログ（送信失敗・再送）関連
+//! - [`CoreServiceTenantClient`] — テナント設定関連
 //!
-//! [`CoreServiceClient`] はスーパートレイトとして 5 つを束ね、
+//! [`CoreServiceClient`] はスーパートレイトとしてこれらを束ね、
 //! `dyn CoreServiceClient` は引き続き使用可能。
 //!
 //! 詳細: [08_AuthService設計.md](../../../../docs/40_詳細設計書/08_AuthService設計.md)
@@ -21,19 +30,35 @@ mod client_impl;
 mod document_client;
 mod error;
 mod folder_client;
+mod notification_client;
+mod notification_log_client;
+mod notification_template_client;
+mod proxy_grant_client;
 mod response;
 mod role_client;
 mod task_client;
+mod tenant_client;
 mod types;
 mod user_client;
+mod webhook_client;
+mod workflow_admin_client;
 mod workflow_client;
+mod workflow_schedule_client;
 
 pub use client_impl::*;
 pub use document_client::*;
 pub use error::*;
 pub use folder_client::*;
+pub use notification_client::*;
+pub use notification_log_client::*;
+pub use notification_template_client::*;
+pub use proxy_grant_client::*;
 pub use role_client::*;
 pub use task_client::*;
+pub use tenant_client::*;
 pub use types::*;
 pub use user_client::*;
+pub use webhook_client::*;
+pub use workflow_admin_client::*;
 pub use workflow_client::*;
+pub use workflow_schedule_client::*;
@@ -3,16 +3,24 @@
 use super::{
     document_client::CoreServiceDocumentClient,
     folder_client::CoreServiceFolderClient,
+    notification_client::CoreServiceNotificationClient,
+    notification_log_client::CoreServiceNotificationLogClient,
+    notification_template_client::CoreServiceNotificationTemplateClient,
+    proxy_grant_client::CoreServiceProxyGrantClient,
     role_client::CoreServiceRoleClient,
     task_client::CoreServiceTaskClient,
+    tenant_client::CoreServiceTenantClient,
     user_client::CoreServiceUserClient,
+    webhook_client::CoreServiceWebhookClient,
+    workflow_admin_client::CoreServiceWorkflowAdminClient,
     workflow_client::CoreServiceWorkflowClient,
+    workflow_schedule_client::CoreServiceWorkflowScheduleClient,
 };
 
 /// Core Service クライアントトレイト（スーパートレイト）
 ///
-/// User / Workflow / Task / Role / Folder / Document の各サブトレイトを束ねる
-/// スーパートレイト。テスト時にはサブトレイト単位でスタブを使用できる。
+/// User / Workflow / Task / Role / Folder / Document / Webhook / WorkflowAdmin /
+/// WorkflowSchedule / ProxyGrant / Notification / NotificationTemplate / NotificationLog / Tenant の各サブトレイトを束ねるスーパートレイト。テスト時にはサブトレイト単位でスタブを使用できる。
 ///
 /// `dyn CoreServiceClient` はオブジェクトセーフであり、従来通り
 /// `Arc<dyn CoreServiceClient>` として使用可能。
@@ -23,10 +31,18 @@ pub trait CoreServiceClient:
     + CoreServiceRoleClient
     + CoreServiceFolderClient
     + CoreServiceDocumentClient
+    + CoreServiceWebhookClient
+    + CoreServiceWorkflowAdminClient
+    + CoreServiceWorkflowScheduleClient
+    + CoreServiceProxyGrantClient
+    + CoreServiceNotificationClient
+    + CoreServiceNotificationTemplateClient
+    + CoreServiceNotificationLogClient
+    + CoreServiceTenantClient
 {
 }
 
-/// ブランケット impl: 6 つのサブトレイトをすべて実装する型は
+/// ブランケット impl: 14 個のサブトレイトをすべて実装する型は
 /// 自動的に `CoreServiceClient` を実装する。
 impl<T> CoreServiceClient for T where
     T: CoreServiceUserClient
@@ -35,6 +51,14 @@ impl<T> CoreServiceClient for T where
         + CoreServiceRoleClient
         + CoreServiceFolderClient
         + CoreServiceDocumentClient
+        + CoreServiceWebhookClient
+        + CoreServiceWorkflowAdminClient
+        + CoreServiceWorkflowScheduleClient
+        + CoreServiceProxyGrantClient
+        + CoreServiceNotificationClient
+        + CoreServiceNotificationTemplateClient
+        + CoreServiceNotificationLogClient
+        + CoreServiceTenantClient
 {
 }
 
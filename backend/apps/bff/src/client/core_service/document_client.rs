@@ -6,12 +6,13 @@ use uuid::Uuid;
 use super::{
     client_impl::CoreServiceClientImpl,
     error::CoreServiceError,
-    response::handle_response,
+    response::{error_message, handle_response},
     types::{
         DocumentDetailCoreDto,
         DownloadUrlCoreDto,
         RequestUploadUrlCoreRequest,
         UploadUrlCoreDto,
+        WorkflowViewerQuery,
     },
 };
 use crate::middleware::request_id::inject_request_id;
@@ -39,10 +40,11 @@ pub trait CoreServiceDocumentClient: Send + Sync {
     /// ダウンロード URL を発行する
     ///
     /// Core Service の `POST /internal/documents/{document_id}/download-url` を呼び出す。
+    /// 閲覧者が添付先のワークフローを閲覧できない場合は 404 が返る。
     async fn generate_download_url(
         &self,
         document_id: Uuid,
-        tenant_id: Uuid,
+        viewer: &WorkflowViewerQuery,
     ) -> Result<DownloadUrlCoreDto, CoreServiceError>;
 
     /// ドキュメントを削除する（ソフトデリート）
@@ -67,11 +69,12 @@ pub trait CoreServiceDocumentClient: Send + Sync {
 
     /// ワークフロー添付ファイル一覧を取得する
     ///
-    /// Core Service の `GET /internal/workflows/{id}/attachments?tenant_id=...` を呼び出す。
+    /// Core Service の `GET /internal/workflows/{id}/attachments` を呼び出す。
+    /// 閲覧者がワークフローを閲覧できない場合は 404 が返る。
     async fn list_workflow_attachments(
         &self,
         workflow_instance_id: Uuid,
-        tenant_id: Uuid,
+        viewer: &WorkflowViewerQuery,
     ) -> Result<Vec<DocumentDetailCoreDto>, CoreServiceError>;
 }
 
@@ -106,18 +109,20 @@ impl CoreServiceDocumentClient for CoreServiceClientImpl {
         handle_response(response, Some(CoreServiceError::DocumentNotFound)).await
     }
 
-    #[tracing::instrument(skip_all, level = "debug", fields(%document_id, %tenant_id))]
+    #[tracing::instrument(skip_all, level = "debug", fields(%document_id, tenant_id = %viewer.tenant_id))]
     async fn generate_download_url(
         &self,
         document_id: Uuid,
-        tenant_id: Uuid,
+        viewer: &WorkflowViewerQuery,
     ) -> Result<DownloadUrlCoreDto, CoreServiceError> {
         let url = format!(
-            "{}/internal/documents/{}/download-url?tenant_id={}",
-            self.base_url, document_id, tenant_id
+            "{}/internal/documents/{}/download-url",
+            self.base_url, document_id
         );
 
-        let response = inject_request_id(self.client.post(&url)).send().await?;
+        let response = inject_request_id(self.client.post(&url).query(viewer))
+            .send()
+            .await?;
         handle_response(response, Some(CoreServiceError::DocumentNotFound)).await
     }
 
@@ -145,8 +150,10 @@ impl CoreServiceDocumentClient for CoreServiceClientImpl {
 
         let error = match status {
             reqwest::StatusCode::NOT_FOUND => CoreServiceError::DocumentNotFound,
-            reqwest::StatusCode::BAD_REQUEST => CoreServiceError::ValidationError(body),
-            reqwest::StatusCode::FORBIDDEN => CoreServiceError::Forbidden(body),
+            reqwest::StatusCode::BAD_REQUEST => {
+                CoreServiceError::ValidationError(error_message(&body))
+            }
+            reqwest::StatusCode::FORBIDDEN => CoreServiceError::Forbidden(error_message(&body)),
             _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
         };
 
@@ -168,18 +175,20 @@ impl CoreServiceDocumentClient for CoreServiceClientImpl {
         handle_response(response, None).await
     }
 
-    #[tracing::instrument(skip_all, level = "debug", fields(%workflow_instance_id, %tenant_id))]
+    #[tracing::instrument(skip_all, level = "debug", fields(%workflow_instance_id, tenant_id = %viewer.tenant_id))]
     async fn list_workflow_attachments(
         &self,
         workflow_instance_id: Uuid,
-        tenant_id: Uuid,
+        viewer: &WorkflowViewerQuery,
     ) -> Result<Vec<DocumentDetailCoreDto>, CoreServiceError> {
         let url = format!(
-            "{}/internal/workflows/{}/attachments?tenant_id={}",
-            self.base_url, workflow_instance_id, tenant_id
+            "{}/internal/workflows/{}/attachments",
+            self.base_url, workflow_instance_id
         );
 
-        let response = inject_request_id(self.client.get(&url)).send().await?;
-        handle_response(response, None).await
+        let response = inject_request_id(self.client.get(&url).query(viewer))
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
     }
 }
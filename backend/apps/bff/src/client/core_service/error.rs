@@ -1,5 +1,6 @@
 //! Core Service クライアントのエラー型
 
+use ringiflow_domain::message::Message;
 use thiserror::Error;
 
 /// Core Service クライアントエラー
@@ -21,6 +22,10 @@ pub enum CoreServiceError {
     #[error("ステップが見つかりません")]
     StepNotFound,
 
+    /// コメントが見つからない（404）
+    #[error("コメントが見つかりません")]
+    CommentNotFound,
+
     /// ロールが見つからない（404）
     #[error("ロールが見つかりません")]
     RoleNotFound,
@@ -33,13 +38,29 @@ pub enum CoreServiceError {
     #[error("ドキュメントが見つかりません")]
     DocumentNotFound,
 
+    /// Webhook が見つからない（404）
+    #[error("Webhook が見つかりません")]
+    WebhookNotFound,
+
+    /// ワークフロースケジュールが見つからない（404）
+    #[error("スケジュールが見つかりません")]
+    WorkflowScheduleNotFound,
+
+    /// 通知が見つからない（404）
+    #[error("通知が見つかりません")]
+    NotificationNotFound,
+
+    /// 通知テンプレートが見つからない（404）
+    #[error("通知テンプレートが見つかりません")]
+    NotificationTemplateNotFound,
+
     /// バリデーションエラー（400）
     #[error("バリデーションエラー: {0}")]
-    ValidationError(String),
+    ValidationError(Message),
 
     /// 権限不足（403）
     #[error("権限がありません: {0}")]
-    Forbidden(String),
+    Forbidden(Message),
 
     /// メールアドレスが既に使用されている（409）
     #[error("メールアドレスは既に使用されています")]
@@ -47,7 +68,7 @@ pub enum CoreServiceError {
 
     /// 競合（409）
     #[error("競合が発生しました: {0}")]
-    Conflict(String),
+    Conflict(Message),
 
     /// ネットワークエラー
     #[error("ネットワークエラー: {0}")]
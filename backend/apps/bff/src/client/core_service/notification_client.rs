@@ -0,0 +1,188 @@
+//! アプリ内通知関連の Core Service クライアント
+
+use async_trait::async_trait;
+use ringiflow_shared::PaginatedResponse;
+use uuid::Uuid;
+
+use super::{
+    client_impl::CoreServiceClientImpl,
+    error::CoreServiceError,
+    response::handle_response,
+    types::{
+        InboxNotificationDto,
+        MarkAllReadDto,
+        NotificationPreferenceDto,
+        NotificationUserCoreRequest,
+        PageCoreQuery,
+        UnreadCountDto,
+        UpdateNotificationPreferencesCoreRequest,
+    },
+};
+use crate::middleware::request_id::inject_request_id;
+
+/// アプリ内通知関連の Core Service クライアントトレイト
+#[async_trait]
+pub trait CoreServiceNotificationClient: Send + Sync {
+    /// 自分宛ての通知一覧を取得する
+    ///
+    /// Core Service の `GET /internal/notifications` を呼び出す。
+    async fn list_notifications(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        page: &PageCoreQuery,
+    ) -> Result<PaginatedResponse<InboxNotificationDto>, CoreServiceError>;
+
+    /// 自分宛ての未読通知数を取得する
+    ///
+    /// Core Service の `GET /internal/notifications/unread-count` を呼び出す。
+    async fn get_unread_notification_count(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<UnreadCountDto, CoreServiceError>;
+
+    /// 通知を既読にする
+    ///
+    /// Core Service の `POST /internal/notifications/{id}/read` を呼び出す。
+    async fn mark_notification_read(
+        &self,
+        notification_id: Uuid,
+        req: &NotificationUserCoreRequest,
+    ) -> Result<(), CoreServiceError>;
+
+    /// 自分宛ての未読通知をすべて既読にする
+    ///
+    /// Core Service の `POST /internal/notifications/read-all` を呼び出す。
+    async fn mark_all_notifications_read(
+        &self,
+        req: &NotificationUserCoreRequest,
+    ) -> Result<MarkAllReadDto, CoreServiceError>;
+
+    /// 自分の通知設定を取得する
+    ///
+    /// Core Service の `GET /internal/notifications/preferences` を呼び出す。
+    async fn list_notification_preferences(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<NotificationPreferenceDto>, CoreServiceError>;
+
+    /// 自分の通知設定を変更する
+    ///
+    /// Core Service の `PUT /internal/notifications/preferences` を呼び出す。
+    async fn update_notification_preferences(
+        &self,
+        req: &UpdateNotificationPreferencesCoreRequest,
+    ) -> Result<Vec<NotificationPreferenceDto>, CoreServiceError>;
+}
+
+#[async_trait]
+impl CoreServiceNotificationClient for CoreServiceClientImpl {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn list_notifications(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        page: &PageCoreQuery,
+    ) -> Result<PaginatedResponse<InboxNotificationDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/notifications?tenant_id={}&user_id={}",
+            self.base_url, tenant_id, user_id
+        );
+
+        let response = inject_request_id(self.client.get(&url).query(page))
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn get_unread_notification_count(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<UnreadCountDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/notifications/unread-count?tenant_id={}&user_id={}",
+            self.base_url, tenant_id, user_id
+        );
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%notification_id))]
+    async fn mark_notification_read(
+        &self,
+        notification_id: Uuid,
+        req: &NotificationUserCoreRequest,
+    ) -> Result<(), CoreServiceError> {
+        let url = format!(
+            "{}/internal/notifications/{}/read",
+            self.base_url, notification_id
+        );
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = response.text().await.unwrap_or_default();
+
+        let error = match status {
+            reqwest::StatusCode::NOT_FOUND => CoreServiceError::NotificationNotFound,
+            _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
+        };
+
+        Err(error)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn mark_all_notifications_read(
+        &self,
+        req: &NotificationUserCoreRequest,
+    ) -> Result<MarkAllReadDto, CoreServiceError> {
+        let url = format!("{}/internal/notifications/read-all", self.base_url);
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn list_notification_preferences(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<NotificationPreferenceDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/notifications/preferences?tenant_id={}&user_id={}",
+            self.base_url, tenant_id, user_id
+        );
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn update_notification_preferences(
+        &self,
+        req: &UpdateNotificationPreferencesCoreRequest,
+    ) -> Result<Vec<NotificationPreferenceDto>, CoreServiceError> {
+        let url = format!("{}/internal/notifications/preferences", self.base_url);
+
+        let response = inject_request_id(self.client.put(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+}
@@ -0,0 +1,65 @@
+//! 通知ログ関連の Core Service クライアント
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{
+    client_impl::CoreServiceClientImpl,
+    error::CoreServiceError,
+    response::handle_response,
+    types::{NotificationLogDto, ResendNotificationLogCoreRequest},
+};
+use crate::middleware::request_id::inject_request_id;
+
+/// 通知ログ関連の Core Service クライアントトレイト
+#[async_trait]
+pub trait CoreServiceNotificationLogClient: Send + Sync {
+    /// 送信に失敗した通知一覧を取得する
+    ///
+    /// Core Service の `GET /internal/notification-logs/failed` を呼び出す。
+    async fn list_failed_notification_logs(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Vec<NotificationLogDto>, CoreServiceError>;
+
+    /// 送信に失敗した通知を再送する
+    ///
+    /// Core Service の `POST /internal/notification-logs/{id}/resend` を呼び出す。
+    async fn resend_notification_log(
+        &self,
+        id: Uuid,
+        req: &ResendNotificationLogCoreRequest,
+    ) -> Result<NotificationLogDto, CoreServiceError>;
+}
+
+#[async_trait]
+impl CoreServiceNotificationLogClient for CoreServiceClientImpl {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn list_failed_notification_logs(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Vec<NotificationLogDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/notification-logs/failed?tenant_id={}",
+            self.base_url, tenant_id
+        );
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id))]
+    async fn resend_notification_log(
+        &self,
+        id: Uuid,
+        req: &ResendNotificationLogCoreRequest,
+    ) -> Result<NotificationLogDto, CoreServiceError> {
+        let url = format!("{}/internal/notification-logs/{}/resend", self.base_url, id);
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::NotificationNotFound)).await
+    }
+}
@@ -0,0 +1,197 @@
+//! 通知テンプレート関連の Core Service クライアント
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{
+    client_impl::CoreServiceClientImpl,
+    error::CoreServiceError,
+    response::{error_message, handle_response},
+    types::{
+        NotificationTemplateDto,
+        NotificationTemplatePreviewDto,
+        NotificationTemplateValidationDto,
+        PreviewNotificationTemplateCoreRequest,
+        SaveNotificationTemplateCoreRequest,
+        ValidateNotificationTemplateCoreRequest,
+    },
+};
+use crate::middleware::request_id::inject_request_id;
+
+/// 通知テンプレート関連の Core Service クライアントトレイト
+#[async_trait]
+pub trait CoreServiceNotificationTemplateClient: Send + Sync {
+    /// テナントが上書きした通知テンプレート一覧を取得する
+    ///
+    /// Core Service の `GET /internal/notification-templates` を呼び出す。
+    async fn list_notification_templates(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Vec<NotificationTemplateDto>, CoreServiceError>;
+
+    /// 通知テンプレートを取得する
+    ///
+    /// Core Service の `GET /internal/notification-templates/{event_type}` を呼び出す。
+    async fn get_notification_template(
+        &self,
+        event_type: &str,
+        tenant_id: Uuid,
+    ) -> Result<NotificationTemplateDto, CoreServiceError>;
+
+    /// 通知テンプレートを保存する
+    ///
+    /// Core Service の `PUT /internal/notification-templates/{event_type}` を呼び出す。
+    async fn save_notification_template(
+        &self,
+        event_type: &str,
+        req: &SaveNotificationTemplateCoreRequest,
+    ) -> Result<NotificationTemplateDto, CoreServiceError>;
+
+    /// 通知テンプレートを削除する
+    ///
+    /// Core Service の `DELETE /internal/notification-templates/{event_type}` を呼び出す。
+    async fn delete_notification_template(
+        &self,
+        event_type: &str,
+        tenant_id: Uuid,
+    ) -> Result<(), CoreServiceError>;
+
+    /// 通知テンプレートを保存せずに検証する
+    ///
+    /// Core Service の `POST /internal/notification-templates/{event_type}/validate` を呼び出す。
+    async fn validate_notification_template(
+        &self,
+        event_type: &str,
+        req: &ValidateNotificationTemplateCoreRequest,
+    ) -> Result<NotificationTemplateValidationDto, CoreServiceError>;
+
+    /// 通知テンプレートのプレビューを生成する
+    ///
+    /// Core Service の `POST /internal/notification-templates/{event_type}/preview` を呼び出す。
+    async fn preview_notification_template(
+        &self,
+        event_type: &str,
+        req: &PreviewNotificationTemplateCoreRequest,
+    ) -> Result<NotificationTemplatePreviewDto, CoreServiceError>;
+}
+
+#[async_trait]
+impl CoreServiceNotificationTemplateClient for CoreServiceClientImpl {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn list_notification_templates(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<Vec<NotificationTemplateDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/notification-templates?tenant_id={}",
+            self.base_url, tenant_id
+        );
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%event_type, %tenant_id))]
+    async fn get_notification_template(
+        &self,
+        event_type: &str,
+        tenant_id: Uuid,
+    ) -> Result<NotificationTemplateDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/notification-templates/{}?tenant_id={}",
+            self.base_url, event_type, tenant_id
+        );
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(
+            response,
+            Some(CoreServiceError::NotificationTemplateNotFound),
+        )
+        .await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%event_type))]
+    async fn save_notification_template(
+        &self,
+        event_type: &str,
+        req: &SaveNotificationTemplateCoreRequest,
+    ) -> Result<NotificationTemplateDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/notification-templates/{}",
+            self.base_url, event_type
+        );
+
+        let response = inject_request_id(self.client.put(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%event_type, %tenant_id))]
+    async fn delete_notification_template(
+        &self,
+        event_type: &str,
+        tenant_id: Uuid,
+    ) -> Result<(), CoreServiceError> {
+        let url = format!(
+            "{}/internal/notification-templates/{}?tenant_id={}",
+            self.base_url, event_type, tenant_id
+        );
+
+        let response = inject_request_id(self.client.delete(&url)).send().await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = response.text().await.unwrap_or_default();
+
+        let error = match status {
+            reqwest::StatusCode::NOT_FOUND => CoreServiceError::NotificationTemplateNotFound,
+            reqwest::StatusCode::BAD_REQUEST => {
+                CoreServiceError::ValidationError(error_message(&body))
+            }
+            _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
+        };
+
+        Err(error)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%event_type))]
+    async fn validate_notification_template(
+        &self,
+        event_type: &str,
+        req: &ValidateNotificationTemplateCoreRequest,
+    ) -> Result<NotificationTemplateValidationDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/notification-templates/{}/validate",
+            self.base_url, event_type
+        );
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%event_type))]
+    async fn preview_notification_template(
+        &self,
+        event_type: &str,
+        req: &PreviewNotificationTemplateCoreRequest,
+    ) -> Result<NotificationTemplatePreviewDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/notification-templates/{}/preview",
+            self.base_url, event_type
+        );
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+}
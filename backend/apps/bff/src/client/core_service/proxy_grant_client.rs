@@ -0,0 +1,101 @@
+//! 代理権限関連の Core Service クライアント
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{
+    client_impl::CoreServiceClientImpl,
+    error::CoreServiceError,
+    response::handle_response,
+    types::{CreateProxyGrantCoreRequest, ProxyGrantDto, ProxyGrantsDto},
+};
+use crate::middleware::request_id::inject_request_id;
+
+/// 代理権限関連の Core Service クライアントトレイト
+#[async_trait]
+pub trait CoreServiceProxyGrantClient: Send + Sync {
+    /// 自分が付与した・付与されている代理権限一覧を取得する
+    ///
+    /// Core Service の `GET /internal/proxy-grants` を呼び出す。
+    async fn list_proxy_grants(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ProxyGrantsDto, CoreServiceError>;
+
+    /// 代理権限を付与する
+    ///
+    /// Core Service の `POST /internal/proxy-grants` を呼び出す。
+    async fn create_proxy_grant(
+        &self,
+        req: &CreateProxyGrantCoreRequest,
+    ) -> Result<ProxyGrantDto, CoreServiceError>;
+
+    /// 代理権限を取り消す
+    ///
+    /// Core Service の `DELETE /internal/proxy-grants/{proxy_user_id}` を呼び出す。
+    async fn delete_proxy_grant(
+        &self,
+        proxy_user_id: Uuid,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), CoreServiceError>;
+}
+
+#[async_trait]
+impl CoreServiceProxyGrantClient for CoreServiceClientImpl {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn list_proxy_grants(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<ProxyGrantsDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/proxy-grants?tenant_id={}&user_id={}",
+            self.base_url, tenant_id, user_id
+        );
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn create_proxy_grant(
+        &self,
+        req: &CreateProxyGrantCoreRequest,
+    ) -> Result<ProxyGrantDto, CoreServiceError> {
+        let url = format!("{}/internal/proxy-grants", self.base_url);
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%proxy_user_id, %tenant_id))]
+    async fn delete_proxy_grant(
+        &self,
+        proxy_user_id: Uuid,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), CoreServiceError> {
+        let url = format!(
+            "{}/internal/proxy-grants/{}?tenant_id={}&user_id={}",
+            self.base_url, proxy_user_id, tenant_id, user_id
+        );
+
+        let response = inject_request_id(self.client.delete(&url)).send().await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = response.text().await.unwrap_or_default();
+        Err(CoreServiceError::Unexpected(format!(
+            "予期しないステータス {}: {}",
+            status, body
+        )))
+    }
+}
@@ -1,5 +1,7 @@
 //! Core Service レスポンスの共通ハンドリング
 
+use ringiflow_domain::message::Message;
+use ringiflow_shared::ErrorResponse;
 use serde::de::DeserializeOwned;
 
 use super::error::CoreServiceError;
@@ -34,15 +36,31 @@ pub(super) async fn handle_response<T: DeserializeOwned>(
     let body = response.text().await.unwrap_or_default();
 
     let error = match status {
-        reqwest::StatusCode::BAD_REQUEST => CoreServiceError::ValidationError(body),
-        reqwest::StatusCode::FORBIDDEN => CoreServiceError::Forbidden(body),
-        reqwest::StatusCode::CONFLICT => CoreServiceError::Conflict(body),
+        reqwest::StatusCode::BAD_REQUEST => CoreServiceError::ValidationError(error_message(&body)),
+        reqwest::StatusCode::FORBIDDEN => CoreServiceError::Forbidden(error_message(&body)),
+        reqwest::StatusCode::CONFLICT => CoreServiceError::Conflict(error_message(&body)),
         _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
     };
 
     Err(error)
 }
 
+/// Core Service のエラーレスポンスボディからメッセージを取り出す
+///
+/// メッセージコードがあればコードとパラメータを引き継ぎ、BFF でロケールに合わせて展開できるようにする。
+/// コードがない場合は `detail`（Problem Details でなければボディ全体）をそのまま表示する。
+pub(super) fn error_message(body: &str) -> Message {
+    match serde_json::from_str::<ErrorResponse>(body) {
+        Ok(ErrorResponse {
+            message_code: Some(code),
+            params,
+            ..
+        }) => Message::from_parts(code, params),
+        Ok(error) => Message::text(error.detail),
+        Err(_) => Message::text(body),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde::Deserialize;
@@ -115,7 +133,55 @@ mod tests {
 
         assert!(matches!(
             result,
-            Err(CoreServiceError::ValidationError(body)) if body == "invalid input"
+            Err(CoreServiceError::ValidationError(message)) if message == Message::text("invalid input")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_400でメッセージコードとパラメータを引き継ぐ() {
+        let response = make_response(
+            400,
+            r#"{
+                "type": "https://ringiflow.example.com/errors/bad-request",
+                "code": "bad-request",
+                "title": "Bad Request",
+                "status": 400,
+                "detail": "ユーザー名は 100 文字以内である必要があります",
+                "message_code": "too-long",
+                "params": {"field": "user-name", "max": "100"}
+            }"#,
+        );
+
+        let result: Result<TestData, _> = handle_response(response, None).await;
+
+        let expected = Message::new("too-long")
+            .with("field", "user-name")
+            .with("max", 100);
+        assert!(matches!(
+            result,
+            Err(CoreServiceError::ValidationError(message)) if message == expected
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_400でメッセージコードがない場合はdetailをそのまま使う() {
+        let response = make_response(
+            400,
+            r#"{
+                "type": "https://ringiflow.example.com/errors/bad-request",
+                "code": "bad-request",
+                "title": "Bad Request",
+                "status": 400,
+                "detail": "不正なリクエスト"
+            }"#,
+        );
+
+        let result: Result<TestData, _> = handle_response(response, None).await;
+
+        assert!(matches!(
+            result,
+            Err(CoreServiceError::ValidationError(message))
+                if message == Message::text("不正なリクエスト")
         ));
     }
 
@@ -127,7 +193,7 @@ mod tests {
 
         assert!(matches!(
             result,
-            Err(CoreServiceError::Forbidden(body)) if body == "access denied"
+            Err(CoreServiceError::Forbidden(message)) if message == Message::text("access denied")
         ));
     }
 
@@ -139,7 +205,7 @@ mod tests {
 
         assert!(matches!(
             result,
-            Err(CoreServiceError::Conflict(body)) if body == "conflict occurred"
+            Err(CoreServiceError::Conflict(message)) if message == Message::text("conflict occurred")
         ));
     }
 
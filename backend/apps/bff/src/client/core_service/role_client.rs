@@ -6,7 +6,7 @@ use uuid::Uuid;
 use super::{
     client_impl::CoreServiceClientImpl,
     error::CoreServiceError,
-    response::handle_response,
+    response::{error_message, handle_response},
     types::{CreateRoleCoreRequest, RoleDetailDto, RoleItemDto, UpdateRoleCoreRequest},
 };
 use crate::middleware::request_id::inject_request_id;
@@ -129,8 +129,10 @@ impl CoreServiceRoleClient for CoreServiceClientImpl {
 
         let error = match status {
             reqwest::StatusCode::NOT_FOUND => CoreServiceError::RoleNotFound,
-            reqwest::StatusCode::BAD_REQUEST => CoreServiceError::ValidationError(body),
-            reqwest::StatusCode::CONFLICT => CoreServiceError::Conflict(body),
+            reqwest::StatusCode::BAD_REQUEST => {
+                CoreServiceError::ValidationError(error_message(&body))
+            }
+            reqwest::StatusCode::CONFLICT => CoreServiceError::Conflict(error_message(&body)),
             _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
         };
 
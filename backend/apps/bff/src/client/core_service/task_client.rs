@@ -1,13 +1,21 @@
 //! タスク・ダッシュボード関連の Core Service クライアント
 
 use async_trait::async_trait;
+use ringiflow_shared::PaginatedResponse;
 use uuid::Uuid;
 
 use super::{
     client_impl::CoreServiceClientImpl,
     error::CoreServiceError,
     response::handle_response,
-    types::{DashboardStatsDto, TaskDetailDto, TaskItemDto},
+    types::{
+        BulkDecisionCoreRequest,
+        BulkDecisionItemResultDto,
+        DashboardStatsDto,
+        PageCoreQuery,
+        TaskDetailDto,
+        TaskItemDto,
+    },
 };
 use crate::middleware::request_id::inject_request_id;
 
@@ -21,7 +29,8 @@ pub trait CoreServiceTaskClient: Send + Sync {
         &self,
         tenant_id: Uuid,
         user_id: Uuid,
-    ) -> Result<Vec<TaskItemDto>, CoreServiceError>;
+        page: &PageCoreQuery,
+    ) -> Result<PaginatedResponse<TaskItemDto>, CoreServiceError>;
 
     /// タスク詳細を取得する
     ///
@@ -54,6 +63,16 @@ pub trait CoreServiceTaskClient: Send + Sync {
         tenant_id: Uuid,
         user_id: Uuid,
     ) -> Result<TaskDetailDto, CoreServiceError>;
+
+    /// 複数のステップを一括で承認/却下する
+    ///
+    /// Core Service の `POST /internal/workflows/bulk-decision` を呼び出す。
+    /// 項目ごとの失敗は結果の `outcome` で返るため、エラーになるのは
+    /// リクエスト全体が不正な場合のみ。
+    async fn bulk_decide_steps(
+        &self,
+        req: &BulkDecisionCoreRequest,
+    ) -> Result<Vec<BulkDecisionItemResultDto>, CoreServiceError>;
 }
 
 #[async_trait]
@@ -63,13 +82,16 @@ impl CoreServiceTaskClient for CoreServiceClientImpl {
         &self,
         tenant_id: Uuid,
         user_id: Uuid,
-    ) -> Result<Vec<TaskItemDto>, CoreServiceError> {
+        page: &PageCoreQuery,
+    ) -> Result<PaginatedResponse<TaskItemDto>, CoreServiceError> {
         let url = format!(
             "{}/internal/tasks/my?tenant_id={}&user_id={}",
             self.base_url, tenant_id, user_id
         );
 
-        let response = inject_request_id(self.client.get(&url)).send().await?;
+        let response = inject_request_id(self.client.get(&url).query(page))
+            .send()
+            .await?;
         handle_response(response, None).await
     }
 
@@ -120,4 +142,18 @@ impl CoreServiceTaskClient for CoreServiceClientImpl {
         let response = inject_request_id(self.client.get(&url)).send().await?;
         handle_response(response, Some(CoreServiceError::StepNotFound)).await
     }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(item_count = req.items.len()))]
+    async fn bulk_decide_steps(
+        &self,
+        req: &BulkDecisionCoreRequest,
+    ) -> Result<Vec<BulkDecisionItemResultDto>, CoreServiceError> {
+        let url = format!("{}/internal/workflows/bulk-decision", self.base_url);
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
 }
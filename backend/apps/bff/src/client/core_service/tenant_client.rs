@@ -0,0 +1,62 @@
+//! テナント設定関連の Core Service クライアント
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{
+    client_impl::CoreServiceClientImpl,
+    error::CoreServiceError,
+    response::handle_response,
+    types::{TenantSettingsDto, UpdateTenantSettingsCoreRequest},
+};
+use crate::middleware::request_id::inject_request_id;
+
+/// テナント設定関連の Core Service クライアントトレイト
+#[async_trait]
+pub trait CoreServiceTenantClient: Send + Sync {
+    /// テナント設定を取得する
+    ///
+    /// Core Service の `GET /internal/tenants/{tenant_id}/settings` を呼び出す。
+    async fn get_tenant_settings(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<TenantSettingsDto, CoreServiceError>;
+
+    /// テナント設定を更新する
+    ///
+    /// Core Service の `PUT /internal/tenants/{tenant_id}/settings` を呼び出す。
+    async fn update_tenant_settings(
+        &self,
+        tenant_id: Uuid,
+        req: &UpdateTenantSettingsCoreRequest,
+    ) -> Result<TenantSettingsDto, CoreServiceError>;
+}
+
+#[async_trait]
+impl CoreServiceTenantClient for CoreServiceClientImpl {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn get_tenant_settings(
+        &self,
+        tenant_id: Uuid,
+    ) -> Result<TenantSettingsDto, CoreServiceError> {
+        let url = format!("{}/internal/tenants/{}/settings", self.base_url, tenant_id);
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn update_tenant_settings(
+        &self,
+        tenant_id: Uuid,
+        req: &UpdateTenantSettingsCoreRequest,
+    ) -> Result<TenantSettingsDto, CoreServiceError> {
+        let url = format!("{}/internal/tenants/{}/settings", self.base_url, tenant_id);
+
+        let response = inject_request_id(self.client.put(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+}
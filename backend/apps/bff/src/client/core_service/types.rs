@@ -1,8 +1,25 @@
 //! Core Service クライアントの DTO / リクエスト型
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+// --- ページネーション ---
+
+/// ページ指定クエリ（Core Service 内部 API 用）
+///
+/// 一覧系エンドポイントのクエリ文字列に追加する。未指定の項目は送信しない。
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PageCoreQuery {
+    /// 前ページの `next_cursor`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    /// 取得件数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit:  Option<i64>,
+}
+
 // --- レスポンス型 ---
 
 /// ユーザー情報レスポンス
@@ -13,6 +30,8 @@ pub struct UserResponse {
     pub email:     String,
     pub name:      String,
     pub status:    String,
+    /// ユーザーが選んだロケール（未設定ならテナントの既定ロケールに従う）
+    pub locale:    Option<String>,
 }
 
 /// ユーザー詳細データ（権限付き）
@@ -22,6 +41,8 @@ pub struct UserWithPermissionsData {
     pub tenant_name: String,
     pub roles:       Vec<String>,
     pub permissions: Vec<String>,
+    /// 実際に使うロケール（ユーザーのロケール → テナントの既定ロケール）
+    pub locale:      String,
 }
 
 /// ユーザー一覧の要素 DTO
@@ -71,6 +92,13 @@ pub struct UpdateUserStatusCoreRequest {
     pub requester_id: Uuid,
 }
 
+/// ユーザーロケール変更リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct UpdateUserLocaleCoreRequest {
+    pub tenant_id: Uuid,
+    pub locale:    Option<String>,
+}
+
 // --- ユーザー参照型 ---
 
 /// ユーザー参照 DTO（Core Service からのデシリアライズ用）
@@ -88,10 +116,20 @@ pub struct CreateWorkflowRequest {
     pub definition_id: Uuid,
     pub title:         String,
     pub form_data:     serde_json::Value,
+    pub on_behalf_of:  Option<Uuid>,
     pub tenant_id:     Uuid,
     pub user_id:       Uuid,
 }
 
+/// ワークフロー複製リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct DuplicateWorkflowCoreRequest {
+    pub copy_attachments:  bool,
+    pub tenant_id:         Uuid,
+    pub user_id:           Uuid,
+    pub is_workflow_admin: bool,
+}
+
 /// ステップ承認者リクエスト（Core Service 内部 API 用）
 #[derive(Debug, Serialize)]
 pub struct StepApproverRequest {
@@ -109,8 +147,18 @@ pub struct SubmitWorkflowRequest {
 /// ステップ承認/却下リクエスト（Core Service 内部 API 用）
 #[derive(Debug, Serialize)]
 pub struct ApproveRejectRequest {
+    pub version:         i32,
+    pub comment:         Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub form_data_edits: Option<serde_json::Map<String, serde_json::Value>>,
+    pub tenant_id:       Uuid,
+    pub user_id:         Uuid,
+}
+
+/// 承認取り消しリクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct RetractApprovalCoreRequest {
     pub version:   i32,
-    pub comment:   Option<String>,
     pub tenant_id: Uuid,
     pub user_id:   Uuid,
 }
@@ -161,6 +209,8 @@ pub struct WorkflowInstanceSummaryDto {
     pub version: i32,
     pub form_data: serde_json::Value,
     pub initiated_by: UserRefDto,
+    #[serde(default)]
+    pub submitted_by: Option<UserRefDto>,
     pub current_step_id: Option<String>,
     pub submitted_at: Option<String>,
     pub completed_at: Option<String>,
@@ -183,6 +233,8 @@ pub struct WorkflowInstanceDto {
     pub version: i32,
     pub form_data: serde_json::Value,
     pub initiated_by: UserRefDto,
+    #[serde(default)]
+    pub submitted_by: Option<UserRefDto>,
     pub current_step_id: Option<String>,
     #[serde(default)]
     pub steps: Vec<WorkflowStepDto>,
@@ -195,15 +247,79 @@ pub struct WorkflowInstanceDto {
 /// ワークフロー定義 DTO
 #[derive(Debug, Clone, Deserialize)]
 pub struct WorkflowDefinitionDto {
-    pub id:          String,
-    pub name:        String,
+    pub id: String,
+    pub name: String,
     pub description: Option<String>,
+    pub version: i32,
+    pub definition: serde_json::Value,
+    pub status: String,
+    pub confidential: bool,
+    pub default_watchers: Vec<String>,
+    pub created_by: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// ワークフロー閲覧者クエリ（Core Service 内部 API 用）
+///
+/// ワークフローの参照系 GET リクエストのクエリ文字列にシリアライズする。
+/// Core Service はこの閲覧者をもとに閲覧権限をチェックする。
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowViewerQuery {
+    pub tenant_id:         Uuid,
+    pub user_id:           Uuid,
+    /// `workflow:admin` 権限を持つか
+    pub is_workflow_admin: bool,
+}
+
+/// ワークフロー検索クエリ（Core Service 内部 API 用）
+///
+/// `GET /internal/workflows/search` のクエリ文字列にシリアライズする。
+/// 複数指定可能な条件はカンマ区切りの文字列で渡す。
+#[derive(Debug, Default, Serialize)]
+pub struct SearchWorkflowsCoreQuery {
+    pub tenant_id: Uuid,
+    pub user_id: Uuid,
+    pub is_workflow_admin: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub definition_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiated_by: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_approver: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+// --- テナント管理者向けワークフロー操作リクエスト型 ---
+
+/// ワークフロー強制取消・強制完了リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct AdminActionCoreRequest {
+    pub version:   i32,
+    pub reason:    String,
+    pub tenant_id: Uuid,
+    pub user_id:   Uuid,
+}
+
+/// ステップ担当者変更リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct ReassignStepCoreRequest {
     pub version:     i32,
-    pub definition:  serde_json::Value,
-    pub status:      String,
-    pub created_by:  String,
-    pub created_at:  String,
-    pub updated_at:  String,
+    pub assigned_to: Uuid,
+    pub reason:      String,
+    pub tenant_id:   Uuid,
+    pub user_id:     Uuid,
 }
 
 // --- ワークフロー定義管理リクエスト型 ---
@@ -211,21 +327,27 @@ pub struct WorkflowDefinitionDto {
 /// ワークフロー定義作成リクエスト（Core Service 内部 API 用）
 #[derive(Debug, Serialize)]
 pub struct CreateDefinitionCoreRequest {
-    pub name:        String,
+    pub name: String,
     pub description: Option<String>,
-    pub definition:  serde_json::Value,
-    pub tenant_id:   Uuid,
-    pub user_id:     Uuid,
+    pub definition: serde_json::Value,
+    pub confidential: bool,
+    pub default_watchers: Vec<Uuid>,
+    pub tenant_id: Uuid,
+    pub user_id: Uuid,
 }
 
 /// ワークフロー定義更新リクエスト（Core Service 内部 API 用）
 #[derive(Debug, Serialize)]
 pub struct UpdateDefinitionCoreRequest {
-    pub name:        String,
+    pub name: String,
     pub description: Option<String>,
-    pub definition:  serde_json::Value,
-    pub version:     i32,
-    pub tenant_id:   Uuid,
+    pub definition: serde_json::Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidential: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_watchers: Option<Vec<Uuid>>,
+    pub version: i32,
+    pub tenant_id: Uuid,
 }
 
 /// ワークフロー定義公開/アーカイブリクエスト（Core Service 内部 API 用）
@@ -292,6 +414,40 @@ pub struct TaskDetailDto {
     pub workflow: WorkflowInstanceDto,
 }
 
+/// 一括判断の対象ステップ（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct BulkDecisionItemCoreRequest {
+    pub workflow_display_number: i64,
+    pub step_display_number: i64,
+    pub version: i32,
+}
+
+/// ステップ一括承認/却下リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct BulkDecisionCoreRequest {
+    /// `approve` / `reject`
+    pub action:    String,
+    pub items:     Vec<BulkDecisionItemCoreRequest>,
+    pub comment:   Option<String>,
+    pub tenant_id: Uuid,
+    pub user_id:   Uuid,
+}
+
+/// 一括判断の項目ごとの結果 DTO
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkDecisionItemResultDto {
+    pub workflow_display_number: i64,
+    pub step_display_number: i64,
+    pub outcome: String,
+    pub message: Option<String>,
+    /// `message` の元になったメッセージコード
+    #[serde(default)]
+    pub message_code: Option<String>,
+    /// `message_code` のパラメータ
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+}
+
 // --- ロール関連の型 ---
 
 /// ロール一覧の要素 DTO
@@ -339,6 +495,15 @@ pub struct UpdateRoleCoreRequest {
 /// コメント投稿リクエスト（Core Service 内部 API 用）
 #[derive(Debug, Serialize)]
 pub struct PostCommentCoreRequest {
+    pub body:      String,
+    pub parent_id: Option<Uuid>,
+    pub tenant_id: Uuid,
+    pub user_id:   Uuid,
+}
+
+/// コメント編集リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct EditCommentCoreRequest {
     pub body:      String,
     pub tenant_id: Uuid,
     pub user_id:   Uuid,
@@ -348,11 +513,91 @@ pub struct PostCommentCoreRequest {
 #[derive(Debug, Clone, Deserialize)]
 pub struct WorkflowCommentDto {
     pub id:         String,
+    pub parent_id:  Option<String>,
     pub posted_by:  UserRefDto,
     pub body:       String,
+    pub edited_at:  Option<String>,
+    pub deleted_at: Option<String>,
     pub created_at: String,
 }
 
+/// ワークフローコメント編集履歴 DTO
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowCommentRevisionDto {
+    pub id:        String,
+    pub body:      String,
+    pub edited_by: UserRefDto,
+    pub edited_at: String,
+}
+
+/// ワークフローウォッチャー DTO
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowWatcherDto {
+    pub user:       UserRefDto,
+    pub created_at: String,
+}
+
+/// フォームデータ変更履歴 DTO
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowFormDataChangeDto {
+    pub id:         String,
+    pub step_id:    String,
+    pub field_id:   String,
+    pub old_value:  serde_json::Value,
+    pub new_value:  serde_json::Value,
+    pub changed_by: UserRefDto,
+    pub changed_at: String,
+}
+
+/// ワークフローアクティビティ DTO
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowActivityDto {
+    pub id: String,
+    pub activity_type: String,
+    pub actor: Option<UserRefDto>,
+    pub step_id: Option<String>,
+    pub details: serde_json::Value,
+    pub occurred_at: String,
+}
+
+/// 申請スナップショットの添付ファイル DTO
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubmissionAttachmentDto {
+    pub document_id:  String,
+    pub filename:     String,
+    pub content_type: String,
+    pub size:         i64,
+}
+
+/// 申請スナップショット DTO
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowSubmissionDto {
+    pub id:           String,
+    pub round:        i32,
+    pub form_data:    serde_json::Value,
+    pub attachments:  Vec<SubmissionAttachmentDto>,
+    pub submitted_by: UserRefDto,
+    pub submitted_at: String,
+}
+
+/// フィールド単位の差分 DTO
+#[derive(Debug, Clone, Deserialize)]
+pub struct FormFieldDiffDto {
+    pub field_id:  String,
+    pub old_value: serde_json::Value,
+    pub new_value: serde_json::Value,
+}
+
+/// 申請ラウンド間の差分 DTO
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubmissionDiffDto {
+    pub from_round: i32,
+    pub to_round: i32,
+    pub form_data: Vec<FormFieldDiffDto>,
+    pub added_attachments: Vec<SubmissionAttachmentDto>,
+    pub removed_attachments: Vec<SubmissionAttachmentDto>,
+}
+
 // --- フォルダ関連の型 ---
 
 /// フォルダ DTO（Core Service からのデシリアライズ用）
@@ -384,6 +629,315 @@ pub struct UpdateFolderCoreRequest {
     pub parent_id: Option<Option<Uuid>>,
 }
 
+// --- Webhook 関連の型 ---
+
+/// Webhook DTO（Core Service からのデシリアライズ用）
+///
+/// シークレットは Core Service のレスポンスに含まれない。
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookDto {
+    pub id:          Uuid,
+    pub name:        String,
+    pub url:         String,
+    pub event_types: Vec<String>,
+    pub is_active:   bool,
+    pub created_by:  Uuid,
+    pub created_at:  String,
+    pub updated_at:  String,
+}
+
+/// Webhook 配信 DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookDeliveryDto {
+    pub id: Uuid,
+    pub event_id: Option<Uuid>,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: String,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub delivered_at: Option<String>,
+}
+
+/// Webhook 作成リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct CreateWebhookCoreRequest {
+    pub tenant_id:   Uuid,
+    pub name:        String,
+    pub url:         String,
+    pub event_types: Vec<String>,
+    pub secret:      String,
+    pub created_by:  Uuid,
+}
+
+/// Webhook 更新リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct UpdateWebhookCoreRequest {
+    pub tenant_id:   Uuid,
+    pub name:        Option<String>,
+    pub url:         Option<String>,
+    pub event_types: Option<Vec<String>>,
+    pub secret:      Option<String>,
+    pub is_active:   Option<bool>,
+}
+
+// --- ワークフロースケジュール関連の型 ---
+
+/// 繰り返し設定（Core Service とのやり取り用、時刻は UTC）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduleRecurrenceDto {
+    /// 毎月 N 日（存在しない日は月末）
+    Monthly {
+        day:    u32,
+        hour:   u32,
+        minute: u32,
+    },
+    /// cron 形式（分 時 日 月 曜日）
+    Cron { expression: String },
+}
+
+/// 自動申請時の承認者（Core Service とのやり取り用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledApproverDto {
+    pub step_id:     String,
+    pub assigned_to: Uuid,
+}
+
+/// ワークフロースケジュール DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkflowScheduleDto {
+    pub id: Uuid,
+    pub definition_id: Uuid,
+    pub name: String,
+    pub title: String,
+    pub form_data: serde_json::Value,
+    pub recurrence: ScheduleRecurrenceDto,
+    pub time_zone: String,
+    pub auto_submit: bool,
+    pub approvers: Vec<ScheduledApproverDto>,
+    pub is_active: bool,
+    pub next_run_at: Option<String>,
+    pub last_run_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// ワークフロースケジュール作成リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct CreateWorkflowScheduleCoreRequest {
+    pub tenant_id:     Uuid,
+    pub user_id:       Uuid,
+    pub definition_id: Uuid,
+    pub name:          String,
+    pub title:         String,
+    pub form_data:     serde_json::Value,
+    pub recurrence:    ScheduleRecurrenceDto,
+    pub time_zone:     Option<String>,
+    pub auto_submit:   bool,
+    pub approvers:     Vec<ScheduledApproverDto>,
+}
+
+/// ワークフロースケジュール更新リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct UpdateWorkflowScheduleCoreRequest {
+    pub tenant_id:   Uuid,
+    pub user_id:     Uuid,
+    pub name:        Option<String>,
+    pub title:       Option<String>,
+    pub form_data:   Option<serde_json::Value>,
+    pub recurrence:  Option<ScheduleRecurrenceDto>,
+    pub time_zone:   Option<String>,
+    pub auto_submit: Option<bool>,
+    pub approvers:   Option<Vec<ScheduledApproverDto>>,
+    pub is_active:   Option<bool>,
+}
+
+// --- 代理権限関連の型 ---
+
+/// 代理権限付与リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct CreateProxyGrantCoreRequest {
+    pub tenant_id:     Uuid,
+    pub user_id:       Uuid,
+    pub proxy_user_id: Uuid,
+}
+
+/// 代理権限 DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProxyGrantDto {
+    pub principal:  UserRefDto,
+    pub proxy:      UserRefDto,
+    pub created_at: String,
+}
+
+/// 代理権限一覧 DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProxyGrantsDto {
+    pub granted:  Vec<ProxyGrantDto>,
+    pub received: Vec<ProxyGrantDto>,
+}
+
+// --- アプリ内通知関連の型 ---
+
+/// 既読化リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct NotificationUserCoreRequest {
+    pub tenant_id: Uuid,
+    pub user_id:   Uuid,
+}
+
+/// アプリ内通知 DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct InboxNotificationDto {
+    pub id: String,
+    pub event_type: String,
+    pub workflow_instance_id: String,
+    pub workflow_title: String,
+    pub workflow_display_id: String,
+    pub message: String,
+    pub read_at: Option<String>,
+    pub created_at: String,
+}
+
+/// 未読通知数 DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct UnreadCountDto {
+    pub unread_count: i64,
+}
+
+/// 一括既読化の結果 DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarkAllReadDto {
+    pub updated_count: u64,
+}
+
+/// 通知設定 DTO（Core Service との送受信用）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationPreferenceDto {
+    pub event_type: String,
+    pub delivery:   String,
+}
+
+/// 通知設定変更リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct UpdateNotificationPreferencesCoreRequest {
+    pub tenant_id:   Uuid,
+    pub user_id:     Uuid,
+    pub preferences: Vec<NotificationPreferenceDto>,
+}
+
+// --- 通知テンプレート関連の型 ---
+
+/// 通知テンプレート DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationTemplateDto {
+    pub event_type: String,
+    pub subject:    String,
+    pub html_body:  String,
+    pub text_body:  String,
+    pub updated_by: Uuid,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// 通知テンプレート検証結果 DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationTemplateValidationDto {
+    pub valid:  bool,
+    pub errors: Vec<NotificationTemplateValidationErrorDto>,
+}
+
+/// 通知テンプレート検証エラー DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationTemplateValidationErrorDto {
+    pub field:   String,
+    pub message: String,
+}
+
+/// 通知テンプレートプレビュー DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationTemplatePreviewDto {
+    pub subject:   String,
+    pub html_body: String,
+    pub text_body: String,
+}
+
+/// 通知テンプレート保存リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct SaveNotificationTemplateCoreRequest {
+    pub tenant_id:  Uuid,
+    pub subject:    String,
+    pub html_body:  String,
+    pub text_body:  String,
+    pub updated_by: Uuid,
+}
+
+/// 通知テンプレート検証リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct ValidateNotificationTemplateCoreRequest {
+    pub subject:   String,
+    pub html_body: String,
+    pub text_body: String,
+}
+
+/// 通知テンプレートプレビューリクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct PreviewNotificationTemplateCoreRequest {
+    pub tenant_id: Uuid,
+    pub subject:   Option<String>,
+    pub html_body: Option<String>,
+    pub text_body: Option<String>,
+    pub locale:    Option<String>,
+}
+
+// --- 通知ログ関連の型 ---
+
+/// 通知ログ DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct NotificationLogDto {
+    pub id: Uuid,
+    pub event_type: String,
+    pub workflow_instance_id: Uuid,
+    pub workflow_title: String,
+    pub workflow_display_id: String,
+    pub recipient_user_id: Uuid,
+    pub recipient_email: String,
+    pub subject: String,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: String,
+    pub error_message: Option<String>,
+    pub resendable: bool,
+    pub created_at: String,
+    pub sent_at: Option<String>,
+}
+
+/// 通知再送リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct ResendNotificationLogCoreRequest {
+    pub tenant_id: Uuid,
+}
+
+// --- テナント設定関連の型 ---
+
+/// テナント設定 DTO（Core Service からのデシリアライズ用）
+#[derive(Debug, Clone, Deserialize)]
+pub struct TenantSettingsDto {
+    pub default_locale: String,
+    pub time_zone:      String,
+}
+
+/// テナント設定更新リクエスト（Core Service 内部 API 用）
+#[derive(Debug, Serialize)]
+pub struct UpdateTenantSettingsCoreRequest {
+    pub default_locale: String,
+    pub time_zone:      Option<String>,
+}
+
 // --- ドキュメント関連の型 ---
 
 /// Upload URL 発行リクエスト（Core Service 内部 API 用）
@@ -395,6 +949,7 @@ pub struct RequestUploadUrlCoreRequest {
     pub content_length: i64,
     pub folder_id: Option<Uuid>,
     pub workflow_instance_id: Option<Uuid>,
+    pub comment_id: Option<Uuid>,
     pub uploaded_by: Uuid,
 }
 
@@ -414,6 +969,7 @@ pub struct DocumentDetailCoreDto {
     pub content_type: String,
     pub size:         i64,
     pub status:       String,
+    pub comment_id:   Option<Uuid>,
     pub created_at:   String,
 }
 
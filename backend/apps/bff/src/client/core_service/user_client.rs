@@ -11,6 +11,7 @@ use super::{
         CreateUserCoreRequest,
         CreateUserCoreResponse,
         UpdateUserCoreRequest,
+        UpdateUserLocaleCoreRequest,
         UpdateUserStatusCoreRequest,
         UserItemDto,
         UserResponse,
@@ -76,6 +77,15 @@ pub trait CoreServiceUserClient: Send + Sync {
         req: &UpdateUserStatusCoreRequest,
     ) -> Result<UserResponse, CoreServiceError>;
 
+    /// ユーザーのロケールを変更する
+    ///
+    /// Core Service の `PUT /internal/users/{user_id}/locale` を呼び出す。
+    async fn update_user_locale(
+        &self,
+        user_id: Uuid,
+        req: &UpdateUserLocaleCoreRequest,
+    ) -> Result<UserResponse, CoreServiceError>;
+
     /// 表示用連番でユーザーを取得する
     ///
     /// Core Service の `GET /internal/users/by-display-number/{display_number}`
@@ -173,6 +183,21 @@ impl CoreServiceUserClient for CoreServiceClientImpl {
         handle_response(response, Some(CoreServiceError::UserNotFound)).await
     }
 
+    #[tracing::instrument(skip_all, level = "debug", fields(%user_id))]
+    async fn update_user_locale(
+        &self,
+        user_id: Uuid,
+        req: &UpdateUserLocaleCoreRequest,
+    ) -> Result<UserResponse, CoreServiceError> {
+        let url = format!("{}/internal/users/{}/locale", self.base_url, user_id);
+
+        let response = inject_request_id(self.client.put(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::UserNotFound)).await
+    }
+
     #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, display_number))]
     async fn get_user_by_display_number(
         &self,
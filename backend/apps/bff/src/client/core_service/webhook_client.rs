@@ -0,0 +1,197 @@
+//! Webhook 関連の Core Service クライアント
+
+use async_trait::async_trait;
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::{
+    client_impl::CoreServiceClientImpl,
+    error::CoreServiceError,
+    response::handle_response,
+    types::{CreateWebhookCoreRequest, UpdateWebhookCoreRequest, WebhookDeliveryDto, WebhookDto},
+};
+use crate::middleware::request_id::inject_request_id;
+
+/// テスト送信リクエスト（Core Service 内部 API 用）
+#[derive(Serialize)]
+struct SendTestWebhookCoreRequest {
+    tenant_id: Uuid,
+}
+
+/// Webhook 関連の Core Service クライアントトレイト
+#[async_trait]
+pub trait CoreServiceWebhookClient: Send + Sync {
+    /// テナント内の Webhook 一覧を取得する
+    ///
+    /// Core Service の `GET /internal/webhooks` を呼び出す。
+    async fn list_webhooks(&self, tenant_id: Uuid) -> Result<Vec<WebhookDto>, CoreServiceError>;
+
+    /// Webhook を作成する
+    ///
+    /// Core Service の `POST /internal/webhooks` を呼び出す。
+    async fn create_webhook(
+        &self,
+        req: &CreateWebhookCoreRequest,
+    ) -> Result<WebhookDto, CoreServiceError>;
+
+    /// Webhook を取得する
+    ///
+    /// Core Service の `GET /internal/webhooks/{webhook_id}` を呼び出す。
+    async fn get_webhook(
+        &self,
+        webhook_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<WebhookDto, CoreServiceError>;
+
+    /// Webhook を更新する
+    ///
+    /// Core Service の `PUT /internal/webhooks/{webhook_id}` を呼び出す。
+    async fn update_webhook(
+        &self,
+        webhook_id: Uuid,
+        req: &UpdateWebhookCoreRequest,
+    ) -> Result<WebhookDto, CoreServiceError>;
+
+    /// Webhook を削除する
+    ///
+    /// Core Service の `DELETE /internal/webhooks/{webhook_id}` を呼び出す。
+    async fn delete_webhook(
+        &self,
+        webhook_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<(), CoreServiceError>;
+
+    /// Webhook の配信ログを取得する
+    ///
+    /// Core Service の `GET /internal/webhooks/{webhook_id}/deliveries` を呼び出す。
+    async fn list_webhook_deliveries(
+        &self,
+        webhook_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<WebhookDeliveryDto>, CoreServiceError>;
+
+    /// テストイベントを送信する
+    ///
+    /// Core Service の `POST /internal/webhooks/{webhook_id}/test` を呼び出す。
+    async fn send_test_webhook(
+        &self,
+        webhook_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<WebhookDeliveryDto, CoreServiceError>;
+}
+
+#[async_trait]
+impl CoreServiceWebhookClient for CoreServiceClientImpl {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn list_webhooks(&self, tenant_id: Uuid) -> Result<Vec<WebhookDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/webhooks?tenant_id={}",
+            self.base_url, tenant_id
+        );
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn create_webhook(
+        &self,
+        req: &CreateWebhookCoreRequest,
+    ) -> Result<WebhookDto, CoreServiceError> {
+        let url = format!("{}/internal/webhooks", self.base_url);
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%webhook_id, %tenant_id))]
+    async fn get_webhook(
+        &self,
+        webhook_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<WebhookDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/webhooks/{}?tenant_id={}",
+            self.base_url, webhook_id, tenant_id
+        );
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(response, Some(CoreServiceError::WebhookNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%webhook_id))]
+    async fn update_webhook(
+        &self,
+        webhook_id: Uuid,
+        req: &UpdateWebhookCoreRequest,
+    ) -> Result<WebhookDto, CoreServiceError> {
+        let url = format!("{}/internal/webhooks/{}", self.base_url, webhook_id);
+
+        let response = inject_request_id(self.client.put(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WebhookNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%webhook_id, %tenant_id))]
+    async fn delete_webhook(
+        &self,
+        webhook_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<(), CoreServiceError> {
+        let url = format!(
+            "{}/internal/webhooks/{}?tenant_id={}",
+            self.base_url, webhook_id, tenant_id
+        );
+
+        let response = inject_request_id(self.client.delete(&url)).send().await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = response.text().await.unwrap_or_default();
+
+        let error = match status {
+            reqwest::StatusCode::NOT_FOUND => CoreServiceError::WebhookNotFound,
+            _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
+        };
+
+        Err(error)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%webhook_id, %tenant_id))]
+    async fn list_webhook_deliveries(
+        &self,
+        webhook_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<Vec<WebhookDeliveryDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/webhooks/{}/deliveries?tenant_id={}",
+            self.base_url, webhook_id, tenant_id
+        );
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(response, Some(CoreServiceError::WebhookNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%webhook_id, %tenant_id))]
+    async fn send_test_webhook(
+        &self,
+        webhook_id: Uuid,
+        tenant_id: Uuid,
+    ) -> Result<WebhookDeliveryDto, CoreServiceError> {
+        let url = format!("{}/internal/webhooks/{}/test", self.base_url, webhook_id);
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(&SendTestWebhookCoreRequest { tenant_id })
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WebhookNotFound)).await
+    }
+}
@@ -0,0 +1,161 @@
+//! テナント管理者向けワークフロー操作の Core Service クライアント
+
+use async_trait::async_trait;
+use ringiflow_shared::PaginatedResponse;
+
+use super::{
+    client_impl::CoreServiceClientImpl,
+    error::CoreServiceError,
+    response::handle_response,
+    types::{
+        AdminActionCoreRequest,
+        ReassignStepCoreRequest,
+        SearchWorkflowsCoreQuery,
+        WorkflowInstanceDto,
+        WorkflowInstanceSummaryDto,
+        WorkflowViewerQuery,
+    },
+};
+use crate::middleware::request_id::inject_request_id;
+
+/// テナント管理者向けワークフロー操作の Core Service クライアントトレイト
+#[async_trait]
+pub trait CoreServiceWorkflowAdminClient: Send + Sync {
+    /// テナント内の全ワークフローを一覧・絞り込みする（他人の下書きを含む）
+    ///
+    /// Core Service の `GET /internal/admin/workflows` を呼び出す。
+    async fn list_all_workflows(
+        &self,
+        query: &SearchWorkflowsCoreQuery,
+    ) -> Result<PaginatedResponse<WorkflowInstanceSummaryDto>, CoreServiceError>;
+
+    /// テナント内の任意のワークフローの詳細を取得する
+    ///
+    /// Core Service の `GET /internal/workflows/by-display-number/{display_number}`
+    /// を呼び出す。機密定義のワークフローは関与者でなければ 404 が返る。
+    async fn get_any_workflow(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError>;
+
+    /// ワークフローを強制的に取り消す
+    ///
+    /// Core Service の `POST
+    /// /internal/admin/workflows/by-display-number/{display_number}/cancel`
+    /// を呼び出す。
+    async fn force_cancel_workflow(
+        &self,
+        display_number: i64,
+        req: &AdminActionCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError>;
+
+    /// 現在のステップの担当者を変更する
+    ///
+    /// Core Service の `POST
+    /// /internal/admin/workflows/by-display-number/{display_number}/reassign`
+    /// を呼び出す。
+    async fn reassign_current_step(
+        &self,
+        display_number: i64,
+        req: &ReassignStepCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError>;
+
+    /// ワークフローを強制的に承認完了にする
+    ///
+    /// Core Service の `POST
+    /// /internal/admin/workflows/by-display-number/{display_number}/force-complete`
+    /// を呼び出す。
+    async fn force_complete_workflow(
+        &self,
+        display_number: i64,
+        req: &AdminActionCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError>;
+}
+
+#[async_trait]
+impl CoreServiceWorkflowAdminClient for CoreServiceClientImpl {
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn list_all_workflows(
+        &self,
+        query: &SearchWorkflowsCoreQuery,
+    ) -> Result<PaginatedResponse<WorkflowInstanceSummaryDto>, CoreServiceError> {
+        let url = format!("{}/internal/admin/workflows", self.base_url);
+
+        let response = inject_request_id(self.client.get(&url).query(query))
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, tenant_id = %viewer.tenant_id))]
+    async fn get_any_workflow(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.get(&url).query(viewer))
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number))]
+    async fn force_cancel_workflow(
+        &self,
+        display_number: i64,
+        req: &AdminActionCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/admin/workflows/by-display-number/{}/cancel",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number))]
+    async fn reassign_current_step(
+        &self,
+        display_number: i64,
+        req: &ReassignStepCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/admin/workflows/by-display-number/{}/reassign",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number))]
+    async fn force_complete_workflow(
+        &self,
+        display_number: i64,
+        req: &AdminActionCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/admin/workflows/by-display-number/{}/force-complete",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
+    }
+}
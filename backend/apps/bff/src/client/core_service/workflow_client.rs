@@ -1,27 +1,40 @@
 //! ワークフロー関連の Core Service クライアント
 
 use async_trait::async_trait;
+use ringiflow_shared::PaginatedResponse;
 use uuid::Uuid;
 
 use super::{
     client_impl::CoreServiceClientImpl,
     error::CoreServiceError,
-    response::handle_response,
+    response::{error_message, handle_response},
     types::{
         ApproveRejectRequest,
         CreateDefinitionCoreRequest,
         CreateWorkflowRequest,
+        DuplicateWorkflowCoreRequest,
+        EditCommentCoreRequest,
+        PageCoreQuery,
         PostCommentCoreRequest,
         PublishArchiveCoreRequest,
         ResubmitWorkflowRequest,
+        RetractApprovalCoreRequest,
+        SearchWorkflowsCoreQuery,
+        SubmissionDiffDto,
         SubmitWorkflowRequest,
         UpdateDefinitionCoreRequest,
         ValidateDefinitionCoreRequest,
         ValidationResultDto,
+        WorkflowActivityDto,
         WorkflowCommentDto,
+        WorkflowCommentRevisionDto,
         WorkflowDefinitionDto,
+        WorkflowFormDataChangeDto,
         WorkflowInstanceDto,
         WorkflowInstanceSummaryDto,
+        WorkflowSubmissionDto,
+        WorkflowViewerQuery,
+        WorkflowWatcherDto,
     },
 };
 use crate::middleware::request_id::inject_request_id;
@@ -37,6 +50,29 @@ pub trait CoreServiceWorkflowClient: Send + Sync {
         req: CreateWorkflowRequest,
     ) -> Result<WorkflowInstanceDto, CoreServiceError>;
 
+    /// 既存のワークフローを複製して下書きを作成する
+    ///
+    /// Core Service の `POST
+    /// /internal/workflows/by-display-number/{display_number}/duplicate`
+    /// を呼び出す。
+    async fn duplicate_workflow(
+        &self,
+        display_number: i64,
+        req: &DuplicateWorkflowCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError>;
+
+    /// 下書きのワークフローを削除する
+    ///
+    /// Core Service の `DELETE
+    /// /internal/workflows/by-display-number/{display_number}`
+    /// を呼び出す。
+    async fn delete_workflow(
+        &self,
+        display_number: i64,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), CoreServiceError>;
+
     /// ワークフローを申請する
     ///
     /// Core Service の `POST /internal/workflows/{id}/submit` を呼び出す。
@@ -70,7 +106,16 @@ pub trait CoreServiceWorkflowClient: Send + Sync {
         &self,
         tenant_id: Uuid,
         user_id: Uuid,
-    ) -> Result<Vec<WorkflowInstanceSummaryDto>, CoreServiceError>;
+        page: &PageCoreQuery,
+    ) -> Result<PaginatedResponse<WorkflowInstanceSummaryDto>, CoreServiceError>;
+
+    /// ワークフローを検索する
+    ///
+    /// Core Service の `GET /internal/workflows/search` を呼び出す。
+    async fn search_workflows(
+        &self,
+        query: &SearchWorkflowsCoreQuery,
+    ) -> Result<PaginatedResponse<WorkflowInstanceSummaryDto>, CoreServiceError>;
 
     /// ワークフローの詳細を取得する
     ///
@@ -78,7 +123,7 @@ pub trait CoreServiceWorkflowClient: Send + Sync {
     async fn get_workflow(
         &self,
         workflow_id: Uuid,
-        tenant_id: Uuid,
+        viewer: &WorkflowViewerQuery,
     ) -> Result<WorkflowInstanceDto, CoreServiceError>;
 
     /// ワークフローステップを承認する
@@ -110,7 +155,7 @@ pub trait CoreServiceWorkflowClient: Send + Sync {
     async fn get_workflow_by_display_number(
         &self,
         display_number: i64,
-        tenant_id: Uuid,
+        viewer: &WorkflowViewerQuery,
     ) -> Result<WorkflowInstanceDto, CoreServiceError>;
 
     /// display_number でワークフローを申請する
@@ -160,6 +205,18 @@ pub trait CoreServiceWorkflowClient: Send + Sync {
         req: ApproveRejectRequest,
     ) -> Result<WorkflowInstanceDto, CoreServiceError>;
 
+    /// display_number でワークフローステップの承認を取り消す
+    ///
+    /// Core Service の `POST
+    /// /internal/workflows/by-display-number/{dn}/steps/by-display-number/
+    /// {step_dn}/retract` を呼び出す。
+    async fn retract_approval_by_display_number(
+        &self,
+        workflow_display_number: i64,
+        step_display_number: i64,
+        req: RetractApprovalCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError>;
+
     /// display_number でワークフローを再申請する
     ///
     /// Core Service の `POST
@@ -189,9 +246,124 @@ pub trait CoreServiceWorkflowClient: Send + Sync {
     async fn list_comments(
         &self,
         display_number: i64,
-        tenant_id: Uuid,
+        viewer: &WorkflowViewerQuery,
     ) -> Result<Vec<WorkflowCommentDto>, CoreServiceError>;
 
+    /// 自分が投稿したコメントを編集する
+    ///
+    /// Core Service の `PATCH
+    /// /internal/workflows/by-display-number/{display_number}/comments/{comment_id}`
+    /// を呼び出す。
+    async fn edit_comment(
+        &self,
+        display_number: i64,
+        comment_id: Uuid,
+        req: EditCommentCoreRequest,
+    ) -> Result<WorkflowCommentDto, CoreServiceError>;
+
+    /// コメントを削除する（論理削除）
+    ///
+    /// Core Service の `DELETE
+    /// /internal/workflows/by-display-number/{display_number}/comments/{comment_id}`
+    /// を呼び出す。
+    async fn delete_comment(
+        &self,
+        display_number: i64,
+        comment_id: Uuid,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<(), CoreServiceError>;
+
+    /// コメントの編集履歴を取得する
+    ///
+    /// Core Service の `GET
+    /// /internal/workflows/by-display-number/{display_number}/comments/{comment_id}/revisions`
+    /// を呼び出す。
+    async fn list_comment_revisions(
+        &self,
+        display_number: i64,
+        comment_id: Uuid,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<WorkflowCommentRevisionDto>, CoreServiceError>;
+
+    /// ワークフローのフォームデータ変更履歴を取得する
+    ///
+    /// Core Service の `GET
+    /// /internal/workflows/by-display-number/{display_number}/form-data-changes`
+    /// を呼び出す。
+    async fn list_form_data_changes(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<WorkflowFormDataChangeDto>, CoreServiceError>;
+
+    /// ワークフローのアクティビティを取得する
+    ///
+    /// Core Service の `GET
+    /// /internal/workflows/by-display-number/{display_number}/activities`
+    /// を呼び出す。
+    async fn list_activities(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<WorkflowActivityDto>, CoreServiceError>;
+
+    /// ワークフローの申請スナップショット一覧を取得する
+    ///
+    /// Core Service の `GET
+    /// /internal/workflows/by-display-number/{display_number}/submissions`
+    /// を呼び出す。
+    async fn list_submissions(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<WorkflowSubmissionDto>, CoreServiceError>;
+
+    /// 2 つの申請ラウンド間の差分を取得する
+    ///
+    /// Core Service の `GET
+    /// /internal/workflows/by-display-number/{display_number}/submissions/diff`
+    /// を呼び出す。`from` / `to` を省略すると最新ラウンドと直前のラウンドを比較する。
+    async fn diff_submissions(
+        &self,
+        display_number: i64,
+        from: Option<i32>,
+        to: Option<i32>,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<SubmissionDiffDto, CoreServiceError>;
+
+    /// ワークフローのウォッチャー一覧を取得する
+    ///
+    /// Core Service の `GET
+    /// /internal/workflows/by-display-number/{display_number}/watchers`
+    /// を呼び出す。
+    async fn list_watchers(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<WorkflowWatcherDto>, CoreServiceError>;
+
+    /// ワークフローをウォッチする
+    ///
+    /// Core Service の `POST
+    /// /internal/workflows/by-display-number/{display_number}/watchers`
+    /// を呼び出す。閲覧者自身がウォッチャーとして登録される。
+    async fn watch_workflow(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<(), CoreServiceError>;
+
+    /// ワークフローのウォッチを解除する
+    ///
+    /// Core Service の `DELETE
+    /// /internal/workflows/by-display-number/{display_number}/watchers`
+    /// を呼び出す。
+    async fn unwatch_workflow(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<(), CoreServiceError>;
+
     // ===== ワークフロー定義管理 =====
 
     /// ワークフロー定義を作成する
@@ -266,6 +438,58 @@ impl CoreServiceWorkflowClient for CoreServiceClientImpl {
         handle_response(response, Some(CoreServiceError::WorkflowDefinitionNotFound)).await
     }
 
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number))]
+    async fn duplicate_workflow(
+        &self,
+        display_number: i64,
+        req: &DuplicateWorkflowCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/duplicate",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, %tenant_id))]
+    async fn delete_workflow(
+        &self,
+        display_number: i64,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}?tenant_id={}&user_id={}",
+            self.base_url, display_number, tenant_id, user_id
+        );
+
+        let response = inject_request_id(self.client.delete(&url)).send().await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = response.text().await.unwrap_or_default();
+
+        let error = match status {
+            reqwest::StatusCode::NOT_FOUND => CoreServiceError::WorkflowInstanceNotFound,
+            reqwest::StatusCode::BAD_REQUEST => {
+                CoreServiceError::ValidationError(error_message(&body))
+            }
+            reqwest::StatusCode::FORBIDDEN => CoreServiceError::Forbidden(error_message(&body)),
+            reqwest::StatusCode::CONFLICT => CoreServiceError::Conflict(error_message(&body)),
+            _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
+        };
+
+        Err(error)
+    }
+
     #[tracing::instrument(skip_all, level = "debug", fields(%workflow_id))]
     async fn submit_workflow(
         &self,
@@ -318,28 +542,43 @@ impl CoreServiceWorkflowClient for CoreServiceClientImpl {
         &self,
         tenant_id: Uuid,
         user_id: Uuid,
-    ) -> Result<Vec<WorkflowInstanceSummaryDto>, CoreServiceError> {
+        page: &PageCoreQuery,
+    ) -> Result<PaginatedResponse<WorkflowInstanceSummaryDto>, CoreServiceError> {
         let url = format!(
             "{}/internal/workflows?tenant_id={}&user_id={}",
             self.base_url, tenant_id, user_id
         );
 
-        let response = inject_request_id(self.client.get(&url)).send().await?;
+        let response = inject_request_id(self.client.get(&url).query(page))
+            .send()
+            .await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(tenant_id = %query.tenant_id, user_id = %query.user_id))]
+    async fn search_workflows(
+        &self,
+        query: &SearchWorkflowsCoreQuery,
+    ) -> Result<PaginatedResponse<WorkflowInstanceSummaryDto>, CoreServiceError> {
+        let url = format!("{}/internal/workflows/search", self.base_url);
+
+        let response = inject_request_id(self.client.get(&url).query(query))
+            .send()
+            .await?;
         handle_response(response, None).await
     }
 
-    #[tracing::instrument(skip_all, level = "debug", fields(%workflow_id, %tenant_id))]
+    #[tracing::instrument(skip_all, level = "debug", fields(%workflow_id, tenant_id = %viewer.tenant_id))]
     async fn get_workflow(
         &self,
         workflow_id: Uuid,
-        tenant_id: Uuid,
+        viewer: &WorkflowViewerQuery,
     ) -> Result<WorkflowInstanceDto, CoreServiceError> {
-        let url = format!(
-            "{}/internal/workflows/{}?tenant_id={}",
-            self.base_url, workflow_id, tenant_id
-        );
+        let url = format!("{}/internal/workflows/{}", self.base_url, workflow_id);
 
-        let response = inject_request_id(self.client.get(&url)).send().await?;
+        let response = inject_request_id(self.client.get(&url).query(viewer))
+            .send()
+            .await?;
         handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
     }
 
@@ -381,18 +620,20 @@ impl CoreServiceWorkflowClient for CoreServiceClientImpl {
         handle_response(response, Some(CoreServiceError::StepNotFound)).await
     }
 
-    #[tracing::instrument(skip_all, level = "debug", fields(display_number, %tenant_id))]
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, tenant_id = %viewer.tenant_id))]
     async fn get_workflow_by_display_number(
         &self,
         display_number: i64,
-        tenant_id: Uuid,
+        viewer: &WorkflowViewerQuery,
     ) -> Result<WorkflowInstanceDto, CoreServiceError> {
         let url = format!(
-            "{}/internal/workflows/by-display-number/{}?tenant_id={}",
-            self.base_url, display_number, tenant_id
+            "{}/internal/workflows/by-display-number/{}",
+            self.base_url, display_number
         );
 
-        let response = inject_request_id(self.client.get(&url)).send().await?;
+        let response = inject_request_id(self.client.get(&url).query(viewer))
+            .send()
+            .await?;
         handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
     }
 
@@ -483,6 +724,29 @@ impl CoreServiceWorkflowClient for CoreServiceClientImpl {
         handle_response(response, Some(CoreServiceError::StepNotFound)).await
     }
 
+    #[tracing::instrument(
+        skip_all,
+        level = "debug",
+        fields(workflow_display_number, step_display_number)
+    )]
+    async fn retract_approval_by_display_number(
+        &self,
+        workflow_display_number: i64,
+        step_display_number: i64,
+        req: RetractApprovalCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/steps/by-display-number/{}/retract",
+            self.base_url, workflow_display_number, step_display_number
+        );
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(&req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::StepNotFound)).await
+    }
+
     #[tracing::instrument(skip_all, level = "debug", fields(display_number))]
     async fn resubmit_workflow_by_display_number(
         &self,
@@ -519,21 +783,255 @@ impl CoreServiceWorkflowClient for CoreServiceClientImpl {
         handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
     }
 
-    #[tracing::instrument(skip_all, level = "debug", fields(display_number, %tenant_id))]
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, tenant_id = %viewer.tenant_id))]
     async fn list_comments(
         &self,
         display_number: i64,
-        tenant_id: Uuid,
+        viewer: &WorkflowViewerQuery,
     ) -> Result<Vec<WorkflowCommentDto>, CoreServiceError> {
         let url = format!(
-            "{}/internal/workflows/by-display-number/{}/comments?tenant_id={}",
-            self.base_url, display_number, tenant_id
+            "{}/internal/workflows/by-display-number/{}/comments",
+            self.base_url, display_number
         );
 
-        let response = inject_request_id(self.client.get(&url)).send().await?;
+        let response = inject_request_id(self.client.get(&url).query(viewer))
+            .send()
+            .await?;
         handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
     }
 
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, %comment_id))]
+    async fn edit_comment(
+        &self,
+        display_number: i64,
+        comment_id: Uuid,
+        req: EditCommentCoreRequest,
+    ) -> Result<WorkflowCommentDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/comments/{}",
+            self.base_url, display_number, comment_id
+        );
+
+        let response = inject_request_id(self.client.patch(&url))
+            .json(&req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::CommentNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, %comment_id, tenant_id = %viewer.tenant_id))]
+    async fn delete_comment(
+        &self,
+        display_number: i64,
+        comment_id: Uuid,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<(), CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/comments/{}",
+            self.base_url, display_number, comment_id
+        );
+
+        let response = inject_request_id(self.client.delete(&url).query(viewer))
+            .send()
+            .await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = response.text().await.unwrap_or_default();
+
+        let error = match status {
+            reqwest::StatusCode::NOT_FOUND => CoreServiceError::CommentNotFound,
+            reqwest::StatusCode::BAD_REQUEST => {
+                CoreServiceError::ValidationError(error_message(&body))
+            }
+            reqwest::StatusCode::FORBIDDEN => CoreServiceError::Forbidden(error_message(&body)),
+            _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
+        };
+
+        Err(error)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, %comment_id, tenant_id = %viewer.tenant_id))]
+    async fn list_comment_revisions(
+        &self,
+        display_number: i64,
+        comment_id: Uuid,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<WorkflowCommentRevisionDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/comments/{}/revisions",
+            self.base_url, display_number, comment_id
+        );
+
+        let response = inject_request_id(self.client.get(&url).query(viewer))
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::CommentNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, tenant_id = %viewer.tenant_id))]
+    async fn list_form_data_changes(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<WorkflowFormDataChangeDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/form-data-changes",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.get(&url).query(viewer))
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, tenant_id = %viewer.tenant_id))]
+    async fn list_activities(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<WorkflowActivityDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/activities",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.get(&url).query(viewer))
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, tenant_id = %viewer.tenant_id))]
+    async fn list_submissions(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<WorkflowSubmissionDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/submissions",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.get(&url).query(viewer))
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, tenant_id = %viewer.tenant_id))]
+    async fn diff_submissions(
+        &self,
+        display_number: i64,
+        from: Option<i32>,
+        to: Option<i32>,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<SubmissionDiffDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/submissions/diff",
+            self.base_url, display_number
+        );
+        let mut request = self.client.get(&url).query(viewer);
+        if let Some(from) = from {
+            request = request.query(&[("from", from)]);
+        }
+        if let Some(to) = to {
+            request = request.query(&[("to", to)]);
+        }
+
+        let response = inject_request_id(request).send().await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, tenant_id = %viewer.tenant_id))]
+    async fn list_watchers(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<WorkflowWatcherDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/watchers",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.get(&url).query(viewer))
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowInstanceNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, tenant_id = %viewer.tenant_id))]
+    async fn watch_workflow(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<(), CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/watchers",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.post(&url).query(viewer))
+            .send()
+            .await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = response.text().await.unwrap_or_default();
+
+        let error = match status {
+            reqwest::StatusCode::NOT_FOUND => CoreServiceError::WorkflowInstanceNotFound,
+            reqwest::StatusCode::BAD_REQUEST => {
+                CoreServiceError::ValidationError(error_message(&body))
+            }
+            reqwest::StatusCode::FORBIDDEN => CoreServiceError::Forbidden(error_message(&body)),
+            _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
+        };
+
+        Err(error)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(display_number, tenant_id = %viewer.tenant_id))]
+    async fn unwatch_workflow(
+        &self,
+        display_number: i64,
+        viewer: &WorkflowViewerQuery,
+    ) -> Result<(), CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflows/by-display-number/{}/watchers",
+            self.base_url, display_number
+        );
+
+        let response = inject_request_id(self.client.delete(&url).query(viewer))
+            .send()
+            .await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = response.text().await.unwrap_or_default();
+
+        let error = match status {
+            reqwest::StatusCode::NOT_FOUND => CoreServiceError::WorkflowInstanceNotFound,
+            reqwest::StatusCode::BAD_REQUEST => {
+                CoreServiceError::ValidationError(error_message(&body))
+            }
+            reqwest::StatusCode::FORBIDDEN => CoreServiceError::Forbidden(error_message(&body)),
+            _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
+        };
+
+        Err(error)
+    }
+
     // ===== ワークフロー定義管理 =====
 
     #[tracing::instrument(skip_all, level = "debug")]
@@ -590,7 +1088,9 @@ impl CoreServiceWorkflowClient for CoreServiceClientImpl {
 
         let error = match status {
             reqwest::StatusCode::NOT_FOUND => CoreServiceError::WorkflowDefinitionNotFound,
-            reqwest::StatusCode::BAD_REQUEST => CoreServiceError::ValidationError(body),
+            reqwest::StatusCode::BAD_REQUEST => {
+                CoreServiceError::ValidationError(error_message(&body))
+            }
             _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
         };
 
@@ -0,0 +1,135 @@
+//! ワークフロースケジュール関連の Core Service クライアント
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{
+    client_impl::CoreServiceClientImpl,
+    error::CoreServiceError,
+    response::handle_response,
+    types::{
+        CreateWorkflowScheduleCoreRequest,
+        UpdateWorkflowScheduleCoreRequest,
+        WorkflowScheduleDto,
+    },
+};
+use crate::middleware::request_id::inject_request_id;
+
+/// ワークフロースケジュール関連の Core Service クライアントトレイト
+#[async_trait]
+pub trait CoreServiceWorkflowScheduleClient: Send + Sync {
+    /// 自分のスケジュール一覧を取得する
+    ///
+    /// Core Service の `GET /internal/workflow-schedules` を呼び出す。
+    async fn list_workflow_schedules(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<WorkflowScheduleDto>, CoreServiceError>;
+
+    /// スケジュールを作成する
+    ///
+    /// Core Service の `POST /internal/workflow-schedules` を呼び出す。
+    async fn create_workflow_schedule(
+        &self,
+        req: &CreateWorkflowScheduleCoreRequest,
+    ) -> Result<WorkflowScheduleDto, CoreServiceError>;
+
+    /// スケジュールを更新する
+    ///
+    /// Core Service の `PUT /internal/workflow-schedules/{schedule_id}` を呼び出す。
+    async fn update_workflow_schedule(
+        &self,
+        schedule_id: Uuid,
+        req: &UpdateWorkflowScheduleCoreRequest,
+    ) -> Result<WorkflowScheduleDto, CoreServiceError>;
+
+    /// スケジュールを削除する
+    ///
+    /// Core Service の `DELETE /internal/workflow-schedules/{schedule_id}` を呼び出す。
+    async fn delete_workflow_schedule(
+        &self,
+        schedule_id: Uuid,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), CoreServiceError>;
+}
+
+#[async_trait]
+impl CoreServiceWorkflowScheduleClient for CoreServiceClientImpl {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn list_workflow_schedules(
+        &self,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<WorkflowScheduleDto>, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflow-schedules?tenant_id={}&user_id={}",
+            self.base_url, tenant_id, user_id
+        );
+
+        let response = inject_request_id(self.client.get(&url)).send().await?;
+        handle_response(response, None).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn create_workflow_schedule(
+        &self,
+        req: &CreateWorkflowScheduleCoreRequest,
+    ) -> Result<WorkflowScheduleDto, CoreServiceError> {
+        let url = format!("{}/internal/workflow-schedules", self.base_url);
+
+        let response = inject_request_id(self.client.post(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowDefinitionNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%schedule_id))]
+    async fn update_workflow_schedule(
+        &self,
+        schedule_id: Uuid,
+        req: &UpdateWorkflowScheduleCoreRequest,
+    ) -> Result<WorkflowScheduleDto, CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflow-schedules/{}",
+            self.base_url, schedule_id
+        );
+
+        let response = inject_request_id(self.client.put(&url))
+            .json(req)
+            .send()
+            .await?;
+        handle_response(response, Some(CoreServiceError::WorkflowScheduleNotFound)).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%schedule_id, %tenant_id))]
+    async fn delete_workflow_schedule(
+        &self,
+        schedule_id: Uuid,
+        tenant_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), CoreServiceError> {
+        let url = format!(
+            "{}/internal/workflow-schedules/{}?tenant_id={}&user_id={}",
+            self.base_url, schedule_id, tenant_id, user_id
+        );
+
+        let response = inject_request_id(self.client.delete(&url)).send().await?;
+        let status = response.status();
+
+        if status.is_success() {
+            return Ok(());
+        }
+
+        let body = response.text().await.unwrap_or_default();
+
+        let error = match status {
+            reqwest::StatusCode::NOT_FOUND => CoreServiceError::WorkflowScheduleNotFound,
+            _ => CoreServiceError::Unexpected(format!("予期しないステータス {}: {}", status, body)),
+        };
+
+        Err(error)
+    }
+}
@@ -61,6 +61,9 @@ pub const DEV_USER_PERMISSIONS: &[&str] = &[
     "workflow:*",
     "workflow_definition:manage",
     "task:*",
+    "webhook:manage",
+    "notification_template:manage",
+    "notification_log:manage",
 ];
 
 /// 開発用セッションをセットアップする
@@ -10,7 +10,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use axum_extra::extract::CookieJar;
-use ringiflow_domain::tenant::TenantId;
+use ringiflow_domain::{message::Message, tenant::TenantId};
 use ringiflow_infra::{SessionData, SessionManager};
 use ringiflow_shared::ErrorResponse;
 use uuid::Uuid;
@@ -33,15 +33,11 @@ pub enum TenantIdError {
 
 impl IntoResponse for TenantIdError {
     fn into_response(self) -> Response {
-        let detail = match self {
-            TenantIdError::Missing => "X-Tenant-ID ヘッダーが必要です",
-            TenantIdError::InvalidFormat => "X-Tenant-ID の形式が不正です",
+        let message = match self {
+            TenantIdError::Missing => Message::new("tenant-id-header-required"),
+            TenantIdError::InvalidFormat => Message::new("tenant-id-header-invalid"),
         };
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::validation_error(detail)),
-        )
-            .into_response()
+        validation_error_response(message)
     }
 }
 
@@ -110,42 +106,69 @@ impl IntoResponse for CoreServiceError {
             CoreServiceError::UserNotFound => not_found_response(
                 "user-not-found",
                 "User Not Found",
-                "ユーザーが見つかりません",
+                Message::new("user-not-found"),
             ),
             CoreServiceError::WorkflowDefinitionNotFound => not_found_response(
                 "workflow-definition-not-found",
                 "Workflow Definition Not Found",
-                "ワークフロー定義が見つかりません",
+                Message::new("workflow-definition-not-found"),
             ),
             CoreServiceError::WorkflowInstanceNotFound => not_found_response(
                 "workflow-instance-not-found",
                 "Workflow Instance Not Found",
-                "ワークフローインスタンスが見つかりません",
+                Message::new("workflow-instance-not-found"),
             ),
             CoreServiceError::StepNotFound => not_found_response(
                 "step-not-found",
                 "Step Not Found",
-                "ステップが見つかりません",
+                Message::new("step-not-found"),
+            ),
+            CoreServiceError::CommentNotFound => not_found_response(
+                "comment-not-found",
+                "Comment Not Found",
+                Message::new("comment-not-found"),
+            ),
+            CoreServiceError::RoleNotFound => not_found_response(
+                "role-not-found",
+                "Role Not Found",
+                Message::new("role-not-found"),
             ),
-            CoreServiceError::RoleNotFound => {
-                not_found_response("role-not-found", "Role Not Found", "ロールが見つかりません")
-            }
             CoreServiceError::FolderNotFound => not_found_response(
                 "folder-not-found",
                 "Folder Not Found",
-                "フォルダが見つかりません",
+                Message::new("folder-not-found"),
             ),
             CoreServiceError::DocumentNotFound => not_found_response(
                 "document-not-found",
                 "Document Not Found",
-                "ドキュメントが見つかりません",
+                Message::new("document-not-found"),
+            ),
+            CoreServiceError::WebhookNotFound => not_found_response(
+                "webhook-not-found",
+                "Webhook Not Found",
+                Message::new("webhook-not-found"),
+            ),
+            CoreServiceError::WorkflowScheduleNotFound => not_found_response(
+                "workflow-schedule-not-found",
+                "Workflow Schedule Not Found",
+                Message::new("workflow-schedule-not-found"),
+            ),
+            CoreServiceError::NotificationNotFound => not_found_response(
+                "notification-not-found",
+                "Notification Not Found",
+                Message::new("notification-not-found"),
             ),
-            CoreServiceError::ValidationError(ref detail) => validation_error_response(detail),
-            CoreServiceError::Forbidden(ref detail) => forbidden_response(detail),
+            CoreServiceError::NotificationTemplateNotFound => not_found_response(
+                "notification-template-not-found",
+                "Notification Template Not Found",
+                Message::new("notification-template-not-found"),
+            ),
+            CoreServiceError::ValidationError(message) => validation_error_response(message),
+            CoreServiceError::Forbidden(message) => forbidden_response(message),
             CoreServiceError::EmailAlreadyExists => {
-                conflict_response("このメールアドレスは既に使用されています")
+                conflict_response(Message::new("email-already-used"))
             }
-            CoreServiceError::Conflict(ref detail) => conflict_response(detail),
+            CoreServiceError::Conflict(message) => conflict_response(message),
             CoreServiceError::Network(_) | CoreServiceError::Unexpected(_) => {
                 internal_error_response()
             }
@@ -175,27 +198,42 @@ pub fn log_and_convert_core_error(context: &str, err: CoreServiceError) -> Respo
 
 // --- レスポンスヘルパー ---
 
+/// `message` を日本語の `detail` に展開し、メッセージコードとパラメータを付けたレスポンス
+///
+/// `detail` は [`crate::middleware::locale`] で要求されたロケールの文言に展開し直す。
+pub fn message_response(
+    status: StatusCode,
+    constructor: impl FnOnce(String) -> ErrorResponse,
+    message: Message,
+) -> Response {
+    let detail = message.to_string();
+    let (code, params) = message.into_parts();
+    (status, Json(constructor(detail).with_message(code, params))).into_response()
+}
+
 /// 認証失敗レスポンス
 pub fn authentication_failed_response() -> Response {
-    (
+    message_response(
         StatusCode::UNAUTHORIZED,
-        Json(ErrorResponse::new(
-            "authentication-failed",
-            "Authentication Failed",
-            401,
-            "メールアドレスまたはパスワードが正しくありません",
-        )),
+        |detail| {
+            ErrorResponse::new(
+                "authentication-failed",
+                "Authentication Failed",
+                401,
+                detail,
+            )
+        },
+        Message::new("authentication-failed"),
     )
-        .into_response()
 }
 
 /// 未認証レスポンス
 pub fn unauthorized_response() -> Response {
-    (
+    message_response(
         StatusCode::UNAUTHORIZED,
-        Json(ErrorResponse::unauthorized("認証が必要です")),
+        ErrorResponse::unauthorized,
+        Message::new("authentication-required"),
     )
-        .into_response()
 }
 
 /// 内部エラーレスポンス
@@ -209,45 +247,39 @@ pub fn internal_error_response() -> Response {
 
 /// Auth Service 利用不可レスポンス
 pub fn service_unavailable_response() -> Response {
-    (
+    message_response(
         StatusCode::SERVICE_UNAVAILABLE,
-        Json(ErrorResponse::service_unavailable(
-            "認証サービスが一時的に利用できません",
-        )),
+        ErrorResponse::service_unavailable,
+        Message::new("auth-service-unavailable"),
     )
-        .into_response()
 }
 
 /// 404 Not Found レスポンス
-pub fn not_found_response(error_type_suffix: &str, title: &str, detail: &str) -> Response {
-    (
+pub fn not_found_response(error_type_suffix: &str, title: &str, message: Message) -> Response {
+    message_response(
         StatusCode::NOT_FOUND,
-        Json(ErrorResponse::new(error_type_suffix, title, 404, detail)),
+        |detail| ErrorResponse::new(error_type_suffix, title, 404, detail),
+        message,
     )
-        .into_response()
 }
 
 /// バリデーションエラーレスポンス
-pub fn validation_error_response(detail: &str) -> Response {
-    (
+pub fn validation_error_response(message: Message) -> Response {
+    message_response(
         StatusCode::BAD_REQUEST,
-        Json(ErrorResponse::validation_error(detail)),
+        ErrorResponse::validation_error,
+        message,
     )
-        .into_response()
 }
 
 /// 403 Forbidden レスポンス
-pub fn forbidden_response(detail: &str) -> Response {
-    (
-        StatusCode::FORBIDDEN,
-        Json(ErrorResponse::forbidden(detail)),
-    )
-        .into_response()
+pub fn forbidden_response(message: Message) -> Response {
+    message_response(StatusCode::FORBIDDEN, ErrorResponse::forbidden, message)
 }
 
 /// 409 Conflict レスポンス
-pub fn conflict_response(detail: &str) -> Response {
-    (StatusCode::CONFLICT, Json(ErrorResponse::conflict(detail))).into_response()
+pub fn conflict_response(message: Message) -> Response {
+    message_response(StatusCode::CONFLICT, ErrorResponse::conflict, message)
 }
 
 #[cfg(test)]
@@ -502,7 +534,7 @@ mod tests {
     #[tokio::test]
     async fn core_service_error_validation_errorで400() {
         let response =
-            CoreServiceError::ValidationError("入力が不正です".to_string()).into_response();
+            CoreServiceError::ValidationError(Message::text("入力が不正です")).into_response();
         let (status, body) = response_status_and_body(response).await;
         assert_eq!(status, StatusCode::BAD_REQUEST);
         assert_error_type_ends_with(&body, "/validation-error");
@@ -510,7 +542,7 @@ mod tests {
 
     #[tokio::test]
     async fn core_service_error_forbiddenで403() {
-        let response = CoreServiceError::Forbidden("権限なし".to_string()).into_response();
+        let response = CoreServiceError::Forbidden(Message::text("権限なし")).into_response();
         let (status, body) = response_status_and_body(response).await;
         assert_eq!(status, StatusCode::FORBIDDEN);
         assert_error_type_ends_with(&body, "/forbidden");
@@ -526,7 +558,7 @@ mod tests {
 
     #[tokio::test]
     async fn core_service_error_conflictで409() {
-        let response = CoreServiceError::Conflict("バージョン競合".to_string()).into_response();
+        let response = CoreServiceError::Conflict(Message::text("バージョン競合")).into_response();
         let (status, body) = response_status_and_body(response).await;
         assert_eq!(status, StatusCode::CONFLICT);
         assert_error_type_ends_with(&body, "/conflict");
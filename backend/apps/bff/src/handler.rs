@@ -20,14 +20,23 @@ pub mod dashboard;
 pub mod document;
 pub mod folder;
 pub mod health;
+pub mod notification;
+pub mod notification_log;
+pub mod notification_template;
+pub mod proxy_grant;
+pub mod realtime;
 pub mod role;
 pub mod task;
+pub mod tenant;
 pub mod user;
+pub mod webhook;
 pub mod workflow;
+pub mod workflow_admin;
 pub mod workflow_definition;
+pub mod workflow_schedule;
 
 pub use audit_log::{AuditLogState, list_audit_logs};
-pub use auth::{AuthState, csrf, login, logout, me};
+pub use auth::{AuthState, csrf, login, logout, me, update_my_locale};
 pub use dashboard::get_dashboard_stats;
 pub use document::{
     DocumentState,
@@ -40,8 +49,34 @@ pub use document::{
 };
 pub use folder::{FolderState, create_folder, delete_folder, list_folders, update_folder};
 pub use health::{ReadinessState, health_check, readiness_check};
+pub use notification::{
+    NotificationState,
+    get_unread_notification_count,
+    list_notification_preferences,
+    list_notifications,
+    mark_all_notifications_read,
+    mark_notification_read,
+    update_notification_preferences,
+};
+pub use notification_log::{
+    NotificationLogState,
+    list_failed_notification_logs,
+    resend_notification_log,
+};
+pub use notification_template::{
+    NotificationTemplateState,
+    delete_notification_template,
+    get_notification_template,
+    list_notification_templates,
+    preview_notification_template,
+    save_notification_template,
+    validate_notification_template,
+};
+pub use proxy_grant::{ProxyGrantState, create_proxy_grant, delete_proxy_grant, list_proxy_grants};
+pub use realtime::{RealtimeState, stream_events};
 pub use role::{RoleState, create_role, delete_role, get_role, list_roles, update_role};
-pub use task::list_my_tasks;
+pub use task::{bulk_decide_tasks, list_my_tasks};
+pub use tenant::{TenantState, get_tenant_settings, update_tenant_settings};
 pub use user::{
     UserState,
     create_user,
@@ -50,21 +85,53 @@ pub use user::{
     update_user,
     update_user_status,
 };
+pub use webhook::{
+    WebhookState,
+    create_webhook,
+    delete_webhook,
+    get_webhook,
+    list_webhook_deliveries,
+    list_webhooks,
+    send_test_webhook,
+    update_webhook,
+};
 pub use workflow::{
     WorkflowState,
     approve_step,
     create_workflow,
+    delete_comment,
+    delete_workflow,
+    diff_submissions,
+    duplicate_workflow,
+    edit_comment,
     get_task_by_display_numbers,
     get_workflow,
     get_workflow_definition,
+    list_activities,
+    list_comment_revisions,
     list_comments,
+    list_form_data_changes,
     list_my_workflows,
+    list_submissions,
+    list_watchers,
     list_workflow_definitions,
     post_comment,
     reject_step,
     request_changes_step,
     resubmit_workflow,
+    retract_approval,
+    search_workflows,
     submit_workflow,
+    unwatch_workflow,
+    watch_workflow,
+};
+pub use workflow_admin::{
+    WorkflowAdminState,
+    force_cancel_workflow,
+    force_complete_workflow,
+    get_any_workflow,
+    list_all_workflows,
+    reassign_current_step,
 };
 pub use workflow_definition::{
     WorkflowDefinitionState,
@@ -75,3 +142,10 @@ pub use workflow_definition::{
     update_definition,
     validate_definition,
 };
+pub use workflow_schedule::{
+    WorkflowScheduleState,
+    create_workflow_schedule,
+    delete_workflow_schedule,
+    list_workflow_schedules,
+    update_workflow_schedule,
+};
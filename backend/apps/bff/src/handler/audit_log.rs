@@ -19,6 +19,7 @@ use axum_extra::extract::CookieJar;
 use chrono::DateTime;
 use ringiflow_domain::{
     audit_log::{AuditAction, AuditResult},
+    message::Message,
     user::UserId,
 };
 use ringiflow_infra::{
@@ -168,7 +169,7 @@ pub async fn list_audit_logs(
         }
         Err(e) if matches!(e.kind(), InfraErrorKind::InvalidInput(_)) => {
             tracing::warn!("監査ログの検索でバリデーションエラー: {}", e);
-            Err(validation_error_response("カーソルの形式が不正です"))
+            Err(validation_error_response(Message::new("invalid-cursor")))
         }
         Err(e) => {
             tracing::error!(error.span_trace = %e.span_trace(), "監査ログの検索に失敗: {}", e);
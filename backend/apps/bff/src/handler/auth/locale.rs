@@ -0,0 +1,118 @@
+//! ロケール設定ハンドラ
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use ringiflow_shared::ErrorResponse;
+
+use super::{AuthState, UpdateLocaleRequest};
+use crate::{
+    client::UpdateUserLocaleCoreRequest,
+    error::{authenticate, log_and_convert_core_error},
+};
+
+/// PUT /api/v1/auth/me/locale
+///
+/// ログイン中のユーザーのロケールを変更する。
+/// 通知メール・アプリ内通知はこのロケールで生成される。
+#[utoipa::path(
+   put,
+   path = "/api/v1/auth/me/locale",
+   tag = "auth",
+   security(("session_auth" = [])),
+   request_body = UpdateLocaleRequest,
+   responses(
+      (status = 204, description = "変更成功"),
+      (status = 400, description = "不正なロケール", body = ErrorResponse),
+      (status = 401, description = "未認証", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn update_my_locale(
+    State(state): State<Arc<AuthState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Json(req): Json<UpdateLocaleRequest>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = UpdateUserLocaleCoreRequest {
+        tenant_id: *session_data.tenant_id().as_uuid(),
+        locale:    req.locale,
+    };
+
+    state
+        .core_service_client
+        .update_user_locale(*session_data.user_id().as_uuid(), &core_request)
+        .await
+        .map_err(|e| log_and_convert_core_error("ロケール変更", e))?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        body::Body,
+        http::{Method, Request, StatusCode},
+    };
+    use ringiflow_domain::{tenant::TenantId, user::UserId};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use super::super::test_utils::*;
+
+    fn locale_request(cookie: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder()
+            .method(Method::PUT)
+            .uri("/api/v1/auth/me/locale")
+            .header("X-Tenant-ID", TEST_TENANT_ID)
+            .header("Content-Type", "application/json");
+        if let Some(cookie) = cookie {
+            builder = builder.header("Cookie", cookie);
+        }
+        builder.body(Body::from(r#"{"locale":"en"}"#)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_update_my_locale_認証済みでロケールを変更できる() {
+        // Given
+        let tenant_id = TenantId::from_uuid(Uuid::parse_str(TEST_TENANT_ID).unwrap());
+        let sut = create_test_app(
+            StubCoreServiceClient::success(),
+            StubAuthServiceClient::success(),
+            StubSessionManager::with_session(UserId::new(), tenant_id),
+        );
+
+        // When
+        let response = sut
+            .oneshot(locale_request(Some("session_id=test-session-id")))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn test_update_my_locale_未認証で401() {
+        // Given
+        let sut = create_test_app(
+            StubCoreServiceClient::success(),
+            StubAuthServiceClient::success(),
+            StubSessionManager::new(),
+        );
+
+        // When
+        let response = sut.oneshot(locale_request(None)).await.unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -7,15 +7,18 @@
 //! - `POST /api/v1/auth/login` - ログイン
 //! - `POST /api/v1/auth/logout` - ログアウト
 //! - `GET /api/v1/auth/me` - 現在のユーザー情報を取得
+//! - `PUT /api/v1/auth/me/locale` - ロケールを変更
 //! - `GET /api/v1/auth/csrf` - CSRF トークン取得
 //!
 //! 詳細: [08_AuthService設計.md](../../../../docs/40_詳細設計書/08_AuthService設計.md)
 
+mod locale;
 mod login;
 mod session;
 
 use std::sync::Arc;
 
+pub use locale::*;
 pub use login::*;
 use ringiflow_infra::SessionManager;
 use serde::{Deserialize, Serialize};
@@ -76,6 +79,8 @@ pub struct MeResponseData {
     pub tenant_name: String,
     pub roles:       Vec<String>,
     pub permissions: Vec<String>,
+    /// 表示・通知に使うロケール（`ja` / `en`）
+    pub locale:      String,
 }
 
 impl From<UserWithPermissionsData> for MeResponseData {
@@ -88,10 +93,18 @@ impl From<UserWithPermissionsData> for MeResponseData {
             tenant_name: res.tenant_name,
             roles:       res.roles,
             permissions: res.permissions,
+            locale:      res.locale,
         }
     }
 }
 
+/// ロケール変更リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateLocaleRequest {
+    /// ロケール（`ja` / `en`）。`null` の場合はテナントの既定ロケールに従う
+    pub locale: Option<String>,
+}
+
 /// CSRF トークンデータ
 #[derive(Debug, Serialize, ToSchema)]
 pub struct CsrfResponseData {
@@ -149,13 +162,13 @@ pub(super) mod test_utils {
     use async_trait::async_trait;
     use axum::{
         Router,
-        routing::{get, post},
+        routing::{get, post, put},
     };
     use ringiflow_domain::{tenant::TenantId, user::UserId};
     use ringiflow_infra::{InfraError, SessionData, SessionManager};
     use uuid::Uuid;
 
-    use super::{AuthState, csrf, login, logout, me};
+    use super::{AuthState, csrf, login, logout, me, update_my_locale};
     use crate::client::{
         AuthServiceClient,
         AuthServiceError,
@@ -179,6 +192,7 @@ pub(super) mod test_utils {
                 email:     "user@example.com".to_string(),
                 name:      "Test User".to_string(),
                 status:    "active".to_string(),
+                locale:    None,
             };
             Self {
                 user_by_email_result: Ok(user.clone()),
@@ -187,6 +201,7 @@ pub(super) mod test_utils {
                     tenant_name: "Development Tenant".to_string(),
                     roles: vec!["user".to_string()],
                     permissions: vec!["workflow:read".to_string()],
+                    locale: "ja".to_string(),
                 }),
             }
         }
@@ -247,6 +262,14 @@ pub(super) mod test_utils {
             unimplemented!("update_user_status is not used in auth tests")
         }
 
+        async fn update_user_locale(
+            &self,
+            _user_id: Uuid,
+            _req: &crate::client::UpdateUserLocaleCoreRequest,
+        ) -> Result<UserResponse, CoreServiceError> {
+            self.user_by_email_result.clone()
+        }
+
         async fn get_user_by_display_number(
             &self,
             _tenant_id: Uuid,
@@ -412,6 +435,7 @@ pub(super) mod test_utils {
             .route("/api/v1/auth/login", post(login))
             .route("/api/v1/auth/logout", post(logout))
             .route("/api/v1/auth/me", get(me))
+            .route("/api/v1/auth/me/locale", put(update_my_locale))
             .route("/api/v1/auth/csrf", get(csrf))
             .with_state(state)
     }
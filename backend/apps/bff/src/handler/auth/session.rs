@@ -201,6 +201,7 @@ mod tests {
         assert_eq!(json["tenant_name"], "Development Tenant");
         assert!(json["roles"].is_array());
         assert!(json["permissions"].is_array());
+        assert_eq!(json["locale"], "ja");
     }
 
     #[tokio::test]
@@ -29,6 +29,7 @@ use uuid::Uuid;
 use crate::{
     client::{CoreServiceDocumentClient, RequestUploadUrlCoreRequest},
     error::{authenticate, log_and_convert_core_error},
+    handler::workflow::workflow_viewer,
 };
 
 /// ドキュメント管理 API の共有状態
@@ -43,6 +44,7 @@ pub struct DocumentState {
 ///
 /// `tenant_id` と `uploaded_by` はセッションから取得するため、
 /// フロントエンドからは指定しない。
+/// `folder_id` / `workflow_instance_id` / `comment_id` のいずれか 1 つを指定する。
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct RequestUploadUrlRequest {
     pub filename: String,
@@ -50,6 +52,8 @@ pub struct RequestUploadUrlRequest {
     pub content_length: i64,
     pub folder_id: Option<Uuid>,
     pub workflow_instance_id: Option<Uuid>,
+    /// 添付先のコメント ID（コメント投稿者本人のみ指定できる）
+    pub comment_id: Option<Uuid>,
 }
 
 // --- レスポンス型 ---
@@ -70,6 +74,8 @@ pub struct DocumentData {
     pub content_type: String,
     pub size:         i64,
     pub status:       String,
+    /// 添付先のコメント ID（コメント添付の場合のみ）
+    pub comment_id:   Option<String>,
     pub created_at:   String,
 }
 
@@ -103,7 +109,9 @@ pub struct ListDocumentsQuery {
    responses(
       (status = 200, description = "Upload URL 発行成功", body = UploadUrlData),
       (status = 400, description = "バリデーションエラー", body = ErrorResponse),
-      (status = 401, description = "認証エラー", body = ErrorResponse)
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 403, description = "コメント投稿者以外によるコメントへの添付", body = ErrorResponse),
+      (status = 404, description = "添付先のコメントが見つからない", body = ErrorResponse)
    )
 )]
 #[tracing::instrument(skip_all)]
@@ -122,6 +130,7 @@ pub async fn request_upload_url(
         content_length: req.content_length,
         folder_id: req.folder_id,
         workflow_instance_id: req.workflow_instance_id,
+        comment_id: req.comment_id,
         uploaded_by: *session_data.user_id().as_uuid(),
     };
 
@@ -174,6 +183,7 @@ pub async fn confirm_upload(
         content_type: dto.content_type,
         size:         dto.size,
         status:       dto.status,
+        comment_id:   dto.comment_id.map(|id| id.to_string()),
         created_at:   dto.created_at,
     };
     Ok((StatusCode::OK, Json(response)).into_response())
@@ -182,6 +192,7 @@ pub async fn confirm_upload(
 /// POST /api/v1/documents/{document_id}/download-url
 ///
 /// Presigned GET URL を発行する。
+/// ワークフロー・コメントへの添付ファイルは、添付先のワークフローを閲覧できる場合のみ発行する。
 #[utoipa::path(
    post,
    path = "/api/v1/documents/{document_id}/download-url",
@@ -206,7 +217,7 @@ pub async fn generate_download_url(
 
     let dto = state
         .core_service_client
-        .generate_download_url(document_id, *session_data.tenant_id().as_uuid())
+        .generate_download_url(document_id, &workflow_viewer(&session_data))
         .await
         .map_err(|e| log_and_convert_core_error("ダウンロード URL 発行", e))?;
     let response = DownloadUrlData {
@@ -296,6 +307,7 @@ pub async fn list_documents(
             content_type: dto.content_type,
             size:         dto.size,
             status:       dto.status,
+            comment_id:   dto.comment_id.map(|id| id.to_string()),
             created_at:   dto.created_at,
         })
         .collect();
@@ -305,7 +317,8 @@ pub async fn list_documents(
 
 /// GET /api/v1/workflows/{workflow_instance_id}/attachments
 ///
-/// ワークフロー添付ファイル一覧を取得する。
+/// ワークフロー添付ファイル一覧を取得する。コメントへの添付も含む。
+/// ワークフローを閲覧できないユーザーには 404 を返す。
 #[utoipa::path(
    get,
    path = "/api/v1/workflows/{workflow_instance_id}/attachments",
@@ -314,7 +327,8 @@ pub async fn list_documents(
    params(("workflow_instance_id" = Uuid, Path, description = "ワークフローインスタンスID")),
    responses(
       (status = 200, description = "添付ファイル一覧", body = Vec<DocumentData>),
-      (status = 401, description = "認証エラー", body = ErrorResponse)
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 404, description = "ワークフローが見つからない、または閲覧権限がない", body = ErrorResponse)
    )
 )]
 #[tracing::instrument(skip_all, fields(%workflow_instance_id))]
@@ -328,7 +342,7 @@ pub async fn list_workflow_attachments(
 
     let core_response = state
         .core_service_client
-        .list_workflow_attachments(workflow_instance_id, *session_data.tenant_id().as_uuid())
+        .list_workflow_attachments(workflow_instance_id, &workflow_viewer(&session_data))
         .await
         .map_err(|e| log_and_convert_core_error("ワークフロー添付ファイル一覧取得", e))?;
 
@@ -340,6 +354,7 @@ pub async fn list_workflow_attachments(
             content_type: dto.content_type,
             size:         dto.size,
             status:       dto.status,
+            comment_id:   dto.comment_id.map(|id| id.to_string()),
             created_at:   dto.created_at,
         })
         .collect();
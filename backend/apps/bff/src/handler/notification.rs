@@ -0,0 +1,374 @@
+//! # アプリ内通知 API ハンドラ
+//!
+//! BFF のアプリ内通知（通知受信箱）と通知設定のエンドポイントを提供する。
+//! 通知受信箱は SPA ヘッダーのベルアイコンから利用する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /api/v1/notifications` - 自分宛ての通知一覧（新しい順）
+//! - `GET /api/v1/notifications/unread-count` - 自分宛ての未読通知数
+//! - `POST /api/v1/notifications/{id}/read` - 通知を既読にする
+//! - `POST /api/v1/notifications/read-all` - 自分宛ての未読通知をすべて既読にする
+//! - `GET /api/v1/notifications/preferences` - 自分の通知設定
+//! - `PUT /api/v1/notifications/preferences` - 自分の通知設定を変更する
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use ringiflow_infra::SessionManager;
+use ringiflow_shared::{ErrorResponse, PaginatedResponse};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::workflow::PageQuery;
+use crate::{
+    client::{
+        CoreServiceNotificationClient,
+        InboxNotificationDto,
+        NotificationPreferenceDto,
+        NotificationUserCoreRequest,
+        PageCoreQuery,
+        UpdateNotificationPreferencesCoreRequest,
+    },
+    error::{authenticate, log_and_convert_core_error},
+};
+
+/// アプリ内通知 API の共有状態
+pub struct NotificationState {
+    pub core_service_client: Arc<dyn CoreServiceNotificationClient>,
+    pub session_manager:     Arc<dyn SessionManager>,
+}
+
+// --- リクエスト型 ---
+
+/// 通知設定変更リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateNotificationPreferencesRequest {
+    /// 変更する通知イベント種別ごとの配信方法（指定しない種別は変更しない）
+    pub preferences: Vec<NotificationPreferenceData>,
+}
+
+// --- レスポンス型 ---
+
+/// アプリ内通知データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationData {
+    pub id: String,
+    /// 通知イベント種別（`approval_request`, `step_approved`, `approved`, `rejected`,
+    /// `changes_requested`, `scheduled_workflow_created`, `proxy_submitted`,
+    /// `approval_retracted`, `mentioned`, `cancelled`）
+    pub event_type: String,
+    pub workflow_instance_id: String,
+    pub workflow_title: String,
+    /// ワークフローの表示用 ID（例: `WF-0042`）。通知から詳細画面へ遷移する際に使用する
+    pub workflow_display_id: String,
+    /// 通知の要約（1 行）
+    pub message: String,
+    /// 既読日時（未読の場合は `null`）
+    pub read_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<InboxNotificationDto> for NotificationData {
+    fn from(dto: InboxNotificationDto) -> Self {
+        Self {
+            id: dto.id,
+            event_type: dto.event_type,
+            workflow_instance_id: dto.workflow_instance_id,
+            workflow_title: dto.workflow_title,
+            workflow_display_id: dto.workflow_display_id,
+            message: dto.message,
+            read_at: dto.read_at,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+/// 通知設定データ
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationPreferenceData {
+    /// 通知イベント種別（`NotificationData.event_type` と同じ値）
+    pub event_type: String,
+    /// メールの配信方法（`immediate`: 即時, `digest`: ダイジェスト, `off`: 停止）
+    pub delivery:   String,
+}
+
+impl From<NotificationPreferenceDto> for NotificationPreferenceData {
+    fn from(dto: NotificationPreferenceDto) -> Self {
+        Self {
+            event_type: dto.event_type,
+            delivery:   dto.delivery,
+        }
+    }
+}
+
+/// 未読通知数データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UnreadCountData {
+    pub unread_count: i64,
+}
+
+/// 一括既読化の結果データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MarkAllReadData {
+    /// 既読にした通知の件数
+    pub updated_count: u64,
+}
+
+// --- ハンドラ ---
+
+/// GET /api/v1/notifications
+///
+/// 自分宛ての通知一覧を取得する（新しい順）。
+/// カーソルベースページネーション対応。
+#[utoipa::path(
+   get,
+   path = "/api/v1/notifications",
+   tag = "notifications",
+   security(("session_auth" = [])),
+   params(PageQuery),
+   responses(
+      (status = 200, description = "通知一覧", body = PaginatedResponse<NotificationData>),
+      (status = 400, description = "不正なカーソル", body = ErrorResponse),
+      (status = 401, description = "認証エラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn list_notifications(
+    State(state): State<Arc<NotificationState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Query(query): Query<PageQuery>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_notifications(
+            *session_data.tenant_id().as_uuid(),
+            *session_data.user_id().as_uuid(),
+            &PageCoreQuery {
+                cursor: query.cursor,
+                limit:  query.limit,
+            },
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("通知一覧取得", e))?;
+
+    let response = PaginatedResponse {
+        items:       core_response
+            .items
+            .into_iter()
+            .map(NotificationData::from)
+            .collect::<Vec<_>>(),
+        next_cursor: core_response.next_cursor,
+    };
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// GET /api/v1/notifications/unread-count
+///
+/// 自分宛ての未読通知数を取得する。ヘッダーのベルのバッジ表示に使う。
+#[utoipa::path(
+   get,
+   path = "/api/v1/notifications/unread-count",
+   tag = "notifications",
+   security(("session_auth" = [])),
+   responses(
+      (status = 200, description = "未読通知数", body = UnreadCountData),
+      (status = 401, description = "認証エラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn get_unread_notification_count(
+    State(state): State<Arc<NotificationState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let dto = state
+        .core_service_client
+        .get_unread_notification_count(
+            *session_data.tenant_id().as_uuid(),
+            *session_data.user_id().as_uuid(),
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("未読通知数取得", e))?;
+
+    let response = UnreadCountData {
+        unread_count: dto.unread_count,
+    };
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// POST /api/v1/notifications/{id}/read
+///
+/// 通知を既読にする。既読済みの場合も成功として扱う。
+#[utoipa::path(
+   post,
+   path = "/api/v1/notifications/{id}/read",
+   tag = "notifications",
+   security(("session_auth" = [])),
+   params(("id" = Uuid, Path, description = "通知 ID")),
+   responses(
+      (status = 204, description = "既読化成功"),
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 404, description = "通知が見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%id))]
+pub async fn mark_notification_read(
+    State(state): State<Arc<NotificationState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(id): Path<Uuid>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = NotificationUserCoreRequest {
+        tenant_id: *session_data.tenant_id().as_uuid(),
+        user_id:   *session_data.user_id().as_uuid(),
+    };
+
+    state
+        .core_service_client
+        .mark_notification_read(id, &core_request)
+        .await
+        .map_err(|e| log_and_convert_core_error("通知既読化", e))?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// POST /api/v1/notifications/read-all
+///
+/// 自分宛ての未読通知をすべて既読にする。
+#[utoipa::path(
+   post,
+   path = "/api/v1/notifications/read-all",
+   tag = "notifications",
+   security(("session_auth" = [])),
+   responses(
+      (status = 200, description = "一括既読化成功", body = MarkAllReadData),
+      (status = 401, description = "認証エラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn mark_all_notifications_read(
+    State(state): State<Arc<NotificationState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = NotificationUserCoreRequest {
+        tenant_id: *session_data.tenant_id().as_uuid(),
+        user_id:   *session_data.user_id().as_uuid(),
+    };
+
+    let dto = state
+        .core_service_client
+        .mark_all_notifications_read(&core_request)
+        .await
+        .map_err(|e| log_and_convert_core_error("通知一括既読化", e))?;
+
+    let response = MarkAllReadData {
+        updated_count: dto.updated_count,
+    };
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// GET /api/v1/notifications/preferences
+///
+/// 自分の通知設定を全通知イベント種別分取得する。
+/// 設定はメールにのみ適用され、アプリ内通知は常に記録される。
+#[utoipa::path(
+   get,
+   path = "/api/v1/notifications/preferences",
+   tag = "notifications",
+   security(("session_auth" = [])),
+   responses(
+      (status = 200, description = "通知設定", body = Vec<NotificationPreferenceData>),
+      (status = 401, description = "認証エラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn list_notification_preferences(
+    State(state): State<Arc<NotificationState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let dtos = state
+        .core_service_client
+        .list_notification_preferences(
+            *session_data.tenant_id().as_uuid(),
+            *session_data.user_id().as_uuid(),
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("通知設定取得", e))?;
+
+    let response = dtos
+        .into_iter()
+        .map(NotificationPreferenceData::from)
+        .collect::<Vec<_>>();
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// PUT /api/v1/notifications/preferences
+///
+/// 自分の通知設定を変更する。指定した通知イベント種別のみ上書きし、変更後の全種別分を返す。
+#[utoipa::path(
+   put,
+   path = "/api/v1/notifications/preferences",
+   tag = "notifications",
+   security(("session_auth" = [])),
+   request_body = UpdateNotificationPreferencesRequest,
+   responses(
+      (status = 200, description = "変更後の通知設定", body = Vec<NotificationPreferenceData>),
+      (status = 400, description = "不正な通知イベント種別・配信方法", body = ErrorResponse),
+      (status = 401, description = "認証エラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn update_notification_preferences(
+    State(state): State<Arc<NotificationState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Json(req): Json<UpdateNotificationPreferencesRequest>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = UpdateNotificationPreferencesCoreRequest {
+        tenant_id:   *session_data.tenant_id().as_uuid(),
+        user_id:     *session_data.user_id().as_uuid(),
+        preferences: req
+            .preferences
+            .into_iter()
+            .map(|p| NotificationPreferenceDto {
+                event_type: p.event_type,
+                delivery:   p.delivery,
+            })
+            .collect(),
+    };
+
+    let dtos = state
+        .core_service_client
+        .update_notification_preferences(&core_request)
+        .await
+        .map_err(|e| log_and_convert_core_error("通知設定変更", e))?;
+
+    let response = dtos
+        .into_iter()
+        .map(NotificationPreferenceData::from)
+        .collect::<Vec<_>>();
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
@@ -0,0 +1,193 @@
+//! # 通知ログ管理 API ハンドラ
+//!
+//! BFF の送信に失敗した通知メールの確認・再送エンドポイントを提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /api/v1/notification-logs/failed` - 送信に失敗した通知一覧
+//! - `POST /api/v1/notification-logs/{id}/resend` - 送信に失敗した通知を再送
+//!
+//! すべてのエンドポイントは `notification_log:manage` 権限を要求する。
+//! 一時的なエラーで失敗した通知は再送ワーカーが自動で再送するため、
+//! 一覧に載るのは恒久的なエラーまたは最大試行回数に達した通知のみ。
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use ringiflow_domain::audit_log::{AuditAction, AuditLog};
+use ringiflow_infra::{SessionManager, repository::AuditLogRepository};
+use ringiflow_shared::ErrorResponse;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    client::{
+        CoreServiceNotificationLogClient,
+        NotificationLogDto,
+        ResendNotificationLogCoreRequest,
+    },
+    error::{authenticate, log_and_convert_core_error},
+};
+
+/// 通知ログ管理 API の共有状態
+pub struct NotificationLogState {
+    pub core_service_client:  Arc<dyn CoreServiceNotificationLogClient>,
+    pub session_manager:      Arc<dyn SessionManager>,
+    pub audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+// --- レスポンス型 ---
+
+/// 通知ログデータ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationLogData {
+    pub id: String,
+    /// 通知イベント種別（例: `approval_request`, `approved`）
+    pub event_type: String,
+    pub workflow_instance_id: String,
+    pub workflow_title: String,
+    pub workflow_display_id: String,
+    pub recipient_user_id: String,
+    pub recipient_email: String,
+    pub subject: String,
+    /// 送信状態（`pending` / `sent` / `failed`）
+    pub status: String,
+    /// 送信を試みた回数
+    pub attempts: i32,
+    /// 次回の送信予定日時（`pending` の場合のみ意味を持つ）
+    pub next_attempt_at: String,
+    /// 直近の送信エラー
+    pub error_message: Option<String>,
+    /// 再送できるか（ダイジェストは本文を保存しないため再送できない）
+    pub resendable: bool,
+    pub created_at: String,
+    pub sent_at: Option<String>,
+}
+
+impl From<NotificationLogDto> for NotificationLogData {
+    fn from(dto: NotificationLogDto) -> Self {
+        Self {
+            id: dto.id.to_string(),
+            event_type: dto.event_type,
+            workflow_instance_id: dto.workflow_instance_id.to_string(),
+            workflow_title: dto.workflow_title,
+            workflow_display_id: dto.workflow_display_id,
+            recipient_user_id: dto.recipient_user_id.to_string(),
+            recipient_email: dto.recipient_email,
+            subject: dto.subject,
+            status: dto.status,
+            attempts: dto.attempts,
+            next_attempt_at: dto.next_attempt_at,
+            error_message: dto.error_message,
+            resendable: dto.resendable,
+            created_at: dto.created_at,
+            sent_at: dto.sent_at,
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /api/v1/notification-logs/failed
+///
+/// 送信に失敗した通知を新しい順で取得する（最大 100 件）。
+#[utoipa::path(
+   get,
+   path = "/api/v1/notification-logs/failed",
+   tag = "notification-logs",
+   security(("session_auth" = [])),
+   responses(
+      (status = 200, description = "送信に失敗した通知一覧", body = Vec<NotificationLogData>),
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn list_failed_notification_logs(
+    State(state): State<Arc<NotificationLogState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_failed_notification_logs(*session_data.tenant_id().as_uuid())
+        .await
+        .map_err(|e| log_and_convert_core_error("送信失敗通知一覧取得", e))?;
+
+    let items: Vec<NotificationLogData> = core_response
+        .into_iter()
+        .map(NotificationLogData::from)
+        .collect();
+    Ok((StatusCode::OK, Json(items)).into_response())
+}
+
+/// POST /api/v1/notification-logs/{id}/resend
+///
+/// 送信に失敗した通知を再送待ちに戻す。
+/// 試行回数をリセットし、再送ワーカーが次回のポーリングで送信する。
+#[utoipa::path(
+   post,
+   path = "/api/v1/notification-logs/{id}/resend",
+   tag = "notification-logs",
+   security(("session_auth" = [])),
+   params(("id" = Uuid, Path, description = "通知ログ ID")),
+   responses(
+      (status = 200, description = "再送待ちに戻した通知", body = NotificationLogData),
+      (status = 400, description = "再送できない通知（ダイジェスト）", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "通知が見つからない", body = ErrorResponse),
+      (status = 409, description = "送信に失敗した通知ではない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%id))]
+pub async fn resend_notification_log(
+    State(state): State<Arc<NotificationLogState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(id): Path<Uuid>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = ResendNotificationLogCoreRequest {
+        tenant_id: *session_data.tenant_id().as_uuid(),
+    };
+
+    match state
+        .core_service_client
+        .resend_notification_log(id, &core_request)
+        .await
+    {
+        Ok(dto) => {
+            // 監査ログ記録
+            let audit_log = AuditLog::new_success(
+                session_data.tenant_id().clone(),
+                session_data.user_id().clone(),
+                session_data.name().to_string(),
+                AuditAction::NotificationLogResend,
+                "notification_log",
+                dto.id.to_string(),
+                Some(serde_json::json!({
+                   "event_type": &dto.event_type,
+                   "recipient_email": &dto.recipient_email,
+                   "workflow_display_id": &dto.workflow_display_id,
+                })),
+                None,
+            );
+            if let Err(e) = state.audit_log_repository.record(&audit_log).await {
+                tracing::error!("監査ログ記録に失敗: {}", e);
+            }
+
+            Ok((StatusCode::OK, Json(NotificationLogData::from(dto))).into_response())
+        }
+        Err(e) => Err(log_and_convert_core_error("通知再送", e)),
+    }
+}
@@ -0,0 +1,436 @@
+//! # 通知テンプレート管理 API ハンドラ
+//!
+//! BFF の通知テンプレート管理エンドポイントを提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /api/v1/notification-templates` - テナントが上書きした通知テンプレート一覧
+//! - `GET /api/v1/notification-templates/{event_type}` - 通知テンプレート詳細
+//! - `PUT /api/v1/notification-templates/{event_type}` - 通知テンプレートを保存
+//! - `DELETE /api/v1/notification-templates/{event_type}` - 通知テンプレートを削除（組み込みに戻す）
+//! - `POST /api/v1/notification-templates/{event_type}/validate` - 保存せずに検証
+//! - `POST /api/v1/notification-templates/{event_type}/preview` - サンプルデータでプレビュー
+//!
+//! すべてのエンドポイントは `notification_template:manage` 権限を要求する。
+//! テンプレートは tera 構文で記述し、通知イベント種別ごとに使える変数が異なる。
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use ringiflow_domain::audit_log::{AuditAction, AuditLog};
+use ringiflow_infra::{SessionManager, repository::AuditLogRepository};
+use ringiflow_shared::ErrorResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    client::{
+        CoreServiceNotificationTemplateClient,
+        NotificationTemplateDto,
+        NotificationTemplatePreviewDto,
+        NotificationTemplateValidationDto,
+        PreviewNotificationTemplateCoreRequest,
+        SaveNotificationTemplateCoreRequest,
+        ValidateNotificationTemplateCoreRequest,
+    },
+    error::{authenticate, log_and_convert_core_error},
+};
+
+/// 通知テンプレート管理 API の共有状態
+pub struct NotificationTemplateState {
+    pub core_service_client:  Arc<dyn CoreServiceNotificationTemplateClient>,
+    pub session_manager:      Arc<dyn SessionManager>,
+    pub audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+// --- リクエスト型 ---
+
+/// 通知テンプレートの内容
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct NotificationTemplateContentRequest {
+    /// 件名テンプレート（255 文字以内）
+    pub subject:   String,
+    /// HTML 本文テンプレート（変数は自動でエスケープされる）
+    pub html_body: String,
+    /// テキスト本文テンプレート
+    pub text_body: String,
+}
+
+/// 通知テンプレートプレビューリクエスト
+///
+/// 内容をすべて省略した場合は保存済みの通知テンプレート（なければ組み込み）でプレビューする。
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PreviewNotificationTemplateRequest {
+    pub subject:   Option<String>,
+    pub html_body: Option<String>,
+    pub text_body: Option<String>,
+    /// 組み込みテンプレートを表示するロケール（`ja` / `en`）。省略時は日本語
+    pub locale:    Option<String>,
+}
+
+// --- レスポンス型 ---
+
+/// 通知テンプレートデータ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationTemplateData {
+    /// 通知イベント種別（例: `approval_request`, `approved`）
+    pub event_type: String,
+    pub subject:    String,
+    pub html_body:  String,
+    pub text_body:  String,
+    pub updated_by: String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<NotificationTemplateDto> for NotificationTemplateData {
+    fn from(dto: NotificationTemplateDto) -> Self {
+        Self {
+            event_type: dto.event_type,
+            subject:    dto.subject,
+            html_body:  dto.html_body,
+            text_body:  dto.text_body,
+            updated_by: dto.updated_by.to_string(),
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+/// 通知テンプレート検証結果データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationTemplateValidationData {
+    pub valid:  bool,
+    pub errors: Vec<NotificationTemplateValidationErrorData>,
+}
+
+/// 通知テンプレート検証エラーデータ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationTemplateValidationErrorData {
+    /// エラーのある部分（`subject` / `html_body` / `text_body`）
+    pub field:   String,
+    pub message: String,
+}
+
+impl From<NotificationTemplateValidationDto> for NotificationTemplateValidationData {
+    fn from(dto: NotificationTemplateValidationDto) -> Self {
+        Self {
+            valid:  dto.valid,
+            errors: dto
+                .errors
+                .into_iter()
+                .map(|e| NotificationTemplateValidationErrorData {
+                    field:   e.field,
+                    message: e.message,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// 通知テンプレートプレビューデータ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationTemplatePreviewData {
+    pub subject:   String,
+    pub html_body: String,
+    pub text_body: String,
+}
+
+impl From<NotificationTemplatePreviewDto> for NotificationTemplatePreviewData {
+    fn from(dto: NotificationTemplatePreviewDto) -> Self {
+        Self {
+            subject:   dto.subject,
+            html_body: dto.html_body,
+            text_body: dto.text_body,
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /api/v1/notification-templates
+///
+/// テナントが上書きした通知テンプレート一覧を取得する。
+/// 一覧にない通知イベント種別は組み込みテンプレートで送信される。
+#[utoipa::path(
+   get,
+   path = "/api/v1/notification-templates",
+   tag = "notification-templates",
+   security(("session_auth" = [])),
+   responses(
+      (status = 200, description = "通知テンプレート一覧", body = Vec<NotificationTemplateData>),
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn list_notification_templates(
+    State(state): State<Arc<NotificationTemplateState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_notification_templates(*session_data.tenant_id().as_uuid())
+        .await
+        .map_err(|e| log_and_convert_core_error("通知テンプレート一覧取得", e))?;
+
+    let items: Vec<NotificationTemplateData> = core_response
+        .into_iter()
+        .map(NotificationTemplateData::from)
+        .collect();
+    Ok((StatusCode::OK, Json(items)).into_response())
+}
+
+/// GET /api/v1/notification-templates/{event_type}
+///
+/// 通知テンプレート詳細を取得する。
+#[utoipa::path(
+   get,
+   path = "/api/v1/notification-templates/{event_type}",
+   tag = "notification-templates",
+   security(("session_auth" = [])),
+   params(("event_type" = String, Path, description = "通知イベント種別")),
+   responses(
+      (status = 200, description = "通知テンプレート詳細", body = NotificationTemplateData),
+      (status = 400, description = "不正な通知イベント種別", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "通知テンプレートが見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%event_type))]
+pub async fn get_notification_template(
+    State(state): State<Arc<NotificationTemplateState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(event_type): Path<String>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let dto = state
+        .core_service_client
+        .get_notification_template(&event_type, *session_data.tenant_id().as_uuid())
+        .await
+        .map_err(|e| log_and_convert_core_error("通知テンプレート詳細取得", e))?;
+
+    Ok((StatusCode::OK, Json(NotificationTemplateData::from(dto))).into_response())
+}
+
+/// PUT /api/v1/notification-templates/{event_type}
+///
+/// 通知テンプレートを保存する。保存済みの場合は内容を置き換える。
+#[utoipa::path(
+   put,
+   path = "/api/v1/notification-templates/{event_type}",
+   tag = "notification-templates",
+   security(("session_auth" = [])),
+   params(("event_type" = String, Path, description = "通知イベント種別")),
+   request_body = NotificationTemplateContentRequest,
+   responses(
+      (status = 200, description = "保存成功", body = NotificationTemplateData),
+      (status = 400, description = "バリデーションエラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%event_type))]
+pub async fn save_notification_template(
+    State(state): State<Arc<NotificationTemplateState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(event_type): Path<String>,
+    Json(req): Json<NotificationTemplateContentRequest>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = SaveNotificationTemplateCoreRequest {
+        tenant_id:  *session_data.tenant_id().as_uuid(),
+        subject:    req.subject,
+        html_body:  req.html_body,
+        text_body:  req.text_body,
+        updated_by: *session_data.user_id().as_uuid(),
+    };
+
+    match state
+        .core_service_client
+        .save_notification_template(&event_type, &core_request)
+        .await
+    {
+        Ok(dto) => {
+            // 監査ログ記録（本文は大きくなりうるため件名のみ記録する）
+            let audit_log = AuditLog::new_success(
+                session_data.tenant_id().clone(),
+                session_data.user_id().clone(),
+                session_data.name().to_string(),
+                AuditAction::NotificationTemplateUpdate,
+                "notification_template",
+                dto.event_type.clone(),
+                Some(serde_json::json!({
+                   "event_type": &dto.event_type,
+                   "subject": &dto.subject,
+                })),
+                None,
+            );
+            if let Err(e) = state.audit_log_repository.record(&audit_log).await {
+                tracing::error!("監査ログ記録に失敗: {}", e);
+            }
+
+            Ok((StatusCode::OK, Json(NotificationTemplateData::from(dto))).into_response())
+        }
+        Err(e) => Err(log_and_convert_core_error("通知テンプレート保存", e)),
+    }
+}
+
+/// DELETE /api/v1/notification-templates/{event_type}
+///
+/// 通知テンプレートを削除し、組み込みテンプレートに戻す。
+#[utoipa::path(
+   delete,
+   path = "/api/v1/notification-templates/{event_type}",
+   tag = "notification-templates",
+   security(("session_auth" = [])),
+   params(("event_type" = String, Path, description = "通知イベント種別")),
+   responses(
+      (status = 204, description = "削除成功"),
+      (status = 400, description = "不正な通知イベント種別", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "通知テンプレートが見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%event_type))]
+pub async fn delete_notification_template(
+    State(state): State<Arc<NotificationTemplateState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(event_type): Path<String>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    match state
+        .core_service_client
+        .delete_notification_template(&event_type, *session_data.tenant_id().as_uuid())
+        .await
+    {
+        Ok(()) => {
+            // 監査ログ記録
+            let audit_log = AuditLog::new_success(
+                session_data.tenant_id().clone(),
+                session_data.user_id().clone(),
+                session_data.name().to_string(),
+                AuditAction::NotificationTemplateDelete,
+                "notification_template",
+                event_type.clone(),
+                Some(serde_json::json!({
+                   "event_type": &event_type,
+                })),
+                None,
+            );
+            if let Err(e) = state.audit_log_repository.record(&audit_log).await {
+                tracing::error!("監査ログ記録に失敗: {}", e);
+            }
+
+            Ok(StatusCode::NO_CONTENT.into_response())
+        }
+        Err(e) => Err(log_and_convert_core_error("通知テンプレート削除", e)),
+    }
+}
+
+/// POST /api/v1/notification-templates/{event_type}/validate
+///
+/// 通知テンプレートを保存せずに検証する。
+/// 検証エラーがあっても `200 OK` で検証結果を返す。
+#[utoipa::path(
+   post,
+   path = "/api/v1/notification-templates/{event_type}/validate",
+   tag = "notification-templates",
+   security(("session_auth" = [])),
+   params(("event_type" = String, Path, description = "通知イベント種別")),
+   request_body = NotificationTemplateContentRequest,
+   responses(
+      (status = 200, description = "検証結果", body = NotificationTemplateValidationData),
+      (status = 400, description = "不正な通知イベント種別", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%event_type))]
+pub async fn validate_notification_template(
+    State(state): State<Arc<NotificationTemplateState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(event_type): Path<String>,
+    Json(req): Json<NotificationTemplateContentRequest>,
+) -> Result<Response, Response> {
+    authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = ValidateNotificationTemplateCoreRequest {
+        subject:   req.subject,
+        html_body: req.html_body,
+        text_body: req.text_body,
+    };
+
+    let dto = state
+        .core_service_client
+        .validate_notification_template(&event_type, &core_request)
+        .await
+        .map_err(|e| log_and_convert_core_error("通知テンプレート検証", e))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(NotificationTemplateValidationData::from(dto)),
+    )
+        .into_response())
+}
+
+/// POST /api/v1/notification-templates/{event_type}/preview
+///
+/// 通知イベント種別のサンプルデータでメールをレンダリングする。
+#[utoipa::path(
+   post,
+   path = "/api/v1/notification-templates/{event_type}/preview",
+   tag = "notification-templates",
+   security(("session_auth" = [])),
+   params(("event_type" = String, Path, description = "通知イベント種別")),
+   request_body = PreviewNotificationTemplateRequest,
+   responses(
+      (status = 200, description = "プレビュー", body = NotificationTemplatePreviewData),
+      (status = 400, description = "バリデーションエラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%event_type))]
+pub async fn preview_notification_template(
+    State(state): State<Arc<NotificationTemplateState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(event_type): Path<String>,
+    Json(req): Json<PreviewNotificationTemplateRequest>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = PreviewNotificationTemplateCoreRequest {
+        tenant_id: *session_data.tenant_id().as_uuid(),
+        subject:   req.subject,
+        html_body: req.html_body,
+        text_body: req.text_body,
+        locale:    req.locale,
+    };
+
+    let dto = state
+        .core_service_client
+        .preview_notification_template(&event_type, &core_request)
+        .await
+        .map_err(|e| log_and_convert_core_error("通知テンプレートプレビュー", e))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(NotificationTemplatePreviewData::from(dto)),
+    )
+        .into_response())
+}
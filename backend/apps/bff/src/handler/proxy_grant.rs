@@ -0,0 +1,203 @@
+//! # 代理権限 API ハンドラ
+//!
+//! BFF の代理権限（代理申請）エンドポイントを提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /api/v1/proxy-grants` - 自分が付与した・付与されている代理権限一覧
+//! - `POST /api/v1/proxy-grants` - 代理権限の付与
+//! - `DELETE /api/v1/proxy-grants/{proxy_user_id}` - 代理権限の取り消し
+//!
+//! 代理権限はログインユーザー本人が自分の代理人に対してのみ付与・取り消しできる。
+//! 代理人は `POST /api/v1/workflows` の `on_behalf_of` に本人を指定して
+//! ワークフローを作成・申請できる。
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use ringiflow_infra::SessionManager;
+use ringiflow_shared::ErrorResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::workflow::UserRefData;
+use crate::{
+    client::{
+        CoreServiceProxyGrantClient,
+        CreateProxyGrantCoreRequest,
+        ProxyGrantDto,
+        ProxyGrantsDto,
+    },
+    error::{authenticate, log_and_convert_core_error},
+};
+
+/// 代理権限 API の共有状態
+pub struct ProxyGrantState {
+    pub core_service_client: Arc<dyn CoreServiceProxyGrantClient>,
+    pub session_manager:     Arc<dyn SessionManager>,
+}
+
+// --- リクエスト型 ---
+
+/// 代理権限付与リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateProxyGrantRequest {
+    /// 代理人のユーザー ID（同じテナントの有効なユーザーのみ）
+    pub proxy_user_id: Uuid,
+}
+
+// --- レスポンス型 ---
+
+/// 代理権限データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProxyGrantData {
+    /// 本人（代理で申請される側）
+    pub principal:  UserRefData,
+    /// 代理人
+    pub proxy:      UserRefData,
+    pub created_at: String,
+}
+
+impl From<ProxyGrantDto> for ProxyGrantData {
+    fn from(dto: ProxyGrantDto) -> Self {
+        Self {
+            principal:  UserRefData::from(dto.principal),
+            proxy:      UserRefData::from(dto.proxy),
+            created_at: dto.created_at,
+        }
+    }
+}
+
+/// 代理権限一覧データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProxyGrantsData {
+    /// 自分が付与した代理権限（自分が本人）
+    pub granted:  Vec<ProxyGrantData>,
+    /// 自分が付与されている代理権限（自分が代理人）
+    pub received: Vec<ProxyGrantData>,
+}
+
+impl From<ProxyGrantsDto> for ProxyGrantsData {
+    fn from(dto: ProxyGrantsDto) -> Self {
+        Self {
+            granted:  dto.granted.into_iter().map(ProxyGrantData::from).collect(),
+            received: dto.received.into_iter().map(ProxyGrantData::from).collect(),
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /api/v1/proxy-grants
+///
+/// 自分が付与した代理権限と、自分が付与されている代理権限を取得する。
+#[utoipa::path(
+   get,
+   path = "/api/v1/proxy-grants",
+   tag = "proxy-grants",
+   security(("session_auth" = [])),
+   responses(
+      (status = 200, description = "代理権限一覧", body = ProxyGrantsData),
+      (status = 401, description = "認証エラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn list_proxy_grants(
+    State(state): State<Arc<ProxyGrantState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_proxy_grants(
+            *session_data.tenant_id().as_uuid(),
+            *session_data.user_id().as_uuid(),
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("代理権限一覧取得", e))?;
+
+    Ok((StatusCode::OK, Json(ProxyGrantsData::from(core_response))).into_response())
+}
+
+/// POST /api/v1/proxy-grants
+///
+/// ログインユーザーの代理人を追加する。付与済みの場合も成功として扱う。
+#[utoipa::path(
+   post,
+   path = "/api/v1/proxy-grants",
+   tag = "proxy-grants",
+   security(("session_auth" = [])),
+   request_body = CreateProxyGrantRequest,
+   responses(
+      (status = 201, description = "代理権限付与成功", body = ProxyGrantData),
+      (status = 400, description = "バリデーションエラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn create_proxy_grant(
+    State(state): State<Arc<ProxyGrantState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Json(req): Json<CreateProxyGrantRequest>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = CreateProxyGrantCoreRequest {
+        tenant_id:     *session_data.tenant_id().as_uuid(),
+        user_id:       *session_data.user_id().as_uuid(),
+        proxy_user_id: req.proxy_user_id,
+    };
+
+    let dto = state
+        .core_service_client
+        .create_proxy_grant(&core_request)
+        .await
+        .map_err(|e| log_and_convert_core_error("代理権限付与", e))?;
+
+    Ok((StatusCode::CREATED, Json(ProxyGrantData::from(dto))).into_response())
+}
+
+/// DELETE /api/v1/proxy-grants/{proxy_user_id}
+///
+/// 代理人の代理権限を取り消す。代理人が作成済みのワークフローには影響しない。
+#[utoipa::path(
+   delete,
+   path = "/api/v1/proxy-grants/{proxy_user_id}",
+   tag = "proxy-grants",
+   security(("session_auth" = [])),
+   params(("proxy_user_id" = Uuid, Path, description = "代理人のユーザー ID")),
+   responses(
+      (status = 204, description = "代理権限取り消し成功"),
+      (status = 401, description = "認証エラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%proxy_user_id))]
+pub async fn delete_proxy_grant(
+    State(state): State<Arc<ProxyGrantState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(proxy_user_id): Path<Uuid>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    state
+        .core_service_client
+        .delete_proxy_grant(
+            proxy_user_id,
+            *session_data.tenant_id().as_uuid(),
+            *session_data.user_id().as_uuid(),
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("代理権限取り消し", e))?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -0,0 +1,181 @@
+//! # リアルタイムイベント配信ハンドラ
+//!
+//! ワークフロー・タスクの更新を Server-Sent Events（SSE）でブラウザに配信する。
+//! SPA はイベントを受けてタスク一覧やダッシュボードを再取得し、ポーリングを不要にする。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /api/v1/events` - 自分宛てのリアルタイムイベントのストリーム（`text/event-stream`）
+//!
+//! ## イベント
+//!
+//! | event | 説明 |
+//! |-------|------|
+//! | `task_assigned` | 承認タスクが割り当てられた |
+//! | `workflow_status_changed` | 関与しているワークフローの状態が変わった |
+//! | `comment_posted` | 関与しているワークフローにコメントが投稿された |
+//! | `resync` | 配信が追いつかずイベントを取りこぼした（一覧を取得し直す） |
+//!
+//! `data` はイベントの JSON（`type` と `occurred_at` を含む）。
+//!
+//! ## 認証
+//!
+//! セッション Cookie で認証する。ブラウザの `EventSource` はヘッダーを付与できないため、
+//! テナント ID は `X-Tenant-ID` ヘッダーのほか `tenant_id` クエリパラメータでも受け付ける。
+//! 接続後にログアウトなどでセッションが失効した場合は、次のイベント送信時にストリームを終了する。
+
+use std::{convert::Infallible, sync::Arc};
+
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::{
+        IntoResponse,
+        Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use axum_extra::extract::CookieJar;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{tenant::TenantId, user::UserId};
+use ringiflow_infra::{
+    SessionManager,
+    realtime::{RealtimeEvent, RealtimeMessage},
+};
+use ringiflow_shared::ErrorResponse;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::{self, error::RecvError};
+use utoipa::IntoParams;
+use uuid::Uuid;
+
+use crate::error::{extract_tenant_id, get_session};
+
+/// Cookie 名
+const SESSION_COOKIE_NAME: &str = "session_id";
+
+/// リアルタイムイベント配信の共有状態
+pub struct RealtimeState {
+    pub session_manager: Arc<dyn SessionManager>,
+    /// Redis Pub/Sub から受信したメッセージの配信元
+    pub events:          broadcast::Sender<Arc<RealtimeMessage>>,
+}
+
+/// イベントストリームのクエリパラメータ
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventStreamQuery {
+    /// テナント ID（`X-Tenant-ID` ヘッダーを付与できない `EventSource` 用）
+    pub tenant_id: Option<Uuid>,
+}
+
+/// SSE の `data` に載せるイベント
+#[derive(Debug, Serialize)]
+struct RealtimeEventData<'a> {
+    #[serde(flatten)]
+    event:       &'a RealtimeEvent,
+    occurred_at: DateTime<Utc>,
+}
+
+/// GET /api/v1/events
+///
+/// 自分宛てのリアルタイムイベントを SSE で配信する。
+#[utoipa::path(
+   get,
+   path = "/api/v1/events",
+   tag = "events",
+   security(("session_auth" = [])),
+   params(EventStreamQuery),
+   responses(
+      (status = 200, description = "イベントストリーム（`task_assigned` / `workflow_status_changed` / `comment_posted` / `resync`）", content_type = "text/event-stream", body = String),
+      (status = 400, description = "テナント ID がない", body = ErrorResponse),
+      (status = 401, description = "認証エラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn stream_events(
+    State(state): State<Arc<RealtimeState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Query(query): Query<EventStreamQuery>,
+) -> Result<Response, Response> {
+    let tenant_id = extract_tenant_id(&headers)
+        .or_else(|e| query.tenant_id.ok_or(e))
+        .map_err(IntoResponse::into_response)?;
+    let session = get_session(state.session_manager.as_ref(), &jar, tenant_id).await?;
+    ringiflow_shared::observability::record_user_id(session.user_id());
+
+    let subscription = EventSubscription {
+        receiver:        state.events.subscribe(),
+        session_manager: state.session_manager.clone(),
+        session_id:      jar
+            .get(SESSION_COOKIE_NAME)
+            .map(|cookie| cookie.value().to_string())
+            .unwrap_or_default(),
+        tenant_id:       session.tenant_id().clone(),
+        user_id:         session.user_id().clone(),
+    };
+    let stream = futures_util::stream::unfold(subscription, |mut subscription| async move {
+        let event = subscription.next_event().await?;
+        Some((Ok::<_, Infallible>(event), subscription))
+    });
+
+    Ok(Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response())
+}
+
+/// 1 接続分の購読
+struct EventSubscription {
+    receiver:        broadcast::Receiver<Arc<RealtimeMessage>>,
+    session_manager: Arc<dyn SessionManager>,
+    session_id:      String,
+    tenant_id:       TenantId,
+    user_id:         UserId,
+}
+
+impl EventSubscription {
+    /// 次に送信するイベントを待つ（ストリームを終了する場合は `None`）
+    async fn next_event(&mut self) -> Option<Event> {
+        loop {
+            let event = match self.receiver.recv().await {
+                Ok(message) if message.is_addressed_to(&self.tenant_id, &self.user_id) => {
+                    let data = RealtimeEventData {
+                        event:       &message.event,
+                        occurred_at: message.occurred_at,
+                    };
+                    match Event::default().event(message.event.name()).json_data(data) {
+                        Ok(event) => event,
+                        Err(e) => {
+                            tracing::error!(error = %e, "リアルタイムイベントのシリアライズに失敗");
+                            continue;
+                        }
+                    }
+                }
+                Ok(_) => continue,
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(
+                        skipped,
+                        "リアルタイムイベントの配信が追いつかず取りこぼしました"
+                    );
+                    Event::default().event("resync").data("{}")
+                }
+                Err(RecvError::Closed) => return None,
+            };
+
+            if !self.session_is_active().await {
+                return None;
+            }
+            return Some(event);
+        }
+    }
+
+    /// セッションが有効か（ログアウト済み・期限切れ・取得失敗の場合は `false`）
+    async fn session_is_active(&self) -> bool {
+        matches!(
+            self.session_manager
+                .get(&self.tenant_id, &self.session_id)
+                .await,
+            Ok(Some(_))
+        )
+    }
+}
@@ -5,6 +5,7 @@
 //! ## エンドポイント
 //!
 //! - `GET /api/v1/tasks/my` - 自分のタスク一覧
+//! - `POST /api/v1/tasks/bulk-decision` - タスクの一括承認/却下
 //! - `GET /api/v1/workflows/{display_number}/tasks/{step_display_number}` -
 //!   タスク詳細（workflow ハンドラに移動）
 
@@ -12,17 +13,46 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::State,
+    extract::{Query, State},
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
 };
 use axum_extra::extract::CookieJar;
-use ringiflow_shared::ErrorResponse;
-use serde::Serialize;
+use ringiflow_domain::{locale::Locale, message::Message};
+use ringiflow_shared::{ErrorResponse, PaginatedResponse};
+use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use super::workflow::{UserRefData, WorkflowData, WorkflowState, WorkflowStepData};
-use crate::error::{authenticate, log_and_convert_core_error};
+use super::workflow::{PageQuery, UserRefData, WorkflowData, WorkflowState, WorkflowStepData};
+use crate::{
+    client::PageCoreQuery,
+    error::{authenticate, log_and_convert_core_error, validation_error_response},
+    middleware::request_locale,
+};
+
+// --- リクエスト型 ---
+
+/// 一括判断の対象ステップ
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkDecisionItem {
+    /// ワークフローの表示用連番
+    pub workflow_display_number: i64,
+    /// ステップの表示用連番
+    pub step_display_number: i64,
+    /// 楽観的ロック用バージョン（タスク一覧取得時の値）
+    pub version: i32,
+}
+
+/// タスク一括承認/却下リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkDecisionRequest {
+    /// 操作種別（`approve` / `reject`）
+    pub action:  String,
+    /// 対象ステップ
+    pub items:   Vec<BulkDecisionItem>,
+    /// コメント（任意、全項目共通）
+    pub comment: Option<String>,
+}
 
 // --- レスポンス型 ---
 
@@ -100,18 +130,51 @@ impl From<crate::client::TaskDetailDto> for TaskDetailData {
     }
 }
 
+/// 一括判断の項目ごとの結果データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkDecisionItemResultData {
+    pub workflow_display_number: i64,
+    pub step_display_number: i64,
+    /// `succeeded` / `conflict` / `not_found` / `forbidden` / `invalid` /
+    /// `failed`
+    pub outcome: String,
+    /// 失敗理由（成功時は null）。`Accept-Language` のロケールで返す
+    pub message: Option<String>,
+}
+
+impl BulkDecisionItemResultData {
+    /// Core Service の結果を `locale` の失敗理由に展開して変換する
+    ///
+    /// メッセージコードがない場合は Core Service の文言をそのまま返す。
+    fn from_dto(dto: crate::client::BulkDecisionItemResultDto, locale: Locale) -> Self {
+        let message = match dto.message_code {
+            Some(code) => Some(Message::from_parts(code, dto.params).render(locale)),
+            None => dto.message,
+        };
+        Self {
+            workflow_display_number: dto.workflow_display_number,
+            step_display_number: dto.step_display_number,
+            outcome: dto.outcome,
+            message,
+        }
+    }
+}
+
 // --- ハンドラ ---
 
 /// GET /api/v1/tasks/my
 ///
-/// 自分のタスク一覧を取得する
+/// 自分のタスク一覧を取得する（新しい順）。
+/// カーソルベースページネーション対応。
 #[utoipa::path(
    get,
    path = "/api/v1/tasks/my",
    tag = "tasks",
    security(("session_auth" = [])),
+   params(PageQuery),
    responses(
-      (status = 200, description = "タスク一覧", body = Vec<TaskItemData>),
+      (status = 200, description = "タスク一覧", body = PaginatedResponse<TaskItemData>),
+      (status = 400, description = "不正なカーソル", body = ErrorResponse),
       (status = 401, description = "認証エラー", body = ErrorResponse)
    )
 )]
@@ -120,6 +183,7 @@ pub async fn list_my_tasks(
     State(state): State<Arc<WorkflowState>>,
     headers: HeaderMap,
     jar: CookieJar,
+    Query(query): Query<PageQuery>,
 ) -> Result<Response, Response> {
     let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
 
@@ -128,13 +192,134 @@ pub async fn list_my_tasks(
         .list_my_tasks(
             *session_data.tenant_id().as_uuid(),
             *session_data.user_id().as_uuid(),
+            &PageCoreQuery {
+                cursor: query.cursor,
+                limit:  query.limit,
+            },
         )
         .await
         .map_err(|e| log_and_convert_core_error("タスク一覧取得", e))?;
 
+    let response = PaginatedResponse {
+        items:       core_response
+            .items
+            .into_iter()
+            .map(TaskItemData::from)
+            .collect::<Vec<_>>(),
+        next_cursor: core_response.next_cursor,
+    };
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// POST /api/v1/tasks/bulk-decision
+///
+/// 複数のタスクを一括で承認/却下する
+///
+/// 各項目は個別に楽観的ロックを検証して処理される。一部の項目が競合等で
+/// 処理できなくても 200 を返し、項目ごとの結果を `outcome` で示す。
+///
+/// ## 処理フロー
+///
+/// 1. セッションから `tenant_id`, `user_id` を取得
+/// 2. Core Service の `POST /internal/workflows/bulk-decision` を呼び出し
+/// 3. 200 OK + 項目ごとの結果を返す
+#[utoipa::path(
+   post,
+   path = "/api/v1/tasks/bulk-decision",
+   tag = "tasks",
+   security(("session_auth" = [])),
+   request_body = BulkDecisionRequest,
+   responses(
+      (status = 200, description = "項目ごとの処理結果", body = Vec<BulkDecisionItemResultData>),
+      (status = 400, description = "バリデーションエラー", body = ErrorResponse),
+      (status = 401, description = "認証エラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(action = %req.action, item_count = req.items.len()))]
+pub async fn bulk_decide_tasks(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Json(req): Json<BulkDecisionRequest>,
+) -> Result<Response, Response> {
+    if req.action != "approve" && req.action != "reject" {
+        return Err(validation_error_response(Message::new(
+            "invalid-decision-action",
+        )));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_req = crate::client::BulkDecisionCoreRequest {
+        action:    req.action,
+        items:     req
+            .items
+            .into_iter()
+            .map(|item| crate::client::BulkDecisionItemCoreRequest {
+                workflow_display_number: item.workflow_display_number,
+                step_display_number: item.step_display_number,
+                version: item.version,
+            })
+            .collect(),
+        comment:   req.comment,
+        tenant_id: *session_data.tenant_id().as_uuid(),
+        user_id:   *session_data.user_id().as_uuid(),
+    };
+
+    let core_response = state
+        .core_service_client
+        .bulk_decide_steps(&core_req)
+        .await
+        .map_err(|e| log_and_convert_core_error("タスク一括判断", e))?;
+
+    let locale = request_locale(&headers);
     let response = core_response
         .into_iter()
-        .map(TaskItemData::from)
+        .map(|dto| BulkDecisionItemResultData::from_dto(dto, locale))
         .collect::<Vec<_>>();
     Ok((StatusCode::OK, Json(response)).into_response())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::client::BulkDecisionItemResultDto;
+
+    fn conflict_result(message_code: Option<&str>) -> BulkDecisionItemResultDto {
+        BulkDecisionItemResultDto {
+            workflow_display_number: 1,
+            step_display_number: 2,
+            outcome: "conflict".to_string(),
+            message: Some(
+                "ステップは既に更新されています。最新の情報を取得してください。".to_string(),
+            ),
+            message_code: message_code.map(str::to_string),
+            params: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_一括判断の失敗理由を要求されたロケールで展開する() {
+        let data = BulkDecisionItemResultData::from_dto(
+            conflict_result(Some("step-version-conflict")),
+            Locale::En,
+        );
+
+        assert_eq!(
+            data.message.as_deref(),
+            Some("The step has already been updated. Reload the latest version.")
+        );
+    }
+
+    #[test]
+    fn test_メッセージコードがない失敗理由はそのまま返す() {
+        let data = BulkDecisionItemResultData::from_dto(conflict_result(None), Locale::En);
+
+        assert_eq!(
+            data.message.as_deref(),
+            Some("ステップは既に更新されています。最新の情報を取得してください。")
+        );
+    }
+}
@@ -0,0 +1,160 @@
+//! # テナント設定 API ハンドラ
+//!
+//! BFF のテナント設定エンドポイントを提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /api/v1/tenant/settings` - テナント設定を取得
+//! - `PUT /api/v1/tenant/settings` - テナント設定を更新（`tenant:update` 権限）
+//!
+//! 既定ロケールは、ロケールを選んでいないユーザーの通知に使われる。
+//! タイムゾーンは、通知ダイジェストの送信時刻の解釈に使われる。
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use ringiflow_domain::audit_log::{AuditAction, AuditLog};
+use ringiflow_infra::{SessionManager, repository::AuditLogRepository};
+use ringiflow_shared::ErrorResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::{
+    client::{CoreServiceTenantClient, TenantSettingsDto, UpdateTenantSettingsCoreRequest},
+    error::{authenticate, log_and_convert_core_error},
+};
+
+/// テナント設定 API の共有状態
+pub struct TenantState {
+    pub core_service_client:  Arc<dyn CoreServiceTenantClient>,
+    pub session_manager:      Arc<dyn SessionManager>,
+    pub audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+// --- リクエスト型 ---
+
+/// テナント設定更新リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTenantSettingsRequest {
+    /// 既定ロケール（`ja` / `en`）
+    pub default_locale: String,
+    /// タイムゾーン（IANA タイムゾーン名、例: `Asia/Tokyo`。省略時は変更しない）
+    pub time_zone:      Option<String>,
+}
+
+// --- レスポンス型 ---
+
+/// テナント設定データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TenantSettingsData {
+    /// 既定ロケール（`ja` / `en`）
+    pub default_locale: String,
+    /// タイムゾーン（IANA タイムゾーン名）
+    pub time_zone:      String,
+}
+
+impl From<TenantSettingsDto> for TenantSettingsData {
+    fn from(dto: TenantSettingsDto) -> Self {
+        Self {
+            default_locale: dto.default_locale,
+            time_zone:      dto.time_zone,
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /api/v1/tenant/settings
+///
+/// テナント設定を取得する。
+#[utoipa::path(
+   get,
+   path = "/api/v1/tenant/settings",
+   tag = "tenant",
+   security(("session_auth" = [])),
+   responses(
+      (status = 200, description = "テナント設定", body = TenantSettingsData),
+      (status = 401, description = "未認証", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn get_tenant_settings(
+    State(state): State<Arc<TenantState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let dto = state
+        .core_service_client
+        .get_tenant_settings(*session_data.tenant_id().as_uuid())
+        .await
+        .map_err(|e| log_and_convert_core_error("テナント設定取得", e))?;
+
+    Ok((StatusCode::OK, Json(TenantSettingsData::from(dto))).into_response())
+}
+
+/// PUT /api/v1/tenant/settings
+///
+/// テナント設定を更新する。
+#[utoipa::path(
+   put,
+   path = "/api/v1/tenant/settings",
+   tag = "tenant",
+   security(("session_auth" = [])),
+   request_body = UpdateTenantSettingsRequest,
+   responses(
+      (status = 200, description = "更新成功", body = TenantSettingsData),
+      (status = 400, description = "不正なロケールまたはタイムゾーン", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn update_tenant_settings(
+    State(state): State<Arc<TenantState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Json(req): Json<UpdateTenantSettingsRequest>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = UpdateTenantSettingsCoreRequest {
+        default_locale: req.default_locale,
+        time_zone:      req.time_zone,
+    };
+
+    match state
+        .core_service_client
+        .update_tenant_settings(*session_data.tenant_id().as_uuid(), &core_request)
+        .await
+    {
+        Ok(dto) => {
+            // 監査ログ記録
+            let audit_log = AuditLog::new_success(
+                session_data.tenant_id().clone(),
+                session_data.user_id().clone(),
+                session_data.name().to_string(),
+                AuditAction::TenantSettingsUpdate,
+                "tenant",
+                session_data.tenant_id().as_uuid().to_string(),
+                Some(serde_json::json!({
+                   "default_locale": &dto.default_locale,
+                   "time_zone": &dto.time_zone,
+                })),
+                None,
+            );
+            if let Err(e) = state.audit_log_repository.record(&audit_log).await {
+                tracing::error!("監査ログ記録に失敗: {}", e);
+            }
+
+            Ok((StatusCode::OK, Json(TenantSettingsData::from(dto))).into_response())
+        }
+        Err(e) => Err(log_and_convert_core_error("テナント設定更新", e)),
+    }
+}
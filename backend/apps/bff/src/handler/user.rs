@@ -19,7 +19,10 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use axum_extra::extract::CookieJar;
-use ringiflow_domain::audit_log::{AuditAction, AuditLog};
+use ringiflow_domain::{
+    audit_log::{AuditAction, AuditLog},
+    message::Message,
+};
 use ringiflow_infra::{SessionManager, repository::AuditLogRepository};
 use ringiflow_shared::ErrorResponse;
 use serde::{Deserialize, Serialize};
@@ -33,7 +36,7 @@ use crate::{
         UpdateUserCoreRequest,
         UpdateUserStatusCoreRequest,
     },
-    error::{authenticate, log_and_convert_core_error},
+    error::{authenticate, log_and_convert_core_error, validation_error_response},
 };
 
 /// ユーザー管理 API の共有状態
@@ -230,11 +233,7 @@ pub async fn create_user(
 
     // role_id の UUID パース
     let role_id = uuid::Uuid::parse_str(&req.role_id).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::validation_error("role_id の形式が不正です")),
-        )
-            .into_response()
+        validation_error_response(Message::new("invalid-format").with("name", "role_id"))
     })?;
 
     // Core Service でユーザー作成
@@ -379,11 +378,7 @@ pub async fn update_user(
         .map(|id| uuid::Uuid::parse_str(&id))
         .transpose()
         .map_err(|_| {
-            (
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse::validation_error("role_id の形式が不正です")),
-            )
-                .into_response()
+            validation_error_response(Message::new("invalid-format").with("name", "role_id"))
         })?;
 
     let core_request = UpdateUserCoreRequest {
@@ -0,0 +1,466 @@
+//! # Webhook 管理 API ハンドラ
+//!
+//! BFF の Webhook 管理エンドポイントを提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /api/v1/webhooks` - テナント内の Webhook 一覧
+//! - `POST /api/v1/webhooks` - Webhook 作成
+//! - `GET /api/v1/webhooks/{webhook_id}` - Webhook 詳細
+//! - `PUT /api/v1/webhooks/{webhook_id}` - Webhook 更新
+//! - `DELETE /api/v1/webhooks/{webhook_id}` - Webhook 削除
+//! - `GET /api/v1/webhooks/{webhook_id}/deliveries` - 配信ログ
+//! - `POST /api/v1/webhooks/{webhook_id}/test` - テストイベント送信
+//!
+//! すべてのエンドポイントは `webhook:manage` 権限を要求する。
+//! シークレットは登録・更新時に受け取るのみで、レスポンスには含めない。
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use ringiflow_domain::audit_log::{AuditAction, AuditLog};
+use ringiflow_infra::{SessionManager, repository::AuditLogRepository};
+use ringiflow_shared::ErrorResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    client::{
+        CoreServiceWebhookClient,
+        CreateWebhookCoreRequest,
+        UpdateWebhookCoreRequest,
+        WebhookDeliveryDto,
+        WebhookDto,
+    },
+    error::{authenticate, log_and_convert_core_error},
+};
+
+/// Webhook 管理 API の共有状態
+pub struct WebhookState {
+    pub core_service_client:  Arc<dyn CoreServiceWebhookClient>,
+    pub session_manager:      Arc<dyn SessionManager>,
+    pub audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+// --- リクエスト型 ---
+
+/// Webhook 作成リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWebhookRequest {
+    pub name:        String,
+    /// 送信先 URL（http / https）
+    pub url:         String,
+    /// 購読するイベント種別（例: `submitted`, `approved`）
+    pub event_types: Vec<String>,
+    /// 署名用シークレット（16〜256 文字）
+    pub secret:      String,
+}
+
+/// Webhook 更新リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateWebhookRequest {
+    pub name:        Option<String>,
+    pub url:         Option<String>,
+    pub event_types: Option<Vec<String>>,
+    pub secret:      Option<String>,
+    pub is_active:   Option<bool>,
+}
+
+// --- レスポンス型 ---
+
+/// Webhook データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookData {
+    pub id:          String,
+    pub name:        String,
+    pub url:         String,
+    pub event_types: Vec<String>,
+    pub is_active:   bool,
+    pub created_by:  String,
+    pub created_at:  String,
+    pub updated_at:  String,
+}
+
+impl From<WebhookDto> for WebhookData {
+    fn from(dto: WebhookDto) -> Self {
+        Self {
+            id:          dto.id.to_string(),
+            name:        dto.name,
+            url:         dto.url,
+            event_types: dto.event_types,
+            is_active:   dto.is_active,
+            created_by:  dto.created_by.to_string(),
+            created_at:  dto.created_at,
+            updated_at:  dto.updated_at,
+        }
+    }
+}
+
+/// Webhook 配信データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryData {
+    pub id: String,
+    /// 配信元のイベント ID（テスト送信の場合は null）
+    pub event_id: Option<String>,
+    pub event_type: String,
+    /// 送信した JSON 本文
+    pub payload: serde_json::Value,
+    /// 配信ステータス（`pending` / `succeeded` / `failed`）
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: String,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub delivered_at: Option<String>,
+}
+
+impl From<WebhookDeliveryDto> for WebhookDeliveryData {
+    fn from(dto: WebhookDeliveryDto) -> Self {
+        Self {
+            id: dto.id.to_string(),
+            event_id: dto.event_id.map(|id| id.to_string()),
+            event_type: dto.event_type,
+            payload: dto.payload,
+            status: dto.status,
+            attempts: dto.attempts,
+            next_attempt_at: dto.next_attempt_at,
+            response_status: dto.response_status,
+            last_error: dto.last_error,
+            created_at: dto.created_at,
+            delivered_at: dto.delivered_at,
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /api/v1/webhooks
+///
+/// テナント内の Webhook 一覧を取得する。
+#[utoipa::path(
+   get,
+   path = "/api/v1/webhooks",
+   tag = "webhooks",
+   security(("session_auth" = [])),
+   responses(
+      (status = 200, description = "Webhook 一覧", body = Vec<WebhookData>),
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn list_webhooks(
+    State(state): State<Arc<WebhookState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_webhooks(*session_data.tenant_id().as_uuid())
+        .await
+        .map_err(|e| log_and_convert_core_error("Webhook 一覧取得", e))?;
+
+    let items: Vec<WebhookData> = core_response.into_iter().map(WebhookData::from).collect();
+    Ok((StatusCode::OK, Json(items)).into_response())
+}
+
+/// POST /api/v1/webhooks
+///
+/// Webhook を作成する。
+#[utoipa::path(
+   post,
+   path = "/api/v1/webhooks",
+   tag = "webhooks",
+   security(("session_auth" = [])),
+   request_body = CreateWebhookRequest,
+   responses(
+      (status = 201, description = "Webhook 作成成功", body = WebhookData),
+      (status = 400, description = "バリデーションエラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn create_webhook(
+    State(state): State<Arc<WebhookState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Json(req): Json<CreateWebhookRequest>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = CreateWebhookCoreRequest {
+        tenant_id:   *session_data.tenant_id().as_uuid(),
+        name:        req.name,
+        url:         req.url,
+        event_types: req.event_types,
+        secret:      req.secret,
+        created_by:  *session_data.user_id().as_uuid(),
+    };
+
+    match state
+        .core_service_client
+        .create_webhook(&core_request)
+        .await
+    {
+        Ok(dto) => {
+            // 監査ログ記録（シークレットは記録しない）
+            let audit_log = AuditLog::new_success(
+                session_data.tenant_id().clone(),
+                session_data.user_id().clone(),
+                session_data.name().to_string(),
+                AuditAction::WebhookCreate,
+                "webhook",
+                dto.id.to_string(),
+                Some(serde_json::json!({
+                   "name": &dto.name,
+                   "url": &dto.url,
+                   "event_types": &dto.event_types,
+                })),
+                None,
+            );
+            if let Err(e) = state.audit_log_repository.record(&audit_log).await {
+                tracing::error!("監査ログ記録に失敗: {}", e);
+            }
+
+            Ok((StatusCode::CREATED, Json(WebhookData::from(dto))).into_response())
+        }
+        Err(e) => Err(log_and_convert_core_error("Webhook 作成", e)),
+    }
+}
+
+/// GET /api/v1/webhooks/{webhook_id}
+///
+/// Webhook 詳細を取得する。
+#[utoipa::path(
+   get,
+   path = "/api/v1/webhooks/{webhook_id}",
+   tag = "webhooks",
+   security(("session_auth" = [])),
+   params(("webhook_id" = Uuid, Path, description = "Webhook ID")),
+   responses(
+      (status = 200, description = "Webhook 詳細", body = WebhookData),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "Webhook が見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%webhook_id))]
+pub async fn get_webhook(
+    State(state): State<Arc<WebhookState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let dto = state
+        .core_service_client
+        .get_webhook(webhook_id, *session_data.tenant_id().as_uuid())
+        .await
+        .map_err(|e| log_and_convert_core_error("Webhook 詳細取得", e))?;
+
+    Ok((StatusCode::OK, Json(WebhookData::from(dto))).into_response())
+}
+
+/// PUT /api/v1/webhooks/{webhook_id}
+///
+/// Webhook を更新する。指定した項目のみ変更する。
+#[utoipa::path(
+   put,
+   path = "/api/v1/webhooks/{webhook_id}",
+   tag = "webhooks",
+   security(("session_auth" = [])),
+   params(("webhook_id" = Uuid, Path, description = "Webhook ID")),
+   request_body = UpdateWebhookRequest,
+   responses(
+      (status = 200, description = "Webhook 更新成功", body = WebhookData),
+      (status = 400, description = "バリデーションエラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "Webhook が見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%webhook_id))]
+pub async fn update_webhook(
+    State(state): State<Arc<WebhookState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(webhook_id): Path<Uuid>,
+    Json(req): Json<UpdateWebhookRequest>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let secret_changed = req.secret.is_some();
+    let core_request = UpdateWebhookCoreRequest {
+        tenant_id:   *session_data.tenant_id().as_uuid(),
+        name:        req.name,
+        url:         req.url,
+        event_types: req.event_types,
+        secret:      req.secret,
+        is_active:   req.is_active,
+    };
+
+    match state
+        .core_service_client
+        .update_webhook(webhook_id, &core_request)
+        .await
+    {
+        Ok(dto) => {
+            // 監査ログ記録（シークレットは変更有無のみ記録する）
+            let audit_log = AuditLog::new_success(
+                session_data.tenant_id().clone(),
+                session_data.user_id().clone(),
+                session_data.name().to_string(),
+                AuditAction::WebhookUpdate,
+                "webhook",
+                dto.id.to_string(),
+                Some(serde_json::json!({
+                   "name": &dto.name,
+                   "url": &dto.url,
+                   "event_types": &dto.event_types,
+                   "is_active": dto.is_active,
+                   "secret_changed": secret_changed,
+                })),
+                None,
+            );
+            if let Err(e) = state.audit_log_repository.record(&audit_log).await {
+                tracing::error!("監査ログ記録に失敗: {}", e);
+            }
+
+            Ok((StatusCode::OK, Json(WebhookData::from(dto))).into_response())
+        }
+        Err(e) => Err(log_and_convert_core_error("Webhook 更新", e)),
+    }
+}
+
+/// DELETE /api/v1/webhooks/{webhook_id}
+///
+/// Webhook を削除する。配信ログも併せて削除される。
+#[utoipa::path(
+   delete,
+   path = "/api/v1/webhooks/{webhook_id}",
+   tag = "webhooks",
+   security(("session_auth" = [])),
+   params(("webhook_id" = Uuid, Path, description = "Webhook ID")),
+   responses(
+      (status = 204, description = "削除成功"),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "Webhook が見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%webhook_id))]
+pub async fn delete_webhook(
+    State(state): State<Arc<WebhookState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    match state
+        .core_service_client
+        .delete_webhook(webhook_id, *session_data.tenant_id().as_uuid())
+        .await
+    {
+        Ok(()) => {
+            // 監査ログ記録
+            let audit_log = AuditLog::new_success(
+                session_data.tenant_id().clone(),
+                session_data.user_id().clone(),
+                session_data.name().to_string(),
+                AuditAction::WebhookDelete,
+                "webhook",
+                webhook_id.to_string(),
+                Some(serde_json::json!({
+                   "webhook_id": webhook_id.to_string(),
+                })),
+                None,
+            );
+            if let Err(e) = state.audit_log_repository.record(&audit_log).await {
+                tracing::error!("監査ログ記録に失敗: {}", e);
+            }
+
+            Ok(StatusCode::NO_CONTENT.into_response())
+        }
+        Err(e) => Err(log_and_convert_core_error("Webhook 削除", e)),
+    }
+}
+
+/// GET /api/v1/webhooks/{webhook_id}/deliveries
+///
+/// Webhook の配信ログを新しい順で取得する（最新 100 件）。
+#[utoipa::path(
+   get,
+   path = "/api/v1/webhooks/{webhook_id}/deliveries",
+   tag = "webhooks",
+   security(("session_auth" = [])),
+   params(("webhook_id" = Uuid, Path, description = "Webhook ID")),
+   responses(
+      (status = 200, description = "配信ログ", body = Vec<WebhookDeliveryData>),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "Webhook が見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%webhook_id))]
+pub async fn list_webhook_deliveries(
+    State(state): State<Arc<WebhookState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_webhook_deliveries(webhook_id, *session_data.tenant_id().as_uuid())
+        .await
+        .map_err(|e| log_and_convert_core_error("Webhook 配信ログ取得", e))?;
+
+    let items: Vec<WebhookDeliveryData> = core_response
+        .into_iter()
+        .map(WebhookDeliveryData::from)
+        .collect();
+    Ok((StatusCode::OK, Json(items)).into_response())
+}
+
+/// POST /api/v1/webhooks/{webhook_id}/test
+///
+/// テストイベント（`ping`）を送信し、配信結果を返す。
+/// 送信先がエラーを返した場合も `200 OK` で失敗した配信結果を返す。
+#[utoipa::path(
+   post,
+   path = "/api/v1/webhooks/{webhook_id}/test",
+   tag = "webhooks",
+   security(("session_auth" = [])),
+   params(("webhook_id" = Uuid, Path, description = "Webhook ID")),
+   responses(
+      (status = 200, description = "配信結果", body = WebhookDeliveryData),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "Webhook が見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%webhook_id))]
+pub async fn send_test_webhook(
+    State(state): State<Arc<WebhookState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let dto = state
+        .core_service_client
+        .send_test_webhook(webhook_id, *session_data.tenant_id().as_uuid())
+        .await
+        .map_err(|e| log_and_convert_core_error("Webhook テスト送信", e))?;
+
+    Ok((StatusCode::OK, Json(WebhookDeliveryData::from(dto))).into_response())
+}
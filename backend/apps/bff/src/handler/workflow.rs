@@ -19,12 +19,13 @@ use std::sync::Arc;
 
 pub use command::*;
 pub use query::*;
-use ringiflow_infra::SessionManager;
+use ringiflow_domain::role::Permission;
+use ringiflow_infra::{SessionData, SessionManager};
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
-use crate::client::CoreServiceClient;
+use crate::client::{CoreServiceClient, WorkflowViewerQuery};
 
 /// ワークフローハンドラの共有状態
 pub struct WorkflowState {
@@ -32,6 +33,22 @@ pub struct WorkflowState {
     pub session_manager:     Arc<dyn SessionManager>,
 }
 
+/// セッションからワークフローの閲覧者を組み立てる
+///
+/// `workflow:admin` 権限を持つユーザーは、機密定義以外のワークフローを
+/// 関与していなくても閲覧できる（判定は Core Service が行う）。
+pub(crate) fn workflow_viewer(session_data: &SessionData) -> WorkflowViewerQuery {
+    let required = Permission::new("workflow:admin");
+    WorkflowViewerQuery {
+        tenant_id:         *session_data.tenant_id().as_uuid(),
+        user_id:           *session_data.user_id().as_uuid(),
+        is_workflow_admin: session_data
+            .permissions()
+            .iter()
+            .any(|p| Permission::new(p).satisfies(&required)),
+    }
+}
+
 // --- リクエスト/レスポンス型 ---
 
 /// ワークフロー作成リクエスト（BFF 公開 API）
@@ -43,6 +60,17 @@ pub struct CreateWorkflowRequest {
     pub title:         String,
     /// フォームデータ
     pub form_data:     serde_json::Value,
+    /// 代理で作成する場合の本人のユーザー ID（本人から代理権限を付与されている必要がある）
+    #[serde(default)]
+    pub on_behalf_of:  Option<Uuid>,
+}
+
+/// ワークフロー複製リクエスト（BFF 公開 API）
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct DuplicateWorkflowRequest {
+    /// 添付ファイルも複製するか（省略時は false）
+    #[serde(default)]
+    pub copy_attachments: bool,
 }
 
 /// ステップ承認者リクエスト（BFF 公開 API）
@@ -65,9 +93,22 @@ pub struct SubmitWorkflowRequest {
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct ApproveRejectRequest {
     /// 楽観的ロック用バージョン
-    pub version: i32,
+    pub version:         i32,
     /// コメント（任意）
-    pub comment: Option<String>,
+    pub comment:         Option<String>,
+    /// 承認者によるフォームデータの編集（任意、承認時のみ指定可能）
+    ///
+    /// キーは定義の承認ステップで `editableFields` に宣言されたフィールド ID。
+    #[serde(default)]
+    #[schema(value_type = Option<Object>)]
+    pub form_data_edits: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// 承認取り消しリクエスト（BFF 公開 API）
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RetractApprovalRequest {
+    /// 楽観的ロック用バージョン（取り消すステップのバージョン）
+    pub version: i32,
 }
 
 /// ワークフロー再申請リクエスト（BFF 公開 API）
@@ -91,6 +132,60 @@ pub struct StepPathParams {
     pub step_display_number: i64,
 }
 
+/// コメントパスパラメータ
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Path)]
+pub struct CommentPathParams {
+    /// ワークフローの表示用連番
+    pub display_number: i64,
+    /// コメント ID
+    pub comment_id:     Uuid,
+}
+
+/// 申請ラウンド差分クエリパラメータ
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SubmissionDiffQuery {
+    /// 比較元ラウンド（省略時は比較先の直前のラウンド）
+    pub from: Option<i32>,
+    /// 比較先ラウンド（省略時は最新ラウンド）
+    pub to:   Option<i32>,
+}
+
+/// 一覧取得のページ指定クエリパラメータ
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PageQuery {
+    /// カーソル（次ページ取得用、opaque 文字列）
+    pub cursor: Option<String>,
+    /// 取得件数（デフォルト 20、最大 100）
+    pub limit:  Option<i64>,
+}
+
+/// ワークフロー検索クエリパラメータ
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchWorkflowsQuery {
+    /// 検索キーワード（件名・表示用 ID・フォームデータの値・コメント本文）
+    pub q: Option<String>,
+    /// ワークフロー定義 ID（カンマ区切りで複数指定可）
+    pub definition_id: Option<String>,
+    /// ステータス（カンマ区切りで複数指定可、例: `pending,in_progress`）
+    pub status: Option<String>,
+    /// 作成日時の下限（ISO 8601、この日時を含む）
+    pub created_from: Option<String>,
+    /// 作成日時の上限（ISO 8601、この日時を含まない）
+    pub created_to: Option<String>,
+    /// 申請者のユーザー ID でフィルタ
+    pub initiated_by: Option<Uuid>,
+    /// 現在の承認者のユーザー ID でフィルタ
+    pub current_approver: Option<Uuid>,
+    /// カーソル（次ページ取得用、opaque 文字列）
+    pub cursor: Option<String>,
+    /// 取得件数（デフォルト 20、最大 100）
+    pub limit: Option<i64>,
+}
+
 /// ユーザー参照データ（フロントエンドへの Serialize 用）
 #[derive(Debug, Serialize, ToSchema)]
 pub struct UserRefData {
@@ -165,6 +260,8 @@ pub struct WorkflowSummaryData {
     pub version: i32,
     pub form_data: serde_json::Value,
     pub initiated_by: UserRefData,
+    /// 代理申請の代理人（本人による申請の場合は null）
+    pub submitted_by: Option<UserRefData>,
     pub current_step_id: Option<String>,
     pub submitted_at: Option<String>,
     pub completed_at: Option<String>,
@@ -184,6 +281,7 @@ impl From<crate::client::WorkflowInstanceSummaryDto> for WorkflowSummaryData {
             version: dto.version,
             form_data: dto.form_data,
             initiated_by: UserRefData::from(dto.initiated_by),
+            submitted_by: dto.submitted_by.map(UserRefData::from),
             current_step_id: dto.current_step_id,
             submitted_at: dto.submitted_at,
             completed_at: dto.completed_at,
@@ -207,6 +305,8 @@ pub struct WorkflowData {
     pub version: i32,
     pub form_data: serde_json::Value,
     pub initiated_by: UserRefData,
+    /// 代理申請の代理人（本人による申請の場合は null）
+    pub submitted_by: Option<UserRefData>,
     pub current_step_id: Option<String>,
     pub steps: Vec<WorkflowStepData>,
     pub submitted_at: Option<String>,
@@ -227,6 +327,7 @@ impl From<crate::client::WorkflowInstanceDto> for WorkflowData {
             version: dto.version,
             form_data: dto.form_data,
             initiated_by: UserRefData::from(dto.initiated_by),
+            submitted_by: dto.submitted_by.map(UserRefData::from),
             current_step_id: dto.current_step_id,
             steps: dto.steps.into_iter().map(WorkflowStepData::from).collect(),
             submitted_at: dto.submitted_at,
@@ -240,7 +341,17 @@ impl From<crate::client::WorkflowInstanceDto> for WorkflowData {
 /// コメント投稿リクエスト（BFF 公開 API）
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct PostCommentRequest {
-    /// コメント本文（1〜2000 文字）
+    /// コメント本文（1〜2000 文字）。`@USER-42` または `@user@example.com` でテナント内のユーザーをメンションできる
+    pub body:      String,
+    /// 返信先の親コメント ID（トップレベルのコメントにのみ返信できる）
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
+}
+
+/// コメント編集リクエスト（BFF 公開 API）
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct EditCommentRequest {
+    /// 編集後のコメント本文（1〜2000 文字）。編集で新たに追加されたメンションのみ通知される
     pub body: String,
 }
 
@@ -248,8 +359,15 @@ pub struct PostCommentRequest {
 #[derive(Debug, Serialize, ToSchema)]
 pub struct WorkflowCommentData {
     pub id:         String,
+    /// 返信先の親コメント ID（トップレベルのコメントは null）
+    pub parent_id:  Option<String>,
     pub posted_by:  UserRefData,
+    /// 本文（削除済みのコメントは空文字）
     pub body:       String,
+    /// 最終編集日時（未編集の場合は null）
+    pub edited_at:  Option<String>,
+    /// 削除日時（削除されていない場合は null）
+    pub deleted_at: Option<String>,
     pub created_at: String,
 }
 
@@ -257,39 +375,282 @@ impl From<crate::client::WorkflowCommentDto> for WorkflowCommentData {
     fn from(dto: crate::client::WorkflowCommentDto) -> Self {
         Self {
             id:         dto.id,
+            parent_id:  dto.parent_id,
             posted_by:  UserRefData::from(dto.posted_by),
             body:       dto.body,
+            edited_at:  dto.edited_at,
+            deleted_at: dto.deleted_at,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+/// ワークフローコメント編集履歴データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkflowCommentRevisionData {
+    pub id:        String,
+    /// 編集前の本文
+    pub body:      String,
+    pub edited_by: UserRefData,
+    pub edited_at: String,
+}
+
+impl From<crate::client::WorkflowCommentRevisionDto> for WorkflowCommentRevisionData {
+    fn from(dto: crate::client::WorkflowCommentRevisionDto) -> Self {
+        Self {
+            id:        dto.id,
+            body:      dto.body,
+            edited_by: UserRefData::from(dto.edited_by),
+            edited_at: dto.edited_at,
+        }
+    }
+}
+
+/// ワークフローウォッチャーデータ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkflowWatcherData {
+    pub user:       UserRefData,
+    pub created_at: String,
+}
+
+impl From<crate::client::WorkflowWatcherDto> for WorkflowWatcherData {
+    fn from(dto: crate::client::WorkflowWatcherDto) -> Self {
+        Self {
+            user:       UserRefData::from(dto.user),
             created_at: dto.created_at,
         }
     }
 }
 
+/// フォームデータ変更履歴データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkflowFormDataChangeData {
+    pub id:         String,
+    pub step_id:    String,
+    pub field_id:   String,
+    pub old_value:  serde_json::Value,
+    pub new_value:  serde_json::Value,
+    pub changed_by: UserRefData,
+    pub changed_at: String,
+}
+
+impl From<crate::client::WorkflowFormDataChangeDto> for WorkflowFormDataChangeData {
+    fn from(dto: crate::client::WorkflowFormDataChangeDto) -> Self {
+        Self {
+            id:         dto.id,
+            step_id:    dto.step_id,
+            field_id:   dto.field_id,
+            old_value:  dto.old_value,
+            new_value:  dto.new_value,
+            changed_by: UserRefData::from(dto.changed_by),
+            changed_at: dto.changed_at,
+        }
+    }
+}
+
+/// ワークフローアクティビティデータ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkflowActivityData {
+    pub id: String,
+    /// 種別（created / submitted / step_activated / approved / rejected /
+    /// changes_requested / resubmitted / approval_retracted / comment_posted /
+    /// comment_edited / comment_deleted / attachment_added / cancelled /
+    /// reassigned / force_completed）
+    pub activity_type: String,
+    /// 操作者（システムによる操作の場合は null）
+    pub actor: Option<UserRefData>,
+    pub step_id: Option<String>,
+    /// 種別ごとの付加情報
+    pub details: serde_json::Value,
+    pub occurred_at: String,
+}
+
+impl From<crate::client::WorkflowActivityDto> for WorkflowActivityData {
+    fn from(dto: crate::client::WorkflowActivityDto) -> Self {
+        Self {
+            id: dto.id,
+            activity_type: dto.activity_type,
+            actor: dto.actor.map(UserRefData::from),
+            step_id: dto.step_id,
+            details: dto.details,
+            occurred_at: dto.occurred_at,
+        }
+    }
+}
+
+/// 申請スナップショットの添付ファイルデータ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubmissionAttachmentData {
+    pub document_id:  String,
+    pub filename:     String,
+    pub content_type: String,
+    pub size:         i64,
+}
+
+impl From<crate::client::SubmissionAttachmentDto> for SubmissionAttachmentData {
+    fn from(dto: crate::client::SubmissionAttachmentDto) -> Self {
+        Self {
+            document_id:  dto.document_id,
+            filename:     dto.filename,
+            content_type: dto.content_type,
+            size:         dto.size,
+        }
+    }
+}
+
+/// 申請スナップショットデータ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkflowSubmissionData {
+    pub id:           String,
+    /// 申請ラウンド（初回申請 = 1、再申請ごとに +1）
+    pub round:        i32,
+    pub form_data:    serde_json::Value,
+    pub attachments:  Vec<SubmissionAttachmentData>,
+    pub submitted_by: UserRefData,
+    pub submitted_at: String,
+}
+
+impl From<crate::client::WorkflowSubmissionDto> for WorkflowSubmissionData {
+    fn from(dto: crate::client::WorkflowSubmissionDto) -> Self {
+        Self {
+            id:           dto.id,
+            round:        dto.round,
+            form_data:    dto.form_data,
+            attachments:  dto
+                .attachments
+                .into_iter()
+                .map(SubmissionAttachmentData::from)
+                .collect(),
+            submitted_by: UserRefData::from(dto.submitted_by),
+            submitted_at: dto.submitted_at,
+        }
+    }
+}
+
+/// フィールド単位の差分データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FormFieldDiffData {
+    pub field_id:  String,
+    /// 変更前の値（比較元に存在しない場合は null）
+    pub old_value: serde_json::Value,
+    /// 変更後の値（比較先に存在しない場合は null）
+    pub new_value: serde_json::Value,
+}
+
+impl From<crate::client::FormFieldDiffDto> for FormFieldDiffData {
+    fn from(dto: crate::client::FormFieldDiffDto) -> Self {
+        Self {
+            field_id:  dto.field_id,
+            old_value: dto.old_value,
+            new_value: dto.new_value,
+        }
+    }
+}
+
+/// 申請ラウンド間の差分データ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubmissionDiffData {
+    pub from_round: i32,
+    pub to_round: i32,
+    pub form_data: Vec<FormFieldDiffData>,
+    pub added_attachments: Vec<SubmissionAttachmentData>,
+    pub removed_attachments: Vec<SubmissionAttachmentData>,
+}
+
+impl From<crate::client::SubmissionDiffDto> for SubmissionDiffData {
+    fn from(dto: crate::client::SubmissionDiffDto) -> Self {
+        Self {
+            from_round: dto.from_round,
+            to_round: dto.to_round,
+            form_data: dto
+                .form_data
+                .into_iter()
+                .map(FormFieldDiffData::from)
+                .collect(),
+            added_attachments: dto
+                .added_attachments
+                .into_iter()
+                .map(SubmissionAttachmentData::from)
+                .collect(),
+            removed_attachments: dto
+                .removed_attachments
+                .into_iter()
+                .map(SubmissionAttachmentData::from)
+                .collect(),
+        }
+    }
+}
+
 /// ワークフロー定義データ
 #[derive(Debug, Serialize, ToSchema)]
 pub struct WorkflowDefinitionData {
-    pub id:          String,
-    pub name:        String,
+    pub id: String,
+    pub name: String,
     pub description: Option<String>,
-    pub version:     i32,
-    pub definition:  serde_json::Value,
-    pub status:      String,
-    pub created_by:  String,
-    pub created_at:  String,
-    pub updated_at:  String,
+    pub version: i32,
+    pub definition: serde_json::Value,
+    pub status: String,
+    /// 機密定義か（申請者・承認者以外は管理者でも閲覧できない）
+    pub confidential: bool,
+    /// 既定ウォッチャーのユーザー ID 一覧（申請時に自動でウォッチャーに登録される）
+    pub default_watchers: Vec<String>,
+    pub created_by: String,
+    pub created_at: String,
+    pub updated_at: String,
 }
 
 impl From<crate::client::WorkflowDefinitionDto> for WorkflowDefinitionData {
     fn from(dto: crate::client::WorkflowDefinitionDto) -> Self {
         Self {
-            id:          dto.id,
-            name:        dto.name,
+            id: dto.id,
+            name: dto.name,
             description: dto.description,
-            version:     dto.version,
-            definition:  dto.definition,
-            status:      dto.status,
-            created_by:  dto.created_by,
-            created_at:  dto.created_at,
-            updated_at:  dto.updated_at,
+            version: dto.version,
+            definition: dto.definition,
+            status: dto.status,
+            confidential: dto.confidential,
+            default_watchers: dto.default_watchers,
+            created_by: dto.created_by,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ringiflow_domain::{tenant::TenantId, user::UserId};
+
+    use super::*;
+
+    fn session_with_permissions(permissions: &[&str]) -> SessionData {
+        SessionData::new(
+            UserId::new(),
+            TenantId::new(),
+            "user@example.com".to_string(),
+            "Test User".to_string(),
+            vec!["user".to_string()],
+            permissions.iter().map(|p| p.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn test_workflow_viewer_一般ユーザーはワークフロー管理者ではない() {
+        let session = session_with_permissions(&["workflow:read", "workflow:create"]);
+
+        let viewer = workflow_viewer(&session);
+
+        assert_eq!(viewer.tenant_id, *session.tenant_id().as_uuid());
+        assert_eq!(viewer.user_id, *session.user_id().as_uuid());
+        assert!(!viewer.is_workflow_admin);
+    }
+
+    #[test]
+    fn test_workflow_viewer_ワイルドカード権限でワークフロー管理者になる() {
+        let session = session_with_permissions(&["workflow:*"]);
+
+        let viewer = workflow_viewer(&session);
+
+        assert!(viewer.is_workflow_admin);
+    }
+}
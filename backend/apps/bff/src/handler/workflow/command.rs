@@ -9,17 +9,23 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use axum_extra::extract::CookieJar;
+use ringiflow_domain::message::Message;
 
 use super::{
     ApproveRejectRequest,
+    CommentPathParams,
     CreateWorkflowRequest,
+    DuplicateWorkflowRequest,
+    EditCommentRequest,
     PostCommentRequest,
     ResubmitWorkflowRequest,
+    RetractApprovalRequest,
     StepPathParams,
     SubmitWorkflowRequest,
     WorkflowCommentData,
     WorkflowData,
     WorkflowState,
+    workflow_viewer,
 };
 use crate::error::{authenticate, log_and_convert_core_error, validation_error_response};
 
@@ -41,6 +47,7 @@ use crate::error::{authenticate, log_and_convert_core_error, validation_error_re
    responses(
       (status = 201, description = "ワークフロー作成", body = WorkflowData),
       (status = 400, description = "バリデーションエラー", body = ringiflow_shared::ErrorResponse),
+      (status = 403, description = "本人から代理権限を付与されていない", body = ringiflow_shared::ErrorResponse),
       (status = 404, description = "定義が見つからない", body = ringiflow_shared::ErrorResponse)
    )
 )]
@@ -57,6 +64,7 @@ pub async fn create_workflow(
         definition_id: req.definition_id,
         title:         req.title,
         form_data:     req.form_data,
+        on_behalf_of:  req.on_behalf_of,
         tenant_id:     *session_data.tenant_id().as_uuid(),
         user_id:       *session_data.user_id().as_uuid(),
     };
@@ -71,6 +79,112 @@ pub async fn create_workflow(
     Ok((StatusCode::CREATED, Json(response)).into_response())
 }
 
+/// POST /api/v1/workflows/{display_number}/duplicate
+///
+/// 既存のワークフローを複製して下書きを作成する
+///
+/// ## 処理フロー
+///
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
+/// 2. Core Service の `POST /internal/workflows/by-display-number/{display_number}/duplicate` を呼び出し
+/// 3. 201 Created + 作成した下書きを返す
+#[utoipa::path(
+   post,
+   path = "/api/v1/workflows/{display_number}/duplicate",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "複製元のワークフロー表示番号")),
+   request_body = DuplicateWorkflowRequest,
+   responses(
+      (status = 201, description = "下書き作成", body = WorkflowData),
+      (status = 400, description = "定義が公開されていない", body = ringiflow_shared::ErrorResponse),
+      (status = 404, description = "ワークフローが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn duplicate_workflow(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+    Json(req): Json<DuplicateWorkflowRequest>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+    let viewer = workflow_viewer(&session_data);
+
+    let core_req = crate::client::DuplicateWorkflowCoreRequest {
+        copy_attachments:  req.copy_attachments,
+        tenant_id:         viewer.tenant_id,
+        user_id:           viewer.user_id,
+        is_workflow_admin: viewer.is_workflow_admin,
+    };
+
+    let core_response = state
+        .core_service_client
+        .duplicate_workflow(display_number, &core_req)
+        .await
+        .map_err(|e| log_and_convert_core_error("ワークフロー複製", e))?;
+
+    let response = WorkflowData::from(core_response);
+    Ok((StatusCode::CREATED, Json(response)).into_response())
+}
+
+/// DELETE /api/v1/workflows/{display_number}
+///
+/// 下書きのワークフローを削除する
+///
+/// ## 処理フロー
+///
+/// 1. セッションから `tenant_id`, `user_id` を取得
+/// 2. Core Service の `DELETE /internal/workflows/by-display-number/{display_number}` を呼び出し
+/// 3. 204 No Content を返す
+#[utoipa::path(
+   delete,
+   path = "/api/v1/workflows/{display_number}",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   responses(
+      (status = 204, description = "削除成功"),
+      (status = 400, description = "下書き以外は削除できない", body = ringiflow_shared::ErrorResponse),
+      (status = 403, description = "申請者以外は削除できない", body = ringiflow_shared::ErrorResponse),
+      (status = 404, description = "ワークフローが見つからない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn delete_workflow(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    state
+        .core_service_client
+        .delete_workflow(
+            display_number,
+            *session_data.tenant_id().as_uuid(),
+            *session_data.user_id().as_uuid(),
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("ワークフロー削除", e))?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
 /// POST /api/v1/workflows/{display_number}/submit
 ///
 /// ワークフローを申請する
@@ -103,7 +217,7 @@ pub async fn submit_workflow(
 ) -> Result<Response, Response> {
     if display_number <= 0 {
         return Err(validation_error_response(
-            "display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "display_number"),
         ));
     }
 
@@ -167,22 +281,23 @@ pub async fn approve_step(
 ) -> Result<Response, Response> {
     if params.display_number <= 0 {
         return Err(validation_error_response(
-            "display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "display_number"),
         ));
     }
     if params.step_display_number <= 0 {
         return Err(validation_error_response(
-            "step_display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "step_display_number"),
         ));
     }
 
     let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
 
     let core_req = crate::client::ApproveRejectRequest {
-        version:   req.version,
-        comment:   req.comment,
-        tenant_id: *session_data.tenant_id().as_uuid(),
-        user_id:   *session_data.user_id().as_uuid(),
+        version:         req.version,
+        comment:         req.comment,
+        form_data_edits: req.form_data_edits,
+        tenant_id:       *session_data.tenant_id().as_uuid(),
+        user_id:         *session_data.user_id().as_uuid(),
     };
 
     let core_response = state
@@ -229,22 +344,23 @@ pub async fn reject_step(
 ) -> Result<Response, Response> {
     if params.display_number <= 0 {
         return Err(validation_error_response(
-            "display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "display_number"),
         ));
     }
     if params.step_display_number <= 0 {
         return Err(validation_error_response(
-            "step_display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "step_display_number"),
         ));
     }
 
     let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
 
     let core_req = crate::client::ApproveRejectRequest {
-        version:   req.version,
-        comment:   req.comment,
-        tenant_id: *session_data.tenant_id().as_uuid(),
-        user_id:   *session_data.user_id().as_uuid(),
+        version:         req.version,
+        comment:         req.comment,
+        form_data_edits: req.form_data_edits,
+        tenant_id:       *session_data.tenant_id().as_uuid(),
+        user_id:         *session_data.user_id().as_uuid(),
     };
 
     let core_response = state
@@ -291,33 +407,101 @@ pub async fn request_changes_step(
 ) -> Result<Response, Response> {
     if params.display_number <= 0 {
         return Err(validation_error_response(
-            "display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "display_number"),
         ));
     }
     if params.step_display_number <= 0 {
         return Err(validation_error_response(
-            "step_display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "step_display_number"),
         ));
     }
 
     let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
 
     let core_req = crate::client::ApproveRejectRequest {
+        version:         req.version,
+        comment:         req.comment,
+        form_data_edits: req.form_data_edits,
+        tenant_id:       *session_data.tenant_id().as_uuid(),
+        user_id:         *session_data.user_id().as_uuid(),
+    };
+
+    let core_response = state
+        .core_service_client
+        .request_changes_step_by_display_number(
+            params.display_number,
+            params.step_display_number,
+            core_req,
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("ステップ差し戻し", e))?;
+
+    let response = WorkflowData::from(core_response);
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// POST /api/v1/workflows/{display_number}/steps/{step_display_number}/retract
+///
+/// ワークフローステップの承認を取り消す
+///
+/// 承認した本人のみ、次のステップが処理される前（定義で期限が指定されている場合はその期限内）に限り取り消せる。
+///
+/// ## 処理フロー
+///
+/// 1. セッションから `tenant_id`, `user_id` を取得
+/// 2. Core Service の `POST /internal/workflows/by-display-number/{dn}/steps/by-display-number/{step_dn}/retract` を呼び出し
+/// 3. 200 OK + 更新されたワークフローを返す
+#[utoipa::path(
+   post,
+   path = "/api/v1/workflows/{display_number}/steps/{step_display_number}/retract",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(StepPathParams),
+   request_body = RetractApprovalRequest,
+   responses(
+      (status = 200, description = "承認取り消し成功", body = WorkflowData),
+      (status = 400, description = "取り消しできない状態", body = ringiflow_shared::ErrorResponse),
+      (status = 403, description = "権限なし", body = ringiflow_shared::ErrorResponse),
+      (status = 404, description = "ステップが見つからない", body = ringiflow_shared::ErrorResponse),
+      (status = 409, description = "競合", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number = params.display_number, step_display_number = params.step_display_number))]
+pub async fn retract_approval(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(params): Path<StepPathParams>,
+    Json(req): Json<RetractApprovalRequest>,
+) -> Result<Response, Response> {
+    if params.display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+    if params.step_display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "step_display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_req = crate::client::RetractApprovalCoreRequest {
         version:   req.version,
-        comment:   req.comment,
         tenant_id: *session_data.tenant_id().as_uuid(),
         user_id:   *session_data.user_id().as_uuid(),
     };
 
     let core_response = state
         .core_service_client
-        .request_changes_step_by_display_number(
+        .retract_approval_by_display_number(
             params.display_number,
             params.step_display_number,
             core_req,
         )
         .await
-        .map_err(|e| log_and_convert_core_error("ステップ差し戻し", e))?;
+        .map_err(|e| log_and_convert_core_error("承認取り消し", e))?;
 
     let response = WorkflowData::from(core_response);
     Ok((StatusCode::OK, Json(response)).into_response())
@@ -357,7 +541,7 @@ pub async fn resubmit_workflow(
 ) -> Result<Response, Response> {
     if display_number <= 0 {
         return Err(validation_error_response(
-            "display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "display_number"),
         ));
     }
 
@@ -423,7 +607,7 @@ pub async fn post_comment(
 ) -> Result<Response, Response> {
     if display_number <= 0 {
         return Err(validation_error_response(
-            "display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "display_number"),
         ));
     }
 
@@ -431,6 +615,7 @@ pub async fn post_comment(
 
     let core_req = crate::client::PostCommentCoreRequest {
         body:      req.body,
+        parent_id: req.parent_id,
         tenant_id: *session_data.tenant_id().as_uuid(),
         user_id:   *session_data.user_id().as_uuid(),
     };
@@ -444,3 +629,203 @@ pub async fn post_comment(
     let response = WorkflowCommentData::from(core_response);
     Ok((StatusCode::CREATED, Json(response)).into_response())
 }
+
+/// PATCH /api/v1/workflows/{display_number}/comments/{comment_id}
+///
+/// 自分が投稿したコメントを編集する
+///
+/// 投稿から一定時間内に限り編集できる。編集前の本文は編集履歴として残る。
+///
+/// ## 処理フロー
+///
+/// 1. セッションから `tenant_id`, `user_id` を取得
+/// 2. Core Service の `PATCH /internal/workflows/by-display-number/{display_number}/comments/{comment_id}` を呼び出し
+/// 3. 200 OK + 編集後のコメントを返す
+#[utoipa::path(
+   patch,
+   path = "/api/v1/workflows/{display_number}/comments/{comment_id}",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(CommentPathParams),
+   request_body = EditCommentRequest,
+   responses(
+      (status = 200, description = "コメント編集成功", body = WorkflowCommentData),
+      (status = 400, description = "バリデーションエラー、削除済み、または編集期間の経過", body = ringiflow_shared::ErrorResponse),
+      (status = 403, description = "投稿者本人ではない", body = ringiflow_shared::ErrorResponse),
+      (status = 404, description = "コメントが見つからない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number = params.display_number, comment_id = %params.comment_id))]
+pub async fn edit_comment(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(params): Path<CommentPathParams>,
+    Json(req): Json<EditCommentRequest>,
+) -> Result<Response, Response> {
+    if params.display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_req = crate::client::EditCommentCoreRequest {
+        body:      req.body,
+        tenant_id: *session_data.tenant_id().as_uuid(),
+        user_id:   *session_data.user_id().as_uuid(),
+    };
+
+    let core_response = state
+        .core_service_client
+        .edit_comment(params.display_number, params.comment_id, core_req)
+        .await
+        .map_err(|e| log_and_convert_core_error("コメント編集", e))?;
+
+    let response = WorkflowCommentData::from(core_response);
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// DELETE /api/v1/workflows/{display_number}/comments/{comment_id}
+///
+/// コメントを削除する（論理削除）
+///
+/// 投稿者本人または `workflow:admin` 権限を持つユーザーが削除できる。
+/// 削除後も返信のスレッドを保つため、一覧には本文が空のコメントとして残る。
+///
+/// ## 処理フロー
+///
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
+/// 2. Core Service の `DELETE /internal/workflows/by-display-number/{display_number}/comments/{comment_id}` を呼び出し
+/// 3. 204 No Content を返す
+#[utoipa::path(
+   delete,
+   path = "/api/v1/workflows/{display_number}/comments/{comment_id}",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(CommentPathParams),
+   responses(
+      (status = 204, description = "コメント削除成功"),
+      (status = 400, description = "既に削除されている", body = ringiflow_shared::ErrorResponse),
+      (status = 403, description = "投稿者本人でも管理者でもない", body = ringiflow_shared::ErrorResponse),
+      (status = 404, description = "コメントが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number = params.display_number, comment_id = %params.comment_id))]
+pub async fn delete_comment(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(params): Path<CommentPathParams>,
+) -> Result<Response, Response> {
+    if params.display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    state
+        .core_service_client
+        .delete_comment(
+            params.display_number,
+            params.comment_id,
+            &workflow_viewer(&session_data),
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("コメント削除", e))?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+// ===== ウォッチャーハンドラ =====
+
+/// POST /api/v1/workflows/{display_number}/watch
+///
+/// ワークフローをウォッチする
+///
+/// ## 処理フロー
+///
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
+/// 2. Core Service の `POST /internal/workflows/by-display-number/{display_number}/watchers` を呼び出し
+/// 3. 204 No Content を返す（ウォッチ済みの場合も成功）
+#[utoipa::path(
+   post,
+   path = "/api/v1/workflows/{display_number}/watch",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   responses(
+      (status = 204, description = "ウォッチ成功"),
+      (status = 404, description = "ワークフローが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn watch_workflow(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    state
+        .core_service_client
+        .watch_workflow(display_number, &workflow_viewer(&session_data))
+        .await
+        .map_err(|e| log_and_convert_core_error("ワークフローウォッチ", e))?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// DELETE /api/v1/workflows/{display_number}/watch
+///
+/// ワークフローのウォッチを解除する
+///
+/// ## 処理フロー
+///
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
+/// 2. Core Service の `DELETE /internal/workflows/by-display-number/{display_number}/watchers` を呼び出し
+/// 3. 204 No Content を返す（ウォッチしていない場合も成功）
+#[utoipa::path(
+   delete,
+   path = "/api/v1/workflows/{display_number}/watch",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   responses(
+      (status = 204, description = "ウォッチ解除成功"),
+      (status = 404, description = "ワークフローが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn unwatch_workflow(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    state
+        .core_service_client
+        .unwatch_workflow(display_number, &workflow_viewer(&session_data))
+        .await
+        .map_err(|e| log_and_convert_core_error("ワークフローウォッチ解除", e))?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
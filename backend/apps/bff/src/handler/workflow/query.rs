@@ -4,22 +4,35 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
 };
 use axum_extra::extract::CookieJar;
+use ringiflow_domain::message::Message;
+use ringiflow_shared::PaginatedResponse;
 
 use super::{
+    CommentPathParams,
+    PageQuery,
+    SearchWorkflowsQuery,
     StepPathParams,
+    SubmissionDiffData,
+    SubmissionDiffQuery,
+    WorkflowActivityData,
     WorkflowCommentData,
+    WorkflowCommentRevisionData,
     WorkflowData,
     WorkflowDefinitionData,
+    WorkflowFormDataChangeData,
     WorkflowState,
+    WorkflowSubmissionData,
     WorkflowSummaryData,
+    WorkflowWatcherData,
+    workflow_viewer,
 };
 use crate::{
-    client::CoreServiceError,
+    client::{CoreServiceError, PageCoreQuery, SearchWorkflowsCoreQuery},
     error::{
         authenticate,
         log_and_convert_core_error,
@@ -109,7 +122,8 @@ pub async fn get_workflow_definition(
 
 /// GET /api/v1/workflows
 ///
-/// 自分のワークフロー一覧を取得する
+/// 自分のワークフロー一覧を取得する（新しい順）。
+/// カーソルベースページネーション対応。
 ///
 /// ## 処理フロー
 ///
@@ -121,8 +135,10 @@ pub async fn get_workflow_definition(
    path = "/api/v1/workflows",
    tag = "workflows",
    security(("session_auth" = [])),
+   params(PageQuery),
    responses(
-      (status = 200, description = "自分のワークフロー一覧", body = Vec<WorkflowSummaryData>),
+      (status = 200, description = "自分のワークフロー一覧", body = PaginatedResponse<WorkflowSummaryData>),
+      (status = 400, description = "不正なカーソル", body = ringiflow_shared::ErrorResponse),
       (status = 401, description = "認証エラー", body = ringiflow_shared::ErrorResponse)
    )
 )]
@@ -131,6 +147,7 @@ pub async fn list_my_workflows(
     State(state): State<Arc<WorkflowState>>,
     headers: HeaderMap,
     jar: CookieJar,
+    Query(query): Query<PageQuery>,
 ) -> Result<Response, Response> {
     let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
 
@@ -139,14 +156,86 @@ pub async fn list_my_workflows(
         .list_my_workflows(
             *session_data.tenant_id().as_uuid(),
             *session_data.user_id().as_uuid(),
+            &PageCoreQuery {
+                cursor: query.cursor,
+                limit:  query.limit,
+            },
         )
         .await
         .map_err(|e| log_and_convert_core_error("ワークフロー一覧取得", e))?;
 
-    let response = core_response
-        .into_iter()
-        .map(WorkflowSummaryData::from)
-        .collect::<Vec<_>>();
+    let response = PaginatedResponse {
+        items:       core_response
+            .items
+            .into_iter()
+            .map(WorkflowSummaryData::from)
+            .collect::<Vec<_>>(),
+        next_cursor: core_response.next_cursor,
+    };
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// GET /api/v1/workflows/search
+///
+/// テナント内のワークフローをキーワードとファセットで検索する（新しい順）。
+/// カーソルベースページネーション対応。
+///
+/// ## 処理フロー
+///
+/// 1. セッションから `tenant_id`, `user_id` を取得
+/// 2. Core Service の `GET /internal/workflows/search` を呼び出し
+/// 3. レスポンスを返す
+#[utoipa::path(
+   get,
+   path = "/api/v1/workflows/search",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(SearchWorkflowsQuery),
+   responses(
+      (status = 200, description = "検索結果", body = PaginatedResponse<WorkflowSummaryData>),
+      (status = 400, description = "バリデーションエラー（不正な条件・カーソル等）", body = ringiflow_shared::ErrorResponse),
+      (status = 401, description = "認証エラー", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn search_workflows(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Query(query): Query<SearchWorkflowsQuery>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let viewer = workflow_viewer(&session_data);
+    let core_query = SearchWorkflowsCoreQuery {
+        tenant_id: viewer.tenant_id,
+        user_id: viewer.user_id,
+        is_workflow_admin: viewer.is_workflow_admin,
+        q: query.q,
+        definition_id: query.definition_id,
+        status: query.status,
+        created_from: query.created_from,
+        created_to: query.created_to,
+        initiated_by: query.initiated_by,
+        current_approver: query.current_approver,
+        cursor: query.cursor,
+        limit: query.limit,
+    };
+
+    let core_response = state
+        .core_service_client
+        .search_workflows(&core_query)
+        .await
+        .map_err(|e| log_and_convert_core_error("ワークフロー検索", e))?;
+
+    let response = PaginatedResponse {
+        items:       core_response
+            .items
+            .into_iter()
+            .map(WorkflowSummaryData::from)
+            .collect::<Vec<_>>(),
+        next_cursor: core_response.next_cursor,
+    };
     Ok((StatusCode::OK, Json(response)).into_response())
 }
 
@@ -156,7 +245,7 @@ pub async fn list_my_workflows(
 ///
 /// ## 処理フロー
 ///
-/// 1. セッションから `tenant_id` を取得
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
 /// 2. Core Service の `GET /internal/workflows/by-display-number/{display_number}` を呼び出し
 /// 3. レスポンスを返す
 #[utoipa::path(
@@ -167,7 +256,7 @@ pub async fn list_my_workflows(
    params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
    responses(
       (status = 200, description = "ワークフロー詳細", body = WorkflowData),
-      (status = 404, description = "ワークフローが見つからない", body = ringiflow_shared::ErrorResponse)
+      (status = 404, description = "ワークフローが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
    )
 )]
 #[tracing::instrument(skip_all, fields(display_number))]
@@ -179,7 +268,7 @@ pub async fn get_workflow(
 ) -> Result<Response, Response> {
     if display_number <= 0 {
         return Err(validation_error_response(
-            "display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "display_number"),
         ));
     }
 
@@ -187,7 +276,7 @@ pub async fn get_workflow(
 
     let core_response = state
         .core_service_client
-        .get_workflow_by_display_number(display_number, *session_data.tenant_id().as_uuid())
+        .get_workflow_by_display_number(display_number, &workflow_viewer(&session_data))
         .await
         .map_err(|e| log_and_convert_core_error("ワークフロー取得", e))?;
 
@@ -220,12 +309,12 @@ pub async fn get_task_by_display_numbers(
 ) -> Result<Response, Response> {
     if params.display_number <= 0 {
         return Err(validation_error_response(
-            "display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "display_number"),
         ));
     }
     if params.step_display_number <= 0 {
         return Err(validation_error_response(
-            "step_display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "step_display_number"),
         ));
     }
 
@@ -242,9 +331,11 @@ pub async fn get_task_by_display_numbers(
         .await
         .map_err(|e| match e {
             // タスクコンテキストでは StepNotFound を task-not-found として返す
-            CoreServiceError::StepNotFound => {
-                not_found_response("task-not-found", "Task Not Found", "タスクが見つかりません")
-            }
+            CoreServiceError::StepNotFound => not_found_response(
+                "task-not-found",
+                "Task Not Found",
+                Message::new("task-not-found"),
+            ),
             e => log_and_convert_core_error("タスク詳細取得", e),
         })?;
 
@@ -260,7 +351,7 @@ pub async fn get_task_by_display_numbers(
 ///
 /// ## 処理フロー
 ///
-/// 1. セッションから `tenant_id` を取得
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
 /// 2. Core Service の `GET /internal/workflows/by-display-number/{display_number}/comments` を呼び出し
 /// 3. 200 OK + コメント一覧を返す
 #[utoipa::path(
@@ -271,7 +362,7 @@ pub async fn get_task_by_display_numbers(
    params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
    responses(
       (status = 200, description = "コメント一覧", body = Vec<WorkflowCommentData>),
-      (status = 404, description = "ワークフローが見つからない", body = ringiflow_shared::ErrorResponse)
+      (status = 404, description = "ワークフローが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
    )
 )]
 #[tracing::instrument(skip_all, fields(display_number))]
@@ -283,7 +374,7 @@ pub async fn list_comments(
 ) -> Result<Response, Response> {
     if display_number <= 0 {
         return Err(validation_error_response(
-            "display_number は 1 以上である必要があります",
+            Message::new("must-be-at-least-one").with("name", "display_number"),
         ));
     }
 
@@ -291,7 +382,7 @@ pub async fn list_comments(
 
     let core_response = state
         .core_service_client
-        .list_comments(display_number, *session_data.tenant_id().as_uuid())
+        .list_comments(display_number, &workflow_viewer(&session_data))
         .await
         .map_err(|e| log_and_convert_core_error("コメント一覧取得", e))?;
 
@@ -301,3 +392,310 @@ pub async fn list_comments(
         .collect::<Vec<_>>();
     Ok((StatusCode::OK, Json(response)).into_response())
 }
+
+/// GET /api/v1/workflows/{display_number}/comments/{comment_id}/revisions
+///
+/// コメントの編集履歴（編集前の本文）を取得する
+///
+/// ## 処理フロー
+///
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
+/// 2. Core Service の `GET /internal/workflows/by-display-number/{display_number}/comments/{comment_id}/revisions` を呼び出し
+/// 3. 200 OK + 編集履歴を返す
+#[utoipa::path(
+   get,
+   path = "/api/v1/workflows/{display_number}/comments/{comment_id}/revisions",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(CommentPathParams),
+   responses(
+      (status = 200, description = "コメント編集履歴", body = Vec<WorkflowCommentRevisionData>),
+      (status = 404, description = "コメントが見つからない、削除済み、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number = params.display_number, comment_id = %params.comment_id))]
+pub async fn list_comment_revisions(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(params): Path<CommentPathParams>,
+) -> Result<Response, Response> {
+    if params.display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_comment_revisions(
+            params.display_number,
+            params.comment_id,
+            &workflow_viewer(&session_data),
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("コメント編集履歴取得", e))?;
+
+    let response = core_response
+        .into_iter()
+        .map(WorkflowCommentRevisionData::from)
+        .collect::<Vec<_>>();
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+// ===== ウォッチャーハンドラ =====
+
+/// GET /api/v1/workflows/{display_number}/watchers
+///
+/// ワークフローのウォッチャー一覧を取得する
+///
+/// ## 処理フロー
+///
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
+/// 2. Core Service の `GET /internal/workflows/by-display-number/{display_number}/watchers` を呼び出し
+/// 3. 200 OK + ウォッチャー一覧（登録日時の昇順）を返す
+#[utoipa::path(
+   get,
+   path = "/api/v1/workflows/{display_number}/watchers",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   responses(
+      (status = 200, description = "ウォッチャー一覧", body = Vec<WorkflowWatcherData>),
+      (status = 404, description = "ワークフローが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn list_watchers(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_watchers(display_number, &workflow_viewer(&session_data))
+        .await
+        .map_err(|e| log_and_convert_core_error("ウォッチャー一覧取得", e))?;
+
+    let response = core_response
+        .into_iter()
+        .map(WorkflowWatcherData::from)
+        .collect::<Vec<_>>();
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+// ===== フォームデータ変更履歴ハンドラ =====
+
+/// GET /api/v1/workflows/{display_number}/form-data-changes
+///
+/// 承認者によるフォームデータ編集の変更履歴を取得する
+///
+/// ## 処理フロー
+///
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
+/// 2. Core Service の `GET /internal/workflows/by-display-number/{display_number}/form-data-changes` を呼び出し
+/// 3. 200 OK + 変更履歴（変更日時の昇順）を返す
+#[utoipa::path(
+   get,
+   path = "/api/v1/workflows/{display_number}/form-data-changes",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   responses(
+      (status = 200, description = "フォームデータ変更履歴", body = Vec<WorkflowFormDataChangeData>),
+      (status = 404, description = "ワークフローが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn list_form_data_changes(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_form_data_changes(display_number, &workflow_viewer(&session_data))
+        .await
+        .map_err(|e| log_and_convert_core_error("フォームデータ変更履歴取得", e))?;
+
+    let response = core_response
+        .into_iter()
+        .map(WorkflowFormDataChangeData::from)
+        .collect::<Vec<_>>();
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+// ===== 申請スナップショットハンドラ =====
+
+/// GET /api/v1/workflows/{display_number}/activities
+///
+/// 作成・申請・承認・差し戻し・コメント・添付などの出来事を時系列で取得する
+///
+/// ## 処理フロー
+///
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
+/// 2. Core Service の `GET /internal/workflows/by-display-number/{display_number}/activities` を呼び出し
+/// 3. 200 OK + アクティビティ一覧（発生日時昇順）を返す
+#[utoipa::path(
+   get,
+   path = "/api/v1/workflows/{display_number}/activities",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   responses(
+      (status = 200, description = "アクティビティ一覧", body = Vec<WorkflowActivityData>),
+      (status = 404, description = "ワークフローが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn list_activities(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_activities(display_number, &workflow_viewer(&session_data))
+        .await
+        .map_err(|e| log_and_convert_core_error("アクティビティ一覧取得", e))?;
+
+    let response = core_response
+        .into_iter()
+        .map(WorkflowActivityData::from)
+        .collect::<Vec<_>>();
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// GET /api/v1/workflows/{display_number}/submissions
+///
+/// 申請ラウンドごとのフォームデータと添付ファイル一覧のスナップショットを取得する
+///
+/// ## 処理フロー
+///
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
+/// 2. Core Service の `GET /internal/workflows/by-display-number/{display_number}/submissions` を呼び出し
+/// 3. 200 OK + スナップショット一覧（ラウンド昇順）を返す
+#[utoipa::path(
+   get,
+   path = "/api/v1/workflows/{display_number}/submissions",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   responses(
+      (status = 200, description = "申請スナップショット一覧", body = Vec<WorkflowSubmissionData>),
+      (status = 404, description = "ワークフローが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn list_submissions(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_submissions(display_number, &workflow_viewer(&session_data))
+        .await
+        .map_err(|e| log_and_convert_core_error("申請スナップショット一覧取得", e))?;
+
+    let response = core_response
+        .into_iter()
+        .map(WorkflowSubmissionData::from)
+        .collect::<Vec<_>>();
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// GET /api/v1/workflows/{display_number}/submissions/diff
+///
+/// 2 つの申請ラウンド間のフォームデータと添付ファイルの差分を取得する
+///
+/// `from` / `to` を省略すると、最新ラウンドとその直前のラウンドを比較する。
+///
+/// ## 処理フロー
+///
+/// 1. セッションから閲覧者（`tenant_id`, `user_id`, `workflow:admin` 権限の有無）を取得
+/// 2. Core Service の `GET /internal/workflows/by-display-number/{display_number}/submissions/diff` を呼び出し
+/// 3. 200 OK + 差分を返す
+#[utoipa::path(
+   get,
+   path = "/api/v1/workflows/{display_number}/submissions/diff",
+   tag = "workflows",
+   security(("session_auth" = [])),
+   params(
+      ("display_number" = i64, Path, description = "ワークフロー表示番号"),
+      SubmissionDiffQuery
+   ),
+   responses(
+      (status = 200, description = "申請ラウンド間の差分", body = SubmissionDiffData),
+      (status = 400, description = "比較元が比較先以降のラウンド", body = ringiflow_shared::ErrorResponse),
+      (status = 404, description = "ワークフローまたは申請ラウンドが見つからない、または閲覧権限がない", body = ringiflow_shared::ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn diff_submissions(
+    State(state): State<Arc<WorkflowState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+    Query(query): Query<SubmissionDiffQuery>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .diff_submissions(
+            display_number,
+            query.from,
+            query.to,
+            &workflow_viewer(&session_data),
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("申請ラウンド差分取得", e))?;
+
+    let response = SubmissionDiffData::from(core_response);
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
@@ -0,0 +1,390 @@
+//! # ワークフロー管理 API ハンドラ（テナント管理者向け）
+//!
+//! テナント内のすべてのワークフローを閲覧し、通常のフローの外で強制操作する
+//! エンドポイントを提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /api/v1/admin/workflows` - テナント内の全ワークフロー一覧（検索・絞り込み）
+//! - `GET /api/v1/admin/workflows/{display_number}` - 任意のワークフローの詳細
+//! - `POST /api/v1/admin/workflows/{display_number}/cancel` - 強制取消
+//! - `POST /api/v1/admin/workflows/{display_number}/reassign` - 現在のステップの担当者変更
+//! - `POST /api/v1/admin/workflows/{display_number}/force-complete` - 強制承認完了
+//!
+//! すべてのエンドポイントは `workflow:admin` 権限を要求する（`tenant_admin` ロールは
+//! `workflow:*` により保持する。一般ユーザーの `workflow:read` / `workflow:create` では不可）。
+//! 強制操作は理由の入力を必須とし、監査ログに記録する。
+//!
+//! 機密定義のワークフローは、管理者であっても関与者でなければ一覧・詳細に含まれない。
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use ringiflow_domain::{
+    audit_log::{AuditAction, AuditLog},
+    message::Message,
+};
+use ringiflow_infra::{SessionData, SessionManager, repository::AuditLogRepository};
+use ringiflow_shared::{ErrorResponse, PaginatedResponse};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::workflow::{SearchWorkflowsQuery, WorkflowData, WorkflowSummaryData, workflow_viewer};
+use crate::{
+    client::{
+        AdminActionCoreRequest,
+        CoreServiceWorkflowAdminClient,
+        ReassignStepCoreRequest,
+        SearchWorkflowsCoreQuery,
+        WorkflowInstanceDto,
+    },
+    error::{authenticate, log_and_convert_core_error, validation_error_response},
+};
+
+/// ワークフロー管理 API の共有状態
+pub struct WorkflowAdminState {
+    pub core_service_client:  Arc<dyn CoreServiceWorkflowAdminClient>,
+    pub session_manager:      Arc<dyn SessionManager>,
+    pub audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+// --- リクエスト型 ---
+
+/// 強制取消・強制完了リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AdminWorkflowActionRequest {
+    /// 楽観的ロック用バージョン（ワークフロー詳細取得時の値）
+    pub version: i32,
+    /// 操作理由（必須、1000 文字以内）
+    pub reason:  String,
+}
+
+/// 担当者変更リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReassignWorkflowStepRequest {
+    /// 楽観的ロック用バージョン（ワークフロー詳細取得時の値）
+    pub version:     i32,
+    /// 変更後の担当者のユーザー ID
+    pub assigned_to: Uuid,
+    /// 操作理由（必須、1000 文字以内）
+    pub reason:      String,
+}
+
+// --- ハンドラ ---
+
+/// GET /api/v1/admin/workflows
+///
+/// テナント内の全ワークフローを一覧する（作成日時の降順）。
+/// 検索条件は `GET /api/v1/workflows/search` と同じで、他のユーザーの下書きも含む。
+#[utoipa::path(
+   get,
+   path = "/api/v1/admin/workflows",
+   tag = "admin-workflows",
+   security(("session_auth" = [])),
+   params(SearchWorkflowsQuery),
+   responses(
+      (status = 200, description = "ワークフロー一覧", body = PaginatedResponse<WorkflowSummaryData>),
+      (status = 400, description = "バリデーションエラー（不正な条件・カーソル等）", body = ErrorResponse),
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn list_all_workflows(
+    State(state): State<Arc<WorkflowAdminState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Query(query): Query<SearchWorkflowsQuery>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_query = SearchWorkflowsCoreQuery {
+        tenant_id: *session_data.tenant_id().as_uuid(),
+        user_id: *session_data.user_id().as_uuid(),
+        is_workflow_admin: true,
+        q: query.q,
+        definition_id: query.definition_id,
+        status: query.status,
+        created_from: query.created_from,
+        created_to: query.created_to,
+        initiated_by: query.initiated_by,
+        current_approver: query.current_approver,
+        cursor: query.cursor,
+        limit: query.limit,
+    };
+
+    let core_response = state
+        .core_service_client
+        .list_all_workflows(&core_query)
+        .await
+        .map_err(|e| log_and_convert_core_error("全ワークフロー一覧取得", e))?;
+
+    let response = PaginatedResponse {
+        items:       core_response
+            .items
+            .into_iter()
+            .map(WorkflowSummaryData::from)
+            .collect::<Vec<_>>(),
+        next_cursor: core_response.next_cursor,
+    };
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// GET /api/v1/admin/workflows/{display_number}
+///
+/// テナント内の任意のワークフローの詳細を取得する。
+#[utoipa::path(
+   get,
+   path = "/api/v1/admin/workflows/{display_number}",
+   tag = "admin-workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   responses(
+      (status = 200, description = "ワークフロー詳細", body = WorkflowData),
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "ワークフローが見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn get_any_workflow(
+    State(state): State<Arc<WorkflowAdminState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .get_any_workflow(display_number, &workflow_viewer(&session_data))
+        .await
+        .map_err(|e| log_and_convert_core_error("ワークフロー取得", e))?;
+
+    Ok((StatusCode::OK, Json(WorkflowData::from(core_response))).into_response())
+}
+
+/// POST /api/v1/admin/workflows/{display_number}/cancel
+///
+/// ワークフローを強制的に取り消す。未完了のステップはスキップされる。
+#[utoipa::path(
+   post,
+   path = "/api/v1/admin/workflows/{display_number}/cancel",
+   tag = "admin-workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   request_body = AdminWorkflowActionRequest,
+   responses(
+      (status = 200, description = "取消後のワークフロー", body = WorkflowData),
+      (status = 400, description = "バリデーションエラー（理由の未入力・完了済み等）", body = ErrorResponse),
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "ワークフローが見つからない", body = ErrorResponse),
+      (status = 409, description = "競合", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn force_cancel_workflow(
+    State(state): State<Arc<WorkflowAdminState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+    Json(req): Json<AdminWorkflowActionRequest>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_req = AdminActionCoreRequest {
+        version:   req.version,
+        reason:    req.reason,
+        tenant_id: *session_data.tenant_id().as_uuid(),
+        user_id:   *session_data.user_id().as_uuid(),
+    };
+
+    let dto = state
+        .core_service_client
+        .force_cancel_workflow(display_number, &core_req)
+        .await
+        .map_err(|e| log_and_convert_core_error("ワークフロー強制取消", e))?;
+
+    record_audit_log(
+        &state,
+        &session_data,
+        AuditAction::WorkflowCancel,
+        &dto,
+        serde_json::json!({
+           "display_id": &dto.display_id,
+           "reason": &core_req.reason,
+        }),
+    )
+    .await;
+
+    Ok((StatusCode::OK, Json(WorkflowData::from(dto))).into_response())
+}
+
+/// POST /api/v1/admin/workflows/{display_number}/reassign
+///
+/// 現在のステップの担当者を変更する。
+/// 変更前の担当者が開いている画面からの承認・却下は競合（409）になる。
+#[utoipa::path(
+   post,
+   path = "/api/v1/admin/workflows/{display_number}/reassign",
+   tag = "admin-workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   request_body = ReassignWorkflowStepRequest,
+   responses(
+      (status = 200, description = "担当者変更後のワークフロー", body = WorkflowData),
+      (status = 400, description = "バリデーションエラー（理由の未入力・処理中でない・無効な担当者等）", body = ErrorResponse),
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "ワークフローが見つからない", body = ErrorResponse),
+      (status = 409, description = "競合", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn reassign_current_step(
+    State(state): State<Arc<WorkflowAdminState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+    Json(req): Json<ReassignWorkflowStepRequest>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_req = ReassignStepCoreRequest {
+        version:     req.version,
+        assigned_to: req.assigned_to,
+        reason:      req.reason,
+        tenant_id:   *session_data.tenant_id().as_uuid(),
+        user_id:     *session_data.user_id().as_uuid(),
+    };
+
+    let dto = state
+        .core_service_client
+        .reassign_current_step(display_number, &core_req)
+        .await
+        .map_err(|e| log_and_convert_core_error("ステップ担当者変更", e))?;
+
+    record_audit_log(
+        &state,
+        &session_data,
+        AuditAction::WorkflowReassign,
+        &dto,
+        serde_json::json!({
+           "display_id": &dto.display_id,
+           "assigned_to": core_req.assigned_to.to_string(),
+           "reason": &core_req.reason,
+        }),
+    )
+    .await;
+
+    Ok((StatusCode::OK, Json(WorkflowData::from(dto))).into_response())
+}
+
+/// POST /api/v1/admin/workflows/{display_number}/force-complete
+///
+/// 残りのステップの判断を待たずにワークフローを承認完了にする。
+/// 通常の承認完了と同じく申請者への通知と Webhook 配信が行われる。
+#[utoipa::path(
+   post,
+   path = "/api/v1/admin/workflows/{display_number}/force-complete",
+   tag = "admin-workflows",
+   security(("session_auth" = [])),
+   params(("display_number" = i64, Path, description = "ワークフロー表示番号")),
+   request_body = AdminWorkflowActionRequest,
+   responses(
+      (status = 200, description = "承認完了後のワークフロー", body = WorkflowData),
+      (status = 400, description = "バリデーションエラー（理由の未入力・処理中でない等）", body = ErrorResponse),
+      (status = 401, description = "認証エラー", body = ErrorResponse),
+      (status = 403, description = "権限不足", body = ErrorResponse),
+      (status = 404, description = "ワークフローが見つからない", body = ErrorResponse),
+      (status = 409, description = "競合", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn force_complete_workflow(
+    State(state): State<Arc<WorkflowAdminState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(display_number): Path<i64>,
+    Json(req): Json<AdminWorkflowActionRequest>,
+) -> Result<Response, Response> {
+    if display_number <= 0 {
+        return Err(validation_error_response(
+            Message::new("must-be-at-least-one").with("name", "display_number"),
+        ));
+    }
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_req = AdminActionCoreRequest {
+        version:   req.version,
+        reason:    req.reason,
+        tenant_id: *session_data.tenant_id().as_uuid(),
+        user_id:   *session_data.user_id().as_uuid(),
+    };
+
+    let dto = state
+        .core_service_client
+        .force_complete_workflow(display_number, &core_req)
+        .await
+        .map_err(|e| log_and_convert_core_error("ワークフロー強制完了", e))?;
+
+    record_audit_log(
+        &state,
+        &session_data,
+        AuditAction::WorkflowForceComplete,
+        &dto,
+        serde_json::json!({
+           "display_id": &dto.display_id,
+           "reason": &core_req.reason,
+        }),
+    )
+    .await;
+
+    Ok((StatusCode::OK, Json(WorkflowData::from(dto))).into_response())
+}
+
+/// 強制操作の監査ログを記録する（記録の失敗は操作の結果に影響させない）
+async fn record_audit_log(
+    state: &WorkflowAdminState,
+    session_data: &SessionData,
+    action: AuditAction,
+    dto: &WorkflowInstanceDto,
+    detail: serde_json::Value,
+) {
+    let audit_log = AuditLog::new_success(
+        session_data.tenant_id().clone(),
+        session_data.user_id().clone(),
+        session_data.name().to_string(),
+        action,
+        "workflow_instance",
+        dto.id.clone(),
+        Some(detail),
+        None,
+    );
+    if let Err(e) = state.audit_log_repository.record(&audit_log).await {
+        tracing::error!("監査ログ記録に失敗: {}", e);
+    }
+}
@@ -51,24 +51,36 @@ pub struct WorkflowDefinitionState {
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateDefinitionRequest {
     /// ワークフロー定義名
-    pub name:        String,
+    pub name: String,
     /// 説明（任意）
     pub description: Option<String>,
     /// 定義 JSON
-    pub definition:  serde_json::Value,
+    pub definition: serde_json::Value,
+    /// 機密定義か（省略時は false）
+    #[serde(default)]
+    pub confidential: bool,
+    /// 既定ウォッチャーのユーザー ID 一覧（省略時は空）
+    #[serde(default)]
+    pub default_watchers: Vec<Uuid>,
 }
 
 /// 定義更新リクエスト（BFF 公開 API）
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateDefinitionRequest {
     /// ワークフロー定義名
-    pub name:        String,
+    pub name: String,
     /// 説明（任意）
     pub description: Option<String>,
     /// 定義 JSON
-    pub definition:  serde_json::Value,
+    pub definition: serde_json::Value,
+    /// 機密定義か（省略時は現在の値を維持）
+    #[serde(default)]
+    pub confidential: Option<bool>,
+    /// 既定ウォッチャーのユーザー ID 一覧（省略時は現在の値を維持）
+    #[serde(default)]
+    pub default_watchers: Option<Vec<Uuid>>,
     /// 楽観的ロック用バージョン
-    pub version:     i32,
+    pub version: i32,
 }
 
 /// 公開/アーカイブリクエスト（BFF 公開 API）
@@ -129,11 +141,13 @@ pub async fn create_definition(
     let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
 
     let core_request = CreateDefinitionCoreRequest {
-        name:        req.name,
+        name: req.name,
         description: req.description,
-        definition:  req.definition,
-        tenant_id:   *session_data.tenant_id().as_uuid(),
-        user_id:     *session_data.user_id().as_uuid(),
+        definition: req.definition,
+        confidential: req.confidential,
+        default_watchers: req.default_watchers,
+        tenant_id: *session_data.tenant_id().as_uuid(),
+        user_id: *session_data.user_id().as_uuid(),
     };
 
     let core_response = state
@@ -174,11 +188,13 @@ pub async fn update_definition(
     let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
 
     let core_request = UpdateDefinitionCoreRequest {
-        name:        req.name,
+        name: req.name,
         description: req.description,
-        definition:  req.definition,
-        version:     req.version,
-        tenant_id:   *session_data.tenant_id().as_uuid(),
+        definition: req.definition,
+        confidential: req.confidential,
+        default_watchers: req.default_watchers,
+        version: req.version,
+        tenant_id: *session_data.tenant_id().as_uuid(),
     };
 
     let core_response = state
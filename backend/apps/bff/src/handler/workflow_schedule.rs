@@ -0,0 +1,363 @@
+//! # ワークフロースケジュール API ハンドラ
+//!
+//! BFF のワークフロースケジュール（定期作成）エンドポイントを提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /api/v1/workflow-schedules` - 自分のスケジュール一覧
+//! - `POST /api/v1/workflow-schedules` - スケジュール作成
+//! - `PUT /api/v1/workflow-schedules/{schedule_id}` - スケジュール更新
+//! - `DELETE /api/v1/workflow-schedules/{schedule_id}` - スケジュール削除
+//!
+//! スケジュールはログインユーザー本人のものだけを操作できる。
+//! 実行日時に達すると、本人を申請者としてワークフローの下書きが作成され
+//! （自動申請の場合は申請まで行われ）、本人にメールで通知される。
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::CookieJar;
+use ringiflow_infra::SessionManager;
+use ringiflow_shared::ErrorResponse;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::{
+    client::{
+        CoreServiceWorkflowScheduleClient,
+        CreateWorkflowScheduleCoreRequest,
+        ScheduleRecurrenceDto,
+        ScheduledApproverDto,
+        UpdateWorkflowScheduleCoreRequest,
+        WorkflowScheduleDto,
+    },
+    error::{authenticate, log_and_convert_core_error},
+};
+
+/// ワークフロースケジュール API の共有状態
+pub struct WorkflowScheduleState {
+    pub core_service_client: Arc<dyn CoreServiceWorkflowScheduleClient>,
+    pub session_manager:     Arc<dyn SessionManager>,
+}
+
+// --- リクエスト/レスポンス共通型 ---
+
+/// 繰り返し設定（時刻はスケジュールのタイムゾーンの現地時刻）
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecurrenceData {
+    /// 毎月 `day` 日の `hour`:`minute`（存在しない日は月末）
+    Monthly {
+        day:    u32,
+        hour:   u32,
+        minute: u32,
+    },
+    /// cron 形式（`分 時 日 月 曜日`、例: `0 9 1 1,4,7,10 *`）
+    Cron { expression: String },
+}
+
+impl From<RecurrenceData> for ScheduleRecurrenceDto {
+    fn from(data: RecurrenceData) -> Self {
+        match data {
+            RecurrenceData::Monthly { day, hour, minute } => {
+                ScheduleRecurrenceDto::Monthly { day, hour, minute }
+            }
+            RecurrenceData::Cron { expression } => ScheduleRecurrenceDto::Cron { expression },
+        }
+    }
+}
+
+impl From<ScheduleRecurrenceDto> for RecurrenceData {
+    fn from(dto: ScheduleRecurrenceDto) -> Self {
+        match dto {
+            ScheduleRecurrenceDto::Monthly { day, hour, minute } => {
+                RecurrenceData::Monthly { day, hour, minute }
+            }
+            ScheduleRecurrenceDto::Cron { expression } => RecurrenceData::Cron { expression },
+        }
+    }
+}
+
+/// 自動申請時の承認者
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ScheduledApproverData {
+    /// 定義 JSON の承認ステップ ID
+    pub step_id:     String,
+    /// 承認者のユーザー ID
+    pub assigned_to: Uuid,
+}
+
+impl From<ScheduledApproverData> for ScheduledApproverDto {
+    fn from(data: ScheduledApproverData) -> Self {
+        Self {
+            step_id:     data.step_id,
+            assigned_to: data.assigned_to,
+        }
+    }
+}
+
+// --- リクエスト型 ---
+
+/// スケジュール作成リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateWorkflowScheduleRequest {
+    /// ワークフロー定義 ID（公開済みの定義のみ）
+    pub definition_id: Uuid,
+    pub name:          String,
+    /// 作成するワークフローのタイトル
+    pub title:         String,
+    /// 作成するワークフローのフォームデータ
+    pub form_data:     serde_json::Value,
+    pub recurrence:    RecurrenceData,
+    /// 実行時刻を解釈するタイムゾーン（IANA タイムゾーン名、例: `Asia/Tokyo`。省略時は `Asia/Tokyo`）
+    pub time_zone:     Option<String>,
+    /// 作成した下書きをそのまま申請するか
+    #[serde(default)]
+    pub auto_submit:   bool,
+    /// 自動申請時の承認者（定義の承認ステップごとに指定）
+    #[serde(default)]
+    pub approvers:     Vec<ScheduledApproverData>,
+}
+
+/// スケジュール更新リクエスト
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateWorkflowScheduleRequest {
+    pub name:        Option<String>,
+    pub title:       Option<String>,
+    pub form_data:   Option<serde_json::Value>,
+    pub recurrence:  Option<RecurrenceData>,
+    /// 実行時刻を解釈するタイムゾーン（IANA タイムゾーン名）
+    pub time_zone:   Option<String>,
+    pub auto_submit: Option<bool>,
+    pub approvers:   Option<Vec<ScheduledApproverData>>,
+    pub is_active:   Option<bool>,
+}
+
+// --- レスポンス型 ---
+
+/// スケジュールデータ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkflowScheduleData {
+    pub id: String,
+    pub definition_id: String,
+    pub name: String,
+    pub title: String,
+    pub form_data: serde_json::Value,
+    pub recurrence: RecurrenceData,
+    /// 実行時刻を解釈するタイムゾーン（IANA タイムゾーン名）
+    pub time_zone: String,
+    pub auto_submit: bool,
+    pub approvers: Vec<ScheduledApproverData>,
+    pub is_active: bool,
+    /// 次回実行日時（無効化されている場合は null）
+    pub next_run_at: Option<String>,
+    pub last_run_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<WorkflowScheduleDto> for WorkflowScheduleData {
+    fn from(dto: WorkflowScheduleDto) -> Self {
+        Self {
+            id: dto.id.to_string(),
+            definition_id: dto.definition_id.to_string(),
+            name: dto.name,
+            title: dto.title,
+            form_data: dto.form_data,
+            recurrence: dto.recurrence.into(),
+            time_zone: dto.time_zone,
+            auto_submit: dto.auto_submit,
+            approvers: dto
+                .approvers
+                .into_iter()
+                .map(|approver| ScheduledApproverData {
+                    step_id:     approver.step_id,
+                    assigned_to: approver.assigned_to,
+                })
+                .collect(),
+            is_active: dto.is_active,
+            next_run_at: dto.next_run_at,
+            last_run_at: dto.last_run_at,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /api/v1/workflow-schedules
+///
+/// 自分のスケジュール一覧を取得する。
+#[utoipa::path(
+   get,
+   path = "/api/v1/workflow-schedules",
+   tag = "workflow-schedules",
+   security(("session_auth" = [])),
+   responses(
+      (status = 200, description = "スケジュール一覧", body = Vec<WorkflowScheduleData>),
+      (status = 401, description = "認証エラー", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn list_workflow_schedules(
+    State(state): State<Arc<WorkflowScheduleState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_response = state
+        .core_service_client
+        .list_workflow_schedules(
+            *session_data.tenant_id().as_uuid(),
+            *session_data.user_id().as_uuid(),
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("スケジュール一覧取得", e))?;
+
+    let items: Vec<WorkflowScheduleData> = core_response
+        .into_iter()
+        .map(WorkflowScheduleData::from)
+        .collect();
+    Ok((StatusCode::OK, Json(items)).into_response())
+}
+
+/// POST /api/v1/workflow-schedules
+///
+/// スケジュールを作成する。ログインユーザーが所有者になる。
+#[utoipa::path(
+   post,
+   path = "/api/v1/workflow-schedules",
+   tag = "workflow-schedules",
+   security(("session_auth" = [])),
+   request_body = CreateWorkflowScheduleRequest,
+   responses(
+      (status = 201, description = "スケジュール作成成功", body = WorkflowScheduleData),
+      (status = 400, description = "バリデーションエラー", body = ErrorResponse),
+      (status = 404, description = "ワークフロー定義が見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all)]
+pub async fn create_workflow_schedule(
+    State(state): State<Arc<WorkflowScheduleState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Json(req): Json<CreateWorkflowScheduleRequest>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = CreateWorkflowScheduleCoreRequest {
+        tenant_id:     *session_data.tenant_id().as_uuid(),
+        user_id:       *session_data.user_id().as_uuid(),
+        definition_id: req.definition_id,
+        name:          req.name,
+        title:         req.title,
+        form_data:     req.form_data,
+        recurrence:    req.recurrence.into(),
+        time_zone:     req.time_zone,
+        auto_submit:   req.auto_submit,
+        approvers:     req.approvers.into_iter().map(Into::into).collect(),
+    };
+
+    let dto = state
+        .core_service_client
+        .create_workflow_schedule(&core_request)
+        .await
+        .map_err(|e| log_and_convert_core_error("スケジュール作成", e))?;
+
+    Ok((StatusCode::CREATED, Json(WorkflowScheduleData::from(dto))).into_response())
+}
+
+/// PUT /api/v1/workflow-schedules/{schedule_id}
+///
+/// スケジュールを更新する。指定した項目のみ変更し、有効なスケジュールは
+/// 更新時点から次回実行日時を計算し直す。
+#[utoipa::path(
+   put,
+   path = "/api/v1/workflow-schedules/{schedule_id}",
+   tag = "workflow-schedules",
+   security(("session_auth" = [])),
+   params(("schedule_id" = Uuid, Path, description = "スケジュール ID")),
+   request_body = UpdateWorkflowScheduleRequest,
+   responses(
+      (status = 200, description = "スケジュール更新成功", body = WorkflowScheduleData),
+      (status = 400, description = "バリデーションエラー", body = ErrorResponse),
+      (status = 404, description = "スケジュールが見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%schedule_id))]
+pub async fn update_workflow_schedule(
+    State(state): State<Arc<WorkflowScheduleState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(schedule_id): Path<Uuid>,
+    Json(req): Json<UpdateWorkflowScheduleRequest>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    let core_request = UpdateWorkflowScheduleCoreRequest {
+        tenant_id:   *session_data.tenant_id().as_uuid(),
+        user_id:     *session_data.user_id().as_uuid(),
+        name:        req.name,
+        title:       req.title,
+        form_data:   req.form_data,
+        recurrence:  req.recurrence.map(Into::into),
+        time_zone:   req.time_zone,
+        auto_submit: req.auto_submit,
+        approvers:   req
+            .approvers
+            .map(|approvers| approvers.into_iter().map(Into::into).collect()),
+        is_active:   req.is_active,
+    };
+
+    let dto = state
+        .core_service_client
+        .update_workflow_schedule(schedule_id, &core_request)
+        .await
+        .map_err(|e| log_and_convert_core_error("スケジュール更新", e))?;
+
+    Ok((StatusCode::OK, Json(WorkflowScheduleData::from(dto))).into_response())
+}
+
+/// DELETE /api/v1/workflow-schedules/{schedule_id}
+///
+/// スケジュールを削除する。作成済みのワークフローは削除しない。
+#[utoipa::path(
+   delete,
+   path = "/api/v1/workflow-schedules/{schedule_id}",
+   tag = "workflow-schedules",
+   security(("session_auth" = [])),
+   params(("schedule_id" = Uuid, Path, description = "スケジュール ID")),
+   responses(
+      (status = 204, description = "スケジュール削除成功"),
+      (status = 404, description = "スケジュールが見つからない", body = ErrorResponse)
+   )
+)]
+#[tracing::instrument(skip_all, fields(%schedule_id))]
+pub async fn delete_workflow_schedule(
+    State(state): State<Arc<WorkflowScheduleState>>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    Path(schedule_id): Path<Uuid>,
+) -> Result<Response, Response> {
+    let session_data = authenticate(state.session_manager.as_ref(), &headers, &jar).await?;
+
+    state
+        .core_service_client
+        .delete_workflow_schedule(
+            schedule_id,
+            *session_data.tenant_id().as_uuid(),
+            *session_data.user_id().as_uuid(),
+        )
+        .await
+        .map_err(|e| log_and_convert_core_error("スケジュール削除", e))?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
@@ -64,11 +64,17 @@ use ringiflow_infra::{
     RedisSessionManager,
     SessionManager,
     dynamodb,
+    realtime::RedisRealtimeSubscriber,
     redis,
     repository::DynamoDbAuditLogRepository,
 };
 use ringiflow_shared::observability::TracingConfig;
-use tokio::net::TcpListener;
+use tokio::{net::TcpListener, sync::broadcast};
+
+/// リアルタイム通知のブロードキャストバッファ容量
+///
+/// 受信が追いつかない SSE 接続は古いメッセージを取りこぼし、`resync` イベントを受け取る。
+const REALTIME_EVENTS_CAPACITY: usize = 1024;
 
 /// BFF サーバーのエントリーポイント
 ///
@@ -147,12 +153,22 @@ async fn main() -> anyhow::Result<()> {
         "audit_logs".to_string(),
     ));
 
+    // リアルタイム通知の購読を起動
+    // Redis から受信したメッセージをブロードキャストで各 SSE 接続に配る
+    let (realtime_events, _) = broadcast::channel(REALTIME_EVENTS_CAPACITY);
+    let realtime_subscriber = RedisRealtimeSubscriber::new(&config.redis_url)
+        .expect("Redis への接続に失敗しました（リアルタイム通知用）");
+    let realtime_sender = realtime_events.clone();
+    tokio::spawn(async move { realtime_subscriber.run(realtime_sender).await });
+    tracing::info!("リアルタイム通知の購読を開始しました");
+
     // アプリケーション構築（DI + ルーター）
     let app = app_builder::build_app(
         &config,
         session_manager,
         readiness_state,
         audit_log_repository,
+        realtime_events,
     );
 
     // jscpd:ignore-start — サーバー起動パターン（意図的な重複）
@@ -5,8 +5,10 @@
 mod authz;
 mod cache_control;
 mod csrf;
+mod locale;
 pub mod request_id;
 
 pub use authz::{AuthzState, require_permission};
 pub use cache_control::no_cache;
 pub use csrf::{CsrfState, csrf_middleware};
+pub use locale::{localize_error_response, request_locale};
@@ -27,7 +27,7 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use axum_extra::extract::CookieJar;
-use ringiflow_domain::role::Permission;
+use ringiflow_domain::{message::Message, role::Permission};
 use ringiflow_infra::SessionManager;
 
 use crate::error::{extract_tenant_id, forbidden_response, get_session};
@@ -70,7 +70,7 @@ pub async fn require_permission(
         .any(|p| Permission::new(p).satisfies(&required));
 
     if !has_permission {
-        return forbidden_response("この操作を実行する権限がありません");
+        return forbidden_response(Message::new("permission-denied"));
     }
 
     next.run(request).await
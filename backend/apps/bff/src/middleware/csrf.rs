@@ -7,20 +7,21 @@
 use std::sync::Arc;
 
 use axum::{
-    Json,
     body::Body,
     extract::State,
     http::{Method, Request, StatusCode},
     middleware::Next,
-    response::{IntoResponse, Response},
+    response::Response,
 };
 use axum_extra::extract::CookieJar;
-use ringiflow_domain::tenant::TenantId;
+use ringiflow_domain::{message::Message, tenant::TenantId};
 use ringiflow_infra::SessionManager;
 use ringiflow_shared::ErrorResponse;
 use subtle::ConstantTimeEq;
 use uuid::Uuid;
 
+use crate::error::message_response;
+
 /// CSRF 検証用のヘッダー名
 const CSRF_HEADER: &str = "X-CSRF-Token";
 
@@ -36,17 +37,19 @@ pub struct CsrfState {
     pub session_manager: Arc<dyn SessionManager>,
 }
 
-fn csrf_error_response(detail: &str) -> Response {
-    (
+fn csrf_error_response(message: Message) -> Response {
+    message_response(
         StatusCode::FORBIDDEN,
-        Json(ErrorResponse::new(
-            "csrf-validation-failed",
-            "CSRF Validation Failed",
-            403,
-            detail,
-        )),
+        |detail| {
+            ErrorResponse::new(
+                "csrf-validation-failed",
+                "CSRF Validation Failed",
+                403,
+                detail,
+            )
+        },
+        message,
     )
-        .into_response()
 }
 
 /// CSRF 検証が必要なメソッドかどうか
@@ -85,13 +88,13 @@ pub async fn csrf_middleware(
         .and_then(|s| Uuid::parse_str(s).ok())
     {
         Some(id) => TenantId::from_uuid(id),
-        None => return csrf_error_response("テナント ID が必要です"),
+        None => return csrf_error_response(Message::new("csrf-tenant-id-required")),
     };
 
     // Cookie からセッション ID を取得
     let session_id = match jar.get(SESSION_COOKIE_NAME) {
         Some(cookie) => cookie.value().to_string(),
-        None => return csrf_error_response("セッションが必要です"),
+        None => return csrf_error_response(Message::new("csrf-session-required")),
     };
 
     // X-CSRF-Token ヘッダーを取得
@@ -101,7 +104,7 @@ pub async fn csrf_middleware(
         .and_then(|v| v.to_str().ok())
     {
         Some(token) => token.to_string(),
-        None => return csrf_error_response("CSRF トークンが必要です"),
+        None => return csrf_error_response(Message::new("csrf-token-required")),
     };
 
     // Redis から CSRF トークンを取得して検証
@@ -117,13 +120,13 @@ pub async fn csrf_middleware(
                 .ct_eq(provided_token.as_bytes())
                 .into();
             if !is_valid {
-                return csrf_error_response("CSRF トークンが無効です");
+                return csrf_error_response(Message::new("csrf-token-invalid"));
             }
         }
-        Ok(None) => return csrf_error_response("CSRF トークンが無効です"),
+        Ok(None) => return csrf_error_response(Message::new("csrf-token-invalid")),
         Err(e) => {
             tracing::error!("CSRF トークン取得で内部エラー: {}", e);
-            return csrf_error_response("内部エラーが発生しました");
+            return csrf_error_response(Message::new("internal-error"));
         }
     }
 
@@ -0,0 +1,223 @@
+//! # エラーメッセージのローカライズミドルウェア
+//!
+//! `Accept-Language` ヘッダーで要求されたロケールに合わせて、
+//! エラーレスポンス（RFC 9457 Problem Details）の `detail` を書き換える。
+//!
+//! ## 設計方針
+//!
+//! - **既定は日本語**: ヘッダーがない、または対応言語がない場合は日本語で返す
+//! - **発生元のメッセージを展開**: 各サービスはエラーの発生元でメッセージコードとパラメータ
+//!   （`message_code` / `params`）を付ける。BFF はこれをメッセージカタログで要求されたロケールの
+//!   文言に展開するため、英語でも個別の理由（フィールド名・上限値など）が残る
+//! - **コードがなければそのまま**: `message_code` がないレスポンスは `detail` を書き換えない
+//! - **`Content-Language`**: エラーレスポンスには解決したロケールを付与する
+//! - **成功レスポンス中のメッセージ**: 一括判断の項目ごとの失敗理由など、成功レスポンスに含まれる
+//!   メッセージはハンドラが [`request_locale`] で同じロケールを解決して展開する
+//!
+//! → 詳細設計: `docs/40_詳細設計書/03_API設計.md`（エラーレスポンス）
+
+use axum::{
+    body::{Body, to_bytes},
+    extract::Request,
+    http::{HeaderMap, HeaderValue, header},
+    middleware::Next,
+    response::Response,
+};
+use ringiflow_domain::{locale::Locale, message::Message};
+use ringiflow_shared::ErrorResponse;
+
+use crate::error::internal_error_response;
+
+/// 書き換え対象とするエラーレスポンスボディの上限サイズ
+const MAX_ERROR_BODY_BYTES: usize = 64 * 1024;
+
+/// `Accept-Language` ヘッダーから応答のロケールを解決する（対応言語がなければ日本語）
+pub fn request_locale(headers: &HeaderMap) -> Locale {
+    headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(Locale::from_accept_language)
+        .unwrap_or_default()
+}
+
+/// エラーレスポンスの `detail` を `Accept-Language` のロケールに合わせる
+pub async fn localize_error_response(request: Request, next: Next) -> Response {
+    let locale = request_locale(request.headers());
+
+    let response = next.run(request).await;
+    if !is_json_error(&response) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, MAX_ERROR_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::error!(error = %e, "エラーレスポンスの読み取りに失敗");
+            return internal_error_response();
+        }
+    };
+
+    let body = match serde_json::from_slice::<ErrorResponse>(&bytes) {
+        Ok(mut error) => {
+            if let Some(code) = &error.message_code {
+                error.detail =
+                    Message::from_parts(code.clone(), error.params.clone()).render(locale);
+            }
+            let locale: &'static str = locale.into();
+            parts
+                .headers
+                .insert(header::CONTENT_LANGUAGE, HeaderValue::from_static(locale));
+            // 本文の長さが変わるため Content-Length は付け直させる
+            parts.headers.remove(header::CONTENT_LENGTH);
+            Body::from(serde_json::to_vec(&error).unwrap_or_else(|_| bytes.to_vec()))
+        }
+        // Problem Details 以外の JSON はそのまま返す
+        Err(_) => Body::from(bytes),
+    };
+
+    Response::from_parts(parts, body)
+}
+
+/// JSON 本文を持つエラーレスポンスかを判定する
+fn is_json_error(response: &Response) -> bool {
+    let status = response.status();
+    (status.is_client_error() || status.is_server_error())
+        && response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("application/json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Json,
+        Router,
+        http::{Request as HttpRequest, StatusCode},
+        middleware::from_fn,
+        response::IntoResponse,
+        routing::get,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::error::{not_found_response, validation_error_response};
+
+    fn app() -> Router {
+        Router::new()
+            .route(
+                "/not-found",
+                get(|| async {
+                    not_found_response(
+                        "user-not-found",
+                        "User Not Found",
+                        Message::new("user-not-found"),
+                    )
+                }),
+            )
+            .route(
+                "/validation",
+                get(|| async {
+                    validation_error_response(
+                        Message::new("too-long")
+                            .with("field", "user-name")
+                            .with("max", 100),
+                    )
+                }),
+            )
+            .route(
+                "/custom",
+                get(|| async {
+                    (
+                        StatusCode::BAD_REQUEST,
+                        Json(ErrorResponse::new(
+                            "unknown-code",
+                            "Unknown",
+                            400,
+                            "未知のエラー",
+                        )),
+                    )
+                }),
+            )
+            .route("/ok", get(|| async { Json("成功").into_response() }))
+            .layer(from_fn(localize_error_response))
+    }
+
+    async fn call(path: &str, accept_language: Option<&str>) -> Response {
+        let mut request = HttpRequest::builder().uri(path);
+        if let Some(value) = accept_language {
+            request = request.header(header::ACCEPT_LANGUAGE, value);
+        }
+        app()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn error_body(response: Response) -> ErrorResponse {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_英語が要求された場合はメッセージコードを英語のdetailに展開する() {
+        let response = call("/not-found", Some("en-US,en;q=0.9,ja;q=0.8")).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers()[header::CONTENT_LANGUAGE], "en");
+        let error = error_body(response).await;
+        assert_eq!(error.code, "user-not-found");
+        assert_eq!(error.detail, "The user was not found.");
+    }
+
+    #[tokio::test]
+    async fn test_英語でもフィールドごとのバリデーションの理由が残る() {
+        let response = call("/validation", Some("en")).await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.headers()[header::CONTENT_LANGUAGE], "en");
+        let error = error_body(response).await;
+        assert_eq!(error.code, "validation-error");
+        assert_eq!(error.message_code.as_deref(), Some("too-long"));
+        assert_eq!(error.detail, "User name must be at most 100 characters.");
+    }
+
+    #[tokio::test]
+    async fn test_日本語が要求された場合はフィールドごとのバリデーションの理由を日本語で返す() {
+        let response = call("/validation", Some("ja")).await;
+
+        let error = error_body(response).await;
+        assert_eq!(
+            error.detail,
+            "ユーザー名は 100 文字以内である必要があります"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_accept_languageがない場合は日本語のdetailのまま返す() {
+        let response = call("/not-found", None).await;
+
+        assert_eq!(response.headers()[header::CONTENT_LANGUAGE], "ja");
+        let error = error_body(response).await;
+        assert_eq!(error.detail, "ユーザーが見つかりません");
+    }
+
+    #[tokio::test]
+    async fn test_メッセージコードがないレスポンスはdetailを書き換えない() {
+        let response = call("/custom", Some("en")).await;
+
+        let error = error_body(response).await;
+        assert_eq!(error.code, "unknown-code");
+        assert_eq!(error.detail, "未知のエラー");
+    }
+
+    #[tokio::test]
+    async fn test_成功レスポンスは変更しない() {
+        let response = call("/ok", Some("en")).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(header::CONTENT_LANGUAGE).is_none());
+    }
+}
@@ -16,11 +16,20 @@ use crate::handler::{
     document,
     folder,
     health,
+    notification,
+    notification_log,
+    notification_template,
+    proxy_grant,
+    realtime,
     role,
     task,
+    tenant,
     user,
+    webhook,
     workflow,
+    workflow_admin,
     workflow_definition,
+    workflow_schedule,
 };
 
 #[derive(OpenApi)]
@@ -37,20 +46,35 @@ use crate::handler::{
       auth::login,
       auth::logout,
       auth::me,
+      auth::update_my_locale,
       auth::csrf,
       // workflows
       workflow::list_workflow_definitions,
       workflow::get_workflow_definition,
       workflow::list_my_workflows,
+      workflow::search_workflows,
       workflow::create_workflow,
+      workflow::duplicate_workflow,
+      workflow::delete_workflow,
       workflow::get_workflow,
       workflow::submit_workflow,
       workflow::approve_step,
       workflow::reject_step,
       workflow::request_changes_step,
+      workflow::retract_approval,
       workflow::resubmit_workflow,
       workflow::post_comment,
       workflow::list_comments,
+      workflow::edit_comment,
+      workflow::delete_comment,
+      workflow::list_comment_revisions,
+      workflow::watch_workflow,
+      workflow::unwatch_workflow,
+      workflow::list_watchers,
+      workflow::list_form_data_changes,
+      workflow::list_activities,
+      workflow::list_submissions,
+      workflow::diff_submissions,
       // workflow-definitions (管理)
       workflow_definition::create_definition,
       workflow_definition::update_definition,
@@ -58,8 +82,27 @@ use crate::handler::{
       workflow_definition::publish_definition,
       workflow_definition::archive_definition,
       workflow_definition::validate_definition,
+      // workflow-schedules
+      workflow_schedule::list_workflow_schedules,
+      workflow_schedule::create_workflow_schedule,
+      workflow_schedule::update_workflow_schedule,
+      workflow_schedule::delete_workflow_schedule,
+      // proxy-grants
+      proxy_grant::list_proxy_grants,
+      proxy_grant::create_proxy_grant,
+      proxy_grant::delete_proxy_grant,
+      // notifications
+      notification::list_notifications,
+      notification::get_unread_notification_count,
+      notification::mark_notification_read,
+      notification::mark_all_notifications_read,
+      notification::list_notification_preferences,
+      notification::update_notification_preferences,
+      // events
+      realtime::stream_events,
       // tasks
       task::list_my_tasks,
+      task::bulk_decide_tasks,
       workflow::get_task_by_display_numbers,
       // users
       user::list_users,
@@ -87,6 +130,33 @@ use crate::handler::{
       document::delete_document,
       document::list_documents,
       document::list_workflow_attachments,
+      // webhooks
+      webhook::list_webhooks,
+      webhook::create_webhook,
+      webhook::get_webhook,
+      webhook::update_webhook,
+      webhook::delete_webhook,
+      webhook::list_webhook_deliveries,
+      webhook::send_test_webhook,
+      // notification-templates
+      notification_template::list_notification_templates,
+      notification_template::get_notification_template,
+      notification_template::save_notification_template,
+      notification_template::delete_notification_template,
+      notification_template::validate_notification_template,
+      notification_template::preview_notification_template,
+      // notification-logs
+      notification_log::list_failed_notification_logs,
+      notification_log::resend_notification_log,
+      // tenant
+      tenant::get_tenant_settings,
+      tenant::update_tenant_settings,
+      // admin workflows
+      workflow_admin::list_all_workflows,
+      workflow_admin::get_any_workflow,
+      workflow_admin::force_cancel_workflow,
+      workflow_admin::reassign_current_step,
+      workflow_admin::force_complete_workflow,
       // dashboard
       dashboard::get_dashboard_stats,
    ),
@@ -98,12 +168,21 @@ use crate::handler::{
       (name = "auth", description = "認証"),
       (name = "workflows", description = "ワークフロー管理"),
       (name = "workflow-definitions", description = "ワークフロー定義管理"),
+      (name = "workflow-schedules", description = "ワークフローの定期作成"),
+      (name = "proxy-grants", description = "代理申請の権限管理"),
+      (name = "notifications", description = "アプリ内通知"),
+      (name = "events", description = "リアルタイム通知（Server-Sent Events）"),
       (name = "tasks", description = "タスク管理"),
       (name = "users", description = "ユーザー管理"),
       (name = "roles", description = "ロール管理"),
       (name = "folders", description = "フォルダ管理"),
       (name = "documents", description = "ドキュメント管理"),
       (name = "audit-logs", description = "監査ログ"),
+      (name = "webhooks", description = "Webhook 管理"),
+      (name = "notification-templates", description = "通知テンプレート管理"),
+      (name = "notification-logs", description = "通知メールの送信失敗と再送"),
+      (name = "tenant", description = "テナント設定"),
+      (name = "admin-workflows", description = "ワークフロー管理（テナント管理者向け）"),
       (name = "dashboard", description = "ダッシュボード"),
    ),
    modifiers(&SecurityAddon)
@@ -122,6 +122,7 @@ impl StubCoreServiceClient {
             email:     "user@example.com".to_string(),
             name:      "Test User".to_string(),
             status:    "active".to_string(),
+            locale:    None,
         }
     }
 }
@@ -150,6 +151,7 @@ impl CoreServiceUserClient for StubCoreServiceClient {
             tenant_name: "Development Tenant".to_string(),
             roles:       vec!["user".to_string()],
             permissions: vec!["workflow:read".to_string()],
+            locale:      "ja".to_string(),
         })
     }
 
@@ -184,6 +186,14 @@ impl CoreServiceUserClient for StubCoreServiceClient {
         unimplemented!("update_user_status is not used in auth tests")
     }
 
+    async fn update_user_locale(
+        &self,
+        _user_id: Uuid,
+        _req: &ringiflow_bff::client::UpdateUserLocaleCoreRequest,
+    ) -> Result<UserResponse, CoreServiceError> {
+        unimplemented!("update_user_locale is not used in auth tests")
+    }
+
     async fn get_user_by_display_number(
         &self,
         _tenant_id: Uuid,
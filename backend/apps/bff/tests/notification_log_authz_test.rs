@@ -0,0 +1,266 @@
+//! 通知ログ管理 API の認可テスト
+//!
+//! BFF の認可ミドルウェアが `notification_log:manage` 権限を
+//! 正しく検証することを確認する。
+//!
+//! ## テストケース
+//!
+//! - `notification_template:manage` 権限では 403（通知ログ管理は専用権限が必要）
+//! - `notification_log:manage` 権限では認可通過
+//! - 未認証では 401
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::{
+    Router,
+    body::Body,
+    http::{Method, Request, StatusCode},
+    middleware::from_fn_with_state,
+    routing::post,
+};
+use ringiflow_bff::{
+    client::{
+        CoreServiceError,
+        CoreServiceNotificationLogClient,
+        NotificationLogDto,
+        ResendNotificationLogCoreRequest,
+    },
+    handler::{NotificationLogState, resend_notification_log},
+    middleware::{AuthzState, require_permission},
+};
+use ringiflow_domain::{audit_log::AuditLog, tenant::TenantId, user::UserId};
+use ringiflow_infra::{
+    InfraError,
+    SessionData,
+    SessionManager,
+    repository::{AuditLogFilter, AuditLogPage, AuditLogRepository},
+};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const TEST_TENANT_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+// --- SessionManager スタブ ---
+
+/// テスト用スタブ SessionManager
+struct StubSessionManager {
+    session: Option<SessionData>,
+}
+
+impl StubSessionManager {
+    fn no_session() -> Self {
+        Self { session: None }
+    }
+
+    fn with_permissions(permissions: Vec<String>) -> Self {
+        let tenant_id = TenantId::from_uuid(Uuid::parse_str(TEST_TENANT_ID).unwrap());
+        Self {
+            session: Some(SessionData::new(
+                UserId::new(),
+                tenant_id,
+                "user@example.com".to_string(),
+                "Test User".to_string(),
+                vec!["user".to_string()],
+                permissions,
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl SessionManager for StubSessionManager {
+    async fn create(&self, _data: &SessionData) -> Result<String, InfraError> {
+        Ok(Uuid::now_v7().to_string())
+    }
+
+    async fn create_with_id(
+        &self,
+        _session_id: &str,
+        _data: &SessionData,
+    ) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<Option<SessionData>, InfraError> {
+        Ok(self.session.clone())
+    }
+
+    async fn delete(&self, _tenant_id: &TenantId, _session_id: &str) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn delete_all_for_tenant(&self, _tenant_id: &TenantId) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn get_ttl(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<Option<i64>, InfraError> {
+        Ok(Some(28800))
+    }
+
+    async fn create_csrf_token(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<String, InfraError> {
+        Ok("a".repeat(64))
+    }
+
+    async fn get_csrf_token(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<Option<String>, InfraError> {
+        Ok(None)
+    }
+
+    async fn delete_csrf_token(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn delete_all_csrf_for_tenant(&self, _tenant_id: &TenantId) -> Result<(), InfraError> {
+        Ok(())
+    }
+}
+
+// --- CoreServiceNotificationLogClient スタブ ---
+//
+// 認可ミドルウェアが拒否する場合、ハンドラは呼ばれないため
+// これらのメソッドは実行されない。
+
+struct UnusedNotificationLogClient;
+
+#[async_trait]
+impl CoreServiceNotificationLogClient for UnusedNotificationLogClient {
+    async fn list_failed_notification_logs(
+        &self,
+        _tenant_id: Uuid,
+    ) -> Result<Vec<NotificationLogDto>, CoreServiceError> {
+        unimplemented!("再送のテストでは呼ばれない")
+    }
+
+    async fn resend_notification_log(
+        &self,
+        _id: Uuid,
+        _req: &ResendNotificationLogCoreRequest,
+    ) -> Result<NotificationLogDto, CoreServiceError> {
+        // 認可通過テストではハンドラまで到達するため、パニックではなくエラーを返す
+        Err(CoreServiceError::NotificationNotFound)
+    }
+}
+
+// --- AuditLogRepository スタブ ---
+
+struct UnusedAuditLogRepository;
+
+#[async_trait]
+impl AuditLogRepository for UnusedAuditLogRepository {
+    async fn record(&self, _log: &AuditLog) -> Result<(), InfraError> {
+        unimplemented!("再送に失敗した場合は監査ログを記録しない")
+    }
+
+    async fn find_by_tenant(
+        &self,
+        _tenant_id: &TenantId,
+        _cursor: Option<&str>,
+        _limit: i32,
+        _filter: &AuditLogFilter,
+    ) -> Result<AuditLogPage, InfraError> {
+        unimplemented!()
+    }
+}
+
+// --- テストヘルパー ---
+
+fn create_test_app(session_manager: StubSessionManager) -> Router {
+    let session_manager: Arc<dyn SessionManager> = Arc::new(session_manager);
+
+    let authz_state = AuthzState {
+        session_manager:     session_manager.clone(),
+        required_permission: "notification_log:manage".to_string(),
+    };
+
+    let notification_log_state = Arc::new(NotificationLogState {
+        core_service_client:  Arc::new(UnusedNotificationLogClient),
+        session_manager:      session_manager.clone(),
+        audit_log_repository: Arc::new(UnusedAuditLogRepository),
+    });
+
+    Router::new()
+        .route(
+            "/api/v1/notification-logs/{id}/resend",
+            post(resend_notification_log),
+        )
+        .layer(from_fn_with_state(authz_state, require_permission))
+        .with_state(notification_log_state)
+}
+
+fn create_request() -> Request<Body> {
+    Request::builder()
+        .method(Method::POST)
+        .uri(format!(
+            "/api/v1/notification-logs/{}/resend",
+            Uuid::now_v7()
+        ))
+        .header("X-Tenant-ID", TEST_TENANT_ID)
+        .header("Cookie", "session_id=test-session-id")
+        .body(Body::empty())
+        .unwrap()
+}
+
+// --- テストケース ---
+
+#[tokio::test]
+async fn test_notification_template_manage権限では通知の再送が拒否される() {
+    // Given: notification_template:manage 権限のみを持つユーザー
+    let sut = create_test_app(StubSessionManager::with_permissions(vec![
+        "notification_template:manage".to_string(),
+    ]));
+
+    // When
+    let response = sut.oneshot(create_request()).await.unwrap();
+
+    // Then: 403 Forbidden
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_notification_log_manage権限があれば認可を通過する() {
+    // Given: notification_log:manage 権限を持つユーザー
+    let sut = create_test_app(StubSessionManager::with_permissions(vec![
+        "notification_log:manage".to_string(),
+    ]));
+
+    // When
+    let response = sut.oneshot(create_request()).await.unwrap();
+
+    // Then: 403 ではない（ミドルウェアを通過した）
+    // ハンドラ内でスタブ CoreService が呼ばれるため 404 になるが、
+    // 認可が通過したことが重要
+    assert_ne!(response.status(), StatusCode::FORBIDDEN);
+    assert_ne!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_未認証ユーザーは401を返す() {
+    // Given: セッションなし
+    let sut = create_test_app(StubSessionManager::no_session());
+
+    // When
+    let response = sut.oneshot(create_request()).await.unwrap();
+
+    // Then: 401 Unauthorized
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
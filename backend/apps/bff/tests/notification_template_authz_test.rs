@@ -0,0 +1,299 @@
+//! 通知テンプレート管理 API の認可テスト
+//!
+//! BFF の認可ミドルウェアが `notification_template:manage` 権限を
+//! 正しく検証することを確認する。
+//!
+//! ## テストケース
+//!
+//! - `webhook:manage` 権限では 403（通知テンプレート管理は専用権限が必要）
+//! - `notification_template:manage` 権限では認可通過
+//! - 未認証では 401
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::{
+    Router,
+    body::Body,
+    http::{Method, Request, StatusCode},
+    middleware::from_fn_with_state,
+    routing::get,
+};
+use ringiflow_bff::{
+    client::{
+        CoreServiceError,
+        CoreServiceNotificationTemplateClient,
+        NotificationTemplateDto,
+        NotificationTemplatePreviewDto,
+        NotificationTemplateValidationDto,
+        PreviewNotificationTemplateCoreRequest,
+        SaveNotificationTemplateCoreRequest,
+        ValidateNotificationTemplateCoreRequest,
+    },
+    handler::{NotificationTemplateState, list_notification_templates},
+    middleware::{AuthzState, require_permission},
+};
+use ringiflow_domain::{audit_log::AuditLog, tenant::TenantId, user::UserId};
+use ringiflow_infra::{
+    InfraError,
+    SessionData,
+    SessionManager,
+    repository::{AuditLogFilter, AuditLogPage, AuditLogRepository},
+};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const TEST_TENANT_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+// --- SessionManager スタブ ---
+
+/// テスト用スタブ SessionManager
+struct StubSessionManager {
+    session: Option<SessionData>,
+}
+
+impl StubSessionManager {
+    fn no_session() -> Self {
+        Self { session: None }
+    }
+
+    fn with_permissions(permissions: Vec<String>) -> Self {
+        let tenant_id = TenantId::from_uuid(Uuid::parse_str(TEST_TENANT_ID).unwrap());
+        Self {
+            session: Some(SessionData::new(
+                UserId::new(),
+                tenant_id,
+                "user@example.com".to_string(),
+                "Test User".to_string(),
+                vec!["user".to_string()],
+                permissions,
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl SessionManager for StubSessionManager {
+    async fn create(&self, _data: &SessionData) -> Result<String, InfraError> {
+        Ok(Uuid::now_v7().to_string())
+    }
+
+    async fn create_with_id(
+        &self,
+        _session_id: &str,
+        _data: &SessionData,
+    ) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<Option<SessionData>, InfraError> {
+        Ok(self.session.clone())
+    }
+
+    async fn delete(&self, _tenant_id: &TenantId, _session_id: &str) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn delete_all_for_tenant(&self, _tenant_id: &TenantId) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn get_ttl(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<Option<i64>, InfraError> {
+        Ok(Some(28800))
+    }
+
+    async fn create_csrf_token(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<String, InfraError> {
+        Ok("a".repeat(64))
+    }
+
+    async fn get_csrf_token(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<Option<String>, InfraError> {
+        Ok(None)
+    }
+
+    async fn delete_csrf_token(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn delete_all_csrf_for_tenant(&self, _tenant_id: &TenantId) -> Result<(), InfraError> {
+        Ok(())
+    }
+}
+
+// --- CoreServiceNotificationTemplateClient スタブ ---
+//
+// 認可ミドルウェアが拒否する場合、ハンドラは呼ばれないため
+// これらのメソッドは実行されない。
+
+struct UnusedNotificationTemplateClient;
+
+#[async_trait]
+impl CoreServiceNotificationTemplateClient for UnusedNotificationTemplateClient {
+    async fn list_notification_templates(
+        &self,
+        _tenant_id: Uuid,
+    ) -> Result<Vec<NotificationTemplateDto>, CoreServiceError> {
+        // 認可通過テストではハンドラまで到達するため、パニックではなくエラーを返す
+        Err(CoreServiceError::Unexpected("テスト用スタブ".to_string()))
+    }
+
+    async fn get_notification_template(
+        &self,
+        _event_type: &str,
+        _tenant_id: Uuid,
+    ) -> Result<NotificationTemplateDto, CoreServiceError> {
+        unimplemented!("ミドルウェアが拒否するため呼ばれない")
+    }
+
+    async fn save_notification_template(
+        &self,
+        _event_type: &str,
+        _req: &SaveNotificationTemplateCoreRequest,
+    ) -> Result<NotificationTemplateDto, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn delete_notification_template(
+        &self,
+        _event_type: &str,
+        _tenant_id: Uuid,
+    ) -> Result<(), CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn validate_notification_template(
+        &self,
+        _event_type: &str,
+        _req: &ValidateNotificationTemplateCoreRequest,
+    ) -> Result<NotificationTemplateValidationDto, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn preview_notification_template(
+        &self,
+        _event_type: &str,
+        _req: &PreviewNotificationTemplateCoreRequest,
+    ) -> Result<NotificationTemplatePreviewDto, CoreServiceError> {
+        unimplemented!()
+    }
+}
+
+// --- AuditLogRepository スタブ ---
+
+struct UnusedAuditLogRepository;
+
+#[async_trait]
+impl AuditLogRepository for UnusedAuditLogRepository {
+    async fn record(&self, _log: &AuditLog) -> Result<(), InfraError> {
+        unimplemented!("一覧取得では監査ログを記録しない")
+    }
+
+    async fn find_by_tenant(
+        &self,
+        _tenant_id: &TenantId,
+        _cursor: Option<&str>,
+        _limit: i32,
+        _filter: &AuditLogFilter,
+    ) -> Result<AuditLogPage, InfraError> {
+        unimplemented!()
+    }
+}
+
+// --- テストヘルパー ---
+
+fn create_test_app(session_manager: StubSessionManager) -> Router {
+    let session_manager: Arc<dyn SessionManager> = Arc::new(session_manager);
+
+    let authz_state = AuthzState {
+        session_manager:     session_manager.clone(),
+        required_permission: "notification_template:manage".to_string(),
+    };
+
+    let notification_template_state = Arc::new(NotificationTemplateState {
+        core_service_client:  Arc::new(UnusedNotificationTemplateClient),
+        session_manager:      session_manager.clone(),
+        audit_log_repository: Arc::new(UnusedAuditLogRepository),
+    });
+
+    Router::new()
+        .route(
+            "/api/v1/notification-templates",
+            get(list_notification_templates),
+        )
+        .layer(from_fn_with_state(authz_state, require_permission))
+        .with_state(notification_template_state)
+}
+
+fn create_request() -> Request<Body> {
+    Request::builder()
+        .method(Method::GET)
+        .uri("/api/v1/notification-templates")
+        .header("X-Tenant-ID", TEST_TENANT_ID)
+        .header("Cookie", "session_id=test-session-id")
+        .body(Body::empty())
+        .unwrap()
+}
+
+// --- テストケース ---
+
+#[tokio::test]
+async fn test_webhook_manage権限では通知テンプレート管理が拒否される() {
+    // Given: webhook:manage 権限のみを持つユーザー
+    let sut = create_test_app(StubSessionManager::with_permissions(vec![
+        "webhook:manage".to_string(),
+    ]));
+
+    // When
+    let response = sut.oneshot(create_request()).await.unwrap();
+
+    // Then: 403 Forbidden
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_notification_template_manage権限があれば認可を通過する() {
+    // Given: notification_template:manage 権限を持つユーザー
+    let sut = create_test_app(StubSessionManager::with_permissions(vec![
+        "notification_template:manage".to_string(),
+    ]));
+
+    // When
+    let response = sut.oneshot(create_request()).await.unwrap();
+
+    // Then: 403 ではない（ミドルウェアを通過した）
+    // ハンドラ内でスタブ CoreService が呼ばれるため 500 になるが、
+    // 認可が通過したことが重要
+    assert_ne!(response.status(), StatusCode::FORBIDDEN);
+    assert_ne!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_未認証ユーザーは401を返す() {
+    // Given: セッションなし
+    let sut = create_test_app(StubSessionManager::no_session());
+
+    // When
+    let response = sut.oneshot(create_request()).await.unwrap();
+
+    // Then: 401 Unauthorized
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -17,15 +17,16 @@ fn test_全パスが含まれている() {
     let doc = ApiDoc::openapi();
     let paths: Vec<&str> = doc.paths.paths.keys().map(|k| k.as_str()).collect();
 
-    // 35 パス（46 ハンドラ、同一パスに複数メソッドがあるため 35 パス）
+    // 74 パス（98 ハンドラ、同一パスに複数メソッドがあるため 74 パス）
     // /health はインフラ用のため OpenAPI 仕様には含めない、/health/ready は含める
-    assert_eq!(paths.len(), 35, "パス数が 35 であること: {paths:?}");
+    assert_eq!(paths.len(), 74, "パス数が 74 であること: {paths:?}");
 
     // 全パスの存在確認
     assert!(paths.contains(&"/health/ready"));
     assert!(paths.contains(&"/api/v1/auth/login"));
     assert!(paths.contains(&"/api/v1/auth/logout"));
     assert!(paths.contains(&"/api/v1/auth/me"));
+    assert!(paths.contains(&"/api/v1/auth/me/locale"));
     assert!(paths.contains(&"/api/v1/auth/csrf"));
     assert!(paths.contains(&"/api/v1/workflow-definitions"));
     assert!(paths.contains(&"/api/v1/workflow-definitions/{id}"));
@@ -33,6 +34,7 @@ fn test_全パスが含まれている() {
     assert!(paths.contains(&"/api/v1/workflow-definitions/{id}/archive"));
     assert!(paths.contains(&"/api/v1/workflow-definitions/validate"));
     assert!(paths.contains(&"/api/v1/workflows"));
+    assert!(paths.contains(&"/api/v1/workflows/search"));
     assert!(paths.contains(&"/api/v1/workflows/{display_number}"));
     assert!(paths.contains(&"/api/v1/workflows/{display_number}/submit"));
     assert!(
@@ -44,8 +46,13 @@ fn test_全パスが含まれている() {
     assert!(paths.contains(
         &"/api/v1/workflows/{display_number}/steps/{step_display_number}/request-changes"
     ));
+    assert!(
+        paths.contains(&"/api/v1/workflows/{display_number}/steps/{step_display_number}/retract")
+    );
     assert!(paths.contains(&"/api/v1/workflows/{display_number}/resubmit"));
+    assert!(paths.contains(&"/api/v1/workflows/{display_number}/duplicate"));
     assert!(paths.contains(&"/api/v1/tasks/my"));
+    assert!(paths.contains(&"/api/v1/tasks/bulk-decision"));
     assert!(paths.contains(&"/api/v1/workflows/{display_number}/tasks/{step_display_number}"));
     assert!(paths.contains(&"/api/v1/users"));
     assert!(paths.contains(&"/api/v1/users/{display_number}"));
@@ -54,6 +61,16 @@ fn test_全パスが含まれている() {
     assert!(paths.contains(&"/api/v1/roles/{role_id}"));
     assert!(paths.contains(&"/api/v1/folders"));
     assert!(paths.contains(&"/api/v1/folders/{folder_id}"));
+    assert!(paths.contains(&"/api/v1/workflow-schedules"));
+    assert!(paths.contains(&"/api/v1/workflow-schedules/{schedule_id}"));
+    assert!(paths.contains(&"/api/v1/proxy-grants"));
+    assert!(paths.contains(&"/api/v1/proxy-grants/{proxy_user_id}"));
+    assert!(paths.contains(&"/api/v1/notifications"));
+    assert!(paths.contains(&"/api/v1/notifications/unread-count"));
+    assert!(paths.contains(&"/api/v1/notifications/read-all"));
+    assert!(paths.contains(&"/api/v1/notifications/preferences"));
+    assert!(paths.contains(&"/api/v1/events"));
+    assert!(paths.contains(&"/api/v1/notifications/{id}/read"));
     assert!(paths.contains(&"/api/v1/documents"));
     assert!(paths.contains(&"/api/v1/documents/upload-url"));
     assert!(paths.contains(&"/api/v1/documents/{document_id}"));
@@ -62,7 +79,20 @@ fn test_全パスが含まれている() {
     assert!(paths.contains(&"/api/v1/workflows/{workflow_instance_id}/attachments"));
     assert!(paths.contains(&"/api/v1/audit-logs"));
     assert!(paths.contains(&"/api/v1/workflows/{display_number}/comments"));
+    assert!(paths.contains(&"/api/v1/workflows/{display_number}/comments/{comment_id}"));
+    assert!(paths.contains(&"/api/v1/workflows/{display_number}/comments/{comment_id}/revisions"));
+    assert!(paths.contains(&"/api/v1/workflows/{display_number}/watch"));
+    assert!(paths.contains(&"/api/v1/workflows/{display_number}/watchers"));
     assert!(paths.contains(&"/api/v1/dashboard/stats"));
+    assert!(paths.contains(&"/api/v1/webhooks"));
+    assert!(paths.contains(&"/api/v1/webhooks/{webhook_id}"));
+    assert!(paths.contains(&"/api/v1/webhooks/{webhook_id}/deliveries"));
+    assert!(paths.contains(&"/api/v1/webhooks/{webhook_id}/test"));
+    assert!(paths.contains(&"/api/v1/notification-templates"));
+    assert!(paths.contains(&"/api/v1/notification-templates/{event_type}"));
+    assert!(paths.contains(&"/api/v1/notification-templates/{event_type}/validate"));
+    assert!(paths.contains(&"/api/v1/notification-templates/{event_type}/preview"));
+    assert!(paths.contains(&"/api/v1/tenant/settings"));
 }
 
 #[test]
@@ -90,12 +120,19 @@ fn test_全タグが含まれている() {
     assert!(tags.contains(&"auth"));
     assert!(tags.contains(&"workflows"));
     assert!(tags.contains(&"workflow-definitions"));
+    assert!(tags.contains(&"workflow-schedules"));
+    assert!(tags.contains(&"proxy-grants"));
+    assert!(tags.contains(&"notifications"));
+    assert!(tags.contains(&"events"));
     assert!(tags.contains(&"tasks"));
     assert!(tags.contains(&"users"));
     assert!(tags.contains(&"roles"));
     assert!(tags.contains(&"folders"));
     assert!(tags.contains(&"documents"));
     assert!(tags.contains(&"audit-logs"));
+    assert!(tags.contains(&"webhooks"));
+    assert!(tags.contains(&"notification-templates"));
+    assert!(tags.contains(&"tenant"));
     assert!(tags.contains(&"dashboard"));
 }
 
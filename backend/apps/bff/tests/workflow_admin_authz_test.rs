@@ -0,0 +1,367 @@
+//! ワークフロー管理 API（テナント管理者向け）の認可・監査ログテスト
+//!
+//! BFF の認可ミドルウェアが `workflow:admin` 権限を正しく検証し、
+//! 強制操作が監査ログに記録されることを確認する。
+//!
+//! ## テストケース
+//!
+//! - 一般ユーザーの `workflow:read` / `workflow:create` 権限では 403
+//! - `workflow:*`（tenant_admin）権限では認可通過
+//! - 未認証では 401
+//! - 強制取消の成功時に監査ログ（理由付き）が記録される
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::{
+    Router,
+    body::Body,
+    http::{Method, Request, StatusCode},
+    middleware::from_fn_with_state,
+    routing::{get, post},
+};
+use ringiflow_bff::{
+    client::{
+        AdminActionCoreRequest,
+        CoreServiceError,
+        CoreServiceWorkflowAdminClient,
+        ReassignStepCoreRequest,
+        SearchWorkflowsCoreQuery,
+        WorkflowInstanceDto,
+        WorkflowInstanceSummaryDto,
+        WorkflowViewerQuery,
+    },
+    handler::{WorkflowAdminState, force_cancel_workflow, list_all_workflows},
+    middleware::{AuthzState, require_permission},
+};
+use ringiflow_domain::{
+    audit_log::{AuditAction, AuditLog},
+    tenant::TenantId,
+    user::UserId,
+};
+use ringiflow_infra::{
+    InfraError,
+    SessionData,
+    SessionManager,
+    repository::{AuditLogFilter, AuditLogPage, AuditLogRepository},
+};
+use ringiflow_shared::PaginatedResponse;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const TEST_TENANT_ID: &str = "00000000-0000-0000-0000-000000000001";
+
+// --- SessionManager スタブ ---
+
+/// テスト用スタブ SessionManager
+struct StubSessionManager {
+    session: Option<SessionData>,
+}
+
+impl StubSessionManager {
+    fn no_session() -> Self {
+        Self { session: None }
+    }
+
+    fn with_permissions(permissions: Vec<String>) -> Self {
+        let tenant_id = TenantId::from_uuid(Uuid::parse_str(TEST_TENANT_ID).unwrap());
+        Self {
+            session: Some(SessionData::new(
+                UserId::new(),
+                tenant_id,
+                "user@example.com".to_string(),
+                "Test User".to_string(),
+                vec!["user".to_string()],
+                permissions,
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl SessionManager for StubSessionManager {
+    async fn create(&self, _data: &SessionData) -> Result<String, InfraError> {
+        Ok(Uuid::now_v7().to_string())
+    }
+
+    async fn create_with_id(
+        &self,
+        _session_id: &str,
+        _data: &SessionData,
+    ) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn get(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<Option<SessionData>, InfraError> {
+        Ok(self.session.clone())
+    }
+
+    async fn delete(&self, _tenant_id: &TenantId, _session_id: &str) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn delete_all_for_tenant(&self, _tenant_id: &TenantId) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn get_ttl(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<Option<i64>, InfraError> {
+        Ok(Some(28800))
+    }
+
+    async fn create_csrf_token(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<String, InfraError> {
+        Ok("a".repeat(64))
+    }
+
+    async fn get_csrf_token(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<Option<String>, InfraError> {
+        Ok(None)
+    }
+
+    async fn delete_csrf_token(
+        &self,
+        _tenant_id: &TenantId,
+        _session_id: &str,
+    ) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn delete_all_csrf_for_tenant(&self, _tenant_id: &TenantId) -> Result<(), InfraError> {
+        Ok(())
+    }
+}
+
+// --- CoreServiceWorkflowAdminClient スタブ ---
+
+struct StubWorkflowAdminClient;
+
+fn cancelled_workflow() -> WorkflowInstanceDto {
+    serde_json::from_value(serde_json::json!({
+        "id": "019500000000700080000000000000aa",
+        "display_id": "WF-42",
+        "display_number": 42,
+        "title": "経費精算",
+        "definition_id": "019500000000700080000000000000bb",
+        "status": "cancelled",
+        "version": 2,
+        "form_data": {},
+        "initiated_by": {"id": "019500000000700080000000000000cc", "name": "申請者"},
+        "current_step_id": null,
+        "submitted_at": null,
+        "completed_at": null,
+        "created_at": "2026-01-01T00:00:00Z",
+        "updated_at": "2026-01-01T00:00:00Z"
+    }))
+    .unwrap()
+}
+
+#[async_trait]
+impl CoreServiceWorkflowAdminClient for StubWorkflowAdminClient {
+    async fn list_all_workflows(
+        &self,
+        _query: &SearchWorkflowsCoreQuery,
+    ) -> Result<PaginatedResponse<WorkflowInstanceSummaryDto>, CoreServiceError> {
+        Ok(PaginatedResponse {
+            items:       vec![],
+            next_cursor: None,
+        })
+    }
+
+    async fn get_any_workflow(
+        &self,
+        _display_number: i64,
+        _viewer: &WorkflowViewerQuery,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn force_cancel_workflow(
+        &self,
+        _display_number: i64,
+        _req: &AdminActionCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        Ok(cancelled_workflow())
+    }
+
+    async fn reassign_current_step(
+        &self,
+        _display_number: i64,
+        _req: &ReassignStepCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn force_complete_workflow(
+        &self,
+        _display_number: i64,
+        _req: &AdminActionCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        unimplemented!()
+    }
+}
+
+// --- AuditLogRepository スタブ ---
+
+/// 記録された監査ログを保持するスタブ
+#[derive(Default)]
+struct RecordingAuditLogRepository {
+    logs: Mutex<Vec<AuditLog>>,
+}
+
+#[async_trait]
+impl AuditLogRepository for RecordingAuditLogRepository {
+    async fn record(&self, log: &AuditLog) -> Result<(), InfraError> {
+        self.logs.lock().unwrap().push(log.clone());
+        Ok(())
+    }
+
+    async fn find_by_tenant(
+        &self,
+        _tenant_id: &TenantId,
+        _cursor: Option<&str>,
+        _limit: i32,
+        _filter: &AuditLogFilter,
+    ) -> Result<AuditLogPage, InfraError> {
+        unimplemented!()
+    }
+}
+
+// --- テストヘルパー ---
+
+fn create_test_app(
+    session_manager: StubSessionManager,
+    audit_log_repository: Arc<RecordingAuditLogRepository>,
+) -> Router {
+    let session_manager: Arc<dyn SessionManager> = Arc::new(session_manager);
+
+    let authz_state = AuthzState {
+        session_manager:     session_manager.clone(),
+        required_permission: "workflow:admin".to_string(),
+    };
+
+    let state = Arc::new(WorkflowAdminState {
+        core_service_client: Arc::new(StubWorkflowAdminClient),
+        session_manager: session_manager.clone(),
+        audit_log_repository,
+    });
+
+    Router::new()
+        .route("/api/v1/admin/workflows", get(list_all_workflows))
+        .route(
+            "/api/v1/admin/workflows/{display_number}/cancel",
+            post(force_cancel_workflow),
+        )
+        .layer(from_fn_with_state(authz_state, require_permission))
+        .with_state(state)
+}
+
+fn list_request() -> Request<Body> {
+    Request::builder()
+        .method(Method::GET)
+        .uri("/api/v1/admin/workflows")
+        .header("X-Tenant-ID", TEST_TENANT_ID)
+        .header("Cookie", "session_id=test-session-id")
+        .body(Body::empty())
+        .unwrap()
+}
+
+fn cancel_request() -> Request<Body> {
+    Request::builder()
+        .method(Method::POST)
+        .uri("/api/v1/admin/workflows/42/cancel")
+        .header("X-Tenant-ID", TEST_TENANT_ID)
+        .header("Cookie", "session_id=test-session-id")
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            r#"{"version": 1, "reason": "担当者退職のため"}"#,
+        ))
+        .unwrap()
+}
+
+// --- テストケース ---
+
+#[tokio::test]
+async fn test_一般ユーザーの権限ではworkflow_adminが拒否される() {
+    // Given: 一般ユーザー（user ロール）の権限
+    let sut = create_test_app(
+        StubSessionManager::with_permissions(vec![
+            "workflow:read".to_string(),
+            "workflow:create".to_string(),
+        ]),
+        Arc::default(),
+    );
+
+    // When
+    let response = sut.oneshot(list_request()).await.unwrap();
+
+    // Then: 403 Forbidden
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_workflow_wildcard権限があれば認可を通過する() {
+    // Given: tenant_admin ロールの workflow:* 権限
+    let sut = create_test_app(
+        StubSessionManager::with_permissions(vec!["workflow:*".to_string()]),
+        Arc::default(),
+    );
+
+    // When
+    let response = sut.oneshot(list_request()).await.unwrap();
+
+    // Then
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_未認証ユーザーは401を返す() {
+    // Given: セッションなし
+    let sut = create_test_app(StubSessionManager::no_session(), Arc::default());
+
+    // When
+    let response = sut.oneshot(list_request()).await.unwrap();
+
+    // Then: 401 Unauthorized
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_強制取消は理由付きで監査ログに記録される() {
+    // Given: workflow:admin 権限を持つユーザー
+    let audit_log_repository = Arc::new(RecordingAuditLogRepository::default());
+    let sut = create_test_app(
+        StubSessionManager::with_permissions(vec!["workflow:admin".to_string()]),
+        audit_log_repository.clone(),
+    );
+
+    // When
+    let response = sut.oneshot(cancel_request()).await.unwrap();
+
+    // Then
+    assert_eq!(response.status(), StatusCode::OK);
+    let logs = audit_log_repository.logs.lock().unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].action, AuditAction::WorkflowCancel);
+    assert_eq!(logs[0].resource_type, "workflow_instance");
+    assert_eq!(
+        logs[0].detail,
+        Some(serde_json::json!({
+           "display_id": "WF-42",
+           "reason": "担当者退職のため",
+        }))
+    );
+}
@@ -31,6 +31,7 @@ use ringiflow_bff::{
         WorkflowDefinitionDto,
         WorkflowInstanceDto,
         WorkflowInstanceSummaryDto,
+        WorkflowViewerQuery,
     },
     handler::{WorkflowDefinitionState, create_definition},
     middleware::{AuthzState, require_permission},
@@ -152,6 +153,23 @@ impl CoreServiceWorkflowClient for UnusedWorkflowClient {
         unimplemented!("ミドルウェアが拒否するため呼ばれない")
     }
 
+    async fn duplicate_workflow(
+        &self,
+        _display_number: i64,
+        _req: &ringiflow_bff::client::DuplicateWorkflowCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn delete_workflow(
+        &self,
+        _display_number: i64,
+        _tenant_id: Uuid,
+        _user_id: Uuid,
+    ) -> Result<(), CoreServiceError> {
+        unimplemented!()
+    }
+
     async fn submit_workflow(
         &self,
         _workflow_id: Uuid,
@@ -179,14 +197,24 @@ impl CoreServiceWorkflowClient for UnusedWorkflowClient {
         &self,
         _tenant_id: Uuid,
         _user_id: Uuid,
-    ) -> Result<Vec<WorkflowInstanceSummaryDto>, CoreServiceError> {
+        _page: &ringiflow_bff::client::PageCoreQuery,
+    ) -> Result<ringiflow_shared::PaginatedResponse<WorkflowInstanceSummaryDto>, CoreServiceError>
+    {
+        unimplemented!()
+    }
+
+    async fn search_workflows(
+        &self,
+        _query: &ringiflow_bff::client::SearchWorkflowsCoreQuery,
+    ) -> Result<ringiflow_shared::PaginatedResponse<WorkflowInstanceSummaryDto>, CoreServiceError>
+    {
         unimplemented!()
     }
 
     async fn get_workflow(
         &self,
         _workflow_id: Uuid,
-        _tenant_id: Uuid,
+        _viewer: &WorkflowViewerQuery,
     ) -> Result<WorkflowInstanceDto, CoreServiceError> {
         unimplemented!()
     }
@@ -212,7 +240,7 @@ impl CoreServiceWorkflowClient for UnusedWorkflowClient {
     async fn get_workflow_by_display_number(
         &self,
         _display_number: i64,
-        _tenant_id: Uuid,
+        _viewer: &WorkflowViewerQuery,
     ) -> Result<WorkflowInstanceDto, CoreServiceError> {
         unimplemented!()
     }
@@ -252,6 +280,15 @@ impl CoreServiceWorkflowClient for UnusedWorkflowClient {
         unimplemented!()
     }
 
+    async fn retract_approval_by_display_number(
+        &self,
+        _workflow_display_number: i64,
+        _step_display_number: i64,
+        _req: ringiflow_bff::client::RetractApprovalCoreRequest,
+    ) -> Result<WorkflowInstanceDto, CoreServiceError> {
+        unimplemented!()
+    }
+
     async fn resubmit_workflow_by_display_number(
         &self,
         _display_number: i64,
@@ -271,11 +308,96 @@ impl CoreServiceWorkflowClient for UnusedWorkflowClient {
     async fn list_comments(
         &self,
         _display_number: i64,
-        _tenant_id: Uuid,
+        _viewer: &WorkflowViewerQuery,
     ) -> Result<Vec<ringiflow_bff::client::WorkflowCommentDto>, CoreServiceError> {
         unimplemented!()
     }
 
+    async fn edit_comment(
+        &self,
+        _display_number: i64,
+        _comment_id: Uuid,
+        _req: ringiflow_bff::client::EditCommentCoreRequest,
+    ) -> Result<ringiflow_bff::client::WorkflowCommentDto, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn delete_comment(
+        &self,
+        _display_number: i64,
+        _comment_id: Uuid,
+        _viewer: &WorkflowViewerQuery,
+    ) -> Result<(), CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn list_comment_revisions(
+        &self,
+        _display_number: i64,
+        _comment_id: Uuid,
+        _viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<ringiflow_bff::client::WorkflowCommentRevisionDto>, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn list_form_data_changes(
+        &self,
+        _display_number: i64,
+        _viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<ringiflow_bff::client::WorkflowFormDataChangeDto>, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn list_activities(
+        &self,
+        _display_number: i64,
+        _viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<ringiflow_bff::client::WorkflowActivityDto>, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn list_submissions(
+        &self,
+        _display_number: i64,
+        _viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<ringiflow_bff::client::WorkflowSubmissionDto>, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn diff_submissions(
+        &self,
+        _display_number: i64,
+        _from: Option<i32>,
+        _to: Option<i32>,
+        _viewer: &WorkflowViewerQuery,
+    ) -> Result<ringiflow_bff::client::SubmissionDiffDto, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn list_watchers(
+        &self,
+        _display_number: i64,
+        _viewer: &WorkflowViewerQuery,
+    ) -> Result<Vec<ringiflow_bff::client::WorkflowWatcherDto>, CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn watch_workflow(
+        &self,
+        _display_number: i64,
+        _viewer: &WorkflowViewerQuery,
+    ) -> Result<(), CoreServiceError> {
+        unimplemented!()
+    }
+
+    async fn unwatch_workflow(
+        &self,
+        _display_number: i64,
+        _viewer: &WorkflowViewerQuery,
+    ) -> Result<(), CoreServiceError> {
+        unimplemented!()
+    }
+
     async fn create_workflow_definition(
         &self,
         _req: &CreateDefinitionCoreRequest,
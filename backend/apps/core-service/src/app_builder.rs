@@ -1,6 +1,7 @@
 //! # Core Service アプリケーション構築
 //!
-//! DI（リポジトリ・UseCase・State）の初期化とルーター構築を担当する。
+//! DI（リポジトリ・UseCase・State）の初期化とルーター構築、
+//! ワークフローイベントディスパッチャと各種ワーカーの構築を担当する。
 //! `main.rs` はインフラ初期化とサーバー起動に集中する。
 
 use std::sync::Arc;
@@ -15,30 +16,60 @@ use ringiflow_infra::{
     S3Client,
     TransactionManager,
     notification::NotificationSender,
+    realtime::RealtimePublisher,
     repository::{
         DisplayIdCounterRepository,
         DocumentRepository,
         FolderRepository,
+        InboxNotificationRepository,
+        NotificationDigestRepository,
         NotificationLogRepository,
+        NotificationPreferenceRepository,
+        NotificationTemplateRepository,
         RoleRepository,
         TenantRepository,
         UserRepository,
+        WebhookDeliveryRepository,
+        WebhookSubscriptionRepository,
+        WorkflowActivityRepository,
         WorkflowCommentRepository,
         WorkflowDefinitionRepository,
+        WorkflowEventOutboxRepository,
+        WorkflowFormDataChangeRepository,
         WorkflowInstanceRepository,
+        WorkflowProxyGrantRepository,
+        WorkflowScheduleRepository,
+        WorkflowSearchRepository,
         WorkflowStepRepository,
+        WorkflowSubmissionRepository,
+        WorkflowWatcherRepository,
         display_id_counter_repository::PostgresDisplayIdCounterRepository,
         document_repository::PostgresDocumentRepository,
         folder_repository::PostgresFolderRepository,
+        inbox_notification_repository::PostgresInboxNotificationRepository,
+        notification_digest_repository::PostgresNotificationDigestRepository,
         notification_log_repository::PostgresNotificationLogRepository,
+        notification_preference_repository::PostgresNotificationPreferenceRepository,
+        notification_template_repository::PostgresNotificationTemplateRepository,
         role_repository::PostgresRoleRepository,
         tenant_repository::PostgresTenantRepository,
         user_repository::PostgresUserRepository,
+        webhook_delivery_repository::PostgresWebhookDeliveryRepository,
+        webhook_subscription_repository::PostgresWebhookSubscriptionRepository,
+        workflow_activity_repository::PostgresWorkflowActivityRepository,
         workflow_comment_repository::PostgresWorkflowCommentRepository,
         workflow_definition_repository::PostgresWorkflowDefinitionRepository,
+        workflow_event_outbox_repository::PostgresWorkflowEventOutboxRepository,
+        workflow_form_data_change_repository::PostgresWorkflowFormDataChangeRepository,
         workflow_instance_repository::PostgresWorkflowInstanceRepository,
+        workflow_proxy_grant_repository::PostgresWorkflowProxyGrantRepository,
+        workflow_schedule_repository::PostgresWorkflowScheduleRepository,
+        workflow_search_repository::PostgresWorkflowSearchRepository,
         workflow_step_repository::PostgresWorkflowStepRepository,
+        workflow_submission_repository::PostgresWorkflowSubmissionRepository,
+        workflow_watcher_repository::PostgresWorkflowWatcherRepository,
     },
+    webhook::WebhookSender,
 };
 use ringiflow_shared::{canonical_log::CanonicalLogLineLayer, observability::make_request_span};
 use tower_http::trace::TraceLayer;
@@ -49,75 +80,154 @@ use crate::{
         DashboardState,
         DocumentState,
         FolderState,
+        NotificationLogState,
+        NotificationState,
+        NotificationTemplateState,
+        ProxyGrantState,
         ReadinessState,
         RoleState,
         TaskState,
+        TenantState,
         UserState,
+        WebhookState,
         WorkflowDefinitionState,
+        WorkflowScheduleState,
+        WorkflowSearchState,
         WorkflowState,
         approve_step,
         approve_step_by_display_number,
         archive_definition,
+        bulk_decide_steps,
         confirm_upload,
         create_definition,
         create_folder,
+        create_proxy_grant,
         create_role,
         create_user,
+        create_webhook,
         create_workflow,
+        create_workflow_schedule,
+        delete_comment,
         delete_definition,
         delete_document,
         delete_folder,
+        delete_notification_template,
+        delete_proxy_grant,
         delete_role,
+        delete_webhook,
+        delete_workflow,
+        delete_workflow_schedule,
+        diff_submissions,
+        duplicate_workflow,
+        edit_comment,
+        force_cancel_workflow,
+        force_complete_workflow,
         generate_download_url,
         get_dashboard_stats,
         get_definition,
+        get_notification_template,
         get_role,
         get_task,
         get_task_by_display_numbers,
+        get_tenant_settings,
+        get_unread_notification_count,
         get_user,
         get_user_by_display_number,
         get_user_by_email,
+        get_webhook,
         get_workflow,
         get_workflow_by_display_number,
         health_check,
+        list_activities,
+        list_comment_revisions,
         list_comments,
         list_definitions,
         list_documents,
+        list_failed_notification_logs,
         list_folders,
+        list_form_data_changes,
         list_my_tasks,
         list_my_workflows,
+        list_notification_preferences,
+        list_notification_templates,
+        list_notifications,
+        list_proxy_grants,
         list_roles,
+        list_submissions,
         list_users,
+        list_watchers,
+        list_webhook_deliveries,
+        list_webhooks,
         list_workflow_attachments,
+        list_workflow_schedules,
+        mark_all_notifications_read,
+        mark_notification_read,
         post_comment,
+        preview_notification_template,
         publish_definition,
         readiness_check,
+        reassign_current_step,
         reject_step,
         reject_step_by_display_number,
         request_changes_step,
         request_changes_step_by_display_number,
         request_upload_url,
+        resend_notification_log,
         resubmit_workflow,
         resubmit_workflow_by_display_number,
+        retract_approval,
+        retract_approval_by_display_number,
+        save_notification_template,
+        search_all_workflows,
+        search_workflows,
+        send_test_webhook,
         submit_workflow,
         submit_workflow_by_display_number,
+        unwatch_workflow,
         update_definition,
         update_folder,
+        update_notification_preferences,
         update_role,
+        update_tenant_settings,
         update_user,
+        update_user_locale,
         update_user_status,
+        update_webhook,
+        update_workflow_schedule,
         validate_definition,
+        validate_notification_template,
+        watch_workflow,
     },
     usecase::{
         DashboardUseCaseImpl,
         DocumentUseCaseImpl,
+        DraftPurgeWorker,
         FolderUseCaseImpl,
+        InboxPurgeWorker,
+        InboxUseCaseImpl,
+        NotificationDigestWorker,
+        NotificationEventConsumer,
+        NotificationLogUseCaseImpl,
+        NotificationPreferenceUseCaseImpl,
+        NotificationRetryWorker,
         NotificationService,
+        NotificationTemplateUseCaseImpl,
+        ProxyGrantUseCaseImpl,
+        RealtimeEventConsumer,
         RoleUseCaseImpl,
         TaskUseCaseImpl,
         TemplateRenderer,
+        TenantUseCaseImpl,
         UserUseCaseImpl,
+        WebhookDeliveryWorker,
+        WebhookEventConsumer,
+        WebhookUseCaseImpl,
         WorkflowDefinitionUseCaseImpl,
+        WorkflowEventConsumer,
+        WorkflowEventDispatcher,
+        WorkflowScheduleUseCaseImpl,
+        WorkflowScheduleWorker,
+        WorkflowSearchUseCaseImpl,
         WorkflowUseCaseImpl,
         workflow::WorkflowUseCaseDeps,
     },
@@ -130,7 +240,7 @@ use crate::{
 pub(crate) fn build_app(
     pool: sqlx::PgPool,
     s3_client: Arc<dyn S3Client>,
-    notification_sender: Arc<dyn NotificationSender>,
+    webhook_sender: Arc<dyn WebhookSender>,
     config: &CoreConfig,
 ) -> Router {
     // Readiness Check 用 State
@@ -148,8 +258,22 @@ pub(crate) fn build_app(
         Arc::new(PostgresWorkflowStepRepository::new(pool.clone()));
     let comment_repo: Arc<dyn WorkflowCommentRepository> =
         Arc::new(PostgresWorkflowCommentRepository::new(pool.clone()));
+    let form_data_change_repo: Arc<dyn WorkflowFormDataChangeRepository> =
+        Arc::new(PostgresWorkflowFormDataChangeRepository::new(pool.clone()));
+    let submission_repo: Arc<dyn WorkflowSubmissionRepository> =
+        Arc::new(PostgresWorkflowSubmissionRepository::new(pool.clone()));
+    let watcher_repo: Arc<dyn WorkflowWatcherRepository> =
+        Arc::new(PostgresWorkflowWatcherRepository::new(pool.clone()));
+    let proxy_grant_repo: Arc<dyn WorkflowProxyGrantRepository> =
+        Arc::new(PostgresWorkflowProxyGrantRepository::new(pool.clone()));
+    let activity_repo: Arc<dyn WorkflowActivityRepository> =
+        Arc::new(PostgresWorkflowActivityRepository::new(pool.clone()));
+    let outbox_repo: Arc<dyn WorkflowEventOutboxRepository> =
+        Arc::new(PostgresWorkflowEventOutboxRepository::new(pool.clone()));
     let counter_repo: Arc<dyn DisplayIdCounterRepository> =
         Arc::new(PostgresDisplayIdCounterRepository::new(pool.clone()));
+    let search_repo: Arc<dyn WorkflowSearchRepository> =
+        Arc::new(PostgresWorkflowSearchRepository::new(pool.clone()));
 
     let folder_repo: Arc<dyn FolderRepository> =
         Arc::new(PostgresFolderRepository::new(pool.clone()));
@@ -158,6 +282,11 @@ pub(crate) fn build_app(
 
     let role_repo: Arc<dyn RoleRepository> = Arc::new(PostgresRoleRepository::new(pool.clone()));
 
+    let webhook_subscription_repo: Arc<dyn WebhookSubscriptionRepository> =
+        Arc::new(PostgresWebhookSubscriptionRepository::new(pool.clone()));
+    let webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository> =
+        Arc::new(PostgresWebhookDeliveryRepository::new(pool.clone()));
+
     // Clock（複数ユースケースで共有）
     let clock: Arc<dyn ringiflow_domain::clock::Clock> = Arc::new(SystemClock);
 
@@ -168,10 +297,15 @@ pub(crate) fn build_app(
     let user_usecase = UserUseCaseImpl::new(user_repo.clone(), counter_repo.clone(), clock.clone());
     let user_state = Arc::new(UserState {
         user_repository:   user_repo.clone(),
-        tenant_repository: tenant_repo,
+        tenant_repository: tenant_repo.clone(),
         usecase:           user_usecase,
     });
 
+    // テナント設定 UseCase + State
+    let tenant_state = Arc::new(TenantState {
+        usecase: TenantUseCaseImpl::new(tenant_repo),
+    });
+
     // フォルダ UseCase + State
     let folder_usecase = FolderUseCaseImpl::new(folder_repo, clock.clone(), tx_manager.clone());
     let folder_state = Arc::new(FolderState {
@@ -180,9 +314,14 @@ pub(crate) fn build_app(
 
     // ドキュメント UseCase + State
     let document_usecase = DocumentUseCaseImpl::new(
-        document_repo,
+        document_repo.clone(),
         instance_repo.clone(),
-        s3_client,
+        activity_repo.clone(),
+        step_repo.clone(),
+        definition_repo.clone(),
+        watcher_repo.clone(),
+        comment_repo.clone(),
+        s3_client.clone(),
         clock.clone(),
     );
     let document_state = Arc::new(DocumentState {
@@ -196,23 +335,74 @@ pub(crate) fn build_app(
         usecase:         role_usecase,
     });
 
+    // Webhook UseCase + State
+    let webhook_usecase = WebhookUseCaseImpl::new(
+        webhook_subscription_repo,
+        webhook_delivery_repo,
+        webhook_sender,
+        clock.clone(),
+    );
+    let webhook_state = Arc::new(WebhookState {
+        usecase: webhook_usecase,
+    });
+
     // ワークフロー定義管理 UseCase + State
-    let definition_usecase =
-        WorkflowDefinitionUseCaseImpl::new(definition_repo.clone(), clock.clone());
+    let definition_usecase = WorkflowDefinitionUseCaseImpl::new(
+        definition_repo.clone(),
+        user_repo.clone(),
+        clock.clone(),
+    );
     let definition_state = Arc::new(WorkflowDefinitionState {
         usecase: definition_usecase,
     });
 
-    // 通知サービス
-    let notification_log_repo: Arc<dyn NotificationLogRepository> =
-        Arc::new(PostgresNotificationLogRepository::new(pool));
-    let template_renderer = TemplateRenderer::new().expect("テンプレートエンジンの初期化に失敗");
-    let notification_service = Arc::new(NotificationService::new(
-        notification_sender,
-        template_renderer,
-        notification_log_repo,
-        config.notification.base_url.clone(),
-    ));
+    // ワークフロースケジュール UseCase + State
+    let schedule_repo: Arc<dyn WorkflowScheduleRepository> =
+        Arc::new(PostgresWorkflowScheduleRepository::new(pool.clone()));
+    let schedule_usecase =
+        WorkflowScheduleUseCaseImpl::new(schedule_repo, definition_repo.clone(), clock.clone());
+    let schedule_state = Arc::new(WorkflowScheduleState {
+        usecase: schedule_usecase,
+    });
+
+    // 代理権限 UseCase + State
+    let proxy_grant_usecase =
+        ProxyGrantUseCaseImpl::new(proxy_grant_repo.clone(), user_repo.clone(), clock.clone());
+    let proxy_grant_state = Arc::new(ProxyGrantState {
+        usecase: proxy_grant_usecase,
+    });
+
+    // アプリ内通知・通知設定 UseCase + State
+    let notification_state = Arc::new(NotificationState {
+        usecase: InboxUseCaseImpl::new(
+            Arc::new(PostgresInboxNotificationRepository::new(pool.clone())),
+            clock.clone(),
+        ),
+        preference_usecase: NotificationPreferenceUseCaseImpl::new(
+            Arc::new(PostgresNotificationPreferenceRepository::new(pool.clone())),
+            clock.clone(),
+            tx_manager.clone(),
+        ),
+    });
+
+    // 通知ログ UseCase + State
+    let notification_log_state = Arc::new(NotificationLogState {
+        usecase: NotificationLogUseCaseImpl::new(
+            Arc::new(PostgresNotificationLogRepository::new(pool.clone())),
+            tx_manager.clone(),
+            clock.clone(),
+        ),
+    });
+
+    // 通知テンプレート UseCase + State
+    let notification_template_state = Arc::new(NotificationTemplateState {
+        usecase: NotificationTemplateUseCaseImpl::new(
+            Arc::new(PostgresNotificationTemplateRepository::new(pool.clone())),
+            TemplateRenderer::new().expect("テンプレートエンジンの初期化に失敗"),
+            clock.clone(),
+            config.notification.base_url.clone(),
+        ),
+    });
 
     // ワークフロー UseCase
     let workflow_usecase = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
@@ -220,22 +410,37 @@ pub(crate) fn build_app(
         instance_repo: instance_repo.clone(),
         step_repo: step_repo.clone(),
         comment_repo,
+        form_data_change_repo,
+        submission_repo,
+        watcher_repo,
+        proxy_grant_repo,
+        document_repo,
+        activity_repo,
+        outbox_repo,
         user_repo: user_repo.clone(),
         counter_repo,
+        s3_client,
         clock,
         tx_manager,
-        notification_service,
     });
     let workflow_state = Arc::new(WorkflowState {
         usecase: workflow_usecase,
     });
 
     // タスク UseCase
-    let task_usecase = TaskUseCaseImpl::new(instance_repo.clone(), step_repo.clone(), user_repo);
+    let task_usecase =
+        TaskUseCaseImpl::new(instance_repo.clone(), step_repo.clone(), user_repo.clone());
     let task_state = Arc::new(TaskState {
         usecase: task_usecase,
     });
 
+    // ワークフロー検索 UseCase
+    let search_usecase =
+        WorkflowSearchUseCaseImpl::new(search_repo, instance_repo.clone(), user_repo);
+    let search_state = Arc::new(WorkflowSearchState {
+        usecase: search_usecase,
+    });
+
     // ダッシュボード UseCase
     let dashboard_usecase = DashboardUseCaseImpl::new(instance_repo, step_repo);
     let dashboard_state = Arc::new(DashboardState {
@@ -260,11 +465,21 @@ pub(crate) fn build_app(
          "/internal/users/{user_id}/status",
          patch(update_user_status),
       )
+      .route(
+         "/internal/users/{user_id}/locale",
+         put(update_user_locale),
+      )
       .route(
          "/internal/users/by-display-number/{display_number}",
          get(get_user_by_display_number),
       )
       .with_state(user_state)
+      // テナント設定 API
+      .route(
+         "/internal/tenants/{tenant_id}/settings",
+         get(get_tenant_settings).put(update_tenant_settings),
+      )
+      .with_state(tenant_state)
       // フォルダ管理 API
       .route(
          "/internal/folders",
@@ -311,6 +526,24 @@ pub(crate) fn build_app(
          get(get_role).patch(update_role).delete(delete_role),
       )
       .with_state(role_state)
+      // Webhook 管理 API
+      .route(
+         "/internal/webhooks",
+         get(list_webhooks).post(create_webhook),
+      )
+      .route(
+         "/internal/webhooks/{webhook_id}",
+         get(get_webhook).put(update_webhook).delete(delete_webhook),
+      )
+      .route(
+         "/internal/webhooks/{webhook_id}/deliveries",
+         get(list_webhook_deliveries),
+      )
+      .route(
+         "/internal/webhooks/{webhook_id}/test",
+         post(send_test_webhook),
+      )
+      .with_state(webhook_state)
       // ワークフロー定義管理 API
       .route(
          "/internal/workflow-definitions",
@@ -333,6 +566,75 @@ pub(crate) fn build_app(
          post(validate_definition),
       )
       .with_state(definition_state)
+      // ワークフロースケジュール API
+      .route(
+         "/internal/workflow-schedules",
+         get(list_workflow_schedules).post(create_workflow_schedule),
+      )
+      .route(
+         "/internal/workflow-schedules/{schedule_id}",
+         put(update_workflow_schedule).delete(delete_workflow_schedule),
+      )
+      .with_state(schedule_state)
+      // 代理権限 API
+      .route(
+         "/internal/proxy-grants",
+         get(list_proxy_grants).post(create_proxy_grant),
+      )
+      .route(
+         "/internal/proxy-grants/{proxy_user_id}",
+         delete(delete_proxy_grant),
+      )
+      .with_state(proxy_grant_state)
+      // アプリ内通知 API
+      .route("/internal/notifications", get(list_notifications))
+      .route(
+         "/internal/notifications/preferences",
+         get(list_notification_preferences).put(update_notification_preferences),
+      )
+      .route(
+         "/internal/notifications/unread-count",
+         get(get_unread_notification_count),
+      )
+      .route(
+         "/internal/notifications/read-all",
+         post(mark_all_notifications_read),
+      )
+      .route(
+         "/internal/notifications/{id}/read",
+         post(mark_notification_read),
+      )
+      .with_state(notification_state)
+      // 通知テンプレート API
+      .route(
+         "/internal/notification-templates",
+         get(list_notification_templates),
+      )
+      .route(
+         "/internal/notification-templates/{event_type}",
+         get(get_notification_template)
+            .put(save_notification_template)
+            .delete(delete_notification_template),
+      )
+      .route(
+         "/internal/notification-templates/{event_type}/validate",
+         post(validate_notification_template),
+      )
+      .route(
+         "/internal/notification-templates/{event_type}/preview",
+         post(preview_notification_template),
+      )
+      .with_state(notification_template_state)
+      // 通知ログ API
+      .route(
+         "/internal/notification-logs/failed",
+         get(list_failed_notification_logs),
+      )
+      .route(
+         "/internal/notification-logs/{id}/resend",
+         post(resend_notification_log),
+      )
+      .with_state(notification_log_state)
       // ワークフローインスタンス API
       .route(
          "/internal/workflows",
@@ -352,14 +654,22 @@ pub(crate) fn build_app(
          "/internal/workflows/{id}/steps/{step_id}/request-changes",
          post(request_changes_step),
       )
+      .route(
+         "/internal/workflows/{id}/steps/{step_id}/retract",
+         post(retract_approval),
+      )
       .route(
          "/internal/workflows/{id}/resubmit",
          post(resubmit_workflow),
       )
+      .route(
+         "/internal/workflows/bulk-decision",
+         post(bulk_decide_steps),
+      )
       // display_number 対応 API
       .route(
          "/internal/workflows/by-display-number/{display_number}",
-         get(get_workflow_by_display_number),
+         get(get_workflow_by_display_number).delete(delete_workflow),
       )
       .route(
          "/internal/workflows/by-display-number/{display_number}/submit",
@@ -377,14 +687,63 @@ pub(crate) fn build_app(
          "/internal/workflows/by-display-number/{display_number}/steps/by-display-number/{step_display_number}/request-changes",
          post(request_changes_step_by_display_number),
       )
+      .route(
+         "/internal/workflows/by-display-number/{display_number}/steps/by-display-number/{step_display_number}/retract",
+         post(retract_approval_by_display_number),
+      )
       .route(
          "/internal/workflows/by-display-number/{display_number}/resubmit",
          post(resubmit_workflow_by_display_number),
       )
+      .route(
+         "/internal/workflows/by-display-number/{display_number}/duplicate",
+         post(duplicate_workflow),
+      )
       .route(
          "/internal/workflows/by-display-number/{display_number}/comments",
          get(list_comments).post(post_comment),
       )
+      .route(
+         "/internal/workflows/by-display-number/{display_number}/comments/{comment_id}",
+         patch(edit_comment).delete(delete_comment),
+      )
+      .route(
+         "/internal/workflows/by-display-number/{display_number}/comments/{comment_id}/revisions",
+         get(list_comment_revisions),
+      )
+      .route(
+         "/internal/workflows/by-display-number/{display_number}/watchers",
+         get(list_watchers).post(watch_workflow).delete(unwatch_workflow),
+      )
+      .route(
+         "/internal/workflows/by-display-number/{display_number}/form-data-changes",
+         get(list_form_data_changes),
+      )
+      .route(
+         "/internal/workflows/by-display-number/{display_number}/activities",
+         get(list_activities),
+      )
+      .route(
+         "/internal/workflows/by-display-number/{display_number}/submissions",
+         get(list_submissions),
+      )
+      .route(
+         "/internal/workflows/by-display-number/{display_number}/submissions/diff",
+         get(diff_submissions),
+      )
+      // テナント管理者向けの強制操作 API
+      .route(
+         "/internal/admin/workflows/by-display-number/{display_number}/cancel",
+         post(force_cancel_workflow),
+      )
+      .route(
+         "/internal/admin/workflows/by-display-number/{display_number}/reassign",
+         post(reassign_current_step),
+      )
+      .route(
+         "/internal/admin/workflows/by-display-number/{display_number}/force-complete",
+         post(force_complete_workflow),
+      )
       .with_state(workflow_state)
       // タスク API
       .route("/internal/tasks/my", get(list_my_tasks))
@@ -397,6 +756,245 @@ pub(crate) fn build_app(
       // ダッシュボード API
       .route("/internal/dashboard/stats", get(get_dashboard_stats))
       .with_state(dashboard_state)
+      // ワークフロー検索 API
+      .route("/internal/workflows/search", get(search_workflows))
+      .route("/internal/admin/workflows", get(search_all_workflows))
+      .with_state(search_state)
       .layer(CanonicalLogLineLayer)
       .layer(TraceLayer::new_for_http().make_span_with(make_request_span))
 }
+
+/// ワークフローイベントディスパッチャを構築する
+///
+/// アウトボックスに記録されたイベントを配信するコンシューマを登録する。
+/// 新たなコンシューマはここで `consumers` に追加する。
+/// リアルタイム通知のコンシューマは発行先（Redis）が設定されている場合のみ登録する。
+pub(crate) fn build_event_dispatcher(
+    pool: sqlx::PgPool,
+    notification_sender: Arc<dyn NotificationSender>,
+    realtime_publisher: Option<Arc<dyn RealtimePublisher>>,
+    config: &CoreConfig,
+) -> WorkflowEventDispatcher {
+    let user_repo: Arc<dyn UserRepository> = Arc::new(PostgresUserRepository::new(pool.clone()));
+    let outbox_repo: Arc<dyn WorkflowEventOutboxRepository> =
+        Arc::new(PostgresWorkflowEventOutboxRepository::new(pool.clone()));
+    let watcher_repo: Arc<dyn WorkflowWatcherRepository> =
+        Arc::new(PostgresWorkflowWatcherRepository::new(pool.clone()));
+    let step_repo: Arc<dyn WorkflowStepRepository> =
+        Arc::new(PostgresWorkflowStepRepository::new(pool.clone()));
+    let tx_manager: Arc<dyn TransactionManager> = Arc::new(PgTransactionManager::new(pool.clone()));
+    let webhook_subscription_repo: Arc<dyn WebhookSubscriptionRepository> =
+        Arc::new(PostgresWebhookSubscriptionRepository::new(pool.clone()));
+    let webhook_delivery_repo: Arc<dyn WebhookDeliveryRepository> =
+        Arc::new(PostgresWebhookDeliveryRepository::new(pool.clone()));
+
+    // 通知サービス
+    let notification_log_repo: Arc<dyn NotificationLogRepository> =
+        Arc::new(PostgresNotificationLogRepository::new(pool.clone()));
+    let inbox_repo: Arc<dyn InboxNotificationRepository> =
+        Arc::new(PostgresInboxNotificationRepository::new(pool.clone()));
+    let preference_repo: Arc<dyn NotificationPreferenceRepository> =
+        Arc::new(PostgresNotificationPreferenceRepository::new(pool.clone()));
+    let digest_repo: Arc<dyn NotificationDigestRepository> =
+        Arc::new(PostgresNotificationDigestRepository::new(pool.clone()));
+    let template_repo: Arc<dyn NotificationTemplateRepository> =
+        Arc::new(PostgresNotificationTemplateRepository::new(pool));
+    let template_renderer = TemplateRenderer::new().expect("テンプレートエンジンの初期化に失敗");
+    let notification_service = Arc::new(NotificationService::new(
+        notification_sender,
+        template_renderer,
+        notification_log_repo,
+        inbox_repo,
+        preference_repo,
+        digest_repo,
+        template_repo,
+        user_repo.clone(),
+        config.notification.base_url.clone(),
+    ));
+
+    let mut consumers: Vec<Arc<dyn WorkflowEventConsumer>> = vec![
+        Arc::new(NotificationEventConsumer::new(
+            user_repo,
+            watcher_repo.clone(),
+            notification_service,
+        )),
+        Arc::new(WebhookEventConsumer::new(
+            webhook_subscription_repo,
+            webhook_delivery_repo,
+        )),
+    ];
+    if let Some(publisher) = realtime_publisher {
+        consumers.push(Arc::new(RealtimeEventConsumer::new(
+            step_repo,
+            watcher_repo,
+            publisher,
+        )));
+    }
+
+    WorkflowEventDispatcher::new(
+        outbox_repo,
+        consumers,
+        tx_manager,
+        Arc::new(SystemClock),
+        config.outbox.batch_size,
+    )
+}
+
+/// Webhook 配信ワーカーを構築する
+pub(crate) fn build_webhook_worker(
+    pool: sqlx::PgPool,
+    webhook_sender: Arc<dyn WebhookSender>,
+    config: &CoreConfig,
+) -> WebhookDeliveryWorker {
+    let delivery_repo: Arc<dyn WebhookDeliveryRepository> =
+        Arc::new(PostgresWebhookDeliveryRepository::new(pool.clone()));
+    let subscription_repo: Arc<dyn WebhookSubscriptionRepository> =
+        Arc::new(PostgresWebhookSubscriptionRepository::new(pool.clone()));
+    let tx_manager: Arc<dyn TransactionManager> = Arc::new(PgTransactionManager::new(pool));
+
+    WebhookDeliveryWorker::new(
+        delivery_repo,
+        subscription_repo,
+        webhook_sender,
+        tx_manager,
+        Arc::new(SystemClock),
+        config.webhook.batch_size,
+    )
+}
+
+/// 通知メールの再送ワーカーを構築する
+pub(crate) fn build_notification_retry_worker(
+    pool: sqlx::PgPool,
+    notification_sender: Arc<dyn NotificationSender>,
+    config: &CoreConfig,
+) -> NotificationRetryWorker {
+    let log_repo: Arc<dyn NotificationLogRepository> =
+        Arc::new(PostgresNotificationLogRepository::new(pool.clone()));
+    let tx_manager: Arc<dyn TransactionManager> = Arc::new(PgTransactionManager::new(pool));
+
+    NotificationRetryWorker::new(
+        log_repo,
+        notification_sender,
+        tx_manager,
+        Arc::new(SystemClock),
+        config.notification_retry.batch_size,
+    )
+}
+
+/// 下書きの自動パージワーカーを構築する
+///
+/// `DRAFT_PURGE_AFTER_DAYS` が未設定の場合は `None` を返す。
+pub(crate) fn build_draft_purge_worker(
+    pool: sqlx::PgPool,
+    s3_client: Arc<dyn S3Client>,
+    config: &CoreConfig,
+) -> Option<DraftPurgeWorker> {
+    let after_days = config.draft_purge.after_days?;
+    let instance_repo: Arc<dyn WorkflowInstanceRepository> =
+        Arc::new(PostgresWorkflowInstanceRepository::new(pool.clone()));
+    let document_repo: Arc<dyn DocumentRepository> =
+        Arc::new(PostgresDocumentRepository::new(pool.clone()));
+    let tx_manager: Arc<dyn TransactionManager> = Arc::new(PgTransactionManager::new(pool));
+
+    Some(DraftPurgeWorker::new(
+        instance_repo,
+        document_repo,
+        s3_client,
+        tx_manager,
+        Arc::new(SystemClock),
+        chrono::Duration::days(i64::from(after_days)),
+        config.draft_purge.batch_size,
+    ))
+}
+
+/// アプリ内通知の保持期間切れパージワーカーを構築する
+pub(crate) fn build_inbox_purge_worker(
+    pool: sqlx::PgPool,
+    config: &CoreConfig,
+) -> InboxPurgeWorker {
+    InboxPurgeWorker::new(
+        Arc::new(PostgresInboxNotificationRepository::new(pool)),
+        Arc::new(SystemClock),
+        chrono::Duration::days(i64::from(config.inbox.retention_days)),
+        config.inbox.batch_size,
+    )
+}
+
+/// 通知ダイジェストの送信ワーカーを構築する
+pub(crate) fn build_notification_digest_worker(
+    pool: sqlx::PgPool,
+    notification_sender: Arc<dyn NotificationSender>,
+    config: &CoreConfig,
+) -> NotificationDigestWorker {
+    NotificationDigestWorker::new(
+        notification_sender,
+        TemplateRenderer::new().expect("テンプレートエンジンの初期化に失敗"),
+        Arc::new(PostgresNotificationLogRepository::new(pool.clone())),
+        Arc::new(PostgresNotificationDigestRepository::new(pool.clone())),
+        Arc::new(PostgresUserRepository::new(pool.clone())),
+        Arc::new(PostgresTenantRepository::new(pool.clone())),
+        Arc::new(PgTransactionManager::new(pool)),
+        Arc::new(SystemClock),
+        config.notification.base_url.clone(),
+        config.notification_digest.send_at,
+        config.notification_digest.batch_size,
+    )
+}
+
+/// ワークフロースケジュールワーカーを構築する
+///
+/// 所有者への通知に使う `NotificationService` はディスパッチャとは別に構築する
+/// （送信バックエンドは共有する）。
+pub(crate) fn build_workflow_schedule_worker(
+    pool: sqlx::PgPool,
+    s3_client: Arc<dyn S3Client>,
+    notification_sender: Arc<dyn NotificationSender>,
+    config: &CoreConfig,
+) -> WorkflowScheduleWorker {
+    let user_repo: Arc<dyn UserRepository> = Arc::new(PostgresUserRepository::new(pool.clone()));
+    let clock: Arc<dyn ringiflow_domain::clock::Clock> = Arc::new(SystemClock);
+    let tx_manager: Arc<dyn TransactionManager> = Arc::new(PgTransactionManager::new(pool.clone()));
+
+    let workflow_usecase = Arc::new(WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
+        definition_repo: Arc::new(PostgresWorkflowDefinitionRepository::new(pool.clone())),
+        instance_repo: Arc::new(PostgresWorkflowInstanceRepository::new(pool.clone())),
+        step_repo: Arc::new(PostgresWorkflowStepRepository::new(pool.clone())),
+        comment_repo: Arc::new(PostgresWorkflowCommentRepository::new(pool.clone())),
+        form_data_change_repo: Arc::new(PostgresWorkflowFormDataChangeRepository::new(
+            pool.clone(),
+        )),
+        submission_repo: Arc::new(PostgresWorkflowSubmissionRepository::new(pool.clone())),
+        watcher_repo: Arc::new(PostgresWorkflowWatcherRepository::new(pool.clone())),
+        proxy_grant_repo: Arc::new(PostgresWorkflowProxyGrantRepository::new(pool.clone())),
+        document_repo: Arc::new(PostgresDocumentRepository::new(pool.clone())),
+        activity_repo: Arc::new(PostgresWorkflowActivityRepository::new(pool.clone())),
+        outbox_repo: Arc::new(PostgresWorkflowEventOutboxRepository::new(pool.clone())),
+        user_repo: user_repo.clone(),
+        counter_repo: Arc::new(PostgresDisplayIdCounterRepository::new(pool.clone())),
+        s3_client,
+        clock: clock.clone(),
+        tx_manager: tx_manager.clone(),
+    }));
+
+    let notification_service = Arc::new(NotificationService::new(
+        notification_sender,
+        TemplateRenderer::new().expect("テンプレートエンジンの初期化に失敗"),
+        Arc::new(PostgresNotificationLogRepository::new(pool.clone())),
+        Arc::new(PostgresInboxNotificationRepository::new(pool.clone())),
+        Arc::new(PostgresNotificationPreferenceRepository::new(pool.clone())),
+        Arc::new(PostgresNotificationDigestRepository::new(pool.clone())),
+        Arc::new(PostgresNotificationTemplateRepository::new(pool.clone())),
+        user_repo.clone(),
+        config.notification.base_url.clone(),
+    ));
+
+    WorkflowScheduleWorker::new(
+        Arc::new(PostgresWorkflowScheduleRepository::new(pool)),
+        workflow_usecase,
+        user_repo,
+        notification_service,
+        tx_manager,
+        clock,
+        config.schedule.batch_size,
+    )
+}
@@ -4,6 +4,8 @@
 
 use std::env;
 
+use chrono::NaiveTime;
+
 /// Core Service サーバーの設定
 #[derive(Debug, Clone)]
 pub struct CoreConfig {
@@ -17,8 +19,24 @@ pub struct CoreConfig {
     pub s3_endpoint_url: Option<String>,
     /// S3 バケット名
     pub s3_bucket_name: String,
+    /// Redis 接続 URL（リアルタイム通知の発行先、未設定で発行しない）
+    pub redis_url: Option<String>,
     /// 通知設定
     pub notification: NotificationConfig,
+    /// イベントアウトボックス設定
+    pub outbox: OutboxConfig,
+    /// Webhook 配信設定
+    pub webhook: WebhookConfig,
+    /// 下書き自動パージ設定
+    pub draft_purge: DraftPurgeConfig,
+    /// アプリ内通知の保持設定
+    pub inbox: InboxConfig,
+    /// 通知ダイジェストの送信設定
+    pub notification_digest: NotificationDigestConfig,
+    /// 送信に失敗した通知メールの再送設定
+    pub notification_retry: NotificationRetryConfig,
+    /// ワークフロースケジュール実行設定
+    pub schedule: ScheduleConfig,
 }
 
 /// 通知機能の設定
@@ -41,6 +59,90 @@ pub struct NotificationConfig {
     pub base_url:     String,
 }
 
+/// イベントアウトボックスのディスパッチ設定
+#[derive(Debug, Clone)]
+pub struct OutboxConfig {
+    /// 未配信イベントのポーリング間隔（ミリ秒）
+    pub poll_interval_ms: u64,
+    /// 1 回のポーリングで処理するイベントの最大件数
+    pub batch_size:       i64,
+}
+
+/// Webhook 配信の設定
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    /// 送信待ちの配信のポーリング間隔（ミリ秒）
+    pub poll_interval_ms: u64,
+    /// 1 回のポーリングで送信する配信の最大件数
+    pub batch_size: i64,
+    /// 1 リクエストあたりのタイムアウト（秒）
+    pub timeout_secs: u64,
+    /// http の URL と内部アドレスへの送信を許可するか
+    ///
+    /// `ENVIRONMENT=development` のときのみ有効になり、ローカルの受信サーバーで動作確認できる。
+    pub allow_insecure_destinations: bool,
+}
+
+/// 下書きの自動パージ設定
+///
+/// `DRAFT_PURGE_AFTER_DAYS` が未設定の場合は自動パージを行わない。
+#[derive(Debug, Clone)]
+pub struct DraftPurgeConfig {
+    /// 最終更新からこの日数を経過した下書きを削除する（未設定で無効）
+    pub after_days:       Option<u32>,
+    /// パージ対象のポーリング間隔（ミリ秒）
+    pub poll_interval_ms: u64,
+    /// 1 回のポーリングで削除する下書きの最大件数
+    pub batch_size:       i64,
+}
+
+/// アプリ内通知の保持設定
+///
+/// 作成から `retention_days` 日を経過した通知を既読・未読にかかわらず削除する。
+#[derive(Debug, Clone)]
+pub struct InboxConfig {
+    /// 通知の保持日数
+    pub retention_days:   u32,
+    /// パージ対象のポーリング間隔（ミリ秒）
+    pub poll_interval_ms: u64,
+    /// 1 回のポーリングで削除する通知の最大件数
+    pub batch_size:       i64,
+}
+
+/// 通知ダイジェストの送信設定
+///
+/// 配信方法がダイジェストの通知を毎日 `send_at`（テナントのタイムゾーンの現地時刻）に
+/// 受信者単位でまとめて送信する。
+#[derive(Debug, Clone)]
+pub struct NotificationDigestConfig {
+    /// 送信時刻（テナントのタイムゾーンの現地時刻）
+    pub send_at:          NaiveTime,
+    /// 送信時刻を過ぎたかを確認するポーリング間隔（ミリ秒）
+    pub poll_interval_ms: u64,
+    /// 1 回のポーリングで送信するダイジェストの最大件数（受信者数）
+    pub batch_size:       i64,
+}
+
+/// 通知メールの再送設定
+///
+/// 一時的な失敗で再送待ちになった通知を `poll_interval_ms` ごとに再送する。
+#[derive(Debug, Clone)]
+pub struct NotificationRetryConfig {
+    /// 再送待ちの通知のポーリング間隔（ミリ秒）
+    pub poll_interval_ms: u64,
+    /// 1 回のポーリングで再送する通知の最大件数
+    pub batch_size:       i64,
+}
+
+/// ワークフロースケジュールの実行設定
+#[derive(Debug, Clone)]
+pub struct ScheduleConfig {
+    /// 実行日時に達したスケジュールのポーリング間隔（ミリ秒）
+    pub poll_interval_ms: u64,
+    /// 1 回のポーリングで実行するスケジュールの最大件数
+    pub batch_size:       i64,
+}
+
 impl CoreConfig {
     /// 環境変数から設定を読み込む
     pub fn from_env() -> Result<Self, env::VarError> {
@@ -55,7 +157,15 @@ impl CoreConfig {
             s3_endpoint_url: env::var("S3_ENDPOINT_URL").ok(),
             s3_bucket_name: env::var("S3_BUCKET_NAME")
                 .expect("S3_BUCKET_NAME が設定されていません（just setup-env を実行してください）"),
+            redis_url: env::var("REDIS_URL").ok(),
             notification: NotificationConfig::from_env(),
+            outbox: OutboxConfig::from_env(),
+            webhook: WebhookConfig::from_env(),
+            draft_purge: DraftPurgeConfig::from_env(),
+            inbox: InboxConfig::from_env(),
+            notification_digest: NotificationDigestConfig::from_env(),
+            notification_retry: NotificationRetryConfig::from_env(),
+            schedule: ScheduleConfig::from_env(),
         })
     }
 }
@@ -77,3 +187,133 @@ impl NotificationConfig {
         }
     }
 }
+
+impl OutboxConfig {
+    /// 環境変数からアウトボックス設定を読み込む
+    fn from_env() -> Self {
+        Self {
+            poll_interval_ms: env::var("OUTBOX_POLL_INTERVAL_MS")
+                .unwrap_or_else(|_| "1000".to_string())
+                .parse()
+                .expect("OUTBOX_POLL_INTERVAL_MS は有効な数値である必要があります"),
+            batch_size:       env::var("OUTBOX_BATCH_SIZE")
+                .unwrap_or_else(|_| "50".to_string())
+                .parse()
+                .expect("OUTBOX_BATCH_SIZE は有効な数値である必要があります"),
+        }
+    }
+}
+
+impl WebhookConfig {
+    /// 環境変数から Webhook 配信設定を読み込む
+    fn from_env() -> Self {
+        Self {
+            poll_interval_ms: env::var("WEBHOOK_POLL_INTERVAL_MS")
+                .unwrap_or_else(|_| "5000".to_string())
+                .parse()
+                .expect("WEBHOOK_POLL_INTERVAL_MS は有効な数値である必要があります"),
+            batch_size: env::var("WEBHOOK_BATCH_SIZE")
+                .unwrap_or_else(|_| "20".to_string())
+                .parse()
+                .expect("WEBHOOK_BATCH_SIZE は有効な数値である必要があります"),
+            timeout_secs: env::var("WEBHOOK_TIMEOUT_SECS")
+                .unwrap_or_else(|_| "10".to_string())
+                .parse()
+                .expect("WEBHOOK_TIMEOUT_SECS は有効な数値である必要があります"),
+            allow_insecure_destinations: env::var("ENVIRONMENT").is_ok_and(|v| v == "development"),
+        }
+    }
+}
+
+impl DraftPurgeConfig {
+    /// 環境変数から下書き自動パージ設定を読み込む
+    fn from_env() -> Self {
+        Self {
+            after_days:       env::var("DRAFT_PURGE_AFTER_DAYS").ok().map(|v| {
+                v.parse()
+                    .expect("DRAFT_PURGE_AFTER_DAYS は有効な数値である必要があります")
+            }),
+            poll_interval_ms: env::var("DRAFT_PURGE_POLL_INTERVAL_MS")
+                .unwrap_or_else(|_| "3600000".to_string())
+                .parse()
+                .expect("DRAFT_PURGE_POLL_INTERVAL_MS は有効な数値である必要があります"),
+            batch_size:       env::var("DRAFT_PURGE_BATCH_SIZE")
+                .unwrap_or_else(|_| "100".to_string())
+                .parse()
+                .expect("DRAFT_PURGE_BATCH_SIZE は有効な数値である必要があります"),
+        }
+    }
+}
+
+impl InboxConfig {
+    /// 環境変数からアプリ内通知の保持設定を読み込む
+    fn from_env() -> Self {
+        Self {
+            retention_days:   env::var("INBOX_RETENTION_DAYS")
+                .unwrap_or_else(|_| "90".to_string())
+                .parse()
+                .expect("INBOX_RETENTION_DAYS は有効な数値である必要があります"),
+            poll_interval_ms: env::var("INBOX_PURGE_POLL_INTERVAL_MS")
+                .unwrap_or_else(|_| "3600000".to_string())
+                .parse()
+                .expect("INBOX_PURGE_POLL_INTERVAL_MS は有効な数値である必要があります"),
+            batch_size:       env::var("INBOX_PURGE_BATCH_SIZE")
+                .unwrap_or_else(|_| "1000".to_string())
+                .parse()
+                .expect("INBOX_PURGE_BATCH_SIZE は有効な数値である必要があります"),
+        }
+    }
+}
+
+impl NotificationDigestConfig {
+    /// 環境変数から通知ダイジェストの送信設定を読み込む
+    fn from_env() -> Self {
+        Self {
+            send_at:          NaiveTime::parse_from_str(
+                &env::var("NOTIFICATION_DIGEST_SEND_AT").unwrap_or_else(|_| "00:00".to_string()),
+                "%H:%M",
+            )
+            .expect("NOTIFICATION_DIGEST_SEND_AT は HH:MM 形式である必要があります"),
+            poll_interval_ms: env::var("NOTIFICATION_DIGEST_POLL_INTERVAL_MS")
+                .unwrap_or_else(|_| "60000".to_string())
+                .parse()
+                .expect("NOTIFICATION_DIGEST_POLL_INTERVAL_MS は有効な数値である必要があります"),
+            batch_size:       env::var("NOTIFICATION_DIGEST_BATCH_SIZE")
+                .unwrap_or_else(|_| "100".to_string())
+                .parse()
+                .expect("NOTIFICATION_DIGEST_BATCH_SIZE は有効な数値である必要があります"),
+        }
+    }
+}
+
+impl NotificationRetryConfig {
+    /// 環境変数から通知メールの再送設定を読み込む
+    fn from_env() -> Self {
+        Self {
+            poll_interval_ms: env::var("NOTIFICATION_RETRY_POLL_INTERVAL_MS")
+                .unwrap_or_else(|_| "30000".to_string())
+                .parse()
+                .expect("NOTIFICATION_RETRY_POLL_INTERVAL_MS は有効な数値である必要があります"),
+            batch_size:       env::var("NOTIFICATION_RETRY_BATCH_SIZE")
+                .unwrap_or_else(|_| "50".to_string())
+                .parse()
+                .expect("NOTIFICATION_RETRY_BATCH_SIZE は有効な数値である必要があります"),
+        }
+    }
+}
+
+impl ScheduleConfig {
+    /// 環境変数からワークフロースケジュール実行設定を読み込む
+    fn from_env() -> Self {
+        Self {
+            poll_interval_ms: env::var("SCHEDULE_POLL_INTERVAL_MS")
+                .unwrap_or_else(|_| "60000".to_string())
+                .parse()
+                .expect("SCHEDULE_POLL_INTERVAL_MS は有効な数値である必要があります"),
+            batch_size:       env::var("SCHEDULE_BATCH_SIZE")
+                .unwrap_or_else(|_| "20".to_string())
+                .parse()
+                .expect("SCHEDULE_BATCH_SIZE は有効な数値である必要があります"),
+        }
+    }
+}
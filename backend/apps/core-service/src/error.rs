@@ -1,12 +1,16 @@
 //! # Core Service エラー定義
 //!
 //! Core Service 固有のエラーと、HTTP レスポンスへの変換を定義する。
+//!
+//! 4xx のエラーは [`Message`] を持ち、レスポンスには日本語の `detail` と、
+//! BFF がロケールに合わせて展開するためのメッセージコード・パラメータを含める。
 
 use axum::{
     Json,
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use ringiflow_domain::message::Message;
 use ringiflow_shared::ErrorResponse;
 use thiserror::Error;
 
@@ -15,19 +19,19 @@ use thiserror::Error;
 pub enum CoreError {
     /// リソースが見つからない
     #[error("リソースが見つかりません: {0}")]
-    NotFound(String),
+    NotFound(Message),
 
     /// 不正なリクエスト
     #[error("不正なリクエスト: {0}")]
-    BadRequest(String),
+    BadRequest(Message),
 
     /// 権限不足
     #[error("権限がありません: {0}")]
-    Forbidden(String),
+    Forbidden(Message),
 
     /// 競合（楽観的ロック失敗）
     #[error("競合が発生しました: {0}")]
-    Conflict(String),
+    Conflict(Message),
 
     /// データベースエラー
     #[error("データベースエラー: {0}")]
@@ -41,19 +45,22 @@ pub enum CoreError {
 impl IntoResponse for CoreError {
     fn into_response(self) -> Response {
         let (status, error_response) = match &self {
-            CoreError::NotFound(msg) => {
-                (StatusCode::NOT_FOUND, ErrorResponse::not_found(msg.clone()))
-            }
-            CoreError::BadRequest(msg) => (
+            CoreError::NotFound(message) => (
+                StatusCode::NOT_FOUND,
+                with_message(ErrorResponse::not_found, message),
+            ),
+            CoreError::BadRequest(message) => (
                 StatusCode::BAD_REQUEST,
-                ErrorResponse::bad_request(msg.clone()),
+                with_message(ErrorResponse::bad_request, message),
+            ),
+            CoreError::Forbidden(message) => (
+                StatusCode::FORBIDDEN,
+                with_message(ErrorResponse::forbidden, message),
+            ),
+            CoreError::Conflict(message) => (
+                StatusCode::CONFLICT,
+                with_message(ErrorResponse::conflict, message),
             ),
-            CoreError::Forbidden(msg) => {
-                (StatusCode::FORBIDDEN, ErrorResponse::forbidden(msg.clone()))
-            }
-            CoreError::Conflict(msg) => {
-                (StatusCode::CONFLICT, ErrorResponse::conflict(msg.clone()))
-            }
             CoreError::Database(e) => {
                 tracing::error!(
                     error.category = "infrastructure",
@@ -86,3 +93,38 @@ impl IntoResponse for CoreError {
         (status, Json(error_response)).into_response()
     }
 }
+
+/// `message` を日本語の `detail` に展開し、メッセージコードとパラメータを付ける
+pub(crate) fn with_message(
+    constructor: fn(String) -> ErrorResponse,
+    message: &Message,
+) -> ErrorResponse {
+    constructor(message.to_string()).with_message(message.code(), message.params().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::to_bytes;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_レスポンスにメッセージコードとパラメータを含める() {
+        let error = CoreError::BadRequest(
+            Message::new("too-long")
+                .with("field", "user-name")
+                .with("max", 100),
+        );
+
+        let response = error.into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: ErrorResponse = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body.detail, "ユーザー名は 100 文字以内である必要があります");
+        assert_eq!(body.message_code.as_deref(), Some("too-long"));
+        assert_eq!(body.params["field"], "user-name");
+        assert_eq!(body.params["max"], "100");
+    }
+}
@@ -17,10 +17,18 @@ pub mod dashboard;
 pub mod document;
 pub mod folder;
 pub mod health;
+pub mod notification;
+pub mod notification_log;
+pub mod notification_template;
+pub mod proxy_grant;
 pub mod role;
 pub mod task;
+pub mod tenant;
+pub mod webhook;
 pub mod workflow;
 pub mod workflow_definition;
+pub mod workflow_schedule;
+pub mod workflow_search;
 
 pub use auth::{
     UserState,
@@ -30,6 +38,7 @@ pub use auth::{
     get_user_by_email,
     list_users,
     update_user,
+    update_user_locale,
     update_user_status,
 };
 pub use dashboard::{DashboardState, get_dashboard_stats};
@@ -44,26 +53,79 @@ pub use document::{
 };
 pub use folder::{FolderState, create_folder, delete_folder, list_folders, update_folder};
 pub use health::{ReadinessState, health_check, readiness_check};
+pub use notification::{
+    NotificationState,
+    get_unread_notification_count,
+    list_notification_preferences,
+    list_notifications,
+    mark_all_notifications_read,
+    mark_notification_read,
+    update_notification_preferences,
+};
+pub use notification_log::{
+    NotificationLogState,
+    list_failed_notification_logs,
+    resend_notification_log,
+};
+pub use notification_template::{
+    NotificationTemplateState,
+    delete_notification_template,
+    get_notification_template,
+    list_notification_templates,
+    preview_notification_template,
+    save_notification_template,
+    validate_notification_template,
+};
+pub use proxy_grant::{ProxyGrantState, create_proxy_grant, delete_proxy_grant, list_proxy_grants};
 pub use role::{RoleState, create_role, delete_role, get_role, list_roles, update_role};
 pub use task::{TaskState, get_task, get_task_by_display_numbers, list_my_tasks};
+pub use tenant::{TenantState, get_tenant_settings, update_tenant_settings};
+pub use webhook::{
+    WebhookState,
+    create_webhook,
+    delete_webhook,
+    get_webhook,
+    list_webhook_deliveries,
+    list_webhooks,
+    send_test_webhook,
+    update_webhook,
+};
 pub use workflow::{
     WorkflowState,
     approve_step,
     approve_step_by_display_number,
+    bulk_decide_steps,
     create_workflow,
+    delete_comment,
+    delete_workflow,
+    diff_submissions,
+    duplicate_workflow,
+    edit_comment,
+    force_cancel_workflow,
+    force_complete_workflow,
     get_workflow,
     get_workflow_by_display_number,
+    list_activities,
+    list_comment_revisions,
     list_comments,
+    list_form_data_changes,
     list_my_workflows,
+    list_submissions,
+    list_watchers,
     post_comment,
+    reassign_current_step,
     reject_step,
     reject_step_by_display_number,
     request_changes_step,
     request_changes_step_by_display_number,
     resubmit_workflow,
     resubmit_workflow_by_display_number,
+    retract_approval,
+    retract_approval_by_display_number,
     submit_workflow,
     submit_workflow_by_display_number,
+    unwatch_workflow,
+    watch_workflow,
 };
 pub use workflow_definition::{
     WorkflowDefinitionState,
@@ -76,3 +138,11 @@ pub use workflow_definition::{
     update_definition,
     validate_definition,
 };
+pub use workflow_schedule::{
+    WorkflowScheduleState,
+    create_workflow_schedule,
+    delete_workflow_schedule,
+    list_workflow_schedules,
+    update_workflow_schedule,
+};
+pub use workflow_search::{WorkflowSearchState, search_all_workflows, search_workflows};
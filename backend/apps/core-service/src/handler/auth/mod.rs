@@ -7,6 +7,7 @@
 //! - `GET /internal/users` - テナント内のアクティブユーザー一覧
 //! - `GET /internal/users/by-email` - メールアドレスでユーザーを検索
 //! - `GET /internal/users/{user_id}` - ユーザー情報を取得
+//! - `PUT /internal/users/{user_id}/locale` - ユーザーのロケールを変更
 //!
 //! 詳細: [08_AuthService設計.md](../../../../docs/40_詳細設計書/08_AuthService設計.md)
 
@@ -19,6 +20,8 @@ use axum::{
     response::IntoResponse,
 };
 use ringiflow_domain::{
+    locale::Locale,
+    message::Message,
     role::{Role, RoleId},
     tenant::TenantId,
     user::{Email, User, UserId, UserStatus},
@@ -30,8 +33,14 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
-    error::CoreError,
-    usecase::user::{CreateUserInput, UpdateUserInput, UpdateUserStatusInput, UserUseCaseImpl},
+    error::{CoreError, with_message},
+    usecase::user::{
+        CreateUserInput,
+        UpdateUserInput,
+        UpdateUserLocaleInput,
+        UpdateUserStatusInput,
+        UserUseCaseImpl,
+    },
 };
 
 /// ユーザー API の共有状態
@@ -58,6 +67,8 @@ pub struct UserResponse {
     pub email:     String,
     pub name:      String,
     pub status:    String,
+    /// ユーザーが選んだロケール（未設定ならテナントの既定ロケールに従う）
+    pub locale:    Option<String>,
 }
 
 impl From<&User> for UserResponse {
@@ -68,6 +79,7 @@ impl From<&User> for UserResponse {
             email:     user.email().as_str().to_string(),
             name:      user.name().as_str().to_string(),
             status:    user.status().to_string(),
+            locale:    user.locale().map(|locale| locale.to_string()),
         }
     }
 }
@@ -79,6 +91,8 @@ pub struct UserWithPermissionsData {
     pub tenant_name: String,
     pub roles:       Vec<String>,
     pub permissions: Vec<String>,
+    /// 実際に使うロケール（ユーザーのロケール → テナントの既定ロケール）
+    pub locale:      String,
 }
 
 /// User と Role リストから UserWithPermissionsData を構築する
@@ -86,6 +100,7 @@ fn build_user_with_permissions(
     user: &User,
     roles: &[Role],
     tenant_name: String,
+    tenant_default_locale: Locale,
 ) -> UserWithPermissionsData {
     let permissions: Vec<String> = roles
         .iter()
@@ -97,6 +112,7 @@ fn build_user_with_permissions(
         tenant_name,
         roles: roles.iter().map(|r| r.name().to_string()).collect(),
         permissions,
+        locale: user.effective_locale(tenant_default_locale).to_string(),
     }
 }
 
@@ -154,6 +170,15 @@ pub struct UpdateUserRequest {
     pub role_id: Option<Uuid>,
 }
 
+/// ユーザーロケール変更リクエスト
+///
+/// `locale` が `null` の場合はテナントの既定ロケールに従う。
+#[derive(Debug, Deserialize)]
+pub struct UpdateUserLocaleRequest {
+    pub tenant_id: Uuid,
+    pub locale:    Option<String>,
+}
+
 /// ユーザーステータス変更リクエスト
 #[derive(Debug, Deserialize)]
 pub struct UpdateUserStatusRequest {
@@ -201,7 +226,10 @@ pub async fn list_users(
             Err(_) => {
                 return (
                     StatusCode::BAD_REQUEST,
-                    Json(ErrorResponse::bad_request("不正なステータス値です")),
+                    Json(with_message(
+                        ErrorResponse::bad_request,
+                        &Message::new("invalid-status-value"),
+                    )),
                 )
                     .into_response();
             }
@@ -277,8 +305,9 @@ pub async fn get_user_by_email(
     let Ok(email) = Email::new(&query.email) else {
         return (
             StatusCode::BAD_REQUEST,
-            Json(ErrorResponse::validation_error(
-                "メールアドレスの形式が不正です",
+            Json(with_message(
+                ErrorResponse::validation_error,
+                &Message::new("invalid-email-format"),
             )),
         )
             .into_response();
@@ -298,7 +327,10 @@ pub async fn get_user_by_email(
         }
         Ok(None) => (
             StatusCode::NOT_FOUND,
-            Json(ErrorResponse::not_found("ユーザーが見つかりません")),
+            Json(with_message(
+                ErrorResponse::not_found,
+                &Message::new("user-not-found"),
+            )),
         )
             .into_response(),
         Err(e) => {
@@ -331,7 +363,10 @@ pub async fn get_user(
         Ok(None) => {
             return (
                 StatusCode::NOT_FOUND,
-                Json(ErrorResponse::not_found("ユーザーが見つかりません")),
+                Json(with_message(
+                    ErrorResponse::not_found,
+                    &Message::new("user-not-found"),
+                )),
             )
                 .into_response();
         }
@@ -345,9 +380,9 @@ pub async fn get_user(
         }
     };
 
-    // テナント名を取得
-    let tenant_name = match state.tenant_repository.find_by_id(user.tenant_id()).await {
-        Ok(Some(tenant)) => tenant.name().to_string(),
+    // テナントを取得（テナント名・既定ロケール）
+    let tenant = match state.tenant_repository.find_by_id(user.tenant_id()).await {
+        Ok(Some(tenant)) => tenant,
         Ok(None) => {
             tracing::error!(
                 "テナントが見つかりません: user_id={}, tenant_id={}",
@@ -370,7 +405,12 @@ pub async fn get_user(
         }
     };
 
-    let response = build_user_with_permissions(&user, &roles, tenant_name);
+    let response = build_user_with_permissions(
+        &user,
+        &roles,
+        tenant.name().to_string(),
+        tenant.default_locale(),
+    );
     (StatusCode::OK, Json(response)).into_response()
 }
 
@@ -395,8 +435,8 @@ pub async fn create_user(
     State(state): State<Arc<UserState>>,
     Json(req): Json<CreateUserRequest>,
 ) -> Result<impl IntoResponse, CoreError> {
-    let email = Email::new(&req.email).map_err(|e| CoreError::BadRequest(e.to_string()))?;
-    let name = UserName::new(&req.name).map_err(|e| CoreError::BadRequest(e.to_string()))?;
+    let email = Email::new(&req.email).map_err(|e| CoreError::BadRequest(e.into_message()))?;
+    let name = UserName::new(&req.name).map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
     let input = CreateUserInput {
         tenant_id: TenantId::from_uuid(req.tenant_id),
@@ -443,30 +483,30 @@ pub async fn get_user_by_display_number(
 ) -> Result<impl IntoResponse, CoreError> {
     let tenant_id = TenantId::from_uuid(query.tenant_id);
     let display_number =
-        DisplayNumber::new(display_number).map_err(|e| CoreError::BadRequest(e.to_string()))?;
+        DisplayNumber::new(display_number).map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
     let user = state
         .user_repository
         .find_by_display_number(&tenant_id, display_number)
         .await?
-        .ok_or_else(|| CoreError::NotFound("ユーザーが見つかりません".to_string()))?;
+        .ok_or_else(|| CoreError::NotFound(Message::new("user-not-found")))?;
 
     // ロール・権限情報を取得
     let (_, roles) = state
         .user_repository
         .find_with_roles(user.id())
         .await?
-        .ok_or_else(|| CoreError::NotFound("ユーザーが見つかりません".to_string()))?;
+        .ok_or_else(|| CoreError::NotFound(Message::new("user-not-found")))?;
 
-    // テナント名を取得
-    let tenant_name = state
+    // テナント名・既定ロケールを取得
+    let (tenant_name, tenant_default_locale) = state
         .tenant_repository
         .find_by_id(user.tenant_id())
         .await?
-        .map(|t| t.name().to_string())
+        .map(|t| (t.name().to_string(), t.default_locale()))
         .unwrap_or_default();
 
-    let response = build_user_with_permissions(&user, &roles, tenant_name);
+    let response = build_user_with_permissions(&user, &roles, tenant_name, tenant_default_locale);
 
     Ok((StatusCode::OK, Json(response)))
 }
@@ -499,7 +539,7 @@ pub async fn update_user(
         .name
         .map(|n| UserName::new(&n))
         .transpose()
-        .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+        .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
     let input = UpdateUserInput {
         user_id: UserId::from_uuid(user_id),
@@ -541,7 +581,7 @@ pub async fn update_user_status(
     let status: UserStatus = req
         .status
         .parse()
-        .map_err(|_| CoreError::BadRequest("不正なステータス値です".to_string()))?;
+        .map_err(|_| CoreError::BadRequest(Message::new("invalid-status-value")))?;
 
     let input = UpdateUserStatusInput {
         user_id: UserId::from_uuid(user_id),
@@ -556,5 +596,47 @@ pub async fn update_user_status(
     Ok((StatusCode::OK, Json(response)))
 }
 
+/// PUT /internal/users/{user_id}/locale
+///
+/// ユーザーのロケールを変更する。
+///
+/// ## パスパラメータ
+///
+/// - `user_id`: ユーザー ID
+///
+/// ## リクエストボディ
+///
+/// - `tenant_id`: テナント ID
+/// - `locale`: ロケール（"ja", "en"）。`null` の場合はテナントの既定ロケールに従う
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 更新後のユーザー情報
+/// - `400 Bad Request`: 不正なロケール
+/// - `404 Not Found`: ユーザーが見つからない
+#[tracing::instrument(skip_all, fields(%user_id))]
+pub async fn update_user_locale(
+    State(state): State<Arc<UserState>>,
+    Path(user_id): Path<Uuid>,
+    Json(req): Json<UpdateUserLocaleRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let locale = req
+        .locale
+        .map(|locale| locale.parse::<Locale>())
+        .transpose()
+        .map_err(|_| CoreError::BadRequest(Message::new("unsupported-locale")))?;
+
+    let input = UpdateUserLocaleInput {
+        user_id: UserId::from_uuid(user_id),
+        tenant_id: TenantId::from_uuid(req.tenant_id),
+        locale,
+    };
+
+    let user = state.usecase.update_user_locale(input).await?;
+
+    let response = UserResponse::from(&user);
+    Ok((StatusCode::OK, Json(response)))
+}
+
 #[cfg(test)]
 mod tests;
@@ -5,12 +5,13 @@ use axum::{
     Router,
     body::Body,
     http::{Method, Request},
-    routing::get,
+    routing::{get, put},
 };
 use ringiflow_domain::{
     clock::Clock,
     role::{Permission, Role, RoleId},
     tenant::{Tenant, TenantId, TenantName},
+    time_zone::TimeZone,
     user::{Email, User, UserId, UserStatus},
     value_objects::{DisplayIdEntityType, DisplayNumber, UserName},
 };
@@ -87,6 +88,18 @@ impl UserRepository for StubUserRepository {
         todo!()
     }
 
+    async fn update_locale(&self, _user: &User) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn find_effective_locale(
+        &self,
+        _tenant_id: &TenantId,
+        _user_id: &UserId,
+    ) -> Result<Option<Locale>, InfraError> {
+        todo!()
+    }
+
     async fn find_by_display_number(
         &self,
         _tenant_id: &TenantId,
@@ -174,6 +187,10 @@ impl TenantRepository for StubTenantRepository {
     async fn find_by_id(&self, _id: &TenantId) -> Result<Option<Tenant>, InfraError> {
         Ok(self.tenant.clone())
     }
+
+    async fn update_settings(&self, _tenant: &Tenant) -> Result<(), InfraError> {
+        todo!()
+    }
 }
 
 struct StubDisplayIdCounterRepository;
@@ -208,6 +225,7 @@ fn create_active_user(tenant_id: &TenantId) -> User {
         UserName::new("Test User").unwrap(),
         UserStatus::Active,
         None,
+        None,
         chrono::Utc::now(),
         chrono::Utc::now(),
     )
@@ -227,7 +245,12 @@ fn create_user_role() -> Role {
 }
 
 fn create_tenant(tenant_id: &TenantId) -> Tenant {
-    Tenant::from_db(tenant_id.clone(), TenantName::new("Test Tenant").unwrap())
+    Tenant::from_db(
+        tenant_id.clone(),
+        TenantName::new("Test Tenant").unwrap(),
+        Locale::En,
+        TimeZone::UTC,
+    )
 }
 
 fn create_test_app(user_repo: StubUserRepository, tenant_repo: StubTenantRepository) -> Router {
@@ -246,6 +269,7 @@ fn create_test_app(user_repo: StubUserRepository, tenant_repo: StubTenantReposit
     Router::new()
         .route("/internal/users/by-email", get(get_user_by_email))
         .route("/internal/users/{user_id}", get(get_user))
+        .route("/internal/users/{user_id}/locale", put(update_user_locale))
         .with_state(state)
 }
 
@@ -362,6 +386,9 @@ async fn test_get_user_ユーザー情報を取得できる() {
     let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
 
     assert_eq!(json["tenant_name"], "Test Tenant");
+    // ユーザーがロケールを選んでいなければテナントの既定ロケールを返す
+    assert_eq!(json["user"]["locale"], serde_json::Value::Null);
+    assert_eq!(json["locale"], "en");
 }
 
 #[tokio::test]
@@ -417,7 +444,7 @@ fn test_build_user_with_permissions_ロールありで権限が集約される()
     let user = create_active_user(&tenant_id);
     let roles = vec![create_user_role()];
 
-    let result = build_user_with_permissions(&user, &roles, "Test Tenant".to_string());
+    let result = build_user_with_permissions(&user, &roles, "Test Tenant".to_string(), Locale::Ja);
 
     assert_eq!(result.tenant_name, "Test Tenant");
     assert_eq!(result.roles, vec!["user"]);
@@ -431,8 +458,109 @@ fn test_build_user_with_permissions_ロール空で空リスト() {
     let tenant_id = TenantId::new();
     let user = create_active_user(&tenant_id);
 
-    let result = build_user_with_permissions(&user, &[], "Test Tenant".to_string());
+    let result = build_user_with_permissions(&user, &[], "Test Tenant".to_string(), Locale::Ja);
 
     assert!(result.roles.is_empty());
     assert!(result.permissions.is_empty());
 }
+
+#[test]
+fn test_build_user_with_permissions_ユーザーのロケールをテナントの既定より優先する() {
+    let tenant_id = TenantId::new();
+    let user = create_active_user(&tenant_id).with_locale(Some(Locale::En), chrono::Utc::now());
+
+    let result = build_user_with_permissions(&user, &[], "Test Tenant".to_string(), Locale::Ja);
+
+    assert_eq!(result.locale, "en");
+    assert_eq!(result.user.locale.as_deref(), Some("en"));
+}
+
+// --- update_user_locale テスト ---
+
+#[tokio::test]
+async fn test_update_user_locale_ロケールを変更できる() {
+    // Given
+    let tenant_id = TenantId::new();
+    let user = create_active_user(&tenant_id);
+    let user_id = *user.id().as_uuid();
+    let sut = create_test_app(
+        StubUserRepository::with_user(user, vec![]),
+        StubTenantRepository::empty(),
+    );
+
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/internal/users/{}/locale", user_id))
+        .header("content-type", "application/json")
+        .body(Body::from(
+            serde_json::json!({ "tenant_id": tenant_id.as_uuid(), "locale": "en" }).to_string(),
+        ))
+        .unwrap();
+
+    // When
+    let response = sut.oneshot(request).await.unwrap();
+
+    // Then
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(json["locale"], "en");
+}
+
+#[tokio::test]
+async fn test_update_user_locale_不正なロケールで400() {
+    // Given
+    let tenant_id = TenantId::new();
+    let user = create_active_user(&tenant_id);
+    let user_id = *user.id().as_uuid();
+    let sut = create_test_app(
+        StubUserRepository::with_user(user, vec![]),
+        StubTenantRepository::empty(),
+    );
+
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/internal/users/{}/locale", user_id))
+        .header("content-type", "application/json")
+        .body(Body::from(
+            serde_json::json!({ "tenant_id": tenant_id.as_uuid(), "locale": "fr" }).to_string(),
+        ))
+        .unwrap();
+
+    // When
+    let response = sut.oneshot(request).await.unwrap();
+
+    // Then
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_update_user_locale_他テナントのユーザーは404() {
+    // Given
+    let user = create_active_user(&TenantId::new());
+    let user_id = *user.id().as_uuid();
+    let sut = create_test_app(
+        StubUserRepository::with_user(user, vec![]),
+        StubTenantRepository::empty(),
+    );
+
+    let request = Request::builder()
+        .method(Method::PUT)
+        .uri(format!("/internal/users/{}/locale", user_id))
+        .header("content-type", "application/json")
+        .body(Body::from(
+            serde_json::json!({ "tenant_id": TenantId::new().as_uuid(), "locale": null })
+                .to_string(),
+        ))
+        .unwrap();
+
+    // When
+    let response = sut.oneshot(request).await.unwrap();
+
+    // Then
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
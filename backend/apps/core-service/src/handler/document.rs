@@ -24,6 +24,7 @@ use uuid::Uuid;
 
 use crate::{
     error::CoreError,
+    handler::workflow::ViewerQuery,
     usecase::document::{DocumentUseCaseImpl, RequestUploadUrlInput, SoftDeleteInput},
 };
 
@@ -43,6 +44,7 @@ pub struct RequestUploadUrlRequest {
     pub content_length: i64,
     pub folder_id: Option<Uuid>,
     pub workflow_instance_id: Option<Uuid>,
+    pub comment_id: Option<Uuid>,
     pub uploaded_by: Uuid,
 }
 
@@ -68,6 +70,8 @@ pub struct DocumentDto {
     pub content_type: String,
     pub size:         i64,
     pub status:       String,
+    /// 添付先のコメント ID（コメント添付の場合のみ）
+    pub comment_id:   Option<Uuid>,
     pub created_at:   String,
 }
 
@@ -86,12 +90,6 @@ pub struct DeleteDocumentQuery {
     pub is_tenant_admin: bool,
 }
 
-/// テナント ID クエリパラメータ（汎用）
-#[derive(Debug, Deserialize)]
-pub struct TenantQuery {
-    pub tenant_id: Uuid,
-}
-
 /// ドキュメント一覧クエリパラメータ
 #[derive(Debug, Deserialize)]
 pub struct ListDocumentsQuery {
@@ -109,6 +107,8 @@ pub struct ListDocumentsQuery {
 ///
 /// - `200 OK`: upload URL と document_id
 /// - `400 Bad Request`: バリデーションエラー（Content-Type、サイズ、コンテキスト）
+/// - `403 Forbidden`: コメント添付でコメント投稿者以外
+/// - `404 Not Found`: コメント添付でコメントが存在しない
 #[tracing::instrument(skip_all)]
 pub async fn request_upload_url(
     State(state): State<Arc<DocumentState>>,
@@ -121,6 +121,7 @@ pub async fn request_upload_url(
         content_length: req.content_length,
         folder_id: req.folder_id,
         workflow_instance_id: req.workflow_instance_id,
+        comment_id: req.comment_id,
         uploaded_by: req.uploaded_by,
     };
 
@@ -165,6 +166,10 @@ pub async fn confirm_upload(
         content_type: document.content_type().to_string(),
         size:         document.size(),
         status:       document.status().to_string(),
+        comment_id:   document
+            .upload_context()
+            .comment_id()
+            .map(|id| *id.as_uuid()),
         created_at:   document.created_at().to_rfc3339(),
     };
 
@@ -180,19 +185,19 @@ pub async fn confirm_upload(
 ///
 /// - `200 OK`: download_url と expires_in
 /// - `400 Bad Request`: ステータスが active ではない
-/// - `404 Not Found`: ドキュメントが見つからない
+/// - `404 Not Found`: ドキュメントが見つからない / 添付先のワークフローを閲覧できない
 #[tracing::instrument(skip_all, fields(%document_id))]
 pub async fn generate_download_url(
     State(state): State<Arc<DocumentState>>,
     Path(document_id): Path<Uuid>,
-    Query(query): Query<TenantQuery>,
+    Query(query): Query<ViewerQuery>,
 ) -> Result<impl IntoResponse, CoreError> {
     let document_id = ringiflow_domain::document::DocumentId::from_uuid(document_id);
     let tenant_id = ringiflow_domain::tenant::TenantId::from_uuid(query.tenant_id);
 
     let output = state
         .usecase
-        .generate_download_url(&document_id, &tenant_id)
+        .generate_download_url(&document_id, &tenant_id, &query.viewer())
         .await?;
 
     let dto = DownloadUrlDto {
@@ -257,6 +262,7 @@ pub async fn list_documents(
             content_type: doc.content_type().to_string(),
             size:         doc.size(),
             status:       doc.status().to_string(),
+            comment_id:   doc.upload_context().comment_id().map(|id| *id.as_uuid()),
             created_at:   doc.created_at().to_rfc3339(),
         })
         .collect();
@@ -267,16 +273,17 @@ pub async fn list_documents(
 
 /// GET /internal/workflows/{workflow_instance_id}/attachments
 ///
-/// ワークフロー添付ファイル一覧を取得する。
+/// ワークフロー添付ファイル一覧を取得する。コメントへの添付も含む。
 ///
 /// ## レスポンス
 ///
 /// - `200 OK`: ドキュメント配列
+/// - `404 Not Found`: インスタンスが存在しない、または閲覧権限がない
 #[tracing::instrument(skip_all, fields(%workflow_instance_id))]
 pub async fn list_workflow_attachments(
     State(state): State<Arc<DocumentState>>,
     Path(workflow_instance_id): Path<Uuid>,
-    Query(query): Query<TenantQuery>,
+    Query(query): Query<ViewerQuery>,
 ) -> Result<impl IntoResponse, CoreError> {
     let workflow_instance_id =
         ringiflow_domain::workflow::WorkflowInstanceId::from_uuid(workflow_instance_id);
@@ -284,7 +291,7 @@ pub async fn list_workflow_attachments(
 
     let documents = state
         .usecase
-        .list_workflow_attachments(&workflow_instance_id, &tenant_id)
+        .list_workflow_attachments(&workflow_instance_id, &tenant_id, &query.viewer())
         .await?;
 
     let dtos: Vec<DocumentDto> = documents
@@ -295,6 +302,7 @@ pub async fn list_workflow_attachments(
             content_type: doc.content_type().to_string(),
             size:         doc.size(),
             status:       doc.status().to_string(),
+            comment_id:   doc.upload_context().comment_id().map(|id| *id.as_uuid()),
             created_at:   doc.created_at().to_rfc3339(),
         })
         .collect();
@@ -321,8 +329,15 @@ mod tests {
         folder::FolderId,
         tenant::TenantId,
         user::UserId,
-        value_objects::{DisplayNumber, Version},
+        value_objects::{DisplayNumber, Version, WorkflowName},
         workflow::{
+            CommentBody,
+            NewWorkflowComment,
+            NewWorkflowDefinition,
+            WorkflowActivityType,
+            WorkflowComment,
+            WorkflowCommentId,
+            WorkflowDefinition,
             WorkflowDefinitionId,
             WorkflowInstance,
             WorkflowInstanceId,
@@ -333,7 +348,23 @@ mod tests {
     use ringiflow_infra::{
         InfraError,
         TxContext,
-        repository::{DocumentRepository, WorkflowInstanceRepository},
+        fake::{
+            FakeWorkflowActivityRepository,
+            FakeWorkflowCommentRepository,
+            FakeWorkflowDefinitionRepository,
+            FakeWorkflowStepRepository,
+            FakeWorkflowWatcherRepository,
+        },
+        repository::{
+            DocumentRepository,
+            KeysetPage,
+            WorkflowActivityRepository,
+            WorkflowCommentRepository,
+            WorkflowDefinitionRepository,
+            WorkflowInstanceRepository,
+            WorkflowStepRepository,
+            WorkflowWatcherRepository,
+        },
         s3::S3Client,
     };
     use tower::ServiceExt;
@@ -402,6 +433,16 @@ mod tests {
             Ok((count, total_size))
         }
 
+        async fn count_and_total_size_by_comment(
+            &self,
+            _comment_id: &WorkflowCommentId,
+            _tenant_id: &TenantId,
+        ) -> Result<(usize, i64), InfraError> {
+            let count = self.documents.len();
+            let total_size: i64 = self.documents.iter().map(|d| d.size()).sum();
+            Ok((count, total_size))
+        }
+
         async fn soft_delete(
             &self,
             _id: &DocumentId,
@@ -426,6 +467,31 @@ mod tests {
         ) -> Result<Vec<Document>, InfraError> {
             Ok(self.documents.clone())
         }
+
+        async fn list_by_comments(
+            &self,
+            _comment_ids: &[WorkflowCommentId],
+            _tenant_id: &TenantId,
+        ) -> Result<Vec<Document>, InfraError> {
+            Ok(Vec::new())
+        }
+
+        async fn delete_by_workflow(
+            &self,
+            _tx: &mut TxContext,
+            _workflow_instance_id: &WorkflowInstanceId,
+            _tenant_id: &TenantId,
+        ) -> Result<Vec<String>, InfraError> {
+            unimplemented!()
+        }
+
+        async fn exists_by_s3_key(
+            &self,
+            _s3_key: &str,
+            _tenant_id: &TenantId,
+        ) -> Result<bool, InfraError> {
+            unimplemented!()
+        }
     }
 
     struct StubWorkflowInstanceRepository {
@@ -483,7 +549,18 @@ mod tests {
             &self,
             _tenant_id: &TenantId,
             _user_id: &UserId,
-        ) -> Result<Vec<WorkflowInstance>, InfraError> {
+            _cursor: Option<&str>,
+            _limit: i64,
+        ) -> Result<KeysetPage<WorkflowInstance>, InfraError> {
+            unimplemented!()
+        }
+
+        async fn count_by_initiated_by(
+            &self,
+            _tenant_id: &TenantId,
+            _user_id: &UserId,
+            _status: WorkflowInstanceStatus,
+        ) -> Result<i64, InfraError> {
             unimplemented!()
         }
 
@@ -502,6 +579,23 @@ mod tests {
         ) -> Result<Option<WorkflowInstance>, InfraError> {
             unimplemented!()
         }
+
+        async fn delete_draft(
+            &self,
+            _tx: &mut TxContext,
+            _id: &WorkflowInstanceId,
+            _tenant_id: &TenantId,
+        ) -> Result<bool, InfraError> {
+            unimplemented!()
+        }
+
+        async fn find_stale_drafts(
+            &self,
+            _updated_before: DateTime<Utc>,
+            _limit: i64,
+        ) -> Result<Vec<WorkflowInstance>, InfraError> {
+            unimplemented!()
+        }
     }
 
     struct StubS3Client {
@@ -546,6 +640,10 @@ mod tests {
         async fn head_object(&self, s3_key: &str) -> Result<bool, InfraError> {
             Ok(self.existing_keys.contains(s3_key))
         }
+
+        async fn delete_object(&self, _s3_key: &str) -> Result<(), InfraError> {
+            unimplemented!()
+        }
     }
 
     struct StubClock;
@@ -562,13 +660,82 @@ mod tests {
         repo: StubDocumentRepository,
         workflow_repo: StubWorkflowInstanceRepository,
         s3_client: StubS3Client,
+    ) -> Router {
+        create_test_app_with_activity_repo(
+            repo,
+            workflow_repo,
+            s3_client,
+            FakeWorkflowActivityRepository::new(),
+        )
+    }
+
+    fn create_test_app_with_activity_repo(
+        repo: StubDocumentRepository,
+        workflow_repo: StubWorkflowInstanceRepository,
+        s3_client: StubS3Client,
+        activity_repo: FakeWorkflowActivityRepository,
+    ) -> Router {
+        create_test_app_with_repos(
+            repo,
+            workflow_repo,
+            s3_client,
+            activity_repo,
+            FakeWorkflowCommentRepository::new(),
+            FakeWorkflowDefinitionRepository::new(),
+        )
+    }
+
+    fn create_test_app_with_definition_repo(
+        repo: StubDocumentRepository,
+        workflow_repo: StubWorkflowInstanceRepository,
+        s3_client: StubS3Client,
+        definition_repo: FakeWorkflowDefinitionRepository,
+    ) -> Router {
+        create_test_app_with_repos(
+            repo,
+            workflow_repo,
+            s3_client,
+            FakeWorkflowActivityRepository::new(),
+            FakeWorkflowCommentRepository::new(),
+            definition_repo,
+        )
+    }
+
+    fn create_test_app_with_comment_repo(
+        repo: StubDocumentRepository,
+        s3_client: StubS3Client,
+        comment_repo: FakeWorkflowCommentRepository,
+    ) -> Router {
+        create_test_app_with_repos(
+            repo,
+            StubWorkflowInstanceRepository::empty(),
+            s3_client,
+            FakeWorkflowActivityRepository::new(),
+            comment_repo,
+            FakeWorkflowDefinitionRepository::new(),
+        )
+    }
+
+    fn create_test_app_with_repos(
+        repo: StubDocumentRepository,
+        workflow_repo: StubWorkflowInstanceRepository,
+        s3_client: StubS3Client,
+        activity_repo: FakeWorkflowActivityRepository,
+        comment_repo: FakeWorkflowCommentRepository,
+        definition_repo: FakeWorkflowDefinitionRepository,
     ) -> Router {
         let repo_arc = Arc::new(repo) as Arc<dyn DocumentRepository>;
         let workflow_repo_arc = Arc::new(workflow_repo) as Arc<dyn WorkflowInstanceRepository>;
+        let activity_repo_arc = Arc::new(activity_repo) as Arc<dyn WorkflowActivityRepository>;
         let s3_arc = Arc::new(s3_client) as Arc<dyn S3Client>;
         let usecase = DocumentUseCaseImpl::new(
             repo_arc,
             workflow_repo_arc,
+            activity_repo_arc,
+            Arc::new(FakeWorkflowStepRepository::new()) as Arc<dyn WorkflowStepRepository>,
+            Arc::new(definition_repo) as Arc<dyn WorkflowDefinitionRepository>,
+            Arc::new(FakeWorkflowWatcherRepository::new()) as Arc<dyn WorkflowWatcherRepository>,
+            Arc::new(comment_repo) as Arc<dyn WorkflowCommentRepository>,
             s3_arc,
             Arc::new(StubClock) as Arc<dyn Clock>,
         );
@@ -674,6 +841,7 @@ mod tests {
             version: Version::initial(),
             current_step_id: None,
             initiated_by: UserId::new(),
+            proxy_submitted_by: None,
             submitted_at: None,
             completed_at: None,
             created_at: fixed_now(),
@@ -698,6 +866,7 @@ mod tests {
             version: Version::initial(),
             current_step_id: None,
             initiated_by: UserId::new(),
+            proxy_submitted_by: None,
             submitted_at: Some(fixed_now()),
             completed_at: None,
             created_at: fixed_now(),
@@ -885,6 +1054,167 @@ mod tests {
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
+    // upload-url コメント添付
+
+    async fn insert_comment(
+        comment_repo: &FakeWorkflowCommentRepository,
+        tenant_id: &TenantId,
+        posted_by: &UserId,
+    ) -> WorkflowCommentId {
+        let comment_id = WorkflowCommentId::new();
+        let comment = WorkflowComment::new(NewWorkflowComment {
+            id:          comment_id.clone(),
+            tenant_id:   tenant_id.clone(),
+            instance_id: WorkflowInstanceId::new(),
+            parent_id:   None,
+            posted_by:   posted_by.clone(),
+            body:        CommentBody::new("注釈を付けた PDF を添付します").unwrap(),
+            now:         fixed_now(),
+        });
+        let mut tx = TxContext::mock();
+        comment_repo
+            .insert(&mut tx, &comment, tenant_id)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+        comment_id
+    }
+
+    fn comment_upload_request(
+        tenant_id: &TenantId,
+        comment_id: &WorkflowCommentId,
+        uploaded_by: &UserId,
+    ) -> Request<Body> {
+        Request::builder()
+            .method(axum::http::Method::POST)
+            .uri("/internal/documents/upload-url")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::to_string(&serde_json::json!({
+                    "tenant_id": tenant_id.as_uuid(),
+                    "filename": "screenshot.png",
+                    "content_type": "image/png",
+                    "content_length": 1024,
+                    "comment_id": comment_id.as_uuid(),
+                    "uploaded_by": uploaded_by.as_uuid()
+                }))
+                .unwrap(),
+            ))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_post_upload_url_コメント投稿者はコメントに添付できる() {
+        // Given
+        let tenant_id = TenantId::new();
+        let author = UserId::new();
+        let comment_repo = FakeWorkflowCommentRepository::new();
+        let comment_id = insert_comment(&comment_repo, &tenant_id, &author).await;
+        let sut = create_test_app_with_comment_repo(
+            StubDocumentRepository::empty(),
+            StubS3Client::new("https://s3.example.com/presigned"),
+            comment_repo,
+        );
+
+        // When
+        let response = sut
+            .oneshot(comment_upload_request(&tenant_id, &comment_id, &author))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: UploadUrlDto = response_body(response).await;
+        assert_eq!(body.upload_url, "https://s3.example.com/presigned");
+    }
+
+    #[tokio::test]
+    async fn test_post_upload_url_コメント投稿者以外は403が返る() {
+        // Given
+        let tenant_id = TenantId::new();
+        let comment_repo = FakeWorkflowCommentRepository::new();
+        let comment_id = insert_comment(&comment_repo, &tenant_id, &UserId::new()).await;
+        let sut = create_test_app_with_comment_repo(
+            StubDocumentRepository::empty(),
+            StubS3Client::new("url"),
+            comment_repo,
+        );
+
+        // When
+        let response = sut
+            .oneshot(comment_upload_request(
+                &tenant_id,
+                &comment_id,
+                &UserId::new(),
+            ))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_post_upload_url_存在しないコメントで404が返る() {
+        // Given
+        let sut = create_test_app_with_comment_repo(
+            StubDocumentRepository::empty(),
+            StubS3Client::new("url"),
+            FakeWorkflowCommentRepository::new(),
+        );
+
+        // When
+        let response = sut
+            .oneshot(comment_upload_request(
+                &TenantId::new(),
+                &WorkflowCommentId::new(),
+                &UserId::new(),
+            ))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_post_upload_url_コメントとワークフローの同時指定で400が返る() {
+        // Given
+        let tenant_id = TenantId::new();
+        let author = UserId::new();
+        let comment_repo = FakeWorkflowCommentRepository::new();
+        let comment_id = insert_comment(&comment_repo, &tenant_id, &author).await;
+        let sut = create_test_app_with_comment_repo(
+            StubDocumentRepository::empty(),
+            StubS3Client::new("url"),
+            comment_repo,
+        );
+
+        let request = Request::builder()
+            .method(axum::http::Method::POST)
+            .uri("/internal/documents/upload-url")
+            .header("content-type", "application/json")
+            .body(Body::from(
+                serde_json::to_string(&serde_json::json!({
+                    "tenant_id": tenant_id.as_uuid(),
+                    "filename": "test.pdf",
+                    "content_type": "application/pdf",
+                    "content_length": 1024,
+                    "workflow_instance_id": WorkflowInstanceId::new().as_uuid(),
+                    "comment_id": comment_id.as_uuid(),
+                    "uploaded_by": author.as_uuid()
+                }))
+                .unwrap(),
+            ))
+            .unwrap();
+
+        // When
+        let response = sut.oneshot(request).await.unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     // confirm 正常系
 
     #[tokio::test]
@@ -921,6 +1251,61 @@ mod tests {
         assert_eq!(body.filename, "test.pdf");
     }
 
+    #[tokio::test]
+    async fn test_post_confirm_ワークフロー添付で添付ファイル追加のアクティビティが記録される() {
+        // Given
+        let tenant_id = TenantId::new();
+        let uploaded_by = UserId::new();
+        let workflow_instance_id = WorkflowInstanceId::new();
+        let doc = Document::new_uploading(
+            DocumentId::new(),
+            tenant_id.clone(),
+            "見積書.pdf".to_string(),
+            "application/pdf".to_string(),
+            1024,
+            "wf-key".to_string(),
+            UploadContext::Workflow(workflow_instance_id.clone()),
+            Some(uploaded_by.clone()),
+            fixed_now(),
+        );
+        let doc_id = *doc.id().as_uuid();
+        let activity_repo = FakeWorkflowActivityRepository::new();
+
+        let sut = create_test_app_with_activity_repo(
+            StubDocumentRepository::with_documents(vec![doc]),
+            StubWorkflowInstanceRepository::empty(),
+            StubS3Client::new("url").with_existing_keys(vec!["wf-key".to_string()]),
+            activity_repo.clone(),
+        );
+
+        let request = Request::builder()
+            .method(axum::http::Method::POST)
+            .uri(format!(
+                "/internal/documents/{}/confirm?tenant_id={}",
+                doc_id,
+                tenant_id.as_uuid()
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        // When
+        let response = sut.oneshot(request).await.unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::OK);
+        let activities = activity_repo
+            .find_by_instance(&workflow_instance_id, &tenant_id)
+            .await
+            .unwrap();
+        assert_eq!(activities.len(), 1);
+        assert_eq!(
+            activities[0].activity_type(),
+            WorkflowActivityType::AttachmentAdded
+        );
+        assert_eq!(activities[0].actor_id(), Some(&uploaded_by));
+        assert_eq!(activities[0].details()["filename"], "見積書.pdf");
+    }
+
     // confirm 準正常系
 
     #[tokio::test]
@@ -1044,9 +1429,10 @@ mod tests {
         let request = Request::builder()
             .method(axum::http::Method::POST)
             .uri(format!(
-                "/internal/documents/{}/download-url?tenant_id={}",
+                "/internal/documents/{}/download-url?tenant_id={}&user_id={}",
                 doc_id,
-                tenant_id.as_uuid()
+                tenant_id.as_uuid(),
+                Uuid::new_v4()
             ))
             .body(Body::empty())
             .unwrap();
@@ -1074,9 +1460,10 @@ mod tests {
         let request = Request::builder()
             .method(axum::http::Method::POST)
             .uri(format!(
-                "/internal/documents/{}/download-url?tenant_id={}",
+                "/internal/documents/{}/download-url?tenant_id={}&user_id={}",
                 Uuid::new_v4(),
-                tenant_id.as_uuid()
+                tenant_id.as_uuid(),
+                Uuid::new_v4()
             ))
             .body(Body::empty())
             .unwrap();
@@ -1104,9 +1491,10 @@ mod tests {
         let request = Request::builder()
             .method(axum::http::Method::POST)
             .uri(format!(
-                "/internal/documents/{}/download-url?tenant_id={}",
+                "/internal/documents/{}/download-url?tenant_id={}&user_id={}",
                 doc_id,
-                tenant_id.as_uuid()
+                tenant_id.as_uuid(),
+                Uuid::new_v4()
             ))
             .body(Body::empty())
             .unwrap();
@@ -1118,6 +1506,132 @@ mod tests {
         assert_eq!(response.status(), StatusCode::BAD_REQUEST);
     }
 
+    /// ワークフローと、機密区分を指定したその定義を用意する
+    fn make_workflow_with_definition(
+        tenant_id: &TenantId,
+        confidential: bool,
+    ) -> (WorkflowInstance, FakeWorkflowDefinitionRepository) {
+        let workflow = make_draft_workflow(tenant_id, &WorkflowInstanceId::new());
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id:          workflow.definition_id().clone(),
+            tenant_id:   tenant_id.clone(),
+            name:        WorkflowName::new("人事異動申請").unwrap(),
+            description: None,
+            definition:  serde_json::json!({"steps": []}),
+            created_by:  UserId::new(),
+            now:         fixed_now(),
+        })
+        .with_confidential(confidential);
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        definition_repo.add_definition(definition);
+        (workflow, definition_repo)
+    }
+
+    fn download_url_request(
+        document_id: &DocumentId,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        is_workflow_admin: bool,
+    ) -> Request<Body> {
+        Request::builder()
+            .method(axum::http::Method::POST)
+            .uri(format!(
+                "/internal/documents/{}/download-url?tenant_id={}&user_id={}&is_workflow_admin={}",
+                document_id.as_uuid(),
+                tenant_id.as_uuid(),
+                user_id.as_uuid(),
+                is_workflow_admin
+            ))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_post_download_url_機密ワークフローの添付は関与者なら200が返る() {
+        // Given
+        let tenant_id = TenantId::new();
+        let (workflow, definition_repo) = make_workflow_with_definition(&tenant_id, true);
+        let applicant = workflow.initiated_by().clone();
+        let doc = make_active_workflow_document(&tenant_id, &applicant, workflow.id());
+        let doc_id = doc.id().clone();
+
+        let sut = create_test_app_with_definition_repo(
+            StubDocumentRepository::with_documents(vec![doc]),
+            StubWorkflowInstanceRepository::with_instance(workflow),
+            StubS3Client::new("https://s3.example.com/download"),
+            definition_repo,
+        );
+
+        // When
+        let response = sut
+            .oneshot(download_url_request(&doc_id, &tenant_id, &applicant, false))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_post_download_url_機密ワークフローの添付は関与者でなければ管理者でも404が返る() {
+        // Given
+        let tenant_id = TenantId::new();
+        let (workflow, definition_repo) = make_workflow_with_definition(&tenant_id, true);
+        let doc = make_active_workflow_document(&tenant_id, workflow.initiated_by(), workflow.id());
+        let doc_id = doc.id().clone();
+
+        let sut = create_test_app_with_definition_repo(
+            StubDocumentRepository::with_documents(vec![doc]),
+            StubWorkflowInstanceRepository::with_instance(workflow),
+            StubS3Client::new("https://s3.example.com/download"),
+            definition_repo,
+        );
+
+        // When
+        let response = sut
+            .oneshot(download_url_request(
+                &doc_id,
+                &tenant_id,
+                &UserId::new(),
+                true,
+            ))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_post_download_url_機密でないワークフローの添付は管理者なら200が返る() {
+        // Given
+        let tenant_id = TenantId::new();
+        let (workflow, definition_repo) = make_workflow_with_definition(&tenant_id, false);
+        let doc = make_active_workflow_document(&tenant_id, workflow.initiated_by(), workflow.id());
+        let doc_id = doc.id().clone();
+
+        let sut = create_test_app_with_definition_repo(
+            StubDocumentRepository::with_documents(vec![doc]),
+            StubWorkflowInstanceRepository::with_instance(workflow),
+            StubS3Client::new("https://s3.example.com/download"),
+            definition_repo,
+        );
+
+        // When
+        let response = sut
+            .oneshot(download_url_request(
+                &doc_id,
+                &tenant_id,
+                &UserId::new(),
+                true,
+            ))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
     // --- delete テスト ---
 
     #[tokio::test]
@@ -1356,22 +1870,24 @@ mod tests {
     async fn test_get_workflow_attachments正常系_200でドキュメント配列が返る() {
         // Given
         let tenant_id = TenantId::new();
-        let user_id = UserId::new();
         let workflow_instance_id = WorkflowInstanceId::new();
-        let doc = make_active_workflow_document(&tenant_id, &user_id, &workflow_instance_id);
+        let workflow = make_draft_workflow(&tenant_id, &workflow_instance_id);
+        let applicant = workflow.initiated_by().clone();
+        let doc = make_active_workflow_document(&tenant_id, &applicant, &workflow_instance_id);
 
         let sut = create_test_app(
             StubDocumentRepository::with_documents(vec![doc]),
-            StubWorkflowInstanceRepository::empty(),
+            StubWorkflowInstanceRepository::with_instance(workflow),
             StubS3Client::new("url"),
         );
 
         let request = Request::builder()
             .method(axum::http::Method::GET)
             .uri(format!(
-                "/internal/workflows/{}/attachments?tenant_id={}",
+                "/internal/workflows/{}/attachments?tenant_id={}&user_id={}",
                 workflow_instance_id.as_uuid(),
-                tenant_id.as_uuid()
+                tenant_id.as_uuid(),
+                applicant.as_uuid()
             ))
             .body(Body::empty())
             .unwrap();
@@ -1385,4 +1901,41 @@ mod tests {
         assert_eq!(body.len(), 1);
         assert_eq!(body[0].filename, "attachment.pdf");
     }
+
+    #[tokio::test]
+    async fn test_get_workflow_attachments_閲覧権限がない場合に404が返る() {
+        // Given
+        let tenant_id = TenantId::new();
+        let workflow_instance_id = WorkflowInstanceId::new();
+        let workflow = make_draft_workflow(&tenant_id, &workflow_instance_id);
+        let doc = make_active_workflow_document(
+            &tenant_id,
+            workflow.initiated_by(),
+            &workflow_instance_id,
+        );
+        let other_user = UserId::new();
+
+        let sut = create_test_app(
+            StubDocumentRepository::with_documents(vec![doc]),
+            StubWorkflowInstanceRepository::with_instance(workflow),
+            StubS3Client::new("url"),
+        );
+
+        let request = Request::builder()
+            .method(axum::http::Method::GET)
+            .uri(format!(
+                "/internal/workflows/{}/attachments?tenant_id={}&user_id={}",
+                workflow_instance_id.as_uuid(),
+                tenant_id.as_uuid(),
+                other_user.as_uuid()
+            ))
+            .body(Body::empty())
+            .unwrap();
+
+        // When
+        let response = sut.oneshot(request).await.unwrap();
+
+        // Then
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }
@@ -0,0 +1,263 @@
+//! # アプリ内通知ハンドラ
+//!
+//! Core API のアプリ内通知（通知受信箱）と通知設定の内部 API を提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /internal/notifications` - 自分宛ての通知一覧（新しい順、キーセットページネーション）
+//! - `GET /internal/notifications/unread-count` - 自分宛ての未読通知数
+//! - `POST /internal/notifications/{id}/read` - 通知を既読にする
+//! - `POST /internal/notifications/read-all` - 自分宛ての未読通知をすべて既読にする
+//! - `GET /internal/notifications/preferences` - 自分の通知設定（全通知イベント種別）
+//! - `PUT /internal/notifications/preferences` - 自分の通知設定を変更する
+//!
+//! 通知と通知設定は本人のみ参照・変更できる。
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use ringiflow_domain::{
+    notification::{InboxNotification, InboxNotificationId, NotificationPreference},
+    tenant::TenantId,
+    user::UserId,
+};
+use ringiflow_shared::PaginatedResponse;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::CoreError,
+    handler::workflow::{UserPageQuery, UserQuery},
+    usecase::{InboxUseCaseImpl, NotificationPreferenceInput, NotificationPreferenceUseCaseImpl},
+};
+
+/// アプリ内通知 API の共有状態
+pub struct NotificationState {
+    pub usecase: InboxUseCaseImpl,
+    pub preference_usecase: NotificationPreferenceUseCaseImpl,
+}
+
+// --- リクエスト/レスポンス型 ---
+
+/// 既読化リクエスト
+#[derive(Debug, Deserialize)]
+pub struct NotificationUserRequest {
+    pub tenant_id: Uuid,
+    pub user_id:   Uuid,
+}
+
+/// 通知設定変更リクエスト
+#[derive(Debug, Deserialize)]
+pub struct UpdateNotificationPreferencesRequest {
+    pub tenant_id:   Uuid,
+    pub user_id:     Uuid,
+    pub preferences: Vec<NotificationPreferenceItemRequest>,
+}
+
+/// 通知設定変更リクエストの 1 通知イベント種別分
+#[derive(Debug, Deserialize)]
+pub struct NotificationPreferenceItemRequest {
+    pub event_type: String,
+    pub delivery:   String,
+}
+
+/// アプリ内通知 DTO
+#[derive(Debug, Serialize)]
+pub struct InboxNotificationDto {
+    pub id: String,
+    pub event_type: String,
+    pub workflow_instance_id: String,
+    pub workflow_title: String,
+    pub workflow_display_id: String,
+    pub message: String,
+    pub read_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<&InboxNotification> for InboxNotificationDto {
+    fn from(notification: &InboxNotification) -> Self {
+        Self {
+            id: notification.id().to_string(),
+            event_type: notification.event_type().to_string(),
+            workflow_instance_id: notification.workflow_instance_id().to_string(),
+            workflow_title: notification.workflow_title().to_string(),
+            workflow_display_id: notification.workflow_display_id().to_string(),
+            message: notification.message().to_string(),
+            read_at: notification.read_at().map(|t| t.to_rfc3339()),
+            created_at: notification.created_at().to_rfc3339(),
+        }
+    }
+}
+
+/// 未読通知数 DTO
+#[derive(Debug, Serialize)]
+pub struct UnreadCountDto {
+    pub unread_count: i64,
+}
+
+/// 一括既読化の結果 DTO
+#[derive(Debug, Serialize)]
+pub struct MarkAllReadDto {
+    /// 既読にした通知の件数
+    pub updated_count: u64,
+}
+
+/// 通知設定 DTO
+#[derive(Debug, Serialize)]
+pub struct NotificationPreferenceDto {
+    pub event_type: String,
+    pub delivery:   String,
+}
+
+impl From<&NotificationPreference> for NotificationPreferenceDto {
+    fn from(preference: &NotificationPreference) -> Self {
+        Self {
+            event_type: preference.event_type.to_string(),
+            delivery:   preference.delivery.to_string(),
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /internal/notifications
+///
+/// 自分宛ての通知を新しい順に 1 ページ分取得する。
+#[tracing::instrument(skip_all)]
+pub async fn list_notifications(
+    State(state): State<Arc<NotificationState>>,
+    Query(query): Query<UserPageQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+    let user_id = UserId::from_uuid(query.user_id);
+
+    let page = state
+        .usecase
+        .list_notifications(&tenant_id, &user_id, query.cursor.as_deref(), query.limit)
+        .await?;
+
+    let response = PaginatedResponse {
+        items:       page.items.iter().map(InboxNotificationDto::from).collect(),
+        next_cursor: page.next_cursor,
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// GET /internal/notifications/unread-count
+///
+/// 自分宛ての未読通知数を取得する。ヘッダーのベルのバッジ表示に使う。
+#[tracing::instrument(skip_all)]
+pub async fn get_unread_notification_count(
+    State(state): State<Arc<NotificationState>>,
+    Query(query): Query<UserQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+    let user_id = UserId::from_uuid(query.user_id);
+
+    let unread_count = state.usecase.count_unread(&tenant_id, &user_id).await?;
+
+    Ok((StatusCode::OK, Json(UnreadCountDto { unread_count })))
+}
+
+/// POST /internal/notifications/{id}/read
+///
+/// 通知を既読にする。既読済みの場合も成功として扱う。
+///
+/// ## レスポンス
+///
+/// - `204 No Content`: 既読化成功
+/// - `404 Not Found`: 通知が存在しない、または他ユーザー宛て
+#[tracing::instrument(skip_all, fields(%id))]
+pub async fn mark_notification_read(
+    State(state): State<Arc<NotificationState>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<NotificationUserRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+    let user_id = UserId::from_uuid(req.user_id);
+    let id = InboxNotificationId::from_uuid(id);
+
+    state.usecase.mark_read(&tenant_id, &user_id, &id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /internal/notifications/read-all
+///
+/// 自分宛ての未読通知をすべて既読にする。
+#[tracing::instrument(skip_all)]
+pub async fn mark_all_notifications_read(
+    State(state): State<Arc<NotificationState>>,
+    Json(req): Json<NotificationUserRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+    let user_id = UserId::from_uuid(req.user_id);
+
+    let updated_count = state.usecase.mark_all_read(&tenant_id, &user_id).await?;
+
+    Ok((StatusCode::OK, Json(MarkAllReadDto { updated_count })))
+}
+
+/// GET /internal/notifications/preferences
+///
+/// 自分の通知設定を全通知イベント種別分取得する。設定していない種別は `immediate` を返す。
+#[tracing::instrument(skip_all)]
+pub async fn list_notification_preferences(
+    State(state): State<Arc<NotificationState>>,
+    Query(query): Query<UserQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+    let user_id = UserId::from_uuid(query.user_id);
+
+    let preferences = state
+        .preference_usecase
+        .list_preferences(&tenant_id, &user_id)
+        .await?;
+
+    let response: Vec<NotificationPreferenceDto> = preferences
+        .iter()
+        .map(NotificationPreferenceDto::from)
+        .collect();
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// PUT /internal/notifications/preferences
+///
+/// 自分の通知設定を変更する。指定した通知イベント種別のみ上書きし、変更後の全種別分の設定を返す。
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 変更後の通知設定
+/// - `400 Bad Request`: 通知イベント種別・配信方法が不正、または種別が重複
+#[tracing::instrument(skip_all)]
+pub async fn update_notification_preferences(
+    State(state): State<Arc<NotificationState>>,
+    Json(req): Json<UpdateNotificationPreferencesRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+    let user_id = UserId::from_uuid(req.user_id);
+    let inputs = req
+        .preferences
+        .into_iter()
+        .map(|p| NotificationPreferenceInput {
+            event_type: p.event_type,
+            delivery:   p.delivery,
+        })
+        .collect();
+
+    let preferences = state
+        .preference_usecase
+        .update_preferences(&tenant_id, &user_id, inputs)
+        .await?;
+
+    let response: Vec<NotificationPreferenceDto> = preferences
+        .iter()
+        .map(NotificationPreferenceDto::from)
+        .collect();
+    Ok((StatusCode::OK, Json(response)))
+}
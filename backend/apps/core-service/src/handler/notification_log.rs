@@ -0,0 +1,130 @@
+//! # 通知ログハンドラ
+//!
+//! Core API の送信に失敗した通知の確認・再送内部 API を提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /internal/notification-logs/failed` - 送信に失敗した通知一覧
+//! - `POST /internal/notification-logs/{id}/resend` - 送信に失敗した通知を再送
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use ringiflow_domain::{
+    notification::{NotificationLog, NotificationLogId},
+    tenant::TenantId,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{error::CoreError, usecase::NotificationLogUseCaseImpl};
+
+/// 通知ログ API の共有状態
+pub struct NotificationLogState {
+    pub usecase: NotificationLogUseCaseImpl,
+}
+
+// --- リクエスト/レスポンス型 ---
+
+/// テナント ID クエリパラメータ
+#[derive(Debug, Deserialize)]
+pub struct NotificationLogTenantQuery {
+    pub tenant_id: Uuid,
+}
+
+/// テナント ID を含むリクエストボディ
+#[derive(Debug, Deserialize)]
+pub struct NotificationLogTenantRequest {
+    pub tenant_id: Uuid,
+}
+
+/// 通知ログ DTO
+///
+/// 本文は返さず、再送できるか（本文を保存しているか）のみを返す。
+#[derive(Debug, Serialize)]
+pub struct NotificationLogDto {
+    pub id: Uuid,
+    pub event_type: String,
+    pub workflow_instance_id: Uuid,
+    pub workflow_title: String,
+    pub workflow_display_id: String,
+    pub recipient_user_id: Uuid,
+    pub recipient_email: String,
+    pub subject: String,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: String,
+    pub error_message: Option<String>,
+    pub resendable: bool,
+    pub created_at: String,
+    pub sent_at: Option<String>,
+}
+
+impl From<&NotificationLog> for NotificationLogDto {
+    fn from(log: &NotificationLog) -> Self {
+        Self {
+            id: *log.id().as_uuid(),
+            event_type: log.event_type().to_string(),
+            workflow_instance_id: *log.workflow_instance_id().as_uuid(),
+            workflow_title: log.workflow_title().to_string(),
+            workflow_display_id: log.workflow_display_id().to_string(),
+            recipient_user_id: *log.recipient_user_id().as_uuid(),
+            recipient_email: log.recipient_email().to_string(),
+            subject: log.subject().to_string(),
+            status: log.status().to_string(),
+            attempts: log.attempts(),
+            next_attempt_at: log.next_attempt_at().to_rfc3339(),
+            error_message: log.error_message().map(str::to_string),
+            resendable: log.email().is_some(),
+            created_at: log.created_at().to_rfc3339(),
+            sent_at: log.sent_at().map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /internal/notification-logs/failed
+///
+/// 送信に失敗した通知を新しい順で取得する（最大 100 件）。
+#[tracing::instrument(skip_all)]
+pub async fn list_failed_notification_logs(
+    State(state): State<Arc<NotificationLogState>>,
+    Query(query): Query<NotificationLogTenantQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let logs = state.usecase.list_failed(&tenant_id).await?;
+
+    let items: Vec<NotificationLogDto> = logs.iter().map(NotificationLogDto::from).collect();
+    Ok((StatusCode::OK, Json(items)))
+}
+
+/// POST /internal/notification-logs/{id}/resend
+///
+/// 送信に失敗した通知を再送待ちに戻す。再送ワーカーが次回のポーリングで送信する。
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 再送待ちに戻した通知ログ
+/// - `400 Bad Request`: 本文を保存していない通知（ダイジェスト等）
+/// - `404 Not Found`: 通知ログが見つからない
+/// - `409 Conflict`: 送信に失敗した通知ではない
+#[tracing::instrument(skip_all, fields(%id))]
+pub async fn resend_notification_log(
+    State(state): State<Arc<NotificationLogState>>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<NotificationLogTenantRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let id = NotificationLogId::from_uuid(id);
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+
+    let log = state.usecase.resend(&id, &tenant_id).await?;
+
+    Ok((StatusCode::OK, Json(NotificationLogDto::from(&log))))
+}
@@ -0,0 +1,320 @@
+//! # 通知テンプレートハンドラ
+//!
+//! Core API のテナント独自の通知テンプレート管理内部 API を提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /internal/notification-templates` - テナントが上書きした通知テンプレート一覧
+//! - `GET /internal/notification-templates/{event_type}` - 通知テンプレート詳細
+//! - `PUT /internal/notification-templates/{event_type}` - 通知テンプレートを保存
+//! - `DELETE /internal/notification-templates/{event_type}` - 通知テンプレートを削除（組み込みに戻す）
+//! - `POST /internal/notification-templates/{event_type}/validate` - 保存せずに検証
+//! - `POST /internal/notification-templates/{event_type}/preview` - サンプルデータでプレビュー
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use ringiflow_domain::{
+    notification::{EmailMessage, NotificationTemplate},
+    tenant::TenantId,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::CoreError,
+    usecase::{
+        NotificationTemplateContentInput,
+        NotificationTemplateUseCaseImpl,
+        SaveNotificationTemplateInput,
+        TemplateValidationError,
+    },
+};
+
+/// 通知テンプレート API の共有状態
+pub struct NotificationTemplateState {
+    pub usecase: NotificationTemplateUseCaseImpl,
+}
+
+// --- リクエスト/レスポンス型 ---
+
+/// テナント ID クエリパラメータ
+#[derive(Debug, Deserialize)]
+pub struct NotificationTemplateTenantQuery {
+    pub tenant_id: Uuid,
+}
+
+/// 通知テンプレート保存リクエスト
+#[derive(Debug, Deserialize)]
+pub struct SaveNotificationTemplateRequest {
+    pub tenant_id:  Uuid,
+    pub subject:    String,
+    pub html_body:  String,
+    pub text_body:  String,
+    pub updated_by: Uuid,
+}
+
+/// 通知テンプレート検証リクエスト
+#[derive(Debug, Deserialize)]
+pub struct ValidateNotificationTemplateRequest {
+    pub subject:   String,
+    pub html_body: String,
+    pub text_body: String,
+}
+
+/// 通知テンプレートプレビューリクエスト
+///
+/// 内容を省略した場合は保存済みの通知テンプレート（なければ組み込み）でプレビューする。
+/// `locale` は組み込みテンプレートの言語（省略時は日本語）。
+#[derive(Debug, Deserialize)]
+pub struct PreviewNotificationTemplateRequest {
+    pub tenant_id: Uuid,
+    pub subject:   Option<String>,
+    pub html_body: Option<String>,
+    pub text_body: Option<String>,
+    pub locale:    Option<String>,
+}
+
+/// 通知テンプレート DTO
+#[derive(Debug, Serialize)]
+pub struct NotificationTemplateDto {
+    pub event_type: String,
+    pub subject:    String,
+    pub html_body:  String,
+    pub text_body:  String,
+    pub updated_by: Uuid,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&NotificationTemplate> for NotificationTemplateDto {
+    fn from(template: &NotificationTemplate) -> Self {
+        Self {
+            event_type: template.event_type().to_string(),
+            subject:    template.subject().as_str().to_string(),
+            html_body:  template.html_body().as_str().to_string(),
+            text_body:  template.text_body().as_str().to_string(),
+            updated_by: *template.updated_by().as_uuid(),
+            created_at: template.created_at().to_rfc3339(),
+            updated_at: template.updated_at().to_rfc3339(),
+        }
+    }
+}
+
+/// 通知テンプレート検証結果 DTO
+#[derive(Debug, Serialize)]
+pub struct NotificationTemplateValidationDto {
+    pub valid:  bool,
+    pub errors: Vec<NotificationTemplateValidationErrorDto>,
+}
+
+/// 通知テンプレート検証エラー DTO
+#[derive(Debug, Serialize)]
+pub struct NotificationTemplateValidationErrorDto {
+    pub field:   String,
+    pub message: String,
+}
+
+impl From<TemplateValidationError> for NotificationTemplateValidationErrorDto {
+    fn from(error: TemplateValidationError) -> Self {
+        Self {
+            field:   error.field.to_string(),
+            message: error.message,
+        }
+    }
+}
+
+/// 通知テンプレートプレビュー DTO
+#[derive(Debug, Serialize)]
+pub struct NotificationTemplatePreviewDto {
+    pub subject:   String,
+    pub html_body: String,
+    pub text_body: String,
+}
+
+impl From<EmailMessage> for NotificationTemplatePreviewDto {
+    fn from(email: EmailMessage) -> Self {
+        Self {
+            subject:   email.subject,
+            html_body: email.html_body,
+            text_body: email.text_body,
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /internal/notification-templates
+///
+/// テナントが上書きした通知テンプレートを通知イベント種別順で取得する。
+#[tracing::instrument(skip_all)]
+pub async fn list_notification_templates(
+    State(state): State<Arc<NotificationTemplateState>>,
+    Query(query): Query<NotificationTemplateTenantQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let templates = state.usecase.list_templates(&tenant_id).await?;
+
+    let items: Vec<NotificationTemplateDto> = templates
+        .iter()
+        .map(NotificationTemplateDto::from)
+        .collect();
+    Ok((StatusCode::OK, Json(items)))
+}
+
+/// GET /internal/notification-templates/{event_type}
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 通知テンプレート
+/// - `400 Bad Request`: 通知イベント種別が不正
+/// - `404 Not Found`: テナントが上書きしていない
+#[tracing::instrument(skip_all, fields(%event_type))]
+pub async fn get_notification_template(
+    State(state): State<Arc<NotificationTemplateState>>,
+    Path(event_type): Path<String>,
+    Query(query): Query<NotificationTemplateTenantQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let template = state.usecase.get_template(&tenant_id, &event_type).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(NotificationTemplateDto::from(&template)),
+    ))
+}
+
+/// PUT /internal/notification-templates/{event_type}
+///
+/// 通知テンプレートを保存する。保存済みの場合は内容を置き換える。
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 保存した通知テンプレート
+/// - `400 Bad Request`: 通知イベント種別が不正、またはテンプレートの検証エラー
+#[tracing::instrument(skip_all, fields(%event_type))]
+pub async fn save_notification_template(
+    State(state): State<Arc<NotificationTemplateState>>,
+    Path(event_type): Path<String>,
+    Json(req): Json<SaveNotificationTemplateRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let input = SaveNotificationTemplateInput {
+        tenant_id: TenantId::from_uuid(req.tenant_id),
+        event_type,
+        content: NotificationTemplateContentInput {
+            subject:   req.subject,
+            html_body: req.html_body,
+            text_body: req.text_body,
+        },
+        updated_by: req.updated_by,
+    };
+
+    let template = state.usecase.save_template(input).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(NotificationTemplateDto::from(&template)),
+    ))
+}
+
+/// DELETE /internal/notification-templates/{event_type}
+///
+/// 通知テンプレートを削除し、組み込みテンプレートに戻す。
+///
+/// ## レスポンス
+///
+/// - `204 No Content`: 削除成功
+/// - `400 Bad Request`: 通知イベント種別が不正
+/// - `404 Not Found`: テナントが上書きしていない
+#[tracing::instrument(skip_all, fields(%event_type))]
+pub async fn delete_notification_template(
+    State(state): State<Arc<NotificationTemplateState>>,
+    Path(event_type): Path<String>,
+    Query(query): Query<NotificationTemplateTenantQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    state
+        .usecase
+        .delete_template(&tenant_id, &event_type)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /internal/notification-templates/{event_type}/validate
+///
+/// 通知テンプレートを保存せずに検証する。
+/// 検証エラーがあっても `200 OK` で検証結果を返す。
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 検証結果
+/// - `400 Bad Request`: 通知イベント種別が不正
+#[tracing::instrument(skip_all, fields(%event_type))]
+pub async fn validate_notification_template(
+    State(state): State<Arc<NotificationTemplateState>>,
+    Path(event_type): Path<String>,
+    Json(req): Json<ValidateNotificationTemplateRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let content = NotificationTemplateContentInput {
+        subject:   req.subject,
+        html_body: req.html_body,
+        text_body: req.text_body,
+    };
+
+    let errors = state.usecase.validate_template(&event_type, content)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(NotificationTemplateValidationDto {
+            valid:  errors.is_empty(),
+            errors: errors
+                .into_iter()
+                .map(NotificationTemplateValidationErrorDto::from)
+                .collect(),
+        }),
+    ))
+}
+
+/// POST /internal/notification-templates/{event_type}/preview
+///
+/// 通知イベント種別のサンプルデータでメールをレンダリングする。
+///
+/// ## レスポンス
+///
+/// - `200 OK`: レンダリング結果
+/// - `400 Bad Request`: 通知イベント種別が不正、またはテンプレートの検証エラー
+#[tracing::instrument(skip_all, fields(%event_type))]
+pub async fn preview_notification_template(
+    State(state): State<Arc<NotificationTemplateState>>,
+    Path(event_type): Path<String>,
+    Json(req): Json<PreviewNotificationTemplateRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+    let content = match (req.subject, req.html_body, req.text_body) {
+        (None, None, None) => None,
+        (subject, html_body, text_body) => Some(NotificationTemplateContentInput {
+            subject:   subject.unwrap_or_default(),
+            html_body: html_body.unwrap_or_default(),
+            text_body: text_body.unwrap_or_default(),
+        }),
+    };
+
+    let email = state
+        .usecase
+        .preview_template(&tenant_id, &event_type, content, req.locale.as_deref())
+        .await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(NotificationTemplatePreviewDto::from(email)),
+    ))
+}
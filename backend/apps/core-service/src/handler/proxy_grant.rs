@@ -0,0 +1,177 @@
+//! # 代理権限ハンドラ
+//!
+//! Core API の代理権限管理内部 API を提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /internal/proxy-grants` - 自分が付与した・付与されている代理権限一覧
+//! - `POST /internal/proxy-grants` - 代理権限の付与
+//! - `DELETE /internal/proxy-grants/{proxy_user_id}` - 代理権限の取り消し
+//!
+//! 代理権限は本人（付与する側）のみ付与・取り消しできる。
+
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use itertools::Itertools;
+use ringiflow_domain::{tenant::TenantId, user::UserId, workflow::ProxyGrant};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::CoreError,
+    handler::workflow::{UserRefDto, to_user_ref},
+    usecase::ProxyGrantUseCaseImpl,
+};
+
+/// 代理権限 API の共有状態
+pub struct ProxyGrantState {
+    pub usecase: ProxyGrantUseCaseImpl,
+}
+
+// --- リクエスト/レスポンス型 ---
+
+/// テナント ID・ユーザー ID クエリパラメータ
+#[derive(Debug, Deserialize)]
+pub struct ProxyGrantUserQuery {
+    pub tenant_id: Uuid,
+    pub user_id:   Uuid,
+}
+
+/// 代理権限付与リクエスト
+#[derive(Debug, Deserialize)]
+pub struct CreateProxyGrantRequest {
+    pub tenant_id:     Uuid,
+    /// 本人（付与する側）のユーザー ID
+    pub user_id:       Uuid,
+    /// 代理人のユーザー ID
+    pub proxy_user_id: Uuid,
+}
+
+/// 代理権限 DTO
+#[derive(Debug, Serialize)]
+pub struct ProxyGrantDto {
+    pub principal:  UserRefDto,
+    pub proxy:      UserRefDto,
+    pub created_at: String,
+}
+
+impl ProxyGrantDto {
+    fn from_grant(grant: &ProxyGrant, user_names: &HashMap<UserId, String>) -> Self {
+        Self {
+            principal:  to_user_ref(grant.principal_id(), user_names),
+            proxy:      to_user_ref(grant.proxy_id(), user_names),
+            created_at: grant.created_at().to_rfc3339(),
+        }
+    }
+}
+
+/// 代理権限一覧 DTO
+#[derive(Debug, Serialize)]
+pub struct ProxyGrantsDto {
+    /// 自分が付与した代理権限
+    pub granted:  Vec<ProxyGrantDto>,
+    /// 自分が付与されている代理権限
+    pub received: Vec<ProxyGrantDto>,
+}
+
+// --- ハンドラ ---
+
+/// GET /internal/proxy-grants
+///
+/// 自分が付与した代理権限と、自分が付与されている代理権限を付与日時順で取得する。
+#[tracing::instrument(skip_all)]
+pub async fn list_proxy_grants(
+    State(state): State<Arc<ProxyGrantState>>,
+    Query(query): Query<ProxyGrantUserQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+    let user_id = UserId::from_uuid(query.user_id);
+
+    let grants = state.usecase.list_grants(&tenant_id, &user_id).await?;
+
+    let user_ids: Vec<UserId> = grants
+        .granted
+        .iter()
+        .chain(&grants.received)
+        .flat_map(|g| [g.principal_id().clone(), g.proxy_id().clone()])
+        .unique()
+        .collect();
+    let user_names = state.usecase.resolve_user_names(&user_ids).await?;
+
+    let response = ProxyGrantsDto {
+        granted:  grants
+            .granted
+            .iter()
+            .map(|g| ProxyGrantDto::from_grant(g, &user_names))
+            .collect(),
+        received: grants
+            .received
+            .iter()
+            .map(|g| ProxyGrantDto::from_grant(g, &user_names))
+            .collect(),
+    };
+    Ok((StatusCode::OK, Json(response)))
+}
+
+/// POST /internal/proxy-grants
+///
+/// 代理人に代理権限を付与する。付与済みの場合も成功として扱う。
+///
+/// ## レスポンス
+///
+/// - `201 Created`: 付与された代理権限
+/// - `400 Bad Request`: 自分自身、または同じテナントの有効なユーザーでない
+#[tracing::instrument(skip_all)]
+pub async fn create_proxy_grant(
+    State(state): State<Arc<ProxyGrantState>>,
+    Json(req): Json<CreateProxyGrantRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+    let principal_id = UserId::from_uuid(req.user_id);
+    let proxy_id = UserId::from_uuid(req.proxy_user_id);
+
+    let grant = state
+        .usecase
+        .grant(&tenant_id, &principal_id, &proxy_id)
+        .await?;
+
+    let user_names = state
+        .usecase
+        .resolve_user_names(&[principal_id, proxy_id])
+        .await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ProxyGrantDto::from_grant(&grant, &user_names)),
+    ))
+}
+
+/// DELETE /internal/proxy-grants/{proxy_user_id}
+///
+/// 自分が付与した代理権限を取り消す。未付与の場合も成功として扱う。
+///
+/// ## レスポンス
+///
+/// - `204 No Content`: 取り消し成功
+#[tracing::instrument(skip_all, fields(%proxy_user_id))]
+pub async fn delete_proxy_grant(
+    State(state): State<Arc<ProxyGrantState>>,
+    Path(proxy_user_id): Path<Uuid>,
+    Query(query): Query<ProxyGrantUserQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+    let principal_id = UserId::from_uuid(query.user_id);
+    let proxy_id = UserId::from_uuid(proxy_user_id);
+
+    state
+        .usecase
+        .revoke(&tenant_id, &principal_id, &proxy_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
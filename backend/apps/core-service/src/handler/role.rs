@@ -19,6 +19,7 @@ use axum::{
     response::IntoResponse,
 };
 use ringiflow_domain::{
+    message::Message,
     role::{Role, RoleId},
     tenant::TenantId,
 };
@@ -150,11 +151,11 @@ pub async fn get_role(
         .role_repository
         .find_by_id(&role_id)
         .await?
-        .ok_or_else(|| CoreError::NotFound("ロールが見つかりません".to_string()))?;
+        .ok_or_else(|| CoreError::NotFound(Message::new("role-not-found")))?;
 
     // テナント分離: テナントロールは所属テナントのみアクセス可能
     if !role.is_system() && role.tenant_id() != Some(&tenant_id) {
-        return Err(CoreError::NotFound("ロールが見つかりません".to_string()));
+        return Err(CoreError::NotFound(Message::new("role-not-found")));
     }
 
     let response = RoleDetailDto::from(&role);
@@ -12,17 +12,20 @@ use axum::{
 };
 use itertools::Itertools;
 use ringiflow_domain::{
+    message::Message,
     tenant::TenantId,
     user::UserId,
     value_objects::{DisplayId, DisplayNumber, display_prefix},
     workflow::{WorkflowInstance, WorkflowStepId},
 };
+use ringiflow_shared::PaginatedResponse;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
     error::CoreError,
     handler::workflow::{
+        UserPageQuery,
         UserQuery,
         UserRefDto,
         WorkflowInstanceDetailDto,
@@ -118,19 +121,23 @@ pub struct TaskDetailDto {
 /// 自分のタスク一覧を取得する
 ///
 /// ## エンドポイント
-/// GET /internal/tasks/my?tenant_id={tenant_id}&user_id={user_id}
+/// GET /internal/tasks/my?tenant_id={tenant_id}&user_id={user_id}&cursor={cursor}&limit={limit}
 #[tracing::instrument(skip_all)]
 pub async fn list_my_tasks(
     State(state): State<Arc<TaskState>>,
-    Query(query): Query<UserQuery>,
+    Query(query): Query<UserPageQuery>,
 ) -> Result<Response, CoreError> {
     let tenant_id = TenantId::from_uuid(query.tenant_id);
     let user_id = UserId::from_uuid(query.user_id);
 
-    let tasks = state.usecase.list_my_tasks(tenant_id, user_id).await?;
+    let page = state
+        .usecase
+        .list_my_tasks(tenant_id, user_id, query.cursor.as_deref(), query.limit)
+        .await?;
 
-    // 全タスクのユーザー ID を収集して一括解決
-    let all_user_ids: Vec<UserId> = tasks
+    // ページ内のタスクのユーザー ID を収集して一括解決
+    let all_user_ids: Vec<UserId> = page
+        .items
         .iter()
         .flat_map(|task| {
             std::iter::once(task.workflow.initiated_by().clone())
@@ -140,10 +147,14 @@ pub async fn list_my_tasks(
         .collect();
     let user_names = state.usecase.resolve_user_names(&all_user_ids).await?;
 
-    let response = tasks
-        .iter()
-        .map(|t| TaskItemDto::from_task_item(t, &user_names))
-        .collect::<Vec<_>>();
+    let response = PaginatedResponse {
+        items:       page
+            .items
+            .iter()
+            .map(|t| TaskItemDto::from_task_item(t, &user_names))
+            .collect(),
+        next_cursor: page.next_cursor,
+    };
 
     Ok((StatusCode::OK, Json(response)).into_response())
 }
@@ -195,10 +206,14 @@ pub async fn get_task_by_display_numbers(
     Query(query): Query<UserQuery>,
 ) -> Result<Response, CoreError> {
     let workflow_dn = DisplayNumber::new(params.workflow_display_number).map_err(|_| {
-        CoreError::BadRequest("workflow_display_number は正の整数である必要があります".to_string())
+        CoreError::BadRequest(
+            Message::new("must-be-positive-integer").with("name", "workflow_display_number"),
+        )
     })?;
     let step_dn = DisplayNumber::new(params.step_display_number).map_err(|_| {
-        CoreError::BadRequest("step_display_number は正の整数である必要があります".to_string())
+        CoreError::BadRequest(
+            Message::new("must-be-positive-integer").with("name", "step_display_number"),
+        )
     })?;
     let tenant_id = TenantId::from_uuid(query.tenant_id);
     let user_id = UserId::from_uuid(query.user_id);
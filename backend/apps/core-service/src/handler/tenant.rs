@@ -0,0 +1,119 @@
+//! # テナント設定ハンドラ
+//!
+//! Core API のテナント設定内部 API を提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /internal/tenants/{tenant_id}/settings` - テナント設定を取得
+//! - `PUT /internal/tenants/{tenant_id}/settings` - テナント設定を更新
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use ringiflow_domain::{
+    locale::Locale,
+    message::Message,
+    tenant::{Tenant, TenantId},
+    time_zone::TimeZone,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{error::CoreError, usecase::TenantUseCaseImpl};
+
+/// テナント設定 API の共有状態
+pub struct TenantState {
+    pub usecase: TenantUseCaseImpl,
+}
+
+// --- リクエスト/レスポンス型 ---
+
+/// テナント設定更新リクエスト
+#[derive(Debug, Deserialize)]
+pub struct UpdateTenantSettingsRequest {
+    pub default_locale: String,
+    pub time_zone:      Option<String>,
+}
+
+/// テナント設定 DTO
+#[derive(Debug, Serialize)]
+pub struct TenantSettingsDto {
+    pub default_locale: String,
+    pub time_zone:      String,
+}
+
+impl From<&Tenant> for TenantSettingsDto {
+    fn from(tenant: &Tenant) -> Self {
+        Self {
+            default_locale: tenant.default_locale().to_string(),
+            time_zone:      tenant.time_zone().to_string(),
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /internal/tenants/{tenant_id}/settings
+///
+/// テナント設定を取得する。
+///
+/// ## レスポンス
+///
+/// - `200 OK`: テナント設定
+/// - `404 Not Found`: テナントが見つからない
+#[tracing::instrument(skip_all, fields(%tenant_id))]
+pub async fn get_tenant_settings(
+    State(state): State<Arc<TenantState>>,
+    Path(tenant_id): Path<Uuid>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant = state
+        .usecase
+        .get_tenant(&TenantId::from_uuid(tenant_id))
+        .await?;
+
+    Ok((StatusCode::OK, Json(TenantSettingsDto::from(&tenant))))
+}
+
+/// PUT /internal/tenants/{tenant_id}/settings
+///
+/// テナント設定を更新する。
+///
+/// ## リクエストボディ
+///
+/// - `default_locale`: 既定ロケール（"ja", "en"）
+/// - `time_zone`: タイムゾーン（IANA タイムゾーン名、省略時は変更しない）
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 更新後のテナント設定
+/// - `400 Bad Request`: 不正なロケールまたはタイムゾーン
+/// - `404 Not Found`: テナントが見つからない
+#[tracing::instrument(skip_all, fields(%tenant_id))]
+pub async fn update_tenant_settings(
+    State(state): State<Arc<TenantState>>,
+    Path(tenant_id): Path<Uuid>,
+    Json(req): Json<UpdateTenantSettingsRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let default_locale: Locale = req
+        .default_locale
+        .parse()
+        .map_err(|_| CoreError::BadRequest(Message::new("unsupported-locale")))?;
+    let time_zone = req
+        .time_zone
+        .as_deref()
+        .map(str::parse::<TimeZone>)
+        .transpose()
+        .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+    let tenant = state
+        .usecase
+        .update_settings(&TenantId::from_uuid(tenant_id), default_locale, time_zone)
+        .await?;
+
+    Ok((StatusCode::OK, Json(TenantSettingsDto::from(&tenant))))
+}
@@ -0,0 +1,303 @@
+//! # Webhook ハンドラ
+//!
+//! Core API の Webhook 管理内部 API を提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /internal/webhooks` - テナントの Webhook 一覧
+//! - `POST /internal/webhooks` - Webhook 作成
+//! - `GET /internal/webhooks/{webhook_id}` - Webhook 詳細
+//! - `PUT /internal/webhooks/{webhook_id}` - Webhook 更新
+//! - `DELETE /internal/webhooks/{webhook_id}` - Webhook 削除
+//! - `GET /internal/webhooks/{webhook_id}/deliveries` - 配信ログ
+//! - `POST /internal/webhooks/{webhook_id}/test` - テストイベント送信
+//!
+//! シークレットはレスポンスに含めない。
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use ringiflow_domain::{
+    tenant::TenantId,
+    webhook::{WebhookDelivery, WebhookSubscription, WebhookSubscriptionId},
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::CoreError,
+    usecase::webhook::{CreateWebhookInput, UpdateWebhookInput, WebhookUseCaseImpl},
+};
+
+/// Webhook API の共有状態
+pub struct WebhookState {
+    pub usecase: WebhookUseCaseImpl,
+}
+
+// --- リクエスト/レスポンス型 ---
+
+/// テナント ID クエリパラメータ
+#[derive(Debug, Deserialize)]
+pub struct WebhookTenantQuery {
+    pub tenant_id: Uuid,
+}
+
+/// テナント ID のみを含むリクエストボディ（テスト送信用）
+#[derive(Debug, Deserialize)]
+pub struct WebhookTenantRequest {
+    pub tenant_id: Uuid,
+}
+
+/// Webhook 作成リクエスト
+#[derive(Debug, Deserialize)]
+pub struct CreateWebhookRequest {
+    pub tenant_id:   Uuid,
+    pub name:        String,
+    pub url:         String,
+    pub event_types: Vec<String>,
+    pub secret:      String,
+    pub created_by:  Uuid,
+}
+
+/// Webhook 更新リクエスト
+#[derive(Debug, Deserialize)]
+pub struct UpdateWebhookRequest {
+    pub tenant_id:   Uuid,
+    pub name:        Option<String>,
+    pub url:         Option<String>,
+    pub event_types: Option<Vec<String>>,
+    pub secret:      Option<String>,
+    pub is_active:   Option<bool>,
+}
+
+/// Webhook DTO
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct WebhookDto {
+    pub id:          Uuid,
+    pub name:        String,
+    pub url:         String,
+    pub event_types: Vec<String>,
+    pub is_active:   bool,
+    pub created_by:  Uuid,
+    pub created_at:  String,
+    pub updated_at:  String,
+}
+
+impl From<&WebhookSubscription> for WebhookDto {
+    fn from(subscription: &WebhookSubscription) -> Self {
+        Self {
+            id:          *subscription.id().as_uuid(),
+            name:        subscription.name().as_str().to_string(),
+            url:         subscription.url().as_str().to_string(),
+            event_types: subscription
+                .event_types()
+                .iter()
+                .map(|t| t.to_string())
+                .collect(),
+            is_active:   subscription.is_active(),
+            created_by:  *subscription.created_by().as_uuid(),
+            created_at:  subscription.created_at().to_rfc3339(),
+            updated_at:  subscription.updated_at().to_rfc3339(),
+        }
+    }
+}
+
+/// Webhook 配信 DTO
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct WebhookDeliveryDto {
+    pub id: Uuid,
+    pub event_id: Option<Uuid>,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: String,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub delivered_at: Option<String>,
+}
+
+impl From<&WebhookDelivery> for WebhookDeliveryDto {
+    fn from(delivery: &WebhookDelivery) -> Self {
+        Self {
+            id: *delivery.id().as_uuid(),
+            event_id: delivery.event_id().map(|id| *id.as_uuid()),
+            event_type: delivery.event_type().to_string(),
+            payload: delivery.payload().clone(),
+            status: delivery.status().to_string(),
+            attempts: delivery.attempts(),
+            next_attempt_at: delivery.next_attempt_at().to_rfc3339(),
+            response_status: delivery.response_status(),
+            last_error: delivery.last_error().map(str::to_string),
+            created_at: delivery.created_at().to_rfc3339(),
+            delivered_at: delivery.delivered_at().map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /internal/webhooks
+///
+/// テナントの Webhook 一覧を作成日時順で取得する。
+#[tracing::instrument(skip_all)]
+pub async fn list_webhooks(
+    State(state): State<Arc<WebhookState>>,
+    Query(query): Query<WebhookTenantQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let subscriptions = state.usecase.list_webhooks(&tenant_id).await?;
+
+    let items: Vec<WebhookDto> = subscriptions.iter().map(WebhookDto::from).collect();
+    Ok((StatusCode::OK, Json(items)))
+}
+
+/// POST /internal/webhooks
+///
+/// Webhook を作成する。
+///
+/// ## レスポンス
+///
+/// - `201 Created`: 作成された Webhook
+/// - `400 Bad Request`: バリデーションエラー
+#[tracing::instrument(skip_all)]
+pub async fn create_webhook(
+    State(state): State<Arc<WebhookState>>,
+    Json(req): Json<CreateWebhookRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let input = CreateWebhookInput {
+        tenant_id:   TenantId::from_uuid(req.tenant_id),
+        name:        req.name,
+        url:         req.url,
+        event_types: req.event_types,
+        secret:      req.secret,
+        created_by:  req.created_by,
+    };
+
+    let subscription = state.usecase.create_webhook(input).await?;
+
+    Ok((StatusCode::CREATED, Json(WebhookDto::from(&subscription))))
+}
+
+/// GET /internal/webhooks/{webhook_id}
+///
+/// ## レスポンス
+///
+/// - `200 OK`: Webhook
+/// - `404 Not Found`: Webhook が見つからない
+#[tracing::instrument(skip_all, fields(%webhook_id))]
+pub async fn get_webhook(
+    State(state): State<Arc<WebhookState>>,
+    Path(webhook_id): Path<Uuid>,
+    Query(query): Query<WebhookTenantQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let id = WebhookSubscriptionId::from_uuid(webhook_id);
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let subscription = state.usecase.get_webhook(&id, &tenant_id).await?;
+
+    Ok((StatusCode::OK, Json(WebhookDto::from(&subscription))))
+}
+
+/// PUT /internal/webhooks/{webhook_id}
+///
+/// Webhook を更新する。指定した項目のみ変更する。
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 更新後の Webhook
+/// - `400 Bad Request`: バリデーションエラー
+/// - `404 Not Found`: Webhook が見つからない
+#[tracing::instrument(skip_all, fields(%webhook_id))]
+pub async fn update_webhook(
+    State(state): State<Arc<WebhookState>>,
+    Path(webhook_id): Path<Uuid>,
+    Json(req): Json<UpdateWebhookRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let input = UpdateWebhookInput {
+        subscription_id: WebhookSubscriptionId::from_uuid(webhook_id),
+        tenant_id: TenantId::from_uuid(req.tenant_id),
+        name: req.name,
+        url: req.url,
+        event_types: req.event_types,
+        secret: req.secret,
+        is_active: req.is_active,
+    };
+
+    let subscription = state.usecase.update_webhook(input).await?;
+
+    Ok((StatusCode::OK, Json(WebhookDto::from(&subscription))))
+}
+
+/// DELETE /internal/webhooks/{webhook_id}
+///
+/// ## レスポンス
+///
+/// - `204 No Content`: 削除成功
+/// - `404 Not Found`: Webhook が見つからない
+#[tracing::instrument(skip_all, fields(%webhook_id))]
+pub async fn delete_webhook(
+    State(state): State<Arc<WebhookState>>,
+    Path(webhook_id): Path<Uuid>,
+    Query(query): Query<WebhookTenantQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let id = WebhookSubscriptionId::from_uuid(webhook_id);
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    state.usecase.delete_webhook(&id, &tenant_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /internal/webhooks/{webhook_id}/deliveries
+///
+/// 配信ログを新しい順で取得する。
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 配信ログ
+/// - `404 Not Found`: Webhook が見つからない
+#[tracing::instrument(skip_all, fields(%webhook_id))]
+pub async fn list_webhook_deliveries(
+    State(state): State<Arc<WebhookState>>,
+    Path(webhook_id): Path<Uuid>,
+    Query(query): Query<WebhookTenantQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let id = WebhookSubscriptionId::from_uuid(webhook_id);
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let deliveries = state.usecase.list_deliveries(&id, &tenant_id).await?;
+
+    let items: Vec<WebhookDeliveryDto> = deliveries.iter().map(WebhookDeliveryDto::from).collect();
+    Ok((StatusCode::OK, Json(items)))
+}
+
+/// POST /internal/webhooks/{webhook_id}/test
+///
+/// テストイベント（`ping`）を送信し、配信結果を返す。
+/// 送信先がエラーを返した場合も `200 OK` で配信結果（失敗）を返す。
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 配信結果
+/// - `404 Not Found`: Webhook が見つからない
+#[tracing::instrument(skip_all, fields(%webhook_id))]
+pub async fn send_test_webhook(
+    State(state): State<Arc<WebhookState>>,
+    Path(webhook_id): Path<Uuid>,
+    Json(req): Json<WebhookTenantRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let id = WebhookSubscriptionId::from_uuid(webhook_id);
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+
+    let delivery = state.usecase.send_test(&id, &tenant_id).await?;
+
+    Ok((StatusCode::OK, Json(WebhookDeliveryDto::from(&delivery))))
+}
@@ -9,21 +9,43 @@
 mod command;
 mod query;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 pub use command::*;
 pub use query::*;
 use ringiflow_domain::{
+    message::Message,
     user::UserId,
     value_objects::{DisplayId, DisplayNumber, Version, display_prefix},
-    workflow::{WorkflowComment, WorkflowDefinition, WorkflowInstance, WorkflowStep},
+    workflow::{
+        FormFieldDiff,
+        SubmissionAttachment,
+        SubmissionDiff,
+        WorkflowActivity,
+        WorkflowComment,
+        WorkflowCommentRevision,
+        WorkflowDefinition,
+        WorkflowFormDataChange,
+        WorkflowInstance,
+        WorkflowStep,
+        WorkflowSubmission,
+        WorkflowViewer,
+        WorkflowWatcher,
+    },
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
     error::CoreError,
-    usecase::{StepApprover, WorkflowUseCaseImpl, WorkflowWithSteps},
+    usecase::{
+        BulkDecisionAction,
+        BulkDecisionItemResult,
+        BulkDecisionOutcome,
+        StepApprover,
+        WorkflowUseCaseImpl,
+        WorkflowWithSteps,
+    },
 };
 
 /// ワークフロー作成リクエスト
@@ -35,12 +57,30 @@ pub struct CreateWorkflowRequest {
     pub title:         String,
     /// フォームデータ
     pub form_data:     serde_json::Value,
+    /// 代理作成時の本人のユーザー ID（省略時は本人として作成）
+    #[serde(default)]
+    pub on_behalf_of:  Option<Uuid>,
     /// テナント ID (内部 API 用)
     pub tenant_id:     Uuid,
-    /// 申請者のユーザー ID (内部 API 用)
+    /// 操作者のユーザー ID (内部 API 用)
     pub user_id:       Uuid,
 }
 
+/// ワークフロー複製リクエスト
+#[derive(Debug, Deserialize)]
+pub struct DuplicateWorkflowRequest {
+    /// 添付ファイルも複製するか（省略時は false）
+    #[serde(default)]
+    pub copy_attachments:  bool,
+    /// テナント ID (内部 API 用)
+    pub tenant_id:         Uuid,
+    /// 複製するユーザー ID (内部 API 用、複製先の申請者になる)
+    pub user_id:           Uuid,
+    /// 複製するユーザーがワークフロー管理者か（`workflow:admin` 権限）
+    #[serde(default)]
+    pub is_workflow_admin: bool,
+}
+
 /// ステップ承認者リクエスト
 #[derive(Debug, Deserialize)]
 pub struct StepApproverRequest {
@@ -78,8 +118,65 @@ pub struct ResubmitWorkflowRequest {
 #[derive(Debug, Deserialize)]
 pub struct ApproveRejectRequest {
     /// 楽観的ロック用バージョン
-    pub version:   i32,
+    pub version:         i32,
     /// コメント（任意）
+    pub comment:         Option<String>,
+    /// 承認者によるフォームデータ編集（承認時のみ、任意）
+    #[serde(default)]
+    pub form_data_edits: Option<serde_json::Map<String, serde_json::Value>>,
+    /// テナント ID (内部 API 用)
+    pub tenant_id:       Uuid,
+    /// 操作するユーザー ID (内部 API 用)
+    pub user_id:         Uuid,
+}
+
+/// 承認取り消しリクエスト
+#[derive(Debug, Deserialize)]
+pub struct RetractApprovalRequest {
+    /// 楽観的ロック用バージョン
+    pub version:   i32,
+    /// テナント ID (内部 API 用)
+    pub tenant_id: Uuid,
+    /// 操作するユーザー ID (内部 API 用)
+    pub user_id:   Uuid,
+}
+
+/// 一括判断の操作種別（リクエスト用）
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkDecisionActionRequest {
+    Approve,
+    Reject,
+}
+
+impl From<BulkDecisionActionRequest> for BulkDecisionAction {
+    fn from(action: BulkDecisionActionRequest) -> Self {
+        match action {
+            BulkDecisionActionRequest::Approve => Self::Approve,
+            BulkDecisionActionRequest::Reject => Self::Reject,
+        }
+    }
+}
+
+/// 一括判断の対象ステップ（リクエスト用）
+#[derive(Debug, Deserialize)]
+pub struct BulkDecisionItemRequest {
+    /// ワークフローインスタンスの表示用連番
+    pub workflow_display_number: i64,
+    /// ステップの表示用連番
+    pub step_display_number: i64,
+    /// 楽観的ロック用バージョン
+    pub version: i32,
+}
+
+/// ステップ一括承認/却下リクエスト
+#[derive(Debug, Deserialize)]
+pub struct BulkDecisionRequest {
+    /// 操作種別
+    pub action:    BulkDecisionActionRequest,
+    /// 対象ステップ
+    pub items:     Vec<BulkDecisionItemRequest>,
+    /// コメント（任意、全項目共通）
     pub comment:   Option<String>,
     /// テナント ID (内部 API 用)
     pub tenant_id: Uuid,
@@ -108,6 +205,15 @@ pub struct StepByDisplayNumberPathParams {
     pub step_display_number: i64,
 }
 
+/// コメント操作のパスパラメータ
+#[derive(Debug, Deserialize)]
+pub struct CommentPathParams {
+    /// ワークフローインスタンスの表示用連番
+    pub display_number: i64,
+    /// コメント ID
+    pub comment_id:     Uuid,
+}
+
 /// テナント指定クエリパラメータ（GET リクエスト用）
 #[derive(Debug, Deserialize)]
 pub struct TenantQuery {
@@ -115,6 +221,42 @@ pub struct TenantQuery {
     pub tenant_id: Uuid,
 }
 
+/// 閲覧者指定クエリパラメータ（ワークフローの参照系 GET リクエスト用）
+///
+/// 閲覧権限のチェックに使用する（[`WorkflowViewer`] 参照）。
+#[derive(Debug, Deserialize)]
+pub struct ViewerQuery {
+    /// テナント ID
+    pub tenant_id:         Uuid,
+    /// 閲覧するユーザー ID
+    pub user_id:           Uuid,
+    /// 閲覧するユーザーがワークフロー管理者か（`workflow:admin` 権限）
+    #[serde(default)]
+    pub is_workflow_admin: bool,
+}
+
+impl ViewerQuery {
+    pub(crate) fn viewer(&self) -> WorkflowViewer {
+        WorkflowViewer::new(UserId::from_uuid(self.user_id), self.is_workflow_admin)
+    }
+}
+
+/// 申請ラウンド差分のクエリパラメータ
+#[derive(Debug, Deserialize)]
+pub struct SubmissionDiffQuery {
+    /// テナント ID
+    pub tenant_id: Uuid,
+    /// 閲覧するユーザー ID
+    pub user_id: Uuid,
+    /// 閲覧するユーザーがワークフロー管理者か（`workflow:admin` 権限）
+    #[serde(default)]
+    pub is_workflow_admin: bool,
+    /// 比較元ラウンド（省略時は比較先の直前のラウンド）
+    pub from: Option<i32>,
+    /// 比較先ラウンド（省略時は最新ラウンド）
+    pub to: Option<i32>,
+}
+
 /// ユーザー指定クエリパラメータ（GET リクエスト用）
 #[derive(Debug, Deserialize)]
 pub struct UserQuery {
@@ -124,6 +266,19 @@ pub struct UserQuery {
     pub user_id:   Uuid,
 }
 
+/// ページ指定付きのユーザー指定クエリパラメータ（一覧 API 用）
+#[derive(Debug, Deserialize)]
+pub struct UserPageQuery {
+    /// テナント ID
+    pub tenant_id: Uuid,
+    /// ユーザー ID
+    pub user_id:   Uuid,
+    /// 前ページの `next_cursor`
+    pub cursor:    Option<String>,
+    /// 取得件数
+    pub limit:     Option<i64>,
+}
+
 /// ユーザー参照 DTO
 ///
 /// UUID 文字列の代わりに、ID とユーザー名をペアで返す。
@@ -149,29 +304,37 @@ pub(crate) fn to_user_ref(user_id: &UserId, user_names: &HashMap<UserId, String>
 /// ワークフロー定義 DTO
 #[derive(Debug, Serialize)]
 pub struct WorkflowDefinitionDto {
-    pub id:          String,
-    pub name:        String,
+    pub id: String,
+    pub name: String,
     pub description: Option<String>,
-    pub version:     i32,
-    pub definition:  serde_json::Value,
-    pub status:      String,
-    pub created_by:  String,
-    pub created_at:  String,
-    pub updated_at:  String,
+    pub version: i32,
+    pub definition: serde_json::Value,
+    pub status: String,
+    pub confidential: bool,
+    pub default_watchers: Vec<String>,
+    pub created_by: String,
+    pub created_at: String,
+    pub updated_at: String,
 }
 
 impl From<WorkflowDefinition> for WorkflowDefinitionDto {
     fn from(def: WorkflowDefinition) -> Self {
         Self {
-            id:          def.id().to_string(),
-            name:        def.name().to_string(),
+            id: def.id().to_string(),
+            name: def.name().to_string(),
             description: def.description().map(|s| s.to_string()),
-            version:     def.version().as_i32(),
-            definition:  def.definition().clone(),
-            status:      format!("{:?}", def.status()),
-            created_by:  def.created_by().to_string(),
-            created_at:  def.created_at().to_rfc3339(),
-            updated_at:  def.updated_at().to_rfc3339(),
+            version: def.version().as_i32(),
+            definition: def.definition().clone(),
+            status: format!("{:?}", def.status()),
+            confidential: def.is_confidential(),
+            default_watchers: def
+                .default_watchers()
+                .iter()
+                .map(|u| u.to_string())
+                .collect(),
+            created_by: def.created_by().to_string(),
+            created_at: def.created_at().to_rfc3339(),
+            updated_at: def.updated_at().to_rfc3339(),
         }
     }
 }
@@ -236,6 +399,8 @@ pub struct WorkflowInstanceBaseDto {
     pub version: i32,
     pub form_data: serde_json::Value,
     pub initiated_by: UserRefDto,
+    /// 代理申請の代理人（本人による申請の場合は None）
+    pub submitted_by: Option<UserRefDto>,
     pub current_step_id: Option<String>,
     pub submitted_at: Option<String>,
     pub completed_at: Option<String>,
@@ -259,6 +424,9 @@ fn build_base_dto(
         version: instance.version().as_i32(),
         form_data: instance.form_data().clone(),
         initiated_by: to_user_ref(instance.initiated_by(), user_names),
+        submitted_by: instance
+            .proxy_submitter()
+            .map(|u| to_user_ref(u, user_names)),
         current_step_id: instance.current_step_id().map(|s| s.to_string()),
         submitted_at: instance.submitted_at().map(|t| t.to_rfc3339()),
         completed_at: instance.completed_at().map(|t| t.to_rfc3339()),
@@ -345,23 +513,70 @@ impl WorkflowInstanceDetailDto {
     }
 }
 
+/// テナント管理者による強制操作（取消・強制完了）リクエスト
+#[derive(Debug, Deserialize)]
+pub struct AdminActionRequest {
+    /// 楽観的ロック用バージョン（インスタンスのバージョン）
+    pub version:   i32,
+    /// 操作理由
+    pub reason:    String,
+    /// テナント ID (内部 API 用)
+    pub tenant_id: Uuid,
+    /// 操作する管理者のユーザー ID (内部 API 用)
+    pub user_id:   Uuid,
+}
+
+/// テナント管理者による担当者変更リクエスト
+#[derive(Debug, Deserialize)]
+pub struct ReassignStepRequest {
+    /// 楽観的ロック用バージョン（インスタンスのバージョン）
+    pub version:     i32,
+    /// 変更後の担当者のユーザー ID
+    pub assigned_to: Uuid,
+    /// 操作理由
+    pub reason:      String,
+    /// テナント ID (内部 API 用)
+    pub tenant_id:   Uuid,
+    /// 操作する管理者のユーザー ID (内部 API 用)
+    pub user_id:     Uuid,
+}
+
 /// コメント投稿リクエスト
 #[derive(Debug, Deserialize)]
 pub struct PostCommentRequest {
     /// コメント本文
     pub body:      String,
+    /// 返信先の親コメント ID（トップレベルのコメントは省略）
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
     /// テナント ID (内部 API 用)
     pub tenant_id: Uuid,
     /// 投稿者のユーザー ID (内部 API 用)
     pub user_id:   Uuid,
 }
 
+/// コメント編集リクエスト
+#[derive(Debug, Deserialize)]
+pub struct EditCommentRequest {
+    /// 編集後のコメント本文
+    pub body:      String,
+    /// テナント ID (内部 API 用)
+    pub tenant_id: Uuid,
+    /// 編集者のユーザー ID (内部 API 用)
+    pub user_id:   Uuid,
+}
+
 /// ワークフローコメント DTO
+///
+/// 削除済みのコメントは返信のスレッドを保つため一覧に残し、本文を空にして返す。
 #[derive(Debug, Serialize)]
 pub struct WorkflowCommentDto {
     pub id:         String,
+    pub parent_id:  Option<String>,
     pub posted_by:  UserRefDto,
     pub body:       String,
+    pub edited_at:  Option<String>,
+    pub deleted_at: Option<String>,
     pub created_at: String,
 }
 
@@ -372,13 +587,269 @@ impl WorkflowCommentDto {
     ) -> Self {
         Self {
             id:         comment.id().to_string(),
+            parent_id:  comment.parent_id().map(|id| id.to_string()),
             posted_by:  to_user_ref(comment.posted_by(), user_names),
-            body:       comment.body().as_str().to_string(),
+            body:       if comment.is_deleted() {
+                String::new()
+            } else {
+                comment.body().as_str().to_string()
+            },
+            edited_at:  comment.edited_at().map(|t| t.to_rfc3339()),
+            deleted_at: comment.deleted_at().map(|t| t.to_rfc3339()),
             created_at: comment.created_at().to_rfc3339(),
         }
     }
 }
 
+/// ワークフローコメント編集履歴 DTO
+#[derive(Debug, Serialize)]
+pub struct WorkflowCommentRevisionDto {
+    pub id:        String,
+    /// 編集前の本文
+    pub body:      String,
+    pub edited_by: UserRefDto,
+    pub edited_at: String,
+}
+
+impl WorkflowCommentRevisionDto {
+    pub(crate) fn from_revision(
+        revision: &WorkflowCommentRevision,
+        user_names: &HashMap<UserId, String>,
+    ) -> Self {
+        Self {
+            id:        revision.id().to_string(),
+            body:      revision.body().as_str().to_string(),
+            edited_by: to_user_ref(revision.edited_by(), user_names),
+            edited_at: revision.edited_at().to_rfc3339(),
+        }
+    }
+}
+
+/// ワークフローウォッチャー DTO
+#[derive(Debug, Serialize)]
+pub struct WorkflowWatcherDto {
+    pub user:       UserRefDto,
+    pub created_at: String,
+}
+
+impl WorkflowWatcherDto {
+    pub(crate) fn from_watcher(
+        watcher: &WorkflowWatcher,
+        user_names: &HashMap<UserId, String>,
+    ) -> Self {
+        Self {
+            user:       to_user_ref(watcher.user_id(), user_names),
+            created_at: watcher.created_at().to_rfc3339(),
+        }
+    }
+}
+
+/// フォームデータ変更履歴 DTO
+#[derive(Debug, Serialize)]
+pub struct WorkflowFormDataChangeDto {
+    pub id:         String,
+    pub step_id:    String,
+    pub field_id:   String,
+    pub old_value:  serde_json::Value,
+    pub new_value:  serde_json::Value,
+    pub changed_by: UserRefDto,
+    pub changed_at: String,
+}
+
+impl WorkflowFormDataChangeDto {
+    pub(crate) fn from_change(
+        change: &WorkflowFormDataChange,
+        user_names: &HashMap<UserId, String>,
+    ) -> Self {
+        Self {
+            id:         change.id().to_string(),
+            step_id:    change.step_id().to_string(),
+            field_id:   change.field_id().to_string(),
+            old_value:  change.old_value().clone(),
+            new_value:  change.new_value().clone(),
+            changed_by: to_user_ref(change.changed_by(), user_names),
+            changed_at: change.changed_at().to_rfc3339(),
+        }
+    }
+}
+
+/// ワークフローアクティビティ DTO
+#[derive(Debug, Serialize)]
+pub struct WorkflowActivityDto {
+    pub id: String,
+    pub activity_type: String,
+    /// 操作者（システムによる操作の場合は None）
+    pub actor: Option<UserRefDto>,
+    pub step_id: Option<String>,
+    pub details: serde_json::Value,
+    pub occurred_at: String,
+}
+
+impl WorkflowActivityDto {
+    pub(crate) fn from_activity(
+        activity: &WorkflowActivity,
+        user_names: &HashMap<UserId, String>,
+    ) -> Self {
+        Self {
+            id: activity.id().to_string(),
+            activity_type: activity.activity_type().to_string(),
+            actor: activity
+                .actor_id()
+                .map(|actor_id| to_user_ref(actor_id, user_names)),
+            step_id: activity.step_id().map(|id| id.to_string()),
+            details: activity.details().clone(),
+            occurred_at: activity.occurred_at().to_rfc3339(),
+        }
+    }
+}
+
+/// 一括判断の項目ごとの結果 DTO
+#[derive(Debug, Serialize)]
+pub struct BulkDecisionItemResultDto {
+    pub workflow_display_number: i64,
+    pub step_display_number: i64,
+    /// `succeeded` / `conflict` / `not_found` / `forbidden` / `invalid` / `failed`
+    pub outcome: &'static str,
+    /// 失敗理由（既定のロケールで展開した文言）
+    pub message: Option<String>,
+    /// `message` の元になったメッセージコード（BFF が要求されたロケールで展開し直す）
+    pub message_code: Option<String>,
+    /// `message_code` のパラメータ
+    pub params: BTreeMap<String, String>,
+}
+
+impl From<BulkDecisionItemResult> for BulkDecisionItemResultDto {
+    fn from(result: BulkDecisionItemResult) -> Self {
+        let outcome = match result.outcome {
+            BulkDecisionOutcome::Succeeded => "succeeded",
+            BulkDecisionOutcome::Conflict => "conflict",
+            BulkDecisionOutcome::NotFound => "not_found",
+            BulkDecisionOutcome::Forbidden => "forbidden",
+            BulkDecisionOutcome::Invalid => "invalid",
+            BulkDecisionOutcome::Failed => "failed",
+        };
+        let (message, message_code, params) = match result.message {
+            Some(message) => {
+                let detail = message.to_string();
+                let (code, params) = message.into_parts();
+                (Some(detail), Some(code), params)
+            }
+            None => (None, None, BTreeMap::new()),
+        };
+        Self {
+            workflow_display_number: result.workflow_display_number.as_i64(),
+            step_display_number: result.step_display_number.as_i64(),
+            outcome,
+            message,
+            message_code,
+            params,
+        }
+    }
+}
+
+/// 申請スナップショットの添付ファイル DTO
+#[derive(Debug, Serialize)]
+pub struct SubmissionAttachmentDto {
+    pub document_id:  String,
+    pub filename:     String,
+    pub content_type: String,
+    pub size:         i64,
+}
+
+impl From<&SubmissionAttachment> for SubmissionAttachmentDto {
+    fn from(attachment: &SubmissionAttachment) -> Self {
+        Self {
+            document_id:  attachment.document_id.to_string(),
+            filename:     attachment.filename.clone(),
+            content_type: attachment.content_type.clone(),
+            size:         attachment.size,
+        }
+    }
+}
+
+/// 申請スナップショット DTO
+#[derive(Debug, Serialize)]
+pub struct WorkflowSubmissionDto {
+    pub id:           String,
+    pub round:        i32,
+    pub form_data:    serde_json::Value,
+    pub attachments:  Vec<SubmissionAttachmentDto>,
+    pub submitted_by: UserRefDto,
+    pub submitted_at: String,
+}
+
+impl WorkflowSubmissionDto {
+    pub(crate) fn from_submission(
+        submission: &WorkflowSubmission,
+        user_names: &HashMap<UserId, String>,
+    ) -> Self {
+        Self {
+            id:           submission.id().to_string(),
+            round:        submission.round(),
+            form_data:    submission.form_data().clone(),
+            attachments:  submission
+                .attachments()
+                .iter()
+                .map(SubmissionAttachmentDto::from)
+                .collect(),
+            submitted_by: to_user_ref(submission.submitted_by(), user_names),
+            submitted_at: submission.submitted_at().to_rfc3339(),
+        }
+    }
+}
+
+/// フィールド単位の差分 DTO
+#[derive(Debug, Serialize)]
+pub struct FormFieldDiffDto {
+    pub field_id:  String,
+    pub old_value: serde_json::Value,
+    pub new_value: serde_json::Value,
+}
+
+impl From<FormFieldDiff> for FormFieldDiffDto {
+    fn from(diff: FormFieldDiff) -> Self {
+        Self {
+            field_id:  diff.field_id,
+            old_value: diff.old_value,
+            new_value: diff.new_value,
+        }
+    }
+}
+
+/// 申請ラウンド間の差分 DTO
+#[derive(Debug, Serialize)]
+pub struct SubmissionDiffDto {
+    pub from_round: i32,
+    pub to_round: i32,
+    pub form_data: Vec<FormFieldDiffDto>,
+    pub added_attachments: Vec<SubmissionAttachmentDto>,
+    pub removed_attachments: Vec<SubmissionAttachmentDto>,
+}
+
+impl From<SubmissionDiff> for SubmissionDiffDto {
+    fn from(diff: SubmissionDiff) -> Self {
+        Self {
+            from_round: diff.from_round,
+            to_round: diff.to_round,
+            form_data: diff
+                .form_data
+                .into_iter()
+                .map(FormFieldDiffDto::from)
+                .collect(),
+            added_attachments: diff
+                .added_attachments
+                .iter()
+                .map(SubmissionAttachmentDto::from)
+                .collect(),
+            removed_attachments: diff
+                .removed_attachments
+                .iter()
+                .map(SubmissionAttachmentDto::from)
+                .collect(),
+        }
+    }
+}
+
 /// ワークフローハンドラーの State
 pub struct WorkflowState {
     pub usecase: WorkflowUseCaseImpl,
@@ -387,13 +858,20 @@ pub struct WorkflowState {
 /// i64 を DisplayNumber に変換する。
 /// 不正な値の場合は CoreError::BadRequest を返す。
 pub(crate) fn parse_display_number(value: i64, field: &str) -> Result<DisplayNumber, CoreError> {
-    DisplayNumber::try_from(value)
-        .map_err(|e| CoreError::BadRequest(format!("不正な {field}: {e}")))
+    DisplayNumber::try_from(value).map_err(|e| {
+        CoreError::BadRequest(
+            Message::new("invalid-parameter")
+                .with("name", field)
+                .with("value", e.into_message()),
+        )
+    })
 }
 
 /// i32 を Version に変換する。
 pub(crate) fn parse_version(value: i32) -> Result<Version, CoreError> {
-    Version::try_from(value).map_err(|e| CoreError::BadRequest(format!("不正なバージョン: {e}")))
+    Version::try_from(value).map_err(|e| {
+        CoreError::BadRequest(Message::new("invalid-version").with("value", e.into_message()))
+    })
 }
 
 /// StepApproverRequest のリストを StepApprover のリストに変換する。
@@ -424,7 +902,7 @@ mod tests {
     fn test_parse_display_numberはゼロで不正リクエストエラーを返す() {
         let result = parse_display_number(0, "display_number");
         assert!(
-            matches!(result, Err(CoreError::BadRequest(msg)) if msg.contains("display_number"))
+            matches!(result, Err(CoreError::BadRequest(msg)) if msg.to_string().contains("display_number"))
         );
     }
 
@@ -432,7 +910,7 @@ mod tests {
     fn test_parse_display_numberは負数で不正リクエストエラーを返す() {
         let result = parse_display_number(-1, "step_display_number");
         assert!(
-            matches!(result, Err(CoreError::BadRequest(msg)) if msg.contains("step_display_number"))
+            matches!(result, Err(CoreError::BadRequest(msg)) if msg.to_string().contains("step_display_number"))
         );
     }
 
@@ -446,7 +924,9 @@ mod tests {
     #[test]
     fn test_parse_versionはゼロで不正リクエストエラーを返す() {
         let result = parse_version(0);
-        assert!(matches!(result, Err(CoreError::BadRequest(msg)) if msg.contains("バージョン")));
+        assert!(
+            matches!(result, Err(CoreError::BadRequest(msg)) if msg.to_string().contains("バージョン"))
+        );
     }
 
     #[test]
@@ -478,4 +958,24 @@ mod tests {
         assert_eq!(result[1].step_id, "step-2");
         assert_eq!(*result[1].assigned_to.as_uuid(), uuid2);
     }
+
+    #[test]
+    fn test_一括判断の結果はメッセージコードとパラメータを含む() {
+        let result = BulkDecisionItemResult {
+            workflow_display_number: DisplayNumber::new(1).unwrap(),
+            step_display_number: DisplayNumber::new(2).unwrap(),
+            outcome: BulkDecisionOutcome::Invalid,
+            message: Some(Message::new("step-approve-not-active").with("status", "completed")),
+        };
+
+        let dto = BulkDecisionItemResultDto::from(result);
+
+        assert_eq!(dto.outcome, "invalid");
+        assert_eq!(dto.message_code.as_deref(), Some("step-approve-not-active"));
+        assert_eq!(
+            dto.params,
+            BTreeMap::from([("status".to_string(), "completed".to_string())])
+        );
+        assert!(dto.message.is_some());
+    }
 }
@@ -4,25 +4,42 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::{IntoResponse, Response},
 };
 use ringiflow_domain::{
     tenant::TenantId,
     user::UserId,
-    workflow::{WorkflowDefinitionId, WorkflowInstanceId, WorkflowStepId},
+    workflow::{
+        AdminActionReason,
+        WorkflowCommentId,
+        WorkflowDefinitionId,
+        WorkflowInstanceId,
+        WorkflowStepId,
+        WorkflowViewer,
+    },
 };
 use uuid::Uuid;
 
 use super::{
+    AdminActionRequest,
     ApproveRejectRequest,
+    BulkDecisionItemResultDto,
+    BulkDecisionRequest,
+    CommentPathParams,
     CreateWorkflowRequest,
+    DuplicateWorkflowRequest,
+    EditCommentRequest,
     PostCommentRequest,
+    ReassignStepRequest,
     ResubmitWorkflowRequest,
+    RetractApprovalRequest,
     StepByDisplayNumberPathParams,
     StepPathParams,
     SubmitWorkflowRequest,
+    UserQuery,
+    ViewerQuery,
     WorkflowCommentDto,
     WorkflowInstanceDetailDto,
     WorkflowState,
@@ -33,10 +50,17 @@ use super::{
 use crate::{
     error::CoreError,
     usecase::{
+        AdminActionInput,
         ApproveRejectInput,
+        BulkDecisionInput,
+        BulkDecisionItem,
         CreateWorkflowInput,
+        DuplicateWorkflowInput,
+        EditCommentInput,
         PostCommentInput,
+        ReassignStepInput,
         ResubmitWorkflowInput,
+        RetractApprovalInput,
         SubmitWorkflowInput,
     },
 };
@@ -65,6 +89,7 @@ pub async fn create_workflow(
         definition_id,
         title: req.title,
         form_data: req.form_data,
+        on_behalf_of: req.on_behalf_of.map(UserId::from_uuid),
     };
 
     let instance = state
@@ -79,6 +104,73 @@ pub async fn create_workflow(
     Ok((StatusCode::CREATED, Json(response)).into_response())
 }
 
+/// 既存のワークフローを複製して下書きを作成する
+///
+/// ## エンドポイント
+/// POST /internal/workflows/by-display-number/{display_number}/duplicate
+///
+/// ## 処理フロー
+/// 1. パスパラメータから display_number を取得
+/// 2. リクエストから閲覧者（複製するユーザー）を取得
+/// 3. ユースケースを呼び出し
+/// 4. 201 Created + 作成した下書きを返す
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn duplicate_workflow(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Json(req): Json<DuplicateWorkflowRequest>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let viewer = WorkflowViewer::new(UserId::from_uuid(req.user_id), req.is_workflow_admin);
+    let input = DuplicateWorkflowInput {
+        copy_attachments: req.copy_attachments,
+    };
+
+    let instance = state
+        .usecase
+        .duplicate_workflow(
+            input,
+            display_number,
+            TenantId::from_uuid(req.tenant_id),
+            &viewer,
+        )
+        .await?;
+
+    let dto = WorkflowInstanceDetailDto::resolve_from_instance(&instance, &state.usecase).await?;
+    Ok((StatusCode::CREATED, Json(dto)).into_response())
+}
+
+/// 下書きのワークフローを削除する
+///
+/// ## エンドポイント
+/// DELETE /internal/workflows/by-display-number/{display_number}?
+/// tenant_id={tenant_id}&user_id={user_id}
+///
+/// ## 処理フロー
+/// 1. パスパラメータから display_number を取得
+/// 2. クエリパラメータから tenant_id, user_id を取得
+/// 3. ユースケースを呼び出し
+/// 4. 204 No Content を返す
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn delete_workflow(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Query(query): Query<UserQuery>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+
+    state
+        .usecase
+        .delete_workflow(
+            display_number,
+            TenantId::from_uuid(query.tenant_id),
+            UserId::from_uuid(query.user_id),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
 /// ワークフローを申請する
 ///
 /// ## エンドポイント
@@ -140,6 +232,7 @@ pub async fn approve_step(
     let input = ApproveRejectInput {
         version,
         comment: req.comment,
+        form_data_edits: req.form_data_edits,
     };
 
     let workflow_with_steps = state
@@ -181,6 +274,7 @@ pub async fn reject_step(
     let input = ApproveRejectInput {
         version,
         comment: req.comment,
+        form_data_edits: req.form_data_edits,
     };
 
     let workflow_with_steps = state
@@ -261,6 +355,7 @@ pub async fn approve_step_by_display_number(
     let input = ApproveRejectInput {
         version,
         comment: req.comment,
+        form_data_edits: req.form_data_edits,
     };
 
     let workflow_with_steps = state
@@ -302,6 +397,7 @@ pub async fn request_changes_step(
     let input = ApproveRejectInput {
         version,
         comment: req.comment,
+        form_data_edits: req.form_data_edits,
     };
 
     let workflow_with_steps = state
@@ -382,6 +478,7 @@ pub async fn reject_step_by_display_number(
     let input = ApproveRejectInput {
         version,
         comment: req.comment,
+        form_data_edits: req.form_data_edits,
     };
 
     let workflow_with_steps = state
@@ -405,6 +502,59 @@ pub async fn reject_step_by_display_number(
     Ok((StatusCode::OK, Json(response)).into_response())
 }
 
+/// ワークフローステップを一括で承認/却下する
+///
+/// ## エンドポイント
+/// POST /internal/workflows/bulk-decision
+///
+/// ## 処理フロー
+/// 1. リクエストをパース（display_number / version の検証）
+/// 2. ユースケースを呼び出し（項目ごとに承認/却下）
+/// 3. 200 OK + 項目ごとの結果を返す
+#[tracing::instrument(skip_all, fields(item_count = req.items.len()))]
+pub async fn bulk_decide_steps(
+    State(state): State<Arc<WorkflowState>>,
+    Json(req): Json<BulkDecisionRequest>,
+) -> Result<Response, CoreError> {
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+    let user_id = UserId::from_uuid(req.user_id);
+    let items = req
+        .items
+        .into_iter()
+        .map(|item| {
+            Ok(BulkDecisionItem {
+                workflow_display_number: parse_display_number(
+                    item.workflow_display_number,
+                    "workflow_display_number",
+                )?,
+                step_display_number: parse_display_number(
+                    item.step_display_number,
+                    "step_display_number",
+                )?,
+                version: parse_version(item.version)?,
+            })
+        })
+        .collect::<Result<Vec<_>, CoreError>>()?;
+
+    let input = BulkDecisionInput {
+        action: req.action.into(),
+        items,
+        comment: req.comment,
+    };
+
+    let results = state
+        .usecase
+        .bulk_decide_steps(input, tenant_id, user_id)
+        .await?;
+
+    let response: Vec<BulkDecisionItemResultDto> = results
+        .into_iter()
+        .map(BulkDecisionItemResultDto::from)
+        .collect();
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
 /// display_number でワークフローステップを差し戻す
 ///
 /// ## エンドポイント
@@ -426,6 +576,7 @@ pub async fn request_changes_step_by_display_number(
     let input = ApproveRejectInput {
         version,
         comment: req.comment,
+        form_data_edits: req.form_data_edits,
     };
 
     let workflow_with_steps = state
@@ -449,6 +600,79 @@ pub async fn request_changes_step_by_display_number(
     Ok((StatusCode::OK, Json(response)).into_response())
 }
 
+/// ワークフローステップの承認を取り消す
+///
+/// ## エンドポイント
+/// POST /internal/workflows/{id}/steps/{step_id}/retract
+#[tracing::instrument(skip_all, fields(%params.step_id))]
+pub async fn retract_approval(
+    State(state): State<Arc<WorkflowState>>,
+    Path(params): Path<StepPathParams>,
+    Json(req): Json<RetractApprovalRequest>,
+) -> Result<Response, CoreError> {
+    let step_id = WorkflowStepId::from_uuid(params.step_id);
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+    let user_id = UserId::from_uuid(req.user_id);
+    let version = parse_version(req.version)?;
+
+    let input = RetractApprovalInput { version };
+
+    let workflow_with_steps = state
+        .usecase
+        .retract_approval(input, step_id, tenant_id, user_id)
+        .await?;
+
+    let dto = WorkflowInstanceDetailDto::resolve_from_workflow_with_steps(
+        &workflow_with_steps,
+        &state.usecase,
+    )
+    .await?;
+    let response = dto;
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// display_number でワークフローステップの承認を取り消す
+///
+/// ## エンドポイント
+/// POST /internal/workflows/by-display-number/{display_number}/steps/
+/// by-display-number/{step_display_number}/retract
+#[tracing::instrument(skip_all, fields(display_number = params.display_number, step_display_number = params.step_display_number))]
+pub async fn retract_approval_by_display_number(
+    State(state): State<Arc<WorkflowState>>,
+    Path(params): Path<StepByDisplayNumberPathParams>,
+    Json(req): Json<RetractApprovalRequest>,
+) -> Result<Response, CoreError> {
+    let workflow_display_number = parse_display_number(params.display_number, "display_number")?;
+    let step_display_number =
+        parse_display_number(params.step_display_number, "step_display_number")?;
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+    let user_id = UserId::from_uuid(req.user_id);
+    let version = parse_version(req.version)?;
+
+    let input = RetractApprovalInput { version };
+
+    let workflow_with_steps = state
+        .usecase
+        .retract_approval_by_display_number(
+            input,
+            workflow_display_number,
+            step_display_number,
+            tenant_id,
+            user_id,
+        )
+        .await?;
+
+    let dto = WorkflowInstanceDetailDto::resolve_from_workflow_with_steps(
+        &workflow_with_steps,
+        &state.usecase,
+    )
+    .await?;
+    let response = dto;
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
 /// display_number でワークフローを再申請する
 ///
 /// ## エンドポイント
@@ -507,7 +731,10 @@ pub async fn post_comment(
     let tenant_id = TenantId::from_uuid(req.tenant_id);
     let user_id = UserId::from_uuid(req.user_id);
 
-    let input = PostCommentInput { body: req.body };
+    let input = PostCommentInput {
+        body:      req.body,
+        parent_id: req.parent_id.map(WorkflowCommentId::from_uuid),
+    };
 
     let comment = state
         .usecase
@@ -522,3 +749,234 @@ pub async fn post_comment(
 
     Ok((StatusCode::CREATED, Json(response)).into_response())
 }
+
+/// 自分が投稿したコメントを編集する
+///
+/// ## エンドポイント
+/// PATCH /internal/workflows/by-display-number/{display_number}/comments/{comment_id}
+///
+/// ## 処理フロー
+/// 1. パスパラメータから display_number・コメント ID を取得
+/// 2. リクエストをパース
+/// 3. ユースケースを呼び出し
+/// 4. 200 OK + 編集後のコメントを返す
+#[tracing::instrument(skip_all, fields(display_number = params.display_number, comment_id = %params.comment_id))]
+pub async fn edit_comment(
+    State(state): State<Arc<WorkflowState>>,
+    Path(params): Path<CommentPathParams>,
+    Json(req): Json<EditCommentRequest>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(params.display_number, "display_number")?;
+    let tenant_id = TenantId::from_uuid(req.tenant_id);
+    let user_id = UserId::from_uuid(req.user_id);
+
+    let input = EditCommentInput { body: req.body };
+
+    let comment = state
+        .usecase
+        .edit_comment(
+            input,
+            display_number,
+            WorkflowCommentId::from_uuid(params.comment_id),
+            tenant_id,
+            user_id,
+        )
+        .await?;
+
+    let user_ids = vec![comment.posted_by().clone()];
+    let user_names = state.usecase.resolve_user_names(&user_ids).await?;
+
+    let response = WorkflowCommentDto::from_comment(&comment, &user_names);
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// コメントを削除する（論理削除）
+///
+/// ## エンドポイント
+/// DELETE /internal/workflows/by-display-number/{display_number}/comments/{comment_id}?
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
+#[tracing::instrument(skip_all, fields(display_number = params.display_number, comment_id = %params.comment_id))]
+pub async fn delete_comment(
+    State(state): State<Arc<WorkflowState>>,
+    Path(params): Path<CommentPathParams>,
+    Query(query): Query<ViewerQuery>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(params.display_number, "display_number")?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    state
+        .usecase
+        .delete_comment(
+            display_number,
+            WorkflowCommentId::from_uuid(params.comment_id),
+            tenant_id,
+            &query.viewer(),
+        )
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+// ===== ウォッチハンドラ =====
+
+/// ワークフローをウォッチする
+///
+/// ## エンドポイント
+/// POST /internal/workflows/by-display-number/{display_number}/watchers?
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
+///
+/// ## 処理フロー
+/// 1. パスパラメータから display_number を取得
+/// 2. クエリパラメータからテナント ID・閲覧者（ウォッチするユーザー）を取得
+/// 3. ユースケースを呼び出し
+/// 4. 204 No Content を返す
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn watch_workflow(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Query(query): Query<ViewerQuery>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    state
+        .usecase
+        .watch_workflow(display_number, tenant_id, &query.viewer())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// ワークフローのウォッチを解除する
+///
+/// ## エンドポイント
+/// DELETE /internal/workflows/by-display-number/{display_number}/watchers?
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn unwatch_workflow(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Query(query): Query<ViewerQuery>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    state
+        .usecase
+        .unwatch_workflow(display_number, tenant_id, &query.viewer())
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+// ===== テナント管理者による強制操作ハンドラ =====
+
+/// ワークフローを強制的に取り消す
+///
+/// ## エンドポイント
+/// POST /internal/admin/workflows/by-display-number/{display_number}/cancel
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn force_cancel_workflow(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Json(req): Json<AdminActionRequest>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let input = AdminActionInput {
+        version: parse_version(req.version)?,
+        reason:  AdminActionReason::new(req.reason)
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?,
+    };
+
+    let workflow_with_steps = state
+        .usecase
+        .force_cancel_workflow(
+            input,
+            display_number,
+            TenantId::from_uuid(req.tenant_id),
+            UserId::from_uuid(req.user_id),
+        )
+        .await?;
+
+    let response = WorkflowInstanceDetailDto::resolve_from_workflow_with_steps(
+        &workflow_with_steps,
+        &state.usecase,
+    )
+    .await?;
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// 現在のステップの担当者を変更する
+///
+/// ## エンドポイント
+/// POST /internal/admin/workflows/by-display-number/{display_number}/reassign
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn reassign_current_step(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Json(req): Json<ReassignStepRequest>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let input = ReassignStepInput {
+        version:     parse_version(req.version)?,
+        assigned_to: UserId::from_uuid(req.assigned_to),
+        reason:      AdminActionReason::new(req.reason)
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?,
+    };
+
+    let workflow_with_steps = state
+        .usecase
+        .reassign_current_step(
+            input,
+            display_number,
+            TenantId::from_uuid(req.tenant_id),
+            UserId::from_uuid(req.user_id),
+        )
+        .await?;
+
+    let response = WorkflowInstanceDetailDto::resolve_from_workflow_with_steps(
+        &workflow_with_steps,
+        &state.usecase,
+    )
+    .await?;
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// ワークフローを強制的に承認完了にする
+///
+/// ## エンドポイント
+/// POST /internal/admin/workflows/by-display-number/{display_number}/force-complete
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn force_complete_workflow(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Json(req): Json<AdminActionRequest>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let input = AdminActionInput {
+        version: parse_version(req.version)?,
+        reason:  AdminActionReason::new(req.reason)
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?,
+    };
+
+    let workflow_with_steps = state
+        .usecase
+        .force_complete_workflow(
+            input,
+            display_number,
+            TenantId::from_uuid(req.tenant_id),
+            UserId::from_uuid(req.user_id),
+        )
+        .await?;
+
+    let response = WorkflowInstanceDetailDto::resolve_from_workflow_with_steps(
+        &workflow_with_steps,
+        &state.usecase,
+    )
+    .await?;
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
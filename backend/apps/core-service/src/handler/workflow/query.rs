@@ -8,52 +8,74 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
-use ringiflow_domain::{tenant::TenantId, user::UserId, workflow::WorkflowInstanceId};
+use ringiflow_domain::{
+    tenant::TenantId,
+    user::UserId,
+    workflow::{WorkflowCommentId, WorkflowInstanceId, WorkflowViewer},
+};
+use ringiflow_shared::PaginatedResponse;
 use uuid::Uuid;
 
 use super::{
-    TenantQuery,
-    UserQuery,
+    CommentPathParams,
+    SubmissionDiffDto,
+    SubmissionDiffQuery,
+    UserPageQuery,
+    ViewerQuery,
+    WorkflowActivityDto,
     WorkflowCommentDto,
+    WorkflowCommentRevisionDto,
+    WorkflowFormDataChangeDto,
     WorkflowInstanceDetailDto,
     WorkflowInstanceSummaryDto,
     WorkflowState,
+    WorkflowSubmissionDto,
+    WorkflowWatcherDto,
     parse_display_number,
 };
 use crate::error::CoreError;
 
-/// 自分のワークフロー一覧を取得する
+/// 自分のワークフロー一覧を 1 ページ分取得する
 ///
 /// ## エンドポイント
-/// GET /internal/workflows?tenant_id={tenant_id}&user_id={user_id}
+/// GET /internal/workflows?tenant_id={tenant_id}&user_id={user_id}&cursor={cursor}&limit={limit}
 ///
 /// ## 処理フロー
-/// 1. クエリパラメータからテナント ID とユーザー ID を取得
+/// 1. クエリパラメータからテナント ID・ユーザー ID・ページ指定を取得
 /// 2. ユースケースを呼び出し
-/// 3. レスポンスを返す
+/// 3. ページ形式でレスポンスを返す
 #[tracing::instrument(skip_all)]
 pub async fn list_my_workflows(
     State(state): State<Arc<WorkflowState>>,
-    Query(query): Query<UserQuery>,
+    Query(query): Query<UserPageQuery>,
 ) -> Result<Response, CoreError> {
     let tenant_id = TenantId::from_uuid(query.tenant_id);
     let user_id = UserId::from_uuid(query.user_id);
 
-    let workflows = state.usecase.list_my_workflows(tenant_id, user_id).await?;
+    let page = state
+        .usecase
+        .list_my_workflows(tenant_id, user_id, query.cursor.as_deref(), query.limit)
+        .await?;
 
-    // 全ワークフローの initiated_by を収集してユーザー名を一括解決
-    let all_user_ids: Vec<UserId> = workflows
+    // ページ内の initiated_by と代理人を収集してユーザー名を一括解決
+    let all_user_ids: Vec<UserId> = page
+        .items
         .iter()
-        .map(|w| w.initiated_by().clone())
+        .flat_map(|w| std::iter::once(w.initiated_by()).chain(w.proxy_submitter()))
+        .cloned()
         .collect::<HashSet<_>>()
         .into_iter()
         .collect();
     let user_names = state.usecase.resolve_user_names(&all_user_ids).await?;
 
-    let response = workflows
-        .iter()
-        .map(|w| WorkflowInstanceSummaryDto::from_instance(w, &user_names))
-        .collect::<Vec<_>>();
+    let response = PaginatedResponse {
+        items:       page
+            .items
+            .iter()
+            .map(|w| WorkflowInstanceSummaryDto::from_instance(w, &user_names))
+            .collect(),
+        next_cursor: page.next_cursor,
+    };
 
     Ok((StatusCode::OK, Json(response)).into_response())
 }
@@ -61,23 +83,26 @@ pub async fn list_my_workflows(
 /// ワークフローの詳細を取得する
 ///
 /// ## エンドポイント
-/// GET /internal/workflows/{id}?tenant_id={tenant_id}
+/// GET /internal/workflows/{id}?tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
 ///
 /// ## 処理フロー
 /// 1. パスパラメータから ID を取得
-/// 2. クエリパラメータからテナント ID を取得
+/// 2. クエリパラメータからテナント ID・閲覧者を取得
 /// 3. ユースケースを呼び出し
 /// 4. レスポンスを返す
 #[tracing::instrument(skip_all, fields(%id))]
 pub async fn get_workflow(
     State(state): State<Arc<WorkflowState>>,
     Path(id): Path<Uuid>,
-    Query(query): Query<TenantQuery>,
+    Query(query): Query<ViewerQuery>,
 ) -> Result<Response, CoreError> {
     let instance_id = WorkflowInstanceId::from_uuid(id);
     let tenant_id = TenantId::from_uuid(query.tenant_id);
 
-    let workflow_with_steps = state.usecase.get_workflow(instance_id, tenant_id).await?;
+    let workflow_with_steps = state
+        .usecase
+        .get_workflow(instance_id, tenant_id, &query.viewer())
+        .await?;
 
     let dto = WorkflowInstanceDetailDto::resolve_from_workflow_with_steps(
         &workflow_with_steps,
@@ -93,25 +118,25 @@ pub async fn get_workflow(
 ///
 /// ## エンドポイント
 /// GET /internal/workflows/by-display-number/{display_number}?
-/// tenant_id={tenant_id}
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
 ///
 /// ## 処理フロー
 /// 1. パスパラメータから display_number を取得
-/// 2. クエリパラメータからテナント ID を取得
+/// 2. クエリパラメータからテナント ID・閲覧者を取得
 /// 3. ユースケースを呼び出し
 /// 4. 200 OK + ワークフロー詳細を返す
 #[tracing::instrument(skip_all, fields(display_number))]
 pub async fn get_workflow_by_display_number(
     State(state): State<Arc<WorkflowState>>,
     Path(display_number): Path<i64>,
-    Query(query): Query<TenantQuery>,
+    Query(query): Query<ViewerQuery>,
 ) -> Result<Response, CoreError> {
     let display_number = parse_display_number(display_number, "display_number")?;
     let tenant_id = TenantId::from_uuid(query.tenant_id);
 
     let workflow_with_steps = state
         .usecase
-        .get_workflow_by_display_number(display_number, tenant_id)
+        .get_workflow_by_display_number(display_number, tenant_id, &query.viewer())
         .await?;
 
     let dto = WorkflowInstanceDetailDto::resolve_from_workflow_with_steps(
@@ -128,25 +153,25 @@ pub async fn get_workflow_by_display_number(
 ///
 /// ## エンドポイント
 /// GET /internal/workflows/by-display-number/{display_number}/comments?
-/// tenant_id={tenant_id}
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
 ///
 /// ## 処理フロー
 /// 1. パスパラメータから display_number を取得
-/// 2. クエリパラメータからテナント ID を取得
+/// 2. クエリパラメータからテナント ID・閲覧者を取得
 /// 3. ユースケースを呼び出し
 /// 4. 200 OK + コメント一覧を返す
 #[tracing::instrument(skip_all, fields(display_number))]
 pub async fn list_comments(
     State(state): State<Arc<WorkflowState>>,
     Path(display_number): Path<i64>,
-    Query(query): Query<TenantQuery>,
+    Query(query): Query<ViewerQuery>,
 ) -> Result<Response, CoreError> {
     let display_number = parse_display_number(display_number, "display_number")?;
     let tenant_id = TenantId::from_uuid(query.tenant_id);
 
     let comments = state
         .usecase
-        .list_comments(display_number, tenant_id)
+        .list_comments(display_number, tenant_id, &query.viewer())
         .await?;
 
     // コメント投稿者のユーザー名を一括解決
@@ -165,3 +190,232 @@ pub async fn list_comments(
 
     Ok((StatusCode::OK, Json(response)).into_response())
 }
+
+/// コメントの編集履歴を取得する
+///
+/// ## エンドポイント
+/// GET /internal/workflows/by-display-number/{display_number}/comments/{comment_id}/revisions?
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
+#[tracing::instrument(skip_all, fields(display_number = params.display_number, comment_id = %params.comment_id))]
+pub async fn list_comment_revisions(
+    State(state): State<Arc<WorkflowState>>,
+    Path(params): Path<CommentPathParams>,
+    Query(query): Query<ViewerQuery>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(params.display_number, "display_number")?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let revisions = state
+        .usecase
+        .list_comment_revisions(
+            display_number,
+            WorkflowCommentId::from_uuid(params.comment_id),
+            tenant_id,
+            &query.viewer(),
+        )
+        .await?;
+
+    let all_user_ids: Vec<UserId> = revisions
+        .iter()
+        .map(|r| r.edited_by().clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let user_names = state.usecase.resolve_user_names(&all_user_ids).await?;
+
+    let response = revisions
+        .iter()
+        .map(|r| WorkflowCommentRevisionDto::from_revision(r, &user_names))
+        .collect::<Vec<_>>();
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// ワークフローのウォッチャー一覧を取得する
+///
+/// ## エンドポイント
+/// GET /internal/workflows/by-display-number/{display_number}/watchers?
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
+///
+/// ## 処理フロー
+/// 1. パスパラメータから display_number を取得
+/// 2. クエリパラメータからテナント ID・閲覧者を取得
+/// 3. ユースケースを呼び出し
+/// 4. 200 OK + ウォッチャー一覧を返す
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn list_watchers(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Query(query): Query<ViewerQuery>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let watchers = state
+        .usecase
+        .list_watchers(display_number, tenant_id, &query.viewer())
+        .await?;
+
+    let user_ids: Vec<UserId> = watchers.iter().map(|w| w.user_id().clone()).collect();
+    let user_names = state.usecase.resolve_user_names(&user_ids).await?;
+
+    let response = watchers
+        .iter()
+        .map(|w| WorkflowWatcherDto::from_watcher(w, &user_names))
+        .collect::<Vec<_>>();
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// ワークフローのフォームデータ変更履歴を取得する
+///
+/// ## エンドポイント
+/// GET /internal/workflows/by-display-number/{display_number}/form-data-changes?
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
+///
+/// ## 処理フロー
+/// 1. パスパラメータから display_number を取得
+/// 2. クエリパラメータからテナント ID・閲覧者を取得
+/// 3. ユースケースを呼び出し
+/// 4. 200 OK + 変更履歴を返す
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn list_form_data_changes(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Query(query): Query<ViewerQuery>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let changes = state
+        .usecase
+        .list_form_data_changes(display_number, tenant_id, &query.viewer())
+        .await?;
+
+    // 変更者のユーザー名を一括解決
+    let all_user_ids: Vec<UserId> = changes
+        .iter()
+        .map(|c| c.changed_by().clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let user_names = state.usecase.resolve_user_names(&all_user_ids).await?;
+
+    let response = changes
+        .iter()
+        .map(|c| WorkflowFormDataChangeDto::from_change(c, &user_names))
+        .collect::<Vec<_>>();
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// ワークフローのアクティビティを取得する
+///
+/// ## エンドポイント
+/// GET /internal/workflows/by-display-number/{display_number}/activities?
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
+///
+/// ## 処理フロー
+///
+/// 1. パスパラメータから display_number を取得
+/// 2. クエリパラメータからテナント ID・閲覧者を取得
+/// 3. ユースケースを呼び出し
+/// 4. 200 OK + アクティビティ一覧（発生日時昇順）を返す
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn list_activities(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Query(query): Query<ViewerQuery>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let activities = state
+        .usecase
+        .list_activities(display_number, tenant_id, &query.viewer())
+        .await?;
+
+    // 操作者のユーザー名を一括解決
+    let all_user_ids: Vec<UserId> = activities
+        .iter()
+        .filter_map(|a| a.actor_id().cloned())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let user_names = state.usecase.resolve_user_names(&all_user_ids).await?;
+
+    let response = activities
+        .iter()
+        .map(|a| WorkflowActivityDto::from_activity(a, &user_names))
+        .collect::<Vec<_>>();
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// ワークフローの申請スナップショット一覧を取得する
+///
+/// ## エンドポイント
+/// GET /internal/workflows/by-display-number/{display_number}/submissions?
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}
+///
+/// ## 処理フロー
+///
+/// 1. パスパラメータから display_number を取得
+/// 2. クエリパラメータからテナント ID・閲覧者を取得
+/// 3. ユースケースを呼び出し
+/// 4. 200 OK + スナップショット一覧（ラウンド昇順）を返す
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn list_submissions(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Query(query): Query<ViewerQuery>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+
+    let submissions = state
+        .usecase
+        .list_submissions(display_number, tenant_id, &query.viewer())
+        .await?;
+
+    // 申請者のユーザー名を一括解決
+    let all_user_ids: Vec<UserId> = submissions
+        .iter()
+        .map(|s| s.submitted_by().clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let user_names = state.usecase.resolve_user_names(&all_user_ids).await?;
+
+    let response = submissions
+        .iter()
+        .map(|s| WorkflowSubmissionDto::from_submission(s, &user_names))
+        .collect::<Vec<_>>();
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+/// 2 つの申請ラウンド間の差分を取得する
+///
+/// ## エンドポイント
+/// GET /internal/workflows/by-display-number/{display_number}/submissions/diff?
+/// tenant_id={tenant_id}&user_id={user_id}&is_workflow_admin={bool}&from={from}&to={to}
+///
+/// `from` / `to` を省略すると、最新ラウンドとその直前のラウンドを比較する。
+#[tracing::instrument(skip_all, fields(display_number))]
+pub async fn diff_submissions(
+    State(state): State<Arc<WorkflowState>>,
+    Path(display_number): Path<i64>,
+    Query(query): Query<SubmissionDiffQuery>,
+) -> Result<Response, CoreError> {
+    let display_number = parse_display_number(display_number, "display_number")?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+    let viewer = WorkflowViewer::new(UserId::from_uuid(query.user_id), query.is_workflow_admin);
+
+    let diff = state
+        .usecase
+        .diff_submissions(display_number, query.from, query.to, tenant_id, &viewer)
+        .await?;
+
+    Ok((StatusCode::OK, Json(SubmissionDiffDto::from(diff))).into_response())
+}
@@ -44,30 +44,42 @@ pub struct WorkflowDefinitionState {
 #[derive(Debug, Deserialize)]
 pub struct CreateDefinitionRequest {
     /// ワークフロー定義名
-    pub name:        String,
+    pub name: String,
     /// 説明（任意）
     pub description: Option<String>,
     /// 定義 JSON
-    pub definition:  serde_json::Value,
+    pub definition: serde_json::Value,
+    /// 機密定義か（関与者のみ閲覧可能。省略時は false）
+    #[serde(default)]
+    pub confidential: bool,
+    /// 既定ウォッチャーのユーザー ID（インスタンス作成時にウォッチャーとして登録。省略時は空）
+    #[serde(default)]
+    pub default_watchers: Vec<Uuid>,
     /// テナント ID
-    pub tenant_id:   Uuid,
+    pub tenant_id: Uuid,
     /// 作成者のユーザー ID
-    pub user_id:     Uuid,
+    pub user_id: Uuid,
 }
 
 /// 定義更新リクエスト
 #[derive(Debug, Deserialize)]
 pub struct UpdateDefinitionRequest {
     /// ワークフロー定義名
-    pub name:        String,
+    pub name: String,
     /// 説明（任意）
     pub description: Option<String>,
     /// 定義 JSON
-    pub definition:  serde_json::Value,
+    pub definition: serde_json::Value,
+    /// 機密定義か（省略時は現在の値を維持）
+    #[serde(default)]
+    pub confidential: Option<bool>,
+    /// 既定ウォッチャーのユーザー ID（省略時は現在の値を維持）
+    #[serde(default)]
+    pub default_watchers: Option<Vec<Uuid>>,
     /// 楽観的ロック用バージョン
-    pub version:     i32,
+    pub version: i32,
     /// テナント ID
-    pub tenant_id:   Uuid,
+    pub tenant_id: Uuid,
 }
 
 /// 公開/アーカイブリクエスト
@@ -152,7 +164,7 @@ pub async fn create_definition(
     State(state): State<Arc<WorkflowDefinitionState>>,
     Json(req): Json<CreateDefinitionRequest>,
 ) -> Result<impl IntoResponse, CoreError> {
-    let name = WorkflowName::new(&req.name).map_err(|e| CoreError::BadRequest(e.to_string()))?;
+    let name = WorkflowName::new(&req.name).map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
     let definition = state
         .usecase
@@ -160,6 +172,11 @@ pub async fn create_definition(
             name,
             req.description,
             req.definition,
+            req.confidential,
+            req.default_watchers
+                .into_iter()
+                .map(UserId::from_uuid)
+                .collect(),
             TenantId::from_uuid(req.tenant_id),
             UserId::from_uuid(req.user_id),
         )
@@ -187,7 +204,7 @@ pub async fn update_definition(
     Json(req): Json<UpdateDefinitionRequest>,
 ) -> Result<impl IntoResponse, CoreError> {
     let definition_id = WorkflowDefinitionId::from_uuid(id);
-    let name = WorkflowName::new(&req.name).map_err(|e| CoreError::BadRequest(e.to_string()))?;
+    let name = WorkflowName::new(&req.name).map_err(|e| CoreError::BadRequest(e.into_message()))?;
     let version = parse_version(req.version)?;
     let tenant_id = TenantId::from_uuid(req.tenant_id);
 
@@ -198,6 +215,9 @@ pub async fn update_definition(
             name,
             req.description,
             req.definition,
+            req.confidential,
+            req.default_watchers
+                .map(|ids| ids.into_iter().map(UserId::from_uuid).collect()),
             version,
             &tenant_id,
         )
@@ -331,7 +351,7 @@ mod tests {
         value_objects::WorkflowName,
         workflow::{NewWorkflowDefinition, WorkflowDefinition, WorkflowDefinitionId},
     };
-    use ringiflow_infra::fake::FakeWorkflowDefinitionRepository;
+    use ringiflow_infra::fake::{FakeUserRepository, FakeWorkflowDefinitionRepository};
     use serde_json::json;
     use tower::ServiceExt;
 
@@ -350,7 +370,8 @@ mod tests {
         let tid = tenant_id();
         let repo = Arc::new(FakeWorkflowDefinitionRepository::new());
         let clock = Arc::new(FixedClock::new(fixed_now()));
-        let usecase = WorkflowDefinitionUseCaseImpl::new(repo, clock);
+        let usecase =
+            WorkflowDefinitionUseCaseImpl::new(repo, Arc::new(FakeUserRepository::new()), clock);
         let state = Arc::new(WorkflowDefinitionState { usecase });
 
         let app = Router::new()
@@ -400,7 +421,8 @@ mod tests {
         repo.add_definition(published);
 
         let clock = Arc::new(FixedClock::new(fixed_now()));
-        let usecase = WorkflowDefinitionUseCaseImpl::new(repo, clock);
+        let usecase =
+            WorkflowDefinitionUseCaseImpl::new(repo, Arc::new(FakeUserRepository::new()), clock);
         let state = Arc::new(WorkflowDefinitionState { usecase });
 
         let app = Router::new()
@@ -0,0 +1,307 @@
+//! # ワークフロースケジュールハンドラ
+//!
+//! Core API のワークフロースケジュール管理内部 API を提供する。
+//!
+//! ## エンドポイント
+//!
+//! - `GET /internal/workflow-schedules` - 自分のスケジュール一覧
+//! - `POST /internal/workflow-schedules` - スケジュール作成
+//! - `PUT /internal/workflow-schedules/{schedule_id}` - スケジュール更新
+//! - `DELETE /internal/workflow-schedules/{schedule_id}` - スケジュール削除
+//!
+//! スケジュールは所有者本人のみ参照・操作できる。
+
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use ringiflow_domain::{
+    tenant::TenantId,
+    user::UserId,
+    workflow::{
+        Recurrence,
+        ScheduledApprover,
+        WorkflowDefinitionId,
+        WorkflowSchedule,
+        WorkflowScheduleId,
+    },
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::CoreError,
+    usecase::workflow_schedule::{
+        CreateWorkflowScheduleInput,
+        RecurrenceInput,
+        UpdateWorkflowScheduleInput,
+        WorkflowScheduleUseCaseImpl,
+    },
+};
+
+/// ワークフロースケジュール API の共有状態
+pub struct WorkflowScheduleState {
+    pub usecase: WorkflowScheduleUseCaseImpl,
+}
+
+// --- リクエスト/レスポンス型 ---
+
+/// テナント ID・ユーザー ID クエリパラメータ
+#[derive(Debug, Deserialize)]
+pub struct ScheduleOwnerQuery {
+    pub tenant_id: Uuid,
+    pub user_id:   Uuid,
+}
+
+/// 繰り返し設定 DTO（時刻はスケジュールのタイムゾーンの現地時刻）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RecurrenceDto {
+    /// 毎月 N 日（存在しない日は月末）
+    Monthly {
+        day:    u32,
+        hour:   u32,
+        minute: u32,
+    },
+    /// cron 形式（分 時 日 月 曜日）
+    Cron { expression: String },
+}
+
+impl From<RecurrenceDto> for RecurrenceInput {
+    fn from(dto: RecurrenceDto) -> Self {
+        match dto {
+            RecurrenceDto::Monthly { day, hour, minute } => {
+                RecurrenceInput::Monthly { day, hour, minute }
+            }
+            RecurrenceDto::Cron { expression } => RecurrenceInput::Cron { expression },
+        }
+    }
+}
+
+impl From<&Recurrence> for RecurrenceDto {
+    fn from(recurrence: &Recurrence) -> Self {
+        match recurrence {
+            Recurrence::Monthly(monthly) => RecurrenceDto::Monthly {
+                day:    monthly.day(),
+                hour:   monthly.hour(),
+                minute: monthly.minute(),
+            },
+            Recurrence::Cron(cron) => RecurrenceDto::Cron {
+                expression: cron.as_str().to_string(),
+            },
+        }
+    }
+}
+
+/// 自動申請時の承認者 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScheduledApproverDto {
+    pub step_id:     String,
+    pub assigned_to: Uuid,
+}
+
+impl From<ScheduledApproverDto> for ScheduledApprover {
+    fn from(dto: ScheduledApproverDto) -> Self {
+        ScheduledApprover {
+            step_id:     dto.step_id,
+            assigned_to: UserId::from_uuid(dto.assigned_to),
+        }
+    }
+}
+
+/// スケジュール作成リクエスト
+#[derive(Debug, Deserialize)]
+pub struct CreateWorkflowScheduleRequest {
+    pub tenant_id:     Uuid,
+    pub user_id:       Uuid,
+    pub definition_id: Uuid,
+    pub name:          String,
+    pub title:         String,
+    pub form_data:     serde_json::Value,
+    pub recurrence:    RecurrenceDto,
+    pub time_zone:     Option<String>,
+    #[serde(default)]
+    pub auto_submit:   bool,
+    #[serde(default)]
+    pub approvers:     Vec<ScheduledApproverDto>,
+}
+
+/// スケジュール更新リクエスト
+#[derive(Debug, Deserialize)]
+pub struct UpdateWorkflowScheduleRequest {
+    pub tenant_id:   Uuid,
+    pub user_id:     Uuid,
+    pub name:        Option<String>,
+    pub title:       Option<String>,
+    pub form_data:   Option<serde_json::Value>,
+    pub recurrence:  Option<RecurrenceDto>,
+    pub time_zone:   Option<String>,
+    pub auto_submit: Option<bool>,
+    pub approvers:   Option<Vec<ScheduledApproverDto>>,
+    pub is_active:   Option<bool>,
+}
+
+/// ワークフロースケジュール DTO
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct WorkflowScheduleDto {
+    pub id: Uuid,
+    pub definition_id: Uuid,
+    pub name: String,
+    pub title: String,
+    pub form_data: serde_json::Value,
+    pub recurrence: RecurrenceDto,
+    pub time_zone: String,
+    pub auto_submit: bool,
+    pub approvers: Vec<ScheduledApproverDto>,
+    pub is_active: bool,
+    pub next_run_at: Option<String>,
+    pub last_run_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&WorkflowSchedule> for WorkflowScheduleDto {
+    fn from(schedule: &WorkflowSchedule) -> Self {
+        Self {
+            id: *schedule.id().as_uuid(),
+            definition_id: *schedule.definition_id().as_uuid(),
+            name: schedule.name().as_str().to_string(),
+            title: schedule.title().to_string(),
+            form_data: schedule.form_data().clone(),
+            recurrence: RecurrenceDto::from(schedule.recurrence()),
+            time_zone: schedule.time_zone().to_string(),
+            auto_submit: schedule.auto_submit(),
+            approvers: schedule
+                .approvers()
+                .iter()
+                .map(|approver| ScheduledApproverDto {
+                    step_id:     approver.step_id.clone(),
+                    assigned_to: *approver.assigned_to.as_uuid(),
+                })
+                .collect(),
+            is_active: schedule.is_active(),
+            next_run_at: schedule.next_run_at().map(|t| t.to_rfc3339()),
+            last_run_at: schedule.last_run_at().map(|t| t.to_rfc3339()),
+            created_at: schedule.created_at().to_rfc3339(),
+            updated_at: schedule.updated_at().to_rfc3339(),
+        }
+    }
+}
+
+// --- ハンドラ ---
+
+/// GET /internal/workflow-schedules
+///
+/// 自分のスケジュール一覧を作成日時順で取得する。
+#[tracing::instrument(skip_all)]
+pub async fn list_workflow_schedules(
+    State(state): State<Arc<WorkflowScheduleState>>,
+    Query(query): Query<ScheduleOwnerQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+    let owner_id = UserId::from_uuid(query.user_id);
+
+    let schedules = state.usecase.list_schedules(&tenant_id, &owner_id).await?;
+
+    let items: Vec<WorkflowScheduleDto> = schedules.iter().map(WorkflowScheduleDto::from).collect();
+    Ok((StatusCode::OK, Json(items)))
+}
+
+/// POST /internal/workflow-schedules
+///
+/// スケジュールを作成する。リクエストのユーザーが所有者になる。
+///
+/// ## レスポンス
+///
+/// - `201 Created`: 作成されたスケジュール
+/// - `400 Bad Request`: バリデーションエラー
+/// - `404 Not Found`: ワークフロー定義が見つからない
+#[tracing::instrument(skip_all)]
+pub async fn create_workflow_schedule(
+    State(state): State<Arc<WorkflowScheduleState>>,
+    Json(req): Json<CreateWorkflowScheduleRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let input = CreateWorkflowScheduleInput {
+        tenant_id:     TenantId::from_uuid(req.tenant_id),
+        owner_id:      UserId::from_uuid(req.user_id),
+        definition_id: WorkflowDefinitionId::from_uuid(req.definition_id),
+        name:          req.name,
+        title:         req.title,
+        form_data:     req.form_data,
+        recurrence:    req.recurrence.into(),
+        time_zone:     req.time_zone,
+        auto_submit:   req.auto_submit,
+        approvers:     req.approvers.into_iter().map(Into::into).collect(),
+    };
+
+    let schedule = state.usecase.create_schedule(input).await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(WorkflowScheduleDto::from(&schedule)),
+    ))
+}
+
+/// PUT /internal/workflow-schedules/{schedule_id}
+///
+/// スケジュールを更新する。指定した項目のみ変更する。
+///
+/// ## レスポンス
+///
+/// - `200 OK`: 更新後のスケジュール
+/// - `400 Bad Request`: バリデーションエラー
+/// - `404 Not Found`: スケジュールが見つからない
+#[tracing::instrument(skip_all, fields(%schedule_id))]
+pub async fn update_workflow_schedule(
+    State(state): State<Arc<WorkflowScheduleState>>,
+    Path(schedule_id): Path<Uuid>,
+    Json(req): Json<UpdateWorkflowScheduleRequest>,
+) -> Result<impl IntoResponse, CoreError> {
+    let input = UpdateWorkflowScheduleInput {
+        schedule_id: WorkflowScheduleId::from_uuid(schedule_id),
+        tenant_id:   TenantId::from_uuid(req.tenant_id),
+        owner_id:    UserId::from_uuid(req.user_id),
+        name:        req.name,
+        title:       req.title,
+        form_data:   req.form_data,
+        recurrence:  req.recurrence.map(Into::into),
+        time_zone:   req.time_zone,
+        auto_submit: req.auto_submit,
+        approvers:   req
+            .approvers
+            .map(|approvers| approvers.into_iter().map(Into::into).collect()),
+        is_active:   req.is_active,
+    };
+
+    let schedule = state.usecase.update_schedule(input).await?;
+
+    Ok((StatusCode::OK, Json(WorkflowScheduleDto::from(&schedule))))
+}
+
+/// DELETE /internal/workflow-schedules/{schedule_id}
+///
+/// ## レスポンス
+///
+/// - `204 No Content`: 削除成功
+/// - `404 Not Found`: スケジュールが見つからない
+#[tracing::instrument(skip_all, fields(%schedule_id))]
+pub async fn delete_workflow_schedule(
+    State(state): State<Arc<WorkflowScheduleState>>,
+    Path(schedule_id): Path<Uuid>,
+    Query(query): Query<ScheduleOwnerQuery>,
+) -> Result<impl IntoResponse, CoreError> {
+    let id = WorkflowScheduleId::from_uuid(schedule_id);
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+    let owner_id = UserId::from_uuid(query.user_id);
+
+    state
+        .usecase
+        .delete_schedule(&id, &tenant_id, &owner_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
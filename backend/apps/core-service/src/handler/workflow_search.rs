@@ -0,0 +1,250 @@
+//! # ワークフロー検索 API ハンドラ
+//!
+//! Core Service のワークフロー検索エンドポイントを実装する。
+
+use std::{collections::HashSet, sync::Arc};
+
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    message::Message,
+    tenant::TenantId,
+    user::UserId,
+    workflow::{WorkflowDefinitionId, WorkflowInstanceStatus, WorkflowViewer},
+};
+use ringiflow_shared::PaginatedResponse;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    error::CoreError,
+    handler::workflow::WorkflowInstanceSummaryDto,
+    usecase::{
+        WorkflowSearchUseCaseImpl,
+        workflow_search::{SearchWorkflowsInput, WorkflowSearchResult},
+    },
+};
+
+/// ワークフロー検索ハンドラーの State
+pub struct WorkflowSearchState {
+    pub usecase: WorkflowSearchUseCaseImpl,
+}
+
+/// ワークフロー検索クエリパラメータ
+#[derive(Debug, Deserialize)]
+pub struct SearchWorkflowsQuery {
+    /// テナント ID
+    pub tenant_id: Uuid,
+    /// 検索するユーザー ID
+    pub user_id: Uuid,
+    /// 検索するユーザーがワークフロー管理者か（`workflow:admin` 権限）
+    #[serde(default)]
+    pub is_workflow_admin: bool,
+    /// 検索キーワード
+    pub q: Option<String>,
+    /// ワークフロー定義 ID（カンマ区切りで複数指定可）
+    pub definition_id: Option<String>,
+    /// ステータス（カンマ区切りで複数指定可、snake_case）
+    pub status: Option<String>,
+    /// 作成日時の下限（RFC 3339）
+    pub created_from: Option<String>,
+    /// 作成日時の上限（RFC 3339）
+    pub created_to: Option<String>,
+    /// 申請者のユーザー ID
+    pub initiated_by: Option<Uuid>,
+    /// 現在の承認者のユーザー ID
+    pub current_approver: Option<Uuid>,
+    /// 前ページの `next_cursor`
+    pub cursor: Option<String>,
+    /// 取得件数
+    pub limit: Option<i64>,
+}
+
+/// カンマ区切りの値を分割する（空要素は無視する）
+fn split_comma(value: Option<&str>) -> impl Iterator<Item = &str> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+fn parse_datetime(value: Option<&str>, field: &str) -> Result<Option<DateTime<Utc>>, CoreError> {
+    value
+        .map(|s| {
+            DateTime::parse_from_rfc3339(s)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|_| {
+                    CoreError::BadRequest(Message::new("invalid-rfc3339").with("name", field))
+                })
+        })
+        .transpose()
+}
+
+impl TryFrom<&SearchWorkflowsQuery> for SearchWorkflowsInput {
+    type Error = CoreError;
+
+    fn try_from(query: &SearchWorkflowsQuery) -> Result<Self, Self::Error> {
+        let definition_ids = split_comma(query.definition_id.as_deref())
+            .map(|s| {
+                Uuid::parse_str(s)
+                    .map(WorkflowDefinitionId::from_uuid)
+                    .map_err(|_| {
+                        CoreError::BadRequest(
+                            Message::new("invalid-parameter")
+                                .with("name", "definition_id")
+                                .with("value", s),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let statuses = split_comma(query.status.as_deref())
+            .map(|s| {
+                s.parse::<WorkflowInstanceStatus>()
+                    .map_err(|e| CoreError::BadRequest(e.into_message()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            keyword: query.q.clone(),
+            definition_ids,
+            statuses,
+            created_from: parse_datetime(query.created_from.as_deref(), "created_from")?,
+            created_to: parse_datetime(query.created_to.as_deref(), "created_to")?,
+            initiated_by: query.initiated_by.map(UserId::from_uuid),
+            current_approver: query.current_approver.map(UserId::from_uuid),
+            cursor: query.cursor.clone(),
+            limit: query.limit,
+        })
+    }
+}
+
+/// ワークフローを検索する
+///
+/// ## エンドポイント
+/// GET /internal/workflows/search?tenant_id={tenant_id}&user_id={user_id}&q=...
+///
+/// ## 処理フロー
+/// 1. クエリパラメータをパース
+/// 2. ユースケースを呼び出し
+/// 3. 申請者のユーザー名を解決してページ形式で返す
+#[tracing::instrument(skip_all)]
+pub async fn search_workflows(
+    State(state): State<Arc<WorkflowSearchState>>,
+    Query(query): Query<SearchWorkflowsQuery>,
+) -> Result<Response, CoreError> {
+    let input = SearchWorkflowsInput::try_from(&query)?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+    let viewer = WorkflowViewer::new(UserId::from_uuid(query.user_id), query.is_workflow_admin);
+
+    let result = state
+        .usecase
+        .search_workflows(input, tenant_id, viewer)
+        .await?;
+
+    into_page_response(&state, result).await
+}
+
+/// テナント内の全ワークフローを一覧・絞り込みする（テナント管理者向け）
+///
+/// 検索条件は [`search_workflows`] と同じ。他のユーザーの下書きも含める。
+///
+/// ## エンドポイント
+/// GET /internal/admin/workflows?tenant_id={tenant_id}&user_id={user_id}&q=...
+#[tracing::instrument(skip_all)]
+pub async fn search_all_workflows(
+    State(state): State<Arc<WorkflowSearchState>>,
+    Query(query): Query<SearchWorkflowsQuery>,
+) -> Result<Response, CoreError> {
+    let input = SearchWorkflowsInput::try_from(&query)?;
+    let tenant_id = TenantId::from_uuid(query.tenant_id);
+    let user_id = UserId::from_uuid(query.user_id);
+
+    let result = state
+        .usecase
+        .search_all_workflows(input, tenant_id, user_id)
+        .await?;
+
+    into_page_response(&state, result).await
+}
+
+/// 検索結果の申請者名を解決してページ形式のレスポンスにする
+async fn into_page_response(
+    state: &WorkflowSearchState,
+    result: WorkflowSearchResult,
+) -> Result<Response, CoreError> {
+    let user_ids: Vec<UserId> = result
+        .items
+        .iter()
+        .flat_map(|w| std::iter::once(w.initiated_by()).chain(w.proxy_submitter()))
+        .cloned()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let user_names = state.usecase.resolve_user_names(&user_ids).await?;
+
+    let response = PaginatedResponse {
+        items:       result
+            .items
+            .iter()
+            .map(|w| WorkflowInstanceSummaryDto::from_instance(w, &user_names))
+            .collect(),
+        next_cursor: result.next_cursor,
+    };
+
+    Ok((StatusCode::OK, Json(response)).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(status: Option<&str>, created_from: Option<&str>) -> SearchWorkflowsQuery {
+        SearchWorkflowsQuery {
+            tenant_id: Uuid::now_v7(),
+            user_id: Uuid::now_v7(),
+            is_workflow_admin: false,
+            q: None,
+            definition_id: None,
+            status: status.map(str::to_string),
+            created_from: created_from.map(str::to_string),
+            created_to: None,
+            initiated_by: None,
+            current_approver: None,
+            cursor: None,
+            limit: None,
+        }
+    }
+
+    #[test]
+    fn test_カンマ区切りのステータスを解釈する() {
+        let input =
+            SearchWorkflowsInput::try_from(&query(Some("pending, in_progress,"), None)).unwrap();
+
+        assert_eq!(
+            input.statuses,
+            vec![
+                WorkflowInstanceStatus::Pending,
+                WorkflowInstanceStatus::InProgress
+            ]
+        );
+    }
+
+    #[test]
+    fn test_不正なステータスや日時は400() {
+        for q in [
+            query(Some("unknown"), None),
+            query(None, Some("2026-01-01")),
+        ] {
+            assert!(matches!(
+                SearchWorkflowsInput::try_from(&q),
+                Err(CoreError::BadRequest(_))
+            ));
+        }
+    }
+}
@@ -8,7 +8,7 @@
 //!
 //! - **ビジネスロジック**: ワークフロー実行、承認処理、タスク管理
 //! - **データ永続化**: PostgreSQL へのエンティティ保存
-//! - **ドメインイベント**: イベント駆動処理のトリガー（将来）
+//! - **ドメインイベント**: アウトボックス経由で通知などの副作用を配信
 //!
 //! ## アクセス制御
 //!
@@ -34,6 +34,19 @@
 //! | `CORE_HOST` | No | バインドアドレス（デフォルト: `0.0.0.0`） |
 //! | `CORE_PORT` | **Yes** | ポート番号 |
 //! | `DATABASE_URL` | **Yes** | PostgreSQL 接続 URL |
+//! | `REDIS_URL` | No | リアルタイム通知を発行する Redis の接続 URL（未設定で発行しない） |
+//! | `OUTBOX_POLL_INTERVAL_MS` | No | イベントアウトボックスのポーリング間隔（デフォルト: `1000`） |
+//! | `OUTBOX_BATCH_SIZE` | No | 1 回のポーリングで配信するイベント数（デフォルト: `50`） |
+//! | `WEBHOOK_POLL_INTERVAL_MS` | No | Webhook 配信ワーカーのポーリング間隔（デフォルト: `5000`） |
+//! | `WEBHOOK_BATCH_SIZE` | No | 1 回のポーリングで送信する Webhook 数（デフォルト: `20`） |
+//! | `WEBHOOK_TIMEOUT_SECS` | No | Webhook 送信のタイムアウト秒数（デフォルト: `10`） |
+//! | `ENVIRONMENT` | No | `development` の場合、Webhook の http 送信先と内部アドレスへの送信を許可する |
+//! | `INBOX_RETENTION_DAYS` | No | アプリ内通知の保持日数（デフォルト: `90`） |
+//! | `INBOX_PURGE_POLL_INTERVAL_MS` | No | アプリ内通知パージのポーリング間隔（デフォルト: `3600000`） |
+//! | `INBOX_PURGE_BATCH_SIZE` | No | 1 回のポーリングで削除するアプリ内通知数（デフォルト: `1000`） |
+//! | `NOTIFICATION_DIGEST_SEND_AT` | No | 通知ダイジェストを毎日送信する時刻（UTC、`HH:MM`。デフォルト: `00:00`） |
+//! | `NOTIFICATION_DIGEST_POLL_INTERVAL_MS` | No | 通知ダイジェストの送信時刻を確認するポーリング間隔（デフォルト: `60000`） |
+//! | `NOTIFICATION_DIGEST_BATCH_SIZE` | No | 1 回のポーリングで送信するダイジェスト数（デフォルト: `100`） |
 //!
 //! ## 起動方法
 //!
@@ -58,9 +71,14 @@ mod app_builder;
 mod config;
 mod error;
 mod handler;
+// ライブラリと共有するテストユーティリティ（ユースケースのテストから使う）。
+// バイナリでは使わない項目があるため、未使用の警告を抑制する
+#[cfg(test)]
+#[allow(dead_code, unused_imports)]
+mod test_utils;
 mod usecase;
 
-use std::{net::SocketAddr, sync::Arc};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use config::CoreConfig;
 use ringiflow_infra::{
@@ -72,9 +90,13 @@ use ringiflow_infra::{
         SmtpNotificationSender,
         create_ses_client,
     },
+    realtime::{RealtimePublisher, RedisRealtimePublisher},
+    redis,
+    webhook::{ReqwestWebhookSender, WebhookSender},
 };
 use ringiflow_shared::observability::TracingConfig;
 use tokio::net::TcpListener;
+use usecase::spawn_polling_worker;
 
 /// Core Service サーバーのエントリーポイント
 ///
@@ -147,8 +169,90 @@ async fn main() -> anyhow::Result<()> {
             }
         };
 
+    // リアルタイム通知の発行先を初期化（設定時のみ）
+    let realtime_publisher: Option<Arc<dyn RealtimePublisher>> = match &config.redis_url {
+        Some(redis_url) => {
+            let conn = redis::create_connection_manager(redis_url)
+                .await
+                .expect("Redis への接続に失敗しました");
+            tracing::info!("リアルタイム通知の発行先として Redis に接続しました");
+            Some(Arc::new(RedisRealtimePublisher::new(conn)))
+        }
+        None => None,
+    };
+
+    // バックグラウンドワーカーを起動
+    let dispatcher = app_builder::build_event_dispatcher(
+        pool.clone(),
+        notification_sender.clone(),
+        realtime_publisher,
+        &config,
+    );
+    spawn_polling_worker(
+        dispatcher,
+        Duration::from_millis(config.outbox.poll_interval_ms),
+    );
+
+    let webhook_sender: Arc<dyn WebhookSender> = Arc::new(ReqwestWebhookSender::new(
+        Duration::from_secs(config.webhook.timeout_secs),
+        config.webhook.allow_insecure_destinations,
+    ));
+    let webhook_worker =
+        app_builder::build_webhook_worker(pool.clone(), webhook_sender.clone(), &config);
+    spawn_polling_worker(
+        webhook_worker,
+        Duration::from_millis(config.webhook.poll_interval_ms),
+    );
+
+    // 下書き自動パージは設定時のみ起動する
+    if let Some(draft_purge_worker) =
+        app_builder::build_draft_purge_worker(pool.clone(), s3_client.clone(), &config)
+    {
+        spawn_polling_worker(
+            draft_purge_worker,
+            Duration::from_millis(config.draft_purge.poll_interval_ms),
+        );
+    }
+
+    let inbox_purge_worker = app_builder::build_inbox_purge_worker(pool.clone(), &config);
+    spawn_polling_worker(
+        inbox_purge_worker,
+        Duration::from_millis(config.inbox.poll_interval_ms),
+    );
+
+    let digest_worker = app_builder::build_notification_digest_worker(
+        pool.clone(),
+        notification_sender.clone(),
+        &config,
+    );
+    spawn_polling_worker(
+        digest_worker,
+        Duration::from_millis(config.notification_digest.poll_interval_ms),
+    );
+
+    let retry_worker = app_builder::build_notification_retry_worker(
+        pool.clone(),
+        notification_sender.clone(),
+        &config,
+    );
+    spawn_polling_worker(
+        retry_worker,
+        Duration::from_millis(config.notification_retry.poll_interval_ms),
+    );
+
+    let schedule_worker = app_builder::build_workflow_schedule_worker(
+        pool.clone(),
+        s3_client.clone(),
+        notification_sender,
+        &config,
+    );
+    spawn_polling_worker(
+        schedule_worker,
+        Duration::from_millis(config.schedule.poll_interval_ms),
+    );
+
     // アプリケーション構築（DI + ルーター）
-    let app = app_builder::build_app(pool, s3_client, notification_sender, &config);
+    let app = app_builder::build_app(pool, s3_client, webhook_sender, &config);
 
     // jscpd:ignore-start — サーバー起動パターン（意図的な重複）
     let addr: SocketAddr = format!("{}:{}", config.host, config.port)
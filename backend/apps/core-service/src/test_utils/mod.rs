@@ -4,4 +4,4 @@
 
 mod workflow_test_builder;
 
-pub use workflow_test_builder::WorkflowTestBuilder;
+pub use workflow_test_builder::{WorkflowTestBuilder, WorkflowTestSetup};
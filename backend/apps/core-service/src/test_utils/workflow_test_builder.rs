@@ -13,40 +13,45 @@ use ringiflow_domain::{
     value_objects::{DisplayNumber, Version},
     workflow::{NewWorkflowInstance, WorkflowDefinitionId, WorkflowInstance, WorkflowInstanceId},
 };
-use ringiflow_infra::{
-    fake::{
-        FakeDisplayIdCounterRepository,
-        FakeNotificationLogRepository,
-        FakeNotificationSender,
-        FakeTransactionManager,
-        FakeUserRepository,
-        FakeWorkflowCommentRepository,
-        FakeWorkflowDefinitionRepository,
-        FakeWorkflowInstanceRepository,
-        FakeWorkflowStepRepository,
-    },
-    repository::{
-        WorkflowCommentRepository,
-        WorkflowDefinitionRepository,
-        WorkflowInstanceRepository,
-        WorkflowStepRepository,
-    },
+use ringiflow_infra::fake::{
+    FakeDisplayIdCounterRepository,
+    FakeDocumentRepository,
+    FakeS3Client,
+    FakeTransactionManager,
+    FakeUserRepository,
+    FakeWorkflowActivityRepository,
+    FakeWorkflowCommentRepository,
+    FakeWorkflowDefinitionRepository,
+    FakeWorkflowEventOutboxRepository,
+    FakeWorkflowFormDataChangeRepository,
+    FakeWorkflowInstanceRepository,
+    FakeWorkflowProxyGrantRepository,
+    FakeWorkflowStepRepository,
+    FakeWorkflowSubmissionRepository,
+    FakeWorkflowWatcherRepository,
 };
 
-use crate::usecase::{
-    notification::{NotificationService, TemplateRenderer},
-    workflow::{WorkflowUseCaseDeps, WorkflowUseCaseImpl},
-};
+use crate::usecase::workflow::{WorkflowUseCaseDeps, WorkflowUseCaseImpl};
 
 /// ワークフローテストのセットアップデータ
 ///
 /// WorkflowTestBuilder が生成する SUT と Fake リポジトリのセット。
+/// Fake は SUT とステートを共有するため、初期データの投入や結果の確認に使える。
 pub struct WorkflowTestSetup {
     pub sut: WorkflowUseCaseImpl,
-    pub definition_repo: Arc<dyn WorkflowDefinitionRepository>,
-    pub instance_repo: Arc<dyn WorkflowInstanceRepository>,
-    pub step_repo: Arc<dyn WorkflowStepRepository>,
-    pub comment_repo: Arc<dyn WorkflowCommentRepository>,
+    pub definition_repo: FakeWorkflowDefinitionRepository,
+    pub instance_repo: FakeWorkflowInstanceRepository,
+    pub step_repo: FakeWorkflowStepRepository,
+    pub comment_repo: FakeWorkflowCommentRepository,
+    pub form_data_change_repo: FakeWorkflowFormDataChangeRepository,
+    pub submission_repo: FakeWorkflowSubmissionRepository,
+    pub watcher_repo: FakeWorkflowWatcherRepository,
+    pub proxy_grant_repo: FakeWorkflowProxyGrantRepository,
+    pub document_repo: FakeDocumentRepository,
+    pub activity_repo: FakeWorkflowActivityRepository,
+    pub outbox_repo: FakeWorkflowEventOutboxRepository,
+    pub user_repo: FakeUserRepository,
+    pub s3_client: FakeS3Client,
 }
 
 /// ワークフローテストビルダー
@@ -120,18 +125,13 @@ impl WorkflowTestBuilder {
         self.now
     }
 
-    /// 標準的なワークフローインスタンスを作成（submitted状態）
+    /// 標準的なワークフローインスタンスを作成（draft状態）
     ///
     /// # 引数
     ///
     /// - `title`: ワークフロータイトル
     /// - `display_number`: 表示番号
-    ///
-    /// # 戻り値
-    ///
-    /// submitted状態のワークフローインスタンス（current_stepが"approval"
-    /// に設定済み）
-    pub fn build_submitted_instance(&self, title: &str, display_number: i64) -> WorkflowInstance {
+    pub fn build_draft_instance(&self, title: &str, display_number: i64) -> WorkflowInstance {
         WorkflowInstance::new(NewWorkflowInstance {
             id: WorkflowInstanceId::new(),
             tenant_id: self.tenant_id.clone(),
@@ -143,10 +143,25 @@ impl WorkflowTestBuilder {
             initiated_by: self.user_id.clone(),
             now: self.now,
         })
-        .submitted(self.now)
-        .unwrap()
-        .with_current_step("approval".to_string(), self.now)
-        .unwrap()
+    }
+
+    /// 標準的なワークフローインスタンスを作成（submitted状態）
+    ///
+    /// # 引数
+    ///
+    /// - `title`: ワークフロータイトル
+    /// - `display_number`: 表示番号
+    ///
+    /// # 戻り値
+    ///
+    /// submitted状態のワークフローインスタンス（current_stepが"approval"
+    /// に設定済み）
+    pub fn build_submitted_instance(&self, title: &str, display_number: i64) -> WorkflowInstance {
+        self.build_draft_instance(title, display_number)
+            .submitted(self.now)
+            .unwrap()
+            .with_current_step("approval".to_string(), self.now)
+            .unwrap()
     }
 
     /// Fake リポジトリ群を含む SUT（System Under Test）を構築
@@ -155,32 +170,37 @@ impl WorkflowTestBuilder {
     ///
     /// WorkflowTestSetup（SUT と各 Fake リポジトリへの参照を含む）
     pub fn build_workflow_usecase_impl(&self) -> WorkflowTestSetup {
-        let definition_repo: Arc<dyn WorkflowDefinitionRepository> =
-            Arc::new(FakeWorkflowDefinitionRepository::new());
-        let instance_repo: Arc<dyn WorkflowInstanceRepository> =
-            Arc::new(FakeWorkflowInstanceRepository::new());
-        let step_repo: Arc<dyn WorkflowStepRepository> =
-            Arc::new(FakeWorkflowStepRepository::new());
-        let comment_repo: Arc<dyn WorkflowCommentRepository> =
-            Arc::new(FakeWorkflowCommentRepository::new());
-
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(FakeNotificationSender::new()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let comment_repo = FakeWorkflowCommentRepository::new();
+        let form_data_change_repo = FakeWorkflowFormDataChangeRepository::new();
+        let submission_repo = FakeWorkflowSubmissionRepository::new();
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        let proxy_grant_repo = FakeWorkflowProxyGrantRepository::new();
+        let document_repo = FakeDocumentRepository::new();
+        let activity_repo = FakeWorkflowActivityRepository::new();
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
+        let user_repo = FakeUserRepository::new();
+        let s3_client = FakeS3Client::new();
 
         let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
-            definition_repo: definition_repo.clone(),
-            instance_repo: instance_repo.clone(),
-            step_repo: step_repo.clone(),
-            comment_repo: comment_repo.clone(),
-            user_repo: Arc::new(FakeUserRepository::new()),
+            definition_repo: Arc::new(definition_repo.clone()),
+            instance_repo: Arc::new(instance_repo.clone()),
+            step_repo: Arc::new(step_repo.clone()),
+            comment_repo: Arc::new(comment_repo.clone()),
+            form_data_change_repo: Arc::new(form_data_change_repo.clone()),
+            submission_repo: Arc::new(submission_repo.clone()),
+            watcher_repo: Arc::new(watcher_repo.clone()),
+            proxy_grant_repo: Arc::new(proxy_grant_repo.clone()),
+            document_repo: Arc::new(document_repo.clone()),
+            activity_repo: Arc::new(activity_repo.clone()),
+            outbox_repo: Arc::new(outbox_repo.clone()),
+            user_repo: Arc::new(user_repo.clone()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(s3_client.clone()),
             clock: Arc::new(FixedClock::new(self.now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         });
 
         WorkflowTestSetup {
@@ -189,6 +209,15 @@ impl WorkflowTestBuilder {
             instance_repo,
             step_repo,
             comment_repo,
+            form_data_change_repo,
+            submission_repo,
+            watcher_repo,
+            proxy_grant_repo,
+            document_repo,
+            activity_repo,
+            outbox_repo,
+            user_repo,
+            s3_client,
         }
     }
 }
@@ -201,6 +230,9 @@ impl Default for WorkflowTestBuilder {
 
 #[cfg(test)]
 mod tests {
+    use ringiflow_domain::workflow::WorkflowViewer;
+    use ringiflow_infra::repository::WorkflowInstanceRepositoryTestExt;
+
     use super::*;
 
     #[test]
@@ -266,16 +298,44 @@ mod tests {
         assert_eq!(instance.current_step_id().unwrap(), "approval");
     }
 
-    #[tokio::test]
-    async fn test_build_workflow_usecase_impl_sutが作成される() {
+    #[test]
+    fn test_build_draft_instance_下書きインスタンスが作成される() {
         // Arrange
         let builder = WorkflowTestBuilder::new();
 
         // Act
-        let setup = builder.build_workflow_usecase_impl();
+        let instance = builder.build_draft_instance("テスト申請", 100);
 
         // Assert
-        // WorkflowTestSetup が正常に作成できることを確認
-        assert!(Arc::strong_count(&setup.instance_repo) >= 2);
+        assert_eq!(instance.title(), "テスト申請");
+        assert_eq!(instance.tenant_id(), &builder.tenant_id);
+        assert_eq!(instance.initiated_by(), &builder.user_id);
+        assert!(instance.submitted_at().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_build_workflow_usecase_impl_sutとfakeがステートを共有する() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let instance = builder.build_submitted_instance("テスト申請", 100);
+
+        // Act
+        let setup = builder.build_workflow_usecase_impl();
+        setup
+            .instance_repo
+            .insert_for_test(&instance)
+            .await
+            .unwrap();
+
+        // Assert: Fake に投入したデータを SUT から参照できる
+        let detail = setup
+            .sut
+            .get_workflow_by_display_number(
+                DisplayNumber::new(100).unwrap(),
+                builder.tenant_id().clone(),
+                &WorkflowViewer::new(builder.user_id().clone(), false),
+            )
+            .await;
+        assert!(detail.is_ok());
     }
 }
@@ -10,6 +10,11 @@
 //! ## モジュール構成
 //!
 //! - `workflow`: ワークフロー関連のユースケース
+//! - `workflow_event`: ワークフローイベントのアウトボックス配信
+//! - `webhook`: Webhook の管理と配信
+//! - `workflow_schedule`: ワークフローの定期作成
+//! - `polling`: バックグラウンドワーカー共通のポーリングループ
+//! - `proxy_grant`: 代理申請の代理権限の管理
 
 pub(crate) mod helpers;
 
@@ -17,34 +22,79 @@ pub mod dashboard;
 pub mod document;
 pub mod folder;
 pub mod notification;
+pub mod polling;
+pub mod proxy_grant;
 pub mod role;
 pub mod task;
+pub mod tenant;
 pub mod user;
+pub mod webhook;
 pub mod workflow;
 pub mod workflow_definition;
+pub mod workflow_event;
+pub mod workflow_schedule;
+pub mod workflow_search;
 
 use std::collections::HashMap;
 
 pub use dashboard::DashboardUseCaseImpl;
 pub use document::DocumentUseCaseImpl;
 pub use folder::FolderUseCaseImpl;
-pub use notification::{NotificationService, TemplateRenderer};
+pub use notification::{
+    InboxPurgeWorker,
+    InboxUseCaseImpl,
+    NotificationDigestWorker,
+    NotificationLogUseCaseImpl,
+    NotificationPreferenceInput,
+    NotificationPreferenceUseCaseImpl,
+    NotificationRetryWorker,
+    NotificationService,
+    NotificationTemplateContentInput,
+    NotificationTemplateUseCaseImpl,
+    SaveNotificationTemplateInput,
+    TemplateRenderer,
+    TemplateValidationError,
+};
+pub use polling::{PollingWorker, spawn_polling_worker};
+pub use proxy_grant::ProxyGrantUseCaseImpl;
 use ringiflow_domain::user::UserId;
 use ringiflow_infra::repository::UserRepository;
 pub use role::RoleUseCaseImpl;
 pub use task::TaskUseCaseImpl;
+pub use tenant::TenantUseCaseImpl;
 pub use user::UserUseCaseImpl;
+pub use webhook::{WebhookDeliveryWorker, WebhookUseCaseImpl};
 pub use workflow::{
+    AdminActionInput,
     ApproveRejectInput,
+    BulkDecisionAction,
+    BulkDecisionInput,
+    BulkDecisionItem,
+    BulkDecisionItemResult,
+    BulkDecisionOutcome,
     CreateWorkflowInput,
+    DraftPurgeWorker,
+    DuplicateWorkflowInput,
+    EditCommentInput,
     PostCommentInput,
+    ReassignStepInput,
     ResubmitWorkflowInput,
+    RetractApprovalInput,
     StepApprover,
     SubmitWorkflowInput,
     WorkflowUseCaseImpl,
     WorkflowWithSteps,
 };
 pub use workflow_definition::WorkflowDefinitionUseCaseImpl;
+pub use workflow_event::{
+    NotificationEventConsumer,
+    RealtimeEventConsumer,
+    WebhookEventConsumer,
+    WorkflowEventConsumer,
+    WorkflowEventDispatcher,
+};
+pub use workflow_schedule::{WorkflowScheduleUseCaseImpl, WorkflowScheduleWorker};
+pub use workflow_search::WorkflowSearchUseCaseImpl;
 
 use crate::error::CoreError;
 
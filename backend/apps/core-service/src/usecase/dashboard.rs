@@ -11,11 +11,7 @@
 use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
-use ringiflow_domain::{
-    tenant::TenantId,
-    user::UserId,
-    workflow::{WorkflowInstanceStatus, WorkflowStepStatus},
-};
+use ringiflow_domain::{tenant::TenantId, user::UserId, workflow::WorkflowInstanceStatus};
 use ringiflow_infra::repository::{WorkflowInstanceRepository, WorkflowStepRepository};
 use serde::Serialize;
 
@@ -56,41 +52,28 @@ impl DashboardUseCaseImpl {
         now: DateTime<Utc>,
     ) -> Result<DashboardStats, CoreError> {
         // 1. 承認待ちタスク数: 自分にアサインされた Active なステップ
-        let my_steps = self
+        let pending_tasks = self
             .step_repo
-            .find_by_assigned_to(&tenant_id, &user_id)
+            .count_active_by_assigned_to(&tenant_id, &user_id)
             .await
             .map_err(|e| CoreError::Internal(format!("ステップ取得エラー: {}", e)))?;
 
-        let pending_tasks = my_steps
-            .iter()
-            .filter(|s| s.status() == WorkflowStepStatus::Active)
-            .count() as i64;
-
         // 2. 申請中ワークフロー数: 自分が申請した InProgress なインスタンス
-        let my_instances = self
+        let my_workflows_in_progress = self
             .instance_repo
-            .find_by_initiated_by(&tenant_id, &user_id)
+            .count_by_initiated_by(&tenant_id, &user_id, WorkflowInstanceStatus::InProgress)
             .await
             .map_err(|e| CoreError::Internal(format!("インスタンス取得エラー: {}", e)))?;
 
-        let my_workflows_in_progress = my_instances
-            .iter()
-            .filter(|i| i.status() == WorkflowInstanceStatus::InProgress)
-            .count() as i64;
-
         // 3. 本日完了タスク数: 自分にアサインされた本日 completed_at のステップ
         let today_start = now.date_naive().and_hms_opt(0, 0, 0).unwrap();
         let today_start_utc = today_start.and_utc();
 
-        let completed_today = my_steps
-            .iter()
-            .filter(|s| {
-                s.status() == WorkflowStepStatus::Completed
-                    && s.completed_at()
-                        .is_some_and(|completed| completed >= today_start_utc)
-            })
-            .count() as i64;
+        let completed_today = self
+            .step_repo
+            .count_completed_by_assigned_to_since(&tenant_id, &user_id, today_start_utc)
+            .await
+            .map_err(|e| CoreError::Internal(format!("ステップ取得エラー: {}", e)))?;
 
         Ok(DashboardStats {
             pending_tasks,
@@ -13,17 +13,35 @@ use ringiflow_domain::{
         UploadContext,
     },
     folder::FolderId,
+    message::Message,
     tenant::TenantId,
     user::UserId,
-    workflow::{WorkflowInstanceId, WorkflowInstanceStatus},
+    workflow::{
+        NewWorkflowActivity,
+        WorkflowActivity,
+        WorkflowActivityId,
+        WorkflowActivityType,
+        WorkflowCommentId,
+        WorkflowInstanceId,
+        WorkflowInstanceStatus,
+        WorkflowViewer,
+    },
 };
 use ringiflow_infra::{
-    repository::{DocumentRepository, WorkflowInstanceRepository},
+    repository::{
+        DocumentRepository,
+        WorkflowActivityRepository,
+        WorkflowCommentRepository,
+        WorkflowDefinitionRepository,
+        WorkflowInstanceRepository,
+        WorkflowStepRepository,
+        WorkflowWatcherRepository,
+    },
     s3::S3Client,
 };
 use uuid::Uuid;
 
-use crate::error::CoreError;
+use crate::{error::CoreError, usecase::helpers::check_workflow_visible};
 
 /// Presigned URL の有効期限（5 分）
 const UPLOAD_URL_EXPIRES_IN: Duration = Duration::from_secs(300);
@@ -39,6 +57,7 @@ pub struct RequestUploadUrlInput {
     pub content_length: i64,
     pub folder_id: Option<Uuid>,
     pub workflow_instance_id: Option<Uuid>,
+    pub comment_id: Option<Uuid>,
     pub uploaded_by: Uuid,
 }
 
@@ -68,20 +87,41 @@ pub struct DocumentUseCaseImpl {
     document_repository: Arc<dyn DocumentRepository>,
     /// 削除時のワークフロー状態チェックに使用
     workflow_instance_repository: Arc<dyn WorkflowInstanceRepository>,
+    /// ワークフロー添付ファイル追加のアクティビティ記録に使用
+    workflow_activity_repository: Arc<dyn WorkflowActivityRepository>,
+    /// 添付ファイル一覧の閲覧権限チェックに使用
+    workflow_step_repository: Arc<dyn WorkflowStepRepository>,
+    /// 添付ファイル一覧の閲覧権限チェック（機密定義の判定）に使用
+    workflow_definition_repository: Arc<dyn WorkflowDefinitionRepository>,
+    /// 添付ファイル一覧の閲覧権限チェック（ウォッチャーの判定）に使用
+    workflow_watcher_repository: Arc<dyn WorkflowWatcherRepository>,
+    /// コメント添付の投稿者チェックと添付ファイル一覧の集約に使用
+    workflow_comment_repository: Arc<dyn WorkflowCommentRepository>,
     s3_client: Arc<dyn S3Client>,
     clock: Arc<dyn Clock>,
 }
 
 impl DocumentUseCaseImpl {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         document_repository: Arc<dyn DocumentRepository>,
         workflow_instance_repository: Arc<dyn WorkflowInstanceRepository>,
+        workflow_activity_repository: Arc<dyn WorkflowActivityRepository>,
+        workflow_step_repository: Arc<dyn WorkflowStepRepository>,
+        workflow_definition_repository: Arc<dyn WorkflowDefinitionRepository>,
+        workflow_watcher_repository: Arc<dyn WorkflowWatcherRepository>,
+        workflow_comment_repository: Arc<dyn WorkflowCommentRepository>,
         s3_client: Arc<dyn S3Client>,
         clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
             document_repository,
             workflow_instance_repository,
+            workflow_activity_repository,
+            workflow_step_repository,
+            workflow_definition_repository,
+            workflow_watcher_repository,
+            workflow_comment_repository,
             s3_client,
             clock,
         }
@@ -89,7 +129,8 @@ impl DocumentUseCaseImpl {
 
     /// Upload URL を発行する
     ///
-    /// 1. UploadContext 構築（folder_id XOR workflow_instance_id）
+    /// 1. UploadContext 構築（folder_id / workflow_instance_id / comment_id のいずれか 1 つ）
+    ///    - コメント添付はコメント投稿者本人のみ
     /// 2. ファイルバリデーション（Content-Type、サイズ）
     /// 3. 既存ドキュメントの集計バリデーション（数量、合計サイズ）
     /// 4. Document エンティティ作成・挿入
@@ -99,25 +140,38 @@ impl DocumentUseCaseImpl {
         input: RequestUploadUrlInput,
     ) -> Result<UploadUrlOutput, CoreError> {
         // 1. UploadContext 構築
-        let upload_context = match (input.folder_id, input.workflow_instance_id) {
-            (Some(fid), None) => UploadContext::Folder(FolderId::from_uuid(fid)),
-            (None, Some(wid)) => UploadContext::Workflow(WorkflowInstanceId::from_uuid(wid)),
-            (Some(_), Some(_)) => {
-                return Err(CoreError::BadRequest(
-                    "folder_id と workflow_instance_id は同時に指定できません".to_string(),
-                ));
+        let upload_context = match (
+            input.folder_id,
+            input.workflow_instance_id,
+            input.comment_id,
+        ) {
+            (Some(fid), None, None) => UploadContext::Folder(FolderId::from_uuid(fid)),
+            (None, Some(wid), None) => UploadContext::Workflow(WorkflowInstanceId::from_uuid(wid)),
+            (None, None, Some(cid)) => {
+                let comment_id = WorkflowCommentId::from_uuid(cid);
+                self.ensure_comment_attachable(
+                    &comment_id,
+                    &input.tenant_id,
+                    &UserId::from_uuid(input.uploaded_by),
+                )
+                .await?;
+                UploadContext::Comment(comment_id)
             }
-            (None, None) => {
-                return Err(CoreError::BadRequest(
-                    "folder_id または workflow_instance_id のいずれかを指定してください"
-                        .to_string(),
-                ));
+            (None, None, None) => {
+                return Err(CoreError::BadRequest(Message::new(
+                    "document-owner-required",
+                )));
+            }
+            _ => {
+                return Err(CoreError::BadRequest(Message::new(
+                    "document-owner-conflict",
+                )));
             }
         };
 
         // 2. ファイルバリデーション
         FileValidation::validate_file(&input.content_type, input.content_length)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         // 3. 既存ドキュメントの集計バリデーション
         let (existing_count, existing_total_size) = match &upload_context {
@@ -131,9 +185,14 @@ impl DocumentUseCaseImpl {
                     .count_and_total_size_by_workflow(instance_id, &input.tenant_id)
                     .await?
             }
+            UploadContext::Comment(comment_id) => {
+                self.document_repository
+                    .count_and_total_size_by_comment(comment_id, &input.tenant_id)
+                    .await?
+            }
         };
         FileValidation::validate_total(existing_count, existing_total_size, input.content_length)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         // 4. Document エンティティ作成・挿入
         let now = self.clock.now();
@@ -178,11 +237,36 @@ impl DocumentUseCaseImpl {
         })
     }
 
+    /// コメントにファイルを添付できるかを確認する
+    ///
+    /// 削除済みコメントには添付できず、添付できるのはコメント投稿者本人のみ。
+    async fn ensure_comment_attachable(
+        &self,
+        comment_id: &WorkflowCommentId,
+        tenant_id: &TenantId,
+        uploaded_by: &UserId,
+    ) -> Result<(), CoreError> {
+        let comment = self
+            .workflow_comment_repository
+            .find_by_id(comment_id, tenant_id)
+            .await?
+            .filter(|comment| !comment.is_deleted())
+            .ok_or_else(|| CoreError::NotFound(Message::new("comment-not-found")))?;
+
+        if comment.posted_by() != uploaded_by {
+            return Err(CoreError::Forbidden(Message::new(
+                "comment-attachment-forbidden",
+            )));
+        }
+        Ok(())
+    }
+
     /// アップロード完了を確認する
     ///
     /// 1. ドキュメント取得
     /// 2. S3 上のファイル存在確認
     /// 3. ステータスを active に遷移
+    /// 4. ワークフロー添付の場合は添付ファイル追加のアクティビティを記録
     pub async fn confirm_upload(
         &self,
         document_id: &DocumentId,
@@ -193,14 +277,13 @@ impl DocumentUseCaseImpl {
             .document_repository
             .find_by_id(document_id, tenant_id)
             .await?
-            .ok_or_else(|| CoreError::NotFound("ドキュメントが見つかりません".to_string()))?;
+            .ok_or_else(|| CoreError::NotFound(Message::new("document-not-found")))?;
 
         // ステータスチェック
         if document.status() != DocumentStatus::Uploading {
-            return Err(CoreError::BadRequest(format!(
-                "ドキュメントのステータスが uploading ではありません: {}",
-                document.status()
-            )));
+            return Err(CoreError::BadRequest(
+                Message::new("document-not-uploading").with("status", document.status()),
+            ));
         }
 
         // 2. S3 上のファイル存在確認
@@ -210,17 +293,16 @@ impl DocumentUseCaseImpl {
             .await
             .map_err(|e| CoreError::Internal(e.to_string()))?;
         if !exists {
-            return Err(CoreError::BadRequest(
-                "S3 上にファイルが見つかりません。アップロードが完了していない可能性があります"
-                    .to_string(),
-            ));
+            return Err(CoreError::BadRequest(Message::new(
+                "document-file-not-uploaded",
+            )));
         }
 
         // 3. ステータスを active に遷移
         let now = self.clock.now();
         let confirmed = document
             .confirm(now)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         self.document_repository
             .update_status(
@@ -231,28 +313,52 @@ impl DocumentUseCaseImpl {
             )
             .await?;
 
+        // 4. ワークフロー添付の場合はアクティビティを記録
+        if let UploadContext::Workflow(instance_id) = confirmed.upload_context() {
+            let activity = WorkflowActivity::new(NewWorkflowActivity {
+                id: WorkflowActivityId::new(),
+                tenant_id: tenant_id.clone(),
+                instance_id: instance_id.clone(),
+                activity_type: WorkflowActivityType::AttachmentAdded,
+                actor_id: confirmed.uploaded_by().cloned(),
+                step_id: None,
+                details: serde_json::json!({
+                    "document_id": confirmed.id().to_string(),
+                    "filename": confirmed.filename(),
+                }),
+                now,
+            });
+            self.workflow_activity_repository
+                .insert(&activity, tenant_id)
+                .await?;
+        }
+
         Ok(confirmed)
     }
 
     /// ダウンロード URL を発行する
     ///
     /// active なドキュメントに対して Presigned GET URL を発行する。
+    /// ワークフロー・コメントへの添付ファイルは、閲覧者がそのワークフローインスタンスを
+    /// 閲覧できない場合に NotFound を返す。
     pub async fn generate_download_url(
         &self,
         document_id: &DocumentId,
         tenant_id: &TenantId,
+        viewer: &WorkflowViewer,
     ) -> Result<DownloadUrlOutput, CoreError> {
         let document = self
             .document_repository
             .find_by_id(document_id, tenant_id)
             .await?
-            .ok_or_else(|| CoreError::NotFound("ドキュメントが見つかりません".to_string()))?;
+            .ok_or_else(|| CoreError::NotFound(Message::new("document-not-found")))?;
+        self.check_attachment_visible(&document, tenant_id, viewer)
+            .await?;
 
         if document.status() != DocumentStatus::Active {
-            return Err(CoreError::BadRequest(format!(
-                "ドキュメントのステータスが active ではありません: {}",
-                document.status()
-            )));
+            return Err(CoreError::BadRequest(
+                Message::new("document-not-active").with("status", document.status()),
+            ));
         }
 
         let download_url = self
@@ -267,6 +373,53 @@ impl DocumentUseCaseImpl {
         })
     }
 
+    /// 添付先のワークフローインスタンスを閲覧者が閲覧できるか確認する
+    ///
+    /// フォルダ内のドキュメントは確認しない。削除済みのコメントへの添付ファイルは閲覧できない。
+    /// 閲覧できない場合はドキュメントの存在を明かさないよう NotFound を返す。
+    async fn check_attachment_visible(
+        &self,
+        document: &Document,
+        tenant_id: &TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<(), CoreError> {
+        let not_found = || CoreError::NotFound(Message::new("document-not-found"));
+        let workflow_instance_id = match document.upload_context() {
+            UploadContext::Folder(_) => return Ok(()),
+            UploadContext::Workflow(workflow_instance_id) => workflow_instance_id.clone(),
+            UploadContext::Comment(comment_id) => self
+                .workflow_comment_repository
+                .find_by_id(comment_id, tenant_id)
+                .await?
+                .filter(|comment| !comment.is_deleted())
+                .ok_or_else(not_found)?
+                .instance_id()
+                .clone(),
+        };
+
+        let instance = self
+            .workflow_instance_repository
+            .find_by_id(&workflow_instance_id, tenant_id)
+            .await?
+            .ok_or_else(not_found)?;
+        let steps = self
+            .workflow_step_repository
+            .find_by_instance(&workflow_instance_id, tenant_id)
+            .await?;
+        check_workflow_visible(
+            self.workflow_definition_repository.as_ref(),
+            self.workflow_watcher_repository.as_ref(),
+            viewer,
+            &instance,
+            &steps,
+        )
+        .await
+        .map_err(|e| match e {
+            CoreError::NotFound(_) => not_found(),
+            e => e,
+        })
+    }
+
     /// ドキュメントをソフトデリートする
     ///
     /// 権限チェック:
@@ -280,16 +433,16 @@ impl DocumentUseCaseImpl {
             .document_repository
             .find_by_id(&input.document_id, &input.tenant_id)
             .await?
-            .ok_or_else(|| CoreError::NotFound("ドキュメントが見つかりません".to_string()))?;
+            .ok_or_else(|| CoreError::NotFound(Message::new("document-not-found")))?;
 
         // 権限チェック
         let is_uploader = document
             .uploaded_by()
             .is_some_and(|uid| uid == &input.user_id);
         if !input.is_tenant_admin && !is_uploader {
-            return Err(CoreError::Forbidden(
-                "このドキュメントを削除する権限がありません".to_string(),
-            ));
+            return Err(CoreError::Forbidden(Message::new(
+                "document-delete-forbidden",
+            )));
         }
 
         // ワークフロー添付の場合、ワークフロー状態チェック
@@ -303,9 +456,9 @@ impl DocumentUseCaseImpl {
                 })?;
 
             if workflow.status() != WorkflowInstanceStatus::Draft {
-                return Err(CoreError::BadRequest(
-                    "下書き以外のワークフローの添付ファイルは削除できません".to_string(),
-                ));
+                return Err(CoreError::BadRequest(Message::new(
+                    "attachment-delete-not-draft",
+                )));
             }
         }
 
@@ -313,7 +466,7 @@ impl DocumentUseCaseImpl {
         let now = self.clock.now();
         let _deleted = document
             .soft_delete(now)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         self.document_repository
             .soft_delete(&input.document_id, &input.tenant_id, now)
@@ -336,15 +489,55 @@ impl DocumentUseCaseImpl {
     }
 
     /// ワークフロー添付ファイル一覧を取得する
+    ///
+    /// 申請への添付に加え、削除されていないコメントへの添付も含める（新しい順）。
+    /// 閲覧者がワークフローインスタンスを閲覧できない場合は NotFound を返す。
     pub async fn list_workflow_attachments(
         &self,
         workflow_instance_id: &WorkflowInstanceId,
         tenant_id: &TenantId,
+        viewer: &WorkflowViewer,
     ) -> Result<Vec<Document>, CoreError> {
-        let documents = self
+        let instance = self
+            .workflow_instance_repository
+            .find_by_id(workflow_instance_id, tenant_id)
+            .await?
+            .ok_or_else(|| CoreError::NotFound(Message::new("workflow-instance-not-found")))?;
+        let steps = self
+            .workflow_step_repository
+            .find_by_instance(workflow_instance_id, tenant_id)
+            .await?;
+        check_workflow_visible(
+            self.workflow_definition_repository.as_ref(),
+            self.workflow_watcher_repository.as_ref(),
+            viewer,
+            &instance,
+            &steps,
+        )
+        .await?;
+
+        let mut documents = self
             .document_repository
             .list_by_workflow(workflow_instance_id, tenant_id)
             .await?;
+
+        let comment_ids: Vec<WorkflowCommentId> = self
+            .workflow_comment_repository
+            .find_by_instance(workflow_instance_id, tenant_id)
+            .await?
+            .into_iter()
+            .filter(|comment| !comment.is_deleted())
+            .map(|comment| comment.id().clone())
+            .collect();
+        let comment_documents = self
+            .document_repository
+            .list_by_comments(&comment_ids, tenant_id)
+            .await?;
+        if !comment_documents.is_empty() {
+            documents.extend(comment_documents);
+            documents.sort_by_key(|d| std::cmp::Reverse(d.created_at()));
+        }
+
         Ok(documents)
     }
 }
@@ -5,6 +5,7 @@ use std::sync::Arc;
 use ringiflow_domain::{
     clock::Clock,
     folder::{Folder, FolderId, FolderName, MAX_FOLDER_DEPTH},
+    message::Message,
     tenant::TenantId,
     user::UserId,
 };
@@ -66,7 +67,8 @@ impl FolderUseCaseImpl {
     /// 3. Folder エンティティ生成・挿入
     /// 4. UNIQUE 制約違反は Conflict にマッピング
     pub async fn create_folder(&self, input: CreateFolderInput) -> Result<Folder, CoreError> {
-        let name = FolderName::new(input.name).map_err(|e| CoreError::BadRequest(e.to_string()))?;
+        let name =
+            FolderName::new(input.name).map_err(|e| CoreError::BadRequest(e.into_message()))?;
         let now = self.clock.now();
 
         let (parent_id, parent_path, parent_depth) = match input.parent_id {
@@ -76,11 +78,11 @@ impl FolderUseCaseImpl {
                     .folder_repository
                     .find_by_id(&parent_folder_id, &input.tenant_id)
                     .await?
-                    .ok_or_else(|| CoreError::NotFound("親フォルダが見つかりません".to_string()))?;
+                    .ok_or_else(|| CoreError::NotFound(Message::new("parent-folder-not-found")))?;
                 // depth チェック（child_depth で MAX_FOLDER_DEPTH を超えないか確認）
                 parent
                     .child_depth()
-                    .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+                    .map_err(|e| CoreError::BadRequest(e.into_message()))?;
                 (
                     Some(parent_folder_id),
                     Some(parent.path().to_string()),
@@ -100,7 +102,7 @@ impl FolderUseCaseImpl {
             Some(UserId::from_uuid(input.created_by)),
             now,
         )
-        .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+        .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         self.folder_repository.insert(&folder).await.map_err(|e| {
             // UNIQUE 制約違反（tenant_id, parent_id, name）の場合は Conflict
@@ -108,7 +110,7 @@ impl FolderUseCaseImpl {
                 && let Some(constraint) = db_err.as_database_error().and_then(|d| d.constraint())
                 && constraint == "folders_tenant_id_parent_id_name_key"
             {
-                return CoreError::Conflict("同名のフォルダが既に存在します".to_string());
+                return CoreError::Conflict(Message::new("folder-name-duplicate"));
             }
             CoreError::Database(e)
         })?;
@@ -125,7 +127,7 @@ impl FolderUseCaseImpl {
             .folder_repository
             .find_by_id(&input.folder_id, &input.tenant_id)
             .await?
-            .ok_or_else(|| CoreError::NotFound("フォルダが見つかりません".to_string()))?;
+            .ok_or_else(|| CoreError::NotFound(Message::new("folder-not-found")))?;
 
         let now = self.clock.now();
         let old_path = folder.path().to_string();
@@ -140,9 +142,7 @@ impl FolderUseCaseImpl {
 
                     // 自分自身への移動を拒否
                     if new_parent_folder_id == *folder.id() {
-                        return Err(CoreError::BadRequest(
-                            "フォルダを自分自身に移動することはできません".to_string(),
-                        ));
+                        return Err(CoreError::BadRequest(Message::new("folder-move-to-self")));
                     }
 
                     let parent = self
@@ -150,20 +150,20 @@ impl FolderUseCaseImpl {
                         .find_by_id(&new_parent_folder_id, &input.tenant_id)
                         .await?
                         .ok_or_else(|| {
-                            CoreError::NotFound("移動先の親フォルダが見つかりません".to_string())
+                            CoreError::NotFound(Message::new("destination-folder-not-found"))
                         })?;
 
                     // 循環検出: 移動先が自身のサブツリー内かチェック
                     if parent.path().starts_with(&old_path) {
-                        return Err(CoreError::BadRequest(
-                            "フォルダを自身の子孫に移動することはできません".to_string(),
-                        ));
+                        return Err(CoreError::BadRequest(Message::new(
+                            "folder-move-to-descendant",
+                        )));
                     }
 
                     // depth チェック
                     parent
                         .child_depth()
-                        .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+                        .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
                     (
                         Some(new_parent_folder_id),
@@ -181,7 +181,7 @@ impl FolderUseCaseImpl {
                     new_parent_depth,
                     now,
                 )
-                .map_err(|e| CoreError::BadRequest(e.to_string()))?
+                .map_err(|e| CoreError::BadRequest(e.into_message()))?
         } else {
             folder
         };
@@ -189,7 +189,7 @@ impl FolderUseCaseImpl {
         // 名前変更処理
         let folder = if let Some(new_name) = input.name {
             let new_name =
-                FolderName::new(new_name).map_err(|e| CoreError::BadRequest(e.to_string()))?;
+                FolderName::new(new_name).map_err(|e| CoreError::BadRequest(e.into_message()))?;
             folder.rename(new_name, now)
         } else {
             folder
@@ -205,7 +205,7 @@ impl FolderUseCaseImpl {
                     .await?;
                 if max_subtree_depth + depth_delta > MAX_FOLDER_DEPTH {
                     return Err(CoreError::BadRequest(
-                        "移動先ではサブツリーの階層が上限（5 階層）を超えます".to_string(),
+                        Message::new("folder-move-too-deep").with("max", MAX_FOLDER_DEPTH),
                     ));
                 }
             }
@@ -227,7 +227,7 @@ impl FolderUseCaseImpl {
                         db_err.as_database_error().and_then(|d| d.constraint())
                     && constraint == "folders_tenant_id_parent_id_name_key"
                 {
-                    return CoreError::Conflict("同名のフォルダが既に存在します".to_string());
+                    return CoreError::Conflict(Message::new("folder-name-duplicate"));
                 }
                 CoreError::Database(e)
             })?;
@@ -265,7 +265,7 @@ impl FolderUseCaseImpl {
             .folder_repository
             .find_by_id(folder_id, tenant_id)
             .await?
-            .ok_or_else(|| CoreError::NotFound("フォルダが見つかりません".to_string()))?;
+            .ok_or_else(|| CoreError::NotFound(Message::new("folder-not-found")))?;
 
         // 子フォルダチェック
         let child_count = self
@@ -273,9 +273,7 @@ impl FolderUseCaseImpl {
             .count_children(folder_id, tenant_id)
             .await?;
         if child_count > 0 {
-            return Err(CoreError::BadRequest(
-                "子フォルダが存在するため削除できません".to_string(),
-            ));
+            return Err(CoreError::BadRequest(Message::new("folder-has-children")));
         }
 
         self.folder_repository.delete(folder_id, tenant_id).await?;
@@ -390,7 +388,7 @@ mod tests {
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(
-            matches!(err, CoreError::BadRequest(ref msg) if msg.contains("階層")),
+            matches!(err, CoreError::BadRequest(ref msg) if msg.to_string().contains("階層")),
             "expected BadRequest with 階層 message, got: {:?}",
             err
         );
@@ -3,8 +3,16 @@
 //! リポジトリ呼び出し結果の変換や権限チェックなど、
 //! 複数のユースケースで繰り返されるパターンを共通化する。
 
-use ringiflow_domain::{user::UserId, workflow::WorkflowStep};
-use ringiflow_infra::InfraError;
+use ringiflow_domain::{
+    message::Message,
+    user::UserId,
+    workflow::{WorkflowInstance, WorkflowStep, WorkflowViewer},
+};
+use ringiflow_infra::{
+    InfraError,
+    InfraErrorKind,
+    repository::{WorkflowDefinitionRepository, WorkflowWatcherRepository},
+};
 
 use crate::error::CoreError;
 
@@ -17,21 +25,45 @@ use crate::error::CoreError;
 /// // Before
 /// let step = self.step_repo.find_by_id(&step_id, &tenant_id).await
 ///     .map_err(|e| CoreError::Internal(format!("ステップの取得に失敗: {}", e)))?
-///     .ok_or_else(|| CoreError::NotFound("ステップが見つかりません".to_string()))?;
+///     .ok_or_else(|| CoreError::NotFound(Message::new("step-not-found")))?;
 ///
 /// // After
 /// let step = self.step_repo.find_by_id(&step_id, &tenant_id).await
-///     .or_not_found("ステップ")?;
+///     .or_not_found("step")?;
 /// ```
 pub(crate) trait FindResultExt<T> {
     /// `None` の場合は `CoreError::NotFound`、`InfraError` の場合は `CoreError::Internal` を返す
-    fn or_not_found(self, entity_name: &str) -> Result<T, CoreError>;
+    fn or_not_found(self, entity: &str) -> Result<T, CoreError>;
 }
 
 impl<T> FindResultExt<T> for Result<Option<T>, InfraError> {
-    fn or_not_found(self, entity_name: &str) -> Result<T, CoreError> {
-        self.map_err(|e| CoreError::Internal(format!("{}の取得に失敗: {}", entity_name, e)))?
-            .ok_or_else(|| CoreError::NotFound(format!("{}が見つかりません", entity_name)))
+    fn or_not_found(self, entity: &str) -> Result<T, CoreError> {
+        self.map_err(|e| CoreError::Internal(format!("{} の取得に失敗: {}", entity, e)))?
+            .ok_or_else(|| CoreError::NotFound(Message::new(format!("{entity}-not-found"))))
+    }
+}
+
+/// 一覧 API の 1 ページあたりの件数のデフォルト値
+pub(crate) const DEFAULT_PAGE_LIMIT: i64 = 20;
+
+/// 一覧 API の 1 ページあたりの件数の上限
+pub(crate) const MAX_PAGE_LIMIT: i64 = 100;
+
+/// 1 ページあたりの件数を決める
+///
+/// 未指定の場合は [`DEFAULT_PAGE_LIMIT`]、範囲外の場合は 1〜[`MAX_PAGE_LIMIT`] に丸める。
+pub(crate) fn page_limit(limit: Option<i64>) -> i64 {
+    limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT)
+}
+
+/// キーセットページネーションの取得エラーを `CoreError` に変換する
+///
+/// 不正なカーソル（`InfraErrorKind::InvalidInput`）はクライアントの誤りなので
+/// `CoreError::BadRequest`、それ以外は `CoreError::Internal` とする。
+pub(crate) fn page_error(e: InfraError, context: &str) -> CoreError {
+    match e.kind() {
+        InfraErrorKind::InvalidInput(_) => CoreError::BadRequest(Message::new("invalid-cursor")),
+        _ => CoreError::Internal(format!("{}に失敗: {}", context, e)),
     }
 }
 
@@ -44,9 +76,48 @@ pub(crate) fn check_step_assigned_to(
     action: &str,
 ) -> Result<(), CoreError> {
     if step.assigned_to() != Some(user_id) {
-        return Err(CoreError::Forbidden(format!(
-            "このステップを{}する権限がありません",
-            action,
+        return Err(CoreError::Forbidden(
+            Message::new("step-action-forbidden").with("action", action),
+        ));
+    }
+    Ok(())
+}
+
+/// ワークフローインスタンスの閲覧権限をチェックする
+///
+/// 閲覧できない場合は、インスタンスの存在を秘匿するため `CoreError::NotFound` を返す。
+/// 申請者・承認者でない場合のみウォッチャーを取得し、関与者でもない場合のみ
+/// 定義を取得して機密区分を確認する（定義が見つからない場合は機密とみなす）。
+pub(crate) async fn check_workflow_visible(
+    definition_repo: &dyn WorkflowDefinitionRepository,
+    watcher_repo: &dyn WorkflowWatcherRepository,
+    viewer: &WorkflowViewer,
+    instance: &WorkflowInstance,
+    steps: &[WorkflowStep],
+) -> Result<(), CoreError> {
+    if viewer.is_participant(instance, steps, &[]) {
+        return Ok(());
+    }
+
+    let watchers: Vec<UserId> = watcher_repo
+        .find_by_instance(instance.id(), instance.tenant_id())
+        .await
+        .map_err(|e| CoreError::Internal(format!("ウォッチャーの取得に失敗: {}", e)))?
+        .into_iter()
+        .map(|w| w.user_id().clone())
+        .collect();
+    if viewer.is_participant(instance, steps, &watchers) {
+        return Ok(());
+    }
+
+    let confidential = definition_repo
+        .find_by_id(instance.definition_id(), instance.tenant_id())
+        .await
+        .map_err(|e| CoreError::Internal(format!("ワークフロー定義の取得に失敗: {}", e)))?
+        .is_none_or(|definition| definition.is_confidential());
+    if !viewer.can_view(instance, steps, &watchers, confidential) {
+        return Err(CoreError::NotFound(Message::new(
+            "workflow-instance-not-found",
         )));
     }
     Ok(())
@@ -69,7 +140,7 @@ mod tests {
     fn test_or_not_found_ok_some_は値を返す() {
         let result: Result<Option<i32>, InfraError> = Ok(Some(42));
 
-        let value = result.or_not_found("テスト").unwrap();
+        let value = result.or_not_found("step").unwrap();
 
         assert_eq!(value, 42);
     }
@@ -78,11 +149,12 @@ mod tests {
     fn test_or_not_found_ok_none_はnotfoundエラーを返す() {
         let result: Result<Option<i32>, InfraError> = Ok(None);
 
-        let err = result.or_not_found("ステップ").unwrap_err();
+        let err = result.or_not_found("step").unwrap_err();
 
         match err {
             CoreError::NotFound(msg) => {
-                assert_eq!(msg, "ステップが見つかりません");
+                assert_eq!(msg.code(), "step-not-found");
+                assert_eq!(msg.to_string(), "ステップが見つかりません");
             }
             other => panic!("NotFound を期待したが {:?} を受信", other),
         }
@@ -92,17 +164,39 @@ mod tests {
     fn test_or_not_found_errはinternalエラーを返す() {
         let result: Result<Option<i32>, InfraError> = Err(InfraError::unexpected("接続失敗"));
 
-        let err = result.or_not_found("インスタンス").unwrap_err();
+        let err = result.or_not_found("workflow-instance").unwrap_err();
 
         match err {
             CoreError::Internal(msg) => {
-                assert!(msg.contains("インスタンスの取得に失敗"));
+                assert!(msg.contains("workflow-instance の取得に失敗"));
                 assert!(msg.contains("接続失敗"));
             }
             other => panic!("Internal を期待したが {:?} を受信", other),
         }
     }
 
+    // === page_limit / page_error ===
+
+    #[test]
+    fn test_page_limit_未指定はデフォルト値_範囲外は丸める() {
+        assert_eq!(page_limit(None), DEFAULT_PAGE_LIMIT);
+        assert_eq!(page_limit(Some(50)), 50);
+        assert_eq!(page_limit(Some(0)), 1);
+        assert_eq!(page_limit(Some(MAX_PAGE_LIMIT + 1)), MAX_PAGE_LIMIT);
+    }
+
+    #[test]
+    fn test_page_error_不正なカーソルはbadrequest_それ以外はinternal() {
+        let err = page_error(InfraError::invalid_input("デコード失敗"), "一覧の取得");
+        assert!(matches!(err, CoreError::BadRequest(_)));
+
+        let err = page_error(InfraError::unexpected("接続失敗"), "一覧の取得");
+        match err {
+            CoreError::Internal(msg) => assert!(msg.contains("一覧の取得に失敗")),
+            other => panic!("Internal を期待したが {:?} を受信", other),
+        }
+    }
+
     // === check_step_assigned_to ===
 
     fn create_test_step(assigned_to: Option<UserId>) -> WorkflowStep {
@@ -124,7 +218,7 @@ mod tests {
         let user_id = UserId::new();
         let step = create_test_step(Some(user_id.clone()));
 
-        let result = check_step_assigned_to(&step, &user_id, "承認");
+        let result = check_step_assigned_to(&step, &user_id, "approve");
 
         assert!(result.is_ok());
     }
@@ -135,11 +229,11 @@ mod tests {
         let other_user = UserId::new();
         let step = create_test_step(Some(assigned_user));
 
-        let err = check_step_assigned_to(&step, &other_user, "承認").unwrap_err();
+        let err = check_step_assigned_to(&step, &other_user, "approve").unwrap_err();
 
         match err {
             CoreError::Forbidden(msg) => {
-                assert_eq!(msg, "このステップを承認する権限がありません");
+                assert_eq!(msg.to_string(), "このステップを承認する権限がありません");
             }
             other => panic!("Forbidden を期待したが {:?} を受信", other),
         }
@@ -150,11 +244,11 @@ mod tests {
         let user_id = UserId::new();
         let step = create_test_step(None);
 
-        let err = check_step_assigned_to(&step, &user_id, "却下").unwrap_err();
+        let err = check_step_assigned_to(&step, &user_id, "reject").unwrap_err();
 
         match err {
             CoreError::Forbidden(msg) => {
-                assert_eq!(msg, "このステップを却下する権限がありません");
+                assert_eq!(msg.to_string(), "このステップを却下する権限がありません");
             }
             other => panic!("Forbidden を期待したが {:?} を受信", other),
         }
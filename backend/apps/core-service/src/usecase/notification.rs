@@ -1,14 +1,33 @@
 //! # 通知ユースケース
 //!
-//! ワークフロー操作に伴うメール通知の生成・送信・ログ記録を統合する。
+//! ワークフロー操作に伴うメール通知の生成・送信・ログ記録と、アプリ内通知（通知受信箱）を扱う。
 //!
 //! ## モジュール構成
 //!
 //! - [`template_renderer`] - tera テンプレートエンジンによるメール生成
-//! - [`service`] - テンプレートレンダリング + 送信 + ログ記録の統合サービス
+//! - [`service`] - アプリ内通知記録 + テンプレートレンダリング + 送信 + ログ記録の統合サービス
+//! - [`inbox`] - アプリ内通知の参照・既読化と保持期間切れのパージ
+//! - [`preference`] - 通知イベント種別ごとのメール配信方法（即時・ダイジェスト・停止）の設定
+//! - [`digest`] - ダイジェスト待ち通知を 1 通のメールにまとめて送信するワーカー
+//! - [`log`] - 送信待ちの通知メールの再送ワーカーと、送信に失敗した通知の確認・再送
+//! - [`template`] - テナント独自の通知テンプレートの保存・検証・プレビュー
 
+pub mod digest;
+pub mod inbox;
+pub mod log;
+pub mod preference;
 pub mod service;
+pub mod template;
 pub mod template_renderer;
 
+pub use digest::NotificationDigestWorker;
+pub use inbox::{InboxPurgeWorker, InboxUseCaseImpl};
+pub use log::{NotificationLogUseCaseImpl, NotificationRetryWorker};
+pub use preference::{NotificationPreferenceInput, NotificationPreferenceUseCaseImpl};
 pub use service::NotificationService;
-pub use template_renderer::TemplateRenderer;
+pub use template::{
+    NotificationTemplateContentInput,
+    NotificationTemplateUseCaseImpl,
+    SaveNotificationTemplateInput,
+};
+pub use template_renderer::{CustomTemplate, TemplateRenderer, TemplateValidationError};
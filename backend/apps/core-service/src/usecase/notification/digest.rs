@@ -0,0 +1,680 @@
+//! # 通知ダイジェスト
+//!
+//! 配信方法がダイジェストの通知（`notification_digest_items`）を受信者ごとに
+//! 1 通のメールにまとめて送信するワーカーを提供する。
+//!
+//! ## 設計方針
+//!
+//! - **送信時刻**: 毎日決まった時刻（テナントのタイムゾーンの現地時刻）に、その時刻までに
+//!   保留された通知を送信する。送信対象は保留日時から決まるため、プロセスの起動時刻や
+//!   再起動に左右されない。停止中に送信時刻を過ぎた場合は、起動後のポーリングで送信する
+//! - **全テナント横断**: 送信時刻がテナントごとに異なるため、保留中の通知があるテナントごとに
+//!   直近の送信時刻を求め、受信者を古い順にバッチで処理する
+//! - **受信者単位のトランザクション**: 受信者の通知を `FOR UPDATE SKIP LOCKED` でロックして
+//!   削除し、送信して通知ログを記録してからコミットする。複数のワーカーが同じ通知を送信せず、
+//!   削除に失敗した場合は送信しない
+//! - **fire-and-forget**: 送信に失敗しても再送しない（即時送信と異なり本文を保存しない）。
+//!   成否は通知ごとに `notification_logs` に記録し、失敗はエラーの種類によらず `failed` で確定する
+//! - **ロケール**: 件名と本文の枠は送信時点の受信者のロケールで生成する。
+//!   各通知の要約は保留した時点のロケールのまま表示する
+//!
+//! 通知の保留は [`NotificationService::notify`] が通知設定に従って行う。
+//!
+//! [`NotificationService::notify`]: super::NotificationService::notify
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveTime, TimeDelta, Utc};
+use ringiflow_domain::{
+    clock::Clock,
+    locale::Locale,
+    notification::{
+        EmailMessage,
+        NewNotificationLog,
+        NotificationDigestItem,
+        NotificationError,
+        NotificationLog,
+        NotificationLogId,
+    },
+    tenant::TenantId,
+    time_zone::TimeZone,
+    user::UserId,
+};
+use ringiflow_infra::{
+    TransactionManager,
+    notification::NotificationSender,
+    repository::{
+        NotificationDigestRepository,
+        NotificationLogRepository,
+        TenantRepository,
+        UserRepository,
+    },
+};
+use ringiflow_shared::{event_log::event, log_business_event};
+
+use super::TemplateRenderer;
+use crate::{error::CoreError, usecase::polling::PollingWorker};
+
+/// 通知ダイジェストの送信ワーカー
+pub struct NotificationDigestWorker {
+    sender: Arc<dyn NotificationSender>,
+    template_renderer: TemplateRenderer,
+    log_repo: Arc<dyn NotificationLogRepository>,
+    digest_repo: Arc<dyn NotificationDigestRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    tenant_repo: Arc<dyn TenantRepository>,
+    tx_manager: Arc<dyn TransactionManager>,
+    clock: Arc<dyn Clock>,
+    base_url: String,
+    send_at: NaiveTime,
+    batch_size: i64,
+}
+
+impl NotificationDigestWorker {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sender: Arc<dyn NotificationSender>,
+        template_renderer: TemplateRenderer,
+        log_repo: Arc<dyn NotificationLogRepository>,
+        digest_repo: Arc<dyn NotificationDigestRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        tenant_repo: Arc<dyn TenantRepository>,
+        tx_manager: Arc<dyn TransactionManager>,
+        clock: Arc<dyn Clock>,
+        base_url: String,
+        send_at: NaiveTime,
+        batch_size: i64,
+    ) -> Self {
+        Self {
+            sender,
+            template_renderer,
+            log_repo,
+            digest_repo,
+            user_repo,
+            tenant_repo,
+            tx_manager,
+            clock,
+            base_url,
+            send_at,
+            batch_size,
+        }
+    }
+
+    /// 直近の送信時刻までに保留された通知がある受信者 1 バッチ分にダイジェストを送信する
+    ///
+    /// 直近の送信時刻はテナントのタイムゾーンで決まる。
+    /// 処理を終えた（保留中の通知を削除した）受信者の人数を返す。
+    /// テナント・受信者単位の失敗はログ出力のみで、その通知は次回に持ち越す。
+    pub async fn send_digests(&self) -> Result<usize, CoreError> {
+        let now = self.clock.now();
+        let tenant_ids = self.digest_repo.find_pending_tenants().await.map_err(|e| {
+            CoreError::Internal(format!("ダイジェストの送信対象の取得に失敗: {}", e))
+        })?;
+
+        let mut processed = 0;
+        for tenant_id in tenant_ids {
+            let remaining = self.batch_size - processed as i64;
+            if remaining <= 0 {
+                break;
+            }
+            let Some(time_zone) = self.tenant_time_zone(&tenant_id).await else {
+                continue;
+            };
+            let cutoff = latest_send_time(now, self.send_at, time_zone);
+            let recipients = self
+                .digest_repo
+                .find_pending_recipients(&tenant_id, cutoff, remaining)
+                .await
+                .map_err(|e| {
+                    CoreError::Internal(format!("ダイジェストの送信対象の取得に失敗: {}", e))
+                })?;
+
+            for user_id in recipients {
+                match self.send_digest(&tenant_id, &user_id, cutoff).await {
+                    Ok(()) => processed += 1,
+                    Err(e) => {
+                        tracing::error!(
+                            error = %e,
+                            %tenant_id,
+                            %user_id,
+                            "通知ダイジェストの送信に失敗"
+                        );
+                    }
+                }
+            }
+        }
+        Ok(processed)
+    }
+
+    /// テナントのタイムゾーンを取得する
+    ///
+    /// 取得できない場合はログ出力のみで `None` を返し、そのテナントの通知は次回に持ち越す
+    /// （誤った時刻に送信しない）。
+    async fn tenant_time_zone(&self, tenant_id: &TenantId) -> Option<TimeZone> {
+        match self.tenant_repo.find_by_id(tenant_id).await {
+            Ok(Some(tenant)) => Some(tenant.time_zone()),
+            Ok(None) => {
+                tracing::error!(%tenant_id, "ダイジェストの送信対象のテナントが見つからない");
+                None
+            }
+            Err(e) => {
+                tracing::error!(
+                    error = %e,
+                    %tenant_id,
+                    "テナントのタイムゾーンの取得に失敗"
+                );
+                None
+            }
+        }
+    }
+
+    /// 1 人の受信者の保留中の通知を取り出してダイジェストを送信する
+    ///
+    /// 通知の削除・送信・通知ログの記録を 1 つのトランザクションで行う。
+    /// 削除またはレンダリングに失敗した場合は送信せずにロールバックし、通知は次回に持ち越される。
+    /// 送信後に記録またはコミットに失敗した場合もロールバックされ、通知は次回に持ち越される
+    /// （再度送信される）。
+    async fn send_digest(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        cutoff: DateTime<Utc>,
+    ) -> Result<(), CoreError> {
+        let mut tx = self
+            .tx_manager
+            .begin()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクション開始に失敗: {}", e)))?;
+
+        let items = self
+            .digest_repo
+            .claim_by_user(&mut tx, tenant_id, user_id, cutoff)
+            .await?;
+        // 通知時点のメールアドレスのうち最新のものに送る
+        // （他のワーカーが取り出し済みの場合は空になる）
+        let Some(latest) = items.last() else {
+            return Ok(());
+        };
+        let recipient_email = latest.recipient_email().to_string();
+        // ロケールの取得に失敗しても送信は止めない
+        let locale = match self
+            .user_repo
+            .find_effective_locale(tenant_id, user_id)
+            .await
+        {
+            Ok(locale) => locale.unwrap_or_default(),
+            Err(e) => {
+                tracing::error!(
+                    error = %e,
+                    "受信者のロケールの取得に失敗したため既定のロケールを使用"
+                );
+                Locale::default()
+            }
+        };
+
+        // レンダリングに失敗した場合はトランザクションを破棄し、通知を次回に持ち越す
+        let email = self
+            .template_renderer
+            .render_digest(&recipient_email, &items, locale, &self.base_url)
+            .map_err(|e| {
+                CoreError::Internal(format!(
+                    "通知ダイジェストのテンプレートのレンダリングに失敗: {}",
+                    e
+                ))
+            })?;
+        let result = self.sender.send_email(&email).await;
+        match &result {
+            Ok(()) => {
+                log_business_event!(
+                    event.category = event::category::NOTIFICATION,
+                    event.action = event::action::NOTIFICATION_SENT,
+                    event.tenant_id = %tenant_id,
+                    event.entity_type = event::entity_type::NOTIFICATION_LOG,
+                    event.result = event::result::SUCCESS,
+                    notification.recipient = %recipient_email,
+                    notification.count = items.len(),
+                    "通知ダイジェスト送信成功"
+                );
+            }
+            Err(e) => {
+                log_business_event!(
+                    event.category = event::category::NOTIFICATION,
+                    event.action = event::action::NOTIFICATION_FAILED,
+                    event.tenant_id = %tenant_id,
+                    event.entity_type = event::entity_type::NOTIFICATION_LOG,
+                    event.result = event::result::FAILURE,
+                    notification.recipient = %recipient_email,
+                    notification.count = items.len(),
+                    error = %e,
+                    "通知ダイジェスト送信失敗"
+                );
+            }
+        }
+        let logs = self.digest_logs(&items, &email, result.err());
+        self.log_repo
+            .insert_all(&mut tx, &logs)
+            .await
+            .map_err(|e| CoreError::Internal(format!("通知ログの記録に失敗: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))
+    }
+
+    /// ダイジェストにまとめた通知ごとの通知ログを作成する
+    ///
+    /// 本文は保存せず、送信に失敗した場合は一時的なエラーでも失敗で確定する
+    /// （再送ワーカーの対象にしない）。
+    fn digest_logs(
+        &self,
+        items: &[NotificationDigestItem],
+        email: &EmailMessage,
+        error: Option<NotificationError>,
+    ) -> Vec<NotificationLog> {
+        let now = self.clock.now();
+        items
+            .iter()
+            .map(|item| {
+                let log = NotificationLog::for_digest(NewNotificationLog {
+                    id: NotificationLogId::new(),
+                    tenant_id: item.tenant_id().clone(),
+                    event_type: item.event_type(),
+                    workflow_instance_id: item.workflow_instance_id().clone(),
+                    workflow_title: item.workflow_title().to_string(),
+                    workflow_display_id: item.workflow_display_id().to_string(),
+                    recipient_user_id: item.user_id().clone(),
+                    email: email.clone(),
+                    now,
+                });
+                match &error {
+                    None => log.sent(now),
+                    Some(e) => log.failed_permanently(e),
+                }
+            })
+            .collect()
+    }
+}
+
+/// `now` 以前で直近のダイジェスト送信時刻
+///
+/// `send_at` は `time_zone` の現地時刻として解釈する。
+/// 夏時間の切り替えで送信時刻が存在しない日は切り替え後の最初の時刻とする。
+fn latest_send_time(now: DateTime<Utc>, send_at: NaiveTime, time_zone: TimeZone) -> DateTime<Utc> {
+    let today = time_zone.to_local(now).date();
+    [today, today - TimeDelta::days(1)]
+        .into_iter()
+        .filter_map(|date| time_zone.from_local(date.and_time(send_at)))
+        .find(|at| *at <= now)
+        .unwrap_or(now)
+}
+
+#[async_trait]
+impl PollingWorker for NotificationDigestWorker {
+    fn name(&self) -> &'static str {
+        "通知ダイジェストワーカー"
+    }
+
+    fn batch_size(&self) -> i64 {
+        self.batch_size
+    }
+
+    async fn step(&self) -> Result<usize, CoreError> {
+        self.send_digests().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone as _;
+    use ringiflow_domain::{
+        clock::FixedClock,
+        notification::{
+            NotificationDigestItemId,
+            NotificationEventType,
+            NotificationLogStatus,
+            WorkflowNotification,
+        },
+        tenant::{Tenant, TenantName},
+        workflow::WorkflowInstanceId,
+    };
+    use ringiflow_infra::fake::{
+        FakeNotificationDigestRepository,
+        FakeNotificationLogRepository,
+        FakeNotificationSender,
+        FakeTenantRepository,
+        FakeTransactionManager,
+        FakeUserRepository,
+    };
+
+    use super::*;
+
+    fn item(
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        email: &str,
+        created_at: DateTime<Utc>,
+    ) -> NotificationDigestItem {
+        NotificationDigestItem::from_notification(
+            NotificationDigestItemId::new(),
+            tenant_id.clone(),
+            WorkflowInstanceId::new(),
+            &WorkflowNotification::Approved {
+                workflow_title:      "経費精算申請".to_string(),
+                workflow_display_id: "WF-0042".to_string(),
+                recipient_email:     email.to_string(),
+                recipient_user_id:   user_id.clone(),
+            },
+            Locale::Ja,
+            created_at,
+        )
+    }
+
+    /// テナントを登録したテナントリポジトリ
+    fn tenant_repo(tenants: &[(&TenantId, TimeZone)]) -> FakeTenantRepository {
+        let tenant_repo = FakeTenantRepository::new();
+        for (tenant_id, time_zone) in tenants {
+            tenant_repo.add_tenant(Tenant::from_db(
+                (*tenant_id).clone(),
+                TenantName::new("Test Tenant").unwrap(),
+                Locale::Ja,
+                *time_zone,
+            ));
+        }
+        tenant_repo
+    }
+
+    fn make_worker(
+        sender: FakeNotificationSender,
+        log_repo: FakeNotificationLogRepository,
+        digest_repo: FakeNotificationDigestRepository,
+        tenant_repo: FakeTenantRepository,
+        now: DateTime<Utc>,
+    ) -> NotificationDigestWorker {
+        NotificationDigestWorker::new(
+            Arc::new(sender),
+            TemplateRenderer::new().unwrap(),
+            Arc::new(log_repo),
+            Arc::new(digest_repo),
+            Arc::new(FakeUserRepository::new()),
+            Arc::new(tenant_repo),
+            Arc::new(FakeTransactionManager),
+            Arc::new(FixedClock::new(now)),
+            "http://localhost:5173".to_string(),
+            send_at(),
+            100,
+        )
+    }
+
+    /// 毎日 9:00（テナントの現地時刻）に送信する
+    fn send_at() -> NaiveTime {
+        NaiveTime::from_hms_opt(9, 0, 0).unwrap()
+    }
+
+    /// 送信時刻を過ぎた直後
+    fn after_send_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 20, 9, 0, 30).unwrap()
+    }
+
+    /// 直近の送信時刻より前の保留日時
+    fn before_send_time() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 20, 8, 59, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_send_digests_受信者ごとに1通にまとめて送信し保留中の通知を削除する() {
+        let now = after_send_time();
+        let tenant_id = TenantId::new();
+        let tanaka = UserId::new();
+        let suzuki = UserId::new();
+        let digest_repo = FakeNotificationDigestRepository::new();
+        for created_at in [before_send_time() - TimeDelta::hours(2), before_send_time()] {
+            digest_repo
+                .insert(&item(&tenant_id, &tanaka, "tanaka@example.com", created_at))
+                .await
+                .unwrap();
+        }
+        digest_repo
+            .insert(&item(
+                &tenant_id,
+                &suzuki,
+                "suzuki@example.com",
+                before_send_time(),
+            ))
+            .await
+            .unwrap();
+        let sender = FakeNotificationSender::new();
+        let log_repo = FakeNotificationLogRepository::new();
+        let sut = make_worker(
+            sender.clone(),
+            log_repo.clone(),
+            digest_repo.clone(),
+            tenant_repo(&[(&tenant_id, TimeZone::UTC)]),
+            now,
+        );
+
+        let processed = sut.send_digests().await.unwrap();
+
+        assert_eq!(processed, 2);
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0].to, "tanaka@example.com");
+        assert_eq!(sent[0].subject, "[RingiFlow] 通知のまとめ（2 件）");
+        assert_eq!(sent[1].to, "suzuki@example.com");
+        assert_eq!(sent[1].subject, "[RingiFlow] 通知のまとめ（1 件）");
+        assert!(digest_repo.items().is_empty());
+
+        let logs = log_repo.logs();
+        assert_eq!(logs.len(), 3);
+        assert!(
+            logs.iter()
+                .all(|log| log.status() == NotificationLogStatus::Sent)
+        );
+        assert!(
+            logs.iter()
+                .all(|log| log.event_type() == NotificationEventType::Approved)
+        );
+        assert!(logs.iter().all(|log| log.sent_at() == Some(now)));
+        assert!(logs.iter().all(|log| log.email().is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_send_digests_保留中の通知がなければ何もしない() {
+        let sender = FakeNotificationSender::new();
+        let sut = make_worker(
+            sender.clone(),
+            FakeNotificationLogRepository::new(),
+            FakeNotificationDigestRepository::new(),
+            FakeTenantRepository::new(),
+            after_send_time(),
+        );
+
+        let processed = sut.send_digests().await.unwrap();
+
+        assert_eq!(processed, 0);
+        assert!(sender.sent_emails().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_digests_送信に失敗した場合は再送せず失敗として記録する() {
+        let now = after_send_time();
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let digest_repo = FakeNotificationDigestRepository::new();
+        digest_repo
+            .insert(&item(
+                &tenant_id,
+                &user_id,
+                "tanaka@example.com",
+                before_send_time(),
+            ))
+            .await
+            .unwrap();
+        let sender = FakeNotificationSender::new();
+        sender.fail_with(Some(NotificationError::SendFailed(
+            "接続タイムアウト".to_string(),
+        )));
+        let log_repo = FakeNotificationLogRepository::new();
+        let sut = make_worker(
+            sender,
+            log_repo.clone(),
+            digest_repo.clone(),
+            tenant_repo(&[(&tenant_id, TimeZone::UTC)]),
+            now,
+        );
+
+        sut.send_digests().await.unwrap();
+
+        let logs = log_repo.logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].status(), NotificationLogStatus::Failed);
+        assert!(logs[0].sent_at().is_none());
+        assert!(digest_repo.items().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_digests_送信時刻より後に保留された通知は次の送信時刻まで持ち越す() {
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let digest_repo = FakeNotificationDigestRepository::new();
+        digest_repo
+            .insert(&item(
+                &tenant_id,
+                &user_id,
+                "tanaka@example.com",
+                after_send_time(),
+            ))
+            .await
+            .unwrap();
+        let sender = FakeNotificationSender::new();
+
+        // Act: 同じ日の送信時刻を過ぎた後は送信しない
+        let same_day = make_worker(
+            sender.clone(),
+            FakeNotificationLogRepository::new(),
+            digest_repo.clone(),
+            tenant_repo(&[(&tenant_id, TimeZone::UTC)]),
+            after_send_time() + TimeDelta::hours(12),
+        )
+        .send_digests()
+        .await
+        .unwrap();
+
+        // Act: 翌日の送信時刻を過ぎると送信する
+        let next_day = make_worker(
+            sender.clone(),
+            FakeNotificationLogRepository::new(),
+            digest_repo.clone(),
+            tenant_repo(&[(&tenant_id, TimeZone::UTC)]),
+            after_send_time() + TimeDelta::days(1),
+        )
+        .send_digests()
+        .await
+        .unwrap();
+
+        assert_eq!(same_day, 0);
+        assert_eq!(next_day, 1);
+        assert_eq!(sender.sent_emails().len(), 1);
+        assert!(digest_repo.items().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_digests_送信時刻はテナントのタイムゾーンで判定する() {
+        let tokyo: TimeZone = "Asia/Tokyo".parse().unwrap();
+        let tokyo_tenant = TenantId::new();
+        let utc_tenant = TenantId::new();
+        let user_id = UserId::new();
+        let digest_repo = FakeNotificationDigestRepository::new();
+        // 東京の 9:00 は UTC の 0:00
+        let created_at = Utc.with_ymd_and_hms(2026, 3, 19, 23, 0, 0).unwrap();
+        for tenant_id in [&tokyo_tenant, &utc_tenant] {
+            digest_repo
+                .insert(&item(tenant_id, &user_id, "tanaka@example.com", created_at))
+                .await
+                .unwrap();
+        }
+        let sender = FakeNotificationSender::new();
+        let sut = make_worker(
+            sender.clone(),
+            FakeNotificationLogRepository::new(),
+            digest_repo.clone(),
+            tenant_repo(&[(&tokyo_tenant, tokyo), (&utc_tenant, TimeZone::UTC)]),
+            Utc.with_ymd_and_hms(2026, 3, 20, 0, 0, 30).unwrap(),
+        );
+
+        let processed = sut.send_digests().await.unwrap();
+
+        // 東京のテナントだけ送信時刻を過ぎている
+        assert_eq!(processed, 1);
+        assert_eq!(sender.sent_emails().len(), 1);
+        let remaining = digest_repo.items();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].tenant_id(), &utc_tenant);
+    }
+
+    #[tokio::test]
+    async fn test_send_digests_テナントが見つからない場合は通知を持ち越す() {
+        let tenant_id = TenantId::new();
+        let digest_repo = FakeNotificationDigestRepository::new();
+        digest_repo
+            .insert(&item(
+                &tenant_id,
+                &UserId::new(),
+                "tanaka@example.com",
+                before_send_time(),
+            ))
+            .await
+            .unwrap();
+        let sender = FakeNotificationSender::new();
+        let sut = make_worker(
+            sender.clone(),
+            FakeNotificationLogRepository::new(),
+            digest_repo.clone(),
+            FakeTenantRepository::new(),
+            after_send_time(),
+        );
+
+        let processed = sut.send_digests().await.unwrap();
+
+        assert_eq!(processed, 0);
+        assert!(sender.sent_emails().is_empty());
+        assert_eq!(digest_repo.items().len(), 1);
+    }
+
+    #[test]
+    fn test_latest_send_time_当日の送信時刻前は前日の送信時刻を返す() {
+        let before = Utc.with_ymd_and_hms(2026, 3, 20, 8, 59, 59).unwrap();
+        let at = Utc.with_ymd_and_hms(2026, 3, 20, 9, 0, 0).unwrap();
+
+        assert_eq!(
+            latest_send_time(before, send_at(), TimeZone::UTC),
+            Utc.with_ymd_and_hms(2026, 3, 19, 9, 0, 0).unwrap()
+        );
+        assert_eq!(latest_send_time(at, send_at(), TimeZone::UTC), at);
+    }
+
+    #[test]
+    fn test_latest_send_time_送信時刻をタイムゾーンの現地時刻として解釈する() {
+        let tokyo: TimeZone = "Asia/Tokyo".parse().unwrap();
+        // UTC では 3/20 だが、東京では 3/21 の 8:00（送信時刻前）
+        let now = Utc.with_ymd_and_hms(2026, 3, 20, 23, 0, 0).unwrap();
+
+        assert_eq!(
+            latest_send_time(now, send_at(), tokyo),
+            Utc.with_ymd_and_hms(2026, 3, 20, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_latest_send_time_夏時間で存在しない送信時刻は切り替え後の時刻になる() {
+        let new_york: TimeZone = "America/New_York".parse().unwrap();
+        let send_at = NaiveTime::from_hms_opt(2, 30, 0).unwrap();
+        // 2026-03-08 は 02:00 EST → 03:00 EDT（02:30 は存在しない）
+        let now = Utc.with_ymd_and_hms(2026, 3, 8, 12, 0, 0).unwrap();
+
+        assert_eq!(
+            latest_send_time(now, send_at, new_york),
+            Utc.with_ymd_and_hms(2026, 3, 8, 7, 0, 0).unwrap()
+        );
+    }
+}
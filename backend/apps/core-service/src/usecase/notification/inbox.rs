@@ -0,0 +1,262 @@
+//! # アプリ内通知（通知受信箱）
+//!
+//! ヘッダーのベルに表示するユーザーごとの通知の参照・既読化と、
+//! 保持期間を過ぎた通知を全テナント横断で削除するパージワーカーを提供する。
+//!
+//! 通知の記録は [`NotificationService::notify`] がメール送信と併せて行う。
+//!
+//! [`NotificationService::notify`]: super::NotificationService::notify
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ringiflow_domain::{
+    clock::Clock,
+    message::Message,
+    notification::{InboxNotification, InboxNotificationId},
+    tenant::TenantId,
+    user::UserId,
+};
+use ringiflow_infra::repository::{InboxNotificationRepository, KeysetPage};
+
+use crate::{
+    error::CoreError,
+    usecase::{
+        helpers::{page_error, page_limit},
+        polling::PollingWorker,
+    },
+};
+
+/// アプリ内通知ユースケース
+pub struct InboxUseCaseImpl {
+    inbox_repo: Arc<dyn InboxNotificationRepository>,
+    clock:      Arc<dyn Clock>,
+}
+
+impl InboxUseCaseImpl {
+    pub fn new(inbox_repo: Arc<dyn InboxNotificationRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self { inbox_repo, clock }
+    }
+
+    /// 自分宛ての通知を新しい順に 1 ページ分取得する
+    pub async fn list_notifications(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        cursor: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<KeysetPage<InboxNotification>, CoreError> {
+        self.inbox_repo
+            .find_by_user(tenant_id, user_id, cursor, page_limit(limit))
+            .await
+            .map_err(|e| page_error(e, "通知の取得"))
+    }
+
+    /// 自分宛ての未読通知数を取得する
+    pub async fn count_unread(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<i64, CoreError> {
+        Ok(self.inbox_repo.count_unread(tenant_id, user_id).await?)
+    }
+
+    /// 通知を既読にする（既読済みの場合は何もしない）
+    ///
+    /// ## エラー
+    ///
+    /// - 通知が存在しない、または他ユーザー宛ての場合: 404
+    pub async fn mark_read(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        id: &InboxNotificationId,
+    ) -> Result<(), CoreError> {
+        let found = self
+            .inbox_repo
+            .mark_read(tenant_id, user_id, id, self.clock.now())
+            .await?;
+        if !found {
+            return Err(CoreError::NotFound(Message::new("notification-not-found")));
+        }
+        Ok(())
+    }
+
+    /// 自分宛ての未読通知をすべて既読にする
+    ///
+    /// 既読にした件数を返す。
+    pub async fn mark_all_read(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<u64, CoreError> {
+        Ok(self
+            .inbox_repo
+            .mark_all_read(tenant_id, user_id, self.clock.now())
+            .await?)
+    }
+}
+
+/// アプリ内通知の保持期間切れパージワーカー
+pub struct InboxPurgeWorker {
+    inbox_repo: Arc<dyn InboxNotificationRepository>,
+    clock:      Arc<dyn Clock>,
+    retention:  chrono::Duration,
+    batch_size: i64,
+}
+
+impl InboxPurgeWorker {
+    pub fn new(
+        inbox_repo: Arc<dyn InboxNotificationRepository>,
+        clock: Arc<dyn Clock>,
+        retention: chrono::Duration,
+        batch_size: i64,
+    ) -> Self {
+        Self {
+            inbox_repo,
+            clock,
+            retention,
+            batch_size,
+        }
+    }
+
+    /// 保持期間を過ぎた通知を 1 バッチ分削除する
+    ///
+    /// 削除した通知の件数を返す。
+    pub async fn purge_expired(&self) -> Result<u64, CoreError> {
+        let created_before = self.clock.now() - self.retention;
+        let count = self
+            .inbox_repo
+            .delete_created_before(created_before, self.batch_size)
+            .await
+            .map_err(|e| CoreError::Internal(format!("保持期間切れの通知の削除に失敗: {}", e)))?;
+        if count > 0 {
+            tracing::info!(count, "保持期間切れのアプリ内通知を削除");
+        }
+        Ok(count)
+    }
+}
+
+#[async_trait]
+impl PollingWorker for InboxPurgeWorker {
+    fn name(&self) -> &'static str {
+        "アプリ内通知パージワーカー"
+    }
+
+    fn batch_size(&self) -> i64 {
+        self.batch_size
+    }
+
+    async fn step(&self) -> Result<usize, CoreError> {
+        self.purge_expired().await.map(|count| count as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, Utc};
+    use ringiflow_domain::{
+        clock::FixedClock,
+        locale::Locale,
+        notification::{InboxNotification, InboxNotificationId, WorkflowNotification},
+        tenant::TenantId,
+        user::UserId,
+        workflow::WorkflowInstanceId,
+    };
+    use ringiflow_infra::{
+        fake::FakeInboxNotificationRepository,
+        repository::InboxNotificationRepository,
+    };
+
+    use super::{InboxPurgeWorker, InboxUseCaseImpl};
+    use crate::error::CoreError;
+
+    fn notification(
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        created_at: DateTime<Utc>,
+    ) -> InboxNotification {
+        InboxNotification::from_notification(
+            InboxNotificationId::new(),
+            tenant_id.clone(),
+            WorkflowInstanceId::new(),
+            &WorkflowNotification::Approved {
+                workflow_title:      "経費精算申請".to_string(),
+                workflow_display_id: "WF-0042".to_string(),
+                recipient_email:     "tanaka@example.com".to_string(),
+                recipient_user_id:   user_id.clone(),
+            },
+            Locale::Ja,
+            created_at,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_mark_read_他ユーザーの通知は404を返す() {
+        let now = Utc::now();
+        let tenant_id = TenantId::new();
+        let owner = UserId::new();
+        let inbox_repo = FakeInboxNotificationRepository::new();
+        let target = notification(&tenant_id, &owner, now);
+        inbox_repo.insert(&target).await.unwrap();
+        let sut = InboxUseCaseImpl::new(Arc::new(inbox_repo), Arc::new(FixedClock::new(now)));
+
+        let result = sut.mark_read(&tenant_id, &UserId::new(), target.id()).await;
+
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+        assert_eq!(sut.count_unread(&tenant_id, &owner).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_mark_all_read_自分宛ての未読通知のみ既読にする() {
+        let now = Utc::now();
+        let tenant_id = TenantId::new();
+        let me = UserId::new();
+        let other = UserId::new();
+        let inbox_repo = FakeInboxNotificationRepository::new();
+        inbox_repo
+            .insert(&notification(&tenant_id, &me, now))
+            .await
+            .unwrap();
+        inbox_repo
+            .insert(&notification(&tenant_id, &me, now))
+            .await
+            .unwrap();
+        inbox_repo
+            .insert(&notification(&tenant_id, &other, now))
+            .await
+            .unwrap();
+        let sut = InboxUseCaseImpl::new(Arc::new(inbox_repo), Arc::new(FixedClock::new(now)));
+
+        let updated = sut.mark_all_read(&tenant_id, &me).await.unwrap();
+
+        assert_eq!(updated, 2);
+        assert_eq!(sut.count_unread(&tenant_id, &me).await.unwrap(), 0);
+        assert_eq!(sut.count_unread(&tenant_id, &other).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_purge_expired_保持期間を過ぎた通知のみ削除する() {
+        let now = Utc::now();
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let inbox_repo = FakeInboxNotificationRepository::new();
+        let expired = notification(&tenant_id, &user_id, now - chrono::Duration::days(91));
+        let fresh = notification(&tenant_id, &user_id, now - chrono::Duration::days(1));
+        inbox_repo.insert(&expired).await.unwrap();
+        inbox_repo.insert(&fresh).await.unwrap();
+        let sut = InboxPurgeWorker::new(
+            Arc::new(inbox_repo.clone()),
+            Arc::new(FixedClock::new(now)),
+            chrono::Duration::days(90),
+            100,
+        );
+
+        let count = sut.purge_expired().await.unwrap();
+
+        assert_eq!(count, 1);
+        assert_eq!(inbox_repo.notifications(), vec![fresh]);
+    }
+}
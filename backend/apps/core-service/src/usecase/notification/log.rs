@@ -0,0 +1,485 @@
+//! # 通知ログ（送信リトライ）
+//!
+//! 送信待ちの通知メールを全テナント横断で再送するワーカーと、
+//! 管理者が送信に失敗した通知を確認・再送するユースケースを提供する。
+//!
+//! ## 処理フロー（再送ワーカー）
+//!
+//! 1. トランザクションを開始し、送信待ちの通知ログを `FOR UPDATE SKIP LOCKED` で取得
+//! 2. 保存した本文でメールを送信する
+//! 3. 送信結果（成功 / 指数バックオフでの再送予定 / 恒久的なエラー・最大試行回数での失敗）を記録する
+//! 4. コミット
+//!
+//! 通知ログの作成と初回の送信は [`NotificationService::notify`] が行う。
+//!
+//! [`NotificationService::notify`]: super::NotificationService::notify
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ringiflow_domain::{
+    DomainError,
+    clock::Clock,
+    message::Message,
+    notification::{NotificationError, NotificationLog, NotificationLogId},
+    tenant::TenantId,
+};
+use ringiflow_infra::{
+    TransactionManager,
+    TxContext,
+    notification::NotificationSender,
+    repository::NotificationLogRepository,
+};
+use ringiflow_shared::{event_log::event, log_business_event};
+
+use crate::{error::CoreError, usecase::polling::PollingWorker};
+
+/// 失敗した通知一覧の最大件数
+const FAILED_LOG_LIMIT: i64 = 100;
+
+/// 通知ログユースケース
+pub struct NotificationLogUseCaseImpl {
+    log_repo:   Arc<dyn NotificationLogRepository>,
+    tx_manager: Arc<dyn TransactionManager>,
+    clock:      Arc<dyn Clock>,
+}
+
+impl NotificationLogUseCaseImpl {
+    pub fn new(
+        log_repo: Arc<dyn NotificationLogRepository>,
+        tx_manager: Arc<dyn TransactionManager>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            log_repo,
+            tx_manager,
+            clock,
+        }
+    }
+
+    /// テナントの送信に失敗した通知を新しい順に取得する（最大 100 件）
+    pub async fn list_failed(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<NotificationLog>, CoreError> {
+        Ok(self
+            .log_repo
+            .find_failed(tenant_id, FAILED_LOG_LIMIT)
+            .await?)
+    }
+
+    /// 送信に失敗した通知を再送待ちに戻す
+    ///
+    /// 再送ワーカーが次回のポーリングで送信する。
+    ///
+    /// ## エラー
+    ///
+    /// - 通知ログが存在しない場合: 404
+    /// - 送信に失敗した通知ではない場合: 409
+    /// - 本文を保存していない（ダイジェスト等の）場合: 400
+    pub async fn resend(
+        &self,
+        id: &NotificationLogId,
+        tenant_id: &TenantId,
+    ) -> Result<NotificationLog, CoreError> {
+        let log = self
+            .log_repo
+            .find_by_id(id, tenant_id)
+            .await?
+            .ok_or_else(|| CoreError::NotFound(Message::new("notification-not-found")))?;
+        let log = log.resend(self.clock.now()).map_err(|e| match e {
+            DomainError::Conflict(message) => CoreError::Conflict(message),
+            e => CoreError::BadRequest(e.into_message()),
+        })?;
+
+        let mut tx = self
+            .tx_manager
+            .begin()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクション開始に失敗: {}", e)))?;
+        self.log_repo.update(&mut tx, &log).await?;
+        tx.commit()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))?;
+
+        Ok(log)
+    }
+}
+
+/// 通知メールの再送ワーカー
+pub struct NotificationRetryWorker {
+    log_repo:   Arc<dyn NotificationLogRepository>,
+    sender:     Arc<dyn NotificationSender>,
+    tx_manager: Arc<dyn TransactionManager>,
+    clock:      Arc<dyn Clock>,
+    batch_size: i64,
+}
+
+impl NotificationRetryWorker {
+    pub fn new(
+        log_repo: Arc<dyn NotificationLogRepository>,
+        sender: Arc<dyn NotificationSender>,
+        tx_manager: Arc<dyn TransactionManager>,
+        clock: Arc<dyn Clock>,
+        batch_size: i64,
+    ) -> Self {
+        Self {
+            log_repo,
+            sender,
+            tx_manager,
+            clock,
+            batch_size,
+        }
+    }
+
+    /// 送信時刻に達した通知を 1 バッチ分再送する
+    ///
+    /// 処理した通知数（成功・失敗の合計）を返す。
+    pub async fn resend_due(&self) -> Result<usize, CoreError> {
+        let now = self.clock.now();
+        let mut tx = self
+            .tx_manager
+            .begin()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクション開始に失敗: {}", e)))?;
+
+        let due = self
+            .log_repo
+            .lock_due(&mut tx, now, self.batch_size)
+            .await
+            .map_err(|e| CoreError::Internal(format!("送信待ちの通知の取得に失敗: {}", e)))?;
+        let count = due.len();
+
+        for log in due {
+            self.resend(&mut tx, log).await?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))?;
+
+        Ok(count)
+    }
+
+    /// 1 件の通知を再送し、結果を記録する
+    async fn resend(&self, tx: &mut TxContext, log: NotificationLog) -> Result<(), CoreError> {
+        let result = match log.email() {
+            Some(email) => self.sender.send_email(&email).await,
+            None => Err(NotificationError::PermanentSendFailure(
+                "本文が保存されていません".to_string(),
+            )),
+        };
+
+        let event_type: &str = log.event_type().into();
+        let log = match result {
+            Ok(()) => {
+                log_business_event!(
+                    event.category = event::category::NOTIFICATION,
+                    event.action = event::action::NOTIFICATION_SENT,
+                    event.tenant_id = %log.tenant_id(),
+                    event.entity_type = event::entity_type::NOTIFICATION_LOG,
+                    event.entity_id = %log.id(),
+                    event.result = event::result::SUCCESS,
+                    notification.event_type = event_type,
+                    notification.recipient = %log.recipient_email(),
+                    notification.attempts = log.attempts() + 1,
+                    "通知メール再送成功"
+                );
+                log.sent(self.clock.now())
+            }
+            Err(e) => {
+                let log = log.failed(&e, self.clock.now());
+                log_business_event!(
+                    event.category = event::category::NOTIFICATION,
+                    event.action = event::action::NOTIFICATION_FAILED,
+                    event.tenant_id = %log.tenant_id(),
+                    event.entity_type = event::entity_type::NOTIFICATION_LOG,
+                    event.entity_id = %log.id(),
+                    event.result = event::result::FAILURE,
+                    notification.event_type = event_type,
+                    notification.recipient = %log.recipient_email(),
+                    notification.attempts = log.attempts(),
+                    notification.status = %log.status(),
+                    error = %e,
+                    "通知メール再送失敗"
+                );
+                log
+            }
+        };
+
+        self.log_repo
+            .update(tx, &log)
+            .await
+            .map_err(|e| CoreError::Internal(format!("送信結果の記録に失敗: {}", e)))
+    }
+}
+
+#[async_trait]
+impl PollingWorker for NotificationRetryWorker {
+    fn name(&self) -> &'static str {
+        "通知メール再送ワーカー"
+    }
+
+    fn batch_size(&self) -> i64 {
+        self.batch_size
+    }
+
+    async fn step(&self) -> Result<usize, CoreError> {
+        self.resend_due().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeDelta, Utc};
+    use ringiflow_domain::{
+        clock::FixedClock,
+        notification::{
+            EmailMessage,
+            NewNotificationLog,
+            NotificationEventType,
+            NotificationLogStatus,
+        },
+        user::UserId,
+        workflow::WorkflowInstanceId,
+    };
+    use ringiflow_infra::fake::{
+        FakeNotificationLogRepository,
+        FakeNotificationSender,
+        FakeTransactionManager,
+    };
+
+    use super::*;
+
+    fn make_log(tenant_id: &TenantId, now: DateTime<Utc>) -> NotificationLog {
+        NotificationLog::new(NewNotificationLog {
+            id: NotificationLogId::new(),
+            tenant_id: tenant_id.clone(),
+            event_type: NotificationEventType::ApprovalRequest,
+            workflow_instance_id: WorkflowInstanceId::new(),
+            workflow_title: "経費精算申請".to_string(),
+            workflow_display_id: "WF-0042".to_string(),
+            recipient_user_id: UserId::new(),
+            email: EmailMessage {
+                to:        "suzuki@example.com".to_string(),
+                subject:   "[RingiFlow] 承認依頼".to_string(),
+                html_body: "<p>承認依頼</p>".to_string(),
+                text_body: "承認依頼".to_string(),
+            },
+            now,
+        })
+    }
+
+    fn build_worker(
+        log_repo: &FakeNotificationLogRepository,
+        sender: &FakeNotificationSender,
+        now: DateTime<Utc>,
+    ) -> NotificationRetryWorker {
+        NotificationRetryWorker::new(
+            Arc::new(log_repo.clone()),
+            Arc::new(sender.clone()),
+            Arc::new(FakeTransactionManager),
+            Arc::new(FixedClock::new(now)),
+            50,
+        )
+    }
+
+    fn build_usecase(
+        log_repo: &FakeNotificationLogRepository,
+        now: DateTime<Utc>,
+    ) -> NotificationLogUseCaseImpl {
+        NotificationLogUseCaseImpl::new(
+            Arc::new(log_repo.clone()),
+            Arc::new(FakeTransactionManager),
+            Arc::new(FixedClock::new(now)),
+        )
+    }
+
+    fn transient_error() -> NotificationError {
+        NotificationError::SendFailed("接続タイムアウト".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_resend_due_失敗した通知はバックオフ後に保存した本文で再送される() {
+        // Arrange: 初回送信で一時的なエラー
+        let now = Utc::now();
+        let log_repo = FakeNotificationLogRepository::new();
+        let log = make_log(&TenantId::new(), now).failed(&transient_error(), now);
+        log_repo.insert(&log).await.unwrap();
+        let sender = FakeNotificationSender::new();
+
+        // Act: バックオフ中は再送されない
+        let during_backoff = build_worker(&log_repo, &sender, now)
+            .resend_due()
+            .await
+            .unwrap();
+
+        // Act: 次回送信時刻を過ぎると再送される
+        let after_backoff = build_worker(&log_repo, &sender, now + TimeDelta::minutes(1))
+            .resend_due()
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(during_backoff, 0);
+        assert_eq!(after_backoff, 1);
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, "suzuki@example.com");
+        assert_eq!(sent[0].subject, "[RingiFlow] 承認依頼");
+        let stored = &log_repo.logs()[0];
+        assert_eq!(stored.status(), NotificationLogStatus::Sent);
+        assert_eq!(stored.attempts(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resend_due_一時的なエラーが続くと最大試行回数で失敗になる() {
+        // Arrange
+        let now = Utc::now();
+        let log_repo = FakeNotificationLogRepository::new();
+        log_repo
+            .insert(&make_log(&TenantId::new(), now))
+            .await
+            .unwrap();
+        let sender = FakeNotificationSender::new();
+        sender.fail_with(Some(transient_error()));
+
+        // Act: 1 日後までに毎時ポーリングする
+        for hours in 0..=24 {
+            build_worker(&log_repo, &sender, now + TimeDelta::hours(hours))
+                .resend_due()
+                .await
+                .unwrap();
+        }
+
+        // Assert
+        let stored = &log_repo.logs()[0];
+        assert_eq!(stored.status(), NotificationLogStatus::Failed);
+        assert_eq!(
+            stored.attempts(),
+            ringiflow_domain::notification::MAX_NOTIFICATION_ATTEMPTS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resend_due_恒久的なエラーは再送せず失敗になる() {
+        // Arrange
+        let now = Utc::now();
+        let log_repo = FakeNotificationLogRepository::new();
+        log_repo
+            .insert(&make_log(&TenantId::new(), now))
+            .await
+            .unwrap();
+        let sender = FakeNotificationSender::new();
+        sender.fail_with(Some(NotificationError::PermanentSendFailure(
+            "550 宛先不明".to_string(),
+        )));
+        let sut = build_worker(&log_repo, &sender, now);
+
+        // Act
+        sut.resend_due().await.unwrap();
+
+        // Assert
+        let stored = &log_repo.logs()[0];
+        assert_eq!(stored.status(), NotificationLogStatus::Failed);
+        assert_eq!(stored.attempts(), 1);
+        assert_eq!(sut.resend_due().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_failed_テナントの失敗した通知のみ返す() {
+        // Arrange
+        let now = Utc::now();
+        let tenant_id = TenantId::new();
+        let permanent = NotificationError::PermanentSendFailure("554 送信拒否".to_string());
+        let failed = make_log(&tenant_id, now).failed(&permanent, now);
+        let log_repo = FakeNotificationLogRepository::new();
+        for log in [
+            failed.clone(),
+            make_log(&tenant_id, now).sent(now),
+            make_log(&TenantId::new(), now).failed(&permanent, now),
+        ] {
+            log_repo.insert(&log).await.unwrap();
+        }
+
+        // Act
+        let result = build_usecase(&log_repo, now)
+            .list_failed(&tenant_id)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(result, vec![failed]);
+    }
+
+    #[tokio::test]
+    async fn test_resend_失敗した通知を再送待ちに戻しワーカーが送信する() {
+        // Arrange
+        let now = Utc::now();
+        let tenant_id = TenantId::new();
+        let failed = make_log(&tenant_id, now).failed(
+            &NotificationError::PermanentSendFailure("554 送信拒否".to_string()),
+            now,
+        );
+        let log_repo = FakeNotificationLogRepository::new();
+        log_repo.insert(&failed).await.unwrap();
+        let later = now + TimeDelta::hours(1);
+        let sender = FakeNotificationSender::new();
+
+        // Act
+        let resent = build_usecase(&log_repo, later)
+            .resend(failed.id(), &tenant_id)
+            .await
+            .unwrap();
+        build_worker(&log_repo, &sender, later)
+            .resend_due()
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(resent.status(), NotificationLogStatus::Pending);
+        assert_eq!(resent.attempts(), 0);
+        assert_eq!(sender.sent_emails().len(), 1);
+        let stored = &log_repo.logs()[0];
+        assert_eq!(stored.status(), NotificationLogStatus::Sent);
+        assert_eq!(stored.attempts(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resend_他テナントの通知は404() {
+        // Arrange
+        let now = Utc::now();
+        let failed = make_log(&TenantId::new(), now).failed(
+            &NotificationError::PermanentSendFailure("554 送信拒否".to_string()),
+            now,
+        );
+        let log_repo = FakeNotificationLogRepository::new();
+        log_repo.insert(&failed).await.unwrap();
+
+        // Act
+        let result = build_usecase(&log_repo, now)
+            .resend(failed.id(), &TenantId::new())
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_resend_送信済みの通知は409() {
+        // Arrange
+        let now = Utc::now();
+        let tenant_id = TenantId::new();
+        let sent = make_log(&tenant_id, now).sent(now);
+        let log_repo = FakeNotificationLogRepository::new();
+        log_repo.insert(&sent).await.unwrap();
+
+        // Act
+        let result = build_usecase(&log_repo, now)
+            .resend(sent.id(), &tenant_id)
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::Conflict(_))));
+    }
+}
@@ -0,0 +1,226 @@
+//! # 通知設定
+//!
+//! ユーザーが通知イベント種別ごとにメールの配信方法（即時・ダイジェスト・停止）を
+//! 参照・変更するユースケースを提供する。
+//!
+//! 設定の適用は [`NotificationService::notify`] が行う。
+//!
+//! [`NotificationService::notify`]: super::NotificationService::notify
+
+use std::sync::Arc;
+
+use ringiflow_domain::{
+    clock::Clock,
+    message::Message,
+    notification::{NotificationDelivery, NotificationEventType, NotificationPreference},
+    tenant::TenantId,
+    user::UserId,
+};
+use ringiflow_infra::{TransactionManager, repository::NotificationPreferenceRepository};
+
+use crate::error::CoreError;
+
+/// 通知設定の変更入力（1 通知イベント種別分）
+#[derive(Debug, Clone)]
+pub struct NotificationPreferenceInput {
+    pub event_type: String,
+    pub delivery:   String,
+}
+
+/// 通知設定ユースケース
+pub struct NotificationPreferenceUseCaseImpl {
+    preference_repo: Arc<dyn NotificationPreferenceRepository>,
+    clock:           Arc<dyn Clock>,
+    tx_manager:      Arc<dyn TransactionManager>,
+}
+
+impl NotificationPreferenceUseCaseImpl {
+    pub fn new(
+        preference_repo: Arc<dyn NotificationPreferenceRepository>,
+        clock: Arc<dyn Clock>,
+        tx_manager: Arc<dyn TransactionManager>,
+    ) -> Self {
+        Self {
+            preference_repo,
+            clock,
+            tx_manager,
+        }
+    }
+
+    /// 自分の通知設定を全通知イベント種別分取得する
+    ///
+    /// 設定していない種別は即時として返す。
+    pub async fn list_preferences(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<Vec<NotificationPreference>, CoreError> {
+        let saved = self
+            .preference_repo
+            .find_by_user(tenant_id, user_id)
+            .await?;
+        Ok(NotificationPreference::resolve_all(&saved))
+    }
+
+    /// 自分の通知設定を変更する
+    ///
+    /// 指定した種別のみ上書きし、変更後の全通知イベント種別分の設定を返す。
+    ///
+    /// ## エラー
+    ///
+    /// - 通知イベント種別または配信方法が不正な場合: 400
+    /// - 同じ通知イベント種別を重複して指定した場合: 400
+    pub async fn update_preferences(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        inputs: Vec<NotificationPreferenceInput>,
+    ) -> Result<Vec<NotificationPreference>, CoreError> {
+        let preferences = parse_preferences(&inputs)?;
+
+        let mut tx = self
+            .tx_manager
+            .begin()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクション開始に失敗: {}", e)))?;
+
+        self.preference_repo
+            .upsert_all(&mut tx, tenant_id, user_id, &preferences, self.clock.now())
+            .await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))?;
+
+        self.list_preferences(tenant_id, user_id).await
+    }
+}
+
+/// 通知設定の変更入力をパースする
+fn parse_preferences(
+    inputs: &[NotificationPreferenceInput],
+) -> Result<Vec<NotificationPreference>, CoreError> {
+    let mut preferences: Vec<NotificationPreference> = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        let event_type = input
+            .event_type
+            .parse::<NotificationEventType>()
+            .map_err(|_| {
+                CoreError::BadRequest(
+                    Message::new("invalid-notification-event-type")
+                        .with("value", &input.event_type),
+                )
+            })?;
+        let delivery = input
+            .delivery
+            .parse::<NotificationDelivery>()
+            .map_err(|_| {
+                CoreError::BadRequest(
+                    Message::new("invalid-notification-delivery").with("value", &input.delivery),
+                )
+            })?;
+        if preferences.iter().any(|p| p.event_type == event_type) {
+            return Err(CoreError::BadRequest(
+                Message::new("notification-event-type-duplicate").with("value", &input.event_type),
+            ));
+        }
+        preferences.push(NotificationPreference {
+            event_type,
+            delivery,
+        });
+    }
+    Ok(preferences)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use ringiflow_domain::clock::FixedClock;
+    use ringiflow_infra::fake::{FakeNotificationPreferenceRepository, FakeTransactionManager};
+
+    use super::*;
+
+    fn make_sut(
+        preference_repo: FakeNotificationPreferenceRepository,
+    ) -> NotificationPreferenceUseCaseImpl {
+        NotificationPreferenceUseCaseImpl::new(
+            Arc::new(preference_repo),
+            Arc::new(FixedClock::new(Utc::now())),
+            Arc::new(FakeTransactionManager),
+        )
+    }
+
+    fn input(event_type: &str, delivery: &str) -> NotificationPreferenceInput {
+        NotificationPreferenceInput {
+            event_type: event_type.to_string(),
+            delivery:   delivery.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_preferences_指定した種別のみ上書きし全種別を返す() {
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let preference_repo = FakeNotificationPreferenceRepository::new();
+        preference_repo.set(
+            &tenant_id,
+            &user_id,
+            NotificationEventType::Approved,
+            NotificationDelivery::Off,
+        );
+        let sut = make_sut(preference_repo);
+
+        let preferences = sut
+            .update_preferences(
+                &tenant_id,
+                &user_id,
+                vec![input("approval_request", "digest")],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(preferences.len(), 10);
+        let delivery_of = |event_type| {
+            preferences
+                .iter()
+                .find(|p| p.event_type == event_type)
+                .unwrap()
+                .delivery
+        };
+        assert_eq!(
+            delivery_of(NotificationEventType::ApprovalRequest),
+            NotificationDelivery::Digest
+        );
+        assert_eq!(
+            delivery_of(NotificationEventType::Approved),
+            NotificationDelivery::Off
+        );
+        assert_eq!(
+            delivery_of(NotificationEventType::Rejected),
+            NotificationDelivery::Immediate
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_preferences_不正な値は400を返す() {
+        let sut = make_sut(FakeNotificationPreferenceRepository::new());
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+
+        for inputs in [
+            vec![input("unknown", "digest")],
+            vec![input("approved", "weekly")],
+            vec![input("approved", "off"), input("approved", "digest")],
+        ] {
+            let result = sut.update_preferences(&tenant_id, &user_id, inputs).await;
+            assert!(matches!(result, Err(CoreError::BadRequest(_))));
+        }
+        assert!(
+            sut.list_preferences(&tenant_id, &user_id)
+                .await
+                .unwrap()
+                .iter()
+                .all(|p| p.delivery == NotificationDelivery::Immediate)
+        );
+    }
+}
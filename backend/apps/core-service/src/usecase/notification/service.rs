@@ -1,58 +1,106 @@
 //! # 通知サービス
 //!
-//! テンプレートレンダリング → メール送信 → ログ記録を統合するサービス。
+//! アプリ内通知の記録 → テンプレートレンダリング → メール送信 → ログ記録を統合するサービス。
 //!
 //! ## 設計方針
 //!
 //! - **fire-and-forget**: `notify()` は送信失敗してもエラーを返さない
 //! - **ログ記録**: 成功・失敗どちらも `notification_logs` テーブルに記録
-//! - **依存性注入**: `NotificationSender` と `NotificationLogRepository` は trait で抽象化
+//! - **リトライ**: 一時的なエラーで送信に失敗したメールは本文ごと送信待ちとして記録し、
+//!   再送ワーカー（[`NotificationRetryWorker`](super::NotificationRetryWorker)）が再送する
+//! - **アプリ内通知**: メール送信の成否に関係なく `inbox_notifications` テーブルに記録
+//! - **通知設定**: メールは受信者の通知設定（即時・ダイジェスト・停止）に従う。
+//!   ダイジェストの通知は `notification_digest_items` に保留し、ダイジェストワーカーがまとめて送信する
+//! - **テナント独自テンプレート**: テナントが上書きしたテンプレートがあれば使い、
+//!   なければ（または取得・レンダリングに失敗した場合は）組み込みテンプレートで送信する
+//! - **ロケール**: アプリ内通知の要約・メールは受信者のロケール（未設定ならテナントの既定ロケール）で
+//!   生成する。解決に失敗した場合は既定のロケール（日本語）にフォールバックする
+//! - **依存性注入**: `NotificationSender` と各リポジトリは trait で抽象化
 
 use std::sync::Arc;
 
 use chrono::Utc;
 use ringiflow_domain::{
-    notification::{NotificationLogId, WorkflowNotification},
+    locale::Locale,
+    notification::{
+        EmailMessage,
+        InboxNotification,
+        InboxNotificationId,
+        NewNotificationLog,
+        NotificationDelivery,
+        NotificationDigestItem,
+        NotificationDigestItemId,
+        NotificationError,
+        NotificationLog,
+        NotificationLogId,
+        WorkflowNotification,
+    },
     tenant::TenantId,
+    user::UserId,
     workflow::WorkflowInstanceId,
 };
 use ringiflow_infra::{
     notification::NotificationSender,
-    repository::{NotificationLog, NotificationLogRepository},
+    repository::{
+        InboxNotificationRepository,
+        NotificationDigestRepository,
+        NotificationLogRepository,
+        NotificationPreferenceRepository,
+        NotificationTemplateRepository,
+        UserRepository,
+    },
 };
 use ringiflow_shared::{event_log::event, log_business_event};
 
-use super::TemplateRenderer;
+use super::{CustomTemplate, TemplateRenderer};
 
 /// 通知サービス
 ///
-/// ワークフロー操作に伴うメール通知の全体フローを統合する。
+/// ワークフロー操作に伴うアプリ内通知・メール通知の全体フローを統合する。
 /// `notify()` は fire-and-forget で、送信失敗してもエラーを返さない。
 pub struct NotificationService {
     sender: Arc<dyn NotificationSender>,
     template_renderer: TemplateRenderer,
     log_repo: Arc<dyn NotificationLogRepository>,
+    inbox_repo: Arc<dyn InboxNotificationRepository>,
+    preference_repo: Arc<dyn NotificationPreferenceRepository>,
+    digest_repo: Arc<dyn NotificationDigestRepository>,
+    template_repo: Arc<dyn NotificationTemplateRepository>,
+    user_repo: Arc<dyn UserRepository>,
     base_url: String,
 }
 
 impl NotificationService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         sender: Arc<dyn NotificationSender>,
         template_renderer: TemplateRenderer,
         log_repo: Arc<dyn NotificationLogRepository>,
+        inbox_repo: Arc<dyn InboxNotificationRepository>,
+        preference_repo: Arc<dyn NotificationPreferenceRepository>,
+        digest_repo: Arc<dyn NotificationDigestRepository>,
+        template_repo: Arc<dyn NotificationTemplateRepository>,
+        user_repo: Arc<dyn UserRepository>,
         base_url: String,
     ) -> Self {
         Self {
             sender,
             template_renderer,
             log_repo,
+            inbox_repo,
+            preference_repo,
+            digest_repo,
+            template_repo,
+            user_repo,
             base_url,
         }
     }
 
     /// 通知を送信する（fire-and-forget）
     ///
-    /// テンプレートレンダリング → メール送信 → ログ記録を行う。
+    /// アプリ内通知の記録 → テンプレートレンダリング → メール送信 → ログ記録を行う。
+    /// アプリ内通知はメールより先に記録し、メールの生成・送信に失敗しても受信箱には残す。
+    /// メールは受信者の通知設定に従い、即時送信・ダイジェストへの保留・送信しないのいずれかとなる。
     /// いずれのステップで失敗してもエラーを返さない（ログ出力のみ）。
     pub async fn notify(
         &self,
@@ -62,13 +110,160 @@ impl NotificationService {
     ) {
         let event_type = notification.event_type();
         let event_type_str: &str = event_type.into();
-        let workflow_title = notification.workflow_title().to_string();
-        let workflow_display_id = notification.workflow_display_id().to_string();
-        let recipient_user_id = notification.recipient_user_id().clone();
-        let recipient_email = notification.recipient_email().to_string();
+        let locale = self
+            .resolve_locale(tenant_id, notification.recipient_user_id())
+            .await;
+
+        // アプリ内通知記録
+        let inbox_notification = InboxNotification::from_notification(
+            InboxNotificationId::new(),
+            tenant_id.clone(),
+            workflow_instance_id.clone(),
+            &notification,
+            locale,
+            Utc::now(),
+        );
+        if let Err(e) = self.inbox_repo.insert(&inbox_notification).await {
+            tracing::error!(
+                error = %e,
+                event_type = event_type_str,
+                "アプリ内通知の記録に失敗"
+            );
+        }
+
+        // 通知設定の解決（取得に失敗した場合は即時送信にフォールバック）
+        let delivery = match self
+            .preference_repo
+            .find_delivery(tenant_id, notification.recipient_user_id(), event_type)
+            .await
+        {
+            Ok(delivery) => delivery.unwrap_or_default(),
+            Err(e) => {
+                tracing::error!(
+                    error = %e,
+                    event_type = event_type_str,
+                    "通知設定の取得に失敗"
+                );
+                NotificationDelivery::default()
+            }
+        };
+
+        match delivery {
+            NotificationDelivery::Immediate => {
+                self.send_immediately(notification, locale, tenant_id, workflow_instance_id)
+                    .await;
+            }
+            NotificationDelivery::Digest => {
+                let item = NotificationDigestItem::from_notification(
+                    NotificationDigestItemId::new(),
+                    tenant_id.clone(),
+                    workflow_instance_id.clone(),
+                    &notification,
+                    locale,
+                    Utc::now(),
+                );
+                if let Err(e) = self.digest_repo.insert(&item).await {
+                    tracing::error!(
+                        error = %e,
+                        event_type = event_type_str,
+                        "ダイジェスト待ち通知の記録に失敗"
+                    );
+                }
+            }
+            NotificationDelivery::Off => {
+                tracing::debug!(
+                    event_type = event_type_str,
+                    "通知設定によりメール送信をスキップ"
+                );
+            }
+        }
+    }
+
+    /// 受信者のロケールを解決する
+    ///
+    /// ユーザーのロケール → テナントの既定ロケールの順に解決する。
+    /// 取得に失敗した場合はログ出力し、既定のロケールにフォールバックする。
+    async fn resolve_locale(&self, tenant_id: &TenantId, user_id: &UserId) -> Locale {
+        match self
+            .user_repo
+            .find_effective_locale(tenant_id, user_id)
+            .await
+        {
+            Ok(locale) => locale.unwrap_or_default(),
+            Err(e) => {
+                tracing::error!(
+                    error = %e,
+                    "受信者のロケールの取得に失敗したため既定のロケールを使用"
+                );
+                Locale::default()
+            }
+        }
+    }
+
+    /// メールメッセージを生成する
+    ///
+    /// テナントが上書きしたテンプレートがあれば使う。テンプレートの取得または
+    /// レンダリングに失敗した場合はログ出力し、`locale` の組み込みテンプレートにフォールバックする。
+    async fn render_email(
+        &self,
+        notification: &WorkflowNotification,
+        locale: Locale,
+        tenant_id: &TenantId,
+    ) -> Result<EmailMessage, NotificationError> {
+        let event_type = notification.event_type();
+        let custom = match self
+            .template_repo
+            .find_by_event_type(tenant_id, event_type)
+            .await
+        {
+            Ok(custom) => custom,
+            Err(e) => {
+                tracing::error!(
+                    error = %e,
+                    %event_type,
+                    "通知テンプレートの取得に失敗したため組み込みテンプレートを使用"
+                );
+                None
+            }
+        };
+
+        if let Some(custom) = custom {
+            match self.template_renderer.render_custom(
+                notification,
+                &CustomTemplate::from(&custom),
+                locale,
+                &self.base_url,
+            ) {
+                Ok(email) => return Ok(email),
+                Err(e) => {
+                    tracing::error!(
+                        error = %e,
+                        %event_type,
+                        "テナントの通知テンプレートのレンダリングに失敗したため組み込みテンプレートを使用"
+                    );
+                }
+            }
+        }
+
+        self.template_renderer
+            .render(notification, locale, &self.base_url)
+    }
+
+    /// メールを即時送信し、通知ログを記録する
+    ///
+    /// 一時的なエラーで失敗した場合は送信待ちとして記録し、再送ワーカーが再送する。
+    async fn send_immediately(
+        &self,
+        notification: WorkflowNotification,
+        locale: Locale,
+        tenant_id: &TenantId,
+        workflow_instance_id: &WorkflowInstanceId,
+    ) {
+        let event_type = notification.event_type();
+        let event_type_str: &str = event_type.into();
 
         // テンプレートレンダリング
-        let email = match self.template_renderer.render(&notification, &self.base_url) {
+        let email = match self.render_email(&notification, locale, tenant_id).await {
             Ok(email) => email,
             Err(e) => {
                 tracing::error!(
@@ -80,55 +275,54 @@ impl NotificationService {
             }
         };
 
-        let subject = email.subject.clone();
+        let log = NotificationLog::new(NewNotificationLog {
+            id: NotificationLogId::new(),
+            tenant_id: tenant_id.clone(),
+            event_type,
+            workflow_instance_id: workflow_instance_id.clone(),
+            workflow_title: notification.workflow_title().to_string(),
+            workflow_display_id: notification.workflow_display_id().to_string(),
+            recipient_user_id: notification.recipient_user_id().clone(),
+            email: email.clone(),
+            now: Utc::now(),
+        });
 
         // メール送信
-        let (status, error_message) = match self.sender.send_email(&email).await {
+        let log = match self.sender.send_email(&email).await {
             Ok(()) => {
                 log_business_event!(
                     event.category = event::category::NOTIFICATION,
                     event.action = event::action::NOTIFICATION_SENT,
                     event.tenant_id = %tenant_id,
                     event.entity_type = event::entity_type::NOTIFICATION_LOG,
+                    event.entity_id = %log.id(),
                     event.result = event::result::SUCCESS,
                     notification.event_type = event_type_str,
-                    notification.recipient = %recipient_email,
+                    notification.recipient = %email.to,
                     "通知メール送信成功"
                 );
-                ("sent".to_string(), None)
+                log.sent(Utc::now())
             }
             Err(e) => {
+                let log = log.failed(&e, Utc::now());
                 log_business_event!(
                     event.category = event::category::NOTIFICATION,
                     event.action = event::action::NOTIFICATION_FAILED,
                     event.tenant_id = %tenant_id,
                     event.entity_type = event::entity_type::NOTIFICATION_LOG,
+                    event.entity_id = %log.id(),
                     event.result = event::result::FAILURE,
                     notification.event_type = event_type_str,
-                    notification.recipient = %recipient_email,
+                    notification.recipient = %email.to,
+                    notification.status = %log.status(),
                     error = %e,
                     "通知メール送信失敗"
                 );
-                ("failed".to_string(), Some(e.to_string()))
+                log
             }
         };
 
         // 通知ログ記録
-        let log = NotificationLog {
-            id: NotificationLogId::new(),
-            tenant_id: tenant_id.clone(),
-            event_type: event_type_str.to_string(),
-            workflow_instance_id: workflow_instance_id.clone(),
-            workflow_title,
-            workflow_display_id,
-            recipient_user_id,
-            recipient_email,
-            subject,
-            status,
-            error_message,
-            sent_at: Utc::now(),
-        };
-
         if let Err(e) = self.log_repo.insert(&log).await {
             tracing::error!(
                 error = %e,
@@ -140,24 +334,117 @@ impl NotificationService {
 
 #[cfg(test)]
 mod tests {
-    use ringiflow_domain::user::UserId;
-    use ringiflow_infra::fake::{FakeNotificationLogRepository, FakeNotificationSender};
+    use ringiflow_domain::{
+        notification::{
+            NewNotificationTemplate,
+            NotificationEventType,
+            NotificationHtmlTemplate,
+            NotificationLogStatus,
+            NotificationSubjectTemplate,
+            NotificationTemplate,
+            NotificationTextTemplate,
+        },
+        user::{Email, User, UserId},
+        value_objects::{DisplayNumber, UserName},
+    };
+    use ringiflow_infra::fake::{
+        FakeInboxNotificationRepository,
+        FakeNotificationDigestRepository,
+        FakeNotificationLogRepository,
+        FakeNotificationPreferenceRepository,
+        FakeNotificationSender,
+        FakeNotificationTemplateRepository,
+        FakeUserRepository,
+    };
 
     use super::*;
 
     fn make_service(
         sender: FakeNotificationSender,
         log_repo: FakeNotificationLogRepository,
+        inbox_repo: FakeInboxNotificationRepository,
+    ) -> NotificationService {
+        make_service_with_preferences(
+            sender,
+            log_repo,
+            inbox_repo,
+            FakeNotificationPreferenceRepository::new(),
+            FakeNotificationDigestRepository::new(),
+        )
+    }
+
+    fn make_service_with_preferences(
+        sender: FakeNotificationSender,
+        log_repo: FakeNotificationLogRepository,
+        inbox_repo: FakeInboxNotificationRepository,
+        preference_repo: FakeNotificationPreferenceRepository,
+        digest_repo: FakeNotificationDigestRepository,
     ) -> NotificationService {
-        let template_renderer = TemplateRenderer::new().unwrap();
         NotificationService::new(
             Arc::new(sender),
-            template_renderer,
+            TemplateRenderer::new().unwrap(),
             Arc::new(log_repo),
+            Arc::new(inbox_repo),
+            Arc::new(preference_repo),
+            Arc::new(digest_repo),
+            Arc::new(FakeNotificationTemplateRepository::new()),
+            Arc::new(FakeUserRepository::new()),
+            "http://localhost:5173".to_string(),
+        )
+    }
+
+    fn make_service_with_templates(
+        sender: FakeNotificationSender,
+        template_repo: FakeNotificationTemplateRepository,
+    ) -> NotificationService {
+        NotificationService::new(
+            Arc::new(sender),
+            TemplateRenderer::new().unwrap(),
+            Arc::new(FakeNotificationLogRepository::new()),
+            Arc::new(FakeInboxNotificationRepository::new()),
+            Arc::new(FakeNotificationPreferenceRepository::new()),
+            Arc::new(FakeNotificationDigestRepository::new()),
+            Arc::new(template_repo),
+            Arc::new(FakeUserRepository::new()),
+            "http://localhost:5173".to_string(),
+        )
+    }
+
+    fn make_service_with_users(
+        sender: FakeNotificationSender,
+        inbox_repo: FakeInboxNotificationRepository,
+        user_repo: FakeUserRepository,
+    ) -> NotificationService {
+        NotificationService::new(
+            Arc::new(sender),
+            TemplateRenderer::new().unwrap(),
+            Arc::new(FakeNotificationLogRepository::new()),
+            Arc::new(inbox_repo),
+            Arc::new(FakeNotificationPreferenceRepository::new()),
+            Arc::new(FakeNotificationDigestRepository::new()),
+            Arc::new(FakeNotificationTemplateRepository::new()),
+            Arc::new(user_repo),
             "http://localhost:5173".to_string(),
         )
     }
 
+    fn make_custom_template(
+        tenant_id: &TenantId,
+        event_type: NotificationEventType,
+        subject: &str,
+    ) -> NotificationTemplate {
+        NotificationTemplate::new(NewNotificationTemplate {
+            tenant_id: tenant_id.clone(),
+            event_type,
+            subject: NotificationSubjectTemplate::new(subject).unwrap(),
+            html_body: NotificationHtmlTemplate::new("<p>{{ applicant_name }} さんの申請</p>")
+                .unwrap(),
+            text_body: NotificationTextTemplate::new("{{ applicant_name }} さんの申請").unwrap(),
+            updated_by: UserId::new(),
+            now: Utc::now(),
+        })
+    }
+
     fn make_notification() -> WorkflowNotification {
         WorkflowNotification::ApprovalRequest {
             workflow_title:      "経費精算申請".to_string(),
@@ -173,7 +460,11 @@ mod tests {
     async fn 送信成功時にlog_repoにstatus_sentで記録する() {
         let sender = FakeNotificationSender::new();
         let log_repo = FakeNotificationLogRepository::new();
-        let service = make_service(sender.clone(), log_repo.clone());
+        let service = make_service(
+            sender.clone(),
+            log_repo.clone(),
+            FakeInboxNotificationRepository::new(),
+        );
 
         let tenant_id = TenantId::new();
         let instance_id = WorkflowInstanceId::new();
@@ -184,34 +475,83 @@ mod tests {
 
         let logs = log_repo.logs();
         assert_eq!(logs.len(), 1);
-        assert_eq!(logs[0].status, "sent");
-        assert!(logs[0].error_message.is_none());
-        assert_eq!(logs[0].event_type, "approval_request");
-        assert_eq!(logs[0].recipient_email, "suzuki@example.com");
+        assert_eq!(logs[0].status(), NotificationLogStatus::Sent);
+        assert!(logs[0].error_message().is_none());
+        assert_eq!(logs[0].event_type(), NotificationEventType::ApprovalRequest);
+        assert_eq!(logs[0].recipient_email(), "suzuki@example.com");
+        assert_eq!(logs[0].attempts(), 1);
     }
 
     #[tokio::test]
-    async fn 送信失敗してもエラーを返さない() {
-        // FakeNotificationSender は常に成功するため、このテストは
-        // service.notify() が Result ではなく () を返すことの確認
+    async fn 一時的なエラーで送信失敗した場合は本文ごと送信待ちとして記録する() {
         let sender = FakeNotificationSender::new();
+        sender.fail_with(Some(NotificationError::SendFailed(
+            "接続タイムアウト".to_string(),
+        )));
         let log_repo = FakeNotificationLogRepository::new();
-        let service = make_service(sender, log_repo);
+        let service = make_service(
+            sender,
+            log_repo.clone(),
+            FakeInboxNotificationRepository::new(),
+        );
 
         let tenant_id = TenantId::new();
         let instance_id = WorkflowInstanceId::new();
 
-        // notify() は () を返す（コンパイル時検証）
+        // notify() は送信に失敗してもエラーを返さない
         service
             .notify(make_notification(), &tenant_id, &instance_id)
             .await;
+
+        let logs = log_repo.logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].status(), NotificationLogStatus::Pending);
+        assert_eq!(logs[0].attempts(), 1);
+        assert!(logs[0].next_attempt_at() > logs[0].created_at());
+        let email = logs[0].email().unwrap();
+        assert_eq!(email.to, "suzuki@example.com");
+        assert!(email.subject.contains("承認依頼"));
+    }
+
+    #[tokio::test]
+    async fn 恒久的なエラーで送信失敗した場合は再送せず失敗として記録する() {
+        let sender = FakeNotificationSender::new();
+        sender.fail_with(Some(NotificationError::PermanentSendFailure(
+            "550 宛先不明".to_string(),
+        )));
+        let log_repo = FakeNotificationLogRepository::new();
+        let service = make_service(
+            sender,
+            log_repo.clone(),
+            FakeInboxNotificationRepository::new(),
+        );
+
+        service
+            .notify(
+                make_notification(),
+                &TenantId::new(),
+                &WorkflowInstanceId::new(),
+            )
+            .await;
+
+        let logs = log_repo.logs();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].status(), NotificationLogStatus::Failed);
+        assert_eq!(
+            logs[0].error_message(),
+            Some("メール送信に失敗（再送不可）: 550 宛先不明")
+        );
     }
 
     #[tokio::test]
     async fn fake_notification_senderが送信メッセージを記録する() {
         let sender = FakeNotificationSender::new();
         let log_repo = FakeNotificationLogRepository::new();
-        let service = make_service(sender.clone(), log_repo);
+        let service = make_service(
+            sender.clone(),
+            log_repo,
+            FakeInboxNotificationRepository::new(),
+        );
 
         let tenant_id = TenantId::new();
         let instance_id = WorkflowInstanceId::new();
@@ -228,4 +568,251 @@ mod tests {
             "[RingiFlow] 承認依頼: 経費精算申請 WF-0042"
         );
     }
+
+    #[tokio::test]
+    async fn 受信者の受信箱に未読のアプリ内通知を記録する() {
+        let inbox_repo = FakeInboxNotificationRepository::new();
+        let service = make_service(
+            FakeNotificationSender::new(),
+            FakeNotificationLogRepository::new(),
+            inbox_repo.clone(),
+        );
+        let notification = make_notification();
+        let recipient_user_id = notification.recipient_user_id().clone();
+
+        let tenant_id = TenantId::new();
+        let instance_id = WorkflowInstanceId::new();
+
+        service.notify(notification, &tenant_id, &instance_id).await;
+
+        let notifications = inbox_repo.notifications();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].tenant_id(), &tenant_id);
+        assert_eq!(notifications[0].user_id(), &recipient_user_id);
+        assert_eq!(notifications[0].workflow_instance_id(), &instance_id);
+        assert_eq!(
+            notifications[0].message(),
+            "田中太郎 さんから「上長承認」の承認依頼が届きました"
+        );
+        assert!(!notifications[0].is_read());
+    }
+
+    #[tokio::test]
+    async fn 配信方法がダイジェストの場合はメールを送らずダイジェスト待ちに保留する() {
+        let sender = FakeNotificationSender::new();
+        let log_repo = FakeNotificationLogRepository::new();
+        let inbox_repo = FakeInboxNotificationRepository::new();
+        let preference_repo = FakeNotificationPreferenceRepository::new();
+        let digest_repo = FakeNotificationDigestRepository::new();
+        let notification = make_notification();
+        let tenant_id = TenantId::new();
+        let instance_id = WorkflowInstanceId::new();
+        preference_repo.set(
+            &tenant_id,
+            notification.recipient_user_id(),
+            NotificationEventType::ApprovalRequest,
+            NotificationDelivery::Digest,
+        );
+        let service = make_service_with_preferences(
+            sender.clone(),
+            log_repo.clone(),
+            inbox_repo.clone(),
+            preference_repo,
+            digest_repo.clone(),
+        );
+
+        service.notify(notification, &tenant_id, &instance_id).await;
+
+        assert!(sender.sent_emails().is_empty());
+        assert!(log_repo.logs().is_empty());
+        assert_eq!(inbox_repo.notifications().len(), 1);
+        let items = digest_repo.items();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].tenant_id(), &tenant_id);
+        assert_eq!(items[0].workflow_instance_id(), &instance_id);
+        assert_eq!(items[0].recipient_email(), "suzuki@example.com");
+        assert_eq!(
+            items[0].message(),
+            "田中太郎 さんから「上長承認」の承認依頼が届きました"
+        );
+    }
+
+    #[tokio::test]
+    async fn 配信方法が停止の場合はメールを送らずアプリ内通知のみ記録する() {
+        let sender = FakeNotificationSender::new();
+        let log_repo = FakeNotificationLogRepository::new();
+        let inbox_repo = FakeInboxNotificationRepository::new();
+        let preference_repo = FakeNotificationPreferenceRepository::new();
+        let digest_repo = FakeNotificationDigestRepository::new();
+        let notification = make_notification();
+        let tenant_id = TenantId::new();
+        preference_repo.set(
+            &tenant_id,
+            notification.recipient_user_id(),
+            NotificationEventType::ApprovalRequest,
+            NotificationDelivery::Off,
+        );
+        let service = make_service_with_preferences(
+            sender.clone(),
+            log_repo.clone(),
+            inbox_repo.clone(),
+            preference_repo,
+            digest_repo.clone(),
+        );
+
+        service
+            .notify(notification, &tenant_id, &WorkflowInstanceId::new())
+            .await;
+
+        assert!(sender.sent_emails().is_empty());
+        assert!(log_repo.logs().is_empty());
+        assert!(digest_repo.items().is_empty());
+        assert_eq!(inbox_repo.notifications().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn 他の通知イベント種別の設定は適用しない() {
+        let sender = FakeNotificationSender::new();
+        let preference_repo = FakeNotificationPreferenceRepository::new();
+        let notification = make_notification();
+        let tenant_id = TenantId::new();
+        preference_repo.set(
+            &tenant_id,
+            notification.recipient_user_id(),
+            NotificationEventType::Approved,
+            NotificationDelivery::Off,
+        );
+        let service = make_service_with_preferences(
+            sender.clone(),
+            FakeNotificationLogRepository::new(),
+            FakeInboxNotificationRepository::new(),
+            preference_repo,
+            FakeNotificationDigestRepository::new(),
+        );
+
+        service
+            .notify(notification, &tenant_id, &WorkflowInstanceId::new())
+            .await;
+
+        assert_eq!(sender.sent_emails().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn 受信者のロケールでアプリ内通知とメールを生成する() {
+        let tenant_id = TenantId::new();
+        let user = User::new(
+            UserId::new(),
+            tenant_id.clone(),
+            DisplayNumber::new(1).unwrap(),
+            Email::new("suzuki@example.com").unwrap(),
+            UserName::new("Ichiro Suzuki").unwrap(),
+            Utc::now(),
+        )
+        .with_locale(Some(Locale::En), Utc::now());
+        let notification = WorkflowNotification::ApprovalRequest {
+            workflow_title:      "Travel expenses".to_string(),
+            workflow_display_id: "WF-0042".to_string(),
+            applicant_name:      "John Smith".to_string(),
+            step_name:           "Manager approval".to_string(),
+            approver_email:      "suzuki@example.com".to_string(),
+            approver_user_id:    user.id().clone(),
+        };
+        let user_repo = FakeUserRepository::new();
+        user_repo.add_user(user);
+        let sender = FakeNotificationSender::new();
+        let inbox_repo = FakeInboxNotificationRepository::new();
+        let sut = make_service_with_users(sender.clone(), inbox_repo.clone(), user_repo);
+
+        sut.notify(notification, &tenant_id, &WorkflowInstanceId::new())
+            .await;
+
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(
+            sent[0].subject,
+            "[RingiFlow] Approval request: Travel expenses WF-0042"
+        );
+        assert_eq!(
+            inbox_repo.notifications()[0].message(),
+            "John Smith requested your approval for \"Manager approval\""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_notify_テナントの通知テンプレートがあれば使用する() {
+        let tenant_id = TenantId::new();
+        let template_repo = FakeNotificationTemplateRepository::new();
+        template_repo
+            .upsert(&make_custom_template(
+                &tenant_id,
+                NotificationEventType::ApprovalRequest,
+                "【要対応】{{ workflow_title }}",
+            ))
+            .await
+            .unwrap();
+        let sender = FakeNotificationSender::new();
+        let sut = make_service_with_templates(sender.clone(), template_repo);
+
+        sut.notify(make_notification(), &tenant_id, &WorkflowInstanceId::new())
+            .await;
+
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].subject, "【要対応】経費精算申請");
+        assert_eq!(sent[0].text_body, "田中太郎 さんの申請");
+    }
+
+    #[tokio::test]
+    async fn test_notify_他テナントや他の通知イベント種別のテンプレートは使用しない() {
+        let tenant_id = TenantId::new();
+        let template_repo = FakeNotificationTemplateRepository::new();
+        for (tenant, event_type) in [
+            (TenantId::new(), NotificationEventType::ApprovalRequest),
+            (tenant_id.clone(), NotificationEventType::Approved),
+        ] {
+            template_repo
+                .upsert(&make_custom_template(&tenant, event_type, "独自の件名"))
+                .await
+                .unwrap();
+        }
+        let sender = FakeNotificationSender::new();
+        let sut = make_service_with_templates(sender.clone(), template_repo);
+
+        sut.notify(make_notification(), &tenant_id, &WorkflowInstanceId::new())
+            .await;
+
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(
+            sent[0].subject,
+            "[RingiFlow] 承認依頼: 経費精算申請 WF-0042"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_notify_テナントの通知テンプレートのレンダリングに失敗したら組み込みテンプレートで送信する()
+     {
+        let tenant_id = TenantId::new();
+        let template_repo = FakeNotificationTemplateRepository::new();
+        template_repo
+            .upsert(&make_custom_template(
+                &tenant_id,
+                NotificationEventType::ApprovalRequest,
+                "{{ removed_variable }}",
+            ))
+            .await
+            .unwrap();
+        let sender = FakeNotificationSender::new();
+        let sut = make_service_with_templates(sender.clone(), template_repo);
+
+        sut.notify(make_notification(), &tenant_id, &WorkflowInstanceId::new())
+            .await;
+
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(
+            sent[0].subject,
+            "[RingiFlow] 承認依頼: 経費精算申請 WF-0042"
+        );
+    }
 }
@@ -0,0 +1,522 @@
+//! # 通知テンプレート
+//!
+//! テナント管理者が通知イベント種別ごとにメールの件名・HTML 本文・テキスト本文の
+//! テンプレートを上書きするユースケースを提供する。
+//!
+//! 保存前にサンプルデータでレンダリングして構文と変数を検証する。
+//! 上書きしたテンプレートの適用は [`NotificationService::notify`] が行い、
+//! 上書きのない通知イベント種別は組み込みテンプレートで送信する。
+//!
+//! [`NotificationService::notify`]: super::NotificationService::notify
+
+use std::sync::Arc;
+
+use ringiflow_domain::{
+    DomainError,
+    clock::Clock,
+    locale::Locale,
+    message::Message,
+    notification::{
+        EmailMessage,
+        NewNotificationTemplate,
+        NotificationEventType,
+        NotificationHtmlTemplate,
+        NotificationSubjectTemplate,
+        NotificationTemplate,
+        NotificationTextTemplate,
+    },
+    tenant::TenantId,
+    user::UserId,
+};
+use ringiflow_infra::repository::NotificationTemplateRepository;
+use uuid::Uuid;
+
+use super::{CustomTemplate, TemplateRenderer, TemplateValidationError};
+use crate::error::CoreError;
+
+/// 通知テンプレートの内容の入力
+#[derive(Debug, Clone)]
+pub struct NotificationTemplateContentInput {
+    pub subject:   String,
+    pub html_body: String,
+    pub text_body: String,
+}
+
+/// 通知テンプレート保存の入力
+pub struct SaveNotificationTemplateInput {
+    pub tenant_id:  TenantId,
+    pub event_type: String,
+    pub content:    NotificationTemplateContentInput,
+    pub updated_by: Uuid,
+}
+
+/// 検証済みの通知テンプレートの内容
+struct ValidatedContent {
+    subject:   NotificationSubjectTemplate,
+    html_body: NotificationHtmlTemplate,
+    text_body: NotificationTextTemplate,
+}
+
+impl ValidatedContent {
+    fn as_custom(&self) -> CustomTemplate<'_> {
+        CustomTemplate {
+            subject:   self.subject.as_str(),
+            html_body: self.html_body.as_str(),
+            text_body: self.text_body.as_str(),
+        }
+    }
+}
+
+/// 通知テンプレートユースケース
+pub struct NotificationTemplateUseCaseImpl {
+    template_repo: Arc<dyn NotificationTemplateRepository>,
+    template_renderer: TemplateRenderer,
+    clock: Arc<dyn Clock>,
+    base_url: String,
+}
+
+impl NotificationTemplateUseCaseImpl {
+    pub fn new(
+        template_repo: Arc<dyn NotificationTemplateRepository>,
+        template_renderer: TemplateRenderer,
+        clock: Arc<dyn Clock>,
+        base_url: String,
+    ) -> Self {
+        Self {
+            template_repo,
+            template_renderer,
+            clock,
+            base_url,
+        }
+    }
+
+    /// テナントが上書きした通知テンプレートの一覧を取得する
+    pub async fn list_templates(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<NotificationTemplate>, CoreError> {
+        let templates = self.template_repo.find_all_by_tenant(tenant_id).await?;
+        Ok(templates)
+    }
+
+    /// 通知イベント種別の通知テンプレートを取得する
+    ///
+    /// ## エラー
+    ///
+    /// - 通知イベント種別が不正な場合: 400
+    /// - テナントが上書きしていない場合: 404
+    pub async fn get_template(
+        &self,
+        tenant_id: &TenantId,
+        event_type: &str,
+    ) -> Result<NotificationTemplate, CoreError> {
+        let event_type = parse_event_type(event_type)?;
+        self.template_repo
+            .find_by_event_type(tenant_id, event_type)
+            .await?
+            .ok_or_else(|| CoreError::NotFound(Message::new("notification-template-not-found")))
+    }
+
+    /// 通知テンプレートを保存する（保存済みの場合は内容を置き換える）
+    ///
+    /// ## エラー
+    ///
+    /// - 通知イベント種別が不正な場合: 400
+    /// - テンプレートの検証に失敗した場合: 400
+    pub async fn save_template(
+        &self,
+        input: SaveNotificationTemplateInput,
+    ) -> Result<NotificationTemplate, CoreError> {
+        let event_type = parse_event_type(&input.event_type)?;
+        let content = self
+            .validate_content(event_type, input.content)
+            .map_err(validation_failed)?;
+        let updated_by = UserId::from_uuid(input.updated_by);
+        let now = self.clock.now();
+
+        let template = match self
+            .template_repo
+            .find_by_event_type(&input.tenant_id, event_type)
+            .await?
+        {
+            Some(existing) => existing.replaced(
+                content.subject,
+                content.html_body,
+                content.text_body,
+                updated_by,
+                now,
+            ),
+            None => NotificationTemplate::new(NewNotificationTemplate {
+                tenant_id: input.tenant_id,
+                event_type,
+                subject: content.subject,
+                html_body: content.html_body,
+                text_body: content.text_body,
+                updated_by,
+                now,
+            }),
+        };
+
+        self.template_repo.upsert(&template).await?;
+        Ok(template)
+    }
+
+    /// 通知テンプレートを削除する（以降は組み込みテンプレートで送信する）
+    ///
+    /// ## エラー
+    ///
+    /// - 通知イベント種別が不正な場合: 400
+    /// - テナントが上書きしていない場合: 404
+    pub async fn delete_template(
+        &self,
+        tenant_id: &TenantId,
+        event_type: &str,
+    ) -> Result<(), CoreError> {
+        let event_type = parse_event_type(event_type)?;
+        if !self.template_repo.delete(tenant_id, event_type).await? {
+            return Err(CoreError::NotFound(Message::new(
+                "notification-template-not-found",
+            )));
+        }
+        Ok(())
+    }
+
+    /// 通知テンプレートを保存せずに検証する
+    ///
+    /// 検証エラーの一覧を返す（空の場合は保存できる）。
+    ///
+    /// ## エラー
+    ///
+    /// - 通知イベント種別が不正な場合: 400
+    pub fn validate_template(
+        &self,
+        event_type: &str,
+        content: NotificationTemplateContentInput,
+    ) -> Result<Vec<TemplateValidationError>, CoreError> {
+        let event_type = parse_event_type(event_type)?;
+        Ok(self
+            .validate_content(event_type, content)
+            .err()
+            .unwrap_or_default())
+    }
+
+    /// 通知イベント種別のサンプルデータでメールのプレビューを生成する
+    ///
+    /// `content` を指定した場合はその内容で、指定しない場合はテナントが保存した
+    /// 通知テンプレート（なければ `locale` の組み込みテンプレート）でレンダリングする。
+    /// `locale` を省略した場合は既定のロケール（日本語）を使う。
+    ///
+    /// ## エラー
+    ///
+    /// - 通知イベント種別またはロケールが不正な場合: 400
+    /// - `content` の検証に失敗した場合: 400
+    pub async fn preview_template(
+        &self,
+        tenant_id: &TenantId,
+        event_type: &str,
+        content: Option<NotificationTemplateContentInput>,
+        locale: Option<&str>,
+    ) -> Result<EmailMessage, CoreError> {
+        let event_type = parse_event_type(event_type)?;
+        let locale = locale.map(parse_locale).transpose()?.unwrap_or_default();
+
+        let email = match content {
+            Some(content) => {
+                let content = self
+                    .validate_content(event_type, content)
+                    .map_err(validation_failed)?;
+                self.template_renderer.preview(
+                    event_type,
+                    Some(&content.as_custom()),
+                    locale,
+                    &self.base_url,
+                )
+            }
+            None => {
+                let saved = self
+                    .template_repo
+                    .find_by_event_type(tenant_id, event_type)
+                    .await?;
+                self.template_renderer.preview(
+                    event_type,
+                    saved.as_ref().map(CustomTemplate::from).as_ref(),
+                    locale,
+                    &self.base_url,
+                )
+            }
+        };
+
+        email.map_err(|e| {
+            CoreError::BadRequest(Message::new("notification-preview-failed").with("reason", e))
+        })
+    }
+
+    /// 通知テンプレートの内容を検証する
+    ///
+    /// 文字数の検証に通った部分のみサンプルデータでのレンダリングを検証する。
+    fn validate_content(
+        &self,
+        event_type: NotificationEventType,
+        content: NotificationTemplateContentInput,
+    ) -> Result<ValidatedContent, Vec<TemplateValidationError>> {
+        let mut errors = Vec::new();
+        let subject = collect_error(
+            &mut errors,
+            "subject",
+            NotificationSubjectTemplate::new(content.subject),
+        );
+        let html_body = collect_error(
+            &mut errors,
+            "html_body",
+            NotificationHtmlTemplate::new(content.html_body),
+        );
+        let text_body = collect_error(
+            &mut errors,
+            "text_body",
+            NotificationTextTemplate::new(content.text_body),
+        );
+
+        let custom = CustomTemplate {
+            subject:   subject.as_ref().map_or("", |s| s.as_str()),
+            html_body: html_body.as_ref().map_or("", |s| s.as_str()),
+            text_body: text_body.as_ref().map_or("", |s| s.as_str()),
+        };
+        let render_errors: Vec<TemplateValidationError> = self
+            .template_renderer
+            .validate_custom(event_type, &custom)
+            .into_iter()
+            .filter(|e| !errors.iter().any(|existing| existing.field == e.field))
+            .collect();
+        errors.extend(render_errors);
+
+        match (subject, html_body, text_body) {
+            (Some(subject), Some(html_body), Some(text_body)) if errors.is_empty() => {
+                Ok(ValidatedContent {
+                    subject,
+                    html_body,
+                    text_body,
+                })
+            }
+            _ => Err(errors),
+        }
+    }
+}
+
+/// 値オブジェクトの検証エラーを部分ごとの検証エラーとして集める
+fn collect_error<T>(
+    errors: &mut Vec<TemplateValidationError>,
+    field: &'static str,
+    result: Result<T, DomainError>,
+) -> Option<T> {
+    result
+        .map_err(|e| {
+            errors.push(TemplateValidationError {
+                field,
+                message: e.to_string(),
+            });
+        })
+        .ok()
+}
+
+/// 通知イベント種別をパースする
+fn parse_event_type(value: &str) -> Result<NotificationEventType, CoreError> {
+    value.parse().map_err(|_| {
+        CoreError::BadRequest(Message::new("invalid-notification-event-type").with("value", value))
+    })
+}
+
+/// ロケールの文字列表現をパースする（不正な値は 400）
+fn parse_locale(value: &str) -> Result<Locale, CoreError> {
+    value
+        .parse()
+        .map_err(|_| CoreError::BadRequest(Message::new("invalid-locale").with("value", value)))
+}
+
+/// 検証エラーを 400 に変換する
+fn validation_failed(errors: Vec<TemplateValidationError>) -> CoreError {
+    let messages: Vec<String> = errors
+        .iter()
+        .map(|e| format!("{}: {}", e.field, e.message))
+        .collect();
+    CoreError::BadRequest(
+        Message::new("notification-template-invalid").with("errors", messages.join(" / ")),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+    use ringiflow_domain::clock::FixedClock;
+    use ringiflow_infra::fake::FakeNotificationTemplateRepository;
+
+    use super::*;
+
+    fn make_sut(
+        template_repo: FakeNotificationTemplateRepository,
+    ) -> NotificationTemplateUseCaseImpl {
+        NotificationTemplateUseCaseImpl::new(
+            Arc::new(template_repo),
+            TemplateRenderer::new().unwrap(),
+            Arc::new(FixedClock::new(Utc::now())),
+            "http://localhost:5173".to_string(),
+        )
+    }
+
+    fn content(
+        subject: &str,
+        html_body: &str,
+        text_body: &str,
+    ) -> NotificationTemplateContentInput {
+        NotificationTemplateContentInput {
+            subject:   subject.to_string(),
+            html_body: html_body.to_string(),
+            text_body: text_body.to_string(),
+        }
+    }
+
+    fn save_input(tenant_id: &TenantId, subject: &str) -> SaveNotificationTemplateInput {
+        SaveNotificationTemplateInput {
+            tenant_id:  tenant_id.clone(),
+            event_type: "approved".to_string(),
+            content:    content(
+                subject,
+                "<p>{{ workflow_title }} が承認されました</p>",
+                "{{ workflow_title }} が承認されました",
+            ),
+            updated_by: Uuid::now_v7(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_save_template_新規保存と上書きで作成日時を維持する() {
+        let tenant_id = TenantId::new();
+        let template_repo = FakeNotificationTemplateRepository::new();
+        let sut = make_sut(template_repo.clone());
+        let created = sut
+            .save_template(save_input(&tenant_id, "承認: {{ workflow_title }}"))
+            .await
+            .unwrap();
+        let sut = NotificationTemplateUseCaseImpl::new(
+            Arc::new(template_repo.clone()),
+            TemplateRenderer::new().unwrap(),
+            Arc::new(FixedClock::new(created.created_at() + Duration::hours(1))),
+            "http://localhost:5173".to_string(),
+        );
+
+        let updated = sut
+            .save_template(save_input(&tenant_id, "【承認完了】{{ workflow_title }}"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            updated.subject().as_str(),
+            "【承認完了】{{ workflow_title }}"
+        );
+        assert_eq!(updated.created_at(), created.created_at());
+        assert_eq!(
+            updated.updated_at(),
+            created.created_at() + Duration::hours(1)
+        );
+        assert_eq!(template_repo.templates(), vec![updated]);
+    }
+
+    #[tokio::test]
+    async fn test_save_template_検証に失敗したテンプレートは保存しない() {
+        let tenant_id = TenantId::new();
+        let template_repo = FakeNotificationTemplateRepository::new();
+        let sut = make_sut(template_repo.clone());
+
+        let result = sut.save_template(save_input(&tenant_id, "{% if %}")).await;
+
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+        assert!(template_repo.templates().is_empty());
+    }
+
+    #[test]
+    fn test_validate_template_部分ごとに検証エラーを返す() {
+        let sut = make_sut(FakeNotificationTemplateRepository::new());
+
+        let errors = sut
+            .validate_template(
+                "approval_request",
+                content("", "<p>{{ applicant_name }}</p>", "{{ approver_name }}"),
+            )
+            .unwrap();
+
+        let fields: Vec<&str> = errors.iter().map(|e| e.field).collect();
+        assert_eq!(fields, vec!["subject", "text_body"]);
+        assert!(
+            sut.validate_template("unknown", content("a", "b", "c"))
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_preview_template_内容の指定がなければ保存済みか組み込みのテンプレートを使う() {
+        let tenant_id = TenantId::new();
+        let sut = make_sut(FakeNotificationTemplateRepository::new());
+
+        let builtin = sut
+            .preview_template(&tenant_id, "approved", None, None)
+            .await
+            .unwrap();
+        sut.save_template(save_input(&tenant_id, "【承認完了】{{ workflow_title }}"))
+            .await
+            .unwrap();
+        let saved = sut
+            .preview_template(&tenant_id, "approved", None, None)
+            .await
+            .unwrap();
+        let draft = sut
+            .preview_template(
+                &tenant_id,
+                "approved",
+                Some(content(
+                    "下書き: {{ workflow_display_id }}",
+                    "<p>下書き</p>",
+                    "下書き",
+                )),
+                None,
+            )
+            .await
+            .unwrap();
+        let english = sut
+            .preview_template(&tenant_id, "rejected", None, Some("en"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            builtin.subject,
+            "[RingiFlow] 承認完了: 経費精算申請 WF-0001"
+        );
+        assert_eq!(saved.subject, "【承認完了】経費精算申請");
+        assert_eq!(draft.subject, "下書き: WF-0001");
+        assert_eq!(
+            english.subject,
+            "[RingiFlow] Rejected: 経費精算申請 WF-0001"
+        );
+        assert!(
+            sut.preview_template(&tenant_id, "approved", None, Some("fr"))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_template_上書きしていなければ404を返す() {
+        let tenant_id = TenantId::new();
+        let sut = make_sut(FakeNotificationTemplateRepository::new());
+        sut.save_template(save_input(&tenant_id, "承認"))
+            .await
+            .unwrap();
+
+        sut.delete_template(&tenant_id, "approved").await.unwrap();
+        let result = sut.delete_template(&tenant_id, "approved").await;
+
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+        assert!(matches!(
+            sut.get_template(&tenant_id, "approved").await,
+            Err(CoreError::NotFound(_))
+        ));
+    }
+}
@@ -6,11 +6,28 @@
 //!
 //! - **`include_str!` によるコンパイル時埋め込み**: テンプレートはバイナリに埋め込まれる
 //! - **件名パターン**: `[RingiFlow] {イベント種別}: {title} {display_id}`
+//!   （ダイジェストは `[RingiFlow] 通知のまとめ（{件数} 件）`）
+//! - **ロケール**: 組み込みテンプレートと件名は受信者のロケールで切り替える。
+//!   英語テンプレートは `en/` 配下に同じ変数で用意する
 //! - **ワークフロー詳細リンク**: `{base_url}/workflows/{display_id}` をテンプレートに渡す
+//! - **テナント独自テンプレート**: 件名・本文とも組み込みテンプレートと同じ変数で tera 構文として
+//!   レンダリングする。保存前の検証・プレビューには通知イベント種別ごとのサンプルデータを使う
 //!
 //! → 詳細設計: `docs/40_詳細設計書/16_通知機能設計.md`
 
-use ringiflow_domain::notification::{EmailMessage, NotificationError, WorkflowNotification};
+use ringiflow_domain::{
+    locale::Locale,
+    notification::{
+        EmailMessage,
+        NotificationDigestItem,
+        NotificationError,
+        NotificationEventType,
+        NotificationTemplate,
+        WorkflowNotification,
+    },
+    user::UserId,
+};
+use serde::Serialize;
 use tera::{Context, Tera};
 
 /// テンプレートレンダラー
@@ -70,6 +87,148 @@ impl TemplateRenderer {
                     "changes_requested.txt",
                     include_str!("../../../templates/notifications/changes_requested.txt"),
                 ),
+                (
+                    "scheduled_workflow_created.html",
+                    include_str!(
+                        "../../../templates/notifications/scheduled_workflow_created.html"
+                    ),
+                ),
+                (
+                    "scheduled_workflow_created.txt",
+                    include_str!("../../../templates/notifications/scheduled_workflow_created.txt"),
+                ),
+                (
+                    "proxy_submitted.html",
+                    include_str!("../../../templates/notifications/proxy_submitted.html"),
+                ),
+                (
+                    "proxy_submitted.txt",
+                    include_str!("../../../templates/notifications/proxy_submitted.txt"),
+                ),
+                (
+                    "approval_retracted.html",
+                    include_str!("../../../templates/notifications/approval_retracted.html"),
+                ),
+                (
+                    "approval_retracted.txt",
+                    include_str!("../../../templates/notifications/approval_retracted.txt"),
+                ),
+                (
+                    "mentioned.html",
+                    include_str!("../../../templates/notifications/mentioned.html"),
+                ),
+                (
+                    "mentioned.txt",
+                    include_str!("../../../templates/notifications/mentioned.txt"),
+                ),
+                (
+                    "cancelled.html",
+                    include_str!("../../../templates/notifications/cancelled.html"),
+                ),
+                (
+                    "cancelled.txt",
+                    include_str!("../../../templates/notifications/cancelled.txt"),
+                ),
+                (
+                    "digest.html",
+                    include_str!("../../../templates/notifications/digest.html"),
+                ),
+                (
+                    "digest.txt",
+                    include_str!("../../../templates/notifications/digest.txt"),
+                ),
+                (
+                    "en/approval_request.html",
+                    include_str!("../../../templates/notifications/en/approval_request.html"),
+                ),
+                (
+                    "en/approval_request.txt",
+                    include_str!("../../../templates/notifications/en/approval_request.txt"),
+                ),
+                (
+                    "en/step_approved.html",
+                    include_str!("../../../templates/notifications/en/step_approved.html"),
+                ),
+                (
+                    "en/step_approved.txt",
+                    include_str!("../../../templates/notifications/en/step_approved.txt"),
+                ),
+                (
+                    "en/approved.html",
+                    include_str!("../../../templates/notifications/en/approved.html"),
+                ),
+                (
+                    "en/approved.txt",
+                    include_str!("../../../templates/notifications/en/approved.txt"),
+                ),
+                (
+                    "en/rejected.html",
+                    include_str!("../../../templates/notifications/en/rejected.html"),
+                ),
+                (
+                    "en/rejected.txt",
+                    include_str!("../../../templates/notifications/en/rejected.txt"),
+                ),
+                (
+                    "en/changes_requested.html",
+                    include_str!("../../../templates/notifications/en/changes_requested.html"),
+                ),
+                (
+                    "en/changes_requested.txt",
+                    include_str!("../../../templates/notifications/en/changes_requested.txt"),
+                ),
+                (
+                    "en/scheduled_workflow_created.html",
+                    include_str!(
+                        "../../../templates/notifications/en/scheduled_workflow_created.html"
+                    ),
+                ),
+                (
+                    "en/scheduled_workflow_created.txt",
+                    include_str!(
+                        "../../../templates/notifications/en/scheduled_workflow_created.txt"
+                    ),
+                ),
+                (
+                    "en/proxy_submitted.html",
+                    include_str!("../../../templates/notifications/en/proxy_submitted.html"),
+                ),
+                (
+                    "en/proxy_submitted.txt",
+                    include_str!("../../../templates/notifications/en/proxy_submitted.txt"),
+                ),
+                (
+                    "en/approval_retracted.html",
+                    include_str!("../../../templates/notifications/en/approval_retracted.html"),
+                ),
+                (
+                    "en/approval_retracted.txt",
+                    include_str!("../../../templates/notifications/en/approval_retracted.txt"),
+                ),
+                (
+                    "en/mentioned.html",
+                    include_str!("../../../templates/notifications/en/mentioned.html"),
+                ),
+                (
+                    "en/mentioned.txt",
+                    include_str!("../../../templates/notifications/en/mentioned.txt"),
+                ),
+                (
+                    "en/cancelled.html",
+                    include_str!("../../../templates/notifications/en/cancelled.html"),
+                ),
+                (
+                    "en/cancelled.txt",
+                    include_str!("../../../templates/notifications/en/cancelled.txt"),
+                ),
+                (
+                    "en/digest.html",
+                    include_str!("../../../templates/notifications/en/digest.html"),
+                ),
+                (
+                    "en/digest.txt",
+                    include_str!("../../../templates/notifications/en/digest.txt"),
+                ),
             ])
             .map_err(|e| NotificationError::TemplateFailed(e.to_string()))?;
 
@@ -81,38 +240,172 @@ impl TemplateRenderer {
     /// # 引数
     ///
     /// - `notification`: ワークフロー通知イベント
+    /// - `locale`: 受信者のロケール
     /// - `base_url`: アプリケーションのベース URL（例: `http://localhost:5173`）
     pub fn render(
         &self,
         notification: &WorkflowNotification,
+        locale: Locale,
         base_url: &str,
     ) -> Result<EmailMessage, NotificationError> {
-        let (template_name, subject, context) = self.build_template_params(notification, base_url);
+        let (template_name, context) = self.build_template_params(notification, locale, base_url);
 
         let html_body = self
             .engine
-            .render(&format!("{template_name}.html"), &context)
+            .render(&template_path(template_name, "html", locale), &context)
             .map_err(|e| NotificationError::TemplateFailed(e.to_string()))?;
 
         let text_body = self
             .engine
-            .render(&format!("{template_name}.txt"), &context)
+            .render(&template_path(template_name, "txt", locale), &context)
             .map_err(|e| NotificationError::TemplateFailed(e.to_string()))?;
 
         Ok(EmailMessage {
             to: notification.recipient_email().to_string(),
-            subject,
+            subject: format!(
+                "[RingiFlow] {}: {} {}",
+                event_label(notification.event_type(), locale),
+                notification.workflow_title(),
+                notification.workflow_display_id()
+            ),
             html_body,
             text_body,
         })
     }
 
-    /// テンプレート名、件名、コンテキストを構築する
+    /// ダイジェスト待ち通知をまとめたメールメッセージを生成する
+    ///
+    /// # 引数
+    ///
+    /// - `recipient_email`: 送信先メールアドレス
+    /// - `items`: 1 通にまとめる通知（古い順）
+    /// - `locale`: 受信者のロケール
+    /// - `base_url`: アプリケーションのベース URL（例: `http://localhost:5173`）
+    pub fn render_digest(
+        &self,
+        recipient_email: &str,
+        items: &[NotificationDigestItem],
+        locale: Locale,
+        base_url: &str,
+    ) -> Result<EmailMessage, NotificationError> {
+        let entries: Vec<DigestEntry> = items
+            .iter()
+            .map(|item| DigestEntry {
+                event_label: event_label(item.event_type(), locale),
+                message: item.message(),
+                workflow_title: item.workflow_title(),
+                workflow_display_id: item.workflow_display_id(),
+                workflow_url: format!("{base_url}/workflows/{}", item.workflow_display_id()),
+            })
+            .collect();
+
+        let mut context = Context::new();
+        context.insert("count", &items.len());
+        context.insert("items", &entries);
+
+        let html_body = self
+            .engine
+            .render(&template_path("digest", "html", locale), &context)
+            .map_err(|e| NotificationError::TemplateFailed(e.to_string()))?;
+
+        let text_body = self
+            .engine
+            .render(&template_path("digest", "txt", locale), &context)
+            .map_err(|e| NotificationError::TemplateFailed(e.to_string()))?;
+
+        Ok(EmailMessage {
+            to: recipient_email.to_string(),
+            subject: match locale {
+                Locale::Ja => format!("[RingiFlow] 通知のまとめ（{} 件）", items.len()),
+                Locale::En => format!("[RingiFlow] Notification digest ({})", items.len()),
+            },
+            html_body,
+            text_body,
+        })
+    }
+
+    /// テナントが上書きしたテンプレートで通知イベントからメールメッセージを生成する
+    ///
+    /// HTML 本文のみ変数を自動エスケープする。件名の改行は空白に置き換える。
+    /// テナント独自テンプレートはロケールごとに持たないため、固定の文言は受信者のロケールによらず
+    /// 同じ内容になる。自動申請の失敗理由など、変数に入る文言は `locale` で展開する。
+    pub fn render_custom(
+        &self,
+        notification: &WorkflowNotification,
+        custom: &CustomTemplate<'_>,
+        locale: Locale,
+        base_url: &str,
+    ) -> Result<EmailMessage, NotificationError> {
+        let (_, context) = self.build_template_params(notification, locale, base_url);
+
+        let render = |part: TemplatePart| {
+            render_part(part, custom, &context)
+                .map_err(|e| NotificationError::TemplateFailed(format!("{}: {}", part.label(), e)))
+        };
+
+        Ok(EmailMessage {
+            to:        notification.recipient_email().to_string(),
+            subject:   render(TemplatePart::Subject)?,
+            html_body: render(TemplatePart::HtmlBody)?,
+            text_body: render(TemplatePart::TextBody)?,
+        })
+    }
+
+    /// テナントが上書きするテンプレートを検証する
+    ///
+    /// 通知イベント種別のサンプルデータでレンダリングし、失敗した部分ごとにエラーを返す。
+    /// 構文エラーに加えて存在しない変数の参照も検出する（実行されない分岐内の参照は検出しない）。
+    pub fn validate_custom(
+        &self,
+        event_type: NotificationEventType,
+        custom: &CustomTemplate<'_>,
+    ) -> Vec<TemplateValidationError> {
+        let (_, context) =
+            self.build_template_params(&sample_notification(event_type), Locale::default(), "");
+
+        [
+            TemplatePart::Subject,
+            TemplatePart::HtmlBody,
+            TemplatePart::TextBody,
+        ]
+        .into_iter()
+        .filter_map(|part| {
+            render_part(part, custom, &context)
+                .err()
+                .map(|message| TemplateValidationError {
+                    field: part.field(),
+                    message,
+                })
+        })
+        .collect()
+    }
+
+    /// 通知イベント種別のサンプルデータでメールのプレビューを生成する
+    ///
+    /// `custom` が `None` の場合は `locale` の組み込みテンプレートでレンダリングする。
+    pub fn preview(
+        &self,
+        event_type: NotificationEventType,
+        custom: Option<&CustomTemplate<'_>>,
+        locale: Locale,
+        base_url: &str,
+    ) -> Result<EmailMessage, NotificationError> {
+        let notification = sample_notification(event_type);
+        match custom {
+            Some(custom) => self.render_custom(&notification, custom, locale, base_url),
+            None => self.render(&notification, locale, base_url),
+        }
+    }
+
+    /// テンプレート名（拡張子なし）とコンテキストを構築する
+    ///
+    /// 変数に入るメッセージ（自動申請の失敗理由）は `locale` で展開する。
     fn build_template_params(
         &self,
         notification: &WorkflowNotification,
+        locale: Locale,
         base_url: &str,
-    ) -> (String, String, Context) {
+    ) -> (&'static str, Context) {
         let workflow_title = notification.workflow_title();
         let workflow_display_id = notification.workflow_display_id();
         let workflow_url = format!("{base_url}/workflows/{workflow_display_id}");
@@ -122,7 +415,7 @@ impl TemplateRenderer {
         context.insert("workflow_display_id", workflow_display_id);
         context.insert("workflow_url", &workflow_url);
 
-        let (template_name, subject) = match notification {
+        let template_name = match notification {
             WorkflowNotification::ApprovalRequest {
                 applicant_name,
                 step_name,
@@ -130,10 +423,7 @@ impl TemplateRenderer {
             } => {
                 context.insert("applicant_name", applicant_name);
                 context.insert("step_name", step_name);
-                (
-                    "approval_request".to_string(),
-                    format!("[RingiFlow] 承認依頼: {workflow_title} {workflow_display_id}"),
-                )
+                "approval_request"
             }
             WorkflowNotification::StepApproved {
                 step_name,
@@ -142,38 +432,291 @@ impl TemplateRenderer {
             } => {
                 context.insert("step_name", step_name);
                 context.insert("approver_name", approver_name);
-                (
-                    "step_approved".to_string(),
-                    format!("[RingiFlow] ステップ承認: {workflow_title} {workflow_display_id}"),
-                )
+                "step_approved"
             }
-            WorkflowNotification::Approved { .. } => (
-                "approved".to_string(),
-                format!("[RingiFlow] 承認完了: {workflow_title} {workflow_display_id}"),
-            ),
+            WorkflowNotification::Approved { .. } => "approved",
             WorkflowNotification::Rejected { comment, .. } => {
                 context.insert("comment", &comment.as_deref().unwrap_or(""));
-                (
-                    "rejected".to_string(),
-                    format!("[RingiFlow] 却下: {workflow_title} {workflow_display_id}"),
-                )
+                "rejected"
             }
             WorkflowNotification::ChangesRequested { comment, .. } => {
                 context.insert("comment", &comment.as_deref().unwrap_or(""));
-                (
-                    "changes_requested".to_string(),
-                    format!("[RingiFlow] 要修正: {workflow_title} {workflow_display_id}"),
-                )
+                "changes_requested"
+            }
+            WorkflowNotification::ScheduledWorkflowCreated {
+                schedule_name,
+                submitted,
+                submit_error,
+                ..
+            } => {
+                context.insert("schedule_name", schedule_name);
+                context.insert("submitted", submitted);
+                context.insert(
+                    "submit_error",
+                    &submit_error
+                        .as_ref()
+                        .map(|e| e.render(locale))
+                        .unwrap_or_default(),
+                );
+                "scheduled_workflow_created"
             }
+            WorkflowNotification::ProxySubmitted { proxy_name, .. } => {
+                context.insert("proxy_name", proxy_name);
+                "proxy_submitted"
+            }
+            WorkflowNotification::ApprovalRetracted {
+                step_name,
+                approver_name,
+                ..
+            } => {
+                context.insert("step_name", step_name);
+                context.insert("approver_name", approver_name);
+                "approval_retracted"
+            }
+            WorkflowNotification::Mentioned {
+                mentioned_by_name,
+                comment,
+                ..
+            } => {
+                context.insert("mentioned_by_name", mentioned_by_name);
+                context.insert("comment", comment);
+                "mentioned"
+            }
+            WorkflowNotification::Cancelled { .. } => "cancelled",
         };
 
-        (template_name, subject, context)
+        (template_name, context)
+    }
+}
+
+/// テナントが上書きするテンプレート（tera 構文）
+#[derive(Debug, Clone, Copy)]
+pub struct CustomTemplate<'a> {
+    pub subject:   &'a str,
+    pub html_body: &'a str,
+    pub text_body: &'a str,
+}
+
+impl<'a> From<&'a NotificationTemplate> for CustomTemplate<'a> {
+    fn from(template: &'a NotificationTemplate) -> Self {
+        Self {
+            subject:   template.subject().as_str(),
+            html_body: template.html_body().as_str(),
+            text_body: template.text_body().as_str(),
+        }
+    }
+}
+
+/// テンプレートの検証エラー（1 部分分）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateValidationError {
+    /// エラーのある部分（`subject` / `html_body` / `text_body`）
+    pub field:   &'static str,
+    pub message: String,
+}
+
+/// テナントが上書きするテンプレートの部分
+#[derive(Debug, Clone, Copy)]
+enum TemplatePart {
+    Subject,
+    HtmlBody,
+    TextBody,
+}
+
+impl TemplatePart {
+    fn field(self) -> &'static str {
+        match self {
+            Self::Subject => "subject",
+            Self::HtmlBody => "html_body",
+            Self::TextBody => "text_body",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Subject => "件名",
+            Self::HtmlBody => "HTML 本文",
+            Self::TextBody => "テキスト本文",
+        }
+    }
+}
+
+/// テンプレートの 1 部分をレンダリングする
+///
+/// 失敗した場合は tera のエラーを原因までつなげたメッセージを返す。
+fn render_part(
+    part: TemplatePart,
+    custom: &CustomTemplate<'_>,
+    context: &Context,
+) -> Result<String, String> {
+    let (source, autoescape) = match part {
+        TemplatePart::Subject => (custom.subject, false),
+        TemplatePart::HtmlBody => (custom.html_body, true),
+        TemplatePart::TextBody => (custom.text_body, false),
+    };
+
+    let rendered = Tera::one_off(source, context, autoescape).map_err(|e| {
+        let mut message = e.to_string();
+        let mut source = std::error::Error::source(&e);
+        while let Some(cause) = source {
+            message.push_str(": ");
+            message.push_str(&cause.to_string());
+            source = cause.source();
+        }
+        message
+    })?;
+
+    Ok(match part {
+        TemplatePart::Subject => rendered.trim().replace(['\r', '\n'], " "),
+        TemplatePart::HtmlBody | TemplatePart::TextBody => rendered,
+    })
+}
+
+/// 検証・プレビュー用のサンプル通知イベントを生成する
+fn sample_notification(event_type: NotificationEventType) -> WorkflowNotification {
+    let workflow_title = "経費精算申請".to_string();
+    let workflow_display_id = "WF-0001".to_string();
+    let recipient_email = "recipient@example.com".to_string();
+    let recipient_user_id = UserId::new();
+
+    match event_type {
+        NotificationEventType::ApprovalRequest => WorkflowNotification::ApprovalRequest {
+            workflow_title,
+            workflow_display_id,
+            applicant_name: "山田太郎".to_string(),
+            step_name: "上長承認".to_string(),
+            approver_email: recipient_email,
+            approver_user_id: recipient_user_id,
+        },
+        NotificationEventType::StepApproved => WorkflowNotification::StepApproved {
+            workflow_title,
+            workflow_display_id,
+            step_name: "上長承認".to_string(),
+            approver_name: "鈴木一郎".to_string(),
+            recipient_email,
+            recipient_user_id,
+        },
+        NotificationEventType::Approved => WorkflowNotification::Approved {
+            workflow_title,
+            workflow_display_id,
+            recipient_email,
+            recipient_user_id,
+        },
+        NotificationEventType::Rejected => WorkflowNotification::Rejected {
+            workflow_title,
+            workflow_display_id,
+            comment: Some("予算超過のため却下します".to_string()),
+            recipient_email,
+            recipient_user_id,
+        },
+        NotificationEventType::ChangesRequested => WorkflowNotification::ChangesRequested {
+            workflow_title,
+            workflow_display_id,
+            comment: Some("領収書を添付してください".to_string()),
+            recipient_email,
+            recipient_user_id,
+        },
+        NotificationEventType::ScheduledWorkflowCreated => {
+            WorkflowNotification::ScheduledWorkflowCreated {
+                workflow_title,
+                workflow_display_id,
+                schedule_name: "月次経費精算".to_string(),
+                submitted: true,
+                submit_error: None,
+                recipient_email,
+                recipient_user_id,
+            }
+        }
+        NotificationEventType::ProxySubmitted => WorkflowNotification::ProxySubmitted {
+            workflow_title,
+            workflow_display_id,
+            proxy_name: "佐藤花子".to_string(),
+            recipient_email,
+            recipient_user_id,
+        },
+        NotificationEventType::ApprovalRetracted => WorkflowNotification::ApprovalRetracted {
+            workflow_title,
+            workflow_display_id,
+            step_name: "上長承認".to_string(),
+            approver_name: "鈴木一郎".to_string(),
+            recipient_email,
+            recipient_user_id,
+        },
+        NotificationEventType::Mentioned => WorkflowNotification::Mentioned {
+            workflow_title,
+            workflow_display_id,
+            mentioned_by_name: "鈴木一郎".to_string(),
+            comment: "@山田太郎 ご確認をお願いします".to_string(),
+            recipient_email,
+            recipient_user_id,
+        },
+        NotificationEventType::Cancelled => WorkflowNotification::Cancelled {
+            workflow_title,
+            workflow_display_id,
+            recipient_email,
+            recipient_user_id,
+        },
+    }
+}
+
+/// ダイジェストメールの 1 行分のテンプレートコンテキスト
+#[derive(Serialize)]
+struct DigestEntry<'a> {
+    event_label: &'static str,
+    message: &'a str,
+    workflow_title: &'a str,
+    workflow_display_id: &'a str,
+    workflow_url: String,
+}
+
+/// 組み込みテンプレートの登録名を返す（英語は `en/` 配下）
+fn template_path(name: &str, extension: &str, locale: Locale) -> String {
+    match locale {
+        Locale::Ja => format!("{name}.{extension}"),
+        Locale::En => format!("en/{name}.{extension}"),
+    }
+}
+
+/// 通知イベント種別のラベル（個別メールの件名とダイジェストメールで共通の表記）
+fn event_label(event_type: NotificationEventType, locale: Locale) -> &'static str {
+    match locale {
+        Locale::Ja => match event_type {
+            NotificationEventType::ApprovalRequest => "承認依頼",
+            NotificationEventType::StepApproved => "ステップ承認",
+            NotificationEventType::Approved => "承認完了",
+            NotificationEventType::Rejected => "却下",
+            NotificationEventType::ChangesRequested => "要修正",
+            NotificationEventType::ScheduledWorkflowCreated => "定期作成",
+            NotificationEventType::ProxySubmitted => "代理申請",
+            NotificationEventType::ApprovalRetracted => "承認取り消し",
+            NotificationEventType::Mentioned => "メンション",
+            NotificationEventType::Cancelled => "取消",
+        },
+        Locale::En => match event_type {
+            NotificationEventType::ApprovalRequest => "Approval request",
+            NotificationEventType::StepApproved => "Step approved",
+            NotificationEventType::Approved => "Approved",
+            NotificationEventType::Rejected => "Rejected",
+            NotificationEventType::ChangesRequested => "Changes requested",
+            NotificationEventType::ScheduledWorkflowCreated => "Scheduled request created",
+            NotificationEventType::ProxySubmitted => "Submitted on your behalf",
+            NotificationEventType::ApprovalRetracted => "Approval retracted",
+            NotificationEventType::Mentioned => "Mentioned",
+            NotificationEventType::Cancelled => "Cancelled",
+        },
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use ringiflow_domain::user::UserId;
+    use chrono::Utc;
+    use ringiflow_domain::{
+        message::Message,
+        notification::NotificationDigestItemId,
+        tenant::TenantId,
+        user::UserId,
+        workflow::WorkflowInstanceId,
+    };
 
     use super::*;
 
@@ -199,7 +742,9 @@ mod tests {
             approver_user_id:    UserId::new(),
         };
 
-        let email = renderer.render(&notification, make_base_url()).unwrap();
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
 
         assert_eq!(email.to, "suzuki@example.com");
         assert_eq!(email.subject, "[RingiFlow] 承認依頼: 経費精算申請 WF-0042");
@@ -214,6 +759,32 @@ mod tests {
         assert!(email.text_body.contains("上長承認"));
     }
 
+    #[test]
+    fn 英語ロケールでは英語のテンプレートと件名でレンダリングする() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let notification = WorkflowNotification::ApprovalRequest {
+            workflow_title:      "Travel expenses".to_string(),
+            workflow_display_id: "WF-0042".to_string(),
+            applicant_name:      "John Smith".to_string(),
+            step_name:           "Manager approval".to_string(),
+            approver_email:      "suzuki@example.com".to_string(),
+            approver_user_id:    UserId::new(),
+        };
+
+        let email = renderer
+            .render(&notification, Locale::En, make_base_url())
+            .unwrap();
+
+        assert_eq!(
+            email.subject,
+            "[RingiFlow] Approval request: Travel expenses WF-0042"
+        );
+        assert!(email.html_body.contains("John Smith"));
+        assert!(email.text_body.contains("You have a new approval request."));
+        assert!(email.text_body.contains("Approval step: Manager approval"));
+        assert!(!email.text_body.contains("承認"));
+    }
+
     #[test]
     fn step_approvedのレンダリングが正しい() {
         let renderer = TemplateRenderer::new().unwrap();
@@ -222,11 +793,13 @@ mod tests {
             workflow_display_id: "WF-0042".to_string(),
             step_name:           "上長承認".to_string(),
             approver_name:       "鈴木一郎".to_string(),
-            applicant_email:     "tanaka@example.com".to_string(),
-            applicant_user_id:   UserId::new(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
         };
 
-        let email = renderer.render(&notification, make_base_url()).unwrap();
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
 
         assert_eq!(email.to, "tanaka@example.com");
         assert_eq!(
@@ -243,11 +816,13 @@ mod tests {
         let notification = WorkflowNotification::Approved {
             workflow_title:      "経費精算申請".to_string(),
             workflow_display_id: "WF-0042".to_string(),
-            applicant_email:     "tanaka@example.com".to_string(),
-            applicant_user_id:   UserId::new(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
         };
 
-        let email = renderer.render(&notification, make_base_url()).unwrap();
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
 
         assert_eq!(email.to, "tanaka@example.com");
         assert_eq!(email.subject, "[RingiFlow] 承認完了: 経費精算申請 WF-0042");
@@ -255,6 +830,26 @@ mod tests {
         assert!(email.html_body.contains("WF-0042"));
     }
 
+    #[test]
+    fn cancelledのレンダリングが正しい() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let notification = WorkflowNotification::Cancelled {
+            workflow_title:      "経費精算申請".to_string(),
+            workflow_display_id: "WF-0042".to_string(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
+        };
+
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
+
+        assert_eq!(email.to, "tanaka@example.com");
+        assert_eq!(email.subject, "[RingiFlow] 取消: 経費精算申請 WF-0042");
+        assert!(email.html_body.contains("取り消されました"));
+        assert!(email.text_body.contains("WF-0042"));
+    }
+
     #[test]
     fn rejectedのレンダリングでcommentありの場合が正しい() {
         let renderer = TemplateRenderer::new().unwrap();
@@ -262,11 +857,13 @@ mod tests {
             workflow_title: "経費精算申請".to_string(),
             workflow_display_id: "WF-0042".to_string(),
             comment: Some("領収書が添付されていません".to_string()),
-            applicant_email: "tanaka@example.com".to_string(),
-            applicant_user_id: UserId::new(),
+            recipient_email: "tanaka@example.com".to_string(),
+            recipient_user_id: UserId::new(),
         };
 
-        let email = renderer.render(&notification, make_base_url()).unwrap();
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
 
         assert_eq!(email.subject, "[RingiFlow] 却下: 経費精算申請 WF-0042");
         assert!(email.html_body.contains("領収書が添付されていません"));
@@ -280,11 +877,13 @@ mod tests {
             workflow_title: "経費精算申請".to_string(),
             workflow_display_id: "WF-0042".to_string(),
             comment: None,
-            applicant_email: "tanaka@example.com".to_string(),
-            applicant_user_id: UserId::new(),
+            recipient_email: "tanaka@example.com".to_string(),
+            recipient_user_id: UserId::new(),
         };
 
-        let email = renderer.render(&notification, make_base_url()).unwrap();
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
 
         assert_eq!(email.subject, "[RingiFlow] 却下: 経費精算申請 WF-0042");
         // comment なしの場合、コメント行が表示されないことを確認
@@ -298,11 +897,13 @@ mod tests {
             workflow_title: "経費精算申請".to_string(),
             workflow_display_id: "WF-0042".to_string(),
             comment: Some("金額の内訳を追記してください".to_string()),
-            applicant_email: "tanaka@example.com".to_string(),
-            applicant_user_id: UserId::new(),
+            recipient_email: "tanaka@example.com".to_string(),
+            recipient_user_id: UserId::new(),
         };
 
-        let email = renderer.render(&notification, make_base_url()).unwrap();
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
 
         assert_eq!(email.subject, "[RingiFlow] 要修正: 経費精算申請 WF-0042");
         assert!(email.html_body.contains("金額の内訳を追記してください"));
@@ -316,11 +917,13 @@ mod tests {
             workflow_title: "経費精算申請".to_string(),
             workflow_display_id: "WF-0042".to_string(),
             comment: None,
-            applicant_email: "tanaka@example.com".to_string(),
-            applicant_user_id: UserId::new(),
+            recipient_email: "tanaka@example.com".to_string(),
+            recipient_user_id: UserId::new(),
         };
 
-        let email = renderer.render(&notification, make_base_url()).unwrap();
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
 
         assert!(!email.html_body.contains("コメント:"));
     }
@@ -331,11 +934,13 @@ mod tests {
         let notification = WorkflowNotification::Approved {
             workflow_title:      "経費精算申請".to_string(),
             workflow_display_id: "WF-0042".to_string(),
-            applicant_email:     "tanaka@example.com".to_string(),
-            applicant_user_id:   UserId::new(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
         };
 
-        let email = renderer.render(&notification, make_base_url()).unwrap();
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
 
         assert!(
             email
@@ -343,4 +948,349 @@ mod tests {
                 .contains("http://localhost:5173/workflows/WF-0042")
         );
     }
+
+    #[test]
+    fn scheduled_workflow_createdのレンダリングで自動申請した場合が正しい() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let notification = WorkflowNotification::ScheduledWorkflowCreated {
+            workflow_title:      "サブスクリプション更新".to_string(),
+            workflow_display_id: "WF-0043".to_string(),
+            schedule_name:       "月次サブスクリプション更新".to_string(),
+            submitted:           true,
+            submit_error:        None,
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
+        };
+
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
+
+        assert_eq!(email.to, "tanaka@example.com");
+        assert_eq!(
+            email.subject,
+            "[RingiFlow] 定期作成: サブスクリプション更新 WF-0043"
+        );
+        assert!(email.html_body.contains("月次サブスクリプション更新"));
+        assert!(email.html_body.contains("申請しました"));
+        assert!(!email.text_body.contains("自動申請に失敗"));
+    }
+
+    #[test]
+    fn scheduled_workflow_createdのレンダリングで自動申請に失敗した場合が正しい() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let notification = WorkflowNotification::ScheduledWorkflowCreated {
+            workflow_title:      "サブスクリプション更新".to_string(),
+            workflow_display_id: "WF-0043".to_string(),
+            schedule_name:       "月次サブスクリプション更新".to_string(),
+            submitted:           false,
+            submit_error:        Some(
+                Message::new("approver-count-mismatch")
+                    .with("count", 1)
+                    .with("expected", 2),
+            ),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
+        };
+
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
+
+        assert!(email.html_body.contains("下書きとして作成しました"));
+        assert!(email.html_body.contains("承認者の数(1)"));
+        assert!(email.text_body.contains("自動申請に失敗"));
+    }
+
+    #[test]
+    fn scheduled_workflow_createdの自動申請の失敗理由は受信者のロケールで展開される() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let notification = WorkflowNotification::ScheduledWorkflowCreated {
+            workflow_title:      "Subscription renewal".to_string(),
+            workflow_display_id: "WF-0043".to_string(),
+            schedule_name:       "Monthly renewal".to_string(),
+            submitted:           false,
+            submit_error:        Some(
+                Message::new("approver-count-mismatch")
+                    .with("count", 1)
+                    .with("expected", 2),
+            ),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
+        };
+
+        let email = renderer
+            .render(&notification, Locale::En, make_base_url())
+            .unwrap();
+
+        assert!(email.text_body.contains(
+            "The number of approvers (1) does not match the number of steps in the definition (2)."
+        ));
+        assert!(!email.text_body.contains("承認者の数"));
+    }
+
+    #[test]
+    fn proxy_submittedのレンダリングが正しい() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let notification = WorkflowNotification::ProxySubmitted {
+            workflow_title:      "役員出張申請".to_string(),
+            workflow_display_id: "WF-0044".to_string(),
+            proxy_name:          "佐藤花子".to_string(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
+        };
+
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
+
+        assert_eq!(email.to, "tanaka@example.com");
+        assert_eq!(email.subject, "[RingiFlow] 代理申請: 役員出張申請 WF-0044");
+        assert!(email.html_body.contains("佐藤花子 さんがあなたの代理で"));
+        assert!(email.text_body.contains("代理人: 佐藤花子"));
+        assert!(
+            email
+                .text_body
+                .contains("http://localhost:5173/workflows/WF-0044")
+        );
+    }
+
+    #[test]
+    fn approval_retractedのレンダリングが正しい() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let notification = WorkflowNotification::ApprovalRetracted {
+            workflow_title:      "経費精算申請".to_string(),
+            workflow_display_id: "WF-0042".to_string(),
+            step_name:           "上長承認".to_string(),
+            approver_name:       "鈴木一郎".to_string(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
+        };
+
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
+
+        assert_eq!(email.to, "tanaka@example.com");
+        assert_eq!(
+            email.subject,
+            "[RingiFlow] 承認取り消し: 経費精算申請 WF-0042"
+        );
+        assert!(email.html_body.contains("承認が取り消されました"));
+        assert!(email.html_body.contains("鈴木一郎"));
+        assert!(email.text_body.contains("承認ステップ: 上長承認"));
+    }
+
+    #[test]
+    fn mentionedのレンダリングが正しい() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let notification = WorkflowNotification::Mentioned {
+            workflow_title: "経費精算申請".to_string(),
+            workflow_display_id: "WF-0042".to_string(),
+            mentioned_by_name: "鈴木一郎".to_string(),
+            comment: "@USER-5 <b>金額</b>の確認をお願いします".to_string(),
+            recipient_email: "sato@example.com".to_string(),
+            recipient_user_id: UserId::new(),
+        };
+
+        let email = renderer
+            .render(&notification, Locale::Ja, make_base_url())
+            .unwrap();
+
+        assert_eq!(email.to, "sato@example.com");
+        assert_eq!(
+            email.subject,
+            "[RingiFlow] メンション: 経費精算申請 WF-0042"
+        );
+        assert!(
+            email
+                .html_body
+                .contains("鈴木一郎 さんがコメントであなたをメンションしました")
+        );
+        // コメント本文は HTML エスケープされる
+        assert!(email.html_body.contains("&lt;b&gt;金額&lt;&#x2F;b&gt;"));
+        assert!(
+            email
+                .text_body
+                .contains("コメント: @USER-5 <b>金額</b>の確認をお願いします")
+        );
+    }
+
+    #[test]
+    fn digestのレンダリングで通知をまとめて件数を件名に含める() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let recipient_user_id = UserId::new();
+        let items: Vec<_> = [
+            WorkflowNotification::ApprovalRequest {
+                workflow_title:      "経費精算申請".to_string(),
+                workflow_display_id: "WF-0042".to_string(),
+                applicant_name:      "田中太郎".to_string(),
+                step_name:           "上長承認".to_string(),
+                approver_email:      "suzuki@example.com".to_string(),
+                approver_user_id:    recipient_user_id.clone(),
+            },
+            WorkflowNotification::Approved {
+                workflow_title:      "<出張>申請".to_string(),
+                workflow_display_id: "WF-0043".to_string(),
+                recipient_email:     "suzuki@example.com".to_string(),
+                recipient_user_id:   recipient_user_id.clone(),
+            },
+        ]
+        .iter()
+        .map(|notification| {
+            NotificationDigestItem::from_notification(
+                NotificationDigestItemId::new(),
+                TenantId::new(),
+                WorkflowInstanceId::new(),
+                notification,
+                Locale::Ja,
+                Utc::now(),
+            )
+        })
+        .collect();
+
+        let email = renderer
+            .render_digest("suzuki@example.com", &items, Locale::Ja, make_base_url())
+            .unwrap();
+
+        assert_eq!(email.to, "suzuki@example.com");
+        assert_eq!(email.subject, "[RingiFlow] 通知のまとめ（2 件）");
+        assert!(email.html_body.contains("2 件の通知"));
+        assert!(email.html_body.contains("承認依頼"));
+        assert!(
+            email
+                .html_body
+                .contains("田中太郎 さんから「上長承認」の承認依頼が届きました")
+        );
+        assert!(
+            email
+                .html_body
+                .contains("http://localhost:5173/workflows/WF-0043")
+        );
+        // ワークフロータイトルは HTML エスケープされる
+        assert!(email.html_body.contains("&lt;出張&gt;申請"));
+        assert!(email.text_body.contains("[承認完了]"));
+        assert!(email.text_body.contains("<出張>申請（WF-0043）"));
+    }
+
+    #[test]
+    fn 英語ロケールのdigestは英語の件名とラベルでレンダリングする() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let notification = WorkflowNotification::Approved {
+            workflow_title:      "Travel expenses".to_string(),
+            workflow_display_id: "WF-0043".to_string(),
+            recipient_email:     "suzuki@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
+        };
+        let items = vec![NotificationDigestItem::from_notification(
+            NotificationDigestItemId::new(),
+            TenantId::new(),
+            WorkflowInstanceId::new(),
+            &notification,
+            Locale::En,
+            Utc::now(),
+        )];
+
+        let email = renderer
+            .render_digest("suzuki@example.com", &items, Locale::En, make_base_url())
+            .unwrap();
+
+        assert_eq!(email.subject, "[RingiFlow] Notification digest (1)");
+        assert!(email.text_body.contains("You have 1 new notification(s)"));
+        assert!(
+            email
+                .text_body
+                .contains("[Approved] Your request has been approved")
+        );
+    }
+
+    #[test]
+    fn テナント独自テンプレートで件名と本文をレンダリングする() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let notification = WorkflowNotification::Rejected {
+            workflow_title: "<出張>申請".to_string(),
+            workflow_display_id: "WF-0042".to_string(),
+            comment: Some("予算超過".to_string()),
+            recipient_email: "tanaka@example.com".to_string(),
+            recipient_user_id: UserId::new(),
+        };
+        let custom = CustomTemplate {
+            subject:   "【却下】{{ workflow_title }}\n{{ workflow_display_id }}",
+            html_body: "<img src=\"https://example.com/logo.png\"><p>{{ workflow_title }}: {{ comment }}</p><a href=\"{{ workflow_url }}\">開く</a>",
+            text_body: "{{ workflow_title }}: {{ comment }}",
+        };
+
+        let email = renderer
+            .render_custom(&notification, &custom, Locale::Ja, make_base_url())
+            .unwrap();
+
+        assert_eq!(email.to, "tanaka@example.com");
+        assert_eq!(email.subject, "【却下】<出張>申請 WF-0042");
+        assert!(
+            email
+                .html_body
+                .starts_with("<img src=\"https://example.com/logo.png\">")
+        );
+        assert!(email.html_body.contains("&lt;出張&gt;申請: 予算超過"));
+        assert_eq!(email.text_body, "<出張>申請: 予算超過");
+    }
+
+    #[test]
+    fn テナント独自テンプレートの検証で不正な部分ごとにエラーを返す() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let custom = CustomTemplate {
+            subject:   "承認依頼: {{ workflow_title }}",
+            html_body: "<p>{{ workflow_title </p>",
+            text_body: "{{ unknown_variable }}",
+        };
+
+        let errors = renderer.validate_custom(NotificationEventType::ApprovalRequest, &custom);
+
+        let fields: Vec<&str> = errors.iter().map(|e| e.field).collect();
+        assert_eq!(fields, vec!["html_body", "text_body"]);
+        assert!(errors[1].message.contains("unknown_variable"));
+    }
+
+    #[test]
+    fn テナント独自テンプレートの検証で通知イベント種別ごとの変数を使える() {
+        let renderer = TemplateRenderer::new().unwrap();
+        let custom = CustomTemplate {
+            subject:   "{{ applicant_name }}: {{ step_name }}",
+            html_body: "<p>{{ applicant_name }}</p>",
+            text_body: "{{ workflow_url }}",
+        };
+
+        assert!(
+            renderer
+                .validate_custom(NotificationEventType::ApprovalRequest, &custom)
+                .is_empty()
+        );
+        // 承認完了には申請者名の変数がない
+        assert_eq!(
+            renderer
+                .validate_custom(NotificationEventType::Approved, &custom)
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn 全通知イベント種別と全ロケールで組み込みテンプレートのプレビューを生成できる() {
+        let renderer = TemplateRenderer::new().unwrap();
+
+        for &locale in Locale::all() {
+            for &event_type in NotificationEventType::all() {
+                let email = renderer
+                    .preview(event_type, None, locale, make_base_url())
+                    .unwrap();
+
+                assert!(email.subject.contains("経費精算申請 WF-0001"));
+                assert!(
+                    email
+                        .html_body
+                        .contains("http://localhost:5173/workflows/WF-0001")
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,115 @@
+//! # ポーリングワーカー
+//!
+//! バックグラウンドワーカー共通のポーリングループを提供する。
+//!
+//! 各ワーカーは [`PollingWorker`] を実装して 1 バッチ分の処理だけを定義し、
+//! 起動は [`spawn_polling_worker`] に任せる。
+//! ポーリング間隔ごとにバッチを処理し、バッチが上限まで埋まっている間は
+//! 待たずに次のバッチを処理する。
+
+use std::{future::Future, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+
+use crate::error::CoreError;
+
+/// ポーリングで 1 バッチずつ処理するワーカー
+#[async_trait]
+pub trait PollingWorker: Send + Sync + 'static {
+    /// ログに出力するワーカー名
+    fn name(&self) -> &'static str;
+
+    /// 1 バッチで処理する上限件数
+    fn batch_size(&self) -> i64;
+
+    /// 1 バッチ分を処理し、処理した件数を返す
+    async fn step(&self) -> Result<usize, CoreError>;
+}
+
+/// ポーリングループを実行する（終了しない）
+///
+/// `step` が返した件数が `batch_size` 以上の間は待たずに次のバッチを処理する。
+/// `step` が失敗した場合はログに記録し、次のポーリングまで待つ。
+pub async fn poll_loop<F, Fut>(name: &str, poll_interval: Duration, batch_size: i64, mut step: F)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<usize, CoreError>>,
+{
+    let mut interval = tokio::time::interval(poll_interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        interval.tick().await;
+        loop {
+            match step().await {
+                Ok(count) if count as i64 >= batch_size => continue,
+                Ok(_) => break,
+                Err(e) => {
+                    tracing::error!(error = %e, worker = name, "バックグラウンド処理に失敗");
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// ワーカーのポーリングループをバックグラウンドタスクとして起動する
+pub fn spawn_polling_worker<W: PollingWorker>(worker: W, poll_interval: Duration) {
+    let worker = Arc::new(worker);
+    let name = worker.name();
+    tokio::spawn(async move {
+        poll_loop(name, poll_interval, worker.batch_size(), || {
+            let worker = worker.clone();
+            async move { worker.step().await }
+        })
+        .await;
+    });
+    tracing::info!(
+        worker = name,
+        poll_interval_ms = poll_interval.as_millis() as u64,
+        "バックグラウンドワーカーを起動しました"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// 呼び出しごとに `results` の先頭を返し、尽きたら 0 件を返す
+    async fn run_steps(results: Vec<Result<usize, CoreError>>) -> usize {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let results = Arc::new(Mutex::new(results.into_iter()));
+        let counter = calls.clone();
+        let handle = tokio::spawn(async move {
+            poll_loop("test", Duration::from_secs(3600), 2, move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let result = results.lock().unwrap().next().unwrap_or(Ok(0));
+                async move { result }
+            })
+            .await;
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handle.abort();
+        calls.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn test_バッチが埋まっている間は待たずに次のバッチを処理する() {
+        let calls = run_steps(vec![Ok(2), Ok(2), Ok(1)]).await;
+
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn test_失敗したら次のポーリングまで待つ() {
+        let calls = run_steps(vec![Ok(2), Err(CoreError::Internal("失敗".to_string()))]).await;
+
+        assert_eq!(calls, 2);
+    }
+}
@@ -0,0 +1,271 @@
+//! # 代理権限ユースケース
+//!
+//! 本人が、自分に代わってワークフローを作成・申請できるユーザー（代理人）を
+//! 付与・取り消しする。役員の稟議を秘書が起票する、といった運用を想定する。
+//!
+//! 代理権限を使った作成は [`WorkflowUseCaseImpl::create_workflow`] が担当する。
+//! 取り消し後も、代理人は取り消し前に作成したワークフローを引き続き操作できる。
+//!
+//! [`WorkflowUseCaseImpl::create_workflow`]: crate::usecase::WorkflowUseCaseImpl::create_workflow
+
+use std::{collections::HashMap, sync::Arc};
+
+use ringiflow_domain::{
+    clock::Clock,
+    message::Message,
+    tenant::TenantId,
+    user::UserId,
+    workflow::ProxyGrant,
+};
+use ringiflow_infra::repository::{UserRepository, WorkflowProxyGrantRepository};
+use ringiflow_shared::{event_log::event, log_business_event};
+
+use crate::error::CoreError;
+
+/// 自分に関係する代理権限の一覧
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProxyGrants {
+    /// 自分が付与した代理権限（自分が本人）
+    pub granted:  Vec<ProxyGrant>,
+    /// 自分が付与されている代理権限（自分が代理人）
+    pub received: Vec<ProxyGrant>,
+}
+
+/// 代理権限ユースケース
+pub struct ProxyGrantUseCaseImpl {
+    proxy_grant_repo: Arc<dyn WorkflowProxyGrantRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ProxyGrantUseCaseImpl {
+    pub fn new(
+        proxy_grant_repo: Arc<dyn WorkflowProxyGrantRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            proxy_grant_repo,
+            user_repo,
+            clock,
+        }
+    }
+
+    /// ユーザー ID のリストからユーザー名を一括解決する
+    pub async fn resolve_user_names(
+        &self,
+        user_ids: &[UserId],
+    ) -> Result<HashMap<UserId, String>, CoreError> {
+        crate::usecase::resolve_user_names(self.user_repo.as_ref(), user_ids).await
+    }
+
+    /// 自分が付与した代理権限と、自分が付与されている代理権限を取得する
+    pub async fn list_grants(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<ProxyGrants, CoreError> {
+        let granted = self
+            .proxy_grant_repo
+            .find_by_principal(user_id, tenant_id)
+            .await?;
+        let received = self
+            .proxy_grant_repo
+            .find_by_proxy(user_id, tenant_id)
+            .await?;
+        Ok(ProxyGrants { granted, received })
+    }
+
+    /// 代理人に代理権限を付与する（付与済みの場合は何もしない）
+    ///
+    /// ## エラー
+    ///
+    /// - 自分自身を代理人に指定した場合: 400
+    /// - 代理人が同じテナントの有効なユーザーでない場合: 400
+    pub async fn grant(
+        &self,
+        tenant_id: &TenantId,
+        principal_id: &UserId,
+        proxy_id: &UserId,
+    ) -> Result<ProxyGrant, CoreError> {
+        if principal_id == proxy_id {
+            return Err(CoreError::BadRequest(Message::new("proxy-self")));
+        }
+
+        let proxy = self.user_repo.find_by_id(proxy_id).await?;
+        if !proxy.is_some_and(|u| u.tenant_id() == tenant_id && u.is_active()) {
+            return Err(CoreError::BadRequest(Message::new("proxy-invalid-user")));
+        }
+
+        let grant = ProxyGrant::new(principal_id.clone(), proxy_id.clone(), self.clock.now());
+        self.proxy_grant_repo.insert(&grant, tenant_id).await?;
+
+        log_business_event!(
+            event.category = event::category::WORKFLOW,
+            event.action = event::action::PROXY_GRANTED,
+            event.entity_type = event::entity_type::USER,
+            event.entity_id = %proxy_id,
+            event.actor_id = %principal_id,
+            event.tenant_id = %tenant_id,
+            event.result = event::result::SUCCESS,
+            "代理権限付与"
+        );
+
+        Ok(grant)
+    }
+
+    /// 代理人の代理権限を取り消す（未付与の場合は何もしない）
+    pub async fn revoke(
+        &self,
+        tenant_id: &TenantId,
+        principal_id: &UserId,
+        proxy_id: &UserId,
+    ) -> Result<(), CoreError> {
+        self.proxy_grant_repo
+            .delete(principal_id, proxy_id, tenant_id)
+            .await?;
+
+        log_business_event!(
+            event.category = event::category::WORKFLOW,
+            event.action = event::action::PROXY_REVOKED,
+            event.entity_type = event::entity_type::USER,
+            event.entity_id = %proxy_id,
+            event.actor_id = %principal_id,
+            event.tenant_id = %tenant_id,
+            event.result = event::result::SUCCESS,
+            "代理権限取り消し"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ringiflow_domain::{
+        clock::FixedClock,
+        user::{Email, User, UserStatus},
+        value_objects::{DisplayNumber, UserName},
+    };
+    use ringiflow_infra::fake::{FakeUserRepository, FakeWorkflowProxyGrantRepository};
+
+    use super::*;
+
+    fn make_sut(user_repo: &FakeUserRepository) -> ProxyGrantUseCaseImpl {
+        ProxyGrantUseCaseImpl::new(
+            Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            Arc::new(user_repo.clone()),
+            Arc::new(FixedClock::new(chrono::Utc::now())),
+        )
+    }
+
+    fn add_user(
+        user_repo: &FakeUserRepository,
+        tenant_id: &TenantId,
+        status: UserStatus,
+    ) -> UserId {
+        let now = chrono::Utc::now();
+        let user = User::new(
+            UserId::new(),
+            tenant_id.clone(),
+            DisplayNumber::new(1).unwrap(),
+            Email::new("secretary@example.com").unwrap(),
+            UserName::new("秘書").unwrap(),
+            now,
+        )
+        .with_status(status, now);
+        let user_id = user.id().clone();
+        user_repo.add_user(user);
+        user_id
+    }
+
+    #[tokio::test]
+    async fn test_grant_付与した代理権限が本人と代理人の一覧に含まれる() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let principal_id = UserId::new();
+        let user_repo = FakeUserRepository::new();
+        let sut = make_sut(&user_repo);
+        let proxy_id = add_user(&user_repo, &tenant_id, UserStatus::Active);
+
+        // Act
+        let grant = sut
+            .grant(&tenant_id, &principal_id, &proxy_id)
+            .await
+            .unwrap();
+
+        // Assert
+        let principal_view = sut.list_grants(&tenant_id, &principal_id).await.unwrap();
+        assert_eq!(
+            principal_view,
+            ProxyGrants {
+                granted:  vec![grant.clone()],
+                received: vec![],
+            }
+        );
+        let proxy_view = sut.list_grants(&tenant_id, &proxy_id).await.unwrap();
+        assert_eq!(
+            proxy_view,
+            ProxyGrants {
+                granted:  vec![],
+                received: vec![grant],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_grant_自分自身は代理人に指定できない() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let principal_id = UserId::new();
+        let user_repo = FakeUserRepository::new();
+        let sut = make_sut(&user_repo);
+
+        // Act
+        let result = sut.grant(&tenant_id, &principal_id, &principal_id).await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_grant_無効なユーザーや他テナントのユーザーは代理人に指定できない() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let principal_id = UserId::new();
+        let user_repo = FakeUserRepository::new();
+        let sut = make_sut(&user_repo);
+        let inactive_id = add_user(&user_repo, &tenant_id, UserStatus::Inactive);
+        let other_tenant_id = add_user(&user_repo, &TenantId::new(), UserStatus::Active);
+
+        for proxy_id in [inactive_id, other_tenant_id, UserId::new()] {
+            // Act
+            let result = sut.grant(&tenant_id, &principal_id, &proxy_id).await;
+
+            // Assert
+            assert!(matches!(result, Err(CoreError::BadRequest(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_revoke_取り消した代理権限は一覧から消える() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let principal_id = UserId::new();
+        let user_repo = FakeUserRepository::new();
+        let sut = make_sut(&user_repo);
+        let proxy_id = add_user(&user_repo, &tenant_id, UserStatus::Active);
+        sut.grant(&tenant_id, &principal_id, &proxy_id)
+            .await
+            .unwrap();
+
+        // Act
+        sut.revoke(&tenant_id, &principal_id, &proxy_id)
+            .await
+            .unwrap();
+
+        // Assert
+        let grants = sut.list_grants(&tenant_id, &principal_id).await.unwrap();
+        assert!(grants.granted.is_empty());
+    }
+}
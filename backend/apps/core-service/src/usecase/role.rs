@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 use ringiflow_domain::{
     clock::Clock,
+    message::Message,
     role::{Permission, Role, RoleId},
     tenant::TenantId,
 };
@@ -49,9 +50,7 @@ impl RoleUseCaseImpl {
     pub async fn create_role(&self, input: CreateRoleInput) -> Result<Role, CoreError> {
         // 権限が空でないことを検証
         if input.permissions.is_empty() {
-            return Err(CoreError::BadRequest(
-                "権限を1つ以上指定してください".to_string(),
-            ));
+            return Err(CoreError::BadRequest(Message::new("permissions-required")));
         }
 
         let now = self.clock.now();
@@ -73,7 +72,7 @@ impl RoleUseCaseImpl {
                 && let Some(constraint) = db_err.as_database_error().and_then(|d| d.constraint())
                 && constraint == "roles_tenant_name_key"
             {
-                return CoreError::Conflict("同名のロールが既に存在します".to_string());
+                return CoreError::Conflict(Message::new("role-name-duplicate"));
             }
             CoreError::Database(e)
         })?;
@@ -90,13 +89,13 @@ impl RoleUseCaseImpl {
             .role_repository
             .find_by_id(&input.role_id)
             .await?
-            .ok_or_else(|| CoreError::NotFound("ロールが見つかりません".to_string()))?;
+            .ok_or_else(|| CoreError::NotFound(Message::new("role-not-found")))?;
 
         // システムロールは編集不可
         if role.is_system() {
-            return Err(CoreError::BadRequest(
-                "システムロールは編集できません".to_string(),
-            ));
+            return Err(CoreError::BadRequest(Message::new(
+                "system-role-not-editable",
+            )));
         }
 
         let now = self.clock.now();
@@ -116,9 +115,7 @@ impl RoleUseCaseImpl {
 
         let role = if let Some(permissions) = input.permissions {
             if permissions.is_empty() {
-                return Err(CoreError::BadRequest(
-                    "権限を1つ以上指定してください".to_string(),
-                ));
+                return Err(CoreError::BadRequest(Message::new("permissions-required")));
             }
             let perms: Vec<Permission> = permissions.into_iter().map(Permission::new).collect();
             role.with_permissions(perms, now)
@@ -140,22 +137,21 @@ impl RoleUseCaseImpl {
             .role_repository
             .find_by_id(role_id)
             .await?
-            .ok_or_else(|| CoreError::NotFound("ロールが見つかりません".to_string()))?;
+            .ok_or_else(|| CoreError::NotFound(Message::new("role-not-found")))?;
 
         // システムロールは削除不可
         if role.is_system() {
-            return Err(CoreError::BadRequest(
-                "システムロールは削除できません".to_string(),
-            ));
+            return Err(CoreError::BadRequest(Message::new(
+                "system-role-not-deletable",
+            )));
         }
 
         // ユーザー割り当てチェック
         let user_count = self.role_repository.count_users_with_role(role_id).await?;
         if user_count > 0 {
-            return Err(CoreError::Conflict(format!(
-                "このロールは {} 人のユーザーに割り当てられているため削除できません",
-                user_count
-            )));
+            return Err(CoreError::Conflict(
+                Message::new("role-in-use").with("count", user_count),
+            ));
         }
 
         self.role_repository.delete(role_id).await?;
@@ -6,24 +6,23 @@
 use std::{collections::HashMap, sync::Arc};
 
 use ringiflow_domain::{
+    message::Message,
     tenant::TenantId,
     user::UserId,
     value_objects::DisplayNumber,
-    workflow::{
-        WorkflowInstance,
-        WorkflowInstanceId,
-        WorkflowStep,
-        WorkflowStepId,
-        WorkflowStepStatus,
-    },
+    workflow::{WorkflowInstance, WorkflowInstanceId, WorkflowStep, WorkflowStepId},
 };
 use ringiflow_infra::repository::{
+    KeysetPage,
     UserRepository,
     WorkflowInstanceRepository,
     WorkflowStepRepository,
 };
 
-use crate::{error::CoreError, usecase::helpers::FindResultExt};
+use crate::{
+    error::CoreError,
+    usecase::helpers::{FindResultExt, page_error, page_limit},
+};
 
 /// タスク一覧の要素: ステップ + ワークフロー概要
 #[derive(Debug, PartialEq, Eq)]
@@ -68,37 +67,38 @@ impl TaskUseCaseImpl {
         crate::usecase::resolve_user_names(self.user_repo.as_ref(), user_ids).await
     }
 
-    /// 自分のタスク一覧を取得する
+    /// 自分のタスク一覧を 1 ページ分取得する
+    ///
+    /// アサインされた Active なステップを作成日時の降順で返す。
+    /// ページ内の各ステップに対応するワークフローインスタンスを一括取得し結合する。
     ///
-    /// アサインされた Active なステップのみ返す。
-    /// 各ステップに対応するワークフローインスタンスを一括取得し結合する。
+    /// ## エラー
+    ///
+    /// - `BadRequest`: カーソルが不正な場合
     pub async fn list_my_tasks(
         &self,
         tenant_id: TenantId,
         user_id: UserId,
-    ) -> Result<Vec<TaskItem>, CoreError> {
-        // 1. 担当者でステップを取得
-        let steps = self
+        cursor: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<KeysetPage<TaskItem>, CoreError> {
+        // 1. 担当者の Active なステップを 1 ページ分取得
+        let page = self
             .step_repo
-            .find_by_assigned_to(&tenant_id, &user_id)
+            .find_active_by_assigned_to(&tenant_id, &user_id, cursor, page_limit(limit))
             .await
-            .map_err(|e| CoreError::Internal(format!("ステップ取得エラー: {}", e)))?;
+            .map_err(|e| page_error(e, "ステップ取得"))?;
 
-        // 2. Active のみフィルタ
-        let active_steps: Vec<WorkflowStep> = steps
-            .into_iter()
-            .filter(|s| s.status() == WorkflowStepStatus::Active)
-            .collect();
-
-        if active_steps.is_empty() {
-            return Ok(Vec::new());
+        if page.items.is_empty() {
+            return Ok(KeysetPage {
+                items:       Vec::new(),
+                next_cursor: page.next_cursor,
+            });
         }
 
-        // 3. ワークフローインスタンスを一括取得
-        let instance_ids: Vec<WorkflowInstanceId> = active_steps
-            .iter()
-            .map(|s| s.instance_id().clone())
-            .collect();
+        // 2. ワークフローインスタンスを一括取得
+        let instance_ids: Vec<WorkflowInstanceId> =
+            page.items.iter().map(|s| s.instance_id().clone()).collect();
 
         let instances = self
             .instance_repo
@@ -106,14 +106,15 @@ impl TaskUseCaseImpl {
             .await
             .map_err(|e| CoreError::Internal(format!("インスタンス取得エラー: {}", e)))?;
 
-        // 4. instance_id → WorkflowInstance のマップを構築
+        // 3. instance_id → WorkflowInstance のマップを構築
         let instance_map: HashMap<String, WorkflowInstance> = instances
             .into_iter()
             .map(|i| (i.id().to_string(), i))
             .collect();
 
-        // 5. ステップ + インスタンスを結合
-        let tasks = active_steps
+        // 4. ステップ + インスタンスを結合
+        let tasks = page
+            .items
             .into_iter()
             .filter_map(|step| {
                 let instance_id_str = step.instance_id().to_string();
@@ -124,7 +125,10 @@ impl TaskUseCaseImpl {
             })
             .collect();
 
-        Ok(tasks)
+        Ok(KeysetPage {
+            items:       tasks,
+            next_cursor: page.next_cursor,
+        })
     }
 
     /// タスク詳細を取得する
@@ -142,13 +146,11 @@ impl TaskUseCaseImpl {
             .step_repo
             .find_by_id(&step_id, &tenant_id)
             .await
-            .or_not_found("タスク")?;
+            .or_not_found("task")?;
 
         // 2. 権限チェック: 担当者のみアクセス可能
         if step.assigned_to() != Some(&user_id) {
-            return Err(CoreError::Forbidden(
-                "このタスクにアクセスする権限がありません".to_string(),
-            ));
+            return Err(CoreError::Forbidden(Message::new("task-access-forbidden")));
         }
 
         // 3. ワークフローインスタンスを取得
@@ -191,20 +193,18 @@ impl TaskUseCaseImpl {
             .instance_repo
             .find_by_display_number(workflow_display_number, &tenant_id)
             .await
-            .or_not_found("ワークフロー")?;
+            .or_not_found("workflow")?;
 
         // 2. ステップを display_number で取得
         let step = self
             .step_repo
             .find_by_display_number(step_display_number, workflow.id(), &tenant_id)
             .await
-            .or_not_found("タスク")?;
+            .or_not_found("task")?;
 
         // 3. 権限チェック: 担当者のみアクセス可能
         if step.assigned_to() != Some(&user_id) {
-            return Err(CoreError::Forbidden(
-                "このタスクにアクセスする権限がありません".to_string(),
-            ));
+            return Err(CoreError::Forbidden(Message::new("task-access-forbidden")));
         }
 
         // 4. ワークフローの全ステップを取得
@@ -316,14 +316,20 @@ mod tests {
         );
 
         // Act
-        let result = sut.list_my_tasks(tenant_id, approver_id).await;
+        let result = sut.list_my_tasks(tenant_id, approver_id, None, None).await;
 
         // Assert
         let expected = vec![TaskItem {
             step:     active_step,
             workflow: instance,
         }];
-        assert_eq!(result.unwrap(), expected);
+        assert_eq!(
+            result.unwrap(),
+            KeysetPage {
+                items:       expected,
+                next_cursor: None,
+            }
+        );
     }
 
     #[tokio::test]
@@ -374,14 +380,20 @@ mod tests {
         );
 
         // Act
-        let result = sut.list_my_tasks(tenant_id, approver_id).await;
+        let result = sut.list_my_tasks(tenant_id, approver_id, None, None).await;
 
         // Assert
         let expected = vec![TaskItem {
             step,
             workflow: instance,
         }];
-        assert_eq!(result.unwrap(), expected);
+        assert_eq!(
+            result.unwrap(),
+            KeysetPage {
+                items:       expected,
+                next_cursor: None,
+            }
+        );
     }
 
     #[tokio::test]
@@ -433,11 +445,13 @@ mod tests {
         );
 
         // Act: 別のユーザーで取得
-        let result = sut.list_my_tasks(tenant_id, other_user_id).await;
+        let result = sut
+            .list_my_tasks(tenant_id, other_user_id, None, None)
+            .await;
 
         // Assert
         assert!(result.is_ok());
-        assert!(result.unwrap().is_empty());
+        assert!(result.unwrap().items.is_empty());
     }
 
     #[tokio::test]
@@ -456,11 +470,94 @@ mod tests {
         );
 
         // Act
-        let result = sut.list_my_tasks(tenant_id, user_id).await;
+        let result = sut.list_my_tasks(tenant_id, user_id, None, None).await;
 
         // Assert
         assert!(result.is_ok());
-        assert!(result.unwrap().is_empty());
+        assert!(result.unwrap().items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_my_tasks_カーソルで次ページを取得できる() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let approver_id = UserId::new();
+
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+
+        let now = chrono::Utc::now();
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: WorkflowDefinitionId::new(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "テスト申請".to_string(),
+            form_data: serde_json::json!({}),
+            initiated_by: UserId::new(),
+            now,
+        });
+        instance_repo.insert_for_test(&instance).await.unwrap();
+
+        // 作成日時をずらした Active ステップを 3 件作成（新しい順に返る）
+        let mut steps = Vec::new();
+        for n in 1..=3 {
+            let created_at = now + chrono::Duration::seconds(n);
+            let step = WorkflowStep::new(NewWorkflowStep {
+                id: WorkflowStepId::new(),
+                instance_id: instance.id().clone(),
+                display_number: DisplayNumber::new(n).unwrap(),
+                step_id: format!("approval_{n}"),
+                step_name: "承認".to_string(),
+                step_type: "approval".to_string(),
+                assigned_to: Some(approver_id.clone()),
+                now: created_at,
+            })
+            .activated(created_at);
+            step_repo.insert_for_test(&step, &tenant_id).await.unwrap();
+            steps.push(step);
+        }
+
+        let sut = TaskUseCaseImpl::new(
+            Arc::new(instance_repo),
+            Arc::new(step_repo),
+            Arc::new(FakeUserRepository::new()),
+        );
+
+        // Act
+        let first = sut
+            .list_my_tasks(tenant_id.clone(), approver_id.clone(), None, Some(2))
+            .await
+            .unwrap();
+        let second = sut
+            .list_my_tasks(
+                tenant_id.clone(),
+                approver_id.clone(),
+                first.next_cursor.as_deref(),
+                Some(2),
+            )
+            .await
+            .unwrap();
+        let invalid = sut
+            .list_my_tasks(tenant_id, approver_id, Some("invalid"), Some(2))
+            .await;
+
+        // Assert
+        let step_ids = |page: &KeysetPage<TaskItem>| {
+            page.items
+                .iter()
+                .map(|t| t.step.id().clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            step_ids(&first),
+            vec![steps[2].id().clone(), steps[1].id().clone()]
+        );
+        assert!(first.next_cursor.is_some());
+        assert_eq!(step_ids(&second), vec![steps[0].id().clone()]);
+        assert_eq!(second.next_cursor, None);
+        assert!(matches!(invalid, Err(CoreError::BadRequest(_))));
     }
 
     #[tokio::test]
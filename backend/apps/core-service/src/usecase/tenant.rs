@@ -0,0 +1,56 @@
+//! テナント設定ユースケース
+
+use std::sync::Arc;
+
+use ringiflow_domain::{
+    locale::Locale,
+    message::Message,
+    tenant::{Tenant, TenantId},
+    time_zone::TimeZone,
+};
+use ringiflow_infra::repository::TenantRepository;
+
+use crate::error::CoreError;
+
+/// テナント設定ユースケース
+pub struct TenantUseCaseImpl {
+    tenant_repository: Arc<dyn TenantRepository>,
+}
+
+impl TenantUseCaseImpl {
+    pub fn new(tenant_repository: Arc<dyn TenantRepository>) -> Self {
+        Self { tenant_repository }
+    }
+
+    /// テナントを取得する
+    pub async fn get_tenant(&self, tenant_id: &TenantId) -> Result<Tenant, CoreError> {
+        self.tenant_repository
+            .find_by_id(tenant_id)
+            .await?
+            .ok_or_else(|| CoreError::NotFound(Message::new("tenant-not-found")))
+    }
+
+    /// テナントの設定を変更する
+    ///
+    /// 既定ロケールはロケールを選んでいないユーザーの通知・画面表示に、
+    /// タイムゾーンは通知ダイジェストの送信時刻の解釈に使われる。
+    /// タイムゾーンが `None` の場合は変更しない。
+    pub async fn update_settings(
+        &self,
+        tenant_id: &TenantId,
+        default_locale: Locale,
+        time_zone: Option<TimeZone>,
+    ) -> Result<Tenant, CoreError> {
+        let tenant = self
+            .get_tenant(tenant_id)
+            .await?
+            .with_default_locale(default_locale);
+        let tenant = match time_zone {
+            Some(time_zone) => tenant.with_time_zone(time_zone),
+            None => tenant,
+        };
+        self.tenant_repository.update_settings(&tenant).await?;
+
+        Ok(tenant)
+    }
+}
@@ -4,6 +4,8 @@ use std::sync::Arc;
 
 use ringiflow_domain::{
     clock::Clock,
+    locale::Locale,
+    message::Message,
     role::{Role, RoleId},
     tenant::TenantId,
     user::{Email, User, UserId, UserStatus},
@@ -28,6 +30,14 @@ pub struct UpdateUserInput {
     pub role_id: Option<RoleId>,
 }
 
+/// ユーザーロケール変更の入力
+pub struct UpdateUserLocaleInput {
+    pub user_id:   UserId,
+    pub tenant_id: TenantId,
+    /// `None` の場合はテナントの既定ロケールに従う
+    pub locale:    Option<Locale>,
+}
+
 /// ユーザーステータス変更の入力
 pub struct UpdateUserStatusInput {
     pub user_id:      UserId,
@@ -71,9 +81,7 @@ impl UserUseCaseImpl {
             .find_by_email(&input.tenant_id, &input.email)
             .await?
         {
-            return Err(CoreError::Conflict(
-                "このメールアドレスは既に使用されています".to_string(),
-            ));
+            return Err(CoreError::Conflict(Message::new("email-already-used")));
         }
 
         // ロールの存在確認
@@ -82,7 +90,9 @@ impl UserUseCaseImpl {
             .find_role_by_id(&input.role_id)
             .await?
             .ok_or_else(|| {
-                CoreError::BadRequest(format!("ロール ID '{}' が見つかりません", input.role_id))
+                CoreError::BadRequest(
+                    Message::new("role-id-not-found").with("role_id", input.role_id),
+                )
             })?;
 
         // display_number 採番
@@ -120,7 +130,7 @@ impl UserUseCaseImpl {
             .user_repository
             .find_by_id(&input.user_id)
             .await?
-            .ok_or_else(|| CoreError::NotFound("ユーザーが見つかりません".to_string()))?;
+            .ok_or_else(|| CoreError::NotFound(Message::new("user-not-found")))?;
 
         let now = self.clock.now();
 
@@ -140,7 +150,9 @@ impl UserUseCaseImpl {
                 .find_role_by_id(&role_id)
                 .await?
                 .ok_or_else(|| {
-                    CoreError::BadRequest(format!("ロール ID '{}' が見つかりません", role_id))
+                    CoreError::BadRequest(
+                        Message::new("role-id-not-found").with("role_id", role_id),
+                    )
                 })?;
 
             self.user_repository
@@ -161,16 +173,14 @@ impl UserUseCaseImpl {
     ) -> Result<User, CoreError> {
         // 自己無効化防止
         if input.status != UserStatus::Active && input.requester_id == input.user_id {
-            return Err(CoreError::BadRequest(
-                "自分自身を無効化することはできません".to_string(),
-            ));
+            return Err(CoreError::BadRequest(Message::new("deactivate-self")));
         }
 
         let user = self
             .user_repository
             .find_by_id(&input.user_id)
             .await?
-            .ok_or_else(|| CoreError::NotFound("ユーザーが見つかりません".to_string()))?;
+            .ok_or_else(|| CoreError::NotFound(Message::new("user-not-found")))?;
 
         // 最後のテナント管理者保護
         if input.status != UserStatus::Active
@@ -178,9 +188,7 @@ impl UserUseCaseImpl {
                 .is_last_tenant_admin(&input.tenant_id, &input.user_id)
                 .await?
         {
-            return Err(CoreError::BadRequest(
-                "最後のテナント管理者を無効化することはできません".to_string(),
-            ));
+            return Err(CoreError::BadRequest(Message::new("last-tenant-admin")));
         }
 
         let now = self.clock.now();
@@ -190,6 +198,26 @@ impl UserUseCaseImpl {
         Ok(updated)
     }
 
+    /// ユーザーのロケールを変更する
+    ///
+    /// 他テナントのユーザーは存在しないものとして扱う。
+    pub async fn update_user_locale(
+        &self,
+        input: UpdateUserLocaleInput,
+    ) -> Result<User, CoreError> {
+        let user = self
+            .user_repository
+            .find_by_id(&input.user_id)
+            .await?
+            .filter(|user| user.tenant_id() == &input.tenant_id)
+            .ok_or_else(|| CoreError::NotFound(Message::new("user-not-found")))?;
+
+        let updated = user.with_locale(input.locale, self.clock.now());
+        self.user_repository.update_locale(&updated).await?;
+
+        Ok(updated)
+    }
+
     /// 対象ユーザーがテナント内で最後の管理者かどうかを判定する
     async fn is_last_tenant_admin(
         &self,
@@ -210,7 +238,7 @@ impl UserUseCaseImpl {
             .user_repository
             .find_with_roles(user_id)
             .await?
-            .ok_or_else(|| CoreError::NotFound("ユーザーが見つかりません".to_string()))?;
+            .ok_or_else(|| CoreError::NotFound(Message::new("user-not-found")))?;
 
         Ok(roles.iter().any(|r| r.name() == "tenant_admin"))
     }
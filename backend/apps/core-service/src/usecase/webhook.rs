@@ -0,0 +1,538 @@
+//! # Webhook 管理ユースケース
+//!
+//! テナント管理者が登録する Webhook 購読の CRUD、配信ログの参照、テスト送信を提供する。
+//!
+//! ワークフローイベントからの配信登録は `workflow_event::WebhookEventConsumer`、
+//! 送信とリトライは [`worker::WebhookDeliveryWorker`] が担当する。
+//!
+//! ## モジュール構成
+//!
+//! - `worker`: 送信待ちの配信のポーリングと送信
+
+pub mod worker;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    clock::Clock,
+    message::Message,
+    tenant::TenantId,
+    user::UserId,
+    webhook::{
+        DELIVERY_HEADER,
+        EVENT_HEADER,
+        NewWebhookDelivery,
+        NewWebhookSubscription,
+        PING_EVENT_TYPE,
+        SIGNATURE_HEADER,
+        TIMESTAMP_HEADER,
+        WebhookDelivery,
+        WebhookDeliveryId,
+        WebhookName,
+        WebhookSecret,
+        WebhookSubscription,
+        WebhookSubscriptionChanges,
+        WebhookSubscriptionId,
+        WebhookUrl,
+    },
+    workflow::WorkflowEventType,
+};
+use ringiflow_infra::{
+    repository::{WebhookDeliveryRepository, WebhookSubscriptionRepository},
+    webhook::{WebhookRequest, WebhookSendError, WebhookSender},
+};
+use uuid::Uuid;
+pub use worker::WebhookDeliveryWorker;
+
+use crate::error::CoreError;
+
+/// 配信ログの取得件数
+const DELIVERY_LOG_LIMIT: i64 = 100;
+
+/// Webhook 作成の入力
+pub struct CreateWebhookInput {
+    pub tenant_id:   TenantId,
+    pub name:        String,
+    pub url:         String,
+    pub event_types: Vec<String>,
+    pub secret:      String,
+    pub created_by:  Uuid,
+}
+
+/// Webhook 更新の入力（`None` の項目は変更しない）
+pub struct UpdateWebhookInput {
+    pub subscription_id: WebhookSubscriptionId,
+    pub tenant_id: TenantId,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub event_types: Option<Vec<String>>,
+    pub secret: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Webhook 管理ユースケース
+pub struct WebhookUseCaseImpl {
+    subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    sender: Arc<dyn WebhookSender>,
+    clock: Arc<dyn Clock>,
+}
+
+impl WebhookUseCaseImpl {
+    pub fn new(
+        subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+        sender: Arc<dyn WebhookSender>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            subscription_repo,
+            delivery_repo,
+            sender,
+            clock,
+        }
+    }
+
+    /// Webhook 一覧を取得する（作成日時順）
+    pub async fn list_webhooks(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, CoreError> {
+        let subscriptions = self.subscription_repo.find_all_by_tenant(tenant_id).await?;
+        Ok(subscriptions)
+    }
+
+    /// Webhook を取得する
+    pub async fn get_webhook(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<WebhookSubscription, CoreError> {
+        self.find_subscription(id, tenant_id).await
+    }
+
+    /// Webhook を作成する（有効状態で作成される）
+    ///
+    /// 送信先 URL は送信できる宛先か（https かつ内部アドレスに解決されないか）を検証する。
+    pub async fn create_webhook(
+        &self,
+        input: CreateWebhookInput,
+    ) -> Result<WebhookSubscription, CoreError> {
+        let url = WebhookUrl::new(input.url).map_err(bad_request)?;
+        self.verify_destination(&url).await?;
+
+        let subscription = WebhookSubscription::new(NewWebhookSubscription {
+            id: WebhookSubscriptionId::new(),
+            tenant_id: input.tenant_id,
+            name: WebhookName::new(input.name).map_err(bad_request)?,
+            url,
+            event_types: parse_event_types(&input.event_types)?,
+            secret: WebhookSecret::new(input.secret).map_err(bad_request)?,
+            created_by: UserId::from_uuid(input.created_by),
+            now: self.clock.now(),
+        })
+        .map_err(bad_request)?;
+
+        self.subscription_repo.insert(&subscription).await?;
+        Ok(subscription)
+    }
+
+    /// Webhook を更新する
+    ///
+    /// 送信先 URL を変更する場合は作成時と同じ検証を行う。
+    pub async fn update_webhook(
+        &self,
+        input: UpdateWebhookInput,
+    ) -> Result<WebhookSubscription, CoreError> {
+        let subscription = self
+            .find_subscription(&input.subscription_id, &input.tenant_id)
+            .await?;
+
+        let changes = WebhookSubscriptionChanges {
+            name:        input
+                .name
+                .map(WebhookName::new)
+                .transpose()
+                .map_err(bad_request)?,
+            url:         match input.url {
+                Some(url) => {
+                    let url = WebhookUrl::new(url).map_err(bad_request)?;
+                    self.verify_destination(&url).await?;
+                    Some(url)
+                }
+                None => None,
+            },
+            event_types: input
+                .event_types
+                .as_deref()
+                .map(parse_event_types)
+                .transpose()?,
+            secret:      input
+                .secret
+                .map(WebhookSecret::new)
+                .transpose()
+                .map_err(bad_request)?,
+            is_active:   input.is_active,
+        };
+        let updated = subscription
+            .updated(changes, self.clock.now())
+            .map_err(bad_request)?;
+
+        self.subscription_repo.update(&updated).await?;
+        Ok(updated)
+    }
+
+    /// Webhook を削除する（配信ログも削除される）
+    pub async fn delete_webhook(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<(), CoreError> {
+        self.find_subscription(id, tenant_id).await?;
+        self.subscription_repo.delete(id, tenant_id).await?;
+        Ok(())
+    }
+
+    /// 配信ログを取得する（新しい順、最大 100 件）
+    pub async fn list_deliveries(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookDelivery>, CoreError> {
+        self.find_subscription(id, tenant_id).await?;
+        let deliveries = self
+            .delivery_repo
+            .find_by_subscription(id, tenant_id, DELIVERY_LOG_LIMIT)
+            .await?;
+        Ok(deliveries)
+    }
+
+    /// テストイベント（`ping`）を送信する
+    ///
+    /// 無効化された Webhook にも送信できる。送信は同期的に 1 回だけ試行し、
+    /// 結果を配信ログに記録して返す。失敗した場合は通常の配信と同様にワーカーが再送する。
+    pub async fn send_test(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<WebhookDelivery, CoreError> {
+        let subscription = self.find_subscription(id, tenant_id).await?;
+        let now = self.clock.now();
+
+        let delivery_id = WebhookDeliveryId::new();
+        let payload = serde_json::json!({
+            "id": delivery_id.to_string(),
+            "type": PING_EVENT_TYPE,
+            "occurred_at": now.to_rfc3339(),
+            "tenant_id": tenant_id.to_string(),
+            "data": {
+                "webhook": {
+                    "id": subscription.id().to_string(),
+                    "name": subscription.name().as_str(),
+                },
+            },
+        });
+        let delivery = WebhookDelivery::new(NewWebhookDelivery {
+            id: delivery_id,
+            tenant_id: tenant_id.clone(),
+            subscription_id: subscription.id().clone(),
+            event_id: None,
+            event_type: PING_EVENT_TYPE.to_string(),
+            payload,
+            now,
+        });
+
+        let delivery = attempt_delivery(self.sender.as_ref(), &subscription, delivery, now).await;
+        self.delivery_repo
+            .insert_all(std::slice::from_ref(&delivery))
+            .await?;
+        Ok(delivery)
+    }
+
+    async fn verify_destination(&self, url: &WebhookUrl) -> Result<(), CoreError> {
+        self.sender
+            .verify_destination(url.as_str())
+            .await
+            .map_err(|e| {
+                let message = match e {
+                    WebhookSendError::Forbidden(reason) => {
+                        Message::new("webhook-destination-forbidden").with("reason", reason)
+                    }
+                    WebhookSendError::Transport(reason) => {
+                        Message::new("webhook-destination-unreachable").with("reason", reason)
+                    }
+                };
+                CoreError::BadRequest(message)
+            })
+    }
+
+    async fn find_subscription(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<WebhookSubscription, CoreError> {
+        self.subscription_repo
+            .find_by_id(id, tenant_id)
+            .await?
+            .ok_or_else(|| CoreError::NotFound(Message::new("webhook-not-found")))
+    }
+}
+
+/// 配信を 1 回試行し、結果を反映した配信を返す
+///
+/// 2xx 応答を成功、それ以外の応答と通信エラーを失敗とする。
+pub(crate) async fn attempt_delivery(
+    sender: &dyn WebhookSender,
+    subscription: &WebhookSubscription,
+    delivery: WebhookDelivery,
+    now: DateTime<Utc>,
+) -> WebhookDelivery {
+    let body = delivery.body();
+    let timestamp = now.timestamp();
+    let request = WebhookRequest {
+        url: subscription.url().as_str().to_string(),
+        headers: vec![
+            (EVENT_HEADER, delivery.event_type().to_string()),
+            (DELIVERY_HEADER, delivery.id().to_string()),
+            (TIMESTAMP_HEADER, timestamp.to_string()),
+            (
+                SIGNATURE_HEADER,
+                subscription.secret().sign(timestamp, &body),
+            ),
+        ],
+        body,
+    };
+
+    match sender.send(&request).await {
+        Ok(status) if (200..300).contains(&status) => delivery.succeeded(i32::from(status), now),
+        Ok(status) => delivery.failed(Some(i32::from(status)), format!("HTTP {}", status), now),
+        Err(e) => delivery.failed(None, e.to_string(), now),
+    }
+}
+
+/// 購読するイベント種別の文字列をパースする
+fn parse_event_types(event_types: &[String]) -> Result<Vec<WorkflowEventType>, CoreError> {
+    event_types
+        .iter()
+        .map(|s| s.parse::<WorkflowEventType>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(bad_request)
+}
+
+fn bad_request(e: ringiflow_domain::DomainError) -> CoreError {
+    CoreError::BadRequest(e.into_message())
+}
+
+#[cfg(test)]
+mod tests {
+    use ringiflow_domain::{clock::FixedClock, webhook::WebhookDeliveryStatus};
+    use ringiflow_infra::fake::{
+        FakeWebhookDeliveryRepository,
+        FakeWebhookSender,
+        FakeWebhookSubscriptionRepository,
+    };
+
+    use super::*;
+
+    fn fixed_now() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    struct Sut {
+        usecase:       WebhookUseCaseImpl,
+        delivery_repo: FakeWebhookDeliveryRepository,
+        sender:        FakeWebhookSender,
+    }
+
+    fn create_sut() -> Sut {
+        let delivery_repo = FakeWebhookDeliveryRepository::new();
+        let sender = FakeWebhookSender::new();
+        let usecase = WebhookUseCaseImpl::new(
+            Arc::new(FakeWebhookSubscriptionRepository::new()),
+            Arc::new(delivery_repo.clone()),
+            Arc::new(sender.clone()),
+            Arc::new(FixedClock::new(fixed_now())),
+        );
+        Sut {
+            usecase,
+            delivery_repo,
+            sender,
+        }
+    }
+
+    fn create_input(tenant_id: &TenantId, event_types: &[&str]) -> CreateWebhookInput {
+        CreateWebhookInput {
+            tenant_id:   tenant_id.clone(),
+            name:        "ERP 連携".to_string(),
+            url:         "https://erp.example.com/hooks".to_string(),
+            event_types: event_types.iter().map(|s| s.to_string()).collect(),
+            secret:      "0123456789abcdef".to_string(),
+            created_by:  Uuid::now_v7(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_不明なイベント種別はbad_request() {
+        let sut = create_sut();
+        let tenant_id = TenantId::new();
+
+        let result = sut
+            .usecase
+            .create_webhook(create_input(&tenant_id, &["approved", "deleted"]))
+            .await;
+
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_許可されていない送信先はbad_request() {
+        let sut = create_sut();
+        sut.sender
+            .forbid_destination("https://erp.example.com/hooks");
+
+        let result = sut
+            .usecase
+            .create_webhook(create_input(&TenantId::new(), &["approved"]))
+            .await;
+
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_webhook_許可されていない送信先への変更はbad_request() {
+        let sut = create_sut();
+        let tenant_id = TenantId::new();
+        let created = sut
+            .usecase
+            .create_webhook(create_input(&tenant_id, &["approved"]))
+            .await
+            .unwrap();
+        sut.sender
+            .forbid_destination("https://169.254.169.254/latest");
+
+        let result = sut
+            .usecase
+            .update_webhook(UpdateWebhookInput {
+                subscription_id: created.id().clone(),
+                tenant_id: tenant_id.clone(),
+                name: None,
+                url: Some("https://169.254.169.254/latest".to_string()),
+                event_types: None,
+                secret: None,
+                is_active: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_webhook_指定した項目のみ更新される() {
+        let sut = create_sut();
+        let tenant_id = TenantId::new();
+        let created = sut
+            .usecase
+            .create_webhook(create_input(&tenant_id, &["approved"]))
+            .await
+            .unwrap();
+
+        let updated = sut
+            .usecase
+            .update_webhook(UpdateWebhookInput {
+                subscription_id: created.id().clone(),
+                tenant_id: tenant_id.clone(),
+                name: None,
+                url: None,
+                event_types: Some(vec!["rejected".to_string()]),
+                secret: None,
+                is_active: Some(false),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(updated.name(), created.name());
+        assert_eq!(updated.event_types(), &[WorkflowEventType::Rejected]);
+        assert!(!updated.is_active());
+    }
+
+    #[tokio::test]
+    async fn test_他テナントのwebhookはnot_found() {
+        let sut = create_sut();
+        let created = sut
+            .usecase
+            .create_webhook(create_input(&TenantId::new(), &["approved"]))
+            .await
+            .unwrap();
+
+        let result = sut
+            .usecase
+            .delete_webhook(created.id(), &TenantId::new())
+            .await;
+
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_send_test_署名付きのpingを送信し配信ログに記録する() {
+        let sut = create_sut();
+        let tenant_id = TenantId::new();
+        let created = sut
+            .usecase
+            .create_webhook(create_input(&tenant_id, &["approved"]))
+            .await
+            .unwrap();
+
+        let delivery = sut
+            .usecase
+            .send_test(created.id(), &tenant_id)
+            .await
+            .unwrap();
+
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Succeeded);
+        assert_eq!(delivery.event_type(), PING_EVENT_TYPE);
+        assert_eq!(sut.delivery_repo.deliveries(), vec![delivery.clone()]);
+
+        let requests = sut.sender.requests();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.url, "https://erp.example.com/hooks");
+        let header = |name: &str| {
+            request
+                .headers
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| v.clone())
+                .unwrap()
+        };
+        assert_eq!(header(EVENT_HEADER), "ping");
+        assert_eq!(header(TIMESTAMP_HEADER), "1700000000");
+        assert_eq!(
+            header(SIGNATURE_HEADER),
+            created.secret().sign(1_700_000_000, &request.body)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_test_2xx以外の応答は失敗として記録される() {
+        let sut = create_sut();
+        let tenant_id = TenantId::new();
+        let created = sut
+            .usecase
+            .create_webhook(create_input(&tenant_id, &["approved"]))
+            .await
+            .unwrap();
+        sut.sender.respond_with(Some(500));
+
+        let delivery = sut
+            .usecase
+            .send_test(created.id(), &tenant_id)
+            .await
+            .unwrap();
+
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.attempts(), 1);
+        assert_eq!(delivery.response_status(), Some(500));
+        assert_eq!(delivery.last_error(), Some("HTTP 500"));
+    }
+}
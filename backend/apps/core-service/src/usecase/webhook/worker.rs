@@ -0,0 +1,392 @@
+//! # Webhook 配信ワーカー
+//!
+//! 送信時刻に達した配信をポーリングし、購読先へ送信する。
+//!
+//! ## 処理フロー
+//!
+//! 1. 短いトランザクションで送信待ちの配信を `FOR UPDATE SKIP LOCKED` で取り出し、
+//!    `next_attempt_at` を処理期限（リース）まで進めてコミットする
+//! 2. 配信ごとに購読を取得し、トランザクション外で署名付きリクエストを送信する
+//! 3. 配信ごとに短いトランザクションで送信結果（成功 / 指数バックオフでの再送予定 /
+//!    最大試行回数での失敗）を記録する
+//!
+//! 行ロックを保持したまま HTTP 送信を待たないため、遅い送信先があっても DB 接続や
+//! ロックを占有しない。送信結果の記録前にプロセスが停止した配信は、処理期限を過ぎると
+//! 再び取り出される（at-least-once）。
+//!
+//! 購読が無効化されている場合は送信せず、失敗として記録する。
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use ringiflow_domain::{clock::Clock, webhook::WebhookDelivery};
+use ringiflow_infra::{
+    TransactionManager,
+    repository::{WebhookDeliveryRepository, WebhookSubscriptionRepository},
+    webhook::WebhookSender,
+};
+
+use super::attempt_delivery;
+use crate::{error::CoreError, usecase::polling::PollingWorker};
+
+/// 取り出した配信の処理期限（秒）
+///
+/// 送信タイムアウトより十分長くし、送信中に他のワーカーが取り出さないようにする。
+const LEASE_SECONDS: i64 = 5 * 60;
+
+/// Webhook 配信ワーカー
+pub struct WebhookDeliveryWorker {
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    sender: Arc<dyn WebhookSender>,
+    tx_manager: Arc<dyn TransactionManager>,
+    clock: Arc<dyn Clock>,
+    batch_size: i64,
+}
+
+impl WebhookDeliveryWorker {
+    pub fn new(
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+        subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+        sender: Arc<dyn WebhookSender>,
+        tx_manager: Arc<dyn TransactionManager>,
+        clock: Arc<dyn Clock>,
+        batch_size: i64,
+    ) -> Self {
+        Self {
+            delivery_repo,
+            subscription_repo,
+            sender,
+            tx_manager,
+            clock,
+            batch_size,
+        }
+    }
+
+    /// 送信時刻に達した配信を 1 バッチ分送信する
+    ///
+    /// 処理した配信数（成功・失敗の合計）を返す。
+    pub async fn deliver_due(&self) -> Result<usize, CoreError> {
+        let due = self.claim(self.clock.now()).await?;
+        let count = due.len();
+
+        for delivery in due {
+            self.deliver(delivery).await?;
+        }
+
+        Ok(count)
+    }
+
+    /// 送信時刻に達した配信を取り出し、処理期限を設定してコミットする
+    async fn claim(&self, now: DateTime<Utc>) -> Result<Vec<WebhookDelivery>, CoreError> {
+        let mut tx = self
+            .tx_manager
+            .begin()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクション開始に失敗: {}", e)))?;
+
+        let due = self
+            .delivery_repo
+            .claim_due(
+                &mut tx,
+                now,
+                now + TimeDelta::seconds(LEASE_SECONDS),
+                self.batch_size,
+            )
+            .await
+            .map_err(|e| CoreError::Internal(format!("送信待ちの配信の取得に失敗: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))?;
+
+        Ok(due)
+    }
+
+    /// 1 件の配信を送信し、結果を記録する
+    async fn deliver(&self, delivery: WebhookDelivery) -> Result<(), CoreError> {
+        let now = self.clock.now();
+        let subscription = self
+            .subscription_repo
+            .find_by_id(delivery.subscription_id(), delivery.tenant_id())
+            .await?;
+
+        let delivery = match subscription {
+            Some(subscription) if subscription.is_active() => {
+                attempt_delivery(self.sender.as_ref(), &subscription, delivery, now).await
+            }
+            _ => delivery.failed(None, "Webhook が無効化されています", now),
+        };
+
+        if let Some(error) = delivery.last_error() {
+            tracing::warn!(
+                delivery_id = %delivery.id(),
+                subscription_id = %delivery.subscription_id(),
+                attempts = delivery.attempts(),
+                status = %delivery.status(),
+                error = %error,
+                "Webhook の送信に失敗"
+            );
+        }
+
+        self.record(&delivery).await
+    }
+
+    /// 送信結果を短いトランザクションで記録する
+    async fn record(&self, delivery: &WebhookDelivery) -> Result<(), CoreError> {
+        let mut tx = self
+            .tx_manager
+            .begin()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクション開始に失敗: {}", e)))?;
+
+        self.delivery_repo
+            .update(&mut tx, delivery)
+            .await
+            .map_err(|e| CoreError::Internal(format!("配信結果の記録に失敗: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))
+    }
+}
+
+#[async_trait]
+impl PollingWorker for WebhookDeliveryWorker {
+    fn name(&self) -> &'static str {
+        "Webhook 配信ワーカー"
+    }
+
+    fn batch_size(&self) -> i64 {
+        self.batch_size
+    }
+
+    async fn step(&self) -> Result<usize, CoreError> {
+        self.deliver_due().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeDelta, Utc};
+    use ringiflow_domain::{
+        clock::FixedClock,
+        tenant::TenantId,
+        user::UserId,
+        webhook::{
+            NewWebhookDelivery,
+            NewWebhookSubscription,
+            WebhookDeliveryId,
+            WebhookDeliveryStatus,
+            WebhookName,
+            WebhookSecret,
+            WebhookSubscription,
+            WebhookSubscriptionChanges,
+            WebhookSubscriptionId,
+            WebhookUrl,
+        },
+        workflow::WorkflowEventType,
+    };
+    use ringiflow_infra::fake::{
+        FakeTransactionManager,
+        FakeWebhookDeliveryRepository,
+        FakeWebhookSender,
+        FakeWebhookSubscriptionRepository,
+    };
+
+    use super::*;
+
+    /// 承認完了イベントを購読する Webhook を登録する
+    async fn add_subscription(
+        subscription_repo: &FakeWebhookSubscriptionRepository,
+        now: DateTime<Utc>,
+    ) -> WebhookSubscription {
+        let subscription = WebhookSubscription::new(NewWebhookSubscription {
+            id: WebhookSubscriptionId::new(),
+            tenant_id: TenantId::new(),
+            name: WebhookName::new("ERP 連携").unwrap(),
+            url: WebhookUrl::new("https://erp.example.com/hooks").unwrap(),
+            event_types: vec![WorkflowEventType::Approved],
+            secret: WebhookSecret::new("0123456789abcdef").unwrap(),
+            created_by: UserId::new(),
+            now,
+        })
+        .unwrap();
+        subscription_repo.insert(&subscription).await.unwrap();
+        subscription
+    }
+
+    /// 購読先への配信を 1 件登録する
+    async fn add_delivery(
+        delivery_repo: &FakeWebhookDeliveryRepository,
+        subscription: &WebhookSubscription,
+        now: DateTime<Utc>,
+    ) {
+        let delivery = WebhookDelivery::new(NewWebhookDelivery {
+            id: WebhookDeliveryId::new(),
+            tenant_id: subscription.tenant_id().clone(),
+            subscription_id: subscription.id().clone(),
+            event_id: None,
+            event_type: "approved".to_string(),
+            payload: serde_json::json!({"type": "approved"}),
+            now,
+        });
+        delivery_repo.insert_all(&[delivery]).await.unwrap();
+    }
+
+    fn make_worker(
+        delivery_repo: &FakeWebhookDeliveryRepository,
+        subscription_repo: &FakeWebhookSubscriptionRepository,
+        sender: &FakeWebhookSender,
+        now: DateTime<Utc>,
+    ) -> WebhookDeliveryWorker {
+        WebhookDeliveryWorker::new(
+            Arc::new(delivery_repo.clone()),
+            Arc::new(subscription_repo.clone()),
+            Arc::new(sender.clone()),
+            Arc::new(FakeTransactionManager),
+            Arc::new(FixedClock::new(now)),
+            50,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_送信に成功すると配信済みになる() {
+        // Arrange
+        let now = Utc::now();
+        let delivery_repo = FakeWebhookDeliveryRepository::new();
+        let subscription_repo = FakeWebhookSubscriptionRepository::new();
+        let sender = FakeWebhookSender::new();
+        let subscription = add_subscription(&subscription_repo, now).await;
+        add_delivery(&delivery_repo, &subscription, now).await;
+        let sut = make_worker(&delivery_repo, &subscription_repo, &sender, now);
+
+        // Act
+        let count = sut.deliver_due().await.unwrap();
+
+        // Assert
+        assert_eq!(count, 1);
+        assert_eq!(sender.requests().len(), 1);
+        let delivery = &delivery_repo.deliveries()[0];
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Succeeded);
+        assert_eq!(sut.deliver_due().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_失敗した配信はバックオフ後に再送される() {
+        // Arrange
+        let now = Utc::now();
+        let delivery_repo = FakeWebhookDeliveryRepository::new();
+        let subscription_repo = FakeWebhookSubscriptionRepository::new();
+        let sender = FakeWebhookSender::new();
+        let subscription = add_subscription(&subscription_repo, now).await;
+        add_delivery(&delivery_repo, &subscription, now).await;
+        sender.respond_with(None);
+        make_worker(&delivery_repo, &subscription_repo, &sender, now)
+            .deliver_due()
+            .await
+            .unwrap();
+
+        // Act: バックオフ中は再送されない
+        let during_backoff = make_worker(&delivery_repo, &subscription_repo, &sender, now)
+            .deliver_due()
+            .await
+            .unwrap();
+
+        // Act: 次回送信時刻を過ぎると再送される
+        sender.respond_with(Some(204));
+        let after_backoff = make_worker(
+            &delivery_repo,
+            &subscription_repo,
+            &sender,
+            now + TimeDelta::minutes(1),
+        )
+        .deliver_due()
+        .await
+        .unwrap();
+
+        // Assert
+        assert_eq!(during_backoff, 0);
+        assert_eq!(after_backoff, 1);
+        assert_eq!(sender.requests().len(), 2);
+        let delivery = &delivery_repo.deliveries()[0];
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Succeeded);
+        assert_eq!(delivery.attempts(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_取り出した配信は処理期限まで再度取り出されない() {
+        // Arrange: 取り出した後、送信結果を記録する前にワーカーが停止した状態
+        let now = Utc::now();
+        let delivery_repo = FakeWebhookDeliveryRepository::new();
+        let subscription_repo = FakeWebhookSubscriptionRepository::new();
+        let sender = FakeWebhookSender::new();
+        let subscription = add_subscription(&subscription_repo, now).await;
+        add_delivery(&delivery_repo, &subscription, now).await;
+        let claimed = make_worker(&delivery_repo, &subscription_repo, &sender, now)
+            .claim(now)
+            .await
+            .unwrap();
+        assert_eq!(claimed.len(), 1);
+
+        // Act
+        let before_lease = make_worker(
+            &delivery_repo,
+            &subscription_repo,
+            &sender,
+            now + TimeDelta::minutes(1),
+        )
+        .deliver_due()
+        .await
+        .unwrap();
+        let after_lease = make_worker(
+            &delivery_repo,
+            &subscription_repo,
+            &sender,
+            now + TimeDelta::seconds(LEASE_SECONDS),
+        )
+        .deliver_due()
+        .await
+        .unwrap();
+
+        // Assert
+        assert_eq!(before_lease, 0);
+        assert_eq!(after_lease, 1);
+        assert_eq!(sender.requests().len(), 1);
+        let delivery = &delivery_repo.deliveries()[0];
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Succeeded);
+        assert_eq!(delivery.attempts(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_deliver_due_無効化された購読には送信しない() {
+        // Arrange
+        let now = Utc::now();
+        let delivery_repo = FakeWebhookDeliveryRepository::new();
+        let subscription_repo = FakeWebhookSubscriptionRepository::new();
+        let sender = FakeWebhookSender::new();
+        let subscription = add_subscription(&subscription_repo, now).await;
+        add_delivery(&delivery_repo, &subscription, now).await;
+        let deactivated = subscription
+            .updated(
+                WebhookSubscriptionChanges {
+                    is_active: Some(false),
+                    ..Default::default()
+                },
+                now,
+            )
+            .unwrap();
+        subscription_repo.update(&deactivated).await.unwrap();
+        let sut = make_worker(&delivery_repo, &subscription_repo, &sender, now);
+
+        // Act
+        sut.deliver_due().await.unwrap();
+
+        // Assert
+        assert!(sender.requests().is_empty());
+        let delivery = &delivery_repo.deliveries()[0];
+        assert_eq!(delivery.attempts(), 1);
+        assert_eq!(delivery.last_error(), Some("Webhook が無効化されています"));
+    }
+}
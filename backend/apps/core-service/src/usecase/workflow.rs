@@ -3,6 +3,7 @@
 //! ワークフローの作成・取得・申請に関するビジネスロジックを実装する。
 
 mod command;
+mod draft_purge;
 mod query;
 
 use std::{collections::HashMap, sync::Arc};
@@ -10,24 +11,40 @@ use std::{collections::HashMap, sync::Arc};
 use itertools::Itertools;
 use ringiflow_domain::{
     clock::Clock,
+    message::Message,
     user::UserId,
-    value_objects::Version,
-    workflow::{WorkflowDefinitionId, WorkflowInstance, WorkflowStep},
+    value_objects::{DisplayNumber, Version},
+    workflow::{
+        AdminActionReason,
+        WorkflowCommentId,
+        WorkflowDefinitionId,
+        WorkflowInstance,
+        WorkflowStep,
+    },
 };
 use ringiflow_infra::{
     TransactionManager,
     repository::{
         DisplayIdCounterRepository,
+        DocumentRepository,
         UserRepository,
+        WorkflowActivityRepository,
         WorkflowCommentRepository,
         WorkflowDefinitionRepository,
+        WorkflowEventOutboxRepository,
+        WorkflowFormDataChangeRepository,
         WorkflowInstanceRepository,
+        WorkflowProxyGrantRepository,
         WorkflowStepRepository,
+        WorkflowSubmissionRepository,
+        WorkflowWatcherRepository,
     },
+    s3::S3Client,
 };
-use serde_json::Value as JsonValue;
+use serde_json::{Map as JsonMap, Value as JsonValue};
 
-use crate::{error::CoreError, usecase::notification::NotificationService};
+pub use self::draft_purge::DraftPurgeWorker;
+use crate::error::CoreError;
 
 /// ユースケースの出力: ワークフローインスタンスとステップの集約
 ///
@@ -49,6 +66,15 @@ pub struct CreateWorkflowInput {
     pub title:         String,
     /// フォームデータ
     pub form_data:     JsonValue,
+    /// 代理作成時の本人（申請者）。指定時は操作ユーザーが代理人になる
+    pub on_behalf_of:  Option<UserId>,
+}
+
+/// ワークフロー複製入力
+#[derive(Debug, Clone)]
+pub struct DuplicateWorkflowInput {
+    /// 添付ファイルも複製するか
+    pub copy_attachments: bool,
 }
 
 /// 承認ステップごとの承認者指定
@@ -71,11 +97,86 @@ pub struct SubmitWorkflowInput {
 #[derive(Debug, Clone)]
 pub struct ApproveRejectInput {
     /// 楽観的ロック用バージョン
-    pub version: Version,
+    pub version:         Version,
     /// コメント（任意）
+    pub comment:         Option<String>,
+    /// 承認者によるフォームデータ編集（フィールド ID → 変更後の値）
+    ///
+    /// 承認時のみ指定可能。編集できるフィールドは定義 JSON の
+    /// 承認ステップの `editableFields` で宣言されたものに限る。
+    pub form_data_edits: Option<JsonMap<String, JsonValue>>,
+}
+
+/// 承認取り消し入力
+#[derive(Debug, Clone)]
+pub struct RetractApprovalInput {
+    /// 楽観的ロック用バージョン
+    pub version: Version,
+}
+
+/// 一括判断の操作種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkDecisionAction {
+    /// 承認
+    Approve,
+    /// 却下
+    Reject,
+}
+
+/// 一括判断の対象ステップ
+#[derive(Debug, Clone)]
+pub struct BulkDecisionItem {
+    /// ワークフローインスタンスの表示用連番
+    pub workflow_display_number: DisplayNumber,
+    /// ステップの表示用連番
+    pub step_display_number: DisplayNumber,
+    /// 楽観的ロック用バージョン（項目ごとに検証する）
+    pub version: Version,
+}
+
+/// ステップ一括承認/却下入力
+#[derive(Debug, Clone)]
+pub struct BulkDecisionInput {
+    /// 操作種別（全項目共通）
+    pub action:  BulkDecisionAction,
+    /// 対象ステップ
+    pub items:   Vec<BulkDecisionItem>,
+    /// コメント（任意、全項目共通）
     pub comment: Option<String>,
 }
 
+/// 一括判断の項目ごとの結果種別
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkDecisionOutcome {
+    /// 承認/却下できた
+    Succeeded,
+    /// 楽観的ロックの競合（他の操作で既に更新されている）
+    Conflict,
+    /// ワークフローまたはステップが見つからない
+    NotFound,
+    /// 担当者ではない
+    Forbidden,
+    /// ステップが判断できない状態（アクティブでない等）
+    Invalid,
+    /// 内部エラー
+    Failed,
+}
+
+/// 一括判断の項目ごとの結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BulkDecisionItemResult {
+    /// ワークフローインスタンスの表示用連番
+    pub workflow_display_number: DisplayNumber,
+    /// ステップの表示用連番
+    pub step_display_number: DisplayNumber,
+    /// 結果種別
+    pub outcome: BulkDecisionOutcome,
+    /// 失敗理由（成功時は `None`）
+    ///
+    /// 表示言語は呼び出し元で決まるため、文言ではなくメッセージコードとパラメータで返す。
+    pub message: Option<Message>,
+}
+
 /// ワークフロー再申請入力
 #[derive(Debug, Clone)]
 pub struct ResubmitWorkflowInput {
@@ -87,24 +188,57 @@ pub struct ResubmitWorkflowInput {
     pub version:   Version,
 }
 
+/// テナント管理者による強制操作（取消・強制完了）の入力
+#[derive(Debug, Clone)]
+pub struct AdminActionInput {
+    /// 楽観的ロック用バージョン（インスタンスのバージョン）
+    pub version: Version,
+    /// 操作理由
+    pub reason:  AdminActionReason,
+}
+
+/// テナント管理者による現在のステップの担当者変更入力
+#[derive(Debug, Clone)]
+pub struct ReassignStepInput {
+    /// 楽観的ロック用バージョン（インスタンスのバージョン）
+    pub version:     Version,
+    /// 変更後の担当者
+    pub assigned_to: UserId,
+    /// 操作理由
+    pub reason:      AdminActionReason,
+}
+
 /// WorkflowInstance + Steps からユーザー ID を収集する
 ///
-/// ワークフローの initiated_by と各ステップの assigned_to を
+/// ワークフローの initiated_by・submitted_by と各ステップの assigned_to を
 /// 重複排除して返す。ユーザー名一括解決の前処理として使用する。
 pub(crate) fn collect_user_ids_from_workflow(
     instance: &WorkflowInstance,
     steps: &[WorkflowStep],
 ) -> Vec<UserId> {
-    std::iter::once(instance.initiated_by().clone())
-        .chain(steps.iter().filter_map(|s| s.assigned_to().cloned()))
-        .unique()
-        .collect()
+    [
+        instance.initiated_by().clone(),
+        instance.submitted_by().clone(),
+    ]
+    .into_iter()
+    .chain(steps.iter().filter_map(|s| s.assigned_to().cloned()))
+    .unique()
+    .collect()
 }
 
 /// コメント投稿入力
 #[derive(Debug, Clone)]
 pub struct PostCommentInput {
     /// コメント本文
+    pub body:      String,
+    /// 返信先の親コメント（トップレベルのコメントは `None`）
+    pub parent_id: Option<WorkflowCommentId>,
+}
+
+/// コメント編集入力
+#[derive(Debug, Clone)]
+pub struct EditCommentInput {
+    /// 編集後のコメント本文
     pub body: String,
 }
 
@@ -114,11 +248,18 @@ pub(crate) struct WorkflowUseCaseDeps {
     pub instance_repo: Arc<dyn WorkflowInstanceRepository>,
     pub step_repo: Arc<dyn WorkflowStepRepository>,
     pub comment_repo: Arc<dyn WorkflowCommentRepository>,
+    pub form_data_change_repo: Arc<dyn WorkflowFormDataChangeRepository>,
+    pub submission_repo: Arc<dyn WorkflowSubmissionRepository>,
+    pub watcher_repo: Arc<dyn WorkflowWatcherRepository>,
+    pub proxy_grant_repo: Arc<dyn WorkflowProxyGrantRepository>,
+    pub document_repo: Arc<dyn DocumentRepository>,
+    pub activity_repo: Arc<dyn WorkflowActivityRepository>,
+    pub outbox_repo: Arc<dyn WorkflowEventOutboxRepository>,
     pub user_repo: Arc<dyn UserRepository>,
     pub counter_repo: Arc<dyn DisplayIdCounterRepository>,
+    pub s3_client: Arc<dyn S3Client>,
     pub clock: Arc<dyn Clock>,
     pub tx_manager: Arc<dyn TransactionManager>,
-    pub notification_service: Arc<NotificationService>,
 }
 
 /// ワークフローユースケース実装
@@ -1,9 +1,11 @@
 //! ワークフローユースケースの状態変更操作
 
+mod admin;
 mod comment;
 mod decision;
 mod helpers;
 mod lifecycle;
+mod watch;
 
 #[cfg(test)]
 pub(super) mod test_helpers {
@@ -28,20 +30,23 @@ pub(super) mod test_helpers {
     };
     use ringiflow_infra::fake::{
         FakeDisplayIdCounterRepository,
-        FakeNotificationLogRepository,
-        FakeNotificationSender,
+        FakeDocumentRepository,
+        FakeS3Client,
         FakeTransactionManager,
         FakeUserRepository,
+        FakeWorkflowActivityRepository,
         FakeWorkflowCommentRepository,
         FakeWorkflowDefinitionRepository,
+        FakeWorkflowEventOutboxRepository,
+        FakeWorkflowFormDataChangeRepository,
         FakeWorkflowInstanceRepository,
+        FakeWorkflowProxyGrantRepository,
         FakeWorkflowStepRepository,
+        FakeWorkflowSubmissionRepository,
+        FakeWorkflowWatcherRepository,
     };
 
-    use crate::usecase::{
-        notification::{NotificationService, TemplateRenderer},
-        workflow::{WorkflowUseCaseDeps, WorkflowUseCaseImpl},
-    };
+    use crate::usecase::workflow::{WorkflowUseCaseDeps, WorkflowUseCaseImpl};
 
     /// SUT（WorkflowUseCaseImpl）を構築する
     ///
@@ -53,55 +58,87 @@ pub(super) mod test_helpers {
         step_repo: &FakeWorkflowStepRepository,
         now: chrono::DateTime<chrono::Utc>,
     ) -> WorkflowUseCaseImpl {
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(FakeNotificationSender::new()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
         WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
             definition_repo: Arc::new(definition_repo.clone()),
             instance_repo: Arc::new(instance_repo.clone()),
             step_repo: Arc::new(step_repo.clone()),
             comment_repo: Arc::new(FakeWorkflowCommentRepository::new()),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
             user_repo: Arc::new(FakeUserRepository::new()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
             clock: Arc::new(FixedClock::new(now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         })
     }
 
-    /// SUT を構築する（通知検証用）
+    /// SUT を構築する（ドメインイベント検証用）
+    ///
+    /// `FakeWorkflowEventOutboxRepository` を返すため、テスト側で `events()` を確認できる。
+    pub fn build_sut_with_outbox(
+        definition_repo: &FakeWorkflowDefinitionRepository,
+        instance_repo: &FakeWorkflowInstanceRepository,
+        step_repo: &FakeWorkflowStepRepository,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> (WorkflowUseCaseImpl, FakeWorkflowEventOutboxRepository) {
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
+        let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
+            definition_repo: Arc::new(definition_repo.clone()),
+            instance_repo: Arc::new(instance_repo.clone()),
+            step_repo: Arc::new(step_repo.clone()),
+            comment_repo: Arc::new(FakeWorkflowCommentRepository::new()),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(outbox_repo.clone()),
+            user_repo: Arc::new(FakeUserRepository::new()),
+            counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
+            clock: Arc::new(FixedClock::new(now)),
+            tx_manager: Arc::new(FakeTransactionManager),
+        });
+        (sut, outbox_repo)
+    }
+
+    /// SUT を構築する（ユーザーの存在確認を伴う操作の検証用）
     ///
-    /// `FakeNotificationSender` を返すため、テスト側で `sent_emails()` を確認できる。
-    /// ユーザー情報を返す Fake を使用するため、通知のユーザー情報取得が成功する。
-    pub fn build_sut_with_notification(
+    /// `build_sut_with_outbox` と同じく `FakeWorkflowEventOutboxRepository` も返す。
+    pub fn build_sut_with_user_repo(
         definition_repo: &FakeWorkflowDefinitionRepository,
         instance_repo: &FakeWorkflowInstanceRepository,
         step_repo: &FakeWorkflowStepRepository,
-        user_repo: Arc<dyn ringiflow_infra::repository::UserRepository>,
+        user_repo: &FakeUserRepository,
         now: chrono::DateTime<chrono::Utc>,
-    ) -> (WorkflowUseCaseImpl, FakeNotificationSender) {
-        let sender = FakeNotificationSender::new();
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(sender.clone()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
+    ) -> (WorkflowUseCaseImpl, FakeWorkflowEventOutboxRepository) {
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
         let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
             definition_repo: Arc::new(definition_repo.clone()),
             instance_repo: Arc::new(instance_repo.clone()),
             step_repo: Arc::new(step_repo.clone()),
             comment_repo: Arc::new(FakeWorkflowCommentRepository::new()),
-            user_repo,
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(outbox_repo.clone()),
+            user_repo: Arc::new(user_repo.clone()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
             clock: Arc::new(FixedClock::new(now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         });
-        (sut, sender)
+        (sut, outbox_repo)
     }
 
     /// テスト用の1段階承認定義 JSON
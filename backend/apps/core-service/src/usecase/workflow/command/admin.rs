@@ -0,0 +1,659 @@
+//! テナント管理者によるワークフローの強制操作
+//!
+//! 通常のフロー（担当者による承認・却下）の外でワークフローを取消・担当者変更・
+//! 強制完了する。権限チェック（`workflow:admin`）と監査ログの記録は BFF が担う。
+//!
+//! いずれの操作も理由を必須とし、アクティビティの details に記録する。
+//! 楽観的ロックは管理画面で取得したインスタンスのバージョンで検証する。
+
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    message::Message,
+    tenant::TenantId,
+    user::UserId,
+    value_objects::{DisplayNumber, Version},
+    workflow::{
+        NewWorkflowActivity,
+        WorkflowActivity,
+        WorkflowActivityId,
+        WorkflowActivityType,
+        WorkflowEventType,
+        WorkflowInstance,
+        WorkflowInstanceStatus,
+        WorkflowStep,
+        WorkflowStepStatus,
+    },
+};
+use ringiflow_shared::{event_log::event, log_business_event};
+use serde_json::json;
+
+use super::helpers::{instance_activity, workflow_event};
+use crate::{
+    error::CoreError,
+    usecase::{
+        helpers::FindResultExt,
+        workflow::{AdminActionInput, ReassignStepInput, WorkflowUseCaseImpl, WorkflowWithSteps},
+    },
+};
+
+impl WorkflowUseCaseImpl {
+    /// ワークフローを強制的に取り消す
+    ///
+    /// 完了済み（承認・却下・取消）以外のワークフローを取り消す。
+    /// 未完了のステップはすべて Skipped に遷移させる。
+    /// 関与者に取消を知らせるため `Cancelled` ドメインイベントを発行する。
+    ///
+    /// ## エラー
+    ///
+    /// - `NotFound`: ワークフローが存在しない
+    /// - `BadRequest`: 完了済みのワークフロー
+    /// - `Conflict`: バージョン不一致
+    pub async fn force_cancel_workflow(
+        &self,
+        input: AdminActionInput,
+        workflow_display_number: DisplayNumber,
+        tenant_id: TenantId,
+        user_id: UserId,
+    ) -> Result<WorkflowWithSteps, CoreError> {
+        let instance = self
+            .find_instance_for_admin(workflow_display_number, input.version, &tenant_id)
+            .await?;
+
+        let now = self.deps.clock.now();
+        let instance_expected_version = instance.version();
+        let cancelled_instance = instance
+            .cancelled(now)
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        let steps = self
+            .fetch_instance_steps(cancelled_instance.id(), &tenant_id)
+            .await?;
+        let active_step = steps
+            .iter()
+            .find(|s| s.status() == WorkflowStepStatus::Active)
+            .cloned();
+        let aborted_steps = abort_open_steps(steps, now)?;
+
+        let activity = instance_activity(
+            &cancelled_instance,
+            WorkflowActivityType::Cancelled,
+            &user_id,
+            json!({ "reason": input.reason.as_str() }),
+            now,
+        );
+        let domain_event = workflow_event(
+            &cancelled_instance,
+            WorkflowEventType::Cancelled,
+            &user_id,
+            active_step.as_ref(),
+            None,
+            now,
+        );
+
+        let mut tx = self.begin_tx().await?;
+        for (step, expected_version) in &aborted_steps {
+            self.save_step(&mut tx, step, *expected_version, &tenant_id)
+                .await?;
+        }
+        self.save_instance(
+            &mut tx,
+            &cancelled_instance,
+            instance_expected_version,
+            &tenant_id,
+        )
+        .await?;
+        self.save_activities(&mut tx, &[activity], &tenant_id)
+            .await?;
+        self.save_event(&mut tx, &domain_event, &tenant_id).await?;
+        self.commit_tx(tx).await?;
+
+        let steps = self
+            .fetch_instance_steps(cancelled_instance.id(), &tenant_id)
+            .await?;
+
+        log_business_event!(
+            event.category = event::category::WORKFLOW,
+            event.action = event::action::WORKFLOW_FORCE_CANCELLED,
+            event.entity_type = event::entity_type::WORKFLOW_INSTANCE,
+            event.entity_id = %cancelled_instance.id(),
+            event.actor_id = %user_id,
+            event.tenant_id = %tenant_id,
+            event.result = event::result::SUCCESS,
+            "ワークフロー強制取消"
+        );
+
+        Ok(WorkflowWithSteps {
+            instance: cancelled_instance,
+            steps,
+        })
+    }
+
+    /// 現在のステップの担当者を変更する
+    ///
+    /// 処理中のワークフローのアクティブなステップの担当者を差し替える。
+    /// ステップの version が変わるため、変更前の担当者による承認・却下は競合になる。
+    /// 変更後の担当者に承認依頼を届けるため `StepReassigned` ドメインイベントを発行する。
+    ///
+    /// ## エラー
+    ///
+    /// - `NotFound`: ワークフローが存在しない
+    /// - `BadRequest`: 処理中でない、変更後の担当者がテナントの有効なユーザーでない、
+    ///   現在の担当者と同じ
+    /// - `Conflict`: バージョン不一致
+    pub async fn reassign_current_step(
+        &self,
+        input: ReassignStepInput,
+        workflow_display_number: DisplayNumber,
+        tenant_id: TenantId,
+        user_id: UserId,
+    ) -> Result<WorkflowWithSteps, CoreError> {
+        let instance = self
+            .find_instance_for_admin(workflow_display_number, input.version, &tenant_id)
+            .await?;
+        if instance.status() != WorkflowInstanceStatus::InProgress {
+            return Err(CoreError::BadRequest(
+                Message::new("reassign-not-in-progress").with("status", instance.status()),
+            ));
+        }
+
+        let assignee = self
+            .deps
+            .user_repo
+            .find_by_id(&input.assigned_to)
+            .await
+            .map_err(|e| CoreError::Internal(format!("ユーザーの取得に失敗: {}", e)))?;
+        if !assignee.is_some_and(|u| u.tenant_id() == &tenant_id && u.is_active()) {
+            return Err(CoreError::BadRequest(Message::new("reassign-invalid-user")));
+        }
+
+        let active_step = self
+            .fetch_instance_steps(instance.id(), &tenant_id)
+            .await?
+            .into_iter()
+            .find(|s| s.status() == WorkflowStepStatus::Active)
+            .ok_or_else(|| CoreError::BadRequest(Message::new("no-active-step")))?;
+
+        let now = self.deps.clock.now();
+        let step_expected_version = active_step.version();
+        let previous_step = active_step.clone();
+        let reassigned_step = active_step
+            .reassigned(input.assigned_to.clone(), now)
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        let activity = WorkflowActivity::new(NewWorkflowActivity {
+            id: WorkflowActivityId::new(),
+            tenant_id: tenant_id.clone(),
+            instance_id: instance.id().clone(),
+            activity_type: WorkflowActivityType::Reassigned,
+            actor_id: Some(user_id.clone()),
+            step_id: Some(reassigned_step.id().clone()),
+            details: json!({
+                "step_name": reassigned_step.step_name(),
+                "from": previous_step.assigned_to().map(|id| id.to_string()),
+                "to": input.assigned_to.to_string(),
+                "reason": input.reason.as_str(),
+            }),
+            now,
+        });
+        let domain_event = workflow_event(
+            &instance,
+            WorkflowEventType::StepReassigned,
+            &user_id,
+            Some(&previous_step),
+            Some(&reassigned_step),
+            now,
+        );
+
+        let mut tx = self.begin_tx().await?;
+        self.save_step(&mut tx, &reassigned_step, step_expected_version, &tenant_id)
+            .await?;
+        self.save_activities(&mut tx, &[activity], &tenant_id)
+            .await?;
+        self.save_event(&mut tx, &domain_event, &tenant_id).await?;
+        self.commit_tx(tx).await?;
+
+        let steps = self.fetch_instance_steps(instance.id(), &tenant_id).await?;
+
+        log_business_event!(
+            event.category = event::category::WORKFLOW,
+            event.action = event::action::STEP_REASSIGNED,
+            event.entity_type = event::entity_type::WORKFLOW_STEP,
+            event.entity_id = %reassigned_step.id(),
+            event.actor_id = %user_id,
+            event.tenant_id = %tenant_id,
+            event.result = event::result::SUCCESS,
+            "ステップ担当者変更"
+        );
+
+        Ok(WorkflowWithSteps { instance, steps })
+    }
+
+    /// ワークフローを強制的に承認完了にする
+    ///
+    /// 処理中のワークフローを、残りのステップの判断を待たずに承認完了にする。
+    /// 未完了のステップはすべて Skipped に遷移させる。
+    /// 通常の最終承認と同じく `Approved` ドメインイベントを発行する。
+    ///
+    /// ## エラー
+    ///
+    /// - `NotFound`: ワークフローが存在しない
+    /// - `BadRequest`: 処理中でない
+    /// - `Conflict`: バージョン不一致
+    pub async fn force_complete_workflow(
+        &self,
+        input: AdminActionInput,
+        workflow_display_number: DisplayNumber,
+        tenant_id: TenantId,
+        user_id: UserId,
+    ) -> Result<WorkflowWithSteps, CoreError> {
+        let instance = self
+            .find_instance_for_admin(workflow_display_number, input.version, &tenant_id)
+            .await?;
+
+        let now = self.deps.clock.now();
+        let instance_expected_version = instance.version();
+        let completed_instance = instance
+            .complete_with_approval(now)
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        let steps = self
+            .fetch_instance_steps(completed_instance.id(), &tenant_id)
+            .await?;
+        let aborted_steps = abort_open_steps(steps, now)?;
+
+        let activity = instance_activity(
+            &completed_instance,
+            WorkflowActivityType::ForceCompleted,
+            &user_id,
+            json!({ "reason": input.reason.as_str() }),
+            now,
+        );
+        let domain_event = workflow_event(
+            &completed_instance,
+            WorkflowEventType::Approved,
+            &user_id,
+            None,
+            None,
+            now,
+        );
+
+        let mut tx = self.begin_tx().await?;
+        for (step, expected_version) in &aborted_steps {
+            self.save_step(&mut tx, step, *expected_version, &tenant_id)
+                .await?;
+        }
+        self.save_instance(
+            &mut tx,
+            &completed_instance,
+            instance_expected_version,
+            &tenant_id,
+        )
+        .await?;
+        self.save_activities(&mut tx, &[activity], &tenant_id)
+            .await?;
+        self.save_event(&mut tx, &domain_event, &tenant_id).await?;
+        self.commit_tx(tx).await?;
+
+        let steps = self
+            .fetch_instance_steps(completed_instance.id(), &tenant_id)
+            .await?;
+
+        log_business_event!(
+            event.category = event::category::WORKFLOW,
+            event.action = event::action::WORKFLOW_FORCE_COMPLETED,
+            event.entity_type = event::entity_type::WORKFLOW_INSTANCE,
+            event.entity_id = %completed_instance.id(),
+            event.actor_id = %user_id,
+            event.tenant_id = %tenant_id,
+            event.result = event::result::SUCCESS,
+            "ワークフロー強制完了"
+        );
+
+        Ok(WorkflowWithSteps {
+            instance: completed_instance,
+            steps,
+        })
+    }
+
+    /// 強制操作の対象インスタンスを取得し、バージョンを検証する
+    async fn find_instance_for_admin(
+        &self,
+        workflow_display_number: DisplayNumber,
+        expected_version: Version,
+        tenant_id: &TenantId,
+    ) -> Result<WorkflowInstance, CoreError> {
+        let instance = self
+            .deps
+            .instance_repo
+            .find_by_display_number(workflow_display_number, tenant_id)
+            .await
+            .or_not_found("workflow-instance")?;
+
+        // 楽観的ロック（バージョン一致チェック — 早期フェイル）
+        if instance.version() != expected_version {
+            return Err(CoreError::Conflict(Message::new(
+                "instance-version-conflict",
+            )));
+        }
+        Ok(instance)
+    }
+}
+
+/// 未完了（Pending / Active）のステップを打ち切る
+///
+/// 戻り値は打ち切り後のステップと、保存時の version check に使う変更前のバージョン。
+fn abort_open_steps(
+    steps: Vec<WorkflowStep>,
+    now: DateTime<Utc>,
+) -> Result<Vec<(WorkflowStep, Version)>, CoreError> {
+    steps
+        .into_iter()
+        .filter(|s| {
+            matches!(
+                s.status(),
+                WorkflowStepStatus::Pending | WorkflowStepStatus::Active
+            )
+        })
+        .map(|step| {
+            let version = step.version();
+            step.aborted(now)
+                .map(|aborted| (aborted, version))
+                .map_err(|e| CoreError::Internal(format!("ステップの打ち切りに失敗: {}", e)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ringiflow_domain::{
+        tenant::TenantId,
+        user::{Email, User, UserId},
+        value_objects::{DisplayNumber, UserName},
+        workflow::{
+            AdminActionReason,
+            WorkflowEventType,
+            WorkflowInstance,
+            WorkflowInstanceStatus,
+            WorkflowStepStatus,
+        },
+    };
+    use ringiflow_infra::{
+        fake::{
+            FakeUserRepository,
+            FakeWorkflowDefinitionRepository,
+            FakeWorkflowInstanceRepository,
+            FakeWorkflowStepRepository,
+        },
+        repository::{WorkflowInstanceRepositoryTestExt, WorkflowStepRepositoryTestExt},
+    };
+
+    use super::super::test_helpers::{
+        build_sut,
+        build_sut_with_outbox,
+        build_sut_with_user_repo,
+        setup_two_step_approval,
+    };
+    use crate::{
+        error::CoreError,
+        usecase::workflow::{AdminActionInput, ReassignStepInput},
+    };
+
+    /// 2段階承認の 1 段階目がアクティブなワークフローを登録する
+    async fn setup_in_progress(
+        tenant_id: &TenantId,
+        instance_repo: &FakeWorkflowInstanceRepository,
+        step_repo: &FakeWorkflowStepRepository,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> WorkflowInstance {
+        let (_definition, instance, step1, step2) = setup_two_step_approval(
+            tenant_id,
+            &UserId::new(),
+            &UserId::new(),
+            &UserId::new(),
+            now,
+        );
+        instance_repo.insert_for_test(&instance).await.unwrap();
+        step_repo.insert_for_test(&step1, tenant_id).await.unwrap();
+        step_repo.insert_for_test(&step2, tenant_id).await.unwrap();
+        instance
+    }
+
+    fn reason() -> AdminActionReason {
+        AdminActionReason::new("担当者退職のため").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_強制取消で未完了のステップが打ち切られイベントが発行される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let now = chrono::Utc::now();
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let instance = setup_in_progress(&tenant_id, &instance_repo, &step_repo, now).await;
+        let (sut, outbox) =
+            build_sut_with_outbox(&definition_repo, &instance_repo, &step_repo, now);
+
+        // Act
+        let result = sut
+            .force_cancel_workflow(
+                AdminActionInput {
+                    version: instance.version(),
+                    reason:  reason(),
+                },
+                instance.display_number(),
+                tenant_id.clone(),
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(result.instance.status(), WorkflowInstanceStatus::Cancelled);
+        assert!(
+            result
+                .steps
+                .iter()
+                .all(|s| s.status() == WorkflowStepStatus::Skipped)
+        );
+        let events = outbox.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), WorkflowEventType::Cancelled);
+        let step = events[0].payload().step.as_ref().unwrap();
+        assert_eq!(step.step_name, "上長承認");
+    }
+
+    #[tokio::test]
+    async fn test_バージョン不一致は409() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let now = chrono::Utc::now();
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let instance = setup_in_progress(&tenant_id, &instance_repo, &step_repo, now).await;
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
+
+        // Act
+        let result = sut
+            .force_cancel_workflow(
+                AdminActionInput {
+                    version: instance.version().next(),
+                    reason:  reason(),
+                },
+                instance.display_number(),
+                tenant_id.clone(),
+                UserId::new(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_担当者変更でアクティブなステップの担当者が変わりイベントが発行される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let now = chrono::Utc::now();
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let instance = setup_in_progress(&tenant_id, &instance_repo, &step_repo, now).await;
+        let user_repo = FakeUserRepository::new();
+        let new_assignee = User::new(
+            UserId::new(),
+            tenant_id.clone(),
+            DisplayNumber::new(10).unwrap(),
+            Email::new("new-approver@example.com").unwrap(),
+            UserName::new("新担当者").unwrap(),
+            now,
+        );
+        user_repo.add_user(new_assignee.clone());
+        let (sut, outbox) = build_sut_with_user_repo(
+            &definition_repo,
+            &instance_repo,
+            &step_repo,
+            &user_repo,
+            now,
+        );
+
+        // Act
+        let result = sut
+            .reassign_current_step(
+                ReassignStepInput {
+                    version:     instance.version(),
+                    assigned_to: new_assignee.id().clone(),
+                    reason:      reason(),
+                },
+                instance.display_number(),
+                tenant_id.clone(),
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(result.instance.status(), WorkflowInstanceStatus::InProgress);
+        let active = result
+            .steps
+            .iter()
+            .find(|s| s.status() == WorkflowStepStatus::Active)
+            .unwrap();
+        assert_eq!(active.assigned_to(), Some(new_assignee.id()));
+        let events = outbox.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), WorkflowEventType::StepReassigned);
+        let activated_step = events[0].payload().activated_step.as_ref().unwrap();
+        assert_eq!(activated_step.assigned_to.as_ref(), Some(new_assignee.id()));
+    }
+
+    #[tokio::test]
+    async fn test_担当者変更で存在しないユーザーは400() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let now = chrono::Utc::now();
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let instance = setup_in_progress(&tenant_id, &instance_repo, &step_repo, now).await;
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
+
+        // Act
+        let result = sut
+            .reassign_current_step(
+                ReassignStepInput {
+                    version:     instance.version(),
+                    assigned_to: UserId::new(),
+                    reason:      reason(),
+                },
+                instance.display_number(),
+                tenant_id.clone(),
+                UserId::new(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_強制完了で承認完了になりイベントが発行される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let now = chrono::Utc::now();
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let instance = setup_in_progress(&tenant_id, &instance_repo, &step_repo, now).await;
+        let (sut, outbox) =
+            build_sut_with_outbox(&definition_repo, &instance_repo, &step_repo, now);
+
+        // Act
+        let result = sut
+            .force_complete_workflow(
+                AdminActionInput {
+                    version: instance.version(),
+                    reason:  reason(),
+                },
+                instance.display_number(),
+                tenant_id.clone(),
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(result.instance.status(), WorkflowInstanceStatus::Approved);
+        assert!(
+            result
+                .steps
+                .iter()
+                .all(|s| s.status() == WorkflowStepStatus::Skipped)
+        );
+        let events = outbox.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), WorkflowEventType::Approved);
+    }
+
+    #[tokio::test]
+    async fn test_強制完了は完了済みのワークフローでは400() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let now = chrono::Utc::now();
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let instance = setup_in_progress(&tenant_id, &instance_repo, &step_repo, now).await;
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
+        let input = AdminActionInput {
+            version: instance.version(),
+            reason:  reason(),
+        };
+        let cancelled = sut
+            .force_cancel_workflow(
+                input.clone(),
+                instance.display_number(),
+                tenant_id.clone(),
+                UserId::new(),
+            )
+            .await
+            .unwrap();
+
+        // Act
+        let result = sut
+            .force_complete_workflow(
+                AdminActionInput {
+                    version: cancelled.instance.version(),
+                    ..input
+                },
+                instance.display_number(),
+                tenant_id.clone(),
+                UserId::new(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+}
@@ -1,41 +1,64 @@
-//! ワークフローのコラボレーション（コメント投稿）
+//! ワークフローのコラボレーション（コメントの投稿・返信・編集・削除・メンション）
 
 use ringiflow_domain::{
+    message::Message,
     tenant::TenantId,
     user::UserId,
     value_objects::DisplayNumber,
     workflow::{
         CommentBody,
+        CommentMention,
         NewWorkflowComment,
+        NewWorkflowEvent,
+        WorkflowActivityType,
         WorkflowComment,
         WorkflowCommentId,
+        WorkflowCommentRevision,
+        WorkflowEvent,
+        WorkflowEventId,
+        WorkflowEventPayload,
+        WorkflowEventType,
         WorkflowInstance,
+        WorkflowViewer,
+        WorkflowWatcher,
     },
 };
+use ringiflow_infra::TxContext;
 
+use super::helpers::instance_activity;
 use crate::{
     error::CoreError,
     usecase::{
         helpers::FindResultExt,
-        workflow::{PostCommentInput, WorkflowUseCaseImpl},
+        workflow::{EditCommentInput, PostCommentInput, WorkflowUseCaseImpl},
     },
 };
 
 impl WorkflowUseCaseImpl {
     /// ワークフローにコメントを投稿する
     ///
+    /// `input.parent_id` を指定した場合は、そのコメントへの返信として投稿する。
+    ///
     /// ## 処理フロー
     ///
     /// 1. ワークフローインスタンスを取得
     /// 2. 権限チェック（申請者 OR 承認者のみ投稿可能）
     /// 3. コメント本文のバリデーション
-    /// 4. コメントを作成して保存
+    /// 4. 本文中のメンションをテナント内のユーザーに解決
+    /// 5. 返信の場合は親コメントを検証（同じワークフローのトップレベルのコメントのみ）
+    /// 6. コメントを作成して保存
+    /// 7. コメント投稿のアクティビティを記録
+    /// 8. コメント投稿イベントを記録（リアルタイム通知用）
+    /// 9. メンションされたユーザーをウォッチャーに追加し、メンションイベントを記録
+    ///
+    /// 6〜9 は同一トランザクションで保存する。いずれかが失敗した場合はコメントも保存しない。
     ///
     /// ## エラー
     ///
-    /// - インスタンスが見つからない場合: 404
+    /// - インスタンスまたは親コメントが見つからない場合: 404
     /// - 関与者でない場合: 403
-    /// - コメント本文が不正な場合: 400
+    /// - コメント本文が不正、メンション先のユーザーが見つからない、
+    ///   または親コメントに返信できない場合: 400
     /// - データベースエラー
     pub async fn post_comment(
         &self,
@@ -50,42 +73,338 @@ impl WorkflowUseCaseImpl {
             .instance_repo
             .find_by_display_number(display_number, &tenant_id)
             .await
-            .or_not_found("ワークフローインスタンス")?;
+            .or_not_found("workflow-instance")?;
 
         // 2. 権限チェック
         if !self.is_participant(&instance, &user_id, &tenant_id).await? {
-            return Err(CoreError::Forbidden(
-                "このワークフローにコメントする権限がありません".to_string(),
-            ));
+            return Err(CoreError::Forbidden(Message::new("comment-forbidden")));
         }
 
         // 3. コメント本文のバリデーション
         let body =
-            CommentBody::new(input.body).map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            CommentBody::new(input.body).map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        // 4. メンションされたユーザーを解決
+        let mentioned = self
+            .resolve_mentions(&body.mentions(), &tenant_id, &user_id)
+            .await?;
+
+        // 5. 返信先の親コメントを検証
+        if let Some(parent_id) = &input.parent_id {
+            let parent = self
+                .find_instance_comment(&instance, parent_id, &tenant_id)
+                .await?;
+            parent
+                .ensure_repliable()
+                .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+        }
 
-        // 4. コメントを作成して保存
+        // 6. コメントを作成して保存
         let now = self.deps.clock.now();
         let comment = WorkflowComment::new(NewWorkflowComment {
             id: WorkflowCommentId::new(),
             tenant_id: tenant_id.clone(),
             instance_id: instance.id().clone(),
-            posted_by: user_id,
+            parent_id: input.parent_id,
+            posted_by: user_id.clone(),
             body,
             now,
         });
 
+        let mut tx = self.begin_tx().await?;
         self.deps
             .comment_repo
-            .insert(&comment, &tenant_id)
+            .insert(&mut tx, &comment, &tenant_id)
             .await
             .map_err(|e| CoreError::Internal(format!("コメントの保存に失敗: {}", e)))?;
 
+        // 7. アクティビティを記録
+        self.record_comment_activity(
+            &mut tx,
+            &instance,
+            &comment,
+            WorkflowActivityType::CommentPosted,
+            &user_id,
+        )
+        .await?;
+
+        // 8. コメント投稿イベントを記録
+        self.record_comment_posted_event(&mut tx, &instance, &comment, &user_id)
+            .await?;
+
+        // 9. メンションされたユーザーに閲覧権限を付与して通知する
+        self.notify_mentions(&mut tx, &instance, &comment, mentioned, &user_id)
+            .await?;
+        self.commit_tx(tx).await?;
+
         Ok(comment)
     }
 
+    /// 自分が投稿したコメントを編集する
+    ///
+    /// 編集できるのは投稿から一定時間内に限る（[`COMMENT_EDIT_WINDOW_MINUTES`]）。
+    /// 編集前の本文は編集履歴として残す。
+    /// 編集で新たに追加されたメンションのみ通知する（編集前からのメンションは再通知しない）。
+    ///
+    /// ## エラー
+    ///
+    /// - インスタンスまたはコメントが見つからない場合: 404
+    /// - 投稿者本人でない場合: 403
+    /// - 本文が不正、メンション先のユーザーが見つからない、削除済み、
+    ///   または編集期間を過ぎている場合: 400
+    /// - データベースエラー
+    ///
+    /// [`COMMENT_EDIT_WINDOW_MINUTES`]: ringiflow_domain::workflow::COMMENT_EDIT_WINDOW_MINUTES
+    pub async fn edit_comment(
+        &self,
+        input: EditCommentInput,
+        display_number: DisplayNumber,
+        comment_id: WorkflowCommentId,
+        tenant_id: TenantId,
+        user_id: UserId,
+    ) -> Result<WorkflowComment, CoreError> {
+        let instance = self
+            .deps
+            .instance_repo
+            .find_by_display_number(display_number, &tenant_id)
+            .await
+            .or_not_found("workflow-instance")?;
+
+        let comment = self
+            .find_instance_comment(&instance, &comment_id, &tenant_id)
+            .await?;
+
+        if comment.posted_by() != &user_id {
+            return Err(CoreError::Forbidden(Message::new(
+                "comment-edit-not-author",
+            )));
+        }
+
+        let body =
+            CommentBody::new(input.body).map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        let previous_mentions = comment.body().mentions();
+        let added_mentions: Vec<CommentMention> = body
+            .mentions()
+            .into_iter()
+            .filter(|mention| !previous_mentions.contains(mention))
+            .collect();
+        let mentioned = self
+            .resolve_mentions(&added_mentions, &tenant_id, &user_id)
+            .await?;
+
+        let now = self.deps.clock.now();
+        let revision = WorkflowCommentRevision::of(&comment, user_id.clone(), now);
+        let edited = comment
+            .edited(body, now)
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        // 編集履歴・編集後の本文・アクティビティ・メンションは同一トランザクションで保存する
+        let mut tx = self.begin_tx().await?;
+        self.deps
+            .comment_repo
+            .insert_revision(&mut tx, &revision, &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("コメント編集履歴の保存に失敗: {}", e)))?;
+        self.deps
+            .comment_repo
+            .update(&mut tx, &edited, &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("コメントの保存に失敗: {}", e)))?;
+        self.record_comment_activity(
+            &mut tx,
+            &instance,
+            &edited,
+            WorkflowActivityType::CommentEdited,
+            &user_id,
+        )
+        .await?;
+        self.notify_mentions(&mut tx, &instance, &edited, mentioned, &user_id)
+            .await?;
+        self.commit_tx(tx).await?;
+
+        Ok(edited)
+    }
+
+    /// コメントを論理削除する
+    ///
+    /// 投稿者本人またはワークフロー管理者が削除できる。
+    /// 返信のスレッドを保つため、削除後もコメント自体は残る。
+    ///
+    /// ## エラー
+    ///
+    /// - インスタンスまたはコメントが見つからない、または閲覧権限がない場合: 404
+    /// - 投稿者本人でもワークフロー管理者でもない場合: 403
+    /// - 既に削除されている場合: 400
+    /// - データベースエラー
+    pub async fn delete_comment(
+        &self,
+        display_number: DisplayNumber,
+        comment_id: WorkflowCommentId,
+        tenant_id: TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<(), CoreError> {
+        let instance = self
+            .find_visible_by_display_number(display_number, &tenant_id, viewer)
+            .await?;
+
+        let comment = self
+            .find_instance_comment(&instance, &comment_id, &tenant_id)
+            .await?;
+
+        if comment.posted_by() != viewer.user_id() && !viewer.is_workflow_admin() {
+            return Err(CoreError::Forbidden(Message::new(
+                "comment-delete-not-author",
+            )));
+        }
+
+        let now = self.deps.clock.now();
+        let deleted = comment
+            .deleted(viewer.user_id().clone(), now)
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        let mut tx = self.begin_tx().await?;
+        self.deps
+            .comment_repo
+            .update(&mut tx, &deleted, &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("コメントの保存に失敗: {}", e)))?;
+        self.record_comment_activity(
+            &mut tx,
+            &instance,
+            &deleted,
+            WorkflowActivityType::CommentDeleted,
+            viewer.user_id(),
+        )
+        .await?;
+        self.commit_tx(tx).await
+    }
+
+    /// メンションをテナント内のユーザーに解決する
+    ///
+    /// 投稿者本人へのメンションは除く。
+    /// 存在しない（他テナントを含む）ユーザーや、無効化されたユーザーへのメンションは 400 とする。
+    async fn resolve_mentions(
+        &self,
+        mentions: &[CommentMention],
+        tenant_id: &TenantId,
+        author_id: &UserId,
+    ) -> Result<Vec<UserId>, CoreError> {
+        let mut user_ids: Vec<UserId> = Vec::with_capacity(mentions.len());
+        for mention in mentions {
+            let user = match mention {
+                CommentMention::DisplayNumber(number) => {
+                    self.deps
+                        .user_repo
+                        .find_by_display_number(tenant_id, *number)
+                        .await
+                }
+                CommentMention::Email(email) => {
+                    self.deps.user_repo.find_by_email(tenant_id, email).await
+                }
+            }
+            .map_err(|e| CoreError::Internal(format!("ユーザーの取得に失敗: {}", e)))?
+            .filter(|user| user.is_active())
+            .ok_or_else(|| {
+                CoreError::BadRequest(
+                    Message::new("mentioned-user-not-found").with("user", mention),
+                )
+            })?;
+
+            if user.id() != author_id && !user_ids.contains(user.id()) {
+                user_ids.push(user.id().clone());
+            }
+        }
+        Ok(user_ids)
+    }
+
+    /// メンションされたユーザーをウォッチャーに追加し、メンションイベントを記録する
+    ///
+    /// ウォッチャーになることで、機密ワークフローでもメンションされたユーザーが閲覧できる。
+    /// 通知はイベントのコンシューマが送信する。
+    async fn notify_mentions(
+        &self,
+        tx: &mut TxContext,
+        instance: &WorkflowInstance,
+        comment: &WorkflowComment,
+        mentioned: Vec<UserId>,
+        author_id: &UserId,
+    ) -> Result<(), CoreError> {
+        if mentioned.is_empty() {
+            return Ok(());
+        }
+
+        let now = self.deps.clock.now();
+        let tenant_id = instance.tenant_id();
+        let watchers: Vec<WorkflowWatcher> = mentioned
+            .iter()
+            .map(|user_id| WorkflowWatcher::new(instance.id().clone(), user_id.clone(), now))
+            .collect();
+        let event = WorkflowEvent::new(NewWorkflowEvent {
+            id: WorkflowEventId::new(),
+            tenant_id: tenant_id.clone(),
+            instance_id: instance.id().clone(),
+            event_type: WorkflowEventType::Mentioned,
+            actor_id: Some(author_id.clone()),
+            payload: WorkflowEventPayload::mentioned(instance, comment, mentioned),
+            now,
+        });
+
+        self.deps
+            .watcher_repo
+            .insert_all(tx, &watchers, tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("ウォッチャーの保存に失敗: {}", e)))?;
+        self.save_event(tx, &event, tenant_id).await
+    }
+
+    /// コメント投稿イベントを記録する
+    ///
+    /// 関与者へのリアルタイム通知はイベントのコンシューマが行う。
+    async fn record_comment_posted_event(
+        &self,
+        tx: &mut TxContext,
+        instance: &WorkflowInstance,
+        comment: &WorkflowComment,
+        author_id: &UserId,
+    ) -> Result<(), CoreError> {
+        let tenant_id = instance.tenant_id();
+        let event = WorkflowEvent::new(NewWorkflowEvent {
+            id:          WorkflowEventId::new(),
+            tenant_id:   tenant_id.clone(),
+            instance_id: instance.id().clone(),
+            event_type:  WorkflowEventType::CommentPosted,
+            actor_id:    Some(author_id.clone()),
+            payload:     WorkflowEventPayload::comment_posted(instance, comment),
+            now:         self.deps.clock.now(),
+        });
+
+        self.save_event(tx, &event, tenant_id).await
+    }
+
+    /// コメント操作のアクティビティを記録する
+    async fn record_comment_activity(
+        &self,
+        tx: &mut TxContext,
+        instance: &WorkflowInstance,
+        comment: &WorkflowComment,
+        activity_type: WorkflowActivityType,
+        actor_id: &UserId,
+    ) -> Result<(), CoreError> {
+        let activity = instance_activity(
+            instance,
+            activity_type,
+            actor_id,
+            serde_json::json!({ "comment_id": comment.id().to_string() }),
+            self.deps.clock.now(),
+        );
+        self.save_activities(tx, &[activity], instance.tenant_id())
+            .await
+    }
+
     /// ユーザーがワークフローの関与者かチェックする
     ///
-    /// 関与者 = 申請者 OR いずれかのステップの承認者
+    /// 関与者 = 申請者（代理申請の代理人を含む） OR いずれかのステップの承認者
     async fn is_participant(
         &self,
         instance: &WorkflowInstance,
@@ -93,7 +412,7 @@ impl WorkflowUseCaseImpl {
         tenant_id: &TenantId,
     ) -> Result<bool, CoreError> {
         // 申請者チェック
-        if instance.initiated_by() == user_id {
+        if instance.is_applicant(user_id) {
             return Ok(true);
         }
 
@@ -121,7 +440,9 @@ mod tests {
         workflow::{
             NewWorkflowInstance,
             NewWorkflowStep,
+            WorkflowActivityType,
             WorkflowDefinitionId,
+            WorkflowEventType,
             WorkflowInstance,
             WorkflowInstanceId,
             WorkflowStep,
@@ -131,24 +452,32 @@ mod tests {
     use ringiflow_infra::{
         fake::{
             FakeDisplayIdCounterRepository,
-            FakeNotificationLogRepository,
-            FakeNotificationSender,
+            FakeDocumentRepository,
+            FakeS3Client,
             FakeTransactionManager,
             FakeUserRepository,
+            FakeWorkflowActivityRepository,
             FakeWorkflowCommentRepository,
             FakeWorkflowDefinitionRepository,
+            FakeWorkflowEventOutboxRepository,
+            FakeWorkflowFormDataChangeRepository,
             FakeWorkflowInstanceRepository,
+            FakeWorkflowProxyGrantRepository,
             FakeWorkflowStepRepository,
+            FakeWorkflowSubmissionRepository,
+            FakeWorkflowWatcherRepository,
+        },
+        repository::{
+            WorkflowActivityRepository,
+            WorkflowCommentRepository,
+            WorkflowInstanceRepositoryTestExt,
+            WorkflowStepRepositoryTestExt,
         },
-        repository::{WorkflowInstanceRepositoryTestExt, WorkflowStepRepositoryTestExt},
     };
 
     use crate::{
         error::CoreError,
-        usecase::{
-            notification::{NotificationService, TemplateRenderer},
-            workflow::{PostCommentInput, WorkflowUseCaseDeps, WorkflowUseCaseImpl},
-        },
+        usecase::workflow::{PostCommentInput, WorkflowUseCaseDeps, WorkflowUseCaseImpl},
     };
 
     #[tokio::test]
@@ -181,38 +510,133 @@ mod tests {
         .unwrap();
         instance_repo.insert_for_test(&instance).await.unwrap();
 
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(FakeNotificationSender::new()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
-
+        let activity_repo = FakeWorkflowActivityRepository::new();
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
         let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
             definition_repo: Arc::new(definition_repo),
             instance_repo: Arc::new(instance_repo),
             step_repo: Arc::new(step_repo),
             comment_repo: Arc::new(comment_repo),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(activity_repo.clone()),
+            outbox_repo: Arc::new(outbox_repo.clone()),
             user_repo: Arc::new(FakeUserRepository::new()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
             clock: Arc::new(FixedClock::new(now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         });
 
         let input = PostCommentInput {
-            body: "テストコメント".to_string(),
+            parent_id: None,
+            body:      "テストコメント".to_string(),
         };
 
         // Act
         let result = sut
-            .post_comment(input, DisplayNumber::new(100).unwrap(), tenant_id, user_id)
+            .post_comment(
+                input,
+                DisplayNumber::new(100).unwrap(),
+                tenant_id.clone(),
+                user_id.clone(),
+            )
             .await;
 
         // Assert
         assert!(result.is_ok());
         let comment = result.unwrap();
         assert_eq!(comment.body().as_str(), "テストコメント");
+
+        let activities = activity_repo
+            .find_by_instance(instance.id(), &tenant_id)
+            .await
+            .unwrap();
+        assert_eq!(activities.len(), 1);
+        assert_eq!(
+            activities[0].activity_type(),
+            WorkflowActivityType::CommentPosted
+        );
+        assert_eq!(activities[0].actor_id(), Some(&user_id));
+
+        let events = outbox_repo.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), WorkflowEventType::CommentPosted);
+    }
+
+    #[tokio::test]
+    async fn test_post_comment_イベントの記録に失敗した場合はコメントを保存しない() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let comment_repo = FakeWorkflowCommentRepository::new();
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
+        outbox_repo.fail_insert(true);
+
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: WorkflowDefinitionId::new(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "テスト申請".to_string(),
+            form_data: serde_json::json!({}),
+            initiated_by: user_id.clone(),
+            now,
+        })
+        .submitted(now)
+        .unwrap()
+        .with_current_step("approval".to_string(), now)
+        .unwrap();
+        instance_repo.insert_for_test(&instance).await.unwrap();
+
+        let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
+            definition_repo: Arc::new(FakeWorkflowDefinitionRepository::new()),
+            instance_repo: Arc::new(instance_repo),
+            step_repo: Arc::new(FakeWorkflowStepRepository::new()),
+            comment_repo: Arc::new(comment_repo.clone()),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(outbox_repo),
+            user_repo: Arc::new(FakeUserRepository::new()),
+            counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
+            clock: Arc::new(FixedClock::new(now)),
+            tx_manager: Arc::new(FakeTransactionManager),
+        });
+
+        let input = PostCommentInput {
+            parent_id: None,
+            body:      "保存されないコメント".to_string(),
+        };
+
+        // Act
+        let result = sut
+            .post_comment(
+                input,
+                DisplayNumber::new(100).unwrap(),
+                tenant_id.clone(),
+                user_id,
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::Internal(_))));
+        let comments = comment_repo
+            .find_by_instance(instance.id(), &tenant_id)
+            .await
+            .unwrap();
+        assert!(comments.is_empty());
     }
 
     #[tokio::test]
@@ -260,27 +684,28 @@ mod tests {
         .activated(now);
         step_repo.insert_for_test(&step, &tenant_id).await.unwrap();
 
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(FakeNotificationSender::new()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
-
         let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
             definition_repo: Arc::new(definition_repo),
             instance_repo: Arc::new(instance_repo),
             step_repo: Arc::new(step_repo),
             comment_repo: Arc::new(comment_repo),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
             user_repo: Arc::new(FakeUserRepository::new()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
             clock: Arc::new(FixedClock::new(now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         });
 
         let input = PostCommentInput {
-            body: "承認者のコメント".to_string(),
+            parent_id: None,
+            body:      "承認者のコメント".to_string(),
         };
 
         // Act: 承認者がコメントを投稿
@@ -329,27 +754,28 @@ mod tests {
         .unwrap();
         instance_repo.insert_for_test(&instance).await.unwrap();
 
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(FakeNotificationSender::new()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
-
         let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
             definition_repo: Arc::new(definition_repo),
             instance_repo: Arc::new(instance_repo),
             step_repo: Arc::new(step_repo),
             comment_repo: Arc::new(comment_repo),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
             user_repo: Arc::new(FakeUserRepository::new()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
             clock: Arc::new(FixedClock::new(now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         });
 
         let input = PostCommentInput {
-            body: "無関係なコメント".to_string(),
+            parent_id: None,
+            body:      "無関係なコメント".to_string(),
         };
 
         // Act: 関与していないユーザーがコメントを試みる
@@ -380,27 +806,28 @@ mod tests {
 
         // インスタンスを作成しない
 
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(FakeNotificationSender::new()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
-
         let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
             definition_repo: Arc::new(definition_repo),
             instance_repo: Arc::new(instance_repo),
             step_repo: Arc::new(step_repo),
             comment_repo: Arc::new(comment_repo),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
             user_repo: Arc::new(FakeUserRepository::new()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
             clock: Arc::new(FixedClock::new(now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         });
 
         let input = PostCommentInput {
-            body: "存在しないワークフローへのコメント".to_string(),
+            parent_id: None,
+            body:      "存在しないワークフローへのコメント".to_string(),
         };
 
         // Act
@@ -1,6 +1,8 @@
-//! ワークフローステップの承認判断（承認・却下・差し戻し）
+//! ワークフローステップの承認判断（承認・却下・差し戻し・承認取り消し・一括承認/却下）
 
 mod approve;
+mod bulk;
 mod common;
 mod reject;
 mod request_changes;
+mod retract;
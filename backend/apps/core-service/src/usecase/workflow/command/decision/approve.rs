@@ -1,14 +1,22 @@
 //! ワークフローステップの承認
 
 use ringiflow_domain::{
-    notification::WorkflowNotification,
+    message::Message,
     tenant::TenantId,
     user::UserId,
-    value_objects::{DisplayId, DisplayNumber, display_prefix},
-    workflow::WorkflowStepId,
+    value_objects::DisplayNumber,
+    workflow::{
+        NewWorkflowFormDataChange,
+        WorkflowActivityType,
+        WorkflowEventType,
+        WorkflowFormDataChange,
+        WorkflowFormDataChangeId,
+        WorkflowStepId,
+    },
 };
 use ringiflow_shared::{event_log::event, log_business_event};
 
+use super::super::helpers::{step_activity, workflow_event};
 use crate::{
     error::CoreError,
     usecase::{
@@ -31,16 +39,14 @@ impl WorkflowUseCaseImpl {
             .step_repo
             .find_by_id(&step_id, &tenant_id)
             .await
-            .or_not_found("ステップ")?;
+            .or_not_found("step")?;
 
         // 2. 権限チェック
-        check_step_assigned_to(&step, &user_id, "承認")?;
+        check_step_assigned_to(&step, &user_id, "approve")?;
 
         // 3. 楽観的ロック（バージョン一致チェック — 早期フェイル）
         if step.version() != input.version {
-            return Err(CoreError::Conflict(
-                "ステップは既に更新されています。最新の情報を取得してください。".to_string(),
-            ));
+            return Err(CoreError::Conflict(Message::new("step-version-conflict")));
         }
 
         // 4. ステップを承認
@@ -49,7 +55,7 @@ impl WorkflowUseCaseImpl {
         let current_step_id = step.step_id().to_string();
         let approved_step = step
             .approve(input.comment, now)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         // 5. インスタンスを取得
         let instance = self
@@ -57,7 +63,7 @@ impl WorkflowUseCaseImpl {
             .instance_repo
             .find_by_id(approved_step.instance_id(), &tenant_id)
             .await
-            .or_not_found("インスタンス")?;
+            .or_not_found("workflow-instance")?;
 
         let instance_expected_version = instance.version();
 
@@ -81,22 +87,50 @@ impl WorkflowUseCaseImpl {
 
         let next_step_def = current_index.and_then(|i| approval_step_defs.get(i + 1));
 
-        // 7. 次ステップの有無でインスタンスの遷移を分岐
+        // 7. 承認者によるフォームデータ編集を適用（編集可能フィールドは定義で宣言）
+        let (instance, form_data_changes) = match input.form_data_edits {
+            Some(edits) if !edits.is_empty() => {
+                let editable_fields = current_index
+                    .map(|i| approval_step_defs[i].editable_fields.as_slice())
+                    .unwrap_or_default();
+                let (edited, diffs) = instance
+                    .with_approver_edits(edits, editable_fields, now)
+                    .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+                let changes: Vec<WorkflowFormDataChange> = diffs
+                    .into_iter()
+                    .map(|diff| {
+                        WorkflowFormDataChange::new(NewWorkflowFormDataChange {
+                            id: WorkflowFormDataChangeId::new(),
+                            tenant_id: tenant_id.clone(),
+                            instance_id: edited.id().clone(),
+                            step_id: approved_step.id().clone(),
+                            diff,
+                            changed_by: user_id.clone(),
+                            now,
+                        })
+                    })
+                    .collect();
+                (edited, changes)
+            }
+            _ => (instance, Vec::new()),
+        };
+
+        // 8. 次ステップの有無でインスタンスの遷移を分岐
         let (updated_instance, next_step_to_activate) = if let Some(next_def) = next_step_def {
             // 次ステップあり → current_step_id を更新、InProgress のまま
             let advanced = instance
                 .advance_to_next_step(next_def.id.clone(), now)
-                .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+                .map_err(|e| CoreError::BadRequest(e.into_message()))?;
             (advanced, Some(next_def.id.clone()))
         } else {
             // 最終ステップ → インスタンスを Approved に遷移
             let completed = instance
                 .complete_with_approval(now)
-                .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+                .map_err(|e| CoreError::BadRequest(e.into_message()))?;
             (completed, None)
         };
 
-        // 8. 次ステップがあればデータを準備（トランザクション開始前に読み取り）
+        // 9. 次ステップがあればデータを準備（トランザクション開始前に読み取り）
         let activated_next_step = if let Some(next_step_id) = next_step_to_activate {
             let all_steps = self
                 .fetch_instance_steps(updated_instance.id(), &tenant_id)
@@ -114,7 +148,42 @@ impl WorkflowUseCaseImpl {
             None
         };
 
-        // 9. 全更新を単一トランザクションで実行
+        // 10. アクティビティ（承認・次ステップのアクティブ化）を構築
+        let activities: Vec<_> = std::iter::once(step_activity(
+            &approved_step,
+            &tenant_id,
+            WorkflowActivityType::Approved,
+            &user_id,
+            now,
+        ))
+        .chain(activated_next_step.as_ref().map(|(step, _)| {
+            step_activity(
+                step,
+                &tenant_id,
+                WorkflowActivityType::StepActivated,
+                &user_id,
+                now,
+            )
+        }))
+        .collect();
+
+        // 11. ドメインイベントを構築（次ステップあり → StepApproved、最終ステップ → Approved）
+        let activated_step = activated_next_step.as_ref().map(|(step, _)| step);
+        let event_type = if activated_step.is_some() {
+            WorkflowEventType::StepApproved
+        } else {
+            WorkflowEventType::Approved
+        };
+        let domain_event = workflow_event(
+            &updated_instance,
+            event_type,
+            &user_id,
+            Some(&approved_step),
+            activated_step,
+            now,
+        );
+
+        // 12. 全更新を単一トランザクションで実行
         let mut tx = self.begin_tx().await?;
 
         self.save_step(&mut tx, &approved_step, step_expected_version, &tenant_id)
@@ -133,9 +202,24 @@ impl WorkflowUseCaseImpl {
         )
         .await?;
 
+        if !form_data_changes.is_empty() {
+            self.deps
+                .form_data_change_repo
+                .insert_all(&mut tx, &form_data_changes, &tenant_id)
+                .await
+                .map_err(|e| {
+                    CoreError::Internal(format!("フォームデータ変更履歴の保存に失敗: {}", e))
+                })?;
+        }
+
+        self.save_activities(&mut tx, &activities, &tenant_id)
+            .await?;
+
+        self.save_event(&mut tx, &domain_event, &tenant_id).await?;
+
         self.commit_tx(tx).await?;
 
-        // 10. 保存後のステップ一覧を取得して返却
+        // 13. 保存後のステップ一覧を取得して返却
         let steps = self
             .fetch_instance_steps(updated_instance.id(), &tenant_id)
             .await?;
@@ -151,15 +235,19 @@ impl WorkflowUseCaseImpl {
             "承認ステップ完了"
         );
 
-        // 通知送信（fire-and-forget）
-        self.send_approval_notifications(
-            &updated_instance,
-            &approved_step,
-            activated_next_step.as_ref().map(|(s, _)| s),
-            &steps,
-            &tenant_id,
-        )
-        .await;
+        if !form_data_changes.is_empty() {
+            log_business_event!(
+                event.category = event::category::WORKFLOW,
+                event.action = event::action::FORM_DATA_EDITED,
+                event.entity_type = event::entity_type::WORKFLOW_INSTANCE,
+                event.entity_id = %updated_instance.id(),
+                event.actor_id = %user_id,
+                event.tenant_id = %tenant_id,
+                event.result = event::result::SUCCESS,
+                changed_fields = form_data_changes.len(),
+                "承認者によるフォームデータ編集"
+            );
+        }
 
         Ok(WorkflowWithSteps {
             instance: updated_instance,
@@ -185,7 +273,7 @@ impl WorkflowUseCaseImpl {
             .instance_repo
             .find_by_display_number(workflow_display_number, &tenant_id)
             .await
-            .or_not_found("ワークフローインスタンス")?;
+            .or_not_found("workflow-instance")?;
 
         // display_number → WorkflowStepId を解決
         let step = self
@@ -193,125 +281,37 @@ impl WorkflowUseCaseImpl {
             .step_repo
             .find_by_display_number(step_display_number, instance.id(), &tenant_id)
             .await
-            .or_not_found("ステップ")?;
+            .or_not_found("step")?;
 
         // 既存の approve_step を呼び出し
         self.approve_step(input, step.id().clone(), tenant_id, user_id)
             .await
     }
-
-    /// 承認操作後の通知を送信する（fire-and-forget）
-    ///
-    /// 最終ステップか中間ステップかで送信する通知が異なる:
-    /// - 最終ステップ: `Approved` → 申請者
-    /// - 中間ステップ: `StepApproved` → 申請者 + `ApprovalRequest` → 次の承認者
-    async fn send_approval_notifications(
-        &self,
-        instance: &ringiflow_domain::workflow::WorkflowInstance,
-        approved_step: &ringiflow_domain::workflow::WorkflowStep,
-        activated_next_step: Option<&ringiflow_domain::workflow::WorkflowStep>,
-        all_steps: &[ringiflow_domain::workflow::WorkflowStep],
-        tenant_id: &TenantId,
-    ) {
-        let workflow_display_id =
-            DisplayId::new(display_prefix::WORKFLOW_INSTANCE, instance.display_number())
-                .to_string();
-
-        // 申請者の情報を取得
-        let applicant = match self
-            .deps
-            .user_repo
-            .find_by_id(instance.initiated_by())
-            .await
-        {
-            Ok(Some(user)) => user,
-            Ok(None) => {
-                tracing::warn!(
-                    user_id = %instance.initiated_by(),
-                    "通知用の申請者情報が見つかりません"
-                );
-                return;
-            }
-            Err(e) => {
-                tracing::warn!(
-                    error = %e,
-                    user_id = %instance.initiated_by(),
-                    "通知用の申請者情報の取得に失敗"
-                );
-                return;
-            }
-        };
-
-        if activated_next_step.is_some() {
-            // 中間ステップ: StepApproved → 申請者
-            let step_approved = WorkflowNotification::StepApproved {
-                workflow_title:      instance.title().to_string(),
-                workflow_display_id: workflow_display_id.clone(),
-                step_name:           approved_step.step_name().to_string(),
-                approver_name:       self.resolve_user_name(approved_step.assigned_to()).await,
-                applicant_email:     applicant.email().as_str().to_string(),
-                applicant_user_id:   applicant.id().clone(),
-            };
-            self.deps
-                .notification_service
-                .notify(step_approved, tenant_id, instance.id())
-                .await;
-
-            // ApprovalRequest → 次の承認者（既存ヘルパーを再利用）
-            self.send_approval_request_notification(instance, all_steps, tenant_id)
-                .await;
-        } else {
-            // 最終ステップ: Approved → 申請者
-            let approved = WorkflowNotification::Approved {
-                workflow_title: instance.title().to_string(),
-                workflow_display_id,
-                applicant_email: applicant.email().as_str().to_string(),
-                applicant_user_id: applicant.id().clone(),
-            };
-            self.deps
-                .notification_service
-                .notify(approved, tenant_id, instance.id())
-                .await;
-        }
-    }
-
-    /// ユーザー ID からユーザー名を解決する（通知用）
-    ///
-    /// 取得できない場合は空文字を返す。
-    async fn resolve_user_name(&self, user_id: Option<&UserId>) -> String {
-        let Some(user_id) = user_id else {
-            return String::new();
-        };
-        match self.deps.user_repo.find_by_id(user_id).await {
-            Ok(Some(user)) => user.name().as_str().to_string(),
-            _ => String::new(),
-        }
-    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
-
     use ringiflow_domain::{
         tenant::TenantId,
-        user::{Email, User, UserId},
-        value_objects::{DisplayNumber, UserName, Version, WorkflowName},
+        user::UserId,
+        value_objects::{DisplayNumber, Version, WorkflowName},
         workflow::{
             NewWorkflowDefinition,
             NewWorkflowInstance,
             NewWorkflowStep,
+            WorkflowActivityType,
             WorkflowDefinition,
             WorkflowDefinitionId,
+            WorkflowEventType,
             WorkflowInstance,
             WorkflowInstanceId,
             WorkflowStep,
             WorkflowStepId,
+            WorkflowViewer,
         },
     };
     use ringiflow_infra::{
         fake::{
-            FakeUserRepository,
             FakeWorkflowDefinitionRepository,
             FakeWorkflowInstanceRepository,
             FakeWorkflowStepRepository,
@@ -321,7 +321,7 @@ mod tests {
 
     use super::super::super::test_helpers::{
         build_sut,
-        build_sut_with_notification,
+        build_sut_with_outbox,
         setup_two_step_approval,
         single_approval_definition_json,
     };
@@ -391,8 +391,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("承認しました".to_string()),
+            version:         step.version(),
+            comment:         Some("承認しました".to_string()),
+            form_data_edits: None,
         };
 
         // Act
@@ -414,6 +415,199 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    /// 編集可能フィールド付きの1段階承認定義・InProgress インスタンス・Active ステップを登録する
+    async fn setup_editable_approval(
+        tenant_id: &TenantId,
+        approver_id: &UserId,
+        definition_repo: &FakeWorkflowDefinitionRepository,
+        instance_repo: &FakeWorkflowInstanceRepository,
+        step_repo: &FakeWorkflowStepRepository,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> (WorkflowInstance, WorkflowStep) {
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id: tenant_id.clone(),
+            name: WorkflowName::new("経費申請").unwrap(),
+            description: None,
+            definition: serde_json::json!({
+               "steps": [
+                  {"id": "start", "type": "start", "name": "開始"},
+                  {"id": "approval", "type": "approval", "name": "承認", "editableFields": ["amount"]},
+                  {"id": "end_approved", "type": "end", "name": "承認完了", "status": "approved"},
+                  {"id": "end_rejected", "type": "end", "name": "却下", "status": "rejected"}
+               ]
+            }),
+            created_by: UserId::new(),
+            now,
+        })
+        .published(now)
+        .unwrap();
+        definition_repo.add_definition(definition.clone());
+
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: definition.id().clone(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "テスト申請".to_string(),
+            form_data: serde_json::json!({"amount": 1000, "purpose": "出張"}),
+            initiated_by: UserId::new(),
+            now,
+        })
+        .submitted(now)
+        .unwrap()
+        .with_current_step("approval".to_string(), now)
+        .unwrap();
+        instance_repo.insert_for_test(&instance).await.unwrap();
+
+        let step = WorkflowStep::new(NewWorkflowStep {
+            id: WorkflowStepId::new(),
+            instance_id: instance.id().clone(),
+            display_number: DisplayNumber::new(1).unwrap(),
+            step_id: "approval".to_string(),
+            step_name: "承認".to_string(),
+            step_type: "approval".to_string(),
+            assigned_to: Some(approver_id.clone()),
+            now,
+        })
+        .activated(now);
+        step_repo.insert_for_test(&step, tenant_id).await.unwrap();
+
+        (instance, step)
+    }
+
+    #[tokio::test]
+    async fn test_approve_step_編集可能フィールドを編集すると変更履歴が記録される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let approver_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let (_, step) = setup_editable_approval(
+            &tenant_id,
+            &approver_id,
+            &definition_repo,
+            &instance_repo,
+            &step_repo,
+            now,
+        )
+        .await;
+
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
+
+        let input = ApproveRejectInput {
+            version:         step.version(),
+            comment:         None,
+            form_data_edits: Some(
+                serde_json::json!({"amount": 800})
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+            ),
+        };
+
+        // Act
+        let result = sut
+            .approve_step(
+                input,
+                step.id().clone(),
+                tenant_id.clone(),
+                approver_id.clone(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            result.instance.form_data(),
+            &serde_json::json!({"amount": 800, "purpose": "出張"})
+        );
+        let changes = sut
+            .list_form_data_changes(
+                DisplayNumber::new(100).unwrap(),
+                tenant_id,
+                &WorkflowViewer::new(approver_id.clone(), false),
+            )
+            .await
+            .unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field_id(), "amount");
+        assert_eq!(changes[0].old_value(), &serde_json::json!(1000));
+        assert_eq!(changes[0].new_value(), &serde_json::json!(800));
+        assert_eq!(changes[0].step_id(), step.id());
+        assert_eq!(changes[0].changed_by(), &approver_id);
+        assert_eq!(changes[0].changed_at(), now);
+    }
+
+    #[tokio::test]
+    async fn test_approve_step_編集不可フィールドを編集すると400() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let approver_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let (instance, step) = setup_editable_approval(
+            &tenant_id,
+            &approver_id,
+            &definition_repo,
+            &instance_repo,
+            &step_repo,
+            now,
+        )
+        .await;
+
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
+
+        let input = ApproveRejectInput {
+            version:         step.version(),
+            comment:         None,
+            form_data_edits: Some(
+                serde_json::json!({"purpose": "私用"})
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+            ),
+        };
+
+        // Act
+        let result = sut
+            .approve_step(
+                input,
+                step.id().clone(),
+                tenant_id.clone(),
+                approver_id.clone(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+        let changes = sut
+            .list_form_data_changes(
+                DisplayNumber::new(100).unwrap(),
+                tenant_id.clone(),
+                &WorkflowViewer::new(approver_id.clone(), false),
+            )
+            .await
+            .unwrap();
+        assert!(changes.is_empty());
+        let stored = sut
+            .get_workflow(
+                instance.id().clone(),
+                tenant_id,
+                &WorkflowViewer::new(approver_id.clone(), false),
+            )
+            .await
+            .unwrap();
+        assert_eq!(stored.instance, instance);
+    }
+
     #[tokio::test]
     async fn test_approve_step_未割り当てユーザーは403() {
         // Arrange
@@ -460,8 +654,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: None,
+            version:         step.version(),
+            comment:         None,
+            form_data_edits: None,
         };
 
         // Act: 別のユーザーで承認を試みる
@@ -519,8 +714,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: None,
+            version:         step.version(),
+            comment:         None,
+            form_data_edits: None,
         };
 
         // Act
@@ -579,8 +775,9 @@ mod tests {
         // 不一致バージョンを指定（ステップの version は 1 だが、2 を指定）
         let wrong_version = Version::initial().next();
         let input = ApproveRejectInput {
-            version: wrong_version,
-            comment: None,
+            version:         wrong_version,
+            comment:         None,
+            form_data_edits: None,
         };
 
         // Act
@@ -616,8 +813,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step1.version(),
-            comment: Some("上長承認OK".to_string()),
+            version:         step1.version(),
+            comment:         Some("上長承認OK".to_string()),
+            form_data_edits: None,
         };
 
         // Act
@@ -668,6 +866,77 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_approve_step_承認と次ステップのアクティブ化がアクティビティとして記録される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let approver1_id = UserId::new();
+        let approver2_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let (definition, instance, step1, step2) =
+            setup_two_step_approval(&tenant_id, &user_id, &approver1_id, &approver2_id, now);
+
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+
+        definition_repo.add_definition(definition);
+        instance_repo.insert_for_test(&instance).await.unwrap();
+        step_repo.insert_for_test(&step1, &tenant_id).await.unwrap();
+        step_repo.insert_for_test(&step2, &tenant_id).await.unwrap();
+
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
+
+        let input = ApproveRejectInput {
+            version:         step1.version(),
+            comment:         Some("上長承認OK".to_string()),
+            form_data_edits: None,
+        };
+
+        // Act
+        sut.approve_step(
+            input,
+            step1.id().clone(),
+            tenant_id.clone(),
+            approver1_id.clone(),
+        )
+        .await
+        .unwrap();
+
+        // Assert
+        let activities = sut
+            .list_activities(
+                instance.display_number(),
+                tenant_id,
+                &WorkflowViewer::new(approver1_id.clone(), false),
+            )
+            .await
+            .unwrap();
+        let summary: Vec<_> = activities
+            .iter()
+            .map(|a| (a.activity_type(), a.step_id().cloned()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (WorkflowActivityType::Approved, Some(step1.id().clone())),
+                (
+                    WorkflowActivityType::StepActivated,
+                    Some(step2.id().clone())
+                ),
+            ]
+        );
+        assert!(
+            activities
+                .iter()
+                .all(|a| a.actor_id() == Some(&approver1_id))
+        );
+        assert_eq!(activities[0].details()["comment"], "上長承認OK");
+        assert_eq!(activities[1].details()["step_name"], "経理承認");
+    }
+
     #[tokio::test]
     async fn test_approve_step_最終ステップ_インスタンスがapprovedになる() {
         // Arrange
@@ -709,8 +978,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: active_step2.version(),
-            comment: Some("経理承認OK".to_string()),
+            version:         active_step2.version(),
+            comment:         Some("経理承認OK".to_string()),
+            form_data_edits: None,
         };
 
         // Act
@@ -744,10 +1014,10 @@ mod tests {
         );
     }
 
-    // ===== 通知テスト =====
+    // ===== ドメインイベントテスト =====
 
     #[tokio::test]
-    async fn test_approve_step_最終承認で承認完了通知が申請者に送信される() {
+    async fn test_approve_step_最終承認でapprovedイベントが記録される() {
         // Arrange
         let tenant_id = TenantId::new();
         let user_id = UserId::new(); // 申請者
@@ -802,28 +1072,13 @@ mod tests {
         .activated(now);
         step_repo.insert_for_test(&step, &tenant_id).await.unwrap();
 
-        // ユーザー情報を Fake に登録
-        let user_repo = FakeUserRepository::new();
-        user_repo.add_user(User::new(
-            user_id.clone(),
-            tenant_id.clone(),
-            DisplayNumber::new(1).unwrap(),
-            Email::new("tanaka@example.com").unwrap(),
-            UserName::new("田中太郎").unwrap(),
-            now,
-        ));
-
-        let (sut, sender) = build_sut_with_notification(
-            &definition_repo,
-            &instance_repo,
-            &step_repo,
-            Arc::new(user_repo),
-            now,
-        );
+        let (sut, outbox_repo) =
+            build_sut_with_outbox(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("承認します".to_string()),
+            version:         step.version(),
+            comment:         Some("承認します".to_string()),
+            form_data_edits: None,
         };
 
         // Act
@@ -839,24 +1094,22 @@ mod tests {
         // Assert: ワークフロー操作は成功
         assert!(result.is_ok());
 
-        // Assert: 承認完了通知が申請者に送信されている
-        let sent = sender.sent_emails();
-        assert_eq!(sent.len(), 1, "承認完了メールが1通送信されるべき");
-        assert_eq!(sent[0].to, "tanaka@example.com");
-        assert!(
-            sent[0].subject.contains("承認完了"),
-            "件名に「承認完了」が含まれるべき: {}",
-            sent[0].subject
-        );
-        assert!(
-            sent[0].subject.contains("テスト申請"),
-            "件名にワークフロータイトルが含まれるべき: {}",
-            sent[0].subject
-        );
+        // Assert: Approved イベントが承認者をアクターとして記録されている
+        let events = outbox_repo.events();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.event_type(), WorkflowEventType::Approved);
+        assert_eq!(event.actor_id(), Some(&approver_id));
+        assert_eq!(event.payload().title, "テスト申請");
+        assert_eq!(event.payload().initiated_by, user_id);
+        let approved_step = event.payload().step.as_ref().unwrap();
+        assert_eq!(&approved_step.step_id, step.id());
+        assert_eq!(approved_step.comment.as_deref(), Some("承認します"));
+        assert!(event.payload().activated_step.is_none());
     }
 
     #[tokio::test]
-    async fn test_approve_step_中間ステップで通知2通が送信される() {
+    async fn test_approve_step_中間ステップでstep_approvedイベントが次ステップ付きで記録される() {
         // Arrange
         let tenant_id = TenantId::new();
         let user_id = UserId::new(); // 申請者
@@ -876,44 +1129,13 @@ mod tests {
         step_repo.insert_for_test(&step1, &tenant_id).await.unwrap();
         step_repo.insert_for_test(&step2, &tenant_id).await.unwrap();
 
-        // ユーザー情報を Fake に登録
-        let user_repo = FakeUserRepository::new();
-        user_repo.add_user(User::new(
-            user_id.clone(),
-            tenant_id.clone(),
-            DisplayNumber::new(1).unwrap(),
-            Email::new("tanaka@example.com").unwrap(),
-            UserName::new("田中太郎").unwrap(),
-            now,
-        ));
-        user_repo.add_user(User::new(
-            approver1_id.clone(),
-            tenant_id.clone(),
-            DisplayNumber::new(2).unwrap(),
-            Email::new("suzuki@example.com").unwrap(),
-            UserName::new("鈴木一郎").unwrap(),
-            now,
-        ));
-        user_repo.add_user(User::new(
-            approver2_id.clone(),
-            tenant_id.clone(),
-            DisplayNumber::new(3).unwrap(),
-            Email::new("yamada@example.com").unwrap(),
-            UserName::new("山田花子").unwrap(),
-            now,
-        ));
-
-        let (sut, sender) = build_sut_with_notification(
-            &definition_repo,
-            &instance_repo,
-            &step_repo,
-            Arc::new(user_repo),
-            now,
-        );
+        let (sut, outbox_repo) =
+            build_sut_with_outbox(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step1.version(),
-            comment: Some("上長承認OK".to_string()),
+            version:         step1.version(),
+            comment:         Some("上長承認OK".to_string()),
+            form_data_edits: None,
         };
 
         // Act
@@ -929,124 +1151,17 @@ mod tests {
         // Assert: ワークフロー操作は成功
         assert!(result.is_ok());
 
-        // Assert: 2通の通知が送信されている
-        // 1. StepApproved → 申請者
-        // 2. ApprovalRequest → 次の承認者
-        let sent = sender.sent_emails();
-        assert_eq!(
-            sent.len(),
-            2,
-            "ステップ承認通知と承認依頼通知の計2通が送信されるべき"
-        );
-
-        // 申請者への StepApproved 通知
-        let step_approved_mail = sent
-            .iter()
-            .find(|m| m.to == "tanaka@example.com")
-            .expect("申請者への通知があるべき");
-        assert!(
-            step_approved_mail.subject.contains("ステップ承認"),
-            "件名に「ステップ承認」が含まれるべき: {}",
-            step_approved_mail.subject
-        );
-
-        // 次の承認者への ApprovalRequest 通知
-        let approval_request_mail = sent
-            .iter()
-            .find(|m| m.to == "yamada@example.com")
-            .expect("次の承認者への通知があるべき");
-        assert!(
-            approval_request_mail.subject.contains("承認依頼"),
-            "件名に「承認依頼」が含まれるべき: {}",
-            approval_request_mail.subject
-        );
-    }
-
-    #[tokio::test]
-    async fn test_approve_step_ユーザー情報取得失敗でも承認操作は成功する() {
-        // Arrange
-        let tenant_id = TenantId::new();
-        let user_id = UserId::new();
-        let approver_id = UserId::new();
-        let now = chrono::Utc::now();
-
-        let definition_repo = FakeWorkflowDefinitionRepository::new();
-        let instance_repo = FakeWorkflowInstanceRepository::new();
-        let step_repo = FakeWorkflowStepRepository::new();
-
-        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
-            id: WorkflowDefinitionId::new(),
-            tenant_id: tenant_id.clone(),
-            name: WorkflowName::new("経費精算申請").unwrap(),
-            description: Some("テスト用定義".to_string()),
-            definition: single_approval_definition_json(),
-            created_by: user_id.clone(),
-            now,
-        })
-        .published(now)
-        .unwrap();
-        definition_repo.add_definition(definition.clone());
-
-        let instance = WorkflowInstance::new(NewWorkflowInstance {
-            id: WorkflowInstanceId::new(),
-            tenant_id: tenant_id.clone(),
-            definition_id: definition.id().clone(),
-            definition_version: Version::initial(),
-            display_number: DisplayNumber::new(100).unwrap(),
-            title: "テスト申請".to_string(),
-            form_data: serde_json::json!({}),
-            initiated_by: user_id.clone(),
-            now,
-        })
-        .submitted(now)
-        .unwrap()
-        .with_current_step("approval".to_string(), now)
-        .unwrap();
-        instance_repo.insert_for_test(&instance).await.unwrap();
-
-        let step = WorkflowStep::new(NewWorkflowStep {
-            id: WorkflowStepId::new(),
-            instance_id: instance.id().clone(),
-            display_number: DisplayNumber::new(1).unwrap(),
-            step_id: "approval".to_string(),
-            step_name: "承認".to_string(),
-            step_type: "approval".to_string(),
-            assigned_to: Some(approver_id.clone()),
-            now,
-        })
-        .activated(now);
-        step_repo.insert_for_test(&step, &tenant_id).await.unwrap();
-
-        // ユーザー情報を登録しない（空の FakeUserRepository）
-        let user_repo = FakeUserRepository::new();
-        let (sut, sender) = build_sut_with_notification(
-            &definition_repo,
-            &instance_repo,
-            &step_repo,
-            Arc::new(user_repo),
-            now,
-        );
-
-        let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("承認します".to_string()),
-        };
-
-        // Act
-        let result = sut
-            .approve_step(
-                input,
-                step.id().clone(),
-                tenant_id.clone(),
-                approver_id.clone(),
-            )
-            .await;
-
-        // Assert: ワークフロー操作自体は成功する（fire-and-forget）
-        assert!(result.is_ok());
-
-        // Assert: 通知は送信されない（ユーザー情報がないため）
-        let sent = sender.sent_emails();
-        assert_eq!(sent.len(), 0, "ユーザー情報がない場合、通知は送信されない");
+        // Assert: StepApproved イベントに承認ステップと次にアクティブになったステップが含まれる
+        let events = outbox_repo.events();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.event_type(), WorkflowEventType::StepApproved);
+        assert_eq!(event.actor_id(), Some(&approver1_id));
+        let approved_step = event.payload().step.as_ref().unwrap();
+        assert_eq!(&approved_step.step_id, step1.id());
+        assert_eq!(approved_step.assigned_to.as_ref(), Some(&approver1_id));
+        let activated_step = event.payload().activated_step.as_ref().unwrap();
+        assert_eq!(&activated_step.step_id, step2.id());
+        assert_eq!(activated_step.assigned_to.as_ref(), Some(&approver2_id));
     }
 }
@@ -0,0 +1,444 @@
+//! ワークフローステップの一括承認・却下
+//!
+//! 各項目に `approve_step` / `reject_step` と同じ処理を適用する。
+//! 項目ごとに独立したトランザクションで実行し、楽観的ロックも項目ごとに検証する。
+//! ある項目が競合等で失敗しても残りの項目の処理は継続し、項目ごとの結果を返す。
+
+use ringiflow_domain::{message::Message, tenant::TenantId, user::UserId};
+
+use crate::{
+    error::CoreError,
+    usecase::workflow::{
+        ApproveRejectInput,
+        BulkDecisionAction,
+        BulkDecisionInput,
+        BulkDecisionItemResult,
+        BulkDecisionOutcome,
+        WorkflowUseCaseImpl,
+    },
+};
+
+/// 一度に処理できる項目数の上限
+pub const MAX_BULK_DECISION_ITEMS: usize = 100;
+
+impl WorkflowUseCaseImpl {
+    /// 複数のステップを一括で承認または却下する
+    ///
+    /// 項目は指定順に処理する。項目単位の失敗（競合・未検出・権限なし・状態不正）は
+    /// エラーにせず、結果の `outcome` で返す。
+    ///
+    /// ## エラー
+    ///
+    /// - `BadRequest`: 項目が空、または上限（[`MAX_BULK_DECISION_ITEMS`]）を超える
+    pub async fn bulk_decide_steps(
+        &self,
+        input: BulkDecisionInput,
+        tenant_id: TenantId,
+        user_id: UserId,
+    ) -> Result<Vec<BulkDecisionItemResult>, CoreError> {
+        if input.items.is_empty() {
+            return Err(CoreError::BadRequest(Message::new("bulk-steps-required")));
+        }
+        if input.items.len() > MAX_BULK_DECISION_ITEMS {
+            return Err(CoreError::BadRequest(
+                Message::new("bulk-too-many-steps").with("max", MAX_BULK_DECISION_ITEMS),
+            ));
+        }
+
+        let mut results = Vec::with_capacity(input.items.len());
+        for item in input.items {
+            let decision = ApproveRejectInput {
+                version:         item.version,
+                comment:         input.comment.clone(),
+                form_data_edits: None,
+            };
+            let result = match input.action {
+                BulkDecisionAction::Approve => {
+                    self.approve_step_by_display_number(
+                        decision,
+                        item.workflow_display_number,
+                        item.step_display_number,
+                        tenant_id.clone(),
+                        user_id.clone(),
+                    )
+                    .await
+                }
+                BulkDecisionAction::Reject => {
+                    self.reject_step_by_display_number(
+                        decision,
+                        item.workflow_display_number,
+                        item.step_display_number,
+                        tenant_id.clone(),
+                        user_id.clone(),
+                    )
+                    .await
+                }
+            };
+
+            let (outcome, message) = match result {
+                Ok(_) => (BulkDecisionOutcome::Succeeded, None),
+                Err(CoreError::Conflict(msg)) => (BulkDecisionOutcome::Conflict, Some(msg)),
+                Err(CoreError::NotFound(msg)) => (BulkDecisionOutcome::NotFound, Some(msg)),
+                Err(CoreError::Forbidden(msg)) => (BulkDecisionOutcome::Forbidden, Some(msg)),
+                Err(CoreError::BadRequest(msg)) => (BulkDecisionOutcome::Invalid, Some(msg)),
+                Err(e @ (CoreError::Database(_) | CoreError::Internal(_))) => {
+                    // 先行する項目は既にコミット済みのため、全体を失敗にせず項目の結果として返す
+                    tracing::error!(
+                        workflow_display_number = %item.workflow_display_number,
+                        step_display_number = %item.step_display_number,
+                        "一括判断の項目処理に失敗: {}",
+                        e
+                    );
+                    (
+                        BulkDecisionOutcome::Failed,
+                        Some(Message::new("internal-error")),
+                    )
+                }
+            };
+
+            results.push(BulkDecisionItemResult {
+                workflow_display_number: item.workflow_display_number,
+                step_display_number: item.step_display_number,
+                outcome,
+                message,
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ringiflow_domain::{
+        user::UserId,
+        value_objects::{DisplayNumber, Version, WorkflowName},
+        workflow::{
+            NewWorkflowDefinition,
+            NewWorkflowInstance,
+            NewWorkflowStep,
+            WorkflowDefinition,
+            WorkflowDefinitionId,
+            WorkflowInstanceId,
+            WorkflowInstanceStatus,
+            WorkflowStep,
+            WorkflowStepId,
+            WorkflowStepStatus,
+        },
+    };
+    use ringiflow_infra::repository::{
+        WorkflowInstanceRepository,
+        WorkflowInstanceRepositoryTestExt,
+        WorkflowStepRepository,
+        WorkflowStepRepositoryTestExt,
+    };
+
+    use super::{super::super::test_helpers::single_approval_definition_json, *};
+    use crate::{
+        test_utils::{WorkflowTestBuilder, WorkflowTestSetup},
+        usecase::workflow::{BulkDecisionItem, WorkflowInstance},
+    };
+
+    /// 1段階承認の公開済み定義を登録する
+    fn add_definition(
+        builder: &WorkflowTestBuilder,
+        setup: &WorkflowTestSetup,
+    ) -> WorkflowDefinition {
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id:          WorkflowDefinitionId::new(),
+            tenant_id:   builder.tenant_id().clone(),
+            name:        WorkflowName::new("経費精算").unwrap(),
+            description: None,
+            definition:  single_approval_definition_json(),
+            created_by:  UserId::new(),
+            now:         builder.now(),
+        })
+        .published(builder.now())
+        .unwrap();
+        setup.definition_repo.add_definition(definition.clone());
+        definition
+    }
+
+    /// 承認待ち（1段階承認・Active ステップ）のワークフローを登録する
+    async fn add_pending_approval(
+        builder: &WorkflowTestBuilder,
+        setup: &WorkflowTestSetup,
+        definition: &WorkflowDefinition,
+        display_number: i64,
+        assigned_to: &UserId,
+    ) -> WorkflowStep {
+        let now = builder.now();
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: builder.tenant_id().clone(),
+            definition_id: definition.id().clone(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(display_number).unwrap(),
+            title: format!("経費精算 {}", display_number),
+            form_data: serde_json::json!({}),
+            initiated_by: UserId::new(),
+            now,
+        })
+        .submitted(now)
+        .unwrap()
+        .with_current_step("approval".to_string(), now)
+        .unwrap();
+        setup
+            .instance_repo
+            .insert_for_test(&instance)
+            .await
+            .unwrap();
+
+        let step = WorkflowStep::new(NewWorkflowStep {
+            id: WorkflowStepId::new(),
+            instance_id: instance.id().clone(),
+            display_number: DisplayNumber::new(1).unwrap(),
+            step_id: "approval".to_string(),
+            step_name: "承認".to_string(),
+            step_type: "approval".to_string(),
+            assigned_to: Some(assigned_to.clone()),
+            now,
+        })
+        .activated(now);
+        setup
+            .step_repo
+            .insert_for_test(&step, builder.tenant_id())
+            .await
+            .unwrap();
+        step
+    }
+
+    async fn instance_status(
+        builder: &WorkflowTestBuilder,
+        setup: &WorkflowTestSetup,
+        display_number: i64,
+    ) -> WorkflowInstanceStatus {
+        setup
+            .instance_repo
+            .find_by_display_number(
+                DisplayNumber::new(display_number).unwrap(),
+                builder.tenant_id(),
+            )
+            .await
+            .unwrap()
+            .unwrap()
+            .status()
+    }
+
+    async fn step_status(
+        builder: &WorkflowTestBuilder,
+        setup: &WorkflowTestSetup,
+        step: &WorkflowStep,
+    ) -> WorkflowStepStatus {
+        setup
+            .step_repo
+            .find_by_id(step.id(), builder.tenant_id())
+            .await
+            .unwrap()
+            .unwrap()
+            .status()
+    }
+
+    fn item(workflow_display_number: i64, version: Version) -> BulkDecisionItem {
+        BulkDecisionItem {
+            workflow_display_number: DisplayNumber::new(workflow_display_number).unwrap(),
+            step_display_number: DisplayNumber::new(1).unwrap(),
+            version,
+        }
+    }
+
+    fn outcomes(results: &[BulkDecisionItemResult]) -> Vec<(i64, BulkDecisionOutcome)> {
+        results
+            .iter()
+            .map(|r| (r.workflow_display_number.as_i64(), r.outcome))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_一括承認で各項目が承認され競合や未検出は項目ごとに返る() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver_id = builder.user_id().clone();
+        let setup = builder.build_workflow_usecase_impl();
+        let definition = add_definition(&builder, &setup);
+        let step1 = add_pending_approval(&builder, &setup, &definition, 1, &approver_id).await;
+        let step2 = add_pending_approval(&builder, &setup, &definition, 2, &approver_id).await;
+        let step3 = add_pending_approval(&builder, &setup, &definition, 3, &approver_id).await;
+        let sut = &setup.sut;
+        let input = BulkDecisionInput {
+            action:  BulkDecisionAction::Approve,
+            items:   vec![
+                item(1, step1.version()),
+                // 画面表示後に更新された（古いバージョン）
+                item(2, step2.version().next()),
+                item(99, Version::initial()),
+                item(3, step3.version()),
+            ],
+            comment: Some("まとめて承認".to_string()),
+        };
+
+        // Act
+        let results = sut
+            .bulk_decide_steps(input, builder.tenant_id().clone(), approver_id.clone())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            outcomes(&results),
+            vec![
+                (1, BulkDecisionOutcome::Succeeded),
+                (2, BulkDecisionOutcome::Conflict),
+                (99, BulkDecisionOutcome::NotFound),
+                (3, BulkDecisionOutcome::Succeeded),
+            ]
+        );
+        assert!(results[0].message.is_none());
+        assert_eq!(
+            results[1].message,
+            Some(Message::new("step-version-conflict"))
+        );
+        assert_eq!(
+            step_status(&builder, &setup, &step1).await,
+            WorkflowStepStatus::Completed
+        );
+        assert_eq!(
+            step_status(&builder, &setup, &step2).await,
+            WorkflowStepStatus::Active
+        );
+        assert_eq!(
+            instance_status(&builder, &setup, 3).await,
+            WorkflowInstanceStatus::Approved
+        );
+    }
+
+    #[tokio::test]
+    async fn test_一括却下で各項目が却下される() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver_id = builder.user_id().clone();
+        let setup = builder.build_workflow_usecase_impl();
+        let definition = add_definition(&builder, &setup);
+        let step1 = add_pending_approval(&builder, &setup, &definition, 1, &approver_id).await;
+        let step2 = add_pending_approval(&builder, &setup, &definition, 2, &approver_id).await;
+        let sut = &setup.sut;
+        let input = BulkDecisionInput {
+            action:  BulkDecisionAction::Reject,
+            items:   vec![item(1, step1.version()), item(2, step2.version())],
+            comment: None,
+        };
+
+        // Act
+        let results = sut
+            .bulk_decide_steps(input, builder.tenant_id().clone(), approver_id.clone())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            outcomes(&results),
+            vec![
+                (1, BulkDecisionOutcome::Succeeded),
+                (2, BulkDecisionOutcome::Succeeded),
+            ]
+        );
+        assert_eq!(
+            instance_status(&builder, &setup, 1).await,
+            WorkflowInstanceStatus::Rejected
+        );
+        assert_eq!(
+            instance_status(&builder, &setup, 2).await,
+            WorkflowInstanceStatus::Rejected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_担当者でない項目と判断済みの項目は処理されない() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver_id = builder.user_id().clone();
+        let setup = builder.build_workflow_usecase_impl();
+        let definition = add_definition(&builder, &setup);
+        let others = add_pending_approval(&builder, &setup, &definition, 1, &UserId::new()).await;
+        let decided = add_pending_approval(&builder, &setup, &definition, 2, &approver_id).await;
+        let sut = &setup.sut;
+        sut.bulk_decide_steps(
+            BulkDecisionInput {
+                action:  BulkDecisionAction::Approve,
+                items:   vec![item(2, decided.version())],
+                comment: None,
+            },
+            builder.tenant_id().clone(),
+            approver_id.clone(),
+        )
+        .await
+        .unwrap();
+
+        // Act: 判断済みのステップを同じバージョンで再送する
+        let results = sut
+            .bulk_decide_steps(
+                BulkDecisionInput {
+                    action:  BulkDecisionAction::Reject,
+                    items:   vec![item(1, others.version()), item(2, decided.version())],
+                    comment: None,
+                },
+                builder.tenant_id().clone(),
+                approver_id.clone(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            outcomes(&results),
+            vec![
+                (1, BulkDecisionOutcome::Forbidden),
+                (2, BulkDecisionOutcome::Conflict),
+            ]
+        );
+        assert_eq!(
+            step_status(&builder, &setup, &others).await,
+            WorkflowStepStatus::Active
+        );
+    }
+
+    #[tokio::test]
+    async fn test_項目が空または上限超過の場合は400() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver_id = builder.user_id().clone();
+        let setup = builder.build_workflow_usecase_impl();
+        let sut = &setup.sut;
+        let too_many = (1..=(MAX_BULK_DECISION_ITEMS as i64 + 1))
+            .map(|n| item(n, Version::initial()))
+            .collect();
+
+        // Act
+        let empty = sut
+            .bulk_decide_steps(
+                BulkDecisionInput {
+                    action:  BulkDecisionAction::Approve,
+                    items:   vec![],
+                    comment: None,
+                },
+                builder.tenant_id().clone(),
+                approver_id.clone(),
+            )
+            .await;
+        let exceeded = sut
+            .bulk_decide_steps(
+                BulkDecisionInput {
+                    action:  BulkDecisionAction::Approve,
+                    items:   too_many,
+                    comment: None,
+                },
+                builder.tenant_id().clone(),
+                approver_id.clone(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(empty, Err(CoreError::BadRequest(_))));
+        assert!(matches!(exceeded, Err(CoreError::BadRequest(_))));
+    }
+}
@@ -4,14 +4,20 @@
 //! イベント）を `StepTerminationType` enum で切り替える。
 
 use ringiflow_domain::{
-    notification::WorkflowNotification,
+    message::Message,
     tenant::TenantId,
     user::UserId,
-    value_objects::{DisplayId, display_prefix},
-    workflow::{WorkflowInstance, WorkflowStep, WorkflowStepId, WorkflowStepStatus},
+    workflow::{
+        WorkflowActivityType,
+        WorkflowEventType,
+        WorkflowStep,
+        WorkflowStepId,
+        WorkflowStepStatus,
+    },
 };
 use ringiflow_shared::{event_log::event, log_business_event};
 
+use super::super::helpers::{step_activity, workflow_event};
 use crate::{
     error::CoreError,
     usecase::{
@@ -29,11 +35,27 @@ pub(super) enum StepTerminationType {
 }
 
 impl StepTerminationType {
-    /// 権限チェック用のアクション名
+    /// 権限チェック用のアクションコード（メッセージの `action` パラメータ）
     fn action_name(&self) -> &'static str {
         match self {
-            Self::Reject => "却下",
-            Self::RequestChanges => "差し戻し",
+            Self::Reject => "reject",
+            Self::RequestChanges => "request-changes",
+        }
+    }
+
+    /// 記録するアクティビティ種別
+    fn activity_type(&self) -> WorkflowActivityType {
+        match self {
+            Self::Reject => WorkflowActivityType::Rejected,
+            Self::RequestChanges => WorkflowActivityType::ChangesRequested,
+        }
+    }
+
+    /// 発行するドメインイベント種別
+    fn event_type(&self) -> WorkflowEventType {
+        match self {
+            Self::Reject => WorkflowEventType::Rejected,
+            Self::RequestChanges => WorkflowEventType::ChangesRequested,
         }
     }
 }
@@ -43,13 +65,14 @@ impl WorkflowUseCaseImpl {
     ///
     /// ## 処理フロー
     ///
+    /// 0. 入力チェック（フォームデータ編集は承認時のみ）
     /// 1. ステップを取得
     /// 2. 権限チェック（担当者のみ操作可能）
     /// 3. 楽観的ロック（バージョン一致チェック）
     /// 4. ステップにドメイン操作を適用（種別で分岐）
     /// 5. 残りの Pending ステップを Skipped に遷移
     /// 6. インスタンスを終了状態に遷移（種別で分岐）
-    /// 7. トランザクション保存
+    /// 7. トランザクション保存（アクティビティ・ドメインイベントも記録）
     /// 8. イベントログ（種別で分岐）
     pub(super) async fn terminate_step(
         &self,
@@ -59,22 +82,27 @@ impl WorkflowUseCaseImpl {
         user_id: UserId,
         termination: StepTerminationType,
     ) -> Result<WorkflowWithSteps, CoreError> {
+        // 0. 入力チェック
+        if input.form_data_edits.is_some() {
+            return Err(CoreError::BadRequest(
+                Message::new("form-edit-only-on-approve").with("action", termination.action_name()),
+            ));
+        }
+
         // 1. ステップを取得
         let step = self
             .deps
             .step_repo
             .find_by_id(&step_id, &tenant_id)
             .await
-            .or_not_found("ステップ")?;
+            .or_not_found("step")?;
 
         // 2. 権限チェック
         check_step_assigned_to(&step, &user_id, termination.action_name())?;
 
         // 3. 楽観的ロック（バージョン一致チェック — 早期フェイル）
         if step.version() != input.version {
-            return Err(CoreError::Conflict(
-                "ステップは既に更新されています。最新の情報を取得してください。".to_string(),
-            ));
+            return Err(CoreError::Conflict(Message::new("step-version-conflict")));
         }
 
         // 4. ステップにドメイン操作を適用（種別で分岐）
@@ -95,19 +123,34 @@ impl WorkflowUseCaseImpl {
             .instance_repo
             .find_by_id(terminated_step.instance_id(), &tenant_id)
             .await
-            .or_not_found("インスタンス")?;
+            .or_not_found("workflow-instance")?;
 
         let instance_expected_version = instance.version();
         let completed_instance = match termination {
             StepTerminationType::Reject => instance
                 .complete_with_rejection(now)
-                .map_err(|e| CoreError::BadRequest(e.to_string()))?,
+                .map_err(|e| CoreError::BadRequest(e.into_message()))?,
             StepTerminationType::RequestChanges => instance
                 .complete_with_request_changes(now)
-                .map_err(|e| CoreError::BadRequest(e.to_string()))?,
+                .map_err(|e| CoreError::BadRequest(e.into_message()))?,
         };
 
         // 7. 全更新を単一トランザクションで実行
+        let activity = step_activity(
+            &terminated_step,
+            &tenant_id,
+            termination.activity_type(),
+            &user_id,
+            now,
+        );
+        let domain_event = workflow_event(
+            &completed_instance,
+            termination.event_type(),
+            &user_id,
+            Some(&terminated_step),
+            None,
+            now,
+        );
         let mut tx = self.begin_tx().await?;
 
         self.save_step(&mut tx, &terminated_step, step_expected_version, &tenant_id)
@@ -126,6 +169,11 @@ impl WorkflowUseCaseImpl {
         )
         .await?;
 
+        self.save_activities(&mut tx, &[activity], &tenant_id)
+            .await?;
+
+        self.save_event(&mut tx, &domain_event, &tenant_id).await?;
+
         self.commit_tx(tx).await?;
 
         // 8. 保存後のステップ一覧を取得して返却
@@ -135,15 +183,6 @@ impl WorkflowUseCaseImpl {
 
         Self::log_termination_event(&termination, &step_id, &user_id, &tenant_id);
 
-        // 9. 通知送信（fire-and-forget）
-        self.send_termination_notification(
-            &completed_instance,
-            &terminated_step,
-            &termination,
-            &tenant_id,
-        )
-        .await;
-
         Ok(WorkflowWithSteps {
             instance: completed_instance,
             steps,
@@ -161,10 +200,10 @@ impl WorkflowUseCaseImpl {
         match termination {
             StepTerminationType::Reject => step
                 .reject(comment, now)
-                .map_err(|e| CoreError::BadRequest(e.to_string())),
+                .map_err(|e| CoreError::BadRequest(e.into_message())),
             StepTerminationType::RequestChanges => step
                 .request_changes(comment, now)
-                .map_err(|e| CoreError::BadRequest(e.to_string())),
+                .map_err(|e| CoreError::BadRequest(e.into_message())),
         }
     }
 
@@ -187,89 +226,6 @@ impl WorkflowUseCaseImpl {
         Ok(skipped_steps)
     }
 
-    /// 終了通知を送信する（fire-and-forget）
-    ///
-    /// 却下・差し戻しの通知を申請者に送信する。
-    /// ユーザー情報の取得失敗や通知送信の失敗はログ出力のみで、
-    /// ワークフロー操作の結果には影響しない。
-    async fn send_termination_notification(
-        &self,
-        instance: &WorkflowInstance,
-        terminated_step: &WorkflowStep,
-        termination: &StepTerminationType,
-        tenant_id: &TenantId,
-    ) {
-        // 申請者の情報を取得
-        let applicant = match self
-            .deps
-            .user_repo
-            .find_by_id(instance.initiated_by())
-            .await
-        {
-            Ok(Some(user)) => user,
-            Ok(None) => {
-                tracing::warn!(
-                    user_id = %instance.initiated_by(),
-                    "通知用の申請者情報が見つかりません"
-                );
-                return;
-            }
-            Err(e) => {
-                tracing::warn!(
-                    error = %e,
-                    user_id = %instance.initiated_by(),
-                    "通知用の申請者情報の取得に失敗"
-                );
-                return;
-            }
-        };
-
-        let workflow_display_id =
-            DisplayId::new(display_prefix::WORKFLOW_INSTANCE, instance.display_number())
-                .to_string();
-
-        let notification = Self::build_termination_notification(
-            termination,
-            instance.title(),
-            &workflow_display_id,
-            terminated_step.comment().map(|s| s.to_string()),
-            applicant.email().as_str().to_string(),
-            applicant.id().clone(),
-        );
-
-        self.deps
-            .notification_service
-            .notify(notification, tenant_id, instance.id())
-            .await;
-    }
-
-    /// 終了種別に応じた通知バリアントを構築する
-    fn build_termination_notification(
-        termination: &StepTerminationType,
-        workflow_title: &str,
-        workflow_display_id: &str,
-        comment: Option<String>,
-        applicant_email: String,
-        applicant_user_id: UserId,
-    ) -> WorkflowNotification {
-        match termination {
-            StepTerminationType::Reject => WorkflowNotification::Rejected {
-                workflow_title: workflow_title.to_string(),
-                workflow_display_id: workflow_display_id.to_string(),
-                comment,
-                applicant_email,
-                applicant_user_id,
-            },
-            StepTerminationType::RequestChanges => WorkflowNotification::ChangesRequested {
-                workflow_title: workflow_title.to_string(),
-                workflow_display_id: workflow_display_id.to_string(),
-                comment,
-                applicant_email,
-                applicant_user_id,
-            },
-        }
-    }
-
     /// イベントログを記録する
     fn log_termination_event(
         termination: &StepTerminationType,
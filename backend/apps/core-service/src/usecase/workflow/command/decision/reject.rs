@@ -56,7 +56,7 @@ impl WorkflowUseCaseImpl {
             .instance_repo
             .find_by_display_number(workflow_display_number, &tenant_id)
             .await
-            .or_not_found("ワークフローインスタンス")?;
+            .or_not_found("workflow-instance")?;
 
         // display_number → WorkflowStepId を解決
         let step = self
@@ -64,7 +64,7 @@ impl WorkflowUseCaseImpl {
             .step_repo
             .find_by_display_number(step_display_number, instance.id(), &tenant_id)
             .await
-            .or_not_found("ステップ")?;
+            .or_not_found("step")?;
 
         // 既存の reject_step を呼び出し
         self.reject_step(input, step.id().clone(), tenant_id, user_id)
@@ -74,18 +74,17 @@ impl WorkflowUseCaseImpl {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
-
     use ringiflow_domain::{
         tenant::TenantId,
-        user::{Email, User, UserId},
-        value_objects::{DisplayNumber, UserName, Version, WorkflowName},
+        user::UserId,
+        value_objects::{DisplayNumber, Version, WorkflowName},
         workflow::{
             NewWorkflowDefinition,
             NewWorkflowInstance,
             NewWorkflowStep,
             WorkflowDefinition,
             WorkflowDefinitionId,
+            WorkflowEventType,
             WorkflowInstance,
             WorkflowInstanceId,
             WorkflowStep,
@@ -94,7 +93,6 @@ mod tests {
     };
     use ringiflow_infra::{
         fake::{
-            FakeUserRepository,
             FakeWorkflowDefinitionRepository,
             FakeWorkflowInstanceRepository,
             FakeWorkflowStepRepository,
@@ -104,7 +102,7 @@ mod tests {
 
     use super::super::super::test_helpers::{
         build_sut,
-        build_sut_with_notification,
+        build_sut_with_outbox,
         setup_two_step_approval,
         single_approval_definition_json,
     };
@@ -158,8 +156,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("却下理由".to_string()),
+            version:         step.version(),
+            comment:         Some("却下理由".to_string()),
+            form_data_edits: None,
         };
 
         // Act
@@ -181,6 +180,37 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[tokio::test]
+    async fn test_reject_step_フォームデータ編集を指定すると400() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let now = chrono::Utc::now();
+
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
+
+        let input = ApproveRejectInput {
+            version:         Version::initial(),
+            comment:         None,
+            form_data_edits: Some(
+                serde_json::json!({"amount": 1})
+                    .as_object()
+                    .unwrap()
+                    .clone(),
+            ),
+        };
+
+        // Act
+        let result = sut
+            .reject_step(input, WorkflowStepId::new(), tenant_id, UserId::new())
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
     #[tokio::test]
     async fn test_reject_step_未割り当てユーザーは403() {
         // Arrange
@@ -240,8 +270,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("却下します".to_string()),
+            version:         step.version(),
+            comment:         Some("却下します".to_string()),
+            form_data_edits: None,
         };
 
         // Act: 別のユーザーで却下を試みる
@@ -311,8 +342,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("却下します".to_string()),
+            version:         step.version(),
+            comment:         Some("却下します".to_string()),
+            form_data_edits: None,
         };
 
         // Act: Pending ステップに対して却下を試みる
@@ -371,8 +403,9 @@ mod tests {
         // 不一致バージョンを指定（ステップの version は 1 だが、2 を指定）
         let wrong_version = Version::initial().next();
         let input = ApproveRejectInput {
-            version: wrong_version,
-            comment: None,
+            version:         wrong_version,
+            comment:         None,
+            form_data_edits: None,
         };
 
         // Act
@@ -408,8 +441,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step1.version(),
-            comment: Some("上長却下".to_string()),
+            version:         step1.version(),
+            comment:         Some("上長却下".to_string()),
+            form_data_edits: None,
         };
 
         // Act: 最初のステップを却下
@@ -497,8 +531,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step2_active.version(),
-            comment: Some("経理却下".to_string()),
+            version:         step2_active.version(),
+            comment:         Some("経理却下".to_string()),
+            form_data_edits: None,
         };
 
         // Act: 最終ステップを却下
@@ -535,10 +570,10 @@ mod tests {
         );
     }
 
-    // ===== 通知テスト =====
+    // ===== ドメインイベントテスト =====
 
     #[tokio::test]
-    async fn test_reject_step_却下通知が申請者に送信される() {
+    async fn test_reject_step_rejectedイベントがコメント付きで記録される() {
         // Arrange
         let tenant_id = TenantId::new();
         let user_id = UserId::new(); // 申請者
@@ -579,28 +614,13 @@ mod tests {
         .activated(now);
         step_repo.insert_for_test(&step, &tenant_id).await.unwrap();
 
-        // ユーザー情報を Fake に登録
-        let user_repo = FakeUserRepository::new();
-        user_repo.add_user(User::new(
-            user_id.clone(),
-            tenant_id.clone(),
-            DisplayNumber::new(1).unwrap(),
-            Email::new("tanaka@example.com").unwrap(),
-            UserName::new("田中太郎").unwrap(),
-            now,
-        ));
-
-        let (sut, sender) = build_sut_with_notification(
-            &definition_repo,
-            &instance_repo,
-            &step_repo,
-            Arc::new(user_repo),
-            now,
-        );
+        let (sut, outbox_repo) =
+            build_sut_with_outbox(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("領収書が添付されていません".to_string()),
+            version:         step.version(),
+            comment:         Some("領収書が添付されていません".to_string()),
+            form_data_edits: None,
         };
 
         // Act
@@ -616,93 +636,18 @@ mod tests {
         // Assert: ワークフロー操作は成功
         assert!(result.is_ok());
 
-        // Assert: 却下通知が申請者に送信されている
-        let sent = sender.sent_emails();
-        assert_eq!(sent.len(), 1, "却下メールが1通送信されるべき");
-        assert_eq!(sent[0].to, "tanaka@example.com");
-        assert!(
-            sent[0].subject.contains("却下"),
-            "件名に「却下」が含まれるべき: {}",
-            sent[0].subject
-        );
-        assert!(
-            sent[0].text_body.contains("領収書が添付されていません"),
-            "本文に却下コメントが含まれるべき"
-        );
-    }
-
-    #[tokio::test]
-    async fn test_reject_step_ユーザー情報取得失敗でも却下操作は成功する() {
-        // Arrange
-        let tenant_id = TenantId::new();
-        let user_id = UserId::new();
-        let approver_id = UserId::new();
-        let now = chrono::Utc::now();
-
-        let definition_repo = FakeWorkflowDefinitionRepository::new();
-        let instance_repo = FakeWorkflowInstanceRepository::new();
-        let step_repo = FakeWorkflowStepRepository::new();
-
-        let instance = WorkflowInstance::new(NewWorkflowInstance {
-            id: WorkflowInstanceId::new(),
-            tenant_id: tenant_id.clone(),
-            definition_id: WorkflowDefinitionId::new(),
-            definition_version: Version::initial(),
-            display_number: DisplayNumber::new(100).unwrap(),
-            title: "テスト申請".to_string(),
-            form_data: serde_json::json!({}),
-            initiated_by: user_id.clone(),
-            now,
-        })
-        .submitted(now)
-        .unwrap()
-        .with_current_step("approval".to_string(), now)
-        .unwrap();
-        instance_repo.insert_for_test(&instance).await.unwrap();
-
-        let step = WorkflowStep::new(NewWorkflowStep {
-            id: WorkflowStepId::new(),
-            instance_id: instance.id().clone(),
-            display_number: DisplayNumber::new(1).unwrap(),
-            step_id: "approval".to_string(),
-            step_name: "承認".to_string(),
-            step_type: "approval".to_string(),
-            assigned_to: Some(approver_id.clone()),
-            now,
-        })
-        .activated(now);
-        step_repo.insert_for_test(&step, &tenant_id).await.unwrap();
-
-        // ユーザー情報を登録しない（空の FakeUserRepository）
-        let user_repo = FakeUserRepository::new();
-        let (sut, sender) = build_sut_with_notification(
-            &definition_repo,
-            &instance_repo,
-            &step_repo,
-            Arc::new(user_repo),
-            now,
+        // Assert: Rejected イベントが却下コメント付きで記録されている
+        let events = outbox_repo.events();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.event_type(), WorkflowEventType::Rejected);
+        assert_eq!(event.actor_id(), Some(&approver_id));
+        assert_eq!(event.payload().initiated_by, user_id);
+        let rejected_step = event.payload().step.as_ref().unwrap();
+        assert_eq!(
+            rejected_step.comment.as_deref(),
+            Some("領収書が添付されていません")
         );
-
-        let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("却下理由".to_string()),
-        };
-
-        // Act
-        let result = sut
-            .reject_step(
-                input,
-                step.id().clone(),
-                tenant_id.clone(),
-                approver_id.clone(),
-            )
-            .await;
-
-        // Assert: ワークフロー操作自体は成功する（fire-and-forget）
-        assert!(result.is_ok());
-
-        // Assert: 通知は送信されない（ユーザー情報がないため）
-        let sent = sender.sent_emails();
-        assert_eq!(sent.len(), 0, "ユーザー情報がない場合、通知は送信されない");
+        assert!(event.payload().activated_step.is_none());
     }
 }
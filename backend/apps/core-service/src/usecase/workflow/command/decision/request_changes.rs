@@ -53,7 +53,7 @@ impl WorkflowUseCaseImpl {
             .instance_repo
             .find_by_display_number(workflow_display_number, &tenant_id)
             .await
-            .or_not_found("ワークフローインスタンス")?;
+            .or_not_found("workflow-instance")?;
 
         // display_number → WorkflowStepId を解決
         let step = self
@@ -61,7 +61,7 @@ impl WorkflowUseCaseImpl {
             .step_repo
             .find_by_display_number(step_display_number, instance.id(), &tenant_id)
             .await
-            .or_not_found("ステップ")?;
+            .or_not_found("step")?;
 
         // 既存の request_changes_step を呼び出し
         self.request_changes_step(input, step.id().clone(), tenant_id, user_id)
@@ -71,27 +71,27 @@ impl WorkflowUseCaseImpl {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
-
     use ringiflow_domain::{
         tenant::TenantId,
-        user::{Email, User, UserId},
-        value_objects::{DisplayNumber, UserName, Version, WorkflowName},
+        user::UserId,
+        value_objects::{DisplayNumber, Version, WorkflowName},
         workflow::{
             NewWorkflowDefinition,
             NewWorkflowInstance,
             NewWorkflowStep,
+            WorkflowActivityType,
             WorkflowDefinition,
             WorkflowDefinitionId,
+            WorkflowEventType,
             WorkflowInstance,
             WorkflowInstanceId,
             WorkflowStep,
             WorkflowStepId,
+            WorkflowViewer,
         },
     };
     use ringiflow_infra::{
         fake::{
-            FakeUserRepository,
             FakeWorkflowDefinitionRepository,
             FakeWorkflowInstanceRepository,
             FakeWorkflowStepRepository,
@@ -101,7 +101,7 @@ mod tests {
 
     use super::super::super::test_helpers::{
         build_sut,
-        build_sut_with_notification,
+        build_sut_with_outbox,
         setup_two_step_approval,
         single_approval_definition_json,
     };
@@ -155,8 +155,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("金額を修正してください".to_string()),
+            version:         step.version(),
+            comment:         Some("金額を修正してください".to_string()),
+            form_data_edits: None,
         };
 
         // Act
@@ -240,8 +241,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("差し戻します".to_string()),
+            version:         step.version(),
+            comment:         Some("差し戻します".to_string()),
+            form_data_edits: None,
         };
 
         // Act: 別のユーザーで差し戻しを試みる
@@ -311,8 +313,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("差し戻します".to_string()),
+            version:         step.version(),
+            comment:         Some("差し戻します".to_string()),
+            form_data_edits: None,
         };
 
         // Act: Pending ステップに対して差し戻しを試みる
@@ -370,8 +373,9 @@ mod tests {
 
         let wrong_version = Version::initial().next();
         let input = ApproveRejectInput {
-            version: wrong_version,
-            comment: None,
+            version:         wrong_version,
+            comment:         None,
+            form_data_edits: None,
         };
 
         // Act
@@ -407,8 +411,9 @@ mod tests {
         let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step1.version(),
-            comment: Some("差し戻します".to_string()),
+            version:         step1.version(),
+            comment:         Some("差し戻します".to_string()),
+            form_data_edits: None,
         };
 
         // Act: 最初のステップを差し戻し
@@ -456,107 +461,71 @@ mod tests {
         );
     }
 
-    // ===== 通知テスト =====
-
     #[tokio::test]
-    async fn test_request_changes_step_差し戻し通知が申請者に送信される() {
+    async fn test_request_changes_step_差し戻しがアクティビティとして記録される() {
         // Arrange
         let tenant_id = TenantId::new();
-        let user_id = UserId::new(); // 申請者
-        let approver_id = UserId::new();
+        let user_id = UserId::new();
+        let approver1_id = UserId::new();
+        let approver2_id = UserId::new();
         let now = chrono::Utc::now();
 
+        let (definition, instance, step1, step2) =
+            setup_two_step_approval(&tenant_id, &user_id, &approver1_id, &approver2_id, now);
+
         let definition_repo = FakeWorkflowDefinitionRepository::new();
         let instance_repo = FakeWorkflowInstanceRepository::new();
         let step_repo = FakeWorkflowStepRepository::new();
 
-        let instance = WorkflowInstance::new(NewWorkflowInstance {
-            id: WorkflowInstanceId::new(),
-            tenant_id: tenant_id.clone(),
-            definition_id: WorkflowDefinitionId::new(),
-            definition_version: Version::initial(),
-            display_number: DisplayNumber::new(100).unwrap(),
-            title: "テスト申請".to_string(),
-            form_data: serde_json::json!({}),
-            initiated_by: user_id.clone(),
-            now,
-        })
-        .submitted(now)
-        .unwrap()
-        .with_current_step("approval".to_string(), now)
-        .unwrap();
+        definition_repo.add_definition(definition);
         instance_repo.insert_for_test(&instance).await.unwrap();
+        step_repo.insert_for_test(&step1, &tenant_id).await.unwrap();
+        step_repo.insert_for_test(&step2, &tenant_id).await.unwrap();
 
-        let step = WorkflowStep::new(NewWorkflowStep {
-            id: WorkflowStepId::new(),
-            instance_id: instance.id().clone(),
-            display_number: DisplayNumber::new(1).unwrap(),
-            step_id: "approval".to_string(),
-            step_name: "承認".to_string(),
-            step_type: "approval".to_string(),
-            assigned_to: Some(approver_id.clone()),
-            now,
-        })
-        .activated(now);
-        step_repo.insert_for_test(&step, &tenant_id).await.unwrap();
-
-        // ユーザー情報を Fake に登録
-        let user_repo = FakeUserRepository::new();
-        user_repo.add_user(User::new(
-            user_id.clone(),
-            tenant_id.clone(),
-            DisplayNumber::new(1).unwrap(),
-            Email::new("tanaka@example.com").unwrap(),
-            UserName::new("田中太郎").unwrap(),
-            now,
-        ));
-
-        let (sut, sender) = build_sut_with_notification(
-            &definition_repo,
-            &instance_repo,
-            &step_repo,
-            Arc::new(user_repo),
-            now,
-        );
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("金額を修正してください".to_string()),
+            version:         step1.version(),
+            comment:         Some("金額を見直してください".to_string()),
+            form_data_edits: None,
         };
 
         // Act
-        let result = sut
-            .request_changes_step(
-                input,
-                step.id().clone(),
-                tenant_id.clone(),
-                approver_id.clone(),
-            )
-            .await;
-
-        // Assert: ワークフロー操作は成功
-        assert!(result.is_ok());
+        sut.request_changes_step(
+            input,
+            step1.id().clone(),
+            tenant_id.clone(),
+            approver1_id.clone(),
+        )
+        .await
+        .unwrap();
 
-        // Assert: 差し戻し通知が申請者に送信されている
-        let sent = sender.sent_emails();
-        assert_eq!(sent.len(), 1, "差し戻しメールが1通送信されるべき");
-        assert_eq!(sent[0].to, "tanaka@example.com");
-        assert!(
-            sent[0].subject.contains("要修正"),
-            "件名に「要修正」が含まれるべき: {}",
-            sent[0].subject
-        );
-        assert!(
-            sent[0].text_body.contains("金額を修正してください"),
-            "本文に差し戻しコメントが含まれるべき"
+        // Assert
+        let activities = sut
+            .list_activities(
+                instance.display_number(),
+                tenant_id,
+                &WorkflowViewer::new(approver1_id.clone(), false),
+            )
+            .await
+            .unwrap();
+        assert_eq!(activities.len(), 1);
+        assert_eq!(
+            activities[0].activity_type(),
+            WorkflowActivityType::ChangesRequested
         );
+        assert_eq!(activities[0].actor_id(), Some(&approver1_id));
+        assert_eq!(activities[0].step_id(), Some(step1.id()));
+        assert_eq!(activities[0].details()["comment"], "金額を見直してください");
     }
 
+    // ===== ドメインイベントテスト =====
+
     #[tokio::test]
-    async fn test_request_changes_step_ユーザー情報取得失敗でも差し戻し操作は成功する() {
+    async fn test_request_changes_step_changes_requestedイベントがコメント付きで記録される() {
         // Arrange
         let tenant_id = TenantId::new();
-        let user_id = UserId::new();
+        let user_id = UserId::new(); // 申請者
         let approver_id = UserId::new();
         let now = chrono::Utc::now();
 
@@ -594,19 +563,13 @@ mod tests {
         .activated(now);
         step_repo.insert_for_test(&step, &tenant_id).await.unwrap();
 
-        // ユーザー情報を登録しない（空の FakeUserRepository）
-        let user_repo = FakeUserRepository::new();
-        let (sut, sender) = build_sut_with_notification(
-            &definition_repo,
-            &instance_repo,
-            &step_repo,
-            Arc::new(user_repo),
-            now,
-        );
+        let (sut, outbox_repo) =
+            build_sut_with_outbox(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ApproveRejectInput {
-            version: step.version(),
-            comment: Some("修正してください".to_string()),
+            version:         step.version(),
+            comment:         Some("金額を修正してください".to_string()),
+            form_data_edits: None,
         };
 
         // Act
@@ -619,11 +582,17 @@ mod tests {
             )
             .await;
 
-        // Assert: ワークフロー操作自体は成功する（fire-and-forget）
+        // Assert: ワークフロー操作は成功
         assert!(result.is_ok());
 
-        // Assert: 通知は送信されない（ユーザー情報がないため）
-        let sent = sender.sent_emails();
-        assert_eq!(sent.len(), 0, "ユーザー情報がない場合、通知は送信されない");
+        // Assert: ChangesRequested イベントが差し戻しコメント付きで記録されている
+        let events = outbox_repo.events();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.event_type(), WorkflowEventType::ChangesRequested);
+        assert_eq!(event.actor_id(), Some(&approver_id));
+        assert_eq!(event.payload().initiated_by, user_id);
+        let step = event.payload().step.as_ref().unwrap();
+        assert_eq!(step.comment.as_deref(), Some("金額を修正してください"));
     }
 }
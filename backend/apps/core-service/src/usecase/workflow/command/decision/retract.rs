@@ -0,0 +1,604 @@
+//! ワークフローステップの承認取り消し
+//!
+//! 誤って承認した承認者が、次のステップが処理される前に承認を取り消す。
+//! 取り消したステップは再びアクティブになり、次のステップは待機中に戻る。
+//!
+//! 取り消しできる条件:
+//!
+//! - 操作者が取り消すステップの担当者である
+//! - ステップが承認済みで、インスタンスが次のステップで処理中である
+//!   （最終ステップの承認でインスタンスが承認完了になった場合は取り消せない）
+//! - 次のステップがまだ判断されていない（アクティブのまま）
+//! - 定義の承認ステップに `retractWindowMinutes` が指定されている場合は、承認からその分数以内
+//!
+//! 承認時に承認者が行ったフォームデータの編集は取り消さない（変更履歴も残す）。
+
+use chrono::Duration;
+use ringiflow_domain::{
+    message::Message,
+    tenant::TenantId,
+    user::UserId,
+    value_objects::DisplayNumber,
+    workflow::{StepDecision, WorkflowActivityType, WorkflowEventType, WorkflowStepId},
+};
+use ringiflow_shared::{event_log::event, log_business_event};
+
+use super::super::helpers::{step_activity, workflow_event};
+use crate::{
+    error::CoreError,
+    usecase::{
+        helpers::FindResultExt,
+        workflow::{RetractApprovalInput, WorkflowUseCaseImpl, WorkflowWithSteps},
+    },
+};
+
+impl WorkflowUseCaseImpl {
+    /// 承認済みのステップの承認を取り消す
+    ///
+    /// ## 処理フロー
+    ///
+    /// 1. ステップを取得
+    /// 2. 権限チェック（承認したステップの担当者のみ操作可能）
+    /// 3. 楽観的ロック（バージョン一致チェック）
+    /// 4. インスタンス・定義を取得し、次のステップで処理中であることを確認
+    /// 5. 取り消し期限のチェック（定義で指定されている場合のみ）
+    /// 6. ステップを Active に、次のステップを Pending に、インスタンスを前のステップに戻す
+    /// 7. トランザクション保存（アクティビティ・ドメインイベントも記録）
+    pub async fn retract_approval(
+        &self,
+        input: RetractApprovalInput,
+        step_id: WorkflowStepId,
+        tenant_id: TenantId,
+        user_id: UserId,
+    ) -> Result<WorkflowWithSteps, CoreError> {
+        // 1. ステップを取得
+        let step = self
+            .deps
+            .step_repo
+            .find_by_id(&step_id, &tenant_id)
+            .await
+            .or_not_found("step")?;
+
+        // 2. 権限チェック
+        if step.assigned_to() != Some(&user_id) {
+            return Err(CoreError::Forbidden(Message::new("retract-forbidden")));
+        }
+
+        // 3. 楽観的ロック（バージョン一致チェック — 早期フェイル）
+        if step.version() != input.version {
+            return Err(CoreError::Conflict(Message::new("step-version-conflict")));
+        }
+
+        let Some(approved_at) = step
+            .completed_at()
+            .filter(|_| step.decision() == Some(StepDecision::Approved))
+        else {
+            return Err(CoreError::BadRequest(Message::new("retract-not-approved")));
+        };
+
+        // 4. インスタンス・定義を取得し、次のステップで処理中であることを確認
+        let instance = self
+            .deps
+            .instance_repo
+            .find_by_id(step.instance_id(), &tenant_id)
+            .await
+            .or_not_found("workflow-instance")?;
+        let instance_expected_version = instance.version();
+
+        let definition = self
+            .deps
+            .definition_repo
+            .find_by_id(instance.definition_id(), &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("定義の取得に失敗: {}", e)))?
+            .ok_or_else(|| CoreError::Internal("定義が見つかりません".to_string()))?;
+
+        let approval_step_defs =
+            ringiflow_domain::workflow::extract_approval_steps(definition.definition())
+                .map_err(|e| CoreError::Internal(format!("定義の解析に失敗: {}", e)))?;
+
+        let current_index = approval_step_defs
+            .iter()
+            .position(|s| s.id == step.step_id())
+            .ok_or_else(|| CoreError::Internal("定義にステップが見つかりません".to_string()))?;
+        let step_def = &approval_step_defs[current_index];
+        let next_step_def = approval_step_defs
+            .get(current_index + 1)
+            .filter(|next| instance.current_step_id() == Some(next.id.as_str()))
+            .ok_or_else(|| CoreError::BadRequest(Message::new("retract-next-step-not-active")))?;
+
+        // 5. 取り消し期限のチェック
+        let now = self.deps.clock.now();
+        if let Some(window) = step_def.retract_window_minutes
+            && now > approved_at + Duration::minutes(i64::from(window))
+        {
+            return Err(CoreError::BadRequest(
+                Message::new("retract-window-expired").with("minutes", window),
+            ));
+        }
+
+        // 6. 各エンティティを取り消し前の状態に戻す（トランザクション開始前にドメインロジック実行）
+        let next_step = self
+            .fetch_instance_steps(instance.id(), &tenant_id)
+            .await?
+            .into_iter()
+            .find(|s| s.step_id() == next_step_def.id)
+            .ok_or_else(|| CoreError::Internal("次のステップが見つかりません".to_string()))?;
+        let next_expected_version = next_step.version();
+        let withdrawn_step = next_step
+            .deactivated(now)
+            .map_err(|_| CoreError::BadRequest(Message::new("retract-next-step-processed")))?;
+
+        let step_expected_version = step.version();
+        let retracted_step = step
+            .retract_approval(now)
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        let reverted_instance = instance
+            .revert_to_step(retracted_step.step_id().to_string(), now)
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        // 7. 全更新を単一トランザクションで実行
+        let activity = step_activity(
+            &retracted_step,
+            &tenant_id,
+            WorkflowActivityType::ApprovalRetracted,
+            &user_id,
+            now,
+        );
+        let domain_event = workflow_event(
+            &reverted_instance,
+            WorkflowEventType::ApprovalRetracted,
+            &user_id,
+            Some(&withdrawn_step),
+            Some(&retracted_step),
+            now,
+        );
+
+        let mut tx = self.begin_tx().await?;
+
+        self.save_step(&mut tx, &retracted_step, step_expected_version, &tenant_id)
+            .await?;
+        self.save_step(&mut tx, &withdrawn_step, next_expected_version, &tenant_id)
+            .await?;
+        self.save_instance(
+            &mut tx,
+            &reverted_instance,
+            instance_expected_version,
+            &tenant_id,
+        )
+        .await?;
+        self.save_activities(&mut tx, &[activity], &tenant_id)
+            .await?;
+        self.save_event(&mut tx, &domain_event, &tenant_id).await?;
+
+        self.commit_tx(tx).await?;
+
+        // 8. 保存後のステップ一覧を取得して返却
+        let steps = self
+            .fetch_instance_steps(reverted_instance.id(), &tenant_id)
+            .await?;
+
+        log_business_event!(
+            event.category = event::category::WORKFLOW,
+            event.action = event::action::STEP_APPROVAL_RETRACTED,
+            event.entity_type = event::entity_type::WORKFLOW_STEP,
+            event.entity_id = %step_id,
+            event.actor_id = %user_id,
+            event.tenant_id = %tenant_id,
+            event.result = event::result::SUCCESS,
+            "承認取り消し"
+        );
+
+        Ok(WorkflowWithSteps {
+            instance: reverted_instance,
+            steps,
+        })
+    }
+
+    /// display_number でワークフローステップの承認を取り消す
+    pub async fn retract_approval_by_display_number(
+        &self,
+        input: RetractApprovalInput,
+        workflow_display_number: DisplayNumber,
+        step_display_number: DisplayNumber,
+        tenant_id: TenantId,
+        user_id: UserId,
+    ) -> Result<WorkflowWithSteps, CoreError> {
+        let instance = self
+            .deps
+            .instance_repo
+            .find_by_display_number(workflow_display_number, &tenant_id)
+            .await
+            .or_not_found("workflow-instance")?;
+
+        let step = self
+            .deps
+            .step_repo
+            .find_by_display_number(step_display_number, instance.id(), &tenant_id)
+            .await
+            .or_not_found("step")?;
+
+        self.retract_approval(input, step.id().clone(), tenant_id, user_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ringiflow_domain::{
+        user::UserId,
+        value_objects::{Version, WorkflowName},
+        workflow::{
+            NewWorkflowDefinition,
+            WorkflowDefinition,
+            WorkflowEventType,
+            WorkflowInstanceStatus,
+            WorkflowStep,
+            WorkflowStepStatus,
+        },
+    };
+    use ringiflow_infra::repository::{
+        WorkflowInstanceRepositoryTestExt,
+        WorkflowStepRepositoryTestExt,
+    };
+
+    use super::super::super::test_helpers::{
+        build_sut,
+        build_sut_with_outbox,
+        setup_two_step_approval,
+    };
+    use crate::{
+        error::CoreError,
+        test_utils::{WorkflowTestBuilder, WorkflowTestSetup},
+        usecase::workflow::{ApproveRejectInput, RetractApprovalInput},
+    };
+
+    /// 2 段階承認の 1 段階目を承認した状態を作る
+    ///
+    /// 申請者はビルダーのユーザーで、ビルダーの現在時刻に承認する。
+    /// `retract_window_minutes` を指定すると、1 段階目の定義に取り消し期限を設定する。
+    ///
+    /// 戻り値: 承認後の (step1(Completed), step2(Active))
+    async fn setup_approved(
+        builder: &WorkflowTestBuilder,
+        setup: &WorkflowTestSetup,
+        approver1_id: &UserId,
+        approver2_id: &UserId,
+        retract_window_minutes: Option<u32>,
+    ) -> (WorkflowStep, WorkflowStep) {
+        let tenant_id = builder.tenant_id();
+        let approved_at = builder.now();
+        let (definition, instance, step1, step2) = setup_two_step_approval(
+            tenant_id,
+            builder.user_id(),
+            approver1_id,
+            approver2_id,
+            approved_at,
+        );
+
+        let definition = match retract_window_minutes {
+            Some(window) => {
+                let mut json = definition.definition().clone();
+                json["steps"][1]["retractWindowMinutes"] = serde_json::json!(window);
+                WorkflowDefinition::new(NewWorkflowDefinition {
+                    id:          definition.id().clone(),
+                    tenant_id:   tenant_id.clone(),
+                    name:        WorkflowName::new("2段階承認").unwrap(),
+                    description: None,
+                    definition:  json,
+                    created_by:  builder.user_id().clone(),
+                    now:         approved_at,
+                })
+                .published(approved_at)
+                .unwrap()
+            }
+            None => definition,
+        };
+        setup.definition_repo.add_definition(definition);
+        setup
+            .instance_repo
+            .insert_for_test(&instance)
+            .await
+            .unwrap();
+        setup
+            .step_repo
+            .insert_for_test(&step1, tenant_id)
+            .await
+            .unwrap();
+        setup
+            .step_repo
+            .insert_for_test(&step2, tenant_id)
+            .await
+            .unwrap();
+
+        let approved = setup
+            .sut
+            .approve_step(
+                ApproveRejectInput {
+                    version:         step1.version(),
+                    comment:         Some("承認します".to_string()),
+                    form_data_edits: None,
+                },
+                step1.id().clone(),
+                tenant_id.clone(),
+                approver1_id.clone(),
+            )
+            .await
+            .unwrap();
+        let step = |step_id: &str| {
+            approved
+                .steps
+                .iter()
+                .find(|s| s.step_id() == step_id)
+                .unwrap()
+                .clone()
+        };
+        (step("manager_approval"), step("finance_approval"))
+    }
+
+    #[tokio::test]
+    async fn test_retract_approval_前のステップがアクティブに次のステップが待機中に戻る() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver1_id = UserId::new();
+        let approver2_id = UserId::new();
+        let setup = builder.build_workflow_usecase_impl();
+        let (step1, _) = setup_approved(&builder, &setup, &approver1_id, &approver2_id, None).await;
+        // 承認時のイベントを含まない outbox で検証する
+        let (sut, outbox_repo) = build_sut_with_outbox(
+            &setup.definition_repo,
+            &setup.instance_repo,
+            &setup.step_repo,
+            builder.now(),
+        );
+
+        // Act
+        let result = sut
+            .retract_approval(
+                RetractApprovalInput {
+                    version: step1.version(),
+                },
+                step1.id().clone(),
+                builder.tenant_id().clone(),
+                approver1_id.clone(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(result.instance.status(), WorkflowInstanceStatus::InProgress);
+        assert_eq!(result.instance.current_step_id(), Some("manager_approval"));
+        let statuses: Vec<_> = result
+            .steps
+            .iter()
+            .map(|s| (s.step_id(), s.status()))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("manager_approval", WorkflowStepStatus::Active),
+                ("finance_approval", WorkflowStepStatus::Pending),
+            ]
+        );
+
+        let events = outbox_repo.events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), WorkflowEventType::ApprovalRetracted);
+        let payload = events[0].payload();
+        assert_eq!(
+            payload.step.as_ref().unwrap().assigned_to.as_ref(),
+            Some(&approver2_id)
+        );
+        assert_eq!(
+            payload.activated_step.as_ref().unwrap().step_id,
+            *step1.id()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retract_approval_取り消し後に再度承認できる() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver1_id = UserId::new();
+        let approver2_id = UserId::new();
+        let setup = builder.build_workflow_usecase_impl();
+        let (step1, _) = setup_approved(&builder, &setup, &approver1_id, &approver2_id, None).await;
+        let sut = &setup.sut;
+        let retracted = sut
+            .retract_approval(
+                RetractApprovalInput {
+                    version: step1.version(),
+                },
+                step1.id().clone(),
+                builder.tenant_id().clone(),
+                approver1_id.clone(),
+            )
+            .await
+            .unwrap();
+        let step1 = retracted
+            .steps
+            .iter()
+            .find(|s| s.step_id() == "manager_approval")
+            .unwrap();
+
+        // Act
+        let result = sut
+            .approve_step(
+                ApproveRejectInput {
+                    version:         step1.version(),
+                    comment:         None,
+                    form_data_edits: None,
+                },
+                step1.id().clone(),
+                builder.tenant_id().clone(),
+                approver1_id.clone(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(result.instance.current_step_id(), Some("finance_approval"));
+        let step2 = result
+            .steps
+            .iter()
+            .find(|s| s.step_id() == "finance_approval")
+            .unwrap();
+        assert_eq!(step2.status(), WorkflowStepStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn test_retract_approval_担当者以外は403() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver1_id = UserId::new();
+        let approver2_id = UserId::new();
+        let setup = builder.build_workflow_usecase_impl();
+        let (step1, _) = setup_approved(&builder, &setup, &approver1_id, &approver2_id, None).await;
+        let sut = &setup.sut;
+
+        // Act
+        let result = sut
+            .retract_approval(
+                RetractApprovalInput {
+                    version: step1.version(),
+                },
+                step1.id().clone(),
+                builder.tenant_id().clone(),
+                approver2_id.clone(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_retract_approval_次のステップが判断済みなら400() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver1_id = UserId::new();
+        let approver2_id = UserId::new();
+        let setup = builder.build_workflow_usecase_impl();
+        let (step1, step2) =
+            setup_approved(&builder, &setup, &approver1_id, &approver2_id, None).await;
+        let sut = &setup.sut;
+        sut.reject_step(
+            ApproveRejectInput {
+                version:         step2.version(),
+                comment:         None,
+                form_data_edits: None,
+            },
+            step2.id().clone(),
+            builder.tenant_id().clone(),
+            approver2_id.clone(),
+        )
+        .await
+        .unwrap();
+
+        // Act
+        let result = sut
+            .retract_approval(
+                RetractApprovalInput {
+                    version: step1.version(),
+                },
+                step1.id().clone(),
+                builder.tenant_id().clone(),
+                approver1_id.clone(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_retract_approval_取り消し期限内なら取り消せる() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver1_id = UserId::new();
+        let approver2_id = UserId::new();
+        let setup = builder.build_workflow_usecase_impl();
+        let (step1, _) =
+            setup_approved(&builder, &setup, &approver1_id, &approver2_id, Some(30)).await;
+        let sut = build_sut(
+            &setup.definition_repo,
+            &setup.instance_repo,
+            &setup.step_repo,
+            builder.now() + chrono::Duration::minutes(30),
+        );
+
+        // Act
+        let result = sut
+            .retract_approval(
+                RetractApprovalInput {
+                    version: step1.version(),
+                },
+                step1.id().clone(),
+                builder.tenant_id().clone(),
+                approver1_id.clone(),
+            )
+            .await;
+
+        // Assert
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
+    #[tokio::test]
+    async fn test_retract_approval_取り消し期限を過ぎると400() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver1_id = UserId::new();
+        let approver2_id = UserId::new();
+        let setup = builder.build_workflow_usecase_impl();
+        let (step1, _) =
+            setup_approved(&builder, &setup, &approver1_id, &approver2_id, Some(30)).await;
+        let sut = build_sut(
+            &setup.definition_repo,
+            &setup.instance_repo,
+            &setup.step_repo,
+            builder.now() + chrono::Duration::minutes(31),
+        );
+
+        // Act
+        let result = sut
+            .retract_approval(
+                RetractApprovalInput {
+                    version: step1.version(),
+                },
+                step1.id().clone(),
+                builder.tenant_id().clone(),
+                approver1_id.clone(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_retract_approval_バージョン不一致で409() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let approver1_id = UserId::new();
+        let approver2_id = UserId::new();
+        let setup = builder.build_workflow_usecase_impl();
+        let (step1, _) = setup_approved(&builder, &setup, &approver1_id, &approver2_id, None).await;
+        let sut = &setup.sut;
+
+        // Act
+        let result = sut
+            .retract_approval(
+                RetractApprovalInput {
+                    version: Version::initial(),
+                },
+                step1.id().clone(),
+                builder.tenant_id().clone(),
+                approver1_id.clone(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::Conflict(_))));
+    }
+}
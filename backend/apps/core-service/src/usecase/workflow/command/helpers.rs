@@ -1,14 +1,31 @@
 //! ワークフローコマンド共通のヘルパー関数
 //!
 //! 永続化ボイラープレート（トランザクション操作、version check 付き更新、
-//! ステップ一覧取得）を共通化する。
+//! ステップ一覧取得、アクティビティ・ドメインイベントの記録）を共通化する。
 
+use chrono::{DateTime, Utc};
 use ringiflow_domain::{
+    message::Message,
     tenant::TenantId,
+    user::UserId,
     value_objects::Version,
-    workflow::{WorkflowInstance, WorkflowInstanceId, WorkflowStep},
+    workflow::{
+        NewWorkflowActivity,
+        NewWorkflowEvent,
+        WorkflowActivity,
+        WorkflowActivityId,
+        WorkflowActivityType,
+        WorkflowEvent,
+        WorkflowEventId,
+        WorkflowEventPayload,
+        WorkflowEventType,
+        WorkflowInstance,
+        WorkflowInstanceId,
+        WorkflowStep,
+    },
 };
 use ringiflow_infra::{InfraErrorKind, TxContext};
+use serde_json::{Value as JsonValue, json};
 
 use super::super::WorkflowUseCaseImpl;
 use crate::error::CoreError;
@@ -43,9 +60,9 @@ impl WorkflowUseCaseImpl {
             .update_with_version_check(tx, step, expected_version, tenant_id)
             .await
             .map_err(|e| match e.kind() {
-                InfraErrorKind::Conflict { .. } => CoreError::Conflict(
-                    "ステップは既に更新されています。最新の情報を取得してください。".to_string(),
-                ),
+                InfraErrorKind::Conflict { .. } => {
+                    CoreError::Conflict(Message::new("step-version-conflict"))
+                }
                 _ => CoreError::Internal(format!("ステップの保存に失敗: {}", e)),
             })
     }
@@ -63,10 +80,9 @@ impl WorkflowUseCaseImpl {
             .update_with_version_check(tx, instance, expected_version, tenant_id)
             .await
             .map_err(|e| match e.kind() {
-                InfraErrorKind::Conflict { .. } => CoreError::Conflict(
-                    "インスタンスは既に更新されています。最新の情報を取得してください。"
-                        .to_string(),
-                ),
+                InfraErrorKind::Conflict { .. } => {
+                    CoreError::Conflict(Message::new("instance-version-conflict"))
+                }
                 _ => CoreError::Internal(format!("インスタンスの保存に失敗: {}", e)),
             })
     }
@@ -83,4 +99,108 @@ impl WorkflowUseCaseImpl {
             .await
             .map_err(|e| CoreError::Internal(format!("ステップの取得に失敗: {}", e)))
     }
+
+    /// アクティビティをトランザクション内で記録する
+    pub(super) async fn save_activities(
+        &self,
+        tx: &mut TxContext,
+        activities: &[WorkflowActivity],
+        tenant_id: &TenantId,
+    ) -> Result<(), CoreError> {
+        self.deps
+            .activity_repo
+            .insert_all(tx, activities, tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("アクティビティの保存に失敗: {}", e)))
+    }
+
+    /// ドメインイベントをトランザクション内でアウトボックスに書き込む
+    pub(super) async fn save_event(
+        &self,
+        tx: &mut TxContext,
+        event: &WorkflowEvent,
+        tenant_id: &TenantId,
+    ) -> Result<(), CoreError> {
+        self.deps
+            .outbox_repo
+            .insert_all(tx, std::slice::from_ref(event), tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("ドメインイベントの保存に失敗: {}", e)))
+    }
+}
+
+/// 遷移後のインスタンスとステップからドメインイベントを構築する
+pub(super) fn workflow_event(
+    instance: &WorkflowInstance,
+    event_type: WorkflowEventType,
+    actor_id: &UserId,
+    step: Option<&WorkflowStep>,
+    activated_step: Option<&WorkflowStep>,
+    now: DateTime<Utc>,
+) -> WorkflowEvent {
+    WorkflowEvent::new(NewWorkflowEvent {
+        id: WorkflowEventId::new(),
+        tenant_id: instance.tenant_id().clone(),
+        instance_id: instance.id().clone(),
+        event_type,
+        actor_id: Some(actor_id.clone()),
+        payload: WorkflowEventPayload::new(instance, step, activated_step),
+        now,
+    })
+}
+
+/// インスタンスに対するアクティビティを構築する
+pub(super) fn instance_activity(
+    instance: &WorkflowInstance,
+    activity_type: WorkflowActivityType,
+    actor_id: &UserId,
+    details: JsonValue,
+    now: DateTime<Utc>,
+) -> WorkflowActivity {
+    WorkflowActivity::new(NewWorkflowActivity {
+        id: WorkflowActivityId::new(),
+        tenant_id: instance.tenant_id().clone(),
+        instance_id: instance.id().clone(),
+        activity_type,
+        actor_id: Some(actor_id.clone()),
+        step_id: None,
+        details,
+        now,
+    })
+}
+
+/// ステップに対するアクティビティを構築する
+///
+/// `StepActivated` は担当者を、承認・却下・差し戻しはコメントを details に含める。
+/// `ApprovalRetracted` はステップ名のみを含める。
+pub(super) fn step_activity(
+    step: &WorkflowStep,
+    tenant_id: &TenantId,
+    activity_type: WorkflowActivityType,
+    actor_id: &UserId,
+    now: DateTime<Utc>,
+) -> WorkflowActivity {
+    let details = match activity_type {
+        WorkflowActivityType::StepActivated => json!({
+            "step_name": step.step_name(),
+            "assigned_to": step.assigned_to().map(|id| id.to_string()),
+        }),
+        WorkflowActivityType::ApprovalRetracted => json!({
+            "step_name": step.step_name(),
+        }),
+        _ => json!({
+            "step_name": step.step_name(),
+            "comment": step.comment(),
+        }),
+    };
+    WorkflowActivity::new(NewWorkflowActivity {
+        id: WorkflowActivityId::new(),
+        tenant_id: tenant_id.clone(),
+        instance_id: step.instance_id().clone(),
+        activity_type,
+        actor_id: Some(actor_id.clone()),
+        step_id: Some(step.id().clone()),
+        details,
+        now,
+    })
 }
@@ -1,6 +1,8 @@
-//! ワークフローのライフサイクル管理（作成・申請・再申請）
+//! ワークフローのライフサイクル管理（作成・複製・申請・再申請・削除）
 
 mod common;
 mod create;
+mod delete;
+mod duplicate;
 mod resubmit;
 mod submit;
@@ -1,22 +1,33 @@
 //! submit / resubmit の共通ヘルパー
 //!
-//! approvers 検証とステップ作成ループは submit / resubmit で同一のため共通化する。
+//! approvers 検証とステップ作成ループ、申請スナップショット・アクティビティ・ドメインイベントの作成は
+//! submit / resubmit で同一のため共通化する。
 
 use chrono::{DateTime, Utc};
 use ringiflow_domain::{
-    notification::WorkflowNotification,
+    message::Message,
     tenant::TenantId,
-    value_objects::{DisplayId, DisplayIdEntityType, display_prefix},
+    value_objects::DisplayIdEntityType,
     workflow::{
         ApprovalStepDef,
         NewWorkflowStep,
+        NewWorkflowSubmission,
+        SubmissionAttachment,
+        WorkflowActivity,
+        WorkflowActivityType,
+        WorkflowEvent,
+        WorkflowEventType,
         WorkflowInstance,
         WorkflowInstanceId,
         WorkflowStep,
         WorkflowStepId,
+        WorkflowStepStatus,
+        WorkflowSubmission,
+        WorkflowSubmissionId,
     },
 };
 
+use super::super::helpers::{instance_activity, step_activity, workflow_event};
 use crate::{
     error::CoreError,
     usecase::workflow::{StepApprover, WorkflowUseCaseImpl},
@@ -26,30 +37,102 @@ use crate::{
 ///
 /// - 数の一致チェック
 /// - 各 step_id の一致チェック
+/// - 代理申請の場合、本人と代理人を承認者に指定していないかのチェック
+///   （承認者の規則は本人を基準に適用し、申請に関与した代理人による承認も防ぐ）
 pub(super) fn validate_approvers(
+    instance: &WorkflowInstance,
     approvers: &[StepApprover],
     approval_step_defs: &[ApprovalStepDef],
 ) -> Result<(), CoreError> {
     if approvers.len() != approval_step_defs.len() {
-        return Err(CoreError::BadRequest(format!(
-            "承認者の数({})が定義のステップ数({})と一致しません",
-            approvers.len(),
-            approval_step_defs.len()
-        )));
+        return Err(CoreError::BadRequest(
+            Message::new("approver-count-mismatch")
+                .with("count", approvers.len())
+                .with("expected", approval_step_defs.len()),
+        ));
     }
 
     for (approver, step_def) in approvers.iter().zip(approval_step_defs) {
         if approver.step_id != step_def.id {
-            return Err(CoreError::BadRequest(format!(
-                "承認者のステップ ID({})が定義のステップ ID({})と一致しません",
-                approver.step_id, step_def.id
-            )));
+            return Err(CoreError::BadRequest(
+                Message::new("approver-step-id-mismatch")
+                    .with("step_id", &approver.step_id)
+                    .with("expected", &step_def.id),
+            ));
         }
     }
 
+    if instance.is_proxy_submission()
+        && approvers
+            .iter()
+            .any(|approver| instance.is_applicant(&approver.assigned_to))
+    {
+        return Err(CoreError::BadRequest(Message::new(
+            "proxy-approver-conflict",
+        )));
+    }
+
     Ok(())
 }
 
+/// 申請・再申請のアクティビティを構築する
+///
+/// 申請（または再申請）と、Active になった最初のステップのアクティブ化を記録する。
+/// 操作者はいずれも申請操作者（代理申請の場合は代理人）。
+pub(super) fn build_submission_activities(
+    instance: &WorkflowInstance,
+    steps: &[WorkflowStep],
+    activity_type: WorkflowActivityType,
+    round: i32,
+    now: DateTime<Utc>,
+) -> Vec<WorkflowActivity> {
+    let actor_id = instance.submitted_by();
+    std::iter::once(instance_activity(
+        instance,
+        activity_type,
+        actor_id,
+        serde_json::json!({ "round": round }),
+        now,
+    ))
+    .chain(
+        steps
+            .iter()
+            .filter(|s| s.status() == WorkflowStepStatus::Active)
+            .map(|s| {
+                step_activity(
+                    s,
+                    instance.tenant_id(),
+                    WorkflowActivityType::StepActivated,
+                    actor_id,
+                    now,
+                )
+            }),
+    )
+    .collect()
+}
+
+/// 申請・再申請のドメインイベントを構築する
+///
+/// Active になった最初のステップを `activated_step` に含める（承認依頼通知の送信先）。
+pub(super) fn build_submission_event(
+    instance: &WorkflowInstance,
+    steps: &[WorkflowStep],
+    event_type: WorkflowEventType,
+    now: DateTime<Utc>,
+) -> WorkflowEvent {
+    let active_step = steps
+        .iter()
+        .find(|s| s.status() == WorkflowStepStatus::Active);
+    workflow_event(
+        instance,
+        event_type,
+        instance.submitted_by(),
+        None,
+        active_step,
+        now,
+    )
+}
+
 impl WorkflowUseCaseImpl {
     /// 定義と approvers に基づいて承認ステップを作成する
     ///
@@ -91,91 +174,43 @@ impl WorkflowUseCaseImpl {
         Ok(steps)
     }
 
-    /// 承認依頼通知を送信する（fire-and-forget）
+    /// 申請時点のフォームデータと添付ファイル一覧のスナップショットを作成する
     ///
-    /// 最初の Active ステップの承認者に対して通知メールを送信する。
-    /// ユーザー情報の取得失敗や通知送信の失敗はログ出力のみで、
-    /// ワークフロー操作の結果には影響しない。
-    pub(in crate::usecase::workflow::command) async fn send_approval_request_notification(
+    /// ラウンド番号は既存スナップショットの最大ラウンド + 1（初回申請は 1）。
+    /// `instance` は申請・再申請の遷移後（フォームデータ更新後）のインスタンスを渡す。
+    pub(super) async fn build_submission_snapshot(
         &self,
         instance: &WorkflowInstance,
-        steps: &[WorkflowStep],
         tenant_id: &TenantId,
-    ) {
-        // Active ステップ（最初の承認ステップ）を取得
-        let Some(active_step) = steps
-            .iter()
-            .find(|s| s.status() == ringiflow_domain::workflow::WorkflowStepStatus::Active)
-        else {
-            return;
-        };
-
-        // 承認者のユーザー ID を取得
-        let Some(approver_id) = active_step.assigned_to() else {
-            return;
-        };
-
-        // 申請者の情報を取得
-        let applicant = match self
+        now: DateTime<Utc>,
+    ) -> Result<WorkflowSubmission, CoreError> {
+        let previous = self
             .deps
-            .user_repo
-            .find_by_id(instance.initiated_by())
+            .submission_repo
+            .find_by_instance(instance.id(), tenant_id)
             .await
-        {
-            Ok(Some(user)) => user,
-            Ok(None) => {
-                tracing::warn!(
-                    user_id = %instance.initiated_by(),
-                    "通知用の申請者情報が見つかりません"
-                );
-                return;
-            }
-            Err(e) => {
-                tracing::warn!(
-                    error = %e,
-                    user_id = %instance.initiated_by(),
-                    "通知用の申請者情報の取得に失敗"
-                );
-                return;
-            }
-        };
-
-        // 承認者の情報を取得
-        let approver = match self.deps.user_repo.find_by_id(approver_id).await {
-            Ok(Some(user)) => user,
-            Ok(None) => {
-                tracing::warn!(
-                    user_id = %approver_id,
-                    "通知用の承認者情報が見つかりません"
-                );
-                return;
-            }
-            Err(e) => {
-                tracing::warn!(
-                    error = %e,
-                    user_id = %approver_id,
-                    "通知用の承認者情報の取得に失敗"
-                );
-                return;
-            }
-        };
-
-        let workflow_display_id =
-            DisplayId::new(display_prefix::WORKFLOW_INSTANCE, instance.display_number())
-                .to_string();
-
-        let notification = WorkflowNotification::ApprovalRequest {
-            workflow_title: instance.title().to_string(),
-            workflow_display_id,
-            applicant_name: applicant.name().as_str().to_string(),
-            step_name: active_step.step_name().to_string(),
-            approver_email: approver.email().as_str().to_string(),
-            approver_user_id: approver_id.clone(),
-        };
-
-        self.deps
-            .notification_service
-            .notify(notification, tenant_id, instance.id())
-            .await;
+            .map_err(|e| CoreError::Internal(format!("申請スナップショットの取得に失敗: {}", e)))?;
+        let round = previous.last().map_or(1, |s| s.round() + 1);
+
+        let attachments = self
+            .deps
+            .document_repo
+            .list_by_workflow(instance.id(), tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("添付ファイルの取得に失敗: {}", e)))?
+            .iter()
+            .map(SubmissionAttachment::from)
+            .collect();
+
+        Ok(WorkflowSubmission::new(NewWorkflowSubmission {
+            id: WorkflowSubmissionId::new(),
+            tenant_id: tenant_id.clone(),
+            instance_id: instance.id().clone(),
+            round,
+            form_data: instance.form_data().clone(),
+            attachments,
+            submitted_by: instance.submitted_by().clone(),
+            now,
+        }))
     }
 }
@@ -1,13 +1,21 @@
 //! ワークフローの作成（下書き）
 
 use ringiflow_domain::{
+    message::Message,
     tenant::TenantId,
     user::UserId,
     value_objects::DisplayIdEntityType,
-    workflow::{NewWorkflowInstance, WorkflowInstance, WorkflowInstanceId},
+    workflow::{
+        NewWorkflowInstance,
+        WorkflowActivityType,
+        WorkflowInstance,
+        WorkflowInstanceId,
+        WorkflowWatcher,
+    },
 };
 use ringiflow_shared::{event_log::event, log_business_event};
 
+use super::super::helpers::instance_activity;
 use crate::{
     error::CoreError,
     usecase::{
@@ -23,13 +31,17 @@ impl WorkflowUseCaseImpl {
     ///
     /// 1. ワークフロー定義が存在するか確認
     /// 2. 公開済み (published) であるか確認
-    /// 3. WorkflowInstance を draft として作成
-    /// 4. リポジトリに保存
+    /// 3. 代理作成の場合は、本人から代理権限を付与されているか確認
+    /// 4. WorkflowInstance を draft として作成
+    /// 5. リポジトリに保存（作成のアクティビティと、定義の既定ウォッチャーも記録）
+    ///
+    /// 代理作成では `initiated_by` が本人、`submitted_by` が操作ユーザー（代理人）になる。
     ///
     /// ## エラー
     ///
     /// - ワークフロー定義が見つからない場合
     /// - ワークフロー定義が公開されていない場合
+    /// - 本人から代理権限を付与されていない場合
     /// - データベースエラー
     pub async fn create_workflow(
         &self,
@@ -43,16 +55,33 @@ impl WorkflowUseCaseImpl {
             .definition_repo
             .find_by_id(&input.definition_id, &tenant_id)
             .await
-            .or_not_found("ワークフロー定義")?;
+            .or_not_found("workflow-definition")?;
 
         // 2. 公開済みであるか確認
         if definition.status() != ringiflow_domain::workflow::WorkflowDefinitionStatus::Published {
-            return Err(CoreError::BadRequest(
-                "公開されていないワークフロー定義です".to_string(),
-            ));
+            return Err(CoreError::BadRequest(Message::new(
+                "definition-not-published",
+            )));
         }
 
-        // 3. WorkflowInstance を draft として作成
+        // 3. 代理作成の場合は代理権限を確認
+        let (initiated_by, proxy_submitter) = match input.on_behalf_of {
+            Some(principal_id) if principal_id != user_id => {
+                let granted = self
+                    .deps
+                    .proxy_grant_repo
+                    .exists(&principal_id, &user_id, &tenant_id)
+                    .await
+                    .map_err(|e| CoreError::Internal(format!("代理権限の取得に失敗: {}", e)))?;
+                if !granted {
+                    return Err(CoreError::Forbidden(Message::new("proxy-submit-forbidden")));
+                }
+                (principal_id, Some(user_id))
+            }
+            _ => (user_id, None),
+        };
+
+        // 4. WorkflowInstance を draft として作成
         let now = self.deps.clock.now();
         let display_number = self
             .deps
@@ -68,11 +97,29 @@ impl WorkflowUseCaseImpl {
             display_number,
             title: input.title,
             form_data: input.form_data,
-            initiated_by: user_id,
+            initiated_by,
             now,
         });
+        let instance = match proxy_submitter {
+            Some(proxy_id) => instance.with_proxy_submitter(proxy_id),
+            None => instance,
+        };
 
-        // 4. リポジトリに保存
+        // 5. リポジトリに保存
+        let activity = instance_activity(
+            &instance,
+            WorkflowActivityType::Created,
+            instance.submitted_by(),
+            serde_json::json!({ "title": instance.title() }),
+            now,
+        );
+        // 申請者（代理申請の代理人を含む）は常に通知を受け取るため、既定ウォッチャーに含まれていても登録しない
+        let watchers: Vec<WorkflowWatcher> = definition
+            .default_watchers()
+            .iter()
+            .filter(|user_id| !instance.is_applicant(user_id))
+            .map(|user_id| WorkflowWatcher::new(instance.id().clone(), user_id.clone(), now))
+            .collect();
         let mut tx = self
             .deps
             .tx_manager
@@ -84,6 +131,13 @@ impl WorkflowUseCaseImpl {
             .insert(&mut tx, &instance)
             .await
             .map_err(|e| CoreError::Internal(format!("インスタンスの保存に失敗: {}", e)))?;
+        self.save_activities(&mut tx, &[activity], instance.tenant_id())
+            .await?;
+        self.deps
+            .watcher_repo
+            .insert_all(&mut tx, &watchers, instance.tenant_id())
+            .await
+            .map_err(|e| CoreError::Internal(format!("ウォッチャーの保存に失敗: {}", e)))?;
         tx.commit()
             .await
             .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))?;
@@ -93,7 +147,7 @@ impl WorkflowUseCaseImpl {
             event.action = event::action::WORKFLOW_CREATED,
             event.entity_type = event::entity_type::WORKFLOW_INSTANCE,
             event.entity_id = %instance.id(),
-            event.actor_id = %instance.initiated_by(),
+            event.actor_id = %instance.submitted_by(),
             event.tenant_id = %instance.tenant_id(),
             event.result = event::result::SUCCESS,
             "ワークフロー作成"
@@ -115,6 +169,8 @@ mod tests {
         workflow::{
             NewWorkflowDefinition,
             NewWorkflowInstance,
+            ProxyGrant,
+            WorkflowActivityType,
             WorkflowDefinition,
             WorkflowDefinitionId,
             WorkflowInstance,
@@ -123,24 +179,32 @@ mod tests {
     use ringiflow_infra::{
         fake::{
             FakeDisplayIdCounterRepository,
-            FakeNotificationLogRepository,
-            FakeNotificationSender,
+            FakeDocumentRepository,
+            FakeS3Client,
             FakeTransactionManager,
             FakeUserRepository,
+            FakeWorkflowActivityRepository,
             FakeWorkflowCommentRepository,
             FakeWorkflowDefinitionRepository,
+            FakeWorkflowEventOutboxRepository,
+            FakeWorkflowFormDataChangeRepository,
             FakeWorkflowInstanceRepository,
+            FakeWorkflowProxyGrantRepository,
             FakeWorkflowStepRepository,
+            FakeWorkflowSubmissionRepository,
+            FakeWorkflowWatcherRepository,
+        },
+        repository::{
+            WorkflowActivityRepository,
+            WorkflowInstanceRepository,
+            WorkflowProxyGrantRepository,
+            WorkflowWatcherRepository,
         },
-        repository::WorkflowInstanceRepository,
     };
 
     use crate::{
         error::CoreError,
-        usecase::{
-            notification::{NotificationService, TemplateRenderer},
-            workflow::{CreateWorkflowInput, WorkflowUseCaseDeps, WorkflowUseCaseImpl},
-        },
+        usecase::workflow::{CreateWorkflowInput, WorkflowUseCaseDeps, WorkflowUseCaseImpl},
     };
 
     #[tokio::test]
@@ -167,29 +231,31 @@ mod tests {
         let published_definition = definition.published(now).unwrap();
         definition_repo.add_definition(published_definition.clone());
 
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(FakeNotificationSender::new()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
-
+        let activity_repo = FakeWorkflowActivityRepository::new();
         let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
             definition_repo: Arc::new(definition_repo),
             instance_repo: Arc::new(instance_repo.clone()),
             step_repo: Arc::new(step_repo),
             comment_repo: Arc::new(FakeWorkflowCommentRepository::new()),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(activity_repo.clone()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
             user_repo: Arc::new(FakeUserRepository::new()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
             clock: Arc::new(FixedClock::new(now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         });
 
         let input = CreateWorkflowInput {
             definition_id: published_definition.id().clone(),
             title:         "テスト申請".to_string(),
             form_data:     serde_json::json!({"note": "test"}),
+            on_behalf_of:  None,
         };
 
         // Act
@@ -220,6 +286,15 @@ mod tests {
             .await
             .unwrap();
         assert_eq!(saved, Some(expected));
+
+        // 作成のアクティビティが記録されていることを確認
+        let activities = activity_repo
+            .find_by_instance(result.id(), &tenant_id)
+            .await
+            .unwrap();
+        assert_eq!(activities.len(), 1);
+        assert_eq!(activities[0].activity_type(), WorkflowActivityType::Created);
+        assert_eq!(activities[0].actor_id(), Some(&user_id));
     }
 
     #[tokio::test]
@@ -234,29 +309,30 @@ mod tests {
 
         let now = chrono::Utc::now();
 
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(FakeNotificationSender::new()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
-
         let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
             definition_repo: Arc::new(definition_repo),
             instance_repo: Arc::new(instance_repo),
             step_repo: Arc::new(step_repo),
             comment_repo: Arc::new(FakeWorkflowCommentRepository::new()),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
             user_repo: Arc::new(FakeUserRepository::new()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
             clock: Arc::new(FixedClock::new(now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         });
 
         let input = CreateWorkflowInput {
             definition_id: WorkflowDefinitionId::new(),
             title:         "テスト申請".to_string(),
             form_data:     serde_json::json!({}),
+            on_behalf_of:  None,
         };
 
         // Act
@@ -265,4 +341,210 @@ mod tests {
         // Assert
         assert!(matches!(result, Err(CoreError::NotFound(_))));
     }
+
+    #[tokio::test]
+    async fn test_create_workflow_定義の既定ウォッチャーが申請者を除いて登録される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let lead_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id: tenant_id.clone(),
+            name: WorkflowName::new("汎用申請").unwrap(),
+            description: None,
+            definition: serde_json::json!({"steps": []}),
+            created_by: user_id.clone(),
+            now,
+        })
+        .with_default_watchers(vec![lead_id.clone(), user_id.clone()])
+        .published(now)
+        .unwrap();
+        definition_repo.add_definition(definition.clone());
+
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
+            definition_repo: Arc::new(definition_repo),
+            instance_repo: Arc::new(FakeWorkflowInstanceRepository::new()),
+            step_repo: Arc::new(FakeWorkflowStepRepository::new()),
+            comment_repo: Arc::new(FakeWorkflowCommentRepository::new()),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(watcher_repo.clone()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
+            user_repo: Arc::new(FakeUserRepository::new()),
+            counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
+            clock: Arc::new(FixedClock::new(now)),
+            tx_manager: Arc::new(FakeTransactionManager),
+        });
+
+        let input = CreateWorkflowInput {
+            definition_id: definition.id().clone(),
+            title:         "テスト申請".to_string(),
+            form_data:     serde_json::json!({}),
+            on_behalf_of:  None,
+        };
+
+        // Act
+        let instance = sut
+            .create_workflow(input, tenant_id.clone(), user_id)
+            .await
+            .unwrap();
+
+        // Assert
+        let watchers = watcher_repo
+            .find_by_instance(instance.id(), &tenant_id)
+            .await
+            .unwrap();
+        let watcher_ids: Vec<_> = watchers.iter().map(|w| w.user_id().clone()).collect();
+        assert_eq!(watcher_ids, vec![lead_id]);
+    }
+
+    /// 代理作成テスト用の SUT を組み立てる
+    fn build_sut_for_proxy(
+        definition: &WorkflowDefinition,
+        proxy_grant_repo: &FakeWorkflowProxyGrantRepository,
+        activity_repo: &FakeWorkflowActivityRepository,
+        watcher_repo: &FakeWorkflowWatcherRepository,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> WorkflowUseCaseImpl {
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        definition_repo.add_definition(definition.clone());
+        WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
+            definition_repo: Arc::new(definition_repo),
+            instance_repo: Arc::new(FakeWorkflowInstanceRepository::new()),
+            step_repo: Arc::new(FakeWorkflowStepRepository::new()),
+            comment_repo: Arc::new(FakeWorkflowCommentRepository::new()),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(watcher_repo.clone()),
+            proxy_grant_repo: Arc::new(proxy_grant_repo.clone()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(activity_repo.clone()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
+            user_repo: Arc::new(FakeUserRepository::new()),
+            counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
+            clock: Arc::new(FixedClock::new(now)),
+            tx_manager: Arc::new(FakeTransactionManager),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_create_workflow_代理権限があれば本人を申請者として代理作成できる() {
+        // Arrange: 役員（本人）が秘書（代理人）に代理権限を付与している。秘書は既定ウォッチャー
+        let tenant_id = TenantId::new();
+        let executive_id = UserId::new();
+        let secretary_id = UserId::new();
+        let lead_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id: tenant_id.clone(),
+            name: WorkflowName::new("汎用申請").unwrap(),
+            description: None,
+            definition: serde_json::json!({"steps": []}),
+            created_by: executive_id.clone(),
+            now,
+        })
+        .with_default_watchers(vec![lead_id.clone(), secretary_id.clone()])
+        .published(now)
+        .unwrap();
+        let proxy_grant_repo = FakeWorkflowProxyGrantRepository::new();
+        proxy_grant_repo
+            .insert(
+                &ProxyGrant::new(executive_id.clone(), secretary_id.clone(), now),
+                &tenant_id,
+            )
+            .await
+            .unwrap();
+        let activity_repo = FakeWorkflowActivityRepository::new();
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        let sut = build_sut_for_proxy(
+            &definition,
+            &proxy_grant_repo,
+            &activity_repo,
+            &watcher_repo,
+            now,
+        );
+
+        let input = CreateWorkflowInput {
+            definition_id: definition.id().clone(),
+            title:         "役員出張申請".to_string(),
+            form_data:     serde_json::json!({}),
+            on_behalf_of:  Some(executive_id.clone()),
+        };
+
+        // Act
+        let instance = sut
+            .create_workflow(input, tenant_id.clone(), secretary_id.clone())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(instance.initiated_by(), &executive_id);
+        assert_eq!(instance.submitted_by(), &secretary_id);
+
+        let activities = activity_repo
+            .find_by_instance(instance.id(), &tenant_id)
+            .await
+            .unwrap();
+        assert_eq!(activities[0].actor_id(), Some(&secretary_id));
+
+        let watchers = watcher_repo
+            .find_by_instance(instance.id(), &tenant_id)
+            .await
+            .unwrap();
+        let watcher_ids: Vec<_> = watchers.iter().map(|w| w.user_id().clone()).collect();
+        assert_eq!(watcher_ids, vec![lead_id]);
+    }
+
+    #[tokio::test]
+    async fn test_create_workflow_代理権限がなければ代理作成できない() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let executive_id = UserId::new();
+        let other_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id: tenant_id.clone(),
+            name: WorkflowName::new("汎用申請").unwrap(),
+            description: None,
+            definition: serde_json::json!({"steps": []}),
+            created_by: executive_id.clone(),
+            now,
+        })
+        .published(now)
+        .unwrap();
+        let sut = build_sut_for_proxy(
+            &definition,
+            &FakeWorkflowProxyGrantRepository::new(),
+            &FakeWorkflowActivityRepository::new(),
+            &FakeWorkflowWatcherRepository::new(),
+            now,
+        );
+
+        let input = CreateWorkflowInput {
+            definition_id: definition.id().clone(),
+            title:         "役員出張申請".to_string(),
+            form_data:     serde_json::json!({}),
+            on_behalf_of:  Some(executive_id),
+        };
+
+        // Act
+        let result = sut.create_workflow(input, tenant_id, other_id).await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
 }
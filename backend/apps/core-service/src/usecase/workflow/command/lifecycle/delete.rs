@@ -0,0 +1,299 @@
+//! ワークフローの削除（申請前の下書きのみ）
+
+use ringiflow_domain::{
+    message::Message,
+    tenant::TenantId,
+    user::UserId,
+    value_objects::DisplayNumber,
+};
+use ringiflow_shared::{event_log::event, log_business_event};
+
+use crate::{
+    error::CoreError,
+    usecase::{
+        helpers::FindResultExt,
+        workflow::{WorkflowUseCaseImpl, draft_purge::remove_draft},
+    },
+};
+
+impl WorkflowUseCaseImpl {
+    /// 下書きのワークフローを削除する
+    ///
+    /// 申請者本人が、不要になった下書きを添付ファイルごと削除する。
+    /// 申請後のワークフローは監査のため削除できない。
+    ///
+    /// ## 処理フロー
+    ///
+    /// 1. ワークフローインスタンスを取得
+    /// 2. 申請者本人（または代理人）であるか確認
+    /// 3. 下書き状態であるか確認
+    /// 4. インスタンスと添付ファイルを削除
+    ///
+    /// ## エラー
+    ///
+    /// - インスタンスが見つからない場合: 404
+    /// - 申請者以外の場合: 403
+    /// - 下書き以外の場合: 400
+    /// - データベースエラー
+    pub async fn delete_workflow(
+        &self,
+        display_number: DisplayNumber,
+        tenant_id: TenantId,
+        user_id: UserId,
+    ) -> Result<(), CoreError> {
+        // 1. ワークフローインスタンスを取得
+        let instance = self
+            .deps
+            .instance_repo
+            .find_by_display_number(display_number, &tenant_id)
+            .await
+            .or_not_found("workflow-instance")?;
+
+        // 2. 権限チェック（申請者本人と代理人のみ削除可能）
+        if !instance.is_applicant(&user_id) {
+            return Err(CoreError::Forbidden(Message::new(
+                "workflow-delete-forbidden",
+            )));
+        }
+
+        // 3. 下書き状態であるか確認
+        instance
+            .can_delete()
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        // 4. インスタンスと添付ファイルを削除
+        let deleted = remove_draft(
+            self.deps.instance_repo.as_ref(),
+            self.deps.document_repo.as_ref(),
+            self.deps.s3_client.as_ref(),
+            self.deps.tx_manager.as_ref(),
+            &instance,
+        )
+        .await?;
+        if !deleted {
+            return Err(CoreError::Conflict(Message::new(
+                "instance-version-conflict",
+            )));
+        }
+
+        log_business_event!(
+            event.category = event::category::WORKFLOW,
+            event.action = event::action::WORKFLOW_DELETED,
+            event.entity_type = event::entity_type::WORKFLOW_INSTANCE,
+            event.entity_id = %instance.id(),
+            event.actor_id = %user_id,
+            event.tenant_id = %tenant_id,
+            event.result = event::result::SUCCESS,
+            "下書き削除"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ringiflow_domain::{
+        document::{Document, DocumentId, DocumentStatus, UploadContext},
+        tenant::TenantId,
+        user::UserId,
+        value_objects::DisplayNumber,
+        workflow::WorkflowInstance,
+    };
+    use ringiflow_infra::repository::{
+        DocumentRepository,
+        WorkflowInstanceRepository,
+        WorkflowInstanceRepositoryTestExt,
+    };
+
+    use crate::{error::CoreError, test_utils::WorkflowTestBuilder};
+
+    fn attachment(instance: &WorkflowInstance, s3_key: &str) -> Document {
+        let now = chrono::Utc::now();
+        Document::from_db(
+            DocumentId::new(),
+            instance.tenant_id().clone(),
+            "receipt.pdf".to_string(),
+            "application/pdf".to_string(),
+            1024,
+            s3_key.to_string(),
+            UploadContext::Workflow(instance.id().clone()),
+            DocumentStatus::Active,
+            Some(instance.initiated_by().clone()),
+            now,
+            now,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_delete_workflow_下書きと添付ファイルを削除する() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let tenant_id = builder.tenant_id().clone();
+        let setup = builder.build_workflow_usecase_impl();
+        let instance = builder.build_draft_instance("下書き", 100);
+        setup
+            .instance_repo
+            .insert_for_test(&instance)
+            .await
+            .unwrap();
+        setup
+            .document_repo
+            .add_document(attachment(&instance, "draft-key"));
+
+        // Act
+        setup
+            .sut
+            .delete_workflow(
+                DisplayNumber::new(100).unwrap(),
+                tenant_id.clone(),
+                builder.user_id().clone(),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(
+            setup
+                .instance_repo
+                .find_by_id(instance.id(), &tenant_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            !setup
+                .document_repo
+                .exists_by_s3_key("draft-key", &tenant_id)
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            setup.s3_client.deleted_keys(),
+            vec!["draft-key".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_workflow_複製元と共有するs3オブジェクトは削除しない() {
+        // Arrange: 複製元の申請と下書きが同じ S3 オブジェクトを参照している
+        let builder = WorkflowTestBuilder::new();
+        let tenant_id = builder.tenant_id().clone();
+        let setup = builder.build_workflow_usecase_impl();
+        let source = builder.build_submitted_instance("下書き", 99);
+        let instance = builder.build_draft_instance("下書き", 100);
+        setup.instance_repo.insert_for_test(&source).await.unwrap();
+        setup
+            .instance_repo
+            .insert_for_test(&instance)
+            .await
+            .unwrap();
+        setup
+            .document_repo
+            .add_document(attachment(&source, "shared-key"));
+        setup
+            .document_repo
+            .add_document(attachment(&instance, "shared-key"));
+
+        // Act
+        setup
+            .sut
+            .delete_workflow(
+                DisplayNumber::new(100).unwrap(),
+                tenant_id.clone(),
+                builder.user_id().clone(),
+            )
+            .await
+            .unwrap();
+
+        // Assert: 複製元の添付ファイルは残り、S3 オブジェクトも削除されない
+        let source_attachments = setup
+            .document_repo
+            .list_by_workflow(source.id(), &tenant_id)
+            .await
+            .unwrap();
+        assert_eq!(source_attachments.len(), 1);
+        assert!(setup.s3_client.deleted_keys().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_delete_workflow_申請者以外は403() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let setup = builder.build_workflow_usecase_impl();
+        let instance = builder.build_draft_instance("下書き", 100);
+        setup
+            .instance_repo
+            .insert_for_test(&instance)
+            .await
+            .unwrap();
+
+        // Act
+        let result = setup
+            .sut
+            .delete_workflow(
+                DisplayNumber::new(100).unwrap(),
+                builder.tenant_id().clone(),
+                UserId::new(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_workflow_申請済みは400() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let tenant_id = builder.tenant_id().clone();
+        let setup = builder.build_workflow_usecase_impl();
+        let instance = builder.build_submitted_instance("下書き", 100);
+        setup
+            .instance_repo
+            .insert_for_test(&instance)
+            .await
+            .unwrap();
+
+        // Act
+        let result = setup
+            .sut
+            .delete_workflow(
+                DisplayNumber::new(100).unwrap(),
+                tenant_id.clone(),
+                builder.user_id().clone(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+        assert!(
+            setup
+                .instance_repo
+                .find_by_id(instance.id(), &tenant_id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_workflow_存在しない場合は404() {
+        // Arrange
+        let setup = WorkflowTestBuilder::new().build_workflow_usecase_impl();
+
+        // Act
+        let result = setup
+            .sut
+            .delete_workflow(
+                DisplayNumber::new(100).unwrap(),
+                TenantId::new(),
+                UserId::new(),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+}
@@ -0,0 +1,313 @@
+//! ワークフローの複製（既存の申請から下書きを作成）
+
+use ringiflow_domain::{
+    document::{DocumentId, UploadContext},
+    tenant::TenantId,
+    value_objects::DisplayNumber,
+    workflow::{WorkflowInstance, WorkflowViewer, copyable_form_data},
+};
+
+use crate::{
+    error::CoreError,
+    usecase::{
+        helpers::FindResultExt,
+        workflow::{CreateWorkflowInput, DuplicateWorkflowInput, WorkflowUseCaseImpl},
+    },
+};
+
+impl WorkflowUseCaseImpl {
+    /// 既存のワークフローを複製して下書きを作成する
+    ///
+    /// 毎月の定例申請などを一から入力し直さずに済むよう、閲覧できる任意の
+    /// インスタンスからタイトルとフォームデータを引き継いだ下書きを作成する。
+    /// 作成者は閲覧者自身になる。
+    ///
+    /// ## 処理フロー
+    ///
+    /// 1. 複製元のインスタンスを取得（閲覧権限をチェック）
+    /// 2. 定義 JSON で `copyable: false` のフィールドをフォームデータから除外
+    /// 3. 定義の現在のバージョンで下書きを作成（公開済みであることを確認）
+    /// 4. 指定時は添付ファイルを複製（S3 オブジェクトは共有する）
+    ///
+    /// ## エラー
+    ///
+    /// - 複製元が見つからない、または閲覧権限がない場合: 404
+    /// - ワークフロー定義が公開されていない場合: 400
+    /// - データベースエラー
+    pub async fn duplicate_workflow(
+        &self,
+        input: DuplicateWorkflowInput,
+        display_number: DisplayNumber,
+        tenant_id: TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<WorkflowInstance, CoreError> {
+        // 1. 複製元のインスタンスを取得
+        let source = self
+            .find_visible_by_display_number(display_number, &tenant_id, viewer)
+            .await?;
+
+        // 2. 引き継ぐフォームデータを抽出
+        let definition = self
+            .deps
+            .definition_repo
+            .find_by_id(source.definition_id(), &tenant_id)
+            .await
+            .or_not_found("workflow-definition")?;
+        let form_data = copyable_form_data(definition.definition(), source.form_data());
+
+        // 3. 下書きを作成
+        // 公開済み定義は更新できないため、定義の現在のバージョンが最新の公開バージョンになる
+        let instance = self
+            .create_workflow(
+                CreateWorkflowInput {
+                    definition_id: source.definition_id().clone(),
+                    title: source.title().to_string(),
+                    form_data,
+                    on_behalf_of: None,
+                },
+                tenant_id.clone(),
+                viewer.user_id().clone(),
+            )
+            .await?;
+
+        // 4. 添付ファイルを複製
+        if input.copy_attachments {
+            let attachments = self
+                .deps
+                .document_repo
+                .list_by_workflow(source.id(), &tenant_id)
+                .await
+                .map_err(|e| CoreError::Internal(format!("添付ファイルの取得に失敗: {}", e)))?;
+            let now = self.deps.clock.now();
+            for attachment in &attachments {
+                let duplicated = attachment
+                    .duplicate(
+                        DocumentId::new(),
+                        UploadContext::Workflow(instance.id().clone()),
+                        Some(viewer.user_id().clone()),
+                        now,
+                    )
+                    .map_err(|e| CoreError::Internal(format!("添付ファイルの複製に失敗: {}", e)))?;
+                self.deps
+                    .document_repo
+                    .insert(&duplicated)
+                    .await
+                    .map_err(|e| CoreError::Internal(format!("添付ファイルの保存に失敗: {}", e)))?;
+            }
+        }
+
+        Ok(instance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ringiflow_domain::{
+        document::{Document, DocumentId, DocumentStatus, UploadContext},
+        user::UserId,
+        value_objects::{DisplayNumber, Version, WorkflowName},
+        workflow::{
+            NewWorkflowDefinition,
+            NewWorkflowInstance,
+            WorkflowDefinition,
+            WorkflowDefinitionId,
+            WorkflowInstance,
+            WorkflowInstanceId,
+            WorkflowInstanceStatus,
+            WorkflowViewer,
+        },
+    };
+    use ringiflow_infra::repository::{DocumentRepository, WorkflowInstanceRepositoryTestExt};
+
+    use crate::{
+        error::CoreError,
+        test_utils::{WorkflowTestBuilder, WorkflowTestSetup},
+        usecase::workflow::DuplicateWorkflowInput,
+    };
+
+    /// 公開済み定義と、その定義で申請済みのインスタンス（表示用連番 100）を用意する
+    ///
+    /// 申請者はビルダーのユーザー。
+    async fn setup_source(
+        builder: &WorkflowTestBuilder,
+        definition_json: serde_json::Value,
+    ) -> (WorkflowTestSetup, WorkflowInstance) {
+        let now = builder.now();
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id: builder.tenant_id().clone(),
+            name: WorkflowName::new("経費精算").unwrap(),
+            description: None,
+            definition: definition_json,
+            created_by: builder.user_id().clone(),
+            now,
+        })
+        .published(now)
+        .unwrap();
+        let source = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: builder.tenant_id().clone(),
+            definition_id: definition.id().clone(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "9月分 経費精算".to_string(),
+            form_data: serde_json::json!({"purpose": "定例", "amount": 12000}),
+            initiated_by: builder.user_id().clone(),
+            now,
+        })
+        .submitted(now)
+        .unwrap();
+
+        let setup = builder.build_workflow_usecase_impl();
+        setup.definition_repo.add_definition(definition);
+        setup.instance_repo.insert_for_test(&source).await.unwrap();
+        (setup, source)
+    }
+
+    fn attachment(instance: &WorkflowInstance) -> Document {
+        let now = chrono::Utc::now();
+        Document::from_db(
+            DocumentId::new(),
+            instance.tenant_id().clone(),
+            "receipt.pdf".to_string(),
+            "application/pdf".to_string(),
+            1024,
+            "receipt-key".to_string(),
+            UploadContext::Workflow(instance.id().clone()),
+            DocumentStatus::Active,
+            Some(instance.initiated_by().clone()),
+            now,
+            now,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_workflow_タイトルと複製可能なフォームデータを引き継いだ下書きを作成する()
+     {
+        // Arrange: amount は複製対象外
+        let builder = WorkflowTestBuilder::new();
+        let (setup, source) = setup_source(
+            &builder,
+            serde_json::json!({
+                "form": {
+                    "fields": [
+                        {"id": "purpose", "type": "text", "label": "目的"},
+                        {"id": "amount", "type": "number", "label": "金額", "copyable": false}
+                    ]
+                },
+                "steps": []
+            }),
+        )
+        .await;
+
+        // Act
+        let result = setup
+            .sut
+            .duplicate_workflow(
+                DuplicateWorkflowInput {
+                    copy_attachments: false,
+                },
+                DisplayNumber::new(100).unwrap(),
+                builder.tenant_id().clone(),
+                &WorkflowViewer::new(builder.user_id().clone(), false),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_ne!(result.id(), source.id());
+        assert_eq!(result.status(), WorkflowInstanceStatus::Draft);
+        assert_eq!(result.title(), "9月分 経費精算");
+        assert_eq!(result.form_data(), &serde_json::json!({"purpose": "定例"}));
+        assert_eq!(result.definition_id(), source.definition_id());
+        assert_eq!(result.initiated_by(), builder.user_id());
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_workflow_指定時は添付ファイルも複製する() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let tenant_id = builder.tenant_id().clone();
+        let (setup, source) = setup_source(&builder, serde_json::json!({"steps": []})).await;
+        setup.document_repo.add_document(attachment(&source));
+
+        // Act
+        let result = setup
+            .sut
+            .duplicate_workflow(
+                DuplicateWorkflowInput {
+                    copy_attachments: true,
+                },
+                DisplayNumber::new(100).unwrap(),
+                tenant_id.clone(),
+                &WorkflowViewer::new(builder.user_id().clone(), false),
+            )
+            .await
+            .unwrap();
+
+        // Assert: 複製先にも同じ S3 オブジェクトを参照する添付ファイルがある
+        let attachments = setup
+            .document_repo
+            .list_by_workflow(result.id(), &tenant_id)
+            .await
+            .unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].s3_key(), "receipt-key");
+        assert_eq!(attachments[0].filename(), "receipt.pdf");
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_workflow_未指定時は添付ファイルを複製しない() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let tenant_id = builder.tenant_id().clone();
+        let (setup, source) = setup_source(&builder, serde_json::json!({"steps": []})).await;
+        setup.document_repo.add_document(attachment(&source));
+
+        // Act
+        let result = setup
+            .sut
+            .duplicate_workflow(
+                DuplicateWorkflowInput {
+                    copy_attachments: false,
+                },
+                DisplayNumber::new(100).unwrap(),
+                tenant_id.clone(),
+                &WorkflowViewer::new(builder.user_id().clone(), false),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        let attachments = setup
+            .document_repo
+            .list_by_workflow(result.id(), &tenant_id)
+            .await
+            .unwrap();
+        assert!(attachments.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_workflow_閲覧できないインスタンスは複製できない() {
+        // Arrange
+        let builder = WorkflowTestBuilder::new();
+        let (setup, _) = setup_source(&builder, serde_json::json!({"steps": []})).await;
+
+        // Act
+        let result = setup
+            .sut
+            .duplicate_workflow(
+                DuplicateWorkflowInput {
+                    copy_attachments: false,
+                },
+                DisplayNumber::new(100).unwrap(),
+                builder.tenant_id().clone(),
+                &WorkflowViewer::new(UserId::new(), false),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+}
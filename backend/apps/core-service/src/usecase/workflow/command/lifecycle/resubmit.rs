@@ -1,14 +1,20 @@
 //! ワークフローの再申請
 
 use ringiflow_domain::{
+    message::Message,
     tenant::TenantId,
     user::UserId,
     value_objects::DisplayNumber,
-    workflow::{WorkflowInstanceId, WorkflowInstanceStatus},
+    workflow::{
+        WorkflowActivityType,
+        WorkflowEventType,
+        WorkflowInstanceId,
+        WorkflowInstanceStatus,
+    },
 };
 use ringiflow_shared::{event_log::event, log_business_event};
 
-use super::common::validate_approvers;
+use super::common::{build_submission_activities, build_submission_event, validate_approvers};
 use crate::{
     error::CoreError,
     usecase::{
@@ -24,13 +30,14 @@ impl WorkflowUseCaseImpl {
     ///
     /// 1. ワークフローインスタンスを取得
     /// 2. ChangesRequested 状態であるか確認
-    /// 3. 権限チェック（申請者本人のみ再申請可能）
+    /// 3. 権限チェック（申請者本人と代理人のみ再申請可能）
     /// 4. 楽観的ロック（バージョン一致チェック）
     /// 5. ワークフロー定義を取得し、承認ステップを抽出
     /// 6. approvers との整合性を検証
     /// 7. 新しい承認ステップを作成
     /// 8. インスタンスを InProgress に遷移（form_data 更新）
-    /// 9. 保存
+    /// 9. 申請スナップショット（次のラウンド）、アクティビティ、ドメインイベントを作成
+    /// 10. 保存
     ///
     /// ## エラー
     ///
@@ -52,27 +59,25 @@ impl WorkflowUseCaseImpl {
             .instance_repo
             .find_by_id(&instance_id, &tenant_id)
             .await
-            .or_not_found("ワークフローインスタンス")?;
+            .or_not_found("workflow-instance")?;
 
         // 2. ChangesRequested 状態であるか確認
         if instance.status() != WorkflowInstanceStatus::ChangesRequested {
-            return Err(CoreError::BadRequest(
-                "要修正状態のワークフローのみ再申請できます".to_string(),
-            ));
+            return Err(CoreError::BadRequest(Message::new(
+                "resubmit-not-changes-requested",
+            )));
         }
 
-        // 3. 権限チェック（申請者本人のみ再申請可能）
-        if instance.initiated_by() != &user_id {
-            return Err(CoreError::Forbidden(
-                "このワークフローを再申請する権限がありません".to_string(),
-            ));
+        // 3. 権限チェック（申請者本人と代理人のみ再申請可能）
+        if !instance.is_applicant(&user_id) {
+            return Err(CoreError::Forbidden(Message::new("resubmit-forbidden")));
         }
 
         // 4. 楽観的ロック（バージョン一致チェック — 早期フェイル）
         if instance.version() != input.version {
-            return Err(CoreError::Conflict(
-                "インスタンスは既に更新されています。最新の情報を取得してください。".to_string(),
-            ));
+            return Err(CoreError::Conflict(Message::new(
+                "instance-version-conflict",
+            )));
         }
 
         // 5. ワークフロー定義を取得
@@ -81,15 +86,15 @@ impl WorkflowUseCaseImpl {
             .definition_repo
             .find_by_id(instance.definition_id(), &tenant_id)
             .await
-            .or_not_found("ワークフロー定義")?;
+            .or_not_found("workflow-definition")?;
 
         // 定義から承認ステップを抽出
         let approval_step_defs = definition
             .extract_approval_steps()
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         // 6. approvers と定義のステップの整合性を検証
-        validate_approvers(&input.approvers, &approval_step_defs)?;
+        validate_approvers(&instance, &input.approvers, &approval_step_defs)?;
 
         // 7. 新しい承認ステップを作成
         let now = self.deps.clock.now();
@@ -108,9 +113,28 @@ impl WorkflowUseCaseImpl {
         let first_step_id = approval_step_defs[0].id.clone();
         let resubmitted_instance = instance
             .resubmitted(input.form_data, first_step_id, now)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+
+        // 9. 再申請時点のスナップショット、アクティビティ、ドメインイベントを作成
+        let submission = self
+            .build_submission_snapshot(&resubmitted_instance, &tenant_id, now)
+            .await?;
+        let activities = build_submission_activities(
+            &resubmitted_instance,
+            &steps,
+            WorkflowActivityType::Resubmitted,
+            submission.round(),
+            now,
+        );
+        let domain_event = build_submission_event(
+            &resubmitted_instance,
+            &steps,
+            WorkflowEventType::Resubmitted,
+            now,
+        );
 
-        // 9. インスタンスとステップを保存（単一トランザクション）
+        // 10. インスタンスとステップ、申請スナップショット、アクティビティ、ドメインイベントを保存
+        //     （単一トランザクション）
         let mut tx = self.begin_tx().await?;
         self.save_instance(
             &mut tx,
@@ -126,6 +150,14 @@ impl WorkflowUseCaseImpl {
                 .await
                 .map_err(|e| CoreError::Internal(format!("ステップの保存に失敗: {}", e)))?;
         }
+        self.deps
+            .submission_repo
+            .insert(&mut tx, &submission, &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("申請スナップショットの保存に失敗: {}", e)))?;
+        self.save_activities(&mut tx, &activities, &tenant_id)
+            .await?;
+        self.save_event(&mut tx, &domain_event, &tenant_id).await?;
         self.commit_tx(tx).await?;
 
         log_business_event!(
@@ -139,10 +171,6 @@ impl WorkflowUseCaseImpl {
             "ワークフロー再申請"
         );
 
-        // 承認依頼通知を送信（fire-and-forget）
-        self.send_approval_request_notification(&resubmitted_instance, &steps, &tenant_id)
-            .await;
-
         Ok(WorkflowWithSteps {
             instance: resubmitted_instance,
             steps,
@@ -163,7 +191,7 @@ impl WorkflowUseCaseImpl {
             .instance_repo
             .find_by_display_number(display_number, &tenant_id)
             .await
-            .or_not_found("ワークフローインスタンス")?;
+            .or_not_found("workflow-instance")?;
 
         // 既存の resubmit_workflow を呼び出し
         self.resubmit_workflow(input, instance.id().clone(), tenant_id, user_id)
@@ -173,39 +201,44 @@ impl WorkflowUseCaseImpl {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
-
     use ringiflow_domain::{
         tenant::TenantId,
-        user::{Email, User, UserId},
-        value_objects::{DisplayNumber, UserName, Version, WorkflowName},
+        user::UserId,
+        value_objects::{DisplayNumber, Version, WorkflowName},
         workflow::{
+            FormFieldDiff,
             NewWorkflowDefinition,
             NewWorkflowInstance,
             WorkflowDefinition,
             WorkflowDefinitionId,
+            WorkflowEventType,
             WorkflowInstance,
             WorkflowInstanceId,
+            WorkflowViewer,
         },
     };
     use ringiflow_infra::{
         fake::{
-            FakeUserRepository,
             FakeWorkflowDefinitionRepository,
             FakeWorkflowInstanceRepository,
             FakeWorkflowStepRepository,
         },
-        repository::WorkflowInstanceRepositoryTestExt,
+        repository::{WorkflowInstanceRepositoryTestExt, WorkflowStepRepository},
     };
 
     use super::super::super::test_helpers::{
         build_sut,
-        build_sut_with_notification,
+        build_sut_with_outbox,
         single_approval_definition_json,
     };
     use crate::{
         error::CoreError,
-        usecase::workflow::{ResubmitWorkflowInput, StepApprover},
+        usecase::workflow::{
+            ApproveRejectInput,
+            ResubmitWorkflowInput,
+            StepApprover,
+            SubmitWorkflowInput,
+        },
     };
 
     #[tokio::test]
@@ -299,10 +332,137 @@ mod tests {
         );
     }
 
-    // ===== 通知テスト =====
+    #[tokio::test]
+    async fn test_resubmit_workflow_申請ラウンドごとのスナップショットが記録され差分を取得できる() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let approver_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id: tenant_id.clone(),
+            name: WorkflowName::new("汎用申請").unwrap(),
+            description: None,
+            definition: single_approval_definition_json(),
+            created_by: user_id.clone(),
+            now,
+        })
+        .published(now)
+        .unwrap();
+        definition_repo.add_definition(definition.clone());
+
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: definition.id().clone(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "テスト申請".to_string(),
+            form_data: serde_json::json!({"amount": 1000, "note": "original"}),
+            initiated_by: user_id.clone(),
+            now,
+        });
+        instance_repo.insert_for_test(&instance).await.unwrap();
+
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
+        let approvers = vec![StepApprover {
+            step_id:     "approval".to_string(),
+            assigned_to: approver_id.clone(),
+        }];
+
+        // 初回申請 → 差し戻し
+        sut.submit_workflow(
+            SubmitWorkflowInput {
+                approvers: approvers.clone(),
+            },
+            instance.id().clone(),
+            tenant_id.clone(),
+        )
+        .await
+        .unwrap();
+        let step = step_repo
+            .find_by_instance(instance.id(), &tenant_id)
+            .await
+            .unwrap()
+            .remove(0);
+        let changes_requested = sut
+            .request_changes_step(
+                ApproveRejectInput {
+                    version:         step.version(),
+                    comment:         Some("金額を見直してください".to_string()),
+                    form_data_edits: None,
+                },
+                step.id().clone(),
+                tenant_id.clone(),
+                approver_id.clone(),
+            )
+            .await
+            .unwrap();
+
+        // Act
+        sut.resubmit_workflow(
+            ResubmitWorkflowInput {
+                form_data: serde_json::json!({"amount": 800, "note": "original"}),
+                approvers,
+                version: changes_requested.instance.version(),
+            },
+            instance.id().clone(),
+            tenant_id.clone(),
+            user_id.clone(),
+        )
+        .await
+        .unwrap();
+
+        // Assert
+        let display_number = DisplayNumber::new(100).unwrap();
+        let submissions = sut
+            .list_submissions(
+                display_number,
+                tenant_id.clone(),
+                &WorkflowViewer::new(user_id.clone(), false),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            submissions.iter().map(|s| s.round()).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            submissions[0].form_data(),
+            &serde_json::json!({"amount": 1000, "note": "original"})
+        );
+
+        let diff = sut
+            .diff_submissions(
+                display_number,
+                None,
+                None,
+                tenant_id,
+                &WorkflowViewer::new(user_id, false),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            diff.form_data,
+            vec![FormFieldDiff {
+                field_id:  "amount".to_string(),
+                old_value: serde_json::json!(1000),
+                new_value: serde_json::json!(800),
+            }]
+        );
+        assert_eq!((diff.from_round, diff.to_round), (1, 2));
+    }
+
+    // ===== ドメインイベントテスト =====
 
     #[tokio::test]
-    async fn test_resubmit_workflow_正常系で承認依頼通知が送信される() {
+    async fn test_resubmit_workflow_resubmittedイベントが最初のステップ付きで記録される() {
         // Arrange
         let tenant_id = TenantId::new();
         let user_id = UserId::new();
@@ -346,32 +506,8 @@ mod tests {
         .unwrap();
         instance_repo.insert_for_test(&instance).await.unwrap();
 
-        // ユーザー情報を Fake に登録
-        let user_repo = FakeUserRepository::new();
-        user_repo.add_user(User::new(
-            user_id.clone(),
-            tenant_id.clone(),
-            DisplayNumber::new(1).unwrap(),
-            Email::new("tanaka@example.com").unwrap(),
-            UserName::new("田中太郎").unwrap(),
-            now,
-        ));
-        user_repo.add_user(User::new(
-            approver_id.clone(),
-            tenant_id.clone(),
-            DisplayNumber::new(2).unwrap(),
-            Email::new("suzuki@example.com").unwrap(),
-            UserName::new("鈴木一郎").unwrap(),
-            now,
-        ));
-
-        let (sut, sender) = build_sut_with_notification(
-            &definition_repo,
-            &instance_repo,
-            &step_repo,
-            Arc::new(user_repo),
-            now,
-        );
+        let (sut, outbox_repo) =
+            build_sut_with_outbox(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = ResubmitWorkflowInput {
             form_data: serde_json::json!({"note": "updated"}),
@@ -395,15 +531,15 @@ mod tests {
         // Assert: ワークフロー操作は成功
         assert!(result.is_ok());
 
-        // Assert: 承認依頼通知が送信されている
-        let sent = sender.sent_emails();
-        assert_eq!(sent.len(), 1, "承認依頼メールが1通送信されるべき");
-        assert_eq!(sent[0].to, "suzuki@example.com");
-        assert!(
-            sent[0].subject.contains("テスト申請"),
-            "件名にワークフロータイトルが含まれるべき: {}",
-            sent[0].subject
-        );
+        // Assert: Resubmitted イベントに最初にアクティブになったステップが含まれる
+        let events = outbox_repo.events();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.event_type(), WorkflowEventType::Resubmitted);
+        assert_eq!(event.actor_id(), Some(&user_id));
+        let activated_step = event.payload().activated_step.as_ref().unwrap();
+        assert_eq!(activated_step.step_name, "承認");
+        assert_eq!(activated_step.assigned_to.as_ref(), Some(&approver_id));
     }
 
     #[tokio::test]
@@ -1,13 +1,20 @@
 //! ワークフローの申請
 
 use ringiflow_domain::{
+    message::Message,
     tenant::TenantId,
     value_objects::DisplayNumber,
-    workflow::{WorkflowInstance, WorkflowInstanceId, WorkflowInstanceStatus},
+    workflow::{
+        WorkflowActivityType,
+        WorkflowEventType,
+        WorkflowInstance,
+        WorkflowInstanceId,
+        WorkflowInstanceStatus,
+    },
 };
 use ringiflow_shared::{event_log::event, log_business_event};
 
-use super::common::validate_approvers;
+use super::common::{build_submission_activities, build_submission_event, validate_approvers};
 use crate::{
     error::CoreError,
     usecase::{
@@ -30,7 +37,9 @@ impl WorkflowUseCaseImpl {
     /// 4. 定義から承認ステップを抽出し、approvers との整合性を検証
     /// 5. 各承認ステップを作成（最初を Active、残りを Pending）
     /// 6. ワークフローインスタンスを pending → in_progress に遷移
-    /// 7. インスタンスとステップをリポジトリに保存
+    /// 7. インスタンスとステップ、申請スナップショット（ラウンド 1）、
+    ///    アクティビティ（申請・最初のステップのアクティブ化）、
+    ///    ドメインイベント（`submitted`）をリポジトリに保存
     ///
     /// ## エラー
     ///
@@ -50,13 +59,11 @@ impl WorkflowUseCaseImpl {
             .instance_repo
             .find_by_id(&instance_id, &tenant_id)
             .await
-            .or_not_found("ワークフローインスタンス")?;
+            .or_not_found("workflow-instance")?;
 
         // 2. draft 状態であるか確認
         if instance.status() != WorkflowInstanceStatus::Draft {
-            return Err(CoreError::BadRequest(
-                "下書き状態のワークフローのみ申請できます".to_string(),
-            ));
+            return Err(CoreError::BadRequest(Message::new("submit-not-draft")));
         }
 
         // 3. ワークフロー定義を取得
@@ -65,15 +72,15 @@ impl WorkflowUseCaseImpl {
             .definition_repo
             .find_by_id(instance.definition_id(), &tenant_id)
             .await
-            .or_not_found("ワークフロー定義")?;
+            .or_not_found("workflow-definition")?;
 
         // 4. 定義から承認ステップを抽出
         let approval_step_defs = definition
             .extract_approval_steps()
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         // approvers と定義のステップの整合性を検証
-        validate_approvers(&input.approvers, &approval_step_defs)?;
+        validate_approvers(&instance, &input.approvers, &approval_step_defs)?;
 
         // 5. 各承認ステップを作成
         let now = self.deps.clock.now();
@@ -92,14 +99,31 @@ impl WorkflowUseCaseImpl {
         let first_step_id = approval_step_defs[0].id.clone();
         let submitted_instance = instance
             .submitted(now)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         // current_step_id を最初の承認ステップに設定して in_progress に遷移
         let in_progress_instance = submitted_instance
             .with_current_step(first_step_id, now)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
-        // 7. インスタンスとステップを保存（単一トランザクション）
+        // 7. インスタンスとステップ、申請スナップショット、アクティビティ、ドメインイベントを保存
+        //    （単一トランザクション）
+        let submission = self
+            .build_submission_snapshot(&in_progress_instance, &tenant_id, now)
+            .await?;
+        let activities = build_submission_activities(
+            &in_progress_instance,
+            &steps,
+            WorkflowActivityType::Submitted,
+            submission.round(),
+            now,
+        );
+        let domain_event = build_submission_event(
+            &in_progress_instance,
+            &steps,
+            WorkflowEventType::Submitted,
+            now,
+        );
         let mut tx = self.begin_tx().await?;
         self.save_instance(&mut tx, &in_progress_instance, expected_version, &tenant_id)
             .await?;
@@ -110,6 +134,14 @@ impl WorkflowUseCaseImpl {
                 .await
                 .map_err(|e| CoreError::Internal(format!("ステップの保存に失敗: {}", e)))?;
         }
+        self.deps
+            .submission_repo
+            .insert(&mut tx, &submission, &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("申請スナップショットの保存に失敗: {}", e)))?;
+        self.save_activities(&mut tx, &activities, &tenant_id)
+            .await?;
+        self.save_event(&mut tx, &domain_event, &tenant_id).await?;
         self.commit_tx(tx).await?;
 
         log_business_event!(
@@ -117,16 +149,12 @@ impl WorkflowUseCaseImpl {
             event.action = event::action::WORKFLOW_SUBMITTED,
             event.entity_type = event::entity_type::WORKFLOW_INSTANCE,
             event.entity_id = %instance_id,
-            event.actor_id = %in_progress_instance.initiated_by(),
+            event.actor_id = %in_progress_instance.submitted_by(),
             event.tenant_id = %tenant_id,
             event.result = event::result::SUCCESS,
             "ワークフロー申請"
         );
 
-        // 承認依頼通知を送信（fire-and-forget）
-        self.send_approval_request_notification(&in_progress_instance, &steps, &tenant_id)
-            .await;
-
         Ok(in_progress_instance)
     }
 
@@ -160,7 +188,7 @@ impl WorkflowUseCaseImpl {
             .instance_repo
             .find_by_display_number(display_number, &tenant_id)
             .await
-            .or_not_found("ワークフローインスタンス")?;
+            .or_not_found("workflow-instance")?;
 
         // 既存の submit_workflow を呼び出し
         self.submit_workflow(input, instance.id().clone(), tenant_id)
@@ -170,24 +198,24 @@ impl WorkflowUseCaseImpl {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::Arc;
-
     use ringiflow_domain::{
         tenant::TenantId,
-        user::{Email, User, UserId},
-        value_objects::{DisplayNumber, UserName, Version, WorkflowName},
+        user::UserId,
+        value_objects::{DisplayNumber, Version, WorkflowName},
         workflow::{
             NewWorkflowDefinition,
             NewWorkflowInstance,
+            WorkflowActivityType,
             WorkflowDefinition,
             WorkflowDefinitionId,
+            WorkflowEventType,
             WorkflowInstance,
             WorkflowInstanceId,
+            WorkflowViewer,
         },
     };
     use ringiflow_infra::{
         fake::{
-            FakeUserRepository,
             FakeWorkflowDefinitionRepository,
             FakeWorkflowInstanceRepository,
             FakeWorkflowStepRepository,
@@ -197,7 +225,7 @@ mod tests {
 
     use super::super::super::test_helpers::{
         build_sut,
-        build_sut_with_notification,
+        build_sut_with_outbox,
         single_approval_definition_json,
         two_step_approval_definition_json,
     };
@@ -427,103 +455,130 @@ mod tests {
         assert!(matches!(result, Err(CoreError::BadRequest(_))));
     }
 
-    // ===== 通知テスト =====
-
     #[tokio::test]
-    async fn test_submit_workflow_正常系で承認依頼通知が送信される() {
-        // Arrange
+    async fn test_submit_workflow_代理申請では本人を承認者に指定できない() {
+        // Arrange: 秘書が役員の代理で作成した下書き
         let tenant_id = TenantId::new();
-        let user_id = UserId::new();
-        let approver_id = UserId::new();
+        let executive_id = UserId::new();
+        let secretary_id = UserId::new();
         let now = chrono::Utc::now();
 
         let definition_repo = FakeWorkflowDefinitionRepository::new();
         let instance_repo = FakeWorkflowInstanceRepository::new();
         let step_repo = FakeWorkflowStepRepository::new();
 
-        // 1段階承認の定義を追加
         let definition = WorkflowDefinition::new(NewWorkflowDefinition {
             id: WorkflowDefinitionId::new(),
             tenant_id: tenant_id.clone(),
-            name: WorkflowName::new("経費精算申請").unwrap(),
+            name: WorkflowName::new("汎用申請").unwrap(),
             description: Some("テスト用定義".to_string()),
             definition: single_approval_definition_json(),
-            created_by: user_id.clone(),
+            created_by: executive_id.clone(),
             now,
         });
         let published_definition = definition.published(now).unwrap();
         definition_repo.add_definition(published_definition.clone());
 
-        // 下書きのインスタンスを作成
         let instance = WorkflowInstance::new(NewWorkflowInstance {
             id: WorkflowInstanceId::new(),
             tenant_id: tenant_id.clone(),
             definition_id: published_definition.id().clone(),
             definition_version: Version::initial(),
             display_number: DisplayNumber::new(100).unwrap(),
-            title: "テスト申請".to_string(),
+            title: "役員出張申請".to_string(),
             form_data: serde_json::json!({}),
-            initiated_by: user_id.clone(),
+            initiated_by: executive_id.clone(),
             now,
-        });
+        })
+        .with_proxy_submitter(secretary_id);
         instance_repo.insert_for_test(&instance).await.unwrap();
 
-        // ユーザー情報を Fake に登録
-        let user_repo = FakeUserRepository::new();
-        user_repo.add_user(User::new(
-            user_id.clone(),
-            tenant_id.clone(),
-            DisplayNumber::new(1).unwrap(),
-            Email::new("tanaka@example.com").unwrap(),
-            UserName::new("田中太郎").unwrap(),
-            now,
-        ));
-        user_repo.add_user(User::new(
-            approver_id.clone(),
-            tenant_id.clone(),
-            DisplayNumber::new(2).unwrap(),
-            Email::new("suzuki@example.com").unwrap(),
-            UserName::new("鈴木一郎").unwrap(),
-            now,
-        ));
-
-        let (sut, sender) = build_sut_with_notification(
-            &definition_repo,
-            &instance_repo,
-            &step_repo,
-            Arc::new(user_repo),
-            now,
-        );
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = SubmitWorkflowInput {
             approvers: vec![StepApprover {
                 step_id:     "approval".to_string(),
-                assigned_to: approver_id.clone(),
+                assigned_to: executive_id,
             }],
         };
 
         // Act
         let result = sut
-            .submit_workflow(input, instance.id().clone(), tenant_id.clone())
+            .submit_workflow(input, instance.id().clone(), tenant_id)
             .await;
 
-        // Assert: ワークフロー操作は成功
-        assert!(result.is_ok());
+        // Assert
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
 
-        // Assert: 承認依頼通知が送信されている
-        let sent = sender.sent_emails();
-        assert_eq!(sent.len(), 1, "承認依頼メールが1通送信されるべき");
-        assert_eq!(sent[0].to, "suzuki@example.com");
-        assert!(
-            sent[0].subject.contains("テスト申請"),
-            "件名にワークフロータイトルが含まれるべき: {}",
-            sent[0].subject
-        );
+    // ===== ドメインイベントテスト =====
+
+    #[tokio::test]
+    async fn test_submit_workflow_代理申請のsubmittedイベントは代理人が操作者になる() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let executive_id = UserId::new();
+        let secretary_id = UserId::new();
+        let approver_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id: tenant_id.clone(),
+            name: WorkflowName::new("汎用申請").unwrap(),
+            description: Some("テスト用定義".to_string()),
+            definition: single_approval_definition_json(),
+            created_by: executive_id.clone(),
+            now,
+        });
+        let published_definition = definition.published(now).unwrap();
+        definition_repo.add_definition(published_definition.clone());
+
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: published_definition.id().clone(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "役員出張申請".to_string(),
+            form_data: serde_json::json!({}),
+            initiated_by: executive_id.clone(),
+            now,
+        })
+        .with_proxy_submitter(secretary_id.clone());
+        instance_repo.insert_for_test(&instance).await.unwrap();
+
+        let (sut, outbox_repo) =
+            build_sut_with_outbox(&definition_repo, &instance_repo, &step_repo, now);
+
+        let input = SubmitWorkflowInput {
+            approvers: vec![StepApprover {
+                step_id:     "approval".to_string(),
+                assigned_to: approver_id,
+            }],
+        };
+
+        // Act
+        sut.submit_workflow(input, instance.id().clone(), tenant_id)
+            .await
+            .unwrap();
+
+        // Assert: 操作者は代理人、申請者は本人
+        let events = outbox_repo.events();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.actor_id(), Some(&secretary_id));
+        assert_eq!(event.payload().initiated_by, executive_id);
+        assert_eq!(event.payload().proxy_submitter, Some(secretary_id));
     }
 
     #[tokio::test]
-    async fn test_submit_workflow_ユーザー情報取得失敗でもワークフロー操作は成功する() {
-        // Arrange: ユーザー情報を登録しない（find_by_id が None を返す）
+    async fn test_submit_workflow_submittedイベントが最初のステップ付きで記録される() {
+        // Arrange
         let tenant_id = TenantId::new();
         let user_id = UserId::new();
         let approver_id = UserId::new();
@@ -533,6 +588,7 @@ mod tests {
         let instance_repo = FakeWorkflowInstanceRepository::new();
         let step_repo = FakeWorkflowStepRepository::new();
 
+        // 1段階承認の定義を追加
         let definition = WorkflowDefinition::new(NewWorkflowDefinition {
             id: WorkflowDefinitionId::new(),
             tenant_id: tenant_id.clone(),
@@ -545,6 +601,7 @@ mod tests {
         let published_definition = definition.published(now).unwrap();
         definition_repo.add_definition(published_definition.clone());
 
+        // 下書きのインスタンスを作成
         let instance = WorkflowInstance::new(NewWorkflowInstance {
             id: WorkflowInstanceId::new(),
             tenant_id: tenant_id.clone(),
@@ -558,15 +615,8 @@ mod tests {
         });
         instance_repo.insert_for_test(&instance).await.unwrap();
 
-        // ユーザー情報を登録しない（空の FakeUserRepository）
-        let user_repo = FakeUserRepository::new();
-        let (sut, sender) = build_sut_with_notification(
-            &definition_repo,
-            &instance_repo,
-            &step_repo,
-            Arc::new(user_repo),
-            now,
-        );
+        let (sut, outbox_repo) =
+            build_sut_with_outbox(&definition_repo, &instance_repo, &step_repo, now);
 
         let input = SubmitWorkflowInput {
             approvers: vec![StepApprover {
@@ -580,12 +630,20 @@ mod tests {
             .submit_workflow(input, instance.id().clone(), tenant_id.clone())
             .await;
 
-        // Assert: ワークフロー操作自体は成功する（fire-and-forget）
+        // Assert: ワークフロー操作は成功
         assert!(result.is_ok());
 
-        // Assert: 通知は送信されない（ユーザー情報がないため）
-        let sent = sender.sent_emails();
-        assert_eq!(sent.len(), 0, "ユーザー情報がない場合、通知は送信されない");
+        // Assert: Submitted イベントに最初にアクティブになったステップが含まれる
+        let events = outbox_repo.events();
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.event_type(), WorkflowEventType::Submitted);
+        assert_eq!(event.actor_id(), Some(&user_id));
+        assert_eq!(event.payload().title, "テスト申請");
+        assert!(event.payload().step.is_none());
+        let activated_step = event.payload().activated_step.as_ref().unwrap();
+        assert_eq!(activated_step.step_name, "承認");
+        assert_eq!(activated_step.assigned_to.as_ref(), Some(&approver_id));
     }
 
     #[tokio::test]
@@ -648,4 +706,161 @@ mod tests {
         // Assert
         assert!(matches!(result, Err(CoreError::BadRequest(_))));
     }
+
+    #[tokio::test]
+    async fn test_submit_workflow_申請スナップショットがラウンド1として記録される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let approver_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id: tenant_id.clone(),
+            name: WorkflowName::new("汎用申請").unwrap(),
+            description: None,
+            definition: single_approval_definition_json(),
+            created_by: user_id.clone(),
+            now,
+        })
+        .published(now)
+        .unwrap();
+        definition_repo.add_definition(definition.clone());
+
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: definition.id().clone(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "テスト申請".to_string(),
+            form_data: serde_json::json!({"amount": 1000}),
+            initiated_by: user_id.clone(),
+            now,
+        });
+        instance_repo.insert_for_test(&instance).await.unwrap();
+
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
+
+        let input = SubmitWorkflowInput {
+            approvers: vec![StepApprover {
+                step_id:     "approval".to_string(),
+                assigned_to: approver_id,
+            }],
+        };
+
+        // Act
+        sut.submit_workflow(input, instance.id().clone(), tenant_id.clone())
+            .await
+            .unwrap();
+
+        // Assert
+        let submissions = sut
+            .list_submissions(
+                DisplayNumber::new(100).unwrap(),
+                tenant_id,
+                &WorkflowViewer::new(user_id.clone(), false),
+            )
+            .await
+            .unwrap();
+        assert_eq!(submissions.len(), 1);
+        assert_eq!(submissions[0].round(), 1);
+        assert_eq!(
+            submissions[0].form_data(),
+            &serde_json::json!({"amount": 1000})
+        );
+        assert!(submissions[0].attachments().is_empty());
+        assert_eq!(submissions[0].submitted_by(), &user_id);
+        assert_eq!(submissions[0].submitted_at(), now);
+    }
+
+    #[tokio::test]
+    async fn test_submit_workflow_申請と最初のステップのアクティブ化がアクティビティとして記録される()
+     {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let approver_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id: tenant_id.clone(),
+            name: WorkflowName::new("2段階承認").unwrap(),
+            description: None,
+            definition: two_step_approval_definition_json(),
+            created_by: user_id.clone(),
+            now,
+        })
+        .published(now)
+        .unwrap();
+        definition_repo.add_definition(definition.clone());
+
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: definition.id().clone(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "テスト申請".to_string(),
+            form_data: serde_json::json!({}),
+            initiated_by: user_id.clone(),
+            now,
+        });
+        instance_repo.insert_for_test(&instance).await.unwrap();
+
+        let sut = build_sut(&definition_repo, &instance_repo, &step_repo, now);
+
+        let input = SubmitWorkflowInput {
+            approvers: vec![
+                StepApprover {
+                    step_id:     "manager_approval".to_string(),
+                    assigned_to: approver_id.clone(),
+                },
+                StepApprover {
+                    step_id:     "finance_approval".to_string(),
+                    assigned_to: UserId::new(),
+                },
+            ],
+        };
+
+        // Act
+        sut.submit_workflow(input, instance.id().clone(), tenant_id.clone())
+            .await
+            .unwrap();
+
+        // Assert
+        let activities = sut
+            .list_activities(
+                DisplayNumber::new(100).unwrap(),
+                tenant_id,
+                &WorkflowViewer::new(user_id.clone(), false),
+            )
+            .await
+            .unwrap();
+        let types: Vec<_> = activities.iter().map(|a| a.activity_type()).collect();
+        assert_eq!(
+            types,
+            vec![
+                WorkflowActivityType::Submitted,
+                WorkflowActivityType::StepActivated
+            ]
+        );
+        assert!(activities.iter().all(|a| a.actor_id() == Some(&user_id)));
+        assert_eq!(activities[0].details()["round"], 1);
+        assert_eq!(activities[1].details()["step_name"], "上長承認");
+        assert_eq!(
+            activities[1].details()["assigned_to"],
+            approver_id.to_string()
+        );
+    }
 }
@@ -0,0 +1,164 @@
+//! ワークフローのウォッチ（進捗通知の購読）
+
+use ringiflow_domain::{
+    tenant::TenantId,
+    value_objects::DisplayNumber,
+    workflow::{WorkflowViewer, WorkflowWatcher},
+};
+
+use crate::{error::CoreError, usecase::workflow::WorkflowUseCaseImpl};
+
+impl WorkflowUseCaseImpl {
+    /// ワークフローをウォッチする
+    ///
+    /// 閲覧できるインスタンスのみウォッチできる。ウォッチ済みの場合は何もしない。
+    ///
+    /// ## エラー
+    ///
+    /// - インスタンスが見つからない、または閲覧権限がない場合: 404
+    /// - データベースエラー
+    pub async fn watch_workflow(
+        &self,
+        display_number: DisplayNumber,
+        tenant_id: TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<(), CoreError> {
+        let instance = self
+            .find_visible_by_display_number(display_number, &tenant_id, viewer)
+            .await?;
+
+        let watcher = WorkflowWatcher::new(
+            instance.id().clone(),
+            viewer.user_id().clone(),
+            self.deps.clock.now(),
+        );
+        self.deps
+            .watcher_repo
+            .insert(&watcher, &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("ウォッチャーの保存に失敗: {}", e)))
+    }
+
+    /// ワークフローのウォッチを解除する
+    ///
+    /// ウォッチしていない場合は何もしない。
+    /// 解除後は、申請者・承認者でなければ閲覧できなくなる場合がある。
+    ///
+    /// ## エラー
+    ///
+    /// - インスタンスが見つからない、または閲覧権限がない場合: 404
+    /// - データベースエラー
+    pub async fn unwatch_workflow(
+        &self,
+        display_number: DisplayNumber,
+        tenant_id: TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<(), CoreError> {
+        let instance = self
+            .find_visible_by_display_number(display_number, &tenant_id, viewer)
+            .await?;
+
+        self.deps
+            .watcher_repo
+            .delete(instance.id(), viewer.user_id(), &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("ウォッチャーの削除に失敗: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ringiflow_domain::{
+        tenant::TenantId,
+        user::UserId,
+        value_objects::DisplayNumber,
+        workflow::WorkflowViewer,
+    };
+    use ringiflow_infra::{
+        fake::{
+            FakeWorkflowDefinitionRepository,
+            FakeWorkflowInstanceRepository,
+            FakeWorkflowStepRepository,
+        },
+        repository::{WorkflowInstanceRepositoryTestExt, WorkflowStepRepositoryTestExt},
+    };
+
+    use super::super::test_helpers::{build_sut, setup_two_step_approval};
+    use crate::{error::CoreError, usecase::workflow::WorkflowUseCaseImpl};
+
+    /// 申請済みの 2 段階承認インスタンス（表示用連番 100）を登録した SUT を構築する
+    async fn setup(tenant_id: &TenantId) -> WorkflowUseCaseImpl {
+        let now = chrono::Utc::now();
+        let (definition, instance, step1, step2) = setup_two_step_approval(
+            tenant_id,
+            &UserId::new(),
+            &UserId::new(),
+            &UserId::new(),
+            now,
+        );
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        definition_repo.add_definition(definition);
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        instance_repo.insert_for_test(&instance).await.unwrap();
+        let step_repo = FakeWorkflowStepRepository::new();
+        step_repo.insert_for_test(&step1, tenant_id).await.unwrap();
+        step_repo.insert_for_test(&step2, tenant_id).await.unwrap();
+
+        build_sut(&definition_repo, &instance_repo, &step_repo, now)
+    }
+
+    #[tokio::test]
+    async fn test_ウォッチすると閲覧でき_解除すると閲覧できなくなる() {
+        // Arrange: ワークフロー管理者が他人の申請をウォッチする
+        let tenant_id = TenantId::new();
+        let sut = setup(&tenant_id).await;
+        let lead = UserId::new();
+        let display_number = DisplayNumber::new(100).unwrap();
+
+        // Act
+        sut.watch_workflow(
+            display_number,
+            tenant_id.clone(),
+            &WorkflowViewer::new(lead.clone(), true),
+        )
+        .await
+        .unwrap();
+
+        // Assert: ワークフロー管理者でなくてもウォッチャーとして閲覧できる
+        let viewer = WorkflowViewer::new(lead, false);
+        let result = sut
+            .get_workflow_by_display_number(display_number, tenant_id.clone(), &viewer)
+            .await;
+        assert!(result.is_ok());
+
+        // Act: ウォッチを解除する
+        sut.unwatch_workflow(display_number, tenant_id.clone(), &viewer)
+            .await
+            .unwrap();
+
+        // Assert
+        let result = sut
+            .get_workflow_by_display_number(display_number, tenant_id, &viewer)
+            .await;
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_閲覧できないインスタンスはウォッチできない() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let sut = setup(&tenant_id).await;
+
+        // Act
+        let result = sut
+            .watch_workflow(
+                DisplayNumber::new(100).unwrap(),
+                tenant_id,
+                &WorkflowViewer::new(UserId::new(), false),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+}
@@ -0,0 +1,315 @@
+//! # 下書きの削除と自動パージ
+//!
+//! 申請前の下書きを添付ファイルごと削除する。
+//! 申請者による削除コマンドと、一定期間更新されていない下書きを
+//! 全テナント横断で削除する自動パージワーカーで共有する。
+//!
+//! ## 処理フロー
+//!
+//! 1. トランザクション内で添付ファイルのドキュメントとインスタンスを物理削除
+//!    （アクティビティ等の子テーブルは `ON DELETE CASCADE` で削除される）
+//! 2. コミット後、どのドキュメントからも参照されなくなった S3 オブジェクトを削除
+//!
+//! 複製された下書きは複製元と S3 オブジェクトを共有するため、
+//! 参照が残っているオブジェクトは削除しない。
+//! 表示用連番などの採番済みの値は解放しない（欠番になる）。
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use itertools::Itertools;
+use ringiflow_domain::{clock::Clock, workflow::WorkflowInstance};
+use ringiflow_infra::{
+    TransactionManager,
+    repository::{DocumentRepository, WorkflowInstanceRepository},
+    s3::S3Client,
+};
+use ringiflow_shared::{event_log::event, log_business_event};
+
+use crate::{error::CoreError, usecase::polling::PollingWorker};
+
+/// 下書きとその添付ファイルを削除する
+///
+/// 並行して申請されるなどして下書きでなくなっていた場合は何もせず `false` を返す。
+/// S3 オブジェクトの削除に失敗しても DB の削除は取り消さない（警告ログのみ）。
+pub(super) async fn remove_draft(
+    instance_repo: &dyn WorkflowInstanceRepository,
+    document_repo: &dyn DocumentRepository,
+    s3_client: &dyn S3Client,
+    tx_manager: &dyn TransactionManager,
+    instance: &WorkflowInstance,
+) -> Result<bool, CoreError> {
+    let tenant_id = instance.tenant_id();
+    let mut tx = tx_manager
+        .begin()
+        .await
+        .map_err(|e| CoreError::Internal(format!("トランザクション開始に失敗: {}", e)))?;
+
+    let s3_keys = document_repo
+        .delete_by_workflow(&mut tx, instance.id(), tenant_id)
+        .await
+        .map_err(|e| CoreError::Internal(format!("添付ファイルの削除に失敗: {}", e)))?;
+    let deleted = instance_repo
+        .delete_draft(&mut tx, instance.id(), tenant_id)
+        .await
+        .map_err(|e| CoreError::Internal(format!("下書きの削除に失敗: {}", e)))?;
+    if !deleted {
+        // コミットせずに破棄し、添付ファイルの削除もロールバックする
+        return Ok(false);
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))?;
+
+    for s3_key in s3_keys.into_iter().unique() {
+        let referenced = match document_repo.exists_by_s3_key(&s3_key, tenant_id).await {
+            Ok(referenced) => referenced,
+            Err(e) => {
+                tracing::warn!(error = %e, %s3_key, "S3 オブジェクトの参照確認に失敗");
+                continue;
+            }
+        };
+        if referenced {
+            continue;
+        }
+        if let Err(e) = s3_client.delete_object(&s3_key).await {
+            tracing::warn!(error = %e, %s3_key, "S3 オブジェクトの削除に失敗");
+        }
+    }
+
+    Ok(true)
+}
+
+/// 下書きの自動パージワーカー
+pub struct DraftPurgeWorker {
+    instance_repo: Arc<dyn WorkflowInstanceRepository>,
+    document_repo: Arc<dyn DocumentRepository>,
+    s3_client:     Arc<dyn S3Client>,
+    tx_manager:    Arc<dyn TransactionManager>,
+    clock:         Arc<dyn Clock>,
+    retention:     chrono::Duration,
+    batch_size:    i64,
+}
+
+impl DraftPurgeWorker {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        instance_repo: Arc<dyn WorkflowInstanceRepository>,
+        document_repo: Arc<dyn DocumentRepository>,
+        s3_client: Arc<dyn S3Client>,
+        tx_manager: Arc<dyn TransactionManager>,
+        clock: Arc<dyn Clock>,
+        retention: chrono::Duration,
+        batch_size: i64,
+    ) -> Self {
+        Self {
+            instance_repo,
+            document_repo,
+            s3_client,
+            tx_manager,
+            clock,
+            retention,
+            batch_size,
+        }
+    }
+
+    /// 保持期間を過ぎた下書きを 1 バッチ分削除する
+    ///
+    /// 取得した下書きの件数を返す（並行して申請されたものは削除されない）。
+    pub async fn purge_stale(&self) -> Result<usize, CoreError> {
+        let updated_before = self.clock.now() - self.retention;
+        let drafts = self
+            .instance_repo
+            .find_stale_drafts(updated_before, self.batch_size)
+            .await
+            .map_err(|e| CoreError::Internal(format!("パージ対象の下書きの取得に失敗: {}", e)))?;
+        let count = drafts.len();
+
+        for draft in &drafts {
+            let deleted = remove_draft(
+                self.instance_repo.as_ref(),
+                self.document_repo.as_ref(),
+                self.s3_client.as_ref(),
+                self.tx_manager.as_ref(),
+                draft,
+            )
+            .await?;
+            if deleted {
+                log_business_event!(
+                    event.category = event::category::WORKFLOW,
+                    event.action = event::action::WORKFLOW_PURGED,
+                    event.entity_type = event::entity_type::WORKFLOW_INSTANCE,
+                    event.entity_id = %draft.id(),
+                    event.tenant_id = %draft.tenant_id(),
+                    event.result = event::result::SUCCESS,
+                    "放置された下書きの自動削除"
+                );
+            }
+        }
+
+        Ok(count)
+    }
+}
+
+#[async_trait]
+impl PollingWorker for DraftPurgeWorker {
+    fn name(&self) -> &'static str {
+        "下書き自動パージワーカー"
+    }
+
+    fn batch_size(&self) -> i64 {
+        self.batch_size
+    }
+
+    async fn step(&self) -> Result<usize, CoreError> {
+        self.purge_stale().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{DateTime, Utc};
+    use ringiflow_domain::{
+        clock::FixedClock,
+        document::{Document, DocumentId, DocumentStatus, UploadContext},
+        tenant::TenantId,
+        user::UserId,
+        value_objects::{DisplayNumber, Version},
+        workflow::{
+            NewWorkflowInstance,
+            WorkflowDefinitionId,
+            WorkflowInstance,
+            WorkflowInstanceId,
+        },
+    };
+    use ringiflow_infra::{
+        fake::{
+            FakeDocumentRepository,
+            FakeS3Client,
+            FakeTransactionManager,
+            FakeWorkflowInstanceRepository,
+        },
+        repository::{WorkflowInstanceRepository, WorkflowInstanceRepositoryTestExt},
+    };
+
+    use super::DraftPurgeWorker;
+
+    fn draft(
+        tenant_id: &TenantId,
+        display_number: i64,
+        updated_at: DateTime<Utc>,
+    ) -> WorkflowInstance {
+        WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: WorkflowDefinitionId::new(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(display_number).unwrap(),
+            title: "下書き".to_string(),
+            form_data: serde_json::json!({}),
+            initiated_by: UserId::new(),
+            now: updated_at,
+        })
+    }
+
+    fn attachment(instance: &WorkflowInstance, s3_key: &str) -> Document {
+        Document::from_db(
+            DocumentId::new(),
+            instance.tenant_id().clone(),
+            "receipt.pdf".to_string(),
+            "application/pdf".to_string(),
+            1024,
+            s3_key.to_string(),
+            UploadContext::Workflow(instance.id().clone()),
+            DocumentStatus::Active,
+            Some(instance.initiated_by().clone()),
+            instance.created_at(),
+            instance.updated_at(),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_purge_stale_保持期間を過ぎた下書きと添付ファイルを削除する() {
+        // Arrange: 31 日前に更新された下書きと 1 日前に更新された下書き
+        let now = Utc::now();
+        let tenant_id = TenantId::new();
+        let stale = draft(&tenant_id, 1, now - chrono::Duration::days(31));
+        let fresh = draft(&tenant_id, 2, now - chrono::Duration::days(1));
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        instance_repo.insert_for_test(&stale).await.unwrap();
+        instance_repo.insert_for_test(&fresh).await.unwrap();
+        let document_repo = FakeDocumentRepository::new();
+        document_repo.add_document(attachment(&stale, "stale-key"));
+        let s3_client = FakeS3Client::new();
+
+        let sut = DraftPurgeWorker::new(
+            Arc::new(instance_repo.clone()),
+            Arc::new(document_repo),
+            Arc::new(s3_client.clone()),
+            Arc::new(FakeTransactionManager),
+            Arc::new(FixedClock::new(now)),
+            chrono::Duration::days(30),
+            100,
+        );
+
+        // Act
+        let count = sut.purge_stale().await.unwrap();
+
+        // Assert
+        assert_eq!(count, 1);
+        assert!(
+            instance_repo
+                .find_by_id(stale.id(), &tenant_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            instance_repo
+                .find_by_id(fresh.id(), &tenant_id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        assert_eq!(s3_client.deleted_keys(), vec!["stale-key".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_purge_stale_申請済みのインスタンスは削除しない() {
+        // Arrange
+        let now = Utc::now();
+        let tenant_id = TenantId::new();
+        let submitted = draft(&tenant_id, 1, now - chrono::Duration::days(31))
+            .submitted(now - chrono::Duration::days(31))
+            .unwrap();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        instance_repo.insert_for_test(&submitted).await.unwrap();
+
+        let sut = DraftPurgeWorker::new(
+            Arc::new(instance_repo.clone()),
+            Arc::new(FakeDocumentRepository::new()),
+            Arc::new(FakeS3Client::new()),
+            Arc::new(FakeTransactionManager),
+            Arc::new(FixedClock::new(now)),
+            chrono::Duration::days(30),
+            100,
+        );
+
+        // Act
+        let count = sut.purge_stale().await.unwrap();
+
+        // Assert
+        assert_eq!(count, 0);
+        assert!(
+            instance_repo
+                .find_by_id(submitted.id(), &tenant_id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+}
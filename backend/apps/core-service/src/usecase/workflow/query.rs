@@ -1,41 +1,66 @@
 //! ワークフローユースケースの読み取り操作
+//!
+//! 詳細・コメント・履歴の取得は、閲覧者が閲覧できるインスタンスに限る
+//! （規則は [`WorkflowViewer`] を参照）。閲覧できない場合は NotFound を返す。
 
 use ringiflow_domain::{
+    message::Message,
     tenant::TenantId,
     user::UserId,
     value_objects::DisplayNumber,
-    workflow::{WorkflowComment, WorkflowInstanceId},
+    workflow::{
+        SubmissionDiff,
+        WorkflowActivity,
+        WorkflowComment,
+        WorkflowCommentId,
+        WorkflowCommentRevision,
+        WorkflowFormDataChange,
+        WorkflowInstance,
+        WorkflowInstanceId,
+        WorkflowSubmission,
+        WorkflowViewer,
+        WorkflowWatcher,
+    },
 };
+use ringiflow_infra::repository::KeysetPage;
 
 use super::{WorkflowUseCaseImpl, WorkflowWithSteps};
-use crate::{error::CoreError, usecase::helpers::FindResultExt};
+use crate::{
+    error::CoreError,
+    usecase::helpers::{FindResultExt, check_workflow_visible, page_error, page_limit},
+};
 
 impl WorkflowUseCaseImpl {
     // ===== GET 系メソッド =====
 
-    /// 自分の申請一覧を取得する
+    /// 自分の申請一覧を 1 ページ分取得する
     ///
-    /// ログインユーザーが申請したワークフローインスタンスの一覧を返す。
+    /// ログインユーザーが申請したワークフローインスタンスを作成日時の降順で返す。
     ///
     /// ## 引数
     ///
     /// - `tenant_id`: テナント ID
     /// - `user_id`: ユーザー ID
+    /// - `cursor`: 前ページの `next_cursor`（先頭ページは `None`）
+    /// - `limit`: 1 ページあたりの件数（省略時は 20 件、最大 100 件）
     ///
     /// ## 戻り値
     ///
-    /// - `Ok(Vec<WorkflowInstance>)`: 申請一覧
+    /// - `Ok(KeysetPage<WorkflowInstance>)`: 申請一覧と次ページのカーソル
+    /// - `Err(BadRequest)`: カーソルが不正な場合
     /// - `Err(_)`: データベースエラー
     pub async fn list_my_workflows(
         &self,
         tenant_id: TenantId,
         user_id: UserId,
-    ) -> Result<Vec<ringiflow_domain::workflow::WorkflowInstance>, CoreError> {
+        cursor: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<KeysetPage<ringiflow_domain::workflow::WorkflowInstance>, CoreError> {
         self.deps
             .instance_repo
-            .find_by_initiated_by(&tenant_id, &user_id)
+            .find_by_initiated_by(&tenant_id, &user_id, cursor, page_limit(limit))
             .await
-            .map_err(|e| CoreError::Internal(format!("申請一覧の取得に失敗: {}", e)))
+            .map_err(|e| page_error(e, "申請一覧の取得"))
     }
 
     /// ワークフローインスタンスの詳細を取得する
@@ -46,32 +71,27 @@ impl WorkflowUseCaseImpl {
     ///
     /// - `id`: ワークフローインスタンス ID
     /// - `tenant_id`: テナント ID
+    /// - `viewer`: 閲覧者
     ///
     /// ## 戻り値
     ///
     /// - `Ok(instance)`: ワークフローインスタンス
-    /// - `Err(NotFound)`: インスタンスが見つからない場合
+    /// - `Err(NotFound)`: インスタンスが見つからない、または閲覧権限がない場合
     /// - `Err(_)`: データベースエラー
     pub async fn get_workflow(
         &self,
         id: WorkflowInstanceId,
         tenant_id: TenantId,
+        viewer: &WorkflowViewer,
     ) -> Result<WorkflowWithSteps, CoreError> {
         let instance = self
             .deps
             .instance_repo
             .find_by_id(&id, &tenant_id)
             .await
-            .or_not_found("ワークフローインスタンス")?;
-
-        let steps = self
-            .deps
-            .step_repo
-            .find_by_instance(&id, &tenant_id)
-            .await
-            .map_err(|e| CoreError::Internal(format!("ステップの取得に失敗: {}", e)))?;
+            .or_not_found("workflow-instance")?;
 
-        Ok(WorkflowWithSteps { instance, steps })
+        self.with_visible_steps(instance, &tenant_id, viewer).await
     }
 
     // ===== display_number 対応メソッド（読み取り） =====
@@ -85,34 +105,108 @@ impl WorkflowUseCaseImpl {
     ///
     /// - `display_number`: 表示用連番
     /// - `tenant_id`: テナント ID
+    /// - `viewer`: 閲覧者
     ///
     /// ## 戻り値
     ///
     /// - `Ok(workflow)`: ワークフロー詳細（インスタンス + ステップ）
-    /// - `Err(NotFound)`: インスタンスが見つからない場合
+    /// - `Err(NotFound)`: インスタンスが見つからない、または閲覧権限がない場合
     /// - `Err(_)`: データベースエラー
     pub async fn get_workflow_by_display_number(
         &self,
         display_number: DisplayNumber,
         tenant_id: TenantId,
+        viewer: &WorkflowViewer,
     ) -> Result<WorkflowWithSteps, CoreError> {
         let instance = self
             .deps
             .instance_repo
             .find_by_display_number(display_number, &tenant_id)
             .await
-            .or_not_found("ワークフローインスタンス")?;
+            .or_not_found("workflow-instance")?;
+
+        self.with_visible_steps(instance, &tenant_id, viewer).await
+    }
+
+    /// インスタンスに属するコメントを取得する
+    ///
+    /// 別のワークフローのコメント ID を指定された場合も 404 とする。
+    pub(super) async fn find_instance_comment(
+        &self,
+        instance: &WorkflowInstance,
+        comment_id: &WorkflowCommentId,
+        tenant_id: &TenantId,
+    ) -> Result<WorkflowComment, CoreError> {
+        self.deps
+            .comment_repo
+            .find_by_id(comment_id, tenant_id)
+            .await
+            .map(|found| found.filter(|c| c.instance_id() == instance.id()))
+            .or_not_found("comment")
+    }
+
+    /// display_number で閲覧者が閲覧できるインスタンスを取得する
+    pub(super) async fn find_visible_by_display_number(
+        &self,
+        display_number: DisplayNumber,
+        tenant_id: &TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<WorkflowInstance, CoreError> {
+        self.get_workflow_by_display_number(display_number, tenant_id.clone(), viewer)
+            .await
+            .map(|workflow| workflow.instance)
+    }
 
+    /// インスタンスのステップを取得し、閲覧権限をチェックする
+    async fn with_visible_steps(
+        &self,
+        instance: WorkflowInstance,
+        tenant_id: &TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<WorkflowWithSteps, CoreError> {
         let steps = self
             .deps
             .step_repo
-            .find_by_instance(instance.id(), &tenant_id)
+            .find_by_instance(instance.id(), tenant_id)
             .await
             .map_err(|e| CoreError::Internal(format!("ステップの取得に失敗: {}", e)))?;
 
+        check_workflow_visible(
+            self.deps.definition_repo.as_ref(),
+            self.deps.watcher_repo.as_ref(),
+            viewer,
+            &instance,
+            &steps,
+        )
+        .await?;
+
         Ok(WorkflowWithSteps { instance, steps })
     }
 
+    /// ワークフローのウォッチャー一覧を取得する
+    ///
+    /// ## 戻り値
+    ///
+    /// - `Ok(Vec<WorkflowWatcher>)`: ウォッチャー一覧（created_at ASC）
+    /// - `Err(NotFound)`: インスタンスが見つからない、または閲覧権限がない場合
+    /// - `Err(_)`: データベースエラー
+    pub async fn list_watchers(
+        &self,
+        display_number: DisplayNumber,
+        tenant_id: TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<Vec<WorkflowWatcher>, CoreError> {
+        let instance = self
+            .find_visible_by_display_number(display_number, &tenant_id, viewer)
+            .await?;
+
+        self.deps
+            .watcher_repo
+            .find_by_instance(instance.id(), &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("ウォッチャーの取得に失敗: {}", e)))
+    }
+
     // ===== コメント取得メソッド =====
 
     /// ワークフローのコメント一覧を取得する
@@ -124,24 +218,23 @@ impl WorkflowUseCaseImpl {
     ///
     /// - `display_number`: 表示用連番
     /// - `tenant_id`: テナント ID
+    /// - `viewer`: 閲覧者
     ///
     /// ## 戻り値
     ///
     /// - `Ok(Vec<WorkflowComment>)`: コメント一覧（created_at ASC）
-    /// - `Err(NotFound)`: インスタンスが見つからない場合
+    /// - `Err(NotFound)`: インスタンスが見つからない、または閲覧権限がない場合
     /// - `Err(_)`: データベースエラー
     pub async fn list_comments(
         &self,
         display_number: DisplayNumber,
         tenant_id: TenantId,
+        viewer: &WorkflowViewer,
     ) -> Result<Vec<WorkflowComment>, CoreError> {
-        // 1. ワークフローインスタンスの存在確認
+        // 1. 閲覧できるワークフローインスタンスか確認
         let instance = self
-            .deps
-            .instance_repo
-            .find_by_display_number(display_number, &tenant_id)
-            .await
-            .or_not_found("ワークフローインスタンス")?;
+            .find_visible_by_display_number(display_number, &tenant_id, viewer)
+            .await?;
 
         // 2. コメント一覧を取得
         self.deps
@@ -150,6 +243,186 @@ impl WorkflowUseCaseImpl {
             .await
             .map_err(|e| CoreError::Internal(format!("コメントの取得に失敗: {}", e)))
     }
+
+    /// コメントの編集履歴を取得する
+    ///
+    /// 編集前の本文を時系列昇順で返す。削除済みのコメントの履歴は返さない。
+    ///
+    /// ## 戻り値
+    ///
+    /// - `Ok(Vec<WorkflowCommentRevision>)`: 編集履歴（edited_at ASC）
+    /// - `Err(NotFound)`: インスタンスまたはコメントが見つからない、削除済み、
+    ///   または閲覧権限がない場合
+    /// - `Err(_)`: データベースエラー
+    pub async fn list_comment_revisions(
+        &self,
+        display_number: DisplayNumber,
+        comment_id: WorkflowCommentId,
+        tenant_id: TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<Vec<WorkflowCommentRevision>, CoreError> {
+        let instance = self
+            .find_visible_by_display_number(display_number, &tenant_id, viewer)
+            .await?;
+
+        let comment = self
+            .find_instance_comment(&instance, &comment_id, &tenant_id)
+            .await?;
+        if comment.is_deleted() {
+            return Err(CoreError::NotFound(Message::new("comment-not-found")));
+        }
+
+        self.deps
+            .comment_repo
+            .find_revisions(comment.id(), &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("コメント編集履歴の取得に失敗: {}", e)))
+    }
+
+    // ===== フォームデータ変更履歴取得メソッド =====
+
+    /// ワークフローのフォームデータ変更履歴を取得する
+    ///
+    /// display_number でワークフローを特定し、承認者による
+    /// フィールド単位の変更履歴を時系列昇順で返す。
+    ///
+    /// ## 引数
+    ///
+    /// - `display_number`: 表示用連番
+    /// - `tenant_id`: テナント ID
+    /// - `viewer`: 閲覧者
+    ///
+    /// ## 戻り値
+    ///
+    /// - `Ok(Vec<WorkflowFormDataChange>)`: 変更履歴（changed_at ASC）
+    /// - `Err(NotFound)`: インスタンスが見つからない、または閲覧権限がない場合
+    /// - `Err(_)`: データベースエラー
+    pub async fn list_form_data_changes(
+        &self,
+        display_number: DisplayNumber,
+        tenant_id: TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<Vec<WorkflowFormDataChange>, CoreError> {
+        let instance = self
+            .find_visible_by_display_number(display_number, &tenant_id, viewer)
+            .await?;
+
+        self.deps
+            .form_data_change_repo
+            .find_by_instance(instance.id(), &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("フォームデータ変更履歴の取得に失敗: {}", e)))
+    }
+
+    // ===== アクティビティ取得メソッド =====
+
+    /// ワークフローのアクティビティ（出来事の時系列記録）を取得する
+    ///
+    /// display_number でワークフローを特定し、作成・申請・承認・コメント投稿などの
+    /// アクティビティを発生日時の昇順で返す。
+    ///
+    /// ## 戻り値
+    ///
+    /// - `Ok(Vec<WorkflowActivity>)`: アクティビティ一覧（occurred_at ASC）
+    /// - `Err(NotFound)`: インスタンスが見つからない、または閲覧権限がない場合
+    /// - `Err(_)`: データベースエラー
+    pub async fn list_activities(
+        &self,
+        display_number: DisplayNumber,
+        tenant_id: TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<Vec<WorkflowActivity>, CoreError> {
+        let instance = self
+            .find_visible_by_display_number(display_number, &tenant_id, viewer)
+            .await?;
+
+        self.deps
+            .activity_repo
+            .find_by_instance(instance.id(), &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("アクティビティの取得に失敗: {}", e)))
+    }
+
+    // ===== 申請スナップショット取得メソッド =====
+
+    /// ワークフローの申請スナップショット一覧を取得する
+    ///
+    /// display_number でワークフローを特定し、申請・再申請ごとの
+    /// フォームデータと添付ファイル一覧をラウンド昇順で返す。
+    ///
+    /// ## 戻り値
+    ///
+    /// - `Ok(Vec<WorkflowSubmission>)`: スナップショット一覧（round ASC）
+    /// - `Err(NotFound)`: インスタンスが見つからない、または閲覧権限がない場合
+    /// - `Err(_)`: データベースエラー
+    pub async fn list_submissions(
+        &self,
+        display_number: DisplayNumber,
+        tenant_id: TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<Vec<WorkflowSubmission>, CoreError> {
+        let instance = self
+            .find_visible_by_display_number(display_number, &tenant_id, viewer)
+            .await?;
+
+        self.deps
+            .submission_repo
+            .find_by_instance(instance.id(), &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("申請スナップショットの取得に失敗: {}", e)))
+    }
+
+    /// 2 つの申請ラウンド間の差分を取得する
+    ///
+    /// `to_round` を省略した場合は最新ラウンド、`from_round` を省略した場合は
+    /// `to_round` の直前のラウンドと比較する。承認者が再申請で変わった点だけを
+    /// 確認する用途を想定している。
+    ///
+    /// ## 戻り値
+    ///
+    /// - `Ok(SubmissionDiff)`: ラウンド間の差分
+    /// - `Err(NotFound)`: インスタンスまたは指定ラウンドが見つからない、または閲覧権限がない場合
+    /// - `Err(BadRequest)`: `from_round` が `to_round` 以降の場合
+    /// - `Err(_)`: データベースエラー
+    pub async fn diff_submissions(
+        &self,
+        display_number: DisplayNumber,
+        from_round: Option<i32>,
+        to_round: Option<i32>,
+        tenant_id: TenantId,
+        viewer: &WorkflowViewer,
+    ) -> Result<SubmissionDiff, CoreError> {
+        let submissions = self
+            .list_submissions(display_number, tenant_id, viewer)
+            .await?;
+
+        let to_round = match to_round {
+            Some(round) => round,
+            None => submissions
+                .last()
+                .map(WorkflowSubmission::round)
+                .ok_or_else(|| CoreError::NotFound(Message::new("submission-round-not-found")))?,
+        };
+        let from_round = from_round.unwrap_or(to_round - 1);
+        if from_round >= to_round {
+            return Err(CoreError::BadRequest(Message::new("round-order-invalid")));
+        }
+
+        let find_round = |round: i32| {
+            submissions
+                .iter()
+                .find(|s| s.round() == round)
+                .ok_or_else(|| {
+                    CoreError::NotFound(
+                        Message::new("submission-round-number-not-found").with("round", round),
+                    )
+                })
+        };
+        let from = find_round(from_round)?;
+        let to = find_round(to_round)?;
+
+        Ok(from.diff(to))
+    }
 }
 
 #[cfg(test)]
@@ -160,38 +433,52 @@ mod tests {
         clock::FixedClock,
         tenant::TenantId,
         user::UserId,
-        value_objects::{DisplayNumber, Version},
+        value_objects::{DisplayNumber, Version, WorkflowName},
         workflow::{
             CommentBody,
             NewWorkflowComment,
+            NewWorkflowDefinition,
             NewWorkflowInstance,
+            NewWorkflowSubmission,
             WorkflowComment,
             WorkflowCommentId,
+            WorkflowDefinition,
             WorkflowDefinitionId,
             WorkflowInstance,
             WorkflowInstanceId,
+            WorkflowSubmission,
+            WorkflowSubmissionId,
+            WorkflowViewer,
         },
     };
     use ringiflow_infra::{
+        db::TxContext,
         fake::{
             FakeDisplayIdCounterRepository,
-            FakeNotificationLogRepository,
-            FakeNotificationSender,
+            FakeDocumentRepository,
+            FakeS3Client,
             FakeTransactionManager,
             FakeUserRepository,
+            FakeWorkflowActivityRepository,
             FakeWorkflowCommentRepository,
             FakeWorkflowDefinitionRepository,
+            FakeWorkflowEventOutboxRepository,
+            FakeWorkflowFormDataChangeRepository,
             FakeWorkflowInstanceRepository,
+            FakeWorkflowProxyGrantRepository,
             FakeWorkflowStepRepository,
+            FakeWorkflowSubmissionRepository,
+            FakeWorkflowWatcherRepository,
+        },
+        repository::{
+            WorkflowCommentRepository,
+            WorkflowInstanceRepositoryTestExt,
+            WorkflowSubmissionRepository,
         },
-        repository::{WorkflowCommentRepository, WorkflowInstanceRepositoryTestExt},
     };
 
     use super::super::{WorkflowUseCaseDeps, WorkflowUseCaseImpl};
-    use crate::{
-        error::CoreError,
-        usecase::notification::{NotificationService, TemplateRenderer},
-    };
+    use crate::error::CoreError;
 
     #[tokio::test]
     async fn test_list_comments_コメント一覧を取得できる() {
@@ -227,6 +514,7 @@ mod tests {
             id: WorkflowCommentId::new(),
             tenant_id: tenant_id.clone(),
             instance_id: instance.id().clone(),
+            parent_id: None,
             posted_by: user_id.clone(),
             body: CommentBody::new("コメント1").unwrap(),
             now,
@@ -235,35 +523,48 @@ mod tests {
             id: WorkflowCommentId::new(),
             tenant_id: tenant_id.clone(),
             instance_id: instance.id().clone(),
+            parent_id: None,
             posted_by: user_id.clone(),
             body: CommentBody::new("コメント2").unwrap(),
             now,
         });
-        comment_repo.insert(&comment1, &tenant_id).await.unwrap();
-        comment_repo.insert(&comment2, &tenant_id).await.unwrap();
-
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(FakeNotificationSender::new()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
+        let mut tx = TxContext::mock();
+        comment_repo
+            .insert(&mut tx, &comment1, &tenant_id)
+            .await
+            .unwrap();
+        comment_repo
+            .insert(&mut tx, &comment2, &tenant_id)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
 
         let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
             definition_repo: Arc::new(definition_repo),
             instance_repo: Arc::new(instance_repo),
             step_repo: Arc::new(step_repo),
             comment_repo: Arc::new(comment_repo),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
             user_repo: Arc::new(FakeUserRepository::new()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
             clock: Arc::new(FixedClock::new(now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         });
 
         // Act
         let result = sut
-            .list_comments(DisplayNumber::new(100).unwrap(), tenant_id)
+            .list_comments(
+                DisplayNumber::new(100).unwrap(),
+                tenant_id,
+                &WorkflowViewer::new(user_id, false),
+            )
             .await;
 
         // Assert
@@ -285,28 +586,240 @@ mod tests {
 
         // インスタンスを作成しない
 
-        let notification_service = Arc::new(NotificationService::new(
-            Arc::new(FakeNotificationSender::new()),
-            TemplateRenderer::new().unwrap(),
-            Arc::new(FakeNotificationLogRepository::new()),
-            "http://localhost:5173".to_string(),
-        ));
-
         let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
             definition_repo: Arc::new(definition_repo),
             instance_repo: Arc::new(instance_repo),
             step_repo: Arc::new(step_repo),
             comment_repo: Arc::new(comment_repo),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
+            user_repo: Arc::new(FakeUserRepository::new()),
+            counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
+            clock: Arc::new(FixedClock::new(now)),
+            tx_manager: Arc::new(FakeTransactionManager),
+        });
+
+        // Act
+        let result = sut
+            .list_comments(
+                DisplayNumber::new(999).unwrap(),
+                tenant_id,
+                &WorkflowViewer::new(UserId::new(), false),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_diff_submissions_指定したラウンドが存在しない場合404() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_id = UserId::new();
+        let now = chrono::Utc::now();
+
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let submission_repo = FakeWorkflowSubmissionRepository::new();
+
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: WorkflowDefinitionId::new(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "テスト申請".to_string(),
+            form_data: serde_json::json!({}),
+            initiated_by: user_id.clone(),
+            now,
+        })
+        .submitted(now)
+        .unwrap()
+        .with_current_step("approval".to_string(), now)
+        .unwrap();
+        instance_repo.insert_for_test(&instance).await.unwrap();
+
+        // ラウンド 1 のみ記録
+        let submission = WorkflowSubmission::new(NewWorkflowSubmission {
+            id: WorkflowSubmissionId::new(),
+            tenant_id: tenant_id.clone(),
+            instance_id: instance.id().clone(),
+            round: 1,
+            form_data: serde_json::json!({}),
+            attachments: vec![],
+            submitted_by: user_id.clone(),
+            now,
+        });
+        submission_repo
+            .insert(&mut TxContext::mock(), &submission, &tenant_id)
+            .await
+            .unwrap();
+
+        let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
+            definition_repo: Arc::new(FakeWorkflowDefinitionRepository::new()),
+            instance_repo: Arc::new(instance_repo),
+            step_repo: Arc::new(FakeWorkflowStepRepository::new()),
+            comment_repo: Arc::new(FakeWorkflowCommentRepository::new()),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(submission_repo),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
+            user_repo: Arc::new(FakeUserRepository::new()),
+            counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
+            clock: Arc::new(FixedClock::new(now)),
+            tx_manager: Arc::new(FakeTransactionManager),
+        });
+
+        // Act: 省略時は最新ラウンド(1)と直前のラウンド(0)の比較になる
+        let result = sut
+            .diff_submissions(
+                DisplayNumber::new(100).unwrap(),
+                None,
+                None,
+                tenant_id,
+                &WorkflowViewer::new(user_id, false),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+
+    /// 閲覧権限テスト用に、機密区分を指定した定義と申請済みインスタンスを用意する
+    async fn setup_visibility_test(confidential: bool) -> (WorkflowUseCaseImpl, WorkflowInstance) {
+        let tenant_id = TenantId::new();
+        let now = chrono::Utc::now();
+
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id: tenant_id.clone(),
+            name: WorkflowName::new("人事異動申請").unwrap(),
+            description: None,
+            definition: serde_json::json!({"steps": []}),
+            created_by: UserId::new(),
+            now,
+        })
+        .with_confidential(confidential);
+        definition_repo.add_definition(definition.clone());
+
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: definition.id().clone(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "テスト申請".to_string(),
+            form_data: serde_json::json!({}),
+            initiated_by: UserId::new(),
+            now,
+        })
+        .submitted(now)
+        .unwrap();
+        instance_repo.insert_for_test(&instance).await.unwrap();
+
+        let sut = WorkflowUseCaseImpl::new(WorkflowUseCaseDeps {
+            definition_repo: Arc::new(definition_repo),
+            instance_repo: Arc::new(instance_repo),
+            step_repo: Arc::new(FakeWorkflowStepRepository::new()),
+            comment_repo: Arc::new(FakeWorkflowCommentRepository::new()),
+            form_data_change_repo: Arc::new(FakeWorkflowFormDataChangeRepository::new()),
+            submission_repo: Arc::new(FakeWorkflowSubmissionRepository::new()),
+            watcher_repo: Arc::new(FakeWorkflowWatcherRepository::new()),
+            proxy_grant_repo: Arc::new(FakeWorkflowProxyGrantRepository::new()),
+            document_repo: Arc::new(FakeDocumentRepository::new()),
+            activity_repo: Arc::new(FakeWorkflowActivityRepository::new()),
+            outbox_repo: Arc::new(FakeWorkflowEventOutboxRepository::new()),
             user_repo: Arc::new(FakeUserRepository::new()),
             counter_repo: Arc::new(FakeDisplayIdCounterRepository::new()),
+            s3_client: Arc::new(FakeS3Client::new()),
             clock: Arc::new(FixedClock::new(now)),
             tx_manager: Arc::new(FakeTransactionManager),
-            notification_service,
         });
 
+        (sut, instance)
+    }
+
+    #[tokio::test]
+    async fn test_get_workflow_by_display_number_関与者でないユーザーは404() {
+        // Arrange
+        let (sut, instance) = setup_visibility_test(false).await;
+
+        // Act
+        let result = sut
+            .get_workflow_by_display_number(
+                instance.display_number(),
+                instance.tenant_id().clone(),
+                &WorkflowViewer::new(UserId::new(), false),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_workflow_by_display_number_ワークフロー管理者は通常の定義のインスタンスを取得できる()
+     {
+        // Arrange
+        let (sut, instance) = setup_visibility_test(false).await;
+
+        // Act
+        let result = sut
+            .get_workflow_by_display_number(
+                instance.display_number(),
+                instance.tenant_id().clone(),
+                &WorkflowViewer::new(UserId::new(), true),
+            )
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap().instance, instance);
+    }
+
+    #[tokio::test]
+    async fn test_get_workflow_by_display_number_ワークフロー管理者でも機密定義のインスタンスは404()
+    {
+        // Arrange
+        let (sut, instance) = setup_visibility_test(true).await;
+
+        // Act
+        let result = sut
+            .get_workflow_by_display_number(
+                instance.display_number(),
+                instance.tenant_id().clone(),
+                &WorkflowViewer::new(UserId::new(), true),
+            )
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_comments_関与者でないユーザーは404() {
+        // Arrange
+        let (sut, instance) = setup_visibility_test(false).await;
+
         // Act
         let result = sut
-            .list_comments(DisplayNumber::new(999).unwrap(), tenant_id)
+            .list_comments(
+                instance.display_number(),
+                instance.tenant_id().clone(),
+                &WorkflowViewer::new(UserId::new(), false),
+            )
             .await;
 
         // Assert
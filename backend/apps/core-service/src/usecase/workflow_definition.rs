@@ -6,6 +6,7 @@ use std::sync::Arc;
 
 use ringiflow_domain::{
     clock::Clock,
+    message::Message,
     tenant::TenantId,
     user::UserId,
     value_objects::{Version, WorkflowName},
@@ -17,7 +18,10 @@ use ringiflow_domain::{
         validate_definition,
     },
 };
-use ringiflow_infra::{InfraErrorKind, repository::WorkflowDefinitionRepository};
+use ringiflow_infra::{
+    InfraErrorKind,
+    repository::{UserRepository, WorkflowDefinitionRepository},
+};
 use serde_json::Value as JsonValue;
 
 use super::helpers::FindResultExt;
@@ -26,20 +30,51 @@ use crate::error::CoreError;
 /// ワークフロー定義ユースケース
 pub struct WorkflowDefinitionUseCaseImpl {
     definition_repo: Arc<dyn WorkflowDefinitionRepository>,
+    /// 既定ウォッチャーの存在確認に使用
+    user_repo:       Arc<dyn UserRepository>,
     clock:           Arc<dyn Clock>,
 }
 
 impl WorkflowDefinitionUseCaseImpl {
     pub fn new(
         definition_repo: Arc<dyn WorkflowDefinitionRepository>,
+        user_repo: Arc<dyn UserRepository>,
         clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
             definition_repo,
+            user_repo,
             clock,
         }
     }
 
+    /// 既定ウォッチャーがすべてテナント内の有効なユーザーか確認する
+    async fn validate_default_watchers(
+        &self,
+        default_watchers: &[UserId],
+        tenant_id: &TenantId,
+    ) -> Result<(), CoreError> {
+        if default_watchers.is_empty() {
+            return Ok(());
+        }
+        let users = self
+            .user_repo
+            .find_by_ids(default_watchers)
+            .await
+            .map_err(|e| CoreError::Internal(format!("ユーザーの取得に失敗: {}", e)))?;
+        let all_valid = default_watchers.iter().all(|user_id| {
+            users
+                .iter()
+                .any(|u| u.id() == user_id && u.tenant_id() == tenant_id && u.is_active())
+        });
+        if !all_valid {
+            return Err(CoreError::BadRequest(Message::new(
+                "default-watcher-invalid-user",
+            )));
+        }
+        Ok(())
+    }
+
     /// テナント内の全定義を取得（ステータス問わず）
     pub async fn list_definitions(
         &self,
@@ -60,18 +95,24 @@ impl WorkflowDefinitionUseCaseImpl {
         self.definition_repo
             .find_by_id(id, tenant_id)
             .await
-            .or_not_found("ワークフロー定義")
+            .or_not_found("workflow-definition")
     }
 
     /// 新規定義を作成（Draft 状態）
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_definition(
         &self,
         name: WorkflowName,
         description: Option<String>,
         definition: JsonValue,
+        confidential: bool,
+        default_watchers: Vec<UserId>,
         tenant_id: TenantId,
         user_id: UserId,
     ) -> Result<WorkflowDefinition, CoreError> {
+        self.validate_default_watchers(&default_watchers, &tenant_id)
+            .await?;
+
         let now = self.clock.now();
         let def = WorkflowDefinition::new(NewWorkflowDefinition {
             id: WorkflowDefinitionId::new(),
@@ -81,7 +122,9 @@ impl WorkflowDefinitionUseCaseImpl {
             definition,
             created_by: user_id,
             now,
-        });
+        })
+        .with_confidential(confidential)
+        .with_default_watchers(default_watchers);
 
         self.definition_repo
             .insert(&def)
@@ -92,25 +135,43 @@ impl WorkflowDefinitionUseCaseImpl {
     }
 
     /// 定義を更新（Draft のみ）
+    ///
+    /// `confidential`・`default_watchers` が `None` の場合は現在の値を変更しない。
+    #[allow(clippy::too_many_arguments)]
     pub async fn update_definition(
         &self,
         id: &WorkflowDefinitionId,
         name: WorkflowName,
         description: Option<String>,
         definition: JsonValue,
+        confidential: Option<bool>,
+        default_watchers: Option<Vec<UserId>>,
         expected_version: Version,
         tenant_id: &TenantId,
     ) -> Result<WorkflowDefinition, CoreError> {
+        if let Some(default_watchers) = &default_watchers {
+            self.validate_default_watchers(default_watchers, tenant_id)
+                .await?;
+        }
+
         let existing = self
             .definition_repo
             .find_by_id(id, tenant_id)
             .await
-            .or_not_found("ワークフロー定義")?;
+            .or_not_found("workflow-definition")?;
 
         let now = self.clock.now();
         let updated = existing
             .update(name, description, definition, now)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
+        let updated = match confidential {
+            Some(confidential) => updated.with_confidential(confidential),
+            None => updated,
+        };
+        let updated = match default_watchers {
+            Some(default_watchers) => updated.with_default_watchers(default_watchers),
+            None => updated,
+        };
 
         self.definition_repo
             .update_with_version_check(&updated, expected_version)
@@ -130,11 +191,11 @@ impl WorkflowDefinitionUseCaseImpl {
             .definition_repo
             .find_by_id(id, tenant_id)
             .await
-            .or_not_found("ワークフロー定義")?;
+            .or_not_found("workflow-definition")?;
 
         existing
             .can_delete()
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         self.definition_repo
             .delete(id, tenant_id)
@@ -155,22 +216,21 @@ impl WorkflowDefinitionUseCaseImpl {
             .definition_repo
             .find_by_id(id, tenant_id)
             .await
-            .or_not_found("ワークフロー定義")?;
+            .or_not_found("workflow-definition")?;
 
         // 公開前バリデーション
         let result = validate_definition(existing.definition());
         if !result.valid {
             let messages: Vec<String> = result.errors.iter().map(|e| e.message.clone()).collect();
-            return Err(CoreError::BadRequest(format!(
-                "バリデーションエラー: {}",
-                messages.join("; ")
-            )));
+            return Err(CoreError::BadRequest(
+                Message::new("definition-invalid").with("errors", messages.join("; ")),
+            ));
         }
 
         let now = self.clock.now();
         let published = existing
             .published(now)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         self.definition_repo
             .update_with_version_check(&published, expected_version)
@@ -191,12 +251,12 @@ impl WorkflowDefinitionUseCaseImpl {
             .definition_repo
             .find_by_id(id, tenant_id)
             .await
-            .or_not_found("ワークフロー定義")?;
+            .or_not_found("workflow-definition")?;
 
         let now = self.clock.now();
         let archived = existing
             .archived(now)
-            .map_err(|e| CoreError::BadRequest(e.to_string()))?;
+            .map_err(|e| CoreError::BadRequest(e.into_message()))?;
 
         self.definition_repo
             .update_with_version_check(&archived, expected_version)
@@ -215,10 +275,9 @@ impl WorkflowDefinitionUseCaseImpl {
 /// InfraError のバージョン競合を CoreError::Conflict にマッピング
 fn map_version_conflict(e: ringiflow_infra::InfraError) -> CoreError {
     match e.kind() {
-        InfraErrorKind::Conflict { .. } => CoreError::Conflict(
-            "このワークフロー定義は既に更新されています。最新の状態を取得してください。"
-                .to_string(),
-        ),
+        InfraErrorKind::Conflict { .. } => {
+            CoreError::Conflict(Message::new("definition-version-conflict"))
+        }
         _ => CoreError::Internal(format!("定義の保存に失敗: {}", e)),
     }
 }
@@ -227,7 +286,7 @@ fn map_version_conflict(e: ringiflow_infra::InfraError) -> CoreError {
 mod tests {
     use chrono::{DateTime, Utc};
     use ringiflow_domain::clock::FixedClock;
-    use ringiflow_infra::fake::FakeWorkflowDefinitionRepository;
+    use ringiflow_infra::fake::{FakeUserRepository, FakeWorkflowDefinitionRepository};
     use serde_json::json;
 
     use super::*;
@@ -242,7 +301,11 @@ mod tests {
     ) {
         let repo = Arc::new(FakeWorkflowDefinitionRepository::new());
         let clock = Arc::new(FixedClock::new(fixed_now()));
-        let usecase = WorkflowDefinitionUseCaseImpl::new(repo.clone(), clock);
+        let usecase = WorkflowDefinitionUseCaseImpl::new(
+            repo.clone(),
+            Arc::new(FakeUserRepository::new()),
+            clock,
+        );
         (usecase, repo)
     }
 
@@ -280,6 +343,8 @@ mod tests {
                 WorkflowName::new("テスト定義").unwrap(),
                 Some("テスト".to_string()),
                 json!({"steps": []}),
+                false,
+                vec![],
                 tid.clone(),
                 user_id(),
             )
@@ -297,6 +362,27 @@ mod tests {
         assert_eq!(list.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_機密定義として作成できる() {
+        let (usecase, _repo) = create_usecase();
+        let tid = tenant_id();
+
+        let def = usecase
+            .create_definition(
+                WorkflowName::new("人事異動申請").unwrap(),
+                None,
+                json!({"steps": []}),
+                true,
+                vec![],
+                tid.clone(),
+                user_id(),
+            )
+            .await
+            .unwrap();
+
+        assert!(def.is_confidential());
+    }
+
     #[tokio::test]
     async fn test_draft定義の更新が成功しバージョンがインクリメントされる() {
         let (usecase, _repo) = create_usecase();
@@ -307,6 +393,8 @@ mod tests {
                 WorkflowName::new("元の名前").unwrap(),
                 None,
                 json!({"steps": []}),
+                false,
+                vec![],
                 tid.clone(),
                 user_id(),
             )
@@ -320,6 +408,8 @@ mod tests {
                 WorkflowName::new("更新後の名前").unwrap(),
                 Some("説明追加".to_string()),
                 json!({"steps": [{"id": "s1"}]}),
+                None,
+                None,
                 original_version,
                 &tid,
             )
@@ -330,6 +420,103 @@ mod tests {
         assert_eq!(updated.version(), original_version.next());
     }
 
+    #[tokio::test]
+    async fn test_機密区分を省略して更新すると現在の値を維持する() {
+        let (usecase, _repo) = create_usecase();
+        let tid = tenant_id();
+
+        let def = usecase
+            .create_definition(
+                WorkflowName::new("人事異動申請").unwrap(),
+                None,
+                json!({"steps": []}),
+                true,
+                vec![],
+                tid.clone(),
+                user_id(),
+            )
+            .await
+            .unwrap();
+
+        let updated = usecase
+            .update_definition(
+                def.id(),
+                WorkflowName::new("人事異動申請（改）").unwrap(),
+                None,
+                json!({"steps": []}),
+                None,
+                None,
+                def.version(),
+                &tid,
+            )
+            .await
+            .unwrap();
+
+        assert!(updated.is_confidential());
+    }
+
+    /// テナント内の有効なユーザーを 1 名登録したユースケースを構築する
+    fn create_usecase_with_user(tenant_id: &TenantId) -> (WorkflowDefinitionUseCaseImpl, UserId) {
+        let user = ringiflow_domain::user::User::new(
+            UserId::new(),
+            tenant_id.clone(),
+            ringiflow_domain::value_objects::DisplayNumber::new(1).unwrap(),
+            ringiflow_domain::user::Email::new("lead@example.com").unwrap(),
+            ringiflow_domain::value_objects::UserName::new("チームリーダー").unwrap(),
+            fixed_now(),
+        );
+        let user_id = user.id().clone();
+        let user_repo = FakeUserRepository::new();
+        user_repo.add_user(user);
+        let usecase = WorkflowDefinitionUseCaseImpl::new(
+            Arc::new(FakeWorkflowDefinitionRepository::new()),
+            Arc::new(user_repo),
+            Arc::new(FixedClock::new(fixed_now())),
+        );
+        (usecase, user_id)
+    }
+
+    #[tokio::test]
+    async fn test_既定ウォッチャーを指定して作成できる() {
+        let tid = tenant_id();
+        let (usecase, lead_id) = create_usecase_with_user(&tid);
+
+        let def = usecase
+            .create_definition(
+                WorkflowName::new("経費申請").unwrap(),
+                None,
+                json!({"steps": []}),
+                false,
+                vec![lead_id.clone()],
+                tid,
+                user_id(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(def.default_watchers(), &[lead_id]);
+    }
+
+    #[tokio::test]
+    async fn test_テナント内の有効なユーザーでなければ既定ウォッチャーに指定できない() {
+        let tid = tenant_id();
+        let (usecase, lead_id) = create_usecase_with_user(&tid);
+
+        let result = usecase
+            .create_definition(
+                WorkflowName::new("経費申請").unwrap(),
+                None,
+                json!({"steps": []}),
+                false,
+                vec![lead_id, UserId::new()],
+                tid,
+                user_id(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
     #[tokio::test]
     async fn test_published定義の更新がエラーを返す() {
         let (usecase, repo) = create_usecase();
@@ -354,6 +541,8 @@ mod tests {
                 WorkflowName::new("更新").unwrap(),
                 None,
                 json!({}),
+                None,
+                None,
                 published.version(),
                 &tid,
             )
@@ -372,6 +561,8 @@ mod tests {
                 WorkflowName::new("削除対象").unwrap(),
                 None,
                 json!({"steps": []}),
+                false,
+                vec![],
                 tid.clone(),
                 user_id(),
             )
@@ -416,6 +607,8 @@ mod tests {
                 WorkflowName::new("公開予定").unwrap(),
                 None,
                 valid_definition_json(),
+                false,
+                vec![],
                 tid.clone(),
                 user_id(),
             )
@@ -444,6 +637,8 @@ mod tests {
                 WorkflowName::new("不正な定義").unwrap(),
                 None,
                 json!({"steps": [], "transitions": []}),
+                false,
+                vec![],
                 tid.clone(),
                 user_id(),
             )
@@ -495,6 +690,8 @@ mod tests {
                 WorkflowName::new("下書き").unwrap(),
                 None,
                 json!({"steps": []}),
+                false,
+                vec![],
                 tid.clone(),
                 user_id(),
             )
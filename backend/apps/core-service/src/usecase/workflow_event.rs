@@ -0,0 +1,45 @@
+//! # ワークフローイベント配信
+//!
+//! トランザクショナルアウトボックス（`workflow_event_outbox`）に書き込まれた
+//! ドメインイベントをコンシューマへ配信する。
+//!
+//! ## 設計方針
+//!
+//! - **状態遷移と同一トランザクションで記録**: コマンドはイベントをアウトボックスに書き込むだけで、
+//!   副作用（通知など）は実行しない。コミットされた遷移のイベントだけが配信される
+//! - **at-least-once 配信**: いずれかのコンシューマが失敗したイベントは全コンシューマに再配信される。
+//!   コンシューマは同じイベントを複数回受け取っても問題ないように実装する
+//! - **拡張点**: 新たな副作用は `WorkflowEventConsumer` を実装してディスパッチャに登録する
+//!
+//! ## モジュール構成
+//!
+//! - `dispatcher`: 未配信イベントのポーリングとリトライ制御
+//! - `notification_consumer`: イベントをメール通知に変換するコンシューマ
+//! - `realtime_consumer`: イベントを関与者へのリアルタイム通知として Redis に発行するコンシューマ
+//! - `webhook_consumer`: イベントを購読している Webhook の配信を登録するコンシューマ
+
+pub mod dispatcher;
+pub mod notification_consumer;
+pub mod realtime_consumer;
+pub mod webhook_consumer;
+
+use async_trait::async_trait;
+pub use dispatcher::WorkflowEventDispatcher;
+pub use notification_consumer::NotificationEventConsumer;
+pub use realtime_consumer::RealtimeEventConsumer;
+use ringiflow_domain::workflow::WorkflowEvent;
+pub use webhook_consumer::WebhookEventConsumer;
+
+use crate::error::CoreError;
+
+/// ワークフローイベントのコンシューマ
+///
+/// `handle` がエラーを返すと、イベントはバックオフ後に再配信される。
+#[async_trait]
+pub trait WorkflowEventConsumer: Send + Sync {
+    /// ログ出力用のコンシューマ名
+    fn name(&self) -> &'static str;
+
+    /// イベントを処理する
+    async fn handle(&self, event: &WorkflowEvent) -> Result<(), CoreError>;
+}
@@ -0,0 +1,523 @@
+//! # イベントディスパッチャ
+//!
+//! アウトボックスの未配信イベントをポーリングし、登録されたコンシューマへ配信する。
+//!
+//! ## 処理フロー
+//!
+//! 1. 配信可能なイベントを `FOR UPDATE SKIP LOCKED` で取り出し、処理期限（リース）を設定してコミット
+//! 2. イベントごとに、まだ配信に成功していないコンシューマを順に呼び出す（トランザクション外）
+//! 3. 短いトランザクションで結果を記録する
+//!    - 全コンシューマが成功 → 配信済みにする
+//!    - いずれかが失敗 → 失敗回数を加算し、指数バックオフで次回試行日時を設定する
+//!    - [`MAX_DISPATCH_ATTEMPTS`] 回失敗 → 配信停止にする
+//!
+//! 配信に成功したコンシューマはイベントごとに記録し、再配信時には呼び出さない
+//! （通知メール・リアルタイム通知を重複して送らない）。
+//! 処理期限までは他のインスタンスが同じイベントを取り出さないため、複数インスタンスで
+//! 同時に実行しても同じイベントを二重に処理しない。処理中にプロセスが停止した場合は、
+//! 処理期限を過ぎてから再配信する。
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use ringiflow_domain::clock::Clock;
+use ringiflow_infra::{
+    TransactionManager,
+    repository::{PendingWorkflowEvent, WorkflowEventOutboxRepository},
+};
+
+use super::WorkflowEventConsumer;
+use crate::{error::CoreError, usecase::polling::PollingWorker};
+
+/// 配信を試みる最大回数（これに達すると配信停止にする）
+pub const MAX_DISPATCH_ATTEMPTS: i32 = 10;
+
+/// リトライ間隔の初期値（秒）
+const RETRY_BASE_SECONDS: i64 = 30;
+
+/// リトライ間隔の上限（秒）
+const RETRY_MAX_SECONDS: i64 = 60 * 60;
+
+/// 取り出したイベントの処理期限（秒）
+///
+/// 期限を過ぎても結果を記録していないイベントは、処理中に停止したとみなして再配信する。
+const LEASE_SECONDS: i64 = 5 * 60;
+
+/// ワークフローイベントディスパッチャ
+pub struct WorkflowEventDispatcher {
+    outbox_repo: Arc<dyn WorkflowEventOutboxRepository>,
+    consumers:   Vec<Arc<dyn WorkflowEventConsumer>>,
+    tx_manager:  Arc<dyn TransactionManager>,
+    clock:       Arc<dyn Clock>,
+    batch_size:  i64,
+}
+
+/// 1 イベントの配信結果
+struct Delivery {
+    /// 配信に成功したコンシューマ名（以前の試行での成功を含む）
+    delivered_consumers: Vec<String>,
+    /// 失敗したコンシューマ名とエラー
+    errors: Vec<String>,
+}
+
+impl WorkflowEventDispatcher {
+    pub fn new(
+        outbox_repo: Arc<dyn WorkflowEventOutboxRepository>,
+        consumers: Vec<Arc<dyn WorkflowEventConsumer>>,
+        tx_manager: Arc<dyn TransactionManager>,
+        clock: Arc<dyn Clock>,
+        batch_size: i64,
+    ) -> Self {
+        Self {
+            outbox_repo,
+            consumers,
+            tx_manager,
+            clock,
+            batch_size,
+        }
+    }
+
+    /// 配信可能なイベントを 1 バッチ分配信する
+    ///
+    /// 処理したイベント数（成功・失敗の合計）を返す。
+    pub async fn dispatch_pending(&self) -> Result<usize, CoreError> {
+        let now = self.clock.now();
+        let pending = self.claim(now).await?;
+
+        for entry in &pending {
+            let delivery = self.deliver(entry).await;
+            self.record(entry, delivery).await?;
+        }
+
+        Ok(pending.len())
+    }
+
+    /// 配信可能なイベントを取り出し、処理期限を設定してコミットする
+    async fn claim(&self, now: DateTime<Utc>) -> Result<Vec<PendingWorkflowEvent>, CoreError> {
+        let mut tx = self
+            .tx_manager
+            .begin()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクション開始に失敗: {}", e)))?;
+
+        let pending = self
+            .outbox_repo
+            .claim_pending(
+                &mut tx,
+                now,
+                now + TimeDelta::seconds(LEASE_SECONDS),
+                self.batch_size,
+            )
+            .await
+            .map_err(|e| CoreError::Internal(format!("未配信イベントの取得に失敗: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))?;
+
+        Ok(pending)
+    }
+
+    /// まだ配信に成功していないコンシューマにイベントを配信する
+    async fn deliver(&self, entry: &PendingWorkflowEvent) -> Delivery {
+        let mut delivered_consumers = entry.delivered_consumers.clone();
+        let mut errors = Vec::new();
+        for consumer in &self.consumers {
+            let name = consumer.name();
+            if delivered_consumers.iter().any(|d| d == name) {
+                continue;
+            }
+            match consumer.handle(&entry.event).await {
+                Ok(()) => delivered_consumers.push(name.to_string()),
+                Err(e) => errors.push(format!("{}: {}", name, e)),
+            }
+        }
+        Delivery {
+            delivered_consumers,
+            errors,
+        }
+    }
+
+    /// 配信結果を短いトランザクションで記録する
+    async fn record(
+        &self,
+        entry: &PendingWorkflowEvent,
+        delivery: Delivery,
+    ) -> Result<(), CoreError> {
+        let event = &entry.event;
+        let now = self.clock.now();
+        let mut tx = self
+            .tx_manager
+            .begin()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクション開始に失敗: {}", e)))?;
+
+        if delivery.errors.is_empty() {
+            self.outbox_repo
+                .mark_dispatched(&mut tx, event.id(), &delivery.delivered_consumers, now)
+                .await
+                .map_err(|e| CoreError::Internal(format!("イベントの配信済み更新に失敗: {}", e)))?;
+        } else {
+            let error = delivery.errors.join("; ");
+            let attempts = entry.attempts + 1;
+            let result = if attempts >= MAX_DISPATCH_ATTEMPTS {
+                tracing::error!(
+                    event_id = %event.id(),
+                    event_type = %event.event_type(),
+                    attempts,
+                    error = %error,
+                    "ワークフローイベントの配信が最大試行回数に達したため配信を停止"
+                );
+                self.outbox_repo
+                    .mark_dead(
+                        &mut tx,
+                        event.id(),
+                        &delivery.delivered_consumers,
+                        &error,
+                        now,
+                    )
+                    .await
+            } else {
+                let next_attempt_at = next_attempt_at(now, attempts);
+                tracing::warn!(
+                    event_id = %event.id(),
+                    event_type = %event.event_type(),
+                    attempts,
+                    next_attempt_at = %next_attempt_at,
+                    error = %error,
+                    "ワークフローイベントの配信に失敗"
+                );
+                self.outbox_repo
+                    .mark_failed(
+                        &mut tx,
+                        event.id(),
+                        &delivery.delivered_consumers,
+                        &error,
+                        next_attempt_at,
+                    )
+                    .await
+            };
+            result.map_err(|e| {
+                CoreError::Internal(format!("イベントの配信失敗の記録に失敗: {}", e))
+            })?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PollingWorker for WorkflowEventDispatcher {
+    fn name(&self) -> &'static str {
+        "ワークフローイベントディスパッチャ"
+    }
+
+    fn batch_size(&self) -> i64 {
+        self.batch_size
+    }
+
+    async fn step(&self) -> Result<usize, CoreError> {
+        self.dispatch_pending().await
+    }
+}
+
+/// 次回試行日時を計算する
+///
+/// 失敗回数 n に対して `30 秒 × 2^(n-1)`（上限 1 時間）後。
+fn next_attempt_at(now: DateTime<Utc>, attempts: i32) -> DateTime<Utc> {
+    let exponent = u32::try_from(attempts.saturating_sub(1))
+        .unwrap_or(0)
+        .min(16);
+    let seconds = RETRY_BASE_SECONDS
+        .saturating_mul(1_i64 << exponent)
+        .min(RETRY_MAX_SECONDS);
+    now + TimeDelta::seconds(seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use ringiflow_domain::{
+        clock::FixedClock,
+        tenant::TenantId,
+        user::UserId,
+        value_objects::{DisplayNumber, Version},
+        workflow::{
+            NewWorkflowEvent,
+            NewWorkflowInstance,
+            WorkflowDefinitionId,
+            WorkflowEvent,
+            WorkflowEventId,
+            WorkflowEventPayload,
+            WorkflowEventType,
+            WorkflowInstance,
+            WorkflowInstanceId,
+        },
+    };
+    use ringiflow_infra::{
+        TxContext,
+        fake::{FakeTransactionManager, FakeWorkflowEventOutboxRepository},
+    };
+
+    use super::*;
+
+    /// 受け取ったイベントを記録し、設定に応じて失敗するコンシューマ
+    struct StubConsumer {
+        name:     &'static str,
+        received: Mutex<Vec<WorkflowEventId>>,
+        fail:     Mutex<bool>,
+    }
+
+    impl StubConsumer {
+        fn new(name: &'static str, fail: bool) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                received: Mutex::new(Vec::new()),
+                fail: Mutex::new(fail),
+            })
+        }
+
+        fn received(&self) -> Vec<WorkflowEventId> {
+            self.received.lock().unwrap().clone()
+        }
+
+        fn set_fail(&self, fail: bool) {
+            *self.fail.lock().unwrap() = fail;
+        }
+    }
+
+    #[async_trait]
+    impl WorkflowEventConsumer for StubConsumer {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn handle(&self, event: &WorkflowEvent) -> Result<(), CoreError> {
+            self.received.lock().unwrap().push(event.id().clone());
+            if *self.fail.lock().unwrap() {
+                Err(CoreError::Internal("送信先に接続できません".to_string()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    async fn seed_event(
+        outbox_repo: &FakeWorkflowEventOutboxRepository,
+        now: DateTime<Utc>,
+    ) -> WorkflowEvent {
+        let tenant_id = TenantId::new();
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: WorkflowDefinitionId::new(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(100).unwrap(),
+            title: "テスト申請".to_string(),
+            form_data: serde_json::json!({}),
+            initiated_by: UserId::new(),
+            now,
+        });
+        let event = WorkflowEvent::new(NewWorkflowEvent {
+            id: WorkflowEventId::new(),
+            tenant_id: tenant_id.clone(),
+            instance_id: instance.id().clone(),
+            event_type: WorkflowEventType::Submitted,
+            actor_id: Some(instance.initiated_by().clone()),
+            payload: WorkflowEventPayload::new(&instance, None, None),
+            now,
+        });
+        outbox_repo
+            .insert_all(
+                &mut TxContext::mock(),
+                std::slice::from_ref(&event),
+                &tenant_id,
+            )
+            .await
+            .unwrap();
+        event
+    }
+
+    fn build_sut(
+        outbox_repo: &FakeWorkflowEventOutboxRepository,
+        consumers: Vec<Arc<dyn WorkflowEventConsumer>>,
+        now: DateTime<Utc>,
+    ) -> WorkflowEventDispatcher {
+        WorkflowEventDispatcher::new(
+            Arc::new(outbox_repo.clone()),
+            consumers,
+            Arc::new(FakeTransactionManager),
+            Arc::new(FixedClock::new(now)),
+            50,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_全コンシューマが成功すると配信済みになる() {
+        // Arrange
+        let now = chrono::Utc::now();
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
+        let event = seed_event(&outbox_repo, now).await;
+        let consumer = StubConsumer::new("stub", false);
+        let sut = build_sut(&outbox_repo, vec![consumer.clone()], now);
+
+        // Act
+        let count = sut.dispatch_pending().await.unwrap();
+
+        // Assert
+        assert_eq!(count, 1);
+        assert_eq!(consumer.received(), vec![event.id().clone()]);
+        assert_eq!(outbox_repo.dispatched_ids(), vec![event.id().clone()]);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_配信済みのイベントは再配信されない() {
+        // Arrange
+        let now = chrono::Utc::now();
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
+        seed_event(&outbox_repo, now).await;
+        let consumer = StubConsumer::new("stub", false);
+        let sut = build_sut(&outbox_repo, vec![consumer.clone()], now);
+        sut.dispatch_pending().await.unwrap();
+
+        // Act
+        let count = sut.dispatch_pending().await.unwrap();
+
+        // Assert
+        assert_eq!(count, 0);
+        assert_eq!(consumer.received().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_コンシューマが失敗すると失敗が記録されバックオフ中は再配信されない()
+     {
+        // Arrange
+        let now = chrono::Utc::now();
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
+        let event = seed_event(&outbox_repo, now).await;
+        let succeeding = StubConsumer::new("succeeding", false);
+        let failing = StubConsumer::new("failing", true);
+        let sut = build_sut(&outbox_repo, vec![succeeding.clone(), failing.clone()], now);
+
+        // Act
+        sut.dispatch_pending().await.unwrap();
+        let retried = sut.dispatch_pending().await.unwrap();
+
+        // Assert: 失敗回数と理由が記録され、配信済みにはならない
+        assert!(outbox_repo.dispatched_ids().is_empty());
+        let (attempts, last_error) = outbox_repo.failure_of(event.id()).unwrap();
+        assert_eq!(attempts, 1);
+        assert!(last_error.unwrap().contains("送信先に接続できません"));
+
+        // Assert: 次回試行日時までは再配信されない
+        assert_eq!(retried, 0);
+        assert_eq!(succeeding.received().len(), 1);
+        assert_eq!(failing.received().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_再配信では失敗したコンシューマにのみ配信する() {
+        // Arrange
+        let now = chrono::Utc::now();
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
+        let event = seed_event(&outbox_repo, now).await;
+        let succeeding = StubConsumer::new("succeeding", false);
+        let failing = StubConsumer::new("failing", true);
+        let sut = build_sut(&outbox_repo, vec![succeeding.clone(), failing.clone()], now);
+        sut.dispatch_pending().await.unwrap();
+        assert_eq!(
+            outbox_repo.delivered_consumers_of(event.id()),
+            vec!["succeeding".to_string()]
+        );
+
+        // Act: バックオフ経過後に失敗していたコンシューマが復旧する
+        outbox_repo.set_next_attempt_at(event.id(), now);
+        failing.set_fail(false);
+        let count = sut.dispatch_pending().await.unwrap();
+
+        // Assert: 成功済みのコンシューマには重複して配信しない
+        assert_eq!(count, 1);
+        assert_eq!(succeeding.received().len(), 1);
+        assert_eq!(failing.received().len(), 2);
+        assert_eq!(outbox_repo.dispatched_ids(), vec![event.id().clone()]);
+        assert_eq!(
+            outbox_repo.delivered_consumers_of(event.id()),
+            vec!["succeeding".to_string(), "failing".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_最大試行回数に達すると配信停止になり再配信されない() {
+        // Arrange
+        let now = chrono::Utc::now();
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
+        let event = seed_event(&outbox_repo, now).await;
+        let failing = StubConsumer::new("failing", true);
+        let sut = build_sut(&outbox_repo, vec![failing.clone()], now);
+
+        // Act
+        for _ in 0..MAX_DISPATCH_ATTEMPTS {
+            outbox_repo.set_next_attempt_at(event.id(), now);
+            sut.dispatch_pending().await.unwrap();
+        }
+        outbox_repo.set_next_attempt_at(event.id(), now);
+        let count = sut.dispatch_pending().await.unwrap();
+
+        // Assert
+        assert_eq!(count, 0);
+        assert_eq!(outbox_repo.dead_ids(), vec![event.id().clone()]);
+        let (attempts, _) = outbox_repo.failure_of(event.id()).unwrap();
+        assert_eq!(attempts, MAX_DISPATCH_ATTEMPTS);
+        assert_eq!(
+            failing.received().len(),
+            usize::try_from(MAX_DISPATCH_ATTEMPTS).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_pending_取り出したイベントは処理期限まで再度取り出されない() {
+        // Arrange
+        let now = chrono::Utc::now();
+        let outbox_repo = FakeWorkflowEventOutboxRepository::new();
+        seed_event(&outbox_repo, now).await;
+
+        // Act: 結果を記録せずに停止した場合を再現するため、取り出しだけを行う
+        let claimed = outbox_repo
+            .claim_pending(
+                &mut TxContext::mock(),
+                now,
+                now + TimeDelta::seconds(LEASE_SECONDS),
+                50,
+            )
+            .await
+            .unwrap();
+        let consumer = StubConsumer::new("stub", false);
+        let sut = build_sut(&outbox_repo, vec![consumer.clone()], now);
+        let count = sut.dispatch_pending().await.unwrap();
+
+        // Assert
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(count, 0);
+        assert!(consumer.received().is_empty());
+    }
+
+    #[test]
+    fn test_next_attempt_at_指数バックオフで上限1時間() {
+        let now = chrono::Utc::now();
+
+        for (attempts, expected_seconds) in [(1, 30), (2, 60), (3, 120), (8, 3600), (100, 3600)] {
+            assert_eq!(
+                next_attempt_at(now, attempts) - now,
+                TimeDelta::seconds(expected_seconds),
+                "attempts = {attempts}"
+            );
+        }
+    }
+}
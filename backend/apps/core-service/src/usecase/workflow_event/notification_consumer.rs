@@ -0,0 +1,1080 @@
+//! # 通知コンシューマ
+//!
+//! ワークフローイベントをメール通知に変換して送信する。
+//!
+//! | イベント | 通知 |
+//! |---------|------|
+//! | `submitted` / `resubmitted` | `ApprovalRequest` → アクティブになったステップの承認者 |
+//! | `step_approved` | `StepApproved` → 申請者、`ApprovalRequest` → 次の承認者 |
+//! | `approved` | `Approved` → 申請者 |
+//! | `rejected` / `changes_requested` | `Rejected` / `ChangesRequested` → 申請者 |
+//! | `approval_retracted` | `ApprovalRetracted` → 申請者、待機中に戻ったステップの承認者 |
+//! | `step_reassigned` | `ApprovalRequest` → 変更後の担当者 |
+//! | `cancelled` | `Cancelled` → 申請者、取消時にアクティブだったステップの承認者 |
+//! | `mentioned` | `Mentioned` → コメントでメンションされたユーザー |
+//! | `comment_posted` | なし（リアルタイム通知のみ） |
+//!
+//! 申請者向けの通知（`StepApproved` / `Approved` / `Rejected` / `ChangesRequested` / `ApprovalRetracted` / `Cancelled`）は
+//! インスタンスのウォッチャーにも同じ内容で送信する。申請者本人とイベントの操作者は除く。
+//!
+//! 代理申請のインスタンスでは、申請者向けの通知を本人と代理人の両方に送信する。
+//! また、`submitted` / `resubmitted` で本人に `ProxySubmitted`（代理で申請されたこと）を送信する。
+//!
+//! ユーザー情報の取得に失敗した場合はエラーを返し、イベントは再配信される。
+//! ユーザーが存在しない場合は再配信しても結果が変わらないため、通知をスキップする。
+//! メール送信の失敗は `NotificationService` が通知ログに記録し、エラーは返さない。
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ringiflow_domain::{
+    notification::WorkflowNotification,
+    user::{User, UserId},
+    value_objects::{DisplayId, display_prefix},
+    workflow::{WorkflowEvent, WorkflowEventStep, WorkflowEventType},
+};
+use ringiflow_infra::repository::{UserRepository, WorkflowWatcherRepository};
+
+use super::WorkflowEventConsumer;
+use crate::{error::CoreError, usecase::notification::NotificationService};
+
+/// 通知コンシューマ
+pub struct NotificationEventConsumer {
+    user_repo: Arc<dyn UserRepository>,
+    watcher_repo: Arc<dyn WorkflowWatcherRepository>,
+    notification_service: Arc<NotificationService>,
+}
+
+impl NotificationEventConsumer {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        watcher_repo: Arc<dyn WorkflowWatcherRepository>,
+        notification_service: Arc<NotificationService>,
+    ) -> Self {
+        Self {
+            user_repo,
+            watcher_repo,
+            notification_service,
+        }
+    }
+
+    /// 通知先のユーザーを取得する
+    ///
+    /// ユーザーが存在しない場合は `None` を返す（警告ログのみ）。
+    async fn find_user(&self, user_id: &UserId, role: &str) -> Result<Option<User>, CoreError> {
+        let user = self.user_repo.find_by_id(user_id).await?;
+        if user.is_none() {
+            tracing::warn!(user_id = %user_id, "通知用の{role}情報が見つかりません");
+        }
+        Ok(user)
+    }
+
+    /// 承認依頼通知を送信する
+    ///
+    /// 承認者が未設定、または存在しない場合は送信しない。
+    async fn send_approval_request(
+        &self,
+        event: &WorkflowEvent,
+        workflow_display_id: &str,
+        applicant: &User,
+        active_step: &WorkflowEventStep,
+    ) -> Result<(), CoreError> {
+        let Some(approver_id) = active_step.assigned_to.as_ref() else {
+            return Ok(());
+        };
+        let Some(approver) = self.find_user(approver_id, "承認者").await? else {
+            return Ok(());
+        };
+
+        let notification = WorkflowNotification::ApprovalRequest {
+            workflow_title:      event.payload().title.clone(),
+            workflow_display_id: workflow_display_id.to_string(),
+            applicant_name:      applicant.name().as_str().to_string(),
+            step_name:           active_step.step_name.clone(),
+            approver_email:      approver.email().as_str().to_string(),
+            approver_user_id:    approver_id.clone(),
+        };
+        self.notification_service
+            .notify(notification, event.tenant_id(), event.instance_id())
+            .await;
+        Ok(())
+    }
+
+    /// ウォッチャーに申請者と同じ通知を送信する
+    ///
+    /// 申請者本人（代理申請の代理人を含む）と、イベントを発生させた操作者（承認者など）には送信しない。
+    /// 承認取り消しで待機中に戻ったステップの承認者、取消時にアクティブだったステップの承認者は
+    /// 個別に通知するため除く。
+    async fn notify_watchers(&self, content: &NotificationContent<'_>) -> Result<(), CoreError> {
+        let event = content.event;
+        let watchers = self
+            .watcher_repo
+            .find_by_instance(event.instance_id(), event.tenant_id())
+            .await?;
+
+        for watcher in watchers {
+            let user_id = watcher.user_id();
+            if user_id == &event.payload().initiated_by
+                || Some(user_id) == event.payload().proxy_submitter.as_ref()
+                || Some(user_id) == event.actor_id()
+                || (notifies_step_assignee(event.event_type())
+                    && Some(user_id)
+                        == event
+                            .payload()
+                            .step
+                            .as_ref()
+                            .and_then(|s| s.assigned_to.as_ref()))
+            {
+                continue;
+            }
+            let Some(user) = self.find_user(user_id, "ウォッチャー").await? else {
+                continue;
+            };
+            if let Some(notification) = content.build(&user) {
+                self.notification_service
+                    .notify(notification, event.tenant_id(), event.instance_id())
+                    .await;
+            }
+        }
+        Ok(())
+    }
+
+    /// 代理申請されたことを本人に通知する
+    ///
+    /// 代理人が存在しない場合は送信しない。
+    async fn send_proxy_submitted(
+        &self,
+        event: &WorkflowEvent,
+        workflow_display_id: &str,
+        applicant: &User,
+        proxy_id: &UserId,
+    ) -> Result<(), CoreError> {
+        let Some(proxy) = self.find_user(proxy_id, "代理人").await? else {
+            return Ok(());
+        };
+
+        let notification = WorkflowNotification::ProxySubmitted {
+            workflow_title:      event.payload().title.clone(),
+            workflow_display_id: workflow_display_id.to_string(),
+            proxy_name:          proxy.name().as_str().to_string(),
+            recipient_email:     applicant.email().as_str().to_string(),
+            recipient_user_id:   applicant.id().clone(),
+        };
+        self.notification_service
+            .notify(notification, event.tenant_id(), event.instance_id())
+            .await;
+        Ok(())
+    }
+
+    /// 申請者向けと同じ通知を、操作対象のステップの承認者に送信する
+    ///
+    /// 承認の取り消しでは待機中に戻ったステップ、取消ではアクティブだったステップが対象。
+    /// 承認者が申請者本人・代理人の場合は申請者向けの通知と重複するため送信しない。
+    async fn send_to_step_assignee(
+        &self,
+        content: &NotificationContent<'_>,
+        step: &WorkflowEventStep,
+    ) -> Result<(), CoreError> {
+        let event = content.event;
+        let Some(approver_id) = step.assigned_to.as_ref() else {
+            return Ok(());
+        };
+        if approver_id == &event.payload().initiated_by
+            || Some(approver_id) == event.payload().proxy_submitter.as_ref()
+        {
+            return Ok(());
+        }
+        let Some(approver) = self.find_user(approver_id, "承認者").await? else {
+            return Ok(());
+        };
+        if let Some(notification) = content.build(&approver) {
+            self.notification_service
+                .notify(notification, event.tenant_id(), event.instance_id())
+                .await;
+        }
+        Ok(())
+    }
+
+    /// コメントでメンションされたユーザーに通知する
+    ///
+    /// コメントの投稿者本人には送信しない。
+    async fn send_mentioned(&self, event: &WorkflowEvent) -> Result<(), CoreError> {
+        let payload = event.payload();
+        let Some(comment) = payload.comment.as_ref() else {
+            return Ok(());
+        };
+
+        let workflow_display_id =
+            DisplayId::new(display_prefix::WORKFLOW_INSTANCE, payload.display_number).to_string();
+        let mentioned_by_name = match event.actor_id() {
+            Some(actor_id) => self
+                .find_user(actor_id, "投稿者")
+                .await?
+                .map(|user| user.name().as_str().to_string())
+                .unwrap_or_default(),
+            None => String::new(),
+        };
+
+        for user_id in &comment.mentioned {
+            if Some(user_id) == event.actor_id() {
+                continue;
+            }
+            let Some(user) = self.find_user(user_id, "メンション先").await? else {
+                continue;
+            };
+            let notification = WorkflowNotification::Mentioned {
+                workflow_title: payload.title.clone(),
+                workflow_display_id: workflow_display_id.clone(),
+                mentioned_by_name: mentioned_by_name.clone(),
+                comment: comment.body.clone(),
+                recipient_email: user.email().as_str().to_string(),
+                recipient_user_id: user_id.clone(),
+            };
+            self.notification_service
+                .notify(notification, event.tenant_id(), event.instance_id())
+                .await;
+        }
+        Ok(())
+    }
+
+    /// ステップ担当者の名前を解決する（取得できない場合は空文字）
+    async fn resolve_assignee_name(&self, step: Option<&WorkflowEventStep>) -> String {
+        let Some(user_id) = step.and_then(|s| s.assigned_to.as_ref()) else {
+            return String::new();
+        };
+        match self.user_repo.find_by_id(user_id).await {
+            Ok(Some(user)) => user.name().as_str().to_string(),
+            _ => String::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl WorkflowEventConsumer for NotificationEventConsumer {
+    fn name(&self) -> &'static str {
+        "notification"
+    }
+
+    async fn handle(&self, event: &WorkflowEvent) -> Result<(), CoreError> {
+        // メンションは申請者ではなく、メンションされたユーザーだけに通知する
+        match event.event_type() {
+            WorkflowEventType::Mentioned => return self.send_mentioned(event).await,
+            WorkflowEventType::CommentPosted => return Ok(()),
+            _ => {}
+        }
+
+        let payload = event.payload();
+        let Some(applicant) = self.find_user(&payload.initiated_by, "申請者").await? else {
+            return Ok(());
+        };
+
+        let workflow_display_id =
+            DisplayId::new(display_prefix::WORKFLOW_INSTANCE, payload.display_number).to_string();
+        let approver_name = match event.event_type() {
+            WorkflowEventType::StepApproved => {
+                self.resolve_assignee_name(payload.step.as_ref()).await
+            }
+            // 取り消した承認者は、再びアクティブになったステップの担当者
+            WorkflowEventType::ApprovalRetracted => {
+                self.resolve_assignee_name(payload.activated_step.as_ref())
+                    .await
+            }
+            _ => String::new(),
+        };
+        let content = NotificationContent {
+            event,
+            workflow_display_id: &workflow_display_id,
+            approver_name: &approver_name,
+        };
+
+        if let Some(notification) = content.build(&applicant) {
+            self.notification_service
+                .notify(notification, event.tenant_id(), event.instance_id())
+                .await;
+            // 代理申請の場合は代理人にも同じ内容で送信する
+            if let Some(proxy_id) = payload.proxy_submitter.as_ref()
+                && let Some(proxy) = self.find_user(proxy_id, "代理人").await?
+                && let Some(notification) = content.build(&proxy)
+            {
+                self.notification_service
+                    .notify(notification, event.tenant_id(), event.instance_id())
+                    .await;
+            }
+            self.notify_watchers(&content).await?;
+        }
+
+        // 代理で申請・再申請されたことを本人に知らせる
+        if matches!(
+            event.event_type(),
+            WorkflowEventType::Submitted | WorkflowEventType::Resubmitted
+        ) && let Some(proxy_id) = payload.proxy_submitter.as_ref()
+        {
+            self.send_proxy_submitted(event, &workflow_display_id, &applicant, proxy_id)
+                .await?;
+        }
+
+        // 承認の取り消しで待機中に戻ったステップ、取消でアクティブだったステップの承認者に知らせる
+        if notifies_step_assignee(event.event_type())
+            && let Some(step) = payload.step.as_ref()
+        {
+            self.send_to_step_assignee(&content, step).await?;
+        }
+
+        // 新たにアクティブになったステップの承認者に承認依頼を送る
+        // （承認の取り消しでは、再びアクティブになるのは取り消した承認者自身のステップのため送らない）
+        if event.event_type() != WorkflowEventType::ApprovalRetracted
+            && let Some(active_step) = payload.activated_step.as_ref()
+        {
+            self.send_approval_request(event, &workflow_display_id, &applicant, active_step)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// 申請者向けと同じ通知を操作対象のステップの承認者にも送信するイベントか
+fn notifies_step_assignee(event_type: WorkflowEventType) -> bool {
+    matches!(
+        event_type,
+        WorkflowEventType::ApprovalRetracted | WorkflowEventType::Cancelled
+    )
+}
+
+/// 申請者・ウォッチャー向け通知の共通内容
+///
+/// 受信者ごとに [`NotificationContent::build`] で通知を組み立てる。
+struct NotificationContent<'a> {
+    event: &'a WorkflowEvent,
+    workflow_display_id: &'a str,
+    approver_name: &'a str,
+}
+
+impl NotificationContent<'_> {
+    /// 受信者向けの通知を組み立てる（申請者・ウォッチャー向けの通知がないイベントは `None`）
+    fn build(&self, recipient: &User) -> Option<WorkflowNotification> {
+        let payload = self.event.payload();
+        let workflow_title = payload.title.clone();
+        let workflow_display_id = self.workflow_display_id.to_string();
+        let recipient_email = recipient.email().as_str().to_string();
+        let recipient_user_id = recipient.id().clone();
+        let comment = payload.step.as_ref().and_then(|s| s.comment.clone());
+
+        match self.event.event_type() {
+            WorkflowEventType::Submitted
+            | WorkflowEventType::Resubmitted
+            | WorkflowEventType::StepReassigned
+            | WorkflowEventType::CommentPosted
+            | WorkflowEventType::Mentioned => None,
+            WorkflowEventType::StepApproved => Some(WorkflowNotification::StepApproved {
+                workflow_title,
+                workflow_display_id,
+                step_name: payload
+                    .step
+                    .as_ref()
+                    .map(|s| s.step_name.clone())
+                    .unwrap_or_default(),
+                approver_name: self.approver_name.to_string(),
+                recipient_email,
+                recipient_user_id,
+            }),
+            WorkflowEventType::Approved => Some(WorkflowNotification::Approved {
+                workflow_title,
+                workflow_display_id,
+                recipient_email,
+                recipient_user_id,
+            }),
+            WorkflowEventType::Rejected => Some(WorkflowNotification::Rejected {
+                workflow_title,
+                workflow_display_id,
+                comment,
+                recipient_email,
+                recipient_user_id,
+            }),
+            WorkflowEventType::ChangesRequested => Some(WorkflowNotification::ChangesRequested {
+                workflow_title,
+                workflow_display_id,
+                comment,
+                recipient_email,
+                recipient_user_id,
+            }),
+            WorkflowEventType::ApprovalRetracted => Some(WorkflowNotification::ApprovalRetracted {
+                workflow_title,
+                workflow_display_id,
+                step_name: payload
+                    .activated_step
+                    .as_ref()
+                    .map(|s| s.step_name.clone())
+                    .unwrap_or_default(),
+                approver_name: self.approver_name.to_string(),
+                recipient_email,
+                recipient_user_id,
+            }),
+            WorkflowEventType::Cancelled => Some(WorkflowNotification::Cancelled {
+                workflow_title,
+                workflow_display_id,
+                recipient_email,
+                recipient_user_id,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ringiflow_domain::{
+        tenant::TenantId,
+        user::Email,
+        value_objects::{DisplayNumber, UserName},
+        workflow::{
+            NewWorkflowEvent,
+            WorkflowCommentId,
+            WorkflowEventComment,
+            WorkflowEventId,
+            WorkflowEventPayload,
+            WorkflowInstanceId,
+            WorkflowStepId,
+            WorkflowWatcher,
+        },
+    };
+    use ringiflow_infra::{
+        fake::{
+            FakeInboxNotificationRepository,
+            FakeNotificationDigestRepository,
+            FakeNotificationLogRepository,
+            FakeNotificationPreferenceRepository,
+            FakeNotificationSender,
+            FakeNotificationTemplateRepository,
+            FakeUserRepository,
+            FakeWorkflowWatcherRepository,
+        },
+        repository::WorkflowWatcherRepository,
+    };
+
+    use super::*;
+    use crate::usecase::notification::TemplateRenderer;
+
+    /// 申請者（田中）と承認者 2 名（鈴木・山田）を登録する
+    ///
+    /// 戻り値: (applicant_id, approver1_id, approver2_id)
+    fn add_users(user_repo: &FakeUserRepository, tenant_id: &TenantId) -> (UserId, UserId, UserId) {
+        let applicant_id = UserId::new();
+        let approver1_id = UserId::new();
+        let approver2_id = UserId::new();
+        let now = chrono::Utc::now();
+        for (id, number, email, name) in [
+            (&applicant_id, 1, "tanaka@example.com", "田中太郎"),
+            (&approver1_id, 2, "suzuki@example.com", "鈴木一郎"),
+            (&approver2_id, 3, "yamada@example.com", "山田花子"),
+        ] {
+            user_repo.add_user(User::new(
+                id.clone(),
+                tenant_id.clone(),
+                DisplayNumber::new(number).unwrap(),
+                Email::new(email).unwrap(),
+                UserName::new(name).unwrap(),
+                now,
+            ));
+        }
+        (applicant_id, approver1_id, approver2_id)
+    }
+
+    fn build_sut(
+        user_repo: FakeUserRepository,
+    ) -> (NotificationEventConsumer, FakeNotificationSender) {
+        build_sut_with_watchers(user_repo, FakeWorkflowWatcherRepository::new())
+    }
+
+    fn build_sut_with_watchers(
+        user_repo: FakeUserRepository,
+        watcher_repo: FakeWorkflowWatcherRepository,
+    ) -> (NotificationEventConsumer, FakeNotificationSender) {
+        let sender = FakeNotificationSender::new();
+        let notification_service = Arc::new(NotificationService::new(
+            Arc::new(sender.clone()),
+            TemplateRenderer::new().unwrap(),
+            Arc::new(FakeNotificationLogRepository::new()),
+            Arc::new(FakeInboxNotificationRepository::new()),
+            Arc::new(FakeNotificationPreferenceRepository::new()),
+            Arc::new(FakeNotificationDigestRepository::new()),
+            Arc::new(FakeNotificationTemplateRepository::new()),
+            Arc::new(user_repo.clone()),
+            "http://localhost:5173".to_string(),
+        ));
+        (
+            NotificationEventConsumer::new(
+                Arc::new(user_repo),
+                Arc::new(watcher_repo),
+                notification_service,
+            ),
+            sender,
+        )
+    }
+
+    fn event_step(name: &str, assigned_to: &UserId, comment: Option<&str>) -> WorkflowEventStep {
+        WorkflowEventStep {
+            step_id:     WorkflowStepId::new(),
+            step_name:   name.to_string(),
+            assigned_to: Some(assigned_to.clone()),
+            comment:     comment.map(|c| c.to_string()),
+        }
+    }
+
+    fn build_event(
+        tenant_id: &TenantId,
+        applicant_id: &UserId,
+        event_type: WorkflowEventType,
+        step: Option<WorkflowEventStep>,
+        activated_step: Option<WorkflowEventStep>,
+    ) -> WorkflowEvent {
+        WorkflowEvent::new(NewWorkflowEvent {
+            id: WorkflowEventId::new(),
+            tenant_id: tenant_id.clone(),
+            instance_id: WorkflowInstanceId::new(),
+            event_type,
+            actor_id: None,
+            payload: WorkflowEventPayload {
+                display_number: DisplayNumber::new(100).unwrap(),
+                title: "テスト申請".to_string(),
+                initiated_by: applicant_id.clone(),
+                step,
+                activated_step,
+                proxy_submitter: None,
+                comment: None,
+            },
+            now: chrono::Utc::now(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_submittedで承認依頼通知がアクティブなステップの承認者に送信される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, _) = add_users(&user_repo, &tenant_id);
+        let event = build_event(
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::Submitted,
+            None,
+            Some(event_step("承認", &approver1_id, None)),
+        );
+        let (sut, sender) = build_sut(user_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1, "承認依頼メールが1通送信されるべき");
+        assert_eq!(sent[0].to, "suzuki@example.com");
+        assert!(
+            sent[0].subject.contains("テスト申請"),
+            "件名にワークフロータイトルが含まれるべき: {}",
+            sent[0].subject
+        );
+    }
+
+    #[tokio::test]
+    async fn test_step_approvedで申請者と次の承認者に通知2通が送信される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, approver2_id) = add_users(&user_repo, &tenant_id);
+        let event = build_event(
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::StepApproved,
+            Some(event_step("上長承認", &approver1_id, Some("上長承認OK"))),
+            Some(event_step("経理承認", &approver2_id, None)),
+        );
+        let (sut, sender) = build_sut(user_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        assert_eq!(
+            sent.len(),
+            2,
+            "ステップ承認通知と承認依頼通知の計2通が送信されるべき"
+        );
+
+        let step_approved_mail = sent
+            .iter()
+            .find(|m| m.to == "tanaka@example.com")
+            .expect("申請者への通知があるべき");
+        assert!(
+            step_approved_mail.subject.contains("ステップ承認"),
+            "件名に「ステップ承認」が含まれるべき: {}",
+            step_approved_mail.subject
+        );
+
+        let approval_request_mail = sent
+            .iter()
+            .find(|m| m.to == "yamada@example.com")
+            .expect("次の承認者への通知があるべき");
+        assert!(
+            approval_request_mail.subject.contains("承認依頼"),
+            "件名に「承認依頼」が含まれるべき: {}",
+            approval_request_mail.subject
+        );
+    }
+
+    #[tokio::test]
+    async fn test_step_reassignedで変更後の担当者にだけ承認依頼通知が送信される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, approver2_id) = add_users(&user_repo, &tenant_id);
+        let event = build_event(
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::StepReassigned,
+            Some(event_step("承認", &approver1_id, None)),
+            Some(event_step("承認", &approver2_id, None)),
+        );
+        let (sut, sender) = build_sut(user_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1, "承認依頼メールが1通だけ送信されるべき");
+        assert_eq!(sent[0].to, "yamada@example.com");
+        assert!(
+            sent[0].subject.contains("承認依頼"),
+            "件名に「承認依頼」が含まれるべき: {}",
+            sent[0].subject
+        );
+    }
+
+    #[tokio::test]
+    async fn test_approvedで承認完了通知が申請者に送信される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, _) = add_users(&user_repo, &tenant_id);
+        let event = build_event(
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::Approved,
+            Some(event_step("承認", &approver1_id, Some("承認します"))),
+            None,
+        );
+        let (sut, sender) = build_sut(user_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1, "承認完了メールが1通送信されるべき");
+        assert_eq!(sent[0].to, "tanaka@example.com");
+        assert!(
+            sent[0].subject.contains("承認完了"),
+            "件名に「承認完了」が含まれるべき: {}",
+            sent[0].subject
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejectedで却下通知がコメント付きで申請者に送信される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, _) = add_users(&user_repo, &tenant_id);
+        let event = build_event(
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::Rejected,
+            Some(event_step(
+                "承認",
+                &approver1_id,
+                Some("領収書が添付されていません"),
+            )),
+            None,
+        );
+        let (sut, sender) = build_sut(user_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1, "却下メールが1通送信されるべき");
+        assert_eq!(sent[0].to, "tanaka@example.com");
+        assert!(
+            sent[0].subject.contains("却下"),
+            "件名に「却下」が含まれるべき: {}",
+            sent[0].subject
+        );
+        assert!(
+            sent[0].text_body.contains("領収書が添付されていません"),
+            "本文に却下コメントが含まれるべき"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_changes_requestedで差し戻し通知がコメント付きで申請者に送信される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, _) = add_users(&user_repo, &tenant_id);
+        let event = build_event(
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::ChangesRequested,
+            Some(event_step(
+                "承認",
+                &approver1_id,
+                Some("金額を修正してください"),
+            )),
+            None,
+        );
+        let (sut, sender) = build_sut(user_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1, "差し戻しメールが1通送信されるべき");
+        assert!(
+            sent[0].subject.contains("要修正"),
+            "件名に「要修正」が含まれるべき: {}",
+            sent[0].subject
+        );
+        assert!(
+            sent[0].text_body.contains("金額を修正してください"),
+            "本文に差し戻しコメントが含まれるべき"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_approval_retractedで申請者と待機中に戻ったステップの承認者に通知される() {
+        // Arrange: 鈴木が上長承認を取り消し、山田の経理承認が待機中に戻った
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, approver2_id) = add_users(&user_repo, &tenant_id);
+        let event = build_event(
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::ApprovalRetracted,
+            Some(event_step("経理承認", &approver2_id, None)),
+            Some(event_step("上長承認", &approver1_id, None)),
+        );
+        let (sut, sender) = build_sut(user_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert: 取り消した承認者（鈴木）への承認依頼は送信しない
+        let sent = sender.sent_emails();
+        let mut recipients: Vec<_> = sent.iter().map(|m| m.to.as_str()).collect();
+        recipients.sort_unstable();
+        assert_eq!(recipients, vec!["tanaka@example.com", "yamada@example.com"]);
+        for mail in &sent {
+            assert!(
+                mail.subject.contains("承認取り消し"),
+                "件名に「承認取り消し」が含まれるべき: {}",
+                mail.subject
+            );
+            assert!(
+                mail.text_body.contains("承認ステップ: 上長承認"),
+                "本文に取り消されたステップ名が含まれるべき"
+            );
+            assert!(
+                mail.text_body.contains("取り消した承認者: 鈴木一郎"),
+                "本文に取り消した承認者名が含まれるべき"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cancelledで申請者とアクティブだったステップの承認者に取消通知が送信される() {
+        // Arrange: 鈴木の上長承認がアクティブな状態で管理者が取り消した
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, _) = add_users(&user_repo, &tenant_id);
+        let event = build_event(
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::Cancelled,
+            Some(event_step("上長承認", &approver1_id, None)),
+            None,
+        );
+        let (sut, sender) = build_sut(user_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        let mut recipients: Vec<_> = sent.iter().map(|m| m.to.as_str()).collect();
+        recipients.sort_unstable();
+        assert_eq!(recipients, vec!["suzuki@example.com", "tanaka@example.com"]);
+        for mail in &sent {
+            assert!(
+                mail.subject.contains("取消"),
+                "件名に「取消」が含まれるべき: {}",
+                mail.subject
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_approval_retractedで待機中に戻ったステップの承認者がウォッチャーでも重複しない() {
+        // Arrange: 山田（待機中に戻ったステップの承認者）がウォッチしている
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, approver2_id) = add_users(&user_repo, &tenant_id);
+        let event = build_event(
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::ApprovalRetracted,
+            Some(event_step("経理承認", &approver2_id, None)),
+            Some(event_step("上長承認", &approver1_id, None)),
+        );
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        watcher_repo
+            .insert(
+                &WorkflowWatcher::new(
+                    event.instance_id().clone(),
+                    approver2_id.clone(),
+                    event.occurred_at(),
+                ),
+                &tenant_id,
+            )
+            .await
+            .unwrap();
+        let (sut, sender) = build_sut_with_watchers(user_repo, watcher_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        let yamada_count = sent.iter().filter(|m| m.to == "yamada@example.com").count();
+        assert_eq!(yamada_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_approvedで承認完了通知がウォッチャーにも送信される() {
+        // Arrange: 山田がウォッチャー。申請者本人と操作者（鈴木）もウォッチしている
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, approver2_id) = add_users(&user_repo, &tenant_id);
+        let event = WorkflowEvent::new(NewWorkflowEvent {
+            id:          WorkflowEventId::new(),
+            tenant_id:   tenant_id.clone(),
+            instance_id: WorkflowInstanceId::new(),
+            event_type:  WorkflowEventType::Approved,
+            actor_id:    Some(approver1_id.clone()),
+            payload:     WorkflowEventPayload {
+                display_number: DisplayNumber::new(100).unwrap(),
+                title: "テスト申請".to_string(),
+                initiated_by: applicant_id.clone(),
+                step: Some(event_step("承認", &approver1_id, None)),
+                activated_step: None,
+                proxy_submitter: None,
+                comment: None,
+            },
+            now:         chrono::Utc::now(),
+        });
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        for user_id in [&applicant_id, &approver1_id, &approver2_id] {
+            let watcher = WorkflowWatcher::new(
+                event.instance_id().clone(),
+                user_id.clone(),
+                event.occurred_at(),
+            );
+            watcher_repo.insert(&watcher, &tenant_id).await.unwrap();
+        }
+        let (sut, sender) = build_sut_with_watchers(user_repo, watcher_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert: 申請者への 1 通と、申請者・操作者を除いたウォッチャーへの 1 通
+        let sent = sender.sent_emails();
+        let mut recipients: Vec<_> = sent.iter().map(|m| m.to.as_str()).collect();
+        recipients.sort_unstable();
+        assert_eq!(recipients, vec!["tanaka@example.com", "yamada@example.com"]);
+        assert!(sent.iter().all(|m| m.subject.contains("承認完了")));
+    }
+
+    #[tokio::test]
+    async fn test_申請者が存在しない場合は通知をスキップして成功する() {
+        // Arrange: ユーザー情報を登録しない（空の FakeUserRepository）
+        let tenant_id = TenantId::new();
+        let event = build_event(
+            &tenant_id,
+            &UserId::new(),
+            WorkflowEventType::Submitted,
+            None,
+            Some(event_step("承認", &UserId::new(), None)),
+        );
+        let (sut, sender) = build_sut(FakeUserRepository::new());
+
+        // Act
+        let result = sut.handle(&event).await;
+
+        // Assert: 再配信しても結果が変わらないため成功扱い
+        assert!(result.is_ok());
+        assert!(sender.sent_emails().is_empty());
+    }
+
+    /// 秘書（佐藤）を登録し、田中の代理で申請したインスタンスのイベントを作る
+    fn build_proxy_event(
+        user_repo: &FakeUserRepository,
+        tenant_id: &TenantId,
+        applicant_id: &UserId,
+        event_type: WorkflowEventType,
+        activated_step: Option<WorkflowEventStep>,
+    ) -> (WorkflowEvent, UserId) {
+        let secretary_id = UserId::new();
+        user_repo.add_user(User::new(
+            secretary_id.clone(),
+            tenant_id.clone(),
+            DisplayNumber::new(4).unwrap(),
+            Email::new("sato@example.com").unwrap(),
+            UserName::new("佐藤花子").unwrap(),
+            chrono::Utc::now(),
+        ));
+        let event = WorkflowEvent::new(NewWorkflowEvent {
+            id: WorkflowEventId::new(),
+            tenant_id: tenant_id.clone(),
+            instance_id: WorkflowInstanceId::new(),
+            event_type,
+            actor_id: Some(secretary_id.clone()),
+            payload: WorkflowEventPayload {
+                display_number: DisplayNumber::new(100).unwrap(),
+                title: "テスト申請".to_string(),
+                initiated_by: applicant_id.clone(),
+                step: None,
+                activated_step,
+                proxy_submitter: Some(secretary_id.clone()),
+                comment: None,
+            },
+            now: chrono::Utc::now(),
+        });
+        (event, secretary_id)
+    }
+
+    #[tokio::test]
+    async fn test_代理申請のsubmittedで本人に代理申請通知と承認者に承認依頼が送信される() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, _) = add_users(&user_repo, &tenant_id);
+        let (event, _) = build_proxy_event(
+            &user_repo,
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::Submitted,
+            Some(event_step("承認", &approver1_id, None)),
+        );
+        let (sut, sender) = build_sut(user_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 2);
+        let approval_request = sent.iter().find(|m| m.to == "suzuki@example.com").unwrap();
+        assert!(approval_request.subject.contains("承認依頼"));
+        let proxy_submitted = sent.iter().find(|m| m.to == "tanaka@example.com").unwrap();
+        assert!(proxy_submitted.subject.contains("代理申請"));
+        assert!(proxy_submitted.text_body.contains("佐藤花子"));
+    }
+
+    #[tokio::test]
+    async fn test_代理申請のapprovedで本人と代理人に通知され代理人のウォッチは重複しない() {
+        // Arrange: 代理人（佐藤）は既定ウォッチャーでもある
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, _, _) = add_users(&user_repo, &tenant_id);
+        let (event, secretary_id) = build_proxy_event(
+            &user_repo,
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::Approved,
+            None,
+        );
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        watcher_repo
+            .insert(
+                &WorkflowWatcher::new(
+                    event.instance_id().clone(),
+                    secretary_id,
+                    event.occurred_at(),
+                ),
+                &tenant_id,
+            )
+            .await
+            .unwrap();
+        let (sut, sender) = build_sut_with_watchers(user_repo, watcher_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        let mut recipients: Vec<_> = sent.iter().map(|m| m.to.as_str()).collect();
+        recipients.sort_unstable();
+        assert_eq!(recipients, vec!["sato@example.com", "tanaka@example.com"]);
+        assert!(sent.iter().all(|m| m.subject.contains("承認完了")));
+    }
+
+    #[tokio::test]
+    async fn test_mentionedでメンションされたユーザーにだけ通知され投稿者は除かれる() {
+        // Arrange: 鈴木がコメントで山田と自分自身をメンションした
+        let tenant_id = TenantId::new();
+        let user_repo = FakeUserRepository::new();
+        let (applicant_id, approver1_id, approver2_id) = add_users(&user_repo, &tenant_id);
+        let base = build_event(
+            &tenant_id,
+            &applicant_id,
+            WorkflowEventType::Mentioned,
+            None,
+            None,
+        );
+        let payload = WorkflowEventPayload {
+            comment: Some(WorkflowEventComment {
+                comment_id: WorkflowCommentId::new(),
+                body:       "@USER-3 @USER-2 金額の確認をお願いします".to_string(),
+                mentioned:  vec![approver2_id.clone(), approver1_id.clone()],
+            }),
+            ..base.payload().clone()
+        };
+        let event = WorkflowEvent::new(NewWorkflowEvent {
+            id: base.id().clone(),
+            tenant_id: tenant_id.clone(),
+            instance_id: base.instance_id().clone(),
+            event_type: WorkflowEventType::Mentioned,
+            actor_id: Some(approver1_id.clone()),
+            payload,
+            now: base.occurred_at(),
+        });
+        let (sut, sender) = build_sut(user_repo);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let sent = sender.sent_emails();
+        assert_eq!(sent.len(), 1, "メンションされた山田にだけ送信されるべき");
+        assert_eq!(sent[0].to, "yamada@example.com");
+        assert!(sent[0].subject.contains("メンション"));
+        assert!(
+            sent[0]
+                .text_body
+                .contains("鈴木一郎 さんがコメントであなたをメンションしました")
+        );
+        assert!(sent[0].text_body.contains("金額の確認をお願いします"));
+    }
+}
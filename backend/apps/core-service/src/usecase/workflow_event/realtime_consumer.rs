@@ -0,0 +1,528 @@
+//! # リアルタイム通知コンシューマ
+//!
+//! ワークフローイベントをブラウザ向けのリアルタイムイベントに変換し、Redis Pub/Sub に発行する。
+//! BFF が購読し、宛先ユーザーの SSE 接続へ配信する。
+//!
+//! | イベント | リアルタイムイベント |
+//! |---------|------------------|
+//! | ステップがアクティブになったイベント | `task_assigned` → アクティブになったステップの承認者 |
+//! | `comment_posted` / `mentioned` 以外 | `workflow_status_changed` → ワークフローの関与者 |
+//! | `comment_posted` | `comment_posted` → ワークフローの関与者 |
+//!
+//! 関与者は申請者・代理人・各ステップの承認者・ウォッチャー。
+//! `step_reassigned` では担当者から外れた変更前の担当者にも状態変更を発行する。
+//! 別のタブや端末の表示も更新するため、操作者本人も宛先に含める。
+//! `mentioned` は `comment_posted` で配信済みのため発行しない。
+//!
+//! 発行の失敗は警告ログのみでエラーを返さない。再配信するとメール通知などが重複するうえ、
+//! ブラウザは再接続時に最新の状態を取得し直すため、取りこぼしても表示は回復する。
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use itertools::Itertools;
+use ringiflow_domain::{
+    user::UserId,
+    workflow::{WorkflowEvent, WorkflowEventType},
+};
+use ringiflow_infra::{
+    realtime::{RealtimeEvent, RealtimeMessage, RealtimePublisher},
+    repository::{WorkflowStepRepository, WorkflowWatcherRepository},
+};
+
+use super::WorkflowEventConsumer;
+use crate::error::CoreError;
+
+/// リアルタイム通知コンシューマ
+pub struct RealtimeEventConsumer {
+    step_repo:    Arc<dyn WorkflowStepRepository>,
+    watcher_repo: Arc<dyn WorkflowWatcherRepository>,
+    publisher:    Arc<dyn RealtimePublisher>,
+}
+
+impl RealtimeEventConsumer {
+    pub fn new(
+        step_repo: Arc<dyn WorkflowStepRepository>,
+        watcher_repo: Arc<dyn WorkflowWatcherRepository>,
+        publisher: Arc<dyn RealtimePublisher>,
+    ) -> Self {
+        Self {
+            step_repo,
+            watcher_repo,
+            publisher,
+        }
+    }
+
+    /// ワークフローの関与者（申請者・代理人・各ステップの承認者・ウォッチャー）を取得する
+    ///
+    /// ペイロードの操作対象ステップの担当者（担当者変更前の担当者）も含める。
+    async fn participants(&self, event: &WorkflowEvent) -> Result<Vec<UserId>, CoreError> {
+        let payload = event.payload();
+        let steps = self
+            .step_repo
+            .find_by_instance(event.instance_id(), event.tenant_id())
+            .await?;
+        let watchers = self
+            .watcher_repo
+            .find_by_instance(event.instance_id(), event.tenant_id())
+            .await?;
+
+        Ok(std::iter::once(&payload.initiated_by)
+            .chain(payload.proxy_submitter.as_ref())
+            .chain(payload.step.as_ref().and_then(|s| s.assigned_to.as_ref()))
+            .chain(steps.iter().filter_map(|s| s.assigned_to()))
+            .chain(watchers.iter().map(|w| w.user_id()))
+            .unique()
+            .cloned()
+            .collect())
+    }
+
+    /// メッセージを発行する（失敗は警告ログのみ）
+    async fn publish(
+        &self,
+        event: &WorkflowEvent,
+        recipients: Vec<UserId>,
+        realtime_event: RealtimeEvent,
+    ) {
+        if recipients.is_empty() {
+            return;
+        }
+        let message = RealtimeMessage {
+            tenant_id: event.tenant_id().clone(),
+            recipients,
+            event: realtime_event,
+            occurred_at: event.occurred_at(),
+        };
+        if let Err(e) = self.publisher.publish(&message).await {
+            tracing::warn!(
+                error = %e,
+                event_id = %event.id(),
+                realtime_event = message.event.name(),
+                "リアルタイム通知の発行に失敗"
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl WorkflowEventConsumer for RealtimeEventConsumer {
+    fn name(&self) -> &'static str {
+        "realtime"
+    }
+
+    async fn handle(&self, event: &WorkflowEvent) -> Result<(), CoreError> {
+        let payload = event.payload();
+        match event.event_type() {
+            WorkflowEventType::Mentioned => {}
+            WorkflowEventType::CommentPosted => {
+                if let (Some(comment), Some(posted_by)) =
+                    (payload.comment.as_ref(), event.actor_id())
+                {
+                    let recipients = self.participants(event).await?;
+                    self.publish(
+                        event,
+                        recipients,
+                        RealtimeEvent::CommentPosted {
+                            workflow_display_number: payload.display_number,
+                            workflow_title: payload.title.clone(),
+                            comment_id: comment.comment_id.clone(),
+                            posted_by: posted_by.clone(),
+                        },
+                    )
+                    .await;
+                }
+            }
+            event_type => {
+                if let Some(step) = payload.activated_step.as_ref()
+                    && let Some(assignee) = step.assigned_to.as_ref()
+                {
+                    self.publish(
+                        event,
+                        vec![assignee.clone()],
+                        RealtimeEvent::TaskAssigned {
+                            workflow_display_number: payload.display_number,
+                            workflow_title: payload.title.clone(),
+                            step_name: step.step_name.clone(),
+                        },
+                    )
+                    .await;
+                }
+
+                let recipients = self.participants(event).await?;
+                self.publish(
+                    event,
+                    recipients,
+                    RealtimeEvent::WorkflowStatusChanged {
+                        workflow_display_number: payload.display_number,
+                        workflow_title: payload.title.clone(),
+                        event_type,
+                    },
+                )
+                .await;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use pretty_assertions::assert_eq;
+    use ringiflow_domain::{
+        tenant::TenantId,
+        value_objects::DisplayNumber,
+        workflow::{
+            NewWorkflowEvent,
+            NewWorkflowStep,
+            WorkflowCommentId,
+            WorkflowEventComment,
+            WorkflowEventId,
+            WorkflowEventPayload,
+            WorkflowEventStep,
+            WorkflowInstanceId,
+            WorkflowStep,
+            WorkflowStepId,
+            WorkflowWatcher,
+        },
+    };
+    use ringiflow_infra::{
+        fake::{FakeRealtimePublisher, FakeWorkflowStepRepository, FakeWorkflowWatcherRepository},
+        repository::{WorkflowStepRepositoryTestExt, WorkflowWatcherRepository},
+    };
+
+    use super::*;
+
+    /// 承認者 1 名（ステップ 1 つ）とウォッチャー 1 名を登録する
+    ///
+    /// 戻り値: (approver_id, watcher_id)
+    async fn add_participants(
+        step_repo: &FakeWorkflowStepRepository,
+        watcher_repo: &FakeWorkflowWatcherRepository,
+        tenant_id: &TenantId,
+        instance_id: &WorkflowInstanceId,
+    ) -> (UserId, UserId) {
+        let approver_id = UserId::new();
+        let watcher_id = UserId::new();
+        let now = Utc::now();
+
+        let step = WorkflowStep::new(NewWorkflowStep {
+            id: WorkflowStepId::new(),
+            instance_id: instance_id.clone(),
+            display_number: DisplayNumber::new(1).unwrap(),
+            step_id: "approval".to_string(),
+            step_name: "上長承認".to_string(),
+            step_type: "approval".to_string(),
+            assigned_to: Some(approver_id.clone()),
+            now,
+        });
+        step_repo.insert_for_test(&step, tenant_id).await.unwrap();
+        watcher_repo
+            .insert(
+                &WorkflowWatcher::new(instance_id.clone(), watcher_id.clone(), now),
+                tenant_id,
+            )
+            .await
+            .unwrap();
+
+        (approver_id, watcher_id)
+    }
+
+    fn make_consumer(
+        step_repo: FakeWorkflowStepRepository,
+        watcher_repo: FakeWorkflowWatcherRepository,
+        publisher: &FakeRealtimePublisher,
+    ) -> RealtimeEventConsumer {
+        RealtimeEventConsumer::new(
+            Arc::new(step_repo),
+            Arc::new(watcher_repo),
+            Arc::new(publisher.clone()),
+        )
+    }
+
+    fn build_event(
+        tenant_id: &TenantId,
+        instance_id: &WorkflowInstanceId,
+        applicant_id: &UserId,
+        event_type: WorkflowEventType,
+        actor_id: &UserId,
+        activated_step: Option<WorkflowEventStep>,
+        comment: Option<WorkflowEventComment>,
+    ) -> WorkflowEvent {
+        WorkflowEvent::new(NewWorkflowEvent {
+            id: WorkflowEventId::new(),
+            tenant_id: tenant_id.clone(),
+            instance_id: instance_id.clone(),
+            event_type,
+            actor_id: Some(actor_id.clone()),
+            payload: WorkflowEventPayload {
+                display_number: DisplayNumber::new(42).unwrap(),
+                title: "経費精算申請".to_string(),
+                initiated_by: applicant_id.clone(),
+                proxy_submitter: None,
+                step: None,
+                activated_step,
+                comment,
+            },
+            now: Utc::now(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_submittedでタスク割り当てと状態変更を発行する() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let instance_id = WorkflowInstanceId::new();
+        let applicant_id = UserId::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        let (approver_id, watcher_id) =
+            add_participants(&step_repo, &watcher_repo, &tenant_id, &instance_id).await;
+        let publisher = FakeRealtimePublisher::new();
+        let sut = make_consumer(step_repo, watcher_repo, &publisher);
+        let activated_step = WorkflowEventStep {
+            step_id:     WorkflowStepId::new(),
+            step_name:   "上長承認".to_string(),
+            assigned_to: Some(approver_id.clone()),
+            comment:     None,
+        };
+        let event = build_event(
+            &tenant_id,
+            &instance_id,
+            &applicant_id,
+            WorkflowEventType::Submitted,
+            &applicant_id,
+            Some(activated_step),
+            None,
+        );
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let messages = publisher.messages();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].recipients, vec![approver_id.clone()]);
+        assert_eq!(
+            messages[0].event,
+            RealtimeEvent::TaskAssigned {
+                workflow_display_number: DisplayNumber::new(42).unwrap(),
+                workflow_title: "経費精算申請".to_string(),
+                step_name: "上長承認".to_string(),
+            }
+        );
+        assert_eq!(
+            messages[1].recipients,
+            vec![
+                applicant_id.clone(),
+                approver_id.clone(),
+                watcher_id.clone(),
+            ]
+        );
+        assert_eq!(
+            messages[1].event,
+            RealtimeEvent::WorkflowStatusChanged {
+                workflow_display_number: DisplayNumber::new(42).unwrap(),
+                workflow_title: "経費精算申請".to_string(),
+                event_type: WorkflowEventType::Submitted,
+            }
+        );
+        assert!(
+            messages
+                .iter()
+                .all(|m| m.tenant_id == tenant_id && m.occurred_at == event.occurred_at())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_approvedではタスク割り当てを発行しない() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let instance_id = WorkflowInstanceId::new();
+        let applicant_id = UserId::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        let (approver_id, _) =
+            add_participants(&step_repo, &watcher_repo, &tenant_id, &instance_id).await;
+        let publisher = FakeRealtimePublisher::new();
+        let sut = make_consumer(step_repo, watcher_repo, &publisher);
+        let event = build_event(
+            &tenant_id,
+            &instance_id,
+            &applicant_id,
+            WorkflowEventType::Approved,
+            &approver_id,
+            None,
+            None,
+        );
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let messages = publisher.messages();
+        assert_eq!(messages.len(), 1);
+        assert!(matches!(
+            messages[0].event,
+            RealtimeEvent::WorkflowStatusChanged {
+                event_type: WorkflowEventType::Approved,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_step_reassignedで変更前の担当者にも状態変更を発行する() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let instance_id = WorkflowInstanceId::new();
+        let applicant_id = UserId::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        let (approver_id, watcher_id) =
+            add_participants(&step_repo, &watcher_repo, &tenant_id, &instance_id).await;
+        let publisher = FakeRealtimePublisher::new();
+        let sut = make_consumer(step_repo, watcher_repo, &publisher);
+        let previous_assignee = UserId::new();
+        let event = WorkflowEvent::new(NewWorkflowEvent {
+            id:          WorkflowEventId::new(),
+            tenant_id:   tenant_id.clone(),
+            instance_id: instance_id.clone(),
+            event_type:  WorkflowEventType::StepReassigned,
+            actor_id:    Some(UserId::new()),
+            payload:     WorkflowEventPayload {
+                display_number: DisplayNumber::new(42).unwrap(),
+                title: "経費精算申請".to_string(),
+                initiated_by: applicant_id.clone(),
+                proxy_submitter: None,
+                step: Some(WorkflowEventStep {
+                    step_id:     WorkflowStepId::new(),
+                    step_name:   "上長承認".to_string(),
+                    assigned_to: Some(previous_assignee.clone()),
+                    comment:     None,
+                }),
+                activated_step: Some(WorkflowEventStep {
+                    step_id:     WorkflowStepId::new(),
+                    step_name:   "上長承認".to_string(),
+                    assigned_to: Some(approver_id.clone()),
+                    comment:     None,
+                }),
+                comment: None,
+            },
+            now:         Utc::now(),
+        });
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let messages = publisher.messages();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].recipients, vec![approver_id.clone()]);
+        assert!(matches!(
+            messages[0].event,
+            RealtimeEvent::TaskAssigned { .. }
+        ));
+        assert_eq!(
+            messages[1].recipients,
+            vec![
+                applicant_id.clone(),
+                previous_assignee,
+                approver_id.clone(),
+                watcher_id.clone(),
+            ]
+        );
+        assert!(matches!(
+            messages[1].event,
+            RealtimeEvent::WorkflowStatusChanged {
+                event_type: WorkflowEventType::StepReassigned,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_comment_postedで関与者にコメント投稿を発行する() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let instance_id = WorkflowInstanceId::new();
+        let applicant_id = UserId::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        let (approver_id, watcher_id) =
+            add_participants(&step_repo, &watcher_repo, &tenant_id, &instance_id).await;
+        let publisher = FakeRealtimePublisher::new();
+        let sut = make_consumer(step_repo, watcher_repo, &publisher);
+        let comment_id = WorkflowCommentId::new();
+        let event = build_event(
+            &tenant_id,
+            &instance_id,
+            &applicant_id,
+            WorkflowEventType::CommentPosted,
+            &approver_id,
+            None,
+            Some(WorkflowEventComment {
+                comment_id: comment_id.clone(),
+                body:       "確認しました".to_string(),
+                mentioned:  vec![],
+            }),
+        );
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let messages = publisher.messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            messages[0].recipients,
+            vec![
+                applicant_id.clone(),
+                approver_id.clone(),
+                watcher_id.clone(),
+            ]
+        );
+        assert_eq!(
+            messages[0].event,
+            RealtimeEvent::CommentPosted {
+                workflow_display_number: DisplayNumber::new(42).unwrap(),
+                workflow_title: "経費精算申請".to_string(),
+                comment_id,
+                posted_by: approver_id.clone(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mentionedは発行しない() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let instance_id = WorkflowInstanceId::new();
+        let applicant_id = UserId::new();
+        let step_repo = FakeWorkflowStepRepository::new();
+        let watcher_repo = FakeWorkflowWatcherRepository::new();
+        let (approver_id, _) =
+            add_participants(&step_repo, &watcher_repo, &tenant_id, &instance_id).await;
+        let publisher = FakeRealtimePublisher::new();
+        let sut = make_consumer(step_repo, watcher_repo, &publisher);
+        let event = build_event(
+            &tenant_id,
+            &instance_id,
+            &applicant_id,
+            WorkflowEventType::Mentioned,
+            &approver_id,
+            None,
+            Some(WorkflowEventComment {
+                comment_id: WorkflowCommentId::new(),
+                body:       "@USER-1 確認をお願いします".to_string(),
+                mentioned:  vec![applicant_id.clone()],
+            }),
+        );
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        assert!(publisher.messages().is_empty());
+    }
+}
@@ -0,0 +1,278 @@
+//! # Webhook コンシューマ
+//!
+//! ワークフローイベントを購読している Webhook ごとに配信を登録する。
+//! 実際の送信とリトライは `WebhookDeliveryWorker` が行う。
+//!
+//! ペイロードはイベント発生時点の情報から作成し、配信ログに保存する。
+//! リトライ時も同じ本文を送るため、受信側は `id`（イベント ID）で重複を判定できる。
+//!
+//! ```json
+//! {
+//!   "id": "<イベント ID>",
+//!   "type": "approved",
+//!   "occurred_at": "2026-03-13T09:00:00+00:00",
+//!   "tenant_id": "<テナント ID>",
+//!   "data": {
+//!     "workflow": { "id": "...", "display_id": "WF-42", "title": "...", "initiated_by": "..." },
+//!     "actor_id": "..." | null,
+//!     "step": { "id": "...", "name": "...", "assigned_to": "..." | null, "comment": "..." | null } | null,
+//!     "activated_step": { ... } | null
+//!   }
+//! }
+//! ```
+//!
+//! 同じイベントが再配信されても、`(subscription_id, event_id)` の一意制約により配信は重複しない。
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ringiflow_domain::{
+    value_objects::{DisplayId, display_prefix},
+    webhook::{NewWebhookDelivery, WebhookDelivery, WebhookDeliveryId},
+    workflow::{WorkflowEvent, WorkflowEventStep},
+};
+use ringiflow_infra::repository::{WebhookDeliveryRepository, WebhookSubscriptionRepository};
+use serde_json::{Value, json};
+
+use super::WorkflowEventConsumer;
+use crate::error::CoreError;
+
+/// Webhook コンシューマ
+pub struct WebhookEventConsumer {
+    subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+    delivery_repo:     Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl WebhookEventConsumer {
+    pub fn new(
+        subscription_repo: Arc<dyn WebhookSubscriptionRepository>,
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    ) -> Self {
+        Self {
+            subscription_repo,
+            delivery_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl WorkflowEventConsumer for WebhookEventConsumer {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    async fn handle(&self, event: &WorkflowEvent) -> Result<(), CoreError> {
+        let subscriptions = self
+            .subscription_repo
+            .find_active_by_event_type(event.tenant_id(), event.event_type())
+            .await?;
+        if subscriptions.is_empty() {
+            return Ok(());
+        }
+
+        let payload = event_payload(event);
+        let deliveries: Vec<WebhookDelivery> = subscriptions
+            .iter()
+            .map(|subscription| {
+                WebhookDelivery::new(NewWebhookDelivery {
+                    id: WebhookDeliveryId::new(),
+                    tenant_id: event.tenant_id().clone(),
+                    subscription_id: subscription.id().clone(),
+                    event_id: Some(event.id().clone()),
+                    event_type: event.event_type().to_string(),
+                    payload: payload.clone(),
+                    now: event.occurred_at(),
+                })
+            })
+            .collect();
+
+        self.delivery_repo.insert_all(&deliveries).await?;
+        Ok(())
+    }
+}
+
+/// イベントから Webhook のペイロードを作成する
+fn event_payload(event: &WorkflowEvent) -> Value {
+    let payload = event.payload();
+    json!({
+        "id": event.id().to_string(),
+        "type": event.event_type().to_string(),
+        "occurred_at": event.occurred_at().to_rfc3339(),
+        "tenant_id": event.tenant_id().to_string(),
+        "data": {
+            "workflow": {
+                "id": event.instance_id().to_string(),
+                "display_id": DisplayId::new(
+                    display_prefix::WORKFLOW_INSTANCE,
+                    payload.display_number,
+                )
+                .to_string(),
+                "title": payload.title,
+                "initiated_by": payload.initiated_by.to_string(),
+            },
+            "actor_id": event.actor_id().map(|id| id.to_string()),
+            "step": payload.step.as_ref().map(step_payload),
+            "activated_step": payload.activated_step.as_ref().map(step_payload),
+        },
+    })
+}
+
+fn step_payload(step: &WorkflowEventStep) -> Value {
+    json!({
+        "id": step.step_id.to_string(),
+        "name": step.step_name,
+        "assigned_to": step.assigned_to.as_ref().map(|id| id.to_string()),
+        "comment": step.comment,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use ringiflow_domain::{
+        tenant::TenantId,
+        user::UserId,
+        value_objects::{DisplayNumber, Version},
+        webhook::{
+            NewWebhookSubscription,
+            WebhookName,
+            WebhookSecret,
+            WebhookSubscription,
+            WebhookSubscriptionId,
+            WebhookUrl,
+        },
+        workflow::{
+            NewWorkflowEvent,
+            NewWorkflowInstance,
+            WorkflowDefinitionId,
+            WorkflowEventId,
+            WorkflowEventPayload,
+            WorkflowEventType,
+            WorkflowInstance,
+            WorkflowInstanceId,
+        },
+    };
+    use ringiflow_infra::fake::{FakeWebhookDeliveryRepository, FakeWebhookSubscriptionRepository};
+
+    use super::*;
+
+    fn approved_event(tenant_id: &TenantId, now: DateTime<Utc>) -> WorkflowEvent {
+        let instance = WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: WorkflowDefinitionId::new(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(42).unwrap(),
+            title: "経費精算".to_string(),
+            form_data: serde_json::json!({}),
+            initiated_by: UserId::new(),
+            now,
+        });
+        WorkflowEvent::new(NewWorkflowEvent {
+            id: WorkflowEventId::new(),
+            tenant_id: tenant_id.clone(),
+            instance_id: instance.id().clone(),
+            event_type: WorkflowEventType::Approved,
+            actor_id: Some(UserId::new()),
+            payload: WorkflowEventPayload::new(&instance, None, None),
+            now,
+        })
+    }
+
+    async fn subscribe(
+        repo: &FakeWebhookSubscriptionRepository,
+        tenant_id: &TenantId,
+        event_types: Vec<WorkflowEventType>,
+        now: DateTime<Utc>,
+    ) -> WebhookSubscription {
+        let subscription = WebhookSubscription::new(NewWebhookSubscription {
+            id: WebhookSubscriptionId::new(),
+            tenant_id: tenant_id.clone(),
+            name: WebhookName::new("ERP 連携").unwrap(),
+            url: WebhookUrl::new("https://erp.example.com/hooks").unwrap(),
+            event_types,
+            secret: WebhookSecret::new("0123456789abcdef").unwrap(),
+            created_by: UserId::new(),
+            now,
+        })
+        .unwrap();
+        repo.insert(&subscription).await.unwrap();
+        subscription
+    }
+
+    #[tokio::test]
+    async fn test_購読しているwebhookにのみ配信が登録される() {
+        // Arrange
+        let now = Utc::now();
+        let tenant_id = TenantId::new();
+        let subscription_repo = FakeWebhookSubscriptionRepository::new();
+        let delivery_repo = FakeWebhookDeliveryRepository::new();
+        let subscribed = subscribe(
+            &subscription_repo,
+            &tenant_id,
+            vec![WorkflowEventType::Approved],
+            now,
+        )
+        .await;
+        subscribe(
+            &subscription_repo,
+            &tenant_id,
+            vec![WorkflowEventType::Rejected],
+            now,
+        )
+        .await;
+        subscribe(
+            &subscription_repo,
+            &TenantId::new(),
+            vec![WorkflowEventType::Approved],
+            now,
+        )
+        .await;
+        let sut =
+            WebhookEventConsumer::new(Arc::new(subscription_repo), Arc::new(delivery_repo.clone()));
+        let event = approved_event(&tenant_id, now);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        let deliveries = delivery_repo.deliveries();
+        assert_eq!(deliveries.len(), 1);
+        let delivery = &deliveries[0];
+        assert_eq!(delivery.subscription_id(), subscribed.id());
+        assert_eq!(delivery.event_id(), Some(event.id()));
+        assert_eq!(delivery.event_type(), "approved");
+        let payload = delivery.payload();
+        assert_eq!(payload["id"], event.id().to_string());
+        assert_eq!(payload["type"], "approved");
+        assert_eq!(payload["data"]["workflow"]["display_id"], "WF-42");
+        assert_eq!(payload["data"]["workflow"]["title"], "経費精算");
+        assert!(payload["data"]["step"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_同じイベントを再処理しても配信は重複しない() {
+        // Arrange
+        let now = Utc::now();
+        let tenant_id = TenantId::new();
+        let subscription_repo = FakeWebhookSubscriptionRepository::new();
+        let delivery_repo = FakeWebhookDeliveryRepository::new();
+        subscribe(
+            &subscription_repo,
+            &tenant_id,
+            vec![WorkflowEventType::Approved],
+            now,
+        )
+        .await;
+        let sut =
+            WebhookEventConsumer::new(Arc::new(subscription_repo), Arc::new(delivery_repo.clone()));
+        let event = approved_event(&tenant_id, now);
+
+        // Act
+        sut.handle(&event).await.unwrap();
+        sut.handle(&event).await.unwrap();
+
+        // Assert
+        assert_eq!(delivery_repo.deliveries().len(), 1);
+    }
+}
@@ -0,0 +1,518 @@
+//! # ワークフロースケジュールユースケース
+//!
+//! 定期的に提出する申請（月次の契約更新、四半期報告など）の繰り返し設定を、
+//! 所有者本人が作成・管理する。
+//!
+//! 各実行日時でのワークフロー作成・自動申請と所有者への通知は
+//! [`worker::WorkflowScheduleWorker`] が担当する。
+//!
+//! ## モジュール構成
+//!
+//! - `worker`: 実行日時に達したスケジュールのポーリングとワークフロー作成
+
+pub mod worker;
+
+use std::sync::Arc;
+
+use ringiflow_domain::{
+    DomainError,
+    clock::Clock,
+    message::Message,
+    tenant::TenantId,
+    time_zone::TimeZone,
+    user::UserId,
+    workflow::{
+        CronExpression,
+        MonthlyRecurrence,
+        NewWorkflowSchedule,
+        Recurrence,
+        ScheduledApprover,
+        WorkflowDefinitionId,
+        WorkflowDefinitionStatus,
+        WorkflowSchedule,
+        WorkflowScheduleChanges,
+        WorkflowScheduleId,
+        WorkflowScheduleName,
+    },
+};
+use ringiflow_infra::repository::{WorkflowDefinitionRepository, WorkflowScheduleRepository};
+use serde_json::Value as JsonValue;
+pub use worker::WorkflowScheduleWorker;
+
+use crate::{error::CoreError, usecase::helpers::FindResultExt};
+
+/// 繰り返し設定の入力
+pub enum RecurrenceInput {
+    /// 毎月 N 日（存在しない日は月末）の指定時刻
+    Monthly {
+        day:    u32,
+        hour:   u32,
+        minute: u32,
+    },
+    /// cron 形式（分 時 日 月 曜日）
+    Cron { expression: String },
+}
+
+/// スケジュール作成の入力
+pub struct CreateWorkflowScheduleInput {
+    pub tenant_id:     TenantId,
+    pub owner_id:      UserId,
+    pub definition_id: WorkflowDefinitionId,
+    pub name:          String,
+    pub title:         String,
+    pub form_data:     JsonValue,
+    pub recurrence:    RecurrenceInput,
+    /// 実行時刻を解釈するタイムゾーン（IANA タイムゾーン名、省略時は `Asia/Tokyo`）
+    pub time_zone:     Option<String>,
+    pub auto_submit:   bool,
+    pub approvers:     Vec<ScheduledApprover>,
+}
+
+/// スケジュール更新の入力（`None` の項目は変更しない）
+pub struct UpdateWorkflowScheduleInput {
+    pub schedule_id: WorkflowScheduleId,
+    pub tenant_id:   TenantId,
+    pub owner_id:    UserId,
+    pub name:        Option<String>,
+    pub title:       Option<String>,
+    pub form_data:   Option<JsonValue>,
+    pub recurrence:  Option<RecurrenceInput>,
+    pub time_zone:   Option<String>,
+    pub auto_submit: Option<bool>,
+    pub approvers:   Option<Vec<ScheduledApprover>>,
+    pub is_active:   Option<bool>,
+}
+
+/// ワークフロースケジュールユースケース
+pub struct WorkflowScheduleUseCaseImpl {
+    schedule_repo:   Arc<dyn WorkflowScheduleRepository>,
+    definition_repo: Arc<dyn WorkflowDefinitionRepository>,
+    clock:           Arc<dyn Clock>,
+}
+
+impl WorkflowScheduleUseCaseImpl {
+    pub fn new(
+        schedule_repo: Arc<dyn WorkflowScheduleRepository>,
+        definition_repo: Arc<dyn WorkflowDefinitionRepository>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            schedule_repo,
+            definition_repo,
+            clock,
+        }
+    }
+
+    /// 自分のスケジュール一覧を取得する（作成日時順）
+    pub async fn list_schedules(
+        &self,
+        tenant_id: &TenantId,
+        owner_id: &UserId,
+    ) -> Result<Vec<WorkflowSchedule>, CoreError> {
+        let schedules = self
+            .schedule_repo
+            .find_by_owner(tenant_id, owner_id)
+            .await?;
+        Ok(schedules)
+    }
+
+    /// スケジュールを作成する（有効状態で作成される）
+    ///
+    /// 公開済みの定義のみ指定できる。自動申請する場合は、
+    /// 定義の承認ステップごとに承認者を指定する必要がある。
+    pub async fn create_schedule(
+        &self,
+        input: CreateWorkflowScheduleInput,
+    ) -> Result<WorkflowSchedule, CoreError> {
+        self.validate_definition(
+            &input.definition_id,
+            &input.tenant_id,
+            input.auto_submit,
+            &input.approvers,
+        )
+        .await?;
+
+        let schedule = WorkflowSchedule::new(NewWorkflowSchedule {
+            id: WorkflowScheduleId::new(),
+            tenant_id: input.tenant_id,
+            owner_id: input.owner_id,
+            definition_id: input.definition_id,
+            name: WorkflowScheduleName::new(input.name).map_err(bad_request)?,
+            title: input.title,
+            form_data: input.form_data,
+            recurrence: parse_recurrence(input.recurrence)?,
+            time_zone: input
+                .time_zone
+                .as_deref()
+                .map(parse_time_zone)
+                .transpose()?
+                .unwrap_or_default(),
+            auto_submit: input.auto_submit,
+            approvers: input.approvers,
+            now: self.clock.now(),
+        })
+        .map_err(bad_request)?;
+
+        self.schedule_repo.insert(&schedule).await?;
+        Ok(schedule)
+    }
+
+    /// スケジュールを更新する
+    ///
+    /// 有効なスケジュールの次回実行日時は、更新時点から計算し直される。
+    /// 定義がアーカイブされた後でも無効化はできるよう、定義の検証は有効な場合のみ行う。
+    pub async fn update_schedule(
+        &self,
+        input: UpdateWorkflowScheduleInput,
+    ) -> Result<WorkflowSchedule, CoreError> {
+        let schedule = self
+            .find_own_schedule(&input.schedule_id, &input.tenant_id, &input.owner_id)
+            .await?;
+
+        let changes = WorkflowScheduleChanges {
+            name:        input
+                .name
+                .map(WorkflowScheduleName::new)
+                .transpose()
+                .map_err(bad_request)?,
+            title:       input.title,
+            form_data:   input.form_data,
+            recurrence:  input.recurrence.map(parse_recurrence).transpose()?,
+            time_zone:   input
+                .time_zone
+                .as_deref()
+                .map(parse_time_zone)
+                .transpose()?,
+            auto_submit: input.auto_submit,
+            approvers:   input.approvers,
+            is_active:   input.is_active,
+        };
+        let updated = schedule
+            .updated(changes, self.clock.now())
+            .map_err(bad_request)?;
+        if updated.is_active() {
+            self.validate_definition(
+                updated.definition_id(),
+                updated.tenant_id(),
+                updated.auto_submit(),
+                updated.approvers(),
+            )
+            .await?;
+        }
+
+        self.schedule_repo.update(&updated).await?;
+        Ok(updated)
+    }
+
+    /// スケジュールを削除する（作成済みのワークフローは削除しない）
+    pub async fn delete_schedule(
+        &self,
+        id: &WorkflowScheduleId,
+        tenant_id: &TenantId,
+        owner_id: &UserId,
+    ) -> Result<(), CoreError> {
+        self.find_own_schedule(id, tenant_id, owner_id).await?;
+        self.schedule_repo.delete(id, tenant_id).await?;
+        Ok(())
+    }
+
+    /// 自分のスケジュールを取得する
+    ///
+    /// 他のユーザーのスケジュールは存在を明かさないため NotFound とする。
+    async fn find_own_schedule(
+        &self,
+        id: &WorkflowScheduleId,
+        tenant_id: &TenantId,
+        owner_id: &UserId,
+    ) -> Result<WorkflowSchedule, CoreError> {
+        let schedule = self
+            .schedule_repo
+            .find_by_id(id, tenant_id)
+            .await
+            .or_not_found("workflow-schedule")?;
+        if schedule.owner_id() != owner_id {
+            return Err(CoreError::NotFound(Message::new(
+                "workflow-schedule-not-found",
+            )));
+        }
+        Ok(schedule)
+    }
+
+    /// 定義が公開済みで、自動申請の承認者が定義の承認ステップと一致するか検証する
+    async fn validate_definition(
+        &self,
+        definition_id: &WorkflowDefinitionId,
+        tenant_id: &TenantId,
+        auto_submit: bool,
+        approvers: &[ScheduledApprover],
+    ) -> Result<(), CoreError> {
+        let definition = self
+            .definition_repo
+            .find_by_id(definition_id, tenant_id)
+            .await
+            .or_not_found("workflow-definition")?;
+        if definition.status() != WorkflowDefinitionStatus::Published {
+            return Err(CoreError::BadRequest(Message::new(
+                "definition-not-published",
+            )));
+        }
+        if !auto_submit {
+            return Ok(());
+        }
+
+        let step_ids: Vec<String> = definition
+            .extract_approval_steps()
+            .map_err(bad_request)?
+            .into_iter()
+            .map(|step| step.id)
+            .collect();
+        let approver_step_ids: Vec<&str> = approvers.iter().map(|a| a.step_id.as_str()).collect();
+        if approver_step_ids != step_ids {
+            return Err(CoreError::BadRequest(
+                Message::new("schedule-approver-steps-mismatch")
+                    .with("step_ids", step_ids.join(", ")),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 繰り返し設定の入力をドメインの値に変換する
+fn parse_recurrence(input: RecurrenceInput) -> Result<Recurrence, CoreError> {
+    let recurrence = match input {
+        RecurrenceInput::Monthly { day, hour, minute } => {
+            Recurrence::Monthly(MonthlyRecurrence::new(day, hour, minute).map_err(bad_request)?)
+        }
+        RecurrenceInput::Cron { expression } => {
+            Recurrence::Cron(CronExpression::parse(&expression).map_err(bad_request)?)
+        }
+    };
+    Ok(recurrence)
+}
+
+/// タイムゾーンの入力（IANA タイムゾーン名）をドメインの値に変換する
+fn parse_time_zone(input: &str) -> Result<TimeZone, CoreError> {
+    input.parse().map_err(bad_request)
+}
+
+fn bad_request(e: DomainError) -> CoreError {
+    CoreError::BadRequest(e.into_message())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone as _, Utc};
+    use ringiflow_domain::{
+        clock::FixedClock,
+        value_objects::WorkflowName,
+        workflow::{NewWorkflowDefinition, WorkflowDefinition},
+    };
+    use ringiflow_infra::fake::{FakeWorkflowDefinitionRepository, FakeWorkflowScheduleRepository};
+
+    use super::*;
+
+    fn fixed_now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 19, 10, 0, 0).unwrap()
+    }
+
+    struct Sut {
+        usecase:    WorkflowScheduleUseCaseImpl,
+        definition: WorkflowDefinition,
+        owner_id:   UserId,
+    }
+
+    fn create_sut() -> Sut {
+        let tenant_id = TenantId::new();
+        let owner_id = UserId::new();
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id: WorkflowDefinitionId::new(),
+            tenant_id,
+            name: WorkflowName::new("契約更新申請").unwrap(),
+            description: None,
+            definition: serde_json::json!({
+                "steps": [
+                    {"id": "start", "type": "start", "name": "開始"},
+                    {"id": "approval", "type": "approval", "name": "承認"},
+                    {"id": "end_approved", "type": "end", "name": "承認完了", "status": "approved"},
+                    {"id": "end_rejected", "type": "end", "name": "却下", "status": "rejected"}
+                ]
+            }),
+            created_by: owner_id.clone(),
+            now: fixed_now(),
+        })
+        .published(fixed_now())
+        .unwrap();
+        let definition_repo = FakeWorkflowDefinitionRepository::new();
+        definition_repo.add_definition(definition.clone());
+        let usecase = WorkflowScheduleUseCaseImpl::new(
+            Arc::new(FakeWorkflowScheduleRepository::new()),
+            Arc::new(definition_repo),
+            Arc::new(FixedClock::new(fixed_now())),
+        );
+        Sut {
+            usecase,
+            definition,
+            owner_id,
+        }
+    }
+
+    fn create_input(sut: &Sut, approvers: Vec<ScheduledApprover>) -> CreateWorkflowScheduleInput {
+        CreateWorkflowScheduleInput {
+            tenant_id: sut.definition.tenant_id().clone(),
+            owner_id: sut.owner_id.clone(),
+            definition_id: sut.definition.id().clone(),
+            name: "月次契約更新".to_string(),
+            title: "契約更新".to_string(),
+            form_data: serde_json::json!({"amount": 1000}),
+            recurrence: RecurrenceInput::Monthly {
+                day:    31,
+                hour:   9,
+                minute: 0,
+            },
+            time_zone: Some("UTC".to_string()),
+            auto_submit: true,
+            approvers,
+        }
+    }
+
+    fn approver(step_id: &str) -> ScheduledApprover {
+        ScheduledApprover {
+            step_id:     step_id.to_string(),
+            assigned_to: UserId::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_schedule_次回実行日時を計算して保存する() {
+        let sut = create_sut();
+
+        let created = sut
+            .usecase
+            .create_schedule(create_input(&sut, vec![approver("approval")]))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            created.next_run_at(),
+            Some(Utc.with_ymd_and_hms(2026, 3, 31, 9, 0, 0).unwrap())
+        );
+        let listed = sut
+            .usecase
+            .list_schedules(sut.definition.tenant_id(), &sut.owner_id)
+            .await
+            .unwrap();
+        assert_eq!(listed, vec![created]);
+    }
+
+    #[tokio::test]
+    async fn test_create_schedule_承認者が定義のステップと一致しない場合はbad_request() {
+        let sut = create_sut();
+
+        let result = sut
+            .usecase
+            .create_schedule(create_input(&sut, vec![approver("manager")]))
+            .await;
+
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_schedule_不正なcron式はbad_request() {
+        let sut = create_sut();
+        let mut input = create_input(&sut, vec![approver("approval")]);
+        input.recurrence = RecurrenceInput::Cron {
+            expression: "0 9 * *".to_string(),
+        };
+
+        let result = sut.usecase.create_schedule(input).await;
+
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_schedule_タイムゾーンの現地時刻で次回実行日時を計算する() {
+        let sut = create_sut();
+        let mut input = create_input(&sut, vec![approver("approval")]);
+        input.time_zone = None;
+
+        let created = sut.usecase.create_schedule(input).await.unwrap();
+
+        // 省略時は Asia/Tokyo の 3 月 31 日 9:00
+        assert_eq!(created.time_zone(), TimeZone::default());
+        assert_eq!(
+            created.next_run_at(),
+            Some(Utc.with_ymd_and_hms(2026, 3, 31, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_schedule_不正なタイムゾーンはbad_request() {
+        let sut = create_sut();
+        let mut input = create_input(&sut, vec![approver("approval")]);
+        input.time_zone = Some("Asia/Nowhere".to_string());
+
+        let result = sut.usecase.create_schedule(input).await;
+
+        assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_schedule_他のユーザーのスケジュールはnot_found() {
+        let sut = create_sut();
+        let created = sut
+            .usecase
+            .create_schedule(create_input(&sut, vec![approver("approval")]))
+            .await
+            .unwrap();
+
+        let result = sut
+            .usecase
+            .update_schedule(UpdateWorkflowScheduleInput {
+                schedule_id: created.id().clone(),
+                tenant_id:   created.tenant_id().clone(),
+                owner_id:    UserId::new(),
+                name:        None,
+                title:       None,
+                form_data:   None,
+                recurrence:  None,
+                time_zone:   None,
+                auto_submit: None,
+                approvers:   None,
+                is_active:   Some(false),
+            })
+            .await;
+
+        assert!(matches!(result, Err(CoreError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_schedule_無効化すると次回実行日時がなくなる() {
+        let sut = create_sut();
+        let created = sut
+            .usecase
+            .create_schedule(create_input(&sut, vec![approver("approval")]))
+            .await
+            .unwrap();
+
+        let updated = sut
+            .usecase
+            .update_schedule(UpdateWorkflowScheduleInput {
+                schedule_id: created.id().clone(),
+                tenant_id:   created.tenant_id().clone(),
+                owner_id:    sut.owner_id.clone(),
+                name:        None,
+                title:       None,
+                form_data:   None,
+                recurrence:  None,
+                time_zone:   None,
+                auto_submit: None,
+                approvers:   None,
+                is_active:   Some(false),
+            })
+            .await
+            .unwrap();
+
+        assert!(!updated.is_active());
+        assert_eq!(updated.next_run_at(), None);
+    }
+}
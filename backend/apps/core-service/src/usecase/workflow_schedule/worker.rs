@@ -0,0 +1,528 @@
+//! # ワークフロースケジュールワーカー
+//!
+//! 実行日時に達したスケジュールをポーリングし、ワークフローを作成する。
+//!
+//! ## 処理フロー
+//!
+//! 1. トランザクションを開始し、実行日時に達したスケジュールを `FOR UPDATE SKIP LOCKED` で取得
+//! 2. 次回実行日時に進めて記録し、コミット
+//! 3. スケジュールごとに所有者を申請者として下書きを作成し、自動申請の場合は申請する
+//! 4. 所有者に作成結果を通知する
+//!
+//! 実行回の消化（次回実行日時への更新）をワークフロー作成より先にコミットするため、
+//! 同じ回のワークフローが二重に作成されることはない（at-most-once）。
+//! コミット後に作成が失敗した、またはプロセスが停止した回は再実行しない。
+//!
+//! 自動申請に失敗した場合は下書きを残し、失敗理由を通知に含める。
+//! 下書きの作成に失敗した場合は警告ログのみ出力する。
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use ringiflow_domain::{
+    clock::Clock,
+    message::Message,
+    notification::WorkflowNotification,
+    value_objects::{DisplayId, display_prefix},
+    workflow::{WorkflowInstance, WorkflowSchedule},
+};
+use ringiflow_infra::{
+    TransactionManager,
+    repository::{UserRepository, WorkflowScheduleRepository},
+};
+use ringiflow_shared::{event_log::event, log_business_event};
+
+use crate::{
+    error::CoreError,
+    usecase::{
+        notification::NotificationService,
+        polling::PollingWorker,
+        workflow::{CreateWorkflowInput, StepApprover, SubmitWorkflowInput, WorkflowUseCaseImpl},
+    },
+};
+
+/// ワークフロースケジュールワーカー
+pub struct WorkflowScheduleWorker {
+    schedule_repo: Arc<dyn WorkflowScheduleRepository>,
+    workflow_usecase: Arc<WorkflowUseCaseImpl>,
+    user_repo: Arc<dyn UserRepository>,
+    notification_service: Arc<NotificationService>,
+    tx_manager: Arc<dyn TransactionManager>,
+    clock: Arc<dyn Clock>,
+    batch_size: i64,
+}
+
+impl WorkflowScheduleWorker {
+    pub fn new(
+        schedule_repo: Arc<dyn WorkflowScheduleRepository>,
+        workflow_usecase: Arc<WorkflowUseCaseImpl>,
+        user_repo: Arc<dyn UserRepository>,
+        notification_service: Arc<NotificationService>,
+        tx_manager: Arc<dyn TransactionManager>,
+        clock: Arc<dyn Clock>,
+        batch_size: i64,
+    ) -> Self {
+        Self {
+            schedule_repo,
+            workflow_usecase,
+            user_repo,
+            notification_service,
+            tx_manager,
+            clock,
+            batch_size,
+        }
+    }
+
+    /// 実行日時に達したスケジュールを 1 バッチ分実行する
+    ///
+    /// 処理したスケジュール数（作成の成否を問わない）を返す。
+    pub async fn run_due(&self) -> Result<usize, CoreError> {
+        let now = self.clock.now();
+        let mut tx = self
+            .tx_manager
+            .begin()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクション開始に失敗: {}", e)))?;
+
+        let due = self
+            .schedule_repo
+            .lock_due(&mut tx, now, self.batch_size)
+            .await
+            .map_err(|e| {
+                CoreError::Internal(format!("実行対象のスケジュールの取得に失敗: {}", e))
+            })?;
+        let count = due.len();
+
+        for schedule in &due {
+            self.schedule_repo
+                .record_run(&mut tx, &schedule.clone().advanced(now))
+                .await
+                .map_err(|e| CoreError::Internal(format!("スケジュールの実行記録に失敗: {}", e)))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| CoreError::Internal(format!("トランザクションコミットに失敗: {}", e)))?;
+
+        for schedule in &due {
+            self.run_schedule(schedule).await;
+        }
+
+        Ok(count)
+    }
+
+    /// 1 件のスケジュールからワークフローを作成し、所有者に通知する
+    async fn run_schedule(&self, schedule: &WorkflowSchedule) {
+        let input = CreateWorkflowInput {
+            definition_id: schedule.definition_id().clone(),
+            title:         schedule.title().to_string(),
+            form_data:     schedule.form_data().clone(),
+            on_behalf_of:  None,
+        };
+        let instance = match self
+            .workflow_usecase
+            .create_workflow(
+                input,
+                schedule.tenant_id().clone(),
+                schedule.owner_id().clone(),
+            )
+            .await
+        {
+            Ok(instance) => instance,
+            Err(e) => {
+                tracing::warn!(
+                    error = %e,
+                    schedule_id = %schedule.id(),
+                    tenant_id = %schedule.tenant_id(),
+                    "スケジュールからのワークフロー作成に失敗"
+                );
+                return;
+            }
+        };
+
+        let submit_error = if schedule.auto_submit() {
+            self.submit(schedule, &instance).await.err()
+        } else {
+            None
+        };
+        let submitted = schedule.auto_submit() && submit_error.is_none();
+
+        log_business_event!(
+            event.category = event::category::WORKFLOW,
+            event.action = event::action::WORKFLOW_SCHEDULE_RUN,
+            event.entity_type = event::entity_type::WORKFLOW_SCHEDULE,
+            event.entity_id = %schedule.id(),
+            event.actor_id = %schedule.owner_id(),
+            event.tenant_id = %schedule.tenant_id(),
+            event.result = if submit_error.is_none() {
+                event::result::SUCCESS
+            } else {
+                event::result::FAILURE
+            },
+            workflow_instance_id = %instance.id(),
+            submitted,
+            "スケジュールによるワークフロー作成"
+        );
+
+        self.notify_owner(schedule, &instance, submitted, submit_error)
+            .await;
+    }
+
+    /// 作成した下書きを申請する
+    ///
+    /// 失敗理由は通知の受信者のロケールで展開できるようメッセージのまま返す。
+    async fn submit(
+        &self,
+        schedule: &WorkflowSchedule,
+        instance: &WorkflowInstance,
+    ) -> Result<(), Message> {
+        let input = SubmitWorkflowInput {
+            approvers: schedule
+                .approvers()
+                .iter()
+                .map(|approver| StepApprover {
+                    step_id:     approver.step_id.clone(),
+                    assigned_to: approver.assigned_to.clone(),
+                })
+                .collect(),
+        };
+        self.workflow_usecase
+            .submit_workflow(input, instance.id().clone(), schedule.tenant_id().clone())
+            .await
+            .map(|_| ())
+            .map_err(|e| {
+                tracing::warn!(
+                    error = %e,
+                    schedule_id = %schedule.id(),
+                    workflow_instance_id = %instance.id(),
+                    "スケジュールからのワークフロー自動申請に失敗"
+                );
+                match e {
+                    CoreError::NotFound(msg)
+                    | CoreError::BadRequest(msg)
+                    | CoreError::Forbidden(msg)
+                    | CoreError::Conflict(msg) => msg,
+                    CoreError::Database(_) | CoreError::Internal(_) => {
+                        Message::new("internal-error")
+                    }
+                }
+            })
+    }
+
+    /// 所有者に作成結果を通知する（所有者が取得できない場合は通知しない）
+    async fn notify_owner(
+        &self,
+        schedule: &WorkflowSchedule,
+        instance: &WorkflowInstance,
+        submitted: bool,
+        submit_error: Option<Message>,
+    ) {
+        let owner = match self.user_repo.find_by_id(schedule.owner_id()).await {
+            Ok(Some(owner)) => owner,
+            Ok(None) => {
+                tracing::warn!(user_id = %schedule.owner_id(), "通知用の所有者情報が見つかりません");
+                return;
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, user_id = %schedule.owner_id(), "通知用の所有者情報の取得に失敗");
+                return;
+            }
+        };
+
+        let notification = WorkflowNotification::ScheduledWorkflowCreated {
+            workflow_title: instance.title().to_string(),
+            workflow_display_id: DisplayId::new(
+                display_prefix::WORKFLOW_INSTANCE,
+                instance.display_number(),
+            )
+            .to_string(),
+            schedule_name: schedule.name().as_str().to_string(),
+            submitted,
+            submit_error,
+            recipient_email: owner.email().as_str().to_string(),
+            recipient_user_id: owner.id().clone(),
+        };
+        self.notification_service
+            .notify(notification, schedule.tenant_id(), instance.id())
+            .await;
+    }
+}
+
+#[async_trait]
+impl PollingWorker for WorkflowScheduleWorker {
+    fn name(&self) -> &'static str {
+        "ワークフロースケジュールワーカー"
+    }
+
+    fn batch_size(&self) -> i64 {
+        self.batch_size
+    }
+
+    async fn step(&self) -> Result<usize, CoreError> {
+        self.run_due().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+    use ringiflow_domain::{
+        clock::FixedClock,
+        user::{Email, User, UserId},
+        value_objects::{DisplayNumber, UserName, WorkflowName},
+        workflow::{
+            CronExpression,
+            NewWorkflowDefinition,
+            NewWorkflowSchedule,
+            Recurrence,
+            ScheduledApprover,
+            WorkflowDefinition,
+            WorkflowDefinitionId,
+            WorkflowInstanceStatus,
+            WorkflowScheduleId,
+            WorkflowScheduleName,
+        },
+    };
+    use ringiflow_infra::{
+        fake::{
+            FakeInboxNotificationRepository,
+            FakeNotificationDigestRepository,
+            FakeNotificationLogRepository,
+            FakeNotificationPreferenceRepository,
+            FakeNotificationSender,
+            FakeNotificationTemplateRepository,
+            FakeTransactionManager,
+            FakeUserRepository,
+            FakeWorkflowScheduleRepository,
+        },
+        repository::WorkflowInstanceRepository,
+    };
+
+    use super::*;
+    use crate::{
+        test_utils::{WorkflowTestBuilder, WorkflowTestSetup},
+        usecase::notification::TemplateRenderer,
+    };
+
+    fn created_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 19, 10, 0, 0).unwrap()
+    }
+
+    /// 毎日 9:00（UTC）に実行するスケジュールを登録する
+    ///
+    /// 所有者はビルダーのユーザー（田中）で、対象の定義とともに登録する。
+    async fn add_daily_schedule(
+        builder: &WorkflowTestBuilder,
+        setup: &WorkflowTestSetup,
+        schedule_repo: &FakeWorkflowScheduleRepository,
+        auto_submit: bool,
+        approver_step_id: &str,
+    ) {
+        let tenant_id = builder.tenant_id();
+        let owner_id = builder.user_id();
+        let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+            id:          WorkflowDefinitionId::new(),
+            tenant_id:   tenant_id.clone(),
+            name:        WorkflowName::new("契約更新申請").unwrap(),
+            description: None,
+            definition:  serde_json::json!({
+                "steps": [
+                    {"id": "start", "type": "start", "name": "開始"},
+                    {"id": "approval", "type": "approval", "name": "承認"},
+                    {"id": "end_approved", "type": "end", "name": "承認完了", "status": "approved"},
+                    {"id": "end_rejected", "type": "end", "name": "却下", "status": "rejected"}
+                ]
+            }),
+            created_by:  owner_id.clone(),
+            now:         created_at(),
+        })
+        .published(created_at())
+        .unwrap();
+        setup.definition_repo.add_definition(definition.clone());
+        setup.user_repo.add_user(User::new(
+            owner_id.clone(),
+            tenant_id.clone(),
+            DisplayNumber::new(1).unwrap(),
+            Email::new("tanaka@example.com").unwrap(),
+            UserName::new("田中太郎").unwrap(),
+            created_at(),
+        ));
+
+        let schedule = WorkflowSchedule::new(NewWorkflowSchedule {
+            id: WorkflowScheduleId::new(),
+            tenant_id: tenant_id.clone(),
+            owner_id: owner_id.clone(),
+            definition_id: definition.id().clone(),
+            name: WorkflowScheduleName::new("日次契約更新").unwrap(),
+            title: "契約更新".to_string(),
+            form_data: serde_json::json!({"amount": 1000}),
+            recurrence: Recurrence::Cron(CronExpression::parse("0 9 * * *").unwrap()),
+            time_zone: "UTC".parse().unwrap(),
+            auto_submit,
+            approvers: vec![ScheduledApprover {
+                step_id:     approver_step_id.to_string(),
+                assigned_to: UserId::new(),
+            }],
+            now: created_at(),
+        })
+        .unwrap();
+        schedule_repo.insert(&schedule).await.unwrap();
+    }
+
+    fn make_worker(
+        workflow_usecase: WorkflowUseCaseImpl,
+        user_repo: &FakeUserRepository,
+        schedule_repo: &FakeWorkflowScheduleRepository,
+        sender: &FakeNotificationSender,
+        now: DateTime<Utc>,
+    ) -> WorkflowScheduleWorker {
+        let notification_service = Arc::new(NotificationService::new(
+            Arc::new(sender.clone()),
+            TemplateRenderer::new().unwrap(),
+            Arc::new(FakeNotificationLogRepository::new()),
+            Arc::new(FakeInboxNotificationRepository::new()),
+            Arc::new(FakeNotificationPreferenceRepository::new()),
+            Arc::new(FakeNotificationDigestRepository::new()),
+            Arc::new(FakeNotificationTemplateRepository::new()),
+            Arc::new(user_repo.clone()),
+            "http://localhost:5173".to_string(),
+        ));
+        WorkflowScheduleWorker::new(
+            Arc::new(schedule_repo.clone()),
+            Arc::new(workflow_usecase),
+            Arc::new(user_repo.clone()),
+            notification_service,
+            Arc::new(FakeTransactionManager),
+            Arc::new(FixedClock::new(now)),
+            50,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_run_due_自動申請してスケジュールを次回に進め所有者に通知する() {
+        // Arrange
+        let run_at = Utc.with_ymd_and_hms(2026, 3, 20, 9, 0, 30).unwrap();
+        let builder = WorkflowTestBuilder::new().with_now(run_at);
+        let setup = builder.build_workflow_usecase_impl();
+        let schedule_repo = FakeWorkflowScheduleRepository::new();
+        let sender = FakeNotificationSender::new();
+        add_daily_schedule(&builder, &setup, &schedule_repo, true, "approval").await;
+        let sut = make_worker(setup.sut, &setup.user_repo, &schedule_repo, &sender, run_at);
+
+        // Act
+        let count = sut.run_due().await.unwrap();
+
+        // Assert
+        assert_eq!(count, 1);
+        let instances = setup
+            .instance_repo
+            .find_by_tenant(builder.tenant_id())
+            .await
+            .unwrap();
+        assert_eq!(instances.len(), 1);
+        assert_eq!(instances[0].status(), WorkflowInstanceStatus::InProgress);
+        assert_eq!(instances[0].initiated_by(), builder.user_id());
+        assert_eq!(instances[0].title(), "契約更新");
+
+        let schedule = &schedule_repo.schedules()[0];
+        assert_eq!(schedule.last_run_at(), Some(run_at));
+        assert_eq!(
+            schedule.next_run_at(),
+            Some(Utc.with_ymd_and_hms(2026, 3, 21, 9, 0, 0).unwrap())
+        );
+
+        let emails = sender.sent_emails();
+        assert_eq!(emails.len(), 1);
+        assert_eq!(emails[0].to, "tanaka@example.com");
+        assert!(emails[0].text_body.contains("申請しました"));
+        assert_eq!(sut.run_due().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_run_due_自動申請に失敗した場合は下書きを残して理由を通知する() {
+        // Arrange: 定義に存在しないステップの承認者を指定したスケジュール
+        let run_at = Utc.with_ymd_and_hms(2026, 3, 20, 9, 0, 0).unwrap();
+        let builder = WorkflowTestBuilder::new().with_now(run_at);
+        let setup = builder.build_workflow_usecase_impl();
+        let schedule_repo = FakeWorkflowScheduleRepository::new();
+        let sender = FakeNotificationSender::new();
+        add_daily_schedule(&builder, &setup, &schedule_repo, true, "manager").await;
+        let sut = make_worker(setup.sut, &setup.user_repo, &schedule_repo, &sender, run_at);
+
+        // Act
+        sut.run_due().await.unwrap();
+
+        // Assert
+        let instances = setup
+            .instance_repo
+            .find_by_tenant(builder.tenant_id())
+            .await
+            .unwrap();
+        assert_eq!(instances.len(), 1);
+        assert_eq!(instances[0].status(), WorkflowInstanceStatus::Draft);
+
+        let emails = sender.sent_emails();
+        assert_eq!(emails.len(), 1);
+        assert!(emails[0].text_body.contains("自動申請に失敗"));
+        assert!(emails[0].text_body.contains("manager"));
+        assert!(schedule_repo.schedules()[0].next_run_at() > Some(run_at));
+    }
+
+    #[tokio::test]
+    async fn test_run_due_実行記録に失敗した回はワークフローを作成せず二重に作成しない() {
+        // Arrange
+        let run_at = Utc.with_ymd_and_hms(2026, 3, 20, 9, 0, 0).unwrap();
+        let builder = WorkflowTestBuilder::new().with_now(run_at);
+        let setup = builder.build_workflow_usecase_impl();
+        let schedule_repo = FakeWorkflowScheduleRepository::new();
+        let sender = FakeNotificationSender::new();
+        add_daily_schedule(&builder, &setup, &schedule_repo, false, "approval").await;
+        let sut = make_worker(setup.sut, &setup.user_repo, &schedule_repo, &sender, run_at);
+        schedule_repo.fail_record_run(true);
+
+        // Act: 実行記録に失敗した場合はワークフローを作成しない
+        let failed = sut.run_due().await;
+
+        // Act: 記録できるようになった後の実行で 1 件だけ作成される
+        schedule_repo.fail_record_run(false);
+        sut.run_due().await.unwrap();
+        sut.run_due().await.unwrap();
+
+        // Assert
+        assert!(failed.is_err());
+        let instances = setup
+            .instance_repo
+            .find_by_tenant(builder.tenant_id())
+            .await
+            .unwrap();
+        assert_eq!(instances.len(), 1);
+        assert_eq!(sender.sent_emails().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_due_実行日時前のスケジュールは実行しない() {
+        // Arrange
+        let run_at = created_at() + TimeDelta::hours(1);
+        let builder = WorkflowTestBuilder::new().with_now(run_at);
+        let setup = builder.build_workflow_usecase_impl();
+        let schedule_repo = FakeWorkflowScheduleRepository::new();
+        let sender = FakeNotificationSender::new();
+        add_daily_schedule(&builder, &setup, &schedule_repo, false, "approval").await;
+        let sut = make_worker(setup.sut, &setup.user_repo, &schedule_repo, &sender, run_at);
+
+        // Act
+        let count = sut.run_due().await.unwrap();
+
+        // Assert
+        assert_eq!(count, 0);
+        assert!(
+            setup
+                .instance_repo
+                .find_by_tenant(builder.tenant_id())
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(sender.sent_emails().is_empty());
+    }
+}
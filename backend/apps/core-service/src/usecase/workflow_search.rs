@@ -0,0 +1,417 @@
+//! # ワークフロー検索ユースケース
+//!
+//! テナント内のワークフローインスタンスをキーワードとファセットで検索する。
+//!
+//! ## 検索対象
+//!
+//! - キーワード: 件名・表示用 ID・フォームデータの値・コメント本文
+//! - ファセット: ワークフロー定義・ステータス・作成日時の範囲・申請者・現在の承認者
+//!
+//! 閲覧権限のあるインスタンスのみを返す（規則は [`WorkflowViewer`] を参照）。
+//! 他のユーザーの下書きは検索結果に含めない（テナント管理者向けの全件検索を除く）。
+//! 結果は作成日時の降順で、カーソルベースでページングする。
+
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    message::Message,
+    tenant::TenantId,
+    user::UserId,
+    value_objects::{DisplayNumber, display_prefix},
+    workflow::{WorkflowDefinitionId, WorkflowInstance, WorkflowInstanceStatus, WorkflowViewer},
+};
+use ringiflow_infra::repository::{
+    UserRepository,
+    WorkflowInstanceRepository,
+    WorkflowSearchCriteria,
+    WorkflowSearchRepository,
+};
+
+use crate::{
+    error::CoreError,
+    usecase::helpers::{page_error, page_limit},
+};
+
+/// キーワードの最大文字数
+const MAX_KEYWORD_LENGTH: usize = 200;
+
+/// ワークフロー検索入力
+#[derive(Debug, Clone, Default)]
+pub struct SearchWorkflowsInput {
+    /// 検索キーワード（空白のみの場合は指定なしとみなす）
+    pub keyword: Option<String>,
+    /// ワークフロー定義
+    pub definition_ids: Vec<WorkflowDefinitionId>,
+    /// ステータス
+    pub statuses: Vec<WorkflowInstanceStatus>,
+    /// 作成日時の下限（以上）
+    pub created_from: Option<DateTime<Utc>>,
+    /// 作成日時の上限（未満）
+    pub created_to: Option<DateTime<Utc>>,
+    /// 申請者
+    pub initiated_by: Option<UserId>,
+    /// 現在の承認者
+    pub current_approver: Option<UserId>,
+    /// 前ページの `next_cursor`
+    pub cursor: Option<String>,
+    /// 1 ページあたりの件数（省略時は 20 件、最大 100 件）
+    pub limit: Option<i64>,
+}
+
+/// ワークフロー検索結果の 1 ページ
+#[derive(Debug, PartialEq, Eq)]
+pub struct WorkflowSearchResult {
+    /// 一致したインスタンス（作成日時の降順）
+    pub items:       Vec<WorkflowInstance>,
+    /// 次ページのカーソル（最後のページの場合は `None`）
+    pub next_cursor: Option<String>,
+}
+
+/// ワークフロー検索ユースケース実装
+pub struct WorkflowSearchUseCaseImpl {
+    search_repo:   Arc<dyn WorkflowSearchRepository>,
+    instance_repo: Arc<dyn WorkflowInstanceRepository>,
+    user_repo:     Arc<dyn UserRepository>,
+}
+
+impl WorkflowSearchUseCaseImpl {
+    pub fn new(
+        search_repo: Arc<dyn WorkflowSearchRepository>,
+        instance_repo: Arc<dyn WorkflowInstanceRepository>,
+        user_repo: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self {
+            search_repo,
+            instance_repo,
+            user_repo,
+        }
+    }
+
+    /// ユーザー ID のリストからユーザー名を一括解決する
+    pub async fn resolve_user_names(
+        &self,
+        user_ids: &[UserId],
+    ) -> Result<HashMap<UserId, String>, CoreError> {
+        crate::usecase::resolve_user_names(self.user_repo.as_ref(), user_ids).await
+    }
+
+    /// ワークフローを検索する
+    ///
+    /// ## エラー
+    ///
+    /// - `BadRequest`: キーワードが長すぎる、日付範囲が逆転している、カーソルが不正
+    pub async fn search_workflows(
+        &self,
+        input: SearchWorkflowsInput,
+        tenant_id: TenantId,
+        viewer: WorkflowViewer,
+    ) -> Result<WorkflowSearchResult, CoreError> {
+        self.search(input, tenant_id, viewer, false).await
+    }
+
+    /// テナント内の全ワークフローを検索する（テナント管理者向け）
+    ///
+    /// [`Self::search_workflows`] と異なり、他のユーザーの下書きも結果に含める。
+    /// 検索するユーザーはワークフロー管理者として扱う（機密定義のインスタンスは関与者のみ）。
+    ///
+    /// ## エラー
+    ///
+    /// - `BadRequest`: キーワードが長すぎる、日付範囲が逆転している、カーソルが不正
+    pub async fn search_all_workflows(
+        &self,
+        input: SearchWorkflowsInput,
+        tenant_id: TenantId,
+        user_id: UserId,
+    ) -> Result<WorkflowSearchResult, CoreError> {
+        self.search(input, tenant_id, WorkflowViewer::new(user_id, true), true)
+            .await
+    }
+
+    async fn search(
+        &self,
+        input: SearchWorkflowsInput,
+        tenant_id: TenantId,
+        viewer: WorkflowViewer,
+        include_all_drafts: bool,
+    ) -> Result<WorkflowSearchResult, CoreError> {
+        let keyword = input
+            .keyword
+            .map(|k| k.trim().to_string())
+            .filter(|k| !k.is_empty());
+        if let Some(k) = &keyword
+            && k.chars().count() > MAX_KEYWORD_LENGTH
+        {
+            return Err(CoreError::BadRequest(
+                Message::new("keyword-too-long").with("max", MAX_KEYWORD_LENGTH),
+            ));
+        }
+        if let (Some(from), Some(to)) = (input.created_from, input.created_to)
+            && from >= to
+        {
+            return Err(CoreError::BadRequest(Message::new("invalid-created-range")));
+        }
+        let limit = page_limit(input.limit);
+
+        let criteria = WorkflowSearchCriteria {
+            keyword_display_number: keyword.as_deref().and_then(parse_display_id),
+            keyword,
+            definition_ids: input.definition_ids,
+            statuses: input.statuses,
+            created_from: input.created_from,
+            created_to: input.created_to,
+            initiated_by: input.initiated_by,
+            current_approver: input.current_approver,
+            viewer_id: viewer.user_id().clone(),
+            viewer_is_workflow_admin: viewer.is_workflow_admin(),
+            include_all_drafts,
+        };
+
+        let page = self
+            .search_repo
+            .search(&tenant_id, &criteria, input.cursor.as_deref(), limit)
+            .await
+            .map_err(|e| page_error(e, "ワークフローの検索"))?;
+
+        let instances = self
+            .instance_repo
+            .find_by_ids(&page.ids, &tenant_id)
+            .await
+            .map_err(|e| CoreError::Internal(format!("インスタンスの取得に失敗: {}", e)))?;
+
+        // find_by_ids は作成日時が同じ行の順序を保証しないため、検索結果の順に並べ直す
+        let mut by_id: HashMap<_, _> = instances
+            .into_iter()
+            .map(|instance| (instance.id().clone(), instance))
+            .collect();
+        let items = page.ids.iter().filter_map(|id| by_id.remove(id)).collect();
+
+        Ok(WorkflowSearchResult {
+            items,
+            next_cursor: page.next_cursor,
+        })
+    }
+}
+
+/// キーワードを表示用 ID（`WF-42`）または連番（`42`）として解釈する
+fn parse_display_id(keyword: &str) -> Option<DisplayNumber> {
+    let number = match keyword.split_once('-') {
+        Some((prefix, number))
+            if prefix.eq_ignore_ascii_case(display_prefix::WORKFLOW_INSTANCE) =>
+        {
+            number
+        }
+        Some(_) => return None,
+        None => keyword,
+    };
+    number
+        .parse::<i64>()
+        .ok()
+        .and_then(|n| DisplayNumber::new(n).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use ringiflow_domain::{
+        value_objects::Version,
+        workflow::{NewWorkflowInstance, WorkflowInstanceId},
+    };
+    use ringiflow_infra::{
+        InfraError,
+        fake::{FakeUserRepository, FakeWorkflowInstanceRepository},
+        repository::{WorkflowInstanceRepositoryTestExt, WorkflowSearchPage},
+    };
+
+    use super::*;
+    use crate::usecase::helpers::DEFAULT_PAGE_LIMIT;
+
+    /// 検索条件を記録し、指定した ID を返すスタブ
+    struct StubSearchRepository {
+        ids:      Vec<WorkflowInstanceId>,
+        criteria: Mutex<Option<(WorkflowSearchCriteria, i64)>>,
+    }
+
+    impl StubSearchRepository {
+        fn returning(ids: Vec<WorkflowInstanceId>) -> Self {
+            Self {
+                ids,
+                criteria: Mutex::new(None),
+            }
+        }
+
+        fn received(&self) -> (WorkflowSearchCriteria, i64) {
+            self.criteria.lock().unwrap().clone().unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl WorkflowSearchRepository for StubSearchRepository {
+        async fn search(
+            &self,
+            _tenant_id: &TenantId,
+            criteria: &WorkflowSearchCriteria,
+            cursor: Option<&str>,
+            limit: i64,
+        ) -> Result<WorkflowSearchPage, InfraError> {
+            if cursor == Some("invalid") {
+                return Err(InfraError::invalid_input("カーソルのデコードに失敗"));
+            }
+            *self.criteria.lock().unwrap() = Some((criteria.clone(), limit));
+            Ok(WorkflowSearchPage {
+                ids:         self.ids.clone(),
+                next_cursor: Some("next".to_string()),
+            })
+        }
+    }
+
+    fn create_instance(tenant_id: &TenantId, display_number: i64) -> WorkflowInstance {
+        WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: tenant_id.clone(),
+            definition_id: WorkflowDefinitionId::new(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(display_number).unwrap(),
+            title: format!("申請 {}", display_number),
+            form_data: serde_json::json!({}),
+            initiated_by: UserId::new(),
+            now: chrono::Utc::now(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_検索結果を検索順のインスタンスで返す() {
+        // Arrange
+        let tenant_id = TenantId::new();
+        let instance_repo = FakeWorkflowInstanceRepository::new();
+        let first = create_instance(&tenant_id, 1);
+        let second = create_instance(&tenant_id, 2);
+        instance_repo.insert_for_test(&first).await.unwrap();
+        instance_repo.insert_for_test(&second).await.unwrap();
+        let search_repo = Arc::new(StubSearchRepository::returning(vec![
+            second.id().clone(),
+            first.id().clone(),
+        ]));
+        let sut = WorkflowSearchUseCaseImpl::new(
+            search_repo.clone(),
+            Arc::new(instance_repo),
+            Arc::new(FakeUserRepository::new()),
+        );
+        let user_id = UserId::new();
+
+        // Act
+        let result = sut
+            .search_workflows(
+                SearchWorkflowsInput {
+                    keyword: Some("  WF-2 ".to_string()),
+                    ..Default::default()
+                },
+                tenant_id,
+                WorkflowViewer::new(user_id.clone(), false),
+            )
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(
+            result,
+            WorkflowSearchResult {
+                items:       vec![second, first],
+                next_cursor: Some("next".to_string()),
+            }
+        );
+        let (criteria, limit) = search_repo.received();
+        assert_eq!(criteria.keyword.as_deref(), Some("WF-2"));
+        assert_eq!(
+            criteria.keyword_display_number,
+            Some(DisplayNumber::new(2).unwrap())
+        );
+        assert_eq!(criteria.viewer_id, user_id);
+        assert!(!criteria.viewer_is_workflow_admin);
+        assert!(!criteria.include_all_drafts);
+        assert_eq!(limit, DEFAULT_PAGE_LIMIT);
+    }
+
+    #[tokio::test]
+    async fn test_全件検索では他人の下書きも対象にする() {
+        let search_repo = Arc::new(StubSearchRepository::returning(vec![]));
+        let sut = WorkflowSearchUseCaseImpl::new(
+            search_repo.clone(),
+            Arc::new(FakeWorkflowInstanceRepository::new()),
+            Arc::new(FakeUserRepository::new()),
+        );
+
+        sut.search_all_workflows(
+            SearchWorkflowsInput::default(),
+            TenantId::new(),
+            UserId::new(),
+        )
+        .await
+        .unwrap();
+
+        let (criteria, _) = search_repo.received();
+        assert!(criteria.viewer_is_workflow_admin);
+        assert!(criteria.include_all_drafts);
+    }
+
+    #[test]
+    fn test_キーワードを表示用idとして解釈する() {
+        let cases = [
+            ("WF-42", Some(42)),
+            ("wf-7", Some(7)),
+            ("42", Some(42)),
+            ("STEP-42", None),
+            ("WF-0", None),
+            ("経費", None),
+        ];
+
+        for (keyword, expected) in cases {
+            assert_eq!(
+                parse_display_id(keyword),
+                expected.map(|n| DisplayNumber::new(n).unwrap()),
+                "{keyword}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_不正な入力は400() {
+        let sut = WorkflowSearchUseCaseImpl::new(
+            Arc::new(StubSearchRepository::returning(vec![])),
+            Arc::new(FakeWorkflowInstanceRepository::new()),
+            Arc::new(FakeUserRepository::new()),
+        );
+        let now = chrono::Utc::now();
+        let invalid_inputs = [
+            SearchWorkflowsInput {
+                keyword: Some("あ".repeat(MAX_KEYWORD_LENGTH + 1)),
+                ..Default::default()
+            },
+            SearchWorkflowsInput {
+                created_from: Some(now),
+                created_to: Some(now),
+                ..Default::default()
+            },
+            SearchWorkflowsInput {
+                cursor: Some("invalid".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        for input in invalid_inputs {
+            let result = sut
+                .search_workflows(
+                    input.clone(),
+                    TenantId::new(),
+                    WorkflowViewer::new(UserId::new(), false),
+                )
+                .await;
+            assert!(
+                matches!(result, Err(CoreError::BadRequest(_))),
+                "{input:?}: {result:?}"
+            );
+        }
+    }
+}
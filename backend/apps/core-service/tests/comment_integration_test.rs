@@ -2,20 +2,71 @@
 //!
 //! WorkflowTestBuilder を使用したテストの例
 
+use chrono::Duration;
 use ringiflow_core_service::{
-    test_utils::WorkflowTestBuilder,
-    usecase::workflow::PostCommentInput,
+    error::CoreError,
+    test_utils::{WorkflowTestBuilder, WorkflowTestSetup},
+    usecase::workflow::{EditCommentInput, PostCommentInput},
 };
 use ringiflow_domain::{
-    user::UserId,
-    value_objects::DisplayNumber,
-    workflow::{NewWorkflowStep, WorkflowStep, WorkflowStepId},
+    user::{Email, User, UserId},
+    value_objects::{DisplayNumber, UserName},
+    workflow::{
+        COMMENT_EDIT_WINDOW_MINUTES,
+        CommentBody,
+        NewWorkflowComment,
+        NewWorkflowStep,
+        WorkflowActivityType,
+        WorkflowComment,
+        WorkflowCommentId,
+        WorkflowEvent,
+        WorkflowEventType,
+        WorkflowInstance,
+        WorkflowStep,
+        WorkflowStepId,
+        WorkflowViewer,
+    },
 };
-use ringiflow_infra::repository::{
-    WorkflowInstanceRepositoryTestExt,
-    WorkflowStepRepositoryTestExt,
+use ringiflow_infra::{
+    TxContext,
+    repository::{
+        UserRepository,
+        WorkflowActivityRepository,
+        WorkflowCommentRepository,
+        WorkflowEventOutboxRepository,
+        WorkflowInstanceRepositoryTestExt,
+        WorkflowStepRepositoryTestExt,
+        WorkflowWatcherRepository,
+    },
 };
 
+/// テナントにユーザーを登録する
+async fn add_user(
+    builder: &WorkflowTestBuilder,
+    setup: &WorkflowTestSetup,
+    display_number: i64,
+    email: &str,
+) -> UserId {
+    let user = User::new(
+        UserId::new(),
+        builder.tenant_id().clone(),
+        DisplayNumber::new(display_number).unwrap(),
+        Email::new(email).unwrap(),
+        UserName::new("メンション先").unwrap(),
+        builder.now(),
+    );
+    setup.user_repo.insert(&user).await.unwrap();
+    user.id().clone()
+}
+
+/// アウトボックスのイベントからメンションイベントだけを取り出す
+fn mention_events(events: Vec<WorkflowEvent>) -> Vec<WorkflowEvent> {
+    events
+        .into_iter()
+        .filter(|e| e.event_type() == WorkflowEventType::Mentioned)
+        .collect()
+}
+
 #[tokio::test]
 async fn test_post_comment_申請者がコメントを投稿できる() {
     // Arrange
@@ -30,7 +81,8 @@ async fn test_post_comment_申請者がコメントを投稿できる() {
         .unwrap();
 
     let input = PostCommentInput {
-        body: "テストコメント".to_string(),
+        parent_id: None,
+        body:      "テストコメント".to_string(),
     };
 
     // Act
@@ -83,7 +135,8 @@ async fn test_post_comment_承認者がコメントを投稿できる() {
         .unwrap();
 
     let input = PostCommentInput {
-        body: "承認者のコメント".to_string(),
+        parent_id: None,
+        body:      "承認者のコメント".to_string(),
     };
 
     // Act
@@ -102,3 +155,577 @@ async fn test_post_comment_承認者がコメントを投稿できる() {
     let comment = result.unwrap();
     assert_eq!(comment.body().as_str(), "承認者のコメント");
 }
+
+/// 申請済みのインスタンスと、申請者が投稿したコメントを用意する
+async fn setup_with_comment(
+    builder: &WorkflowTestBuilder,
+    posted_minutes_ago: i64,
+) -> (WorkflowTestSetup, WorkflowInstance, WorkflowComment) {
+    let instance = builder.build_submitted_instance("テスト申請", 100);
+    let setup = builder.build_workflow_usecase_impl();
+    setup
+        .instance_repo
+        .insert_for_test(&instance)
+        .await
+        .unwrap();
+
+    let comment = WorkflowComment::new(NewWorkflowComment {
+        id:          WorkflowCommentId::new(),
+        tenant_id:   builder.tenant_id().clone(),
+        instance_id: instance.id().clone(),
+        parent_id:   None,
+        posted_by:   builder.user_id().clone(),
+        body:        CommentBody::new("元のコメント").unwrap(),
+        now:         builder.now() - Duration::minutes(posted_minutes_ago),
+    });
+    let mut tx = TxContext::mock();
+    setup
+        .comment_repo
+        .insert(&mut tx, &comment, builder.tenant_id())
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    (setup, instance, comment)
+}
+
+/// 申請者以外の承認者をインスタンスに割り当てる
+async fn assign_approver(
+    builder: &WorkflowTestBuilder,
+    setup: &WorkflowTestSetup,
+    instance: &WorkflowInstance,
+) -> UserId {
+    let approver_id = UserId::new();
+    let step = WorkflowStep::new(NewWorkflowStep {
+        id: WorkflowStepId::new(),
+        instance_id: instance.id().clone(),
+        display_number: DisplayNumber::new(1).unwrap(),
+        step_id: "approval".to_string(),
+        step_name: "承認".to_string(),
+        step_type: "approval".to_string(),
+        assigned_to: Some(approver_id.clone()),
+        now: builder.now(),
+    })
+    .activated(builder.now());
+    setup
+        .step_repo
+        .insert_for_test(&step, builder.tenant_id())
+        .await
+        .unwrap();
+    approver_id
+}
+
+#[tokio::test]
+async fn test_post_comment_トップレベルのコメントに返信できる() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let (setup, _, parent) = setup_with_comment(&builder, 0).await;
+
+    // Act
+    let result = setup
+        .sut
+        .post_comment(
+            PostCommentInput {
+                body:      "返信です".to_string(),
+                parent_id: Some(parent.id().clone()),
+            },
+            DisplayNumber::new(100).unwrap(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await;
+
+    // Assert
+    let reply = result.unwrap();
+    assert_eq!(reply.parent_id(), Some(parent.id()));
+}
+
+#[tokio::test]
+async fn test_post_comment_返信への返信は400() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let (setup, _, parent) = setup_with_comment(&builder, 0).await;
+    let reply = setup
+        .sut
+        .post_comment(
+            PostCommentInput {
+                body:      "返信です".to_string(),
+                parent_id: Some(parent.id().clone()),
+            },
+            DisplayNumber::new(100).unwrap(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await
+        .unwrap();
+
+    // Act
+    let result = setup
+        .sut
+        .post_comment(
+            PostCommentInput {
+                body:      "返信への返信".to_string(),
+                parent_id: Some(reply.id().clone()),
+            },
+            DisplayNumber::new(100).unwrap(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(CoreError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn test_post_comment_存在しない親コメントへの返信は404() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let (setup, ..) = setup_with_comment(&builder, 0).await;
+
+    // Act
+    let result = setup
+        .sut
+        .post_comment(
+            PostCommentInput {
+                body:      "返信です".to_string(),
+                parent_id: Some(WorkflowCommentId::new()),
+            },
+            DisplayNumber::new(100).unwrap(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(CoreError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_edit_comment_投稿者は編集でき編集前の本文が履歴に残る() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let (setup, instance, comment) = setup_with_comment(&builder, 5).await;
+
+    // Act
+    let edited = setup
+        .sut
+        .edit_comment(
+            EditCommentInput {
+                body: "編集後のコメント".to_string(),
+            },
+            DisplayNumber::new(100).unwrap(),
+            comment.id().clone(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(edited.body().as_str(), "編集後のコメント");
+    assert_eq!(edited.edited_at(), Some(builder.now()));
+
+    let viewer = WorkflowViewer::new(builder.user_id().clone(), false);
+    let revisions = setup
+        .sut
+        .list_comment_revisions(
+            DisplayNumber::new(100).unwrap(),
+            comment.id().clone(),
+            builder.tenant_id().clone(),
+            &viewer,
+        )
+        .await
+        .unwrap();
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].body().as_str(), "元のコメント");
+
+    let activities = setup
+        .activity_repo
+        .find_by_instance(instance.id(), builder.tenant_id())
+        .await
+        .unwrap();
+    assert_eq!(
+        activities.last().unwrap().activity_type(),
+        WorkflowActivityType::CommentEdited
+    );
+}
+
+#[tokio::test]
+async fn test_edit_comment_他のユーザーのコメントは403() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let (setup, instance, comment) = setup_with_comment(&builder, 0).await;
+    let approver_id = assign_approver(&builder, &setup, &instance).await;
+
+    // Act
+    let result = setup
+        .sut
+        .edit_comment(
+            EditCommentInput {
+                body: "書き換え".to_string(),
+            },
+            DisplayNumber::new(100).unwrap(),
+            comment.id().clone(),
+            builder.tenant_id().clone(),
+            approver_id,
+        )
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(CoreError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn test_edit_comment_編集期間を過ぎると400() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let (setup, _, comment) = setup_with_comment(&builder, COMMENT_EDIT_WINDOW_MINUTES + 1).await;
+
+    // Act
+    let result = setup
+        .sut
+        .edit_comment(
+            EditCommentInput {
+                body: "編集後のコメント".to_string(),
+            },
+            DisplayNumber::new(100).unwrap(),
+            comment.id().clone(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(CoreError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn test_delete_comment_投稿者は削除できる() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let (setup, instance, comment) = setup_with_comment(&builder, 0).await;
+    let viewer = WorkflowViewer::new(builder.user_id().clone(), false);
+
+    // Act
+    setup
+        .sut
+        .delete_comment(
+            DisplayNumber::new(100).unwrap(),
+            comment.id().clone(),
+            builder.tenant_id().clone(),
+            &viewer,
+        )
+        .await
+        .unwrap();
+
+    // Assert: 一覧には残り、削除済みとして返る
+    let comments = setup
+        .sut
+        .list_comments(
+            DisplayNumber::new(100).unwrap(),
+            builder.tenant_id().clone(),
+            &viewer,
+        )
+        .await
+        .unwrap();
+    assert_eq!(comments.len(), 1);
+    assert!(comments[0].is_deleted());
+    assert_eq!(comments[0].deleted_by(), Some(builder.user_id()));
+
+    let activities = setup
+        .activity_repo
+        .find_by_instance(instance.id(), builder.tenant_id())
+        .await
+        .unwrap();
+    assert_eq!(
+        activities.last().unwrap().activity_type(),
+        WorkflowActivityType::CommentDeleted
+    );
+}
+
+#[tokio::test]
+async fn test_delete_comment_ワークフロー管理者は他のユーザーのコメントを削除できる() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let (setup, instance, comment) = setup_with_comment(&builder, 0).await;
+    let approver_id = assign_approver(&builder, &setup, &instance).await;
+    let admin = WorkflowViewer::new(approver_id, true);
+
+    // Act
+    let result = setup
+        .sut
+        .delete_comment(
+            DisplayNumber::new(100).unwrap(),
+            comment.id().clone(),
+            builder.tenant_id().clone(),
+            &admin,
+        )
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_delete_comment_管理者でない他のユーザーは403() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let (setup, instance, comment) = setup_with_comment(&builder, 0).await;
+    let approver_id = assign_approver(&builder, &setup, &instance).await;
+
+    // Act
+    let result = setup
+        .sut
+        .delete_comment(
+            DisplayNumber::new(100).unwrap(),
+            comment.id().clone(),
+            builder.tenant_id().clone(),
+            &WorkflowViewer::new(approver_id, false),
+        )
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(CoreError::Forbidden(_))));
+}
+
+#[tokio::test]
+async fn test_delete_comment_削除済みのコメントは400() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let (setup, _, comment) = setup_with_comment(&builder, 0).await;
+    let viewer = WorkflowViewer::new(builder.user_id().clone(), false);
+    setup
+        .sut
+        .delete_comment(
+            DisplayNumber::new(100).unwrap(),
+            comment.id().clone(),
+            builder.tenant_id().clone(),
+            &viewer,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    let result = setup
+        .sut
+        .delete_comment(
+            DisplayNumber::new(100).unwrap(),
+            comment.id().clone(),
+            builder.tenant_id().clone(),
+            &viewer,
+        )
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(CoreError::BadRequest(_))));
+}
+
+#[tokio::test]
+async fn test_post_comment_メンションしたユーザーがウォッチャーに追加されメンションイベントが記録される()
+ {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let instance = builder.build_submitted_instance("テスト申請", 100);
+    let setup = builder.build_workflow_usecase_impl();
+    setup
+        .instance_repo
+        .insert_for_test(&instance)
+        .await
+        .unwrap();
+    let by_number = add_user(&builder, &setup, 5, "suzuki@example.com").await;
+    let by_email = add_user(&builder, &setup, 6, "sato@example.com").await;
+
+    // Act
+    let comment = setup
+        .sut
+        .post_comment(
+            PostCommentInput {
+                parent_id: None,
+                body:      "@USER-5 @sato@example.com 確認をお願いします".to_string(),
+            },
+            DisplayNumber::new(100).unwrap(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let watchers: Vec<UserId> = setup
+        .watcher_repo
+        .find_by_instance(instance.id(), builder.tenant_id())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|w| w.user_id().clone())
+        .collect();
+    assert!(watchers.contains(&by_number));
+    assert!(watchers.contains(&by_email));
+
+    let events = setup
+        .outbox_repo
+        .find_by_instance(instance.id(), builder.tenant_id())
+        .await
+        .unwrap();
+    assert!(
+        events
+            .iter()
+            .any(|e| e.event_type() == WorkflowEventType::CommentPosted)
+    );
+    let mentions = mention_events(events);
+    assert_eq!(mentions.len(), 1);
+    assert_eq!(mentions[0].actor_id(), Some(builder.user_id()));
+    let mention = mentions[0].payload().comment.as_ref().unwrap();
+    assert_eq!(&mention.comment_id, comment.id());
+    assert_eq!(mention.mentioned, vec![by_number.clone(), by_email]);
+
+    // ウォッチャーとして閲覧できる（定義のないインスタンスは機密扱い）
+    let viewer = WorkflowViewer::new(by_number, false);
+    assert!(
+        setup
+            .sut
+            .get_workflow_by_display_number(
+                DisplayNumber::new(100).unwrap(),
+                builder.tenant_id().clone(),
+                &viewer,
+            )
+            .await
+            .is_ok()
+    );
+}
+
+#[tokio::test]
+async fn test_post_comment_存在しないユーザーへのメンションは400でコメントは保存されない() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let instance = builder.build_submitted_instance("テスト申請", 100);
+    let setup = builder.build_workflow_usecase_impl();
+    setup
+        .instance_repo
+        .insert_for_test(&instance)
+        .await
+        .unwrap();
+
+    // Act
+    let result = setup
+        .sut
+        .post_comment(
+            PostCommentInput {
+                parent_id: None,
+                body:      "@USER-99 確認をお願いします".to_string(),
+            },
+            DisplayNumber::new(100).unwrap(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(CoreError::BadRequest(_))));
+    let comments = setup
+        .comment_repo
+        .find_by_instance(instance.id(), builder.tenant_id())
+        .await
+        .unwrap();
+    assert!(comments.is_empty());
+}
+
+#[tokio::test]
+async fn test_post_comment_自分へのメンションはイベントを記録しない() {
+    // Arrange
+    let builder = WorkflowTestBuilder::new();
+    let instance = builder.build_submitted_instance("テスト申請", 100);
+    let setup = builder.build_workflow_usecase_impl();
+    setup
+        .instance_repo
+        .insert_for_test(&instance)
+        .await
+        .unwrap();
+    let author = User::new(
+        builder.user_id().clone(),
+        builder.tenant_id().clone(),
+        DisplayNumber::new(1).unwrap(),
+        Email::new("tanaka@example.com").unwrap(),
+        UserName::new("田中太郎").unwrap(),
+        builder.now(),
+    );
+    setup.user_repo.insert(&author).await.unwrap();
+
+    // Act
+    setup
+        .sut
+        .post_comment(
+            PostCommentInput {
+                parent_id: None,
+                body:      "@USER-1 自分用のメモ".to_string(),
+            },
+            DisplayNumber::new(100).unwrap(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let events = setup
+        .outbox_repo
+        .find_by_instance(instance.id(), builder.tenant_id())
+        .await
+        .unwrap();
+    assert!(mention_events(events).is_empty());
+}
+
+#[tokio::test]
+async fn test_edit_comment_編集で追加されたメンションだけを通知する() {
+    // Arrange: USER-5 をメンションしたコメントを投稿済み
+    let builder = WorkflowTestBuilder::new();
+    let instance = builder.build_submitted_instance("テスト申請", 100);
+    let setup = builder.build_workflow_usecase_impl();
+    setup
+        .instance_repo
+        .insert_for_test(&instance)
+        .await
+        .unwrap();
+    add_user(&builder, &setup, 5, "suzuki@example.com").await;
+    let added = add_user(&builder, &setup, 6, "sato@example.com").await;
+    let comment = setup
+        .sut
+        .post_comment(
+            PostCommentInput {
+                parent_id: None,
+                body:      "@USER-5 確認をお願いします".to_string(),
+            },
+            DisplayNumber::new(100).unwrap(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await
+        .unwrap();
+
+    // Act
+    setup
+        .sut
+        .edit_comment(
+            EditCommentInput {
+                body: "@USER-5 @USER-6 確認をお願いします".to_string(),
+            },
+            DisplayNumber::new(100).unwrap(),
+            comment.id().clone(),
+            builder.tenant_id().clone(),
+            builder.user_id().clone(),
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let events = setup
+        .outbox_repo
+        .find_by_instance(instance.id(), builder.tenant_id())
+        .await
+        .unwrap();
+    let mentions = mention_events(events);
+    assert_eq!(mentions.len(), 2);
+    let edited_mention = mentions[1].payload().comment.as_ref().unwrap();
+    assert_eq!(edited_mention.mentioned, vec![added]);
+}
@@ -40,7 +40,7 @@ use ringiflow_core_service::{
     usecase::WorkflowDefinitionUseCaseImpl,
 };
 use ringiflow_domain::clock::FixedClock;
-use ringiflow_infra::fake::FakeWorkflowDefinitionRepository;
+use ringiflow_infra::fake::{FakeUserRepository, FakeWorkflowDefinitionRepository};
 use serde_json::{Value as JsonValue, json};
 use tower::ServiceExt;
 use uuid::Uuid;
@@ -56,7 +56,8 @@ fn create_test_app() -> (Router, Uuid) {
     let tenant_id = Uuid::new_v4();
     let repo = Arc::new(FakeWorkflowDefinitionRepository::new());
     let clock = Arc::new(FixedClock::new(fixed_now()));
-    let usecase = WorkflowDefinitionUseCaseImpl::new(repo, clock);
+    let usecase =
+        WorkflowDefinitionUseCaseImpl::new(repo, Arc::new(FakeUserRepository::new()), clock);
     let state = Arc::new(WorkflowDefinitionState { usecase });
 
     let app = Router::new()
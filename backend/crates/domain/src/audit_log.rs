@@ -21,6 +21,16 @@
 //! | `RoleCreate` | `role.create` |
 //! | `RoleUpdate` | `role.update` |
 //! | `RoleDelete` | `role.delete` |
+//! | `WebhookCreate` | `webhook.create` |
+//! | `WebhookUpdate` | `webhook.update` |
+//! | `WebhookDelete` | `webhook.delete` |
+//! | `NotificationTemplateUpdate` | `notification_template.update` |
+//! | `NotificationTemplateDelete` | `notification_template.delete` |
+//! | `NotificationLogResend` | `notification_log.resend` |
+//! | `TenantSettingsUpdate` | `tenant_settings.update` |
+//! | `WorkflowCancel` | `workflow.cancel` |
+//! | `WorkflowReassign` | `workflow.reassign` |
+//! | `WorkflowForceComplete` | `workflow.force_complete` |
 
 use std::{fmt, str::FromStr};
 
@@ -39,6 +49,16 @@ pub enum AuditAction {
     RoleCreate,
     RoleUpdate,
     RoleDelete,
+    WebhookCreate,
+    WebhookUpdate,
+    WebhookDelete,
+    NotificationTemplateUpdate,
+    NotificationTemplateDelete,
+    NotificationLogResend,
+    TenantSettingsUpdate,
+    WorkflowCancel,
+    WorkflowReassign,
+    WorkflowForceComplete,
 }
 
 impl fmt::Display for AuditAction {
@@ -51,6 +71,16 @@ impl fmt::Display for AuditAction {
             Self::RoleCreate => "role.create",
             Self::RoleUpdate => "role.update",
             Self::RoleDelete => "role.delete",
+            Self::WebhookCreate => "webhook.create",
+            Self::WebhookUpdate => "webhook.update",
+            Self::WebhookDelete => "webhook.delete",
+            Self::NotificationTemplateUpdate => "notification_template.update",
+            Self::NotificationTemplateDelete => "notification_template.delete",
+            Self::NotificationLogResend => "notification_log.resend",
+            Self::TenantSettingsUpdate => "tenant_settings.update",
+            Self::WorkflowCancel => "workflow.cancel",
+            Self::WorkflowReassign => "workflow.reassign",
+            Self::WorkflowForceComplete => "workflow.force_complete",
         };
         write!(f, "{s}")
     }
@@ -68,6 +98,16 @@ impl FromStr for AuditAction {
             "role.create" => Ok(Self::RoleCreate),
             "role.update" => Ok(Self::RoleUpdate),
             "role.delete" => Ok(Self::RoleDelete),
+            "webhook.create" => Ok(Self::WebhookCreate),
+            "webhook.update" => Ok(Self::WebhookUpdate),
+            "webhook.delete" => Ok(Self::WebhookDelete),
+            "notification_template.update" => Ok(Self::NotificationTemplateUpdate),
+            "notification_template.delete" => Ok(Self::NotificationTemplateDelete),
+            "notification_log.resend" => Ok(Self::NotificationLogResend),
+            "tenant_settings.update" => Ok(Self::TenantSettingsUpdate),
+            "workflow.cancel" => Ok(Self::WorkflowCancel),
+            "workflow.reassign" => Ok(Self::WorkflowReassign),
+            "workflow.force_complete" => Ok(Self::WorkflowForceComplete),
             _ => Err(format!("不明な監査アクション: {s}")),
         }
     }
@@ -225,6 +265,34 @@ mod tests {
         assert_eq!(AuditAction::RoleCreate.to_string(), "role.create");
         assert_eq!(AuditAction::RoleUpdate.to_string(), "role.update");
         assert_eq!(AuditAction::RoleDelete.to_string(), "role.delete");
+        assert_eq!(AuditAction::WebhookCreate.to_string(), "webhook.create");
+        assert_eq!(AuditAction::WebhookUpdate.to_string(), "webhook.update");
+        assert_eq!(AuditAction::WebhookDelete.to_string(), "webhook.delete");
+        assert_eq!(
+            AuditAction::NotificationTemplateUpdate.to_string(),
+            "notification_template.update"
+        );
+        assert_eq!(
+            AuditAction::NotificationTemplateDelete.to_string(),
+            "notification_template.delete"
+        );
+        assert_eq!(
+            AuditAction::NotificationLogResend.to_string(),
+            "notification_log.resend"
+        );
+        assert_eq!(
+            AuditAction::TenantSettingsUpdate.to_string(),
+            "tenant_settings.update"
+        );
+        assert_eq!(AuditAction::WorkflowCancel.to_string(), "workflow.cancel");
+        assert_eq!(
+            AuditAction::WorkflowReassign.to_string(),
+            "workflow.reassign"
+        );
+        assert_eq!(
+            AuditAction::WorkflowForceComplete.to_string(),
+            "workflow.force_complete"
+        );
     }
 
     #[test]
@@ -237,6 +305,28 @@ mod tests {
             "role.delete".parse::<AuditAction>().unwrap(),
             AuditAction::RoleDelete
         );
+        assert_eq!(
+            "webhook.update".parse::<AuditAction>().unwrap(),
+            AuditAction::WebhookUpdate
+        );
+        assert_eq!(
+            "notification_template.delete"
+                .parse::<AuditAction>()
+                .unwrap(),
+            AuditAction::NotificationTemplateDelete
+        );
+        assert_eq!(
+            "notification_log.resend".parse::<AuditAction>().unwrap(),
+            AuditAction::NotificationLogResend
+        );
+        assert_eq!(
+            "tenant_settings.update".parse::<AuditAction>().unwrap(),
+            AuditAction::TenantSettingsUpdate
+        );
+        assert_eq!(
+            "workflow.force_complete".parse::<AuditAction>().unwrap(),
+            AuditAction::WorkflowForceComplete
+        );
     }
 
     #[test]
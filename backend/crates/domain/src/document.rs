@@ -13,7 +13,8 @@
 //!
 //! ## 設計判断
 //!
-//! - `UploadContext` enum で `folder_id` XOR `workflow_instance_id` を型レベルで強制
+//! - `UploadContext` enum で `folder_id` / `workflow_instance_id` / `comment_id`
+//!   のいずれか 1 つだけを持つことを型レベルで強制
 //! - `FileValidation` でファイルの Content-Type・サイズ・数量を検証
 //! - `S3KeyGenerator` でテナント分離されたオブジェクトキーを生成
 
@@ -24,9 +25,10 @@ use strum::IntoStaticStr;
 use crate::{
     DomainError,
     folder::FolderId,
+    message::Message,
     tenant::TenantId,
     user::UserId,
-    workflow::WorkflowInstanceId,
+    workflow::{WorkflowCommentId, WorkflowInstanceId},
 };
 
 // ============================================================================
@@ -67,10 +69,9 @@ impl std::str::FromStr for DocumentStatus {
             "uploading" => Ok(Self::Uploading),
             "active" => Ok(Self::Active),
             "deleted" => Ok(Self::Deleted),
-            _ => Err(DomainError::Validation(format!(
-                "不正なドキュメントステータス: {}",
-                s
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-document-status").with("value", s),
+            )),
         }
     }
 }
@@ -81,14 +82,16 @@ impl std::str::FromStr for DocumentStatus {
 
 /// アップロード先のコンテキスト
 ///
-/// `folder_id` と `workflow_instance_id` の排他制約を型レベルで強制する。
-/// DB は 2 つの nullable カラム + CHECK 制約で格納する。
+/// `folder_id` / `workflow_instance_id` / `comment_id` の排他制約を型レベルで強制する。
+/// DB は 3 つの nullable カラム + CHECK 制約で格納する。
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UploadContext {
     /// フォルダ内のドキュメント（ドキュメント管理画面からのアップロード）
     Folder(FolderId),
     /// ワークフロー申請の添付ファイル
     Workflow(WorkflowInstanceId),
+    /// ワークフローコメントの添付ファイル（注釈付き PDF、スクリーンショット等）
+    Comment(WorkflowCommentId),
 }
 
 impl UploadContext {
@@ -96,15 +99,23 @@ impl UploadContext {
     pub fn folder_id(&self) -> Option<&FolderId> {
         match self {
             UploadContext::Folder(id) => Some(id),
-            UploadContext::Workflow(_) => None,
+            UploadContext::Workflow(_) | UploadContext::Comment(_) => None,
         }
     }
 
     /// ワークフローインスタンス ID を取得する（Workflow コンテキストの場合のみ）
     pub fn workflow_instance_id(&self) -> Option<&WorkflowInstanceId> {
         match self {
-            UploadContext::Folder(_) => None,
             UploadContext::Workflow(id) => Some(id),
+            UploadContext::Folder(_) | UploadContext::Comment(_) => None,
+        }
+    }
+
+    /// コメント ID を取得する（Comment コンテキストの場合のみ）
+    pub fn comment_id(&self) -> Option<&WorkflowCommentId> {
+        match self {
+            UploadContext::Comment(id) => Some(id),
+            UploadContext::Folder(_) | UploadContext::Workflow(_) => None,
         }
     }
 }
@@ -145,23 +156,19 @@ impl FileValidation {
     /// Content-Type とファイルサイズを検証する。
     pub fn validate_file(content_type: &str, content_length: i64) -> Result<(), DomainError> {
         if !Self::ALLOWED_CONTENT_TYPES.contains(&content_type) {
-            return Err(DomainError::Validation(format!(
-                "非対応のファイル形式です: {}",
-                content_type
-            )));
+            return Err(DomainError::Validation(
+                Message::new("unsupported-content-type").with("content_type", content_type),
+            ));
         }
 
         if content_length <= 0 {
-            return Err(DomainError::Validation(
-                "ファイルサイズは 1 バイト以上である必要があります".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new("file-size-empty")));
         }
 
         if content_length > Self::MAX_FILE_SIZE {
-            return Err(DomainError::Validation(format!(
-                "ファイルサイズが上限（{} MB）を超えています",
-                Self::MAX_FILE_SIZE / (1024 * 1024)
-            )));
+            return Err(DomainError::Validation(
+                Message::new("file-too-large").with("max_mb", Self::MAX_FILE_SIZE / (1024 * 1024)),
+            ));
         }
 
         Ok(())
@@ -176,17 +183,16 @@ impl FileValidation {
         new_size: i64,
     ) -> Result<(), DomainError> {
         if existing_count >= Self::MAX_FILE_COUNT {
-            return Err(DomainError::Validation(format!(
-                "ファイル数が上限（{}）に達しています",
-                Self::MAX_FILE_COUNT
-            )));
+            return Err(DomainError::Validation(
+                Message::new("too-many-files").with("max", Self::MAX_FILE_COUNT),
+            ));
         }
 
         if existing_total_size + new_size > Self::MAX_TOTAL_SIZE {
-            return Err(DomainError::Validation(format!(
-                "合計ファイルサイズが上限（{} MB）を超えます",
-                Self::MAX_TOTAL_SIZE / (1024 * 1024)
-            )));
+            return Err(DomainError::Validation(
+                Message::new("total-file-size-too-large")
+                    .with("max_mb", Self::MAX_TOTAL_SIZE / (1024 * 1024)),
+            ));
         }
 
         Ok(())
@@ -202,6 +208,7 @@ impl FileValidation {
 /// テナント分離されたキーを生成する。
 /// - ワークフロー: `{tenant_id}/workflows/{instance_id}/{document_id}_{filename}`
 /// - フォルダ: `{tenant_id}/folders/{folder_id}/{document_id}_{filename}`
+/// - コメント: `{tenant_id}/comments/{comment_id}/{document_id}_{filename}`
 pub struct S3KeyGenerator;
 
 impl S3KeyGenerator {
@@ -231,6 +238,15 @@ impl S3KeyGenerator {
                     filename
                 )
             }
+            UploadContext::Comment(comment_id) => {
+                format!(
+                    "{}/comments/{}/{}_{}",
+                    tenant_id.as_uuid(),
+                    comment_id.as_uuid(),
+                    document_id.as_uuid(),
+                    filename
+                )
+            }
         }
     }
 }
@@ -296,10 +312,9 @@ impl Document {
     /// `uploading` 以外のステータスからの遷移はエラーになる。
     pub fn confirm(self, now: DateTime<Utc>) -> Result<Self, DomainError> {
         if self.status != DocumentStatus::Uploading {
-            return Err(DomainError::Validation(format!(
-                "ドキュメントのステータスが uploading ではありません: {}",
-                self.status
-            )));
+            return Err(DomainError::Validation(
+                Message::new("document-not-uploading").with("status", self.status),
+            ));
         }
 
         Ok(Self {
@@ -314,10 +329,9 @@ impl Document {
     /// `active` 以外のステータスからの遷移はエラーになる。
     pub fn soft_delete(self, now: DateTime<Utc>) -> Result<Self, DomainError> {
         if self.status != DocumentStatus::Active {
-            return Err(DomainError::Validation(format!(
-                "ドキュメントのステータスが active ではありません: {}",
-                self.status
-            )));
+            return Err(DomainError::Validation(
+                Message::new("document-not-active").with("status", self.status),
+            ));
         }
         Ok(Self {
             status: DocumentStatus::Deleted,
@@ -327,6 +341,40 @@ impl Document {
         })
     }
 
+    /// 別のアップロード先に複製する
+    ///
+    /// S3 オブジェクトはコピーせず、同じオブジェクトキーを参照する
+    /// （ソフトデリートではオブジェクトを削除しないため共有できる）。
+    /// 複製したドキュメントはステータス `active` で作成される。
+    /// `active` 以外のドキュメントの複製はエラーになる。
+    pub fn duplicate(
+        &self,
+        id: DocumentId,
+        upload_context: UploadContext,
+        uploaded_by: Option<UserId>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        if self.status != DocumentStatus::Active {
+            return Err(DomainError::Validation(
+                Message::new("document-not-active").with("status", self.status),
+            ));
+        }
+        Ok(Self {
+            id,
+            tenant_id: self.tenant_id.clone(),
+            filename: self.filename.clone(),
+            content_type: self.content_type.clone(),
+            size: self.size,
+            s3_key: self.s3_key.clone(),
+            upload_context,
+            status: DocumentStatus::Active,
+            uploaded_by,
+            created_at: now,
+            updated_at: now,
+            deleted_at: None,
+        })
+    }
+
     /// DB からエンティティを復元する（バリデーションをスキップ）
     // FIXME: 引数が多い。DB 行データの中間構造体を経由して引数を削減する
     #[allow(clippy::too_many_arguments)]
@@ -555,6 +603,36 @@ mod tests {
         assert_eq!(key, expected);
     }
 
+    #[test]
+    fn test_s3_key_generatorでコメントコンテキストのキーを生成する() {
+        let tenant_id = TenantId::new();
+        let comment_id = WorkflowCommentId::new();
+        let document_id = DocumentId::new();
+        let context = UploadContext::Comment(comment_id.clone());
+
+        let key = S3KeyGenerator::generate(&tenant_id, &context, &document_id, "注釈.pdf");
+
+        let expected = format!(
+            "{}/comments/{}/{}_注釈.pdf",
+            tenant_id.as_uuid(),
+            comment_id.as_uuid(),
+            document_id.as_uuid()
+        );
+        assert_eq!(key, expected);
+    }
+
+    // --- UploadContext ---
+
+    #[test]
+    fn test_upload_contextのアクセサは該当コンテキストのidのみ返す() {
+        let comment_id = WorkflowCommentId::new();
+        let context = UploadContext::Comment(comment_id.clone());
+
+        assert_eq!(context.comment_id(), Some(&comment_id));
+        assert_eq!(context.folder_id(), None);
+        assert_eq!(context.workflow_instance_id(), None);
+    }
+
     // --- Document ---
 
     fn fixed_now() -> DateTime<Utc> {
@@ -704,4 +782,74 @@ mod tests {
         let result = doc.confirm(later);
         assert!(result.is_err());
     }
+
+    // --- Document::duplicate ---
+
+    #[test]
+    fn test_document_duplicateで同じs3キーを参照するactiveなドキュメントを作成する() {
+        let now = fixed_now();
+        let later = DateTime::from_timestamp(1_700_001_000, 0).unwrap();
+        let doc = Document::from_db(
+            DocumentId::new(),
+            TenantId::new(),
+            "test.pdf".to_string(),
+            "application/pdf".to_string(),
+            1024,
+            "key".to_string(),
+            UploadContext::Workflow(WorkflowInstanceId::new()),
+            DocumentStatus::Active,
+            Some(UserId::new()),
+            now,
+            now,
+            None,
+        );
+        let new_id = DocumentId::new();
+        let new_instance_id = WorkflowInstanceId::new();
+        let user_id = UserId::new();
+
+        let duplicated = doc
+            .duplicate(
+                new_id.clone(),
+                UploadContext::Workflow(new_instance_id.clone()),
+                Some(user_id.clone()),
+                later,
+            )
+            .unwrap();
+
+        assert_eq!(duplicated.id(), &new_id);
+        assert_eq!(duplicated.s3_key(), "key");
+        assert_eq!(duplicated.filename(), "test.pdf");
+        assert_eq!(
+            duplicated.upload_context().workflow_instance_id(),
+            Some(&new_instance_id)
+        );
+        assert_eq!(duplicated.status(), DocumentStatus::Active);
+        assert_eq!(duplicated.uploaded_by(), Some(&user_id));
+        assert_eq!(duplicated.created_at(), later);
+    }
+
+    #[test]
+    fn test_document_duplicateでuploadingステータスの複製を拒否する() {
+        let now = fixed_now();
+
+        let doc = Document::new_uploading(
+            DocumentId::new(),
+            TenantId::new(),
+            "test.pdf".to_string(),
+            "application/pdf".to_string(),
+            1024,
+            "key".to_string(),
+            UploadContext::Workflow(WorkflowInstanceId::new()),
+            None,
+            now,
+        );
+
+        let result = doc.duplicate(
+            DocumentId::new(),
+            UploadContext::Workflow(WorkflowInstanceId::new()),
+            None,
+            now,
+        );
+        assert!(result.is_err());
+    }
 }
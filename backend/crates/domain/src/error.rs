@@ -7,6 +7,8 @@
 //! - **型による分類**: エラーの種類を列挙型で明示し、パターンマッチで処理可能に
 //! - **thiserror 活用**: `#[error(...)]` マクロでエラーメッセージを自動生成
 //! - **HTTP ステータスへのマッピング**: API 層でステータスコードに変換可能
+//! - **メッセージはコードで持つ**: 文言は [`Message`] のコードとパラメータで持ち、
+//!   レスポンスのロケールに合わせて展開できるようにする
 //!
 //! ## エラーの種類と HTTP ステータスの対応
 //!
@@ -20,11 +22,13 @@
 //! ## 使用例
 //!
 //! ```rust
-//! use ringiflow_domain::DomainError;
+//! use ringiflow_domain::{DomainError, message::Message};
 //!
 //! fn validate_name(name: &str) -> Result<(), DomainError> {
 //!     if name.is_empty() {
-//!         return Err(DomainError::Validation("名前は必須です".to_string()));
+//!         return Err(DomainError::Validation(
+//!             Message::new("required").with("field", "user-name"),
+//!         ));
 //!     }
 //!     Ok(())
 //! }
@@ -40,6 +44,8 @@
 
 use thiserror::Error;
 
+use crate::message::Message;
+
 /// ドメイン層で発生するエラー
 ///
 /// ビジネスロジックの実行中に発生する例外状態を表現する。
@@ -48,7 +54,8 @@ use thiserror::Error;
 /// # 設計判断
 ///
 /// - `thiserror` を使用し、`std::error::Error` トレイトを自動実装
-/// - 各バリアントに `#[error(...)]` で人間可読なメッセージを定義
+/// - 各バリアントに `#[error(...)]` で人間可読なメッセージを定義（ログ用の日本語）
+/// - レスポンスの文言は [`DomainError::into_message`] で得た [`Message`] から展開する
 /// - `Debug` derive により、ログ出力時に詳細情報を表示可能
 #[derive(Debug, Error)]
 pub enum DomainError {
@@ -62,7 +69,7 @@ pub enum DomainError {
     /// - 文字数制限の超過
     /// - 不正なフォーマット
     #[error("バリデーションエラー: {0}")]
-    Validation(String),
+    Validation(Message),
 
     /// エンティティが見つからない
     ///
@@ -93,7 +100,7 @@ pub enum DomainError {
     /// このエラーが発生した場合、クライアントは最新データを再取得してから
     /// 再度更新を試みる必要がある。
     #[error("競合が発生しました: {0}")]
-    Conflict(String),
+    Conflict(Message),
 
     /// 権限エラー
     ///
@@ -105,5 +112,52 @@ pub enum DomainError {
     /// - 認証エラー（401）: ユーザーが誰か不明
     /// - 認可エラー（403）: ユーザーは特定できたが、権限がない
     #[error("権限がありません: {0}")]
-    Forbidden(String),
+    Forbidden(Message),
+}
+
+impl DomainError {
+    /// レスポンスに使うメッセージに変換する
+    ///
+    /// `Display` と異なり、エラー種別の接頭辞（「バリデーションエラー: 」など）は付けない。
+    pub fn into_message(self) -> Message {
+        match self {
+            DomainError::Validation(message)
+            | DomainError::Conflict(message)
+            | DomainError::Forbidden(message) => message,
+            DomainError::NotFound { entity_type, id } => Message::new("entity-not-found")
+                .with("entity", entity_type)
+                .with("id", id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_into_messageはエラー種別の接頭辞を付けない() {
+        let error = DomainError::Validation(Message::new("required").with("field", "title"));
+
+        assert_eq!(
+            error.to_string(),
+            "バリデーションエラー: タイトルは必須です"
+        );
+        assert_eq!(error.into_message().to_string(), "タイトルは必須です");
+    }
+
+    #[test]
+    fn test_into_messageは見つからないエンティティをパラメータに持つ() {
+        let error = DomainError::NotFound {
+            entity_type: "Workflow",
+            id:          "wf-123".to_string(),
+        };
+
+        let message = error.into_message();
+
+        assert_eq!(message.code(), "entity-not-found");
+        assert_eq!(message.to_string(), "Workflow が見つかりません: wf-123");
+    }
 }
@@ -45,7 +45,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::{DomainError, tenant::TenantId, user::UserId};
+use crate::{DomainError, message::Message, tenant::TenantId, user::UserId};
 
 define_uuid_id! {
     /// フォルダの一意識別子
@@ -79,21 +79,21 @@ impl FolderName {
         let value = value.into().trim().to_string();
 
         if value.is_empty() {
-            return Err(DomainError::Validation(
-                "フォルダ名を入力してください".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "folder-name-required",
+            )));
         }
 
         if value.chars().count() > MAX_FOLDER_NAME_LENGTH {
             return Err(DomainError::Validation(
-                "フォルダ名は 255 文字以内で入力してください".to_string(),
+                Message::new("folder-name-too-long").with("max", MAX_FOLDER_NAME_LENGTH),
             ));
         }
 
         if value.chars().any(|c| FORBIDDEN_CHARS.contains(&c)) {
-            return Err(DomainError::Validation(
-                "フォルダ名に使用できない文字が含まれています".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "folder-name-invalid-characters",
+            )));
         }
 
         Ok(Self(value))
@@ -169,7 +169,7 @@ impl Folder {
                 let depth = pd + 1;
                 if depth > MAX_FOLDER_DEPTH {
                     return Err(DomainError::Validation(
-                        "フォルダの階層が上限（5 階層）を超えています".to_string(),
+                        Message::new("folder-too-deep").with("max", MAX_FOLDER_DEPTH),
                     ));
                 }
                 let path = format!("{}{}/", pp, name.as_str());
@@ -260,7 +260,7 @@ impl Folder {
                 let depth = pd + 1;
                 if depth > MAX_FOLDER_DEPTH {
                     return Err(DomainError::Validation(
-                        "フォルダの階層が上限（5 階層）を超えています".to_string(),
+                        Message::new("folder-too-deep").with("max", MAX_FOLDER_DEPTH),
                     ));
                 }
                 let path = format!("{}{}/", pp, self.name.as_str());
@@ -295,7 +295,7 @@ impl Folder {
         let new_depth = self.depth + 1;
         if new_depth > MAX_FOLDER_DEPTH {
             return Err(DomainError::Validation(
-                "フォルダの階層が上限（5 階層）を超えています".to_string(),
+                Message::new("folder-too-deep").with("max", MAX_FOLDER_DEPTH),
             ));
         }
         Ok(new_depth)
@@ -24,6 +24,7 @@
 //! ## モジュール構成
 //!
 //! - [`error`] - ドメイン層で発生するエラーの定義
+//! - [`message`] - エラーメッセージのコード・パラメータと多言語の文言
 //! - [`tenant`] - マルチテナント機能のための識別子
 //!
 //! ## 使用例
@@ -49,12 +50,16 @@ pub mod clock;
 pub mod document;
 pub mod error;
 pub mod folder;
+pub mod locale;
+pub mod message;
 pub mod notification;
 pub mod password;
 pub mod role;
 pub mod tenant;
+pub mod time_zone;
 pub mod user;
 pub mod value_objects;
+pub mod webhook;
 pub mod workflow;
 
 pub use error::DomainError;
@@ -0,0 +1,154 @@
+//! # ロケール
+//!
+//! 通知メールやエラーメッセージを表示する言語を定義する。
+//!
+//! ## 設計方針
+//!
+//! - **対応言語は日本語と英語のみ**: 地域（`en-US` / `en-GB` など）は区別しない
+//! - **解決順序**: ユーザーのロケール → テナントの既定ロケール → 日本語
+//! - **`Accept-Language` の解釈**: 品質値（`q`）の高い順に、対応している最初の言語を選ぶ
+//!
+//! → 詳細設計: `docs/40_詳細設計書/16_通知機能設計.md`
+
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+use crate::{DomainError, message::Message};
+
+/// ロケール
+///
+/// users テーブルの `locale` カラムと tenants テーブルの `default_locale` カラムに格納される値。
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    IntoStaticStr,
+    strum::Display,
+    strum::VariantArray,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum Locale {
+    /// 日本語
+    #[default]
+    Ja,
+    /// 英語
+    En,
+}
+
+impl Locale {
+    /// 全ロケールを定義順に返す
+    pub fn all() -> &'static [Locale] {
+        <Self as strum::VariantArray>::VARIANTS
+    }
+
+    /// `Accept-Language` ヘッダーの値から対応しているロケールを選ぶ
+    ///
+    /// 品質値の高い順（同じ品質値なら記述順）に言語タグの主言語を照合する。
+    /// `*` と品質値 0 の言語は無視する。対応している言語がなければ `None` を返す。
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        let mut candidates: Vec<(Self, u16)> = header
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';');
+                let tag = parts.next()?.trim();
+                let primary = tag.split(['-', '_']).next()?;
+                let locale = Self::from_language_code(primary)?;
+                let quality = parts
+                    .find_map(|param| param.trim().strip_prefix("q="))
+                    .map_or(Some(1000), parse_quality)?;
+                (quality > 0).then_some((locale, quality))
+            })
+            .collect();
+
+        // sort_by は安定ソートのため、同じ品質値の言語は記述順が保たれる
+        candidates.sort_by(|a, b| b.1.cmp(&a.1));
+        candidates.first().map(|(locale, _)| *locale)
+    }
+
+    /// 言語コード（大文字小文字を区別しない）からロケールを返す
+    fn from_language_code(code: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|locale| code.eq_ignore_ascii_case(locale.into()))
+    }
+}
+
+/// 品質値（`0`〜`1`、小数点以下 3 桁まで）を 1000 倍の整数に変換する
+///
+/// 形式が不正な場合は `None` を返す（その言語は無視される）。
+fn parse_quality(value: &str) -> Option<u16> {
+    let quality: f32 = value.trim().parse().ok()?;
+    (0.0..=1.0)
+        .contains(&quality)
+        .then(|| (quality * 1000.0).round() as u16)
+}
+
+impl std::str::FromStr for Locale {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ja" => Ok(Self::Ja),
+            "en" => Ok(Self::En),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-locale").with("value", s),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("ja", Locale::Ja)]
+    #[case("en", Locale::En)]
+    fn test_文字列との相互変換(#[case] s: &str, #[case] locale: Locale) {
+        assert_eq!(s.parse::<Locale>().unwrap(), locale);
+        assert_eq!(locale.to_string(), s);
+    }
+
+    #[test]
+    fn test_不正な文字列はエラーになる() {
+        assert!("fr".parse::<Locale>().is_err());
+        assert!("EN".parse::<Locale>().is_err());
+    }
+
+    #[test]
+    fn test_既定のロケールは日本語() {
+        assert_eq!(Locale::default(), Locale::Ja);
+    }
+
+    #[rstest]
+    #[case("en", Some(Locale::En))]
+    #[case("ja", Some(Locale::Ja))]
+    #[case("en-US,en;q=0.9", Some(Locale::En))]
+    #[case("EN-gb", Some(Locale::En))]
+    #[case("ja-JP,ja;q=0.9,en-US;q=0.8,en;q=0.7", Some(Locale::Ja))]
+    #[case("ja;q=0.5, en;q=0.8", Some(Locale::En))]
+    #[case("fr-FR,fr;q=0.9,en;q=0.5", Some(Locale::En))]
+    #[case("en;q=0.8, ja;q=0.8", Some(Locale::En))]
+    #[case("en;q=0, ja;q=0.1", Some(Locale::Ja))]
+    #[case("en;q=abc, ja;q=0.1", Some(Locale::Ja))]
+    #[case("fr, de", None)]
+    #[case("*", None)]
+    #[case("", None)]
+    fn test_accept_languageから対応しているロケールを選ぶ(
+        #[case] header: &str,
+        #[case] expected: Option<Locale>,
+    ) {
+        assert_eq!(Locale::from_accept_language(header), expected);
+    }
+}
@@ -63,23 +63,23 @@ macro_rules! define_uuid_id {
 /// `define_validated_string!` の PII / 非 PII 両アームで共有される
 /// `new()`, `as_str()`, `into_string()` を一括生成する。
 macro_rules! _validated_string_common {
-    ($Name:ident, $label:expr, $max_length:expr) => {
+    ($Name:ident, $field:expr, $max_length:expr) => {
         impl $Name {
             pub fn new(value: impl Into<String>) -> Result<Self, $crate::DomainError> {
                 let value = value.into().trim().to_string();
 
                 if value.is_empty() {
-                    return Err($crate::DomainError::Validation(format!(
-                        "{}は必須です",
-                        $label
-                    )));
+                    return Err($crate::DomainError::Validation(
+                        $crate::message::Message::new("required").with("field", $field),
+                    ));
                 }
 
                 if value.chars().count() > $max_length {
-                    return Err($crate::DomainError::Validation(format!(
-                        "{}は {} 文字以内である必要があります",
-                        $label, $max_length
-                    )));
+                    return Err($crate::DomainError::Validation(
+                        $crate::message::Message::new("too-long")
+                            .with("field", $field)
+                            .with("max", $max_length),
+                    ));
                 }
 
                 Ok(Self(value))
@@ -118,7 +118,8 @@ macro_rules! _validated_string_common {
 ///
 /// # 引数
 ///
-/// - `$label`: エラーメッセージに使うラベル（例: `"ユーザー名"`）
+/// - `$field`: エラーメッセージに使うフィールドコード（例: `"user-name"`）。
+///   フィールド名はメッセージカタログで定義する
 /// - `$max_length`: 最大文字数（`chars().count()` でカウント）
 /// - `pii`: （任意）`true` を指定すると PII 保護モード
 ///
@@ -140,7 +141,7 @@ macro_rules! define_validated_string {
     (
         $(#[$meta:meta])*
         $vis:vis struct $Name:ident {
-            label: $label:expr,
+            field: $field:expr,
             max_length: $max_length:expr,
             pii: true $(,)?
         }
@@ -158,13 +159,13 @@ macro_rules! define_validated_string {
             }
         }
 
-        _validated_string_common!($Name, $label, $max_length);
+        _validated_string_common!($Name, $field, $max_length);
     };
     // 非 PII アーム: derive(Debug) + Display 生成
     (
         $(#[$meta:meta])*
         $vis:vis struct $Name:ident {
-            label: $label:expr,
+            field: $field:expr,
             max_length: $max_length:expr $(,)?
         }
     ) => {
@@ -175,7 +176,7 @@ macro_rules! define_validated_string {
         )]
         $vis struct $Name(String);
 
-        _validated_string_common!($Name, $label, $max_length);
+        _validated_string_common!($Name, $field, $max_length);
 
         impl std::fmt::Display for $Name {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
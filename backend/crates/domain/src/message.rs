@@ -0,0 +1,228 @@
+//! # メッセージ
+//!
+//! ユーザーに表示するメッセージ（主にエラーメッセージ）を、
+//! ロケールによらないメッセージコードとパラメータで表す。
+//!
+//! ## 設計方針
+//!
+//! - **発生元でコード化**: エラーの発生元がメッセージコードとパラメータを決め、
+//!   文言は [`catalog`] で各ロケールに展開する。英語でも個別の理由（フィールド名・上限値など）が残る
+//! - **`{name}` で埋め込み**: 文言中の `{name}` を同名のパラメータで置き換える
+//! - **`field` / `action` パラメータ**: フィールドコード（例: `"user-name"`）・
+//!   アクションコード（例: `"approve"`）として扱い、ロケールの名前に置き換える
+//! - **`Display` は日本語**: ログや既定のレスポンスでは日本語の文言を使う
+//!
+//! → 詳細設計: `docs/40_詳細設計書/03_API設計.md`（エラーレスポンス）
+
+mod catalog;
+
+use std::{collections::BTreeMap, fmt};
+
+use crate::locale::Locale;
+
+/// 文言をそのまま表示するメッセージのコード（パラメータ名も同じ）
+const TEXT_CODE: &str = "text";
+
+/// メッセージコードとパラメータ
+///
+/// # 使用例
+///
+/// ```rust
+/// use ringiflow_domain::{locale::Locale, message::Message};
+///
+/// let message = Message::new("too-long")
+///     .with("field", "user-name")
+///     .with("max", 100);
+/// assert_eq!(
+///     message.to_string(),
+///     "ユーザー名は 100 文字以内である必要があります"
+/// );
+/// assert_eq!(
+///     message.render(Locale::En),
+///     "User name must be at most 100 characters."
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+    code:   String,
+    params: BTreeMap<String, String>,
+}
+
+impl Message {
+    /// パラメータのないメッセージを作成する
+    pub fn new(code: impl Into<String>) -> Self {
+        Self {
+            code:   code.into(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// カタログにない文言をそのまま表示するメッセージを作成する
+    ///
+    /// メッセージコードを持たない他サービスのエラー文言など、翻訳できない文言に使用する。
+    /// どのロケールでも `text` をそのまま返す。
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::new(TEXT_CODE).with(TEXT_CODE, text.into())
+    }
+
+    /// メッセージコードとパラメータから復元する
+    ///
+    /// 他サービスのエラーレスポンスから受け取ったメッセージを展開する場合に使用する。
+    pub fn from_parts(code: impl Into<String>, params: BTreeMap<String, String>) -> Self {
+        Self {
+            code: code.into(),
+            params,
+        }
+    }
+
+    /// パラメータを追加する
+    pub fn with(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.params.insert(name.into(), value.to_string());
+        self
+    }
+
+    /// メッセージコード（ロケールによらず一定）
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// パラメータ
+    pub fn params(&self) -> &BTreeMap<String, String> {
+        &self.params
+    }
+
+    /// メッセージコードとパラメータに分解する
+    pub fn into_parts(self) -> (String, BTreeMap<String, String>) {
+        (self.code, self.params)
+    }
+
+    /// `locale` の文言に展開する
+    ///
+    /// カタログにないコードの場合はコードをそのまま返す。
+    /// 文言中のパラメータがない `{name}` はそのまま残す。
+    pub fn render(&self, locale: Locale) -> String {
+        let Some(template) = catalog::message(&self.code, locale) else {
+            return self.code.clone();
+        };
+
+        let mut rendered = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            rendered.push_str(&rest[..start]);
+            let placeholder = &rest[start..];
+            let Some(end) = placeholder.find('}') else {
+                break;
+            };
+            let name = &placeholder[1..end];
+            match self.params.get(name) {
+                Some(value) => {
+                    rendered.push_str(catalog::name(name, value, locale).unwrap_or(value));
+                }
+                None => rendered.push_str(&placeholder[..=end]),
+            }
+            rest = &placeholder[end + 1..];
+        }
+        rendered.push_str(rest);
+        rendered
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(Locale::Ja))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Locale::Ja, "コメント本文は2000文字以内で入力してください")]
+    #[case(Locale::En, "Comment body must be at most 2000 characters.")]
+    fn test_パラメータをロケールの文言に埋め込む(
+        #[case] locale: Locale,
+        #[case] expected: &str,
+    ) {
+        let message = Message::new("comment-body-too-long").with("max", 2000);
+
+        assert_eq!(message.render(locale), expected);
+    }
+
+    #[rstest]
+    #[case(Locale::Ja, "メールアドレスは必須です")]
+    #[case(Locale::En, "Email address is required.")]
+    fn test_fieldパラメータはロケールのフィールド名に置き換える(
+        #[case] locale: Locale,
+        #[case] expected: &str,
+    ) {
+        let message = Message::new("required").with("field", "email");
+
+        assert_eq!(message.render(locale), expected);
+    }
+
+    #[rstest]
+    #[case(Locale::Ja, "このステップを差し戻しする権限がありません")]
+    #[case(Locale::En, "You are not allowed to request changes on this step.")]
+    fn test_actionパラメータはロケールの操作名に置き換える(
+        #[case] locale: Locale,
+        #[case] expected: &str,
+    ) {
+        let message = Message::new("step-action-forbidden").with("action", "request-changes");
+
+        assert_eq!(message.render(locale), expected);
+    }
+
+    #[test]
+    fn test_カタログにないフィールドコードはそのまま埋め込む() {
+        let message = Message::new("required").with("field", "unknown-field");
+
+        assert_eq!(message.render(Locale::En), "unknown-field is required.");
+    }
+
+    #[test]
+    fn test_カタログにないコードはコードをそのまま返す() {
+        let message = Message::new("unknown-code").with("value", 1);
+
+        assert_eq!(message.render(Locale::En), "unknown-code");
+    }
+
+    #[test]
+    fn test_パラメータがない埋め込み箇所はそのまま残す() {
+        let message = Message::new("comment-body-too-long");
+
+        assert_eq!(
+            message.render(Locale::En),
+            "Comment body must be at most {max} characters."
+        );
+    }
+
+    #[test]
+    fn test_textはどのロケールでも文言をそのまま返す() {
+        let message = Message::text("入力が不正です");
+
+        assert_eq!(message.render(Locale::Ja), "入力が不正です");
+        assert_eq!(message.render(Locale::En), "入力が不正です");
+    }
+
+    #[test]
+    fn test_displayは日本語の文言を返す() {
+        let message = Message::new("invalid-locale").with("value", "fr");
+
+        assert_eq!(message.to_string(), "不正なロケール: fr");
+    }
+
+    #[test]
+    fn test_分解したコードとパラメータから復元できる() {
+        let message = Message::new("too-long")
+            .with("field", "user-name")
+            .with("max", 100);
+
+        let (code, params) = message.clone().into_parts();
+
+        assert_eq!(Message::from_parts(code, params), message);
+    }
+}
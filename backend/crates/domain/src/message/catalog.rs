@@ -0,0 +1,1063 @@
+//! # メッセージカタログ
+//!
+//! メッセージコードごとの日本語・英語の文言と、`field` / `action` パラメータに使う
+//! コードごとの名前を定義する。
+//! 新しいメッセージを追加する場合は、両言語の文言で同じパラメータを使う（テストで検証する）。
+
+use crate::locale::Locale;
+
+/// メッセージの文言（コード, 日本語, 英語）
+const MESSAGES: &[(&str, &str, &str)] = &[
+    // --- 共通 ---
+    ("required", "{field}は必須です", "{field} is required."),
+    (
+        "too-long",
+        "{field}は {max} 文字以内である必要があります",
+        "{field} must be at most {max} characters.",
+    ),
+    (
+        "version-must-be-positive",
+        "バージョン番号は 1 以上である必要があります",
+        "Version must be 1 or greater.",
+    ),
+    (
+        "display-number-must-be-positive",
+        "表示用連番は 1 以上である必要があります",
+        "Display number must be 1 or greater.",
+    ),
+    (
+        "invalid-locale",
+        "不正なロケール: {value}",
+        "Invalid locale: {value}",
+    ),
+    (
+        "invalid-time-zone",
+        "不正なタイムゾーン: {value}",
+        "Invalid time zone: {value}",
+    ),
+    (
+        "entity-not-found",
+        "{entity} が見つかりません: {id}",
+        "{entity} was not found: {id}",
+    ),
+    // --- ユーザー ---
+    (
+        "invalid-email-format",
+        "メールアドレスの形式が不正です",
+        "The email address format is invalid.",
+    ),
+    (
+        "email-too-long",
+        "メールアドレスは{max}文字以内である必要があります",
+        "Email address must be at most {max} characters.",
+    ),
+    (
+        "invalid-user-status",
+        "不正なユーザーステータス: {value}",
+        "Invalid user status: {value}",
+    ),
+    // --- 通知 ---
+    (
+        "invalid-notification-log-status",
+        "不正な通知ログステータス: {value}",
+        "Invalid notification log status: {value}",
+    ),
+    (
+        "notification-not-failed",
+        "送信に失敗した通知のみ再送できます",
+        "Only notifications that failed to send can be resent.",
+    ),
+    (
+        "notification-body-not-stored",
+        "本文が保存されていない通知は再送できません",
+        "Notifications without a stored body cannot be resent.",
+    ),
+    // --- ドキュメント・フォルダ ---
+    (
+        "invalid-document-status",
+        "不正なドキュメントステータス: {value}",
+        "Invalid document status: {value}",
+    ),
+    (
+        "unsupported-content-type",
+        "非対応のファイル形式です: {content_type}",
+        "Unsupported file type: {content_type}",
+    ),
+    (
+        "file-size-empty",
+        "ファイルサイズは 1 バイト以上である必要があります",
+        "File size must be at least 1 byte.",
+    ),
+    (
+        "file-too-large",
+        "ファイルサイズが上限（{max_mb} MB）を超えています",
+        "File size exceeds the limit ({max_mb} MB).",
+    ),
+    (
+        "too-many-files",
+        "ファイル数が上限（{max}）に達しています",
+        "The number of files has reached the limit ({max}).",
+    ),
+    (
+        "total-file-size-too-large",
+        "合計ファイルサイズが上限（{max_mb} MB）を超えます",
+        "Total file size exceeds the limit ({max_mb} MB).",
+    ),
+    (
+        "document-not-uploading",
+        "ドキュメントのステータスが uploading ではありません: {status}",
+        "The document status is not uploading: {status}",
+    ),
+    (
+        "document-not-active",
+        "ドキュメントのステータスが active ではありません: {status}",
+        "The document status is not active: {status}",
+    ),
+    (
+        "folder-name-required",
+        "フォルダ名を入力してください",
+        "Enter a folder name.",
+    ),
+    (
+        "folder-name-too-long",
+        "フォルダ名は {max} 文字以内で入力してください",
+        "Folder name must be at most {max} characters.",
+    ),
+    (
+        "folder-name-invalid-characters",
+        "フォルダ名に使用できない文字が含まれています",
+        "Folder name contains characters that cannot be used.",
+    ),
+    (
+        "folder-too-deep",
+        "フォルダの階層が上限（{max} 階層）を超えています",
+        "Folder nesting exceeds the limit ({max} levels).",
+    ),
+    // --- Webhook ---
+    (
+        "webhook-url-invalid-scheme",
+        "Webhook URL は http:// または https:// で始まる必要があります",
+        "Webhook URL must start with http:// or https://.",
+    ),
+    (
+        "webhook-url-too-long",
+        "Webhook URL は空白を含まない {max} 文字以内である必要があります",
+        "Webhook URL must be at most {max} characters without whitespace.",
+    ),
+    (
+        "webhook-secret-length",
+        "シークレットは {min} 〜 {max} 文字である必要があります",
+        "Secret must be between {min} and {max} characters.",
+    ),
+    (
+        "webhook-events-required",
+        "購読するイベント種別を 1 つ以上指定してください",
+        "Specify at least one event type to subscribe to.",
+    ),
+    (
+        "invalid-webhook-delivery-status",
+        "不正な Webhook 配信ステータス: {value}",
+        "Invalid webhook delivery status: {value}",
+    ),
+    // --- ワークフロー定義 ---
+    (
+        "invalid-workflow-definition-status",
+        "不正なワークフロー定義ステータス: {value}",
+        "Invalid workflow definition status: {value}",
+    ),
+    (
+        "definition-publish-not-draft",
+        "下書き状態の定義のみ公開できます",
+        "Only draft definitions can be published.",
+    ),
+    (
+        "definition-archive-not-published",
+        "公開済みの定義のみアーカイブできます",
+        "Only published definitions can be archived.",
+    ),
+    (
+        "definition-delete-not-draft",
+        "下書き状態の定義のみ削除できます",
+        "Only draft definitions can be deleted.",
+    ),
+    (
+        "definition-update-not-draft",
+        "下書き状態の定義のみ更新できます",
+        "Only draft definitions can be updated.",
+    ),
+    (
+        "definition-steps-missing",
+        "定義 JSON に steps 配列が見つかりません",
+        "The definition JSON has no steps array.",
+    ),
+    (
+        "definition-approval-step-id-missing",
+        "承認ステップに id フィールドがありません",
+        "An approval step has no id field.",
+    ),
+    (
+        "definition-no-approval-steps",
+        "定義に承認ステップが含まれていません",
+        "The definition has no approval steps.",
+    ),
+    // --- ワークフローインスタンス ---
+    (
+        "invalid-workflow-instance-status",
+        "不正なワークフローインスタンスステータス: {value}",
+        "Invalid workflow instance status: {value}",
+    ),
+    (
+        "instance-state-column-missing",
+        "{status} インスタンスには {column} が必要です",
+        "A {status} instance requires {column}.",
+    ),
+    (
+        "instance-cancelled-submitted-at-missing",
+        "Cancelled インスタンスで current_step_id がある場合は submitted_at が必要です",
+        "A Cancelled instance with current_step_id requires submitted_at.",
+    ),
+    (
+        "instance-edit-not-draft",
+        "下書き状態でのみ編集可能です",
+        "Only drafts can be edited.",
+    ),
+    (
+        "instance-delete-not-draft",
+        "下書き状態でのみ削除可能です",
+        "Only drafts can be deleted.",
+    ),
+    (
+        "instance-submit-not-draft",
+        "下書き状態でのみ申請可能です",
+        "Only drafts can be submitted.",
+    ),
+    (
+        "instance-cancel-completed",
+        "完了済みのワークフローは取り消せません",
+        "A completed workflow cannot be cancelled.",
+    ),
+    (
+        "instance-set-step-not-pending",
+        "ステップ設定は承認待ち状態でのみ可能です（現在: {status}）",
+        "Steps can only be set while the workflow is pending (current: {status}).",
+    ),
+    (
+        "instance-advance-not-in-progress",
+        "次ステップ遷移は処理中状態でのみ可能です（現在: {status}）",
+        "The workflow can only move to the next step while in progress (current: {status}).",
+    ),
+    (
+        "instance-retract-not-in-progress",
+        "承認の取り消しは処理中状態でのみ可能です（現在: {status}）",
+        "Approvals can only be retracted while the workflow is in progress (current: {status}).",
+    ),
+    (
+        "instance-edit-form-not-in-progress",
+        "フォームデータの編集は処理中状態でのみ可能です（現在: {status}）",
+        "Form data can only be edited while the workflow is in progress (current: {status}).",
+    ),
+    (
+        "instance-fields-not-editable",
+        "このステップでは編集できないフィールドが含まれています: {fields}",
+        "These fields cannot be edited in this step: {fields}",
+    ),
+    (
+        "instance-approve-not-in-progress",
+        "承認完了は処理中状態でのみ可能です（現在: {status}）",
+        "The workflow can only be approved while in progress (current: {status}).",
+    ),
+    (
+        "instance-request-changes-not-in-progress",
+        "差し戻しは処理中状態でのみ可能です（現在: {status}）",
+        "Changes can only be requested while the workflow is in progress (current: {status}).",
+    ),
+    (
+        "instance-resubmit-not-changes-requested",
+        "再申請は要修正状態でのみ可能です（現在: {status}）",
+        "The workflow can only be resubmitted when changes are requested (current: {status}).",
+    ),
+    (
+        "instance-reject-not-in-progress",
+        "却下完了は処理中状態でのみ可能です（現在: {status}）",
+        "The workflow can only be rejected while in progress (current: {status}).",
+    ),
+    // --- ワークフローステップ ---
+    (
+        "invalid-workflow-step-status",
+        "不正なワークフローステップステータス: {value}",
+        "Invalid workflow step status: {value}",
+    ),
+    (
+        "invalid-step-decision",
+        "不正なステップ判断: {value}",
+        "Invalid step decision: {value}",
+    ),
+    (
+        "step-state-column-missing",
+        "{status} ステップには {column} が必要です",
+        "A {status} step requires {column}.",
+    ),
+    (
+        "step-complete-not-active",
+        "アクティブ状態でのみ完了できます",
+        "A step can only be completed while active.",
+    ),
+    (
+        "step-skip-not-pending",
+        "スキップは待機中状態でのみ可能です（現在: {status}）",
+        "Only pending steps can be skipped (current: {status}).",
+    ),
+    (
+        "step-approve-not-active",
+        "承認はアクティブ状態でのみ可能です（現在: {status}）",
+        "Only active steps can be approved (current: {status}).",
+    ),
+    (
+        "step-reject-not-active",
+        "却下はアクティブ状態でのみ可能です（現在: {status}）",
+        "Only active steps can be rejected (current: {status}).",
+    ),
+    (
+        "step-request-changes-not-active",
+        "差し戻しはアクティブ状態でのみ可能です（現在: {status}）",
+        "Changes can only be requested on active steps (current: {status}).",
+    ),
+    (
+        "step-retract-not-approved",
+        "承認の取り消しは承認済みのステップでのみ可能です",
+        "Only approved steps can have their approval retracted.",
+    ),
+    (
+        "step-deactivate-not-active",
+        "待機中に戻せるのはアクティブ状態のステップのみです（現在: {status}）",
+        "Only active steps can be returned to pending (current: {status}).",
+    ),
+    (
+        "step-reassign-not-active",
+        "担当者の変更はアクティブ状態でのみ可能です（現在: {status}）",
+        "Only active steps can be reassigned (current: {status}).",
+    ),
+    (
+        "step-reassign-same-user",
+        "現在の担当者と同じユーザーには変更できません",
+        "The step is already assigned to this user.",
+    ),
+    (
+        "step-abort-not-open",
+        "打ち切りは待機中またはアクティブ状態でのみ可能です（現在: {status}）",
+        "Only pending or active steps can be aborted (current: {status}).",
+    ),
+    // --- アクティビティ・イベント ---
+    (
+        "invalid-activity-type",
+        "不正なアクティビティ種別: {value}",
+        "Invalid activity type: {value}",
+    ),
+    (
+        "invalid-workflow-event-type",
+        "不正なワークフローイベント種別: {value}",
+        "Invalid workflow event type: {value}",
+    ),
+    // --- コメント ---
+    (
+        "comment-body-too-long",
+        "コメント本文は{max}文字以内で入力してください",
+        "Comment body must be at most {max} characters.",
+    ),
+    (
+        "comment-reply-to-reply",
+        "返信コメントにはさらに返信できません",
+        "You cannot reply to a reply.",
+    ),
+    (
+        "comment-reply-to-deleted",
+        "削除されたコメントには返信できません",
+        "You cannot reply to a deleted comment.",
+    ),
+    (
+        "comment-edit-deleted",
+        "削除されたコメントは編集できません",
+        "A deleted comment cannot be edited.",
+    ),
+    (
+        "comment-edit-window-expired",
+        "コメントは投稿から{minutes}分以内のみ編集できます",
+        "Comments can only be edited within {minutes} minutes of posting.",
+    ),
+    (
+        "comment-already-deleted",
+        "コメントは既に削除されています",
+        "The comment has already been deleted.",
+    ),
+    // --- スケジュール ---
+    (
+        "invalid-cron-field",
+        "cron 式の{field}が不正です: {value}",
+        "Invalid {field} in cron expression: {value}",
+    ),
+    (
+        "cron-field-count",
+        "cron 式は「分 時 日 月 曜日」の 5 フィールドで指定してください",
+        "A cron expression must have 5 fields: minute hour day month day-of-week.",
+    ),
+    (
+        "schedule-day-out-of-range",
+        "実行日は 1〜31 で指定してください",
+        "Specify a day between 1 and 31.",
+    ),
+    (
+        "schedule-time-out-of-range",
+        "実行時刻は 00:00〜23:59 で指定してください",
+        "Specify a time between 00:00 and 23:59.",
+    ),
+    (
+        "schedule-auto-submit-approvers-required",
+        "自動申請する場合は承認者を指定してください",
+        "Specify approvers to submit automatically.",
+    ),
+    (
+        "schedule-no-next-run",
+        "繰り返し設定に一致する実行日時がありません",
+        "No run time matches the recurrence.",
+    ),
+    // --- 共通（サービス） ---
+    (
+        "internal-error",
+        "内部エラーが発生しました",
+        "An internal error occurred.",
+    ),
+    ("text", "{text}", "{text}"),
+    // --- Core Service ---
+    (
+        "document-file-not-uploaded",
+        "S3 上にファイルが見つかりません。アップロードが完了していない可能性があります",
+        "The file was not found in storage. The upload may not have completed.",
+    ),
+    (
+        "webhook-not-found",
+        "Webhook が見つかりません",
+        "The webhook was not found.",
+    ),
+    (
+        "document-owner-required",
+        "folder_id、workflow_instance_id、comment_id のいずれかを指定してください",
+        "Specify one of folder_id, workflow_instance_id, or comment_id.",
+    ),
+    (
+        "document-owner-conflict",
+        "folder_id、workflow_instance_id、comment_id は同時に指定できません",
+        "Only one of folder_id, workflow_instance_id, and comment_id can be specified.",
+    ),
+    (
+        "must-be-positive-integer",
+        "{name} は正の整数である必要があります",
+        "{name} must be a positive integer.",
+    ),
+    (
+        "retract-forbidden",
+        "このステップの承認を取り消す権限がありません",
+        "You are not allowed to retract the approval of this step.",
+    ),
+    (
+        "step-action-forbidden",
+        "このステップを{action}する権限がありません",
+        "You are not allowed to {action} this step.",
+    ),
+    (
+        "task-access-forbidden",
+        "このタスクにアクセスする権限がありません",
+        "You are not allowed to access this task.",
+    ),
+    (
+        "document-delete-forbidden",
+        "このドキュメントを削除する権限がありません",
+        "You are not allowed to delete this document.",
+    ),
+    (
+        "email-already-used",
+        "このメールアドレスは既に使用されています",
+        "This email address is already in use.",
+    ),
+    (
+        "proxy-submit-forbidden",
+        "このユーザーの代理で申請する権限がありません",
+        "You are not allowed to submit on behalf of this user.",
+    ),
+    (
+        "role-in-use",
+        "このロールは {count} 人のユーザーに割り当てられているため削除できません",
+        "This role cannot be deleted because it is assigned to {count} users.",
+    ),
+    (
+        "comment-forbidden",
+        "このワークフローにコメントする権限がありません",
+        "You are not allowed to comment on this workflow.",
+    ),
+    (
+        "resubmit-forbidden",
+        "このワークフローを再申請する権限がありません",
+        "You are not allowed to resubmit this workflow.",
+    ),
+    (
+        "workflow-delete-forbidden",
+        "このワークフローを削除する権限がありません",
+        "You are not allowed to delete this workflow.",
+    ),
+    (
+        "definition-version-conflict",
+        "このワークフロー定義は既に更新されています。最新の状態を取得してください。",
+        "This workflow definition has already been updated. Reload the latest version.",
+    ),
+    (
+        "no-active-step",
+        "アクティブなステップがありません",
+        "There is no active step.",
+    ),
+    (
+        "instance-version-conflict",
+        "インスタンスは既に更新されています。最新の情報を取得してください。",
+        "The workflow has already been updated. Reload the latest version.",
+    ),
+    (
+        "invalid-cursor",
+        "カーソルの形式が不正です",
+        "The cursor format is invalid.",
+    ),
+    (
+        "keyword-too-long",
+        "キーワードは {max} 文字以内で指定してください",
+        "The keyword must be at most {max} characters.",
+    ),
+    (
+        "comment-not-found",
+        "コメントが見つかりません",
+        "The comment was not found.",
+    ),
+    (
+        "comment-attachment-forbidden",
+        "コメントにファイルを添付できるのは投稿者のみです",
+        "Only the author can attach files to a comment.",
+    ),
+    (
+        "system-role-not-deletable",
+        "システムロールは削除できません",
+        "System roles cannot be deleted.",
+    ),
+    (
+        "system-role-not-editable",
+        "システムロールは編集できません",
+        "System roles cannot be edited.",
+    ),
+    (
+        "workflow-schedule-not-found",
+        "スケジュールが見つかりません",
+        "The schedule was not found.",
+    ),
+    (
+        "step-not-found",
+        "ステップが見つかりません",
+        "The approval step was not found.",
+    ),
+    (
+        "step-version-conflict",
+        "ステップは既に更新されています。最新の情報を取得してください。",
+        "The step has already been updated. Reload the latest version.",
+    ),
+    (
+        "tenant-not-found",
+        "テナントが見つかりません",
+        "The tenant was not found.",
+    ),
+    (
+        "document-not-found",
+        "ドキュメントが見つかりません",
+        "The document was not found.",
+    ),
+    (
+        "definition-invalid",
+        "バリデーションエラー: {errors}",
+        "The workflow definition is invalid: {errors}",
+    ),
+    (
+        "folder-not-found",
+        "フォルダが見つかりません",
+        "The folder was not found.",
+    ),
+    (
+        "folder-move-to-self",
+        "フォルダを自分自身に移動することはできません",
+        "A folder cannot be moved into itself.",
+    ),
+    (
+        "folder-move-to-descendant",
+        "フォルダを自身の子孫に移動することはできません",
+        "A folder cannot be moved into its own subfolder.",
+    ),
+    (
+        "form-edit-only-on-approve",
+        "フォームデータの編集は承認時のみ可能です（{action}では指定できません）",
+        "Form data can only be edited when approving (it cannot be specified when you {action}).",
+    ),
+    (
+        "mentioned-user-not-found",
+        "メンションされたユーザーが見つかりません: {user}",
+        "Mentioned user was not found: {user}",
+    ),
+    (
+        "user-not-found",
+        "ユーザーが見つかりません",
+        "The user was not found.",
+    ),
+    (
+        "role-id-not-found",
+        "ロール ID '{role_id}' が見つかりません",
+        "Role ID '{role_id}' was not found.",
+    ),
+    (
+        "role-not-found",
+        "ロールが見つかりません",
+        "The role was not found.",
+    ),
+    (
+        "retract-next-step-not-active",
+        "ワークフローが次のステップで処理中でないため、承認を取り消せません",
+        "The approval cannot be retracted because the workflow is not in progress at the next step.",
+    ),
+    (
+        "workflow-instance-not-found",
+        "ワークフローインスタンスが見つかりません",
+        "The workflow was not found.",
+    ),
+    (
+        "bulk-too-many-steps",
+        "一度に処理できるステップは {max} 件までです",
+        "Up to {max} steps can be processed at once.",
+    ),
+    (
+        "attachment-delete-not-draft",
+        "下書き以外のワークフローの添付ファイルは削除できません",
+        "Attachments can only be deleted from draft workflows.",
+    ),
+    (
+        "submit-not-draft",
+        "下書き状態のワークフローのみ申請できます",
+        "Only draft workflows can be submitted.",
+    ),
+    (
+        "invalid-status-value",
+        "不正なステータス値です",
+        "The status value is invalid.",
+    ),
+    (
+        "unsupported-locale",
+        "不正なロケールです",
+        "The locale is not supported.",
+    ),
+    (
+        "invalid-notification-event-type",
+        "不正な通知イベント種別: {value}",
+        "Invalid notification event type: {value}",
+    ),
+    (
+        "invalid-notification-delivery",
+        "不正な配信方法: {value}",
+        "Invalid delivery method: {value}",
+    ),
+    (
+        "comment-delete-not-author",
+        "他のユーザーのコメントは削除できません",
+        "You cannot delete another user's comment.",
+    ),
+    (
+        "comment-edit-not-author",
+        "他のユーザーのコメントは編集できません",
+        "You cannot edit another user's comment.",
+    ),
+    (
+        "proxy-invalid-user",
+        "代理人には同じテナントの有効なユーザーを指定してください",
+        "Specify an active user in the same tenant as the proxy.",
+    ),
+    (
+        "proxy-approver-conflict",
+        "代理申請では申請者本人と代理人を承認者に指定できません",
+        "In a proxy submission, neither the applicant nor the proxy can be an approver.",
+    ),
+    (
+        "invalid-created-range",
+        "作成日時の範囲が不正です（from は to より前である必要があります）",
+        "The creation date range is invalid (from must be before to).",
+    ),
+    (
+        "definition-not-published",
+        "公開されていないワークフロー定義です",
+        "The workflow definition is not published.",
+    ),
+    (
+        "folder-name-duplicate",
+        "同名のフォルダが既に存在します",
+        "A folder with the same name already exists.",
+    ),
+    (
+        "role-name-duplicate",
+        "同名のロールが既に存在します",
+        "A role with the same name already exists.",
+    ),
+    (
+        "reassign-invalid-user",
+        "変更後の担当者はテナント内の有効なユーザーである必要があります",
+        "The new assignee must be an active user in the tenant.",
+    ),
+    (
+        "folder-has-children",
+        "子フォルダが存在するため削除できません",
+        "The folder cannot be deleted because it has subfolders.",
+    ),
+    (
+        "bulk-steps-required",
+        "対象のステップを 1 件以上指定してください",
+        "Specify at least one step.",
+    ),
+    (
+        "retract-window-expired",
+        "承認の取り消し期限（承認から {minutes} 分）を過ぎています",
+        "The retraction period ({minutes} minutes after approval) has passed.",
+    ),
+    (
+        "retract-not-approved",
+        "承認済みのステップのみ承認を取り消せます",
+        "Only approved steps can be retracted.",
+    ),
+    (
+        "schedule-approver-steps-mismatch",
+        "承認者のステップ ID が定義の承認ステップ（{step_ids}）と一致しません",
+        "The approver step IDs do not match the approval steps of the definition ({step_ids}).",
+    ),
+    (
+        "approver-step-id-mismatch",
+        "承認者のステップ ID({step_id})が定義のステップ ID({expected})と一致しません",
+        "The approver step ID ({step_id}) does not match the step ID in the definition ({expected}).",
+    ),
+    (
+        "approver-count-mismatch",
+        "承認者の数({count})が定義のステップ数({expected})と一致しません",
+        "The number of approvers ({count}) does not match the number of steps in the definition ({expected}).",
+    ),
+    (
+        "reassign-not-in-progress",
+        "担当者の変更は処理中のワークフローでのみ可能です（現在: {status}）",
+        "The assignee can only be changed while the workflow is in progress (current: {status}).",
+    ),
+    (
+        "default-watcher-invalid-user",
+        "既定ウォッチャーはテナント内の有効なユーザーである必要があります",
+        "Default watchers must be active users in the tenant.",
+    ),
+    (
+        "last-tenant-admin",
+        "最後のテナント管理者を無効化することはできません",
+        "The last tenant administrator cannot be deactivated.",
+    ),
+    (
+        "permissions-required",
+        "権限を1つ以上指定してください",
+        "Specify at least one permission.",
+    ),
+    (
+        "retract-next-step-processed",
+        "次のステップが既に処理されているため、承認を取り消せません",
+        "The approval cannot be retracted because the next step has already been processed.",
+    ),
+    (
+        "round-order-invalid",
+        "比較元のラウンドは比較先のラウンドより前を指定してください",
+        "The base round must be earlier than the target round.",
+    ),
+    (
+        "submission-round-number-not-found",
+        "申請ラウンド {round} が見つかりません",
+        "Submission round {round} was not found.",
+    ),
+    (
+        "submission-round-not-found",
+        "申請ラウンドが見つかりません",
+        "The submission round was not found.",
+    ),
+    (
+        "folder-move-too-deep",
+        "移動先ではサブツリーの階層が上限（{max} 階層）を超えます",
+        "Moving here would exceed the maximum folder depth ({max} levels).",
+    ),
+    (
+        "destination-folder-not-found",
+        "移動先の親フォルダが見つかりません",
+        "The destination parent folder was not found.",
+    ),
+    (
+        "proxy-self",
+        "自分自身を代理人に指定することはできません",
+        "You cannot designate yourself as a proxy.",
+    ),
+    (
+        "deactivate-self",
+        "自分自身を無効化することはできません",
+        "You cannot deactivate yourself.",
+    ),
+    (
+        "resubmit-not-changes-requested",
+        "要修正状態のワークフローのみ再申請できます",
+        "Only workflows returned for changes can be resubmitted.",
+    ),
+    (
+        "parent-folder-not-found",
+        "親フォルダが見つかりません",
+        "The parent folder was not found.",
+    ),
+    (
+        "notification-not-found",
+        "通知が見つかりません",
+        "The notification was not found.",
+    ),
+    (
+        "notification-event-type-duplicate",
+        "通知イベント種別が重複しています: {value}",
+        "Duplicate notification event type: {value}",
+    ),
+    (
+        "notification-template-invalid",
+        "通知テンプレートが不正です: {errors}",
+        "The notification template is invalid: {errors}",
+    ),
+    (
+        "notification-template-not-found",
+        "通知テンプレートが見つかりません",
+        "The notification template was not found.",
+    ),
+    (
+        "task-not-found",
+        "タスクが見つかりません",
+        "The task was not found.",
+    ),
+    (
+        "workflow-not-found",
+        "ワークフローが見つかりません",
+        "The workflow was not found.",
+    ),
+    (
+        "workflow-definition-not-found",
+        "ワークフロー定義が見つかりません",
+        "The workflow definition was not found.",
+    ),
+    (
+        "invalid-rfc3339",
+        "{name} は RFC 3339 形式で指定してください",
+        "{name} must be in RFC 3339 format.",
+    ),
+    (
+        "invalid-parameter",
+        "不正な {name}: {value}",
+        "Invalid {name}: {value}",
+    ),
+    (
+        "invalid-version",
+        "不正なバージョン: {value}",
+        "Invalid version: {value}",
+    ),
+    (
+        "webhook-destination-forbidden",
+        "許可されていない送信先です: {reason}",
+        "The webhook destination is not allowed: {reason}",
+    ),
+    (
+        "webhook-destination-unreachable",
+        "送信先に接続できません: {reason}",
+        "Cannot connect to the webhook destination: {reason}",
+    ),
+    (
+        "notification-preview-failed",
+        "{reason}",
+        "Failed to render the preview: {reason}",
+    ),
+    // --- BFF / Auth Service ---
+    (
+        "authentication-required",
+        "認証が必要です",
+        "Authentication is required.",
+    ),
+    (
+        "authentication-failed",
+        "メールアドレスまたはパスワードが正しくありません",
+        "The email address or password is incorrect.",
+    ),
+    (
+        "authentication-failed-generic",
+        "認証に失敗しました",
+        "Authentication failed.",
+    ),
+    (
+        "credential-inactive",
+        "認証情報が無効です",
+        "The credential is inactive.",
+    ),
+    (
+        "auth-service-unavailable",
+        "認証サービスが一時的に利用できません",
+        "The authentication service is temporarily unavailable.",
+    ),
+    (
+        "permission-denied",
+        "この操作を実行する権限がありません",
+        "You do not have permission to perform this operation.",
+    ),
+    (
+        "tenant-id-header-required",
+        "X-Tenant-ID ヘッダーが必要です",
+        "The X-Tenant-ID header is required.",
+    ),
+    (
+        "tenant-id-header-invalid",
+        "X-Tenant-ID の形式が不正です",
+        "The X-Tenant-ID header format is invalid.",
+    ),
+    (
+        "csrf-tenant-id-required",
+        "テナント ID が必要です",
+        "A tenant ID is required.",
+    ),
+    (
+        "csrf-session-required",
+        "セッションが必要です",
+        "A session is required.",
+    ),
+    (
+        "csrf-token-required",
+        "CSRF トークンが必要です",
+        "A CSRF token is required.",
+    ),
+    (
+        "csrf-token-invalid",
+        "CSRF トークンが無効です",
+        "The CSRF token is invalid.",
+    ),
+    (
+        "invalid-decision-action",
+        "action は approve または reject である必要があります",
+        "action must be approve or reject.",
+    ),
+    (
+        "must-be-at-least-one",
+        "{name} は 1 以上である必要があります",
+        "{name} must be 1 or greater.",
+    ),
+    (
+        "invalid-format",
+        "{name} の形式が不正です",
+        "{name} has an invalid format.",
+    ),
+];
+
+/// フィールド名（フィールドコード, 日本語, 英語）
+const FIELDS: &[(&str, &str, &str)] = &[
+    ("email", "メールアドレス", "Email address"),
+    ("user-name", "ユーザー名", "User name"),
+    ("tenant-name", "テナント名", "Tenant name"),
+    ("workflow-name", "ワークフロー名", "Workflow name"),
+    ("title", "タイトル", "Title"),
+    ("comment-body", "コメント本文", "Comment body"),
+    ("reason", "理由", "Reason"),
+    ("webhook-name", "Webhook 名", "Webhook name"),
+    ("schedule-name", "スケジュール名", "Schedule name"),
+    (
+        "notification-subject-template",
+        "件名テンプレート",
+        "Subject template",
+    ),
+    (
+        "notification-html-template",
+        "HTML 本文テンプレート",
+        "HTML body template",
+    ),
+    (
+        "notification-text-template",
+        "テキスト本文テンプレート",
+        "Text body template",
+    ),
+    ("cron-minute", "分", "minute"),
+    ("cron-hour", "時", "hour"),
+    ("cron-day-of-month", "日", "day of month"),
+    ("cron-month", "月", "month"),
+    ("cron-day-of-week", "曜日", "day of week"),
+];
+
+/// 操作名（アクションコード, 日本語, 英語）
+const ACTIONS: &[(&str, &str, &str)] = &[
+    ("approve", "承認", "approve"),
+    ("reject", "却下", "reject"),
+    ("request-changes", "差し戻し", "request changes on"),
+];
+
+/// メッセージコードに対応する `locale` の文言を返す
+pub(super) fn message(code: &str, locale: Locale) -> Option<&'static str> {
+    lookup(MESSAGES, code, locale)
+}
+
+/// パラメータ `param` の値 `code` に対応する `locale` の名前を返す
+///
+/// `field` はフィールド名、`action` は操作名に置き換える。それ以外のパラメータは `None`。
+pub(super) fn name(param: &str, code: &str, locale: Locale) -> Option<&'static str> {
+    let table = match param {
+        "field" => FIELDS,
+        "action" => ACTIONS,
+        _ => return None,
+    };
+    lookup(table, code, locale)
+}
+
+fn lookup(
+    table: &'static [(&'static str, &'static str, &'static str)],
+    code: &str,
+    locale: Locale,
+) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(key, _, _)| *key == code)
+        .map(|(_, ja, en)| match locale {
+            Locale::Ja => *ja,
+            Locale::En => *en,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// 文言中の `{name}` の名前を集める
+    fn placeholders(template: &str) -> BTreeSet<&str> {
+        template
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
+    #[test]
+    fn test_メッセージコードと名前のコードは重複しない() {
+        for table in [MESSAGES, FIELDS, ACTIONS] {
+            let mut codes = HashSet::new();
+            for (code, _, _) in table {
+                assert!(codes.insert(code), "コードが重複しています: {code}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_日本語と英語の文言は同じパラメータを使う() {
+        for (code, ja, en) in MESSAGES {
+            assert_eq!(placeholders(ja), placeholders(en), "{code}");
+        }
+    }
+}
@@ -1,27 +1,62 @@
 //! # 通知
 //!
-//! メール通知に関するドメインモデルを定義する。
+//! メール通知およびアプリ内通知（受信箱）に関するドメインモデルを定義する。
 //!
 //! ## ドメイン用語
 //!
 //! | 型 | ドメイン用語 | 要件 |
 //! |---|------------|------|
 //! | [`WorkflowNotification`] | ワークフロー通知イベント | NOTIFY-001: メール通知基盤 |
-//! | [`NotificationEventType`] | 通知イベント種別 | 5 種類: 承認依頼、ステップ承認、承認完了、却下、差し戻し |
+//! | [`NotificationEventType`] | 通知イベント種別 | 10 種類: 承認依頼、ステップ承認、承認完了、却下、差し戻し、定期作成、代理申請、承認取り消し、メンション、取消 |
+//! | [`InboxNotification`] | アプリ内通知 | ヘッダーのベルに表示する、ユーザーごとの通知受信箱の 1 件 |
+//! | [`NotificationDelivery`] | 配信方法 | 即時・ダイジェスト・停止 |
+//! | [`NotificationPreference`] | 通知設定 | ユーザーが通知イベント種別ごとに選ぶメールの配信方法 |
+//! | [`NotificationDigestItem`] | ダイジェスト待ち通知 | ダイジェストメールでまとめて送信するまで保留している通知 |
+//! | [`NotificationTemplate`] | 通知テンプレート | テナントが通知イベント種別ごとに上書きするメールの件名・本文のテンプレート |
+//! | [`NotificationLog`] | 通知ログ | 1 受信者 × 1 通知のメール送信記録（送信ログ兼リトライキュー） |
 //!
 //! ## 設計方針
 //!
 //! - **enum による通知イベント**: 各バリアントが機能仕様書の通知イベントに対応
 //! - **fire-and-forget**: 通知送信の失敗はワークフロー操作に影響しない
 //! - **テンプレート分離**: 通知イベントとメール生成は分離（TemplateRenderer は core-service）
+//! - **受信箱はメールと独立**: アプリ内通知はメール送信の成否に関係なく記録する
+//! - **通知設定はメールのみに適用**: 配信方法が停止・ダイジェストでもアプリ内通知は即時に記録する
+//! - **テンプレートの上書きは任意**: 通知テンプレートのない通知イベント種別は組み込みテンプレートで送信する
+//! - **受信者のロケール**: アプリ内通知・ダイジェスト待ち通知の要約は作成時点の受信者のロケールで保存する
+//!
+//! ## リトライ
+//!
+//! 即時送信のメールは生成した本文を通知ログに保存し、一時的なエラーで送信に失敗した場合は
+//! 指数バックオフ（1 分 × 2^(n-1)、上限 6 時間）で再送する。恒久的なエラー
+//! （[`NotificationError::is_permanent`]）または [`MAX_NOTIFICATION_ATTEMPTS`] 回の失敗で
+//! `failed` となり、以降は管理者が再送を指示するまで送信しない。
+//! ダイジェストメールは本文を保存せず、再送しない。
 //!
 //! → 詳細設計: `docs/40_詳細設計書/16_通知機能設計.md`
 
+use chrono::{DateTime, TimeDelta, Utc};
 use serde::{Deserialize, Serialize};
 use strum::IntoStaticStr;
 use thiserror::Error;
 
-use crate::user::UserId;
+use crate::{
+    DomainError,
+    locale::Locale,
+    message::Message,
+    tenant::TenantId,
+    user::UserId,
+    workflow::WorkflowInstanceId,
+};
+
+/// 通知メールの最大試行回数
+pub const MAX_NOTIFICATION_ATTEMPTS: i32 = 8;
+
+/// リトライ間隔の初期値（秒）
+const RETRY_BASE_SECONDS: i64 = 60;
+
+/// リトライ間隔の上限（秒）
+const RETRY_MAX_SECONDS: i64 = 6 * 60 * 60;
 
 define_uuid_id! {
     /// 通知ログ ID（一意識別子）
@@ -30,13 +65,55 @@ define_uuid_id! {
     pub struct NotificationLogId;
 }
 
+define_uuid_id! {
+    /// アプリ内通知 ID
+    ///
+    /// inbox_notifications テーブルの主キー。UUID v7 を使用。
+    pub struct InboxNotificationId;
+}
+
+define_uuid_id! {
+    /// ダイジェスト待ち通知 ID
+    ///
+    /// notification_digest_items テーブルの主キー。UUID v7 を使用。
+    pub struct NotificationDigestItemId;
+}
+
+define_validated_string! {
+    /// 件名テンプレート（tera 構文）
+    pub struct NotificationSubjectTemplate {
+        field: "notification-subject-template",
+        max_length: 255,
+    }
+}
+
+define_validated_string! {
+    /// HTML 本文テンプレート（tera 構文）
+    pub struct NotificationHtmlTemplate {
+        field: "notification-html-template",
+        max_length: 100_000,
+    }
+}
+
+define_validated_string! {
+    /// テキスト本文テンプレート（tera 構文）
+    pub struct NotificationTextTemplate {
+        field: "notification-text-template",
+        max_length: 100_000,
+    }
+}
+
 /// 通知送信エラー
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum NotificationError {
-    /// メール送信に失敗
+    /// メール送信に失敗（一時的なエラー。再送すると成功する可能性がある）
     #[error("メール送信に失敗: {0}")]
     SendFailed(String),
 
+    /// メール送信に失敗（恒久的なエラー。宛先不正・送信拒否など、再送しても成功しない）
+    #[error("メール送信に失敗（再送不可）: {0}")]
+    PermanentSendFailure(String),
+
     /// テンプレートレンダリングに失敗
     #[error("テンプレートレンダリングに失敗: {0}")]
     TemplateFailed(String),
@@ -46,6 +123,13 @@ pub enum NotificationError {
     LogFailed(String),
 }
 
+impl NotificationError {
+    /// 再送しても成功しない恒久的なエラーか
+    pub fn is_permanent(&self) -> bool {
+        matches!(self, Self::PermanentSendFailure(_))
+    }
+}
+
 /// 通知イベント種別
 ///
 /// notification_logs テーブルの `event_type` カラムに格納される値。
@@ -61,19 +145,93 @@ pub enum NotificationError {
     IntoStaticStr,
     strum::Display,
     strum::EnumString,
+    strum::VariantArray,
 )]
 #[strum(serialize_all = "snake_case")]
 pub enum NotificationEventType {
     /// 承認依頼: ステップが active になったとき → 承認者に送信
     ApprovalRequest,
-    /// ステップ承認（中間）: 多段階承認の中間ステップ承認 → 申請者に送信
+    /// ステップ承認（中間）: 多段階承認の中間ステップ承認 → 申請者・ウォッチャーに送信
     StepApproved,
-    /// 承認完了: 最終ステップ承認でインスタンスが Approved → 申請者に送信
+    /// 承認完了: 最終ステップ承認でインスタンスが Approved → 申請者・ウォッチャーに送信
     Approved,
-    /// 却下: ステップ却下でインスタンスが Rejected → 申請者に送信
+    /// 却下: ステップ却下でインスタンスが Rejected → 申請者・ウォッチャーに送信
     Rejected,
-    /// 差し戻し: ステップ差し戻しでインスタンスが ChangesRequested → 申請者に送信
+    /// 差し戻し: ステップ差し戻しでインスタンスが ChangesRequested → 申請者・ウォッチャーに送信
     ChangesRequested,
+    /// 定期作成: スケジュールによりワークフローが作成されたとき → スケジュールの所有者に送信
+    ScheduledWorkflowCreated,
+    /// 代理申請: 代理人が本人に代わって申請・再申請したとき → 本人に送信
+    ProxySubmitted,
+    /// 承認取り消し: 承認者が承認を取り消したとき → 申請者・ウォッチャー・待機中に戻ったステップの承認者に送信
+    ApprovalRetracted,
+    /// メンション: コメントでメンションされたとき → メンションされたユーザーに送信
+    Mentioned,
+    /// 取消: 管理者がワークフローを強制的に取り消したとき → 申請者・ウォッチャー・アクティブだったステップの承認者に送信
+    Cancelled,
+}
+
+impl NotificationEventType {
+    /// 全通知イベント種別を定義順に返す
+    pub fn all() -> &'static [NotificationEventType] {
+        <Self as strum::VariantArray>::VARIANTS
+    }
+}
+
+/// 通知メールの配信方法
+///
+/// notification_preferences テーブルの `delivery` カラムに格納される値。
+/// 設定のない通知イベント種別は [`NotificationDelivery::Immediate`] として扱う。
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    IntoStaticStr,
+    strum::Display,
+    strum::EnumString,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum NotificationDelivery {
+    /// 即時: 通知イベントごとにメールを送信する
+    #[default]
+    Immediate,
+    /// ダイジェスト: 保留した通知をダイジェストジョブが 1 通のメールにまとめて送信する
+    Digest,
+    /// 停止: メールを送信しない
+    Off,
+}
+
+/// 通知設定
+///
+/// ユーザーが通知イベント種別ごとに選んだメールの配信方法。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationPreference {
+    pub event_type: NotificationEventType,
+    pub delivery:   NotificationDelivery,
+}
+
+impl NotificationPreference {
+    /// 保存済みの設定を全通知イベント種別に展開する
+    ///
+    /// 設定のない種別は既定の配信方法とする。並び順は [`NotificationEventType`] の定義順。
+    pub fn resolve_all(saved: &[NotificationPreference]) -> Vec<NotificationPreference> {
+        NotificationEventType::all()
+            .iter()
+            .map(|&event_type| NotificationPreference {
+                event_type,
+                delivery: saved
+                    .iter()
+                    .find(|p| p.event_type == event_type)
+                    .map(|p| p.delivery)
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
 }
 
 /// メールメッセージ
@@ -93,7 +251,7 @@ pub struct EmailMessage {
 
 /// ワークフロー通知イベント
 ///
-/// 各バリアントが機能仕様書の通知イベント（5 種類）に対応する。
+/// 各バリアントが機能仕様書の通知イベント（10 種類）に対応する。
 /// → 機能仕様書: `docs/20_機能仕様書/05_通知機能.md`
 #[derive(Debug, Clone)]
 pub enum WorkflowNotification {
@@ -106,37 +264,83 @@ pub enum WorkflowNotification {
         approver_email:      String,
         approver_user_id:    UserId,
     },
-    /// ステップ承認（中間）: 多段階承認の中間ステップ承認 → 申請者に送信
+    /// ステップ承認（中間）: 多段階承認の中間ステップ承認 → 申請者・ウォッチャーに送信
     StepApproved {
         workflow_title:      String,
         workflow_display_id: String,
         step_name:           String,
         approver_name:       String,
-        applicant_email:     String,
-        applicant_user_id:   UserId,
+        recipient_email:     String,
+        recipient_user_id:   UserId,
     },
-    /// 承認完了: 最終ステップ承認でインスタンスが Approved → 申請者に送信
+    /// 承認完了: 最終ステップ承認でインスタンスが Approved → 申請者・ウォッチャーに送信
     Approved {
         workflow_title:      String,
         workflow_display_id: String,
-        applicant_email:     String,
-        applicant_user_id:   UserId,
+        recipient_email:     String,
+        recipient_user_id:   UserId,
     },
-    /// 却下: ステップ却下でインスタンスが Rejected → 申請者に送信
+    /// 却下: ステップ却下でインスタンスが Rejected → 申請者・ウォッチャーに送信
     Rejected {
         workflow_title: String,
         workflow_display_id: String,
         comment: Option<String>,
-        applicant_email: String,
-        applicant_user_id: UserId,
+        recipient_email: String,
+        recipient_user_id: UserId,
     },
-    /// 差し戻し: ステップ差し戻しでインスタンスが ChangesRequested → 申請者に送信
+    /// 差し戻し: ステップ差し戻しでインスタンスが ChangesRequested → 申請者・ウォッチャーに送信
     ChangesRequested {
         workflow_title: String,
         workflow_display_id: String,
         comment: Option<String>,
-        applicant_email: String,
-        applicant_user_id: UserId,
+        recipient_email: String,
+        recipient_user_id: UserId,
+    },
+    /// 定期作成: スケジュールによりワークフローが作成されたとき → スケジュールの所有者に送信
+    ///
+    /// 自動申請に失敗した場合も下書きは残るため、失敗理由を添えて通知する。
+    /// 失敗理由はメール生成時に受信者のロケールで展開する。
+    ScheduledWorkflowCreated {
+        workflow_title:      String,
+        workflow_display_id: String,
+        schedule_name:       String,
+        submitted:           bool,
+        submit_error:        Option<Message>,
+        recipient_email:     String,
+        recipient_user_id:   UserId,
+    },
+    /// 代理申請: 代理人が本人に代わって申請・再申請したとき → 本人に送信
+    ProxySubmitted {
+        workflow_title:      String,
+        workflow_display_id: String,
+        proxy_name:          String,
+        recipient_email:     String,
+        recipient_user_id:   UserId,
+    },
+    /// 承認取り消し: 承認者が承認を取り消したとき → 申請者・ウォッチャー・待機中に戻ったステップの承認者に送信
+    ApprovalRetracted {
+        workflow_title:      String,
+        workflow_display_id: String,
+        step_name:           String,
+        approver_name:       String,
+        recipient_email:     String,
+        recipient_user_id:   UserId,
+    },
+    /// メンション: コメントでメンションされたとき → メンションされたユーザーに送信
+    Mentioned {
+        workflow_title: String,
+        workflow_display_id: String,
+        mentioned_by_name: String,
+        comment: String,
+        recipient_email: String,
+        recipient_user_id: UserId,
+    },
+    /// 取消: 管理者がワークフローを強制的に取り消したとき → 申請者・ウォッチャー・アクティブだったステップの承認者に送信
+    Cancelled {
+        workflow_title:      String,
+        workflow_display_id: String,
+        recipient_email:     String,
+        recipient_user_id:   UserId,
     },
 }
 
@@ -149,6 +353,13 @@ impl WorkflowNotification {
             Self::Approved { .. } => NotificationEventType::Approved,
             Self::Rejected { .. } => NotificationEventType::Rejected,
             Self::ChangesRequested { .. } => NotificationEventType::ChangesRequested,
+            Self::ScheduledWorkflowCreated { .. } => {
+                NotificationEventType::ScheduledWorkflowCreated
+            }
+            Self::ProxySubmitted { .. } => NotificationEventType::ProxySubmitted,
+            Self::ApprovalRetracted { .. } => NotificationEventType::ApprovalRetracted,
+            Self::Mentioned { .. } => NotificationEventType::Mentioned,
+            Self::Cancelled { .. } => NotificationEventType::Cancelled,
         }
     }
 
@@ -157,17 +368,32 @@ impl WorkflowNotification {
         match self {
             Self::ApprovalRequest { approver_email, .. } => approver_email,
             Self::StepApproved {
-                applicant_email, ..
+                recipient_email, ..
             }
             | Self::Approved {
-                applicant_email, ..
+                recipient_email, ..
             }
             | Self::Rejected {
-                applicant_email, ..
+                recipient_email, ..
             }
             | Self::ChangesRequested {
-                applicant_email, ..
-            } => applicant_email,
+                recipient_email, ..
+            }
+            | Self::ScheduledWorkflowCreated {
+                recipient_email, ..
+            }
+            | Self::ProxySubmitted {
+                recipient_email, ..
+            }
+            | Self::ApprovalRetracted {
+                recipient_email, ..
+            }
+            | Self::Mentioned {
+                recipient_email, ..
+            }
+            | Self::Cancelled {
+                recipient_email, ..
+            } => recipient_email,
         }
     }
 
@@ -178,17 +404,32 @@ impl WorkflowNotification {
                 approver_user_id, ..
             } => approver_user_id,
             Self::StepApproved {
-                applicant_user_id, ..
+                recipient_user_id, ..
             }
             | Self::Approved {
-                applicant_user_id, ..
+                recipient_user_id, ..
             }
             | Self::Rejected {
-                applicant_user_id, ..
+                recipient_user_id, ..
             }
             | Self::ChangesRequested {
-                applicant_user_id, ..
-            } => applicant_user_id,
+                recipient_user_id, ..
+            }
+            | Self::ScheduledWorkflowCreated {
+                recipient_user_id, ..
+            }
+            | Self::ProxySubmitted {
+                recipient_user_id, ..
+            }
+            | Self::ApprovalRetracted {
+                recipient_user_id, ..
+            }
+            | Self::Mentioned {
+                recipient_user_id, ..
+            }
+            | Self::Cancelled {
+                recipient_user_id, ..
+            } => recipient_user_id,
         }
     }
 
@@ -199,7 +440,12 @@ impl WorkflowNotification {
             | Self::StepApproved { workflow_title, .. }
             | Self::Approved { workflow_title, .. }
             | Self::Rejected { workflow_title, .. }
-            | Self::ChangesRequested { workflow_title, .. } => workflow_title,
+            | Self::ChangesRequested { workflow_title, .. }
+            | Self::ScheduledWorkflowCreated { workflow_title, .. }
+            | Self::ProxySubmitted { workflow_title, .. }
+            | Self::ApprovalRetracted { workflow_title, .. }
+            | Self::Mentioned { workflow_title, .. }
+            | Self::Cancelled { workflow_title, .. } => workflow_title,
         }
     }
 
@@ -225,9 +471,811 @@ impl WorkflowNotification {
             | Self::ChangesRequested {
                 workflow_display_id,
                 ..
+            }
+            | Self::ScheduledWorkflowCreated {
+                workflow_display_id,
+                ..
+            }
+            | Self::ProxySubmitted {
+                workflow_display_id,
+                ..
+            }
+            | Self::ApprovalRetracted {
+                workflow_display_id,
+                ..
+            }
+            | Self::Mentioned {
+                workflow_display_id,
+                ..
+            }
+            | Self::Cancelled {
+                workflow_display_id,
+                ..
             } => workflow_display_id,
         }
     }
+
+    /// アプリ内通知に表示する 1 行の要約を受信者のロケールで返す
+    ///
+    /// ワークフローのタイトル・表示 ID は別カラムで保持するため、要約には含めない。
+    pub fn summary(&self, locale: Locale) -> String {
+        match locale {
+            Locale::Ja => self.summary_ja(),
+            Locale::En => self.summary_en(),
+        }
+    }
+
+    fn summary_ja(&self) -> String {
+        match self {
+            Self::ApprovalRequest {
+                applicant_name,
+                step_name,
+                ..
+            } => format!("{applicant_name} さんから「{step_name}」の承認依頼が届きました"),
+            Self::StepApproved {
+                step_name,
+                approver_name,
+                ..
+            } => format!("{approver_name} さんが「{step_name}」を承認しました"),
+            Self::Approved { .. } => "申請が承認されました".to_string(),
+            Self::Rejected { .. } => "申請が却下されました".to_string(),
+            Self::ChangesRequested { .. } => "申請が差し戻されました".to_string(),
+            Self::ScheduledWorkflowCreated {
+                schedule_name,
+                submitted,
+                ..
+            } => {
+                if *submitted {
+                    format!("スケジュール「{schedule_name}」により申請されました")
+                } else {
+                    format!("スケジュール「{schedule_name}」により下書きが作成されました")
+                }
+            }
+            Self::ProxySubmitted { proxy_name, .. } => {
+                format!("{proxy_name} さんがあなたの代理で申請しました")
+            }
+            Self::ApprovalRetracted {
+                step_name,
+                approver_name,
+                ..
+            } => format!("{approver_name} さんが「{step_name}」の承認を取り消しました"),
+            Self::Mentioned {
+                mentioned_by_name, ..
+            } => format!("{mentioned_by_name} さんがコメントであなたをメンションしました"),
+            Self::Cancelled { .. } => "申請が管理者により取り消されました".to_string(),
+        }
+    }
+
+    fn summary_en(&self) -> String {
+        match self {
+            Self::ApprovalRequest {
+                applicant_name,
+                step_name,
+                ..
+            } => format!("{applicant_name} requested your approval for \"{step_name}\""),
+            Self::StepApproved {
+                step_name,
+                approver_name,
+                ..
+            } => format!("{approver_name} approved \"{step_name}\""),
+            Self::Approved { .. } => "Your request has been approved".to_string(),
+            Self::Rejected { .. } => "Your request has been rejected".to_string(),
+            Self::ChangesRequested { .. } => {
+                "Changes have been requested on your request".to_string()
+            }
+            Self::ScheduledWorkflowCreated {
+                schedule_name,
+                submitted,
+                ..
+            } => {
+                if *submitted {
+                    format!("Submitted by the schedule \"{schedule_name}\"")
+                } else {
+                    format!("Draft created by the schedule \"{schedule_name}\"")
+                }
+            }
+            Self::ProxySubmitted { proxy_name, .. } => {
+                format!("{proxy_name} submitted a request on your behalf")
+            }
+            Self::ApprovalRetracted {
+                step_name,
+                approver_name,
+                ..
+            } => format!("{approver_name} retracted the approval of \"{step_name}\""),
+            Self::Mentioned {
+                mentioned_by_name, ..
+            } => format!("{mentioned_by_name} mentioned you in a comment"),
+            Self::Cancelled { .. } => {
+                "Your request has been cancelled by an administrator".to_string()
+            }
+        }
+    }
+}
+
+/// アプリ内通知エンティティ
+///
+/// ユーザーごとの通知受信箱の 1 件。作成後に変化するのは既読日時のみ。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InboxNotification {
+    id: InboxNotificationId,
+    tenant_id: TenantId,
+    user_id: UserId,
+    event_type: NotificationEventType,
+    workflow_instance_id: WorkflowInstanceId,
+    workflow_title: String,
+    workflow_display_id: String,
+    message: String,
+    read_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+/// アプリ内通知の DB 復元パラメータ
+pub struct InboxNotificationRecord {
+    pub id: InboxNotificationId,
+    pub tenant_id: TenantId,
+    pub user_id: UserId,
+    pub event_type: NotificationEventType,
+    pub workflow_instance_id: WorkflowInstanceId,
+    pub workflow_title: String,
+    pub workflow_display_id: String,
+    pub message: String,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl InboxNotification {
+    /// ワークフロー通知イベントから未読のアプリ内通知を作成する
+    ///
+    /// 受信者は通知イベントの受信者（[`WorkflowNotification::recipient_user_id`]）となる。
+    /// 要約は受信者のロケール（`locale`）で作成する。
+    pub fn from_notification(
+        id: InboxNotificationId,
+        tenant_id: TenantId,
+        workflow_instance_id: WorkflowInstanceId,
+        notification: &WorkflowNotification,
+        locale: Locale,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            user_id: notification.recipient_user_id().clone(),
+            event_type: notification.event_type(),
+            workflow_instance_id,
+            workflow_title: notification.workflow_title().to_string(),
+            workflow_display_id: notification.workflow_display_id().to_string(),
+            message: notification.summary(locale),
+            read_at: None,
+            created_at: now,
+        }
+    }
+
+    /// 既存のデータから復元する
+    pub fn from_db(record: InboxNotificationRecord) -> Self {
+        Self {
+            id: record.id,
+            tenant_id: record.tenant_id,
+            user_id: record.user_id,
+            event_type: record.event_type,
+            workflow_instance_id: record.workflow_instance_id,
+            workflow_title: record.workflow_title,
+            workflow_display_id: record.workflow_display_id,
+            message: record.message,
+            read_at: record.read_at,
+            created_at: record.created_at,
+        }
+    }
+
+    /// 既読かどうか
+    pub fn is_read(&self) -> bool {
+        self.read_at.is_some()
+    }
+
+    /// 既読にした新しいインスタンスを返す
+    ///
+    /// 既読済みの場合は既読日時を変更しない。
+    pub fn mark_read(self, now: DateTime<Utc>) -> Self {
+        Self {
+            read_at: self.read_at.or(Some(now)),
+            ..self
+        }
+    }
+
+    // Getter メソッド
+
+    pub fn id(&self) -> &InboxNotificationId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn event_type(&self) -> NotificationEventType {
+        self.event_type
+    }
+
+    pub fn workflow_instance_id(&self) -> &WorkflowInstanceId {
+        &self.workflow_instance_id
+    }
+
+    pub fn workflow_title(&self) -> &str {
+        &self.workflow_title
+    }
+
+    pub fn workflow_display_id(&self) -> &str {
+        &self.workflow_display_id
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn read_at(&self) -> Option<DateTime<Utc>> {
+        self.read_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+/// ダイジェスト待ち通知エンティティ
+///
+/// 配信方法がダイジェストの通知を、ダイジェストメールで送信するまで保留する。
+/// 送信後は削除されるため、作成後に変化しない。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationDigestItem {
+    id: NotificationDigestItemId,
+    tenant_id: TenantId,
+    user_id: UserId,
+    recipient_email: String,
+    event_type: NotificationEventType,
+    workflow_instance_id: WorkflowInstanceId,
+    workflow_title: String,
+    workflow_display_id: String,
+    message: String,
+    created_at: DateTime<Utc>,
+}
+
+/// ダイジェスト待ち通知の DB 復元パラメータ
+pub struct NotificationDigestItemRecord {
+    pub id: NotificationDigestItemId,
+    pub tenant_id: TenantId,
+    pub user_id: UserId,
+    pub recipient_email: String,
+    pub event_type: NotificationEventType,
+    pub workflow_instance_id: WorkflowInstanceId,
+    pub workflow_title: String,
+    pub workflow_display_id: String,
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl NotificationDigestItem {
+    /// ワークフロー通知イベントからダイジェスト待ち通知を作成する
+    ///
+    /// メールの本文には受信者のロケール（`locale`）で作成した通知の要約
+    /// （[`WorkflowNotification::summary`]）を使う。
+    pub fn from_notification(
+        id: NotificationDigestItemId,
+        tenant_id: TenantId,
+        workflow_instance_id: WorkflowInstanceId,
+        notification: &WorkflowNotification,
+        locale: Locale,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            tenant_id,
+            user_id: notification.recipient_user_id().clone(),
+            recipient_email: notification.recipient_email().to_string(),
+            event_type: notification.event_type(),
+            workflow_instance_id,
+            workflow_title: notification.workflow_title().to_string(),
+            workflow_display_id: notification.workflow_display_id().to_string(),
+            message: notification.summary(locale),
+            created_at: now,
+        }
+    }
+
+    /// 既存のデータから復元する
+    pub fn from_db(record: NotificationDigestItemRecord) -> Self {
+        Self {
+            id: record.id,
+            tenant_id: record.tenant_id,
+            user_id: record.user_id,
+            recipient_email: record.recipient_email,
+            event_type: record.event_type,
+            workflow_instance_id: record.workflow_instance_id,
+            workflow_title: record.workflow_title,
+            workflow_display_id: record.workflow_display_id,
+            message: record.message,
+            created_at: record.created_at,
+        }
+    }
+
+    // Getter メソッド
+
+    pub fn id(&self) -> &NotificationDigestItemId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn recipient_email(&self) -> &str {
+        &self.recipient_email
+    }
+
+    pub fn event_type(&self) -> NotificationEventType {
+        self.event_type
+    }
+
+    pub fn workflow_instance_id(&self) -> &WorkflowInstanceId {
+        &self.workflow_instance_id
+    }
+
+    pub fn workflow_title(&self) -> &str {
+        &self.workflow_title
+    }
+
+    pub fn workflow_display_id(&self) -> &str {
+        &self.workflow_display_id
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+/// 通知テンプレートエンティティ
+///
+/// テナントが通知イベント種別ごとに組み込みテンプレートを上書きする。
+/// テナント ID と通知イベント種別の組で一意。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationTemplate {
+    tenant_id:  TenantId,
+    event_type: NotificationEventType,
+    subject:    NotificationSubjectTemplate,
+    html_body:  NotificationHtmlTemplate,
+    text_body:  NotificationTextTemplate,
+    updated_by: UserId,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// 通知テンプレートの作成パラメータ
+pub struct NewNotificationTemplate {
+    pub tenant_id:  TenantId,
+    pub event_type: NotificationEventType,
+    pub subject:    NotificationSubjectTemplate,
+    pub html_body:  NotificationHtmlTemplate,
+    pub text_body:  NotificationTextTemplate,
+    pub updated_by: UserId,
+    pub now:        DateTime<Utc>,
+}
+
+/// 通知テンプレートの DB 復元用レコード
+pub struct NotificationTemplateRecord {
+    pub tenant_id:  TenantId,
+    pub event_type: NotificationEventType,
+    pub subject:    NotificationSubjectTemplate,
+    pub html_body:  NotificationHtmlTemplate,
+    pub text_body:  NotificationTextTemplate,
+    pub updated_by: UserId,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl NotificationTemplate {
+    /// 新しい通知テンプレートを作成する
+    pub fn new(params: NewNotificationTemplate) -> Self {
+        Self {
+            tenant_id:  params.tenant_id,
+            event_type: params.event_type,
+            subject:    params.subject,
+            html_body:  params.html_body,
+            text_body:  params.text_body,
+            updated_by: params.updated_by,
+            created_at: params.now,
+            updated_at: params.now,
+        }
+    }
+
+    /// 既存のデータから復元する
+    pub fn from_db(record: NotificationTemplateRecord) -> Self {
+        Self {
+            tenant_id:  record.tenant_id,
+            event_type: record.event_type,
+            subject:    record.subject,
+            html_body:  record.html_body,
+            text_body:  record.text_body,
+            updated_by: record.updated_by,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }
+    }
+
+    /// テンプレートの内容を置き換えた新しいインスタンスを返す
+    ///
+    /// 作成日時は変更しない。
+    pub fn replaced(
+        self,
+        subject: NotificationSubjectTemplate,
+        html_body: NotificationHtmlTemplate,
+        text_body: NotificationTextTemplate,
+        updated_by: UserId,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            subject,
+            html_body,
+            text_body,
+            updated_by,
+            updated_at: now,
+            ..self
+        }
+    }
+
+    // Getter メソッド
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn event_type(&self) -> NotificationEventType {
+        self.event_type
+    }
+
+    pub fn subject(&self) -> &NotificationSubjectTemplate {
+        &self.subject
+    }
+
+    pub fn html_body(&self) -> &NotificationHtmlTemplate {
+        &self.html_body
+    }
+
+    pub fn text_body(&self) -> &NotificationTextTemplate {
+        &self.text_body
+    }
+
+    pub fn updated_by(&self) -> &UserId {
+        &self.updated_by
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+/// 通知ログのステータス
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum NotificationLogStatus {
+    /// 未送信またはリトライ待ち
+    Pending,
+    /// 送信成功
+    Sent,
+    /// 恒久的なエラーまたは最大試行回数に達して失敗
+    Failed,
+}
+
+impl std::str::FromStr for NotificationLogStatus {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "sent" => Ok(Self::Sent),
+            "failed" => Ok(Self::Failed),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-notification-log-status").with("value", s),
+            )),
+        }
+    }
+}
+
+/// 通知ログエンティティ
+///
+/// 1 受信者 × 1 通知のメール送信記録。即時送信のメールは生成した本文を保存し、
+/// リトライでも同じ本文を送る。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotificationLog {
+    id: NotificationLogId,
+    tenant_id: TenantId,
+    event_type: NotificationEventType,
+    workflow_instance_id: WorkflowInstanceId,
+    workflow_title: String,
+    workflow_display_id: String,
+    recipient_user_id: UserId,
+    recipient_email: String,
+    subject: String,
+    html_body: Option<String>,
+    text_body: Option<String>,
+    status: NotificationLogStatus,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    error_message: Option<String>,
+    created_at: DateTime<Utc>,
+    sent_at: Option<DateTime<Utc>>,
+}
+
+/// 通知ログの作成パラメータ
+pub struct NewNotificationLog {
+    pub id: NotificationLogId,
+    pub tenant_id: TenantId,
+    pub event_type: NotificationEventType,
+    pub workflow_instance_id: WorkflowInstanceId,
+    pub workflow_title: String,
+    pub workflow_display_id: String,
+    pub recipient_user_id: UserId,
+    /// 送信するメール（宛先・件名・本文）
+    pub email: EmailMessage,
+    pub now: DateTime<Utc>,
+}
+
+/// 通知ログの DB 復元用レコード
+pub struct NotificationLogRecord {
+    pub id: NotificationLogId,
+    pub tenant_id: TenantId,
+    pub event_type: NotificationEventType,
+    pub workflow_instance_id: WorkflowInstanceId,
+    pub workflow_title: String,
+    pub workflow_display_id: String,
+    pub recipient_user_id: UserId,
+    pub recipient_email: String,
+    pub subject: String,
+    pub html_body: Option<String>,
+    pub text_body: Option<String>,
+    pub status: NotificationLogStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub error_message: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+impl NotificationLog {
+    /// 即時送信する通知のログを作成する（本文を保存し、失敗時は再送する）
+    pub fn new(params: NewNotificationLog) -> Self {
+        Self {
+            id: params.id,
+            tenant_id: params.tenant_id,
+            event_type: params.event_type,
+            workflow_instance_id: params.workflow_instance_id,
+            workflow_title: params.workflow_title,
+            workflow_display_id: params.workflow_display_id,
+            recipient_user_id: params.recipient_user_id,
+            recipient_email: params.email.to,
+            subject: params.email.subject,
+            html_body: Some(params.email.html_body),
+            text_body: Some(params.email.text_body),
+            status: NotificationLogStatus::Pending,
+            attempts: 0,
+            next_attempt_at: params.now,
+            error_message: None,
+            created_at: params.now,
+            sent_at: None,
+        }
+    }
+
+    /// ダイジェストにまとめた通知のログを作成する
+    ///
+    /// ダイジェストメールは複数の通知をまとめた 1 通のため、通知ごとには本文を保存せず、再送しない。
+    pub fn for_digest(params: NewNotificationLog) -> Self {
+        Self {
+            html_body: None,
+            text_body: None,
+            ..Self::new(params)
+        }
+    }
+
+    /// DB から復元する
+    pub fn from_db(record: NotificationLogRecord) -> Self {
+        Self {
+            id: record.id,
+            tenant_id: record.tenant_id,
+            event_type: record.event_type,
+            workflow_instance_id: record.workflow_instance_id,
+            workflow_title: record.workflow_title,
+            workflow_display_id: record.workflow_display_id,
+            recipient_user_id: record.recipient_user_id,
+            recipient_email: record.recipient_email,
+            subject: record.subject,
+            html_body: record.html_body,
+            text_body: record.text_body,
+            status: record.status,
+            attempts: record.attempts,
+            next_attempt_at: record.next_attempt_at,
+            error_message: record.error_message,
+            created_at: record.created_at,
+            sent_at: record.sent_at,
+        }
+    }
+
+    /// 送信成功を記録する
+    pub fn sent(self, now: DateTime<Utc>) -> Self {
+        Self {
+            status: NotificationLogStatus::Sent,
+            attempts: self.attempts + 1,
+            error_message: None,
+            sent_at: Some(now),
+            ..self
+        }
+    }
+
+    /// 送信失敗を記録する
+    ///
+    /// 恒久的なエラー、最大試行回数への到達、本文を保存していない場合は `Failed`、
+    /// それ以外はバックオフ後に再送する。
+    pub fn failed(self, error: &NotificationError, now: DateTime<Utc>) -> Self {
+        let attempts = self.attempts + 1;
+        let status = if error.is_permanent()
+            || attempts >= MAX_NOTIFICATION_ATTEMPTS
+            || self.email().is_none()
+        {
+            NotificationLogStatus::Failed
+        } else {
+            NotificationLogStatus::Pending
+        };
+        Self {
+            status,
+            attempts,
+            next_attempt_at: now + retry_delay(attempts),
+            error_message: Some(error.to_string()),
+            ..self
+        }
+    }
+
+    /// 再送せずに送信失敗を記録する
+    ///
+    /// ダイジェストの通知ログなど、エラーの種類によらず再送しない通知に使う。
+    pub fn failed_permanently(self, error: &NotificationError) -> Self {
+        Self {
+            status: NotificationLogStatus::Failed,
+            attempts: self.attempts + 1,
+            error_message: Some(error.to_string()),
+            ..self
+        }
+    }
+
+    /// 失敗した通知を再送待ちに戻す（管理者による手動再送）
+    ///
+    /// 試行回数をリセットし、次回のポーリングで送信する。
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::Conflict`: 失敗した通知ではない
+    /// - `DomainError::Validation`: 本文を保存していない（ダイジェスト・移行前の通知）
+    pub fn resend(self, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        if self.status != NotificationLogStatus::Failed {
+            return Err(DomainError::Conflict(Message::new(
+                "notification-not-failed",
+            )));
+        }
+        if self.email().is_none() {
+            return Err(DomainError::Validation(Message::new(
+                "notification-body-not-stored",
+            )));
+        }
+        Ok(Self {
+            status: NotificationLogStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            ..self
+        })
+    }
+
+    /// 送信するメール（本文を保存していない場合は `None`）
+    pub fn email(&self) -> Option<EmailMessage> {
+        let (html_body, text_body) = self.html_body.as_ref().zip(self.text_body.as_ref())?;
+        Some(EmailMessage {
+            to:        self.recipient_email.clone(),
+            subject:   self.subject.clone(),
+            html_body: html_body.clone(),
+            text_body: text_body.clone(),
+        })
+    }
+
+    // Getter メソッド
+
+    pub fn id(&self) -> &NotificationLogId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn event_type(&self) -> NotificationEventType {
+        self.event_type
+    }
+
+    pub fn workflow_instance_id(&self) -> &WorkflowInstanceId {
+        &self.workflow_instance_id
+    }
+
+    pub fn workflow_title(&self) -> &str {
+        &self.workflow_title
+    }
+
+    pub fn workflow_display_id(&self) -> &str {
+        &self.workflow_display_id
+    }
+
+    pub fn recipient_user_id(&self) -> &UserId {
+        &self.recipient_user_id
+    }
+
+    pub fn recipient_email(&self) -> &str {
+        &self.recipient_email
+    }
+
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    pub fn html_body(&self) -> Option<&str> {
+        self.html_body.as_deref()
+    }
+
+    pub fn text_body(&self) -> Option<&str> {
+        self.text_body.as_deref()
+    }
+
+    pub fn status(&self) -> NotificationLogStatus {
+        self.status
+    }
+
+    pub fn attempts(&self) -> i32 {
+        self.attempts
+    }
+
+    pub fn next_attempt_at(&self) -> DateTime<Utc> {
+        self.next_attempt_at
+    }
+
+    pub fn error_message(&self) -> Option<&str> {
+        self.error_message.as_deref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn sent_at(&self) -> Option<DateTime<Utc>> {
+        self.sent_at
+    }
+}
+
+/// 失敗回数に応じた再送までの待ち時間（1 分 × 2^(n-1)、上限 6 時間）
+fn retry_delay(attempts: i32) -> TimeDelta {
+    let exponent = u32::try_from(attempts.saturating_sub(1))
+        .unwrap_or(0)
+        .min(16);
+    TimeDelta::seconds(
+        RETRY_BASE_SECONDS
+            .saturating_mul(1_i64 << exponent)
+            .min(RETRY_MAX_SECONDS),
+    )
 }
 
 #[cfg(test)]
@@ -253,6 +1301,20 @@ mod tests {
             NotificationEventType::ChangesRequested.to_string(),
             "changes_requested"
         );
+        assert_eq!(
+            NotificationEventType::ScheduledWorkflowCreated.to_string(),
+            "scheduled_workflow_created"
+        );
+        assert_eq!(
+            NotificationEventType::ProxySubmitted.to_string(),
+            "proxy_submitted"
+        );
+        assert_eq!(
+            NotificationEventType::ApprovalRetracted.to_string(),
+            "approval_retracted"
+        );
+        assert_eq!(NotificationEventType::Mentioned.to_string(), "mentioned");
+        assert_eq!(NotificationEventType::Cancelled.to_string(), "cancelled");
 
         // FromStr (snake_case)
         assert_eq!(
@@ -275,6 +1337,26 @@ mod tests {
             NotificationEventType::from_str("changes_requested").unwrap(),
             NotificationEventType::ChangesRequested
         );
+        assert_eq!(
+            NotificationEventType::from_str("scheduled_workflow_created").unwrap(),
+            NotificationEventType::ScheduledWorkflowCreated
+        );
+        assert_eq!(
+            NotificationEventType::from_str("proxy_submitted").unwrap(),
+            NotificationEventType::ProxySubmitted
+        );
+        assert_eq!(
+            NotificationEventType::from_str("approval_retracted").unwrap(),
+            NotificationEventType::ApprovalRetracted
+        );
+        assert_eq!(
+            NotificationEventType::from_str("mentioned").unwrap(),
+            NotificationEventType::Mentioned
+        );
+        assert_eq!(
+            NotificationEventType::from_str("cancelled").unwrap(),
+            NotificationEventType::Cancelled
+        );
     }
 
     fn make_approval_request() -> WorkflowNotification {
@@ -294,8 +1376,8 @@ mod tests {
             workflow_display_id: "WF-0042".to_string(),
             step_name:           "上長承認".to_string(),
             approver_name:       "鈴木一郎".to_string(),
-            applicant_email:     "tanaka@example.com".to_string(),
-            applicant_user_id:   UserId::new(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
         }
     }
 
@@ -303,8 +1385,8 @@ mod tests {
         WorkflowNotification::Approved {
             workflow_title:      "経費精算申請".to_string(),
             workflow_display_id: "WF-0042".to_string(),
-            applicant_email:     "tanaka@example.com".to_string(),
-            applicant_user_id:   UserId::new(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
         }
     }
 
@@ -313,8 +1395,8 @@ mod tests {
             workflow_title: "経費精算申請".to_string(),
             workflow_display_id: "WF-0042".to_string(),
             comment: Some("領収書が添付されていません".to_string()),
-            applicant_email: "tanaka@example.com".to_string(),
-            applicant_user_id: UserId::new(),
+            recipient_email: "tanaka@example.com".to_string(),
+            recipient_user_id: UserId::new(),
         }
     }
 
@@ -323,8 +1405,61 @@ mod tests {
             workflow_title: "経費精算申請".to_string(),
             workflow_display_id: "WF-0042".to_string(),
             comment: Some("金額の内訳を追記してください".to_string()),
-            applicant_email: "tanaka@example.com".to_string(),
-            applicant_user_id: UserId::new(),
+            recipient_email: "tanaka@example.com".to_string(),
+            recipient_user_id: UserId::new(),
+        }
+    }
+
+    fn make_scheduled_workflow_created() -> WorkflowNotification {
+        WorkflowNotification::ScheduledWorkflowCreated {
+            workflow_title:      "サブスクリプション更新".to_string(),
+            workflow_display_id: "WF-0043".to_string(),
+            schedule_name:       "月次サブスクリプション更新".to_string(),
+            submitted:           true,
+            submit_error:        None,
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
+        }
+    }
+
+    fn make_proxy_submitted() -> WorkflowNotification {
+        WorkflowNotification::ProxySubmitted {
+            workflow_title:      "役員出張申請".to_string(),
+            workflow_display_id: "WF-0044".to_string(),
+            proxy_name:          "佐藤秘書".to_string(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
+        }
+    }
+
+    fn make_approval_retracted() -> WorkflowNotification {
+        WorkflowNotification::ApprovalRetracted {
+            workflow_title:      "経費精算申請".to_string(),
+            workflow_display_id: "WF-0042".to_string(),
+            step_name:           "上長承認".to_string(),
+            approver_name:       "鈴木一郎".to_string(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
+        }
+    }
+
+    fn make_mentioned() -> WorkflowNotification {
+        WorkflowNotification::Mentioned {
+            workflow_title: "経費精算申請".to_string(),
+            workflow_display_id: "WF-0042".to_string(),
+            mentioned_by_name: "鈴木一郎".to_string(),
+            comment: "@USER-5 金額の確認をお願いします".to_string(),
+            recipient_email: "sato@example.com".to_string(),
+            recipient_user_id: UserId::new(),
+        }
+    }
+
+    fn make_cancelled() -> WorkflowNotification {
+        WorkflowNotification::Cancelled {
+            workflow_title:      "経費精算申請".to_string(),
+            workflow_display_id: "WF-0042".to_string(),
+            recipient_email:     "tanaka@example.com".to_string(),
+            recipient_user_id:   UserId::new(),
         }
     }
 
@@ -350,6 +1485,26 @@ mod tests {
             make_changes_requested().event_type(),
             NotificationEventType::ChangesRequested
         );
+        assert_eq!(
+            make_scheduled_workflow_created().event_type(),
+            NotificationEventType::ScheduledWorkflowCreated
+        );
+        assert_eq!(
+            make_proxy_submitted().event_type(),
+            NotificationEventType::ProxySubmitted
+        );
+        assert_eq!(
+            make_approval_retracted().event_type(),
+            NotificationEventType::ApprovalRetracted
+        );
+        assert_eq!(
+            make_mentioned().event_type(),
+            NotificationEventType::Mentioned
+        );
+        assert_eq!(
+            make_cancelled().event_type(),
+            NotificationEventType::Cancelled
+        );
     }
 
     #[test]
@@ -368,6 +1523,30 @@ mod tests {
             make_changes_requested().recipient_email(),
             "tanaka@example.com"
         );
+
+        // ScheduledWorkflowCreated → スケジュールの所有者のメールアドレス
+        assert_eq!(
+            make_scheduled_workflow_created().recipient_email(),
+            "tanaka@example.com"
+        );
+
+        // ProxySubmitted → 本人のメールアドレス
+        assert_eq!(
+            make_proxy_submitted().recipient_email(),
+            "tanaka@example.com"
+        );
+
+        // ApprovalRetracted → 受信者（申請者など）のメールアドレス
+        assert_eq!(
+            make_approval_retracted().recipient_email(),
+            "tanaka@example.com"
+        );
+
+        // Mentioned → メンションされたユーザーのメールアドレス
+        assert_eq!(make_mentioned().recipient_email(), "sato@example.com");
+
+        // Cancelled → 受信者（申請者など）のメールアドレス
+        assert_eq!(make_cancelled().recipient_email(), "tanaka@example.com");
     }
 
     #[test]
@@ -388,9 +1567,340 @@ mod tests {
         let approved = WorkflowNotification::Approved {
             workflow_title:      "テスト".to_string(),
             workflow_display_id: "WF-0001".to_string(),
-            applicant_email:     "applicant@example.com".to_string(),
-            applicant_user_id:   applicant_id.clone(),
+            recipient_email:     "applicant@example.com".to_string(),
+            recipient_user_id:   applicant_id.clone(),
         };
         assert_eq!(approved.recipient_user_id(), &applicant_id);
     }
+
+    #[test]
+    fn summaryが各バリアントの要約を返す() {
+        assert_eq!(
+            make_approval_request().summary(Locale::Ja),
+            "田中太郎 さんから「上長承認」の承認依頼が届きました"
+        );
+        assert_eq!(make_approved().summary(Locale::Ja), "申請が承認されました");
+        assert_eq!(make_rejected().summary(Locale::Ja), "申請が却下されました");
+        assert_eq!(
+            make_changes_requested().summary(Locale::Ja),
+            "申請が差し戻されました"
+        );
+        assert_eq!(
+            make_mentioned().summary(Locale::Ja),
+            "鈴木一郎 さんがコメントであなたをメンションしました"
+        );
+    }
+
+    #[test]
+    fn summaryが英語の要約を返す() {
+        assert_eq!(
+            make_approval_request().summary(Locale::En),
+            "田中太郎 requested your approval for \"上長承認\""
+        );
+        assert_eq!(
+            make_approved().summary(Locale::En),
+            "Your request has been approved"
+        );
+        assert_eq!(
+            make_rejected().summary(Locale::En),
+            "Your request has been rejected"
+        );
+        assert_eq!(
+            make_changes_requested().summary(Locale::En),
+            "Changes have been requested on your request"
+        );
+        assert_eq!(
+            make_mentioned().summary(Locale::En),
+            "鈴木一郎 mentioned you in a comment"
+        );
+    }
+
+    #[test]
+    fn 通知イベントから未読のアプリ内通知を作成できる() {
+        let notification = make_mentioned();
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+
+        let sut = InboxNotification::from_notification(
+            InboxNotificationId::new(),
+            TenantId::new(),
+            WorkflowInstanceId::new(),
+            &notification,
+            Locale::Ja,
+            now,
+        );
+
+        assert_eq!(sut.user_id(), notification.recipient_user_id());
+        assert_eq!(sut.event_type(), NotificationEventType::Mentioned);
+        assert_eq!(sut.workflow_title(), "経費精算申請");
+        assert_eq!(sut.workflow_display_id(), "WF-0042");
+        assert_eq!(sut.message(), notification.summary(Locale::Ja));
+        assert!(!sut.is_read());
+        assert_eq!(sut.created_at(), now);
+    }
+
+    #[test]
+    fn 既読済みの通知を既読にしても既読日時は変わらない() {
+        let first = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let later = DateTime::from_timestamp(1_700_000_600, 0).unwrap();
+        let sut = InboxNotification::from_notification(
+            InboxNotificationId::new(),
+            TenantId::new(),
+            WorkflowInstanceId::new(),
+            &make_approved(),
+            Locale::Ja,
+            first,
+        );
+
+        let sut = sut.mark_read(first).mark_read(later);
+
+        assert!(sut.is_read());
+        assert_eq!(sut.read_at(), Some(first));
+    }
+
+    #[test]
+    fn 通知設定は未設定の種別を即時として全種別に展開する() {
+        let saved = [NotificationPreference {
+            event_type: NotificationEventType::Approved,
+            delivery:   NotificationDelivery::Digest,
+        }];
+
+        let sut = NotificationPreference::resolve_all(&saved);
+
+        assert_eq!(sut.len(), 10);
+        assert_eq!(
+            sut[0],
+            NotificationPreference {
+                event_type: NotificationEventType::ApprovalRequest,
+                delivery:   NotificationDelivery::Immediate,
+            }
+        );
+        assert_eq!(
+            sut[2],
+            NotificationPreference {
+                event_type: NotificationEventType::Approved,
+                delivery:   NotificationDelivery::Digest,
+            }
+        );
+    }
+
+    #[test]
+    fn 通知イベントからダイジェスト待ち通知を作成できる() {
+        let notification = make_approval_request();
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+
+        let sut = NotificationDigestItem::from_notification(
+            NotificationDigestItemId::new(),
+            TenantId::new(),
+            WorkflowInstanceId::new(),
+            &notification,
+            Locale::En,
+            now,
+        );
+
+        assert_eq!(sut.user_id(), notification.recipient_user_id());
+        assert_eq!(sut.recipient_email(), "suzuki@example.com");
+        assert_eq!(sut.event_type(), NotificationEventType::ApprovalRequest);
+        assert_eq!(sut.message(), notification.summary(Locale::En));
+        assert_eq!(sut.created_at(), now);
+    }
+
+    #[test]
+    fn 通知テンプレートの内容を置き換えても作成日時は変わらない() {
+        let created_at = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let updated_at = DateTime::from_timestamp(1_700_003_600, 0).unwrap();
+        let editor = UserId::new();
+        let sut = NotificationTemplate::new(NewNotificationTemplate {
+            tenant_id:  TenantId::new(),
+            event_type: NotificationEventType::Approved,
+            subject:    NotificationSubjectTemplate::new("承認: {{ workflow_title }}").unwrap(),
+            html_body:  NotificationHtmlTemplate::new("<p>{{ workflow_title }}</p>").unwrap(),
+            text_body:  NotificationTextTemplate::new("{{ workflow_title }}").unwrap(),
+            updated_by: UserId::new(),
+            now:        created_at,
+        });
+
+        let sut = sut.replaced(
+            NotificationSubjectTemplate::new("承認完了のお知らせ").unwrap(),
+            NotificationHtmlTemplate::new("<p>承認されました</p>").unwrap(),
+            NotificationTextTemplate::new("承認されました").unwrap(),
+            editor.clone(),
+            updated_at,
+        );
+
+        assert_eq!(sut.subject().as_str(), "承認完了のお知らせ");
+        assert_eq!(sut.updated_by(), &editor);
+        assert_eq!(sut.created_at(), created_at);
+        assert_eq!(sut.updated_at(), updated_at);
+    }
+
+    #[test]
+    fn 件名テンプレートは255文字を超えるとエラー() {
+        assert!(NotificationSubjectTemplate::new("あ".repeat(255)).is_ok());
+        assert!(NotificationSubjectTemplate::new("あ".repeat(256)).is_err());
+        assert!(NotificationSubjectTemplate::new("  ").is_err());
+    }
+
+    fn make_log(now: DateTime<Utc>) -> NotificationLog {
+        NotificationLog::new(NewNotificationLog {
+            id: NotificationLogId::new(),
+            tenant_id: TenantId::new(),
+            event_type: NotificationEventType::ApprovalRequest,
+            workflow_instance_id: WorkflowInstanceId::new(),
+            workflow_title: "経費申請".to_string(),
+            workflow_display_id: "WF-0001".to_string(),
+            recipient_user_id: UserId::new(),
+            email: EmailMessage {
+                to:        "suzuki@example.com".to_string(),
+                subject:   "承認依頼".to_string(),
+                html_body: "<p>承認依頼</p>".to_string(),
+                text_body: "承認依頼".to_string(),
+            },
+            now,
+        })
+    }
+
+    #[test]
+    fn 通知ログのステータスの文字列変換が正しい() {
+        for (s, status) in [
+            ("pending", NotificationLogStatus::Pending),
+            ("sent", NotificationLogStatus::Sent),
+            ("failed", NotificationLogStatus::Failed),
+        ] {
+            assert_eq!(s.parse::<NotificationLogStatus>().unwrap(), status);
+            assert_eq!(status.to_string(), s);
+        }
+        assert!("unknown".parse::<NotificationLogStatus>().is_err());
+    }
+
+    #[test]
+    fn 作成した通知ログは送信待ちで本文を保持する() {
+        let now = Utc::now();
+
+        let sut = make_log(now);
+
+        assert_eq!(sut.status(), NotificationLogStatus::Pending);
+        assert_eq!(sut.attempts(), 0);
+        assert_eq!(sut.next_attempt_at(), now);
+        let email = sut.email().unwrap();
+        assert_eq!(email.to, "suzuki@example.com");
+        assert_eq!(email.subject, "承認依頼");
+        assert_eq!(email.text_body, "承認依頼");
+    }
+
+    #[test]
+    fn 送信成功で送信済みになる() {
+        let now = Utc::now();
+
+        let sut = make_log(now).sent(now);
+
+        assert_eq!(sut.status(), NotificationLogStatus::Sent);
+        assert_eq!(sut.attempts(), 1);
+        assert_eq!(sut.sent_at(), Some(now));
+        assert!(sut.error_message().is_none());
+    }
+
+    #[test]
+    fn 一時的なエラーは指数バックオフで再送され最大試行回数で失敗になる() {
+        let now = Utc::now();
+        let error = NotificationError::SendFailed("接続タイムアウト".to_string());
+
+        let first = make_log(now).failed(&error, now);
+        assert_eq!(first.status(), NotificationLogStatus::Pending);
+        assert_eq!(first.next_attempt_at(), now + TimeDelta::minutes(1));
+
+        let second = first.failed(&error, now);
+        assert_eq!(second.status(), NotificationLogStatus::Pending);
+        assert_eq!(second.next_attempt_at(), now + TimeDelta::minutes(2));
+
+        let exhausted =
+            (2..MAX_NOTIFICATION_ATTEMPTS).fold(second, |log, _| log.failed(&error, now));
+        assert_eq!(exhausted.status(), NotificationLogStatus::Failed);
+        assert_eq!(exhausted.attempts(), MAX_NOTIFICATION_ATTEMPTS);
+        assert_eq!(
+            exhausted.error_message(),
+            Some("メール送信に失敗: 接続タイムアウト")
+        );
+    }
+
+    #[test]
+    fn リトライ間隔は上限で頭打ちになる() {
+        assert_eq!(retry_delay(1), TimeDelta::minutes(1));
+        assert_eq!(retry_delay(4), TimeDelta::minutes(8));
+        assert_eq!(retry_delay(10), TimeDelta::hours(6));
+        assert_eq!(retry_delay(100), TimeDelta::hours(6));
+    }
+
+    #[test]
+    fn 恒久的なエラーは再送せず失敗になる() {
+        let now = Utc::now();
+        let error = NotificationError::PermanentSendFailure("550 宛先不明".to_string());
+
+        let sut = make_log(now).failed(&error, now);
+
+        assert!(error.is_permanent());
+        assert_eq!(sut.status(), NotificationLogStatus::Failed);
+        assert_eq!(sut.attempts(), 1);
+    }
+
+    #[test]
+    fn ダイジェストの通知ログは本文を保存せず失敗しても再送しない() {
+        let now = Utc::now();
+        let log = make_log(now);
+        let digest = NotificationLog::for_digest(NewNotificationLog {
+            id: NotificationLogId::new(),
+            tenant_id: log.tenant_id().clone(),
+            event_type: log.event_type(),
+            workflow_instance_id: log.workflow_instance_id().clone(),
+            workflow_title: log.workflow_title().to_string(),
+            workflow_display_id: log.workflow_display_id().to_string(),
+            recipient_user_id: log.recipient_user_id().clone(),
+            email: log.email().unwrap(),
+            now,
+        });
+
+        let sut = digest.failed(&NotificationError::SendFailed("接続失敗".to_string()), now);
+
+        assert!(sut.email().is_none());
+        assert_eq!(sut.status(), NotificationLogStatus::Failed);
+        assert!(sut.resend(now).is_err());
+    }
+
+    #[test]
+    fn 再送しない失敗は一時的なエラーでも失敗で確定する() {
+        let now = Utc::now();
+
+        let sut = make_log(now)
+            .failed_permanently(&NotificationError::SendFailed("接続失敗".to_string()));
+
+        assert_eq!(sut.status(), NotificationLogStatus::Failed);
+        assert_eq!(sut.attempts(), 1);
+        assert_eq!(sut.error_message(), Some("メール送信に失敗: 接続失敗"));
+    }
+
+    #[test]
+    fn 失敗した通知を再送すると試行回数をリセットして送信待ちに戻る() {
+        let now = Utc::now();
+        let later = now + TimeDelta::hours(1);
+        let failed = make_log(now).failed(
+            &NotificationError::PermanentSendFailure("554 送信拒否".to_string()),
+            now,
+        );
+
+        let sut = failed.resend(later).unwrap();
+
+        assert_eq!(sut.status(), NotificationLogStatus::Pending);
+        assert_eq!(sut.attempts(), 0);
+        assert_eq!(sut.next_attempt_at(), later);
+    }
+
+    #[test]
+    fn 失敗していない通知は再送できない() {
+        let now = Utc::now();
+
+        let pending = make_log(now).resend(now);
+        let sent = make_log(now).sent(now).resend(now);
+
+        assert!(matches!(pending, Err(DomainError::Conflict(_))));
+        assert!(matches!(sent, Err(DomainError::Conflict(_))));
+    }
 }
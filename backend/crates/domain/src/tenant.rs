@@ -46,6 +46,8 @@
 //! # }
 //! ```
 
+use crate::{locale::Locale, time_zone::TimeZone};
+
 define_uuid_id! {
     /// テナント（顧客企業）の一意識別子
     ///
@@ -82,7 +84,7 @@ define_validated_string! {
     /// - 空文字列ではない
     /// - 最大 255 文字（DB: `VARCHAR(255)`）
     pub struct TenantName {
-        label: "テナント名",
+        field: "tenant-name",
         max_length: 255,
     }
 }
@@ -94,21 +96,33 @@ define_validated_string! {
 /// テナント（顧客企業）エンティティ
 ///
 /// マルチテナント環境における顧客企業を表現する。
-/// 現時点では ID と名前のみの最小構成。
+/// 現時点では ID、名前、既定ロケール、タイムゾーンのみの最小構成。
 ///
 /// # 不変条件
 ///
 /// - `id` はシステム内で一意
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Tenant {
-    id:   TenantId,
+    id: TenantId,
     name: TenantName,
+    default_locale: Locale,
+    time_zone: TimeZone,
 }
 
 impl Tenant {
     /// データベースからテナントを復元する
-    pub fn from_db(id: TenantId, name: TenantName) -> Self {
-        Self { id, name }
+    pub fn from_db(
+        id: TenantId,
+        name: TenantName,
+        default_locale: Locale,
+        time_zone: TimeZone,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            default_locale,
+            time_zone,
+        }
     }
 
     /// テナント ID を取得する
@@ -120,6 +134,33 @@ impl Tenant {
     pub fn name(&self) -> &TenantName {
         &self.name
     }
+
+    /// 既定ロケールを取得する
+    ///
+    /// ロケールを選んでいないユーザーへの通知に使う。
+    pub fn default_locale(&self) -> Locale {
+        self.default_locale
+    }
+
+    /// タイムゾーンを取得する
+    ///
+    /// 通知ダイジェストの送信時刻など、テナント単位の時刻の解釈に使う。
+    pub fn time_zone(&self) -> TimeZone {
+        self.time_zone
+    }
+
+    /// 既定ロケールを変更した新しいインスタンスを返す
+    pub fn with_default_locale(self, default_locale: Locale) -> Self {
+        Self {
+            default_locale,
+            ..self
+        }
+    }
+
+    /// タイムゾーンを変更した新しいインスタンスを返す
+    pub fn with_time_zone(self, time_zone: TimeZone) -> Self {
+        Self { time_zone, ..self }
+    }
 }
 
 #[cfg(test)]
@@ -191,9 +232,32 @@ mod tests {
     fn test_from_dbでテナントを復元できる() {
         let id = TenantId::new();
         let name = TenantName::new("Test Tenant").unwrap();
-        let sut = Tenant::from_db(id.clone(), name.clone());
+        let sut = Tenant::from_db(id.clone(), name.clone(), Locale::En, TimeZone::UTC);
 
-        let expected = Tenant::from_db(id, name);
+        let expected = Tenant::from_db(id, name, Locale::En, TimeZone::UTC);
         assert_eq!(sut, expected);
+        assert_eq!(sut.default_locale(), Locale::En);
+        assert_eq!(sut.time_zone(), TimeZone::UTC);
+    }
+
+    #[test]
+    fn test_既定ロケール変更後の状態() {
+        let id = TenantId::new();
+        let name = TenantName::new("Test Tenant").unwrap();
+        let sut = Tenant::from_db(id.clone(), name.clone(), Locale::Ja, TimeZone::UTC)
+            .with_default_locale(Locale::En);
+
+        assert_eq!(sut, Tenant::from_db(id, name, Locale::En, TimeZone::UTC));
+    }
+
+    #[test]
+    fn test_タイムゾーン変更後の状態() {
+        let id = TenantId::new();
+        let name = TenantName::new("Test Tenant").unwrap();
+        let tokyo = TimeZone::default();
+        let sut = Tenant::from_db(id.clone(), name.clone(), Locale::Ja, TimeZone::UTC)
+            .with_time_zone(tokyo);
+
+        assert_eq!(sut, Tenant::from_db(id, name, Locale::Ja, tokyo));
     }
 }
@@ -0,0 +1,146 @@
+//! # タイムゾーン
+//!
+//! 日時の設定（スケジュールの実行時刻など）を解釈するタイムゾーンを定義する。
+//!
+//! ## 設計方針
+//!
+//! - **IANA タイムゾーン名で指定する**: `Asia/Tokyo`、`America/New_York` など。
+//!   夏時間の切り替えも名前から決まるため、固定のオフセット（`+09:00`）は受け付けない
+//! - **既定は `Asia/Tokyo`**: 既定のロケール（日本語）に合わせる
+//! - **存在しない・重複する現地時刻**: 夏時間の切り替えで現地時刻が存在しない場合は
+//!   切り替え後の最初の時刻、2 回現れる場合は早い方の時刻とする
+
+use chrono::{DateTime, LocalResult, NaiveDateTime, TimeDelta, TimeZone as _, Utc};
+
+use crate::{DomainError, message::Message};
+
+/// タイムゾーン
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeZone(chrono_tz::Tz);
+
+impl TimeZone {
+    /// UTC
+    pub const UTC: Self = Self(chrono_tz::UTC);
+
+    /// IANA タイムゾーン名
+    pub fn as_str(&self) -> &'static str {
+        self.0.name()
+    }
+
+    /// UTC の日時をこのタイムゾーンの現地時刻に変換する
+    pub fn to_local(&self, at: DateTime<Utc>) -> NaiveDateTime {
+        at.with_timezone(&self.0).naive_local()
+    }
+
+    /// このタイムゾーンの現地時刻を UTC の日時に変換する
+    ///
+    /// 夏時間の切り替えで現地時刻が存在しない場合は切り替え後の最初の時刻、
+    /// 2 回現れる場合は早い方の時刻を返す。
+    pub fn from_local(&self, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self.0.from_local_datetime(&local) {
+            LocalResult::Single(at) => Some(at.with_timezone(&Utc)),
+            LocalResult::Ambiguous(earliest, _) => Some(earliest.with_timezone(&Utc)),
+            // 切り替えの幅（多くは 1 時間）だけ先に進めて、切り替え直後の時刻を探す
+            LocalResult::None => (1..=24 * 4)
+                .map(|quarters| local + TimeDelta::minutes(15 * quarters))
+                .find_map(|shifted| self.0.from_local_datetime(&shifted).earliest())
+                .map(|at| at.with_timezone(&Utc)),
+        }
+    }
+}
+
+impl Default for TimeZone {
+    fn default() -> Self {
+        Self(chrono_tz::Asia::Tokyo)
+    }
+}
+
+impl std::fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for TimeZone {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<chrono_tz::Tz>().map(Self).map_err(|_| {
+            DomainError::Validation(Message::new("invalid-time-zone").with("value", s))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn local(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, min, 0)
+            .unwrap()
+    }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        local(y, m, d, h, min).and_utc()
+    }
+
+    #[rstest]
+    #[case("Asia/Tokyo")]
+    #[case("America/New_York")]
+    #[case("UTC")]
+    fn test_文字列との相互変換(#[case] s: &str) {
+        assert_eq!(s.parse::<TimeZone>().unwrap().to_string(), s);
+    }
+
+    #[rstest]
+    #[case("Mars/Olympus")]
+    #[case("+09:00")]
+    #[case("")]
+    fn test_不正な文字列はエラーになる(#[case] s: &str) {
+        assert!(s.parse::<TimeZone>().is_err());
+    }
+
+    #[test]
+    fn test_既定のタイムゾーンは東京() {
+        assert_eq!(TimeZone::default().as_str(), "Asia/Tokyo");
+    }
+
+    #[test]
+    fn test_現地時刻とutcを相互に変換する() {
+        let sut: TimeZone = "Asia/Tokyo".parse().unwrap();
+
+        assert_eq!(
+            sut.from_local(local(2026, 3, 1, 9, 0)),
+            Some(utc(2026, 3, 1, 0, 0))
+        );
+        assert_eq!(sut.to_local(utc(2026, 3, 1, 0, 0)), local(2026, 3, 1, 9, 0));
+    }
+
+    #[test]
+    fn test_夏時間で存在しない現地時刻は切り替え後の時刻になる() {
+        let sut: TimeZone = "America/New_York".parse().unwrap();
+
+        // 2026-03-08 02:00 EST → 03:00 EDT（02:30 は存在しない）
+        assert_eq!(
+            sut.from_local(local(2026, 3, 8, 2, 30)),
+            Some(utc(2026, 3, 8, 7, 0))
+        );
+    }
+
+    #[test]
+    fn test_夏時間で重複する現地時刻は早い方の時刻になる() {
+        let sut: TimeZone = "America/New_York".parse().unwrap();
+
+        // 2026-11-01 02:00 EDT → 01:00 EST（01:30 が 2 回現れる）
+        assert_eq!(
+            sut.from_local(local(2026, 11, 1, 1, 30)),
+            Some(utc(2026, 11, 1, 5, 30))
+        );
+    }
+}
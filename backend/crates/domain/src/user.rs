@@ -47,6 +47,8 @@ use strum::IntoStaticStr;
 
 use crate::{
     DomainError,
+    locale::Locale,
+    message::Message,
     tenant::TenantId,
     value_objects::{DisplayNumber, UserName},
 };
@@ -89,26 +91,26 @@ impl Email {
 
         if value.is_empty() {
             return Err(DomainError::Validation(
-                "メールアドレスは必須です".to_string(),
+                Message::new("required").with("field", "email"),
             ));
         }
 
         // 基本的な構造検証: local@domain の形式であること
         let Some((local, domain)) = value.split_once('@') else {
-            return Err(DomainError::Validation(
-                "メールアドレスの形式が不正です".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "invalid-email-format",
+            )));
         };
 
         if local.is_empty() || domain.is_empty() {
-            return Err(DomainError::Validation(
-                "メールアドレスの形式が不正です".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "invalid-email-format",
+            )));
         }
 
         if value.len() > 255 {
             return Err(DomainError::Validation(
-                "メールアドレスは255文字以内である必要があります".to_string(),
+                Message::new("email-too-long").with("max", 255),
             ));
         }
 
@@ -151,10 +153,9 @@ impl std::str::FromStr for UserStatus {
             "active" => Ok(Self::Active),
             "inactive" => Ok(Self::Inactive),
             "deleted" => Ok(Self::Deleted),
-            _ => Err(DomainError::Validation(format!(
-                "不正なユーザーステータス: {}",
-                s
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-user-status").with("value", s),
+            )),
         }
     }
 }
@@ -179,6 +180,7 @@ pub struct User {
     email: Email,
     name: UserName,
     status: UserStatus,
+    locale: Option<Locale>,
     last_login_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -199,6 +201,7 @@ impl User {
     /// # 不変条件
     ///
     /// - 作成時のステータスは `Active`
+    /// - `locale` は None（テナントの既定ロケールに従う）
     /// - `last_login_at` は None
     pub fn new(
         id: UserId,
@@ -215,6 +218,7 @@ impl User {
             email,
             name,
             status: UserStatus::Active,
+            locale: None,
             last_login_at: None,
             created_at: now,
             updated_at: now,
@@ -230,6 +234,7 @@ impl User {
         email: Email,
         name: UserName,
         status: UserStatus,
+        locale: Option<Locale>,
         last_login_at: Option<DateTime<Utc>>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
@@ -241,6 +246,7 @@ impl User {
             email,
             name,
             status,
+            locale,
             last_login_at,
             created_at,
             updated_at,
@@ -273,6 +279,11 @@ impl User {
         self.status
     }
 
+    /// ユーザーが選んだロケール（`None` はテナントの既定ロケールに従う）
+    pub fn locale(&self) -> Option<Locale> {
+        self.locale
+    }
+
     pub fn last_login_at(&self) -> Option<DateTime<Utc>> {
         self.last_login_at
     }
@@ -317,6 +328,24 @@ impl User {
         }
     }
 
+    /// ロケールを変更した新しいインスタンスを返す
+    ///
+    /// `None` を指定するとテナントの既定ロケールに従う。
+    pub fn with_locale(self, locale: Option<Locale>, now: DateTime<Utc>) -> Self {
+        Self {
+            locale,
+            updated_at: now,
+            ..self
+        }
+    }
+
+    /// 実際に使うロケールを返す
+    ///
+    /// ユーザーがロケールを選んでいなければテナントの既定ロケールを使う。
+    pub fn effective_locale(&self, tenant_default: Locale) -> Locale {
+        self.locale.unwrap_or(tenant_default)
+    }
+
     /// ユーザーステータスを変更した新しいインスタンスを返す
     pub fn with_status(self, status: UserStatus, now: DateTime<Utc>) -> Self {
         Self {
@@ -424,6 +453,7 @@ mod tests {
             original.email().clone(),
             original.name().clone(),
             UserStatus::Inactive,
+            original.locale(),
             original.last_login_at(),
             original.created_at(),
             transition_time,
@@ -452,6 +482,7 @@ mod tests {
             original.email().clone(),
             original.name().clone(),
             UserStatus::Deleted,
+            original.locale(),
             original.last_login_at(),
             original.created_at(),
             transition_time,
@@ -480,6 +511,7 @@ mod tests {
             original.email().clone(),
             original.name().clone(),
             original.status(),
+            original.locale(),
             Some(login_time),
             original.created_at(),
             login_time,
@@ -501,6 +533,33 @@ mod tests {
             original.email().clone(),
             new_name,
             original.status(),
+            original.locale(),
+            original.last_login_at(),
+            original.created_at(),
+            transition_time,
+        );
+        assert_eq!(sut, expected);
+    }
+
+    #[rstest]
+    fn test_新規ユーザーはロケール未設定(active_user: User) {
+        assert_eq!(active_user.locale(), None);
+    }
+
+    #[rstest]
+    fn test_ロケール変更後の状態(active_user: User) {
+        let transition_time = DateTime::from_timestamp(1_700_001_000, 0).unwrap();
+        let original = active_user.clone();
+        let sut = active_user.with_locale(Some(Locale::En), transition_time);
+
+        let expected = User::from_db(
+            original.id().clone(),
+            original.tenant_id().clone(),
+            original.display_number(),
+            original.email().clone(),
+            original.name().clone(),
+            original.status(),
+            Some(Locale::En),
             original.last_login_at(),
             original.created_at(),
             transition_time,
@@ -508,6 +567,17 @@ mod tests {
         assert_eq!(sut, expected);
     }
 
+    #[rstest]
+    fn test_ロケール未設定ならテナントの既定ロケールを使う(
+        active_user: User,
+    ) {
+        assert_eq!(active_user.effective_locale(Locale::En), Locale::En);
+
+        let transition_time = DateTime::from_timestamp(1_700_001_000, 0).unwrap();
+        let sut = active_user.with_locale(Some(Locale::Ja), transition_time);
+        assert_eq!(sut.effective_locale(Locale::En), Locale::Ja);
+    }
+
     #[rstest]
     fn test_ユーザーから表示用連番を取得できる(active_user: User) {
         assert_eq!(active_user.display_number().as_i64(), 42);
@@ -22,7 +22,7 @@
 use serde::{Deserialize, Serialize};
 use strum::IntoStaticStr;
 
-use crate::DomainError;
+use crate::{DomainError, message::Message};
 
 // =========================================================================
 // Version（バージョン番号）
@@ -69,9 +69,9 @@ impl Version {
     /// バリデーションに失敗した場合は `DomainError::Validation` を返す。
     pub fn new(value: u32) -> Result<Self, DomainError> {
         if value == 0 {
-            return Err(DomainError::Validation(
-                "バージョン番号は 1 以上である必要があります".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "version-must-be-positive",
+            )));
         }
         Ok(Self(value))
     }
@@ -115,9 +115,9 @@ impl TryFrom<i32> for Version {
     /// - 値が 0 以下の場合は `DomainError::Validation` を返す
     fn try_from(value: i32) -> Result<Self, Self::Error> {
         if value <= 0 {
-            return Err(DomainError::Validation(
-                "バージョン番号は 1 以上である必要があります".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "version-must-be-positive",
+            )));
         }
         Ok(Self(value as u32))
     }
@@ -175,9 +175,9 @@ impl DisplayNumber {
     /// バリデーションに失敗した場合は `DomainError::Validation` を返す。
     pub fn new(value: i64) -> Result<Self, DomainError> {
         if value <= 0 {
-            return Err(DomainError::Validation(
-                "表示用連番は 1 以上である必要があります".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "display-number-must-be-positive",
+            )));
         }
         Ok(Self(value))
     }
@@ -331,7 +331,7 @@ define_validated_string! {
     /// - 空文字列ではない
     /// - 最大 100 文字
     pub struct UserName {
-        label: "ユーザー名",
+        field: "user-name",
         max_length: 100,
         pii: true,
     }
@@ -351,7 +351,7 @@ define_validated_string! {
     /// - 空文字列ではない
     /// - 最大 200 文字
     pub struct WorkflowName {
-        label: "ワークフロー名",
+        field: "workflow-name",
         max_length: 200,
     }
 }
@@ -0,0 +1,733 @@
+//! # Webhook
+//!
+//! ワークフローイベントをテナントが登録した外部 URL（ERP、チャットツール等）へ
+//! HTTP POST で通知する。
+//!
+//! ## ドメイン用語
+//!
+//! | 型 | ドメイン用語 | 説明 |
+//! |---|------------|------|
+//! | [`WebhookSubscription`] | Webhook 購読 | 送信先 URL・購読するイベント種別・署名用シークレット |
+//! | [`WebhookDelivery`] | Webhook 配信 | 1 購読 × 1 イベントの送信記録（配信ログ） |
+//!
+//! ## 署名
+//!
+//! 受信側が送信元とペイロードの改ざんを検証できるよう、リクエストに以下のヘッダーを付与する:
+//!
+//! | ヘッダー | 値 |
+//! |---------|-----|
+//! | `X-Ringiflow-Event` | イベント種別（`approved` 等、テスト送信は `ping`） |
+//! | `X-Ringiflow-Delivery` | 配信 ID |
+//! | `X-Ringiflow-Timestamp` | 送信時刻（UNIX 秒） |
+//! | `X-Ringiflow-Signature` | `sha256=` + HMAC-SHA256(シークレット, `{timestamp}.{body}`) の 16 進表現 |
+//!
+//! タイムスタンプを署名対象に含めることで、受信側はリプレイ攻撃を検出できる。
+//!
+//! ## リトライ
+//!
+//! 2xx 以外の応答または通信エラーは失敗として記録し、指数バックオフ
+//! （1 分 × 2^(n-1)、上限 6 時間）で再送する。[`MAX_DELIVERY_ATTEMPTS`] 回失敗すると
+//! `failed` となり、以降は再送しない。
+
+use chrono::{DateTime, TimeDelta, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use strum::IntoStaticStr;
+
+use crate::{
+    DomainError,
+    message::Message,
+    tenant::TenantId,
+    user::UserId,
+    workflow::{WorkflowEventId, WorkflowEventType},
+};
+
+/// イベント種別ヘッダー名
+pub const EVENT_HEADER: &str = "X-Ringiflow-Event";
+/// 配信 ID ヘッダー名
+pub const DELIVERY_HEADER: &str = "X-Ringiflow-Delivery";
+/// タイムスタンプヘッダー名
+pub const TIMESTAMP_HEADER: &str = "X-Ringiflow-Timestamp";
+/// 署名ヘッダー名
+pub const SIGNATURE_HEADER: &str = "X-Ringiflow-Signature";
+
+/// テスト送信のイベント種別
+pub const PING_EVENT_TYPE: &str = "ping";
+
+/// 配信の最大試行回数
+pub const MAX_DELIVERY_ATTEMPTS: i32 = 8;
+
+/// リトライ間隔の初期値（秒）
+const RETRY_BASE_SECONDS: i64 = 60;
+
+/// リトライ間隔の上限（秒）
+const RETRY_MAX_SECONDS: i64 = 6 * 60 * 60;
+
+define_uuid_id! {
+    /// Webhook 購読 ID
+    pub struct WebhookSubscriptionId;
+}
+
+define_uuid_id! {
+    /// Webhook 配信 ID
+    pub struct WebhookDeliveryId;
+}
+
+define_validated_string! {
+    /// Webhook 名（管理画面での識別用）
+    pub struct WebhookName {
+        field: "webhook-name",
+        max_length: 100,
+    }
+}
+
+/// Webhook 送信先 URL
+///
+/// `http://` または `https://` で始まり、2048 文字以内。
+/// https の強制や内部アドレスの拒否（SSRF 対策）は名前解決が必要なため、
+/// 登録時と送信時に `WebhookSender` が検証する。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebhookUrl(String);
+
+impl WebhookUrl {
+    const MAX_LENGTH: usize = 2048;
+
+    pub fn new(value: impl Into<String>) -> Result<Self, DomainError> {
+        let value = value.into().trim().to_string();
+        if !(value.starts_with("https://") || value.starts_with("http://")) {
+            return Err(DomainError::Validation(Message::new(
+                "webhook-url-invalid-scheme",
+            )));
+        }
+        if value.len() > Self::MAX_LENGTH || value.chars().any(char::is_whitespace) {
+            return Err(DomainError::Validation(
+                Message::new("webhook-url-too-long").with("max", Self::MAX_LENGTH),
+            ));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Webhook 署名用シークレット
+///
+/// 16〜256 文字。`Debug` 出力はマスクする。
+#[derive(Clone, PartialEq, Eq)]
+pub struct WebhookSecret(String);
+
+impl WebhookSecret {
+    const MAX_LENGTH: usize = 256;
+    const MIN_LENGTH: usize = 16;
+
+    pub fn new(value: impl Into<String>) -> Result<Self, DomainError> {
+        let value = value.into();
+        let length = value.chars().count();
+        if !(Self::MIN_LENGTH..=Self::MAX_LENGTH).contains(&length) {
+            return Err(DomainError::Validation(
+                Message::new("webhook-secret-length")
+                    .with("min", Self::MIN_LENGTH)
+                    .with("max", Self::MAX_LENGTH),
+            ));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// ペイロードに署名する
+    ///
+    /// `sha256=` + HMAC-SHA256(シークレット, `{timestamp}.{body}`) の 16 進表現を返す。
+    pub fn sign(&self, timestamp: i64, body: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.0.as_bytes())
+            .expect("HMAC は任意長のキーを受け付ける");
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(body.as_bytes());
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    }
+}
+
+impl std::fmt::Debug for WebhookSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("WebhookSecret")
+            .field(&crate::REDACTED)
+            .finish()
+    }
+}
+
+/// 購読するイベント種別を検証する（空不可、重複は除去）
+fn normalize_event_types(
+    event_types: Vec<WorkflowEventType>,
+) -> Result<Vec<WorkflowEventType>, DomainError> {
+    let mut normalized: Vec<WorkflowEventType> = Vec::with_capacity(event_types.len());
+    for event_type in event_types {
+        if !normalized.contains(&event_type) {
+            normalized.push(event_type);
+        }
+    }
+    if normalized.is_empty() {
+        return Err(DomainError::Validation(Message::new(
+            "webhook-events-required",
+        )));
+    }
+    Ok(normalized)
+}
+
+/// Webhook 購読エンティティ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookSubscription {
+    id:          WebhookSubscriptionId,
+    tenant_id:   TenantId,
+    name:        WebhookName,
+    url:         WebhookUrl,
+    event_types: Vec<WorkflowEventType>,
+    secret:      WebhookSecret,
+    is_active:   bool,
+    created_by:  UserId,
+    created_at:  DateTime<Utc>,
+    updated_at:  DateTime<Utc>,
+}
+
+/// Webhook 購読の作成パラメータ
+pub struct NewWebhookSubscription {
+    pub id:          WebhookSubscriptionId,
+    pub tenant_id:   TenantId,
+    pub name:        WebhookName,
+    pub url:         WebhookUrl,
+    pub event_types: Vec<WorkflowEventType>,
+    pub secret:      WebhookSecret,
+    pub created_by:  UserId,
+    pub now:         DateTime<Utc>,
+}
+
+/// Webhook 購読の更新内容（`None` の項目は変更しない）
+#[derive(Default)]
+pub struct WebhookSubscriptionChanges {
+    pub name:        Option<WebhookName>,
+    pub url:         Option<WebhookUrl>,
+    pub event_types: Option<Vec<WorkflowEventType>>,
+    pub secret:      Option<WebhookSecret>,
+    pub is_active:   Option<bool>,
+}
+
+/// Webhook 購読の DB 復元用レコード
+pub struct WebhookSubscriptionRecord {
+    pub id:          WebhookSubscriptionId,
+    pub tenant_id:   TenantId,
+    pub name:        WebhookName,
+    pub url:         WebhookUrl,
+    pub event_types: Vec<WorkflowEventType>,
+    pub secret:      WebhookSecret,
+    pub is_active:   bool,
+    pub created_by:  UserId,
+    pub created_at:  DateTime<Utc>,
+    pub updated_at:  DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    /// 新しい Webhook 購読を作成する（有効状態）
+    pub fn new(params: NewWebhookSubscription) -> Result<Self, DomainError> {
+        Ok(Self {
+            id:          params.id,
+            tenant_id:   params.tenant_id,
+            name:        params.name,
+            url:         params.url,
+            event_types: normalize_event_types(params.event_types)?,
+            secret:      params.secret,
+            is_active:   true,
+            created_by:  params.created_by,
+            created_at:  params.now,
+            updated_at:  params.now,
+        })
+    }
+
+    /// DB から復元する
+    pub fn from_db(record: WebhookSubscriptionRecord) -> Self {
+        Self {
+            id:          record.id,
+            tenant_id:   record.tenant_id,
+            name:        record.name,
+            url:         record.url,
+            event_types: record.event_types,
+            secret:      record.secret,
+            is_active:   record.is_active,
+            created_by:  record.created_by,
+            created_at:  record.created_at,
+            updated_at:  record.updated_at,
+        }
+    }
+
+    /// 変更を適用した購読を返す
+    pub fn updated(
+        self,
+        changes: WebhookSubscriptionChanges,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let event_types = match changes.event_types {
+            Some(event_types) => normalize_event_types(event_types)?,
+            None => self.event_types,
+        };
+        Ok(Self {
+            name: changes.name.unwrap_or(self.name),
+            url: changes.url.unwrap_or(self.url),
+            event_types,
+            secret: changes.secret.unwrap_or(self.secret),
+            is_active: changes.is_active.unwrap_or(self.is_active),
+            updated_at: now,
+            ..self
+        })
+    }
+
+    /// 指定したイベント種別を配信対象とするか
+    pub fn subscribes_to(&self, event_type: WorkflowEventType) -> bool {
+        self.is_active && self.event_types.contains(&event_type)
+    }
+
+    pub fn id(&self) -> &WebhookSubscriptionId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn name(&self) -> &WebhookName {
+        &self.name
+    }
+
+    pub fn url(&self) -> &WebhookUrl {
+        &self.url
+    }
+
+    pub fn event_types(&self) -> &[WorkflowEventType] {
+        &self.event_types
+    }
+
+    pub fn secret(&self) -> &WebhookSecret {
+        &self.secret
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn created_by(&self) -> &UserId {
+        &self.created_by
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+/// Webhook 配信ステータス
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// 未送信またはリトライ待ち
+    Pending,
+    /// 送信成功（2xx 応答）
+    Succeeded,
+    /// 最大試行回数に達して失敗
+    Failed,
+}
+
+impl std::str::FromStr for WebhookDeliveryStatus {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "succeeded" => Ok(Self::Succeeded),
+            "failed" => Ok(Self::Failed),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-webhook-delivery-status").with("value", s),
+            )),
+        }
+    }
+}
+
+/// Webhook 配信エンティティ
+///
+/// 送信するペイロードは作成時に確定し、リトライでも同じ本文を送る。
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookDelivery {
+    id: WebhookDeliveryId,
+    tenant_id: TenantId,
+    subscription_id: WebhookSubscriptionId,
+    event_id: Option<WorkflowEventId>,
+    event_type: String,
+    payload: serde_json::Value,
+    status: WebhookDeliveryStatus,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    response_status: Option<i32>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+/// Webhook 配信の作成パラメータ
+pub struct NewWebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub tenant_id: TenantId,
+    pub subscription_id: WebhookSubscriptionId,
+    /// 元になったワークフローイベント（テスト送信は `None`）
+    pub event_id: Option<WorkflowEventId>,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub now: DateTime<Utc>,
+}
+
+/// Webhook 配信の DB 復元用レコード
+pub struct WebhookDeliveryRecord {
+    pub id: WebhookDeliveryId,
+    pub tenant_id: TenantId,
+    pub subscription_id: WebhookSubscriptionId,
+    pub event_id: Option<WorkflowEventId>,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+impl WebhookDelivery {
+    /// 新しい配信を作成する（即時送信対象）
+    pub fn new(params: NewWebhookDelivery) -> Self {
+        Self {
+            id: params.id,
+            tenant_id: params.tenant_id,
+            subscription_id: params.subscription_id,
+            event_id: params.event_id,
+            event_type: params.event_type,
+            payload: params.payload,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: params.now,
+            response_status: None,
+            last_error: None,
+            created_at: params.now,
+            delivered_at: None,
+        }
+    }
+
+    /// DB から復元する
+    pub fn from_db(record: WebhookDeliveryRecord) -> Self {
+        Self {
+            id: record.id,
+            tenant_id: record.tenant_id,
+            subscription_id: record.subscription_id,
+            event_id: record.event_id,
+            event_type: record.event_type,
+            payload: record.payload,
+            status: record.status,
+            attempts: record.attempts,
+            next_attempt_at: record.next_attempt_at,
+            response_status: record.response_status,
+            last_error: record.last_error,
+            created_at: record.created_at,
+            delivered_at: record.delivered_at,
+        }
+    }
+
+    /// 配信ワーカーによる取り出しを記録する
+    ///
+    /// 送信結果を記録するまでの処理期限を `next_attempt_at` に設定し、
+    /// 他のワーカーが同じ配信を取り出さないようにする。
+    pub fn claimed(self, lease_until: DateTime<Utc>) -> Self {
+        Self {
+            next_attempt_at: lease_until,
+            ..self
+        }
+    }
+
+    /// 送信成功を記録する
+    pub fn succeeded(self, response_status: i32, now: DateTime<Utc>) -> Self {
+        Self {
+            status: WebhookDeliveryStatus::Succeeded,
+            attempts: self.attempts + 1,
+            response_status: Some(response_status),
+            last_error: None,
+            delivered_at: Some(now),
+            ..self
+        }
+    }
+
+    /// 送信失敗を記録する
+    ///
+    /// 最大試行回数に達した場合は `Failed`、それ以外はバックオフ後に再送する。
+    pub fn failed(
+        self,
+        response_status: Option<i32>,
+        error: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let attempts = self.attempts + 1;
+        let status = if attempts >= MAX_DELIVERY_ATTEMPTS {
+            WebhookDeliveryStatus::Failed
+        } else {
+            WebhookDeliveryStatus::Pending
+        };
+        Self {
+            status,
+            attempts,
+            next_attempt_at: now + retry_delay(attempts),
+            response_status,
+            last_error: Some(error.into()),
+            ..self
+        }
+    }
+
+    /// 送信する本文（JSON 文字列）
+    pub fn body(&self) -> String {
+        self.payload.to_string()
+    }
+
+    pub fn id(&self) -> &WebhookDeliveryId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn subscription_id(&self) -> &WebhookSubscriptionId {
+        &self.subscription_id
+    }
+
+    pub fn event_id(&self) -> Option<&WorkflowEventId> {
+        self.event_id.as_ref()
+    }
+
+    pub fn event_type(&self) -> &str {
+        &self.event_type
+    }
+
+    pub fn payload(&self) -> &serde_json::Value {
+        &self.payload
+    }
+
+    pub fn status(&self) -> WebhookDeliveryStatus {
+        self.status
+    }
+
+    pub fn attempts(&self) -> i32 {
+        self.attempts
+    }
+
+    pub fn next_attempt_at(&self) -> DateTime<Utc> {
+        self.next_attempt_at
+    }
+
+    pub fn response_status(&self) -> Option<i32> {
+        self.response_status
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn delivered_at(&self) -> Option<DateTime<Utc>> {
+        self.delivered_at
+    }
+}
+
+/// 失敗回数に応じた再送までの待ち時間（1 分 × 2^(n-1)、上限 6 時間）
+fn retry_delay(attempts: i32) -> TimeDelta {
+    let exponent = u32::try_from(attempts.saturating_sub(1))
+        .unwrap_or(0)
+        .min(16);
+    TimeDelta::seconds(
+        RETRY_BASE_SECONDS
+            .saturating_mul(1_i64 << exponent)
+            .min(RETRY_MAX_SECONDS),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn new_subscription(
+        event_types: Vec<WorkflowEventType>,
+    ) -> Result<WebhookSubscription, DomainError> {
+        WebhookSubscription::new(NewWebhookSubscription {
+            id: WebhookSubscriptionId::new(),
+            tenant_id: TenantId::new(),
+            name: WebhookName::new("ERP 連携").unwrap(),
+            url: WebhookUrl::new("https://erp.example.com/hooks/ringiflow").unwrap(),
+            event_types,
+            secret: WebhookSecret::new("0123456789abcdef").unwrap(),
+            created_by: UserId::new(),
+            now: Utc::now(),
+        })
+    }
+
+    fn new_delivery() -> WebhookDelivery {
+        WebhookDelivery::new(NewWebhookDelivery {
+            id: WebhookDeliveryId::new(),
+            tenant_id: TenantId::new(),
+            subscription_id: WebhookSubscriptionId::new(),
+            event_id: Some(WorkflowEventId::new()),
+            event_type: "approved".to_string(),
+            payload: serde_json::json!({"type": "approved"}),
+            now: Utc::now(),
+        })
+    }
+
+    #[rstest]
+    #[case("https://example.com/hook", true)]
+    #[case("http://localhost:8080/hook", true)]
+    #[case("ftp://example.com/hook", false)]
+    #[case("example.com/hook", false)]
+    #[case("https://example.com/a b", false)]
+    fn test_webhook_urlはhttpまたはhttpsのみ許可する(
+        #[case] url: &str,
+        #[case] valid: bool,
+    ) {
+        assert_eq!(WebhookUrl::new(url).is_ok(), valid);
+    }
+
+    #[rstest]
+    #[case(15, false)]
+    #[case(16, true)]
+    #[case(256, true)]
+    #[case(257, false)]
+    fn test_シークレットは16から256文字(#[case] length: usize, #[case] valid: bool) {
+        assert_eq!(WebhookSecret::new("a".repeat(length)).is_ok(), valid);
+    }
+
+    #[test]
+    fn test_シークレットのdebug出力はマスクされる() {
+        let secret = WebhookSecret::new("super-secret-value").unwrap();
+
+        let debug = format!("{:?}", secret);
+
+        assert!(debug.contains(crate::REDACTED));
+        assert!(!debug.contains("super-secret-value"));
+    }
+
+    #[test]
+    fn test_署名はタイムスタンプと本文のhmac_sha256() {
+        let secret = WebhookSecret::new("0123456789abcdef").unwrap();
+
+        let signature = secret.sign(1_700_000_000, r#"{"type":"ping"}"#);
+
+        // echo -n '1700000000.{"type":"ping"}' | openssl dgst -sha256 -hmac 0123456789abcdef
+        assert_eq!(
+            signature,
+            "sha256=71cc41f7f94be792fb6a71eadeb0259f6fcbc63451ece0dc3fb8657ad679ba63"
+        );
+    }
+
+    #[test]
+    fn test_購読イベント種別は空不可で重複が除去される() {
+        assert!(new_subscription(vec![]).is_err());
+
+        let subscription = new_subscription(vec![
+            WorkflowEventType::Approved,
+            WorkflowEventType::Rejected,
+            WorkflowEventType::Approved,
+        ])
+        .unwrap();
+
+        assert_eq!(
+            subscription.event_types(),
+            &[WorkflowEventType::Approved, WorkflowEventType::Rejected]
+        );
+    }
+
+    #[test]
+    fn test_無効化した購読はイベントを配信対象としない() {
+        let subscription = new_subscription(vec![WorkflowEventType::Approved]).unwrap();
+        assert!(subscription.subscribes_to(WorkflowEventType::Approved));
+        assert!(!subscription.subscribes_to(WorkflowEventType::Rejected));
+
+        let deactivated = subscription
+            .updated(
+                WebhookSubscriptionChanges {
+                    is_active: Some(false),
+                    ..Default::default()
+                },
+                Utc::now(),
+            )
+            .unwrap();
+
+        assert!(!deactivated.subscribes_to(WorkflowEventType::Approved));
+    }
+
+    #[test]
+    fn test_取り出した配信は処理期限まで送信待ちのまま試行回数が変わらない() {
+        let lease_until = Utc::now() + TimeDelta::minutes(5);
+
+        let delivery = new_delivery().claimed(lease_until);
+
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Pending);
+        assert_eq!(delivery.attempts(), 0);
+        assert_eq!(delivery.next_attempt_at(), lease_until);
+    }
+
+    #[test]
+    fn test_送信成功で配信済みになる() {
+        let now = Utc::now();
+
+        let delivery = new_delivery().succeeded(200, now);
+
+        assert_eq!(delivery.status(), WebhookDeliveryStatus::Succeeded);
+        assert_eq!(delivery.attempts(), 1);
+        assert_eq!(delivery.response_status(), Some(200));
+        assert_eq!(delivery.delivered_at(), Some(now));
+    }
+
+    #[test]
+    fn test_送信失敗は指数バックオフで再送され最大試行回数で失敗になる() {
+        let now = Utc::now();
+
+        let first = new_delivery().failed(Some(500), "HTTP 500", now);
+        assert_eq!(first.status(), WebhookDeliveryStatus::Pending);
+        assert_eq!(first.next_attempt_at(), now + TimeDelta::minutes(1));
+
+        let second = first.failed(None, "接続タイムアウト", now);
+        assert_eq!(second.next_attempt_at(), now + TimeDelta::minutes(2));
+        assert_eq!(second.last_error(), Some("接続タイムアウト"));
+
+        let exhausted = (2..MAX_DELIVERY_ATTEMPTS).fold(second, |delivery, _| {
+            delivery.failed(Some(502), "HTTP 502", now)
+        });
+        assert_eq!(exhausted.attempts(), MAX_DELIVERY_ATTEMPTS);
+        assert_eq!(exhausted.status(), WebhookDeliveryStatus::Failed);
+    }
+
+    #[rstest]
+    #[case(1, 60)]
+    #[case(3, 240)]
+    #[case(10, 21_600)]
+    fn test_リトライ間隔は上限6時間(
+        #[case] attempts: i32,
+        #[case] expected_seconds: i64,
+    ) {
+        assert_eq!(retry_delay(attempts), TimeDelta::seconds(expected_seconds));
+    }
+}
@@ -7,6 +7,15 @@
 //! - **WorkflowDefinition**: ワークフローのテンプレート（再利用可能）
 //! - **WorkflowInstance**: 定義から生成された実行中の案件
 //! - **WorkflowStep**: インスタンス内の各承認ステップ
+//! - **WorkflowFormDataChange**: 承認者によるフォームデータ編集の変更履歴
+//! - **WorkflowSubmission**: 申請ラウンドごとのフォームデータ・添付ファイルのスナップショット
+//! - **WorkflowActivity**: インスタンスに対して発生した出来事の時系列記録
+//! - **WorkflowEvent**: 状態遷移を表すドメインイベント（アウトボックス経由で配信）
+//! - **AdminActionReason**: テナント管理者による強制操作の理由
+//! - **WorkflowViewer**: インスタンスの閲覧者（閲覧権限の判定）
+//! - **WorkflowWatcher**: 承認者以外で進捗通知を受け取るユーザー
+//! - **WorkflowSchedule**: 定期的にワークフローを作成する繰り返し設定
+//! - **ProxyGrant**: 本人に代わってワークフローを作成・申請する代理権限
 //!
 //! ## 使用例
 //!
@@ -34,14 +43,32 @@
 //! # }
 //! ```
 
+mod activity;
+mod admin;
 mod comment;
 mod definition;
 mod definition_validator;
+mod event;
+mod form_data_change;
 mod instance;
+mod proxy_grant;
+mod schedule;
 mod step;
+mod submission;
+mod visibility;
+mod watcher;
 
+pub use activity::*;
+pub use admin::*;
 pub use comment::*;
 pub use definition::*;
 pub use definition_validator::*;
+pub use event::*;
+pub use form_data_change::*;
 pub use instance::*;
+pub use proxy_grant::*;
+pub use schedule::*;
 pub use step::*;
+pub use submission::*;
+pub use visibility::*;
+pub use watcher::*;
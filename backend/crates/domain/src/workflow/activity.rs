@@ -0,0 +1,245 @@
+//! # ワークフローアクティビティ
+//!
+//! ワークフローインスタンスに対して発生した出来事（作成、申請、承認、コメント投稿など）を
+//! 時系列のイベントとして記録する。
+//!
+//! ステップやコメントの現在の状態からは、差し戻し前のステップや再申請の経緯を
+//! 復元できない。そのため操作のたびにイベントを追記し、詳細画面の履歴は
+//! このイベント列から表示する。
+//!
+//! ## details の内容
+//!
+//! | 種別 | details |
+//! |------|---------|
+//! | `created` | `{"title"}` |
+//! | `submitted` / `resubmitted` | `{"round"}` |
+//! | `step_activated` | `{"step_name", "assigned_to"}` |
+//! | `approved` / `rejected` / `changes_requested` | `{"step_name", "comment"}` |
+//! | `approval_retracted` | `{"step_name"}` |
+//! | `comment_posted` / `comment_edited` / `comment_deleted` | `{"comment_id"}` |
+//! | `attachment_added` | `{"document_id", "filename"}` |
+//! | `cancelled` / `force_completed` | `{"reason"}` |
+//! | `reassigned` | `{"step_name", "from", "to", "reason"}` |
+//!
+//! `cancelled` / `reassigned` / `force_completed` はテナント管理者による強制操作で記録する。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use strum::IntoStaticStr;
+
+use super::{instance::WorkflowInstanceId, step::WorkflowStepId};
+use crate::{DomainError, message::Message, tenant::TenantId, user::UserId};
+
+define_uuid_id! {
+    /// ワークフローアクティビティ ID
+    pub struct WorkflowActivityId;
+}
+
+/// アクティビティ種別
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, IntoStaticStr, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WorkflowActivityType {
+    /// 作成（下書き）
+    Created,
+    /// 申請
+    Submitted,
+    /// ステップのアクティブ化（承認待ちになった）
+    StepActivated,
+    /// 承認
+    Approved,
+    /// 却下
+    Rejected,
+    /// 差し戻し
+    ChangesRequested,
+    /// 再申請
+    Resubmitted,
+    /// 承認の取り消し
+    ApprovalRetracted,
+    /// コメント投稿
+    CommentPosted,
+    /// コメント編集
+    CommentEdited,
+    /// コメント削除
+    CommentDeleted,
+    /// 添付ファイル追加
+    AttachmentAdded,
+    /// 取消
+    Cancelled,
+    /// 担当者の変更
+    Reassigned,
+    /// 強制完了
+    ForceCompleted,
+}
+
+impl std::str::FromStr for WorkflowActivityType {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created" => Ok(Self::Created),
+            "submitted" => Ok(Self::Submitted),
+            "step_activated" => Ok(Self::StepActivated),
+            "approved" => Ok(Self::Approved),
+            "rejected" => Ok(Self::Rejected),
+            "changes_requested" => Ok(Self::ChangesRequested),
+            "resubmitted" => Ok(Self::Resubmitted),
+            "approval_retracted" => Ok(Self::ApprovalRetracted),
+            "comment_posted" => Ok(Self::CommentPosted),
+            "comment_edited" => Ok(Self::CommentEdited),
+            "comment_deleted" => Ok(Self::CommentDeleted),
+            "attachment_added" => Ok(Self::AttachmentAdded),
+            "cancelled" => Ok(Self::Cancelled),
+            "reassigned" => Ok(Self::Reassigned),
+            "force_completed" => Ok(Self::ForceCompleted),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-activity-type").with("value", s),
+            )),
+        }
+    }
+}
+
+/// ワークフローアクティビティエンティティ
+///
+/// インスタンスに対する 1 つの出来事を表す。記録後は不変。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowActivity {
+    id: WorkflowActivityId,
+    tenant_id: TenantId,
+    instance_id: WorkflowInstanceId,
+    activity_type: WorkflowActivityType,
+    actor_id: Option<UserId>,
+    step_id: Option<WorkflowStepId>,
+    details: JsonValue,
+    occurred_at: DateTime<Utc>,
+}
+
+/// ワークフローアクティビティの新規作成パラメータ
+pub struct NewWorkflowActivity {
+    pub id: WorkflowActivityId,
+    pub tenant_id: TenantId,
+    pub instance_id: WorkflowInstanceId,
+    pub activity_type: WorkflowActivityType,
+    /// 操作者（システムによる操作の場合は `None`）
+    pub actor_id: Option<UserId>,
+    /// 対象ステップ（ステップに紐づく出来事の場合）
+    pub step_id: Option<WorkflowStepId>,
+    pub details: JsonValue,
+    pub now: DateTime<Utc>,
+}
+
+/// ワークフローアクティビティの DB 復元パラメータ
+pub struct WorkflowActivityRecord {
+    pub id: WorkflowActivityId,
+    pub tenant_id: TenantId,
+    pub instance_id: WorkflowInstanceId,
+    pub activity_type: WorkflowActivityType,
+    pub actor_id: Option<UserId>,
+    pub step_id: Option<WorkflowStepId>,
+    pub details: JsonValue,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl WorkflowActivity {
+    /// 新しいアクティビティを作成する
+    pub fn new(params: NewWorkflowActivity) -> Self {
+        Self {
+            id: params.id,
+            tenant_id: params.tenant_id,
+            instance_id: params.instance_id,
+            activity_type: params.activity_type,
+            actor_id: params.actor_id,
+            step_id: params.step_id,
+            details: params.details,
+            occurred_at: params.now,
+        }
+    }
+
+    /// 既存のデータから復元する
+    pub fn from_db(record: WorkflowActivityRecord) -> Self {
+        Self {
+            id: record.id,
+            tenant_id: record.tenant_id,
+            instance_id: record.instance_id,
+            activity_type: record.activity_type,
+            actor_id: record.actor_id,
+            step_id: record.step_id,
+            details: record.details,
+            occurred_at: record.occurred_at,
+        }
+    }
+
+    // Getter メソッド
+
+    pub fn id(&self) -> &WorkflowActivityId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn instance_id(&self) -> &WorkflowInstanceId {
+        &self.instance_id
+    }
+
+    pub fn activity_type(&self) -> WorkflowActivityType {
+        self.activity_type
+    }
+
+    pub fn actor_id(&self) -> Option<&UserId> {
+        self.actor_id.as_ref()
+    }
+
+    pub fn step_id(&self) -> Option<&WorkflowStepId> {
+        self.step_id.as_ref()
+    }
+
+    pub fn details(&self) -> &JsonValue {
+        &self.details
+    }
+
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(WorkflowActivityType::Created, "created")]
+    #[case(WorkflowActivityType::StepActivated, "step_activated")]
+    #[case(WorkflowActivityType::ChangesRequested, "changes_requested")]
+    #[case(WorkflowActivityType::ApprovalRetracted, "approval_retracted")]
+    #[case(WorkflowActivityType::CommentPosted, "comment_posted")]
+    #[case(WorkflowActivityType::CommentEdited, "comment_edited")]
+    #[case(WorkflowActivityType::CommentDeleted, "comment_deleted")]
+    #[case(WorkflowActivityType::AttachmentAdded, "attachment_added")]
+    #[case(WorkflowActivityType::Reassigned, "reassigned")]
+    #[case(WorkflowActivityType::ForceCompleted, "force_completed")]
+    fn test_アクティビティ種別は文字列と相互変換できる(
+        #[case] activity_type: WorkflowActivityType,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(activity_type.to_string(), expected);
+        assert_eq!(
+            WorkflowActivityType::from_str(expected).unwrap(),
+            activity_type
+        );
+    }
+
+    #[test]
+    fn test_不正なアクティビティ種別はエラー() {
+        assert!(WorkflowActivityType::from_str("unknown").is_err());
+    }
+}
@@ -0,0 +1,41 @@
+//! # テナント管理者による強制操作
+//!
+//! テナント管理者は、担当者が不在・退職した場合などに、ワークフローを通常のフローの外で
+//! 取り消し・担当者変更・強制完了できる。強制操作には理由の入力を必須とし、
+//! 理由はアクティビティと監査ログに記録する。
+
+define_validated_string! {
+    /// 強制操作の理由
+    ///
+    /// # バリデーション
+    ///
+    /// - 空文字列ではない（前後の空白は除去する）
+    /// - 最大 1000 文字
+    pub struct AdminActionReason {
+        field: "reason",
+        max_length: 1000,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_理由は前後の空白を除去して保持する() {
+        let reason = AdminActionReason::new("  担当者退職のため  ").unwrap();
+
+        assert_eq!(reason.as_str(), "担当者退職のため");
+    }
+
+    #[test]
+    fn test_空白のみの理由はエラー() {
+        assert!(AdminActionReason::new("   ").is_err());
+    }
+
+    #[test]
+    fn test_1000文字を超える理由はエラー() {
+        assert!(AdminActionReason::new("あ".repeat(1000)).is_ok());
+        assert!(AdminActionReason::new("あ".repeat(1001)).is_err());
+    }
+}
@@ -7,17 +7,45 @@
 //!
 //! - `workflow_steps.comment`: ステップの判定コメント（承認/却下時に入力）
 //! - `workflow_comments`: ワークフロー単位のコメントスレッド（自由なやり取り）
+//!
+//! ## 編集・削除・返信
+//!
+//! - **返信**: トップレベルのコメントにのみ返信できる（スレッドは 1 階層）
+//! - **編集**: 投稿者本人が投稿から [`COMMENT_EDIT_WINDOW_MINUTES`] 分以内に限り可能。
+//!   編集前の本文は [`WorkflowCommentRevision`] として残す
+//! - **削除**: 投稿者本人またはワークフロー管理者による論理削除。
+//!   返信のスレッドを保つため、削除後もコメント自体は残る
+//!
+//! ## メンション
+//!
+//! 本文中の `@USER-42`（表示用 ID）または `@tanaka@example.com`（メールアドレス）を
+//! [`CommentMention`] として抽出する。メンションされたユーザーはウォッチャーに追加され、
+//! 通知を受け取る。
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 
 use super::instance::WorkflowInstanceId;
-use crate::{DomainError, tenant::TenantId, user::UserId};
+use crate::{
+    DomainError,
+    message::Message,
+    tenant::TenantId,
+    user::{Email, UserId},
+    value_objects::{DisplayId, DisplayNumber, display_prefix},
+};
 
 define_uuid_id! {
     /// ワークフローコメント ID
     pub struct WorkflowCommentId;
 }
 
+define_uuid_id! {
+    /// ワークフローコメント編集履歴 ID
+    pub struct WorkflowCommentRevisionId;
+}
+
+/// コメントを編集できる期間（投稿からの分数）
+pub const COMMENT_EDIT_WINDOW_MINUTES: i64 = 15;
+
 /// コメント本文
 ///
 /// 1〜2,000 文字のバリデーションを型レベルで強制する。
@@ -38,14 +66,13 @@ impl CommentBody {
         let value = value.into();
         if value.is_empty() {
             return Err(DomainError::Validation(
-                "コメント本文は必須です".to_string(),
+                Message::new("required").with("field", "comment-body"),
             ));
         }
         if value.chars().count() > COMMENT_BODY_MAX_LENGTH {
-            return Err(DomainError::Validation(format!(
-                "コメント本文は{}文字以内で入力してください",
-                COMMENT_BODY_MAX_LENGTH
-            )));
+            return Err(DomainError::Validation(
+                Message::new("comment-body-too-long").with("max", COMMENT_BODY_MAX_LENGTH),
+            ));
         }
         Ok(Self(value))
     }
@@ -57,6 +84,91 @@ impl CommentBody {
     pub fn into_inner(self) -> String {
         self.0
     }
+
+    /// 本文中の `@` メンションを出現順に抽出する（重複は除く）
+    ///
+    /// - `@USER-42`: ユーザーの表示用 ID で指定
+    /// - `@tanaka@example.com`: メールアドレスで指定
+    ///
+    /// `@` の直前が英数字などの場合（`tanaka@example.com` のような地の文のメールアドレス）は
+    /// メンションとみなさない。どちらの形式にも当てはまらないトークンは無視する。
+    pub fn mentions(&self) -> Vec<CommentMention> {
+        let body = self.0.as_str();
+        let mut mentions = Vec::new();
+        let mut pos = 0;
+
+        while let Some(offset) = body[pos..].find('@') {
+            let at = pos + offset;
+            let token_start = at + 1;
+            let token_end = body[token_start..]
+                .find(|c: char| !is_mention_char(c))
+                .map_or(body.len(), |len| token_start + len);
+            // 文末のピリオドはトークンに含めない
+            let token = body[token_start..token_end].trim_end_matches('.');
+
+            let preceded_by_word = body[..at].chars().next_back().is_some_and(is_mention_char);
+            if !preceded_by_word
+                && let Some(mention) = CommentMention::parse(token)
+                && !mentions.contains(&mention)
+            {
+                mentions.push(mention);
+            }
+
+            pos = token_end;
+        }
+
+        mentions
+    }
+}
+
+/// メンションのトークンに含まれうる文字
+fn is_mention_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '%' | '+' | '-' | '@')
+}
+
+/// コメント本文中のメンション
+///
+/// テナント内のユーザーへの解決はユースケース層で行う。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommentMention {
+    /// 表示用 ID（`@USER-42`）によるメンション
+    DisplayNumber(DisplayNumber),
+    /// メールアドレス（`@tanaka@example.com`）によるメンション
+    Email(Email),
+}
+
+impl CommentMention {
+    /// `@` に続くトークンをメンションとして解釈する
+    fn parse(token: &str) -> Option<Self> {
+        if let Some(number) = token
+            .strip_prefix(display_prefix::USER)
+            .and_then(|s| s.strip_prefix('-'))
+        {
+            if !number.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            return number
+                .parse::<i64>()
+                .ok()
+                .and_then(|n| DisplayNumber::new(n).ok())
+                .map(Self::DisplayNumber);
+        }
+        if token.contains('@') {
+            return Email::new(token).ok().map(Self::Email);
+        }
+        None
+    }
+}
+
+impl std::fmt::Display for CommentMention {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DisplayNumber(number) => {
+                write!(f, "@{}", DisplayId::new(display_prefix::USER, *number))
+            }
+            Self::Email(email) => write!(f, "@{}", email.as_str()),
+        }
+    }
 }
 
 /// ワークフローコメントエンティティ
@@ -68,8 +180,12 @@ pub struct WorkflowComment {
     id:          WorkflowCommentId,
     tenant_id:   TenantId,
     instance_id: WorkflowInstanceId,
+    parent_id:   Option<WorkflowCommentId>,
     posted_by:   UserId,
     body:        CommentBody,
+    edited_at:   Option<DateTime<Utc>>,
+    deleted_at:  Option<DateTime<Utc>>,
+    deleted_by:  Option<UserId>,
     created_at:  DateTime<Utc>,
     updated_at:  DateTime<Utc>,
 }
@@ -79,6 +195,8 @@ pub struct NewWorkflowComment {
     pub id:          WorkflowCommentId,
     pub tenant_id:   TenantId,
     pub instance_id: WorkflowInstanceId,
+    /// 返信先の親コメント（トップレベルのコメントは `None`）
+    pub parent_id:   Option<WorkflowCommentId>,
     pub posted_by:   UserId,
     pub body:        CommentBody,
     pub now:         DateTime<Utc>,
@@ -89,8 +207,12 @@ pub struct WorkflowCommentRecord {
     pub id:          WorkflowCommentId,
     pub tenant_id:   TenantId,
     pub instance_id: WorkflowInstanceId,
+    pub parent_id:   Option<WorkflowCommentId>,
     pub posted_by:   UserId,
     pub body:        CommentBody,
+    pub edited_at:   Option<DateTime<Utc>>,
+    pub deleted_at:  Option<DateTime<Utc>>,
+    pub deleted_by:  Option<UserId>,
     pub created_at:  DateTime<Utc>,
     pub updated_at:  DateTime<Utc>,
 }
@@ -102,8 +224,12 @@ impl WorkflowComment {
             id:          params.id,
             tenant_id:   params.tenant_id,
             instance_id: params.instance_id,
+            parent_id:   params.parent_id,
             posted_by:   params.posted_by,
             body:        params.body,
+            edited_at:   None,
+            deleted_at:  None,
+            deleted_by:  None,
             created_at:  params.now,
             updated_at:  params.now,
         }
@@ -115,13 +241,85 @@ impl WorkflowComment {
             id:          record.id,
             tenant_id:   record.tenant_id,
             instance_id: record.instance_id,
+            parent_id:   record.parent_id,
             posted_by:   record.posted_by,
             body:        record.body,
+            edited_at:   record.edited_at,
+            deleted_at:  record.deleted_at,
+            deleted_by:  record.deleted_by,
             created_at:  record.created_at,
             updated_at:  record.updated_at,
         }
     }
 
+    /// 返信を受け付けるか検証する
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::Validation`: 返信コメント、または削除済みのコメントの場合
+    pub fn ensure_repliable(&self) -> Result<(), DomainError> {
+        if self.parent_id.is_some() {
+            return Err(DomainError::Validation(Message::new(
+                "comment-reply-to-reply",
+            )));
+        }
+        if self.is_deleted() {
+            return Err(DomainError::Validation(Message::new(
+                "comment-reply-to-deleted",
+            )));
+        }
+        Ok(())
+    }
+
+    /// 本文を編集した新しいインスタンスを返す
+    ///
+    /// 編集できるのは投稿から [`COMMENT_EDIT_WINDOW_MINUTES`] 分以内の削除されていないコメントのみ。
+    /// 編集前の本文の保存（[`WorkflowCommentRevision`]）は呼び出し側の責務。
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::Validation`: 削除済み、または編集期間を過ぎている場合
+    pub fn edited(self, body: CommentBody, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        if self.is_deleted() {
+            return Err(DomainError::Validation(Message::new(
+                "comment-edit-deleted",
+            )));
+        }
+        if now > self.created_at + Duration::minutes(COMMENT_EDIT_WINDOW_MINUTES) {
+            return Err(DomainError::Validation(
+                Message::new("comment-edit-window-expired")
+                    .with("minutes", COMMENT_EDIT_WINDOW_MINUTES),
+            ));
+        }
+        Ok(Self {
+            body,
+            edited_at: Some(now),
+            updated_at: now,
+            ..self
+        })
+    }
+
+    /// 論理削除した新しいインスタンスを返す
+    ///
+    /// 削除できるユーザー（投稿者本人またはワークフロー管理者）の判定は呼び出し側の責務。
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::Validation`: 既に削除されている場合
+    pub fn deleted(self, deleted_by: UserId, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        if self.is_deleted() {
+            return Err(DomainError::Validation(Message::new(
+                "comment-already-deleted",
+            )));
+        }
+        Ok(Self {
+            deleted_at: Some(now),
+            deleted_by: Some(deleted_by),
+            updated_at: now,
+            ..self
+        })
+    }
+
     // Getter メソッド
 
     pub fn id(&self) -> &WorkflowCommentId {
@@ -136,6 +334,10 @@ impl WorkflowComment {
         &self.instance_id
     }
 
+    pub fn parent_id(&self) -> Option<&WorkflowCommentId> {
+        self.parent_id.as_ref()
+    }
+
     pub fn posted_by(&self) -> &UserId {
         &self.posted_by
     }
@@ -144,6 +346,22 @@ impl WorkflowComment {
         &self.body
     }
 
+    pub fn edited_at(&self) -> Option<DateTime<Utc>> {
+        self.edited_at
+    }
+
+    pub fn deleted_at(&self) -> Option<DateTime<Utc>> {
+        self.deleted_at
+    }
+
+    pub fn deleted_by(&self) -> Option<&UserId> {
+        self.deleted_by.as_ref()
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
     }
@@ -153,6 +371,80 @@ impl WorkflowComment {
     }
 }
 
+/// ワークフローコメントの編集履歴
+///
+/// コメントが編集されるたびに、編集前の本文を 1 件記録する。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowCommentRevision {
+    id:         WorkflowCommentRevisionId,
+    tenant_id:  TenantId,
+    comment_id: WorkflowCommentId,
+    body:       CommentBody,
+    edited_by:  UserId,
+    edited_at:  DateTime<Utc>,
+}
+
+/// ワークフローコメント編集履歴の DB 復元パラメータ
+pub struct WorkflowCommentRevisionRecord {
+    pub id:         WorkflowCommentRevisionId,
+    pub tenant_id:  TenantId,
+    pub comment_id: WorkflowCommentId,
+    pub body:       CommentBody,
+    pub edited_by:  UserId,
+    pub edited_at:  DateTime<Utc>,
+}
+
+impl WorkflowCommentRevision {
+    /// 編集前のコメントから編集履歴を作成する
+    pub fn of(comment: &WorkflowComment, edited_by: UserId, now: DateTime<Utc>) -> Self {
+        Self {
+            id: WorkflowCommentRevisionId::new(),
+            tenant_id: comment.tenant_id.clone(),
+            comment_id: comment.id.clone(),
+            body: comment.body.clone(),
+            edited_by,
+            edited_at: now,
+        }
+    }
+
+    /// 既存のデータから復元する
+    pub fn from_db(record: WorkflowCommentRevisionRecord) -> Self {
+        Self {
+            id:         record.id,
+            tenant_id:  record.tenant_id,
+            comment_id: record.comment_id,
+            body:       record.body,
+            edited_by:  record.edited_by,
+            edited_at:  record.edited_at,
+        }
+    }
+
+    pub fn id(&self) -> &WorkflowCommentRevisionId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn comment_id(&self) -> &WorkflowCommentId {
+        &self.comment_id
+    }
+
+    /// 編集前の本文
+    pub fn body(&self) -> &CommentBody {
+        &self.body
+    }
+
+    pub fn edited_by(&self) -> &UserId {
+        &self.edited_by
+    }
+
+    pub fn edited_at(&self) -> DateTime<Utc> {
+        self.edited_at
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::{fixture, rstest};
@@ -216,6 +508,73 @@ mod tests {
         }
     }
 
+    mod comment_mention {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        fn mentions(body: &str) -> Vec<CommentMention> {
+            CommentBody::new(body).unwrap().mentions()
+        }
+
+        fn by_number(n: i64) -> CommentMention {
+            CommentMention::DisplayNumber(DisplayNumber::new(n).unwrap())
+        }
+
+        fn by_email(email: &str) -> CommentMention {
+            CommentMention::Email(Email::new(email).unwrap())
+        }
+
+        #[rstest]
+        fn test_表示用idとメールアドレスのメンションを出現順に抽出する() {
+            assert_eq!(
+                mentions("@USER-42 さん、@sato@example.com さんも確認をお願いします"),
+                vec![by_number(42), by_email("sato@example.com")]
+            );
+        }
+
+        #[rstest]
+        fn test_重複したメンションは1つにまとめる() {
+            assert_eq!(
+                mentions("@USER-42 @USER-42 @USER-7"),
+                vec![by_number(42), by_number(7)]
+            );
+        }
+
+        #[rstest]
+        fn test_文末のピリオドはメンションに含めない() {
+            assert_eq!(
+                mentions("確認してください @sato@example.com."),
+                vec![by_email("sato@example.com")]
+            );
+        }
+
+        #[rstest]
+        fn test_全角文字に続くメンションを抽出する() {
+            assert_eq!(mentions("田中さん（@USER-3）へ"), vec![by_number(3)]);
+        }
+
+        #[rstest]
+        #[case::地の文のメールアドレス("連絡先は tanaka@example.com です")]
+        #[case::プレフィックスなしの番号("@42 を参照")]
+        #[case::不正な表示用id("@USER-abc @USER-0 @USER-")]
+        #[case::単独の記号("@ だけ")]
+        #[case::他エンティティの表示用id("@WF-42")]
+        fn test_メンションとみなさない(#[case] body: &str) {
+            assert_eq!(mentions(body), vec![]);
+        }
+
+        #[rstest]
+        #[case(by_number(42), "@USER-42")]
+        #[case(by_email("sato@example.com"), "@sato@example.com")]
+        fn test_メンションを本文の記法で表示する(
+            #[case] mention: CommentMention,
+            #[case] expected: &str,
+        ) {
+            assert_eq!(mention.to_string(), expected);
+        }
+    }
+
     mod workflow_comment {
         use pretty_assertions::assert_eq;
 
@@ -233,6 +592,7 @@ mod tests {
                 id: id.clone(),
                 tenant_id: tenant_id.clone(),
                 instance_id: instance_id.clone(),
+                parent_id: None,
                 posted_by: posted_by.clone(),
                 body: body.clone(),
                 now,
@@ -242,12 +602,105 @@ mod tests {
                 id,
                 tenant_id,
                 instance_id,
+                parent_id: None,
                 posted_by,
                 body,
+                edited_at: None,
+                deleted_at: None,
+                deleted_by: None,
                 created_at: now,
                 updated_at: now,
             });
             assert_eq!(sut, expected);
         }
+
+        fn comment(parent_id: Option<WorkflowCommentId>, now: DateTime<Utc>) -> WorkflowComment {
+            WorkflowComment::new(NewWorkflowComment {
+                id: WorkflowCommentId::new(),
+                tenant_id: TenantId::new(),
+                instance_id: WorkflowInstanceId::new(),
+                parent_id,
+                posted_by: UserId::new(),
+                body: CommentBody::new("元のコメント").unwrap(),
+                now,
+            })
+        }
+
+        #[rstest]
+        fn test_編集期間内なら本文を編集できる(now: DateTime<Utc>) {
+            let sut = comment(None, now);
+            let edited_at = now + Duration::minutes(COMMENT_EDIT_WINDOW_MINUTES);
+
+            let result = sut
+                .edited(CommentBody::new("編集後のコメント").unwrap(), edited_at)
+                .unwrap();
+
+            assert_eq!(result.body().as_str(), "編集後のコメント");
+            assert_eq!(result.edited_at(), Some(edited_at));
+            assert_eq!(result.updated_at(), edited_at);
+        }
+
+        #[rstest]
+        fn test_編集期間を過ぎると編集できない(now: DateTime<Utc>) {
+            let sut = comment(None, now);
+
+            let result = sut.edited(
+                CommentBody::new("編集後のコメント").unwrap(),
+                now + Duration::minutes(COMMENT_EDIT_WINDOW_MINUTES + 1),
+            );
+
+            assert!(result.is_err());
+        }
+
+        #[rstest]
+        fn test_削除すると削除者と削除日時が記録される(now: DateTime<Utc>) {
+            let sut = comment(None, now);
+            let deleted_by = UserId::new();
+
+            let result = sut.deleted(deleted_by.clone(), now).unwrap();
+
+            assert!(result.is_deleted());
+            assert_eq!(result.deleted_by(), Some(&deleted_by));
+            assert_eq!(result.deleted_at(), Some(now));
+            assert_eq!(result.body().as_str(), "元のコメント");
+        }
+
+        #[rstest]
+        fn test_削除済みのコメントは編集も再削除もできない(
+            now: DateTime<Utc>,
+        ) {
+            let sut = comment(None, now).deleted(UserId::new(), now).unwrap();
+
+            assert!(
+                sut.clone()
+                    .edited(CommentBody::new("編集").unwrap(), now)
+                    .is_err()
+            );
+            assert!(sut.deleted(UserId::new(), now).is_err());
+        }
+
+        #[rstest]
+        fn test_トップレベルのコメントにのみ返信できる(now: DateTime<Utc>) {
+            let parent = comment(None, now);
+            let reply = comment(Some(parent.id().clone()), now);
+            let deleted = comment(None, now).deleted(UserId::new(), now).unwrap();
+
+            assert!(parent.ensure_repliable().is_ok());
+            assert!(reply.ensure_repliable().is_err());
+            assert!(deleted.ensure_repliable().is_err());
+        }
+
+        #[rstest]
+        fn test_編集履歴には編集前の本文が記録される(now: DateTime<Utc>) {
+            let before = comment(None, now);
+            let editor = before.posted_by().clone();
+
+            let sut = WorkflowCommentRevision::of(&before, editor.clone(), now);
+
+            assert_eq!(sut.comment_id(), before.id());
+            assert_eq!(sut.body().as_str(), "元のコメント");
+            assert_eq!(sut.edited_by(), &editor);
+            assert_eq!(sut.edited_at(), now);
+        }
     }
 }
@@ -10,6 +10,7 @@ use strum::IntoStaticStr;
 
 use crate::{
     DomainError,
+    message::Message,
     tenant::TenantId,
     user::UserId,
     value_objects::{Version, WorkflowName},
@@ -41,10 +42,9 @@ impl std::str::FromStr for WorkflowDefinitionStatus {
             "draft" => Ok(Self::Draft),
             "published" => Ok(Self::Published),
             "archived" => Ok(Self::Archived),
-            _ => Err(DomainError::Validation(format!(
-                "不正なワークフロー定義ステータス: {}",
-                s
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-workflow-definition-status").with("value", s),
+            )),
         }
     }
 }
@@ -53,18 +53,24 @@ impl std::str::FromStr for WorkflowDefinitionStatus {
 ///
 /// 再利用可能なワークフローのテンプレート。
 /// JSON 形式の定義を保持し、バージョン管理に対応。
+///
+/// 機密定義（`confidential`）のインスタンスは、関与者以外には
+/// ワークフロー管理者であっても閲覧させない（[`WorkflowViewer`](super::WorkflowViewer) 参照）。
+/// 既定ウォッチャー（`default_watchers`）はインスタンス作成時にウォッチャーとして登録される。
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WorkflowDefinition {
-    id:          WorkflowDefinitionId,
-    tenant_id:   TenantId,
-    name:        WorkflowName,
+    id: WorkflowDefinitionId,
+    tenant_id: TenantId,
+    name: WorkflowName,
     description: Option<String>,
-    version:     Version,
-    definition:  JsonValue,
-    status:      WorkflowDefinitionStatus,
-    created_by:  UserId,
-    created_at:  DateTime<Utc>,
-    updated_at:  DateTime<Utc>,
+    version: Version,
+    definition: JsonValue,
+    status: WorkflowDefinitionStatus,
+    confidential: bool,
+    default_watchers: Vec<UserId>,
+    created_by: UserId,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
 }
 
 /// ワークフロー定義の新規作成パラメータ
@@ -80,48 +86,54 @@ pub struct NewWorkflowDefinition {
 
 /// ワークフロー定義の DB 復元パラメータ
 pub struct WorkflowDefinitionRecord {
-    pub id:          WorkflowDefinitionId,
-    pub tenant_id:   TenantId,
-    pub name:        WorkflowName,
+    pub id: WorkflowDefinitionId,
+    pub tenant_id: TenantId,
+    pub name: WorkflowName,
     pub description: Option<String>,
-    pub version:     Version,
-    pub definition:  JsonValue,
-    pub status:      WorkflowDefinitionStatus,
-    pub created_by:  UserId,
-    pub created_at:  DateTime<Utc>,
-    pub updated_at:  DateTime<Utc>,
+    pub version: Version,
+    pub definition: JsonValue,
+    pub status: WorkflowDefinitionStatus,
+    pub confidential: bool,
+    pub default_watchers: Vec<UserId>,
+    pub created_by: UserId,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
 impl WorkflowDefinition {
-    /// 新しいワークフロー定義を作成する
+    /// 新しいワークフロー定義を作成する（機密区分は「通常」、既定ウォッチャーなし）
     pub fn new(params: NewWorkflowDefinition) -> Self {
         Self {
-            id:          params.id,
-            tenant_id:   params.tenant_id,
-            name:        params.name,
+            id: params.id,
+            tenant_id: params.tenant_id,
+            name: params.name,
             description: params.description,
-            version:     Version::initial(),
-            definition:  params.definition,
-            status:      WorkflowDefinitionStatus::Draft,
-            created_by:  params.created_by,
-            created_at:  params.now,
-            updated_at:  params.now,
+            version: Version::initial(),
+            definition: params.definition,
+            status: WorkflowDefinitionStatus::Draft,
+            confidential: false,
+            default_watchers: Vec::new(),
+            created_by: params.created_by,
+            created_at: params.now,
+            updated_at: params.now,
         }
     }
 
     /// 既存のデータから復元する
     pub fn from_db(record: WorkflowDefinitionRecord) -> Self {
         Self {
-            id:          record.id,
-            tenant_id:   record.tenant_id,
-            name:        record.name,
+            id: record.id,
+            tenant_id: record.tenant_id,
+            name: record.name,
             description: record.description,
-            version:     record.version,
-            definition:  record.definition,
-            status:      record.status,
-            created_by:  record.created_by,
-            created_at:  record.created_at,
-            updated_at:  record.updated_at,
+            version: record.version,
+            definition: record.definition,
+            status: record.status,
+            confidential: record.confidential,
+            default_watchers: record.default_watchers,
+            created_by: record.created_by,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
         }
     }
 
@@ -155,6 +167,14 @@ impl WorkflowDefinition {
         self.status
     }
 
+    pub fn is_confidential(&self) -> bool {
+        self.confidential
+    }
+
+    pub fn default_watchers(&self) -> &[UserId] {
+        &self.default_watchers
+    }
+
     pub fn created_by(&self) -> &UserId {
         &self.created_by
     }
@@ -172,9 +192,9 @@ impl WorkflowDefinition {
     /// 定義が公開可能かチェックする（Draft のみ公開可能）
     pub fn can_publish(&self) -> Result<(), DomainError> {
         if self.status != WorkflowDefinitionStatus::Draft {
-            return Err(DomainError::Validation(
-                "下書き状態の定義のみ公開できます".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "definition-publish-not-draft",
+            )));
         }
         Ok(())
     }
@@ -182,9 +202,9 @@ impl WorkflowDefinition {
     /// 定義がアーカイブ可能かチェックする（Published のみアーカイブ可能）
     pub fn can_archive(&self) -> Result<(), DomainError> {
         if self.status != WorkflowDefinitionStatus::Published {
-            return Err(DomainError::Validation(
-                "公開済みの定義のみアーカイブできます".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "definition-archive-not-published",
+            )));
         }
         Ok(())
     }
@@ -192,9 +212,9 @@ impl WorkflowDefinition {
     /// 定義が削除可能かチェックする（Draft のみ削除可能）
     pub fn can_delete(&self) -> Result<(), DomainError> {
         if self.status != WorkflowDefinitionStatus::Draft {
-            return Err(DomainError::Validation(
-                "下書き状態の定義のみ削除できます".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "definition-delete-not-draft",
+            )));
         }
         Ok(())
     }
@@ -208,9 +228,9 @@ impl WorkflowDefinition {
         now: DateTime<Utc>,
     ) -> Result<Self, DomainError> {
         if self.status != WorkflowDefinitionStatus::Draft {
-            return Err(DomainError::Validation(
-                "下書き状態の定義のみ更新できます".to_string(),
-            ));
+            return Err(DomainError::Validation(Message::new(
+                "definition-update-not-draft",
+            )));
         }
         Ok(Self {
             name,
@@ -222,6 +242,32 @@ impl WorkflowDefinition {
         })
     }
 
+    /// 機密区分を設定した新しいインスタンスを返す
+    ///
+    /// 作成・更新（いずれも Draft）の直後に適用する。
+    pub fn with_confidential(self, confidential: bool) -> Self {
+        Self {
+            confidential,
+            ..self
+        }
+    }
+
+    /// 既定ウォッチャーを設定した新しいインスタンスを返す
+    ///
+    /// 重複したユーザーは 1 件にまとめる。作成・更新（いずれも Draft）の直後に適用する。
+    pub fn with_default_watchers(self, default_watchers: Vec<UserId>) -> Self {
+        let mut unique = Vec::with_capacity(default_watchers.len());
+        for user_id in default_watchers {
+            if !unique.contains(&user_id) {
+                unique.push(user_id);
+            }
+        }
+        Self {
+            default_watchers: unique,
+            ..self
+        }
+    }
+
     /// 定義を公開した新しいインスタンスを返す（Draft のみ公開可能）
     pub fn published(self, now: DateTime<Utc>) -> Result<Self, DomainError> {
         self.can_publish()?;
@@ -262,9 +308,15 @@ impl WorkflowDefinition {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ApprovalStepDef {
     /// ステップ ID（定義 JSON 内の `id` フィールド）
-    pub id:   String,
+    pub id: String,
     /// ステップ名（定義 JSON 内の `name` フィールド）
     pub name: String,
+    /// 承認者が承認時に編集できるフォームフィールド ID（定義 JSON 内の
+    /// `editableFields` フィールド、省略時は空）
+    pub editable_fields: Vec<String>,
+    /// 承認者が承認を取り消せる期限（承認からの分数、定義 JSON 内の
+    /// `retractWindowMinutes` フィールド、省略時は次のステップが未処理の間は無期限）
+    pub retract_window_minutes: Option<u32>,
 }
 
 /// 定義 JSON から承認ステップを順序付きで抽出する
@@ -280,9 +332,7 @@ pub fn extract_approval_steps(definition: &JsonValue) -> Result<Vec<ApprovalStep
     let steps = definition
         .get("steps")
         .and_then(|v| v.as_array())
-        .ok_or_else(|| {
-            DomainError::Validation("定義 JSON に steps 配列が見つかりません".to_string())
-        })?;
+        .ok_or_else(|| DomainError::Validation(Message::new("definition-steps-missing")))?;
 
     let approval_steps: Vec<ApprovalStepDef> = steps
         .iter()
@@ -292,7 +342,7 @@ pub fn extract_approval_steps(definition: &JsonValue) -> Result<Vec<ApprovalStep
                 .get("id")
                 .and_then(|v| v.as_str())
                 .ok_or_else(|| {
-                    DomainError::Validation("承認ステップに id フィールドがありません".to_string())
+                    DomainError::Validation(Message::new("definition-approval-step-id-missing"))
                 })?
                 .to_string();
             let name = step
@@ -300,19 +350,69 @@ pub fn extract_approval_steps(definition: &JsonValue) -> Result<Vec<ApprovalStep
                 .and_then(|v| v.as_str())
                 .unwrap_or_default()
                 .to_string();
-            Ok(ApprovalStepDef { id, name })
+            let editable_fields = step
+                .get("editableFields")
+                .and_then(|v| v.as_array())
+                .map(|fields| {
+                    fields
+                        .iter()
+                        .filter_map(|f| f.as_str().map(str::to_string))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let retract_window_minutes = step
+                .get("retractWindowMinutes")
+                .and_then(|v| v.as_u64())
+                .and_then(|v| u32::try_from(v).ok());
+            Ok(ApprovalStepDef {
+                id,
+                name,
+                editable_fields,
+                retract_window_minutes,
+            })
         })
         .collect::<Result<Vec<_>, _>>()?;
 
     if approval_steps.is_empty() {
-        return Err(DomainError::Validation(
-            "定義に承認ステップが含まれていません".to_string(),
-        ));
+        return Err(DomainError::Validation(Message::new(
+            "definition-no-approval-steps",
+        )));
     }
 
     Ok(approval_steps)
 }
 
+/// 複製時に引き継ぐフォームデータを抽出する
+///
+/// 定義 JSON の `form.fields` で `copyable: false` が指定されたフィールドを
+/// フォームデータから除外する。`copyable` の省略時は引き継ぐ。
+/// フォームデータがオブジェクトでない場合はそのまま返す。
+pub fn copyable_form_data(definition: &JsonValue, form_data: &JsonValue) -> JsonValue {
+    let Some(data) = form_data.as_object() else {
+        return form_data.clone();
+    };
+
+    let non_copyable: Vec<&str> = definition
+        .get("form")
+        .and_then(|f| f.get("fields"))
+        .and_then(|f| f.as_array())
+        .map(|fields| {
+            fields
+                .iter()
+                .filter(|field| field.get("copyable").and_then(|v| v.as_bool()) == Some(false))
+                .filter_map(|field| field.get("id").and_then(|v| v.as_str()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    JsonValue::Object(
+        data.iter()
+            .filter(|(key, _)| !non_copyable.contains(&key.as_str()))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::{fixture, rstest};
@@ -414,6 +514,75 @@ mod tests {
             assert_eq!(result.len(), 1);
             assert_eq!(result[0].id, "approval");
         }
+
+        #[test]
+        fn test_編集可能フィールドを抽出できる() {
+            let definition_json = json!({
+               "steps": [
+                  {"id": "start", "type": "start", "name": "開始"},
+                  {"id": "manager_approval", "type": "approval", "name": "上長承認", "editableFields": ["amount", "note"]},
+                  {"id": "finance_approval", "type": "approval", "name": "経理承認"},
+                  {"id": "end", "type": "end", "name": "完了", "status": "approved"}
+               ]
+            });
+
+            let result = extract_approval_steps(&definition_json).unwrap();
+
+            assert_eq!(result[0].editable_fields, vec!["amount", "note"]);
+            assert!(result[1].editable_fields.is_empty());
+        }
+
+        #[test]
+        fn test_承認取り消しの期限を抽出できる() {
+            let definition_json = json!({
+               "steps": [
+                  {"id": "start", "type": "start", "name": "開始"},
+                  {"id": "manager_approval", "type": "approval", "name": "上長承認", "retractWindowMinutes": 30},
+                  {"id": "finance_approval", "type": "approval", "name": "経理承認"},
+                  {"id": "end", "type": "end", "name": "完了", "status": "approved"}
+               ]
+            });
+
+            let result = extract_approval_steps(&definition_json).unwrap();
+
+            assert_eq!(result[0].retract_window_minutes, Some(30));
+            assert_eq!(result[1].retract_window_minutes, None);
+        }
+    }
+
+    mod copyable_form_data_tests {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        #[test]
+        fn test_copyableがfalseのフィールドは除外される() {
+            let definition_json = json!({
+               "form": {
+                  "fields": [
+                     {"id": "title", "type": "text", "label": "件名"},
+                     {"id": "amount", "type": "number", "label": "金額", "copyable": false},
+                     {"id": "note", "type": "textarea", "label": "備考", "copyable": true}
+                  ]
+               },
+               "steps": []
+            });
+            let form_data = json!({"title": "出張", "amount": 1000, "note": "定例"});
+
+            let result = copyable_form_data(&definition_json, &form_data);
+
+            assert_eq!(result, json!({"title": "出張", "note": "定例"}));
+        }
+
+        #[test]
+        fn test_formがない定義ではフォームデータをそのまま引き継ぐ() {
+            let definition_json = json!({"steps": []});
+            let form_data = json!({"title": "出張"});
+
+            let result = copyable_form_data(&definition_json, &form_data);
+
+            assert_eq!(result, form_data);
+        }
     }
 
     mod workflow_definition {
@@ -428,16 +597,18 @@ mod tests {
             let sut = test_definition.published(now).unwrap();
 
             let expected = WorkflowDefinition::from_db(WorkflowDefinitionRecord {
-                id:          before.id().clone(),
-                tenant_id:   before.tenant_id().clone(),
-                name:        before.name().clone(),
+                id: before.id().clone(),
+                tenant_id: before.tenant_id().clone(),
+                name: before.name().clone(),
                 description: before.description().map(|s| s.to_string()),
-                version:     before.version().next(),
-                definition:  before.definition().clone(),
-                status:      WorkflowDefinitionStatus::Published,
-                created_by:  before.created_by().clone(),
-                created_at:  before.created_at(),
-                updated_at:  now,
+                version: before.version().next(),
+                definition: before.definition().clone(),
+                status: WorkflowDefinitionStatus::Published,
+                confidential: false,
+                default_watchers: Vec::new(),
+                created_by: before.created_by().clone(),
+                created_at: before.created_at(),
+                updated_at: now,
             });
             assert_eq!(sut, expected);
         }
@@ -481,16 +652,18 @@ mod tests {
             let sut = published.archived(now).unwrap();
 
             let expected = WorkflowDefinition::from_db(WorkflowDefinitionRecord {
-                id:          before.id().clone(),
-                tenant_id:   before.tenant_id().clone(),
-                name:        before.name().clone(),
+                id: before.id().clone(),
+                tenant_id: before.tenant_id().clone(),
+                name: before.name().clone(),
                 description: before.description().map(|s| s.to_string()),
-                version:     before.version().next(),
-                definition:  before.definition().clone(),
-                status:      WorkflowDefinitionStatus::Archived,
-                created_by:  before.created_by().clone(),
-                created_at:  before.created_at(),
-                updated_at:  now,
+                version: before.version().next(),
+                definition: before.definition().clone(),
+                status: WorkflowDefinitionStatus::Archived,
+                confidential: false,
+                default_watchers: Vec::new(),
+                created_by: before.created_by().clone(),
+                created_at: before.created_at(),
+                updated_at: now,
             });
             assert_eq!(sut, expected);
         }
@@ -545,6 +718,34 @@ mod tests {
             assert_eq!(sut.status(), WorkflowDefinitionStatus::Draft);
         }
 
+        #[rstest]
+        fn test_新規作成時は機密定義ではない(test_definition: WorkflowDefinition) {
+            assert!(!test_definition.is_confidential());
+        }
+
+        #[rstest]
+        fn test_機密区分を設定できる(test_definition: WorkflowDefinition) {
+            let sut = test_definition.with_confidential(true);
+
+            assert!(sut.is_confidential());
+        }
+
+        #[rstest]
+        fn test_既定ウォッチャーは重複を除いて設定される(
+            test_definition: WorkflowDefinition,
+        ) {
+            let lead = UserId::new();
+            let manager = UserId::new();
+
+            let sut = test_definition.with_default_watchers(vec![
+                lead.clone(),
+                manager.clone(),
+                lead.clone(),
+            ]);
+
+            assert_eq!(sut.default_watchers(), &[lead, manager]);
+        }
+
         #[rstest]
         fn test_published定義の更新はエラー(
             test_definition: WorkflowDefinition,
@@ -50,7 +50,7 @@ impl ValidationError {
 
 /// ワークフロー定義 JSON をバリデーションする
 ///
-/// 10 のルールを順に検証し、すべてのエラーを収集して返す。
+/// 12 のルールを順に検証し、すべてのエラーを収集して返す。
 pub fn validate_definition(definition: &JsonValue) -> ValidationResult {
     let mut errors = Vec::new();
 
@@ -63,6 +63,8 @@ pub fn validate_definition(definition: &JsonValue) -> ValidationResult {
     validate_no_cycles(definition, &mut errors);
     validate_approval_transitions(definition, &mut errors);
     validate_form_fields(definition, &mut errors);
+    validate_editable_fields(definition, &mut errors);
+    validate_retract_windows(definition, &mut errors);
 
     ValidationResult {
         valid: errors.is_empty(),
@@ -424,6 +426,17 @@ fn validate_form_fields(definition: &JsonValue, errors: &mut Vec<ValidationError
             }
         }
 
+        // copyable は省略可、指定時は真偽値
+        if field.get("copyable").is_some_and(|v| !v.is_boolean()) {
+            errors.push(ValidationError::new(
+                "invalid_form_field",
+                format!(
+                    "フォームフィールド '{}' の copyable は真偽値である必要があります",
+                    id
+                ),
+            ));
+        }
+
         // file の固有バリデーション
         if field_type == Some("file") {
             validate_file_field_options(field, id, errors);
@@ -431,6 +444,111 @@ fn validate_form_fields(definition: &JsonValue, errors: &mut Vec<ValidationError
     }
 }
 
+/// ルール 11: approval ステップの編集可能フィールドの参照チェック
+///
+/// `editableFields` は任意で、指定時はフォームフィールド ID の文字列配列。
+/// file フィールドは添付ファイルの差し替えになるため編集対象にできない。
+fn validate_editable_fields(definition: &JsonValue, errors: &mut Vec<ValidationError>) {
+    let Some(steps) = get_steps(definition) else {
+        return;
+    };
+
+    let form_fields: HashMap<&str, Option<&str>> = definition
+        .get("form")
+        .and_then(|f| f.get("fields"))
+        .and_then(|f| f.as_array())
+        .map(|fields| {
+            fields
+                .iter()
+                .filter_map(|field| {
+                    let id = field.get("id").and_then(|v| v.as_str())?;
+                    Some((id, field.get("type").and_then(|v| v.as_str())))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for step in steps.iter().filter(|s| step_type(s) == Some("approval")) {
+        let Some(editable_fields) = step.get("editableFields") else {
+            continue;
+        };
+        let approval_id = step_id(step).unwrap_or_default();
+
+        let Some(editable_fields) = editable_fields.as_array() else {
+            errors.push(ValidationError::with_step_id(
+                "invalid_editable_field",
+                format!(
+                    "承認ステップ '{}' の editableFields は配列で指定してください",
+                    approval_id
+                ),
+                approval_id,
+            ));
+            continue;
+        };
+
+        for field in editable_fields {
+            let Some(field_id) = field.as_str() else {
+                errors.push(ValidationError::with_step_id(
+                    "invalid_editable_field",
+                    format!(
+                        "承認ステップ '{}' の editableFields には文字列を指定してください",
+                        approval_id
+                    ),
+                    approval_id,
+                ));
+                continue;
+            };
+
+            match form_fields.get(field_id) {
+                None => errors.push(ValidationError::with_step_id(
+                    "invalid_editable_field",
+                    format!(
+                        "承認ステップ '{}' の編集可能フィールド '{}' はフォームに存在しません",
+                        approval_id, field_id
+                    ),
+                    approval_id,
+                )),
+                Some(Some("file")) => errors.push(ValidationError::with_step_id(
+                    "invalid_editable_field",
+                    format!(
+                        "承認ステップ '{}' の編集可能フィールド '{}' は file 型のため指定できません",
+                        approval_id, field_id
+                    ),
+                    approval_id,
+                )),
+                Some(_) => {}
+            }
+        }
+    }
+}
+
+/// ルール 12: approval ステップの承認取り消し期限の値チェック
+///
+/// `retractWindowMinutes` は任意で、指定時は 1 以上の整数（分）。
+fn validate_retract_windows(definition: &JsonValue, errors: &mut Vec<ValidationError>) {
+    let Some(steps) = get_steps(definition) else {
+        return;
+    };
+
+    for step in steps.iter().filter(|s| step_type(s) == Some("approval")) {
+        let Some(window) = step.get("retractWindowMinutes") else {
+            continue;
+        };
+        if matches!(window.as_u64(), Some(n) if n >= 1 && u32::try_from(n).is_ok()) {
+            continue;
+        }
+        let approval_id = step_id(step).unwrap_or_default();
+        errors.push(ValidationError::with_step_id(
+            "invalid_retract_window",
+            format!(
+                "承認ステップ '{}' の retractWindowMinutes は 1 以上の整数（分）で指定してください",
+                approval_id
+            ),
+            approval_id,
+        ));
+    }
+}
+
 /// file フィールドの固有プロパティを検証する
 ///
 /// maxFiles, maxFileSize は任意で、指定時は 1 以上の整数。
@@ -899,6 +1017,30 @@ mod tests {
         assert!(has_error(&result, "invalid_form_field"));
     }
 
+    #[test]
+    fn test_フォームフィールドのcopyableが真偽値でない場合エラー() {
+        let definition = json!({
+            "form": {
+                "fields": [{"id": "f1", "type": "text", "label": "名前", "copyable": "no"}]
+            },
+            "steps": [
+                {"id": "start", "type": "start", "name": "開始"},
+                {"id": "approval_1", "type": "approval", "name": "承認"},
+                {"id": "end_approved", "type": "end", "name": "完了", "status": "approved"},
+                {"id": "end_rejected", "type": "end", "name": "却下", "status": "rejected"}
+            ],
+            "transitions": [
+                {"from": "start", "to": "approval_1"},
+                {"from": "approval_1", "to": "end_approved", "trigger": "approve"},
+                {"from": "approval_1", "to": "end_rejected", "trigger": "reject"}
+            ]
+        });
+
+        let result = validate_definition(&definition);
+
+        assert!(has_error(&result, "invalid_form_field"));
+    }
+
     #[test]
     fn test_フォームフィールドidが重複している場合エラー() {
         let definition = json!({
@@ -1121,6 +1263,78 @@ mod tests {
         assert!(result.valid, "errors: {:?}", result.errors);
     }
 
+    #[test]
+    fn test_編集可能フィールドがフォームに存在すれば正常() {
+        let mut definition = valid_definition();
+        definition["steps"][1]["editableFields"] = json!(["amount"]);
+
+        let result = validate_definition(&definition);
+
+        assert!(result.valid, "errors: {:?}", result.errors);
+    }
+
+    #[test]
+    fn test_編集可能フィールドがフォームに存在しない場合エラー() {
+        let mut definition = valid_definition();
+        definition["steps"][1]["editableFields"] = json!(["unknown"]);
+
+        let result = validate_definition(&definition);
+
+        assert!(!result.valid);
+        let error = result
+            .errors
+            .iter()
+            .find(|e| e.code == "invalid_editable_field")
+            .unwrap();
+        assert_eq!(error.step_id.as_deref(), Some("approval_1"));
+    }
+
+    #[test]
+    fn test_編集可能フィールドにfileフィールドを指定した場合エラー() {
+        let mut definition = valid_definition();
+        definition["form"]["fields"]
+            .as_array_mut()
+            .unwrap()
+            .push(json!({"id": "receipt", "type": "file", "label": "領収書"}));
+        definition["steps"][1]["editableFields"] = json!(["receipt"]);
+
+        let result = validate_definition(&definition);
+
+        assert!(has_error(&result, "invalid_editable_field"));
+    }
+
+    #[test]
+    fn test_編集可能フィールドが配列でない場合エラー() {
+        let mut definition = valid_definition();
+        definition["steps"][1]["editableFields"] = json!("amount");
+
+        let result = validate_definition(&definition);
+
+        assert!(has_error(&result, "invalid_editable_field"));
+    }
+
+    #[test]
+    fn test_承認取り消しの期限が1以上の整数なら正常() {
+        let mut definition = valid_definition();
+        definition["steps"][1]["retractWindowMinutes"] = json!(30);
+
+        let result = validate_definition(&definition);
+
+        assert!(result.valid, "errors: {:?}", result.errors);
+    }
+
+    #[test]
+    fn test_承認取り消しの期限が不正な値の場合エラー() {
+        for window in [json!(0), json!(-5), json!(1.5), json!("30")] {
+            let mut definition = valid_definition();
+            definition["steps"][1]["retractWindowMinutes"] = window;
+
+            let result = validate_definition(&definition);
+
+            assert!(has_error(&result, "invalid_retract_window"));
+        }
+    }
+
     // --- テストヘルパー ---
 
     fn has_error(result: &ValidationResult, code: &str) -> bool {
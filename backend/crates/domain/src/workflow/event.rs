@@ -0,0 +1,319 @@
+//! # ワークフロードメインイベント
+//!
+//! インスタンス・ステップの状態遷移（申請、承認、却下など）を表すイベント。
+//!
+//! イベントは状態遷移と同一トランザクションでアウトボックスに書き込まれ、
+//! ディスパッチャが通知などのコンシューマに少なくとも 1 回（at-least-once）配信する。
+//! コミット直後にプロセスが停止しても、コミット済みの状態遷移に対応するイベントは失われない。
+//!
+//! ペイロードはイベント発生時点のインスタンス・ステップの情報を保持する。
+//! 配信時にはインスタンスの状態が先に進んでいる可能性があるため、
+//! コンシューマは現在の状態ではなくペイロードを参照する。
+//!
+//! ## イベント種別とペイロード
+//!
+//! | 種別 | `step` | `activated_step` |
+//! |------|--------|------------------|
+//! | `submitted` / `resubmitted` | なし | 最初にアクティブになったステップ |
+//! | `step_approved` | 承認されたステップ | 次にアクティブになったステップ |
+//! | `approved` | 承認された最終ステップ | なし |
+//! | `rejected` / `changes_requested` | 却下・差し戻しされたステップ | なし |
+//! | `approval_retracted` | 待機中に戻った次のステップ | 承認が取り消され再びアクティブになったステップ |
+//! | `step_reassigned` | 担当者変更前のステップ | 担当者変更後のステップ |
+//! | `cancelled` | 取消時にアクティブだったステップ（なければなし） | なし |
+//! | `comment_posted` | なし | なし |
+//! | `mentioned` | なし | なし |
+//!
+//! `comment_posted` と `mentioned` はインスタンスの状態遷移ではなく、コメントの投稿とメンションを表す。
+//! コメント本文（`mentioned` ではメンションされたユーザーも）は `comment` に保持する。
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum::IntoStaticStr;
+
+use super::{
+    comment::{WorkflowComment, WorkflowCommentId},
+    instance::{WorkflowInstance, WorkflowInstanceId},
+    step::{WorkflowStep, WorkflowStepId},
+};
+use crate::{
+    DomainError,
+    message::Message,
+    tenant::TenantId,
+    user::UserId,
+    value_objects::DisplayNumber,
+};
+
+define_uuid_id! {
+    /// ワークフローイベント ID
+    pub struct WorkflowEventId;
+}
+
+/// ワークフローイベント種別
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, IntoStaticStr, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WorkflowEventType {
+    /// 申請: 最初のステップがアクティブになった
+    Submitted,
+    /// 再申請: 差し戻し後に最初のステップが再びアクティブになった
+    Resubmitted,
+    /// ステップ承認（中間）: 次のステップがアクティブになった
+    StepApproved,
+    /// 承認完了: 最終ステップの承認でインスタンスが Approved になった
+    Approved,
+    /// 却下: インスタンスが Rejected になった
+    Rejected,
+    /// 差し戻し: インスタンスが ChangesRequested になった
+    ChangesRequested,
+    /// 承認取り消し: 承認者が承認を取り消し、前のステップが再びアクティブになった
+    ApprovalRetracted,
+    /// 担当者変更: 管理者がアクティブなステップの担当者を差し替えた
+    StepReassigned,
+    /// 取消: 管理者がインスタンスを強制的に Cancelled にした
+    Cancelled,
+    /// コメント投稿: ワークフローにコメント（返信を含む）が投稿された
+    CommentPosted,
+    /// メンション: コメントでユーザーがメンションされた
+    Mentioned,
+}
+
+impl std::str::FromStr for WorkflowEventType {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "submitted" => Ok(Self::Submitted),
+            "resubmitted" => Ok(Self::Resubmitted),
+            "step_approved" => Ok(Self::StepApproved),
+            "approved" => Ok(Self::Approved),
+            "rejected" => Ok(Self::Rejected),
+            "changes_requested" => Ok(Self::ChangesRequested),
+            "approval_retracted" => Ok(Self::ApprovalRetracted),
+            "step_reassigned" => Ok(Self::StepReassigned),
+            "cancelled" => Ok(Self::Cancelled),
+            "comment_posted" => Ok(Self::CommentPosted),
+            "mentioned" => Ok(Self::Mentioned),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-workflow-event-type").with("value", s),
+            )),
+        }
+    }
+}
+
+/// イベント発生時点のステップ情報
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowEventStep {
+    pub step_id:     WorkflowStepId,
+    pub step_name:   String,
+    pub assigned_to: Option<UserId>,
+    pub comment:     Option<String>,
+}
+
+impl From<&WorkflowStep> for WorkflowEventStep {
+    fn from(step: &WorkflowStep) -> Self {
+        Self {
+            step_id:     step.id().clone(),
+            step_name:   step.step_name().to_string(),
+            assigned_to: step.assigned_to().cloned(),
+            comment:     step.comment().map(|c| c.to_string()),
+        }
+    }
+}
+
+/// イベント発生時点のコメント情報（`comment_posted` / `mentioned` イベントのみ）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowEventComment {
+    pub comment_id: WorkflowCommentId,
+    pub body:       String,
+    /// メンションされたユーザー（投稿者本人を除く。`comment_posted` では空）
+    pub mentioned:  Vec<UserId>,
+}
+
+/// イベント発生時点のインスタンス情報
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowEventPayload {
+    pub display_number: DisplayNumber,
+    pub title: String,
+    pub initiated_by: UserId,
+    /// 代理申請の代理人（本人申請の場合は `None`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy_submitter: Option<UserId>,
+    /// 操作対象のステップ（承認・却下・差し戻しされたステップ、承認取り消しでは待機中に戻ったステップ）
+    pub step: Option<WorkflowEventStep>,
+    /// このイベントでアクティブになったステップ
+    pub activated_step: Option<WorkflowEventStep>,
+    /// 投稿・メンションのコメント（`comment_posted` / `mentioned` 以外のイベントでは `None`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<WorkflowEventComment>,
+}
+
+impl WorkflowEventPayload {
+    /// 遷移後のインスタンスとステップからペイロードを作成する
+    pub fn new(
+        instance: &WorkflowInstance,
+        step: Option<&WorkflowStep>,
+        activated_step: Option<&WorkflowStep>,
+    ) -> Self {
+        Self {
+            display_number: instance.display_number(),
+            title: instance.title().to_string(),
+            initiated_by: instance.initiated_by().clone(),
+            proxy_submitter: instance.proxy_submitter().cloned(),
+            step: step.map(WorkflowEventStep::from),
+            activated_step: activated_step.map(WorkflowEventStep::from),
+            comment: None,
+        }
+    }
+
+    /// コメント投稿のペイロードを作成する
+    pub fn comment_posted(instance: &WorkflowInstance, comment: &WorkflowComment) -> Self {
+        Self::mentioned(instance, comment, Vec::new())
+    }
+
+    /// コメントでのメンションのペイロードを作成する
+    pub fn mentioned(
+        instance: &WorkflowInstance,
+        comment: &WorkflowComment,
+        mentioned: Vec<UserId>,
+    ) -> Self {
+        Self {
+            comment: Some(WorkflowEventComment {
+                comment_id: comment.id().clone(),
+                body: comment.body().as_str().to_string(),
+                mentioned,
+            }),
+            ..Self::new(instance, None, None)
+        }
+    }
+}
+
+/// ワークフローイベントエンティティ
+///
+/// 1 回の状態遷移を表す。記録後は不変。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowEvent {
+    id:          WorkflowEventId,
+    tenant_id:   TenantId,
+    instance_id: WorkflowInstanceId,
+    event_type:  WorkflowEventType,
+    actor_id:    Option<UserId>,
+    payload:     WorkflowEventPayload,
+    occurred_at: DateTime<Utc>,
+}
+
+/// ワークフローイベントの新規作成パラメータ
+pub struct NewWorkflowEvent {
+    pub id:          WorkflowEventId,
+    pub tenant_id:   TenantId,
+    pub instance_id: WorkflowInstanceId,
+    pub event_type:  WorkflowEventType,
+    /// 操作者（システムによる操作の場合は `None`）
+    pub actor_id:    Option<UserId>,
+    pub payload:     WorkflowEventPayload,
+    pub now:         DateTime<Utc>,
+}
+
+/// ワークフローイベントの DB 復元パラメータ
+pub struct WorkflowEventRecord {
+    pub id:          WorkflowEventId,
+    pub tenant_id:   TenantId,
+    pub instance_id: WorkflowInstanceId,
+    pub event_type:  WorkflowEventType,
+    pub actor_id:    Option<UserId>,
+    pub payload:     WorkflowEventPayload,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl WorkflowEvent {
+    /// 新しいイベントを作成する
+    pub fn new(params: NewWorkflowEvent) -> Self {
+        Self {
+            id:          params.id,
+            tenant_id:   params.tenant_id,
+            instance_id: params.instance_id,
+            event_type:  params.event_type,
+            actor_id:    params.actor_id,
+            payload:     params.payload,
+            occurred_at: params.now,
+        }
+    }
+
+    /// 既存のデータから復元する
+    pub fn from_db(record: WorkflowEventRecord) -> Self {
+        Self {
+            id:          record.id,
+            tenant_id:   record.tenant_id,
+            instance_id: record.instance_id,
+            event_type:  record.event_type,
+            actor_id:    record.actor_id,
+            payload:     record.payload,
+            occurred_at: record.occurred_at,
+        }
+    }
+
+    // Getter メソッド
+
+    pub fn id(&self) -> &WorkflowEventId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn instance_id(&self) -> &WorkflowInstanceId {
+        &self.instance_id
+    }
+
+    pub fn event_type(&self) -> WorkflowEventType {
+        self.event_type
+    }
+
+    pub fn actor_id(&self) -> Option<&UserId> {
+        self.actor_id.as_ref()
+    }
+
+    pub fn payload(&self) -> &WorkflowEventPayload {
+        &self.payload
+    }
+
+    pub fn occurred_at(&self) -> DateTime<Utc> {
+        self.occurred_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(WorkflowEventType::Submitted, "submitted")]
+    #[case(WorkflowEventType::Resubmitted, "resubmitted")]
+    #[case(WorkflowEventType::StepApproved, "step_approved")]
+    #[case(WorkflowEventType::Approved, "approved")]
+    #[case(WorkflowEventType::Rejected, "rejected")]
+    #[case(WorkflowEventType::ChangesRequested, "changes_requested")]
+    #[case(WorkflowEventType::ApprovalRetracted, "approval_retracted")]
+    #[case(WorkflowEventType::StepReassigned, "step_reassigned")]
+    #[case(WorkflowEventType::Cancelled, "cancelled")]
+    #[case(WorkflowEventType::CommentPosted, "comment_posted")]
+    #[case(WorkflowEventType::Mentioned, "mentioned")]
+    fn test_イベント種別は文字列と相互変換できる(
+        #[case] event_type: WorkflowEventType,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(event_type.to_string(), expected);
+        assert_eq!(expected.parse::<WorkflowEventType>().unwrap(), event_type);
+    }
+
+    #[test]
+    fn test_不正なイベント種別はエラーになる() {
+        assert!("unknown".parse::<WorkflowEventType>().is_err());
+    }
+}
@@ -0,0 +1,191 @@
+//! # フォームデータ変更履歴
+//!
+//! 承認者が承認時にフォームデータを編集した際の、フィールド単位の変更履歴を管理する。
+//! 誰が・どのステップで・どのフィールドを・何から何へ変更したかを記録し、
+//! ワークフロー詳細で申請者や後続の承認者が確認できるようにする。
+//!
+//! 編集できるフィールドは定義 JSON の承認ステップに `editableFields` として宣言する。
+//! 参照: [`ApprovalStepDef::editable_fields`](super::ApprovalStepDef)
+
+use chrono::{DateTime, Utc};
+use serde_json::Value as JsonValue;
+
+use super::{instance::WorkflowInstanceId, step::WorkflowStepId};
+use crate::{tenant::TenantId, user::UserId};
+
+define_uuid_id! {
+    /// フォームデータ変更履歴 ID
+    pub struct WorkflowFormDataChangeId;
+}
+
+/// フィールド単位の差分
+///
+/// フォームデータの編集で値が変わったフィールドを表す。
+/// 編集前に存在しなかったフィールドの `old_value` は `JsonValue::Null` になる。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormFieldDiff {
+    pub field_id:  String,
+    pub old_value: JsonValue,
+    pub new_value: JsonValue,
+}
+
+/// フォームデータ変更履歴エンティティ
+///
+/// 1 フィールドの 1 回の変更を表す。変更後は不変。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowFormDataChange {
+    id:          WorkflowFormDataChangeId,
+    tenant_id:   TenantId,
+    instance_id: WorkflowInstanceId,
+    step_id:     WorkflowStepId,
+    field_id:    String,
+    old_value:   JsonValue,
+    new_value:   JsonValue,
+    changed_by:  UserId,
+    changed_at:  DateTime<Utc>,
+}
+
+/// フォームデータ変更履歴の新規作成パラメータ
+pub struct NewWorkflowFormDataChange {
+    pub id:          WorkflowFormDataChangeId,
+    pub tenant_id:   TenantId,
+    pub instance_id: WorkflowInstanceId,
+    pub step_id:     WorkflowStepId,
+    pub diff:        FormFieldDiff,
+    pub changed_by:  UserId,
+    pub now:         DateTime<Utc>,
+}
+
+/// フォームデータ変更履歴の DB 復元パラメータ
+pub struct WorkflowFormDataChangeRecord {
+    pub id:          WorkflowFormDataChangeId,
+    pub tenant_id:   TenantId,
+    pub instance_id: WorkflowInstanceId,
+    pub step_id:     WorkflowStepId,
+    pub field_id:    String,
+    pub old_value:   JsonValue,
+    pub new_value:   JsonValue,
+    pub changed_by:  UserId,
+    pub changed_at:  DateTime<Utc>,
+}
+
+impl WorkflowFormDataChange {
+    /// 差分から新しい変更履歴を作成する
+    pub fn new(params: NewWorkflowFormDataChange) -> Self {
+        Self {
+            id:          params.id,
+            tenant_id:   params.tenant_id,
+            instance_id: params.instance_id,
+            step_id:     params.step_id,
+            field_id:    params.diff.field_id,
+            old_value:   params.diff.old_value,
+            new_value:   params.diff.new_value,
+            changed_by:  params.changed_by,
+            changed_at:  params.now,
+        }
+    }
+
+    /// 既存のデータから復元する
+    pub fn from_db(record: WorkflowFormDataChangeRecord) -> Self {
+        Self {
+            id:          record.id,
+            tenant_id:   record.tenant_id,
+            instance_id: record.instance_id,
+            step_id:     record.step_id,
+            field_id:    record.field_id,
+            old_value:   record.old_value,
+            new_value:   record.new_value,
+            changed_by:  record.changed_by,
+            changed_at:  record.changed_at,
+        }
+    }
+
+    // Getter メソッド
+
+    pub fn id(&self) -> &WorkflowFormDataChangeId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn instance_id(&self) -> &WorkflowInstanceId {
+        &self.instance_id
+    }
+
+    pub fn step_id(&self) -> &WorkflowStepId {
+        &self.step_id
+    }
+
+    pub fn field_id(&self) -> &str {
+        &self.field_id
+    }
+
+    pub fn old_value(&self) -> &JsonValue {
+        &self.old_value
+    }
+
+    pub fn new_value(&self) -> &JsonValue {
+        &self.new_value
+    }
+
+    pub fn changed_by(&self) -> &UserId {
+        &self.changed_by
+    }
+
+    pub fn changed_at(&self) -> DateTime<Utc> {
+        self.changed_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use serde_json::json;
+
+    use super::*;
+
+    /// テスト用の固定タイムスタンプ
+    #[fixture]
+    fn now() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    #[rstest]
+    fn test_差分から変更履歴を作成できる(now: DateTime<Utc>) {
+        let id = WorkflowFormDataChangeId::new();
+        let tenant_id = TenantId::new();
+        let instance_id = WorkflowInstanceId::new();
+        let step_id = WorkflowStepId::new();
+        let changed_by = UserId::new();
+
+        let sut = WorkflowFormDataChange::new(NewWorkflowFormDataChange {
+            id: id.clone(),
+            tenant_id: tenant_id.clone(),
+            instance_id: instance_id.clone(),
+            step_id: step_id.clone(),
+            diff: FormFieldDiff {
+                field_id:  "amount".to_string(),
+                old_value: json!(1000),
+                new_value: json!(800),
+            },
+            changed_by: changed_by.clone(),
+            now,
+        });
+
+        let expected = WorkflowFormDataChange::from_db(WorkflowFormDataChangeRecord {
+            id,
+            tenant_id,
+            instance_id,
+            step_id,
+            field_id: "amount".to_string(),
+            old_value: json!(1000),
+            new_value: json!(800),
+            changed_by,
+            changed_at: now,
+        });
+        assert_eq!(sut, expected);
+    }
+}
@@ -26,6 +26,7 @@ use strum::IntoStaticStr;
 use super::definition::WorkflowDefinitionId;
 use crate::{
     DomainError,
+    message::Message,
     tenant::TenantId,
     user::UserId,
     value_objects::{DisplayNumber, Version},
@@ -71,10 +72,9 @@ impl std::str::FromStr for WorkflowInstanceStatus {
             "rejected" => Ok(Self::Rejected),
             "cancelled" => Ok(Self::Cancelled),
             "changes_requested" => Ok(Self::ChangesRequested),
-            _ => Err(DomainError::Validation(format!(
-                "不正なワークフローインスタンスステータス: {}",
-                s
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-workflow-instance-status").with("value", s),
+            )),
         }
     }
 }
@@ -103,6 +103,7 @@ pub struct WorkflowInstance {
     form_data: JsonValue,
     version: Version,
     initiated_by: UserId,
+    submitted_by: UserId,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
     state: WorkflowInstanceState,
@@ -133,6 +134,7 @@ impl WorkflowInstance {
             title: params.title,
             form_data: params.form_data,
             version: Version::initial(),
+            submitted_by: params.initiated_by.clone(),
             initiated_by: params.initiated_by,
             created_at: params.now,
             updated_at: params.now,
@@ -140,6 +142,15 @@ impl WorkflowInstance {
         }
     }
 
+    /// 代理申請として、実際に作成・申請するユーザー（代理人）を設定する
+    ///
+    /// `initiated_by` は本人（申請者）のまま。代理人は本人から代理権限を
+    /// 付与されている必要がある（検証はユースケースで行う）。
+    pub fn with_proxy_submitter(mut self, submitted_by: UserId) -> Self {
+        self.submitted_by = submitted_by;
+        self
+    }
+
     // Getter メソッド
 
     pub fn id(&self) -> &WorkflowInstanceId {
@@ -202,6 +213,26 @@ impl WorkflowInstance {
         &self.initiated_by
     }
 
+    /// 実際に作成・申請したユーザー（代理申請の場合は代理人、それ以外は申請者本人）
+    pub fn submitted_by(&self) -> &UserId {
+        &self.submitted_by
+    }
+
+    /// 代理申請か（申請者本人以外が作成・申請したか）
+    pub fn is_proxy_submission(&self) -> bool {
+        self.submitted_by != self.initiated_by
+    }
+
+    /// 代理申請の代理人（本人申請の場合は `None`）
+    pub fn proxy_submitter(&self) -> Option<&UserId> {
+        self.is_proxy_submission().then_some(&self.submitted_by)
+    }
+
+    /// ユーザーが申請者として操作できるか（申請者本人または代理人）
+    pub fn is_applicant(&self, user_id: &UserId) -> bool {
+        &self.initiated_by == user_id || &self.submitted_by == user_id
+    }
+
     pub fn submitted_at(&self) -> Option<DateTime<Utc>> {
         match &self.state {
             WorkflowInstanceState::Draft => None,
@@ -274,6 +305,7 @@ mod tests {
         use pretty_assertions::assert_eq;
 
         use super::*;
+        use crate::workflow::FormFieldDiff;
 
         /// WorkflowInstance の getter から WorkflowInstanceRecord を構築するヘルパー。
         /// 構造体更新構文 `..record_from(&instance)` と組み合わせて、
@@ -291,6 +323,7 @@ mod tests {
                 version: instance.version(),
                 current_step_id: instance.current_step_id().map(String::from),
                 initiated_by: instance.initiated_by().clone(),
+                proxy_submitted_by: instance.proxy_submitter().cloned(),
                 submitted_at: instance.submitted_at(),
                 completed_at: instance.completed_at(),
                 created_at: instance.created_at(),
@@ -304,6 +337,31 @@ mod tests {
             assert_eq!(test_instance, expected);
         }
 
+        #[rstest]
+        fn test_新規作成時は申請者本人が申請操作者になる(
+            test_instance: WorkflowInstance,
+        ) {
+            assert_eq!(test_instance.submitted_by(), test_instance.initiated_by());
+            assert!(!test_instance.is_proxy_submission());
+        }
+
+        #[rstest]
+        fn test_代理人を設定すると代理申請になり両者が申請者として操作できる(
+            test_instance: WorkflowInstance,
+        ) {
+            let principal = test_instance.initiated_by().clone();
+            let proxy = UserId::new();
+
+            let sut = test_instance.with_proxy_submitter(proxy.clone());
+
+            assert_eq!(sut.initiated_by(), &principal);
+            assert_eq!(sut.submitted_by(), &proxy);
+            assert!(sut.is_proxy_submission());
+            assert!(sut.is_applicant(&principal));
+            assert!(sut.is_applicant(&proxy));
+            assert!(!sut.is_applicant(&UserId::new()));
+        }
+
         #[rstest]
         fn test_申請後の状態(test_instance: WorkflowInstance, now: DateTime<Utc>) {
             let before = test_instance.clone();
@@ -554,6 +612,152 @@ mod tests {
             assert_eq!(sut.version(), before_version.next());
         }
 
+        #[rstest]
+        fn test_前ステップへの復帰_処理中で成功(
+            test_instance: WorkflowInstance,
+            now: DateTime<Utc>,
+        ) {
+            let instance = test_instance
+                .submitted(now)
+                .unwrap()
+                .with_current_step("step_1".to_string(), now)
+                .unwrap()
+                .advance_to_next_step("step_2".to_string(), now)
+                .unwrap();
+            let before = instance.clone();
+
+            let sut = instance.revert_to_step("step_1".to_string(), now).unwrap();
+
+            let expected = WorkflowInstance::from_db(WorkflowInstanceRecord {
+                version: before.version().next(),
+                current_step_id: Some("step_1".to_string()),
+                updated_at: now,
+                ..record_from(&before)
+            })
+            .unwrap();
+            assert_eq!(sut, expected);
+        }
+
+        #[rstest]
+        fn test_前ステップへの復帰_処理中以外ではエラー(
+            test_instance: WorkflowInstance,
+            now: DateTime<Utc>,
+        ) {
+            let approved = test_instance
+                .submitted(now)
+                .unwrap()
+                .with_current_step("step_1".to_string(), now)
+                .unwrap()
+                .complete_with_approval(now)
+                .unwrap();
+
+            let result = approved.revert_to_step("step_1".to_string(), now);
+
+            assert!(result.is_err());
+        }
+
+        // --- with_approver_edits() テスト ---
+
+        #[rstest]
+        fn test_承認者編集で値が変わったフィールドの差分を返す(
+            test_instance: WorkflowInstance,
+            now: DateTime<Utc>,
+        ) {
+            let instance = test_instance
+                .submitted(now)
+                .unwrap()
+                .with_current_step("step_1".to_string(), now)
+                .unwrap();
+            let before = instance.clone();
+            let edits = json!({"field": "edited", "note": "追記"})
+                .as_object()
+                .unwrap()
+                .clone();
+            let editable_fields = vec!["field".to_string(), "note".to_string()];
+
+            let (sut, diffs) = instance
+                .with_approver_edits(edits, &editable_fields, now)
+                .unwrap();
+
+            let expected = WorkflowInstance::from_db(WorkflowInstanceRecord {
+                form_data: json!({"field": "edited", "note": "追記"}),
+                updated_at: now,
+                ..record_from(&before)
+            })
+            .unwrap();
+            assert_eq!(sut, expected);
+            assert_eq!(
+                diffs,
+                vec![
+                    FormFieldDiff {
+                        field_id:  "field".to_string(),
+                        old_value: json!("value"),
+                        new_value: json!("edited"),
+                    },
+                    FormFieldDiff {
+                        field_id:  "note".to_string(),
+                        old_value: JsonValue::Null,
+                        new_value: json!("追記"),
+                    },
+                ]
+            );
+        }
+
+        #[rstest]
+        fn test_承認者編集で値が同じフィールドは差分に含まれない(
+            test_instance: WorkflowInstance,
+            now: DateTime<Utc>,
+        ) {
+            let instance = test_instance
+                .submitted(now)
+                .unwrap()
+                .with_current_step("step_1".to_string(), now)
+                .unwrap();
+            let before = instance.clone();
+            let edits = json!({"field": "value"}).as_object().unwrap().clone();
+
+            let (sut, diffs) = instance
+                .with_approver_edits(edits, &["field".to_string()], now)
+                .unwrap();
+
+            assert_eq!(sut, before);
+            assert!(diffs.is_empty());
+        }
+
+        #[rstest]
+        fn test_承認者編集で編集不可のフィールドを含むとエラー(
+            test_instance: WorkflowInstance,
+            now: DateTime<Utc>,
+        ) {
+            let instance = test_instance
+                .submitted(now)
+                .unwrap()
+                .with_current_step("step_1".to_string(), now)
+                .unwrap();
+            let edits = json!({"field": "edited", "amount": 1})
+                .as_object()
+                .unwrap()
+                .clone();
+
+            let result = instance.with_approver_edits(edits, &["field".to_string()], now);
+
+            assert!(
+                matches!(result, Err(DomainError::Validation(msg)) if msg.to_string().contains("amount"))
+            );
+        }
+
+        #[rstest]
+        fn test_処理中以外で承認者編集するとエラー(
+            test_instance: WorkflowInstance,
+            now: DateTime<Utc>,
+        ) {
+            let edits = json!({"field": "edited"}).as_object().unwrap().clone();
+
+            let result = test_instance.with_approver_edits(edits, &["field".to_string()], now);
+
+            assert!(result.is_err());
+        }
+
         // --- with_current_step() 異常系テスト ---
 
         #[rstest]
@@ -581,6 +785,21 @@ mod tests {
             assert!(result.is_err());
         }
 
+        #[rstest]
+        fn test_下書きは削除できる(test_instance: WorkflowInstance) {
+            assert!(test_instance.can_delete().is_ok());
+        }
+
+        #[rstest]
+        fn test_申請済みは削除できない(
+            test_instance: WorkflowInstance,
+            now: DateTime<Utc>,
+        ) {
+            let instance = test_instance.submitted(now).unwrap();
+
+            assert!(instance.can_delete().is_err());
+        }
+
         #[rstest]
         fn test_処理中からの申請はエラー(
             test_instance: WorkflowInstance,
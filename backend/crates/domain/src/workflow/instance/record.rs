@@ -35,6 +35,7 @@
 //!     version: Version::initial(),
 //!     current_step_id: None,
 //!     initiated_by: UserId::new(),
+//!     proxy_submitted_by: None,
 //!     submitted_at: None,
 //!     completed_at: None,
 //!     created_at: now,
@@ -66,6 +67,7 @@ use super::{
 };
 use crate::{
     DomainError,
+    message::Message,
     tenant::TenantId,
     user::UserId,
     value_objects::{DisplayNumber, Version},
@@ -87,6 +89,8 @@ pub struct WorkflowInstanceRecord {
     pub version: Version,
     pub current_step_id: Option<String>,
     pub initiated_by: UserId,
+    /// 代理申請の代理人（本人申請の場合は `None`）
+    pub proxy_submitted_by: Option<UserId>,
     pub submitted_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
@@ -107,7 +111,9 @@ impl WorkflowInstance {
             WorkflowInstanceStatus::Pending => {
                 let submitted_at = record.submitted_at.ok_or_else(|| {
                     DomainError::Validation(
-                        "Pending インスタンスには submitted_at が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "Pending")
+                            .with("column", "submitted_at"),
                     )
                 })?;
                 WorkflowInstanceState::Pending(PendingState { submitted_at })
@@ -115,12 +121,16 @@ impl WorkflowInstance {
             WorkflowInstanceStatus::InProgress => {
                 let current_step_id = record.current_step_id.ok_or_else(|| {
                     DomainError::Validation(
-                        "InProgress インスタンスには current_step_id が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "InProgress")
+                            .with("column", "current_step_id"),
                     )
                 })?;
                 let submitted_at = record.submitted_at.ok_or_else(|| {
                     DomainError::Validation(
-                        "InProgress インスタンスには submitted_at が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "InProgress")
+                            .with("column", "submitted_at"),
                     )
                 })?;
                 WorkflowInstanceState::InProgress(InProgressState {
@@ -131,17 +141,23 @@ impl WorkflowInstance {
             WorkflowInstanceStatus::Approved => {
                 let current_step_id = record.current_step_id.ok_or_else(|| {
                     DomainError::Validation(
-                        "Approved インスタンスには current_step_id が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "Approved")
+                            .with("column", "current_step_id"),
                     )
                 })?;
                 let submitted_at = record.submitted_at.ok_or_else(|| {
                     DomainError::Validation(
-                        "Approved インスタンスには submitted_at が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "Approved")
+                            .with("column", "submitted_at"),
                     )
                 })?;
                 let completed_at = record.completed_at.ok_or_else(|| {
                     DomainError::Validation(
-                        "Approved インスタンスには completed_at が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "Approved")
+                            .with("column", "completed_at"),
                     )
                 })?;
                 WorkflowInstanceState::Approved(CompletedState {
@@ -153,17 +169,23 @@ impl WorkflowInstance {
             WorkflowInstanceStatus::Rejected => {
                 let current_step_id = record.current_step_id.ok_or_else(|| {
                     DomainError::Validation(
-                        "Rejected インスタンスには current_step_id が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "Rejected")
+                            .with("column", "current_step_id"),
                     )
                 })?;
                 let submitted_at = record.submitted_at.ok_or_else(|| {
                     DomainError::Validation(
-                        "Rejected インスタンスには submitted_at が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "Rejected")
+                            .with("column", "submitted_at"),
                     )
                 })?;
                 let completed_at = record.completed_at.ok_or_else(|| {
                     DomainError::Validation(
-                        "Rejected インスタンスには completed_at が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "Rejected")
+                            .with("column", "completed_at"),
                     )
                 })?;
                 WorkflowInstanceState::Rejected(CompletedState {
@@ -175,7 +197,9 @@ impl WorkflowInstance {
             WorkflowInstanceStatus::Cancelled => {
                 let completed_at = record.completed_at.ok_or_else(|| {
                     DomainError::Validation(
-                        "Cancelled インスタンスには completed_at が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "Cancelled")
+                            .with("column", "completed_at"),
                     )
                 })?;
                 let cancelled_state = match (record.current_step_id, record.submitted_at) {
@@ -190,10 +214,9 @@ impl WorkflowInstance {
                         completed_at,
                     },
                     (Some(_), None) => {
-                        return Err(DomainError::Validation(
-                                "Cancelled インスタンスで current_step_id がある場合は submitted_at が必要です"
-                                    .to_string(),
-                            ));
+                        return Err(DomainError::Validation(Message::new(
+                            "instance-cancelled-submitted-at-missing",
+                        )));
                     }
                 };
                 WorkflowInstanceState::Cancelled(cancelled_state)
@@ -201,12 +224,16 @@ impl WorkflowInstance {
             WorkflowInstanceStatus::ChangesRequested => {
                 let current_step_id = record.current_step_id.ok_or_else(|| {
                     DomainError::Validation(
-                        "ChangesRequested インスタンスには current_step_id が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "ChangesRequested")
+                            .with("column", "current_step_id"),
                     )
                 })?;
                 let submitted_at = record.submitted_at.ok_or_else(|| {
                     DomainError::Validation(
-                        "ChangesRequested インスタンスには submitted_at が必要です".to_string(),
+                        Message::new("instance-state-column-missing")
+                            .with("status", "ChangesRequested")
+                            .with("column", "submitted_at"),
                     )
                 })?;
                 WorkflowInstanceState::ChangesRequested(ChangesRequestedState {
@@ -225,6 +252,9 @@ impl WorkflowInstance {
             title: record.title,
             form_data: record.form_data,
             version: record.version,
+            submitted_by: record
+                .proxy_submitted_by
+                .unwrap_or_else(|| record.initiated_by.clone()),
             initiated_by: record.initiated_by,
             created_at: record.created_at,
             updated_at: record.updated_at,
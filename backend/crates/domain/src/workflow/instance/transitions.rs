@@ -1,6 +1,6 @@
 //! # ワークフローインスタンスの状態遷移
 //!
-//! 申請・承認・却下・差し戻し・再申請・取り消しの状態遷移メソッド。
+//! 申請・承認（承認の取り消しを含む）・却下・差し戻し・再申請・取り消しの状態遷移メソッド。
 //!
 //! ## 使用例
 //!
@@ -44,7 +44,7 @@
 //! 詳細: [エンティティ影響マップ](../../../docs/40_詳細設計書/エンティティ影響マップ/WorkflowInstance.md)
 
 use chrono::{DateTime, Utc};
-use serde_json::Value as JsonValue;
+use serde_json::{Map as JsonMap, Value as JsonValue};
 
 use super::{
     WorkflowInstance,
@@ -57,7 +57,7 @@ use super::{
         WorkflowInstanceState,
     },
 };
-use crate::DomainError;
+use crate::{DomainError, message::Message, workflow::FormFieldDiff};
 
 impl WorkflowInstance {
     // ビジネスロジックメソッド
@@ -66,9 +66,21 @@ impl WorkflowInstance {
     pub fn can_edit(&self) -> Result<(), DomainError> {
         match &self.state {
             WorkflowInstanceState::Draft => Ok(()),
-            _ => Err(DomainError::Validation(
-                "下書き状態でのみ編集可能です".to_string(),
-            )),
+            _ => Err(DomainError::Validation(Message::new(
+                "instance-edit-not-draft",
+            ))),
+        }
+    }
+
+    /// インスタンスが削除可能かチェックする
+    ///
+    /// 申請前の下書きのみ削除できる。申請後は監査のため履歴として残す。
+    pub fn can_delete(&self) -> Result<(), DomainError> {
+        match &self.state {
+            WorkflowInstanceState::Draft => Ok(()),
+            _ => Err(DomainError::Validation(Message::new(
+                "instance-delete-not-draft",
+            ))),
         }
     }
 
@@ -80,9 +92,9 @@ impl WorkflowInstance {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(
-                "下書き状態でのみ申請可能です".to_string(),
-            )),
+            _ => Err(DomainError::Validation(Message::new(
+                "instance-submit-not-draft",
+            ))),
         }
     }
 
@@ -124,9 +136,9 @@ impl WorkflowInstance {
             }),
             WorkflowInstanceState::Approved(_)
             | WorkflowInstanceState::Rejected(_)
-            | WorkflowInstanceState::Cancelled(_) => Err(DomainError::Validation(
-                "完了済みのワークフローは取り消せません".to_string(),
-            )),
+            | WorkflowInstanceState::Cancelled(_) => Err(DomainError::Validation(Message::new(
+                "instance-cancel-completed",
+            ))),
         }
     }
 
@@ -149,10 +161,9 @@ impl WorkflowInstance {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(format!(
-                "ステップ設定は承認待ち状態でのみ可能です（現在: {}）",
-                self.status()
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("instance-set-step-not-pending").with("status", self.status()),
+            )),
         }
     }
 
@@ -180,13 +191,109 @@ impl WorkflowInstance {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(format!(
-                "次ステップ遷移は処理中状態でのみ可能です（現在: {}）",
-                self.status()
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("instance-advance-not-in-progress").with("status", self.status()),
+            )),
+        }
+    }
+
+    /// 承認の取り消しで前の承認ステップに戻る
+    ///
+    /// InProgress 状態のインスタンスの current_step_id を、承認が取り消された
+    /// ステップに戻す。
+    /// version をインクリメントして楽観的ロックに対応。
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::Validation`: InProgress 以外の状態で呼び出した場合
+    pub fn revert_to_step(self, step_id: String, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        match self.state {
+            WorkflowInstanceState::InProgress(in_progress) => Ok(Self {
+                state: WorkflowInstanceState::InProgress(InProgressState {
+                    current_step_id: step_id,
+                    submitted_at:    in_progress.submitted_at,
+                }),
+                version: self.version.next(),
+                updated_at: now,
+                ..self
+            }),
+            _ => Err(DomainError::Validation(
+                Message::new("instance-retract-not-in-progress").with("status", self.status()),
+            )),
         }
     }
 
+    /// 承認者によるフォームデータ編集を適用する
+    ///
+    /// `edits` のキーはフォームフィールド ID、値は変更後の値。
+    /// `editable_fields` に含まれないフィールドの編集は拒否する。
+    /// 値が変わったフィールドの差分を返し、差分がなければフォームデータは変更しない。
+    ///
+    /// 承認処理の一部として呼び出され、直後の `advance_to_next_step` /
+    /// `complete_with_approval` で version がインクリメントされるため、
+    /// ここでは version を変更しない。
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::Validation`: InProgress 以外の状態で呼び出した場合
+    /// - `DomainError::Validation`: 編集不可のフィールドが含まれる場合
+    pub fn with_approver_edits(
+        self,
+        edits: JsonMap<String, JsonValue>,
+        editable_fields: &[String],
+        now: DateTime<Utc>,
+    ) -> Result<(Self, Vec<FormFieldDiff>), DomainError> {
+        if !matches!(self.state, WorkflowInstanceState::InProgress(_)) {
+            return Err(DomainError::Validation(
+                Message::new("instance-edit-form-not-in-progress").with("status", self.status()),
+            ));
+        }
+
+        let mut not_editable: Vec<&str> = edits
+            .keys()
+            .filter(|field_id| !editable_fields.contains(field_id))
+            .map(String::as_str)
+            .collect();
+        if !not_editable.is_empty() {
+            not_editable.sort_unstable();
+            return Err(DomainError::Validation(
+                Message::new("instance-fields-not-editable")
+                    .with("fields", not_editable.join(", ")),
+            ));
+        }
+
+        let mut form_data = match self.form_data {
+            JsonValue::Object(ref map) => map.clone(),
+            _ => JsonMap::new(),
+        };
+        let mut diffs = Vec::new();
+        for (field_id, new_value) in edits {
+            let old_value = form_data.get(&field_id).cloned().unwrap_or(JsonValue::Null);
+            if old_value == new_value {
+                continue;
+            }
+            form_data.insert(field_id.clone(), new_value.clone());
+            diffs.push(FormFieldDiff {
+                field_id,
+                old_value,
+                new_value,
+            });
+        }
+
+        if diffs.is_empty() {
+            return Ok((self, diffs));
+        }
+
+        Ok((
+            Self {
+                form_data: JsonValue::Object(form_data),
+                updated_at: now,
+                ..self
+            },
+            diffs,
+        ))
+    }
+
     /// ステップ承認による完了処理
     ///
     /// InProgress 状態のインスタンスを Approved に遷移させる。
@@ -207,10 +314,9 @@ impl WorkflowInstance {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(format!(
-                "承認完了は処理中状態でのみ可能です（現在: {}）",
-                self.status()
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("instance-approve-not-in-progress").with("status", self.status()),
+            )),
         }
     }
 
@@ -234,10 +340,10 @@ impl WorkflowInstance {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(format!(
-                "差し戻しは処理中状態でのみ可能です（現在: {}）",
-                self.status()
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("instance-request-changes-not-in-progress")
+                    .with("status", self.status()),
+            )),
         }
     }
 
@@ -267,10 +373,10 @@ impl WorkflowInstance {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(format!(
-                "再申請は要修正状態でのみ可能です（現在: {}）",
-                self.status()
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("instance-resubmit-not-changes-requested")
+                    .with("status", self.status()),
+            )),
         }
     }
 
@@ -294,10 +400,9 @@ impl WorkflowInstance {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(format!(
-                "却下完了は処理中状態でのみ可能です（現在: {}）",
-                self.status()
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("instance-reject-not-in-progress").with("status", self.status()),
+            )),
         }
     }
 }
@@ -0,0 +1,56 @@
+//! # 代理権限
+//!
+//! 本人（プリンシパル）に代わってワークフローを作成・申請できるユーザー（代理人）を表す。
+//! 役員の稟議を秘書が起票する、といった運用を想定する。
+//!
+//! 代理権限は本人が付与・取り消しする。代理申請されたインスタンスは
+//! `initiated_by` が本人、`submitted_by` が代理人となり、承認者の規則や
+//! 申請者向けの通知は本人を基準に適用される（[`WorkflowInstance`](super::WorkflowInstance) 参照）。
+
+use chrono::{DateTime, Utc};
+
+use crate::user::UserId;
+
+/// 代理権限エンティティ
+///
+/// 本人と代理人の組で一意。付与後は不変で、取り消しは削除で表す。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyGrant {
+    principal_id: UserId,
+    proxy_id:     UserId,
+    created_at:   DateTime<Utc>,
+}
+
+impl ProxyGrant {
+    /// 新しい代理権限を作成する
+    pub fn new(principal_id: UserId, proxy_id: UserId, now: DateTime<Utc>) -> Self {
+        Self {
+            principal_id,
+            proxy_id,
+            created_at: now,
+        }
+    }
+
+    /// 既存のデータから復元する
+    pub fn from_db(principal_id: UserId, proxy_id: UserId, created_at: DateTime<Utc>) -> Self {
+        Self {
+            principal_id,
+            proxy_id,
+            created_at,
+        }
+    }
+
+    // Getter メソッド
+
+    pub fn principal_id(&self) -> &UserId {
+        &self.principal_id
+    }
+
+    pub fn proxy_id(&self) -> &UserId {
+        &self.proxy_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
@@ -0,0 +1,933 @@
+//! # ワークフロースケジュール
+//!
+//! 定期的に発生する申請（月次のサブスクリプション更新、四半期報告など）を
+//! 決まったタイミングで自動作成するための定義。
+//!
+//! スケジュールは定義とフォームデータのテンプレートを保持し、実行日時ごとに
+//! 所有者を申請者とした下書きを作成する。自動申請が有効な場合は、
+//! 登録済みの承認者でそのまま申請する。
+//!
+//! ## 繰り返し設定
+//!
+//! | 種別 | 説明 |
+//! |------|------|
+//! | 毎月 N 日 | 指定日の指定時刻。月末を超える日は月の最終日に丸める（31 日指定 → 2 月は 28/29 日） |
+//! | cron 形式 | `分 時 日 月 曜日` の 5 フィールド。`*`、リスト（`,`）、範囲（`-`）、間隔（`/`）に対応 |
+//!
+//! 時刻はスケジュールのタイムゾーン（IANA タイムゾーン名）の現地時刻で解釈する。
+//! 夏時間の切り替えで現地時刻が存在しない場合は切り替え後の最初の時刻、
+//! 2 回現れる場合は早い方の時刻に実行する（[`TimeZone::from_local`]）。
+//!
+//! ## 実行日時の管理
+//!
+//! - 停止中などで実行日時を過ぎた回はまとめて実行せず、次回以降の実行日時に進める
+//! - 設定を変更した場合は、変更時点から次回実行日時を計算し直す
+//! - 次回実行日時が存在しなくなった場合（繰り返しが終了した場合）は無効化する
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::WorkflowDefinitionId;
+use crate::{DomainError, message::Message, tenant::TenantId, time_zone::TimeZone, user::UserId};
+
+/// cron 形式で次回実行日時を探索する最大日数
+///
+/// 「2 月 29 日かつ月曜日」のような組み合わせでも見つかるよう、閏年の周期を超える
+/// 範囲を探索する。
+const CRON_SEARCH_DAYS: u32 = 366 * 28;
+
+define_uuid_id! {
+    /// ワークフロースケジュール ID
+    pub struct WorkflowScheduleId;
+}
+
+define_validated_string! {
+    /// スケジュール名（一覧での識別用）
+    pub struct WorkflowScheduleName {
+        field: "schedule-name",
+        max_length: 100,
+    }
+}
+
+/// cron 式の 1 フィールド（許可する値の集合）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CronField {
+    /// 許可する値のビット集合
+    bits:       u64,
+    /// `*` または全範囲を指定したか（日・曜日の組み合わせ判定に使う）
+    restricted: bool,
+}
+
+impl CronField {
+    fn parse(source: &str, field: &str, min: u32, max: u32) -> Result<Self, DomainError> {
+        let invalid = || {
+            DomainError::Validation(
+                Message::new("invalid-cron-field")
+                    .with("field", field)
+                    .with("value", source),
+            )
+        };
+
+        let mut bits = 0u64;
+        for part in source.split(',') {
+            let (range, step) = match part.split_once('/') {
+                Some((range, step)) => (range, step.parse::<u32>().map_err(|_| invalid())?),
+                None => (part, 1),
+            };
+            if step == 0 {
+                return Err(invalid());
+            }
+            let (start, end) = if range == "*" {
+                (min, max)
+            } else if let Some((start, end)) = range.split_once('-') {
+                (
+                    start.parse::<u32>().map_err(|_| invalid())?,
+                    end.parse::<u32>().map_err(|_| invalid())?,
+                )
+            } else {
+                let value = range.parse::<u32>().map_err(|_| invalid())?;
+                // `5/15` は 5 から最大値まで 15 刻みを表す
+                let end = if part.contains('/') { max } else { value };
+                (value, end)
+            };
+            if start < min || end > max || start > end {
+                return Err(invalid());
+            }
+            for value in (start..=end).step_by(step as usize) {
+                bits |= 1 << value;
+            }
+        }
+
+        let all = (min..=max).fold(0u64, |acc, value| acc | (1 << value));
+        Ok(Self {
+            bits,
+            restricted: bits != all,
+        })
+    }
+
+    fn contains(&self, value: u32) -> bool {
+        self.bits & (1 << value) != 0
+    }
+}
+
+/// cron 式（`分 時 日 月 曜日`、現地時刻）
+///
+/// 曜日は 0〜7（0 と 7 はいずれも日曜日）。日と曜日の両方を制限した場合は、
+/// 一般的な cron と同様にどちらか一方に一致すれば実行する。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpression {
+    source:        String,
+    minutes:       CronField,
+    hours:         CronField,
+    days_of_month: CronField,
+    months:        CronField,
+    days_of_week:  CronField,
+}
+
+impl CronExpression {
+    pub fn parse(source: &str) -> Result<Self, DomainError> {
+        let fields: Vec<&str> = source.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields.as_slice() else {
+            return Err(DomainError::Validation(Message::new("cron-field-count")));
+        };
+
+        let mut days_of_week = CronField::parse(day_of_week, "cron-day-of-week", 0, 7)?;
+        // 7 は日曜日（0）として扱う
+        if days_of_week.contains(7) {
+            days_of_week.bits = (days_of_week.bits & !(1 << 7)) | 1;
+        }
+        days_of_week.restricted = days_of_week.bits != 0b111_1111;
+
+        Ok(Self {
+            source: fields.join(" "),
+            minutes: CronField::parse(minute, "cron-minute", 0, 59)?,
+            hours: CronField::parse(hour, "cron-hour", 0, 23)?,
+            days_of_month: CronField::parse(day_of_month, "cron-day-of-month", 1, 31)?,
+            months: CronField::parse(month, "cron-month", 1, 12)?,
+            days_of_week,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// `after` より後の最初の実行日時を返す（見つからない場合は `None`）
+    ///
+    /// 各フィールドは `time_zone` の現地時刻と照合する。
+    pub fn next_after(&self, after: DateTime<Utc>, time_zone: TimeZone) -> Option<DateTime<Utc>> {
+        let start = time_zone.to_local(
+            after.with_second(0).and_then(|t| t.with_nanosecond(0))? + TimeDelta::minutes(1),
+        );
+
+        let mut date = start.date();
+        let mut from = (start.hour(), start.minute());
+        for _ in 0..CRON_SEARCH_DAYS {
+            if self.matches_date(date) {
+                while let Some((hour, minute)) = self.first_time_from(from) {
+                    let candidate = time_zone.from_local(date.and_hms_opt(hour, minute, 0)?)?;
+                    if candidate > after {
+                        return Some(candidate);
+                    }
+                    // 夏時間の終了で 2 回現れる現地時刻の 2 回目は、1 回目の実行日時が
+                    // `after` 以前になるため次の時刻から探し直す
+                    from = (hour + (minute + 1) / 60, (minute + 1) % 60);
+                }
+            }
+            date = date.succ_opt()?;
+            from = (0, 0);
+        }
+        None
+    }
+
+    fn matches_date(&self, date: NaiveDate) -> bool {
+        if !self.months.contains(date.month()) {
+            return false;
+        }
+        let day_matches = self.days_of_month.contains(date.day());
+        let weekday_matches = self
+            .days_of_week
+            .contains(date.weekday().num_days_from_sunday());
+        match (self.days_of_month.restricted, self.days_of_week.restricted) {
+            (true, true) => day_matches || weekday_matches,
+            (true, false) => day_matches,
+            (false, true) => weekday_matches,
+            (false, false) => true,
+        }
+    }
+
+    /// 指定時刻以降で最初に一致する時刻（時, 分）を返す
+    fn first_time_from(&self, (from_hour, from_minute): (u32, u32)) -> Option<(u32, u32)> {
+        (from_hour..24)
+            .filter(|hour| self.hours.contains(*hour))
+            .find_map(|hour| {
+                let start = if hour == from_hour { from_minute } else { 0 };
+                (start..60)
+                    .find(|minute| self.minutes.contains(*minute))
+                    .map(|minute| (hour, minute))
+            })
+    }
+}
+
+/// 毎月 N 日の繰り返し設定（現地時刻）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonthlyRecurrence {
+    day:    u32,
+    hour:   u32,
+    minute: u32,
+}
+
+impl MonthlyRecurrence {
+    pub fn new(day: u32, hour: u32, minute: u32) -> Result<Self, DomainError> {
+        if !(1..=31).contains(&day) {
+            return Err(DomainError::Validation(Message::new(
+                "schedule-day-out-of-range",
+            )));
+        }
+        if hour > 23 || minute > 59 {
+            return Err(DomainError::Validation(Message::new(
+                "schedule-time-out-of-range",
+            )));
+        }
+        Ok(Self { day, hour, minute })
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// `after` より後の最初の実行日時を返す
+    ///
+    /// 実行日と時刻は `time_zone` の現地時刻として扱う。
+    pub fn next_after(&self, after: DateTime<Utc>, time_zone: TimeZone) -> Option<DateTime<Utc>> {
+        let local_after = time_zone.to_local(after);
+        let (mut year, mut month) = (local_after.year(), local_after.month());
+        // 当月の実行日時が過ぎていても、翌月には必ず見つかる
+        for _ in 0..2 {
+            let last_day = last_day_of_month(year, month)?;
+            let candidate = time_zone.from_local(
+                NaiveDate::from_ymd_opt(year, month, self.day.min(last_day))?.and_hms_opt(
+                    self.hour,
+                    self.minute,
+                    0,
+                )?,
+            )?;
+            if candidate > after {
+                return Some(candidate);
+            }
+            (year, month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+        }
+        None
+    }
+}
+
+/// 月の最終日を返す
+fn last_day_of_month(year: i32, month: u32) -> Option<u32> {
+    let first_of_next = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+    Some(first_of_next.pred_opt()?.day())
+}
+
+/// 繰り返し設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Recurrence {
+    /// 毎月 N 日
+    Monthly(MonthlyRecurrence),
+    /// cron 形式
+    Cron(CronExpression),
+}
+
+impl Recurrence {
+    /// 繰り返し種別（`monthly` / `cron`）
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Monthly(_) => "monthly",
+            Self::Cron(_) => "cron",
+        }
+    }
+
+    /// `after` より後の最初の実行日時を返す（時刻は `time_zone` の現地時刻で解釈する）
+    pub fn next_after(&self, after: DateTime<Utc>, time_zone: TimeZone) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Monthly(monthly) => monthly.next_after(after, time_zone),
+            Self::Cron(cron) => cron.next_after(after, time_zone),
+        }
+    }
+}
+
+/// 自動申請時に割り当てる承認者
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledApprover {
+    /// 定義 JSON のステップ ID
+    pub step_id:     String,
+    /// 承認者のユーザー ID
+    pub assigned_to: UserId,
+}
+
+/// ワークフロースケジュールエンティティ
+///
+/// 所有者が作成・管理し、作成されるワークフローの申請者も所有者になる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowSchedule {
+    id: WorkflowScheduleId,
+    tenant_id: TenantId,
+    owner_id: UserId,
+    definition_id: WorkflowDefinitionId,
+    name: WorkflowScheduleName,
+    title: String,
+    form_data: serde_json::Value,
+    recurrence: Recurrence,
+    time_zone: TimeZone,
+    auto_submit: bool,
+    approvers: Vec<ScheduledApprover>,
+    is_active: bool,
+    next_run_at: Option<DateTime<Utc>>,
+    last_run_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// ワークフロースケジュールの作成パラメータ
+pub struct NewWorkflowSchedule {
+    pub id: WorkflowScheduleId,
+    pub tenant_id: TenantId,
+    pub owner_id: UserId,
+    pub definition_id: WorkflowDefinitionId,
+    pub name: WorkflowScheduleName,
+    /// 作成するワークフローのタイトル
+    pub title: String,
+    /// 作成するワークフローのフォームデータ
+    pub form_data: serde_json::Value,
+    pub recurrence: Recurrence,
+    /// 繰り返し設定の時刻を解釈するタイムゾーン
+    pub time_zone: TimeZone,
+    /// 作成した下書きをそのまま申請するか
+    pub auto_submit: bool,
+    /// 自動申請時の承認者（自動申請しない場合は空でよい）
+    pub approvers: Vec<ScheduledApprover>,
+    pub now: DateTime<Utc>,
+}
+
+/// ワークフロースケジュールの更新内容（`None` の項目は変更しない）
+#[derive(Default)]
+pub struct WorkflowScheduleChanges {
+    pub name:        Option<WorkflowScheduleName>,
+    pub title:       Option<String>,
+    pub form_data:   Option<serde_json::Value>,
+    pub recurrence:  Option<Recurrence>,
+    pub time_zone:   Option<TimeZone>,
+    pub auto_submit: Option<bool>,
+    pub approvers:   Option<Vec<ScheduledApprover>>,
+    pub is_active:   Option<bool>,
+}
+
+/// ワークフロースケジュールの DB 復元用レコード
+pub struct WorkflowScheduleRecord {
+    pub id: WorkflowScheduleId,
+    pub tenant_id: TenantId,
+    pub owner_id: UserId,
+    pub definition_id: WorkflowDefinitionId,
+    pub name: WorkflowScheduleName,
+    pub title: String,
+    pub form_data: serde_json::Value,
+    pub recurrence: Recurrence,
+    pub time_zone: TimeZone,
+    pub auto_submit: bool,
+    pub approvers: Vec<ScheduledApprover>,
+    pub is_active: bool,
+    pub next_run_at: Option<DateTime<Utc>>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 作成するワークフローのタイトルを検証する
+fn validate_title(title: String) -> Result<String, DomainError> {
+    let title = title.trim().to_string();
+    if title.is_empty() {
+        return Err(DomainError::Validation(
+            Message::new("required").with("field", "title"),
+        ));
+    }
+    Ok(title)
+}
+
+/// 自動申請の設定を検証する（自動申請には承認者が必要）
+fn validate_auto_submit(
+    auto_submit: bool,
+    approvers: &[ScheduledApprover],
+) -> Result<(), DomainError> {
+    if auto_submit && approvers.is_empty() {
+        return Err(DomainError::Validation(Message::new(
+            "schedule-auto-submit-approvers-required",
+        )));
+    }
+    Ok(())
+}
+
+/// 次回実行日時を計算する（存在しない場合はエラー）
+fn first_run_at(
+    recurrence: &Recurrence,
+    time_zone: TimeZone,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, DomainError> {
+    recurrence
+        .next_after(now, time_zone)
+        .ok_or_else(|| DomainError::Validation(Message::new("schedule-no-next-run")))
+}
+
+impl WorkflowSchedule {
+    /// 新しいスケジュールを作成する（有効状態）
+    pub fn new(params: NewWorkflowSchedule) -> Result<Self, DomainError> {
+        validate_auto_submit(params.auto_submit, &params.approvers)?;
+        let next_run_at = first_run_at(&params.recurrence, params.time_zone, params.now)?;
+        Ok(Self {
+            id: params.id,
+            tenant_id: params.tenant_id,
+            owner_id: params.owner_id,
+            definition_id: params.definition_id,
+            name: params.name,
+            title: validate_title(params.title)?,
+            form_data: params.form_data,
+            recurrence: params.recurrence,
+            time_zone: params.time_zone,
+            auto_submit: params.auto_submit,
+            approvers: params.approvers,
+            is_active: true,
+            next_run_at: Some(next_run_at),
+            last_run_at: None,
+            created_at: params.now,
+            updated_at: params.now,
+        })
+    }
+
+    /// DB から復元する
+    pub fn from_db(record: WorkflowScheduleRecord) -> Self {
+        Self {
+            id: record.id,
+            tenant_id: record.tenant_id,
+            owner_id: record.owner_id,
+            definition_id: record.definition_id,
+            name: record.name,
+            title: record.title,
+            form_data: record.form_data,
+            recurrence: record.recurrence,
+            time_zone: record.time_zone,
+            auto_submit: record.auto_submit,
+            approvers: record.approvers,
+            is_active: record.is_active,
+            next_run_at: record.next_run_at,
+            last_run_at: record.last_run_at,
+            created_at: record.created_at,
+            updated_at: record.updated_at,
+        }
+    }
+
+    /// 変更を適用したスケジュールを返す
+    ///
+    /// 有効なスケジュールは、変更時点から次回実行日時を計算し直す。
+    pub fn updated(
+        self,
+        changes: WorkflowScheduleChanges,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let title = match changes.title {
+            Some(title) => validate_title(title)?,
+            None => self.title,
+        };
+        let recurrence = changes.recurrence.unwrap_or(self.recurrence);
+        let time_zone = changes.time_zone.unwrap_or(self.time_zone);
+        let auto_submit = changes.auto_submit.unwrap_or(self.auto_submit);
+        let approvers = changes.approvers.unwrap_or(self.approvers);
+        validate_auto_submit(auto_submit, &approvers)?;
+        let is_active = changes.is_active.unwrap_or(self.is_active);
+        let next_run_at = if is_active {
+            Some(first_run_at(&recurrence, time_zone, now)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            name: changes.name.unwrap_or(self.name),
+            title,
+            form_data: changes.form_data.unwrap_or(self.form_data),
+            recurrence,
+            time_zone,
+            auto_submit,
+            approvers,
+            is_active,
+            next_run_at,
+            updated_at: now,
+            ..self
+        })
+    }
+
+    /// 実行日時に達したか
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.is_active
+            && self
+                .next_run_at
+                .is_some_and(|next_run_at| next_run_at <= now)
+    }
+
+    /// 1 回分の実行を記録し、次回実行日時に進めたスケジュールを返す
+    ///
+    /// 実行日時を過ぎた回はまとめて実行せず、`now` より後の実行日時に進める。
+    /// 次回実行日時が存在しない場合は無効化する。
+    pub fn advanced(self, now: DateTime<Utc>) -> Self {
+        let next_run_at = self.recurrence.next_after(now, self.time_zone);
+        Self {
+            is_active: self.is_active && next_run_at.is_some(),
+            next_run_at,
+            last_run_at: Some(now),
+            updated_at: now,
+            ..self
+        }
+    }
+
+    pub fn id(&self) -> &WorkflowScheduleId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn owner_id(&self) -> &UserId {
+        &self.owner_id
+    }
+
+    pub fn definition_id(&self) -> &WorkflowDefinitionId {
+        &self.definition_id
+    }
+
+    pub fn name(&self) -> &WorkflowScheduleName {
+        &self.name
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn form_data(&self) -> &serde_json::Value {
+        &self.form_data
+    }
+
+    pub fn recurrence(&self) -> &Recurrence {
+        &self.recurrence
+    }
+
+    pub fn time_zone(&self) -> TimeZone {
+        self.time_zone
+    }
+
+    pub fn auto_submit(&self) -> bool {
+        self.auto_submit
+    }
+
+    pub fn approvers(&self) -> &[ScheduledApprover] {
+        &self.approvers
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn next_run_at(&self) -> Option<DateTime<Utc>> {
+        self.next_run_at
+    }
+
+    pub fn last_run_at(&self) -> Option<DateTime<Utc>> {
+        self.last_run_at
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn at(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    fn new_schedule(recurrence: Recurrence, now: DateTime<Utc>) -> WorkflowSchedule {
+        WorkflowSchedule::new(NewWorkflowSchedule {
+            id: WorkflowScheduleId::new(),
+            tenant_id: TenantId::new(),
+            owner_id: UserId::new(),
+            definition_id: WorkflowDefinitionId::new(),
+            name: WorkflowScheduleName::new("月次サブスクリプション更新").unwrap(),
+            title: "サブスクリプション更新".to_string(),
+            form_data: serde_json::json!({"amount": 1000}),
+            recurrence,
+            time_zone: TimeZone::UTC,
+            auto_submit: false,
+            approvers: vec![],
+            now,
+        })
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case("0 9 * * *", "2026-03-10T08:30:00Z", "2026-03-10T09:00:00Z")]
+    #[case("0 9 * * *", "2026-03-10T09:00:00Z", "2026-03-11T09:00:00Z")]
+    #[case("*/15 * * * *", "2026-03-10T09:07:30Z", "2026-03-10T09:15:00Z")]
+    #[case("30 8 1 1,4,7,10 *", "2026-03-10T00:00:00Z", "2026-04-01T08:30:00Z")]
+    #[case("0 9 * * 1-5", "2026-03-13T10:00:00Z", "2026-03-16T09:00:00Z")]
+    #[case("0 0 * * 7", "2026-03-10T00:00:00Z", "2026-03-15T00:00:00Z")]
+    #[case("0 0 29 2 *", "2026-03-01T00:00:00Z", "2028-02-29T00:00:00Z")]
+    // 日と曜日の両方を制限した場合はどちらかに一致すれば実行する
+    #[case("0 0 13 * 5", "2026-03-10T00:00:00Z", "2026-03-13T00:00:00Z")]
+    #[case("0 0 20 * 1", "2026-03-10T00:00:00Z", "2026-03-16T00:00:00Z")]
+    fn test_cron式の次回実行日時(
+        #[case] expression: &str,
+        #[case] after: &str,
+        #[case] expected: &str,
+    ) {
+        let cron = CronExpression::parse(expression).unwrap();
+
+        assert_eq!(
+            cron.next_after(at(after), TimeZone::UTC),
+            Some(at(expected))
+        );
+    }
+
+    #[rstest]
+    #[case("0 9 * *")]
+    #[case("60 9 * * *")]
+    #[case("0 24 * * *")]
+    #[case("0 9 0 * *")]
+    #[case("0 9 * 13 *")]
+    #[case("0 9 * * 8")]
+    #[case("*/0 * * * *")]
+    #[case("5-1 * * * *")]
+    #[case("a * * * *")]
+    fn test_不正なcron式はエラー(#[case] expression: &str) {
+        assert!(CronExpression::parse(expression).is_err());
+    }
+
+    #[test]
+    fn test_cron式は空白を正規化して保持する() {
+        let cron = CronExpression::parse("  0  9 *  * 1-5 ").unwrap();
+
+        assert_eq!(cron.as_str(), "0 9 * * 1-5");
+    }
+
+    #[rstest]
+    #[case(15, "2026-03-10T00:00:00Z", "2026-03-15T09:00:00Z")]
+    #[case(15, "2026-03-15T09:00:00Z", "2026-04-15T09:00:00Z")]
+    #[case(31, "2026-02-01T00:00:00Z", "2026-02-28T09:00:00Z")]
+    #[case(31, "2026-04-30T09:00:00Z", "2026-05-31T09:00:00Z")]
+    #[case(30, "2028-02-01T00:00:00Z", "2028-02-29T09:00:00Z")]
+    #[case(1, "2026-12-01T10:00:00Z", "2027-01-01T09:00:00Z")]
+    fn test_毎月n日の次回実行日時は月末に丸める(
+        #[case] day: u32,
+        #[case] after: &str,
+        #[case] expected: &str,
+    ) {
+        let monthly = MonthlyRecurrence::new(day, 9, 0).unwrap();
+
+        assert_eq!(
+            monthly.next_after(at(after), TimeZone::UTC),
+            Some(at(expected))
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "0 9 * * *",
+        "Asia/Tokyo",
+        "2026-03-10T00:30:00Z",
+        "2026-03-11T00:00:00Z"
+    )]
+    #[case(
+        "0 9 * * 1",
+        "Asia/Tokyo",
+        "2026-03-15T23:30:00Z",
+        "2026-03-16T00:00:00Z"
+    )]
+    // 夏時間の開始をまたいでも現地時刻の 9:00 に実行する
+    #[case(
+        "0 9 * * *",
+        "America/New_York",
+        "2026-03-07T15:00:00Z",
+        "2026-03-08T13:00:00Z"
+    )]
+    // 夏時間の開始で存在しない 2:30 は切り替え後の 3:00 に実行する
+    #[case(
+        "30 2 * * *",
+        "America/New_York",
+        "2026-03-08T05:00:00Z",
+        "2026-03-08T07:00:00Z"
+    )]
+    // 夏時間の終了で 2 回現れる 1:30 は 1 回だけ実行する
+    #[case(
+        "30 1 * * *",
+        "America/New_York",
+        "2026-11-01T05:30:00Z",
+        "2026-11-02T06:30:00Z"
+    )]
+    fn test_cron式はタイムゾーンの現地時刻で照合する(
+        #[case] expression: &str,
+        #[case] time_zone: &str,
+        #[case] after: &str,
+        #[case] expected: &str,
+    ) {
+        let cron = CronExpression::parse(expression).unwrap();
+
+        assert_eq!(
+            cron.next_after(at(after), time_zone.parse().unwrap()),
+            Some(at(expected))
+        );
+    }
+
+    #[rstest]
+    // UTC では 2 月 28 日だが、東京では既に 3 月 1 日
+    #[case(1, "2026-02-28T23:30:00Z", "2026-03-01T00:00:00Z")]
+    #[case(31, "2026-03-31T15:30:00Z", "2026-04-30T00:00:00Z")]
+    #[case(15, "2026-03-15T00:00:00Z", "2026-04-15T00:00:00Z")]
+    fn test_毎月n日はタイムゾーンの現地時刻で計算する(
+        #[case] day: u32,
+        #[case] after: &str,
+        #[case] expected: &str,
+    ) {
+        let monthly = MonthlyRecurrence::new(day, 9, 0).unwrap();
+
+        assert_eq!(
+            monthly.next_after(at(after), "Asia/Tokyo".parse().unwrap()),
+            Some(at(expected))
+        );
+    }
+
+    #[rstest]
+    #[case(0, 9, 0)]
+    #[case(32, 9, 0)]
+    #[case(1, 24, 0)]
+    #[case(1, 9, 60)]
+    fn test_毎月n日の範囲外の値はエラー(
+        #[case] day: u32,
+        #[case] hour: u32,
+        #[case] minute: u32,
+    ) {
+        assert!(MonthlyRecurrence::new(day, hour, minute).is_err());
+    }
+
+    #[test]
+    fn test_作成時に次回実行日時を計算する() {
+        let now = at("2026-03-10T00:00:00Z");
+        let recurrence = Recurrence::Monthly(MonthlyRecurrence::new(15, 9, 0).unwrap());
+
+        let schedule = new_schedule(recurrence, now);
+
+        assert!(schedule.is_active());
+        assert_eq!(schedule.next_run_at(), Some(at("2026-03-15T09:00:00Z")));
+        assert_eq!(schedule.last_run_at(), None);
+    }
+
+    #[test]
+    fn test_スケジュールのタイムゾーンで次回実行日時を計算する() {
+        let recurrence = Recurrence::Monthly(MonthlyRecurrence::new(15, 9, 0).unwrap());
+        let schedule = WorkflowSchedule::new(NewWorkflowSchedule {
+            id: WorkflowScheduleId::new(),
+            tenant_id: TenantId::new(),
+            owner_id: UserId::new(),
+            definition_id: WorkflowDefinitionId::new(),
+            name: WorkflowScheduleName::new("月次サブスクリプション更新").unwrap(),
+            title: "サブスクリプション更新".to_string(),
+            form_data: serde_json::json!({}),
+            recurrence,
+            time_zone: "Asia/Tokyo".parse().unwrap(),
+            auto_submit: false,
+            approvers: vec![],
+            now: at("2026-03-10T00:00:00Z"),
+        })
+        .unwrap();
+        assert_eq!(schedule.next_run_at(), Some(at("2026-03-15T00:00:00Z")));
+
+        let advanced = schedule.advanced(at("2026-03-15T00:00:00Z"));
+        assert_eq!(advanced.next_run_at(), Some(at("2026-04-15T00:00:00Z")));
+
+        let updated = advanced
+            .updated(
+                WorkflowScheduleChanges {
+                    time_zone: Some(TimeZone::UTC),
+                    ..Default::default()
+                },
+                at("2026-03-16T00:00:00Z"),
+            )
+            .unwrap();
+        assert_eq!(updated.next_run_at(), Some(at("2026-04-15T09:00:00Z")));
+    }
+
+    #[test]
+    fn test_実行日時のないcron式では作成できない() {
+        let recurrence = Recurrence::Cron(CronExpression::parse("0 0 31 2 *").unwrap());
+
+        let result = WorkflowSchedule::new(NewWorkflowSchedule {
+            id: WorkflowScheduleId::new(),
+            tenant_id: TenantId::new(),
+            owner_id: UserId::new(),
+            definition_id: WorkflowDefinitionId::new(),
+            name: WorkflowScheduleName::new("存在しない日").unwrap(),
+            title: "申請".to_string(),
+            form_data: serde_json::json!({}),
+            recurrence,
+            time_zone: TimeZone::UTC,
+            auto_submit: false,
+            approvers: vec![],
+            now: Utc::now(),
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_自動申請には承認者が必要() {
+        let result = WorkflowSchedule::new(NewWorkflowSchedule {
+            id: WorkflowScheduleId::new(),
+            tenant_id: TenantId::new(),
+            owner_id: UserId::new(),
+            definition_id: WorkflowDefinitionId::new(),
+            name: WorkflowScheduleName::new("四半期報告").unwrap(),
+            title: "四半期報告".to_string(),
+            form_data: serde_json::json!({}),
+            recurrence: Recurrence::Cron(CronExpression::parse("0 9 1 1,4,7,10 *").unwrap()),
+            time_zone: TimeZone::UTC,
+            auto_submit: true,
+            approvers: vec![],
+            now: Utc::now(),
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_実行日時を過ぎた回はまとめて実行せず次回に進める() {
+        // Arrange: 3 月 15 日が次回実行日時のまま 5 月 20 日まで停止していた
+        let recurrence = Recurrence::Monthly(MonthlyRecurrence::new(15, 9, 0).unwrap());
+        let schedule = new_schedule(recurrence, at("2026-03-10T00:00:00Z"));
+        let now = at("2026-05-20T00:00:00Z");
+        assert!(schedule.is_due(now));
+
+        // Act
+        let advanced = schedule.advanced(now);
+
+        // Assert
+        assert_eq!(advanced.next_run_at(), Some(at("2026-06-15T09:00:00Z")));
+        assert_eq!(advanced.last_run_at(), Some(now));
+        assert!(!advanced.is_due(now));
+    }
+
+    #[test]
+    fn test_更新時は変更時点から次回実行日時を計算し直す() {
+        let schedule = new_schedule(
+            Recurrence::Monthly(MonthlyRecurrence::new(15, 9, 0).unwrap()),
+            at("2026-03-10T00:00:00Z"),
+        );
+
+        let updated = schedule
+            .updated(
+                WorkflowScheduleChanges {
+                    recurrence: Some(Recurrence::Cron(
+                        CronExpression::parse("0 9 * * 1").unwrap(),
+                    )),
+                    ..Default::default()
+                },
+                at("2026-03-11T00:00:00Z"),
+            )
+            .unwrap();
+
+        assert_eq!(updated.next_run_at(), Some(at("2026-03-16T09:00:00Z")));
+    }
+
+    #[test]
+    fn test_無効化すると実行日時に達しない() {
+        let now = at("2026-03-10T00:00:00Z");
+        let schedule = new_schedule(
+            Recurrence::Cron(CronExpression::parse("* * * * *").unwrap()),
+            now,
+        );
+
+        let updated = schedule
+            .updated(
+                WorkflowScheduleChanges {
+                    is_active: Some(false),
+                    ..Default::default()
+                },
+                now,
+            )
+            .unwrap();
+
+        assert!(!updated.is_active());
+        assert_eq!(updated.next_run_at(), None);
+        assert!(!updated.is_due(at("2027-01-01T00:00:00Z")));
+    }
+}
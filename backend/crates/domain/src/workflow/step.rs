@@ -13,6 +13,7 @@ use strum::IntoStaticStr;
 use super::instance::WorkflowInstanceId;
 use crate::{
     DomainError,
+    message::Message,
     user::UserId,
     value_objects::{DisplayNumber, Version},
 };
@@ -48,10 +49,9 @@ impl std::str::FromStr for WorkflowStepStatus {
             "active" => Ok(Self::Active),
             "completed" => Ok(Self::Completed),
             "skipped" => Ok(Self::Skipped),
-            _ => Err(DomainError::Validation(format!(
-                "不正なワークフローステップステータス: {}",
-                s
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-workflow-step-status").with("value", s),
+            )),
         }
     }
 }
@@ -77,10 +77,9 @@ impl std::str::FromStr for StepDecision {
             "approved" => Ok(Self::Approved),
             "rejected" => Ok(Self::Rejected),
             "request_changes" => Ok(Self::RequestChanges),
-            _ => Err(DomainError::Validation(format!(
-                "不正なステップ判断: {}",
-                s
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("invalid-step-decision").with("value", s),
+            )),
         }
     }
 }
@@ -208,24 +207,34 @@ impl WorkflowStep {
             WorkflowStepStatus::Pending => WorkflowStepState::Pending,
             WorkflowStepStatus::Active => {
                 let started_at = record.started_at.ok_or_else(|| {
-                    DomainError::Validation("Active ステップには started_at が必要です".to_string())
+                    DomainError::Validation(
+                        Message::new("step-state-column-missing")
+                            .with("status", "Active")
+                            .with("column", "started_at"),
+                    )
                 })?;
                 WorkflowStepState::Active(ActiveStepState { started_at })
             }
             WorkflowStepStatus::Completed => {
                 let decision = record.decision.ok_or_else(|| {
                     DomainError::Validation(
-                        "Completed ステップには decision が必要です".to_string(),
+                        Message::new("step-state-column-missing")
+                            .with("status", "Completed")
+                            .with("column", "decision"),
                     )
                 })?;
                 let started_at = record.started_at.ok_or_else(|| {
                     DomainError::Validation(
-                        "Completed ステップには started_at が必要です".to_string(),
+                        Message::new("step-state-column-missing")
+                            .with("status", "Completed")
+                            .with("column", "started_at"),
                     )
                 })?;
                 let completed_at = record.completed_at.ok_or_else(|| {
                     DomainError::Validation(
-                        "Completed ステップには completed_at が必要です".to_string(),
+                        Message::new("step-state-column-missing")
+                            .with("status", "Completed")
+                            .with("column", "completed_at"),
                     )
                 })?;
                 WorkflowStepState::Completed(CompletedStepState {
@@ -372,9 +381,9 @@ impl WorkflowStep {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(
-                "アクティブ状態でのみ完了できます".to_string(),
-            )),
+            _ => Err(DomainError::Validation(Message::new(
+                "step-complete-not-active",
+            ))),
         }
     }
 
@@ -393,10 +402,9 @@ impl WorkflowStep {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(format!(
-                "スキップは待機中状態でのみ可能です（現在: {}）",
-                self.status()
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("step-skip-not-pending").with("status", self.status()),
+            )),
         }
     }
 
@@ -421,10 +429,9 @@ impl WorkflowStep {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(format!(
-                "承認はアクティブ状態でのみ可能です（現在: {}）",
-                self.status()
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("step-approve-not-active").with("status", self.status()),
+            )),
         }
     }
 
@@ -449,10 +456,9 @@ impl WorkflowStep {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(format!(
-                "却下はアクティブ状態でのみ可能です（現在: {}）",
-                self.status()
-            ))),
+            _ => Err(DomainError::Validation(
+                Message::new("step-reject-not-active").with("status", self.status()),
+            )),
         }
     }
 
@@ -481,13 +487,114 @@ impl WorkflowStep {
                 updated_at: now,
                 ..self
             }),
-            _ => Err(DomainError::Validation(format!(
-                "差し戻しはアクティブ状態でのみ可能です（現在: {}）",
-                self.status()
+            _ => Err(DomainError::Validation(
+                Message::new("step-request-changes-not-active").with("status", self.status()),
+            )),
+        }
+    }
+
+    /// 承認を取り消した新しいインスタンスを返す
+    ///
+    /// 承認者による誤承認の取り消しで使用する。
+    /// Completed (Approved) 状態のステップを Active に戻す。開始日時は承認前のものを引き継ぎ、
+    /// 承認時のコメントは破棄する。
+    /// version をインクリメントして楽観的ロックに対応。
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::Validation`: 承認済み以外の状態で呼び出した場合
+    pub fn retract_approval(self, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        match self.state {
+            WorkflowStepState::Completed(CompletedStepState {
+                decision: StepDecision::Approved,
+                started_at,
+                ..
+            }) => Ok(Self {
+                state: WorkflowStepState::Active(ActiveStepState { started_at }),
+                version: self.version.next(),
+                updated_at: now,
+                ..self
+            }),
+            _ => Err(DomainError::Validation(Message::new(
+                "step-retract-not-approved",
             ))),
         }
     }
 
+    /// ステップを待機中に戻した新しいインスタンスを返す
+    ///
+    /// 前のステップの承認が取り消されたときに、アクティブになっていた次のステップを
+    /// 待機中に戻すために使用する。
+    /// version をインクリメントし、担当者による操作を競合として検出する。
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::Validation`: Active 以外の状態で呼び出した場合
+    pub fn deactivated(self, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        match self.state {
+            WorkflowStepState::Active(_) => Ok(Self {
+                state: WorkflowStepState::Pending,
+                version: self.version.next(),
+                updated_at: now,
+                ..self
+            }),
+            _ => Err(DomainError::Validation(
+                Message::new("step-deactivate-not-active").with("status", self.status()),
+            )),
+        }
+    }
+
+    /// 担当者を変更した新しいインスタンスを返す
+    ///
+    /// テナント管理者による強制的な担当者変更で使用する。
+    /// Active 状態のステップのみ変更可能。
+    /// version をインクリメントし、変更前の担当者による操作を競合として検出する。
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::Validation`: Active 以外の状態、または現在の担当者と同じユーザーを指定した場合
+    pub fn reassigned(self, assigned_to: UserId, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        if !matches!(self.state, WorkflowStepState::Active(_)) {
+            return Err(DomainError::Validation(
+                Message::new("step-reassign-not-active").with("status", self.status()),
+            ));
+        }
+        if self.assigned_to.as_ref() == Some(&assigned_to) {
+            return Err(DomainError::Validation(Message::new(
+                "step-reassign-same-user",
+            )));
+        }
+        Ok(Self {
+            assigned_to: Some(assigned_to),
+            version: self.version.next(),
+            updated_at: now,
+            ..self
+        })
+    }
+
+    /// 未完了のステップを打ち切った新しいインスタンスを返す
+    ///
+    /// テナント管理者による強制取消・強制完了で使用する。
+    /// Pending / Active 状態のステップを判断なしで Skipped に遷移させる。
+    /// version をインクリメントし、担当者による操作を競合として検出する。
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::Validation`: Completed / Skipped 状態で呼び出した場合
+    pub fn aborted(self, now: DateTime<Utc>) -> Result<Self, DomainError> {
+        match self.state {
+            WorkflowStepState::Pending | WorkflowStepState::Active(_) => Ok(Self {
+                state: WorkflowStepState::Skipped,
+                version: self.version.next(),
+                updated_at: now,
+                ..self
+            }),
+            _ => Err(DomainError::Validation(
+                Message::new("step-abort-not-open").with("status", self.status()),
+            )),
+        }
+    }
+
     /// ステップが期限切れかチェックする
     pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
         if let Some(due) = self.due_date
@@ -633,6 +740,65 @@ mod tests {
             assert_eq!(sut, expected);
         }
 
+        #[rstest]
+        fn test_承認取り消し後の状態(test_step: WorkflowStep, now: DateTime<Utc>) {
+            let step = test_step.activated(now);
+            let before = step.clone();
+            let later = now + chrono::Duration::minutes(5);
+
+            let sut = step
+                .approve(Some("承認します".to_string()), now)
+                .unwrap()
+                .retract_approval(later)
+                .unwrap();
+
+            let expected = WorkflowStep::from_db(WorkflowStepRecord {
+                version: before.version().next().next(),
+                updated_at: later,
+                ..record_from(&before)
+            })
+            .unwrap();
+            assert_eq!(sut, expected);
+        }
+
+        #[rstest]
+        fn test_承認取り消し_承認済み以外ではエラー(
+            test_step: WorkflowStep,
+            now: DateTime<Utc>,
+        ) {
+            let active = test_step.activated(now);
+            let rejected = active.clone().reject(None, now).unwrap();
+
+            assert!(active.retract_approval(now).is_err());
+            assert!(rejected.retract_approval(now).is_err());
+        }
+
+        #[rstest]
+        fn test_待機中に戻した後の状態(test_step: WorkflowStep, now: DateTime<Utc>) {
+            let before = test_step.clone();
+
+            let sut = test_step.activated(now).deactivated(now).unwrap();
+
+            let expected = WorkflowStep::from_db(WorkflowStepRecord {
+                version: before.version().next(),
+                updated_at: now,
+                ..record_from(&before)
+            })
+            .unwrap();
+            assert_eq!(sut, expected);
+        }
+
+        #[rstest]
+        fn test_待機中に戻す_アクティブ以外ではエラー(
+            test_step: WorkflowStep,
+            now: DateTime<Utc>,
+        ) {
+            let completed = test_step.clone().activated(now).approve(None, now).unwrap();
+
+            assert!(test_step.deactivated(now).is_err());
+            assert!(completed.deactivated(now).is_err());
+        }
+
         #[rstest]
         fn test_is_overdue_期限切れの場合trueを返す(
             test_step: WorkflowStep,
@@ -716,6 +882,84 @@ mod tests {
             assert!(result.is_err());
         }
 
+        // --- reassigned() テスト ---
+
+        #[rstest]
+        fn test_担当者変更後の状態(test_step: WorkflowStep, now: DateTime<Utc>) {
+            let step = test_step.activated(now);
+            let before = step.clone();
+            let new_assignee = UserId::new();
+
+            let sut = step.reassigned(new_assignee.clone(), now).unwrap();
+
+            let expected = WorkflowStep::from_db(WorkflowStepRecord {
+                version: before.version().next(),
+                assigned_to: Some(new_assignee),
+                updated_at: now,
+                ..record_from(&before)
+            })
+            .unwrap();
+            assert_eq!(sut, expected);
+        }
+
+        #[rstest]
+        fn test_担当者変更_アクティブ以外ではエラー(
+            test_step: WorkflowStep,
+            now: DateTime<Utc>,
+        ) {
+            let result = test_step.reassigned(UserId::new(), now);
+
+            assert!(result.is_err());
+        }
+
+        #[rstest]
+        fn test_担当者変更_同じ担当者はエラー(
+            test_step: WorkflowStep,
+            now: DateTime<Utc>,
+        ) {
+            let step = test_step.activated(now);
+            let current = step.assigned_to().cloned().unwrap();
+
+            let result = step.reassigned(current, now);
+
+            assert!(result.is_err());
+        }
+
+        // --- aborted() テスト ---
+
+        #[rstest]
+        fn test_打ち切り_アクティブからスキップになる(
+            test_step: WorkflowStep,
+            now: DateTime<Utc>,
+        ) {
+            let step = test_step.activated(now);
+            let before = step.clone();
+
+            let sut = step.aborted(now).unwrap();
+
+            let expected = WorkflowStep::from_db(WorkflowStepRecord {
+                status: WorkflowStepStatus::Skipped,
+                version: before.version().next(),
+                started_at: None,
+                updated_at: now,
+                ..record_from(&before)
+            })
+            .unwrap();
+            assert_eq!(sut, expected);
+        }
+
+        #[rstest]
+        fn test_打ち切り_完了済みではエラー(
+            test_step: WorkflowStep,
+            now: DateTime<Utc>,
+        ) {
+            let step = test_step.activated(now).approve(None, now).unwrap();
+
+            let result = step.aborted(now);
+
+            assert!(result.is_err());
+        }
+
         #[rstest]
         fn test_差戻し後の状態(test_step: WorkflowStep, now: DateTime<Utc>) {
             let step = test_step.activated(now);
@@ -0,0 +1,331 @@
+//! # 申請ラウンドのスナップショット
+//!
+//! 申請・再申請のたびに、その時点のフォームデータと添付ファイル一覧を
+//! 不変のスナップショットとして記録する。
+//!
+//! 再申請（`resubmitted()`）はインスタンスの `form_data` を上書きするため、
+//! 差し戻し前後で申請者が何を変更したかはインスタンスからは分からない。
+//! ラウンドごとのスナップショットを比較することで、承認者は変更点だけを確認できる。
+//!
+//! ラウンド番号は初回申請が 1、以降の再申請ごとに 1 ずつ増える。
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Utc};
+use serde_json::Value as JsonValue;
+
+use super::{form_data_change::FormFieldDiff, instance::WorkflowInstanceId};
+use crate::{
+    document::{Document, DocumentId},
+    tenant::TenantId,
+    user::UserId,
+};
+
+define_uuid_id! {
+    /// 申請スナップショット ID
+    pub struct WorkflowSubmissionId;
+}
+
+/// スナップショット時点の添付ファイル
+///
+/// ドキュメント本体はソフトデリートされ得るため、表示に必要なメタデータを複製して保持する。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionAttachment {
+    pub document_id:  DocumentId,
+    pub filename:     String,
+    pub content_type: String,
+    pub size:         i64,
+}
+
+impl From<&Document> for SubmissionAttachment {
+    fn from(document: &Document) -> Self {
+        Self {
+            document_id:  document.id().clone(),
+            filename:     document.filename().to_string(),
+            content_type: document.content_type().to_string(),
+            size:         document.size(),
+        }
+    }
+}
+
+/// 申請スナップショットエンティティ
+///
+/// 1 回の申請（または再申請）時点のフォームデータと添付ファイル一覧。作成後は不変。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowSubmission {
+    id:           WorkflowSubmissionId,
+    tenant_id:    TenantId,
+    instance_id:  WorkflowInstanceId,
+    round:        i32,
+    form_data:    JsonValue,
+    attachments:  Vec<SubmissionAttachment>,
+    submitted_by: UserId,
+    submitted_at: DateTime<Utc>,
+}
+
+/// 申請スナップショットの新規作成パラメータ
+pub struct NewWorkflowSubmission {
+    pub id:           WorkflowSubmissionId,
+    pub tenant_id:    TenantId,
+    pub instance_id:  WorkflowInstanceId,
+    pub round:        i32,
+    pub form_data:    JsonValue,
+    pub attachments:  Vec<SubmissionAttachment>,
+    pub submitted_by: UserId,
+    pub now:          DateTime<Utc>,
+}
+
+/// 申請スナップショットの DB 復元パラメータ
+pub struct WorkflowSubmissionRecord {
+    pub id:           WorkflowSubmissionId,
+    pub tenant_id:    TenantId,
+    pub instance_id:  WorkflowInstanceId,
+    pub round:        i32,
+    pub form_data:    JsonValue,
+    pub attachments:  Vec<SubmissionAttachment>,
+    pub submitted_by: UserId,
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// 2 つの申請ラウンド間の差分
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionDiff {
+    pub from_round: i32,
+    pub to_round: i32,
+    /// 値が変わったフィールド（フィールド ID 昇順）
+    pub form_data: Vec<FormFieldDiff>,
+    /// 新しいラウンドで追加された添付ファイル
+    pub added_attachments: Vec<SubmissionAttachment>,
+    /// 新しいラウンドで削除された添付ファイル
+    pub removed_attachments: Vec<SubmissionAttachment>,
+}
+
+impl WorkflowSubmission {
+    /// 新しい申請スナップショットを作成する
+    pub fn new(params: NewWorkflowSubmission) -> Self {
+        Self {
+            id:           params.id,
+            tenant_id:    params.tenant_id,
+            instance_id:  params.instance_id,
+            round:        params.round,
+            form_data:    params.form_data,
+            attachments:  params.attachments,
+            submitted_by: params.submitted_by,
+            submitted_at: params.now,
+        }
+    }
+
+    /// 既存のデータから復元する
+    pub fn from_db(record: WorkflowSubmissionRecord) -> Self {
+        Self {
+            id:           record.id,
+            tenant_id:    record.tenant_id,
+            instance_id:  record.instance_id,
+            round:        record.round,
+            form_data:    record.form_data,
+            attachments:  record.attachments,
+            submitted_by: record.submitted_by,
+            submitted_at: record.submitted_at,
+        }
+    }
+
+    /// より新しいラウンドとの差分を計算する
+    ///
+    /// フォームデータはトップレベルのフィールド単位で比較する。
+    /// 一方にしか存在しないフィールドの値は `JsonValue::Null` として扱う。
+    /// 添付ファイルはドキュメント ID で比較する。
+    pub fn diff(&self, newer: &WorkflowSubmission) -> SubmissionDiff {
+        let empty = serde_json::Map::new();
+        let old_fields = self.form_data.as_object().unwrap_or(&empty);
+        let new_fields = newer.form_data.as_object().unwrap_or(&empty);
+
+        let field_ids: BTreeSet<&String> = old_fields.keys().chain(new_fields.keys()).collect();
+        let form_data = field_ids
+            .into_iter()
+            .filter_map(|field_id| {
+                let old_value = old_fields.get(field_id).cloned().unwrap_or(JsonValue::Null);
+                let new_value = new_fields.get(field_id).cloned().unwrap_or(JsonValue::Null);
+                (old_value != new_value).then(|| FormFieldDiff {
+                    field_id: field_id.clone(),
+                    old_value,
+                    new_value,
+                })
+            })
+            .collect();
+
+        let added_attachments = newer
+            .attachments
+            .iter()
+            .filter(|a| !self.contains_attachment(&a.document_id))
+            .cloned()
+            .collect();
+        let removed_attachments = self
+            .attachments
+            .iter()
+            .filter(|a| !newer.contains_attachment(&a.document_id))
+            .cloned()
+            .collect();
+
+        SubmissionDiff {
+            from_round: self.round,
+            to_round: newer.round,
+            form_data,
+            added_attachments,
+            removed_attachments,
+        }
+    }
+
+    fn contains_attachment(&self, document_id: &DocumentId) -> bool {
+        self.attachments
+            .iter()
+            .any(|a| &a.document_id == document_id)
+    }
+
+    // Getter メソッド
+
+    pub fn id(&self) -> &WorkflowSubmissionId {
+        &self.id
+    }
+
+    pub fn tenant_id(&self) -> &TenantId {
+        &self.tenant_id
+    }
+
+    pub fn instance_id(&self) -> &WorkflowInstanceId {
+        &self.instance_id
+    }
+
+    pub fn round(&self) -> i32 {
+        self.round
+    }
+
+    pub fn form_data(&self) -> &JsonValue {
+        &self.form_data
+    }
+
+    pub fn attachments(&self) -> &[SubmissionAttachment] {
+        &self.attachments
+    }
+
+    pub fn submitted_by(&self) -> &UserId {
+        &self.submitted_by
+    }
+
+    pub fn submitted_at(&self) -> DateTime<Utc> {
+        self.submitted_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+    use serde_json::json;
+
+    use super::*;
+
+    /// テスト用の固定タイムスタンプ
+    #[fixture]
+    fn now() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    fn attachment(filename: &str) -> SubmissionAttachment {
+        SubmissionAttachment {
+            document_id:  DocumentId::new(),
+            filename:     filename.to_string(),
+            content_type: "application/pdf".to_string(),
+            size:         1024,
+        }
+    }
+
+    fn submission(
+        round: i32,
+        form_data: JsonValue,
+        attachments: Vec<SubmissionAttachment>,
+        now: DateTime<Utc>,
+    ) -> WorkflowSubmission {
+        WorkflowSubmission::new(NewWorkflowSubmission {
+            id: WorkflowSubmissionId::new(),
+            tenant_id: TenantId::new(),
+            instance_id: WorkflowInstanceId::new(),
+            round,
+            form_data,
+            attachments,
+            submitted_by: UserId::new(),
+            now,
+        })
+    }
+
+    #[rstest]
+    fn test_フォームデータの変更_追加_削除を差分として検出する(
+        now: DateTime<Utc>,
+    ) {
+        let first = submission(
+            1,
+            json!({"amount": 1000, "note": "初回", "purpose": "出張"}),
+            vec![],
+            now,
+        );
+        let second = submission(
+            2,
+            json!({"amount": 800, "purpose": "出張", "reason": "減額"}),
+            vec![],
+            now,
+        );
+
+        let result = first.diff(&second);
+
+        assert_eq!(
+            result.form_data,
+            vec![
+                FormFieldDiff {
+                    field_id:  "amount".to_string(),
+                    old_value: json!(1000),
+                    new_value: json!(800),
+                },
+                FormFieldDiff {
+                    field_id:  "note".to_string(),
+                    old_value: json!("初回"),
+                    new_value: JsonValue::Null,
+                },
+                FormFieldDiff {
+                    field_id:  "reason".to_string(),
+                    old_value: JsonValue::Null,
+                    new_value: json!("減額"),
+                },
+            ]
+        );
+        assert_eq!((result.from_round, result.to_round), (1, 2));
+    }
+
+    #[rstest]
+    fn test_添付ファイルの追加と削除をドキュメントidで検出する(
+        now: DateTime<Utc>,
+    ) {
+        let kept = attachment("見積書.pdf");
+        let removed = attachment("旧請求書.pdf");
+        let added = attachment("新請求書.pdf");
+        let first = submission(1, json!({}), vec![kept.clone(), removed.clone()], now);
+        let second = submission(2, json!({}), vec![kept, added.clone()], now);
+
+        let result = first.diff(&second);
+
+        assert_eq!(result.added_attachments, vec![added]);
+        assert_eq!(result.removed_attachments, vec![removed]);
+        assert!(result.form_data.is_empty());
+    }
+
+    #[rstest]
+    fn test_同一内容のラウンド間の差分は空(now: DateTime<Utc>) {
+        let doc = attachment("見積書.pdf");
+        let first = submission(1, json!({"amount": 1000}), vec![doc.clone()], now);
+        let second = submission(2, json!({"amount": 1000}), vec![doc], now);
+
+        let result = first.diff(&second);
+
+        assert!(result.form_data.is_empty());
+        assert!(result.added_attachments.is_empty());
+        assert!(result.removed_attachments.is_empty());
+    }
+}
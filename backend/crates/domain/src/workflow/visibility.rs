@@ -0,0 +1,191 @@
+//! # ワークフローの閲覧権限
+//!
+//! ワークフローインスタンスを閲覧できるユーザーを判定する。
+//! 詳細・コメント・履歴・添付ファイル一覧・検索結果のすべてに同じ規則を適用する。
+//!
+//! | 閲覧者 | 通常の定義 | 機密定義 |
+//! |--------|-----------|---------|
+//! | 申請者（代理申請の場合は代理人も含む） | ○ | ○ |
+//! | 承認者（いずれかのステップの担当者） | ○ | ○ |
+//! | ウォッチャー | ○ | ○ |
+//! | ワークフロー管理者（`workflow:admin`） | ○ | × |
+//! | その他のテナント内ユーザー | × | × |
+
+use super::{WorkflowInstance, WorkflowStep};
+use crate::user::UserId;
+
+/// ワークフローインスタンスの閲覧者
+///
+/// ワークフロー管理者かどうかはセッションの権限（`workflow:admin`）から BFF が判定する。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowViewer {
+    user_id:           UserId,
+    is_workflow_admin: bool,
+}
+
+impl WorkflowViewer {
+    pub fn new(user_id: UserId, is_workflow_admin: bool) -> Self {
+        Self {
+            user_id,
+            is_workflow_admin,
+        }
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn is_workflow_admin(&self) -> bool {
+        self.is_workflow_admin
+    }
+
+    /// 閲覧者がインスタンスの関与者（申請者・代理人・いずれかのステップの承認者・ウォッチャー）か
+    pub fn is_participant(
+        &self,
+        instance: &WorkflowInstance,
+        steps: &[WorkflowStep],
+        watchers: &[UserId],
+    ) -> bool {
+        instance.is_applicant(&self.user_id)
+            || steps.iter().any(|s| s.assigned_to() == Some(&self.user_id))
+            || watchers.contains(&self.user_id)
+    }
+
+    /// 閲覧者がインスタンスを閲覧できるか
+    ///
+    /// `confidential` はインスタンスの定義が機密定義かどうか。
+    pub fn can_view(
+        &self,
+        instance: &WorkflowInstance,
+        steps: &[WorkflowStep],
+        watchers: &[UserId],
+        confidential: bool,
+    ) -> bool {
+        self.is_participant(instance, steps, watchers) || (self.is_workflow_admin && !confidential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::{
+        tenant::TenantId,
+        value_objects::{DisplayNumber, Version},
+        workflow::{
+            NewWorkflowInstance,
+            NewWorkflowStep,
+            WorkflowDefinitionId,
+            WorkflowInstanceId,
+            WorkflowStepId,
+        },
+    };
+
+    #[fixture]
+    fn now() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    #[fixture]
+    fn instance(now: DateTime<Utc>) -> WorkflowInstance {
+        WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: TenantId::new(),
+            definition_id: WorkflowDefinitionId::new(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(1).unwrap(),
+            title: "テスト申請".to_string(),
+            form_data: serde_json::json!({}),
+            initiated_by: UserId::new(),
+            now,
+        })
+    }
+
+    fn step_assigned_to(instance: &WorkflowInstance, approver: &UserId) -> WorkflowStep {
+        WorkflowStep::new(NewWorkflowStep {
+            id: WorkflowStepId::new(),
+            instance_id: instance.id().clone(),
+            display_number: DisplayNumber::new(1).unwrap(),
+            step_id: "approval".to_string(),
+            step_name: "承認".to_string(),
+            step_type: "approval".to_string(),
+            assigned_to: Some(approver.clone()),
+            now: instance.created_at(),
+        })
+    }
+
+    #[rstest]
+    #[case::通常の定義(false)]
+    #[case::機密定義(true)]
+    fn test_申請者は閲覧できる(instance: WorkflowInstance, #[case] confidential: bool) {
+        let sut = WorkflowViewer::new(instance.initiated_by().clone(), false);
+
+        assert!(sut.can_view(&instance, &[], &[], confidential));
+    }
+
+    #[rstest]
+    #[case::通常の定義(false)]
+    #[case::機密定義(true)]
+    fn test_代理申請の代理人は閲覧できる(
+        instance: WorkflowInstance,
+        #[case] confidential: bool,
+    ) {
+        let proxy = UserId::new();
+        let instance = instance.with_proxy_submitter(proxy.clone());
+        let sut = WorkflowViewer::new(proxy, false);
+
+        assert!(sut.can_view(&instance, &[], &[], confidential));
+    }
+
+    #[rstest]
+    #[case::通常の定義(false)]
+    #[case::機密定義(true)]
+    fn test_承認者は閲覧できる(instance: WorkflowInstance, #[case] confidential: bool) {
+        let approver = UserId::new();
+        let steps = vec![step_assigned_to(&instance, &approver)];
+        let sut = WorkflowViewer::new(approver, false);
+
+        assert!(sut.can_view(&instance, &steps, &[], confidential));
+    }
+
+    #[rstest]
+    #[case::通常の定義(false)]
+    #[case::機密定義(true)]
+    fn test_ウォッチャーは閲覧できる(
+        instance: WorkflowInstance,
+        #[case] confidential: bool,
+    ) {
+        let watcher = UserId::new();
+        let sut = WorkflowViewer::new(watcher.clone(), false);
+
+        assert!(sut.can_view(&instance, &[], &[watcher], confidential));
+    }
+
+    #[rstest]
+    fn test_関与者でないユーザーは閲覧できない(instance: WorkflowInstance) {
+        let steps = vec![step_assigned_to(&instance, &UserId::new())];
+        let sut = WorkflowViewer::new(UserId::new(), false);
+
+        assert!(!sut.can_view(&instance, &steps, &[], false));
+    }
+
+    #[rstest]
+    fn test_ワークフロー管理者は通常の定義のインスタンスを閲覧できる(
+        instance: WorkflowInstance,
+    ) {
+        let sut = WorkflowViewer::new(UserId::new(), true);
+
+        assert!(sut.can_view(&instance, &[], &[], false));
+    }
+
+    #[rstest]
+    fn test_ワークフロー管理者でも関与者でなければ機密定義のインスタンスは閲覧できない(
+        instance: WorkflowInstance,
+    ) {
+        let sut = WorkflowViewer::new(UserId::new(), true);
+
+        assert!(!sut.can_view(&instance, &[], &[], true));
+    }
+}
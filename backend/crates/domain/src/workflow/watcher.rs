@@ -0,0 +1,63 @@
+//! # ワークフローウォッチャー
+//!
+//! 承認者ではないが、ワークフローインスタンスの進捗を追いかけるユーザーを表す。
+//! ウォッチャーは申請者と同じ通知（承認・却下・差し戻しなど）を受け取り、
+//! 機密定義のインスタンスであっても閲覧できる（[`WorkflowViewer`](super::WorkflowViewer) 参照）。
+//!
+//! ウォッチャーの登録経路は 2 つ:
+//!
+//! - 閲覧できるユーザーが自分でウォッチする
+//! - 定義の既定ウォッチャー（[`WorkflowDefinition::default_watchers`](super::WorkflowDefinition::default_watchers)）がインスタンス作成時に登録される
+
+use chrono::{DateTime, Utc};
+
+use super::instance::WorkflowInstanceId;
+use crate::user::UserId;
+
+/// ワークフローウォッチャーエンティティ
+///
+/// インスタンスとユーザーの組で一意。登録後は不変で、解除は削除で表す。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowWatcher {
+    instance_id: WorkflowInstanceId,
+    user_id:     UserId,
+    created_at:  DateTime<Utc>,
+}
+
+impl WorkflowWatcher {
+    /// 新しいウォッチャーを作成する
+    pub fn new(instance_id: WorkflowInstanceId, user_id: UserId, now: DateTime<Utc>) -> Self {
+        Self {
+            instance_id,
+            user_id,
+            created_at: now,
+        }
+    }
+
+    /// 既存のデータから復元する
+    pub fn from_db(
+        instance_id: WorkflowInstanceId,
+        user_id: UserId,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            instance_id,
+            user_id,
+            created_at,
+        }
+    }
+
+    // Getter メソッド
+
+    pub fn instance_id(&self) -> &WorkflowInstanceId {
+        &self.instance_id
+    }
+
+    pub fn user_id(&self) -> &UserId {
+        &self.user_id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
@@ -214,7 +214,7 @@ pub struct TxContext(TxContextInner);
 enum TxContextInner {
     Pg(Transaction<'static, Postgres>),
     #[cfg(any(test, feature = "test-utils"))]
-    Mock,
+    Mock(Vec<Box<dyn FnOnce() + Send>>),
 }
 
 impl TxContext {
@@ -232,7 +232,21 @@ impl TxContext {
     /// `conn()` を呼ぶと panic するが、Mock リポジトリは `conn()` を使用しない。
     #[cfg(any(test, feature = "test-utils"))]
     pub fn mock() -> Self {
-        Self(TxContextInner::Mock)
+        Self(TxContextInner::Mock(Vec::new()))
+    }
+
+    /// コミット時に反映する書き込みを登録する
+    ///
+    /// Mock リポジトリがロールバックを再現するために使用する。
+    /// 登録した書き込みは `commit()` で反映され、コミットせずにドロップすると破棄される。
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn on_commit(&mut self, write: impl FnOnce() + Send + 'static) {
+        match &mut self.0 {
+            TxContextInner::Pg(_) => {
+                panic!("BUG: on_commit() called on Pg TxContext. Only Mock repos should call it.")
+            }
+            TxContextInner::Mock(writes) => writes.push(Box::new(write)),
+        }
     }
 
     /// トランザクションをコミットする
@@ -245,7 +259,10 @@ impl TxContext {
                 Ok(())
             }
             #[cfg(any(test, feature = "test-utils"))]
-            TxContextInner::Mock => Ok(()),
+            TxContextInner::Mock(writes) => {
+                writes.into_iter().for_each(|write| write());
+                Ok(())
+            }
         }
     }
 
@@ -257,7 +274,7 @@ impl TxContext {
         match &mut self.0 {
             TxContextInner::Pg(tx) => tx,
             #[cfg(any(test, feature = "test-utils"))]
-            TxContextInner::Mock => {
+            TxContextInner::Mock(_) => {
                 panic!("BUG: conn() called on Mock TxContext. Mock repos should not call conn().")
             }
         }
@@ -319,4 +336,27 @@ mod tx_context_tests {
     fn test_transaction_manager_traitはsendとsyncを実装している() {
         assert_send_sync::<Box<dyn TransactionManager>>();
     }
+
+    #[tokio::test]
+    async fn test_mockのtx_contextはコミット時に書き込みを反映する() {
+        let written = std::sync::Arc::new(std::sync::Mutex::new(false));
+        let mut tx = TxContext::mock();
+        let flag = written.clone();
+        tx.on_commit(move || *flag.lock().unwrap() = true);
+
+        assert!(!*written.lock().unwrap());
+        tx.commit().await.unwrap();
+        assert!(*written.lock().unwrap());
+    }
+
+    #[test]
+    fn test_mockのtx_contextはコミットせずにドロップすると書き込みを破棄する() {
+        let written = std::sync::Arc::new(std::sync::Mutex::new(false));
+        let mut tx = TxContext::mock();
+        let flag = written.clone();
+        tx.on_commit(move || *flag.lock().unwrap() = true);
+
+        drop(tx);
+        assert!(!*written.lock().unwrap());
+    }
 }
@@ -27,9 +27,14 @@ pub use postgres_folders::PostgresFoldersDeleter;
 pub use postgres_simple::{
     PostgresDisplayIdCounterDeleter,
     PostgresDocumentDeleter,
+    PostgresInboxNotificationDeleter,
+    PostgresNotificationDigestItemDeleter,
     PostgresNotificationLogDeleter,
+    PostgresNotificationPreferenceDeleter,
+    PostgresNotificationTemplateDeleter,
     PostgresRoleDeleter,
     PostgresUserDeleter,
+    PostgresWebhookDeleter,
 };
 pub use postgres_workflow::PostgresWorkflowDeleter;
 pub use redis_session::RedisSessionDeleter;
@@ -92,10 +92,50 @@ define_simple_postgres_deleter!(
     doc: "PostgreSQL 通知ログ Deleter\n\nworkflow_instances の CASCADE でも削除されるが、正確な件数のため明示的に削除する。"
 );
 
+define_simple_postgres_deleter!(
+    name: PostgresInboxNotificationDeleter,
+    deleter_name: "postgres:inbox_notifications",
+    delete_sql: "DELETE FROM inbox_notifications WHERE tenant_id = $1",
+    count_sql: r#"SELECT COUNT(*) as "count!" FROM inbox_notifications WHERE tenant_id = $1"#,
+    doc: "PostgreSQL アプリ内通知 Deleter\n\nworkflow_instances / users の CASCADE でも削除されるが、正確な件数のため明示的に削除する。"
+);
+
+define_simple_postgres_deleter!(
+    name: PostgresNotificationDigestItemDeleter,
+    deleter_name: "postgres:notification_digest_items",
+    delete_sql: "DELETE FROM notification_digest_items WHERE tenant_id = $1",
+    count_sql: r#"SELECT COUNT(*) as "count!" FROM notification_digest_items WHERE tenant_id = $1"#,
+    doc: "PostgreSQL ダイジェスト待ち通知 Deleter\n\nworkflow_instances / users の CASCADE でも削除されるが、正確な件数のため明示的に削除する。"
+);
+
+define_simple_postgres_deleter!(
+    name: PostgresNotificationPreferenceDeleter,
+    deleter_name: "postgres:notification_preferences",
+    delete_sql: "DELETE FROM notification_preferences WHERE tenant_id = $1",
+    count_sql: r#"SELECT COUNT(*) as "count!" FROM notification_preferences WHERE tenant_id = $1"#,
+    doc: "PostgreSQL 通知設定 Deleter\n\nusers の CASCADE でも削除されるが、正確な件数のため明示的に削除する。"
+);
+
+define_simple_postgres_deleter!(
+    name: PostgresNotificationTemplateDeleter,
+    deleter_name: "postgres:notification_templates",
+    delete_sql: "DELETE FROM notification_templates WHERE tenant_id = $1",
+    count_sql: r#"SELECT COUNT(*) as "count!" FROM notification_templates WHERE tenant_id = $1"#,
+    doc: "PostgreSQL 通知テンプレート Deleter\n\nupdated_by → users(id) は CASCADE しないため、users より先に削除する。"
+);
+
 define_simple_postgres_deleter!(
     name: PostgresDocumentDeleter,
     deleter_name: "postgres:documents",
     delete_sql: "DELETE FROM documents WHERE tenant_id = $1",
     count_sql: r#"SELECT COUNT(*) as "count!" FROM documents WHERE tenant_id = $1"#,
-    doc: "PostgreSQL ドキュメント Deleter\n\nworkflow_instances の CASCADE でも削除されるが、正確な件数のため明示的に削除する。"
+    doc: "PostgreSQL ドキュメント Deleter\n\nworkflow_instances / workflow_comments の CASCADE でも削除されるが、正確な件数のため明示的に削除する。"
+);
+
+define_simple_postgres_deleter!(
+    name: PostgresWebhookDeleter,
+    deleter_name: "postgres:webhooks",
+    delete_sql: "DELETE FROM webhook_subscriptions WHERE tenant_id = $1",
+    count_sql: r#"SELECT COUNT(*) as "count!" FROM webhook_subscriptions WHERE tenant_id = $1"#,
+    doc: "PostgreSQL Webhook 購読 Deleter\n\nwebhook_deliveries は CASCADE で自動削除される。"
 );
@@ -1,17 +1,27 @@
 //! # PostgresWorkflowDeleter
 //!
 //! テナントのワークフローデータを削除する。
-//! workflow_comments → workflow_steps → workflow_instances →
-//! workflow_definitions の順で DELETE する。
+//! workflow_event_outbox → workflow_activities → workflow_form_data_changes → workflow_submissions →
+//! workflow_comment_revisions → workflow_comments → workflow_watchers → workflow_steps → workflow_instances → workflow_schedules →
+//! workflow_definitions → workflow_proxy_grants の順で DELETE する。
 //!
 //! ## FK 制約
 //!
+//! - workflow_event_outbox.instance_id → workflow_instances(id) ON DELETE CASCADE
+//! - workflow_activities.instance_id → workflow_instances(id) ON DELETE CASCADE
+//! - workflow_form_data_changes.step_id → workflow_steps(id) ON DELETE CASCADE
+//! - workflow_submissions.instance_id → workflow_instances(id) ON DELETE CASCADE
+//! - workflow_comment_revisions.comment_id → workflow_comments(id) ON DELETE CASCADE
 //! - workflow_comments.instance_id → workflow_instances(id) ON DELETE CASCADE
+//! - workflow_comments.parent_id → workflow_comments(id) ON DELETE CASCADE
+//! - workflow_watchers.instance_id → workflow_instances(id) ON DELETE CASCADE
 //! - workflow_steps.instance_id → workflow_instances(id) ON DELETE CASCADE
+//! - workflow_schedules.definition_id → workflow_definitions(id) ON DELETE CASCADE
 //! - workflow_instances.definition_id → workflow_definitions(id)（CASCADE
 //!   なし）
+//! - workflow_proxy_grants は他のワークフローテーブルから参照されない
 //!
-//! CASCADE があるため instances 削除で comments/steps も消えるが、
+//! CASCADE があるため instances 削除で子テーブルも消えるが、
 //! 明示的に全テーブルを削除し、正確な件数を返す。
 
 use async_trait::async_trait;
@@ -42,6 +52,41 @@ impl TenantDeleter for PostgresWorkflowDeleter {
         let mut tx = self.pool.begin().await?;
 
         // FK 制約に従い子テーブルから順に削除（トランザクションで一貫性を保証）
+        let outbox_events = sqlx::query!(
+            "DELETE FROM workflow_event_outbox WHERE tenant_id = $1",
+            tenant_id.as_uuid()
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let activities = sqlx::query!(
+            "DELETE FROM workflow_activities WHERE tenant_id = $1",
+            tenant_id.as_uuid()
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let form_data_changes = sqlx::query!(
+            "DELETE FROM workflow_form_data_changes WHERE tenant_id = $1",
+            tenant_id.as_uuid()
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let submissions = sqlx::query!(
+            "DELETE FROM workflow_submissions WHERE tenant_id = $1",
+            tenant_id.as_uuid()
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let comment_revisions = sqlx::query!(
+            "DELETE FROM workflow_comment_revisions WHERE tenant_id = $1",
+            tenant_id.as_uuid()
+        )
+        .execute(&mut *tx)
+        .await?;
+
         let comments = sqlx::query!(
             "DELETE FROM workflow_comments WHERE tenant_id = $1",
             tenant_id.as_uuid()
@@ -49,6 +94,13 @@ impl TenantDeleter for PostgresWorkflowDeleter {
         .execute(&mut *tx)
         .await?;
 
+        let watchers = sqlx::query!(
+            "DELETE FROM workflow_watchers WHERE tenant_id = $1",
+            tenant_id.as_uuid()
+        )
+        .execute(&mut *tx)
+        .await?;
+
         let steps = sqlx::query!(
             "DELETE FROM workflow_steps WHERE tenant_id = $1",
             tenant_id.as_uuid()
@@ -63,6 +115,13 @@ impl TenantDeleter for PostgresWorkflowDeleter {
         .execute(&mut *tx)
         .await?;
 
+        let schedules = sqlx::query!(
+            "DELETE FROM workflow_schedules WHERE tenant_id = $1",
+            tenant_id.as_uuid()
+        )
+        .execute(&mut *tx)
+        .await?;
+
         let definitions = sqlx::query!(
             "DELETE FROM workflow_definitions WHERE tenant_id = $1",
             tenant_id.as_uuid()
@@ -70,13 +129,28 @@ impl TenantDeleter for PostgresWorkflowDeleter {
         .execute(&mut *tx)
         .await?;
 
+        let proxy_grants = sqlx::query!(
+            "DELETE FROM workflow_proxy_grants WHERE tenant_id = $1",
+            tenant_id.as_uuid()
+        )
+        .execute(&mut *tx)
+        .await?;
+
         tx.commit().await?;
 
         Ok(DeletionResult {
-            deleted_count: comments.rows_affected()
+            deleted_count: outbox_events.rows_affected()
+                + activities.rows_affected()
+                + form_data_changes.rows_affected()
+                + submissions.rows_affected()
+                + comment_revisions.rows_affected()
+                + comments.rows_affected()
+                + watchers.rows_affected()
                 + steps.rows_affected()
                 + instances.rows_affected()
-                + definitions.rows_affected(),
+                + schedules.rows_affected()
+                + definitions.rows_affected()
+                + proxy_grants.rows_affected(),
         })
     }
 
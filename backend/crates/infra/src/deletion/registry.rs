@@ -17,9 +17,14 @@ use super::{
     PostgresDisplayIdCounterDeleter,
     PostgresDocumentDeleter,
     PostgresFoldersDeleter,
+    PostgresInboxNotificationDeleter,
+    PostgresNotificationDigestItemDeleter,
     PostgresNotificationLogDeleter,
+    PostgresNotificationPreferenceDeleter,
+    PostgresNotificationTemplateDeleter,
     PostgresRoleDeleter,
     PostgresUserDeleter,
+    PostgresWebhookDeleter,
     PostgresWorkflowDeleter,
     RedisSessionDeleter,
     S3DocumentDeleter,
@@ -69,13 +74,37 @@ impl DeletionRegistry {
         registry.register(Box::new(PostgresNotificationLogDeleter::new(
             pg_pool.clone(),
         )));
+        // inbox_notifications.workflow_instance_id → workflow_instances(id) ON DELETE CASCADE
+        // → inbox_notifications を workflows より先に削除し、正確な件数を記録する
+        registry.register(Box::new(PostgresInboxNotificationDeleter::new(
+            pg_pool.clone(),
+        )));
+        // notification_digest_items.workflow_instance_id → workflow_instances(id) ON DELETE CASCADE
+        // → notification_digest_items を workflows より先に削除し、正確な件数を記録する
+        registry.register(Box::new(PostgresNotificationDigestItemDeleter::new(
+            pg_pool.clone(),
+        )));
+        // notification_preferences.user_id → users(id) ON DELETE CASCADE
+        // → notification_preferences を users より先に削除し、正確な件数を記録する
+        registry.register(Box::new(PostgresNotificationPreferenceDeleter::new(
+            pg_pool.clone(),
+        )));
+        // notification_templates.updated_by → users(id) (NO CASCADE)
+        // → notification_templates を users より先に削除する必要がある
+        registry.register(Box::new(PostgresNotificationTemplateDeleter::new(
+            pg_pool.clone(),
+        )));
         // documents.workflow_instance_id → workflow_instances(id) ON DELETE CASCADE
+        // documents.comment_id → workflow_comments(id) ON DELETE CASCADE
         // → documents を workflows より先に削除し、正確な件数を記録する
         registry.register(Box::new(PostgresDocumentDeleter::new(pg_pool.clone())));
         // workflow_definitions.created_by → users(id) (NO CASCADE)
         // workflow_instances.initiated_by → users(id) (NO CASCADE)
         // → workflows を users より先に削除する必要がある
         registry.register(Box::new(PostgresWorkflowDeleter::new(pg_pool.clone())));
+        // webhook_subscriptions.created_by → users(id) (NO CASCADE)
+        // → webhooks を users より先に削除する必要がある
+        registry.register(Box::new(PostgresWebhookDeleter::new(pg_pool.clone())));
         registry.register(Box::new(AuthCredentialsDeleter::new(pg_pool.clone())));
         registry.register(Box::new(PostgresDisplayIdCounterDeleter::new(
             pg_pool.clone(),
@@ -96,8 +125,13 @@ impl DeletionRegistry {
     pub fn expected_deleter_names() -> Vec<&'static str> {
         vec![
             "postgres:notification_logs",
+            "postgres:inbox_notifications",
+            "postgres:notification_digest_items",
+            "postgres:notification_preferences",
+            "postgres:notification_templates",
             "postgres:documents",
             "postgres:workflows",
+            "postgres:webhooks",
             "auth:credentials",
             "postgres:display_id_counters",
             "postgres:folders",
@@ -11,24 +11,57 @@
 use std::{
     collections::HashMap,
     sync::{Arc, Mutex},
+    time::Duration,
 };
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use ringiflow_domain::{
-    notification::{EmailMessage, NotificationError},
+    document::{Document, DocumentId, DocumentStatus},
+    folder::FolderId,
+    locale::Locale,
+    notification::{
+        EmailMessage,
+        InboxNotification,
+        InboxNotificationId,
+        NotificationDelivery,
+        NotificationDigestItem,
+        NotificationError,
+        NotificationEventType,
+        NotificationLog,
+        NotificationLogId,
+        NotificationLogStatus,
+        NotificationPreference,
+        NotificationTemplate,
+    },
     role::{Role, RoleId},
-    tenant::TenantId,
+    tenant::{Tenant, TenantId},
     user::{Email, User, UserId, UserStatus},
     value_objects::{DisplayIdEntityType, DisplayNumber, Version},
+    webhook::{WebhookDelivery, WebhookDeliveryStatus, WebhookSubscription, WebhookSubscriptionId},
     workflow::{
+        ProxyGrant,
+        WorkflowActivity,
         WorkflowComment,
+        WorkflowCommentId,
+        WorkflowCommentRevision,
         WorkflowDefinition,
         WorkflowDefinitionId,
         WorkflowDefinitionStatus,
+        WorkflowEvent,
+        WorkflowEventId,
+        WorkflowEventType,
+        WorkflowFormDataChange,
         WorkflowInstance,
         WorkflowInstanceId,
+        WorkflowInstanceStatus,
+        WorkflowSchedule,
+        WorkflowScheduleId,
         WorkflowStep,
         WorkflowStepId,
+        WorkflowStepStatus,
+        WorkflowSubmission,
+        WorkflowWatcher,
     },
 };
 
@@ -36,18 +69,67 @@ use crate::{
     db::{TransactionManager, TxContext},
     error::InfraError,
     notification::NotificationSender,
+    realtime::{RealtimeMessage, RealtimePublisher},
     repository::{
         DisplayIdCounterRepository,
-        NotificationLog,
+        DocumentRepository,
+        InboxNotificationRepository,
+        KeysetCursor,
+        KeysetPage,
+        NotificationDigestRepository,
         NotificationLogRepository,
+        NotificationPreferenceRepository,
+        NotificationTemplateRepository,
+        PendingWorkflowEvent,
+        TenantRepository,
         UserRepository,
+        WebhookDeliveryRepository,
+        WebhookSubscriptionRepository,
+        WorkflowActivityRepository,
         WorkflowCommentRepository,
         WorkflowDefinitionRepository,
+        WorkflowEventOutboxRepository,
+        WorkflowFormDataChangeRepository,
         WorkflowInstanceRepository,
+        WorkflowProxyGrantRepository,
+        WorkflowScheduleRepository,
         WorkflowStepRepository,
+        WorkflowSubmissionRepository,
+        WorkflowWatcherRepository,
+        keyset_cursor::paginate,
     },
+    s3::S3Client,
+    webhook::{WebhookRequest, WebhookSendError, WebhookSender},
 };
 
+/// インメモリの要素をキーセットページネーションで 1 ページ分に切り出す
+///
+/// PostgreSQL 実装と同じく `(created_at, id)` の降順で並べ、カーソルより後ろの要素を返す。
+fn paginate_in_memory<T>(
+    mut items: Vec<T>,
+    cursor: Option<&str>,
+    limit: i64,
+    key: impl Fn(&T) -> KeysetCursor,
+) -> Result<KeysetPage<T>, InfraError> {
+    let cursor = cursor.map(KeysetCursor::decode).transpose()?;
+    items.sort_by_key(|item| {
+        let k = key(item);
+        std::cmp::Reverse((k.created_at, k.id))
+    });
+    let items: Vec<T> = items
+        .into_iter()
+        .filter(|item| {
+            cursor.as_ref().is_none_or(|c| {
+                let k = key(item);
+                (k.created_at, k.id) < (c.created_at, c.id)
+            })
+        })
+        .take(usize::try_from(limit + 1).unwrap_or(0))
+        .collect();
+    let (items, next_cursor) = paginate(items, limit, &key);
+    Ok(KeysetPage { items, next_cursor })
+}
+
 // ===== FakeWorkflowDefinitionRepository =====
 
 #[derive(Clone, Default)]
@@ -226,15 +308,37 @@ impl WorkflowInstanceRepository for FakeWorkflowInstanceRepository {
         &self,
         tenant_id: &TenantId,
         user_id: &UserId,
-    ) -> Result<Vec<WorkflowInstance>, InfraError> {
-        Ok(self
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<KeysetPage<WorkflowInstance>, InfraError> {
+        let instances = self
             .instances
             .lock()
             .unwrap()
             .iter()
             .filter(|i| i.tenant_id() == tenant_id && i.initiated_by() == user_id)
             .cloned()
-            .collect())
+            .collect();
+        paginate_in_memory(instances, cursor, limit, |i| {
+            KeysetCursor::new(i.created_at(), *i.id().as_uuid())
+        })
+    }
+
+    async fn count_by_initiated_by(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        status: WorkflowInstanceStatus,
+    ) -> Result<i64, InfraError> {
+        Ok(self
+            .instances
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|i| {
+                i.tenant_id() == tenant_id && i.initiated_by() == user_id && i.status() == status
+            })
+            .count() as i64)
     }
 
     async fn find_by_ids(
@@ -265,6 +369,42 @@ impl WorkflowInstanceRepository for FakeWorkflowInstanceRepository {
             .find(|i| i.display_number() == display_number && i.tenant_id() == tenant_id)
             .cloned())
     }
+
+    async fn delete_draft(
+        &self,
+        _tx: &mut TxContext,
+        id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<bool, InfraError> {
+        let mut instances = self.instances.lock().unwrap();
+        let before = instances.len();
+        instances.retain(|i| {
+            !(i.id() == id
+                && i.tenant_id() == tenant_id
+                && i.status() == WorkflowInstanceStatus::Draft)
+        });
+        Ok(instances.len() < before)
+    }
+
+    async fn find_stale_drafts(
+        &self,
+        updated_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WorkflowInstance>, InfraError> {
+        let mut drafts: Vec<WorkflowInstance> = self
+            .instances
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|i| {
+                i.status() == WorkflowInstanceStatus::Draft && i.updated_at() < updated_before
+            })
+            .cloned()
+            .collect();
+        drafts.sort_by_key(|i| (i.updated_at(), *i.id().as_uuid()));
+        drafts.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(drafts)
+    }
 }
 
 // ===== FakeWorkflowStepRepository =====
@@ -344,19 +484,61 @@ impl WorkflowStepRepository for FakeWorkflowStepRepository {
             .collect())
     }
 
-    async fn find_by_assigned_to(
+    async fn find_active_by_assigned_to(
         &self,
         _tenant_id: &TenantId,
         user_id: &UserId,
-    ) -> Result<Vec<WorkflowStep>, InfraError> {
-        Ok(self
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<KeysetPage<WorkflowStep>, InfraError> {
+        let steps = self
             .steps
             .lock()
             .unwrap()
             .iter()
-            .filter(|s| s.assigned_to() == Some(user_id))
+            .filter(|s| {
+                s.assigned_to() == Some(user_id) && s.status() == WorkflowStepStatus::Active
+            })
             .cloned()
-            .collect())
+            .collect();
+        paginate_in_memory(steps, cursor, limit, |s| {
+            KeysetCursor::new(s.created_at(), *s.id().as_uuid())
+        })
+    }
+
+    async fn count_active_by_assigned_to(
+        &self,
+        _tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<i64, InfraError> {
+        Ok(self
+            .steps
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| {
+                s.assigned_to() == Some(user_id) && s.status() == WorkflowStepStatus::Active
+            })
+            .count() as i64)
+    }
+
+    async fn count_completed_by_assigned_to_since(
+        &self,
+        _tenant_id: &TenantId,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<i64, InfraError> {
+        Ok(self
+            .steps
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|s| {
+                s.assigned_to() == Some(user_id)
+                    && s.status() == WorkflowStepStatus::Completed
+                    && s.completed_at().is_some_and(|completed| completed >= since)
+            })
+            .count() as i64)
     }
 
     async fn find_by_display_number(
@@ -453,12 +635,37 @@ impl UserRepository for FakeUserRepository {
         Ok(())
     }
 
+    async fn update_locale(&self, user: &User) -> Result<(), InfraError> {
+        let mut users = self.users.lock().unwrap();
+        if let Some(stored) = users.iter_mut().find(|u| u.id() == user.id()) {
+            *stored = user.clone();
+        }
+        Ok(())
+    }
+
+    /// テナントの既定ロケールは持たないため、ロケール未設定のユーザーは既定値（日本語）を返す
+    async fn find_effective_locale(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<Option<Locale>, InfraError> {
+        let users = self.users.lock().unwrap();
+        Ok(users
+            .iter()
+            .find(|u| u.tenant_id() == tenant_id && u.id() == user_id)
+            .map(|u| u.locale().unwrap_or_default()))
+    }
+
     async fn find_by_display_number(
         &self,
-        _tenant_id: &TenantId,
-        _display_number: DisplayNumber,
+        tenant_id: &TenantId,
+        display_number: DisplayNumber,
     ) -> Result<Option<User>, InfraError> {
-        Ok(None)
+        let users = self.users.lock().unwrap();
+        Ok(users
+            .iter()
+            .find(|u| u.tenant_id() == tenant_id && u.display_number() == display_number)
+            .cloned())
     }
 
     async fn find_all_by_tenant(
@@ -513,6 +720,43 @@ impl UserRepository for FakeUserRepository {
     }
 }
 
+// ===== FakeTenantRepository =====
+
+/// テスト用の FakeTenantRepository
+///
+/// テナントをインメモリで管理する。`add_tenant()` でテストデータを追加する。
+#[derive(Clone, Default)]
+pub struct FakeTenantRepository {
+    tenants: Arc<Mutex<Vec<Tenant>>>,
+}
+
+impl FakeTenantRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// テスト用テナントを追加する
+    pub fn add_tenant(&self, tenant: Tenant) {
+        self.tenants.lock().unwrap().push(tenant);
+    }
+}
+
+#[async_trait]
+impl TenantRepository for FakeTenantRepository {
+    async fn find_by_id(&self, id: &TenantId) -> Result<Option<Tenant>, InfraError> {
+        let tenants = self.tenants.lock().unwrap();
+        Ok(tenants.iter().find(|t| t.id() == id).cloned())
+    }
+
+    async fn update_settings(&self, tenant: &Tenant) -> Result<(), InfraError> {
+        let mut tenants = self.tenants.lock().unwrap();
+        if let Some(existing) = tenants.iter_mut().find(|t| t.id() == tenant.id()) {
+            *existing = tenant.clone();
+        }
+        Ok(())
+    }
+}
+
 // ===== FakeDisplayIdCounterRepository =====
 
 /// テスト用のモック DisplayIdCounterRepository
@@ -546,15 +790,20 @@ impl DisplayIdCounterRepository for FakeDisplayIdCounterRepository {
 
 // ===== FakeWorkflowCommentRepository =====
 
+/// テスト用の FakeWorkflowCommentRepository
+///
+/// 書き込みはトランザクションのコミット時に反映する（ロールバックを再現する）。
 #[derive(Clone, Default)]
 pub struct FakeWorkflowCommentRepository {
-    comments: Arc<Mutex<Vec<WorkflowComment>>>,
+    comments:  Arc<Mutex<Vec<WorkflowComment>>>,
+    revisions: Arc<Mutex<Vec<WorkflowCommentRevision>>>,
 }
 
 impl FakeWorkflowCommentRepository {
     pub fn new() -> Self {
         Self {
-            comments: Arc::new(Mutex::new(Vec::new())),
+            comments:  Arc::new(Mutex::new(Vec::new())),
+            revisions: Arc::new(Mutex::new(Vec::new())),
         }
     }
 }
@@ -563,11 +812,13 @@ impl FakeWorkflowCommentRepository {
 impl WorkflowCommentRepository for FakeWorkflowCommentRepository {
     async fn insert(
         &self,
+        tx: &mut TxContext,
         comment: &WorkflowComment,
         _tenant_id: &TenantId,
     ) -> Result<(), InfraError> {
-        let mut comments = self.comments.lock().unwrap();
-        comments.push(comment.clone());
+        let comments = self.comments.clone();
+        let comment = comment.clone();
+        tx.on_commit(move || comments.lock().unwrap().push(comment));
         Ok(())
     }
 
@@ -585,199 +836,1845 @@ impl WorkflowCommentRepository for FakeWorkflowCommentRepository {
         result.sort_by_key(|c| c.created_at());
         Ok(result)
     }
+
+    async fn find_by_id(
+        &self,
+        id: &WorkflowCommentId,
+        _tenant_id: &TenantId,
+    ) -> Result<Option<WorkflowComment>, InfraError> {
+        let comments = self.comments.lock().unwrap();
+        Ok(comments.iter().find(|c| c.id() == id).cloned())
+    }
+
+    async fn update(
+        &self,
+        tx: &mut TxContext,
+        comment: &WorkflowComment,
+        _tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        let comments = self.comments.clone();
+        let comment = comment.clone();
+        tx.on_commit(move || {
+            let mut comments = comments.lock().unwrap();
+            if let Some(existing) = comments.iter_mut().find(|c| c.id() == comment.id()) {
+                *existing = comment;
+            }
+        });
+        Ok(())
+    }
+
+    async fn insert_revision(
+        &self,
+        tx: &mut TxContext,
+        revision: &WorkflowCommentRevision,
+        _tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        let revisions = self.revisions.clone();
+        let revision = revision.clone();
+        tx.on_commit(move || revisions.lock().unwrap().push(revision));
+        Ok(())
+    }
+
+    async fn find_revisions(
+        &self,
+        comment_id: &WorkflowCommentId,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowCommentRevision>, InfraError> {
+        let revisions = self.revisions.lock().unwrap();
+        let mut result: Vec<_> = revisions
+            .iter()
+            .filter(|r| r.comment_id() == comment_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|r| r.edited_at());
+        Ok(result)
+    }
 }
 
-// ===== FakeFolderRepository =====
+// ===== FakeWorkflowFormDataChangeRepository =====
 
-/// テスト用の FakeFolderRepository
-///
-/// フォルダをインメモリで管理する。`max_subtree_depth` は
-/// 格納されたフォルダの path プレフィックスマッチで計算する。
 #[derive(Clone, Default)]
-pub struct FakeFolderRepository {
-    folders: Arc<Mutex<Vec<ringiflow_domain::folder::Folder>>>,
+pub struct FakeWorkflowFormDataChangeRepository {
+    changes: Arc<Mutex<Vec<WorkflowFormDataChange>>>,
 }
 
-impl FakeFolderRepository {
+impl FakeWorkflowFormDataChangeRepository {
     pub fn new() -> Self {
         Self {
-            folders: Arc::new(Mutex::new(Vec::new())),
+            changes: Arc::new(Mutex::new(Vec::new())),
         }
     }
-
-    pub fn add_folder(&self, folder: ringiflow_domain::folder::Folder) {
-        self.folders.lock().unwrap().push(folder);
-    }
 }
 
 #[async_trait]
-impl crate::repository::FolderRepository for FakeFolderRepository {
-    async fn find_all_by_tenant(
+impl WorkflowFormDataChangeRepository for FakeWorkflowFormDataChangeRepository {
+    async fn insert_all(
         &self,
-        tenant_id: &TenantId,
-    ) -> Result<Vec<ringiflow_domain::folder::Folder>, InfraError> {
-        Ok(self
-            .folders
-            .lock()
-            .unwrap()
-            .iter()
-            .filter(|f| f.tenant_id() == tenant_id)
-            .cloned()
-            .collect())
+        _tx: &mut TxContext,
+        changes: &[WorkflowFormDataChange],
+        _tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        let mut stored = self.changes.lock().unwrap();
+        stored.extend_from_slice(changes);
+        Ok(())
     }
 
-    async fn find_by_id(
+    async fn find_by_instance(
         &self,
-        id: &ringiflow_domain::folder::FolderId,
+        instance_id: &WorkflowInstanceId,
         _tenant_id: &TenantId,
-    ) -> Result<Option<ringiflow_domain::folder::Folder>, InfraError> {
-        Ok(self
-            .folders
-            .lock()
-            .unwrap()
+    ) -> Result<Vec<WorkflowFormDataChange>, InfraError> {
+        let changes = self.changes.lock().unwrap();
+        let mut result: Vec<_> = changes
             .iter()
-            .find(|f| f.id() == id)
-            .cloned())
+            .filter(|c| c.instance_id() == instance_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|c| c.changed_at());
+        Ok(result)
     }
+}
 
-    async fn insert(&self, folder: &ringiflow_domain::folder::Folder) -> Result<(), InfraError> {
-        self.folders.lock().unwrap().push(folder.clone());
-        Ok(())
+// ===== FakeWorkflowSubmissionRepository =====
+
+#[derive(Clone, Default)]
+pub struct FakeWorkflowSubmissionRepository {
+    submissions: Arc<Mutex<Vec<WorkflowSubmission>>>,
+}
+
+impl FakeWorkflowSubmissionRepository {
+    pub fn new() -> Self {
+        Self {
+            submissions: Arc::new(Mutex::new(Vec::new())),
+        }
     }
+}
 
-    async fn update(
+#[async_trait]
+impl WorkflowSubmissionRepository for FakeWorkflowSubmissionRepository {
+    async fn insert(
         &self,
         _tx: &mut TxContext,
-        folder: &ringiflow_domain::folder::Folder,
+        submission: &WorkflowSubmission,
+        _tenant_id: &TenantId,
     ) -> Result<(), InfraError> {
-        let mut folders = self.folders.lock().unwrap();
-        if let Some(pos) = folders.iter().position(|f| f.id() == folder.id()) {
-            folders[pos] = folder.clone();
-        }
+        let mut submissions = self.submissions.lock().unwrap();
+        submissions.push(submission.clone());
         Ok(())
     }
 
-    async fn update_subtree_paths(
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowSubmission>, InfraError> {
+        let submissions = self.submissions.lock().unwrap();
+        let mut result: Vec<_> = submissions
+            .iter()
+            .filter(|s| s.instance_id() == instance_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|s| s.round());
+        Ok(result)
+    }
+}
+
+// ===== FakeWorkflowWatcherRepository =====
+
+#[derive(Clone, Default)]
+pub struct FakeWorkflowWatcherRepository {
+    watchers: Arc<Mutex<Vec<WorkflowWatcher>>>,
+}
+
+impl FakeWorkflowWatcherRepository {
+    pub fn new() -> Self {
+        Self {
+            watchers: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    fn push_unique(watchers: &mut Vec<WorkflowWatcher>, watcher: &WorkflowWatcher) {
+        let exists = watchers
+            .iter()
+            .any(|w| w.instance_id() == watcher.instance_id() && w.user_id() == watcher.user_id());
+        if !exists {
+            watchers.push(watcher.clone());
+        }
+    }
+}
+
+#[async_trait]
+impl WorkflowWatcherRepository for FakeWorkflowWatcherRepository {
+    async fn insert_all(
         &self,
         _tx: &mut TxContext,
-        _old_path: &str,
-        _new_path: &str,
-        _depth_delta: i32,
+        watchers: &[WorkflowWatcher],
         _tenant_id: &TenantId,
     ) -> Result<(), InfraError> {
+        let mut stored = self.watchers.lock().unwrap();
+        for watcher in watchers {
+            Self::push_unique(&mut stored, watcher);
+        }
         Ok(())
     }
 
-    async fn max_subtree_depth(&self, path: &str, tenant_id: &TenantId) -> Result<i32, InfraError> {
-        let folders = self.folders.lock().unwrap();
-        let max = folders
-            .iter()
-            .filter(|f| f.tenant_id() == tenant_id && f.path().starts_with(path))
-            .map(|f| f.depth())
-            .max()
-            .unwrap_or(0);
-        Ok(max)
+    async fn insert(
+        &self,
+        watcher: &WorkflowWatcher,
+        _tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        let mut stored = self.watchers.lock().unwrap();
+        Self::push_unique(&mut stored, watcher);
+        Ok(())
     }
 
     async fn delete(
         &self,
-        id: &ringiflow_domain::folder::FolderId,
+        instance_id: &WorkflowInstanceId,
+        user_id: &UserId,
         _tenant_id: &TenantId,
     ) -> Result<(), InfraError> {
-        let mut folders = self.folders.lock().unwrap();
-        folders.retain(|f| f.id() != id);
+        let mut stored = self.watchers.lock().unwrap();
+        stored.retain(|w| !(w.instance_id() == instance_id && w.user_id() == user_id));
+        Ok(())
+    }
+
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowWatcher>, InfraError> {
+        let watchers = self.watchers.lock().unwrap();
+        let mut result: Vec<_> = watchers
+            .iter()
+            .filter(|w| w.instance_id() == instance_id)
+            .cloned()
+            .collect();
+        result.sort_by_key(|w| w.created_at());
+        Ok(result)
+    }
+}
+
+// ===== FakeWorkflowProxyGrantRepository =====
+
+#[derive(Clone, Default)]
+pub struct FakeWorkflowProxyGrantRepository {
+    grants: Arc<Mutex<Vec<ProxyGrant>>>,
+}
+
+impl FakeWorkflowProxyGrantRepository {
+    pub fn new() -> Self {
+        Self {
+            grants: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl WorkflowProxyGrantRepository for FakeWorkflowProxyGrantRepository {
+    async fn insert(&self, grant: &ProxyGrant, _tenant_id: &TenantId) -> Result<(), InfraError> {
+        let mut grants = self.grants.lock().unwrap();
+        let exists = grants
+            .iter()
+            .any(|g| g.principal_id() == grant.principal_id() && g.proxy_id() == grant.proxy_id());
+        if !exists {
+            grants.push(grant.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        principal_id: &UserId,
+        proxy_id: &UserId,
+        _tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        let mut grants = self.grants.lock().unwrap();
+        grants.retain(|g| !(g.principal_id() == principal_id && g.proxy_id() == proxy_id));
+        Ok(())
+    }
+
+    async fn exists(
+        &self,
+        principal_id: &UserId,
+        proxy_id: &UserId,
+        _tenant_id: &TenantId,
+    ) -> Result<bool, InfraError> {
+        let grants = self.grants.lock().unwrap();
+        Ok(grants
+            .iter()
+            .any(|g| g.principal_id() == principal_id && g.proxy_id() == proxy_id))
+    }
+
+    async fn find_by_principal(
+        &self,
+        principal_id: &UserId,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<ProxyGrant>, InfraError> {
+        let grants = self.grants.lock().unwrap();
+        Ok(grants
+            .iter()
+            .filter(|g| g.principal_id() == principal_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_proxy(
+        &self,
+        proxy_id: &UserId,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<ProxyGrant>, InfraError> {
+        let grants = self.grants.lock().unwrap();
+        Ok(grants
+            .iter()
+            .filter(|g| g.proxy_id() == proxy_id)
+            .cloned()
+            .collect())
+    }
+}
+
+// ===== FakeWorkflowActivityRepository =====
+
+#[derive(Clone, Default)]
+pub struct FakeWorkflowActivityRepository {
+    activities: Arc<Mutex<Vec<WorkflowActivity>>>,
+}
+
+impl FakeWorkflowActivityRepository {
+    pub fn new() -> Self {
+        Self {
+            activities: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+}
+
+#[async_trait]
+impl WorkflowActivityRepository for FakeWorkflowActivityRepository {
+    async fn insert(
+        &self,
+        activity: &WorkflowActivity,
+        _tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        self.activities.lock().unwrap().push(activity.clone());
+        Ok(())
+    }
+
+    async fn insert_all(
+        &self,
+        _tx: &mut TxContext,
+        activities: &[WorkflowActivity],
+        _tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        self.activities
+            .lock()
+            .unwrap()
+            .extend(activities.iter().cloned());
+        Ok(())
+    }
+
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowActivity>, InfraError> {
+        let activities = self.activities.lock().unwrap();
+        let mut result: Vec<_> = activities
+            .iter()
+            .filter(|a| a.instance_id() == instance_id)
+            .cloned()
+            .collect();
+        // 安定ソートのため、同時刻のアクティビティは記録順を維持する
+        result.sort_by_key(|a| a.occurred_at());
+        Ok(result)
+    }
+}
+
+// ===== FakeWorkflowEventOutboxRepository =====
+
+/// アウトボックスの 1 行（配信状態を含む）
+#[derive(Clone)]
+struct FakeOutboxEntry {
+    event: WorkflowEvent,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    last_error: Option<String>,
+    delivered_consumers: Vec<String>,
+    dispatched_at: Option<DateTime<Utc>>,
+    failed_at: Option<DateTime<Utc>>,
+}
+
+/// テスト用の FakeWorkflowEventOutboxRepository
+///
+/// イベントと配信状態をインメモリで管理する。ロックは行わず、取り出し時に処理期限だけを設定する。
+/// [`fail_insert`](Self::fail_insert) でイベントの書き込みの失敗を再現できる。
+#[derive(Clone, Default)]
+pub struct FakeWorkflowEventOutboxRepository {
+    entries:      Arc<Mutex<Vec<FakeOutboxEntry>>>,
+    insert_fails: Arc<Mutex<bool>>,
+}
+
+impl FakeWorkflowEventOutboxRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 以降のイベントの書き込みを失敗させるか設定する
+    pub fn fail_insert(&self, fails: bool) {
+        *self.insert_fails.lock().unwrap() = fails;
+    }
+
+    /// 書き込まれたイベントの一覧を取得する（書き込み順）
+    pub fn events(&self) -> Vec<WorkflowEvent> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|e| e.event.clone())
+            .collect()
+    }
+
+    /// 配信済みのイベント ID の一覧を取得する
+    pub fn dispatched_ids(&self) -> Vec<WorkflowEventId> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| e.dispatched_at.is_some())
+            .map(|e| e.event.id().clone())
+            .collect()
+    }
+
+    /// イベントの配信失敗回数と直近の失敗理由を取得する
+    pub fn failure_of(&self, id: &WorkflowEventId) -> Option<(i32, Option<String>)> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|e| e.event.id() == id)
+            .map(|e| (e.attempts, e.last_error.clone()))
+    }
+
+    /// 配信停止したイベント ID の一覧を取得する
+    pub fn dead_ids(&self) -> Vec<WorkflowEventId> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|e| e.failed_at.is_some())
+            .map(|e| e.event.id().clone())
+            .collect()
+    }
+
+    /// イベントの配信に成功したコンシューマ名を取得する
+    pub fn delivered_consumers_of(&self, id: &WorkflowEventId) -> Vec<String> {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|e| e.event.id() == id)
+            .map(|e| e.delivered_consumers.clone())
+            .unwrap_or_default()
+    }
+
+    /// イベントの次回配信日時を変更する（バックオフ経過後の再配信を再現する）
+    pub fn set_next_attempt_at(&self, id: &WorkflowEventId, next_attempt_at: DateTime<Utc>) {
+        if let Some(entry) = self
+            .entries
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|e| e.event.id() == id)
+        {
+            entry.next_attempt_at = next_attempt_at;
+        }
+    }
+}
+
+#[async_trait]
+impl WorkflowEventOutboxRepository for FakeWorkflowEventOutboxRepository {
+    async fn insert_all(
+        &self,
+        _tx: &mut TxContext,
+        events: &[WorkflowEvent],
+        _tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        if *self.insert_fails.lock().unwrap() {
+            return Err(InfraError::unexpected("イベントの書き込みの失敗"));
+        }
+        self.entries
+            .lock()
+            .unwrap()
+            .extend(events.iter().map(|event| FakeOutboxEntry {
+                event: event.clone(),
+                attempts: 0,
+                next_attempt_at: event.occurred_at(),
+                last_error: None,
+                delivered_consumers: Vec::new(),
+                dispatched_at: None,
+                failed_at: None,
+            }));
+        Ok(())
+    }
+
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowEvent>, InfraError> {
+        let mut result: Vec<_> = self
+            .events()
+            .into_iter()
+            .filter(|e| e.instance_id() == instance_id)
+            .collect();
+        result.sort_by_key(|e| e.occurred_at());
+        Ok(result)
+    }
+
+    async fn claim_pending(
+        &self,
+        _tx: &mut TxContext,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<PendingWorkflowEvent>, InfraError> {
+        let mut entries = self.entries.lock().unwrap();
+        let mut due: Vec<_> = entries
+            .iter_mut()
+            .filter(|e| {
+                e.dispatched_at.is_none() && e.failed_at.is_none() && e.next_attempt_at <= now
+            })
+            .collect();
+        due.sort_by_key(|e| e.event.occurred_at());
+        due.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(due
+            .into_iter()
+            .map(|e| {
+                e.next_attempt_at = lease_until;
+                PendingWorkflowEvent {
+                    event: e.event.clone(),
+                    attempts: e.attempts,
+                    delivered_consumers: e.delivered_consumers.clone(),
+                }
+            })
+            .collect())
+    }
+
+    async fn mark_dispatched(
+        &self,
+        _tx: &mut TxContext,
+        id: &WorkflowEventId,
+        delivered_consumers: &[String],
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        if let Some(entry) = self
+            .entries
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|e| e.event.id() == id)
+        {
+            entry.dispatched_at = Some(now);
+            entry.delivered_consumers = delivered_consumers.to_vec();
+            entry.last_error = None;
+        }
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        _tx: &mut TxContext,
+        id: &WorkflowEventId,
+        delivered_consumers: &[String],
+        error: &str,
+        next_attempt_at: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        if let Some(entry) = self
+            .entries
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|e| e.event.id() == id)
+        {
+            entry.attempts += 1;
+            entry.delivered_consumers = delivered_consumers.to_vec();
+            entry.last_error = Some(error.to_string());
+            entry.next_attempt_at = next_attempt_at;
+        }
+        Ok(())
+    }
+
+    async fn mark_dead(
+        &self,
+        _tx: &mut TxContext,
+        id: &WorkflowEventId,
+        delivered_consumers: &[String],
+        error: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        if let Some(entry) = self
+            .entries
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .find(|e| e.event.id() == id)
+        {
+            entry.attempts += 1;
+            entry.delivered_consumers = delivered_consumers.to_vec();
+            entry.last_error = Some(error.to_string());
+            entry.failed_at = Some(now);
+        }
+        Ok(())
+    }
+}
+
+// ===== FakeDocumentRepository =====
+
+/// テスト用の FakeDocumentRepository
+///
+/// ドキュメントをインメモリで管理する。一覧・集計は `active` のみを対象にする。
+#[derive(Clone, Default)]
+pub struct FakeDocumentRepository {
+    documents: Arc<Mutex<Vec<Document>>>,
+}
+
+impl FakeDocumentRepository {
+    pub fn new() -> Self {
+        Self {
+            documents: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn add_document(&self, document: Document) {
+        self.documents.lock().unwrap().push(document);
+    }
+
+    fn active_documents(&self, predicate: impl Fn(&Document) -> bool) -> Vec<Document> {
+        self.documents
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|d| d.status() == DocumentStatus::Active && predicate(d))
+            .cloned()
+            .collect()
+    }
+}
+
+#[async_trait]
+impl DocumentRepository for FakeDocumentRepository {
+    async fn find_by_id(
+        &self,
+        id: &DocumentId,
+        _tenant_id: &TenantId,
+    ) -> Result<Option<Document>, InfraError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|d| d.id() == id)
+            .cloned())
+    }
+
+    async fn insert(&self, document: &Document) -> Result<(), InfraError> {
+        self.documents.lock().unwrap().push(document.clone());
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        id: &DocumentId,
+        status: DocumentStatus,
+        _tenant_id: &TenantId,
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        let mut documents = self.documents.lock().unwrap();
+        if let Some(pos) = documents.iter().position(|d| d.id() == id) {
+            let d = &documents[pos];
+            documents[pos] = Document::from_db(
+                d.id().clone(),
+                d.tenant_id().clone(),
+                d.filename().to_string(),
+                d.content_type().to_string(),
+                d.size(),
+                d.s3_key().to_string(),
+                d.upload_context().clone(),
+                status,
+                d.uploaded_by().cloned(),
+                d.created_at(),
+                now,
+                d.deleted_at(),
+            );
+        }
+        Ok(())
+    }
+
+    async fn count_and_total_size_by_folder(
+        &self,
+        folder_id: &FolderId,
+        _tenant_id: &TenantId,
+    ) -> Result<(usize, i64), InfraError> {
+        let documents =
+            self.active_documents(|d| d.upload_context().folder_id() == Some(folder_id));
+        Ok((documents.len(), documents.iter().map(Document::size).sum()))
+    }
+
+    async fn count_and_total_size_by_workflow(
+        &self,
+        workflow_instance_id: &WorkflowInstanceId,
+        _tenant_id: &TenantId,
+    ) -> Result<(usize, i64), InfraError> {
+        let documents = self.active_documents(|d| {
+            d.upload_context().workflow_instance_id() == Some(workflow_instance_id)
+        });
+        Ok((documents.len(), documents.iter().map(Document::size).sum()))
+    }
+
+    async fn count_and_total_size_by_comment(
+        &self,
+        comment_id: &WorkflowCommentId,
+        _tenant_id: &TenantId,
+    ) -> Result<(usize, i64), InfraError> {
+        let documents =
+            self.active_documents(|d| d.upload_context().comment_id() == Some(comment_id));
+        Ok((documents.len(), documents.iter().map(Document::size).sum()))
+    }
+
+    async fn soft_delete(
+        &self,
+        id: &DocumentId,
+        _tenant_id: &TenantId,
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        let mut documents = self.documents.lock().unwrap();
+        if let Some(pos) = documents.iter().position(|d| d.id() == id)
+            && let Ok(deleted) = documents[pos].clone().soft_delete(now)
+        {
+            documents[pos] = deleted;
+        }
+        Ok(())
+    }
+
+    async fn list_by_folder(
+        &self,
+        folder_id: &FolderId,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<Document>, InfraError> {
+        Ok(self.active_documents(|d| d.upload_context().folder_id() == Some(folder_id)))
+    }
+
+    async fn list_by_workflow(
+        &self,
+        workflow_instance_id: &WorkflowInstanceId,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<Document>, InfraError> {
+        Ok(self.active_documents(|d| {
+            d.upload_context().workflow_instance_id() == Some(workflow_instance_id)
+        }))
+    }
+
+    async fn list_by_comments(
+        &self,
+        comment_ids: &[WorkflowCommentId],
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<Document>, InfraError> {
+        Ok(self.active_documents(|d| {
+            d.upload_context()
+                .comment_id()
+                .is_some_and(|id| comment_ids.contains(id))
+        }))
+    }
+
+    /// コメントとインスタンスの対応を持たないため、コメント添付は削除対象に含めない
+    async fn delete_by_workflow(
+        &self,
+        _tx: &mut TxContext,
+        workflow_instance_id: &WorkflowInstanceId,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<String>, InfraError> {
+        let mut documents = self.documents.lock().unwrap();
+        let (deleted, kept): (Vec<Document>, Vec<Document>) = documents
+            .drain(..)
+            .partition(|d| d.upload_context().workflow_instance_id() == Some(workflow_instance_id));
+        *documents = kept;
+        Ok(deleted.iter().map(|d| d.s3_key().to_string()).collect())
+    }
+
+    async fn exists_by_s3_key(
+        &self,
+        s3_key: &str,
+        _tenant_id: &TenantId,
+    ) -> Result<bool, InfraError> {
+        Ok(self
+            .documents
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|d| d.s3_key() == s3_key))
+    }
+}
+
+// ===== FakeFolderRepository =====
+
+/// テスト用の FakeFolderRepository
+///
+/// フォルダをインメモリで管理する。`max_subtree_depth` は
+/// 格納されたフォルダの path プレフィックスマッチで計算する。
+#[derive(Clone, Default)]
+pub struct FakeFolderRepository {
+    folders: Arc<Mutex<Vec<ringiflow_domain::folder::Folder>>>,
+}
+
+impl FakeFolderRepository {
+    pub fn new() -> Self {
+        Self {
+            folders: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn add_folder(&self, folder: ringiflow_domain::folder::Folder) {
+        self.folders.lock().unwrap().push(folder);
+    }
+}
+
+#[async_trait]
+impl crate::repository::FolderRepository for FakeFolderRepository {
+    async fn find_all_by_tenant(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<ringiflow_domain::folder::Folder>, InfraError> {
+        Ok(self
+            .folders
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|f| f.tenant_id() == tenant_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &ringiflow_domain::folder::FolderId,
+        _tenant_id: &TenantId,
+    ) -> Result<Option<ringiflow_domain::folder::Folder>, InfraError> {
+        Ok(self
+            .folders
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|f| f.id() == id)
+            .cloned())
+    }
+
+    async fn insert(&self, folder: &ringiflow_domain::folder::Folder) -> Result<(), InfraError> {
+        self.folders.lock().unwrap().push(folder.clone());
+        Ok(())
+    }
+
+    async fn update(
+        &self,
+        _tx: &mut TxContext,
+        folder: &ringiflow_domain::folder::Folder,
+    ) -> Result<(), InfraError> {
+        let mut folders = self.folders.lock().unwrap();
+        if let Some(pos) = folders.iter().position(|f| f.id() == folder.id()) {
+            folders[pos] = folder.clone();
+        }
+        Ok(())
+    }
+
+    async fn update_subtree_paths(
+        &self,
+        _tx: &mut TxContext,
+        _old_path: &str,
+        _new_path: &str,
+        _depth_delta: i32,
+        _tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        Ok(())
+    }
+
+    async fn max_subtree_depth(&self, path: &str, tenant_id: &TenantId) -> Result<i32, InfraError> {
+        let folders = self.folders.lock().unwrap();
+        let max = folders
+            .iter()
+            .filter(|f| f.tenant_id() == tenant_id && f.path().starts_with(path))
+            .map(|f| f.depth())
+            .max()
+            .unwrap_or(0);
+        Ok(max)
+    }
+
+    async fn delete(
+        &self,
+        id: &ringiflow_domain::folder::FolderId,
+        _tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        let mut folders = self.folders.lock().unwrap();
+        folders.retain(|f| f.id() != id);
         Ok(())
     }
 
     async fn count_children(
         &self,
-        parent_id: &ringiflow_domain::folder::FolderId,
-        _tenant_id: &TenantId,
-    ) -> Result<i64, InfraError> {
-        let count = self
-            .folders
+        parent_id: &ringiflow_domain::folder::FolderId,
+        _tenant_id: &TenantId,
+    ) -> Result<i64, InfraError> {
+        let count = self
+            .folders
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|f| f.parent_id() == Some(parent_id))
+            .count() as i64;
+        Ok(count)
+    }
+}
+
+// ===== FakeTransactionManager =====
+
+/// テスト用の FakeTransactionManager
+///
+/// `begin()` は常に `TxContext::mock()` を返す。
+/// Fake リポジトリはインメモリ実装のため、実際のトランザクションは不要。
+/// ロールバックを再現する Fake リポジトリは [`TxContext::on_commit`] で書き込みを遅延させる。
+pub struct FakeTransactionManager;
+
+#[async_trait]
+impl TransactionManager for FakeTransactionManager {
+    async fn begin(&self) -> Result<TxContext, InfraError> {
+        Ok(TxContext::mock())
+    }
+}
+
+// ===== FakeS3Client =====
+
+/// テスト用の FakeS3Client
+///
+/// Presigned URL はダミー文字列を返し、削除されたキーを記録する。
+#[derive(Clone, Default)]
+pub struct FakeS3Client {
+    deleted_keys: Arc<Mutex<Vec<String>>>,
+}
+
+impl FakeS3Client {
+    pub fn new() -> Self {
+        Self {
+            deleted_keys: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// 削除されたキーの一覧を取得する
+    pub fn deleted_keys(&self) -> Vec<String> {
+        self.deleted_keys.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl S3Client for FakeS3Client {
+    async fn generate_presigned_put_url(
+        &self,
+        s3_key: &str,
+        _content_type: &str,
+        _content_length: i64,
+        _expires_in: Duration,
+    ) -> Result<String, InfraError> {
+        Ok(format!("https://s3.example.com/put/{s3_key}"))
+    }
+
+    async fn generate_presigned_get_url(
+        &self,
+        s3_key: &str,
+        _expires_in: Duration,
+    ) -> Result<String, InfraError> {
+        Ok(format!("https://s3.example.com/get/{s3_key}"))
+    }
+
+    async fn head_object(&self, s3_key: &str) -> Result<bool, InfraError> {
+        Ok(!self
+            .deleted_keys
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|k| k == s3_key))
+    }
+
+    async fn delete_object(&self, s3_key: &str) -> Result<(), InfraError> {
+        self.deleted_keys.lock().unwrap().push(s3_key.to_string());
+        Ok(())
+    }
+}
+
+// ===== FakeNotificationSender =====
+
+/// テスト用のモック NotificationSender
+///
+/// 送信されたメッセージを `Arc<Mutex<Vec<EmailMessage>>>` に記録する。
+/// [`fail_with`](Self::fail_with) で設定したエラーがある間は記録せずにエラーを返す。
+#[derive(Clone, Default)]
+pub struct FakeNotificationSender {
+    sent_emails: Arc<Mutex<Vec<EmailMessage>>>,
+    failure:     Arc<Mutex<Option<NotificationError>>>,
+}
+
+impl FakeNotificationSender {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 以降の送信で返すエラーを設定する（`None` で成功に戻す）
+    pub fn fail_with(&self, error: Option<NotificationError>) {
+        *self.failure.lock().unwrap() = error;
+    }
+
+    /// 送信されたメールの一覧を取得する
+    pub fn sent_emails(&self) -> Vec<EmailMessage> {
+        self.sent_emails.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl NotificationSender for FakeNotificationSender {
+    async fn send_email(&self, email: &EmailMessage) -> Result<(), NotificationError> {
+        if let Some(error) = self.failure.lock().unwrap().clone() {
+            return Err(error);
+        }
+        self.sent_emails.lock().unwrap().push(email.clone());
+        Ok(())
+    }
+}
+
+// ===== FakeNotificationLogRepository =====
+
+/// テスト用のモック NotificationLogRepository
+///
+/// 通知ログをインメモリで管理する。ロックは行わない。
+#[derive(Clone, Default)]
+pub struct FakeNotificationLogRepository {
+    logs: Arc<Mutex<Vec<NotificationLog>>>,
+}
+
+impl FakeNotificationLogRepository {
+    pub fn new() -> Self {
+        Self {
+            logs: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// 保存されている通知ログの一覧を取得する（登録順）
+    pub fn logs(&self) -> Vec<NotificationLog> {
+        self.logs.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl NotificationLogRepository for FakeNotificationLogRepository {
+    async fn insert(&self, log: &NotificationLog) -> Result<(), InfraError> {
+        self.logs.lock().unwrap().push(log.clone());
+        Ok(())
+    }
+
+    async fn insert_all(
+        &self,
+        _tx: &mut TxContext,
+        logs: &[NotificationLog],
+    ) -> Result<(), InfraError> {
+        self.logs.lock().unwrap().extend_from_slice(logs);
+        Ok(())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &NotificationLogId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<NotificationLog>, InfraError> {
+        Ok(self
+            .logs()
+            .into_iter()
+            .find(|l| l.id() == id && l.tenant_id() == tenant_id))
+    }
+
+    async fn find_failed(
+        &self,
+        tenant_id: &TenantId,
+        limit: i64,
+    ) -> Result<Vec<NotificationLog>, InfraError> {
+        let mut result: Vec<_> = self
+            .logs()
+            .into_iter()
+            .filter(|l| l.tenant_id() == tenant_id && l.status() == NotificationLogStatus::Failed)
+            .collect();
+        result.sort_by_key(|l| std::cmp::Reverse(l.created_at()));
+        result.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(result)
+    }
+
+    async fn lock_due(
+        &self,
+        _tx: &mut TxContext,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<NotificationLog>, InfraError> {
+        let mut due: Vec<_> = self
+            .logs()
+            .into_iter()
+            .filter(|l| l.status() == NotificationLogStatus::Pending && l.next_attempt_at() <= now)
+            .collect();
+        due.sort_by_key(|l| l.next_attempt_at());
+        due.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(due)
+    }
+
+    async fn update(&self, _tx: &mut TxContext, log: &NotificationLog) -> Result<(), InfraError> {
+        let mut logs = self.logs.lock().unwrap();
+        if let Some(existing) = logs.iter_mut().find(|l| l.id() == log.id()) {
+            *existing = log.clone();
+        }
+        Ok(())
+    }
+}
+
+// ===== FakeInboxNotificationRepository =====
+
+/// テスト用の FakeInboxNotificationRepository
+///
+/// アプリ内通知をインメモリで管理する。
+#[derive(Clone, Default)]
+pub struct FakeInboxNotificationRepository {
+    notifications: Arc<Mutex<Vec<InboxNotification>>>,
+}
+
+impl FakeInboxNotificationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 保存されたアプリ内通知の一覧を取得する
+    pub fn notifications(&self) -> Vec<InboxNotification> {
+        self.notifications.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl InboxNotificationRepository for FakeInboxNotificationRepository {
+    async fn insert(&self, notification: &InboxNotification) -> Result<(), InfraError> {
+        self.notifications
+            .lock()
+            .unwrap()
+            .push(notification.clone());
+        Ok(())
+    }
+
+    async fn find_by_user(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<KeysetPage<InboxNotification>, InfraError> {
+        let notifications = self
+            .notifications
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|n| n.tenant_id() == tenant_id && n.user_id() == user_id)
+            .cloned()
+            .collect();
+        paginate_in_memory(notifications, cursor, limit, |n| {
+            KeysetCursor::new(n.created_at(), *n.id().as_uuid())
+        })
+    }
+
+    async fn count_unread(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<i64, InfraError> {
+        let count = self
+            .notifications
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|n| n.tenant_id() == tenant_id && n.user_id() == user_id && !n.is_read())
+            .count();
+        Ok(count as i64)
+    }
+
+    async fn mark_read(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        id: &InboxNotificationId,
+        now: DateTime<Utc>,
+    ) -> Result<bool, InfraError> {
+        let mut notifications = self.notifications.lock().unwrap();
+        let Some(notification) = notifications
+            .iter_mut()
+            .find(|n| n.id() == id && n.tenant_id() == tenant_id && n.user_id() == user_id)
+        else {
+            return Ok(false);
+        };
+        *notification = notification.clone().mark_read(now);
+        Ok(true)
+    }
+
+    async fn mark_all_read(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<u64, InfraError> {
+        let mut count = 0;
+        for notification in self
+            .notifications
+            .lock()
+            .unwrap()
+            .iter_mut()
+            .filter(|n| n.tenant_id() == tenant_id && n.user_id() == user_id && !n.is_read())
+        {
+            *notification = notification.clone().mark_read(now);
+            count += 1;
+        }
+        Ok(count)
+    }
+
+    async fn delete_created_before(
+        &self,
+        before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, InfraError> {
+        let mut notifications = self.notifications.lock().unwrap();
+        let mut remaining = u64::try_from(limit).unwrap_or(0);
+        let mut deleted = 0;
+        notifications.retain(|n| {
+            if n.created_at() < before && remaining > 0 {
+                remaining -= 1;
+                deleted += 1;
+                false
+            } else {
+                true
+            }
+        });
+        Ok(deleted)
+    }
+}
+
+// ===== FakeNotificationPreferenceRepository =====
+
+/// テスト用の FakeNotificationPreferenceRepository
+///
+/// 通知設定をテナント ID・ユーザー ID と組にしてインメモリで管理する。
+#[derive(Clone, Default)]
+pub struct FakeNotificationPreferenceRepository {
+    preferences: Arc<Mutex<Vec<(TenantId, UserId, NotificationPreference)>>>,
+}
+
+impl FakeNotificationPreferenceRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 通知設定を追加する（テストデータのセットアップ用）
+    pub fn set(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        event_type: NotificationEventType,
+        delivery: NotificationDelivery,
+    ) {
+        let mut preferences = self.preferences.lock().unwrap();
+        preferences
+            .retain(|(t, u, p)| !(t == tenant_id && u == user_id && p.event_type == event_type));
+        preferences.push((
+            tenant_id.clone(),
+            user_id.clone(),
+            NotificationPreference {
+                event_type,
+                delivery,
+            },
+        ));
+    }
+}
+
+#[async_trait]
+impl NotificationPreferenceRepository for FakeNotificationPreferenceRepository {
+    async fn find_by_user(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<Vec<NotificationPreference>, InfraError> {
+        Ok(self
+            .preferences
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(t, u, _)| t == tenant_id && u == user_id)
+            .map(|(_, _, p)| *p)
+            .collect())
+    }
+
+    async fn find_delivery(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        event_type: NotificationEventType,
+    ) -> Result<Option<NotificationDelivery>, InfraError> {
+        Ok(self
+            .preferences
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(t, u, p)| t == tenant_id && u == user_id && p.event_type == event_type)
+            .map(|(_, _, p)| p.delivery))
+    }
+
+    async fn upsert_all(
+        &self,
+        _tx: &mut TxContext,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        preferences: &[NotificationPreference],
+        _now: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        for preference in preferences {
+            self.set(
+                tenant_id,
+                user_id,
+                preference.event_type,
+                preference.delivery,
+            );
+        }
+        Ok(())
+    }
+}
+
+// ===== FakeNotificationTemplateRepository =====
+
+/// テスト用の FakeNotificationTemplateRepository
+///
+/// テナントが上書きした通知テンプレートをインメモリで管理する。
+#[derive(Clone, Default)]
+pub struct FakeNotificationTemplateRepository {
+    templates: Arc<Mutex<Vec<NotificationTemplate>>>,
+}
+
+impl FakeNotificationTemplateRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 保存されている通知テンプレートの一覧を取得する
+    pub fn templates(&self) -> Vec<NotificationTemplate> {
+        self.templates.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl NotificationTemplateRepository for FakeNotificationTemplateRepository {
+    async fn find_all_by_tenant(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<NotificationTemplate>, InfraError> {
+        Ok(self
+            .templates()
+            .into_iter()
+            .filter(|t| t.tenant_id() == tenant_id)
+            .collect())
+    }
+
+    async fn find_by_event_type(
+        &self,
+        tenant_id: &TenantId,
+        event_type: NotificationEventType,
+    ) -> Result<Option<NotificationTemplate>, InfraError> {
+        Ok(self
+            .templates()
+            .into_iter()
+            .find(|t| t.tenant_id() == tenant_id && t.event_type() == event_type))
+    }
+
+    async fn upsert(&self, template: &NotificationTemplate) -> Result<(), InfraError> {
+        let mut templates = self.templates.lock().unwrap();
+        templates.retain(|t| {
+            !(t.tenant_id() == template.tenant_id() && t.event_type() == template.event_type())
+        });
+        templates.push(template.clone());
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        tenant_id: &TenantId,
+        event_type: NotificationEventType,
+    ) -> Result<bool, InfraError> {
+        let mut templates = self.templates.lock().unwrap();
+        let before = templates.len();
+        templates.retain(|t| !(t.tenant_id() == tenant_id && t.event_type() == event_type));
+        Ok(templates.len() < before)
+    }
+}
+
+// ===== FakeNotificationDigestRepository =====
+
+/// テスト用の FakeNotificationDigestRepository
+///
+/// ダイジェスト待ち通知をインメモリで管理する。
+#[derive(Clone, Default)]
+pub struct FakeNotificationDigestRepository {
+    items: Arc<Mutex<Vec<NotificationDigestItem>>>,
+}
+
+impl FakeNotificationDigestRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 保存されているダイジェスト待ち通知の一覧を取得する
+    pub fn items(&self) -> Vec<NotificationDigestItem> {
+        self.items.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl NotificationDigestRepository for FakeNotificationDigestRepository {
+    async fn insert(&self, item: &NotificationDigestItem) -> Result<(), InfraError> {
+        self.items.lock().unwrap().push(item.clone());
+        Ok(())
+    }
+
+    async fn find_pending_tenants(&self) -> Result<Vec<TenantId>, InfraError> {
+        let mut items = self.items();
+        items.sort_by_key(|item| item.created_at());
+        let mut tenant_ids: Vec<TenantId> = Vec::new();
+        for item in items {
+            if !tenant_ids.contains(item.tenant_id()) {
+                tenant_ids.push(item.tenant_id().clone());
+            }
+        }
+        Ok(tenant_ids)
+    }
+
+    async fn find_pending_recipients(
+        &self,
+        tenant_id: &TenantId,
+        created_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<UserId>, InfraError> {
+        let mut items: Vec<_> = self
+            .items()
+            .into_iter()
+            .filter(|item| item.tenant_id() == tenant_id && item.created_at() <= created_before)
+            .collect();
+        items.sort_by_key(|item| item.created_at());
+        let mut recipients: Vec<UserId> = Vec::new();
+        for item in items {
+            if !recipients.contains(item.user_id()) {
+                recipients.push(item.user_id().clone());
+            }
+        }
+        recipients.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(recipients)
+    }
+
+    async fn claim_by_user(
+        &self,
+        _tx: &mut TxContext,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<NotificationDigestItem>, InfraError> {
+        let mut items = self.items.lock().unwrap();
+        let (mut claimed, rest): (Vec<_>, Vec<_>) = items.drain(..).partition(|item| {
+            item.tenant_id() == tenant_id
+                && item.user_id() == user_id
+                && item.created_at() <= created_before
+        });
+        *items = rest;
+        claimed.sort_by_key(|item| item.created_at());
+        Ok(claimed)
+    }
+}
+
+// ===== FakeWebhookSubscriptionRepository =====
+
+/// テスト用の FakeWebhookSubscriptionRepository
+///
+/// 購読をインメモリで管理する。
+#[derive(Clone, Default)]
+pub struct FakeWebhookSubscriptionRepository {
+    subscriptions: Arc<Mutex<Vec<WebhookSubscription>>>,
+}
+
+impl FakeWebhookSubscriptionRepository {
+    pub fn new() -> Self {
+        Self {
+            subscriptions: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// 保存されている購読の一覧を取得する
+    pub fn subscriptions(&self) -> Vec<WebhookSubscription> {
+        self.subscriptions.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionRepository for FakeWebhookSubscriptionRepository {
+    async fn find_all_by_tenant(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, InfraError> {
+        Ok(self
+            .subscriptions()
+            .into_iter()
+            .filter(|s| s.tenant_id() == tenant_id)
+            .collect())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<WebhookSubscription>, InfraError> {
+        Ok(self
+            .subscriptions()
+            .into_iter()
+            .find(|s| s.id() == id && s.tenant_id() == tenant_id))
+    }
+
+    async fn find_active_by_event_type(
+        &self,
+        tenant_id: &TenantId,
+        event_type: WorkflowEventType,
+    ) -> Result<Vec<WebhookSubscription>, InfraError> {
+        Ok(self
+            .subscriptions()
+            .into_iter()
+            .filter(|s| s.tenant_id() == tenant_id && s.subscribes_to(event_type))
+            .collect())
+    }
+
+    async fn insert(&self, subscription: &WebhookSubscription) -> Result<(), InfraError> {
+        self.subscriptions
             .lock()
             .unwrap()
-            .iter()
-            .filter(|f| f.parent_id() == Some(parent_id))
-            .count() as i64;
-        Ok(count)
+            .push(subscription.clone());
+        Ok(())
+    }
+
+    async fn update(&self, subscription: &WebhookSubscription) -> Result<(), InfraError> {
+        let mut subscriptions = self.subscriptions.lock().unwrap();
+        if let Some(existing) = subscriptions
+            .iter_mut()
+            .find(|s| s.id() == subscription.id())
+        {
+            *existing = subscription.clone();
+        }
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        self.subscriptions
+            .lock()
+            .unwrap()
+            .retain(|s| !(s.id() == id && s.tenant_id() == tenant_id));
+        Ok(())
     }
 }
 
-// ===== FakeTransactionManager =====
+// ===== FakeWebhookDeliveryRepository =====
 
-/// テスト用の FakeTransactionManager
+/// テスト用の FakeWebhookDeliveryRepository
 ///
-/// `begin()` は常に `TxContext::mock()` を返す。
-/// Fake リポジトリはインメモリ実装のため、実際のトランザクションは不要。
-pub struct FakeTransactionManager;
+/// 配信をインメモリで管理する。ロックは行わない。
+#[derive(Clone, Default)]
+pub struct FakeWebhookDeliveryRepository {
+    deliveries: Arc<Mutex<Vec<WebhookDelivery>>>,
+}
+
+impl FakeWebhookDeliveryRepository {
+    pub fn new() -> Self {
+        Self {
+            deliveries: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// 保存されている配信の一覧を取得する（登録順）
+    pub fn deliveries(&self) -> Vec<WebhookDelivery> {
+        self.deliveries.lock().unwrap().clone()
+    }
+}
 
 #[async_trait]
-impl TransactionManager for FakeTransactionManager {
-    async fn begin(&self) -> Result<TxContext, InfraError> {
-        Ok(TxContext::mock())
+impl WebhookDeliveryRepository for FakeWebhookDeliveryRepository {
+    async fn insert_all(&self, deliveries: &[WebhookDelivery]) -> Result<(), InfraError> {
+        let mut stored = self.deliveries.lock().unwrap();
+        for delivery in deliveries {
+            let duplicated = delivery.event_id().is_some()
+                && stored.iter().any(|d| {
+                    d.subscription_id() == delivery.subscription_id()
+                        && d.event_id() == delivery.event_id()
+                });
+            if !duplicated {
+                stored.push(delivery.clone());
+            }
+        }
+        Ok(())
+    }
+
+    async fn find_by_subscription(
+        &self,
+        subscription_id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, InfraError> {
+        let mut result: Vec<_> = self
+            .deliveries()
+            .into_iter()
+            .filter(|d| d.subscription_id() == subscription_id && d.tenant_id() == tenant_id)
+            .collect();
+        result.sort_by_key(|d| std::cmp::Reverse(d.created_at()));
+        result.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(result)
+    }
+
+    async fn claim_due(
+        &self,
+        _tx: &mut TxContext,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, InfraError> {
+        let mut deliveries = self.deliveries.lock().unwrap();
+        let mut due: Vec<_> = deliveries
+            .iter_mut()
+            .filter(|d| d.status() == WebhookDeliveryStatus::Pending && d.next_attempt_at() <= now)
+            .collect();
+        due.sort_by_key(|d| d.next_attempt_at());
+        due.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(due
+            .into_iter()
+            .map(|d| {
+                *d = d.clone().claimed(lease_until);
+                d.clone()
+            })
+            .collect())
+    }
+
+    async fn update(
+        &self,
+        _tx: &mut TxContext,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), InfraError> {
+        let mut deliveries = self.deliveries.lock().unwrap();
+        if let Some(existing) = deliveries.iter_mut().find(|d| d.id() == delivery.id()) {
+            *existing = delivery.clone();
+        }
+        Ok(())
     }
 }
 
-// ===== FakeNotificationSender =====
+// ===== FakeWorkflowScheduleRepository =====
 
-/// テスト用のモック NotificationSender
+/// テスト用の FakeWorkflowScheduleRepository
 ///
-/// 送信されたメッセージを `Arc<Mutex<Vec<EmailMessage>>>` に記録する。
+/// スケジュールをインメモリで管理する。ロックは行わない。
+/// [`fail_record_run`](Self::fail_record_run) で実行記録の失敗を再現できる。
 #[derive(Clone, Default)]
-pub struct FakeNotificationSender {
-    sent_emails: Arc<Mutex<Vec<EmailMessage>>>,
+pub struct FakeWorkflowScheduleRepository {
+    schedules:        Arc<Mutex<Vec<WorkflowSchedule>>>,
+    record_run_fails: Arc<Mutex<bool>>,
 }
 
-impl FakeNotificationSender {
+impl FakeWorkflowScheduleRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 以降の実行記録を失敗させるか設定する
+    pub fn fail_record_run(&self, fails: bool) {
+        *self.record_run_fails.lock().unwrap() = fails;
+    }
+
+    /// 保存されているスケジュールの一覧を取得する（登録順）
+    pub fn schedules(&self) -> Vec<WorkflowSchedule> {
+        self.schedules.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl WorkflowScheduleRepository for FakeWorkflowScheduleRepository {
+    async fn find_by_owner(
+        &self,
+        tenant_id: &TenantId,
+        owner_id: &UserId,
+    ) -> Result<Vec<WorkflowSchedule>, InfraError> {
+        Ok(self
+            .schedules()
+            .into_iter()
+            .filter(|s| s.tenant_id() == tenant_id && s.owner_id() == owner_id)
+            .collect())
+    }
+
+    async fn find_by_id(
+        &self,
+        id: &WorkflowScheduleId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<WorkflowSchedule>, InfraError> {
+        Ok(self
+            .schedules()
+            .into_iter()
+            .find(|s| s.id() == id && s.tenant_id() == tenant_id))
+    }
+
+    async fn insert(&self, schedule: &WorkflowSchedule) -> Result<(), InfraError> {
+        self.schedules.lock().unwrap().push(schedule.clone());
+        Ok(())
+    }
+
+    async fn update(&self, schedule: &WorkflowSchedule) -> Result<(), InfraError> {
+        let mut schedules = self.schedules.lock().unwrap();
+        if let Some(existing) = schedules.iter_mut().find(|s| s.id() == schedule.id()) {
+            *existing = schedule.clone();
+        }
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        id: &WorkflowScheduleId,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        self.schedules
+            .lock()
+            .unwrap()
+            .retain(|s| !(s.id() == id && s.tenant_id() == tenant_id));
+        Ok(())
+    }
+
+    async fn lock_due(
+        &self,
+        _tx: &mut TxContext,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WorkflowSchedule>, InfraError> {
+        let mut due: Vec<_> = self
+            .schedules()
+            .into_iter()
+            .filter(|s| s.is_due(now))
+            .collect();
+        due.sort_by_key(|s| s.next_run_at());
+        due.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(due)
+    }
+
+    async fn record_run(
+        &self,
+        _tx: &mut TxContext,
+        schedule: &WorkflowSchedule,
+    ) -> Result<(), InfraError> {
+        if *self.record_run_fails.lock().unwrap() {
+            return Err(InfraError::unexpected("実行記録の失敗"));
+        }
+        self.update(schedule).await
+    }
+}
+
+// ===== FakeWebhookSender =====
+
+/// テスト用のモック WebhookSender
+///
+/// 送信されたリクエストを記録し、設定したステータスコード
+/// （`None` の場合は接続エラー）を返す。
+/// 送信先の検証は `forbid_destination` で指定した URL のみ拒否する。
+#[derive(Clone)]
+pub struct FakeWebhookSender {
+    requests:       Arc<Mutex<Vec<WebhookRequest>>>,
+    status:         Arc<Mutex<Option<u16>>>,
+    forbidden_urls: Arc<Mutex<Vec<String>>>,
+}
+
+impl Default for FakeWebhookSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FakeWebhookSender {
+    /// 常に 200 を返す送信を作成する
     pub fn new() -> Self {
         Self {
-            sent_emails: Arc::new(Mutex::new(Vec::new())),
+            requests:       Arc::new(Mutex::new(Vec::new())),
+            status:         Arc::new(Mutex::new(Some(200))),
+            forbidden_urls: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
-    /// 送信されたメールの一覧を取得する
-    pub fn sent_emails(&self) -> Vec<EmailMessage> {
-        self.sent_emails.lock().unwrap().clone()
+    /// 送信先として拒否する URL を追加する
+    pub fn forbid_destination(&self, url: &str) {
+        self.forbidden_urls.lock().unwrap().push(url.to_string());
+    }
+
+    /// 以降の送信で返すステータスコードを設定する（`None` は接続エラー）
+    pub fn respond_with(&self, status: Option<u16>) {
+        *self.status.lock().unwrap() = status;
+    }
+
+    /// 送信されたリクエストの一覧を取得する
+    pub fn requests(&self) -> Vec<WebhookRequest> {
+        self.requests.lock().unwrap().clone()
     }
 }
 
 #[async_trait]
-impl NotificationSender for FakeNotificationSender {
-    async fn send_email(&self, email: &EmailMessage) -> Result<(), NotificationError> {
-        self.sent_emails.lock().unwrap().push(email.clone());
+impl WebhookSender for FakeWebhookSender {
+    async fn verify_destination(&self, url: &str) -> Result<(), WebhookSendError> {
+        if self.forbidden_urls.lock().unwrap().iter().any(|u| u == url) {
+            return Err(WebhookSendError::Forbidden(url.to_string()));
+        }
         Ok(())
     }
+
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, WebhookSendError> {
+        self.requests.lock().unwrap().push(request.clone());
+        self.status
+            .lock()
+            .unwrap()
+            .ok_or_else(|| WebhookSendError::Transport("connection refused".to_string()))
+    }
 }
 
-// ===== FakeNotificationLogRepository =====
+// ===== FakeRealtimePublisher =====
 
-/// テスト用のモック NotificationLogRepository
+/// テスト用のモック RealtimePublisher
 ///
-/// 挿入されたログを `Arc<Mutex<Vec<NotificationLog>>>` に記録する。
+/// 発行されたメッセージを `Arc<Mutex<Vec<RealtimeMessage>>>` に記録する。
 #[derive(Clone, Default)]
-pub struct FakeNotificationLogRepository {
-    logs: Arc<Mutex<Vec<NotificationLog>>>,
+pub struct FakeRealtimePublisher {
+    messages: Arc<Mutex<Vec<RealtimeMessage>>>,
 }
 
-impl FakeNotificationLogRepository {
+impl FakeRealtimePublisher {
     pub fn new() -> Self {
         Self {
-            logs: Arc::new(Mutex::new(Vec::new())),
+            messages: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
-    /// 挿入されたログの一覧を取得する
-    pub fn logs(&self) -> Vec<NotificationLog> {
-        self.logs.lock().unwrap().clone()
+    /// 発行されたメッセージの一覧を取得する（発行順）
+    pub fn messages(&self) -> Vec<RealtimeMessage> {
+        self.messages.lock().unwrap().clone()
     }
 }
 
 #[async_trait]
-impl NotificationLogRepository for FakeNotificationLogRepository {
-    async fn insert(&self, log: &NotificationLog) -> Result<(), InfraError> {
-        self.logs.lock().unwrap().push(log.clone());
+impl RealtimePublisher for FakeRealtimePublisher {
+    async fn publish(&self, message: &RealtimeMessage) -> Result<(), InfraError> {
+        self.messages.lock().unwrap().push(message.clone());
         Ok(())
     }
 }
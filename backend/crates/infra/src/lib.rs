@@ -29,6 +29,7 @@
 //! ## モジュール構成
 //!
 //! - [`db`] - PostgreSQL データベース接続管理
+//! - [`realtime`] - Redis Pub/Sub によるリアルタイム通知の発行・購読
 //! - [`redis`] - Redis キャッシュ接続管理
 //! - [`error`] - インフラ層エラー定義
 //! - [`repository`] - リポジトリ実装
@@ -57,10 +58,12 @@ pub mod error;
 pub mod fake;
 pub mod notification;
 pub mod password;
+pub mod realtime;
 pub mod redis;
 pub mod repository;
 pub mod s3;
 pub mod session;
+pub mod webhook;
 
 pub use db::{PgTransactionManager, TransactionManager, TxContext};
 pub use error::{InfraError, InfraErrorKind};
@@ -2,10 +2,15 @@
 //!
 //! AWS SES v2 API を使用してメールを送信する。
 //! 本番環境で使用する。
+//!
+//! メッセージの拒否・送信元ドメイン未検証・不正なリクエストは再送しても成功しないため
+//! 恒久的なエラー（[`NotificationError::PermanentSendFailure`]）とする。
+//! スロットリング・送信上限・一時停止・通信エラーは一時的なエラーとする。
 
 use async_trait::async_trait;
 use aws_sdk_sesv2::{
     Client,
+    operation::send_email::SendEmailError,
     types::{Body, Content, Destination, EmailContent, Message},
 };
 use ringiflow_domain::notification::{EmailMessage, NotificationError};
@@ -60,19 +65,23 @@ impl NotificationSender for SesNotificationSender {
                             .data(&email.subject)
                             .build()
                             .map_err(|e| {
-                                NotificationError::SendFailed(format!("件名構築失敗: {e}"))
+                                NotificationError::PermanentSendFailure(format!(
+                                    "件名構築失敗: {e}"
+                                ))
                             })?,
                     )
                     .body(
                         Body::builder()
                             .html(Content::builder().data(&email.html_body).build().map_err(
                                 |e| {
-                                    NotificationError::SendFailed(format!("HTML 本文構築失敗: {e}"))
+                                    NotificationError::PermanentSendFailure(format!(
+                                        "HTML 本文構築失敗: {e}"
+                                    ))
                                 },
                             )?)
                             .text(Content::builder().data(&email.text_body).build().map_err(
                                 |e| {
-                                    NotificationError::SendFailed(format!(
+                                    NotificationError::PermanentSendFailure(format!(
                                         "テキスト本文構築失敗: {e}"
                                     ))
                                 },
@@ -90,12 +99,30 @@ impl NotificationSender for SesNotificationSender {
             .content(content)
             .send()
             .await
-            .map_err(|e| NotificationError::SendFailed(format!("SES 送信失敗: {e}")))?;
+            .map_err(|e| {
+                let message = format!("SES 送信失敗: {e}");
+                if e.as_service_error().is_some_and(is_permanent_error) {
+                    NotificationError::PermanentSendFailure(message)
+                } else {
+                    NotificationError::SendFailed(message)
+                }
+            })?;
 
         Ok(())
     }
 }
 
+/// 再送しても成功しない SES のエラーか
+fn is_permanent_error(error: &SendEmailError) -> bool {
+    matches!(
+        error,
+        SendEmailError::MessageRejected(_)
+            | SendEmailError::MailFromDomainNotVerifiedException(_)
+            | SendEmailError::BadRequestException(_)
+            | SendEmailError::NotFoundException(_)
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,4 +132,26 @@ mod tests {
         fn assert_send_sync<T: Send + Sync>() {}
         assert_send_sync::<SesNotificationSender>();
     }
+
+    #[test]
+    fn メッセージの拒否は恒久的なエラーとして扱う() {
+        let error = SendEmailError::MessageRejected(
+            aws_sdk_sesv2::types::error::MessageRejected::builder()
+                .message("Email address is not verified.")
+                .build(),
+        );
+
+        assert!(is_permanent_error(&error));
+    }
+
+    #[test]
+    fn スロットリングは一時的なエラーとして扱う() {
+        let error = SendEmailError::TooManyRequestsException(
+            aws_sdk_sesv2::types::error::TooManyRequestsException::builder()
+                .message("Rate exceeded")
+                .build(),
+        );
+
+        assert!(!is_permanent_error(&error));
+    }
 }
@@ -2,6 +2,10 @@
 //!
 //! lettre の `AsyncSmtpTransport` を使用してメールを送信する。
 //! 開発環境では Mailpit（ローカル SMTP サーバー）に接続する。
+//!
+//! SMTP の 5xx 応答とアドレス・メッセージの構築エラーは再送しても成功しないため
+//! 恒久的なエラー（[`NotificationError::PermanentSendFailure`]）とする。
+//! 4xx 応答・接続エラー・タイムアウトは一時的なエラーとする。
 
 use async_trait::async_trait;
 use lettre::{
@@ -47,35 +51,39 @@ impl SmtpNotificationSender {
 #[async_trait]
 impl NotificationSender for SmtpNotificationSender {
     async fn send_email(&self, email: &EmailMessage) -> Result<(), NotificationError> {
-        let message =
-            Message::builder()
-                .from(self.from_address.parse().map_err(|e| {
-                    NotificationError::SendFailed(format!("送信元アドレス不正: {e}"))
-                })?)
-                .to(email
-                    .to
-                    .parse()
-                    .map_err(|e| NotificationError::SendFailed(format!("宛先アドレス不正: {e}")))?)
-                .subject(&email.subject)
-                .multipart(
-                    MultiPart::alternative()
-                        .singlepart(
-                            SinglePart::builder()
-                                .header(ContentType::TEXT_PLAIN)
-                                .body(email.text_body.clone()),
-                        )
-                        .singlepart(
-                            SinglePart::builder()
-                                .header(ContentType::TEXT_HTML)
-                                .body(email.html_body.clone()),
-                        ),
-                )
-                .map_err(|e| NotificationError::SendFailed(format!("メッセージ構築失敗: {e}")))?;
+        let message = Message::builder()
+            .from(self.from_address.parse().map_err(|e| {
+                NotificationError::PermanentSendFailure(format!("送信元アドレス不正: {e}"))
+            })?)
+            .to(email.to.parse().map_err(|e| {
+                NotificationError::PermanentSendFailure(format!("宛先アドレス不正: {e}"))
+            })?)
+            .subject(&email.subject)
+            .multipart(
+                MultiPart::alternative()
+                    .singlepart(
+                        SinglePart::builder()
+                            .header(ContentType::TEXT_PLAIN)
+                            .body(email.text_body.clone()),
+                    )
+                    .singlepart(
+                        SinglePart::builder()
+                            .header(ContentType::TEXT_HTML)
+                            .body(email.html_body.clone()),
+                    ),
+            )
+            .map_err(|e| {
+                NotificationError::PermanentSendFailure(format!("メッセージ構築失敗: {e}"))
+            })?;
 
-        self.transport
-            .send(message)
-            .await
-            .map_err(|e| NotificationError::SendFailed(format!("SMTP 送信失敗: {e}")))?;
+        self.transport.send(message).await.map_err(|e| {
+            let message = format!("SMTP 送信失敗: {e}");
+            if e.is_permanent() {
+                NotificationError::PermanentSendFailure(message)
+            } else {
+                NotificationError::SendFailed(message)
+            }
+        })?;
 
         Ok(())
     }
@@ -90,4 +98,19 @@ mod tests {
         fn assert_send_sync<T: Send + Sync>() {}
         assert_send_sync::<SmtpNotificationSender>();
     }
+
+    #[tokio::test]
+    async fn 宛先アドレスが不正な場合は恒久的なエラーになる() {
+        let sut = SmtpNotificationSender::new("localhost", 1025, "noreply@example.com".to_string());
+        let email = EmailMessage {
+            to:        "invalid-address".to_string(),
+            subject:   "件名".to_string(),
+            html_body: "<p>本文</p>".to_string(),
+            text_body: "本文".to_string(),
+        };
+
+        let result = sut.send_email(&email).await;
+
+        assert!(result.unwrap_err().is_permanent());
+    }
 }
@@ -0,0 +1,235 @@
+//! # リアルタイム通知
+//!
+//! Core Service で発生したワークフロー・タスクの更新を Redis Pub/Sub で BFF に届け、
+//! BFF が Server-Sent Events でブラウザへ配信するためのインフラストラクチャモジュール。
+//!
+//! ## 設計方針
+//!
+//! - **単一チャネル**: すべてのメッセージを [`REALTIME_CHANNEL`] に発行する。
+//!   BFF は 1 本の購読で受け取り、プロセス内で接続ごとに振り分ける
+//! - **宛先はメッセージに含める**: 受信者（テナント ID とユーザー ID の一覧）は Core Service が決める
+//! - **ベストエフォート**: Pub/Sub は購読者がいない間のメッセージを保持しない。
+//!   ブラウザは再接続時に一覧を取得し直す前提とする
+//!
+//! → 詳細設計: `docs/40_詳細設計書/16_通知機能設計.md`
+
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures_util::StreamExt;
+use redis::{AsyncCommands, Client, aio::ConnectionManager};
+use ringiflow_domain::{
+    tenant::TenantId,
+    user::UserId,
+    value_objects::DisplayNumber,
+    workflow::{WorkflowCommentId, WorkflowEventType},
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+
+use crate::error::InfraError;
+
+/// リアルタイム通知の Redis Pub/Sub チャネル名
+pub const REALTIME_CHANNEL: &str = "ringiflow:realtime";
+
+/// 購読が切断された場合の再接続までの待機時間
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// ブラウザに届けるリアルタイムイベント
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RealtimeEvent {
+    /// 承認タスクが割り当てられた
+    TaskAssigned {
+        workflow_display_number: DisplayNumber,
+        workflow_title: String,
+        step_name: String,
+    },
+    /// ワークフローの状態が変わった
+    WorkflowStatusChanged {
+        workflow_display_number: DisplayNumber,
+        workflow_title: String,
+        /// 状態遷移の種別（ワークフローイベント種別と同じ値）
+        event_type: WorkflowEventType,
+    },
+    /// コメントが投稿された
+    CommentPosted {
+        workflow_display_number: DisplayNumber,
+        workflow_title: String,
+        comment_id: WorkflowCommentId,
+        posted_by: UserId,
+    },
+}
+
+impl RealtimeEvent {
+    /// イベント名（SSE の `event` フィールドに使う。`type` と同じ値）
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TaskAssigned { .. } => "task_assigned",
+            Self::WorkflowStatusChanged { .. } => "workflow_status_changed",
+            Self::CommentPosted { .. } => "comment_posted",
+        }
+    }
+}
+
+/// Redis Pub/Sub で配信するメッセージ
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RealtimeMessage {
+    pub tenant_id:   TenantId,
+    /// 宛先のユーザー
+    pub recipients:  Vec<UserId>,
+    pub event:       RealtimeEvent,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl RealtimeMessage {
+    /// 指定したユーザー宛てのメッセージかどうか
+    pub fn is_addressed_to(&self, tenant_id: &TenantId, user_id: &UserId) -> bool {
+        &self.tenant_id == tenant_id && self.recipients.contains(user_id)
+    }
+}
+
+/// リアルタイム通知の発行トレイト
+#[async_trait]
+pub trait RealtimePublisher: Send + Sync {
+    /// メッセージを発行する
+    async fn publish(&self, message: &RealtimeMessage) -> Result<(), InfraError>;
+}
+
+/// Redis Pub/Sub によるリアルタイム通知の発行
+#[derive(Clone)]
+pub struct RedisRealtimePublisher {
+    conn: ConnectionManager,
+}
+
+impl RedisRealtimePublisher {
+    pub fn new(conn: ConnectionManager) -> Self {
+        Self { conn }
+    }
+}
+
+#[async_trait]
+impl RealtimePublisher for RedisRealtimePublisher {
+    async fn publish(&self, message: &RealtimeMessage) -> Result<(), InfraError> {
+        let payload = serde_json::to_string(message)?;
+        let mut conn = self.conn.clone();
+        let _: i64 = conn.publish(REALTIME_CHANNEL, payload).await?;
+        Ok(())
+    }
+}
+
+/// Redis Pub/Sub のリアルタイム通知を購読し、プロセス内のチャネルへ転送する
+pub struct RedisRealtimeSubscriber {
+    client: Client,
+}
+
+impl RedisRealtimeSubscriber {
+    /// 購読用のクライアントを作成する（接続は [`run`](Self::run) で行う）
+    pub fn new(redis_url: &str) -> Result<Self, InfraError> {
+        Ok(Self {
+            client: Client::open(redis_url)?,
+        })
+    }
+
+    /// 購読を開始し、受信したメッセージを `sender` に転送し続ける
+    ///
+    /// 接続が切れた場合は待機後に再接続する。
+    pub async fn run(&self, sender: broadcast::Sender<Arc<RealtimeMessage>>) {
+        loop {
+            match self.forward(&sender).await {
+                Ok(()) => tracing::warn!("リアルタイム通知の購読が切断されました。再接続します"),
+                Err(e) => {
+                    tracing::warn!(error = %e, "リアルタイム通知の購読に失敗しました。再接続します");
+                }
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    /// 1 回の接続で受信したメッセージを転送する（接続が切れると戻る）
+    async fn forward(
+        &self,
+        sender: &broadcast::Sender<Arc<RealtimeMessage>>,
+    ) -> Result<(), InfraError> {
+        let mut pubsub = self.client.get_async_pubsub().await?;
+        pubsub.subscribe(REALTIME_CHANNEL).await?;
+        tracing::info!(channel = REALTIME_CHANNEL, "リアルタイム通知の購読を開始");
+
+        let mut messages = pubsub.into_on_message();
+        while let Some(msg) = messages.next().await {
+            match serde_json::from_slice::<RealtimeMessage>(msg.get_payload_bytes()) {
+                // 接続中のブラウザがない場合は送信先がなくエラーになるが、破棄してよい
+                Ok(message) => {
+                    let _ = sender.send(Arc::new(message));
+                }
+                Err(e) => tracing::warn!(error = %e, "不正なリアルタイム通知メッセージを破棄"),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    fn message(tenant_id: &TenantId, recipients: Vec<UserId>) -> RealtimeMessage {
+        RealtimeMessage {
+            tenant_id: tenant_id.clone(),
+            recipients,
+            event: RealtimeEvent::TaskAssigned {
+                workflow_display_number: DisplayNumber::new(42).unwrap(),
+                workflow_title: "経費精算申請".to_string(),
+                step_name: "上長承認".to_string(),
+            },
+            occurred_at: DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_イベントはtypeタグ付きのjsonになる() {
+        let event = RealtimeEvent::WorkflowStatusChanged {
+            workflow_display_number: DisplayNumber::new(42).unwrap(),
+            workflow_title: "経費精算申請".to_string(),
+            event_type: WorkflowEventType::Approved,
+        };
+
+        let value = serde_json::to_value(&event).unwrap();
+
+        assert_eq!(
+            value,
+            json!({
+                "type": "workflow_status_changed",
+                "workflow_display_number": 42,
+                "workflow_title": "経費精算申請",
+                "event_type": "approved",
+            })
+        );
+        assert_eq!(event.name(), "workflow_status_changed");
+    }
+
+    #[test]
+    fn test_メッセージはjsonで往復できる() {
+        let original = message(&TenantId::new(), vec![UserId::new()]);
+
+        let payload = serde_json::to_string(&original).unwrap();
+        let restored: RealtimeMessage = serde_json::from_str(&payload).unwrap();
+
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn test_宛先のテナントとユーザーが一致する場合のみ宛先と判定する() {
+        let tenant_id = TenantId::new();
+        let recipient = UserId::new();
+        let sut = message(&tenant_id, vec![recipient.clone()]);
+
+        assert!(sut.is_addressed_to(&tenant_id, &recipient));
+        assert!(!sut.is_addressed_to(&tenant_id, &UserId::new()));
+        assert!(!sut.is_addressed_to(&TenantId::new(), &recipient));
+    }
+}
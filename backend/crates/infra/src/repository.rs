@@ -13,14 +13,29 @@ pub mod credentials_repository;
 pub mod display_id_counter_repository;
 pub mod document_repository;
 pub mod folder_repository;
+pub mod inbox_notification_repository;
+pub mod keyset_cursor;
+pub mod notification_digest_repository;
 pub mod notification_log_repository;
+pub mod notification_preference_repository;
+pub mod notification_template_repository;
 pub mod role_repository;
 pub mod tenant_repository;
 pub mod user_repository;
+pub mod webhook_delivery_repository;
+pub mod webhook_subscription_repository;
+pub mod workflow_activity_repository;
 pub mod workflow_comment_repository;
 pub mod workflow_definition_repository;
+pub mod workflow_event_outbox_repository;
+pub mod workflow_form_data_change_repository;
 pub mod workflow_instance_repository;
+pub mod workflow_proxy_grant_repository;
+pub mod workflow_schedule_repository;
+pub mod workflow_search_repository;
 pub mod workflow_step_repository;
+pub mod workflow_submission_repository;
+pub mod workflow_watcher_repository;
 
 pub use audit_log_repository::{
     AuditLogFilter,
@@ -40,14 +55,42 @@ pub use display_id_counter_repository::{
 };
 pub use document_repository::{DocumentRepository, PostgresDocumentRepository};
 pub use folder_repository::{FolderRepository, PostgresFolderRepository};
+pub use inbox_notification_repository::{
+    InboxNotificationRepository,
+    PostgresInboxNotificationRepository,
+};
+pub use keyset_cursor::{KeysetCursor, KeysetPage};
+pub use notification_digest_repository::{
+    NotificationDigestRepository,
+    PostgresNotificationDigestRepository,
+};
 pub use notification_log_repository::{
-    NotificationLog,
     NotificationLogRepository,
     PostgresNotificationLogRepository,
 };
+pub use notification_preference_repository::{
+    NotificationPreferenceRepository,
+    PostgresNotificationPreferenceRepository,
+};
+pub use notification_template_repository::{
+    NotificationTemplateRepository,
+    PostgresNotificationTemplateRepository,
+};
 pub use role_repository::{PostgresRoleRepository, RoleRepository};
 pub use tenant_repository::{PostgresTenantRepository, TenantRepository};
 pub use user_repository::{PostgresUserRepository, UserRepository};
+pub use webhook_delivery_repository::{
+    PostgresWebhookDeliveryRepository,
+    WebhookDeliveryRepository,
+};
+pub use webhook_subscription_repository::{
+    PostgresWebhookSubscriptionRepository,
+    WebhookSubscriptionRepository,
+};
+pub use workflow_activity_repository::{
+    PostgresWorkflowActivityRepository,
+    WorkflowActivityRepository,
+};
 pub use workflow_comment_repository::{
     PostgresWorkflowCommentRepository,
     WorkflowCommentRepository,
@@ -56,12 +99,43 @@ pub use workflow_definition_repository::{
     PostgresWorkflowDefinitionRepository,
     WorkflowDefinitionRepository,
 };
+pub use workflow_event_outbox_repository::{
+    PendingWorkflowEvent,
+    PostgresWorkflowEventOutboxRepository,
+    WorkflowEventOutboxRepository,
+};
+pub use workflow_form_data_change_repository::{
+    PostgresWorkflowFormDataChangeRepository,
+    WorkflowFormDataChangeRepository,
+};
 #[cfg(any(test, feature = "test-utils"))]
 pub use workflow_instance_repository::WorkflowInstanceRepositoryTestExt;
 pub use workflow_instance_repository::{
     PostgresWorkflowInstanceRepository,
     WorkflowInstanceRepository,
 };
+pub use workflow_proxy_grant_repository::{
+    PostgresWorkflowProxyGrantRepository,
+    WorkflowProxyGrantRepository,
+};
+pub use workflow_schedule_repository::{
+    PostgresWorkflowScheduleRepository,
+    WorkflowScheduleRepository,
+};
+pub use workflow_search_repository::{
+    PostgresWorkflowSearchRepository,
+    WorkflowSearchCriteria,
+    WorkflowSearchPage,
+    WorkflowSearchRepository,
+};
 #[cfg(any(test, feature = "test-utils"))]
 pub use workflow_step_repository::WorkflowStepRepositoryTestExt;
 pub use workflow_step_repository::{PostgresWorkflowStepRepository, WorkflowStepRepository};
+pub use workflow_submission_repository::{
+    PostgresWorkflowSubmissionRepository,
+    WorkflowSubmissionRepository,
+};
+pub use workflow_watcher_repository::{
+    PostgresWorkflowWatcherRepository,
+    WorkflowWatcherRepository,
+};
@@ -17,11 +17,11 @@ use ringiflow_domain::{
     folder::FolderId,
     tenant::TenantId,
     user::UserId,
-    workflow::WorkflowInstanceId,
+    workflow::{WorkflowCommentId, WorkflowInstanceId},
 };
 use sqlx::PgPool;
 
-use crate::error::InfraError;
+use crate::{db::TxContext, error::InfraError};
 
 /// ドキュメントリポジトリトレイト
 ///
@@ -61,6 +61,13 @@ pub trait DocumentRepository: Send + Sync {
         tenant_id: &TenantId,
     ) -> Result<(usize, i64), InfraError>;
 
+    /// コメントのドキュメント数と合計サイズを取得する（deleted 除外）
+    async fn count_and_total_size_by_comment(
+        &self,
+        comment_id: &WorkflowCommentId,
+        tenant_id: &TenantId,
+    ) -> Result<(usize, i64), InfraError>;
+
     /// ドキュメントをソフトデリートする（status, updated_at, deleted_at を更新）
     async fn soft_delete(
         &self,
@@ -82,6 +89,33 @@ pub trait DocumentRepository: Send + Sync {
         workflow_instance_id: &WorkflowInstanceId,
         tenant_id: &TenantId,
     ) -> Result<Vec<Document>, InfraError>;
+
+    /// 指定したコメント群の active ドキュメント一覧を取得する
+    async fn list_by_comments(
+        &self,
+        comment_ids: &[WorkflowCommentId],
+        tenant_id: &TenantId,
+    ) -> Result<Vec<Document>, InfraError>;
+
+    /// ワークフローインスタンスのドキュメントをステータスを問わず物理削除する
+    ///
+    /// インスタンスのコメントに添付されたドキュメントも対象に含める。
+    /// 削除したドキュメントの S3 キーを返す。S3 オブジェクトの削除は呼び出し側が行う。
+    async fn delete_by_workflow(
+        &self,
+        tx: &mut TxContext,
+        workflow_instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<String>, InfraError>;
+
+    /// S3 キーを参照するドキュメントが存在するかを判定する（ステータスを問わない）
+    ///
+    /// 複製で S3 オブジェクトを共有するため、削除前の参照確認に使用する。
+    async fn exists_by_s3_key(
+        &self,
+        s3_key: &str,
+        tenant_id: &TenantId,
+    ) -> Result<bool, InfraError>;
 }
 
 /// PostgreSQL 実装の DocumentRepository
@@ -101,13 +135,15 @@ impl PostgresDocumentRepository {
 ///
 /// `find_by_id` と `list_by_*` で共通の変換ロジック。
 fn row_to_document(row: &DocumentRow) -> Result<Document, InfraError> {
-    let upload_context = match (row.folder_id, row.workflow_instance_id) {
-        (Some(fid), None) => UploadContext::Folder(FolderId::from_uuid(fid)),
-        (None, Some(wid)) => UploadContext::Workflow(WorkflowInstanceId::from_uuid(wid)),
-        // CHECK 制約が XOR を保証するため到達しない
+    let upload_context = match (row.folder_id, row.workflow_instance_id, row.comment_id) {
+        (Some(fid), None, None) => UploadContext::Folder(FolderId::from_uuid(fid)),
+        (None, Some(wid), None) => UploadContext::Workflow(WorkflowInstanceId::from_uuid(wid)),
+        (None, None, Some(cid)) => UploadContext::Comment(WorkflowCommentId::from_uuid(cid)),
+        // CHECK 制約がいずれか 1 つのみであることを保証するため到達しない
         _ => {
             return Err(InfraError::unexpected(
-                "documents テーブルの folder_id/workflow_instance_id が不正な状態です".to_string(),
+                "documents テーブルの folder_id/workflow_instance_id/comment_id が不正な状態です"
+                    .to_string(),
             ));
         }
     };
@@ -146,6 +182,7 @@ struct DocumentRow {
     s3_key: String,
     folder_id: Option<uuid::Uuid>,
     workflow_instance_id: Option<uuid::Uuid>,
+    comment_id: Option<uuid::Uuid>,
     status: String,
     uploaded_by: Option<uuid::Uuid>,
     created_at: DateTime<Utc>,
@@ -172,6 +209,7 @@ impl DocumentRepository for PostgresDocumentRepository {
                 s3_key as "s3_key!",
                 folder_id,
                 workflow_instance_id,
+                comment_id,
                 status as "status!",
                 uploaded_by,
                 created_at as "created_at!",
@@ -199,6 +237,7 @@ impl DocumentRepository for PostgresDocumentRepository {
             s3_key: row.s3_key,
             folder_id: row.folder_id,
             workflow_instance_id: row.workflow_instance_id,
+            comment_id: row.comment_id,
             status: row.status,
             uploaded_by: row.uploaded_by,
             created_at: row.created_at,
@@ -215,10 +254,10 @@ impl DocumentRepository for PostgresDocumentRepository {
             r#"
             INSERT INTO documents (
                 id, tenant_id, filename, content_type, size, s3_key,
-                folder_id, workflow_instance_id, status, uploaded_by,
+                folder_id, workflow_instance_id, comment_id, status, uploaded_by,
                 created_at, updated_at, deleted_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
             "#,
             document.id().as_uuid(),
             document.tenant_id().as_uuid(),
@@ -234,6 +273,10 @@ impl DocumentRepository for PostgresDocumentRepository {
                 .upload_context()
                 .workflow_instance_id()
                 .map(|id| *id.as_uuid()),
+            document
+                .upload_context()
+                .comment_id()
+                .map(|id| *id.as_uuid()),
             <DocumentStatus as Into<&str>>::into(document.status()),
             document.uploaded_by().map(|id| *id.as_uuid()),
             document.created_at(),
@@ -317,6 +360,29 @@ impl DocumentRepository for PostgresDocumentRepository {
         Ok((row.count as usize, row.total_size))
     }
 
+    #[tracing::instrument(skip_all, level = "debug", fields(%comment_id, %tenant_id))]
+    async fn count_and_total_size_by_comment(
+        &self,
+        comment_id: &WorkflowCommentId,
+        tenant_id: &TenantId,
+    ) -> Result<(usize, i64), InfraError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                COUNT(*)::bigint as "count!",
+                COALESCE(SUM(size), 0)::bigint as "total_size!"
+            FROM documents
+            WHERE comment_id = $1 AND tenant_id = $2 AND status != 'deleted'
+            "#,
+            comment_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok((row.count as usize, row.total_size))
+    }
+
     #[tracing::instrument(skip_all, level = "debug", fields(%id, %tenant_id))]
     async fn soft_delete(
         &self,
@@ -358,6 +424,7 @@ impl DocumentRepository for PostgresDocumentRepository {
                 s3_key as "s3_key!",
                 folder_id,
                 workflow_instance_id,
+                comment_id,
                 status as "status!",
                 uploaded_by,
                 created_at as "created_at!",
@@ -384,6 +451,7 @@ impl DocumentRepository for PostgresDocumentRepository {
                     s3_key: row.s3_key.clone(),
                     folder_id: row.folder_id,
                     workflow_instance_id: row.workflow_instance_id,
+                    comment_id: row.comment_id,
                     status: row.status.clone(),
                     uploaded_by: row.uploaded_by,
                     created_at: row.created_at,
@@ -412,6 +480,7 @@ impl DocumentRepository for PostgresDocumentRepository {
                 s3_key as "s3_key!",
                 folder_id,
                 workflow_instance_id,
+                comment_id,
                 status as "status!",
                 uploaded_by,
                 created_at as "created_at!",
@@ -438,6 +507,7 @@ impl DocumentRepository for PostgresDocumentRepository {
                     s3_key: row.s3_key.clone(),
                     folder_id: row.folder_id,
                     workflow_instance_id: row.workflow_instance_id,
+                    comment_id: row.comment_id,
                     status: row.status.clone(),
                     uploaded_by: row.uploaded_by,
                     created_at: row.created_at,
@@ -448,6 +518,117 @@ impl DocumentRepository for PostgresDocumentRepository {
             })
             .collect()
     }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn list_by_comments(
+        &self,
+        comment_ids: &[WorkflowCommentId],
+        tenant_id: &TenantId,
+    ) -> Result<Vec<Document>, InfraError> {
+        if comment_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let comment_ids: Vec<uuid::Uuid> = comment_ids.iter().map(|id| *id.as_uuid()).collect();
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                id,
+                tenant_id as "tenant_id!",
+                filename as "filename!",
+                content_type as "content_type!",
+                size as "size!",
+                s3_key as "s3_key!",
+                folder_id,
+                workflow_instance_id,
+                comment_id,
+                status as "status!",
+                uploaded_by,
+                created_at as "created_at!",
+                updated_at as "updated_at!",
+                deleted_at
+            FROM documents
+            WHERE comment_id = ANY($1) AND tenant_id = $2 AND status = 'active'
+            ORDER BY created_at DESC, id ASC
+            "#,
+            &comment_ids,
+            tenant_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.iter()
+            .map(|row| {
+                let doc_row = DocumentRow {
+                    id: row.id,
+                    tenant_id: row.tenant_id,
+                    filename: row.filename.clone(),
+                    content_type: row.content_type.clone(),
+                    size: row.size,
+                    s3_key: row.s3_key.clone(),
+                    folder_id: row.folder_id,
+                    workflow_instance_id: row.workflow_instance_id,
+                    comment_id: row.comment_id,
+                    status: row.status.clone(),
+                    uploaded_by: row.uploaded_by,
+                    created_at: row.created_at,
+                    updated_at: row.updated_at,
+                    deleted_at: row.deleted_at,
+                };
+                row_to_document(&doc_row)
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%workflow_instance_id, %tenant_id))]
+    async fn delete_by_workflow(
+        &self,
+        tx: &mut TxContext,
+        workflow_instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<String>, InfraError> {
+        let rows = sqlx::query!(
+            r#"
+            DELETE FROM documents
+            WHERE tenant_id = $2
+              AND (
+                workflow_instance_id = $1
+                OR comment_id IN (
+                    SELECT id FROM workflow_comments
+                    WHERE instance_id = $1 AND tenant_id = $2
+                )
+              )
+            RETURNING s3_key
+            "#,
+            workflow_instance_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_all(tx.conn())
+        .await?;
+
+        Ok(rows.into_iter().map(|row| row.s3_key).collect())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn exists_by_s3_key(
+        &self,
+        s3_key: &str,
+        tenant_id: &TenantId,
+    ) -> Result<bool, InfraError> {
+        let row = sqlx::query!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM documents WHERE s3_key = $1 AND tenant_id = $2
+            ) as "exists!"
+            "#,
+            s3_key,
+            tenant_id.as_uuid()
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(row.exists)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,317 @@
+//! # InboxNotificationRepository
+//!
+//! アプリ内通知（通知受信箱）の永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **ユーザー単位のアクセス**: 参照・既読化は常にテナント ID とユーザー ID で絞り込む
+//! - **キーセットページネーション**: 作成日時の降順（同時刻は ID の降順）で取得する
+//! - **保持期間**: 期限切れの通知はテナントを横断してバッチ削除する
+//!
+//! → 詳細設計: `docs/40_詳細設計書/16_通知機能設計.md`
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    notification::{
+        InboxNotification,
+        InboxNotificationId,
+        InboxNotificationRecord,
+        NotificationEventType,
+    },
+    tenant::TenantId,
+    user::UserId,
+    workflow::WorkflowInstanceId,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::keyset_cursor::{KeysetCursor, KeysetPage, paginate};
+use crate::error::InfraError;
+
+/// アプリ内通知リポジトリトレイト
+#[async_trait]
+pub trait InboxNotificationRepository: Send + Sync {
+    /// アプリ内通知を挿入する
+    async fn insert(&self, notification: &InboxNotification) -> Result<(), InfraError>;
+
+    /// ユーザーのアプリ内通知を 1 ページ分取得
+    ///
+    /// 作成日時の降順（同時刻は ID の降順）で並べ、キーセットページネーションで取得する。
+    ///
+    /// # 戻り値
+    ///
+    /// - `Ok(KeysetPage<InboxNotification>)`: 通知一覧と次ページのカーソル
+    /// - `Err(InvalidInput)`: カーソルが不正な場合
+    /// - `Err(_)`: データベースエラー
+    async fn find_by_user(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<KeysetPage<InboxNotification>, InfraError>;
+
+    /// ユーザーの未読通知数を取得
+    async fn count_unread(&self, tenant_id: &TenantId, user_id: &UserId)
+    -> Result<i64, InfraError>;
+
+    /// 通知を既読にする
+    ///
+    /// 既読済みの通知は既読日時を更新しない。
+    ///
+    /// # 戻り値
+    ///
+    /// - `Ok(true)`: ユーザーの通知が存在する（既読済みを含む）
+    /// - `Ok(false)`: 通知が存在しない、または他ユーザーの通知
+    async fn mark_read(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        id: &InboxNotificationId,
+        now: DateTime<Utc>,
+    ) -> Result<bool, InfraError>;
+
+    /// ユーザーの未読通知をすべて既読にする
+    ///
+    /// # 戻り値
+    ///
+    /// 既読にした通知の件数
+    async fn mark_all_read(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<u64, InfraError>;
+
+    /// 指定日時より前に作成された通知を削除する（テナント横断）
+    ///
+    /// 保持期間切れの通知の削除に使用する。1 回の呼び出しで最大 `limit` 件を削除する。
+    ///
+    /// # 戻り値
+    ///
+    /// 削除した通知の件数
+    async fn delete_created_before(
+        &self,
+        before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, InfraError>;
+}
+
+/// DB の inbox_notifications テーブルの行を表す中間構造体
+struct InboxNotificationRow {
+    id: Uuid,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    event_type: String,
+    workflow_instance_id: Uuid,
+    workflow_title: String,
+    workflow_display_id: String,
+    message: String,
+    read_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<InboxNotificationRow> for InboxNotification {
+    type Error = InfraError;
+
+    fn try_from(row: InboxNotificationRow) -> Result<Self, Self::Error> {
+        Ok(InboxNotification::from_db(InboxNotificationRecord {
+            id: InboxNotificationId::from_uuid(row.id),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            user_id: UserId::from_uuid(row.user_id),
+            event_type: row
+                .event_type
+                .parse::<NotificationEventType>()
+                .map_err(|e| InfraError::unexpected(format!("不正な通知イベント種別: {}", e)))?,
+            workflow_instance_id: WorkflowInstanceId::from_uuid(row.workflow_instance_id),
+            workflow_title: row.workflow_title,
+            workflow_display_id: row.workflow_display_id,
+            message: row.message,
+            read_at: row.read_at,
+            created_at: row.created_at,
+        }))
+    }
+}
+
+/// PostgreSQL 実装の InboxNotificationRepository
+#[derive(Debug, Clone)]
+pub struct PostgresInboxNotificationRepository {
+    pool: PgPool,
+}
+
+impl PostgresInboxNotificationRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InboxNotificationRepository for PostgresInboxNotificationRepository {
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn insert(&self, notification: &InboxNotification) -> Result<(), InfraError> {
+        let event_type: &str = notification.event_type().into();
+        sqlx::query!(
+            r#"
+            INSERT INTO inbox_notifications (
+                id, tenant_id, user_id, event_type, workflow_instance_id,
+                workflow_title, workflow_display_id, message, read_at, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            notification.id().as_uuid(),
+            notification.tenant_id().as_uuid(),
+            notification.user_id().as_uuid(),
+            event_type,
+            notification.workflow_instance_id().as_uuid(),
+            notification.workflow_title(),
+            notification.workflow_display_id(),
+            notification.message(),
+            notification.read_at(),
+            notification.created_at(),
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn find_by_user(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<KeysetPage<InboxNotification>, InfraError> {
+        let cursor = cursor.map(KeysetCursor::decode).transpose()?;
+
+        // 次ページの有無を判定するため 1 件多く取得する
+        let rows = sqlx::query_as!(
+            InboxNotificationRow,
+            r#"
+            SELECT
+                id, tenant_id, user_id, event_type, workflow_instance_id,
+                workflow_title, workflow_display_id, message, read_at, created_at
+            FROM inbox_notifications
+            WHERE tenant_id = $1 AND user_id = $2
+              AND ($3::timestamptz IS NULL OR (created_at, id) < ($3, $4::uuid))
+            ORDER BY created_at DESC, id DESC
+            LIMIT $5
+            "#,
+            tenant_id.as_uuid(),
+            user_id.as_uuid(),
+            cursor.as_ref().map(|c| c.created_at),
+            cursor.as_ref().map(|c| c.id),
+            limit + 1
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let (rows, next_cursor) =
+            paginate(rows, limit, |row| KeysetCursor::new(row.created_at, row.id));
+
+        Ok(KeysetPage {
+            items: rows
+                .into_iter()
+                .map(InboxNotification::try_from)
+                .collect::<Result<_, _>>()?,
+            next_cursor,
+        })
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn count_unread(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<i64, InfraError> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM inbox_notifications
+            WHERE tenant_id = $1 AND user_id = $2 AND read_at IS NULL
+            "#,
+            tenant_id.as_uuid(),
+            user_id.as_uuid(),
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id, %id))]
+    async fn mark_read(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        id: &InboxNotificationId,
+        now: DateTime<Utc>,
+    ) -> Result<bool, InfraError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE inbox_notifications
+            SET read_at = COALESCE(read_at, $4)
+            WHERE id = $1 AND tenant_id = $2 AND user_id = $3
+            "#,
+            id.as_uuid(),
+            tenant_id.as_uuid(),
+            user_id.as_uuid(),
+            now,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn mark_all_read(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<u64, InfraError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE inbox_notifications
+            SET read_at = $3
+            WHERE tenant_id = $1 AND user_id = $2 AND read_at IS NULL
+            "#,
+            tenant_id.as_uuid(),
+            user_id.as_uuid(),
+            now,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn delete_created_before(
+        &self,
+        before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, InfraError> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM inbox_notifications
+            WHERE id IN (
+                SELECT id FROM inbox_notifications
+                WHERE created_at < $1
+                ORDER BY created_at
+                LIMIT $2
+            )
+            "#,
+            before,
+            limit,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,124 @@
+//! # キーセットページネーション用カーソル
+//!
+//! `(作成日時, ID)` の降順で並べた一覧を、前ページ末尾のキーを起点に取得するためのカーソル。
+//! OFFSET 方式と異なり、ページ送り中に行が追加・削除されても重複や欠落が起きない。
+//!
+//! API には opaque な文字列（JSON を base64 エンコードしたもの）として渡す。
+//! クライアントはカーソルの中身を解釈してはならない。
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD as BASE64};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::InfraError;
+
+/// キーセットカーソル
+///
+/// 前ページの最後の行のソートキー。次ページはこのキーより「後ろ」
+/// （`(created_at, id) < (カーソルの値)`）の行から始まる。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeysetCursor {
+    pub created_at: DateTime<Utc>,
+    pub id:         Uuid,
+}
+
+impl KeysetCursor {
+    pub fn new(created_at: DateTime<Utc>, id: Uuid) -> Self {
+        Self { created_at, id }
+    }
+
+    /// opaque なカーソル文字列にエンコードする
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("KeysetCursor のシリアライズは失敗しない");
+        BASE64.encode(json)
+    }
+
+    /// カーソル文字列をデコードする
+    ///
+    /// ## エラー
+    ///
+    /// - `InvalidInput`: base64 または JSON として不正な場合
+    pub fn decode(cursor: &str) -> Result<Self, InfraError> {
+        let decoded = BASE64
+            .decode(cursor)
+            .map_err(|e| InfraError::invalid_input(format!("カーソルのデコードに失敗: {e}")))?;
+        serde_json::from_slice(&decoded)
+            .map_err(|e| InfraError::invalid_input(format!("カーソルのデシリアライズに失敗: {e}")))
+    }
+}
+
+/// キーセットページネーションの 1 ページ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeysetPage<T> {
+    /// ページ内の要素（`(created_at, id)` の降順）
+    pub items:       Vec<T>,
+    /// 次ページのカーソル（最後のページの場合は `None`）
+    pub next_cursor: Option<String>,
+}
+
+/// `limit + 1` 件取得した行を 1 ページ分に切り詰め、次ページのカーソルを作る
+///
+/// 次ページの有無を判定するため、呼び出し側は `LIMIT limit + 1` で取得しておく。
+/// カーソルはページ内の最後の行のキーから作る。
+pub(crate) fn paginate<R>(
+    mut rows: Vec<R>,
+    limit: i64,
+    key: impl Fn(&R) -> KeysetCursor,
+) -> (Vec<R>, Option<String>) {
+    let limit = usize::try_from(limit).unwrap_or(0);
+    if rows.len() <= limit {
+        return (rows, None);
+    }
+    rows.truncate(limit);
+    let next_cursor = rows.last().map(|row| key(row).encode());
+    (rows, next_cursor)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::error::InfraErrorKind;
+
+    #[test]
+    fn test_エンコードしたカーソルをデコードすると元に戻る() {
+        let cursor = KeysetCursor::new(
+            DateTime::parse_from_rfc3339("2026-01-15T10:00:00.123456Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            Uuid::now_v7(),
+        );
+
+        let decoded = KeysetCursor::decode(&cursor.encode()).unwrap();
+
+        assert_eq!(decoded, cursor);
+    }
+
+    #[test]
+    fn test_limitを超えた行がある場合だけ次ページのカーソルを返す() {
+        let now = Utc::now();
+        let rows: Vec<Uuid> = (0..3).map(|_| Uuid::now_v7()).collect();
+        let key = |id: &Uuid| KeysetCursor::new(now, *id);
+
+        let (page, next_cursor) = paginate(rows.clone(), 2, key);
+        assert_eq!(page, rows[..2]);
+        assert_eq!(next_cursor, Some(KeysetCursor::new(now, rows[1]).encode()));
+
+        let (page, next_cursor) = paginate(rows.clone(), 3, key);
+        assert_eq!(page, rows);
+        assert_eq!(next_cursor, None);
+    }
+
+    #[test]
+    fn test_不正なカーソルはinvalid_inputになる() {
+        for invalid in ["!!!", "bm90LWpzb24"] {
+            let err = KeysetCursor::decode(invalid).unwrap_err();
+            assert!(
+                matches!(err.kind(), InfraErrorKind::InvalidInput(_)),
+                "{invalid}: {err:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! # NotificationDigestRepository
+//!
+//! ダイジェストメールで送信するまで保留している通知の永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **テナント横断の送信対象取得**: ダイジェストワーカーは送信時刻までに保留された通知がある
+//!   受信者を全テナントから取得する
+//! - **受信者単位の取り出し**: 1 通のダイジェストにまとめる通知を `FOR UPDATE SKIP LOCKED` で
+//!   ロックして削除し、古い順に返す。削除は送信結果の記録と同じトランザクションで行い、
+//!   複数のワーカーが同じ通知を送信しないようにする
+//!
+//! → 詳細設計: `docs/40_詳細設計書/16_通知機能設計.md`
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    notification::{
+        NotificationDigestItem,
+        NotificationDigestItemId,
+        NotificationDigestItemRecord,
+        NotificationEventType,
+    },
+    tenant::TenantId,
+    user::UserId,
+    workflow::WorkflowInstanceId,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{db::TxContext, error::InfraError};
+
+/// ダイジェスト待ち通知リポジトリトレイト
+#[async_trait]
+pub trait NotificationDigestRepository: Send + Sync {
+    /// ダイジェスト待ち通知を挿入する
+    async fn insert(&self, item: &NotificationDigestItem) -> Result<(), InfraError>;
+
+    /// ダイジェスト待ち通知があるテナントを取得する（テナント横断）
+    ///
+    /// 送信時刻はテナントのタイムゾーンで決まるため、送信対象の受信者はテナントごとに取得する。
+    /// 最も古い通知の日時が早い順に返す。
+    async fn find_pending_tenants(&self) -> Result<Vec<TenantId>, InfraError>;
+
+    /// テナント内でダイジェスト待ち通知がある受信者を取得する
+    ///
+    /// `created_before` 以前に保留された通知がある受信者を、最も古い通知の日時が早い順に
+    /// 最大 `limit` 人返す。
+    async fn find_pending_recipients(
+        &self,
+        tenant_id: &TenantId,
+        created_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<UserId>, InfraError>;
+
+    /// 受信者のダイジェスト待ち通知を取り出す（古い順）
+    ///
+    /// `created_before` 以前に保留された通知をロックして削除し、削除した通知を返す。
+    /// 他のトランザクションがロック中の通知はスキップする。
+    async fn claim_by_user(
+        &self,
+        tx: &mut TxContext,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<NotificationDigestItem>, InfraError>;
+}
+
+/// DB の notification_digest_items テーブルの行を表す中間構造体
+struct NotificationDigestItemRow {
+    id: Uuid,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    recipient_email: String,
+    event_type: String,
+    workflow_instance_id: Uuid,
+    workflow_title: String,
+    workflow_display_id: String,
+    message: String,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<NotificationDigestItemRow> for NotificationDigestItem {
+    type Error = InfraError;
+
+    fn try_from(row: NotificationDigestItemRow) -> Result<Self, Self::Error> {
+        Ok(NotificationDigestItem::from_db(
+            NotificationDigestItemRecord {
+                id: NotificationDigestItemId::from_uuid(row.id),
+                tenant_id: TenantId::from_uuid(row.tenant_id),
+                user_id: UserId::from_uuid(row.user_id),
+                recipient_email: row.recipient_email,
+                event_type: row
+                    .event_type
+                    .parse::<NotificationEventType>()
+                    .map_err(|e| {
+                        InfraError::unexpected(format!("不正な通知イベント種別: {}", e))
+                    })?,
+                workflow_instance_id: WorkflowInstanceId::from_uuid(row.workflow_instance_id),
+                workflow_title: row.workflow_title,
+                workflow_display_id: row.workflow_display_id,
+                message: row.message,
+                created_at: row.created_at,
+            },
+        ))
+    }
+}
+
+/// PostgreSQL 実装の NotificationDigestRepository
+#[derive(Debug, Clone)]
+pub struct PostgresNotificationDigestRepository {
+    pool: PgPool,
+}
+
+impl PostgresNotificationDigestRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationDigestRepository for PostgresNotificationDigestRepository {
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn insert(&self, item: &NotificationDigestItem) -> Result<(), InfraError> {
+        let event_type: &str = item.event_type().into();
+        sqlx::query!(
+            r#"
+            INSERT INTO notification_digest_items (
+                id, tenant_id, user_id, recipient_email, event_type, workflow_instance_id,
+                workflow_title, workflow_display_id, message, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            item.id().as_uuid(),
+            item.tenant_id().as_uuid(),
+            item.user_id().as_uuid(),
+            item.recipient_email(),
+            event_type,
+            item.workflow_instance_id().as_uuid(),
+            item.workflow_title(),
+            item.workflow_display_id(),
+            item.message(),
+            item.created_at(),
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn find_pending_tenants(&self) -> Result<Vec<TenantId>, InfraError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT tenant_id
+            FROM notification_digest_items
+            GROUP BY tenant_id
+            ORDER BY MIN(created_at)
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TenantId::from_uuid(row.tenant_id))
+            .collect())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn find_pending_recipients(
+        &self,
+        tenant_id: &TenantId,
+        created_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<UserId>, InfraError> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT user_id
+            FROM notification_digest_items
+            WHERE tenant_id = $1 AND created_at <= $2
+            GROUP BY user_id
+            ORDER BY MIN(created_at)
+            LIMIT $3
+            "#,
+            tenant_id.as_uuid(),
+            created_before,
+            limit,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| UserId::from_uuid(row.user_id))
+            .collect())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn claim_by_user(
+        &self,
+        tx: &mut TxContext,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<NotificationDigestItem>, InfraError> {
+        let mut rows = sqlx::query_as!(
+            NotificationDigestItemRow,
+            r#"
+            DELETE FROM notification_digest_items
+            WHERE id IN (
+                SELECT id
+                FROM notification_digest_items
+                WHERE tenant_id = $1 AND user_id = $2 AND created_at <= $3
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING
+                id, tenant_id, user_id, recipient_email, event_type, workflow_instance_id,
+                workflow_title, workflow_display_id, message, created_at
+            "#,
+            tenant_id.as_uuid(),
+            user_id.as_uuid(),
+            created_before,
+        )
+        .fetch_all(tx.conn())
+        .await?;
+
+        // DELETE ... RETURNING は順序を保証しないため、保留順に並べ直す
+        rows.sort_by_key(|row| (row.created_at, row.id));
+        rows.into_iter()
+            .map(NotificationDigestItem::try_from)
+            .collect()
+    }
+}
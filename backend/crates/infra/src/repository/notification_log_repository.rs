@@ -1,48 +1,131 @@
 //! # NotificationLogRepository
 //!
-//! 通知ログの永続化を担当するリポジトリ。
+//! 通知ログ（送信ログ兼リトライキュー）の永続化を担当するリポジトリ。
 //!
 //! ## 設計方針
 //!
-//! - **fire-and-forget ログ**: 送信成功・失敗どちらも記録する
+//! - **送信ログ兼リトライキュー**: 送信成功・失敗どちらも記録し、送信待ちの通知は再送ワーカーが取り出す
 //! - **テナント分離**: RLS + tenant_id で分離
+//! - **テナント横断の取り出し**: 再送ワーカーはシステム処理として全テナントの送信待ちを扱う
+//! - **排他制御**: `FOR UPDATE SKIP LOCKED` で行ロックを取得し、複数のワーカーが同じ通知を
+//!   同時に送信しないようにする。ロックは送信結果の記録と同じトランザクションで保持する
 //!
 //! → 詳細設計: `docs/40_詳細設計書/16_通知機能設計.md`
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use ringiflow_domain::{
-    notification::NotificationLogId,
+    notification::{
+        NotificationEventType,
+        NotificationLog,
+        NotificationLogId,
+        NotificationLogRecord,
+        NotificationLogStatus,
+    },
     tenant::TenantId,
     user::UserId,
     workflow::WorkflowInstanceId,
 };
 use sqlx::PgPool;
+use uuid::Uuid;
 
-use crate::error::InfraError;
-
-/// 通知ログ（リポジトリ INSERT 用データ型）
-#[derive(Debug, Clone)]
-pub struct NotificationLog {
-    pub id: NotificationLogId,
-    pub tenant_id: TenantId,
-    pub event_type: String,
-    pub workflow_instance_id: WorkflowInstanceId,
-    pub workflow_title: String,
-    pub workflow_display_id: String,
-    pub recipient_user_id: UserId,
-    pub recipient_email: String,
-    pub subject: String,
-    pub status: String,
-    pub error_message: Option<String>,
-    pub sent_at: DateTime<Utc>,
-}
+use crate::{db::TxContext, error::InfraError};
 
 /// 通知ログリポジトリトレイト
 #[async_trait]
 pub trait NotificationLogRepository: Send + Sync {
     /// 通知ログを挿入する
     async fn insert(&self, log: &NotificationLog) -> Result<(), InfraError>;
+
+    /// 通知ログをトランザクション内で一括挿入する
+    ///
+    /// ダイジェストのように、送信対象の削除と同じトランザクションで記録する場合に使う。
+    async fn insert_all(
+        &self,
+        tx: &mut TxContext,
+        logs: &[NotificationLog],
+    ) -> Result<(), InfraError>;
+
+    /// ID で通知ログを取得する
+    async fn find_by_id(
+        &self,
+        id: &NotificationLogId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<NotificationLog>, InfraError>;
+
+    /// テナントの送信に失敗した通知ログを新しい順で取得する
+    async fn find_failed(
+        &self,
+        tenant_id: &TenantId,
+        limit: i64,
+    ) -> Result<Vec<NotificationLog>, InfraError>;
+
+    /// 送信時刻に達した通知ログをロックして取得する（next_attempt_at ASC）
+    ///
+    /// `status = 'pending'` かつ `next_attempt_at` が `now` 以前の通知ログを最大 `limit` 件返す。
+    /// 他のトランザクションがロック中の通知ログはスキップする。
+    async fn lock_due(
+        &self,
+        tx: &mut TxContext,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<NotificationLog>, InfraError>;
+
+    /// 送信結果・再送指示を反映する
+    async fn update(&self, tx: &mut TxContext, log: &NotificationLog) -> Result<(), InfraError>;
+}
+
+/// DB の notification_logs テーブルの行を表す中間構造体
+struct NotificationLogRow {
+    id: Uuid,
+    tenant_id: Uuid,
+    event_type: String,
+    workflow_instance_id: Uuid,
+    workflow_title: String,
+    workflow_display_id: String,
+    recipient_user_id: Uuid,
+    recipient_email: String,
+    subject: String,
+    html_body: Option<String>,
+    text_body: Option<String>,
+    status: String,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    error_message: Option<String>,
+    created_at: DateTime<Utc>,
+    sent_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<NotificationLogRow> for NotificationLog {
+    type Error = InfraError;
+
+    fn try_from(row: NotificationLogRow) -> Result<Self, Self::Error> {
+        Ok(NotificationLog::from_db(NotificationLogRecord {
+            id: NotificationLogId::from_uuid(row.id),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            event_type: row
+                .event_type
+                .parse::<NotificationEventType>()
+                .map_err(|e| InfraError::unexpected(format!("不正な通知イベント種別: {}", e)))?,
+            workflow_instance_id: WorkflowInstanceId::from_uuid(row.workflow_instance_id),
+            workflow_title: row.workflow_title,
+            workflow_display_id: row.workflow_display_id,
+            recipient_user_id: UserId::from_uuid(row.recipient_user_id),
+            recipient_email: row.recipient_email,
+            subject: row.subject,
+            html_body: row.html_body,
+            text_body: row.text_body,
+            status: row
+                .status
+                .parse::<NotificationLogStatus>()
+                .map_err(|e| InfraError::unexpected(format!("不正な通知ログステータス: {}", e)))?,
+            attempts: row.attempts,
+            next_attempt_at: row.next_attempt_at,
+            error_message: row.error_message,
+            created_at: row.created_at,
+            sent_at: row.sent_at,
+        }))
+    }
 }
 
 /// PostgreSQL 実装の NotificationLogRepository
@@ -62,36 +145,178 @@ impl PostgresNotificationLogRepository {
 impl NotificationLogRepository for PostgresNotificationLogRepository {
     #[tracing::instrument(skip_all, level = "debug")]
     async fn insert(&self, log: &NotificationLog) -> Result<(), InfraError> {
-        sqlx::query!(
+        insert_log(&self.pool, log).await
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(count = logs.len()))]
+    async fn insert_all(
+        &self,
+        tx: &mut TxContext,
+        logs: &[NotificationLog],
+    ) -> Result<(), InfraError> {
+        for log in logs {
+            insert_log(tx.conn(), log).await?;
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id, %tenant_id))]
+    async fn find_by_id(
+        &self,
+        id: &NotificationLogId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<NotificationLog>, InfraError> {
+        let row = sqlx::query_as!(
+            NotificationLogRow,
+            r#"
+            SELECT
+                id, tenant_id, event_type, workflow_instance_id,
+                workflow_title, workflow_display_id,
+                recipient_user_id, recipient_email,
+                subject, html_body, text_body,
+                status, attempts, next_attempt_at, error_message,
+                created_at, sent_at
+            FROM notification_logs
+            WHERE id = $1 AND tenant_id = $2
+            "#,
+            id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(NotificationLog::try_from).transpose()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn find_failed(
+        &self,
+        tenant_id: &TenantId,
+        limit: i64,
+    ) -> Result<Vec<NotificationLog>, InfraError> {
+        let rows = sqlx::query_as!(
+            NotificationLogRow,
+            r#"
+            SELECT
+                id, tenant_id, event_type, workflow_instance_id,
+                workflow_title, workflow_display_id,
+                recipient_user_id, recipient_email,
+                subject, html_body, text_body,
+                status, attempts, next_attempt_at, error_message,
+                created_at, sent_at
+            FROM notification_logs
+            WHERE tenant_id = $1 AND status = 'failed'
+            ORDER BY created_at DESC, id DESC
+            LIMIT $2
+            "#,
+            tenant_id.as_uuid(),
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(NotificationLog::try_from).collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(limit))]
+    async fn lock_due(
+        &self,
+        tx: &mut TxContext,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<NotificationLog>, InfraError> {
+        let rows = sqlx::query_as!(
+            NotificationLogRow,
             r#"
-            INSERT INTO notification_logs (
+            SELECT
                 id, tenant_id, event_type, workflow_instance_id,
                 workflow_title, workflow_display_id,
                 recipient_user_id, recipient_email,
-                subject, status, error_message, sent_at
-            )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                subject, html_body, text_body,
+                status, attempts, next_attempt_at, error_message,
+                created_at, sent_at
+            FROM notification_logs
+            WHERE status = 'pending' AND next_attempt_at <= $1
+            ORDER BY next_attempt_at ASC, id ASC
+            LIMIT $2
+            FOR UPDATE SKIP LOCKED
             "#,
-            log.id.as_uuid(),
-            log.tenant_id.as_uuid(),
-            log.event_type,
-            log.workflow_instance_id.as_uuid(),
-            log.workflow_title,
-            log.workflow_display_id,
-            log.recipient_user_id.as_uuid(),
-            log.recipient_email,
-            log.subject,
-            log.status,
-            log.error_message,
-            log.sent_at
+            now,
+            limit
         )
-        .execute(&self.pool)
+        .fetch_all(tx.conn())
+        .await?;
+
+        rows.into_iter().map(NotificationLog::try_from).collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(id = %log.id()))]
+    async fn update(&self, tx: &mut TxContext, log: &NotificationLog) -> Result<(), InfraError> {
+        let status: &str = log.status().into();
+        sqlx::query!(
+            r#"
+            UPDATE notification_logs
+            SET status = $2, attempts = $3, next_attempt_at = $4,
+                error_message = $5, sent_at = $6
+            WHERE id = $1
+            "#,
+            log.id().as_uuid(),
+            status,
+            log.attempts(),
+            log.next_attempt_at(),
+            log.error_message(),
+            log.sent_at()
+        )
+        .execute(tx.conn())
         .await?;
 
         Ok(())
     }
 }
 
+/// 通知ログを 1 件挿入する
+async fn insert_log<'e, E>(executor: E, log: &NotificationLog) -> Result<(), InfraError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let event_type: &str = log.event_type().into();
+    let status: &str = log.status().into();
+    sqlx::query!(
+        r#"
+        INSERT INTO notification_logs (
+            id, tenant_id, event_type, workflow_instance_id,
+            workflow_title, workflow_display_id,
+            recipient_user_id, recipient_email,
+            subject, html_body, text_body,
+            status, attempts, next_attempt_at, error_message,
+            created_at, sent_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+        "#,
+        log.id().as_uuid(),
+        log.tenant_id().as_uuid(),
+        event_type,
+        log.workflow_instance_id().as_uuid(),
+        log.workflow_title(),
+        log.workflow_display_id(),
+        log.recipient_user_id().as_uuid(),
+        log.recipient_email(),
+        log.subject(),
+        log.html_body(),
+        log.text_body(),
+        status,
+        log.attempts(),
+        log.next_attempt_at(),
+        log.error_message(),
+        log.created_at(),
+        log.sent_at()
+    )
+    .execute(executor)
+    .await?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
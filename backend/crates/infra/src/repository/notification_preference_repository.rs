@@ -0,0 +1,185 @@
+//! # NotificationPreferenceRepository
+//!
+//! ユーザーの通知設定（通知イベント種別ごとのメール配信方法）の永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **ユーザー単位のアクセス**: 参照・更新は常にテナント ID とユーザー ID で絞り込む
+//! - **既定値は保存しない**: 設定のない種別は呼び出し側で即時として扱う
+//! - **トランザクション**: 複数種別の設定は同一トランザクションで更新する
+//!
+//! → 詳細設計: `docs/40_詳細設計書/16_通知機能設計.md`
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    notification::{NotificationDelivery, NotificationEventType, NotificationPreference},
+    tenant::TenantId,
+    user::UserId,
+};
+use sqlx::PgPool;
+
+use crate::{db::TxContext, error::InfraError};
+
+/// 通知設定リポジトリトレイト
+#[async_trait]
+pub trait NotificationPreferenceRepository: Send + Sync {
+    /// ユーザーの保存済みの通知設定を取得する
+    ///
+    /// 設定を保存していない通知イベント種別は含まない。
+    async fn find_by_user(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<Vec<NotificationPreference>, InfraError>;
+
+    /// 通知イベント種別の配信方法を取得する
+    ///
+    /// # 戻り値
+    ///
+    /// - `Ok(Some(delivery))`: 設定を保存している場合
+    /// - `Ok(None)`: 設定を保存していない場合
+    async fn find_delivery(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        event_type: NotificationEventType,
+    ) -> Result<Option<NotificationDelivery>, InfraError>;
+
+    /// 通知設定を保存する（保存済みの種別は上書き）
+    ///
+    /// `tx` はトランザクションコンテキスト（構造的強制）。
+    async fn upsert_all(
+        &self,
+        tx: &mut TxContext,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        preferences: &[NotificationPreference],
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError>;
+}
+
+/// DB の notification_preferences テーブルの行を表す中間構造体
+struct NotificationPreferenceRow {
+    event_type: String,
+    delivery:   String,
+}
+
+impl TryFrom<NotificationPreferenceRow> for NotificationPreference {
+    type Error = InfraError;
+
+    fn try_from(row: NotificationPreferenceRow) -> Result<Self, Self::Error> {
+        Ok(NotificationPreference {
+            event_type: row
+                .event_type
+                .parse()
+                .map_err(|e| InfraError::unexpected(format!("不正な通知イベント種別: {}", e)))?,
+            delivery:   parse_delivery(&row.delivery)?,
+        })
+    }
+}
+
+fn parse_delivery(value: &str) -> Result<NotificationDelivery, InfraError> {
+    value
+        .parse()
+        .map_err(|e| InfraError::unexpected(format!("不正な配信方法: {}", e)))
+}
+
+/// PostgreSQL 実装の NotificationPreferenceRepository
+#[derive(Debug, Clone)]
+pub struct PostgresNotificationPreferenceRepository {
+    pool: PgPool,
+}
+
+impl PostgresNotificationPreferenceRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationPreferenceRepository for PostgresNotificationPreferenceRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn find_by_user(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<Vec<NotificationPreference>, InfraError> {
+        let rows = sqlx::query_as!(
+            NotificationPreferenceRow,
+            r#"
+            SELECT event_type, delivery
+            FROM notification_preferences
+            WHERE tenant_id = $1 AND user_id = $2
+            ORDER BY event_type
+            "#,
+            tenant_id.as_uuid(),
+            user_id.as_uuid(),
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(NotificationPreference::try_from)
+            .collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id, %event_type))]
+    async fn find_delivery(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        event_type: NotificationEventType,
+    ) -> Result<Option<NotificationDelivery>, InfraError> {
+        let event_type: &str = event_type.into();
+        let delivery = sqlx::query_scalar!(
+            r#"
+            SELECT delivery
+            FROM notification_preferences
+            WHERE tenant_id = $1 AND user_id = $2 AND event_type = $3
+            "#,
+            tenant_id.as_uuid(),
+            user_id.as_uuid(),
+            event_type,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        delivery.as_deref().map(parse_delivery).transpose()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn upsert_all(
+        &self,
+        tx: &mut TxContext,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        preferences: &[NotificationPreference],
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        for preference in preferences {
+            let event_type: &str = preference.event_type.into();
+            let delivery: &str = preference.delivery.into();
+            sqlx::query!(
+                r#"
+                INSERT INTO notification_preferences (
+                    tenant_id, user_id, event_type, delivery, updated_at
+                )
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (tenant_id, user_id, event_type)
+                DO UPDATE SET delivery = EXCLUDED.delivery, updated_at = EXCLUDED.updated_at
+                "#,
+                tenant_id.as_uuid(),
+                user_id.as_uuid(),
+                event_type,
+                delivery,
+                now,
+            )
+            .execute(tx.conn())
+            .await?;
+        }
+
+        Ok(())
+    }
+}
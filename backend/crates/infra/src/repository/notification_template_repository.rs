@@ -0,0 +1,219 @@
+//! # NotificationTemplateRepository
+//!
+//! テナントが上書きした通知メールテンプレートの永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **RLS 二重防御**: WHERE 句で明示的にテナント条件を指定
+//! - **テナント × 通知イベント種別で一意**: 保存は UPSERT で行い、作成日時は維持する
+//! - **上書きのみ保存**: 行のない通知イベント種別は呼び出し側で組み込みテンプレートを使う
+//!
+//! → 詳細設計: `docs/40_詳細設計書/16_通知機能設計.md`
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    notification::{
+        NotificationEventType,
+        NotificationHtmlTemplate,
+        NotificationSubjectTemplate,
+        NotificationTemplate,
+        NotificationTemplateRecord,
+        NotificationTextTemplate,
+    },
+    tenant::TenantId,
+    user::UserId,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::InfraError;
+
+/// 通知テンプレートリポジトリトレイト
+#[async_trait]
+pub trait NotificationTemplateRepository: Send + Sync {
+    /// テナントが上書きした通知テンプレートを通知イベント種別順に取得する
+    async fn find_all_by_tenant(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<NotificationTemplate>, InfraError>;
+
+    /// 通知イベント種別の通知テンプレートを取得する
+    ///
+    /// # 戻り値
+    ///
+    /// - `Ok(Some(template))`: テナントが上書きしている場合
+    /// - `Ok(None)`: 上書きしていない場合（組み込みテンプレートを使う）
+    async fn find_by_event_type(
+        &self,
+        tenant_id: &TenantId,
+        event_type: NotificationEventType,
+    ) -> Result<Option<NotificationTemplate>, InfraError>;
+
+    /// 通知テンプレートを保存する（保存済みの場合は内容を上書き）
+    async fn upsert(&self, template: &NotificationTemplate) -> Result<(), InfraError>;
+
+    /// 通知テンプレートを削除する
+    ///
+    /// # 戻り値
+    ///
+    /// 削除した場合は `true`、該当する通知テンプレートがなかった場合は `false`
+    async fn delete(
+        &self,
+        tenant_id: &TenantId,
+        event_type: NotificationEventType,
+    ) -> Result<bool, InfraError>;
+}
+
+/// DB の notification_templates テーブルの行を表す中間構造体
+struct NotificationTemplateRow {
+    tenant_id:        Uuid,
+    event_type:       String,
+    subject_template: String,
+    html_template:    String,
+    text_template:    String,
+    updated_by:       Uuid,
+    created_at:       DateTime<Utc>,
+    updated_at:       DateTime<Utc>,
+}
+
+impl TryFrom<NotificationTemplateRow> for NotificationTemplate {
+    type Error = InfraError;
+
+    fn try_from(row: NotificationTemplateRow) -> Result<Self, Self::Error> {
+        let invalid = |e: ringiflow_domain::DomainError| {
+            InfraError::unexpected(format!("不正な通知テンプレートデータ: {}", e))
+        };
+
+        Ok(NotificationTemplate::from_db(NotificationTemplateRecord {
+            tenant_id:  TenantId::from_uuid(row.tenant_id),
+            event_type: row
+                .event_type
+                .parse()
+                .map_err(|e| InfraError::unexpected(format!("不正な通知イベント種別: {}", e)))?,
+            subject:    NotificationSubjectTemplate::new(row.subject_template).map_err(invalid)?,
+            html_body:  NotificationHtmlTemplate::new(row.html_template).map_err(invalid)?,
+            text_body:  NotificationTextTemplate::new(row.text_template).map_err(invalid)?,
+            updated_by: UserId::from_uuid(row.updated_by),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+}
+
+/// PostgreSQL 実装の NotificationTemplateRepository
+#[derive(Debug, Clone)]
+pub struct PostgresNotificationTemplateRepository {
+    pool: PgPool,
+}
+
+impl PostgresNotificationTemplateRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationTemplateRepository for PostgresNotificationTemplateRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn find_all_by_tenant(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<NotificationTemplate>, InfraError> {
+        let rows = sqlx::query_as!(
+            NotificationTemplateRow,
+            r#"
+            SELECT
+                tenant_id, event_type, subject_template, html_template, text_template,
+                updated_by, created_at, updated_at
+            FROM notification_templates
+            WHERE tenant_id = $1
+            ORDER BY event_type
+            "#,
+            tenant_id.as_uuid(),
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(NotificationTemplate::try_from)
+            .collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %event_type))]
+    async fn find_by_event_type(
+        &self,
+        tenant_id: &TenantId,
+        event_type: NotificationEventType,
+    ) -> Result<Option<NotificationTemplate>, InfraError> {
+        let event_type: &str = event_type.into();
+        let row = sqlx::query_as!(
+            NotificationTemplateRow,
+            r#"
+            SELECT
+                tenant_id, event_type, subject_template, html_template, text_template,
+                updated_by, created_at, updated_at
+            FROM notification_templates
+            WHERE tenant_id = $1 AND event_type = $2
+            "#,
+            tenant_id.as_uuid(),
+            event_type,
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(NotificationTemplate::try_from).transpose()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(tenant_id = %template.tenant_id(), event_type = %template.event_type()))]
+    async fn upsert(&self, template: &NotificationTemplate) -> Result<(), InfraError> {
+        let event_type: &str = template.event_type().into();
+        sqlx::query!(
+            r#"
+            INSERT INTO notification_templates (
+                tenant_id, event_type, subject_template, html_template, text_template,
+                updated_by, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (tenant_id, event_type)
+            DO UPDATE SET
+                subject_template = EXCLUDED.subject_template,
+                html_template = EXCLUDED.html_template,
+                text_template = EXCLUDED.text_template,
+                updated_by = EXCLUDED.updated_by,
+                updated_at = EXCLUDED.updated_at
+            "#,
+            template.tenant_id().as_uuid(),
+            event_type,
+            template.subject().as_str(),
+            template.html_body().as_str(),
+            template.text_body().as_str(),
+            template.updated_by().as_uuid(),
+            template.created_at(),
+            template.updated_at(),
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %event_type))]
+    async fn delete(
+        &self,
+        tenant_id: &TenantId,
+        event_type: NotificationEventType,
+    ) -> Result<bool, InfraError> {
+        let event_type: &str = event_type.into();
+        let result = sqlx::query!(
+            "DELETE FROM notification_templates WHERE tenant_id = $1 AND event_type = $2",
+            tenant_id.as_uuid(),
+            event_type,
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -4,22 +4,30 @@
 //!
 //! ## 設計方針
 //!
-//! - **読み取り専用**: テナント作成・更新は将来のスコープ
+//! - **更新は設定のみ**: テナント作成・名前の変更は将来のスコープ。更新できるのは既定ロケールと
+//!   タイムゾーンのみ
 //! - **型安全なクエリ**: sqlx のコンパイル時検証を活用
 
 use async_trait::async_trait;
-use ringiflow_domain::tenant::{Tenant, TenantId, TenantName};
+use ringiflow_domain::{
+    locale::Locale,
+    tenant::{Tenant, TenantId, TenantName},
+    time_zone::TimeZone,
+};
 use sqlx::PgPool;
 
 use crate::error::InfraError;
 
 /// テナントリポジトリトレイト
 ///
-/// テナント情報の読み取り操作を定義する。
+/// テナント情報の読み取り・設定の更新操作を定義する。
 #[async_trait]
 pub trait TenantRepository: Send + Sync {
     /// ID でテナントを検索
     async fn find_by_id(&self, id: &TenantId) -> Result<Option<Tenant>, InfraError>;
+
+    /// テナントの設定（既定ロケール・タイムゾーン）を更新する
+    async fn update_settings(&self, tenant: &Tenant) -> Result<(), InfraError>;
 }
 
 /// PostgreSQL 実装の TenantRepository
@@ -41,7 +49,7 @@ impl TenantRepository for PostgresTenantRepository {
     async fn find_by_id(&self, id: &TenantId) -> Result<Option<Tenant>, InfraError> {
         let row = sqlx::query!(
             r#"
-            SELECT id, name
+            SELECT id, name, default_locale, time_zone
             FROM tenants
             WHERE id = $1
             "#,
@@ -57,8 +65,33 @@ impl TenantRepository for PostgresTenantRepository {
         let tenant = Tenant::from_db(
             TenantId::from_uuid(row.id),
             TenantName::new(&row.name).map_err(|e| InfraError::unexpected(e.to_string()))?,
+            row.default_locale
+                .parse::<Locale>()
+                .map_err(|e| InfraError::unexpected(e.to_string()))?,
+            row.time_zone
+                .parse::<TimeZone>()
+                .map_err(|e| InfraError::unexpected(e.to_string()))?,
         );
 
         Ok(Some(tenant))
     }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(id = %tenant.id()))]
+    async fn update_settings(&self, tenant: &Tenant) -> Result<(), InfraError> {
+        let default_locale: &str = tenant.default_locale().into();
+        sqlx::query!(
+            r#"
+            UPDATE tenants
+            SET default_locale = $2, time_zone = $3
+            WHERE id = $1
+            "#,
+            tenant.id().as_uuid(),
+            default_locale,
+            tenant.time_zone().as_str()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
 }
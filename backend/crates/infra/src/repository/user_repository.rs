@@ -15,6 +15,7 @@ use std::collections::HashMap;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use ringiflow_domain::{
+    locale::Locale,
     role::{Role, RoleId},
     tenant::TenantId,
     user::{Email, User, UserId, UserStatus},
@@ -86,6 +87,19 @@ pub trait UserRepository: Send + Sync {
     /// ユーザーステータスを更新する
     async fn update_status(&self, user: &User) -> Result<(), InfraError>;
 
+    /// ユーザーのロケールを更新する（locale, updated_at）
+    async fn update_locale(&self, user: &User) -> Result<(), InfraError>;
+
+    /// 通知などに使うユーザーのロケールを取得する
+    ///
+    /// ユーザーがロケールを選んでいなければテナントの既定ロケールを返す。
+    /// ユーザーが見つからない場合は `None` を返す。
+    async fn find_effective_locale(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<Option<Locale>, InfraError>;
+
     /// 表示用連番でユーザーを検索する
     async fn find_by_display_number(
         &self,
@@ -149,6 +163,7 @@ struct UserRow {
     email: String,
     name: String,
     status: String,
+    locale: Option<String>,
     last_login_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -168,6 +183,10 @@ impl TryFrom<UserRow> for User {
             row.status
                 .parse::<UserStatus>()
                 .map_err(|e| InfraError::unexpected(e.to_string()))?,
+            row.locale
+                .map(|locale| locale.parse::<Locale>())
+                .transpose()
+                .map_err(|e| InfraError::unexpected(e.to_string()))?,
             row.last_login_at,
             row.created_at,
             row.updated_at,
@@ -206,6 +225,7 @@ impl UserRepository for PostgresUserRepository {
                 email,
                 name,
                 status,
+                locale,
                 last_login_at,
                 created_at,
                 updated_at
@@ -233,6 +253,7 @@ impl UserRepository for PostgresUserRepository {
                 email,
                 name,
                 status,
+                locale,
                 last_login_at,
                 created_at,
                 updated_at
@@ -314,6 +335,7 @@ impl UserRepository for PostgresUserRepository {
                 email,
                 name,
                 status,
+                locale,
                 last_login_at,
                 created_at,
                 updated_at
@@ -343,6 +365,7 @@ impl UserRepository for PostgresUserRepository {
                 email,
                 name,
                 status,
+                locale,
                 last_login_at,
                 created_at,
                 updated_at
@@ -377,13 +400,14 @@ impl UserRepository for PostgresUserRepository {
     #[tracing::instrument(skip_all, level = "debug")]
     async fn insert(&self, user: &User) -> Result<(), InfraError> {
         let status: &str = user.status().into();
+        let locale: Option<&str> = user.locale().map(Into::into);
         sqlx::query!(
             r#"
             INSERT INTO users (
                 id, tenant_id, display_number, email, name,
-                status, last_login_at, created_at, updated_at
+                status, locale, last_login_at, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             "#,
             user.id().as_uuid(),
             user.tenant_id().as_uuid(),
@@ -391,6 +415,7 @@ impl UserRepository for PostgresUserRepository {
             user.email().as_str(),
             user.name().as_str(),
             status,
+            locale,
             user.last_login_at(),
             user.created_at(),
             user.updated_at()
@@ -438,6 +463,50 @@ impl UserRepository for PostgresUserRepository {
         Ok(())
     }
 
+    #[tracing::instrument(skip_all, level = "debug")]
+    async fn update_locale(&self, user: &User) -> Result<(), InfraError> {
+        let locale: Option<&str> = user.locale().map(Into::into);
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET locale = $2, updated_at = $3
+            WHERE id = $1
+            "#,
+            user.id().as_uuid(),
+            locale,
+            user.updated_at()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn find_effective_locale(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<Option<Locale>, InfraError> {
+        let locale = sqlx::query_scalar!(
+            r#"
+            SELECT COALESCE(u.locale, t.default_locale) AS "locale!"
+            FROM users u
+            INNER JOIN tenants t ON t.id = u.tenant_id
+            WHERE u.tenant_id = $1 AND u.id = $2
+            "#,
+            tenant_id.as_uuid(),
+            user_id.as_uuid()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        locale
+            .map(|locale| locale.parse::<Locale>())
+            .transpose()
+            .map_err(|e| InfraError::unexpected(e.to_string()))
+    }
+
     #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %display_number))]
     async fn find_by_display_number(
         &self,
@@ -449,7 +518,7 @@ impl UserRepository for PostgresUserRepository {
             r#"
             SELECT
                 id, tenant_id, display_number, email, name,
-                status, last_login_at, created_at, updated_at
+                status, locale, last_login_at, created_at, updated_at
             FROM users
             WHERE tenant_id = $1 AND display_number = $2
             "#,
@@ -478,7 +547,7 @@ impl UserRepository for PostgresUserRepository {
                     r#"
                   SELECT
                       id, tenant_id, display_number, email, name,
-                      status, last_login_at, created_at, updated_at
+                      status, locale, last_login_at, created_at, updated_at
                   FROM users
                   WHERE tenant_id = $1 AND status = $2
                   ORDER BY display_number
@@ -495,7 +564,7 @@ impl UserRepository for PostgresUserRepository {
                     r#"
                   SELECT
                       id, tenant_id, display_number, email, name,
-                      status, last_login_at, created_at, updated_at
+                      status, locale, last_login_at, created_at, updated_at
                   FROM users
                   WHERE tenant_id = $1 AND status != 'deleted'
                   ORDER BY display_number
@@ -0,0 +1,272 @@
+//! # WebhookDeliveryRepository
+//!
+//! Webhook 配信（配信ログ兼リトライキュー）の永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **冪等な登録**: `(subscription_id, event_id)` の一意制約に対して `ON CONFLICT DO NOTHING`
+//!   で挿入し、アウトボックスがイベントを再配信しても同じ配信を二重に作らない
+//! - **テナント横断の取り出し**: 配信ワーカーはシステム処理として全テナントの送信待ちを扱う
+//! - **取り出し（リース）**: `FOR UPDATE SKIP LOCKED` で選んだ配信の `next_attempt_at` を
+//!   処理期限まで進めてコミットする。行ロックを保持したまま HTTP 送信を行わず、
+//!   処理期限までは他のワーカーが同じ配信を取り出さない。送信結果の記録前に
+//!   プロセスが停止した場合は、処理期限を過ぎると再び取り出される
+//!
+//! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    tenant::TenantId,
+    webhook::{
+        WebhookDelivery,
+        WebhookDeliveryId,
+        WebhookDeliveryRecord,
+        WebhookDeliveryStatus,
+        WebhookSubscriptionId,
+    },
+    workflow::WorkflowEventId,
+};
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{db::TxContext, error::InfraError};
+
+/// Webhook 配信リポジトリトレイト
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// 配信を一括で登録する
+    ///
+    /// 同じ購読・イベントの配信が既に存在する場合はスキップする。
+    async fn insert_all(&self, deliveries: &[WebhookDelivery]) -> Result<(), InfraError>;
+
+    /// 購読の配信ログを新しい順で取得する
+    async fn find_by_subscription(
+        &self,
+        subscription_id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, InfraError>;
+
+    /// 送信時刻に達した配信を取り出す（next_attempt_at ASC）
+    ///
+    /// `status = 'pending'` かつ `next_attempt_at` が `now` 以前の配信を最大 `limit` 件選び、
+    /// `next_attempt_at` を `lease_until` に進めて返す。他のトランザクションがロック中の
+    /// 配信はスキップする。呼び出し側は送信前にコミットする。
+    async fn claim_due(
+        &self,
+        tx: &mut TxContext,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, InfraError>;
+
+    /// 送信結果を反映する
+    async fn update(
+        &self,
+        tx: &mut TxContext,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), InfraError>;
+}
+
+/// DB の webhook_deliveries テーブルの行を表す中間構造体
+struct WebhookDeliveryRow {
+    id: Uuid,
+    tenant_id: Uuid,
+    subscription_id: Uuid,
+    event_id: Option<Uuid>,
+    event_type: String,
+    payload: JsonValue,
+    status: String,
+    attempts: i32,
+    next_attempt_at: DateTime<Utc>,
+    response_status: Option<i32>,
+    last_error: Option<String>,
+    created_at: DateTime<Utc>,
+    delivered_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<WebhookDeliveryRow> for WebhookDelivery {
+    type Error = InfraError;
+
+    fn try_from(row: WebhookDeliveryRow) -> Result<Self, Self::Error> {
+        Ok(WebhookDelivery::from_db(WebhookDeliveryRecord {
+            id: WebhookDeliveryId::from_uuid(row.id),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            subscription_id: WebhookSubscriptionId::from_uuid(row.subscription_id),
+            event_id: row.event_id.map(WorkflowEventId::from_uuid),
+            event_type: row.event_type,
+            payload: row.payload,
+            status: row.status.parse::<WebhookDeliveryStatus>().map_err(|e| {
+                InfraError::unexpected(format!("不正な Webhook 配信ステータス: {}", e))
+            })?,
+            attempts: row.attempts,
+            next_attempt_at: row.next_attempt_at,
+            response_status: row.response_status,
+            last_error: row.last_error,
+            created_at: row.created_at,
+            delivered_at: row.delivered_at,
+        }))
+    }
+}
+
+/// PostgreSQL 実装の WebhookDeliveryRepository
+#[derive(Debug, Clone)]
+pub struct PostgresWebhookDeliveryRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookDeliveryRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for PostgresWebhookDeliveryRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(count = deliveries.len()))]
+    async fn insert_all(&self, deliveries: &[WebhookDelivery]) -> Result<(), InfraError> {
+        for delivery in deliveries {
+            let status: &str = delivery.status().into();
+            sqlx::query!(
+                r#"
+                INSERT INTO webhook_deliveries (
+                    id, tenant_id, subscription_id, event_id, event_type, payload,
+                    status, attempts, next_attempt_at, response_status, last_error,
+                    created_at, delivered_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+                ON CONFLICT (subscription_id, event_id) DO NOTHING
+                "#,
+                delivery.id().as_uuid(),
+                delivery.tenant_id().as_uuid(),
+                delivery.subscription_id().as_uuid(),
+                delivery.event_id().map(|id| *id.as_uuid()),
+                delivery.event_type(),
+                delivery.payload(),
+                status,
+                delivery.attempts(),
+                delivery.next_attempt_at(),
+                delivery.response_status(),
+                delivery.last_error(),
+                delivery.created_at(),
+                delivery.delivered_at()
+            )
+            .execute(&self.pool)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%subscription_id, %tenant_id))]
+    async fn find_by_subscription(
+        &self,
+        subscription_id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, InfraError> {
+        let rows = sqlx::query_as!(
+            WebhookDeliveryRow,
+            r#"
+            SELECT
+                id, tenant_id, subscription_id, event_id, event_type, payload,
+                status, attempts, next_attempt_at, response_status, last_error,
+                created_at, delivered_at
+            FROM webhook_deliveries
+            WHERE subscription_id = $1 AND tenant_id = $2
+            ORDER BY created_at DESC, id DESC
+            LIMIT $3
+            "#,
+            subscription_id.as_uuid(),
+            tenant_id.as_uuid(),
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(WebhookDelivery::try_from).collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(limit))]
+    async fn claim_due(
+        &self,
+        tx: &mut TxContext,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WebhookDelivery>, InfraError> {
+        let mut rows = sqlx::query_as!(
+            WebhookDeliveryRow,
+            r#"
+            WITH due AS (
+                SELECT id
+                FROM webhook_deliveries
+                WHERE status = 'pending' AND next_attempt_at <= $1
+                ORDER BY next_attempt_at ASC, id ASC
+                LIMIT $3
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE webhook_deliveries d
+            SET next_attempt_at = $2
+            FROM due
+            WHERE d.id = due.id
+            RETURNING
+                d.id, d.tenant_id, d.subscription_id, d.event_id, d.event_type, d.payload,
+                d.status, d.attempts, d.next_attempt_at, d.response_status, d.last_error,
+                d.created_at, d.delivered_at
+            "#,
+            now,
+            lease_until,
+            limit
+        )
+        .fetch_all(tx.conn())
+        .await?;
+
+        // UPDATE ... RETURNING は順序を保証しないため、作成順に並べ直す
+        rows.sort_by_key(|row| (row.created_at, row.id));
+        rows.into_iter().map(WebhookDelivery::try_from).collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(id = %delivery.id()))]
+    async fn update(
+        &self,
+        tx: &mut TxContext,
+        delivery: &WebhookDelivery,
+    ) -> Result<(), InfraError> {
+        let status: &str = delivery.status().into();
+        sqlx::query!(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $2, attempts = $3, next_attempt_at = $4,
+                response_status = $5, last_error = $6, delivered_at = $7
+            WHERE id = $1
+            "#,
+            delivery.id().as_uuid(),
+            status,
+            delivery.attempts(),
+            delivery.next_attempt_at(),
+            delivery.response_status(),
+            delivery.last_error(),
+            delivery.delivered_at()
+        )
+        .execute(tx.conn())
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// トレイトオブジェクトとして使用できることを確認
+    #[test]
+    fn test_トレイトはsendとsyncを実装している() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Box<dyn WebhookDeliveryRepository>>();
+    }
+}
@@ -0,0 +1,287 @@
+//! # WebhookSubscriptionRepository
+//!
+//! Webhook 購読の永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **RLS 二重防御**: WHERE 句で明示的にテナント条件を指定
+//! - **イベント種別**: `event_types` は JSONB 配列で保持し、配信対象の検索は
+//!   `event_types ? $2`（要素の存在判定）で行う
+//! - **シークレット**: 署名時に平文が必要なため、ハッシュ化せずに保持する
+//!
+//! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    tenant::TenantId,
+    user::UserId,
+    webhook::{
+        WebhookName,
+        WebhookSecret,
+        WebhookSubscription,
+        WebhookSubscriptionId,
+        WebhookSubscriptionRecord,
+        WebhookUrl,
+    },
+    workflow::WorkflowEventType,
+};
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::InfraError;
+
+/// Webhook 購読リポジトリトレイト
+#[async_trait]
+pub trait WebhookSubscriptionRepository: Send + Sync {
+    /// テナント内の全購読を作成日時順で取得する
+    async fn find_all_by_tenant(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, InfraError>;
+
+    /// ID で購読を検索する
+    async fn find_by_id(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<WebhookSubscription>, InfraError>;
+
+    /// 指定したイベント種別を購読している有効な購読を取得する
+    async fn find_active_by_event_type(
+        &self,
+        tenant_id: &TenantId,
+        event_type: WorkflowEventType,
+    ) -> Result<Vec<WebhookSubscription>, InfraError>;
+
+    /// 購読を挿入する
+    async fn insert(&self, subscription: &WebhookSubscription) -> Result<(), InfraError>;
+
+    /// 購読を更新する
+    async fn update(&self, subscription: &WebhookSubscription) -> Result<(), InfraError>;
+
+    /// 購読を削除する（配信ログは CASCADE で削除される）
+    async fn delete(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+}
+
+/// DB の webhook_subscriptions テーブルの行を表す中間構造体
+struct WebhookSubscriptionRow {
+    id:          Uuid,
+    tenant_id:   Uuid,
+    name:        String,
+    url:         String,
+    event_types: JsonValue,
+    secret:      String,
+    is_active:   bool,
+    created_by:  Uuid,
+    created_at:  DateTime<Utc>,
+    updated_at:  DateTime<Utc>,
+}
+
+impl TryFrom<WebhookSubscriptionRow> for WebhookSubscription {
+    type Error = InfraError;
+
+    fn try_from(row: WebhookSubscriptionRow) -> Result<Self, Self::Error> {
+        let invalid = |e: ringiflow_domain::DomainError| {
+            InfraError::unexpected(format!("不正な Webhook 購読データ: {}", e))
+        };
+        let event_types = serde_json::from_value::<Vec<String>>(row.event_types)?
+            .iter()
+            .map(|s| s.parse::<WorkflowEventType>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(invalid)?;
+
+        Ok(WebhookSubscription::from_db(WebhookSubscriptionRecord {
+            id: WebhookSubscriptionId::from_uuid(row.id),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            name: WebhookName::new(row.name).map_err(invalid)?,
+            url: WebhookUrl::new(row.url).map_err(invalid)?,
+            event_types,
+            secret: WebhookSecret::new(row.secret).map_err(invalid)?,
+            is_active: row.is_active,
+            created_by: UserId::from_uuid(row.created_by),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+}
+
+/// PostgreSQL 実装の WebhookSubscriptionRepository
+#[derive(Debug, Clone)]
+pub struct PostgresWebhookSubscriptionRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookSubscriptionRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionRepository for PostgresWebhookSubscriptionRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn find_all_by_tenant(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, InfraError> {
+        let rows = sqlx::query_as!(
+            WebhookSubscriptionRow,
+            r#"
+            SELECT
+                id, tenant_id, name, url, event_types, secret,
+                is_active, created_by, created_at, updated_at
+            FROM webhook_subscriptions
+            WHERE tenant_id = $1
+            ORDER BY created_at ASC, id ASC
+            "#,
+            tenant_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(WebhookSubscription::try_from)
+            .collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id, %tenant_id))]
+    async fn find_by_id(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<WebhookSubscription>, InfraError> {
+        let row = sqlx::query_as!(
+            WebhookSubscriptionRow,
+            r#"
+            SELECT
+                id, tenant_id, name, url, event_types, secret,
+                is_active, created_by, created_at, updated_at
+            FROM webhook_subscriptions
+            WHERE id = $1 AND tenant_id = $2
+            "#,
+            id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(WebhookSubscription::try_from).transpose()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %event_type))]
+    async fn find_active_by_event_type(
+        &self,
+        tenant_id: &TenantId,
+        event_type: WorkflowEventType,
+    ) -> Result<Vec<WebhookSubscription>, InfraError> {
+        let event_type: &str = event_type.into();
+        let rows = sqlx::query_as!(
+            WebhookSubscriptionRow,
+            r#"
+            SELECT
+                id, tenant_id, name, url, event_types, secret,
+                is_active, created_by, created_at, updated_at
+            FROM webhook_subscriptions
+            WHERE tenant_id = $1 AND is_active AND event_types ? $2
+            ORDER BY created_at ASC, id ASC
+            "#,
+            tenant_id.as_uuid(),
+            event_type
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(WebhookSubscription::try_from)
+            .collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(id = %subscription.id()))]
+    async fn insert(&self, subscription: &WebhookSubscription) -> Result<(), InfraError> {
+        let event_types = serde_json::to_value(subscription.event_types())?;
+        sqlx::query!(
+            r#"
+            INSERT INTO webhook_subscriptions (
+                id, tenant_id, name, url, event_types, secret,
+                is_active, created_by, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+            subscription.id().as_uuid(),
+            subscription.tenant_id().as_uuid(),
+            subscription.name().as_str(),
+            subscription.url().as_str(),
+            event_types,
+            subscription.secret().as_str(),
+            subscription.is_active(),
+            subscription.created_by().as_uuid(),
+            subscription.created_at(),
+            subscription.updated_at()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(id = %subscription.id()))]
+    async fn update(&self, subscription: &WebhookSubscription) -> Result<(), InfraError> {
+        let event_types = serde_json::to_value(subscription.event_types())?;
+        sqlx::query!(
+            r#"
+            UPDATE webhook_subscriptions
+            SET name = $3, url = $4, event_types = $5, secret = $6,
+                is_active = $7, updated_at = $8
+            WHERE id = $1 AND tenant_id = $2
+            "#,
+            subscription.id().as_uuid(),
+            subscription.tenant_id().as_uuid(),
+            subscription.name().as_str(),
+            subscription.url().as_str(),
+            event_types,
+            subscription.secret().as_str(),
+            subscription.is_active(),
+            subscription.updated_at()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id, %tenant_id))]
+    async fn delete(
+        &self,
+        id: &WebhookSubscriptionId,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            "DELETE FROM webhook_subscriptions WHERE id = $1 AND tenant_id = $2",
+            id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// トレイトオブジェクトとして使用できることを確認
+    #[test]
+    fn test_トレイトはsendとsyncを実装している() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Box<dyn WebhookSubscriptionRepository>>();
+    }
+}
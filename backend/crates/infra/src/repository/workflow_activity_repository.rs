@@ -0,0 +1,208 @@
+//! # WorkflowActivityRepository
+//!
+//! ワークフローアクティビティ（インスタンスの出来事の時系列記録）の永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **テナント分離**: すべてのクエリでテナント ID を考慮
+//! - **追記のみ**: アクティビティは不変のため更新・削除メソッドを持たない
+//! - **トランザクション**: 状態遷移やコメント操作は対象の保存と同一トランザクションで
+//!   記録する（`insert_all`）。添付ファイル追加のように単独で保存される操作は `insert` で記録する
+//!
+//! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    tenant::TenantId,
+    user::UserId,
+    workflow::{
+        WorkflowActivity,
+        WorkflowActivityId,
+        WorkflowActivityRecord,
+        WorkflowActivityType,
+        WorkflowInstanceId,
+        WorkflowStepId,
+    },
+};
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{db::TxContext, error::InfraError};
+
+/// ワークフローアクティビティリポジトリトレイト
+#[async_trait]
+pub trait WorkflowActivityRepository: Send + Sync {
+    /// アクティビティを 1 件記録する
+    async fn insert(
+        &self,
+        activity: &WorkflowActivity,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// アクティビティを一括で記録する
+    ///
+    /// `tx` はトランザクションコンテキスト（構造的強制）。
+    async fn insert_all(
+        &self,
+        tx: &mut TxContext,
+        activities: &[WorkflowActivity],
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// インスタンス ID でアクティビティを取得する（occurred_at ASC）
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowActivity>, InfraError>;
+}
+
+/// DB の workflow_activities テーブルの行を表す中間構造体
+struct WorkflowActivityRow {
+    id: Uuid,
+    tenant_id: Uuid,
+    instance_id: Uuid,
+    activity_type: String,
+    actor_id: Option<Uuid>,
+    step_id: Option<Uuid>,
+    details: JsonValue,
+    occurred_at: DateTime<Utc>,
+}
+
+impl TryFrom<WorkflowActivityRow> for WorkflowActivity {
+    type Error = InfraError;
+
+    fn try_from(row: WorkflowActivityRow) -> Result<Self, Self::Error> {
+        Ok(WorkflowActivity::from_db(WorkflowActivityRecord {
+            id: WorkflowActivityId::from_uuid(row.id),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            instance_id: WorkflowInstanceId::from_uuid(row.instance_id),
+            activity_type: row
+                .activity_type
+                .parse::<WorkflowActivityType>()
+                .map_err(|e| InfraError::unexpected(format!("不正なアクティビティ種別: {}", e)))?,
+            actor_id: row.actor_id.map(UserId::from_uuid),
+            step_id: row.step_id.map(WorkflowStepId::from_uuid),
+            details: row.details,
+            occurred_at: row.occurred_at,
+        }))
+    }
+}
+
+/// PostgreSQL 実装の WorkflowActivityRepository
+#[derive(Debug, Clone)]
+pub struct PostgresWorkflowActivityRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowActivityRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkflowActivityRepository for PostgresWorkflowActivityRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, activity_type = %activity.activity_type()))]
+    async fn insert(
+        &self,
+        activity: &WorkflowActivity,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        let activity_type: &str = activity.activity_type().into();
+        sqlx::query!(
+            r#"
+            INSERT INTO workflow_activities (
+                id, tenant_id, instance_id, activity_type,
+                actor_id, step_id, details, occurred_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            activity.id().as_uuid(),
+            tenant_id.as_uuid(),
+            activity.instance_id().as_uuid(),
+            activity_type,
+            activity.actor_id().map(|id| *id.as_uuid()),
+            activity.step_id().map(|id| *id.as_uuid()),
+            activity.details(),
+            activity.occurred_at()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, count = activities.len()))]
+    async fn insert_all(
+        &self,
+        tx: &mut TxContext,
+        activities: &[WorkflowActivity],
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        for activity in activities {
+            let activity_type: &str = activity.activity_type().into();
+            sqlx::query!(
+                r#"
+                INSERT INTO workflow_activities (
+                    id, tenant_id, instance_id, activity_type,
+                    actor_id, step_id, details, occurred_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+                activity.id().as_uuid(),
+                tenant_id.as_uuid(),
+                activity.instance_id().as_uuid(),
+                activity_type,
+                activity.actor_id().map(|id| *id.as_uuid()),
+                activity.step_id().map(|id| *id.as_uuid()),
+                activity.details(),
+                activity.occurred_at()
+            )
+            .execute(tx.conn())
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%instance_id, %tenant_id))]
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowActivity>, InfraError> {
+        let rows = sqlx::query_as!(
+            WorkflowActivityRow,
+            r#"
+            SELECT
+                id, tenant_id, instance_id, activity_type,
+                actor_id, step_id, details, occurred_at
+            FROM workflow_activities
+            WHERE instance_id = $1 AND tenant_id = $2
+            ORDER BY occurred_at ASC, id ASC
+            "#,
+            instance_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(WorkflowActivity::try_from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// トレイトオブジェクトとして使用できることを確認
+    #[test]
+    fn test_トレイトはsendとsyncを実装している() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Box<dyn WorkflowActivityRepository>>();
+    }
+}
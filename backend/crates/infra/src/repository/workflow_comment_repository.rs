@@ -6,6 +6,8 @@
 //!
 //! - **テナント分離**: すべてのクエリでテナント ID を考慮
 //! - **時系列ソート**: コメント一覧は created_at ASC で返す
+//! - **論理削除**: 削除済みコメントも返信スレッドを保つため行を残し、`deleted_at` で表す
+//! - **編集履歴**: 編集前の本文を `workflow_comment_revisions` に残す
 //! - **型安全なクエリ**: sqlx のコンパイル時検証を活用
 //!
 //! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
@@ -20,13 +22,16 @@ use ringiflow_domain::{
         WorkflowComment,
         WorkflowCommentId,
         WorkflowCommentRecord,
+        WorkflowCommentRevision,
+        WorkflowCommentRevisionId,
+        WorkflowCommentRevisionRecord,
         WorkflowInstanceId,
     },
 };
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use crate::error::InfraError;
+use crate::{db::TxContext, error::InfraError};
 
 /// ワークフローコメントリポジトリトレイト
 ///
@@ -34,8 +39,11 @@ use crate::error::InfraError;
 #[async_trait]
 pub trait WorkflowCommentRepository: Send + Sync {
     /// 新規コメントを作成する
+    ///
+    /// アクティビティ・イベントの記録と同一トランザクションで実行するため `tx` を受け取る。
     async fn insert(
         &self,
+        tx: &mut TxContext,
         comment: &WorkflowComment,
         tenant_id: &TenantId,
     ) -> Result<(), InfraError>;
@@ -46,6 +54,38 @@ pub trait WorkflowCommentRepository: Send + Sync {
         instance_id: &WorkflowInstanceId,
         tenant_id: &TenantId,
     ) -> Result<Vec<WorkflowComment>, InfraError>;
+
+    /// ID でコメントを取得する
+    async fn find_by_id(
+        &self,
+        id: &WorkflowCommentId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<WorkflowComment>, InfraError>;
+
+    /// コメントの本文・編集日時・削除状態を更新する
+    ///
+    /// 編集履歴の保存と同一トランザクションで実行するため `tx` を受け取る。
+    async fn update(
+        &self,
+        tx: &mut TxContext,
+        comment: &WorkflowComment,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// 編集履歴を記録する
+    async fn insert_revision(
+        &self,
+        tx: &mut TxContext,
+        revision: &WorkflowCommentRevision,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// コメントの編集履歴一覧を取得する（edited_at ASC）
+    async fn find_revisions(
+        &self,
+        comment_id: &WorkflowCommentId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowCommentRevision>, InfraError>;
 }
 
 /// DB の workflow_comments テーブルの行を表す中間構造体
@@ -53,8 +93,12 @@ struct WorkflowCommentRow {
     id:          Uuid,
     tenant_id:   Uuid,
     instance_id: Uuid,
+    parent_id:   Option<Uuid>,
     posted_by:   Uuid,
     body:        String,
+    edited_at:   Option<DateTime<Utc>>,
+    deleted_at:  Option<DateTime<Utc>>,
+    deleted_by:  Option<Uuid>,
     created_at:  DateTime<Utc>,
     updated_at:  DateTime<Utc>,
 }
@@ -67,15 +111,47 @@ impl TryFrom<WorkflowCommentRow> for WorkflowComment {
             id:          WorkflowCommentId::from_uuid(row.id),
             tenant_id:   TenantId::from_uuid(row.tenant_id),
             instance_id: WorkflowInstanceId::from_uuid(row.instance_id),
+            parent_id:   row.parent_id.map(WorkflowCommentId::from_uuid),
             posted_by:   UserId::from_uuid(row.posted_by),
             body:        CommentBody::new(row.body)
                 .map_err(|e| InfraError::unexpected(e.to_string()))?,
+            edited_at:   row.edited_at,
+            deleted_at:  row.deleted_at,
+            deleted_by:  row.deleted_by.map(UserId::from_uuid),
             created_at:  row.created_at,
             updated_at:  row.updated_at,
         }))
     }
 }
 
+/// DB の workflow_comment_revisions テーブルの行を表す中間構造体
+struct WorkflowCommentRevisionRow {
+    id:         Uuid,
+    tenant_id:  Uuid,
+    comment_id: Uuid,
+    body:       String,
+    edited_by:  Uuid,
+    edited_at:  DateTime<Utc>,
+}
+
+impl TryFrom<WorkflowCommentRevisionRow> for WorkflowCommentRevision {
+    type Error = InfraError;
+
+    fn try_from(row: WorkflowCommentRevisionRow) -> Result<Self, Self::Error> {
+        Ok(WorkflowCommentRevision::from_db(
+            WorkflowCommentRevisionRecord {
+                id:         WorkflowCommentRevisionId::from_uuid(row.id),
+                tenant_id:  TenantId::from_uuid(row.tenant_id),
+                comment_id: WorkflowCommentId::from_uuid(row.comment_id),
+                body:       CommentBody::new(row.body)
+                    .map_err(|e| InfraError::unexpected(e.to_string()))?,
+                edited_by:  UserId::from_uuid(row.edited_by),
+                edited_at:  row.edited_at,
+            },
+        ))
+    }
+}
+
 /// PostgreSQL 実装の WorkflowCommentRepository
 #[derive(Debug, Clone)]
 pub struct PostgresWorkflowCommentRepository {
@@ -94,26 +170,28 @@ impl WorkflowCommentRepository for PostgresWorkflowCommentRepository {
     #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
     async fn insert(
         &self,
+        tx: &mut TxContext,
         comment: &WorkflowComment,
         tenant_id: &TenantId,
     ) -> Result<(), InfraError> {
         sqlx::query!(
             r#"
             INSERT INTO workflow_comments (
-                id, tenant_id, instance_id, posted_by, body,
+                id, tenant_id, instance_id, parent_id, posted_by, body,
                 created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
             "#,
             comment.id().as_uuid(),
             tenant_id.as_uuid(),
             comment.instance_id().as_uuid(),
+            comment.parent_id().map(|id| *id.as_uuid()),
             comment.posted_by().as_uuid(),
             comment.body().as_str(),
             comment.created_at(),
             comment.updated_at()
         )
-        .execute(&self.pool)
+        .execute(tx.conn())
         .await?;
 
         Ok(())
@@ -129,7 +207,8 @@ impl WorkflowCommentRepository for PostgresWorkflowCommentRepository {
             WorkflowCommentRow,
             r#"
             SELECT
-                id, tenant_id, instance_id, posted_by, body,
+                id, tenant_id, instance_id, parent_id, posted_by, body,
+                edited_at, deleted_at, deleted_by,
                 created_at, updated_at
             FROM workflow_comments
             WHERE instance_id = $1 AND tenant_id = $2
@@ -143,6 +222,109 @@ impl WorkflowCommentRepository for PostgresWorkflowCommentRepository {
 
         rows.into_iter().map(WorkflowComment::try_from).collect()
     }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id, %tenant_id))]
+    async fn find_by_id(
+        &self,
+        id: &WorkflowCommentId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<WorkflowComment>, InfraError> {
+        let row = sqlx::query_as!(
+            WorkflowCommentRow,
+            r#"
+            SELECT
+                id, tenant_id, instance_id, parent_id, posted_by, body,
+                edited_at, deleted_at, deleted_by,
+                created_at, updated_at
+            FROM workflow_comments
+            WHERE id = $1 AND tenant_id = $2
+            "#,
+            id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(WorkflowComment::try_from).transpose()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn update(
+        &self,
+        tx: &mut TxContext,
+        comment: &WorkflowComment,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            r#"
+            UPDATE workflow_comments
+            SET body = $1, edited_at = $2, deleted_at = $3, deleted_by = $4
+            WHERE id = $5 AND tenant_id = $6
+            "#,
+            comment.body().as_str(),
+            comment.edited_at(),
+            comment.deleted_at(),
+            comment.deleted_by().map(|id| *id.as_uuid()),
+            comment.id().as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .execute(tx.conn())
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
+    async fn insert_revision(
+        &self,
+        tx: &mut TxContext,
+        revision: &WorkflowCommentRevision,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO workflow_comment_revisions (
+                id, tenant_id, comment_id, body, edited_by, edited_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            revision.id().as_uuid(),
+            tenant_id.as_uuid(),
+            revision.comment_id().as_uuid(),
+            revision.body().as_str(),
+            revision.edited_by().as_uuid(),
+            revision.edited_at()
+        )
+        .execute(tx.conn())
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%comment_id, %tenant_id))]
+    async fn find_revisions(
+        &self,
+        comment_id: &WorkflowCommentId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowCommentRevision>, InfraError> {
+        let rows = sqlx::query_as!(
+            WorkflowCommentRevisionRow,
+            r#"
+            SELECT id, tenant_id, comment_id, body, edited_by, edited_at
+            FROM workflow_comment_revisions
+            WHERE comment_id = $1 AND tenant_id = $2
+            ORDER BY edited_at ASC
+            "#,
+            comment_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(WorkflowCommentRevision::try_from)
+            .collect()
+    }
 }
 
 #[cfg(test)]
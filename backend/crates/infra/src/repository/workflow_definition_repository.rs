@@ -98,16 +98,18 @@ pub trait WorkflowDefinitionRepository: Send + Sync {
 /// `query_as!` マクロが SQL 結果を直接マッピングする対象。
 /// `TryFrom` で `WorkflowDefinition` への変換ロジックを一箇所に集約する。
 struct WorkflowDefinitionRow {
-    id:          Uuid,
-    tenant_id:   Uuid,
-    name:        String,
+    id: Uuid,
+    tenant_id: Uuid,
+    name: String,
     description: Option<String>,
-    version:     i32,
-    definition:  serde_json::Value,
-    status:      String,
-    created_by:  Uuid,
-    created_at:  DateTime<Utc>,
-    updated_at:  DateTime<Utc>,
+    version: i32,
+    definition: serde_json::Value,
+    status: String,
+    confidential: bool,
+    default_watchers: Vec<Uuid>,
+    created_by: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
 }
 
 impl TryFrom<WorkflowDefinitionRow> for WorkflowDefinition {
@@ -115,21 +117,27 @@ impl TryFrom<WorkflowDefinitionRow> for WorkflowDefinition {
 
     fn try_from(row: WorkflowDefinitionRow) -> Result<Self, Self::Error> {
         Ok(WorkflowDefinition::from_db(WorkflowDefinitionRecord {
-            id:          WorkflowDefinitionId::from_uuid(row.id),
-            tenant_id:   TenantId::from_uuid(row.tenant_id),
-            name:        WorkflowName::new(&row.name)
+            id: WorkflowDefinitionId::from_uuid(row.id),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            name: WorkflowName::new(&row.name)
                 .map_err(|e| InfraError::unexpected(e.to_string()))?,
             description: row.description,
-            version:     Version::new(row.version as u32)
+            version: Version::new(row.version as u32)
                 .map_err(|e| InfraError::unexpected(e.to_string()))?,
-            definition:  row.definition,
-            status:      row
+            definition: row.definition,
+            status: row
                 .status
                 .parse::<WorkflowDefinitionStatus>()
                 .map_err(|e| InfraError::unexpected(e.to_string()))?,
-            created_by:  UserId::from_uuid(row.created_by),
-            created_at:  row.created_at,
-            updated_at:  row.updated_at,
+            confidential: row.confidential,
+            default_watchers: row
+                .default_watchers
+                .into_iter()
+                .map(UserId::from_uuid)
+                .collect(),
+            created_by: UserId::from_uuid(row.created_by),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
         }))
     }
 }
@@ -165,6 +173,8 @@ impl WorkflowDefinitionRepository for PostgresWorkflowDefinitionRepository {
                 version,
                 definition,
                 status,
+                confidential,
+                default_watchers,
                 created_by,
                 created_at,
                 updated_at
@@ -197,6 +207,8 @@ impl WorkflowDefinitionRepository for PostgresWorkflowDefinitionRepository {
                 version,
                 definition,
                 status,
+                confidential,
+                default_watchers,
                 created_by,
                 created_at,
                 updated_at
@@ -228,6 +240,8 @@ impl WorkflowDefinitionRepository for PostgresWorkflowDefinitionRepository {
                 version,
                 definition,
                 status,
+                confidential,
+                default_watchers,
                 created_by,
                 created_at,
                 updated_at
@@ -246,12 +260,17 @@ impl WorkflowDefinitionRepository for PostgresWorkflowDefinitionRepository {
     #[tracing::instrument(skip_all, level = "debug", fields(id = %definition.id(), tenant_id = %definition.tenant_id()))]
     async fn insert(&self, definition: &WorkflowDefinition) -> Result<(), InfraError> {
         let status: &str = definition.status().into();
+        let default_watchers: Vec<Uuid> = definition
+            .default_watchers()
+            .iter()
+            .map(|u| *u.as_uuid())
+            .collect();
 
         sqlx::query!(
             r#"
             INSERT INTO workflow_definitions
-                (id, tenant_id, name, description, version, definition, status, created_by, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                (id, tenant_id, name, description, version, definition, status, confidential, created_by, created_at, updated_at, default_watchers)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
             "#,
             definition.id().as_uuid(),
             definition.tenant_id().as_uuid(),
@@ -260,9 +279,11 @@ impl WorkflowDefinitionRepository for PostgresWorkflowDefinitionRepository {
             definition.version().as_i32(),
             definition.definition(),
             status,
+            definition.is_confidential(),
             definition.created_by().as_uuid(),
             definition.created_at(),
-            definition.updated_at()
+            definition.updated_at(),
+            &default_watchers as &[Uuid]
         )
         .execute(&self.pool)
         .await?;
@@ -277,6 +298,11 @@ impl WorkflowDefinitionRepository for PostgresWorkflowDefinitionRepository {
         expected_version: Version,
     ) -> Result<(), InfraError> {
         let status: &str = definition.status().into();
+        let default_watchers: Vec<Uuid> = definition
+            .default_watchers()
+            .iter()
+            .map(|u| *u.as_uuid())
+            .collect();
 
         let result = sqlx::query!(
             r#"
@@ -286,7 +312,9 @@ impl WorkflowDefinitionRepository for PostgresWorkflowDefinitionRepository {
                 version = $3,
                 definition = $4,
                 status = $5,
-                updated_at = $6
+                updated_at = $6,
+                confidential = $10,
+                default_watchers = $11
             WHERE id = $7 AND version = $8 AND tenant_id = $9
             "#,
             definition.name().as_str(),
@@ -297,7 +325,9 @@ impl WorkflowDefinitionRepository for PostgresWorkflowDefinitionRepository {
             definition.updated_at(),
             definition.id().as_uuid(),
             expected_version.as_i32(),
-            definition.tenant_id().as_uuid()
+            definition.tenant_id().as_uuid(),
+            definition.is_confidential(),
+            &default_watchers as &[Uuid]
         )
         .execute(&self.pool)
         .await?;
@@ -0,0 +1,379 @@
+//! # WorkflowEventOutboxRepository
+//!
+//! ワークフロードメインイベントのアウトボックスの永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **トランザクション**: イベントはインスタンス・ステップの保存と同一トランザクションで書き込む
+//! - **テナント横断の取り出し**: ディスパッチャはシステム処理として全テナントの未配信イベントを扱う
+//! - **排他制御**: `FOR UPDATE SKIP LOCKED` で取り出したイベントの `next_attempt_at` を処理期限（リース）まで
+//!   進めてコミットする。行ロックを保持せずに配信でき、期限までは他のディスパッチャが取り出さない
+//! - **コンシューマ単位の配信状態**: 配信に成功したコンシューマを `delivered_consumers` に記録し、
+//!   再配信時に成功済みのコンシューマへ重複して配信しない
+//! - **配信停止**: ペイロードを解釈できないイベントは取り出し時に配信停止（`failed_at`）にし、
+//!   残りのイベントの配信を妨げない
+//!
+//! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    tenant::TenantId,
+    user::UserId,
+    workflow::{
+        WorkflowEvent,
+        WorkflowEventId,
+        WorkflowEventRecord,
+        WorkflowEventType,
+        WorkflowInstanceId,
+    },
+};
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{db::TxContext, error::InfraError};
+
+/// 未配信のイベント
+///
+/// ディスパッチャが再配信の間隔と配信先を決めるため、これまでの配信失敗回数と
+/// 配信に成功したコンシューマ名を併せて返す。
+#[derive(Debug, Clone)]
+pub struct PendingWorkflowEvent {
+    pub event: WorkflowEvent,
+    pub attempts: i32,
+    pub delivered_consumers: Vec<String>,
+}
+
+/// ワークフローイベントアウトボックスリポジトリトレイト
+#[async_trait]
+pub trait WorkflowEventOutboxRepository: Send + Sync {
+    /// イベントを一括で書き込む
+    ///
+    /// `tx` はトランザクションコンテキスト（構造的強制）。
+    async fn insert_all(
+        &self,
+        tx: &mut TxContext,
+        events: &[WorkflowEvent],
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// インスタンス ID でイベントを取得する（occurred_at ASC、配信状態は問わない）
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowEvent>, InfraError>;
+
+    /// 配信可能な未配信イベントを取り出す（occurred_at ASC）
+    ///
+    /// `next_attempt_at` が `now` 以前で配信停止していないイベントを最大 `limit` 件返し、
+    /// `next_attempt_at` を `lease_until` まで進める。他のトランザクションがロック中のイベントはスキップする。
+    /// ペイロードを解釈できないイベントは配信停止にし、結果に含めない。
+    async fn claim_pending(
+        &self,
+        tx: &mut TxContext,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<PendingWorkflowEvent>, InfraError>;
+
+    /// イベントを配信済みにする
+    async fn mark_dispatched(
+        &self,
+        tx: &mut TxContext,
+        id: &WorkflowEventId,
+        delivered_consumers: &[String],
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError>;
+
+    /// 配信失敗を記録し、次回の配信日時を設定する
+    async fn mark_failed(
+        &self,
+        tx: &mut TxContext,
+        id: &WorkflowEventId,
+        delivered_consumers: &[String],
+        error: &str,
+        next_attempt_at: DateTime<Utc>,
+    ) -> Result<(), InfraError>;
+
+    /// 配信失敗を記録し、配信停止にする（以降は取り出さない）
+    async fn mark_dead(
+        &self,
+        tx: &mut TxContext,
+        id: &WorkflowEventId,
+        delivered_consumers: &[String],
+        error: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError>;
+}
+
+/// DB の workflow_event_outbox テーブルの行を表す中間構造体
+struct WorkflowEventOutboxRow {
+    id: Uuid,
+    tenant_id: Uuid,
+    instance_id: Uuid,
+    event_type: String,
+    actor_id: Option<Uuid>,
+    payload: JsonValue,
+    occurred_at: DateTime<Utc>,
+    attempts: i32,
+    delivered_consumers: Vec<String>,
+}
+
+impl TryFrom<WorkflowEventOutboxRow> for PendingWorkflowEvent {
+    type Error = InfraError;
+
+    fn try_from(row: WorkflowEventOutboxRow) -> Result<Self, Self::Error> {
+        let event = WorkflowEvent::from_db(WorkflowEventRecord {
+            id:          WorkflowEventId::from_uuid(row.id),
+            tenant_id:   TenantId::from_uuid(row.tenant_id),
+            instance_id: WorkflowInstanceId::from_uuid(row.instance_id),
+            event_type:  row.event_type.parse::<WorkflowEventType>().map_err(|e| {
+                InfraError::unexpected(format!("不正なワークフローイベント種別: {}", e))
+            })?,
+            actor_id:    row.actor_id.map(UserId::from_uuid),
+            payload:     serde_json::from_value(row.payload)?,
+            occurred_at: row.occurred_at,
+        });
+
+        Ok(PendingWorkflowEvent {
+            event,
+            attempts: row.attempts,
+            delivered_consumers: row.delivered_consumers,
+        })
+    }
+}
+
+/// PostgreSQL 実装の WorkflowEventOutboxRepository
+#[derive(Debug, Clone)]
+pub struct PostgresWorkflowEventOutboxRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowEventOutboxRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkflowEventOutboxRepository for PostgresWorkflowEventOutboxRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, count = events.len()))]
+    async fn insert_all(
+        &self,
+        tx: &mut TxContext,
+        events: &[WorkflowEvent],
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        for event in events {
+            let event_type: &str = event.event_type().into();
+            let payload = serde_json::to_value(event.payload())?;
+            sqlx::query!(
+                r#"
+                INSERT INTO workflow_event_outbox (
+                    id, tenant_id, instance_id, event_type,
+                    actor_id, payload, occurred_at, next_attempt_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $7)
+                "#,
+                event.id().as_uuid(),
+                tenant_id.as_uuid(),
+                event.instance_id().as_uuid(),
+                event_type,
+                event.actor_id().map(|id| *id.as_uuid()),
+                payload,
+                event.occurred_at()
+            )
+            .execute(tx.conn())
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%instance_id, %tenant_id))]
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowEvent>, InfraError> {
+        let rows = sqlx::query_as!(
+            WorkflowEventOutboxRow,
+            r#"
+            SELECT
+                id, tenant_id, instance_id, event_type,
+                actor_id, payload, occurred_at, attempts, delivered_consumers
+            FROM workflow_event_outbox
+            WHERE instance_id = $1 AND tenant_id = $2
+            ORDER BY occurred_at ASC, id ASC
+            "#,
+            instance_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter()
+            .map(|row| PendingWorkflowEvent::try_from(row).map(|pending| pending.event))
+            .collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(limit))]
+    async fn claim_pending(
+        &self,
+        tx: &mut TxContext,
+        now: DateTime<Utc>,
+        lease_until: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<PendingWorkflowEvent>, InfraError> {
+        let mut rows = sqlx::query_as!(
+            WorkflowEventOutboxRow,
+            r#"
+            WITH due AS (
+                SELECT id
+                FROM workflow_event_outbox
+                WHERE dispatched_at IS NULL AND failed_at IS NULL AND next_attempt_at <= $1
+                ORDER BY occurred_at ASC, id ASC
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            UPDATE workflow_event_outbox AS o
+            SET next_attempt_at = $3
+            FROM due
+            WHERE o.id = due.id
+            RETURNING
+                o.id, o.tenant_id, o.instance_id, o.event_type,
+                o.actor_id, o.payload, o.occurred_at, o.attempts, o.delivered_consumers
+            "#,
+            now,
+            limit,
+            lease_until
+        )
+        .fetch_all(tx.conn())
+        .await?;
+        // UPDATE ... RETURNING は順序を保証しないため、発生順に並べ直す
+        rows.sort_by_key(|row| (row.occurred_at, row.id));
+
+        let mut pending = Vec::with_capacity(rows.len());
+        for row in rows {
+            let id = row.id;
+            match PendingWorkflowEvent::try_from(row) {
+                Ok(event) => pending.push(event),
+                Err(e) => {
+                    // 1 件の不正なイベントでバッチ全体が止まらないよう、配信停止にして残りを処理する
+                    tracing::error!(
+                        event_id = %id,
+                        error = %e,
+                        "ワークフローイベントを解釈できないため配信を停止"
+                    );
+                    sqlx::query!(
+                        r#"
+                        UPDATE workflow_event_outbox
+                        SET failed_at = $2, last_error = $3
+                        WHERE id = $1
+                        "#,
+                        id,
+                        now,
+                        e.to_string()
+                    )
+                    .execute(tx.conn())
+                    .await?;
+                }
+            }
+        }
+
+        Ok(pending)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id))]
+    async fn mark_dispatched(
+        &self,
+        tx: &mut TxContext,
+        id: &WorkflowEventId,
+        delivered_consumers: &[String],
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            r#"
+            UPDATE workflow_event_outbox
+            SET dispatched_at = $2, delivered_consumers = $3, last_error = NULL
+            WHERE id = $1
+            "#,
+            id.as_uuid(),
+            now,
+            delivered_consumers
+        )
+        .execute(tx.conn())
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id))]
+    async fn mark_failed(
+        &self,
+        tx: &mut TxContext,
+        id: &WorkflowEventId,
+        delivered_consumers: &[String],
+        error: &str,
+        next_attempt_at: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            r#"
+            UPDATE workflow_event_outbox
+            SET attempts = attempts + 1, delivered_consumers = $2,
+                last_error = $3, next_attempt_at = $4
+            WHERE id = $1
+            "#,
+            id.as_uuid(),
+            delivered_consumers,
+            error,
+            next_attempt_at
+        )
+        .execute(tx.conn())
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id))]
+    async fn mark_dead(
+        &self,
+        tx: &mut TxContext,
+        id: &WorkflowEventId,
+        delivered_consumers: &[String],
+        error: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            r#"
+            UPDATE workflow_event_outbox
+            SET attempts = attempts + 1, delivered_consumers = $2,
+                last_error = $3, failed_at = $4
+            WHERE id = $1
+            "#,
+            id.as_uuid(),
+            delivered_consumers,
+            error,
+            now
+        )
+        .execute(tx.conn())
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// トレイトオブジェクトとして使用できることを確認
+    #[test]
+    fn test_トレイトはsendとsyncを実装している() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Box<dyn WorkflowEventOutboxRepository>>();
+    }
+}
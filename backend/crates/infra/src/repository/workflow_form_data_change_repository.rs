@@ -0,0 +1,166 @@
+//! # WorkflowFormDataChangeRepository
+//!
+//! 承認者によるフォームデータ編集の変更履歴の永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **テナント分離**: すべてのクエリでテナント ID を考慮
+//! - **追記のみ**: 変更履歴は監査目的のため更新・削除メソッドを持たない
+//! - **トランザクション**: 承認処理（ステップ・インスタンス更新）と同一トランザクションで記録する
+//!
+//! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    tenant::TenantId,
+    user::UserId,
+    workflow::{
+        WorkflowFormDataChange,
+        WorkflowFormDataChangeId,
+        WorkflowFormDataChangeRecord,
+        WorkflowInstanceId,
+        WorkflowStepId,
+    },
+};
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{db::TxContext, error::InfraError};
+
+/// フォームデータ変更履歴リポジトリトレイト
+#[async_trait]
+pub trait WorkflowFormDataChangeRepository: Send + Sync {
+    /// 変更履歴を一括で記録する
+    ///
+    /// `tx` はトランザクションコンテキスト（構造的強制）。
+    async fn insert_all(
+        &self,
+        tx: &mut TxContext,
+        changes: &[WorkflowFormDataChange],
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// インスタンス ID で変更履歴を取得する（changed_at ASC）
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowFormDataChange>, InfraError>;
+}
+
+/// DB の workflow_form_data_changes テーブルの行を表す中間構造体
+struct WorkflowFormDataChangeRow {
+    id:          Uuid,
+    tenant_id:   Uuid,
+    instance_id: Uuid,
+    step_id:     Uuid,
+    field_id:    String,
+    old_value:   JsonValue,
+    new_value:   JsonValue,
+    changed_by:  Uuid,
+    changed_at:  DateTime<Utc>,
+}
+
+impl From<WorkflowFormDataChangeRow> for WorkflowFormDataChange {
+    fn from(row: WorkflowFormDataChangeRow) -> Self {
+        WorkflowFormDataChange::from_db(WorkflowFormDataChangeRecord {
+            id:          WorkflowFormDataChangeId::from_uuid(row.id),
+            tenant_id:   TenantId::from_uuid(row.tenant_id),
+            instance_id: WorkflowInstanceId::from_uuid(row.instance_id),
+            step_id:     WorkflowStepId::from_uuid(row.step_id),
+            field_id:    row.field_id,
+            old_value:   row.old_value,
+            new_value:   row.new_value,
+            changed_by:  UserId::from_uuid(row.changed_by),
+            changed_at:  row.changed_at,
+        })
+    }
+}
+
+/// PostgreSQL 実装の WorkflowFormDataChangeRepository
+#[derive(Debug, Clone)]
+pub struct PostgresWorkflowFormDataChangeRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowFormDataChangeRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkflowFormDataChangeRepository for PostgresWorkflowFormDataChangeRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, count = changes.len()))]
+    async fn insert_all(
+        &self,
+        tx: &mut TxContext,
+        changes: &[WorkflowFormDataChange],
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        for change in changes {
+            sqlx::query!(
+                r#"
+                INSERT INTO workflow_form_data_changes (
+                    id, tenant_id, instance_id, step_id, field_id,
+                    old_value, new_value, changed_by, changed_at
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+                "#,
+                change.id().as_uuid(),
+                tenant_id.as_uuid(),
+                change.instance_id().as_uuid(),
+                change.step_id().as_uuid(),
+                change.field_id(),
+                change.old_value(),
+                change.new_value(),
+                change.changed_by().as_uuid(),
+                change.changed_at()
+            )
+            .execute(tx.conn())
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%instance_id, %tenant_id))]
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowFormDataChange>, InfraError> {
+        let rows = sqlx::query_as!(
+            WorkflowFormDataChangeRow,
+            r#"
+            SELECT
+                id, tenant_id, instance_id, step_id, field_id,
+                old_value, new_value, changed_by, changed_at
+            FROM workflow_form_data_changes
+            WHERE instance_id = $1 AND tenant_id = $2
+            ORDER BY changed_at ASC, id ASC
+            "#,
+            instance_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(WorkflowFormDataChange::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// トレイトオブジェクトとして使用できることを確認
+    #[test]
+    fn test_トレイトはsendとsyncを実装している() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Box<dyn WorkflowFormDataChangeRepository>>();
+    }
+}
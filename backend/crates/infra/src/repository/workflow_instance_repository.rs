@@ -27,6 +27,7 @@ use ringiflow_domain::{
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use super::keyset_cursor::{KeysetCursor, KeysetPage, paginate};
 use crate::{db::TxContext, error::InfraError};
 
 /// ワークフローインスタンスリポジトリトレイト
@@ -113,22 +114,39 @@ pub trait WorkflowInstanceRepository: Send + Sync {
         tenant_id: &TenantId,
     ) -> Result<Vec<WorkflowInstance>, InfraError>;
 
-    /// 申請者によるインスタンス一覧を取得
+    /// 申請者によるインスタンス一覧を 1 ページ分取得
+    ///
+    /// 作成日時の降順（同時刻は ID の降順）で並べ、キーセットページネーションで取得する。
     ///
     /// # 引数
     ///
     /// - `tenant_id`: テナント ID
     /// - `user_id`: ユーザー ID
+    /// - `cursor`: 前ページの `next_cursor`（先頭ページは `None`）
+    /// - `limit`: 1 ページあたりの件数
     ///
     /// # 戻り値
     ///
-    /// - `Ok(Vec<WorkflowInstance>)`: インスタンス一覧
+    /// - `Ok(KeysetPage<WorkflowInstance>)`: インスタンス一覧と次ページのカーソル
+    /// - `Err(InvalidInput)`: カーソルが不正な場合
     /// - `Err(_)`: データベースエラー
     async fn find_by_initiated_by(
         &self,
         tenant_id: &TenantId,
         user_id: &UserId,
-    ) -> Result<Vec<WorkflowInstance>, InfraError>;
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<KeysetPage<WorkflowInstance>, InfraError>;
+
+    /// 申請者・ステータスごとのインスタンス数を取得
+    ///
+    /// ダッシュボードの集計に使用する。
+    async fn count_by_initiated_by(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        status: WorkflowInstanceStatus,
+    ) -> Result<i64, InfraError>;
 
     /// 複数 ID によるインスタンス一覧を取得
     ///
@@ -167,6 +185,44 @@ pub trait WorkflowInstanceRepository: Send + Sync {
         display_number: DisplayNumber,
         tenant_id: &TenantId,
     ) -> Result<Option<WorkflowInstance>, InfraError>;
+
+    /// 下書きのインスタンスを物理削除する
+    ///
+    /// 下書き状態の行のみを削除対象とし、並行して申請された場合は削除しない。
+    /// 子テーブル（アクティビティ・ウォッチャー等）は外部キーの
+    /// `ON DELETE CASCADE` で削除される。
+    ///
+    /// # 引数
+    ///
+    /// - `tx`: トランザクションコンテキスト（構造的強制）
+    /// - `id`: ワークフローインスタンス ID
+    /// - `tenant_id`: テナント ID（RLS 二重防御）
+    ///
+    /// # 戻り値
+    ///
+    /// - `Ok(true)`: 削除した場合
+    /// - `Ok(false)`: 下書きのインスタンスが存在しなかった場合
+    /// - `Err(_)`: データベースエラー
+    async fn delete_draft(
+        &self,
+        tx: &mut TxContext,
+        id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<bool, InfraError>;
+
+    /// 一定期間更新されていない下書きを全テナント横断で取得
+    ///
+    /// 下書きの自動パージに使用する。更新日時の古い順に返す。
+    ///
+    /// # 引数
+    ///
+    /// - `updated_before`: この日時より前に最終更新された下書きを対象とする
+    /// - `limit`: 取得件数の上限
+    async fn find_stale_drafts(
+        &self,
+        updated_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WorkflowInstance>, InfraError>;
 }
 
 /// DB の workflow_instances テーブルの行を表す中間構造体
@@ -185,6 +241,7 @@ struct WorkflowInstanceRow {
     version: i32,
     current_step_id: Option<String>,
     initiated_by: Uuid,
+    proxy_submitted_by: Option<Uuid>,
     submitted_at: Option<DateTime<Utc>>,
     completed_at: Option<DateTime<Utc>>,
     created_at: DateTime<Utc>,
@@ -213,6 +270,7 @@ impl TryFrom<WorkflowInstanceRow> for WorkflowInstance {
                 .map_err(|e| InfraError::unexpected(e.to_string()))?,
             current_step_id: row.current_step_id,
             initiated_by: UserId::from_uuid(row.initiated_by),
+            proxy_submitted_by: row.proxy_submitted_by.map(UserId::from_uuid),
             submitted_at: row.submitted_at,
             completed_at: row.completed_at,
             created_at: row.created_at,
@@ -249,10 +307,10 @@ impl WorkflowInstanceRepository for PostgresWorkflowInstanceRepository {
             INSERT INTO workflow_instances (
                 id, tenant_id, definition_id, definition_version,
                 display_number, title, form_data, status, version,
-                current_step_id, initiated_by, submitted_at,
+                current_step_id, initiated_by, proxy_submitted_by, submitted_at,
                 completed_at, created_at, updated_at
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
             "#,
             instance.id().as_uuid(),
             instance.tenant_id().as_uuid(),
@@ -265,6 +323,7 @@ impl WorkflowInstanceRepository for PostgresWorkflowInstanceRepository {
             instance.version().as_i32(),
             instance.current_step_id(),
             instance.initiated_by().as_uuid(),
+            instance.proxy_submitter().map(|id| *id.as_uuid()),
             instance.submitted_at(),
             instance.completed_at(),
             instance.created_at(),
@@ -335,7 +394,7 @@ impl WorkflowInstanceRepository for PostgresWorkflowInstanceRepository {
             SELECT
                 id, tenant_id, definition_id, definition_version,
                 display_number, title, form_data, status, version,
-                current_step_id, initiated_by, submitted_at,
+                current_step_id, initiated_by, proxy_submitted_by, submitted_at,
                 completed_at, created_at, updated_at
             FROM workflow_instances
             WHERE id = $1 AND tenant_id = $2
@@ -360,7 +419,7 @@ impl WorkflowInstanceRepository for PostgresWorkflowInstanceRepository {
             SELECT
                 id, tenant_id, definition_id, definition_version,
                 display_number, title, form_data, status, version,
-                current_step_id, initiated_by, submitted_at,
+                current_step_id, initiated_by, proxy_submitted_by, submitted_at,
                 completed_at, created_at, updated_at
             FROM workflow_instances
             WHERE tenant_id = $1
@@ -379,26 +438,68 @@ impl WorkflowInstanceRepository for PostgresWorkflowInstanceRepository {
         &self,
         tenant_id: &TenantId,
         user_id: &UserId,
-    ) -> Result<Vec<WorkflowInstance>, InfraError> {
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<KeysetPage<WorkflowInstance>, InfraError> {
+        let cursor = cursor.map(KeysetCursor::decode).transpose()?;
+
+        // 次ページの有無を判定するため 1 件多く取得する
         let rows = sqlx::query_as!(
             WorkflowInstanceRow,
             r#"
             SELECT
                 id, tenant_id, definition_id, definition_version,
                 display_number, title, form_data, status, version,
-                current_step_id, initiated_by, submitted_at,
+                current_step_id, initiated_by, proxy_submitted_by, submitted_at,
                 completed_at, created_at, updated_at
             FROM workflow_instances
             WHERE tenant_id = $1 AND initiated_by = $2
-            ORDER BY created_at DESC
+              AND ($3::timestamptz IS NULL OR (created_at, id) < ($3, $4::uuid))
+            ORDER BY created_at DESC, id DESC
+            LIMIT $5
             "#,
             tenant_id.as_uuid(),
-            user_id.as_uuid()
+            user_id.as_uuid(),
+            cursor.as_ref().map(|c| c.created_at),
+            cursor.as_ref().map(|c| c.id),
+            limit + 1
         )
         .fetch_all(&self.pool)
         .await?;
 
-        rows.into_iter().map(WorkflowInstance::try_from).collect()
+        let (rows, next_cursor) =
+            paginate(rows, limit, |row| KeysetCursor::new(row.created_at, row.id));
+
+        Ok(KeysetPage {
+            items: rows
+                .into_iter()
+                .map(WorkflowInstance::try_from)
+                .collect::<Result<_, _>>()?,
+            next_cursor,
+        })
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id, %status))]
+    async fn count_by_initiated_by(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        status: WorkflowInstanceStatus,
+    ) -> Result<i64, InfraError> {
+        let count = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) AS "count!"
+            FROM workflow_instances
+            WHERE tenant_id = $1 AND initiated_by = $2 AND status = $3
+            "#,
+            tenant_id.as_uuid(),
+            user_id.as_uuid(),
+            status.to_string()
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
     }
 
     #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id))]
@@ -419,7 +520,7 @@ impl WorkflowInstanceRepository for PostgresWorkflowInstanceRepository {
             SELECT
                 id, tenant_id, definition_id, definition_version,
                 display_number, title, form_data, status, version,
-                current_step_id, initiated_by, submitted_at,
+                current_step_id, initiated_by, proxy_submitted_by, submitted_at,
                 completed_at, created_at, updated_at
             FROM workflow_instances
             WHERE id = ANY($1) AND tenant_id = $2
@@ -446,7 +547,7 @@ impl WorkflowInstanceRepository for PostgresWorkflowInstanceRepository {
             SELECT
                 id, tenant_id, definition_id, definition_version,
                 display_number, title, form_data, status, version,
-                current_step_id, initiated_by, submitted_at,
+                current_step_id, initiated_by, proxy_submitted_by, submitted_at,
                 completed_at, created_at, updated_at
             FROM workflow_instances
             WHERE display_number = $1 AND tenant_id = $2
@@ -459,6 +560,55 @@ impl WorkflowInstanceRepository for PostgresWorkflowInstanceRepository {
 
         row.map(WorkflowInstance::try_from).transpose()
     }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id, %tenant_id))]
+    async fn delete_draft(
+        &self,
+        tx: &mut TxContext,
+        id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<bool, InfraError> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM workflow_instances
+            WHERE id = $1 AND tenant_id = $2 AND status = 'draft'
+            "#,
+            id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .execute(tx.conn())
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%updated_before, limit))]
+    async fn find_stale_drafts(
+        &self,
+        updated_before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WorkflowInstance>, InfraError> {
+        let rows = sqlx::query_as!(
+            WorkflowInstanceRow,
+            r#"
+            SELECT
+                id, tenant_id, definition_id, definition_version,
+                display_number, title, form_data, status, version,
+                current_step_id, initiated_by, proxy_submitted_by, submitted_at,
+                completed_at, created_at, updated_at
+            FROM workflow_instances
+            WHERE status = 'draft' AND updated_at < $1
+            ORDER BY updated_at ASC, id ASC
+            LIMIT $2
+            "#,
+            updated_before,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(WorkflowInstance::try_from).collect()
+    }
 }
 
 // =============================================================================
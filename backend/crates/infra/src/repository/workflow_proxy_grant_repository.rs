@@ -0,0 +1,199 @@
+//! # WorkflowProxyGrantRepository
+//!
+//! 代理申請の代理権限の永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **テナント分離**: すべてのクエリでテナント ID を考慮
+//! - **冪等性**: 付与済みの代理権限の再付与、未付与の代理権限の取り消しはいずれも成功扱い
+//!
+//! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{tenant::TenantId, user::UserId, workflow::ProxyGrant};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::InfraError;
+
+/// 代理権限リポジトリトレイト
+#[async_trait]
+pub trait WorkflowProxyGrantRepository: Send + Sync {
+    /// 代理権限を付与する（付与済みの場合は何もしない）
+    async fn insert(&self, grant: &ProxyGrant, tenant_id: &TenantId) -> Result<(), InfraError>;
+
+    /// 代理権限を取り消す（未付与の場合は何もしない）
+    async fn delete(
+        &self,
+        principal_id: &UserId,
+        proxy_id: &UserId,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// 代理人が本人から代理権限を付与されているか
+    async fn exists(
+        &self,
+        principal_id: &UserId,
+        proxy_id: &UserId,
+        tenant_id: &TenantId,
+    ) -> Result<bool, InfraError>;
+
+    /// 本人が付与した代理権限を取得する（created_at ASC）
+    async fn find_by_principal(
+        &self,
+        principal_id: &UserId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<ProxyGrant>, InfraError>;
+
+    /// 代理人が付与されている代理権限を取得する（created_at ASC）
+    async fn find_by_proxy(
+        &self,
+        proxy_id: &UserId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<ProxyGrant>, InfraError>;
+}
+
+/// DB の workflow_proxy_grants テーブルの行を表す中間構造体
+struct ProxyGrantRow {
+    principal_id: Uuid,
+    proxy_id:     Uuid,
+    created_at:   DateTime<Utc>,
+}
+
+impl From<ProxyGrantRow> for ProxyGrant {
+    fn from(row: ProxyGrantRow) -> Self {
+        ProxyGrant::from_db(
+            UserId::from_uuid(row.principal_id),
+            UserId::from_uuid(row.proxy_id),
+            row.created_at,
+        )
+    }
+}
+
+/// PostgreSQL 実装の WorkflowProxyGrantRepository
+#[derive(Debug, Clone)]
+pub struct PostgresWorkflowProxyGrantRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowProxyGrantRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkflowProxyGrantRepository for PostgresWorkflowProxyGrantRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(principal_id = %grant.principal_id(), %tenant_id))]
+    async fn insert(&self, grant: &ProxyGrant, tenant_id: &TenantId) -> Result<(), InfraError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO workflow_proxy_grants (tenant_id, principal_id, proxy_id, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (principal_id, proxy_id) DO NOTHING
+            "#,
+            tenant_id.as_uuid(),
+            grant.principal_id().as_uuid(),
+            grant.proxy_id().as_uuid(),
+            grant.created_at()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%principal_id, %tenant_id))]
+    async fn delete(
+        &self,
+        principal_id: &UserId,
+        proxy_id: &UserId,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM workflow_proxy_grants
+            WHERE principal_id = $1 AND proxy_id = $2 AND tenant_id = $3
+            "#,
+            principal_id.as_uuid(),
+            proxy_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%principal_id, %proxy_id, %tenant_id))]
+    async fn exists(
+        &self,
+        principal_id: &UserId,
+        proxy_id: &UserId,
+        tenant_id: &TenantId,
+    ) -> Result<bool, InfraError> {
+        let exists = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM workflow_proxy_grants
+                WHERE principal_id = $1 AND proxy_id = $2 AND tenant_id = $3
+            ) AS "exists!"
+            "#,
+            principal_id.as_uuid(),
+            proxy_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%principal_id, %tenant_id))]
+    async fn find_by_principal(
+        &self,
+        principal_id: &UserId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<ProxyGrant>, InfraError> {
+        let rows = sqlx::query_as!(
+            ProxyGrantRow,
+            r#"
+            SELECT principal_id, proxy_id, created_at
+            FROM workflow_proxy_grants
+            WHERE principal_id = $1 AND tenant_id = $2
+            ORDER BY created_at ASC, proxy_id ASC
+            "#,
+            principal_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(ProxyGrant::from).collect())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%proxy_id, %tenant_id))]
+    async fn find_by_proxy(
+        &self,
+        proxy_id: &UserId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<ProxyGrant>, InfraError> {
+        let rows = sqlx::query_as!(
+            ProxyGrantRow,
+            r#"
+            SELECT principal_id, proxy_id, created_at
+            FROM workflow_proxy_grants
+            WHERE proxy_id = $1 AND tenant_id = $2
+            ORDER BY created_at ASC, principal_id ASC
+            "#,
+            proxy_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(ProxyGrant::from).collect())
+    }
+}
@@ -0,0 +1,410 @@
+//! # WorkflowScheduleRepository
+//!
+//! ワークフロースケジュールの永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **RLS 二重防御**: WHERE 句で明示的にテナント条件を指定
+//! - **繰り返し設定**: `recurrence_type` で種別を持ち、種別ごとのカラム
+//!   （`day_of_month` / `run_hour` / `run_minute` または `cron_expression`）に展開する。
+//!   実行時刻を解釈するタイムゾーンは `time_zone` に IANA タイムゾーン名で保持する
+//! - **テナント横断の取り出し**: スケジューラはシステム処理として全テナントの実行待ちを扱う
+//! - **排他制御**: `FOR UPDATE SKIP LOCKED` で行ロックを取得し、複数のスケジューラが同じ
+//!   スケジュールを同時に実行しないようにする。ロックは実行結果の記録と同じトランザクションで保持する
+//!
+//! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    tenant::TenantId,
+    time_zone::TimeZone,
+    user::UserId,
+    workflow::{
+        CronExpression,
+        MonthlyRecurrence,
+        Recurrence,
+        ScheduledApprover,
+        WorkflowDefinitionId,
+        WorkflowSchedule,
+        WorkflowScheduleId,
+        WorkflowScheduleName,
+        WorkflowScheduleRecord,
+    },
+};
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{db::TxContext, error::InfraError};
+
+/// ワークフロースケジュールリポジトリトレイト
+#[async_trait]
+pub trait WorkflowScheduleRepository: Send + Sync {
+    /// 所有者のスケジュールを作成日時順で取得する
+    async fn find_by_owner(
+        &self,
+        tenant_id: &TenantId,
+        owner_id: &UserId,
+    ) -> Result<Vec<WorkflowSchedule>, InfraError>;
+
+    /// ID でスケジュールを検索する
+    async fn find_by_id(
+        &self,
+        id: &WorkflowScheduleId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<WorkflowSchedule>, InfraError>;
+
+    /// スケジュールを挿入する
+    async fn insert(&self, schedule: &WorkflowSchedule) -> Result<(), InfraError>;
+
+    /// スケジュールを更新する
+    async fn update(&self, schedule: &WorkflowSchedule) -> Result<(), InfraError>;
+
+    /// スケジュールを削除する（作成済みのワークフローは残る）
+    async fn delete(&self, id: &WorkflowScheduleId, tenant_id: &TenantId)
+    -> Result<(), InfraError>;
+
+    /// 実行日時に達したスケジュールをロックして取得する（next_run_at ASC）
+    ///
+    /// 有効かつ `next_run_at` が `now` 以前のスケジュールを最大 `limit` 件返す。
+    /// 他のトランザクションがロック中のスケジュールはスキップする。
+    async fn lock_due(
+        &self,
+        tx: &mut TxContext,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WorkflowSchedule>, InfraError>;
+
+    /// 実行結果（次回実行日時・最終実行日時・有効フラグ）を記録する
+    async fn record_run(
+        &self,
+        tx: &mut TxContext,
+        schedule: &WorkflowSchedule,
+    ) -> Result<(), InfraError>;
+}
+
+/// DB の workflow_schedules テーブルの行を表す中間構造体
+struct WorkflowScheduleRow {
+    id: Uuid,
+    tenant_id: Uuid,
+    owner_id: Uuid,
+    definition_id: Uuid,
+    name: String,
+    title: String,
+    form_data: JsonValue,
+    recurrence_type: String,
+    day_of_month: Option<i16>,
+    run_hour: Option<i16>,
+    run_minute: Option<i16>,
+    cron_expression: Option<String>,
+    time_zone: String,
+    auto_submit: bool,
+    approvers: JsonValue,
+    is_active: bool,
+    next_run_at: Option<DateTime<Utc>>,
+    last_run_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<WorkflowScheduleRow> for WorkflowSchedule {
+    type Error = InfraError;
+
+    fn try_from(row: WorkflowScheduleRow) -> Result<Self, Self::Error> {
+        let invalid = |e: ringiflow_domain::DomainError| {
+            InfraError::unexpected(format!("不正なワークフロースケジュールデータ: {}", e))
+        };
+        let recurrence = match (
+            row.recurrence_type.as_str(),
+            row.day_of_month,
+            row.run_hour,
+            row.run_minute,
+            row.cron_expression.as_deref(),
+        ) {
+            ("monthly", Some(day), Some(hour), Some(minute), None) => Recurrence::Monthly(
+                MonthlyRecurrence::new(day as u32, hour as u32, minute as u32).map_err(invalid)?,
+            ),
+            ("cron", None, None, None, Some(expression)) => {
+                Recurrence::Cron(CronExpression::parse(expression).map_err(invalid)?)
+            }
+            (recurrence_type, ..) => {
+                return Err(InfraError::unexpected(format!(
+                    "不正な繰り返し設定: {}",
+                    recurrence_type
+                )));
+            }
+        };
+
+        Ok(WorkflowSchedule::from_db(WorkflowScheduleRecord {
+            id: WorkflowScheduleId::from_uuid(row.id),
+            tenant_id: TenantId::from_uuid(row.tenant_id),
+            owner_id: UserId::from_uuid(row.owner_id),
+            definition_id: WorkflowDefinitionId::from_uuid(row.definition_id),
+            name: WorkflowScheduleName::new(row.name).map_err(invalid)?,
+            title: row.title,
+            form_data: row.form_data,
+            recurrence,
+            time_zone: row.time_zone.parse::<TimeZone>().map_err(invalid)?,
+            auto_submit: row.auto_submit,
+            approvers: serde_json::from_value::<Vec<ScheduledApprover>>(row.approvers)?,
+            is_active: row.is_active,
+            next_run_at: row.next_run_at,
+            last_run_at: row.last_run_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+}
+
+/// 繰り返し設定を DB のカラム値に展開する
+///
+/// `(day_of_month, run_hour, run_minute, cron_expression)` を返す。
+fn recurrence_columns(
+    recurrence: &Recurrence,
+) -> (Option<i16>, Option<i16>, Option<i16>, Option<&str>) {
+    match recurrence {
+        Recurrence::Monthly(monthly) => (
+            Some(monthly.day() as i16),
+            Some(monthly.hour() as i16),
+            Some(monthly.minute() as i16),
+            None,
+        ),
+        Recurrence::Cron(cron) => (None, None, None, Some(cron.as_str())),
+    }
+}
+
+/// PostgreSQL 実装の WorkflowScheduleRepository
+#[derive(Debug, Clone)]
+pub struct PostgresWorkflowScheduleRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowScheduleRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkflowScheduleRepository for PostgresWorkflowScheduleRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %owner_id))]
+    async fn find_by_owner(
+        &self,
+        tenant_id: &TenantId,
+        owner_id: &UserId,
+    ) -> Result<Vec<WorkflowSchedule>, InfraError> {
+        let rows = sqlx::query_as!(
+            WorkflowScheduleRow,
+            r#"
+            SELECT
+                id, tenant_id, owner_id, definition_id, name, title, form_data,
+                recurrence_type, day_of_month, run_hour, run_minute, cron_expression,
+                time_zone, auto_submit, approvers, is_active, next_run_at, last_run_at,
+                created_at, updated_at
+            FROM workflow_schedules
+            WHERE tenant_id = $1 AND owner_id = $2
+            ORDER BY created_at ASC, id ASC
+            "#,
+            tenant_id.as_uuid(),
+            owner_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(WorkflowSchedule::try_from).collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id, %tenant_id))]
+    async fn find_by_id(
+        &self,
+        id: &WorkflowScheduleId,
+        tenant_id: &TenantId,
+    ) -> Result<Option<WorkflowSchedule>, InfraError> {
+        let row = sqlx::query_as!(
+            WorkflowScheduleRow,
+            r#"
+            SELECT
+                id, tenant_id, owner_id, definition_id, name, title, form_data,
+                recurrence_type, day_of_month, run_hour, run_minute, cron_expression,
+                time_zone, auto_submit, approvers, is_active, next_run_at, last_run_at,
+                created_at, updated_at
+            FROM workflow_schedules
+            WHERE id = $1 AND tenant_id = $2
+            "#,
+            id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(WorkflowSchedule::try_from).transpose()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(id = %schedule.id()))]
+    async fn insert(&self, schedule: &WorkflowSchedule) -> Result<(), InfraError> {
+        let (day_of_month, run_hour, run_minute, cron_expression) =
+            recurrence_columns(schedule.recurrence());
+        let approvers = serde_json::to_value(schedule.approvers())?;
+        sqlx::query!(
+            r#"
+            INSERT INTO workflow_schedules (
+                id, tenant_id, owner_id, definition_id, name, title, form_data,
+                recurrence_type, day_of_month, run_hour, run_minute, cron_expression,
+                time_zone, auto_submit, approvers, is_active, next_run_at, last_run_at,
+                created_at, updated_at
+            )
+            VALUES (
+                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10,
+                $11, $12, $13, $14, $15, $16, $17, $18, $19, $20
+            )
+            "#,
+            schedule.id().as_uuid(),
+            schedule.tenant_id().as_uuid(),
+            schedule.owner_id().as_uuid(),
+            schedule.definition_id().as_uuid(),
+            schedule.name().as_str(),
+            schedule.title(),
+            schedule.form_data(),
+            schedule.recurrence().kind(),
+            day_of_month,
+            run_hour,
+            run_minute,
+            cron_expression,
+            schedule.time_zone().as_str(),
+            schedule.auto_submit(),
+            approvers,
+            schedule.is_active(),
+            schedule.next_run_at(),
+            schedule.last_run_at(),
+            schedule.created_at(),
+            schedule.updated_at()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(id = %schedule.id()))]
+    async fn update(&self, schedule: &WorkflowSchedule) -> Result<(), InfraError> {
+        let (day_of_month, run_hour, run_minute, cron_expression) =
+            recurrence_columns(schedule.recurrence());
+        let approvers = serde_json::to_value(schedule.approvers())?;
+        sqlx::query!(
+            r#"
+            UPDATE workflow_schedules
+            SET name = $3, title = $4, form_data = $5,
+                recurrence_type = $6, day_of_month = $7, run_hour = $8, run_minute = $9,
+                cron_expression = $10, time_zone = $11, auto_submit = $12, approvers = $13,
+                is_active = $14, next_run_at = $15, updated_at = $16
+            WHERE id = $1 AND tenant_id = $2
+            "#,
+            schedule.id().as_uuid(),
+            schedule.tenant_id().as_uuid(),
+            schedule.name().as_str(),
+            schedule.title(),
+            schedule.form_data(),
+            schedule.recurrence().kind(),
+            day_of_month,
+            run_hour,
+            run_minute,
+            cron_expression,
+            schedule.time_zone().as_str(),
+            schedule.auto_submit(),
+            approvers,
+            schedule.is_active(),
+            schedule.next_run_at(),
+            schedule.updated_at()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%id, %tenant_id))]
+    async fn delete(
+        &self,
+        id: &WorkflowScheduleId,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            "DELETE FROM workflow_schedules WHERE id = $1 AND tenant_id = $2",
+            id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(limit))]
+    async fn lock_due(
+        &self,
+        tx: &mut TxContext,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<WorkflowSchedule>, InfraError> {
+        let rows = sqlx::query_as!(
+            WorkflowScheduleRow,
+            r#"
+            SELECT
+                id, tenant_id, owner_id, definition_id, name, title, form_data,
+                recurrence_type, day_of_month, run_hour, run_minute, cron_expression,
+                time_zone, auto_submit, approvers, is_active, next_run_at, last_run_at,
+                created_at, updated_at
+            FROM workflow_schedules
+            WHERE is_active AND next_run_at <= $1
+            ORDER BY next_run_at ASC, id ASC
+            LIMIT $2
+            FOR UPDATE SKIP LOCKED
+            "#,
+            now,
+            limit
+        )
+        .fetch_all(tx.conn())
+        .await?;
+
+        rows.into_iter().map(WorkflowSchedule::try_from).collect()
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(id = %schedule.id()))]
+    async fn record_run(
+        &self,
+        tx: &mut TxContext,
+        schedule: &WorkflowSchedule,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            r#"
+            UPDATE workflow_schedules
+            SET is_active = $3, next_run_at = $4, last_run_at = $5, updated_at = $6
+            WHERE id = $1 AND tenant_id = $2
+            "#,
+            schedule.id().as_uuid(),
+            schedule.tenant_id().as_uuid(),
+            schedule.is_active(),
+            schedule.next_run_at(),
+            schedule.last_run_at(),
+            schedule.updated_at()
+        )
+        .execute(tx.conn())
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// トレイトオブジェクトとして使用できることを確認
+    #[test]
+    fn test_トレイトはsendとsyncを実装している() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Box<dyn WorkflowScheduleRepository>>();
+    }
+}
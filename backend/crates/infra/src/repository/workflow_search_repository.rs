@@ -0,0 +1,237 @@
+//! # WorkflowSearchRepository
+//!
+//! ワークフローインスタンスの全文検索・ファセット検索を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **検索専用**: 条件に一致するインスタンスの ID とカーソルのみを返す。
+//!   インスタンス本体は `WorkflowInstanceRepository::find_by_ids` で取得する
+//! - **全文検索**: 件名・フォームデータの値・コメント本文の `search_vector`（GIN
+//!   インデックス）で検索する。日本語の部分一致は pg_trgm インデックスを使った
+//!   ILIKE で補う
+//! - **閲覧権限**: 閲覧者が閲覧できるインスタンスのみを返す（規則は
+//!   [`WorkflowViewer`](ringiflow_domain::workflow::WorkflowViewer) と同じ）
+//! - **キーセットページネーション**: `(created_at DESC, id DESC)`
+//!   で並べ、[`KeysetCursor`] で次ページを指定する
+//!
+//! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    tenant::TenantId,
+    user::UserId,
+    value_objects::DisplayNumber,
+    workflow::{WorkflowDefinitionId, WorkflowInstanceId, WorkflowInstanceStatus},
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::keyset_cursor::{KeysetCursor, paginate};
+use crate::error::InfraError;
+
+/// ワークフロー検索条件
+///
+/// 指定した条件はすべて AND で結合する。`Vec` の条件は空なら絞り込まない。
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowSearchCriteria {
+    /// 検索キーワード（件名・フォームデータの値・コメント本文）
+    pub keyword: Option<String>,
+    /// キーワードが表示用 ID（`WF-42` / `42`）と解釈できる場合の表示用連番
+    ///
+    /// キーワードの全文検索結果に加えて、この連番のインスタンスも一致とみなす。
+    pub keyword_display_number: Option<DisplayNumber>,
+    /// ワークフロー定義
+    pub definition_ids: Vec<WorkflowDefinitionId>,
+    /// ステータス
+    pub statuses: Vec<WorkflowInstanceStatus>,
+    /// 作成日時の下限（以上）
+    pub created_from: Option<DateTime<Utc>>,
+    /// 作成日時の上限（未満）
+    pub created_to: Option<DateTime<Utc>>,
+    /// 申請者
+    pub initiated_by: Option<UserId>,
+    /// 現在の承認者（アクティブなステップの担当者）
+    pub current_approver: Option<UserId>,
+    /// 検索するユーザー（閲覧権限の判定と、他人の下書きの除外に使用）
+    pub viewer_id: UserId,
+    /// 検索するユーザーがワークフロー管理者か
+    ///
+    /// 管理者は関与していないインスタンスも閲覧できる（機密定義のインスタンスを除く）。
+    pub viewer_is_workflow_admin: bool,
+    /// 他人の下書きも結果に含めるか（テナント管理者向け）
+    pub include_all_drafts: bool,
+}
+
+/// ワークフロー検索結果の 1 ページ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowSearchPage {
+    /// 一致したインスタンスの ID（作成日時の降順）
+    pub ids:         Vec<WorkflowInstanceId>,
+    /// 次ページのカーソル（最後のページの場合は `None`）
+    pub next_cursor: Option<String>,
+}
+
+/// ワークフロー検索リポジトリトレイト
+#[async_trait]
+pub trait WorkflowSearchRepository: Send + Sync {
+    /// 条件に一致するインスタンスを作成日時の降順で検索する
+    ///
+    /// ## エラー
+    ///
+    /// - `InvalidInput`: カーソルが不正な場合
+    async fn search(
+        &self,
+        tenant_id: &TenantId,
+        criteria: &WorkflowSearchCriteria,
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<WorkflowSearchPage, InfraError>;
+}
+
+/// PostgreSQL 実装の WorkflowSearchRepository
+#[derive(Debug, Clone)]
+pub struct PostgresWorkflowSearchRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowSearchRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// ILIKE の部分一致パターンを作る（`%` `_` `\` はエスケープする）
+fn contains_pattern(keyword: &str) -> String {
+    let escaped = keyword
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
+
+#[async_trait]
+impl WorkflowSearchRepository for PostgresWorkflowSearchRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, limit))]
+    async fn search(
+        &self,
+        tenant_id: &TenantId,
+        criteria: &WorkflowSearchCriteria,
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<WorkflowSearchPage, InfraError> {
+        let cursor = cursor.map(KeysetCursor::decode).transpose()?;
+        let definition_ids: Vec<Uuid> = criteria
+            .definition_ids
+            .iter()
+            .map(|id| *id.as_uuid())
+            .collect();
+        let statuses: Vec<String> = criteria.statuses.iter().map(|s| s.to_string()).collect();
+        let keyword_pattern = criteria.keyword.as_deref().map(contains_pattern);
+
+        // 次ページの有無を判定するため 1 件多く取得する
+        let rows = sqlx::query!(
+            r#"
+            SELECT wi.id, wi.created_at
+            FROM workflow_instances wi
+            WHERE wi.tenant_id = $1
+              AND ($15::bool OR wi.status <> 'draft' OR wi.initiated_by = $2 OR wi.proxy_submitted_by = $2)
+              AND (wi.initiated_by = $2
+                  OR wi.proxy_submitted_by = $2
+                  OR EXISTS (
+                      SELECT 1 FROM workflow_steps ws
+                      WHERE ws.instance_id = wi.id
+                        AND ws.tenant_id = wi.tenant_id
+                        AND ws.assigned_to = $2
+                  )
+                  OR EXISTS (
+                      SELECT 1 FROM workflow_watchers ww
+                      WHERE ww.instance_id = wi.id
+                        AND ww.tenant_id = wi.tenant_id
+                        AND ww.user_id = $2
+                  )
+                  OR ($16::bool AND EXISTS (
+                      SELECT 1 FROM workflow_definitions wd
+                      WHERE wd.id = wi.definition_id
+                        AND wd.tenant_id = wi.tenant_id
+                        AND NOT wd.confidential
+                  )))
+              AND (cardinality($3::uuid[]) = 0 OR wi.definition_id = ANY($3))
+              AND (cardinality($4::text[]) = 0 OR wi.status = ANY($4))
+              AND ($5::timestamptz IS NULL OR wi.created_at >= $5)
+              AND ($6::timestamptz IS NULL OR wi.created_at < $6)
+              AND ($7::uuid IS NULL OR wi.initiated_by = $7)
+              AND ($8::uuid IS NULL OR EXISTS (
+                  SELECT 1 FROM workflow_steps ws
+                  WHERE ws.instance_id = wi.id
+                    AND ws.tenant_id = wi.tenant_id
+                    AND ws.status = 'active'
+                    AND ws.assigned_to = $8
+              ))
+              AND ($9::text IS NULL
+                  OR wi.search_vector @@ websearch_to_tsquery('simple', $9)
+                  OR wi.title ILIKE $10
+                  OR wi.display_number = $11
+                  OR EXISTS (
+                      SELECT 1 FROM workflow_comments wc
+                      WHERE wc.instance_id = wi.id
+                        AND wc.tenant_id = wi.tenant_id
+                        AND wc.deleted_at IS NULL
+                        AND (wc.search_vector @@ websearch_to_tsquery('simple', $9)
+                             OR wc.body ILIKE $10)
+                  ))
+              AND ($12::timestamptz IS NULL OR (wi.created_at, wi.id) < ($12, $13::uuid))
+            ORDER BY wi.created_at DESC, wi.id DESC
+            LIMIT $14
+            "#,
+            tenant_id.as_uuid(),
+            criteria.viewer_id.as_uuid(),
+            &definition_ids,
+            &statuses,
+            criteria.created_from,
+            criteria.created_to,
+            criteria.initiated_by.as_ref().map(|id| *id.as_uuid()),
+            criteria.current_approver.as_ref().map(|id| *id.as_uuid()),
+            criteria.keyword.as_deref(),
+            keyword_pattern,
+            criteria.keyword_display_number.map(|n| n.as_i64()),
+            cursor.as_ref().map(|c| c.created_at),
+            cursor.as_ref().map(|c| c.id),
+            limit + 1,
+            criteria.include_all_drafts,
+            criteria.viewer_is_workflow_admin
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let (rows, next_cursor) =
+            paginate(rows, limit, |row| KeysetCursor::new(row.created_at, row.id));
+
+        Ok(WorkflowSearchPage {
+            ids: rows
+                .into_iter()
+                .map(|row| WorkflowInstanceId::from_uuid(row.id))
+                .collect(),
+            next_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// トレイトオブジェクトとして使用できることを確認
+    #[test]
+    fn test_トレイトはsendとsyncを実装している() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Box<dyn WorkflowSearchRepository>>();
+    }
+
+    #[test]
+    fn test_部分一致パターンはワイルドカードをエスケープする() {
+        assert_eq!(contains_pattern("経費"), "%経費%");
+        assert_eq!(contains_pattern("100%_off\\"), "%100\\%\\_off\\\\%");
+    }
+}
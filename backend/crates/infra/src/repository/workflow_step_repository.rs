@@ -20,6 +20,7 @@ use ringiflow_domain::{
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use super::keyset_cursor::{KeysetCursor, KeysetPage, paginate};
 use crate::{db::TxContext, error::InfraError};
 
 /// WorkflowStepRepository トレイト
@@ -66,12 +67,32 @@ pub trait WorkflowStepRepository: Send + Sync {
         tenant_id: &TenantId,
     ) -> Result<Vec<WorkflowStep>, InfraError>;
 
-    /// 担当者でステップ一覧を取得する（タスク一覧用）
-    async fn find_by_assigned_to(
+    /// 担当者のアクティブなステップを 1 ページ分取得する（タスク一覧用）
+    ///
+    /// 作成日時の降順（同時刻は ID の降順）で並べ、キーセットページネーションで取得する。
+    /// カーソルが不正な場合は `InfraError::InvalidInput` を返す。
+    async fn find_active_by_assigned_to(
         &self,
         tenant_id: &TenantId,
         user_id: &UserId,
-    ) -> Result<Vec<WorkflowStep>, InfraError>;
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<KeysetPage<WorkflowStep>, InfraError>;
+
+    /// 担当者のアクティブなステップ数を取得する（ダッシュボード用）
+    async fn count_active_by_assigned_to(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<i64, InfraError>;
+
+    /// 担当者が指定日時以降に完了したステップ数を取得する（ダッシュボード用）
+    async fn count_completed_by_assigned_to_since(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<i64, InfraError>;
 
     /// 表示用連番でステップを検索する
     ///
@@ -218,7 +239,8 @@ impl WorkflowStepRepository for PostgresWorkflowStepRepository {
             comment = $4,
             started_at = $5,
             completed_at = $6,
-            updated_at = $7
+            updated_at = $7,
+            assigned_to = $11
          WHERE id = $8 AND version = $9 AND tenant_id = $10
          "#,
             status,
@@ -231,6 +253,7 @@ impl WorkflowStepRepository for PostgresWorkflowStepRepository {
             step.id().as_uuid(),
             expected_version.as_i32(),
             tenant_id.as_uuid(),
+            step.assigned_to().map(|u| *u.as_uuid()),
         )
         .execute(tx.conn())
         .await?;
@@ -299,11 +322,16 @@ impl WorkflowStepRepository for PostgresWorkflowStepRepository {
     }
 
     #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
-    async fn find_by_assigned_to(
+    async fn find_active_by_assigned_to(
         &self,
         tenant_id: &TenantId,
         user_id: &UserId,
-    ) -> Result<Vec<WorkflowStep>, InfraError> {
+        cursor: Option<&str>,
+        limit: i64,
+    ) -> Result<KeysetPage<WorkflowStep>, InfraError> {
+        let cursor = cursor.map(KeysetCursor::decode).transpose()?;
+
+        // 次ページの有無を判定するため 1 件多く取得する
         let rows = sqlx::query_as!(
             WorkflowStepRow,
             r#"
@@ -313,16 +341,75 @@ impl WorkflowStepRepository for PostgresWorkflowStepRepository {
             due_date, started_at, completed_at,
             created_at, updated_at
          FROM workflow_steps
-         WHERE tenant_id = $1 AND assigned_to = $2
-         ORDER BY created_at DESC
+         WHERE tenant_id = $1 AND assigned_to = $2 AND status = 'active'
+           AND ($3::timestamptz IS NULL OR (created_at, id) < ($3, $4::uuid))
+         ORDER BY created_at DESC, id DESC
+         LIMIT $5
          "#,
             tenant_id.as_uuid(),
-            user_id.as_uuid()
+            user_id.as_uuid(),
+            cursor.as_ref().map(|c| c.created_at),
+            cursor.as_ref().map(|c| c.id),
+            limit + 1
         )
         .fetch_all(&self.pool)
         .await?;
 
-        rows.into_iter().map(WorkflowStep::try_from).collect()
+        let (rows, next_cursor) =
+            paginate(rows, limit, |row| KeysetCursor::new(row.created_at, row.id));
+
+        Ok(KeysetPage {
+            items: rows
+                .into_iter()
+                .map(WorkflowStep::try_from)
+                .collect::<Result<_, _>>()?,
+            next_cursor,
+        })
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn count_active_by_assigned_to(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+    ) -> Result<i64, InfraError> {
+        let count = sqlx::query_scalar!(
+            r#"
+         SELECT COUNT(*) AS "count!"
+         FROM workflow_steps
+         WHERE tenant_id = $1 AND assigned_to = $2 AND status = 'active'
+         "#,
+            tenant_id.as_uuid(),
+            user_id.as_uuid()
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, %user_id))]
+    async fn count_completed_by_assigned_to_since(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<i64, InfraError> {
+        let count = sqlx::query_scalar!(
+            r#"
+         SELECT COUNT(*) AS "count!"
+         FROM workflow_steps
+         WHERE tenant_id = $1 AND assigned_to = $2
+           AND status = 'completed' AND completed_at >= $3
+         "#,
+            tenant_id.as_uuid(),
+            user_id.as_uuid(),
+            since
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
     }
 
     #[tracing::instrument(skip_all, level = "debug", fields(%display_number, %instance_id, %tenant_id))]
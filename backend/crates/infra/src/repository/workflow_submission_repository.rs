@@ -0,0 +1,211 @@
+//! # WorkflowSubmissionRepository
+//!
+//! 申請ラウンドごとのスナップショットの永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **テナント分離**: すべてのクエリでテナント ID を考慮
+//! - **追記のみ**: スナップショットは不変のため更新・削除メソッドを持たない
+//! - **トランザクション**: 申請・再申請（インスタンス・ステップ保存）と同一トランザクションで記録する
+//! - **添付ファイル**: メタデータの配列を JSONB カラムに格納する
+//!
+//! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    document::DocumentId,
+    tenant::TenantId,
+    user::UserId,
+    workflow::{
+        SubmissionAttachment,
+        WorkflowInstanceId,
+        WorkflowSubmission,
+        WorkflowSubmissionId,
+        WorkflowSubmissionRecord,
+    },
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{db::TxContext, error::InfraError};
+
+/// 申請スナップショットリポジトリトレイト
+#[async_trait]
+pub trait WorkflowSubmissionRepository: Send + Sync {
+    /// スナップショットを記録する
+    ///
+    /// `tx` はトランザクションコンテキスト（構造的強制）。
+    /// 同一インスタンス・同一ラウンドの重複は一意制約違反になる。
+    async fn insert(
+        &self,
+        tx: &mut TxContext,
+        submission: &WorkflowSubmission,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// インスタンス ID でスナップショットを取得する（round ASC）
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowSubmission>, InfraError>;
+}
+
+/// attachments カラムの JSON 要素
+#[derive(Serialize, Deserialize)]
+struct AttachmentJson {
+    document_id:  Uuid,
+    filename:     String,
+    content_type: String,
+    size:         i64,
+}
+
+impl From<&SubmissionAttachment> for AttachmentJson {
+    fn from(attachment: &SubmissionAttachment) -> Self {
+        Self {
+            document_id:  *attachment.document_id.as_uuid(),
+            filename:     attachment.filename.clone(),
+            content_type: attachment.content_type.clone(),
+            size:         attachment.size,
+        }
+    }
+}
+
+impl From<AttachmentJson> for SubmissionAttachment {
+    fn from(json: AttachmentJson) -> Self {
+        Self {
+            document_id:  DocumentId::from_uuid(json.document_id),
+            filename:     json.filename,
+            content_type: json.content_type,
+            size:         json.size,
+        }
+    }
+}
+
+/// DB の workflow_submissions テーブルの行を表す中間構造体
+struct WorkflowSubmissionRow {
+    id:           Uuid,
+    tenant_id:    Uuid,
+    instance_id:  Uuid,
+    round:        i32,
+    form_data:    JsonValue,
+    attachments:  JsonValue,
+    submitted_by: Uuid,
+    submitted_at: DateTime<Utc>,
+}
+
+impl TryFrom<WorkflowSubmissionRow> for WorkflowSubmission {
+    type Error = InfraError;
+
+    fn try_from(row: WorkflowSubmissionRow) -> Result<Self, Self::Error> {
+        let attachments: Vec<AttachmentJson> = serde_json::from_value(row.attachments)?;
+
+        Ok(WorkflowSubmission::from_db(WorkflowSubmissionRecord {
+            id:           WorkflowSubmissionId::from_uuid(row.id),
+            tenant_id:    TenantId::from_uuid(row.tenant_id),
+            instance_id:  WorkflowInstanceId::from_uuid(row.instance_id),
+            round:        row.round,
+            form_data:    row.form_data,
+            attachments:  attachments
+                .into_iter()
+                .map(SubmissionAttachment::from)
+                .collect(),
+            submitted_by: UserId::from_uuid(row.submitted_by),
+            submitted_at: row.submitted_at,
+        }))
+    }
+}
+
+/// PostgreSQL 実装の WorkflowSubmissionRepository
+#[derive(Debug, Clone)]
+pub struct PostgresWorkflowSubmissionRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowSubmissionRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkflowSubmissionRepository for PostgresWorkflowSubmissionRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, round = submission.round()))]
+    async fn insert(
+        &self,
+        tx: &mut TxContext,
+        submission: &WorkflowSubmission,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        let attachments = serde_json::to_value(
+            submission
+                .attachments()
+                .iter()
+                .map(AttachmentJson::from)
+                .collect::<Vec<_>>(),
+        )?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO workflow_submissions (
+                id, tenant_id, instance_id, round, form_data,
+                attachments, submitted_by, submitted_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            submission.id().as_uuid(),
+            tenant_id.as_uuid(),
+            submission.instance_id().as_uuid(),
+            submission.round(),
+            submission.form_data(),
+            attachments,
+            submission.submitted_by().as_uuid(),
+            submission.submitted_at()
+        )
+        .execute(tx.conn())
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%instance_id, %tenant_id))]
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowSubmission>, InfraError> {
+        let rows = sqlx::query_as!(
+            WorkflowSubmissionRow,
+            r#"
+            SELECT
+                id, tenant_id, instance_id, round, form_data,
+                attachments, submitted_by, submitted_at
+            FROM workflow_submissions
+            WHERE instance_id = $1 AND tenant_id = $2
+            ORDER BY round ASC
+            "#,
+            instance_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(WorkflowSubmission::try_from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// トレイトオブジェクトとして使用できることを確認
+    #[test]
+    fn test_トレイトはsendとsyncを実装している() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Box<dyn WorkflowSubmissionRepository>>();
+    }
+}
@@ -0,0 +1,186 @@
+//! # WorkflowWatcherRepository
+//!
+//! ワークフローインスタンスのウォッチャーの永続化を担当するリポジトリ。
+//!
+//! ## 設計方針
+//!
+//! - **テナント分離**: すべてのクエリでテナント ID を考慮
+//! - **冪等性**: 登録済みのウォッチャーの再登録、未登録のウォッチャーの解除はいずれも成功扱い
+//! - **トランザクション**: 既定ウォッチャーはインスタンス作成と同一トランザクションで登録する
+//!
+//! 詳細: [データベース設計](../../../../docs/40_詳細設計書/02_データベース設計.md)
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use ringiflow_domain::{
+    tenant::TenantId,
+    user::UserId,
+    workflow::{WorkflowInstanceId, WorkflowWatcher},
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{db::TxContext, error::InfraError};
+
+/// ワークフローウォッチャーリポジトリトレイト
+#[async_trait]
+pub trait WorkflowWatcherRepository: Send + Sync {
+    /// ウォッチャーを一括で登録する（登録済みのユーザーは無視）
+    ///
+    /// `tx` はトランザクションコンテキスト（構造的強制）。
+    async fn insert_all(
+        &self,
+        tx: &mut TxContext,
+        watchers: &[WorkflowWatcher],
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// ウォッチャーを登録する（登録済みの場合は何もしない）
+    async fn insert(
+        &self,
+        watcher: &WorkflowWatcher,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// ウォッチャーを解除する（未登録の場合は何もしない）
+    async fn delete(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        user_id: &UserId,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError>;
+
+    /// インスタンス ID でウォッチャーを取得する（created_at ASC）
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowWatcher>, InfraError>;
+}
+
+/// DB の workflow_watchers テーブルの行を表す中間構造体
+struct WorkflowWatcherRow {
+    instance_id: Uuid,
+    user_id:     Uuid,
+    created_at:  DateTime<Utc>,
+}
+
+impl From<WorkflowWatcherRow> for WorkflowWatcher {
+    fn from(row: WorkflowWatcherRow) -> Self {
+        WorkflowWatcher::from_db(
+            WorkflowInstanceId::from_uuid(row.instance_id),
+            UserId::from_uuid(row.user_id),
+            row.created_at,
+        )
+    }
+}
+
+/// PostgreSQL 実装の WorkflowWatcherRepository
+#[derive(Debug, Clone)]
+pub struct PostgresWorkflowWatcherRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkflowWatcherRepository {
+    /// 新しいリポジトリインスタンスを作成
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WorkflowWatcherRepository for PostgresWorkflowWatcherRepository {
+    #[tracing::instrument(skip_all, level = "debug", fields(%tenant_id, count = watchers.len()))]
+    async fn insert_all(
+        &self,
+        tx: &mut TxContext,
+        watchers: &[WorkflowWatcher],
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        for watcher in watchers {
+            sqlx::query!(
+                r#"
+                INSERT INTO workflow_watchers (tenant_id, instance_id, user_id, created_at)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (instance_id, user_id) DO NOTHING
+                "#,
+                tenant_id.as_uuid(),
+                watcher.instance_id().as_uuid(),
+                watcher.user_id().as_uuid(),
+                watcher.created_at()
+            )
+            .execute(tx.conn())
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(instance_id = %watcher.instance_id(), %tenant_id))]
+    async fn insert(
+        &self,
+        watcher: &WorkflowWatcher,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            r#"
+            INSERT INTO workflow_watchers (tenant_id, instance_id, user_id, created_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (instance_id, user_id) DO NOTHING
+            "#,
+            tenant_id.as_uuid(),
+            watcher.instance_id().as_uuid(),
+            watcher.user_id().as_uuid(),
+            watcher.created_at()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%instance_id, %tenant_id))]
+    async fn delete(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        user_id: &UserId,
+        tenant_id: &TenantId,
+    ) -> Result<(), InfraError> {
+        sqlx::query!(
+            r#"
+            DELETE FROM workflow_watchers
+            WHERE instance_id = $1 AND user_id = $2 AND tenant_id = $3
+            "#,
+            instance_id.as_uuid(),
+            user_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(%instance_id, %tenant_id))]
+    async fn find_by_instance(
+        &self,
+        instance_id: &WorkflowInstanceId,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WorkflowWatcher>, InfraError> {
+        let rows = sqlx::query_as!(
+            WorkflowWatcherRow,
+            r#"
+            SELECT instance_id, user_id, created_at
+            FROM workflow_watchers
+            WHERE instance_id = $1 AND tenant_id = $2
+            ORDER BY created_at ASC, user_id ASC
+            "#,
+            instance_id.as_uuid(),
+            tenant_id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(WorkflowWatcher::from).collect())
+    }
+}
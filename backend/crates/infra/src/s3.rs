@@ -36,7 +36,7 @@ use crate::InfraError;
 
 /// S3 クライアントのインターフェース
 ///
-/// Presigned URL の生成とオブジェクトの存在確認を提供する。
+/// Presigned URL の生成とオブジェクトの存在確認・削除を提供する。
 /// テスト時はモックに差し替え可能。
 #[async_trait]
 pub trait S3Client: Send + Sync {
@@ -84,6 +84,15 @@ pub trait S3Client: Send + Sync {
     ///
     /// オブジェクトが存在すれば `true`、存在しなければ `false`
     async fn head_object(&self, s3_key: &str) -> Result<bool, InfraError>;
+
+    /// オブジェクトを削除する（DELETE Object）
+    ///
+    /// 存在しないキーを指定してもエラーにならない（S3 の仕様に準拠）。
+    ///
+    /// # 引数
+    ///
+    /// * `s3_key` - S3 オブジェクトキー
+    async fn delete_object(&self, s3_key: &str) -> Result<(), InfraError>;
 }
 
 /// AWS S3 クライアント
@@ -176,6 +185,18 @@ impl S3Client for AwsS3Client {
             }
         }
     }
+
+    async fn delete_object(&self, s3_key: &str) -> Result<(), InfraError> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket_name)
+            .key(s3_key)
+            .send()
+            .await
+            .map_err(|e| InfraError::s3(format!("DELETE Object の実行に失敗: {e}")))?;
+
+        Ok(())
+    }
 }
 
 /// S3 クライアントを作成する
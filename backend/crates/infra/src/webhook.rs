@@ -0,0 +1,340 @@
+//! # Webhook 送信
+//!
+//! 署名済みの Webhook リクエストを外部 URL へ HTTP POST するインフラストラクチャモジュール。
+//!
+//! ## 設計方針
+//!
+//! - **trait による抽象化**: `WebhookSender` trait で HTTP 送信を抽象化し、
+//!   ユースケースのテストではフェイクに差し替える
+//! - **成否の判定は呼び出し側**: HTTP 応答があればステータスコードを返し、
+//!   2xx 以外を失敗とみなすかどうかはユースケースが決める
+//! - **タイムアウト**: 応答の遅い送信先がワーカーを長時間占有しないよう、
+//!   リクエスト全体にタイムアウトを設定する
+//! - **送信先の制限（SSRF 対策）**: テナントが登録した URL から内部ネットワークへ
+//!   リクエストを送らせないよう、https 以外の URL と、ループバック・プライベート・
+//!   リンクローカル・CGNAT 等の内部アドレスへの送信を拒否する。登録時に検証し、
+//!   送信時も接続直前の名前解決結果を検証する（DNS リバインディング対策）。
+//!   開発環境ではローカルの受信サーバーへ送信できるよう制限を外せる
+
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use thiserror::Error;
+
+/// 送信する Webhook リクエスト
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookRequest {
+    pub url:     String,
+    /// 追加ヘッダー（`Content-Type: application/json` は送信側で付与する）
+    pub headers: Vec<(&'static str, String)>,
+    pub body:    String,
+}
+
+/// Webhook 送信エラー（HTTP 応答を得られなかった場合）
+#[derive(Debug, Error)]
+pub enum WebhookSendError {
+    #[error("送信先に接続できません: {0}")]
+    Transport(String),
+    #[error("許可されていない送信先です: {0}")]
+    Forbidden(String),
+}
+
+/// Webhook 送信トレイト
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// 送信先 URL に送信できるか検証する
+    ///
+    /// Webhook の登録・URL 変更時に呼び出す。送信時にも同じ制限を適用する。
+    async fn verify_destination(&self, url: &str) -> Result<(), WebhookSendError>;
+
+    /// リクエストを送信し、HTTP ステータスコードを返す
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, WebhookSendError>;
+}
+
+/// reqwest による Webhook 送信
+#[derive(Debug, Clone)]
+pub struct ReqwestWebhookSender {
+    client: reqwest::Client,
+    allow_insecure_destinations: bool,
+}
+
+impl ReqwestWebhookSender {
+    /// 指定したタイムアウトで送信クライアントを作成する
+    ///
+    /// `allow_insecure_destinations` が `true` の場合は http の URL と内部アドレスへの
+    /// 送信を許可する（開発環境専用）。
+    pub fn new(timeout: Duration, allow_insecure_destinations: bool) -> Self {
+        let mut builder = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            // プロキシ経由では名前解決がプロキシ側で行われ、送信先を検証できない
+            .no_proxy()
+            .user_agent(concat!("RingiFlow-Webhook/", env!("CARGO_PKG_VERSION")));
+        if !allow_insecure_destinations {
+            builder = builder.dns_resolver(Arc::new(PublicAddressResolver));
+        }
+        let client = builder.build().expect("reqwest クライアントの構築に失敗");
+        Self {
+            client,
+            allow_insecure_destinations,
+        }
+    }
+
+    /// URL のスキームと IP アドレスで指定されたホストを検証し、ホスト名を返す
+    ///
+    /// IP アドレスで指定されたホストは名前解決を経由しないため、ここで検証する。
+    fn check_url(&self, url: &str) -> Result<Option<String>, WebhookSendError> {
+        let parsed = reqwest::Url::parse(url)
+            .map_err(|e| WebhookSendError::Forbidden(format!("URL を解釈できません: {}", e)))?;
+        if self.allow_insecure_destinations {
+            return Ok(None);
+        }
+        if parsed.scheme() != "https" {
+            return Err(WebhookSendError::Forbidden(
+                "https 以外の URL には送信できません".to_string(),
+            ));
+        }
+        let host = parsed
+            .host_str()
+            .ok_or_else(|| WebhookSendError::Forbidden("URL にホストがありません".to_string()))?;
+        match host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<IpAddr>()
+        {
+            Ok(ip) => {
+                check_address(host, ip)?;
+                Ok(None)
+            }
+            Err(_) => Ok(Some(host.to_string())),
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookSender for ReqwestWebhookSender {
+    #[tracing::instrument(skip_all, level = "debug", fields(%url))]
+    async fn verify_destination(&self, url: &str) -> Result<(), WebhookSendError> {
+        if let Some(host) = self.check_url(url)? {
+            resolve_public(&host).await?;
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(skip_all, level = "debug", fields(url = %request.url))]
+    async fn send(&self, request: &WebhookRequest) -> Result<u16, WebhookSendError> {
+        // ホスト名は接続時に PublicAddressResolver が検証する
+        self.check_url(&request.url)?;
+
+        let mut builder = self
+            .client
+            .post(&request.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(request.body.clone());
+        for (name, value) in &request.headers {
+            builder = builder.header(*name, value);
+        }
+
+        let response = builder.send().await.map_err(|e| {
+            forbidden_cause(&e).unwrap_or_else(|| WebhookSendError::Transport(e.to_string()))
+        })?;
+
+        Ok(response.status().as_u16())
+    }
+}
+
+/// 内部アドレスに解決されるホスト名を拒否する DNS リゾルバ
+///
+/// 接続直前の名前解決結果を検証するため、登録後に DNS の応答を内部アドレスへ
+/// 切り替える攻撃（DNS リバインディング）も防ぐ。
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs = resolve_public(&host).await?;
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// ホスト名を名前解決し、内部アドレスが含まれる場合は拒否する
+async fn resolve_public(host: &str) -> Result<Vec<SocketAddr>, WebhookSendError> {
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, 0))
+        .await
+        .map_err(|e| WebhookSendError::Transport(format!("{} の名前解決に失敗: {}", host, e)))?
+        .collect();
+    for addr in &addrs {
+        check_address(host, addr.ip())?;
+    }
+    Ok(addrs)
+}
+
+/// 名前解決で拒否された送信エラーを取り出す
+fn forbidden_cause(error: &reqwest::Error) -> Option<WebhookSendError> {
+    let mut source = std::error::Error::source(error);
+    while let Some(cause) = source {
+        if let Some(WebhookSendError::Forbidden(reason)) = cause.downcast_ref() {
+            return Some(WebhookSendError::Forbidden(reason.clone()));
+        }
+        source = cause.source();
+    }
+    None
+}
+
+fn check_address(host: &str, ip: IpAddr) -> Result<(), WebhookSendError> {
+    if is_internal_address(ip) {
+        return Err(WebhookSendError::Forbidden(format!(
+            "{} は内部ネットワークのアドレス（{}）です",
+            host, ip
+        )));
+    }
+    Ok(())
+}
+
+/// 送信を許可しない内部アドレスか判定する
+fn is_internal_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_internal_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_internal_ipv4(mapped),
+            None => is_internal_ipv6(ip),
+        },
+    }
+}
+
+fn is_internal_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        // 0.0.0.0/8（このネットワーク）
+        || a == 0
+        // 100.64.0.0/10（CGNAT）
+        || (a == 100 && (b & 0b1100_0000) == 64)
+}
+
+fn is_internal_ipv6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+    // 64:ff9b::/96（NAT64）は埋め込まれた IPv4 アドレスで判定する
+    if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+        let [.., a, b, c, d] = ip.octets();
+        return is_internal_ipv4(Ipv4Addr::new(a, b, c, d));
+    }
+    let first = segments[0];
+    ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // fc00::/7（ユニークローカル）
+        || (first & 0xfe00) == 0xfc00
+        // fe80::/10（リンクローカル）
+        || (first & 0xffc0) == 0xfe80
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn request(url: &str) -> WebhookRequest {
+        WebhookRequest {
+            url:     url.to_string(),
+            headers: vec![],
+            body:    "{}".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_接続できない送信先は送信エラーになる() {
+        let sut = ReqwestWebhookSender::new(Duration::from_secs(1), true);
+
+        let result = sut.send(&request("http://127.0.0.1:1/hook")).await;
+
+        assert!(matches!(result, Err(WebhookSendError::Transport(_))));
+    }
+
+    #[rstest]
+    #[case::this_network("0.0.0.1")]
+    #[case::unspecified_v4("0.0.0.0")]
+    #[case::loopback_v4("127.0.0.1")]
+    #[case::private_10("10.1.2.3")]
+    #[case::private_172_16("172.16.0.1")]
+    #[case::private_172_31("172.31.255.255")]
+    #[case::private_192_168("192.168.1.1")]
+    #[case::link_local_v4("169.254.169.254")]
+    #[case::cgnat_first("100.64.0.1")]
+    #[case::cgnat_last("100.127.255.255")]
+    #[case::broadcast("255.255.255.255")]
+    #[case::multicast_v4("224.0.0.1")]
+    #[case::unspecified_v6("::")]
+    #[case::loopback_v6("::1")]
+    #[case::unique_local_fc("fc00::1")]
+    #[case::unique_local_fd("fd12:3456::1")]
+    #[case::link_local_v6("fe80::1")]
+    #[case::multicast_v6("ff02::1")]
+    #[case::ipv4_mapped_loopback("::ffff:127.0.0.1")]
+    #[case::ipv4_mapped_private("::ffff:10.0.0.1")]
+    #[case::nat64_loopback("64:ff9b::127.0.0.1")]
+    #[case::nat64_metadata("64:ff9b::a9fe:a9fe")]
+    fn test_内部アドレスは送信先として拒否される(#[case] ip: &str) {
+        assert!(is_internal_address(ip.parse().unwrap()));
+    }
+
+    #[rstest]
+    #[case("8.8.8.8")]
+    #[case("100.63.255.255")]
+    #[case("100.128.0.1")]
+    #[case("172.32.0.1")]
+    #[case("2001:4860:4860::8888")]
+    #[case("::ffff:8.8.8.8")]
+    #[case("64:ff9b::8.8.8.8")]
+    fn test_公開アドレスは送信先として許可される(#[case] ip: &str) {
+        assert!(!is_internal_address(ip.parse().unwrap()));
+    }
+
+    #[rstest]
+    #[case::http("http://erp.example.com/hook")]
+    #[case::loopback_literal("https://127.0.0.1/hook")]
+    #[case::metadata_literal("https://169.254.169.254/latest/meta-data")]
+    #[case::ipv6_literal("https://[::1]:8443/hook")]
+    #[case::resolves_to_loopback("https://localhost/hook")]
+    #[tokio::test]
+    async fn test_verify_destination_httpと内部アドレスを拒否する(#[case] url: &str) {
+        let sut = ReqwestWebhookSender::new(Duration::from_secs(1), false);
+
+        let result = sut.verify_destination(url).await;
+
+        assert!(matches!(result, Err(WebhookSendError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_verify_destination_開発環境ではローカルの送信先を許可する() {
+        let sut = ReqwestWebhookSender::new(Duration::from_secs(1), true);
+
+        let result = sut.verify_destination("http://localhost:8080/hook").await;
+
+        assert!(result.is_ok());
+    }
+
+    #[rstest]
+    #[case::ip_literal("https://10.0.0.1/hook")]
+    #[case::resolves_to_loopback("https://localhost:1/hook")]
+    #[tokio::test]
+    async fn test_send_内部アドレスへは送信しない(#[case] url: &str) {
+        let sut = ReqwestWebhookSender::new(Duration::from_secs(1), false);
+
+        let result = sut.send(&request(url)).await;
+
+        assert!(matches!(result, Err(WebhookSendError::Forbidden(_))));
+    }
+}
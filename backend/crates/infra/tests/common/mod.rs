@@ -93,6 +93,7 @@ pub fn create_test_comment(
         id:          WorkflowCommentId::new(),
         tenant_id:   seed_tenant_id(),
         instance_id: instance_id.clone(),
+        parent_id:   None,
         posted_by:   posted_by.clone(),
         body:        CommentBody::new(body).unwrap(),
         now:         test_now(),
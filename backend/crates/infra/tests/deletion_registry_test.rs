@@ -57,10 +57,15 @@ fn test_期待リストの具体的な内容() {
 
     let required = HashSet::from([
         "postgres:notification_logs",
+        "postgres:inbox_notifications",
+        "postgres:notification_digest_items",
+        "postgres:notification_preferences",
+        "postgres:notification_templates",
         "postgres:documents",
         "postgres:users",
         "postgres:roles",
         "postgres:workflows",
+        "postgres:webhooks",
         "postgres:display_id_counters",
         "postgres:folders",
         "auth:credentials",
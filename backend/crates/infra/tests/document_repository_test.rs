@@ -17,9 +17,12 @@ use ringiflow_domain::{
     folder::FolderId,
     tenant::TenantId,
     user::UserId,
-    workflow::WorkflowInstanceId,
+    workflow::{WorkflowCommentId, WorkflowInstanceId},
+};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{DocumentRepository, PostgresDocumentRepository},
 };
-use ringiflow_infra::repository::{DocumentRepository, PostgresDocumentRepository};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -69,6 +72,30 @@ async fn insert_test_workflow_instance(
     instance_id
 }
 
+/// テスト用ワークフローコメントを直接 SQL で作成する
+async fn insert_test_comment(
+    pool: &PgPool,
+    tenant_id: &TenantId,
+    instance_id: &WorkflowInstanceId,
+    user_id: &UserId,
+) -> WorkflowCommentId {
+    let comment_id = WorkflowCommentId::from_uuid(Uuid::now_v7());
+    sqlx::query!(
+        r#"
+        INSERT INTO workflow_comments (id, tenant_id, instance_id, posted_by, body)
+        VALUES ($1, $2, $3, $4, 'テストコメント')
+        "#,
+        comment_id.as_uuid(),
+        tenant_id.as_uuid(),
+        instance_id.as_uuid(),
+        user_id.as_uuid()
+    )
+    .execute(pool)
+    .await
+    .expect("コメント作成に失敗");
+    comment_id
+}
+
 /// テスト用ドキュメントを作成するヘルパー
 fn create_test_document(
     tenant_id: &TenantId,
@@ -398,3 +425,182 @@ async fn test_空ワークフローの集計はゼロを返す(pool: PgPool) {
     assert_eq!(count, 0);
     assert_eq!(total_size, 0);
 }
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_ワークフローのドキュメントをステータスを問わず削除しs3キーを返す(
+    pool: PgPool,
+) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    let instance_id = insert_test_workflow_instance(&pool, &tenant_id, &user_id).await;
+    let sut = PostgresDocumentRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+
+    let active = create_test_document(
+        &tenant_id,
+        UploadContext::Workflow(instance_id.clone()),
+        &user_id,
+    );
+    let deleted = create_test_document(
+        &tenant_id,
+        UploadContext::Workflow(instance_id.clone()),
+        &user_id,
+    );
+    sut.insert(&active).await.expect("ドキュメント挿入に失敗");
+    sut.insert(&deleted).await.expect("ドキュメント挿入に失敗");
+    sut.soft_delete(deleted.id(), &tenant_id, test_now())
+        .await
+        .expect("ソフトデリートに失敗");
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    let mut s3_keys = sut
+        .delete_by_workflow(&mut tx, &instance_id, &tenant_id)
+        .await
+        .expect("削除に失敗");
+    tx.commit().await.unwrap();
+
+    s3_keys.sort();
+    let mut expected = vec![active.s3_key().to_string(), deleted.s3_key().to_string()];
+    expected.sort();
+    assert_eq!(s3_keys, expected);
+    assert!(
+        sut.find_by_id(active.id(), &tenant_id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        sut.find_by_id(deleted.id(), &tenant_id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_コメントコンテキストのドキュメントを挿入し取得できる(
+    pool: PgPool,
+) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    let instance_id = insert_test_workflow_instance(&pool, &tenant_id, &user_id).await;
+    let comment_id = insert_test_comment(&pool, &tenant_id, &instance_id, &user_id).await;
+    let sut = PostgresDocumentRepository::new(pool);
+
+    let doc = create_test_document(
+        &tenant_id,
+        UploadContext::Comment(comment_id.clone()),
+        &user_id,
+    );
+    sut.insert(&doc).await.expect("ドキュメント挿入に失敗");
+
+    let found = sut
+        .find_by_id(doc.id(), &tenant_id)
+        .await
+        .expect("取得に失敗")
+        .expect("ドキュメントが見つからない");
+    assert_eq!(found.upload_context(), &UploadContext::Comment(comment_id));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_コメント群のactiveドキュメント一覧と集計を取得できる(
+    pool: PgPool,
+) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    let instance_id = insert_test_workflow_instance(&pool, &tenant_id, &user_id).await;
+    let comment_id = insert_test_comment(&pool, &tenant_id, &instance_id, &user_id).await;
+    let other_comment_id = insert_test_comment(&pool, &tenant_id, &instance_id, &user_id).await;
+    let sut = PostgresDocumentRepository::new(pool);
+
+    let active = create_test_document_with_size(
+        &tenant_id,
+        UploadContext::Comment(comment_id.clone()),
+        &user_id,
+        2048,
+    );
+    let uploading = create_test_document_with_size(
+        &tenant_id,
+        UploadContext::Comment(comment_id.clone()),
+        &user_id,
+        1024,
+    );
+    let other = create_test_document(
+        &tenant_id,
+        UploadContext::Comment(other_comment_id),
+        &user_id,
+    );
+    for doc in [&active, &uploading, &other] {
+        sut.insert(doc).await.expect("ドキュメント挿入に失敗");
+    }
+    sut.update_status(active.id(), DocumentStatus::Active, &tenant_id, test_now())
+        .await
+        .expect("ステータス更新に失敗");
+
+    let documents = sut
+        .list_by_comments(std::slice::from_ref(&comment_id), &tenant_id)
+        .await
+        .expect("一覧取得に失敗");
+    let (count, total_size) = sut
+        .count_and_total_size_by_comment(&comment_id, &tenant_id)
+        .await
+        .expect("集計に失敗");
+
+    assert_eq!(documents.len(), 1);
+    assert_eq!(documents[0].id(), active.id());
+    assert_eq!(count, 2);
+    assert_eq!(total_size, 3072);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_ワークフロー削除でコメントのドキュメントも削除される(
+    pool: PgPool,
+) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    let instance_id = insert_test_workflow_instance(&pool, &tenant_id, &user_id).await;
+    let comment_id = insert_test_comment(&pool, &tenant_id, &instance_id, &user_id).await;
+    let sut = PostgresDocumentRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+
+    let doc = create_test_document(&tenant_id, UploadContext::Comment(comment_id), &user_id);
+    sut.insert(&doc).await.expect("ドキュメント挿入に失敗");
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    let s3_keys = sut
+        .delete_by_workflow(&mut tx, &instance_id, &tenant_id)
+        .await
+        .expect("削除に失敗");
+    tx.commit().await.unwrap();
+
+    assert_eq!(s3_keys, vec![doc.s3_key().to_string()]);
+    assert!(
+        sut.find_by_id(doc.id(), &tenant_id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_s3キーの参照有無を判定できる(pool: PgPool) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    let folder_id = insert_test_folder(&pool, &tenant_id).await;
+    let other_tenant_id = create_other_tenant(&pool).await;
+    let sut = PostgresDocumentRepository::new(pool);
+
+    let doc = create_test_document(&tenant_id, UploadContext::Folder(folder_id), &user_id);
+    sut.insert(&doc).await.expect("ドキュメント挿入に失敗");
+
+    assert!(
+        sut.exists_by_s3_key(doc.s3_key(), &tenant_id)
+            .await
+            .unwrap()
+    );
+    assert!(
+        !sut.exists_by_s3_key("unknown-key", &tenant_id)
+            .await
+            .unwrap()
+    );
+    assert!(
+        !sut.exists_by_s3_key(doc.s3_key(), &other_tenant_id)
+            .await
+            .unwrap()
+    );
+}
@@ -0,0 +1,227 @@
+//! InboxNotificationRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test inbox_notification_repository_test
+//! ```
+
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+use common::{create_test_instance, seed_tenant_id, seed_user_id, test_now};
+use ringiflow_domain::{
+    locale::Locale,
+    notification::{InboxNotification, InboxNotificationId, WorkflowNotification},
+    user::UserId,
+    workflow::WorkflowInstanceId,
+};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{
+        InboxNotificationRepository,
+        PostgresInboxNotificationRepository,
+        PostgresWorkflowInstanceRepository,
+        WorkflowInstanceRepository,
+    },
+};
+use sqlx::PgPool;
+
+/// 通知の対象となるワークフローインスタンスを作成する
+async fn insert_instance(pool: &PgPool) -> WorkflowInstanceId {
+    let instance_repo = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let instance = create_test_instance(100);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    instance_repo.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    instance.id().clone()
+}
+
+/// シードユーザー宛ての承認完了通知を作成する
+fn approved_notification(
+    instance_id: &WorkflowInstanceId,
+    user_id: &UserId,
+    created_at: DateTime<Utc>,
+) -> InboxNotification {
+    InboxNotification::from_notification(
+        InboxNotificationId::new(),
+        seed_tenant_id(),
+        instance_id.clone(),
+        &WorkflowNotification::Approved {
+            workflow_title:      "テスト申請".to_string(),
+            workflow_display_id: "WF-100".to_string(),
+            recipient_email:     "admin@example.com".to_string(),
+            recipient_user_id:   user_id.clone(),
+        },
+        Locale::Ja,
+        created_at,
+    )
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_挿入した通知をユーザーごとに取得できる(pool: PgPool) {
+    let instance_id = insert_instance(&pool).await;
+    let sut = PostgresInboxNotificationRepository::new(pool);
+    let notification = approved_notification(&instance_id, &seed_user_id(), test_now());
+
+    sut.insert(&notification).await.unwrap();
+
+    let page = sut
+        .find_by_user(&seed_tenant_id(), &seed_user_id(), None, 20)
+        .await
+        .unwrap();
+    assert_eq!(page.items, vec![notification]);
+    assert_eq!(page.next_cursor, None);
+
+    let other_user = sut
+        .find_by_user(&seed_tenant_id(), &UserId::new(), None, 20)
+        .await
+        .unwrap();
+    assert!(other_user.items.is_empty());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_新しい順にカーソルでページングできる(pool: PgPool) {
+    let instance_id = insert_instance(&pool).await;
+    let sut = PostgresInboxNotificationRepository::new(pool);
+    let notifications: Vec<_> = (0..3)
+        .map(|i| {
+            approved_notification(
+                &instance_id,
+                &seed_user_id(),
+                test_now() + Duration::minutes(i),
+            )
+        })
+        .collect();
+    for notification in &notifications {
+        sut.insert(notification).await.unwrap();
+    }
+
+    let first = sut
+        .find_by_user(&seed_tenant_id(), &seed_user_id(), None, 2)
+        .await
+        .unwrap();
+    assert_eq!(
+        first.items,
+        vec![notifications[2].clone(), notifications[1].clone()]
+    );
+
+    let second = sut
+        .find_by_user(
+            &seed_tenant_id(),
+            &seed_user_id(),
+            first.next_cursor.as_deref(),
+            2,
+        )
+        .await
+        .unwrap();
+    assert_eq!(second.items, vec![notifications[0].clone()]);
+    assert_eq!(second.next_cursor, None);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_既読化で未読件数が減る(pool: PgPool) {
+    let instance_id = insert_instance(&pool).await;
+    let sut = PostgresInboxNotificationRepository::new(pool);
+    let first = approved_notification(&instance_id, &seed_user_id(), test_now());
+    let second = approved_notification(&instance_id, &seed_user_id(), test_now());
+    sut.insert(&first).await.unwrap();
+    sut.insert(&second).await.unwrap();
+    let read_at = test_now() + Duration::minutes(5);
+
+    let found = sut
+        .mark_read(&seed_tenant_id(), &seed_user_id(), first.id(), read_at)
+        .await
+        .unwrap();
+
+    assert!(found);
+    assert_eq!(
+        sut.count_unread(&seed_tenant_id(), &seed_user_id())
+            .await
+            .unwrap(),
+        1
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_他ユーザーの通知は既読にできない(pool: PgPool) {
+    let instance_id = insert_instance(&pool).await;
+    let sut = PostgresInboxNotificationRepository::new(pool);
+    let notification = approved_notification(&instance_id, &seed_user_id(), test_now());
+    sut.insert(&notification).await.unwrap();
+
+    let found = sut
+        .mark_read(
+            &seed_tenant_id(),
+            &UserId::new(),
+            notification.id(),
+            test_now(),
+        )
+        .await
+        .unwrap();
+
+    assert!(!found);
+    assert_eq!(
+        sut.count_unread(&seed_tenant_id(), &seed_user_id())
+            .await
+            .unwrap(),
+        1
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_すべて既読にすると未読件数が0になる(pool: PgPool) {
+    let instance_id = insert_instance(&pool).await;
+    let sut = PostgresInboxNotificationRepository::new(pool);
+    for _ in 0..3 {
+        let notification = approved_notification(&instance_id, &seed_user_id(), test_now());
+        sut.insert(&notification).await.unwrap();
+    }
+
+    let updated = sut
+        .mark_all_read(&seed_tenant_id(), &seed_user_id(), test_now())
+        .await
+        .unwrap();
+
+    assert_eq!(updated, 3);
+    assert_eq!(
+        sut.count_unread(&seed_tenant_id(), &seed_user_id())
+            .await
+            .unwrap(),
+        0
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_保持期間切れの通知のみ上限件数まで削除される(pool: PgPool) {
+    let instance_id = insert_instance(&pool).await;
+    let sut = PostgresInboxNotificationRepository::new(pool);
+    for days in [30, 20, 10] {
+        let notification = approved_notification(
+            &instance_id,
+            &seed_user_id(),
+            test_now() - Duration::days(days),
+        );
+        sut.insert(&notification).await.unwrap();
+    }
+    let recent = approved_notification(&instance_id, &seed_user_id(), test_now());
+    sut.insert(&recent).await.unwrap();
+    let before = test_now() - Duration::days(1);
+
+    let first = sut.delete_created_before(before, 2).await.unwrap();
+    let second = sut.delete_created_before(before, 2).await.unwrap();
+
+    assert_eq!(first, 2);
+    assert_eq!(second, 1);
+    let page = sut
+        .find_by_user(&seed_tenant_id(), &seed_user_id(), None, 20)
+        .await
+        .unwrap();
+    assert_eq!(page.items, vec![recent]);
+}
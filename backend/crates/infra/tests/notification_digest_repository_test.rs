@@ -0,0 +1,194 @@
+//! NotificationDigestRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test notification_digest_repository_test
+//! ```
+
+mod common;
+
+use chrono::{DateTime, Duration, Utc};
+use common::{create_test_instance, insert_user_raw, seed_tenant_id, seed_user_id, test_now};
+use ringiflow_domain::{
+    locale::Locale,
+    notification::{NotificationDigestItem, NotificationDigestItemId, WorkflowNotification},
+    user::UserId,
+    workflow::WorkflowInstanceId,
+};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{
+        NotificationDigestRepository,
+        PostgresNotificationDigestRepository,
+        PostgresWorkflowInstanceRepository,
+        WorkflowInstanceRepository,
+    },
+};
+use sqlx::PgPool;
+
+/// 通知の対象となるワークフローインスタンスを作成する
+async fn insert_instance(pool: &PgPool) -> WorkflowInstanceId {
+    let instance_repo = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let instance = create_test_instance(100);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    instance_repo.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    instance.id().clone()
+}
+
+/// 承認完了通知のダイジェスト待ち通知を作成する
+fn approved_item(
+    instance_id: &WorkflowInstanceId,
+    user_id: &UserId,
+    created_at: DateTime<Utc>,
+) -> NotificationDigestItem {
+    NotificationDigestItem::from_notification(
+        NotificationDigestItemId::new(),
+        seed_tenant_id(),
+        instance_id.clone(),
+        &WorkflowNotification::Approved {
+            workflow_title:      "テスト申請".to_string(),
+            workflow_display_id: "WF-100".to_string(),
+            recipient_email:     "admin@example.com".to_string(),
+            recipient_user_id:   user_id.clone(),
+        },
+        Locale::Ja,
+        created_at,
+    )
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_受信者の送信時刻までの通知を古い順に取り出して削除する(
+    pool: PgPool,
+) {
+    let instance_id = insert_instance(&pool).await;
+    let sut = PostgresNotificationDigestRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let newer = approved_item(&instance_id, &seed_user_id(), test_now());
+    let older = approved_item(
+        &instance_id,
+        &seed_user_id(),
+        test_now() - Duration::hours(1),
+    );
+    let after_cutoff = approved_item(
+        &instance_id,
+        &seed_user_id(),
+        test_now() + Duration::hours(1),
+    );
+    sut.insert(&newer).await.unwrap();
+    sut.insert(&older).await.unwrap();
+    sut.insert(&after_cutoff).await.unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    let claimed = sut
+        .claim_by_user(&mut tx, &seed_tenant_id(), &seed_user_id(), test_now())
+        .await
+        .unwrap();
+    let other_user = sut
+        .claim_by_user(&mut tx, &seed_tenant_id(), &UserId::new(), test_now())
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    assert_eq!(claimed, vec![older, newer]);
+    assert!(other_user.is_empty());
+    // 送信時刻より後の通知は残る
+    let mut tx = tx_manager.begin().await.unwrap();
+    let remaining = sut
+        .claim_by_user(
+            &mut tx,
+            &seed_tenant_id(),
+            &seed_user_id(),
+            test_now() + Duration::days(1),
+        )
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+    assert_eq!(remaining, vec![after_cutoff]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_ロールバックした場合は取り出した通知が残る(pool: PgPool) {
+    let instance_id = insert_instance(&pool).await;
+    let sut = PostgresNotificationDigestRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let item = approved_item(&instance_id, &seed_user_id(), test_now());
+    sut.insert(&item).await.unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.claim_by_user(&mut tx, &seed_tenant_id(), &seed_user_id(), test_now())
+        .await
+        .unwrap();
+    drop(tx);
+
+    let recipients = sut
+        .find_pending_recipients(&seed_tenant_id(), test_now(), 10)
+        .await
+        .unwrap();
+    assert_eq!(recipients, vec![seed_user_id()]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_送信対象の受信者を最も古い通知の順に上限人数まで取得できる(
+    pool: PgPool,
+) {
+    let instance_id = insert_instance(&pool).await;
+    let other_user = insert_user_raw(
+        &pool,
+        &seed_tenant_id(),
+        900,
+        "other@example.com",
+        "Other User",
+        "active",
+    )
+    .await;
+    let sut = PostgresNotificationDigestRepository::new(pool);
+    sut.insert(&approved_item(&instance_id, &seed_user_id(), test_now()))
+        .await
+        .unwrap();
+    sut.insert(&approved_item(
+        &instance_id,
+        &seed_user_id(),
+        test_now() - Duration::hours(1),
+    ))
+    .await
+    .unwrap();
+    sut.insert(&approved_item(
+        &instance_id,
+        &other_user,
+        test_now() - Duration::hours(2),
+    ))
+    .await
+    .unwrap();
+
+    assert_eq!(
+        sut.find_pending_tenants().await.unwrap(),
+        vec![seed_tenant_id()]
+    );
+
+    let recipients = sut
+        .find_pending_recipients(&seed_tenant_id(), test_now(), 10)
+        .await
+        .unwrap();
+    assert_eq!(recipients, vec![other_user.clone(), seed_user_id()]);
+
+    let limited = sut
+        .find_pending_recipients(&seed_tenant_id(), test_now(), 1)
+        .await
+        .unwrap();
+    assert_eq!(limited, vec![other_user.clone()]);
+
+    // 送信時刻より後に保留された通知しかない受信者は対象外
+    let before_seed_user = sut
+        .find_pending_recipients(&seed_tenant_id(), test_now() - Duration::minutes(90), 10)
+        .await
+        .unwrap();
+    assert_eq!(before_seed_user, vec![other_user]);
+}
@@ -11,18 +11,25 @@
 
 mod common;
 
-use chrono::Utc;
-use common::setup_test_data;
+use chrono::Duration;
+use common::{setup_test_data, test_now};
 use ringiflow_domain::{
-    notification::NotificationLogId,
+    notification::{
+        EmailMessage,
+        NewNotificationLog,
+        NotificationError,
+        NotificationEventType,
+        NotificationLog,
+        NotificationLogId,
+        NotificationLogStatus,
+    },
     tenant::TenantId,
     user::UserId,
     workflow::WorkflowInstanceId,
 };
-use ringiflow_infra::repository::{
-    NotificationLog,
-    NotificationLogRepository,
-    PostgresNotificationLogRepository,
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{NotificationLogRepository, PostgresNotificationLogRepository},
 };
 use serde_json::json;
 use sqlx::PgPool;
@@ -70,42 +77,44 @@ async fn create_test_workflow_instance(
     instance_id
 }
 
+/// テスト用の通知ログを作成する（`minutes` 分後に作成）
+fn create_log(
+    tenant_id: &TenantId,
+    user_id: &UserId,
+    instance_id: &WorkflowInstanceId,
+    minutes: i64,
+) -> NotificationLog {
+    NotificationLog::new(NewNotificationLog {
+        id: NotificationLogId::new(),
+        tenant_id: tenant_id.clone(),
+        event_type: NotificationEventType::ApprovalRequest,
+        workflow_instance_id: instance_id.clone(),
+        workflow_title: "テスト申請".to_string(),
+        workflow_display_id: "WF-0001".to_string(),
+        recipient_user_id: user_id.clone(),
+        email: EmailMessage {
+            to:        "test@example.com".to_string(),
+            subject:   "[RingiFlow] 承認依頼: テスト申請 WF-0001".to_string(),
+            html_body: "<p>承認依頼</p>".to_string(),
+            text_body: "承認依頼".to_string(),
+        },
+        now: test_now() + Duration::minutes(minutes),
+    })
+}
+
 #[sqlx::test(migrations = "../../migrations")]
 async fn test_通知ログを挿入できる(pool: PgPool) {
     let (tenant_id, user_id) = setup_test_data(&pool).await;
     let instance_id = create_test_workflow_instance(&pool, &tenant_id, &user_id).await;
     let sut = PostgresNotificationLogRepository::new(pool.clone());
 
-    let log = NotificationLog {
-        id: NotificationLogId::new(),
-        tenant_id: tenant_id.clone(),
-        event_type: "approval_request".to_string(),
-        workflow_instance_id: instance_id,
-        workflow_title: "テスト申請".to_string(),
-        workflow_display_id: "WF-0001".to_string(),
-        recipient_user_id: user_id,
-        recipient_email: "test@example.com".to_string(),
-        subject: "[RingiFlow] 承認依頼: テスト申請 WF-0001".to_string(),
-        status: "sent".to_string(),
-        error_message: None,
-        sent_at: Utc::now(),
-    };
+    let log = create_log(&tenant_id, &user_id, &instance_id, 0).sent(test_now());
 
     let result = sut.insert(&log).await;
     assert!(result.is_ok());
 
-    // 挿入されたデータを直接 SQL で検証
-    let row = sqlx::query!(
-        r#"SELECT event_type, status, recipient_email FROM notification_logs WHERE id = $1"#,
-        log.id.as_uuid()
-    )
-    .fetch_one(&pool)
-    .await
-    .expect("挿入されたログが見つからない");
-
-    assert_eq!(row.event_type, "approval_request");
-    assert_eq!(row.status, "sent");
-    assert_eq!(row.recipient_email, "test@example.com");
+    let found = sut.find_by_id(log.id(), &tenant_id).await.unwrap();
+    assert_eq!(found, Some(log));
 }
 
 #[sqlx::test(migrations = "../../migrations")]
@@ -114,36 +123,98 @@ async fn test_エラーメッセージ付きの通知ログを挿入できる(po
     let instance_id = create_test_workflow_instance(&pool, &tenant_id, &user_id).await;
     let sut = PostgresNotificationLogRepository::new(pool.clone());
 
-    let log = NotificationLog {
-        id: NotificationLogId::new(),
-        tenant_id,
-        event_type: "approval_request".to_string(),
-        workflow_instance_id: instance_id,
-        workflow_title: "テスト申請".to_string(),
-        workflow_display_id: "WF-0002".to_string(),
-        recipient_user_id: user_id,
-        recipient_email: "test@example.com".to_string(),
-        subject: "[RingiFlow] 承認依頼: テスト申請 WF-0002".to_string(),
-        status: "failed".to_string(),
-        error_message: Some("SMTP connection refused".to_string()),
-        sent_at: Utc::now(),
-    };
+    let log = create_log(&tenant_id, &user_id, &instance_id, 0).failed(
+        &NotificationError::SendFailed("SMTP connection refused".to_string()),
+        test_now(),
+    );
 
     let result = sut.insert(&log).await;
     assert!(result.is_ok());
 
     // エラーメッセージが正しく保存されていることを検証
     let row = sqlx::query!(
-        r#"SELECT status, error_message FROM notification_logs WHERE id = $1"#,
-        log.id.as_uuid()
+        r#"SELECT status, error_message, attempts FROM notification_logs WHERE id = $1"#,
+        log.id().as_uuid()
     )
     .fetch_one(&pool)
     .await
     .expect("挿入されたログが見つからない");
 
-    assert_eq!(row.status, "failed");
+    assert_eq!(row.status, "pending");
+    assert_eq!(row.attempts, 1);
     assert_eq!(
         row.error_message,
-        Some("SMTP connection refused".to_string())
+        Some("メール送信に失敗: SMTP connection refused".to_string())
     );
 }
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_他テナントの通知ログはidで取得できない(pool: PgPool) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    let instance_id = create_test_workflow_instance(&pool, &tenant_id, &user_id).await;
+    let sut = PostgresNotificationLogRepository::new(pool.clone());
+    let log = create_log(&tenant_id, &user_id, &instance_id, 0);
+    sut.insert(&log).await.unwrap();
+
+    let found = sut.find_by_id(log.id(), &TenantId::new()).await.unwrap();
+
+    assert!(found.is_none());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_送信に失敗した通知ログのみ新しい順で取得できる(pool: PgPool) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    let instance_id = create_test_workflow_instance(&pool, &tenant_id, &user_id).await;
+    let sut = PostgresNotificationLogRepository::new(pool.clone());
+    let permanent = NotificationError::PermanentSendFailure("550 宛先不明".to_string());
+    let older = create_log(&tenant_id, &user_id, &instance_id, 0).failed(&permanent, test_now());
+    let newer = create_log(&tenant_id, &user_id, &instance_id, 1).failed(&permanent, test_now());
+    let sent = create_log(&tenant_id, &user_id, &instance_id, 2).sent(test_now());
+    let pending = create_log(&tenant_id, &user_id, &instance_id, 3);
+    for log in [&older, &newer, &sent, &pending] {
+        sut.insert(log).await.unwrap();
+    }
+
+    let found = sut.find_failed(&tenant_id, 100).await.unwrap();
+
+    assert_eq!(found, vec![newer, older]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_送信時刻に達した送信待ちの通知ログのみロックして取得し結果を更新できる(
+    pool: PgPool,
+) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    let instance_id = create_test_workflow_instance(&pool, &tenant_id, &user_id).await;
+    let sut = PostgresNotificationLogRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let due = create_log(&tenant_id, &user_id, &instance_id, 0);
+    let future = create_log(&tenant_id, &user_id, &instance_id, 30);
+    let sent = create_log(&tenant_id, &user_id, &instance_id, 0).sent(test_now());
+    for log in [&due, &future, &sent] {
+        sut.insert(log).await.unwrap();
+    }
+
+    // Act: 送信時刻に達した送信待ちのみ取得される
+    let mut tx = tx_manager.begin().await.unwrap();
+    let locked = sut
+        .lock_due(&mut tx, test_now() + Duration::minutes(1), 10)
+        .await
+        .unwrap();
+    assert_eq!(locked, vec![due.clone()]);
+
+    // Act: 送信成功を記録する
+    let delivered = due.sent(test_now() + Duration::minutes(1));
+    sut.update(&mut tx, &delivered).await.unwrap();
+    tx.commit().await.unwrap();
+
+    // Assert
+    let stored = sut
+        .find_by_id(delivered.id(), &tenant_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored, delivered);
+    assert_eq!(stored.status(), NotificationLogStatus::Sent);
+    assert_eq!(stored.attempts(), 1);
+}
@@ -0,0 +1,133 @@
+//! NotificationPreferenceRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test notification_preference_repository_test
+//! ```
+
+mod common;
+
+use common::{seed_tenant_id, seed_user_id, test_now};
+use ringiflow_domain::notification::{
+    NotificationDelivery,
+    NotificationEventType,
+    NotificationPreference,
+};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{NotificationPreferenceRepository, PostgresNotificationPreferenceRepository},
+};
+use sqlx::PgPool;
+
+async fn upsert(pool: &PgPool, preferences: &[NotificationPreference]) {
+    let sut = PostgresNotificationPreferenceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.upsert_all(
+        &mut tx,
+        &seed_tenant_id(),
+        &seed_user_id(),
+        preferences,
+        test_now(),
+    )
+    .await
+    .unwrap();
+    tx.commit().await.unwrap();
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_保存した設定をユーザーごとに取得できる(pool: PgPool) {
+    upsert(
+        &pool,
+        &[
+            NotificationPreference {
+                event_type: NotificationEventType::ApprovalRequest,
+                delivery:   NotificationDelivery::Digest,
+            },
+            NotificationPreference {
+                event_type: NotificationEventType::Approved,
+                delivery:   NotificationDelivery::Off,
+            },
+        ],
+    )
+    .await;
+    let sut = PostgresNotificationPreferenceRepository::new(pool);
+
+    let mut preferences = sut
+        .find_by_user(&seed_tenant_id(), &seed_user_id())
+        .await
+        .unwrap();
+    preferences.sort_by_key(|p| p.event_type.to_string());
+
+    assert_eq!(
+        preferences,
+        vec![
+            NotificationPreference {
+                event_type: NotificationEventType::ApprovalRequest,
+                delivery:   NotificationDelivery::Digest,
+            },
+            NotificationPreference {
+                event_type: NotificationEventType::Approved,
+                delivery:   NotificationDelivery::Off,
+            },
+        ]
+    );
+    assert_eq!(
+        sut.find_delivery(
+            &seed_tenant_id(),
+            &seed_user_id(),
+            NotificationEventType::ApprovalRequest
+        )
+        .await
+        .unwrap(),
+        Some(NotificationDelivery::Digest)
+    );
+    assert_eq!(
+        sut.find_delivery(
+            &seed_tenant_id(),
+            &seed_user_id(),
+            NotificationEventType::Rejected
+        )
+        .await
+        .unwrap(),
+        None
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_保存済みの種別は上書きされる(pool: PgPool) {
+    upsert(
+        &pool,
+        &[NotificationPreference {
+            event_type: NotificationEventType::Mentioned,
+            delivery:   NotificationDelivery::Off,
+        }],
+    )
+    .await;
+    upsert(
+        &pool,
+        &[NotificationPreference {
+            event_type: NotificationEventType::Mentioned,
+            delivery:   NotificationDelivery::Immediate,
+        }],
+    )
+    .await;
+    let sut = PostgresNotificationPreferenceRepository::new(pool);
+
+    let preferences = sut
+        .find_by_user(&seed_tenant_id(), &seed_user_id())
+        .await
+        .unwrap();
+
+    assert_eq!(
+        preferences,
+        vec![NotificationPreference {
+            event_type: NotificationEventType::Mentioned,
+            delivery:   NotificationDelivery::Immediate,
+        }]
+    );
+}
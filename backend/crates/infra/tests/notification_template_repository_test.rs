@@ -0,0 +1,127 @@
+//! NotificationTemplateRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test notification_template_repository_test
+//! ```
+
+mod common;
+
+use chrono::Duration;
+use common::{seed_tenant_id, seed_user_id, test_now};
+use ringiflow_domain::notification::{
+    NewNotificationTemplate,
+    NotificationEventType,
+    NotificationHtmlTemplate,
+    NotificationSubjectTemplate,
+    NotificationTemplate,
+    NotificationTextTemplate,
+};
+use ringiflow_infra::repository::{
+    NotificationTemplateRepository,
+    PostgresNotificationTemplateRepository,
+};
+use sqlx::PgPool;
+
+fn make_template(event_type: NotificationEventType, subject: &str) -> NotificationTemplate {
+    NotificationTemplate::new(NewNotificationTemplate {
+        tenant_id: seed_tenant_id(),
+        event_type,
+        subject: NotificationSubjectTemplate::new(subject).unwrap(),
+        html_body: NotificationHtmlTemplate::new("<p>{{ workflow_title }}</p>").unwrap(),
+        text_body: NotificationTextTemplate::new("{{ workflow_title }}").unwrap(),
+        updated_by: seed_user_id(),
+        now: test_now(),
+    })
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_保存した通知テンプレートを通知イベント種別で取得できる(
+    pool: PgPool,
+) {
+    let sut = PostgresNotificationTemplateRepository::new(pool);
+    let approved = make_template(
+        NotificationEventType::Approved,
+        "承認: {{ workflow_title }}",
+    );
+    let rejected = make_template(
+        NotificationEventType::Rejected,
+        "却下: {{ workflow_title }}",
+    );
+    sut.upsert(&approved).await.unwrap();
+    sut.upsert(&rejected).await.unwrap();
+
+    let found = sut
+        .find_by_event_type(&seed_tenant_id(), NotificationEventType::Approved)
+        .await
+        .unwrap();
+    let all = sut.find_all_by_tenant(&seed_tenant_id()).await.unwrap();
+    let not_found = sut
+        .find_by_event_type(&seed_tenant_id(), NotificationEventType::Mentioned)
+        .await
+        .unwrap();
+
+    assert_eq!(found, Some(approved.clone()));
+    assert_eq!(all, vec![approved, rejected]);
+    assert_eq!(not_found, None);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_保存済みの通知テンプレートは作成日時を維持して上書きされる(
+    pool: PgPool,
+) {
+    let sut = PostgresNotificationTemplateRepository::new(pool);
+    let original = make_template(
+        NotificationEventType::Approved,
+        "承認: {{ workflow_title }}",
+    );
+    sut.upsert(&original).await.unwrap();
+    let replaced = original.clone().replaced(
+        NotificationSubjectTemplate::new("承認完了のお知らせ").unwrap(),
+        NotificationHtmlTemplate::new("<p>承認されました</p>").unwrap(),
+        NotificationTextTemplate::new("承認されました").unwrap(),
+        seed_user_id(),
+        test_now() + Duration::hours(1),
+    );
+
+    sut.upsert(&replaced).await.unwrap();
+
+    let found = sut
+        .find_by_event_type(&seed_tenant_id(), NotificationEventType::Approved)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.subject().as_str(), "承認完了のお知らせ");
+    assert_eq!(found.created_at(), original.created_at());
+    assert_eq!(found.updated_at(), test_now() + Duration::hours(1));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_通知テンプレートを削除できる(pool: PgPool) {
+    let sut = PostgresNotificationTemplateRepository::new(pool);
+    sut.upsert(&make_template(NotificationEventType::Approved, "承認"))
+        .await
+        .unwrap();
+
+    let deleted = sut
+        .delete(&seed_tenant_id(), NotificationEventType::Approved)
+        .await
+        .unwrap();
+    let deleted_again = sut
+        .delete(&seed_tenant_id(), NotificationEventType::Approved)
+        .await
+        .unwrap();
+
+    assert!(deleted);
+    assert!(!deleted_again);
+    assert!(
+        sut.find_all_by_tenant(&seed_tenant_id())
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
@@ -16,9 +16,14 @@ use ringiflow_infra::deletion::{
     PostgresDisplayIdCounterDeleter,
     PostgresDocumentDeleter,
     PostgresFoldersDeleter,
+    PostgresInboxNotificationDeleter,
+    PostgresNotificationDigestItemDeleter,
     PostgresNotificationLogDeleter,
+    PostgresNotificationPreferenceDeleter,
+    PostgresNotificationTemplateDeleter,
     PostgresRoleDeleter,
     PostgresUserDeleter,
+    PostgresWebhookDeleter,
     PostgresWorkflowDeleter,
     TenantDeleter,
 };
@@ -113,6 +118,49 @@ async fn test_role_deleter_countとdeleteが正しく動作する(pool: PgPool)
     assert_count_delete_count(&sut, &tenant_id, 1, 1).await;
 }
 
+// =============================================================================
+// PostgresWebhookDeleter
+// =============================================================================
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_webhook_deleter_購読を削除し配信ログもcascadeで削除される(
+    pool: PgPool,
+) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+
+    let subscription_id = Uuid::now_v7();
+    sqlx::query!(
+      "INSERT INTO webhook_subscriptions (id, tenant_id, name, url, event_types, secret, created_by) VALUES ($1, $2, 'ERP', 'https://example.com/hook', '[\"approved\"]', '0123456789abcdef', $3)",
+      subscription_id,
+      tenant_id.as_uuid(),
+      user_id.as_uuid()
+   )
+   .execute(&pool)
+   .await
+   .unwrap();
+    sqlx::query!(
+      "INSERT INTO webhook_deliveries (id, tenant_id, subscription_id, event_type, payload) VALUES ($1, $2, $3, 'ping', '{}')",
+      Uuid::now_v7(),
+      tenant_id.as_uuid(),
+      subscription_id
+   )
+   .execute(&pool)
+   .await
+   .unwrap();
+
+    let sut = PostgresWebhookDeleter::new(pool.clone());
+
+    assert_count_delete_count(&sut, &tenant_id, 1, 1).await;
+    let deliveries = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!" FROM webhook_deliveries WHERE tenant_id = $1"#,
+        tenant_id.as_uuid()
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(deliveries, 0);
+}
+
 // =============================================================================
 // PostgresWorkflowDeleter
 // =============================================================================
@@ -315,6 +363,158 @@ async fn test_notification_log_deleter_countとdeleteが正しく動作する(po
     assert_count_delete_count(&sut, &tenant_id, 1, 1).await;
 }
 
+// =============================================================================
+// PostgresInboxNotificationDeleter
+// =============================================================================
+
+/// inbox_notifications テスト用ヘルパー: ワークフローインスタンスを作成し、アプリ内通知を挿入する
+async fn insert_inbox_notification(pool: &PgPool, tenant_id: &TenantId, user_id: &UserId) {
+    // ワークフロー定義
+    let def_id = Uuid::now_v7();
+    sqlx::query!(
+        "INSERT INTO workflow_definitions (id, tenant_id, name, description, definition, version, status, created_by) VALUES ($1, $2, 'Inbox Test WF', 'desc', '{}', 1, 'published', $3)",
+        def_id,
+        tenant_id.as_uuid(),
+        user_id.as_uuid()
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // ワークフローインスタンス
+    let inst_id = Uuid::now_v7();
+    sqlx::query!(
+        "INSERT INTO workflow_instances (id, tenant_id, definition_id, definition_version, display_number, title, form_data, status, initiated_by) VALUES ($1, $2, $3, 1, 301, 'Inbox Test', '{}', 'pending', $4)",
+        inst_id,
+        tenant_id.as_uuid(),
+        def_id,
+        user_id.as_uuid()
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // アプリ内通知
+    sqlx::query!(
+        "INSERT INTO inbox_notifications (id, tenant_id, user_id, event_type, workflow_instance_id, workflow_title, workflow_display_id, message) VALUES ($1, $2, $3, 'approved', $4, 'Inbox Test', 'WF-301', '申請が承認されました')",
+        Uuid::now_v7(),
+        tenant_id.as_uuid(),
+        user_id.as_uuid(),
+        inst_id
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_inbox_notification_deleter_countとdeleteが正しく動作する(pool: PgPool) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    insert_inbox_notification(&pool, &tenant_id, &user_id).await;
+
+    let sut = PostgresInboxNotificationDeleter::new(pool);
+
+    assert_count_delete_count(&sut, &tenant_id, 1, 1).await;
+}
+
+// =============================================================================
+// PostgresNotificationDigestItemDeleter
+// =============================================================================
+
+/// notification_digest_items テスト用ヘルパー: ワークフローインスタンスを作成し、ダイジェスト待ち通知を挿入する
+async fn insert_notification_digest_item(pool: &PgPool, tenant_id: &TenantId, user_id: &UserId) {
+    // ワークフロー定義
+    let def_id = Uuid::now_v7();
+    sqlx::query!(
+        "INSERT INTO workflow_definitions (id, tenant_id, name, description, definition, version, status, created_by) VALUES ($1, $2, 'Digest Test WF', 'desc', '{}', 1, 'published', $3)",
+        def_id,
+        tenant_id.as_uuid(),
+        user_id.as_uuid()
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // ワークフローインスタンス
+    let inst_id = Uuid::now_v7();
+    sqlx::query!(
+        "INSERT INTO workflow_instances (id, tenant_id, definition_id, definition_version, display_number, title, form_data, status, initiated_by) VALUES ($1, $2, $3, 1, 302, 'Digest Test', '{}', 'pending', $4)",
+        inst_id,
+        tenant_id.as_uuid(),
+        def_id,
+        user_id.as_uuid()
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+
+    // ダイジェスト待ち通知
+    sqlx::query!(
+        "INSERT INTO notification_digest_items (id, tenant_id, user_id, recipient_email, event_type, workflow_instance_id, workflow_title, workflow_display_id, message) VALUES ($1, $2, $3, 'digest@example.com', 'approved', $4, 'Digest Test', 'WF-302', '申請が承認されました')",
+        Uuid::now_v7(),
+        tenant_id.as_uuid(),
+        user_id.as_uuid(),
+        inst_id
+    )
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_notification_digest_item_deleter_countとdeleteが正しく動作する(
+    pool: PgPool,
+) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    insert_notification_digest_item(&pool, &tenant_id, &user_id).await;
+
+    let sut = PostgresNotificationDigestItemDeleter::new(pool);
+
+    assert_count_delete_count(&sut, &tenant_id, 1, 1).await;
+}
+
+// =============================================================================
+// PostgresNotificationPreferenceDeleter
+// =============================================================================
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_notification_preference_deleter_countとdeleteが正しく動作する(pool: PgPool) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    sqlx::query!(
+        "INSERT INTO notification_preferences (tenant_id, user_id, event_type, delivery) VALUES ($1, $2, 'approval_request', 'digest'), ($1, $2, 'approved', 'off')",
+        tenant_id.as_uuid(),
+        user_id.as_uuid()
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let sut = PostgresNotificationPreferenceDeleter::new(pool);
+
+    assert_count_delete_count(&sut, &tenant_id, 2, 2).await;
+}
+
+// =============================================================================
+// PostgresNotificationTemplateDeleter
+// =============================================================================
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_notification_template_deleter_countとdeleteが正しく動作する(pool: PgPool) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    sqlx::query!(
+        "INSERT INTO notification_templates (tenant_id, event_type, subject_template, html_template, text_template, updated_by) VALUES ($1, 'approved', '承認完了', '<p>承認</p>', '承認', $2), ($1, 'rejected', '却下', '<p>却下</p>', '却下', $2)",
+        tenant_id.as_uuid(),
+        user_id.as_uuid()
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let sut = PostgresNotificationTemplateDeleter::new(pool);
+
+    assert_count_delete_count(&sut, &tenant_id, 2, 2).await;
+}
+
 // =============================================================================
 // PostgresDocumentDeleter
 // =============================================================================
@@ -423,7 +623,7 @@ async fn test_folders_deleter_countとdeleteが正しく動作する(pool: PgPoo
 /// 統合テスト環境では接続できないため、PostgreSQL 系のみ登録。
 ///
 /// FK 安全な削除順序:
-///   notification_logs → documents → workflows → auth → display_id_counters → folders → roles → users
+///   notification_logs → inbox_notifications → notification_digest_items → notification_preferences → notification_templates → documents → workflows → auth → display_id_counters → folders → roles → users
 #[sqlx::test(migrations = "../../migrations")]
 async fn test_delete_allがfk制約に違反せず全テーブルを削除できる(pool: PgPool) {
     let (tenant_id, user_id) = setup_test_data(&pool).await;
@@ -485,6 +685,50 @@ async fn test_delete_allがfk制約に違反せず全テーブルを削除でき
    )
    .execute(&pool)
    .await
+   .unwrap();
+
+    // アプリ内通知を作成（workflow_instance_id → workflow_instances FK）
+    sqlx::query!(
+      "INSERT INTO inbox_notifications (id, tenant_id, user_id, event_type, workflow_instance_id, workflow_title, workflow_display_id, message) VALUES ($1, $2, $3, 'approved', $4, 'Instance', 'WF-200', '申請が承認されました')",
+      Uuid::now_v7(),
+      tenant_id.as_uuid(),
+      user_id.as_uuid(),
+      inst_id
+   )
+   .execute(&pool)
+   .await
+   .unwrap();
+
+    // ダイジェスト待ち通知を作成（workflow_instance_id → workflow_instances FK）
+    sqlx::query!(
+      "INSERT INTO notification_digest_items (id, tenant_id, user_id, recipient_email, event_type, workflow_instance_id, workflow_title, workflow_display_id, message) VALUES ($1, $2, $3, 'digest@example.com', 'approved', $4, 'Instance', 'WF-200', '申請が承認されました')",
+      Uuid::now_v7(),
+      tenant_id.as_uuid(),
+      user_id.as_uuid(),
+      inst_id
+   )
+   .execute(&pool)
+   .await
+   .unwrap();
+
+    // 通知設定を作成（user_id → users FK）
+    sqlx::query!(
+      "INSERT INTO notification_preferences (tenant_id, user_id, event_type, delivery) VALUES ($1, $2, 'approved', 'digest')",
+      tenant_id.as_uuid(),
+      user_id.as_uuid()
+   )
+   .execute(&pool)
+   .await
+   .unwrap();
+
+    // 通知テンプレートを作成（updated_by → users FK、CASCADE なし）
+    sqlx::query!(
+      "INSERT INTO notification_templates (tenant_id, event_type, subject_template, html_template, text_template, updated_by) VALUES ($1, 'approved', '承認完了', '<p>承認</p>', '承認', $2)",
+      tenant_id.as_uuid(),
+      user_id.as_uuid()
+   )
+   .execute(&pool)
+   .await
    .unwrap();
 
     // ドキュメントを作成（workflow_instance_id → workflow_instances FK）
@@ -546,6 +790,18 @@ async fn test_delete_allがfk制約に違反せず全テーブルを削除でき
     // DeletionRegistry に PostgreSQL 系 Deleter のみ登録（FK 安全な順序で）
     let mut registry = DeletionRegistry::new();
     registry.register(Box::new(PostgresNotificationLogDeleter::new(pool.clone())));
+    registry.register(Box::new(PostgresInboxNotificationDeleter::new(
+        pool.clone(),
+    )));
+    registry.register(Box::new(PostgresNotificationDigestItemDeleter::new(
+        pool.clone(),
+    )));
+    registry.register(Box::new(PostgresNotificationPreferenceDeleter::new(
+        pool.clone(),
+    )));
+    registry.register(Box::new(PostgresNotificationTemplateDeleter::new(
+        pool.clone(),
+    )));
     registry.register(Box::new(PostgresDocumentDeleter::new(pool.clone())));
     registry.register(Box::new(PostgresWorkflowDeleter::new(pool.clone())));
     registry.register(Box::new(AuthCredentialsDeleter::new(pool.clone())));
@@ -566,6 +822,22 @@ async fn test_delete_allがfk制約に違反せず全テーブルを削除でき
         report.succeeded["postgres:notification_logs"].deleted_count,
         1
     );
+    assert_eq!(
+        report.succeeded["postgres:inbox_notifications"].deleted_count,
+        1
+    );
+    assert_eq!(
+        report.succeeded["postgres:notification_digest_items"].deleted_count,
+        1
+    );
+    assert_eq!(
+        report.succeeded["postgres:notification_preferences"].deleted_count,
+        1
+    );
+    assert_eq!(
+        report.succeeded["postgres:notification_templates"].deleted_count,
+        1
+    );
     assert_eq!(report.succeeded["postgres:documents"].deleted_count, 1);
     assert_eq!(report.succeeded["postgres:workflows"].deleted_count, 3); // step + instance + definition
     assert_eq!(report.succeeded["auth:credentials"].deleted_count, 1);
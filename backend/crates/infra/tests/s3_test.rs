@@ -201,6 +201,57 @@ async fn test_head_objectが存在しないオブジェクトにfalseを返す()
     );
 }
 
+#[tokio::test]
+async fn test_delete_objectでオブジェクトが削除される() {
+    let s3_client = create_test_client().await;
+    let s3_key = test_s3_key("delete");
+    let content = b"Delete object test";
+    let content_type = "application/octet-stream";
+
+    let put_url = s3_client
+        .generate_presigned_put_url(
+            &s3_key,
+            content_type,
+            content.len() as i64,
+            Duration::from_secs(300),
+        )
+        .await
+        .expect("PUT URL の生成に失敗");
+
+    let http_client = reqwest::Client::new();
+    http_client
+        .put(&put_url)
+        .header("Content-Type", content_type)
+        .body(content.to_vec())
+        .send()
+        .await
+        .expect("PUT に失敗");
+
+    s3_client
+        .delete_object(&s3_key)
+        .await
+        .expect("delete_object の実行に失敗");
+
+    let exists = s3_client
+        .head_object(&s3_key)
+        .await
+        .expect("head_object の実行に失敗");
+    assert!(!exists, "削除後にオブジェクトが存在しています");
+}
+
+#[tokio::test]
+async fn test_delete_objectは存在しないオブジェクトでもエラーにならない() {
+    let s3_client = create_test_client().await;
+    let s3_key = test_s3_key("delete-not-found");
+
+    let result = s3_client.delete_object(&s3_key).await;
+
+    assert!(
+        result.is_ok(),
+        "存在しないオブジェクトの削除でエラー: {result:?}"
+    );
+}
+
 #[tokio::test]
 async fn test_put_head_getの完全フロー() {
     let s3_client = create_test_client().await;
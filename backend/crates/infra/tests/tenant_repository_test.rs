@@ -11,7 +11,7 @@
 
 mod common;
 
-use ringiflow_domain::tenant::TenantId;
+use ringiflow_domain::{locale::Locale, tenant::TenantId, time_zone::TimeZone};
 use ringiflow_infra::repository::{PostgresTenantRepository, TenantRepository};
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -41,6 +41,35 @@ async fn test_idでテナントを取得できる(pool: PgPool) {
     let tenant = tenant.unwrap();
     assert_eq!(tenant.id(), &tenant_id);
     assert_eq!(tenant.name().as_str(), "Test Tenant");
+    assert_eq!(tenant.default_locale(), Locale::Ja);
+    assert_eq!(tenant.time_zone(), TimeZone::UTC);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_既定ロケールとタイムゾーンを更新できる(pool: PgPool) {
+    let tenant_id = TenantId::from_uuid(Uuid::now_v7());
+    sqlx::query!(
+        r#"
+        INSERT INTO tenants (id, name, subdomain, plan, status)
+        VALUES ($1, 'Test Tenant', 'test-tenant', 'free', 'active')
+        "#,
+        tenant_id.as_uuid()
+    )
+    .execute(&pool)
+    .await
+    .expect("テナント作成に失敗");
+    let sut = PostgresTenantRepository::new(pool);
+    let tenant = sut.find_by_id(&tenant_id).await.unwrap().unwrap();
+
+    let tokyo: TimeZone = "Asia/Tokyo".parse().unwrap();
+
+    sut.update_settings(&tenant.with_default_locale(Locale::En).with_time_zone(tokyo))
+        .await
+        .unwrap();
+
+    let updated = sut.find_by_id(&tenant_id).await.unwrap().unwrap();
+    assert_eq!(updated.default_locale(), Locale::En);
+    assert_eq!(updated.time_zone(), tokyo);
 }
 
 #[sqlx::test(migrations = "../../migrations")]
@@ -13,6 +13,7 @@ mod common;
 
 use common::{assign_role, create_other_tenant, insert_user_raw, setup_test_data};
 use ringiflow_domain::{
+    locale::Locale,
     tenant::TenantId,
     user::{Email, User, UserId, UserStatus},
     value_objects::{DisplayNumber, UserName},
@@ -486,6 +487,71 @@ async fn test_update_statusでステータスが更新される(pool: PgPool) {
     assert_eq!(found.status(), UserStatus::Inactive);
 }
 
+// ===== update_locale / find_effective_locale テスト =====
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_update_localeでロケールが更新される(pool: PgPool) {
+    let (_tenant_id, user_id) = setup_test_data(&pool).await;
+    let sut = PostgresUserRepository::new(pool);
+
+    let user = sut.find_by_id(&user_id).await.unwrap().unwrap();
+    assert_eq!(user.locale(), None);
+
+    let now = chrono::Utc::now();
+    sut.update_locale(&user.with_locale(Some(Locale::En), now))
+        .await
+        .unwrap();
+
+    let found = sut.find_by_id(&user_id).await.unwrap().unwrap();
+    assert_eq!(found.locale(), Some(Locale::En));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_find_effective_localeはロケール未設定ならテナントの既定ロケールを返す(
+    pool: PgPool,
+) {
+    let (tenant_id, user_id) = setup_test_data(&pool).await;
+    sqlx::query!(
+        "UPDATE tenants SET default_locale = 'en' WHERE id = $1",
+        tenant_id.as_uuid()
+    )
+    .execute(&pool)
+    .await
+    .unwrap();
+    let sut = PostgresUserRepository::new(pool);
+
+    let locale = sut
+        .find_effective_locale(&tenant_id, &user_id)
+        .await
+        .unwrap();
+    assert_eq!(locale, Some(Locale::En));
+
+    let user = sut.find_by_id(&user_id).await.unwrap().unwrap();
+    sut.update_locale(&user.with_locale(Some(Locale::Ja), chrono::Utc::now()))
+        .await
+        .unwrap();
+
+    let locale = sut
+        .find_effective_locale(&tenant_id, &user_id)
+        .await
+        .unwrap();
+    assert_eq!(locale, Some(Locale::Ja));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_find_effective_locale別テナントのユーザーはnoneを返す(pool: PgPool) {
+    let (_tenant_id, user_id) = setup_test_data(&pool).await;
+    let other_tenant_id = create_other_tenant(&pool).await;
+    let sut = PostgresUserRepository::new(pool);
+
+    let locale = sut
+        .find_effective_locale(&other_tenant_id, &user_id)
+        .await
+        .unwrap();
+
+    assert_eq!(locale, None);
+}
+
 // ===== insert_user_role テスト =====
 
 #[sqlx::test(migrations = "../../migrations")]
@@ -0,0 +1,257 @@
+//! WebhookSubscriptionRepository / WebhookDeliveryRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test webhook_repository_test
+//! ```
+
+mod common;
+
+use chrono::Duration;
+use common::{seed_tenant_id, seed_user_id, test_now};
+use pretty_assertions::assert_eq;
+use ringiflow_domain::{
+    webhook::{
+        NewWebhookDelivery,
+        NewWebhookSubscription,
+        WebhookDelivery,
+        WebhookDeliveryId,
+        WebhookDeliveryStatus,
+        WebhookName,
+        WebhookSecret,
+        WebhookSubscription,
+        WebhookSubscriptionChanges,
+        WebhookSubscriptionId,
+        WebhookUrl,
+    },
+    workflow::{WorkflowEventId, WorkflowEventType},
+};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{
+        PostgresWebhookDeliveryRepository,
+        PostgresWebhookSubscriptionRepository,
+        WebhookDeliveryRepository,
+        WebhookSubscriptionRepository,
+    },
+};
+use sqlx::PgPool;
+
+fn create_subscription(event_types: Vec<WorkflowEventType>) -> WebhookSubscription {
+    WebhookSubscription::new(NewWebhookSubscription {
+        id: WebhookSubscriptionId::new(),
+        tenant_id: seed_tenant_id(),
+        name: WebhookName::new("ERP 連携").unwrap(),
+        url: WebhookUrl::new("https://erp.example.com/hooks").unwrap(),
+        event_types,
+        secret: WebhookSecret::new("0123456789abcdef").unwrap(),
+        created_by: seed_user_id(),
+        now: test_now(),
+    })
+    .unwrap()
+}
+
+fn create_delivery(
+    subscription: &WebhookSubscription,
+    event_id: Option<WorkflowEventId>,
+    minutes: i64,
+) -> WebhookDelivery {
+    WebhookDelivery::new(NewWebhookDelivery {
+        id: WebhookDeliveryId::new(),
+        tenant_id: seed_tenant_id(),
+        subscription_id: subscription.id().clone(),
+        event_id,
+        event_type: "approved".to_string(),
+        payload: serde_json::json!({"type": "approved"}),
+        now: test_now() + Duration::minutes(minutes),
+    })
+}
+
+/// 購読を INSERT する共通セットアップ
+async fn setup_subscription(
+    pool: &PgPool,
+    event_types: Vec<WorkflowEventType>,
+) -> WebhookSubscription {
+    let subscription = create_subscription(event_types);
+    PostgresWebhookSubscriptionRepository::new(pool.clone())
+        .insert(&subscription)
+        .await
+        .unwrap();
+    subscription
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_購読を保存して取得できる(pool: PgPool) {
+    let sut = PostgresWebhookSubscriptionRepository::new(pool.clone());
+    let subscription = create_subscription(vec![
+        WorkflowEventType::Approved,
+        WorkflowEventType::Rejected,
+    ]);
+
+    sut.insert(&subscription).await.unwrap();
+    let found = sut
+        .find_by_id(subscription.id(), &seed_tenant_id())
+        .await
+        .unwrap();
+
+    assert_eq!(found, Some(subscription));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_購読の更新と削除が反映される(pool: PgPool) {
+    let sut = PostgresWebhookSubscriptionRepository::new(pool.clone());
+    let subscription = setup_subscription(&pool, vec![WorkflowEventType::Approved]).await;
+
+    let updated = subscription
+        .clone()
+        .updated(
+            WebhookSubscriptionChanges {
+                url: Some(WebhookUrl::new("https://erp.example.com/v2/hooks").unwrap()),
+                event_types: Some(vec![WorkflowEventType::Submitted]),
+                is_active: Some(false),
+                ..Default::default()
+            },
+            test_now() + Duration::hours(1),
+        )
+        .unwrap();
+    sut.update(&updated).await.unwrap();
+    let found = sut
+        .find_by_id(subscription.id(), &seed_tenant_id())
+        .await
+        .unwrap();
+    assert_eq!(found, Some(updated));
+
+    sut.delete(subscription.id(), &seed_tenant_id())
+        .await
+        .unwrap();
+    let all = sut.find_all_by_tenant(&seed_tenant_id()).await.unwrap();
+    assert!(all.is_empty());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_イベント種別を購読している有効な購読のみ取得できる(
+    pool: PgPool,
+) {
+    let sut = PostgresWebhookSubscriptionRepository::new(pool.clone());
+    let approved = setup_subscription(&pool, vec![WorkflowEventType::Approved]).await;
+    setup_subscription(&pool, vec![WorkflowEventType::Rejected]).await;
+    let inactive = setup_subscription(&pool, vec![WorkflowEventType::Approved]).await;
+    let inactive = inactive
+        .updated(
+            WebhookSubscriptionChanges {
+                is_active: Some(false),
+                ..Default::default()
+            },
+            test_now(),
+        )
+        .unwrap();
+    sut.update(&inactive).await.unwrap();
+
+    let found = sut
+        .find_active_by_event_type(&seed_tenant_id(), WorkflowEventType::Approved)
+        .await
+        .unwrap();
+
+    assert_eq!(found, vec![approved]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_同じ購読とイベントの配信は重複して登録されない(pool: PgPool) {
+    let subscription = setup_subscription(&pool, vec![WorkflowEventType::Approved]).await;
+    let sut = PostgresWebhookDeliveryRepository::new(pool.clone());
+    let event_id = WorkflowEventId::new();
+    let first = create_delivery(&subscription, Some(event_id.clone()), 0);
+
+    sut.insert_all(std::slice::from_ref(&first)).await.unwrap();
+    sut.insert_all(&[create_delivery(&subscription, Some(event_id), 1)])
+        .await
+        .unwrap();
+    // テスト送信（event_id なし）は重複判定の対象外
+    let ping = create_delivery(&subscription, None, 2);
+    sut.insert_all(std::slice::from_ref(&ping)).await.unwrap();
+
+    let found = sut
+        .find_by_subscription(subscription.id(), &seed_tenant_id(), 100)
+        .await
+        .unwrap();
+    assert_eq!(found, vec![ping, first]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_送信時刻に達した送信待ちの配信のみ取り出して結果を更新できる(
+    pool: PgPool,
+) {
+    let subscription = setup_subscription(&pool, vec![WorkflowEventType::Approved]).await;
+    let sut = PostgresWebhookDeliveryRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let due = create_delivery(&subscription, Some(WorkflowEventId::new()), 0);
+    let future = create_delivery(&subscription, Some(WorkflowEventId::new()), 30);
+    let succeeded =
+        create_delivery(&subscription, Some(WorkflowEventId::new()), 0).succeeded(200, test_now());
+    sut.insert_all(&[due.clone(), future, succeeded])
+        .await
+        .unwrap();
+    let now = test_now() + Duration::minutes(1);
+    let lease_until = now + Duration::minutes(5);
+
+    // Act: 送信時刻に達した送信待ちのみ取り出され、処理期限が設定される
+    let mut tx = tx_manager.begin().await.unwrap();
+    let claimed = sut.claim_due(&mut tx, now, lease_until, 10).await.unwrap();
+    tx.commit().await.unwrap();
+    assert_eq!(claimed, vec![due.clone().claimed(lease_until)]);
+
+    // Act: 失敗結果を記録する
+    let failed =
+        claimed[0]
+            .clone()
+            .failed(Some(503), "HTTP 503", test_now() + Duration::minutes(1));
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.update(&mut tx, &failed).await.unwrap();
+    tx.commit().await.unwrap();
+
+    // Assert
+    let found = sut
+        .find_by_subscription(subscription.id(), &seed_tenant_id(), 100)
+        .await
+        .unwrap();
+    let stored = found.iter().find(|d| d.id() == failed.id()).unwrap();
+    assert_eq!(stored, &failed);
+    assert_eq!(stored.status(), WebhookDeliveryStatus::Pending);
+    assert_eq!(stored.attempts(), 1);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_取り出した配信は処理期限まで再度取り出されない(pool: PgPool) {
+    let subscription = setup_subscription(&pool, vec![WorkflowEventType::Approved]).await;
+    let sut = PostgresWebhookDeliveryRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let due = create_delivery(&subscription, Some(WorkflowEventId::new()), 0);
+    sut.insert_all(std::slice::from_ref(&due)).await.unwrap();
+    let now = test_now() + Duration::minutes(1);
+    let lease_until = now + Duration::minutes(5);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.claim_due(&mut tx, now, lease_until, 10).await.unwrap();
+    tx.commit().await.unwrap();
+
+    // Act
+    let mut tx = tx_manager.begin().await.unwrap();
+    let before_lease = sut
+        .claim_due(&mut tx, now + Duration::minutes(1), lease_until, 10)
+        .await
+        .unwrap();
+    let after_lease = sut
+        .claim_due(&mut tx, lease_until, lease_until + Duration::minutes(5), 10)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    // Assert
+    assert!(before_lease.is_empty());
+    assert_eq!(after_lease.len(), 1);
+    assert_eq!(after_lease[0].id(), due.id());
+}
@@ -0,0 +1,141 @@
+//! WorkflowActivityRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test workflow_activity_repository_test
+//! ```
+
+mod common;
+
+use chrono::Duration;
+use common::{create_other_tenant, create_test_instance, seed_tenant_id, seed_user_id, test_now};
+use pretty_assertions::assert_eq;
+use ringiflow_domain::workflow::{
+    NewWorkflowActivity,
+    WorkflowActivity,
+    WorkflowActivityId,
+    WorkflowActivityType,
+    WorkflowInstance,
+};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{
+        PostgresWorkflowActivityRepository,
+        PostgresWorkflowInstanceRepository,
+        WorkflowActivityRepository,
+        WorkflowInstanceRepository,
+    },
+};
+use serde_json::json;
+use sqlx::PgPool;
+
+/// インスタンスを INSERT する共通セットアップ
+async fn setup_instance(pool: &PgPool) -> WorkflowInstance {
+    let instance_repo = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+
+    let instance = create_test_instance(100);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    instance_repo.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    instance
+}
+
+fn create_activity(
+    instance: &WorkflowInstance,
+    activity_type: WorkflowActivityType,
+    minutes: i64,
+) -> WorkflowActivity {
+    WorkflowActivity::new(NewWorkflowActivity {
+        id: WorkflowActivityId::new(),
+        tenant_id: seed_tenant_id(),
+        instance_id: instance.id().clone(),
+        activity_type,
+        actor_id: Some(seed_user_id()),
+        step_id: None,
+        details: json!({"title": "テスト申請"}),
+        now: test_now() + Duration::minutes(minutes),
+    })
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_記録したアクティビティを発生日時の昇順で取得できる(
+    pool: PgPool,
+) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowActivityRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+    let created = create_activity(&instance, WorkflowActivityType::Created, 0);
+    let submitted = create_activity(&instance, WorkflowActivityType::Submitted, 1);
+    let commented = create_activity(&instance, WorkflowActivityType::CommentPosted, 2);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert_all(&mut tx, &[submitted.clone(), created.clone()], &tenant_id)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+    sut.insert(&commented, &tenant_id).await.unwrap();
+
+    let result = sut
+        .find_by_instance(instance.id(), &tenant_id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, vec![created, submitted, commented]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_操作者なしのアクティビティを記録できる(pool: PgPool) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowActivityRepository::new(pool.clone());
+    let tenant_id = seed_tenant_id();
+    let activity = WorkflowActivity::new(NewWorkflowActivity {
+        id: WorkflowActivityId::new(),
+        tenant_id: tenant_id.clone(),
+        instance_id: instance.id().clone(),
+        activity_type: WorkflowActivityType::Cancelled,
+        actor_id: None,
+        step_id: None,
+        details: json!({}),
+        now: test_now(),
+    });
+
+    sut.insert(&activity, &tenant_id).await.unwrap();
+
+    let result = sut
+        .find_by_instance(instance.id(), &tenant_id)
+        .await
+        .unwrap();
+    assert_eq!(result, vec![activity]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_テナント分離_別テナントのアクティビティは取得できない(
+    pool: PgPool,
+) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowActivityRepository::new(pool.clone());
+    let tenant_id = seed_tenant_id();
+    let other_tenant_id = create_other_tenant(&pool).await;
+
+    sut.insert(
+        &create_activity(&instance, WorkflowActivityType::Created, 0),
+        &tenant_id,
+    )
+    .await
+    .unwrap();
+
+    let result = sut
+        .find_by_instance(instance.id(), &other_tenant_id)
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+}
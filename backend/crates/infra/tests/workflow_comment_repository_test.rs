@@ -11,8 +11,18 @@
 
 mod common;
 
-use common::{create_test_comment, create_test_instance, seed_tenant_id, seed_user_id};
-use ringiflow_domain::{tenant::TenantId, workflow::WorkflowInstanceId};
+use common::{create_test_comment, create_test_instance, seed_tenant_id, seed_user_id, test_now};
+use ringiflow_domain::{
+    tenant::TenantId,
+    workflow::{
+        CommentBody,
+        NewWorkflowComment,
+        WorkflowComment,
+        WorkflowCommentId,
+        WorkflowCommentRevision,
+        WorkflowInstanceId,
+    },
+};
 use ringiflow_infra::{
     db::{PgTransactionManager, TransactionManager},
     repository::{
@@ -39,7 +49,9 @@ async fn test_insert_で新規コメントを作成できる(pool: PgPool) {
 
     let comment = create_test_comment(instance.id(), &seed_user_id(), "テストコメント");
 
-    let result = sut.insert(&comment, &tenant_id).await;
+    let mut tx = tx_manager.begin().await.unwrap();
+    let result = sut.insert(&mut tx, &comment, &tenant_id).await;
+    tx.commit().await.unwrap();
 
     assert!(result.is_ok());
 }
@@ -60,8 +72,10 @@ async fn test_find_by_instance_でコメント一覧を取得できる(pool: PgP
 
     let comment1 = create_test_comment(&instance_id, &seed_user_id(), "コメント1");
     let comment2 = create_test_comment(&instance_id, &seed_user_id(), "コメント2");
-    sut.insert(&comment1, &tenant_id).await.unwrap();
-    sut.insert(&comment2, &tenant_id).await.unwrap();
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &comment1, &tenant_id).await.unwrap();
+    sut.insert(&mut tx, &comment2, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
 
     let result = sut.find_by_instance(&instance_id, &tenant_id).await;
 
@@ -102,9 +116,11 @@ async fn test_find_by_instance_複数コメントが時系列昇順で返る(poo
     let comment1 = create_test_comment(&instance_id, &seed_user_id(), "最初のコメント");
     let comment2 = create_test_comment(&instance_id, &seed_user_id(), "2番目のコメント");
     let comment3 = create_test_comment(&instance_id, &seed_user_id(), "3番目のコメント");
-    sut.insert(&comment1, &tenant_id).await.unwrap();
-    sut.insert(&comment2, &tenant_id).await.unwrap();
-    sut.insert(&comment3, &tenant_id).await.unwrap();
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &comment1, &tenant_id).await.unwrap();
+    sut.insert(&mut tx, &comment2, &tenant_id).await.unwrap();
+    sut.insert(&mut tx, &comment3, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
 
     let result = sut.find_by_instance(&instance_id, &tenant_id).await;
 
@@ -130,7 +146,9 @@ async fn test_テナント分離_別テナントのコメントは取得でき
     tx.commit().await.unwrap();
 
     let comment = create_test_comment(&instance_id, &seed_user_id(), "テストコメント");
-    sut.insert(&comment, &tenant_id).await.unwrap();
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &comment, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
 
     // 別テナントで検索
     let other_tenant_id = TenantId::new();
@@ -140,3 +158,112 @@ async fn test_テナント分離_別テナントのコメントは取得でき
     let comments = result.unwrap();
     assert!(comments.is_empty());
 }
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_find_by_id_で返信先の親コメントが復元される(pool: PgPool) {
+    let instance_repo = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let sut = PostgresWorkflowCommentRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+
+    let instance = create_test_instance(100);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    instance_repo.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let parent = create_test_comment(instance.id(), &seed_user_id(), "親コメント");
+    let reply = WorkflowComment::new(NewWorkflowComment {
+        id:          WorkflowCommentId::new(),
+        tenant_id:   tenant_id.clone(),
+        instance_id: instance.id().clone(),
+        parent_id:   Some(parent.id().clone()),
+        posted_by:   seed_user_id(),
+        body:        CommentBody::new("返信").unwrap(),
+        now:         test_now(),
+    });
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &parent, &tenant_id).await.unwrap();
+    sut.insert(&mut tx, &reply, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let result = sut.find_by_id(reply.id(), &tenant_id).await.unwrap();
+
+    assert_eq!(result, Some(reply));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_update_と_insert_revision_で編集内容と編集履歴が保存される(
+    pool: PgPool,
+) {
+    let instance_repo = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let sut = PostgresWorkflowCommentRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+
+    let instance = create_test_instance(100);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    instance_repo.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let comment = create_test_comment(instance.id(), &seed_user_id(), "編集前");
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &comment, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let revision = WorkflowCommentRevision::of(&comment, seed_user_id(), test_now());
+    let edited = comment
+        .edited(CommentBody::new("編集後").unwrap(), test_now())
+        .unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert_revision(&mut tx, &revision, &tenant_id)
+        .await
+        .unwrap();
+    sut.update(&mut tx, &edited, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let stored = sut
+        .find_by_id(edited.id(), &tenant_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.body().as_str(), "編集後");
+    assert_eq!(stored.edited_at(), edited.edited_at());
+    let revisions = sut.find_revisions(edited.id(), &tenant_id).await.unwrap();
+    assert_eq!(revisions, vec![revision]);
+    assert_eq!(revisions[0].body().as_str(), "編集前");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_update_で論理削除が保存される(pool: PgPool) {
+    let instance_repo = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let sut = PostgresWorkflowCommentRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+
+    let instance = create_test_instance(100);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    instance_repo.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let comment = create_test_comment(instance.id(), &seed_user_id(), "削除するコメント");
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &comment, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let deleted = comment.deleted(seed_user_id(), test_now()).unwrap();
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.update(&mut tx, &deleted, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let comments = sut
+        .find_by_instance(instance.id(), &tenant_id)
+        .await
+        .unwrap();
+    assert_eq!(comments.len(), 1);
+    assert_eq!(comments[0].deleted_at(), deleted.deleted_at());
+    assert_eq!(comments[0].deleted_by(), deleted.deleted_by());
+}
@@ -0,0 +1,310 @@
+//! WorkflowEventOutboxRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test workflow_event_outbox_repository_test
+//! ```
+
+mod common;
+
+use chrono::Duration;
+use common::{create_test_instance, seed_tenant_id, seed_user_id, test_now};
+use pretty_assertions::assert_eq;
+use ringiflow_domain::workflow::{
+    NewWorkflowEvent,
+    WorkflowEvent,
+    WorkflowEventId,
+    WorkflowEventPayload,
+    WorkflowEventType,
+    WorkflowInstance,
+};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{
+        PostgresWorkflowEventOutboxRepository,
+        PostgresWorkflowInstanceRepository,
+        WorkflowEventOutboxRepository,
+        WorkflowInstanceRepository,
+    },
+};
+use sqlx::PgPool;
+
+/// インスタンスを INSERT する共通セットアップ
+async fn setup_instance(pool: &PgPool) -> WorkflowInstance {
+    let instance_repo = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+
+    let instance = create_test_instance(100);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    instance_repo.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    instance
+}
+
+fn create_event(
+    instance: &WorkflowInstance,
+    event_type: WorkflowEventType,
+    minutes: i64,
+) -> WorkflowEvent {
+    WorkflowEvent::new(NewWorkflowEvent {
+        id: WorkflowEventId::new(),
+        tenant_id: seed_tenant_id(),
+        instance_id: instance.id().clone(),
+        event_type,
+        actor_id: Some(seed_user_id()),
+        payload: WorkflowEventPayload::new(instance, None, None),
+        now: test_now() + Duration::minutes(minutes),
+    })
+}
+
+/// イベントを書き込んでコミットする
+async fn insert_events(pool: &PgPool, events: &[WorkflowEvent]) {
+    let sut = PostgresWorkflowEventOutboxRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert_all(&mut tx, events, &seed_tenant_id())
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_書き込んだイベントをペイロードごと取得できる(pool: PgPool) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowEventOutboxRepository::new(pool.clone());
+    let submitted = create_event(&instance, WorkflowEventType::Submitted, 0);
+    let approved = create_event(&instance, WorkflowEventType::Approved, 1);
+
+    insert_events(&pool, &[approved.clone(), submitted.clone()]).await;
+
+    let result = sut
+        .find_by_instance(instance.id(), &seed_tenant_id())
+        .await
+        .unwrap();
+
+    assert_eq!(result, vec![submitted, approved]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_未配信かつ配信日時を過ぎたイベントだけを取り出せる(
+    pool: PgPool,
+) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowEventOutboxRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let dispatched = create_event(&instance, WorkflowEventType::Submitted, 0);
+    let failed = create_event(&instance, WorkflowEventType::StepApproved, 1);
+    let pending = create_event(&instance, WorkflowEventType::Approved, 2);
+    let future = create_event(&instance, WorkflowEventType::Rejected, 60);
+    insert_events(
+        &pool,
+        &[
+            dispatched.clone(),
+            failed.clone(),
+            pending.clone(),
+            future.clone(),
+        ],
+    )
+    .await;
+    let now = test_now() + Duration::minutes(10);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.mark_dispatched(&mut tx, dispatched.id(), &[], now)
+        .await
+        .unwrap();
+    sut.mark_failed(
+        &mut tx,
+        failed.id(),
+        &[],
+        "notification: 接続エラー",
+        now + Duration::minutes(5),
+    )
+    .await
+    .unwrap();
+    tx.commit().await.unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    let result = sut
+        .claim_pending(&mut tx, now, now + Duration::minutes(5), 10)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    let ids: Vec<_> = result.iter().map(|p| p.event.id().clone()).collect();
+    assert_eq!(ids, vec![pending.id().clone()]);
+    assert_eq!(result[0].attempts, 0);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_配信失敗を記録すると再配信時に失敗回数が返る(pool: PgPool) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowEventOutboxRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let event = create_event(&instance, WorkflowEventType::Submitted, 0);
+    insert_events(&pool, std::slice::from_ref(&event)).await;
+    let retry_at = test_now() + Duration::minutes(1);
+
+    let delivered = vec!["realtime".to_string()];
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.mark_failed(&mut tx, event.id(), &[], "1 回目", retry_at)
+        .await
+        .unwrap();
+    sut.mark_failed(&mut tx, event.id(), &delivered, "2 回目", retry_at)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    let result = sut
+        .claim_pending(&mut tx, retry_at, retry_at + Duration::minutes(5), 10)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].event, event);
+    assert_eq!(result[0].attempts, 2);
+    assert_eq!(result[0].delivered_consumers, delivered);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_取り出し件数の上限を指定できる(pool: PgPool) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowEventOutboxRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let first = create_event(&instance, WorkflowEventType::Submitted, 0);
+    let second = create_event(&instance, WorkflowEventType::Approved, 1);
+    insert_events(&pool, &[second, first.clone()]).await;
+
+    let now = test_now() + Duration::minutes(10);
+    let mut tx = tx_manager.begin().await.unwrap();
+    let result = sut
+        .claim_pending(&mut tx, now, now + Duration::minutes(5), 1)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].event, first);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_取り出したイベントは処理期限まで再度取り出されない(
+    pool: PgPool,
+) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowEventOutboxRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let event = create_event(&instance, WorkflowEventType::Submitted, 0);
+    insert_events(&pool, std::slice::from_ref(&event)).await;
+    let now = test_now() + Duration::minutes(10);
+    let lease_until = now + Duration::minutes(5);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    let claimed = sut
+        .claim_pending(&mut tx, now, lease_until, 10)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    let during_lease = sut
+        .claim_pending(&mut tx, now + Duration::minutes(1), lease_until, 10)
+        .await
+        .unwrap();
+    let after_lease = sut
+        .claim_pending(&mut tx, lease_until, lease_until + Duration::minutes(5), 10)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    assert_eq!(claimed.len(), 1);
+    assert!(during_lease.is_empty());
+    assert_eq!(after_lease.len(), 1);
+    assert_eq!(after_lease[0].event, event);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_配信停止したイベントは取り出されない(pool: PgPool) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowEventOutboxRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let event = create_event(&instance, WorkflowEventType::Submitted, 0);
+    insert_events(&pool, std::slice::from_ref(&event)).await;
+    let now = test_now() + Duration::minutes(10);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.mark_dead(
+        &mut tx,
+        event.id(),
+        &["realtime".to_string()],
+        "webhook: 接続エラー",
+        now,
+    )
+    .await
+    .unwrap();
+    let result = sut
+        .claim_pending(&mut tx, now, now + Duration::minutes(5), 10)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    assert!(result.is_empty());
+    let (attempts, failed_at, last_error): (
+        i32,
+        Option<chrono::DateTime<chrono::Utc>>,
+        Option<String>,
+    ) = sqlx::query_as(
+        "SELECT attempts, failed_at, last_error FROM workflow_event_outbox WHERE id = $1",
+    )
+    .bind(event.id().as_uuid())
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    assert_eq!(attempts, 1);
+    assert_eq!(failed_at, Some(now));
+    assert_eq!(last_error.as_deref(), Some("webhook: 接続エラー"));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_解釈できないイベントは配信停止にして残りを取り出す(
+    pool: PgPool,
+) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowEventOutboxRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let broken = create_event(&instance, WorkflowEventType::Submitted, 0);
+    let valid = create_event(&instance, WorkflowEventType::Approved, 1);
+    insert_events(&pool, &[broken.clone(), valid.clone()]).await;
+    sqlx::query("UPDATE workflow_event_outbox SET event_type = 'unknown' WHERE id = $1")
+        .bind(broken.id().as_uuid())
+        .execute(&pool)
+        .await
+        .unwrap();
+    let now = test_now() + Duration::minutes(10);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    let result = sut
+        .claim_pending(&mut tx, now, now + Duration::minutes(5), 10)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    let ids: Vec<_> = result.iter().map(|p| p.event.id().clone()).collect();
+    assert_eq!(ids, vec![valid.id().clone()]);
+    let (failed_at, last_error): (Option<chrono::DateTime<chrono::Utc>>, Option<String>) =
+        sqlx::query_as("SELECT failed_at, last_error FROM workflow_event_outbox WHERE id = $1")
+            .bind(broken.id().as_uuid())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert_eq!(failed_at, Some(now));
+    assert!(last_error.is_some());
+}
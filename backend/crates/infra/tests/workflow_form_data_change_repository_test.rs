@@ -0,0 +1,161 @@
+//! WorkflowFormDataChangeRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test workflow_form_data_change_repository_test
+//! ```
+
+mod common;
+
+use common::{
+    create_other_tenant,
+    create_test_instance,
+    create_test_step,
+    seed_tenant_id,
+    seed_user_id,
+    test_now,
+};
+use pretty_assertions::assert_eq;
+use ringiflow_domain::workflow::{
+    FormFieldDiff,
+    NewWorkflowFormDataChange,
+    WorkflowFormDataChange,
+    WorkflowFormDataChangeId,
+    WorkflowInstance,
+    WorkflowStep,
+};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{
+        PostgresWorkflowFormDataChangeRepository,
+        PostgresWorkflowInstanceRepository,
+        PostgresWorkflowStepRepository,
+        WorkflowFormDataChangeRepository,
+        WorkflowInstanceRepository,
+        WorkflowStepRepository,
+    },
+};
+use serde_json::{Value as JsonValue, json};
+use sqlx::PgPool;
+
+/// インスタンスとステップを INSERT する共通セットアップ
+async fn setup_instance_with_step(pool: &PgPool) -> (WorkflowInstance, WorkflowStep) {
+    let instance_repo = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let step_repo = PostgresWorkflowStepRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let tenant_id = seed_tenant_id();
+
+    let instance = create_test_instance(100);
+    let step = create_test_step(instance.id(), 1);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    instance_repo.insert(&mut tx, &instance).await.unwrap();
+    step_repo.insert(&mut tx, &step, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
+
+    (instance, step)
+}
+
+fn create_change(
+    instance: &WorkflowInstance,
+    step: &WorkflowStep,
+    field_id: &str,
+    old_value: JsonValue,
+    new_value: JsonValue,
+) -> WorkflowFormDataChange {
+    WorkflowFormDataChange::new(NewWorkflowFormDataChange {
+        id:          WorkflowFormDataChangeId::new(),
+        tenant_id:   seed_tenant_id(),
+        instance_id: instance.id().clone(),
+        step_id:     step.id().clone(),
+        diff:        FormFieldDiff {
+            field_id: field_id.to_string(),
+            old_value,
+            new_value,
+        },
+        changed_by:  seed_user_id(),
+        now:         test_now(),
+    })
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_insert_allで記録した変更履歴をfind_by_instanceで取得できる(
+    pool: PgPool,
+) {
+    let (instance, step) = setup_instance_with_step(&pool).await;
+    let sut = PostgresWorkflowFormDataChangeRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+    let changes = vec![
+        create_change(&instance, &step, "amount", json!(1000), json!(800)),
+        create_change(&instance, &step, "note", JsonValue::Null, json!("減額")),
+    ];
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert_all(&mut tx, &changes, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let result = sut
+        .find_by_instance(instance.id(), &tenant_id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, changes);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_コミットしないと変更履歴は記録されない(pool: PgPool) {
+    let (instance, step) = setup_instance_with_step(&pool).await;
+    let sut = PostgresWorkflowFormDataChangeRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+    let changes = vec![create_change(
+        &instance,
+        &step,
+        "amount",
+        json!(1000),
+        json!(800),
+    )];
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert_all(&mut tx, &changes, &tenant_id).await.unwrap();
+    drop(tx);
+
+    let result = sut
+        .find_by_instance(instance.id(), &tenant_id)
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_テナント分離_別テナントの変更履歴は取得できない(pool: PgPool) {
+    let (instance, step) = setup_instance_with_step(&pool).await;
+    let sut = PostgresWorkflowFormDataChangeRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let tenant_id = seed_tenant_id();
+    let other_tenant_id = create_other_tenant(&pool).await;
+    let changes = vec![create_change(
+        &instance,
+        &step,
+        "amount",
+        json!(1000),
+        json!(800),
+    )];
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert_all(&mut tx, &changes, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let result = sut
+        .find_by_instance(instance.id(), &other_tenant_id)
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+}
@@ -16,6 +16,7 @@ use std::collections::HashSet;
 use common::{
     assert_workflow_invariants,
     create_test_instance,
+    insert_user_raw,
     seed_tenant_id,
     seed_user_id,
     test_now,
@@ -23,9 +24,10 @@ use common::{
 use ringiflow_domain::{
     tenant::TenantId,
     value_objects::{DisplayNumber, Version},
-    workflow::WorkflowInstanceId,
+    workflow::{WorkflowInstanceId, WorkflowInstanceStatus},
 };
 use ringiflow_infra::{
+    InfraErrorKind,
     db::{PgTransactionManager, TransactionManager},
     repository::{PostgresWorkflowInstanceRepository, WorkflowInstanceRepository},
 };
@@ -70,6 +72,39 @@ async fn test_find_by_id_でインスタンスを取得できる(pool: PgPool) {
     assert_workflow_invariants(&pool, &instance_id, &tenant_id).await;
 }
 
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_代理申請のインスタンスは本人と代理人を保存して取得できる(
+    pool: PgPool,
+) {
+    let sut = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let tenant_id = seed_tenant_id();
+    let proxy_id = insert_user_raw(
+        &pool,
+        &tenant_id,
+        900,
+        "secretary@example.com",
+        "秘書",
+        "active",
+    )
+    .await;
+
+    let instance = create_test_instance(100).with_proxy_submitter(proxy_id.clone());
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let found = sut
+        .find_by_id(instance.id(), &tenant_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.initiated_by(), &seed_user_id());
+    assert_eq!(found.submitted_by(), &proxy_id);
+    assert!(found.is_proxy_submission());
+}
+
 #[sqlx::test(migrations = "../../migrations")]
 async fn test_find_by_id_存在しない場合はnoneを返す(pool: PgPool) {
     let sut = PostgresWorkflowInstanceRepository::new(pool);
@@ -132,11 +167,98 @@ async fn test_find_by_initiated_by_申請者によるインスタンスを取得
     sut.insert(&mut tx, &instance).await.unwrap();
     tx.commit().await.unwrap();
 
-    let result = sut.find_by_initiated_by(&tenant_id, &user_id).await;
+    let result = sut
+        .find_by_initiated_by(&tenant_id, &user_id, None, 100)
+        .await;
 
     assert!(result.is_ok());
-    let instances = result.unwrap();
-    assert!(!instances.is_empty());
+    let page = result.unwrap();
+    assert!(!page.items.is_empty());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_find_by_initiated_by_カーソルで全件を重複なく降順に取得できる(
+    pool: PgPool,
+) {
+    let sut = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+    let user_id = seed_user_id();
+
+    // 作成日時が同じインスタンスは ID の降順で並ぶ
+    let inserted: Vec<_> = (100..103).map(create_test_instance).collect();
+    let mut tx = tx_manager.begin().await.unwrap();
+    for instance in &inserted {
+        sut.insert(&mut tx, instance).await.unwrap();
+    }
+    tx.commit().await.unwrap();
+
+    let mut keys = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = sut
+            .find_by_initiated_by(&tenant_id, &user_id, cursor.as_deref(), 2)
+            .await
+            .unwrap();
+        assert!(page.items.len() <= 2);
+        keys.extend(
+            page.items
+                .iter()
+                .map(|i| (i.created_at(), *i.id().as_uuid())),
+        );
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    let mut expected = keys.clone();
+    expected.sort_by(|a, b| b.cmp(a));
+    expected.dedup();
+    assert_eq!(keys, expected);
+    for instance in &inserted {
+        assert!(keys.contains(&(instance.created_at(), *instance.id().as_uuid())));
+    }
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_find_by_initiated_by_不正なカーソルはinvalid_input(pool: PgPool) {
+    let sut = PostgresWorkflowInstanceRepository::new(pool);
+
+    let result = sut
+        .find_by_initiated_by(&seed_tenant_id(), &seed_user_id(), Some("invalid"), 20)
+        .await;
+
+    assert!(matches!(
+        result.unwrap_err().kind(),
+        InfraErrorKind::InvalidInput(_)
+    ));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_count_by_initiated_by_ステータスごとの件数を取得できる(pool: PgPool) {
+    let sut = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+    let user_id = seed_user_id();
+    let count = |status| sut.count_by_initiated_by(&tenant_id, &user_id, status);
+    let drafts_before = count(WorkflowInstanceStatus::Draft).await.unwrap();
+    let pending_before = count(WorkflowInstanceStatus::Pending).await.unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &create_test_instance(100))
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    assert_eq!(
+        count(WorkflowInstanceStatus::Draft).await.unwrap(),
+        drafts_before + 1
+    );
+    assert_eq!(
+        count(WorkflowInstanceStatus::Pending).await.unwrap(),
+        pending_before
+    );
 }
 
 #[sqlx::test(migrations = "../../migrations")]
@@ -403,3 +525,75 @@ async fn test_find_by_display_number_別テナントでは見つからない(poo
     assert!(result.is_ok());
     assert!(result.unwrap().is_none());
 }
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_delete_draft_下書きを削除できる(pool: PgPool) {
+    let sut = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+    let instance = create_test_instance(100);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    let deleted = sut
+        .delete_draft(&mut tx, instance.id(), &tenant_id)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    assert!(deleted);
+    let found = sut.find_by_id(instance.id(), &tenant_id).await.unwrap();
+    assert!(found.is_none());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_delete_draft_申請済みは削除しない(pool: PgPool) {
+    let sut = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+    let instance = create_test_instance(100).submitted(test_now()).unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    let deleted = sut
+        .delete_draft(&mut tx, instance.id(), &tenant_id)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    assert!(!deleted);
+    let found = sut.find_by_id(instance.id(), &tenant_id).await.unwrap();
+    assert!(found.is_some());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_find_stale_drafts_指定日時より前に更新された下書きのみ返す(
+    pool: PgPool,
+) {
+    let sut = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let draft = create_test_instance(100);
+    let submitted = create_test_instance(101).submitted(test_now()).unwrap();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &draft).await.unwrap();
+    sut.insert(&mut tx, &submitted).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let stale = sut
+        .find_stale_drafts(test_now() + chrono::Duration::days(1), 100)
+        .await
+        .unwrap();
+    let not_yet_stale = sut.find_stale_drafts(test_now(), 100).await.unwrap();
+
+    let stale_ids: HashSet<_> = stale.iter().map(|i| i.id().clone()).collect();
+    assert!(stale_ids.contains(draft.id()));
+    assert!(!stale_ids.contains(submitted.id()));
+    assert!(!not_yet_stale.iter().any(|i| i.id() == draft.id()));
+}
@@ -0,0 +1,130 @@
+//! WorkflowProxyGrantRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test workflow_proxy_grant_repository_test
+//! ```
+
+mod common;
+
+use common::{create_other_tenant, insert_user_raw, seed_tenant_id, seed_user_id, test_now};
+use pretty_assertions::assert_eq;
+use ringiflow_domain::{user::UserId, workflow::ProxyGrant};
+use ringiflow_infra::repository::{
+    PostgresWorkflowProxyGrantRepository,
+    WorkflowProxyGrantRepository,
+};
+use sqlx::PgPool;
+
+/// 代理人となるユーザーを INSERT する共通セットアップ
+async fn setup_proxy(pool: &PgPool) -> UserId {
+    insert_user_raw(
+        pool,
+        &seed_tenant_id(),
+        900,
+        "secretary@example.com",
+        "秘書",
+        "active",
+    )
+    .await
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_insertした代理権限を本人と代理人の双方から取得できる(
+    pool: PgPool,
+) {
+    let proxy_id = setup_proxy(&pool).await;
+    let sut = PostgresWorkflowProxyGrantRepository::new(pool);
+    let tenant_id = seed_tenant_id();
+    let grant = ProxyGrant::new(seed_user_id(), proxy_id.clone(), test_now());
+
+    sut.insert(&grant, &tenant_id).await.unwrap();
+
+    assert_eq!(
+        sut.find_by_principal(&seed_user_id(), &tenant_id)
+            .await
+            .unwrap(),
+        vec![grant.clone()]
+    );
+    assert_eq!(
+        sut.find_by_proxy(&proxy_id, &tenant_id).await.unwrap(),
+        vec![grant]
+    );
+    assert!(
+        sut.exists(&seed_user_id(), &proxy_id, &tenant_id)
+            .await
+            .unwrap()
+    );
+    // 逆向きの代理権限は付与されていない
+    assert!(
+        !sut.exists(&proxy_id, &seed_user_id(), &tenant_id)
+            .await
+            .unwrap()
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_付与済みの代理権限を再付与しても重複しない(pool: PgPool) {
+    let proxy_id = setup_proxy(&pool).await;
+    let sut = PostgresWorkflowProxyGrantRepository::new(pool);
+    let tenant_id = seed_tenant_id();
+    let grant = ProxyGrant::new(seed_user_id(), proxy_id, test_now());
+
+    sut.insert(&grant, &tenant_id).await.unwrap();
+    sut.insert(&grant, &tenant_id).await.unwrap();
+
+    let result = sut
+        .find_by_principal(&seed_user_id(), &tenant_id)
+        .await
+        .unwrap();
+    assert_eq!(result, vec![grant]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_deleteで代理権限を取り消せる(pool: PgPool) {
+    let proxy_id = setup_proxy(&pool).await;
+    let sut = PostgresWorkflowProxyGrantRepository::new(pool);
+    let tenant_id = seed_tenant_id();
+    let grant = ProxyGrant::new(seed_user_id(), proxy_id.clone(), test_now());
+    sut.insert(&grant, &tenant_id).await.unwrap();
+
+    sut.delete(&seed_user_id(), &proxy_id, &tenant_id)
+        .await
+        .unwrap();
+    // 未付与の代理権限の取り消しも成功する
+    sut.delete(&seed_user_id(), &proxy_id, &tenant_id)
+        .await
+        .unwrap();
+
+    assert!(
+        !sut.exists(&seed_user_id(), &proxy_id, &tenant_id)
+            .await
+            .unwrap()
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_テナント分離_別テナントの代理権限は取得できない(pool: PgPool) {
+    let proxy_id = setup_proxy(&pool).await;
+    let sut = PostgresWorkflowProxyGrantRepository::new(pool.clone());
+    let tenant_id = seed_tenant_id();
+    let other_tenant_id = create_other_tenant(&pool).await;
+    let grant = ProxyGrant::new(seed_user_id(), proxy_id.clone(), test_now());
+    sut.insert(&grant, &tenant_id).await.unwrap();
+
+    assert!(
+        sut.find_by_proxy(&proxy_id, &other_tenant_id)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert!(
+        !sut.exists(&seed_user_id(), &proxy_id, &other_tenant_id)
+            .await
+            .unwrap()
+    );
+}
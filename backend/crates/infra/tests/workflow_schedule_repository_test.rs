@@ -0,0 +1,162 @@
+//! WorkflowScheduleRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test workflow_schedule_repository_test
+//! ```
+
+mod common;
+
+use chrono::Duration;
+use common::{seed_definition_id, seed_tenant_id, seed_user_id, test_now};
+use pretty_assertions::assert_eq;
+use ringiflow_domain::{
+    time_zone::TimeZone,
+    workflow::{
+        CronExpression,
+        MonthlyRecurrence,
+        NewWorkflowSchedule,
+        Recurrence,
+        ScheduledApprover,
+        WorkflowSchedule,
+        WorkflowScheduleChanges,
+        WorkflowScheduleId,
+        WorkflowScheduleName,
+    },
+};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{PostgresWorkflowScheduleRepository, WorkflowScheduleRepository},
+};
+use sqlx::PgPool;
+
+fn create_schedule(recurrence: Recurrence) -> WorkflowSchedule {
+    WorkflowSchedule::new(NewWorkflowSchedule {
+        id: WorkflowScheduleId::new(),
+        tenant_id: seed_tenant_id(),
+        owner_id: seed_user_id(),
+        definition_id: seed_definition_id(),
+        name: WorkflowScheduleName::new("月次サブスクリプション更新").unwrap(),
+        title: "サブスクリプション更新".to_string(),
+        form_data: serde_json::json!({"amount": 1000}),
+        recurrence,
+        time_zone: "Asia/Tokyo".parse().unwrap(),
+        auto_submit: true,
+        approvers: vec![ScheduledApprover {
+            step_id:     "approval".to_string(),
+            assigned_to: seed_user_id(),
+        }],
+        now: test_now(),
+    })
+    .unwrap()
+}
+
+fn monthly(day: u32) -> Recurrence {
+    Recurrence::Monthly(MonthlyRecurrence::new(day, 9, 0).unwrap())
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_スケジュールを保存して取得できる(pool: PgPool) {
+    let sut = PostgresWorkflowScheduleRepository::new(pool);
+    let schedule = create_schedule(monthly(31));
+
+    sut.insert(&schedule).await.unwrap();
+    let found = sut
+        .find_by_id(schedule.id(), &seed_tenant_id())
+        .await
+        .unwrap();
+
+    assert_eq!(found, Some(schedule));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_スケジュールの更新と削除が反映される(pool: PgPool) {
+    let sut = PostgresWorkflowScheduleRepository::new(pool);
+    let schedule = create_schedule(monthly(1));
+    sut.insert(&schedule).await.unwrap();
+
+    // Act: 月次から cron 形式に変更し、タイムゾーンも変更する
+    let updated = schedule
+        .clone()
+        .updated(
+            WorkflowScheduleChanges {
+                recurrence: Some(Recurrence::Cron(
+                    CronExpression::parse("0 9 1 1,4,7,10 *").unwrap(),
+                )),
+                time_zone: Some(TimeZone::UTC),
+                auto_submit: Some(false),
+                ..Default::default()
+            },
+            test_now() + Duration::hours(1),
+        )
+        .unwrap();
+    sut.update(&updated).await.unwrap();
+
+    // Assert
+    let found = sut
+        .find_by_owner(&seed_tenant_id(), &seed_user_id())
+        .await
+        .unwrap();
+    assert_eq!(found, vec![updated]);
+
+    // Act: 削除する
+    sut.delete(schedule.id(), &seed_tenant_id()).await.unwrap();
+
+    // Assert
+    let found = sut
+        .find_by_id(schedule.id(), &seed_tenant_id())
+        .await
+        .unwrap();
+    assert_eq!(found, None);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_実行日時に達した有効なスケジュールのみロックして取得し実行結果を記録できる(
+    pool: PgPool,
+) {
+    // Arrange: 毎分実行・毎月 1 日実行・無効化済みのスケジュール
+    let sut = PostgresWorkflowScheduleRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let every_minute = create_schedule(Recurrence::Cron(
+        CronExpression::parse("* * * * *").unwrap(),
+    ));
+    let first_of_month = create_schedule(monthly(1));
+    let inactive = create_schedule(Recurrence::Cron(
+        CronExpression::parse("* * * * *").unwrap(),
+    ))
+    .updated(
+        WorkflowScheduleChanges {
+            is_active: Some(false),
+            ..Default::default()
+        },
+        test_now(),
+    )
+    .unwrap();
+    for schedule in [&every_minute, &first_of_month, &inactive] {
+        sut.insert(schedule).await.unwrap();
+    }
+    let now = test_now() + Duration::minutes(5);
+
+    // Act
+    let mut tx = tx_manager.begin().await.unwrap();
+    let locked = sut.lock_due(&mut tx, now, 10).await.unwrap();
+    assert_eq!(locked, vec![every_minute.clone()]);
+
+    let advanced = every_minute.advanced(now);
+    sut.record_run(&mut tx, &advanced).await.unwrap();
+    tx.commit().await.unwrap();
+
+    // Assert
+    let found = sut
+        .find_by_id(advanced.id(), &seed_tenant_id())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found, advanced);
+    assert_eq!(found.last_run_at(), Some(now));
+    assert!(!found.is_due(now));
+}
@@ -0,0 +1,510 @@
+//! WorkflowSearchRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! シードデータのインスタンスと混ざらないよう、テストごとに新しいテナントを作成する。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test workflow_search_repository_test
+//! ```
+
+mod common;
+
+use chrono::Duration;
+use common::{insert_user_raw, setup_test_data, test_now};
+use pretty_assertions::assert_eq;
+use ringiflow_domain::{
+    tenant::TenantId,
+    user::UserId,
+    value_objects::{DisplayNumber, Version, WorkflowName},
+    workflow::{
+        CommentBody,
+        NewWorkflowComment,
+        NewWorkflowDefinition,
+        NewWorkflowInstance,
+        NewWorkflowStep,
+        WorkflowComment,
+        WorkflowCommentId,
+        WorkflowDefinition,
+        WorkflowDefinitionId,
+        WorkflowInstance,
+        WorkflowInstanceId,
+        WorkflowInstanceStatus,
+        WorkflowStep,
+        WorkflowStepId,
+        WorkflowWatcher,
+    },
+};
+use ringiflow_infra::{
+    InfraErrorKind,
+    db::{PgTransactionManager, TransactionManager},
+    repository::{
+        PostgresWorkflowCommentRepository,
+        PostgresWorkflowDefinitionRepository,
+        PostgresWorkflowInstanceRepository,
+        PostgresWorkflowSearchRepository,
+        PostgresWorkflowStepRepository,
+        PostgresWorkflowWatcherRepository,
+        WorkflowCommentRepository,
+        WorkflowDefinitionRepository,
+        WorkflowInstanceRepository,
+        WorkflowSearchCriteria,
+        WorkflowSearchRepository,
+        WorkflowStepRepository,
+        WorkflowWatcherRepository,
+    },
+};
+use serde_json::json;
+use sqlx::PgPool;
+
+struct Seeder {
+    pool:          PgPool,
+    tenant_id:     TenantId,
+    user_id:       UserId,
+    definition_id: WorkflowDefinitionId,
+}
+
+impl Seeder {
+    async fn new(pool: &PgPool) -> Self {
+        let (tenant_id, user_id) = setup_test_data(pool).await;
+        let definition_id = insert_definition(pool, &tenant_id, &user_id, false).await;
+        Self {
+            pool: pool.clone(),
+            tenant_id,
+            user_id,
+            definition_id,
+        }
+    }
+
+    /// 申請済み（pending）のインスタンスを作成する
+    ///
+    /// `minutes` で作成日時をずらし、並び順を制御する。
+    async fn submitted(
+        &self,
+        display_number: i64,
+        title: &str,
+        form_data: serde_json::Value,
+        minutes: i64,
+    ) -> WorkflowInstance {
+        let now = test_now() + Duration::minutes(minutes);
+        let instance = self
+            .draft_by(&self.user_id, display_number, title, form_data, now)
+            .submitted(now)
+            .unwrap();
+        self.insert(&instance).await;
+        instance
+    }
+
+    fn draft_by(
+        &self,
+        initiated_by: &UserId,
+        display_number: i64,
+        title: &str,
+        form_data: serde_json::Value,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> WorkflowInstance {
+        WorkflowInstance::new(NewWorkflowInstance {
+            id: WorkflowInstanceId::new(),
+            tenant_id: self.tenant_id.clone(),
+            definition_id: self.definition_id.clone(),
+            definition_version: Version::initial(),
+            display_number: DisplayNumber::new(display_number).unwrap(),
+            title: title.to_string(),
+            form_data,
+            initiated_by: initiated_by.clone(),
+            now,
+        })
+    }
+
+    async fn insert(&self, instance: &WorkflowInstance) {
+        let tx_manager = PgTransactionManager::new(self.pool.clone());
+        let mut tx = tx_manager.begin().await.unwrap();
+        PostgresWorkflowInstanceRepository::new(self.pool.clone())
+            .insert(&mut tx, instance)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+    }
+
+    async fn active_step(&self, instance: &WorkflowInstance, assigned_to: &UserId) {
+        let step = WorkflowStep::new(NewWorkflowStep {
+            id: WorkflowStepId::new(),
+            instance_id: instance.id().clone(),
+            display_number: DisplayNumber::new(1).unwrap(),
+            step_id: "approval".to_string(),
+            step_name: "承認".to_string(),
+            step_type: "approval".to_string(),
+            assigned_to: Some(assigned_to.clone()),
+            now: test_now(),
+        })
+        .activated(test_now());
+        let tx_manager = PgTransactionManager::new(self.pool.clone());
+        let mut tx = tx_manager.begin().await.unwrap();
+        PostgresWorkflowStepRepository::new(self.pool.clone())
+            .insert(&mut tx, &step, &self.tenant_id)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+    }
+
+    async fn comment(&self, instance: &WorkflowInstance, body: &str) -> WorkflowComment {
+        let comment = WorkflowComment::new(NewWorkflowComment {
+            id:          WorkflowCommentId::new(),
+            tenant_id:   self.tenant_id.clone(),
+            instance_id: instance.id().clone(),
+            parent_id:   None,
+            posted_by:   self.user_id.clone(),
+            body:        CommentBody::new(body).unwrap(),
+            now:         test_now(),
+        });
+        let tx_manager = PgTransactionManager::new(self.pool.clone());
+        let mut tx = tx_manager.begin().await.unwrap();
+        PostgresWorkflowCommentRepository::new(self.pool.clone())
+            .insert(&mut tx, &comment, &self.tenant_id)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+        comment
+    }
+
+    async fn delete_comment(&self, comment: WorkflowComment) {
+        let deleted = comment.deleted(self.user_id.clone(), test_now()).unwrap();
+        let tx_manager = PgTransactionManager::new(self.pool.clone());
+        let mut tx = tx_manager.begin().await.unwrap();
+        PostgresWorkflowCommentRepository::new(self.pool.clone())
+            .update(&mut tx, &deleted, &self.tenant_id)
+            .await
+            .unwrap();
+        tx.commit().await.unwrap();
+    }
+
+    fn criteria(&self) -> WorkflowSearchCriteria {
+        WorkflowSearchCriteria {
+            viewer_id: self.user_id.clone(),
+            ..Default::default()
+        }
+    }
+
+    async fn search(&self, criteria: &WorkflowSearchCriteria) -> Vec<WorkflowInstanceId> {
+        PostgresWorkflowSearchRepository::new(self.pool.clone())
+            .search(&self.tenant_id, criteria, None, 100)
+            .await
+            .unwrap()
+            .ids
+    }
+}
+
+async fn insert_definition(
+    pool: &PgPool,
+    tenant_id: &TenantId,
+    created_by: &UserId,
+    confidential: bool,
+) -> WorkflowDefinitionId {
+    let definition = WorkflowDefinition::new(NewWorkflowDefinition {
+        id:          WorkflowDefinitionId::new(),
+        tenant_id:   tenant_id.clone(),
+        name:        WorkflowName::new("汎用申請").unwrap(),
+        description: None,
+        definition:  json!({"steps": []}),
+        created_by:  created_by.clone(),
+        now:         test_now(),
+    })
+    .with_confidential(confidential);
+    PostgresWorkflowDefinitionRepository::new(pool.clone())
+        .insert(&definition)
+        .await
+        .unwrap();
+    definition.id().clone()
+}
+
+fn ids(instances: &[&WorkflowInstance]) -> Vec<WorkflowInstanceId> {
+    instances.iter().map(|i| i.id().clone()).collect()
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_キーワードで件名_フォームデータ_コメント_表示用idを検索できる(
+    pool: PgPool,
+) {
+    let seeder = Seeder::new(&pool).await;
+    let by_title = seeder
+        .submitted(1, "経費精算申請（出張費）", json!({}), 0)
+        .await;
+    let by_form = seeder
+        .submitted(
+            2,
+            "備品購入",
+            json!({"vendor": "Acme Trading", "amount": 1200}),
+            1,
+        )
+        .await;
+    let by_comment = seeder.submitted(3, "休暇申請", json!({}), 2).await;
+    seeder
+        .comment(&by_comment, "経費 の扱いを確認してください")
+        .await;
+    let unmatched = seeder.submitted(4, "在宅勤務申請", json!({}), 3).await;
+
+    // 日本語の部分一致（件名）・フォームデータの値・コメント本文
+    let found = seeder
+        .search(&WorkflowSearchCriteria {
+            keyword: Some("経費".to_string()),
+            ..seeder.criteria()
+        })
+        .await;
+    assert_eq!(found, ids(&[&by_comment, &by_title]));
+
+    // フォームデータの値は全文検索（語単位、大文字小文字を区別しない）で一致する
+    let found = seeder
+        .search(&WorkflowSearchCriteria {
+            keyword: Some("acme".to_string()),
+            ..seeder.criteria()
+        })
+        .await;
+    assert_eq!(found, ids(&[&by_form]));
+
+    // 表示用 ID
+    let found = seeder
+        .search(&WorkflowSearchCriteria {
+            keyword: Some("WF-4".to_string()),
+            keyword_display_number: Some(DisplayNumber::new(4).unwrap()),
+            ..seeder.criteria()
+        })
+        .await;
+    assert_eq!(found, ids(&[&unmatched]));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_削除したコメントの本文はキーワードに一致しない(pool: PgPool) {
+    let seeder = Seeder::new(&pool).await;
+    let instance = seeder.submitted(1, "休暇申請", json!({}), 0).await;
+    let comment = seeder
+        .comment(&instance, "機密情報を含むため削除します")
+        .await;
+    let criteria = WorkflowSearchCriteria {
+        keyword: Some("機密情報".to_string()),
+        ..seeder.criteria()
+    };
+    assert_eq!(seeder.search(&criteria).await, ids(&[&instance]));
+
+    seeder.delete_comment(comment).await;
+
+    assert_eq!(
+        seeder.search(&criteria).await,
+        Vec::<WorkflowInstanceId>::new()
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_ファセットで絞り込める(pool: PgPool) {
+    let seeder = Seeder::new(&pool).await;
+    let approver = insert_user_raw(
+        &pool,
+        &seeder.tenant_id,
+        2,
+        "approver@example.com",
+        "承認者",
+        "active",
+    )
+    .await;
+    let early = seeder.submitted(1, "申請A", json!({}), 0).await;
+    let late = seeder.submitted(2, "申請B", json!({}), 60).await;
+    seeder.active_step(&late, &approver).await;
+    let my_draft = seeder.draft_by(&seeder.user_id, 3, "下書き", json!({}), test_now());
+    seeder.insert(&my_draft).await;
+    // 他人の下書きはどの条件でも検索結果に含まれない
+    let others_draft = seeder.draft_by(&approver, 4, "他人の下書き", json!({}), test_now());
+    seeder.insert(&others_draft).await;
+
+    let by_status = seeder
+        .search(&WorkflowSearchCriteria {
+            statuses: vec![WorkflowInstanceStatus::Draft],
+            ..seeder.criteria()
+        })
+        .await;
+    assert_eq!(by_status, ids(&[&my_draft]));
+
+    let by_approver = seeder
+        .search(&WorkflowSearchCriteria {
+            current_approver: Some(approver.clone()),
+            ..seeder.criteria()
+        })
+        .await;
+    assert_eq!(by_approver, ids(&[&late]));
+
+    let by_date = seeder
+        .search(&WorkflowSearchCriteria {
+            created_from: Some(test_now() + Duration::minutes(1)),
+            ..seeder.criteria()
+        })
+        .await;
+    assert_eq!(by_date, ids(&[&late]));
+
+    let by_applicant = seeder
+        .search(&WorkflowSearchCriteria {
+            initiated_by: Some(approver),
+            ..seeder.criteria()
+        })
+        .await;
+    assert_eq!(by_applicant, Vec::<WorkflowInstanceId>::new());
+
+    let by_definition = seeder
+        .search(&WorkflowSearchCriteria {
+            definition_ids: vec![WorkflowDefinitionId::new()],
+            ..seeder.criteria()
+        })
+        .await;
+    assert_eq!(by_definition, Vec::<WorkflowInstanceId>::new());
+
+    let all = seeder.search(&seeder.criteria()).await;
+    assert_eq!(all.len(), 3);
+    assert!(all.contains(early.id()));
+    assert!(!all.contains(others_draft.id()));
+
+    // テナント管理者向けの検索では他人の下書きも含める
+    let all_drafts = seeder
+        .search(&WorkflowSearchCriteria {
+            statuses: vec![WorkflowInstanceStatus::Draft],
+            include_all_drafts: true,
+            viewer_is_workflow_admin: true,
+            ..seeder.criteria()
+        })
+        .await;
+    assert_eq!(all_drafts.len(), 2);
+    assert!(all_drafts.contains(others_draft.id()));
+    assert!(all_drafts.contains(my_draft.id()));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_閲覧権限のあるインスタンスのみ検索できる(pool: PgPool) {
+    let seeder = Seeder::new(&pool).await;
+    let applicant = insert_user_raw(
+        &pool,
+        &seeder.tenant_id,
+        2,
+        "applicant@example.com",
+        "申請者",
+        "active",
+    )
+    .await;
+    let confidential_definition_id =
+        insert_definition(&pool, &seeder.tenant_id, &seeder.user_id, true).await;
+
+    // 他人の申請（検索するユーザーは関与していない）
+    let unrelated = seeder
+        .draft_by(&applicant, 1, "他人の申請", json!({}), test_now())
+        .submitted(test_now())
+        .unwrap();
+    seeder.insert(&unrelated).await;
+    // 他人の申請で、検索するユーザーが承認者
+    let assigned = seeder
+        .draft_by(&applicant, 2, "承認依頼", json!({}), test_now())
+        .submitted(test_now())
+        .unwrap();
+    seeder.insert(&assigned).await;
+    seeder.active_step(&assigned, &seeder.user_id).await;
+    // 機密定義の他人の申請
+    let confidential = WorkflowInstance::new(NewWorkflowInstance {
+        id: WorkflowInstanceId::new(),
+        tenant_id: seeder.tenant_id.clone(),
+        definition_id: confidential_definition_id,
+        definition_version: Version::initial(),
+        display_number: DisplayNumber::new(3).unwrap(),
+        title: "人事評価".to_string(),
+        form_data: json!({}),
+        initiated_by: applicant,
+        now: test_now(),
+    })
+    .submitted(test_now())
+    .unwrap();
+    seeder.insert(&confidential).await;
+
+    // 一般ユーザーは関与しているインスタンスのみ
+    let found = seeder.search(&seeder.criteria()).await;
+    assert_eq!(found, ids(&[&assigned]));
+
+    // ワークフロー管理者は機密定義以外のすべて
+    let found = seeder
+        .search(&WorkflowSearchCriteria {
+            viewer_is_workflow_admin: true,
+            ..seeder.criteria()
+        })
+        .await;
+    assert_eq!(found.len(), 2);
+    assert!(found.contains(unrelated.id()));
+    assert!(found.contains(assigned.id()));
+    assert!(!found.contains(confidential.id()));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_ウォッチしているインスタンスは機密定義でも検索できる(
+    pool: PgPool,
+) {
+    let seeder = Seeder::new(&pool).await;
+    let applicant = insert_user_raw(
+        &pool,
+        &seeder.tenant_id,
+        2,
+        "applicant@example.com",
+        "申請者",
+        "active",
+    )
+    .await;
+    let confidential_definition_id =
+        insert_definition(&pool, &seeder.tenant_id, &seeder.user_id, true).await;
+    let watched = WorkflowInstance::new(NewWorkflowInstance {
+        id: WorkflowInstanceId::new(),
+        tenant_id: seeder.tenant_id.clone(),
+        definition_id: confidential_definition_id,
+        definition_version: Version::initial(),
+        display_number: DisplayNumber::new(1).unwrap(),
+        title: "人事評価".to_string(),
+        form_data: json!({}),
+        initiated_by: applicant,
+        now: test_now(),
+    })
+    .submitted(test_now())
+    .unwrap();
+    seeder.insert(&watched).await;
+    PostgresWorkflowWatcherRepository::new(pool.clone())
+        .insert(
+            &WorkflowWatcher::new(watched.id().clone(), seeder.user_id.clone(), test_now()),
+            &seeder.tenant_id,
+        )
+        .await
+        .unwrap();
+
+    let found = seeder.search(&seeder.criteria()).await;
+
+    assert_eq!(found, ids(&[&watched]));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_カーソルで次ページを重複なく取得できる(pool: PgPool) {
+    let seeder = Seeder::new(&pool).await;
+    let first = seeder.submitted(1, "申請1", json!({}), 0).await;
+    let second = seeder.submitted(2, "申請2", json!({}), 1).await;
+    let third = seeder.submitted(3, "申請3", json!({}), 2).await;
+    let sut = PostgresWorkflowSearchRepository::new(pool.clone());
+
+    let page1 = sut
+        .search(&seeder.tenant_id, &seeder.criteria(), None, 2)
+        .await
+        .unwrap();
+    assert_eq!(page1.ids, ids(&[&third, &second]));
+    let cursor = page1.next_cursor.expect("次ページがあること");
+
+    let page2 = sut
+        .search(&seeder.tenant_id, &seeder.criteria(), Some(&cursor), 2)
+        .await
+        .unwrap();
+    assert_eq!(page2.ids, ids(&[&first]));
+    assert_eq!(page2.next_cursor, None);
+
+    let err = sut
+        .search(&seeder.tenant_id, &seeder.criteria(), Some("invalid"), 2)
+        .await
+        .unwrap_err();
+    assert!(matches!(err.kind(), InfraErrorKind::InvalidInput(_)));
+}
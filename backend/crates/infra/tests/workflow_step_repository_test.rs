@@ -15,6 +15,7 @@ use common::{
     assert_workflow_invariants,
     create_test_instance,
     create_test_step,
+    insert_user_raw,
     seed_tenant_id,
     seed_user_id,
     test_now,
@@ -22,7 +23,13 @@ use common::{
 use ringiflow_domain::{
     tenant::TenantId,
     value_objects::{DisplayNumber, Version},
-    workflow::{StepDecision, WorkflowInstance, WorkflowInstanceId, WorkflowStepId},
+    workflow::{
+        StepDecision,
+        WorkflowInstance,
+        WorkflowInstanceId,
+        WorkflowStepId,
+        WorkflowStepStatus,
+    },
 };
 use ringiflow_infra::{
     db::{PgTransactionManager, TransactionManager},
@@ -160,24 +167,102 @@ async fn test_find_by_instance_別テナントのステップは取得できな
 }
 
 #[sqlx::test(migrations = "../../migrations")]
-async fn test_find_by_assigned_to_担当者のタスク一覧を取得できる(pool: PgPool) {
+async fn test_find_active_by_assigned_to_担当者のアクティブなタスクを取得できる(
+    pool: PgPool,
+) {
     let ctx = setup_repos_with_instance(pool, 100).await;
     let user_id = seed_user_id();
 
-    let step = create_test_step(ctx.instance.id(), 1);
+    let active_steps: Vec<_> = (1..4)
+        .map(|n| create_test_step(ctx.instance.id(), n).activated(test_now()))
+        .collect();
+    let pending_step = create_test_step(ctx.instance.id(), 4);
 
     let mut tx = ctx.tx_manager.begin().await.unwrap();
-    ctx.sut
-        .insert(&mut tx, &step, &ctx.tenant_id)
+    for step in active_steps.iter().chain([&pending_step]) {
+        ctx.sut.insert(&mut tx, step, &ctx.tenant_id).await.unwrap();
+    }
+    tx.commit().await.unwrap();
+
+    // 2 件ずつ最後のページまで取得する
+    let mut steps = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = ctx
+            .sut
+            .find_active_by_assigned_to(&ctx.tenant_id, &user_id, cursor.as_deref(), 2)
+            .await
+            .unwrap();
+        assert!(page.items.len() <= 2);
+        steps.extend(page.items);
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    let keys: Vec<_> = steps
+        .iter()
+        .map(|s| (s.created_at(), *s.id().as_uuid()))
+        .collect();
+    let mut expected = keys.clone();
+    expected.sort_by(|a, b| b.cmp(a));
+    expected.dedup();
+    assert_eq!(keys, expected);
+    assert!(
+        steps
+            .iter()
+            .all(|s| s.status() == WorkflowStepStatus::Active)
+    );
+    for step in &active_steps {
+        assert!(keys.contains(&(step.created_at(), *step.id().as_uuid())));
+    }
+    assert!(!keys.contains(&(pending_step.created_at(), *pending_step.id().as_uuid())));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_count_by_assigned_to_アクティブと本日完了の件数を取得できる(
+    pool: PgPool,
+) {
+    let ctx = setup_repos_with_instance(pool, 100).await;
+    let user_id = seed_user_id();
+    let now = test_now();
+    let active_before = ctx
+        .sut
+        .count_active_by_assigned_to(&ctx.tenant_id, &user_id)
+        .await
+        .unwrap();
+    let completed_before = ctx
+        .sut
+        .count_completed_by_assigned_to_since(&ctx.tenant_id, &user_id, now)
         .await
         .unwrap();
-    tx.commit().await.unwrap();
 
-    let result = ctx.sut.find_by_assigned_to(&ctx.tenant_id, &user_id).await;
+    let active = create_test_step(ctx.instance.id(), 1).activated(now);
+    let completed = create_test_step(ctx.instance.id(), 2)
+        .activated(now)
+        .approve(None, now)
+        .unwrap();
+    let mut tx = ctx.tx_manager.begin().await.unwrap();
+    for step in [&active, &completed] {
+        ctx.sut.insert(&mut tx, step, &ctx.tenant_id).await.unwrap();
+    }
+    tx.commit().await.unwrap();
 
-    assert!(result.is_ok());
-    let steps = result.unwrap();
-    assert!(!steps.is_empty());
+    assert_eq!(
+        ctx.sut
+            .count_active_by_assigned_to(&ctx.tenant_id, &user_id)
+            .await
+            .unwrap(),
+        active_before + 1
+    );
+    assert_eq!(
+        ctx.sut
+            .count_completed_by_assigned_to_since(&ctx.tenant_id, &user_id, now)
+            .await
+            .unwrap(),
+        completed_before + 1
+    );
 }
 
 #[sqlx::test(migrations = "../../migrations")]
@@ -426,3 +511,56 @@ async fn test_ステップを完了できる(pool: PgPool) {
 
     assert_workflow_invariants(&ctx.pool, ctx.instance.id(), &ctx.tenant_id).await;
 }
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_ステップの担当者を変更できる(pool: PgPool) {
+    let ctx = setup_repos_with_instance(pool, 100).await;
+    let now = test_now();
+    let new_assignee = insert_user_raw(
+        &ctx.pool,
+        &ctx.tenant_id,
+        100,
+        "reassigned@example.com",
+        "新担当者",
+        "active",
+    )
+    .await;
+
+    let step = create_test_step(ctx.instance.id(), 1);
+    let step_id = step.id().clone();
+    let v1 = step.version();
+
+    let mut tx = ctx.tx_manager.begin().await.unwrap();
+    ctx.sut
+        .insert(&mut tx, &step, &ctx.tenant_id)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    let active_step = step.activated(now);
+    let v2 = active_step.version();
+    let reassigned_step = active_step
+        .clone()
+        .reassigned(new_assignee.clone(), now)
+        .unwrap();
+
+    let mut tx = ctx.tx_manager.begin().await.unwrap();
+    ctx.sut
+        .update_with_version_check(&mut tx, &active_step, v1, &ctx.tenant_id)
+        .await
+        .unwrap();
+    ctx.sut
+        .update_with_version_check(&mut tx, &reassigned_step, v2, &ctx.tenant_id)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    let found = ctx
+        .sut
+        .find_by_id(&step_id, &ctx.tenant_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.assigned_to(), Some(&new_assignee));
+    assert_eq!(found.version(), reassigned_step.version());
+}
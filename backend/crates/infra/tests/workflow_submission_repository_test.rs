@@ -0,0 +1,152 @@
+//! WorkflowSubmissionRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test workflow_submission_repository_test
+//! ```
+
+mod common;
+
+use common::{create_other_tenant, create_test_instance, seed_tenant_id, seed_user_id, test_now};
+use pretty_assertions::assert_eq;
+use ringiflow_domain::{
+    document::DocumentId,
+    workflow::{
+        NewWorkflowSubmission,
+        SubmissionAttachment,
+        WorkflowInstance,
+        WorkflowSubmission,
+        WorkflowSubmissionId,
+    },
+};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{
+        PostgresWorkflowInstanceRepository,
+        PostgresWorkflowSubmissionRepository,
+        WorkflowInstanceRepository,
+        WorkflowSubmissionRepository,
+    },
+};
+use serde_json::{Value as JsonValue, json};
+use sqlx::PgPool;
+
+/// インスタンスを INSERT する共通セットアップ
+async fn setup_instance(pool: &PgPool) -> WorkflowInstance {
+    let instance_repo = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+
+    let instance = create_test_instance(100);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    instance_repo.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    instance
+}
+
+fn create_submission(
+    instance: &WorkflowInstance,
+    round: i32,
+    form_data: JsonValue,
+    attachments: Vec<SubmissionAttachment>,
+) -> WorkflowSubmission {
+    WorkflowSubmission::new(NewWorkflowSubmission {
+        id: WorkflowSubmissionId::new(),
+        tenant_id: seed_tenant_id(),
+        instance_id: instance.id().clone(),
+        round,
+        form_data,
+        attachments,
+        submitted_by: seed_user_id(),
+        now: test_now(),
+    })
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_insertで記録したスナップショットをラウンド順に取得できる(
+    pool: PgPool,
+) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowSubmissionRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+    let attachment = SubmissionAttachment {
+        document_id:  DocumentId::new(),
+        filename:     "見積書.pdf".to_string(),
+        content_type: "application/pdf".to_string(),
+        size:         2048,
+    };
+    let second = create_submission(&instance, 2, json!({"amount": 800}), vec![attachment]);
+    let first = create_submission(&instance, 1, json!({"amount": 1000}), vec![]);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(&mut tx, &second, &tenant_id).await.unwrap();
+    sut.insert(&mut tx, &first, &tenant_id).await.unwrap();
+    tx.commit().await.unwrap();
+
+    let result = sut
+        .find_by_instance(instance.id(), &tenant_id)
+        .await
+        .unwrap();
+
+    assert_eq!(result, vec![first, second]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_同一ラウンドの重複記録はエラーになる(pool: PgPool) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowSubmissionRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool);
+    let tenant_id = seed_tenant_id();
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(
+        &mut tx,
+        &create_submission(&instance, 1, json!({}), vec![]),
+        &tenant_id,
+    )
+    .await
+    .unwrap();
+    let result = sut
+        .insert(
+            &mut tx,
+            &create_submission(&instance, 1, json!({}), vec![]),
+            &tenant_id,
+        )
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_テナント分離_別テナントのスナップショットは取得できない(
+    pool: PgPool,
+) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowSubmissionRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let tenant_id = seed_tenant_id();
+    let other_tenant_id = create_other_tenant(&pool).await;
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert(
+        &mut tx,
+        &create_submission(&instance, 1, json!({}), vec![]),
+        &tenant_id,
+    )
+    .await
+    .unwrap();
+    tx.commit().await.unwrap();
+
+    let result = sut
+        .find_by_instance(instance.id(), &other_tenant_id)
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+}
@@ -0,0 +1,144 @@
+//! WorkflowWatcherRepository 統合テスト
+//!
+//! データベースを使用したテスト。sqlx::test マクロを使用して、
+//! テストごとにトランザクションを作成しロールバックする。
+//!
+//! 実行方法:
+//! ```bash
+//! just setup-db
+//! cd backend && cargo test -p ringiflow-infra --test workflow_watcher_repository_test
+//! ```
+
+mod common;
+
+use common::{
+    create_other_tenant,
+    create_test_instance,
+    insert_user_raw,
+    seed_tenant_id,
+    seed_user_id,
+    test_now,
+};
+use pretty_assertions::assert_eq;
+use ringiflow_domain::workflow::{WorkflowInstance, WorkflowWatcher};
+use ringiflow_infra::{
+    db::{PgTransactionManager, TransactionManager},
+    repository::{
+        PostgresWorkflowInstanceRepository,
+        PostgresWorkflowWatcherRepository,
+        WorkflowInstanceRepository,
+        WorkflowWatcherRepository,
+    },
+};
+use sqlx::PgPool;
+
+/// インスタンスを INSERT する共通セットアップ
+async fn setup_instance(pool: &PgPool) -> WorkflowInstance {
+    let instance_repo = PostgresWorkflowInstanceRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+
+    let instance = create_test_instance(100);
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    instance_repo.insert(&mut tx, &instance).await.unwrap();
+    tx.commit().await.unwrap();
+
+    instance
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_insert_allで登録したウォッチャーをfind_by_instanceで取得できる(
+    pool: PgPool,
+) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowWatcherRepository::new(pool.clone());
+    let tx_manager = PgTransactionManager::new(pool.clone());
+    let tenant_id = seed_tenant_id();
+    let other_user_id = insert_user_raw(
+        &pool,
+        &tenant_id,
+        900,
+        "watcher@example.com",
+        "ウォッチャー",
+        "active",
+    )
+    .await;
+    let watchers = vec![
+        WorkflowWatcher::new(instance.id().clone(), seed_user_id(), test_now()),
+        WorkflowWatcher::new(instance.id().clone(), other_user_id, test_now()),
+    ];
+
+    let mut tx = tx_manager.begin().await.unwrap();
+    sut.insert_all(&mut tx, &watchers, &tenant_id)
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    let mut result = sut
+        .find_by_instance(instance.id(), &tenant_id)
+        .await
+        .unwrap();
+    let mut expected = watchers;
+    result.sort_by_key(|w| *w.user_id().as_uuid());
+    expected.sort_by_key(|w| *w.user_id().as_uuid());
+    assert_eq!(result, expected);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_登録済みのウォッチャーを再登録しても重複しない(pool: PgPool) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowWatcherRepository::new(pool);
+    let tenant_id = seed_tenant_id();
+    let watcher = WorkflowWatcher::new(instance.id().clone(), seed_user_id(), test_now());
+
+    sut.insert(&watcher, &tenant_id).await.unwrap();
+    sut.insert(&watcher, &tenant_id).await.unwrap();
+
+    let result = sut
+        .find_by_instance(instance.id(), &tenant_id)
+        .await
+        .unwrap();
+    assert_eq!(result, vec![watcher]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_deleteでウォッチャーを解除できる(pool: PgPool) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowWatcherRepository::new(pool);
+    let tenant_id = seed_tenant_id();
+    let watcher = WorkflowWatcher::new(instance.id().clone(), seed_user_id(), test_now());
+    sut.insert(&watcher, &tenant_id).await.unwrap();
+
+    sut.delete(instance.id(), &seed_user_id(), &tenant_id)
+        .await
+        .unwrap();
+    // 未登録のウォッチャーの解除も成功する
+    sut.delete(instance.id(), &seed_user_id(), &tenant_id)
+        .await
+        .unwrap();
+
+    let result = sut
+        .find_by_instance(instance.id(), &tenant_id)
+        .await
+        .unwrap();
+    assert!(result.is_empty());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_テナント分離_別テナントのウォッチャーは取得できない(
+    pool: PgPool,
+) {
+    let instance = setup_instance(&pool).await;
+    let sut = PostgresWorkflowWatcherRepository::new(pool.clone());
+    let tenant_id = seed_tenant_id();
+    let other_tenant_id = create_other_tenant(&pool).await;
+    let watcher = WorkflowWatcher::new(instance.id().clone(), seed_user_id(), test_now());
+    sut.insert(&watcher, &tenant_id).await.unwrap();
+
+    let result = sut
+        .find_by_instance(instance.id(), &other_tenant_id)
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+}
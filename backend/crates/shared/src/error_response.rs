@@ -9,6 +9,12 @@
 //!   依存を入れない）
 //! - よく使うエラー種別は便利コンストラクタで提供し、URI のハードコードを排除
 //! - サービス固有のエラーは `new()` で自由に作成可能
+//! - `code` は `type` URI の末尾と同じ機械可読なコード。クライアントはこれで分岐し、
+//!   `detail` の文言（ロケールで変わる）には依存しない
+//! - `message_code` / `params` は `detail` の元になったメッセージコードとパラメータ。
+//!   BFF はこれを使って `detail` を要求されたロケールの文言に展開する
+
+use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
@@ -24,17 +30,25 @@ const ERROR_TYPE_BASE: &str = "https://ringiflow.example.com/errors";
 #[cfg_attr(feature = "openapi", schema(as = ProblemDetails))]
 pub struct ErrorResponse {
     #[serde(rename = "type")]
-    pub error_type: String,
-    pub title:      String,
-    pub status:     u16,
-    pub detail:     String,
+    pub error_type:   String,
+    /// 機械可読なエラーコード（例: `"not-found"`）。ロケールによらず一定
+    pub code:         String,
+    pub title:        String,
+    pub status:       u16,
+    pub detail:       String,
+    /// `detail` のメッセージコード（例: `"too-long"`）。ロケールによらず一定
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_code: Option<String>,
+    /// `detail` に埋め込んだパラメータ（例: `{"field": "user-name", "max": "100"}`）
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params:       BTreeMap<String, String>,
 }
 
 impl ErrorResponse {
     /// 汎用コンストラクタ
     ///
     /// サービス固有のエラー種別を作成する場合に使用する。
-    /// `error_type_suffix` はベース URI に付加される（例:
+    /// `error_type_suffix` はベース URI に付加され、そのまま `code` にもなる（例:
     /// `"credential-not-found"`）。
     pub fn new(
         error_type_suffix: &str,
@@ -44,12 +58,26 @@ impl ErrorResponse {
     ) -> Self {
         Self {
             error_type: format!("{ERROR_TYPE_BASE}/{error_type_suffix}"),
+            code: error_type_suffix.to_string(),
             title: title.into(),
             status,
             detail: detail.into(),
+            message_code: None,
+            params: BTreeMap::new(),
         }
     }
 
+    /// `detail` のメッセージコードとパラメータを設定する
+    pub fn with_message(
+        mut self,
+        message_code: impl Into<String>,
+        params: BTreeMap<String, String>,
+    ) -> Self {
+        self.message_code = Some(message_code.into());
+        self.params = params;
+        self
+    }
+
     /// 400 Bad Request
     pub fn bad_request(detail: impl Into<String>) -> Self {
         Self::new("bad-request", "Bad Request", 400, detail)
@@ -90,6 +118,7 @@ impl ErrorResponse {
             500,
             "内部エラーが発生しました",
         )
+        .with_message("internal-error", BTreeMap::new())
     }
 
     /// 503 Service Unavailable
@@ -110,6 +139,7 @@ mod tests {
             error.error_type,
             "https://ringiflow.example.com/errors/custom-error"
         );
+        assert_eq!(error.code, "custom-error");
         assert_eq!(error.title, "Custom Error");
         assert_eq!(error.status, 418);
         assert_eq!(error.detail, "カスタムエラー");
@@ -139,6 +169,31 @@ mod tests {
         assert_eq!(error.title, "Internal Server Error");
         assert_eq!(error.status, 500);
         assert_eq!(error.detail, "内部エラーが発生しました");
+        assert_eq!(error.message_code.as_deref(), Some("internal-error"));
+    }
+
+    #[test]
+    fn test_with_message_でメッセージコードとパラメータがシリアライズされる() {
+        let params = BTreeMap::from([
+            ("field".to_string(), "user-name".to_string()),
+            ("max".to_string(), "100".to_string()),
+        ]);
+        let error =
+            ErrorResponse::validation_error("ユーザー名は 100 文字以内である必要があります")
+                .with_message("too-long", params);
+        let json = serde_json::to_value(&error).unwrap();
+
+        assert_eq!(json["message_code"], "too-long");
+        assert_eq!(json["params"]["field"], "user-name");
+        assert_eq!(json["params"]["max"], "100");
+    }
+
+    #[test]
+    fn test_メッセージコードがない場合はシリアライズしない() {
+        let json = serde_json::to_value(ErrorResponse::bad_request("不正なリクエスト")).unwrap();
+
+        assert!(json.get("message_code").is_none());
+        assert!(json.get("params").is_none());
     }
 
     #[test]
@@ -151,6 +206,7 @@ mod tests {
             json["type"],
             "https://ringiflow.example.com/errors/bad-request"
         );
+        assert_eq!(json["code"], "bad-request");
         assert_eq!(json["title"], "Bad Request");
         assert_eq!(json["status"], 400);
         assert_eq!(json["detail"], "不正なリクエスト");
@@ -174,6 +230,7 @@ mod tests {
     fn test_jsonデシリアライズが正しく動作する() {
         let json = r#"{
             "type": "https://ringiflow.example.com/errors/not-found",
+            "code": "not-found",
             "title": "Not Found",
             "status": 404,
             "detail": "見つかりません"
@@ -184,9 +241,12 @@ mod tests {
             error.error_type,
             "https://ringiflow.example.com/errors/not-found"
         );
+        assert_eq!(error.code, "not-found");
         assert_eq!(error.title, "Not Found");
         assert_eq!(error.status, 404);
         assert_eq!(error.detail, "見つかりません");
+        assert_eq!(error.message_code, None);
+        assert!(error.params.is_empty());
     }
 }
 
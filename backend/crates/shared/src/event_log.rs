@@ -64,7 +64,17 @@ pub mod event {
         pub const STEP_APPROVED: &str = "step.approved";
         pub const STEP_REJECTED: &str = "step.rejected";
         pub const STEP_CHANGES_REQUESTED: &str = "step.changes_requested";
+        pub const STEP_APPROVAL_RETRACTED: &str = "step.approval_retracted";
         pub const WORKFLOW_RESUBMITTED: &str = "workflow.resubmitted";
+        pub const FORM_DATA_EDITED: &str = "workflow.form_data_edited";
+        pub const WORKFLOW_FORCE_CANCELLED: &str = "workflow.force_cancelled";
+        pub const STEP_REASSIGNED: &str = "step.reassigned";
+        pub const WORKFLOW_FORCE_COMPLETED: &str = "workflow.force_completed";
+        pub const WORKFLOW_DELETED: &str = "workflow.deleted";
+        pub const WORKFLOW_PURGED: &str = "workflow.purged";
+        pub const WORKFLOW_SCHEDULE_RUN: &str = "workflow_schedule.run";
+        pub const PROXY_GRANTED: &str = "proxy_grant.granted";
+        pub const PROXY_REVOKED: &str = "proxy_grant.revoked";
 
         // 認証
         pub const LOGIN_SUCCESS: &str = "auth.login_success";
@@ -80,6 +90,7 @@ pub mod event {
     pub mod entity_type {
         pub const WORKFLOW_INSTANCE: &str = "workflow_instance";
         pub const WORKFLOW_STEP: &str = "workflow_step";
+        pub const WORKFLOW_SCHEDULE: &str = "workflow_schedule";
         pub const USER: &str = "user";
         pub const SESSION: &str = "session";
         pub const NOTIFICATION_LOG: &str = "notification_log";
@@ -0,0 +1,49 @@
+-- workflow_form_data_changes テーブルの作成
+-- 構文リファレンス: README.md
+--
+-- 承認者が承認時にフォームデータを編集した際の、フィールド単位の変更履歴。
+-- 誰が（changed_by）・どのステップで（step_id）・どのフィールドを（field_id）・
+-- 何から何へ（old_value → new_value）変更したかを記録する。
+--
+-- 編集可能なフィールドは定義 JSON の承認ステップの editableFields で宣言する。
+--
+-- 注: id は UUID v7（時系列ソート可能）を使用。
+-- アプリケーション側で生成するため DEFAULT 句なし。
+-- 参照: docs/70_ADR/001_ID形式の選定.md
+
+CREATE TABLE workflow_form_data_changes (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    instance_id UUID NOT NULL REFERENCES workflow_instances(id) ON DELETE CASCADE,
+    step_id UUID NOT NULL REFERENCES workflow_steps(id) ON DELETE CASCADE,
+    field_id VARCHAR(100) NOT NULL,
+    old_value JSONB NOT NULL,
+    new_value JSONB NOT NULL,
+    changed_by UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- インデックス
+CREATE INDEX workflow_form_data_changes_instance_idx ON workflow_form_data_changes(instance_id);
+CREATE INDEX workflow_form_data_changes_tenant_idx ON workflow_form_data_changes(tenant_id);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE workflow_form_data_changes ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON workflow_form_data_changes
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE workflow_form_data_changes IS 'ワークフローフォームデータ変更履歴（承認者編集）';
+COMMENT ON COLUMN workflow_form_data_changes.id IS '主キー';
+COMMENT ON COLUMN workflow_form_data_changes.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN workflow_form_data_changes.instance_id IS 'ワークフローインスタンスID（FK）';
+COMMENT ON COLUMN workflow_form_data_changes.step_id IS '編集が行われた承認ステップID（FK）';
+COMMENT ON COLUMN workflow_form_data_changes.field_id IS 'フォームフィールドID';
+COMMENT ON COLUMN workflow_form_data_changes.old_value IS '変更前の値（未設定の場合は JSON null）';
+COMMENT ON COLUMN workflow_form_data_changes.new_value IS '変更後の値';
+COMMENT ON COLUMN workflow_form_data_changes.changed_by IS '変更したユーザーID（FK）';
+COMMENT ON COLUMN workflow_form_data_changes.changed_at IS '変更日時';
@@ -0,0 +1,50 @@
+-- workflow_submissions テーブルの作成
+-- 構文リファレンス: README.md
+--
+-- 申請ラウンドごとのフォームデータと添付ファイル一覧のスナップショット。
+-- 初回申請をラウンド 1 とし、再申請のたびにラウンドを 1 つ増やして記録する。
+-- 記録後は更新しない（追記のみ）。
+--
+-- attachments は申請時点の添付ファイルのメタデータ配列:
+--   [{"document_id": "...", "filename": "...", "content_type": "...", "size": 1024}]
+--
+-- 注: id は UUID v7（時系列ソート可能）を使用。
+-- アプリケーション側で生成するため DEFAULT 句なし。
+-- 参照: docs/70_ADR/001_ID形式の選定.md
+
+CREATE TABLE workflow_submissions (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    instance_id UUID NOT NULL REFERENCES workflow_instances(id) ON DELETE CASCADE,
+    round INTEGER NOT NULL,
+    form_data JSONB NOT NULL,
+    attachments JSONB NOT NULL DEFAULT '[]',
+    submitted_by UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
+    submitted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT workflow_submissions_round_check CHECK (round >= 1),
+    CONSTRAINT workflow_submissions_instance_round_key UNIQUE (instance_id, round)
+);
+
+-- インデックス
+CREATE INDEX workflow_submissions_tenant_idx ON workflow_submissions(tenant_id);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE workflow_submissions ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON workflow_submissions
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE workflow_submissions IS 'ワークフロー申請ラウンドのスナップショット';
+COMMENT ON COLUMN workflow_submissions.id IS '主キー';
+COMMENT ON COLUMN workflow_submissions.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN workflow_submissions.instance_id IS 'ワークフローインスタンスID（FK）';
+COMMENT ON COLUMN workflow_submissions.round IS '申請ラウンド（初回申請 = 1、再申請ごとに +1）';
+COMMENT ON COLUMN workflow_submissions.form_data IS '申請時点のフォームデータ';
+COMMENT ON COLUMN workflow_submissions.attachments IS '申請時点の添付ファイル一覧（メタデータの配列）';
+COMMENT ON COLUMN workflow_submissions.submitted_by IS '申請者ID（FK）';
+COMMENT ON COLUMN workflow_submissions.submitted_at IS '申請日時';
@@ -0,0 +1,48 @@
+-- workflow_activities テーブルの作成
+-- 構文リファレンス: README.md
+--
+-- ワークフローインスタンスに対して発生した出来事（作成、申請、ステップのアクティブ化、
+-- 承認、却下、差し戻し、再申請、コメント投稿、添付ファイル追加、取消、担当者変更）を
+-- 時系列で記録する。記録後は更新しない（追記のみ）。
+--
+-- activity_type ごとの details の内容はドメインモデル（WorkflowActivity）を参照。
+-- actor_id はシステムによる操作の場合 NULL。
+--
+-- 注: id は UUID v7（時系列ソート可能）を使用。
+-- アプリケーション側で生成するため DEFAULT 句なし。
+-- 参照: docs/70_ADR/001_ID形式の選定.md
+
+CREATE TABLE workflow_activities (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    instance_id UUID NOT NULL REFERENCES workflow_instances(id) ON DELETE CASCADE,
+    activity_type VARCHAR(50) NOT NULL,
+    actor_id UUID REFERENCES users(id) ON DELETE RESTRICT,
+    step_id UUID REFERENCES workflow_steps(id) ON DELETE CASCADE,
+    details JSONB NOT NULL DEFAULT '{}',
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- インデックス
+CREATE INDEX workflow_activities_instance_idx ON workflow_activities(instance_id, occurred_at);
+CREATE INDEX workflow_activities_tenant_idx ON workflow_activities(tenant_id);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE workflow_activities ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON workflow_activities
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE workflow_activities IS 'ワークフローアクティビティ（インスタンスの出来事の時系列記録）';
+COMMENT ON COLUMN workflow_activities.id IS '主キー';
+COMMENT ON COLUMN workflow_activities.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN workflow_activities.instance_id IS 'ワークフローインスタンスID（FK）';
+COMMENT ON COLUMN workflow_activities.activity_type IS 'アクティビティ種別（created, submitted, step_activated, approved 等）';
+COMMENT ON COLUMN workflow_activities.actor_id IS '操作者ID（FK、システム操作の場合は NULL）';
+COMMENT ON COLUMN workflow_activities.step_id IS '対象ステップID（FK、ステップに紐づく出来事の場合）';
+COMMENT ON COLUMN workflow_activities.details IS '種別ごとの付加情報';
+COMMENT ON COLUMN workflow_activities.occurred_at IS '発生日時';
@@ -0,0 +1,62 @@
+-- workflow_event_outbox テーブルの作成
+-- 構文リファレンス: README.md
+--
+-- ワークフロードメインイベントのトランザクショナルアウトボックス。
+-- インスタンス・ステップの更新と同一トランザクションでイベントを書き込み、
+-- ディスパッチャが未配信のイベントを取り出してコンシューマ（通知など）に配信する。
+--
+-- 配信に失敗したイベントは attempts を増やし、next_attempt_at まで再配信を待つ。
+-- 配信済みのイベントは dispatched_at が設定される。
+--
+-- payload はイベント発生時点のインスタンス・ステップ情報:
+--   {"display_number": 42, "title": "...", "initiated_by": "...",
+--    "step": {...} | null, "activated_step": {...} | null}
+--
+-- 注: id は UUID v7（時系列ソート可能）を使用。
+-- アプリケーション側で生成するため DEFAULT 句なし。
+-- 参照: docs/70_ADR/001_ID形式の選定.md
+
+CREATE TABLE workflow_event_outbox (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    instance_id UUID NOT NULL REFERENCES workflow_instances(id) ON DELETE CASCADE,
+    event_type VARCHAR(50) NOT NULL,
+    actor_id UUID REFERENCES users(id) ON DELETE RESTRICT,
+    payload JSONB NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_error TEXT,
+    dispatched_at TIMESTAMPTZ,
+
+    CONSTRAINT workflow_event_outbox_attempts_check CHECK (attempts >= 0)
+);
+
+-- インデックス
+-- ディスパッチャのポーリング用（未配信のイベントのみ）
+CREATE INDEX workflow_event_outbox_pending_idx ON workflow_event_outbox(next_attempt_at)
+    WHERE dispatched_at IS NULL;
+CREATE INDEX workflow_event_outbox_tenant_idx ON workflow_event_outbox(tenant_id);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE workflow_event_outbox ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON workflow_event_outbox
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE workflow_event_outbox IS 'ワークフロードメインイベントのアウトボックス';
+COMMENT ON COLUMN workflow_event_outbox.id IS '主キー';
+COMMENT ON COLUMN workflow_event_outbox.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN workflow_event_outbox.instance_id IS 'ワークフローインスタンスID（FK）';
+COMMENT ON COLUMN workflow_event_outbox.event_type IS 'イベント種別（submitted, step_approved, approved 等）';
+COMMENT ON COLUMN workflow_event_outbox.actor_id IS '操作者ID（FK、システム操作の場合は NULL）';
+COMMENT ON COLUMN workflow_event_outbox.payload IS 'イベント発生時点のインスタンス・ステップ情報';
+COMMENT ON COLUMN workflow_event_outbox.occurred_at IS '発生日時';
+COMMENT ON COLUMN workflow_event_outbox.attempts IS '配信失敗回数';
+COMMENT ON COLUMN workflow_event_outbox.next_attempt_at IS '次回配信を試行する日時';
+COMMENT ON COLUMN workflow_event_outbox.last_error IS '直近の配信失敗理由';
+COMMENT ON COLUMN workflow_event_outbox.dispatched_at IS '配信完了日時（未配信の場合は NULL）';
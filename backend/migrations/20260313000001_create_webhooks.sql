@@ -0,0 +1,107 @@
+-- webhook_subscriptions / webhook_deliveries テーブルの作成
+-- 構文リファレンス: README.md
+--
+-- テナントが登録した外部 URL へワークフローイベントを HTTP POST で通知する。
+-- webhook_subscriptions は送信先と購読するイベント種別、
+-- webhook_deliveries は 1 購読 × 1 イベントの送信記録（配信ログ兼リトライキュー）。
+--
+-- 配信に失敗した場合は attempts を増やし、next_attempt_at まで再送を待つ。
+-- 最大試行回数に達すると status = 'failed' となる。
+--
+-- event_id はアウトボックスのイベント ID（テスト送信は NULL）。
+-- アウトボックスの行はインスタンス削除で消えるため FK は張らない。
+-- (subscription_id, event_id) の一意制約により、アウトボックスの再配信で
+-- 同じイベントが二重に送信されることを防ぐ。
+--
+-- 注: id は UUID v7（時系列ソート可能）を使用。
+-- アプリケーション側で生成するため DEFAULT 句なし。
+-- 参照: docs/70_ADR/001_ID形式の選定.md
+
+CREATE TABLE webhook_subscriptions (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    url VARCHAR(2048) NOT NULL,
+    event_types JSONB NOT NULL,
+    secret VARCHAR(256) NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    created_by UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT webhook_subscriptions_event_types_check CHECK (jsonb_typeof(event_types) = 'array')
+);
+
+CREATE TABLE webhook_deliveries (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    subscription_id UUID NOT NULL REFERENCES webhook_subscriptions(id) ON DELETE CASCADE,
+    event_id UUID,
+    event_type VARCHAR(50) NOT NULL,
+    payload JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    response_status INTEGER,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ,
+
+    CONSTRAINT webhook_deliveries_status_check CHECK (status IN ('pending', 'succeeded', 'failed')),
+    CONSTRAINT webhook_deliveries_attempts_check CHECK (attempts >= 0),
+    CONSTRAINT webhook_deliveries_subscription_event_key UNIQUE (subscription_id, event_id)
+);
+
+-- インデックス
+CREATE INDEX webhook_subscriptions_tenant_idx ON webhook_subscriptions(tenant_id);
+-- 配信ワーカーのポーリング用（送信待ちの配信のみ）
+CREATE INDEX webhook_deliveries_pending_idx ON webhook_deliveries(next_attempt_at)
+    WHERE status = 'pending';
+-- 配信ログの一覧表示用
+CREATE INDEX webhook_deliveries_subscription_idx ON webhook_deliveries(subscription_id, created_at DESC);
+CREATE INDEX webhook_deliveries_tenant_idx ON webhook_deliveries(tenant_id);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE webhook_subscriptions ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON webhook_subscriptions
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+ALTER TABLE webhook_deliveries ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON webhook_deliveries
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE webhook_subscriptions IS 'Webhook 購読（送信先 URL と購読イベント種別）';
+COMMENT ON COLUMN webhook_subscriptions.id IS '主キー';
+COMMENT ON COLUMN webhook_subscriptions.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN webhook_subscriptions.name IS 'Webhook 名';
+COMMENT ON COLUMN webhook_subscriptions.url IS '送信先 URL';
+COMMENT ON COLUMN webhook_subscriptions.event_types IS '購読するイベント種別の配列（submitted, approved 等）';
+COMMENT ON COLUMN webhook_subscriptions.secret IS 'HMAC-SHA256 署名用シークレット';
+COMMENT ON COLUMN webhook_subscriptions.is_active IS '有効フラグ（false の場合は配信しない）';
+COMMENT ON COLUMN webhook_subscriptions.created_by IS '作成者ID（FK）';
+COMMENT ON COLUMN webhook_subscriptions.created_at IS '作成日時';
+COMMENT ON COLUMN webhook_subscriptions.updated_at IS '更新日時';
+
+COMMENT ON TABLE webhook_deliveries IS 'Webhook 配信ログ';
+COMMENT ON COLUMN webhook_deliveries.id IS '主キー';
+COMMENT ON COLUMN webhook_deliveries.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN webhook_deliveries.subscription_id IS 'Webhook 購読ID（FK）';
+COMMENT ON COLUMN webhook_deliveries.event_id IS 'ワークフローイベントID（テスト送信の場合は NULL）';
+COMMENT ON COLUMN webhook_deliveries.event_type IS 'イベント種別（テスト送信は ping）';
+COMMENT ON COLUMN webhook_deliveries.payload IS '送信する JSON ペイロード';
+COMMENT ON COLUMN webhook_deliveries.status IS '配信ステータス（pending, succeeded, failed）';
+COMMENT ON COLUMN webhook_deliveries.attempts IS '送信試行回数';
+COMMENT ON COLUMN webhook_deliveries.next_attempt_at IS '次回送信を試行する日時';
+COMMENT ON COLUMN webhook_deliveries.response_status IS '直近の HTTP レスポンスステータス（通信エラーの場合は NULL）';
+COMMENT ON COLUMN webhook_deliveries.last_error IS '直近の送信失敗理由';
+COMMENT ON COLUMN webhook_deliveries.created_at IS '作成日時';
+COMMENT ON COLUMN webhook_deliveries.delivered_at IS '送信成功日時';
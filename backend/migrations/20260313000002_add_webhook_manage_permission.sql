@@ -0,0 +1,8 @@
+-- tenant_admin ロールに webhook:manage 権限を追加する
+-- Webhook の登録・編集・削除・テスト送信には独立した権限が必要
+
+UPDATE roles
+SET permissions = permissions || '["webhook:manage"]'::jsonb
+WHERE id = '00000000-0000-0000-0000-000000000002'
+  AND name = 'tenant_admin'
+  AND NOT permissions ? 'webhook:manage';
@@ -0,0 +1,34 @@
+-- ワークフロー検索用の全文検索インデックスを追加
+-- 構文リファレンス: README.md
+--
+-- 検索対象: 件名・フォームデータの値（workflow_instances）、コメント本文（workflow_comments）
+--
+-- search_vector は生成列（STORED）で、INSERT / UPDATE 時に自動で再計算される。
+-- テキスト検索設定は 'simple'（語幹処理なし）を使用する。
+-- 日本語は空白で分かち書きされないため、tsvector だけでは語の一部に一致しない。
+-- そのため件名とコメント本文には pg_trgm の GIN インデックスを併設し、部分一致（ILIKE）も
+-- インデックスで処理できるようにする。
+-- フォームデータは値（文字列・数値）のみを tsvector 化する（キーは対象外）。
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE workflow_instances
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple'::regconfig, title), 'A')
+        || setweight(jsonb_to_tsvector('simple'::regconfig, form_data, '["string", "numeric"]'), 'B')
+    ) STORED;
+
+ALTER TABLE workflow_comments
+    ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+        to_tsvector('simple'::regconfig, body)
+    ) STORED;
+
+-- インデックス
+CREATE INDEX workflow_instances_search_vector_idx ON workflow_instances USING GIN (search_vector);
+CREATE INDEX workflow_instances_title_trgm_idx ON workflow_instances USING GIN (title gin_trgm_ops);
+CREATE INDEX workflow_comments_search_vector_idx ON workflow_comments USING GIN (search_vector);
+CREATE INDEX workflow_comments_body_trgm_idx ON workflow_comments USING GIN (body gin_trgm_ops);
+
+-- コメント
+COMMENT ON COLUMN workflow_instances.search_vector IS '全文検索用ベクトル（件名 + フォームデータの値、生成列）';
+COMMENT ON COLUMN workflow_comments.search_vector IS '全文検索用ベクトル（本文、生成列）';
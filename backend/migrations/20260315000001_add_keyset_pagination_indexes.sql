@@ -0,0 +1,16 @@
+-- 一覧 API のキーセットページネーション用インデックスを追加
+-- 構文リファレンス: README.md
+--
+-- 自分の申請一覧・タスク一覧は (created_at DESC, id DESC) で並べ、
+-- 前ページ末尾の (created_at, id) より後ろの行を取得する。
+-- 絞り込み列とソートキーを 1 つの複合インデックスにまとめ、
+-- ページ位置に関わらずインデックススキャンだけで 1 ページ分を取得できるようにする。
+
+-- 自分の申請一覧（申請者で絞り込み）
+CREATE INDEX workflow_instances_initiated_by_keyset_idx
+    ON workflow_instances (tenant_id, initiated_by, created_at DESC, id DESC);
+
+-- タスク一覧（担当者のアクティブなステップで絞り込み）
+CREATE INDEX workflow_steps_assigned_to_keyset_idx
+    ON workflow_steps (tenant_id, assigned_to, created_at DESC, id DESC)
+    WHERE status = 'active';
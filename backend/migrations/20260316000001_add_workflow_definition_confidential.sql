@@ -0,0 +1,10 @@
+-- ワークフロー定義に機密区分を追加
+-- 構文リファレンス: README.md
+--
+-- 機密定義のインスタンスは、関与者（申請者・承認者）以外には
+-- ワークフロー管理者であっても閲覧させない。
+
+ALTER TABLE workflow_definitions
+    ADD COLUMN confidential BOOLEAN NOT NULL DEFAULT false;
+
+COMMENT ON COLUMN workflow_definitions.confidential IS '機密定義か（true の場合、関与者以外は管理者でも閲覧不可）';
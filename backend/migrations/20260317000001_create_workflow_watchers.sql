@@ -0,0 +1,40 @@
+-- workflow_watchers テーブルの作成と、ワークフロー定義への既定ウォッチャーの追加
+-- 構文リファレンス: README.md
+--
+-- ウォッチャーは承認者でなくてもワークフローインスタンスの進捗通知を受け取り、
+-- インスタンスを閲覧できるユーザー。自分でウォッチするほか、
+-- 定義の既定ウォッチャーがインスタンス作成時に登録される。
+
+CREATE TABLE workflow_watchers (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    instance_id UUID NOT NULL REFERENCES workflow_instances(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (instance_id, user_id)
+);
+
+-- インデックス
+CREATE INDEX workflow_watchers_user_idx ON workflow_watchers(user_id);
+CREATE INDEX workflow_watchers_tenant_idx ON workflow_watchers(tenant_id);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE workflow_watchers ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON workflow_watchers
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE workflow_watchers IS 'ワークフローウォッチャー（承認者以外で進捗通知を受け取るユーザー）';
+COMMENT ON COLUMN workflow_watchers.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN workflow_watchers.instance_id IS 'ワークフローインスタンスID（FK）';
+COMMENT ON COLUMN workflow_watchers.user_id IS 'ウォッチするユーザーID（FK）';
+COMMENT ON COLUMN workflow_watchers.created_at IS 'ウォッチ開始日時';
+
+-- ワークフロー定義の既定ウォッチャー
+ALTER TABLE workflow_definitions
+    ADD COLUMN default_watchers UUID[] NOT NULL DEFAULT '{}';
+
+COMMENT ON COLUMN workflow_definitions.default_watchers IS 'インスタンス作成時に登録するウォッチャーのユーザーID一覧';
@@ -0,0 +1,15 @@
+-- 下書きワークフローの削除・自動パージ用インデックスを追加
+-- 構文リファレンス: README.md
+--
+-- 自動パージは全テナントの下書きから一定期間更新のないものを古い順に取得する。
+-- 下書き削除時は添付ファイルの S3 オブジェクトを削除する前に、
+-- 複製で共有された同一キーを参照するドキュメントが残っていないかを確認する。
+
+-- 放置された下書きの検索（updated_at の古い順）
+CREATE INDEX workflow_instances_draft_updated_at_idx
+    ON workflow_instances (updated_at, id)
+    WHERE status = 'draft';
+
+-- S3 キーの参照確認
+CREATE INDEX documents_tenant_s3_key_idx
+    ON documents (tenant_id, s3_key);
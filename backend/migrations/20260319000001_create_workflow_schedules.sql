@@ -0,0 +1,87 @@
+-- workflow_schedules テーブルの作成
+-- 構文リファレンス: README.md
+--
+-- 定期的に発生する申請（月次のサブスクリプション更新、四半期報告など）を
+-- 繰り返し設定に従って自動作成するためのスケジュール。
+-- 実行日時（next_run_at）に達すると、所有者を申請者として title / form_data から
+-- 下書きを作成し、auto_submit の場合は approvers でそのまま申請する。
+--
+-- 繰り返し設定は recurrence_type で種別を表す:
+-- - monthly: 毎月 day_of_month 日の run_hour:run_minute（UTC、月末を超える日は最終日に丸める）
+-- - cron: cron_expression（分 時 日 月 曜日、UTC）
+--
+-- 注: id は UUID v7（時系列ソート可能）を使用。
+-- アプリケーション側で生成するため DEFAULT 句なし。
+-- 参照: docs/70_ADR/001_ID形式の選定.md
+
+CREATE TABLE workflow_schedules (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    owner_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    definition_id UUID NOT NULL REFERENCES workflow_definitions(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    form_data JSONB NOT NULL DEFAULT '{}',
+    recurrence_type VARCHAR(20) NOT NULL,
+    day_of_month SMALLINT,
+    run_hour SMALLINT,
+    run_minute SMALLINT,
+    cron_expression VARCHAR(100),
+    auto_submit BOOLEAN NOT NULL DEFAULT false,
+    approvers JSONB NOT NULL DEFAULT '[]',
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    next_run_at TIMESTAMPTZ,
+    last_run_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT workflow_schedules_recurrence_check CHECK (
+        (recurrence_type = 'monthly'
+            AND day_of_month BETWEEN 1 AND 31
+            AND run_hour BETWEEN 0 AND 23
+            AND run_minute BETWEEN 0 AND 59
+            AND cron_expression IS NULL)
+        OR (recurrence_type = 'cron'
+            AND cron_expression IS NOT NULL
+            AND day_of_month IS NULL AND run_hour IS NULL AND run_minute IS NULL)
+    ),
+    CONSTRAINT workflow_schedules_approvers_check CHECK (jsonb_typeof(approvers) = 'array')
+);
+
+-- インデックス
+CREATE INDEX workflow_schedules_owner_idx ON workflow_schedules(tenant_id, owner_id);
+CREATE INDEX workflow_schedules_definition_idx ON workflow_schedules(definition_id);
+-- スケジューラのポーリング用（有効なスケジュールのみ）
+CREATE INDEX workflow_schedules_due_idx ON workflow_schedules(next_run_at)
+    WHERE is_active;
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE workflow_schedules ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON workflow_schedules
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE workflow_schedules IS 'ワークフロースケジュール（定期的にワークフローを作成する繰り返し設定）';
+COMMENT ON COLUMN workflow_schedules.id IS '主キー';
+COMMENT ON COLUMN workflow_schedules.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN workflow_schedules.owner_id IS '所有者ID（FK、作成されるワークフローの申請者）';
+COMMENT ON COLUMN workflow_schedules.definition_id IS 'ワークフロー定義ID（FK）';
+COMMENT ON COLUMN workflow_schedules.name IS 'スケジュール名';
+COMMENT ON COLUMN workflow_schedules.title IS '作成するワークフローのタイトル';
+COMMENT ON COLUMN workflow_schedules.form_data IS '作成するワークフローのフォームデータ';
+COMMENT ON COLUMN workflow_schedules.recurrence_type IS '繰り返し種別（monthly, cron）';
+COMMENT ON COLUMN workflow_schedules.day_of_month IS '実行日（monthly のみ、1〜31）';
+COMMENT ON COLUMN workflow_schedules.run_hour IS '実行時（monthly のみ、UTC）';
+COMMENT ON COLUMN workflow_schedules.run_minute IS '実行分（monthly のみ）';
+COMMENT ON COLUMN workflow_schedules.cron_expression IS 'cron 式（cron のみ、UTC）';
+COMMENT ON COLUMN workflow_schedules.auto_submit IS '作成した下書きを自動申請するか';
+COMMENT ON COLUMN workflow_schedules.approvers IS '自動申請時の承認者（step_id と assigned_to の配列）';
+COMMENT ON COLUMN workflow_schedules.is_active IS '有効フラグ（false の場合は実行しない）';
+COMMENT ON COLUMN workflow_schedules.next_run_at IS '次回実行日時（無効の場合は NULL）';
+COMMENT ON COLUMN workflow_schedules.last_run_at IS '最終実行日時';
+COMMENT ON COLUMN workflow_schedules.created_at IS '作成日時';
+COMMENT ON COLUMN workflow_schedules.updated_at IS '更新日時';
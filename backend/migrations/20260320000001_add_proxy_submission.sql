@@ -0,0 +1,46 @@
+-- 代理申請: workflow_proxy_grants テーブルの作成と、ワークフローインスタンスへの代理人の追加
+-- 構文リファレンス: README.md
+--
+-- 代理権限は本人（principal）が代理人（proxy）に付与し、代理人は本人に代わって
+-- ワークフローを作成・申請できる（役員の稟議を秘書が起票する運用など）。
+-- 代理申請されたインスタンスは initiated_by が本人、proxy_submitted_by が代理人となる。
+
+CREATE TABLE workflow_proxy_grants (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    principal_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    proxy_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (principal_id, proxy_id),
+    CONSTRAINT workflow_proxy_grants_not_self CHECK (principal_id <> proxy_id)
+);
+
+-- インデックス
+CREATE INDEX workflow_proxy_grants_proxy_idx ON workflow_proxy_grants(proxy_id);
+CREATE INDEX workflow_proxy_grants_tenant_idx ON workflow_proxy_grants(tenant_id);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE workflow_proxy_grants ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON workflow_proxy_grants
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE workflow_proxy_grants IS '代理権限（本人に代わってワークフローを作成・申請できるユーザー）';
+COMMENT ON COLUMN workflow_proxy_grants.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN workflow_proxy_grants.principal_id IS '本人のユーザーID（FK、代理権限の付与者）';
+COMMENT ON COLUMN workflow_proxy_grants.proxy_id IS '代理人のユーザーID（FK）';
+COMMENT ON COLUMN workflow_proxy_grants.created_at IS '付与日時';
+
+-- ワークフローインスタンスの代理人
+-- 本人申請（既存のインスタンスを含む）は NULL
+ALTER TABLE workflow_instances
+    ADD COLUMN proxy_submitted_by UUID REFERENCES users(id);
+
+CREATE INDEX workflow_instances_proxy_submitted_by_idx
+    ON workflow_instances(tenant_id, proxy_submitted_by)
+    WHERE proxy_submitted_by IS NOT NULL;
+
+COMMENT ON COLUMN workflow_instances.proxy_submitted_by IS '代理申請の代理人ID（FK、本人申請の場合は NULL）';
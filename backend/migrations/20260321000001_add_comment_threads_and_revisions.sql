@@ -0,0 +1,59 @@
+-- ワークフローコメントの編集・削除・返信
+-- 構文リファレンス: README.md
+--
+-- - 返信: parent_id で親コメントを参照する（返信への返信は不可。スレッドは 1 階層）
+-- - 編集: 投稿者本人のみ、投稿から一定時間内に限り可能。編集前の本文は
+--   workflow_comment_revisions に残す
+-- - 削除: 投稿者本人またはワークフロー管理者による論理削除。
+--   返信のスレッドを保つため行は残し、本文は API で返さない
+
+ALTER TABLE workflow_comments
+    ADD COLUMN parent_id UUID REFERENCES workflow_comments(id) ON DELETE CASCADE,
+    ADD COLUMN edited_at TIMESTAMPTZ,
+    ADD COLUMN deleted_at TIMESTAMPTZ,
+    ADD COLUMN deleted_by UUID REFERENCES users(id) ON DELETE SET NULL;
+
+CREATE INDEX workflow_comments_parent_idx
+    ON workflow_comments(parent_id)
+    WHERE parent_id IS NOT NULL;
+
+COMMENT ON COLUMN workflow_comments.parent_id IS '返信先の親コメントID（FK、トップレベルのコメントは NULL）';
+COMMENT ON COLUMN workflow_comments.edited_at IS '最終編集日時（未編集の場合は NULL）';
+COMMENT ON COLUMN workflow_comments.deleted_at IS '論理削除日時（削除されていない場合は NULL）';
+COMMENT ON COLUMN workflow_comments.deleted_by IS '削除したユーザーID（FK、投稿者本人またはワークフロー管理者）';
+
+-- 編集履歴
+CREATE TABLE workflow_comment_revisions (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    comment_id UUID NOT NULL REFERENCES workflow_comments(id) ON DELETE CASCADE,
+    body TEXT NOT NULL,
+    edited_by UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
+    edited_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT workflow_comment_revisions_body_length CHECK (
+        char_length(body) >= 1 AND char_length(body) <= 2000
+    )
+);
+
+-- インデックス
+CREATE INDEX workflow_comment_revisions_comment_idx ON workflow_comment_revisions(comment_id, edited_at);
+CREATE INDEX workflow_comment_revisions_tenant_idx ON workflow_comment_revisions(tenant_id);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE workflow_comment_revisions ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON workflow_comment_revisions
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE workflow_comment_revisions IS 'ワークフローコメントの編集履歴（編集前の本文）';
+COMMENT ON COLUMN workflow_comment_revisions.id IS '主キー';
+COMMENT ON COLUMN workflow_comment_revisions.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN workflow_comment_revisions.comment_id IS 'コメントID（FK）';
+COMMENT ON COLUMN workflow_comment_revisions.body IS '編集前の本文（1〜2000文字）';
+COMMENT ON COLUMN workflow_comment_revisions.edited_by IS '編集したユーザーID（FK）';
+COMMENT ON COLUMN workflow_comment_revisions.edited_at IS '編集日時';
@@ -0,0 +1,19 @@
+-- ワークフローコメントの添付ファイル
+-- 構文リファレンス: README.md
+-- 詳細設計書: docs/40_詳細設計書/17_ドキュメント管理設計.md
+--
+-- 承認者がコメントに注釈付き PDF やスクリーンショットを添付できるようにする。
+-- documents に 3 つ目のコンテキスト comment_id を追加し、
+-- folder_id / workflow_instance_id / comment_id のいずれか 1 つだけを持つよう制約を差し替える。
+
+ALTER TABLE documents
+    ADD COLUMN comment_id UUID REFERENCES workflow_comments(id) ON DELETE CASCADE;
+
+ALTER TABLE documents DROP CONSTRAINT documents_context_check;
+ALTER TABLE documents ADD CONSTRAINT documents_context_check CHECK (
+    num_nonnulls(folder_id, workflow_instance_id, comment_id) = 1
+);
+
+CREATE INDEX idx_documents_comment_id ON documents (comment_id) WHERE comment_id IS NOT NULL;
+
+COMMENT ON COLUMN documents.comment_id IS '添付先のワークフローコメントID（FK、コメント添付以外は NULL）';
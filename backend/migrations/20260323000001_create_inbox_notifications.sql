@@ -0,0 +1,53 @@
+-- アプリ内通知（通知受信箱）
+-- 構文リファレンス: README.md
+--
+-- ヘッダーのベルに表示するユーザーごとの通知。メール通知（notification_logs）とは独立しており、
+-- メール送信の成否に関係なく、通知イベントごとに受信者 1 人につき 1 行を記録する。
+-- 保持期間を過ぎた行は core-service のバックグラウンドワーカーが削除する。
+
+CREATE TABLE inbox_notifications (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    event_type VARCHAR(50) NOT NULL,
+    workflow_instance_id UUID NOT NULL REFERENCES workflow_instances(id) ON DELETE CASCADE,
+    workflow_title VARCHAR(255) NOT NULL,
+    workflow_display_id VARCHAR(50) NOT NULL,
+    message VARCHAR(500) NOT NULL,
+    read_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- インデックス
+-- 受信箱一覧（キーセットページネーション: created_at DESC, id DESC）
+CREATE INDEX inbox_notifications_user_idx
+    ON inbox_notifications(tenant_id, user_id, created_at DESC, id DESC);
+-- 未読件数
+CREATE INDEX inbox_notifications_unread_idx
+    ON inbox_notifications(tenant_id, user_id)
+    WHERE read_at IS NULL;
+-- 保持期間切れの削除
+CREATE INDEX inbox_notifications_created_at_idx ON inbox_notifications(created_at);
+CREATE INDEX inbox_notifications_workflow_instance_idx ON inbox_notifications(workflow_instance_id);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE inbox_notifications ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON inbox_notifications
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE inbox_notifications IS 'アプリ内通知（ユーザーごとの通知受信箱）';
+COMMENT ON COLUMN inbox_notifications.id IS '主キー';
+COMMENT ON COLUMN inbox_notifications.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN inbox_notifications.user_id IS '受信者のユーザーID（FK）';
+COMMENT ON COLUMN inbox_notifications.event_type IS '通知イベント種別（notification_logs.event_type と同じ値）';
+COMMENT ON COLUMN inbox_notifications.workflow_instance_id IS '対象ワークフローインスタンスID（FK）';
+COMMENT ON COLUMN inbox_notifications.workflow_title IS '通知時点のワークフロータイトル';
+COMMENT ON COLUMN inbox_notifications.workflow_display_id IS '通知時点のワークフロー表示ID';
+COMMENT ON COLUMN inbox_notifications.message IS '通知の要約（1 行）';
+COMMENT ON COLUMN inbox_notifications.read_at IS '既読日時（未読の場合は NULL）';
+COMMENT ON COLUMN inbox_notifications.created_at IS '通知日時';
@@ -0,0 +1,78 @@
+-- 通知設定とダイジェスト待ち通知
+-- 構文リファレンス: README.md
+--
+-- ユーザーが通知イベント種別ごとにメールの配信方法（即時・ダイジェスト・停止）を選ぶ。
+-- 設定のない種別は即時として扱うため、既定値の行は作らない。
+-- 配信方法がダイジェストの通知は notification_digest_items に保留し、
+-- core-service のダイジェストワーカーが 1 通のメールにまとめて送信した後に削除する。
+
+CREATE TABLE notification_preferences (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    event_type VARCHAR(50) NOT NULL,
+    delivery VARCHAR(20) NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (tenant_id, user_id, event_type),
+    CONSTRAINT notification_preferences_delivery_check
+        CHECK (delivery IN ('immediate', 'digest', 'off'))
+);
+
+CREATE INDEX notification_preferences_user_idx ON notification_preferences(user_id);
+
+CREATE TABLE notification_digest_items (
+    id UUID PRIMARY KEY,
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    recipient_email VARCHAR(255) NOT NULL,
+    event_type VARCHAR(50) NOT NULL,
+    workflow_instance_id UUID NOT NULL REFERENCES workflow_instances(id) ON DELETE CASCADE,
+    workflow_title VARCHAR(255) NOT NULL,
+    workflow_display_id VARCHAR(50) NOT NULL,
+    message VARCHAR(500) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- インデックス
+-- ダイジェストの送信対象（受信者ごとに古い順）
+CREATE INDEX notification_digest_items_user_idx
+    ON notification_digest_items(tenant_id, user_id, created_at);
+CREATE INDEX notification_digest_items_workflow_instance_idx
+    ON notification_digest_items(workflow_instance_id);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE notification_preferences ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON notification_preferences
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+ALTER TABLE notification_digest_items ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON notification_digest_items
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE notification_preferences IS 'ユーザーの通知設定（通知イベント種別ごとのメール配信方法）';
+COMMENT ON COLUMN notification_preferences.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN notification_preferences.user_id IS 'ユーザーID（FK）';
+COMMENT ON COLUMN notification_preferences.event_type IS '通知イベント種別（notification_logs.event_type と同じ値）';
+COMMENT ON COLUMN notification_preferences.delivery IS '配信方法（immediate: 即時, digest: ダイジェスト, off: 停止）';
+COMMENT ON COLUMN notification_preferences.updated_at IS '更新日時';
+
+COMMENT ON TABLE notification_digest_items IS 'ダイジェストメールで送信するまで保留している通知';
+COMMENT ON COLUMN notification_digest_items.id IS '主キー';
+COMMENT ON COLUMN notification_digest_items.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN notification_digest_items.user_id IS '受信者のユーザーID（FK）';
+COMMENT ON COLUMN notification_digest_items.recipient_email IS '通知時点の受信者のメールアドレス';
+COMMENT ON COLUMN notification_digest_items.event_type IS '通知イベント種別（notification_logs.event_type と同じ値）';
+COMMENT ON COLUMN notification_digest_items.workflow_instance_id IS '対象ワークフローインスタンスID（FK）';
+COMMENT ON COLUMN notification_digest_items.workflow_title IS '通知時点のワークフロータイトル';
+COMMENT ON COLUMN notification_digest_items.workflow_display_id IS '通知時点のワークフロー表示ID';
+COMMENT ON COLUMN notification_digest_items.message IS '通知の要約（1 行）';
+COMMENT ON COLUMN notification_digest_items.created_at IS '通知日時';
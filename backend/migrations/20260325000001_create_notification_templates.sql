@@ -0,0 +1,39 @@
+-- notification_templates テーブルの作成
+-- 構文リファレンス: README.md
+--
+-- テナントが通知イベント種別ごとにメールの件名・HTML 本文・テキスト本文のテンプレートを上書きする。
+-- 行のない通知イベント種別は core-service に埋め込んだ組み込みテンプレートで送信する。
+-- テンプレートは tera 構文で、保存前に core-service が構文とサンプルデータでのレンダリングを検証する。
+
+CREATE TABLE notification_templates (
+    tenant_id UUID NOT NULL REFERENCES tenants(id) ON DELETE CASCADE,
+    event_type VARCHAR(50) NOT NULL,
+    subject_template VARCHAR(255) NOT NULL,
+    html_template TEXT NOT NULL,
+    text_template TEXT NOT NULL,
+    updated_by UUID NOT NULL REFERENCES users(id) ON DELETE RESTRICT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (tenant_id, event_type)
+);
+
+-- RLS 有効化 + ポリシー作成
+ALTER TABLE notification_templates ENABLE ROW LEVEL SECURITY;
+
+CREATE POLICY tenant_isolation ON notification_templates
+    FOR ALL
+    TO ringiflow_app
+    USING (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID)
+    WITH CHECK (tenant_id = NULLIF(current_setting('app.tenant_id', true), '')::UUID);
+
+-- コメント
+COMMENT ON TABLE notification_templates IS 'テナント独自の通知メールテンプレート（組み込みテンプレートの上書き）';
+COMMENT ON COLUMN notification_templates.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN notification_templates.event_type IS '通知イベント種別（notification_logs.event_type と同じ値）';
+COMMENT ON COLUMN notification_templates.subject_template IS '件名テンプレート（tera 構文）';
+COMMENT ON COLUMN notification_templates.html_template IS 'HTML 本文テンプレート（tera 構文、自動エスケープあり）';
+COMMENT ON COLUMN notification_templates.text_template IS 'テキスト本文テンプレート（tera 構文）';
+COMMENT ON COLUMN notification_templates.updated_by IS '最終更新者ID（FK）';
+COMMENT ON COLUMN notification_templates.created_at IS '作成日時';
+COMMENT ON COLUMN notification_templates.updated_at IS '更新日時';
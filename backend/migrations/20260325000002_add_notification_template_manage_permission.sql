@@ -0,0 +1,8 @@
+-- tenant_admin ロールに notification_template:manage 権限を追加する
+-- 通知テンプレートの上書き・削除・検証・プレビューには独立した権限が必要
+
+UPDATE roles
+SET permissions = permissions || '["notification_template:manage"]'::jsonb
+WHERE id = '00000000-0000-0000-0000-000000000002'
+  AND name = 'tenant_admin'
+  AND NOT permissions ? 'notification_template:manage';
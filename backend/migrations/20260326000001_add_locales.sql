@@ -0,0 +1,17 @@
+-- ユーザーのロケールとテナントの既定ロケールを追加
+-- 構文リファレンス: README.md
+--
+-- 通知メールやアプリ内通知の要約を受信者のロケールで作成する。
+-- ユーザーのロケールが NULL の場合はテナントの既定ロケールに従う。
+-- 既存のテナントは従来どおり日本語とする。
+
+ALTER TABLE tenants
+    ADD COLUMN default_locale VARCHAR(10) NOT NULL DEFAULT 'ja',
+    ADD CONSTRAINT tenants_default_locale_check CHECK (default_locale IN ('ja', 'en'));
+
+ALTER TABLE users
+    ADD COLUMN locale VARCHAR(10),
+    ADD CONSTRAINT users_locale_check CHECK (locale IN ('ja', 'en'));
+
+COMMENT ON COLUMN tenants.default_locale IS '既定ロケール（ja: 日本語, en: 英語）。ロケール未設定のユーザーに適用';
+COMMENT ON COLUMN users.locale IS 'ロケール（ja: 日本語, en: 英語）。NULL の場合はテナントの既定ロケールに従う';
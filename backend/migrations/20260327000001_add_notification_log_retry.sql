@@ -0,0 +1,49 @@
+-- notification_logs に送信リトライ用のカラムを追加
+-- 構文リファレンス: README.md
+--
+-- notification_logs を送信ログ兼リトライキューとして使う。
+-- 即時送信のメールは生成した本文を保存し、一時的なエラーで送信に失敗した場合は
+-- attempts を増やして next_attempt_at まで再送を待つ（status = 'pending'）。
+-- 恒久的なエラーまたは最大試行回数に達すると status = 'failed' となる。
+-- ダイジェストメールは本文を保存せず、再送しない。
+--
+-- sent_at は送信成功日時とし、送信していない行は NULL にする。
+-- 既存の行は 1 回送信を試行済みとして移行する（本文がないため再送の対象外）。
+
+ALTER TABLE notification_logs
+    ADD COLUMN html_body TEXT,
+    ADD COLUMN text_body TEXT,
+    ADD COLUMN attempts INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ADD COLUMN created_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+UPDATE notification_logs
+SET attempts = 1, next_attempt_at = sent_at, created_at = sent_at;
+
+ALTER TABLE notification_logs
+    ALTER COLUMN sent_at DROP NOT NULL,
+    ALTER COLUMN sent_at DROP DEFAULT;
+
+UPDATE notification_logs SET sent_at = NULL WHERE status <> 'sent';
+
+ALTER TABLE notification_logs
+    ADD CONSTRAINT notification_logs_status_check CHECK (status IN ('pending', 'sent', 'failed')),
+    ADD CONSTRAINT notification_logs_attempts_check CHECK (attempts >= 0);
+
+-- インデックス
+-- 再送ワーカーのポーリング用（送信待ちの通知のみ）
+CREATE INDEX idx_notification_logs_pending ON notification_logs (next_attempt_at)
+    WHERE status = 'pending';
+-- 管理画面の失敗した通知一覧用
+CREATE INDEX idx_notification_logs_failed ON notification_logs (tenant_id, created_at DESC)
+    WHERE status = 'failed';
+
+-- コメント
+COMMENT ON COLUMN notification_logs.status IS '送信ステータス（pending: 送信待ち・リトライ待ち, sent: 送信済み, failed: 失敗）';
+COMMENT ON COLUMN notification_logs.html_body IS '送信する HTML 本文（ダイジェスト・移行前の通知は NULL で、再送しない）';
+COMMENT ON COLUMN notification_logs.text_body IS '送信するテキスト本文（ダイジェスト・移行前の通知は NULL で、再送しない）';
+COMMENT ON COLUMN notification_logs.attempts IS '送信試行回数（管理者が再送すると 0 に戻る）';
+COMMENT ON COLUMN notification_logs.next_attempt_at IS '次回送信を試行する日時';
+COMMENT ON COLUMN notification_logs.error_message IS '直近の送信失敗理由';
+COMMENT ON COLUMN notification_logs.created_at IS '作成日時';
+COMMENT ON COLUMN notification_logs.sent_at IS '送信成功日時（未送信・失敗の場合は NULL）';
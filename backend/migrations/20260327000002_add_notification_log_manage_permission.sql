@@ -0,0 +1,8 @@
+-- tenant_admin ロールに notification_log:manage 権限を追加する
+-- 送信に失敗した通知の一覧・再送には独立した権限が必要
+
+UPDATE roles
+SET permissions = permissions || '["notification_log:manage"]'::jsonb
+WHERE id = '00000000-0000-0000-0000-000000000002'
+  AND name = 'tenant_admin'
+  AND NOT permissions ? 'notification_log:manage';
@@ -0,0 +1,26 @@
+-- workflow_event_outbox にコンシューマ単位の配信状態と配信停止状態を追加
+-- 構文リファレンス: README.md
+--
+-- delivered_consumers: 配信に成功したコンシューマ名。再配信時はここに含まれない
+--   コンシューマにのみ配信し、成功済みのコンシューマ（通知メール等）に重複して配信しない。
+-- failed_at: 最大試行回数に達した、またはペイロードを解釈できないイベントの配信停止日時。
+--   配信停止したイベントはディスパッチャが取り出さない。
+--
+-- ディスパッチャは取り出したイベントの next_attempt_at を処理期限（リース）まで進めて
+-- コミットし、行ロックを保持せずにコンシューマへ配信する。
+
+ALTER TABLE workflow_event_outbox
+    ADD COLUMN delivered_consumers TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN failed_at TIMESTAMPTZ;
+
+-- インデックス
+-- ディスパッチャのポーリング用（未配信かつ配信停止していないイベントのみ）
+DROP INDEX workflow_event_outbox_pending_idx;
+CREATE INDEX workflow_event_outbox_pending_idx ON workflow_event_outbox(next_attempt_at)
+    WHERE dispatched_at IS NULL AND failed_at IS NULL;
+
+-- コメント
+COMMENT ON COLUMN workflow_event_outbox.attempts IS '配信失敗回数（いずれかのコンシューマが失敗した回数）';
+COMMENT ON COLUMN workflow_event_outbox.next_attempt_at IS '次回配信を試行する日時（取り出し中は処理期限）';
+COMMENT ON COLUMN workflow_event_outbox.delivered_consumers IS '配信に成功したコンシューマ名';
+COMMENT ON COLUMN workflow_event_outbox.failed_at IS '配信停止日時（最大試行回数到達・ペイロード不正。配信中の場合は NULL）';
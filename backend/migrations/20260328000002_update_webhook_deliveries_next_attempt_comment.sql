@@ -0,0 +1,9 @@
+-- webhook_deliveries.next_attempt_at を取り出し中の処理期限（リース）としても使う
+-- 構文リファレンス: README.md
+--
+-- 配信ワーカーは取り出した配信の next_attempt_at を処理期限まで進めてコミットし、
+-- 行ロックを保持せずに HTTP 送信する。送信結果の記録前にワーカーが停止した場合は、
+-- 処理期限を過ぎると再び取り出される。
+
+-- コメント
+COMMENT ON COLUMN webhook_deliveries.next_attempt_at IS '次回送信を試行する日時（取り出し中は処理期限）';
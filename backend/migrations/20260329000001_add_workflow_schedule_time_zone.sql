@@ -0,0 +1,13 @@
+-- ワークフロースケジュールにタイムゾーンを追加
+-- 構文リファレンス: README.md
+--
+-- 繰り返し設定の実行時刻（monthly の run_hour:run_minute、cron 式）を
+-- スケジュールのタイムゾーン（IANA タイムゾーン名）の現地時刻で解釈する。
+-- 既存のスケジュールは従来どおり UTC とする。
+
+ALTER TABLE workflow_schedules
+    ADD COLUMN time_zone VARCHAR(64) NOT NULL DEFAULT 'UTC';
+
+COMMENT ON COLUMN workflow_schedules.time_zone IS '実行時刻を解釈するタイムゾーン（IANA タイムゾーン名、例: Asia/Tokyo）';
+COMMENT ON COLUMN workflow_schedules.run_hour IS '実行時（monthly のみ、time_zone の現地時刻）';
+COMMENT ON COLUMN workflow_schedules.cron_expression IS 'cron 式（cron のみ、time_zone の現地時刻）';
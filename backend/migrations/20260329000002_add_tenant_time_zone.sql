@@ -0,0 +1,11 @@
+-- テナントにタイムゾーンを追加
+-- 構文リファレンス: README.md
+--
+-- 通知ダイジェストの送信時刻など、テナント単位の時刻を
+-- テナントのタイムゾーン（IANA タイムゾーン名）の現地時刻で解釈する。
+-- 既存のテナントは従来どおり UTC とする。
+
+ALTER TABLE tenants
+    ADD COLUMN time_zone VARCHAR(64) NOT NULL DEFAULT 'UTC';
+
+COMMENT ON COLUMN tenants.time_zone IS 'タイムゾーン（IANA タイムゾーン名、例: Asia/Tokyo）。通知ダイジェストの送信時刻の解釈に使用';
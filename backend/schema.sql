@@ -159,9 +159,16 @@ CREATE TABLE public.documents (
     created_at timestamp with time zone DEFAULT now() NOT NULL,
     updated_at timestamp with time zone DEFAULT now() NOT NULL,
     deleted_at timestamp with time zone,
-    CONSTRAINT documents_context_check CHECK ((((folder_id IS NOT NULL) AND (workflow_instance_id IS NULL)) OR ((folder_id IS NULL) AND (workflow_instance_id IS NOT NULL))))
+    comment_id uuid,
+    CONSTRAINT documents_context_check CHECK ((num_nonnulls(folder_id, workflow_instance_id, comment_id) = 1))
 );
 
+--
+-- Name: COLUMN documents.comment_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.documents.comment_id IS '添付先のワークフローコメントID（FK、コメント添付以外は NULL）';
+
 --
 -- Name: folders; Type: TABLE; Schema: public; Owner: -
 --
@@ -179,6 +186,172 @@ CREATE TABLE public.folders (
     CONSTRAINT folders_depth_check CHECK (((depth >= 1) AND (depth <= 5)))
 );
 
+--
+-- Name: inbox_notifications; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.inbox_notifications (
+    id uuid NOT NULL,
+    tenant_id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    event_type character varying(50) NOT NULL,
+    workflow_instance_id uuid NOT NULL,
+    workflow_title character varying(255) NOT NULL,
+    workflow_display_id character varying(50) NOT NULL,
+    message character varying(500) NOT NULL,
+    read_at timestamp with time zone,
+    created_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+--
+-- Name: TABLE inbox_notifications; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.inbox_notifications IS 'アプリ内通知（ユーザーごとの通知受信箱）';
+
+--
+-- Name: COLUMN inbox_notifications.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.inbox_notifications.id IS '主キー';
+
+--
+-- Name: COLUMN inbox_notifications.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.inbox_notifications.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN inbox_notifications.user_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.inbox_notifications.user_id IS '受信者のユーザーID（FK）';
+
+--
+-- Name: COLUMN inbox_notifications.event_type; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.inbox_notifications.event_type IS '通知イベント種別（notification_logs.event_type と同じ値）';
+
+--
+-- Name: COLUMN inbox_notifications.workflow_instance_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.inbox_notifications.workflow_instance_id IS '対象ワークフローインスタンスID（FK）';
+
+--
+-- Name: COLUMN inbox_notifications.workflow_title; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.inbox_notifications.workflow_title IS '通知時点のワークフロータイトル';
+
+--
+-- Name: COLUMN inbox_notifications.workflow_display_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.inbox_notifications.workflow_display_id IS '通知時点のワークフロー表示ID';
+
+--
+-- Name: COLUMN inbox_notifications.message; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.inbox_notifications.message IS '通知の要約（1 行）';
+
+--
+-- Name: COLUMN inbox_notifications.read_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.inbox_notifications.read_at IS '既読日時（未読の場合は NULL）';
+
+--
+-- Name: COLUMN inbox_notifications.created_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.inbox_notifications.created_at IS '通知日時';
+
+--
+-- Name: notification_digest_items; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.notification_digest_items (
+    id uuid NOT NULL,
+    tenant_id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    recipient_email character varying(255) NOT NULL,
+    event_type character varying(50) NOT NULL,
+    workflow_instance_id uuid NOT NULL,
+    workflow_title character varying(255) NOT NULL,
+    workflow_display_id character varying(50) NOT NULL,
+    message character varying(500) NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+--
+-- Name: TABLE notification_digest_items; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.notification_digest_items IS 'ダイジェストメールで送信するまで保留している通知';
+
+--
+-- Name: COLUMN notification_digest_items.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_digest_items.id IS '主キー';
+
+--
+-- Name: COLUMN notification_digest_items.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_digest_items.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN notification_digest_items.user_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_digest_items.user_id IS '受信者のユーザーID（FK）';
+
+--
+-- Name: COLUMN notification_digest_items.recipient_email; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_digest_items.recipient_email IS '通知時点の受信者のメールアドレス';
+
+--
+-- Name: COLUMN notification_digest_items.event_type; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_digest_items.event_type IS '通知イベント種別（notification_logs.event_type と同じ値）';
+
+--
+-- Name: COLUMN notification_digest_items.workflow_instance_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_digest_items.workflow_instance_id IS '対象ワークフローインスタンスID（FK）';
+
+--
+-- Name: COLUMN notification_digest_items.workflow_title; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_digest_items.workflow_title IS '通知時点のワークフロータイトル';
+
+--
+-- Name: COLUMN notification_digest_items.workflow_display_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_digest_items.workflow_display_id IS '通知時点のワークフロー表示ID';
+
+--
+-- Name: COLUMN notification_digest_items.message; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_digest_items.message IS '通知の要約（1 行）';
+
+--
+-- Name: COLUMN notification_digest_items.created_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_digest_items.created_at IS '通知日時';
+
 --
 -- Name: notification_logs; Type: TABLE; Schema: public; Owner: -
 --
@@ -195,9 +368,182 @@ CREATE TABLE public.notification_logs (
     subject character varying(500) NOT NULL,
     status character varying(20) NOT NULL,
     error_message text,
-    sent_at timestamp with time zone DEFAULT now() NOT NULL
+    sent_at timestamp with time zone,
+    html_body text,
+    text_body text,
+    attempts integer DEFAULT 0 NOT NULL,
+    next_attempt_at timestamp with time zone DEFAULT now() NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    CONSTRAINT notification_logs_attempts_check CHECK ((attempts >= 0)),
+    CONSTRAINT notification_logs_status_check CHECK (((status)::text = ANY ((ARRAY['pending'::character varying, 'sent'::character varying, 'failed'::character varying])::text[])))
+);
+
+--
+-- Name: COLUMN notification_logs.status; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_logs.status IS '送信ステータス（pending: 送信待ち・リトライ待ち, sent: 送信済み, failed: 失敗）';
+
+--
+-- Name: COLUMN notification_logs.error_message; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_logs.error_message IS '直近の送信失敗理由';
+
+--
+-- Name: COLUMN notification_logs.sent_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_logs.sent_at IS '送信成功日時（未送信・失敗の場合は NULL）';
+
+--
+-- Name: COLUMN notification_logs.html_body; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_logs.html_body IS '送信する HTML 本文（ダイジェスト・移行前の通知は NULL で、再送しない）';
+
+--
+-- Name: COLUMN notification_logs.text_body; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_logs.text_body IS '送信するテキスト本文（ダイジェスト・移行前の通知は NULL で、再送しない）';
+
+--
+-- Name: COLUMN notification_logs.attempts; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_logs.attempts IS '送信試行回数（管理者が再送すると 0 に戻る）';
+
+--
+-- Name: COLUMN notification_logs.next_attempt_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_logs.next_attempt_at IS '次回送信を試行する日時';
+
+--
+-- Name: COLUMN notification_logs.created_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_logs.created_at IS '作成日時';
+
+--
+-- Name: notification_preferences; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.notification_preferences (
+    tenant_id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    event_type character varying(50) NOT NULL,
+    delivery character varying(20) NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    CONSTRAINT notification_preferences_delivery_check CHECK (((delivery)::text = ANY ((ARRAY['immediate'::character varying, 'digest'::character varying, 'off'::character varying])::text[])))
+);
+
+--
+-- Name: TABLE notification_preferences; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.notification_preferences IS 'ユーザーの通知設定（通知イベント種別ごとのメール配信方法）';
+
+--
+-- Name: COLUMN notification_preferences.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_preferences.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN notification_preferences.user_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_preferences.user_id IS 'ユーザーID（FK）';
+
+--
+-- Name: COLUMN notification_preferences.event_type; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_preferences.event_type IS '通知イベント種別（notification_logs.event_type と同じ値）';
+
+--
+-- Name: COLUMN notification_preferences.delivery; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_preferences.delivery IS '配信方法（immediate: 即時, digest: ダイジェスト, off: 停止）';
+
+--
+-- Name: COLUMN notification_preferences.updated_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_preferences.updated_at IS '更新日時';
+
+--
+-- Name: notification_templates; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.notification_templates (
+    tenant_id uuid NOT NULL,
+    event_type character varying(50) NOT NULL,
+    subject_template character varying(255) NOT NULL,
+    html_template text NOT NULL,
+    text_template text NOT NULL,
+    updated_by uuid NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL
 );
 
+--
+-- Name: TABLE notification_templates; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.notification_templates IS 'テナント独自の通知メールテンプレート（組み込みテンプレートの上書き）';
+
+--
+-- Name: COLUMN notification_templates.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_templates.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN notification_templates.event_type; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_templates.event_type IS '通知イベント種別（notification_logs.event_type と同じ値）';
+
+--
+-- Name: COLUMN notification_templates.subject_template; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_templates.subject_template IS '件名テンプレート（tera 構文）';
+
+--
+-- Name: COLUMN notification_templates.html_template; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_templates.html_template IS 'HTML 本文テンプレート（tera 構文、自動エスケープあり）';
+
+--
+-- Name: COLUMN notification_templates.text_template; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_templates.text_template IS 'テキスト本文テンプレート（tera 構文）';
+
+--
+-- Name: COLUMN notification_templates.updated_by; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_templates.updated_by IS '最終更新者ID（FK）';
+
+--
+-- Name: COLUMN notification_templates.created_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_templates.created_at IS '作成日時';
+
+--
+-- Name: COLUMN notification_templates.updated_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.notification_templates.updated_at IS '更新日時';
+
 --
 -- Name: roles; Type: TABLE; Schema: public; Owner: -
 --
@@ -268,6 +614,8 @@ CREATE TABLE public.tenants (
     settings jsonb DEFAULT '{}'::jsonb NOT NULL,
     created_at timestamp with time zone DEFAULT now() NOT NULL,
     updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    default_locale character varying(10) DEFAULT 'ja'::character varying NOT NULL,
+    CONSTRAINT tenants_default_locale_check CHECK (((default_locale)::text = ANY ((ARRAY['ja'::character varying, 'en'::character varying])::text[]))),
     CONSTRAINT tenants_plan_check CHECK (((plan)::text = ANY ((ARRAY['free'::character varying, 'standard'::character varying, 'professional'::character varying, 'enterprise'::character varying])::text[]))),
     CONSTRAINT tenants_status_check CHECK (((status)::text = ANY ((ARRAY['active'::character varying, 'suspended'::character varying, 'deleted'::character varying])::text[])))
 );
@@ -314,6 +662,12 @@ COMMENT ON COLUMN public.tenants.status IS '状態（active/suspended/deleted）
 
 COMMENT ON COLUMN public.tenants.settings IS 'テナント設定（JSON）';
 
+--
+-- Name: COLUMN tenants.default_locale; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.tenants.default_locale IS '既定ロケール（ja: 日本語, en: 英語）。ロケール未設定のユーザーに適用';
+
 --
 -- Name: user_roles; Type: TABLE; Schema: public; Owner: -
 --
@@ -370,6 +724,8 @@ CREATE TABLE public.users (
     created_at timestamp with time zone DEFAULT now() NOT NULL,
     updated_at timestamp with time zone DEFAULT now() NOT NULL,
     display_number bigint NOT NULL,
+    locale character varying(10),
+    CONSTRAINT users_locale_check CHECK (((locale)::text = ANY ((ARRAY['ja'::character varying, 'en'::character varying])::text[]))),
     CONSTRAINT users_status_check CHECK (((status)::text = ANY ((ARRAY['active'::character varying, 'inactive'::character varying, 'deleted'::character varying])::text[])))
 );
 
@@ -422,52 +778,378 @@ COMMENT ON COLUMN public.users.last_login_at IS '最終ログイン日時';
 COMMENT ON COLUMN public.users.display_number IS '表示用連番（テナント内で一意）';
 
 --
--- Name: workflow_comments; Type: TABLE; Schema: public; Owner: -
+-- Name: COLUMN users.locale; Type: COMMENT; Schema: public; Owner: -
 --
 
-CREATE TABLE public.workflow_comments (
+COMMENT ON COLUMN public.users.locale IS 'ロケール（ja: 日本語, en: 英語）。NULL の場合はテナントの既定ロケールに従う';
+
+--
+-- Name: webhook_deliveries; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.webhook_deliveries (
     id uuid NOT NULL,
     tenant_id uuid NOT NULL,
-    instance_id uuid NOT NULL,
-    posted_by uuid NOT NULL,
-    body text NOT NULL,
+    subscription_id uuid NOT NULL,
+    event_id uuid,
+    event_type character varying(50) NOT NULL,
+    payload jsonb NOT NULL,
+    status character varying(20) DEFAULT 'pending'::character varying NOT NULL,
+    attempts integer DEFAULT 0 NOT NULL,
+    next_attempt_at timestamp with time zone DEFAULT now() NOT NULL,
+    response_status integer,
+    last_error text,
     created_at timestamp with time zone DEFAULT now() NOT NULL,
-    updated_at timestamp with time zone DEFAULT now() NOT NULL,
-    CONSTRAINT workflow_comments_body_length CHECK (((char_length(body) >= 1) AND (char_length(body) <= 2000)))
+    delivered_at timestamp with time zone,
+    CONSTRAINT webhook_deliveries_attempts_check CHECK ((attempts >= 0)),
+    CONSTRAINT webhook_deliveries_status_check CHECK (((status)::text = ANY ((ARRAY['pending'::character varying, 'succeeded'::character varying, 'failed'::character varying])::text[])))
 );
 
 --
--- Name: TABLE workflow_comments; Type: COMMENT; Schema: public; Owner: -
+-- Name: TABLE webhook_deliveries; Type: COMMENT; Schema: public; Owner: -
 --
 
-COMMENT ON TABLE public.workflow_comments IS 'ワークフローコメント（コメントスレッド）';
+COMMENT ON TABLE public.webhook_deliveries IS 'Webhook 配信ログ';
 
 --
--- Name: COLUMN workflow_comments.id; Type: COMMENT; Schema: public; Owner: -
+-- Name: COLUMN webhook_deliveries.id; Type: COMMENT; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_comments.id IS '主キー';
+COMMENT ON COLUMN public.webhook_deliveries.id IS '主キー';
 
 --
--- Name: COLUMN workflow_comments.tenant_id; Type: COMMENT; Schema: public; Owner: -
+-- Name: COLUMN webhook_deliveries.tenant_id; Type: COMMENT; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_comments.tenant_id IS 'テナントID（FK, RLS用）';
+COMMENT ON COLUMN public.webhook_deliveries.tenant_id IS 'テナントID（FK, RLS用）';
 
 --
--- Name: COLUMN workflow_comments.instance_id; Type: COMMENT; Schema: public; Owner: -
+-- Name: COLUMN webhook_deliveries.subscription_id; Type: COMMENT; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_comments.instance_id IS 'ワークフローインスタンスID（FK）';
+COMMENT ON COLUMN public.webhook_deliveries.subscription_id IS 'Webhook 購読ID（FK）';
 
 --
--- Name: COLUMN workflow_comments.posted_by; Type: COMMENT; Schema: public; Owner: -
+-- Name: COLUMN webhook_deliveries.event_id; Type: COMMENT; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_comments.posted_by IS '投稿者ユーザーID（FK）';
+COMMENT ON COLUMN public.webhook_deliveries.event_id IS 'ワークフローイベントID（テスト送信の場合は NULL）';
 
 --
--- Name: COLUMN workflow_comments.body; Type: COMMENT; Schema: public; Owner: -
+-- Name: COLUMN webhook_deliveries.event_type; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_deliveries.event_type IS 'イベント種別（テスト送信は ping）';
+
+--
+-- Name: COLUMN webhook_deliveries.payload; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_deliveries.payload IS '送信する JSON ペイロード';
+
+--
+-- Name: COLUMN webhook_deliveries.status; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_deliveries.status IS '配信ステータス（pending, succeeded, failed）';
+
+--
+-- Name: COLUMN webhook_deliveries.attempts; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_deliveries.attempts IS '送信試行回数';
+
+--
+-- Name: COLUMN webhook_deliveries.next_attempt_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_deliveries.next_attempt_at IS '次回送信を試行する日時（取り出し中は処理期限）';
+
+--
+-- Name: COLUMN webhook_deliveries.response_status; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_deliveries.response_status IS '直近の HTTP レスポンスステータス（通信エラーの場合は NULL）';
+
+--
+-- Name: COLUMN webhook_deliveries.last_error; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_deliveries.last_error IS '直近の送信失敗理由';
+
+--
+-- Name: COLUMN webhook_deliveries.created_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_deliveries.created_at IS '作成日時';
+
+--
+-- Name: COLUMN webhook_deliveries.delivered_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_deliveries.delivered_at IS '送信成功日時';
+
+--
+-- Name: webhook_subscriptions; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.webhook_subscriptions (
+    id uuid NOT NULL,
+    tenant_id uuid NOT NULL,
+    name character varying(100) NOT NULL,
+    url character varying(2048) NOT NULL,
+    event_types jsonb NOT NULL,
+    secret character varying(256) NOT NULL,
+    is_active boolean DEFAULT true NOT NULL,
+    created_by uuid NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    CONSTRAINT webhook_subscriptions_event_types_check CHECK ((jsonb_typeof(event_types) = 'array'::text))
+);
+
+--
+-- Name: TABLE webhook_subscriptions; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.webhook_subscriptions IS 'Webhook 購読（送信先 URL と購読イベント種別）';
+
+--
+-- Name: COLUMN webhook_subscriptions.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_subscriptions.id IS '主キー';
+
+--
+-- Name: COLUMN webhook_subscriptions.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_subscriptions.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN webhook_subscriptions.name; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_subscriptions.name IS 'Webhook 名';
+
+--
+-- Name: COLUMN webhook_subscriptions.url; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_subscriptions.url IS '送信先 URL';
+
+--
+-- Name: COLUMN webhook_subscriptions.event_types; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_subscriptions.event_types IS '購読するイベント種別の配列（submitted, approved 等）';
+
+--
+-- Name: COLUMN webhook_subscriptions.secret; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_subscriptions.secret IS 'HMAC-SHA256 署名用シークレット';
+
+--
+-- Name: COLUMN webhook_subscriptions.is_active; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_subscriptions.is_active IS '有効フラグ（false の場合は配信しない）';
+
+--
+-- Name: COLUMN webhook_subscriptions.created_by; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_subscriptions.created_by IS '作成者ID（FK）';
+
+--
+-- Name: COLUMN webhook_subscriptions.created_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_subscriptions.created_at IS '作成日時';
+
+--
+-- Name: COLUMN webhook_subscriptions.updated_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.webhook_subscriptions.updated_at IS '更新日時';
+
+--
+-- Name: workflow_activities; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_activities (
+    id uuid NOT NULL,
+    tenant_id uuid NOT NULL,
+    instance_id uuid NOT NULL,
+    activity_type character varying(50) NOT NULL,
+    actor_id uuid,
+    step_id uuid,
+    details jsonb DEFAULT '{}'::jsonb NOT NULL,
+    occurred_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+--
+-- Name: TABLE workflow_activities; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.workflow_activities IS 'ワークフローアクティビティ（インスタンスの出来事の時系列記録）';
+
+--
+-- Name: COLUMN workflow_activities.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_activities.id IS '主キー';
+
+--
+-- Name: COLUMN workflow_activities.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_activities.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN workflow_activities.instance_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_activities.instance_id IS 'ワークフローインスタンスID（FK）';
+
+--
+-- Name: COLUMN workflow_activities.activity_type; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_activities.activity_type IS 'アクティビティ種別（created, submitted, step_activated, approved 等）';
+
+--
+-- Name: COLUMN workflow_activities.actor_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_activities.actor_id IS '操作者ID（FK、システム操作の場合は NULL）';
+
+--
+-- Name: COLUMN workflow_activities.step_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_activities.step_id IS '対象ステップID（FK、ステップに紐づく出来事の場合）';
+
+--
+-- Name: COLUMN workflow_activities.details; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_activities.details IS '種別ごとの付加情報';
+
+--
+-- Name: COLUMN workflow_activities.occurred_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_activities.occurred_at IS '発生日時';
+
+--
+-- Name: workflow_comment_revisions; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_comment_revisions (
+    id uuid NOT NULL,
+    tenant_id uuid NOT NULL,
+    comment_id uuid NOT NULL,
+    body text NOT NULL,
+    edited_by uuid NOT NULL,
+    edited_at timestamp with time zone DEFAULT now() NOT NULL,
+    CONSTRAINT workflow_comment_revisions_body_length CHECK (((char_length(body) >= 1) AND (char_length(body) <= 2000)))
+);
+
+--
+-- Name: TABLE workflow_comment_revisions; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.workflow_comment_revisions IS 'ワークフローコメントの編集履歴（編集前の本文）';
+
+--
+-- Name: COLUMN workflow_comment_revisions.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comment_revisions.id IS '主キー';
+
+--
+-- Name: COLUMN workflow_comment_revisions.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comment_revisions.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN workflow_comment_revisions.comment_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comment_revisions.comment_id IS 'コメントID（FK）';
+
+--
+-- Name: COLUMN workflow_comment_revisions.body; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comment_revisions.body IS '編集前の本文（1〜2000文字）';
+
+--
+-- Name: COLUMN workflow_comment_revisions.edited_by; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comment_revisions.edited_by IS '編集したユーザーID（FK）';
+
+--
+-- Name: COLUMN workflow_comment_revisions.edited_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comment_revisions.edited_at IS '編集日時';
+
+--
+-- Name: workflow_comments; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_comments (
+    id uuid NOT NULL,
+    tenant_id uuid NOT NULL,
+    instance_id uuid NOT NULL,
+    posted_by uuid NOT NULL,
+    body text NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    search_vector tsvector GENERATED ALWAYS AS (to_tsvector('simple'::regconfig, body)) STORED,
+    parent_id uuid,
+    edited_at timestamp with time zone,
+    deleted_at timestamp with time zone,
+    deleted_by uuid,
+    CONSTRAINT workflow_comments_body_length CHECK (((char_length(body) >= 1) AND (char_length(body) <= 2000)))
+);
+
+--
+-- Name: TABLE workflow_comments; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.workflow_comments IS 'ワークフローコメント（コメントスレッド）';
+
+--
+-- Name: COLUMN workflow_comments.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comments.id IS '主キー';
+
+--
+-- Name: COLUMN workflow_comments.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comments.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN workflow_comments.instance_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comments.instance_id IS 'ワークフローインスタンスID（FK）';
+
+--
+-- Name: COLUMN workflow_comments.posted_by; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comments.posted_by IS '投稿者ユーザーID（FK）';
+
+--
+-- Name: COLUMN workflow_comments.body; Type: COMMENT; Schema: public; Owner: -
 --
 
 COMMENT ON COLUMN public.workflow_comments.body IS 'コメント本文（1〜2000文字）';
@@ -484,6 +1166,36 @@ COMMENT ON COLUMN public.workflow_comments.created_at IS '作成日時';
 
 COMMENT ON COLUMN public.workflow_comments.updated_at IS '更新日時';
 
+--
+-- Name: COLUMN workflow_comments.search_vector; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comments.search_vector IS '全文検索用ベクトル（本文、生成列）';
+
+--
+-- Name: COLUMN workflow_comments.parent_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comments.parent_id IS '返信先の親コメントID（FK、トップレベルのコメントは NULL）';
+
+--
+-- Name: COLUMN workflow_comments.edited_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comments.edited_at IS '最終編集日時（未編集の場合は NULL）';
+
+--
+-- Name: COLUMN workflow_comments.deleted_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comments.deleted_at IS '論理削除日時（削除されていない場合は NULL）';
+
+--
+-- Name: COLUMN workflow_comments.deleted_by; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_comments.deleted_by IS '削除したユーザーID（FK、投稿者本人またはワークフロー管理者）';
+
 --
 -- Name: workflow_definitions; Type: TABLE; Schema: public; Owner: -
 --
@@ -499,6 +1211,8 @@ CREATE TABLE public.workflow_definitions (
     created_by uuid NOT NULL,
     created_at timestamp with time zone DEFAULT now() NOT NULL,
     updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    confidential boolean DEFAULT false NOT NULL,
+    default_watchers uuid[] DEFAULT '{}'::uuid[] NOT NULL,
     CONSTRAINT workflow_definitions_status_check CHECK (((status)::text = ANY ((ARRAY['draft'::character varying, 'published'::character varying, 'archived'::character varying])::text[])))
 );
 
@@ -557,18 +1271,211 @@ COMMENT ON COLUMN public.workflow_definitions.status IS '状態（draft/publishe
 COMMENT ON COLUMN public.workflow_definitions.created_by IS '作成者（FK）';
 
 --
--- Name: workflow_instances; Type: TABLE; Schema: public; Owner: -
+-- Name: COLUMN workflow_definitions.confidential; Type: COMMENT; Schema: public; Owner: -
 --
 
-CREATE TABLE public.workflow_instances (
+COMMENT ON COLUMN public.workflow_definitions.confidential IS '機密定義か（true の場合、関与者以外は管理者でも閲覧不可）';
+
+--
+-- Name: COLUMN workflow_definitions.default_watchers; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_definitions.default_watchers IS 'インスタンス作成時に登録するウォッチャーのユーザーID一覧';
+
+--
+-- Name: workflow_event_outbox; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_event_outbox (
     id uuid NOT NULL,
     tenant_id uuid NOT NULL,
-    definition_id uuid NOT NULL,
-    definition_version integer NOT NULL,
-    title character varying(500) NOT NULL,
-    form_data jsonb DEFAULT '{}'::jsonb NOT NULL,
-    status character varying(20) DEFAULT 'draft'::character varying NOT NULL,
-    current_step_id character varying(100),
+    instance_id uuid NOT NULL,
+    event_type character varying(50) NOT NULL,
+    actor_id uuid,
+    payload jsonb NOT NULL,
+    occurred_at timestamp with time zone DEFAULT now() NOT NULL,
+    attempts integer DEFAULT 0 NOT NULL,
+    next_attempt_at timestamp with time zone DEFAULT now() NOT NULL,
+    last_error text,
+    dispatched_at timestamp with time zone,
+    delivered_consumers text[] DEFAULT '{}'::text[] NOT NULL,
+    failed_at timestamp with time zone,
+    CONSTRAINT workflow_event_outbox_attempts_check CHECK ((attempts >= 0))
+);
+
+--
+-- Name: TABLE workflow_event_outbox; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.workflow_event_outbox IS 'ワークフロードメインイベントのアウトボックス';
+
+--
+-- Name: COLUMN workflow_event_outbox.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.id IS '主キー';
+
+--
+-- Name: COLUMN workflow_event_outbox.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN workflow_event_outbox.instance_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.instance_id IS 'ワークフローインスタンスID（FK）';
+
+--
+-- Name: COLUMN workflow_event_outbox.event_type; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.event_type IS 'イベント種別（submitted, step_approved, approved 等）';
+
+--
+-- Name: COLUMN workflow_event_outbox.actor_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.actor_id IS '操作者ID（FK、システム操作の場合は NULL）';
+
+--
+-- Name: COLUMN workflow_event_outbox.payload; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.payload IS 'イベント発生時点のインスタンス・ステップ情報';
+
+--
+-- Name: COLUMN workflow_event_outbox.occurred_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.occurred_at IS '発生日時';
+
+--
+-- Name: COLUMN workflow_event_outbox.attempts; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.attempts IS '配信失敗回数（いずれかのコンシューマが失敗した回数）';
+
+--
+-- Name: COLUMN workflow_event_outbox.next_attempt_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.next_attempt_at IS '次回配信を試行する日時（取り出し中は処理期限）';
+
+--
+-- Name: COLUMN workflow_event_outbox.last_error; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.last_error IS '直近の配信失敗理由';
+
+--
+-- Name: COLUMN workflow_event_outbox.dispatched_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.dispatched_at IS '配信完了日時（未配信の場合は NULL）';
+
+--
+-- Name: COLUMN workflow_event_outbox.delivered_consumers; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.delivered_consumers IS '配信に成功したコンシューマ名';
+
+--
+-- Name: COLUMN workflow_event_outbox.failed_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_event_outbox.failed_at IS '配信停止日時（最大試行回数到達・ペイロード不正。配信中の場合は NULL）';
+
+--
+-- Name: workflow_form_data_changes; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_form_data_changes (
+    id uuid NOT NULL,
+    tenant_id uuid NOT NULL,
+    instance_id uuid NOT NULL,
+    step_id uuid NOT NULL,
+    field_id character varying(100) NOT NULL,
+    old_value jsonb NOT NULL,
+    new_value jsonb NOT NULL,
+    changed_by uuid NOT NULL,
+    changed_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+--
+-- Name: TABLE workflow_form_data_changes; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.workflow_form_data_changes IS 'ワークフローフォームデータ変更履歴（承認者編集）';
+
+--
+-- Name: COLUMN workflow_form_data_changes.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_form_data_changes.id IS '主キー';
+
+--
+-- Name: COLUMN workflow_form_data_changes.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_form_data_changes.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN workflow_form_data_changes.instance_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_form_data_changes.instance_id IS 'ワークフローインスタンスID（FK）';
+
+--
+-- Name: COLUMN workflow_form_data_changes.step_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_form_data_changes.step_id IS '編集が行われた承認ステップID（FK）';
+
+--
+-- Name: COLUMN workflow_form_data_changes.field_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_form_data_changes.field_id IS 'フォームフィールドID';
+
+--
+-- Name: COLUMN workflow_form_data_changes.old_value; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_form_data_changes.old_value IS '変更前の値（未設定の場合は JSON null）';
+
+--
+-- Name: COLUMN workflow_form_data_changes.new_value; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_form_data_changes.new_value IS '変更後の値';
+
+--
+-- Name: COLUMN workflow_form_data_changes.changed_by; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_form_data_changes.changed_by IS '変更したユーザーID（FK）';
+
+--
+-- Name: COLUMN workflow_form_data_changes.changed_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_form_data_changes.changed_at IS '変更日時';
+
+--
+-- Name: workflow_instances; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_instances (
+    id uuid NOT NULL,
+    tenant_id uuid NOT NULL,
+    definition_id uuid NOT NULL,
+    definition_version integer NOT NULL,
+    title character varying(500) NOT NULL,
+    form_data jsonb DEFAULT '{}'::jsonb NOT NULL,
+    status character varying(20) DEFAULT 'draft'::character varying NOT NULL,
+    current_step_id character varying(100),
     initiated_by uuid NOT NULL,
     submitted_at timestamp with time zone,
     completed_at timestamp with time zone,
@@ -576,6 +1483,8 @@ CREATE TABLE public.workflow_instances (
     updated_at timestamp with time zone DEFAULT now() NOT NULL,
     version integer DEFAULT 1 NOT NULL,
     display_number bigint NOT NULL,
+    search_vector tsvector GENERATED ALWAYS AS ((setweight(to_tsvector('simple'::regconfig, (title)::text), 'A'::"char") || setweight(jsonb_to_tsvector('simple'::regconfig, form_data, '["string", "numeric"]'::jsonb), 'B'::"char"))) STORED,
+    proxy_submitted_by uuid,
     CONSTRAINT workflow_instances_status_check CHECK (((status)::text = ANY ((ARRAY['draft'::character varying, 'pending'::character varying, 'in_progress'::character varying, 'approved'::character varying, 'rejected'::character varying, 'cancelled'::character varying, 'changes_requested'::character varying])::text[])))
 );
 
@@ -601,726 +1510,1699 @@ COMMENT ON COLUMN public.workflow_instances.tenant_id IS 'テナントID（FK）
 -- Name: COLUMN workflow_instances.definition_id; Type: COMMENT; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.definition_id IS '定義ID（FK）';
+COMMENT ON COLUMN public.workflow_instances.definition_id IS '定義ID（FK）';
+
+--
+-- Name: COLUMN workflow_instances.definition_version; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.definition_version IS '定義バージョン（作成時点）';
+
+--
+-- Name: COLUMN workflow_instances.title; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.title IS 'タイトル';
+
+--
+-- Name: COLUMN workflow_instances.form_data; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.form_data IS 'フォームデータ（JSON）';
+
+--
+-- Name: COLUMN workflow_instances.status; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.status IS '状態（draft/pending/in_progress/approved/rejected/cancelled/changes_requested）';
+
+--
+-- Name: COLUMN workflow_instances.current_step_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.current_step_id IS '現在のステップID';
+
+--
+-- Name: COLUMN workflow_instances.initiated_by; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.initiated_by IS '申請者（FK）';
+
+--
+-- Name: COLUMN workflow_instances.submitted_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.submitted_at IS '申請日時';
+
+--
+-- Name: COLUMN workflow_instances.completed_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.completed_at IS '完了日時';
+
+--
+-- Name: COLUMN workflow_instances.version; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.version IS '楽観的ロック用バージョン番号';
+
+--
+-- Name: COLUMN workflow_instances.display_number; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.display_number IS '表示用連番（テナント内で一意）';
+
+--
+-- Name: COLUMN workflow_instances.search_vector; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.search_vector IS '全文検索用ベクトル（件名 + フォームデータの値、生成列）';
+
+--
+-- Name: COLUMN workflow_instances.proxy_submitted_by; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_instances.proxy_submitted_by IS '代理申請の代理人ID（FK、本人申請の場合は NULL）';
+
+--
+-- Name: workflow_proxy_grants; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_proxy_grants (
+    tenant_id uuid NOT NULL,
+    principal_id uuid NOT NULL,
+    proxy_id uuid NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    CONSTRAINT workflow_proxy_grants_not_self CHECK ((principal_id <> proxy_id))
+);
+
+--
+-- Name: TABLE workflow_proxy_grants; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.workflow_proxy_grants IS '代理権限（本人に代わってワークフローを作成・申請できるユーザー）';
+
+--
+-- Name: COLUMN workflow_proxy_grants.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_proxy_grants.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN workflow_proxy_grants.principal_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_proxy_grants.principal_id IS '本人のユーザーID（FK、代理権限の付与者）';
+
+--
+-- Name: COLUMN workflow_proxy_grants.proxy_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_proxy_grants.proxy_id IS '代理人のユーザーID（FK）';
+
+--
+-- Name: COLUMN workflow_proxy_grants.created_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_proxy_grants.created_at IS '付与日時';
+
+--
+-- Name: workflow_schedules; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_schedules (
+    id uuid NOT NULL,
+    tenant_id uuid NOT NULL,
+    owner_id uuid NOT NULL,
+    definition_id uuid NOT NULL,
+    name character varying(100) NOT NULL,
+    title character varying(255) NOT NULL,
+    form_data jsonb DEFAULT '{}'::jsonb NOT NULL,
+    recurrence_type character varying(20) NOT NULL,
+    day_of_month smallint,
+    run_hour smallint,
+    run_minute smallint,
+    cron_expression character varying(100),
+    auto_submit boolean DEFAULT false NOT NULL,
+    approvers jsonb DEFAULT '[]'::jsonb NOT NULL,
+    is_active boolean DEFAULT true NOT NULL,
+    next_run_at timestamp with time zone,
+    last_run_at timestamp with time zone,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    CONSTRAINT workflow_schedules_approvers_check CHECK ((jsonb_typeof(approvers) = 'array'::text)),
+    CONSTRAINT workflow_schedules_recurrence_check CHECK (((((recurrence_type)::text = 'monthly'::text) AND ((day_of_month >= 1) AND (day_of_month <= 31)) AND ((run_hour >= 0) AND (run_hour <= 23)) AND ((run_minute >= 0) AND (run_minute <= 59)) AND (cron_expression IS NULL)) OR (((recurrence_type)::text = 'cron'::text) AND (cron_expression IS NOT NULL) AND (day_of_month IS NULL) AND (run_hour IS NULL) AND (run_minute IS NULL))))
+);
+
+--
+-- Name: TABLE workflow_schedules; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.workflow_schedules IS 'ワークフロースケジュール（定期的にワークフローを作成する繰り返し設定）';
+
+--
+-- Name: COLUMN workflow_schedules.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.id IS '主キー';
+
+--
+-- Name: COLUMN workflow_schedules.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN workflow_schedules.owner_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.owner_id IS '所有者ID（FK、作成されるワークフローの申請者）';
+
+--
+-- Name: COLUMN workflow_schedules.definition_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.definition_id IS 'ワークフロー定義ID（FK）';
+
+--
+-- Name: COLUMN workflow_schedules.name; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.name IS 'スケジュール名';
+
+--
+-- Name: COLUMN workflow_schedules.title; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.title IS '作成するワークフローのタイトル';
+
+--
+-- Name: COLUMN workflow_schedules.form_data; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.form_data IS '作成するワークフローのフォームデータ';
+
+--
+-- Name: COLUMN workflow_schedules.recurrence_type; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.recurrence_type IS '繰り返し種別（monthly, cron）';
+
+--
+-- Name: COLUMN workflow_schedules.day_of_month; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.day_of_month IS '実行日（monthly のみ、1〜31）';
+
+--
+-- Name: COLUMN workflow_schedules.run_hour; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.run_hour IS '実行時（monthly のみ、UTC）';
+
+--
+-- Name: COLUMN workflow_schedules.run_minute; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.run_minute IS '実行分（monthly のみ）';
+
+--
+-- Name: COLUMN workflow_schedules.cron_expression; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.cron_expression IS 'cron 式（cron のみ、UTC）';
+
+--
+-- Name: COLUMN workflow_schedules.auto_submit; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.auto_submit IS '作成した下書きを自動申請するか';
+
+--
+-- Name: COLUMN workflow_schedules.approvers; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.approvers IS '自動申請時の承認者（step_id と assigned_to の配列）';
+
+--
+-- Name: COLUMN workflow_schedules.is_active; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.is_active IS '有効フラグ（false の場合は実行しない）';
+
+--
+-- Name: COLUMN workflow_schedules.next_run_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.next_run_at IS '次回実行日時（無効の場合は NULL）';
+
+--
+-- Name: COLUMN workflow_schedules.last_run_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.last_run_at IS '最終実行日時';
+
+--
+-- Name: COLUMN workflow_schedules.created_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.created_at IS '作成日時';
+
+--
+-- Name: COLUMN workflow_schedules.updated_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_schedules.updated_at IS '更新日時';
+
+--
+-- Name: workflow_steps; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_steps (
+    id uuid NOT NULL,
+    instance_id uuid NOT NULL,
+    step_id character varying(100) NOT NULL,
+    step_name character varying(255) NOT NULL,
+    step_type character varying(50) NOT NULL,
+    status character varying(20) DEFAULT 'pending'::character varying NOT NULL,
+    assigned_to uuid,
+    decision character varying(50),
+    comment text,
+    due_date timestamp with time zone,
+    started_at timestamp with time zone,
+    completed_at timestamp with time zone,
+    created_at timestamp with time zone DEFAULT now() NOT NULL,
+    updated_at timestamp with time zone DEFAULT now() NOT NULL,
+    version integer DEFAULT 1 NOT NULL,
+    display_number bigint NOT NULL,
+    tenant_id uuid NOT NULL,
+    CONSTRAINT workflow_steps_decision_check CHECK (((decision IS NULL) OR ((decision)::text = ANY ((ARRAY['approved'::character varying, 'rejected'::character varying, 'request_changes'::character varying])::text[])))),
+    CONSTRAINT workflow_steps_status_check CHECK (((status)::text = ANY ((ARRAY['pending'::character varying, 'active'::character varying, 'completed'::character varying, 'skipped'::character varying])::text[])))
+);
+
+--
+-- Name: TABLE workflow_steps; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.workflow_steps IS 'ワークフローステップ（承認タスク）';
+
+--
+-- Name: COLUMN workflow_steps.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.id IS '主キー';
+
+--
+-- Name: COLUMN workflow_steps.instance_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.instance_id IS 'インスタンスID（FK）';
+
+--
+-- Name: COLUMN workflow_steps.step_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.step_id IS '定義上のステップID';
+
+--
+-- Name: COLUMN workflow_steps.step_name; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.step_name IS 'ステップ名';
+
+--
+-- Name: COLUMN workflow_steps.step_type; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.step_type IS 'ステップ種別（approval/notification/...）';
+
+--
+-- Name: COLUMN workflow_steps.status; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.status IS '状態（pending/active/completed/skipped）';
+
+--
+-- Name: COLUMN workflow_steps.assigned_to; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.assigned_to IS '担当者（FK）';
+
+--
+-- Name: COLUMN workflow_steps.decision; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.decision IS '判断（approved/rejected/request_changes）';
+
+--
+-- Name: COLUMN workflow_steps.comment; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.comment IS 'コメント';
+
+--
+-- Name: COLUMN workflow_steps.due_date; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.due_date IS '期限';
+
+--
+-- Name: COLUMN workflow_steps.started_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.started_at IS '開始日時';
+
+--
+-- Name: COLUMN workflow_steps.completed_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.completed_at IS '完了日時';
+
+--
+-- Name: COLUMN workflow_steps.version; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.version IS '楽観的ロック用バージョン番号';
+
+--
+-- Name: COLUMN workflow_steps.display_number; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.display_number IS '表示用連番（インスタンス内で一意）';
+
+--
+-- Name: COLUMN workflow_steps.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_steps.tenant_id IS 'テナントID（FK、RLS 二重防御用）';
+
+--
+-- Name: workflow_submissions; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_submissions (
+    id uuid NOT NULL,
+    tenant_id uuid NOT NULL,
+    instance_id uuid NOT NULL,
+    round integer NOT NULL,
+    form_data jsonb NOT NULL,
+    attachments jsonb DEFAULT '[]'::jsonb NOT NULL,
+    submitted_by uuid NOT NULL,
+    submitted_at timestamp with time zone DEFAULT now() NOT NULL,
+    CONSTRAINT workflow_submissions_round_check CHECK ((round >= 1))
+);
+
+--
+-- Name: TABLE workflow_submissions; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.workflow_submissions IS 'ワークフロー申請ラウンドのスナップショット';
+
+--
+-- Name: COLUMN workflow_submissions.id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_submissions.id IS '主キー';
+
+--
+-- Name: COLUMN workflow_submissions.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_submissions.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN workflow_submissions.instance_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_submissions.instance_id IS 'ワークフローインスタンスID（FK）';
+
+--
+-- Name: COLUMN workflow_submissions.round; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_submissions.round IS '申請ラウンド（初回申請 = 1、再申請ごとに +1）';
+
+--
+-- Name: COLUMN workflow_submissions.form_data; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_submissions.form_data IS '申請時点のフォームデータ';
+
+--
+-- Name: COLUMN workflow_submissions.attachments; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_submissions.attachments IS '申請時点の添付ファイル一覧（メタデータの配列）';
+
+--
+-- Name: COLUMN workflow_submissions.submitted_by; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_submissions.submitted_by IS '申請者ID（FK）';
+
+--
+-- Name: COLUMN workflow_submissions.submitted_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_submissions.submitted_at IS '申請日時';
+
+--
+-- Name: workflow_watchers; Type: TABLE; Schema: public; Owner: -
+--
+
+CREATE TABLE public.workflow_watchers (
+    tenant_id uuid NOT NULL,
+    instance_id uuid NOT NULL,
+    user_id uuid NOT NULL,
+    created_at timestamp with time zone DEFAULT now() NOT NULL
+);
+
+--
+-- Name: TABLE workflow_watchers; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON TABLE public.workflow_watchers IS 'ワークフローウォッチャー（承認者以外で進捗通知を受け取るユーザー）';
+
+--
+-- Name: COLUMN workflow_watchers.tenant_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_watchers.tenant_id IS 'テナントID（FK, RLS用）';
+
+--
+-- Name: COLUMN workflow_watchers.instance_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_watchers.instance_id IS 'ワークフローインスタンスID（FK）';
+
+--
+-- Name: COLUMN workflow_watchers.user_id; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_watchers.user_id IS 'ウォッチするユーザーID（FK）';
+
+--
+-- Name: COLUMN workflow_watchers.created_at; Type: COMMENT; Schema: public; Owner: -
+--
+
+COMMENT ON COLUMN public.workflow_watchers.created_at IS 'ウォッチ開始日時';
+
+--
+-- Name: credentials credentials_pkey; Type: CONSTRAINT; Schema: auth; Owner: -
+--
+
+ALTER TABLE ONLY auth.credentials
+    ADD CONSTRAINT credentials_pkey PRIMARY KEY (id);
+
+--
+-- Name: credentials uq_credentials_user_type; Type: CONSTRAINT; Schema: auth; Owner: -
+--
+
+ALTER TABLE ONLY auth.credentials
+    ADD CONSTRAINT uq_credentials_user_type UNIQUE (user_id, credential_type);
+
+--
+-- Name: display_id_counters display_id_counters_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.display_id_counters
+    ADD CONSTRAINT display_id_counters_pkey PRIMARY KEY (tenant_id, entity_type);
+
+--
+-- Name: documents documents_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.documents
+    ADD CONSTRAINT documents_pkey PRIMARY KEY (id);
+
+--
+-- Name: folders folders_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.folders
+    ADD CONSTRAINT folders_pkey PRIMARY KEY (id);
+
+--
+-- Name: folders folders_tenant_id_parent_id_name_key; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.folders
+    ADD CONSTRAINT folders_tenant_id_parent_id_name_key UNIQUE NULLS NOT DISTINCT (tenant_id, parent_id, name);
+
+--
+-- Name: inbox_notifications inbox_notifications_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.inbox_notifications
+    ADD CONSTRAINT inbox_notifications_pkey PRIMARY KEY (id);
+
+--
+-- Name: notification_digest_items notification_digest_items_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.notification_digest_items
+    ADD CONSTRAINT notification_digest_items_pkey PRIMARY KEY (id);
+
+--
+-- Name: notification_logs notification_logs_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.notification_logs
+    ADD CONSTRAINT notification_logs_pkey PRIMARY KEY (id);
+
+--
+-- Name: notification_preferences notification_preferences_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.notification_preferences
+    ADD CONSTRAINT notification_preferences_pkey PRIMARY KEY (tenant_id, user_id, event_type);
+
+--
+-- Name: notification_templates notification_templates_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.notification_templates
+    ADD CONSTRAINT notification_templates_pkey PRIMARY KEY (tenant_id, event_type);
+
+--
+-- Name: roles roles_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.roles
+    ADD CONSTRAINT roles_pkey PRIMARY KEY (id);
+
+--
+-- Name: roles roles_tenant_name_key; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.roles
+    ADD CONSTRAINT roles_tenant_name_key UNIQUE (tenant_id, name);
+
+--
+-- Name: tenants tenants_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.tenants
+    ADD CONSTRAINT tenants_pkey PRIMARY KEY (id);
+
+--
+-- Name: tenants tenants_subdomain_key; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.tenants
+    ADD CONSTRAINT tenants_subdomain_key UNIQUE (subdomain);
+
+--
+-- Name: user_roles user_roles_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.user_roles
+    ADD CONSTRAINT user_roles_pkey PRIMARY KEY (id);
+
+--
+-- Name: user_roles user_roles_user_role_key; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.user_roles
+    ADD CONSTRAINT user_roles_user_role_key UNIQUE (user_id, role_id);
+
+--
+-- Name: users users_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.users
+    ADD CONSTRAINT users_pkey PRIMARY KEY (id);
+
+--
+-- Name: users users_tenant_email_key; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.users
+    ADD CONSTRAINT users_tenant_email_key UNIQUE (tenant_id, email);
+
+--
+-- Name: webhook_deliveries webhook_deliveries_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.webhook_deliveries
+    ADD CONSTRAINT webhook_deliveries_pkey PRIMARY KEY (id);
+
+--
+-- Name: webhook_deliveries webhook_deliveries_subscription_event_key; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.webhook_deliveries
+    ADD CONSTRAINT webhook_deliveries_subscription_event_key UNIQUE (subscription_id, event_id);
+
+--
+-- Name: webhook_subscriptions webhook_subscriptions_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.webhook_subscriptions
+    ADD CONSTRAINT webhook_subscriptions_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_activities workflow_activities_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_activities
+    ADD CONSTRAINT workflow_activities_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_comment_revisions workflow_comment_revisions_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_comment_revisions
+    ADD CONSTRAINT workflow_comment_revisions_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_comments workflow_comments_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_comments
+    ADD CONSTRAINT workflow_comments_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_definitions workflow_definitions_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_definitions
+    ADD CONSTRAINT workflow_definitions_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_event_outbox workflow_event_outbox_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_event_outbox
+    ADD CONSTRAINT workflow_event_outbox_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_form_data_changes workflow_form_data_changes_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_form_data_changes
+    ADD CONSTRAINT workflow_form_data_changes_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_instances workflow_instances_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_instances
+    ADD CONSTRAINT workflow_instances_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_proxy_grants workflow_proxy_grants_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_proxy_grants
+    ADD CONSTRAINT workflow_proxy_grants_pkey PRIMARY KEY (principal_id, proxy_id);
+
+--
+-- Name: workflow_schedules workflow_schedules_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_schedules
+    ADD CONSTRAINT workflow_schedules_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_steps workflow_steps_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_steps
+    ADD CONSTRAINT workflow_steps_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_submissions workflow_submissions_instance_round_key; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_submissions
+    ADD CONSTRAINT workflow_submissions_instance_round_key UNIQUE (instance_id, round);
+
+--
+-- Name: workflow_submissions workflow_submissions_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_submissions
+    ADD CONSTRAINT workflow_submissions_pkey PRIMARY KEY (id);
+
+--
+-- Name: workflow_watchers workflow_watchers_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+--
+
+ALTER TABLE ONLY public.workflow_watchers
+    ADD CONSTRAINT workflow_watchers_pkey PRIMARY KEY (instance_id, user_id);
+
+--
+-- Name: idx_credentials_tenant_id; Type: INDEX; Schema: auth; Owner: -
+--
+
+CREATE INDEX idx_credentials_tenant_id ON auth.credentials USING btree (tenant_id);
+
+--
+-- Name: idx_credentials_user_id; Type: INDEX; Schema: auth; Owner: -
+--
+
+CREATE INDEX idx_credentials_user_id ON auth.credentials USING btree (user_id);
+
+--
+-- Name: documents_tenant_s3_key_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX documents_tenant_s3_key_idx ON public.documents USING btree (tenant_id, s3_key);
+
+--
+-- Name: idx_documents_comment_id; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_documents_comment_id ON public.documents USING btree (comment_id) WHERE (comment_id IS NOT NULL);
+
+--
+-- Name: idx_documents_folder_id; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_documents_folder_id ON public.documents USING btree (folder_id) WHERE (folder_id IS NOT NULL);
+
+--
+-- Name: idx_documents_status; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_documents_status ON public.documents USING btree (status) WHERE ((status)::text <> 'deleted'::text);
+
+--
+-- Name: idx_documents_tenant_id; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_documents_tenant_id ON public.documents USING btree (tenant_id);
+
+--
+-- Name: idx_documents_workflow_instance_id; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_documents_workflow_instance_id ON public.documents USING btree (workflow_instance_id) WHERE (workflow_instance_id IS NOT NULL);
+
+--
+-- Name: idx_folders_parent_id; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_folders_parent_id ON public.folders USING btree (parent_id);
+
+--
+-- Name: idx_folders_path; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_folders_path ON public.folders USING btree (path);
+
+--
+-- Name: idx_folders_tenant_id; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_folders_tenant_id ON public.folders USING btree (tenant_id);
+
+--
+-- Name: idx_notification_logs_failed; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_notification_logs_failed ON public.notification_logs USING btree (tenant_id, created_at DESC) WHERE ((status)::text = 'failed'::text);
+
+--
+-- Name: idx_notification_logs_pending; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_notification_logs_pending ON public.notification_logs USING btree (next_attempt_at) WHERE ((status)::text = 'pending'::text);
+
+--
+-- Name: idx_notification_logs_recipient_user_id; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_notification_logs_recipient_user_id ON public.notification_logs USING btree (recipient_user_id);
+
+--
+-- Name: idx_notification_logs_sent_at; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_notification_logs_sent_at ON public.notification_logs USING btree (sent_at DESC);
+
+--
+-- Name: idx_notification_logs_tenant_id; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_notification_logs_tenant_id ON public.notification_logs USING btree (tenant_id);
+
+--
+-- Name: idx_notification_logs_workflow_instance_id; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX idx_notification_logs_workflow_instance_id ON public.notification_logs USING btree (workflow_instance_id);
+
+--
+-- Name: idx_users_display_number; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE UNIQUE INDEX idx_users_display_number ON public.users USING btree (tenant_id, display_number) WHERE (display_number IS NOT NULL);
+
+--
+-- Name: idx_workflow_instances_display_number; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE UNIQUE INDEX idx_workflow_instances_display_number ON public.workflow_instances USING btree (tenant_id, display_number) WHERE (display_number IS NOT NULL);
+
+--
+-- Name: idx_workflow_steps_display_number; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE UNIQUE INDEX idx_workflow_steps_display_number ON public.workflow_steps USING btree (instance_id, display_number) WHERE (display_number IS NOT NULL);
+
+--
+-- Name: inbox_notifications_created_at_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX inbox_notifications_created_at_idx ON public.inbox_notifications USING btree (created_at);
+
+--
+-- Name: inbox_notifications_unread_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX inbox_notifications_unread_idx ON public.inbox_notifications USING btree (tenant_id, user_id) WHERE (read_at IS NULL);
+
+--
+-- Name: inbox_notifications_user_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX inbox_notifications_user_idx ON public.inbox_notifications USING btree (tenant_id, user_id, created_at DESC, id DESC);
+
+--
+-- Name: inbox_notifications_workflow_instance_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX inbox_notifications_workflow_instance_idx ON public.inbox_notifications USING btree (workflow_instance_id);
+
+--
+-- Name: notification_digest_items_user_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX notification_digest_items_user_idx ON public.notification_digest_items USING btree (tenant_id, user_id, created_at);
+
+--
+-- Name: notification_digest_items_workflow_instance_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX notification_digest_items_workflow_instance_idx ON public.notification_digest_items USING btree (workflow_instance_id);
+
+--
+-- Name: notification_preferences_user_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX notification_preferences_user_idx ON public.notification_preferences USING btree (user_id);
+
+--
+-- Name: user_roles_role_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX user_roles_role_idx ON public.user_roles USING btree (role_id);
+
+--
+-- Name: user_roles_tenant_id_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX user_roles_tenant_id_idx ON public.user_roles USING btree (tenant_id);
+
+--
+-- Name: user_roles_user_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX user_roles_user_idx ON public.user_roles USING btree (user_id);
+
+--
+-- Name: users_tenant_status_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX users_tenant_status_idx ON public.users USING btree (tenant_id, status);
+
+--
+-- Name: webhook_deliveries_pending_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX webhook_deliveries_pending_idx ON public.webhook_deliveries USING btree (next_attempt_at) WHERE ((status)::text = 'pending'::text);
+
+--
+-- Name: webhook_deliveries_subscription_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX webhook_deliveries_subscription_idx ON public.webhook_deliveries USING btree (subscription_id, created_at DESC);
+
+--
+-- Name: webhook_deliveries_tenant_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX webhook_deliveries_tenant_idx ON public.webhook_deliveries USING btree (tenant_id);
+
+--
+-- Name: webhook_subscriptions_tenant_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX webhook_subscriptions_tenant_idx ON public.webhook_subscriptions USING btree (tenant_id);
+
+--
+-- Name: workflow_activities_instance_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX workflow_activities_instance_idx ON public.workflow_activities USING btree (instance_id, occurred_at);
+
+--
+-- Name: workflow_activities_tenant_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX workflow_activities_tenant_idx ON public.workflow_activities USING btree (tenant_id);
+
+--
+-- Name: workflow_comment_revisions_comment_idx; Type: INDEX; Schema: public; Owner: -
+--
+
+CREATE INDEX workflow_comment_revisions_comment_idx ON public.workflow_comment_revisions USING btree (comment_id, edited_at);
 
 --
--- Name: COLUMN workflow_instances.definition_version; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_comment_revisions_tenant_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.definition_version IS '定義バージョン（作成時点）';
+CREATE INDEX workflow_comment_revisions_tenant_idx ON public.workflow_comment_revisions USING btree (tenant_id);
 
 --
--- Name: COLUMN workflow_instances.title; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_comments_body_trgm_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.title IS 'タイトル';
+CREATE INDEX workflow_comments_body_trgm_idx ON public.workflow_comments USING gin (body public.gin_trgm_ops);
 
 --
--- Name: COLUMN workflow_instances.form_data; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_comments_instance_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.form_data IS 'フォームデータ（JSON）';
+CREATE INDEX workflow_comments_instance_idx ON public.workflow_comments USING btree (instance_id);
 
 --
--- Name: COLUMN workflow_instances.status; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_comments_parent_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.status IS '状態（draft/pending/in_progress/approved/rejected/cancelled/changes_requested）';
+CREATE INDEX workflow_comments_parent_idx ON public.workflow_comments USING btree (parent_id) WHERE (parent_id IS NOT NULL);
 
 --
--- Name: COLUMN workflow_instances.current_step_id; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_comments_search_vector_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.current_step_id IS '現在のステップID';
+CREATE INDEX workflow_comments_search_vector_idx ON public.workflow_comments USING gin (search_vector);
 
 --
--- Name: COLUMN workflow_instances.initiated_by; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_comments_tenant_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.initiated_by IS '申請者（FK）';
+CREATE INDEX workflow_comments_tenant_idx ON public.workflow_comments USING btree (tenant_id);
 
 --
--- Name: COLUMN workflow_instances.submitted_at; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_definitions_tenant_status_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.submitted_at IS '申請日時';
+CREATE INDEX workflow_definitions_tenant_status_idx ON public.workflow_definitions USING btree (tenant_id, status);
 
 --
--- Name: COLUMN workflow_instances.completed_at; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_event_outbox_pending_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.completed_at IS '完了日時';
+CREATE INDEX workflow_event_outbox_pending_idx ON public.workflow_event_outbox USING btree (next_attempt_at) WHERE ((dispatched_at IS NULL) AND (failed_at IS NULL));
 
 --
--- Name: COLUMN workflow_instances.version; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_event_outbox_tenant_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.version IS '楽観的ロック用バージョン番号';
+CREATE INDEX workflow_event_outbox_tenant_idx ON public.workflow_event_outbox USING btree (tenant_id);
 
 --
--- Name: COLUMN workflow_instances.display_number; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_form_data_changes_instance_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_instances.display_number IS '表示用連番（テナント内で一意）';
+CREATE INDEX workflow_form_data_changes_instance_idx ON public.workflow_form_data_changes USING btree (instance_id);
 
 --
--- Name: workflow_steps; Type: TABLE; Schema: public; Owner: -
+-- Name: workflow_form_data_changes_tenant_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-CREATE TABLE public.workflow_steps (
-    id uuid NOT NULL,
-    instance_id uuid NOT NULL,
-    step_id character varying(100) NOT NULL,
-    step_name character varying(255) NOT NULL,
-    step_type character varying(50) NOT NULL,
-    status character varying(20) DEFAULT 'pending'::character varying NOT NULL,
-    assigned_to uuid,
-    decision character varying(50),
-    comment text,
-    due_date timestamp with time zone,
-    started_at timestamp with time zone,
-    completed_at timestamp with time zone,
-    created_at timestamp with time zone DEFAULT now() NOT NULL,
-    updated_at timestamp with time zone DEFAULT now() NOT NULL,
-    version integer DEFAULT 1 NOT NULL,
-    display_number bigint NOT NULL,
-    tenant_id uuid NOT NULL,
-    CONSTRAINT workflow_steps_decision_check CHECK (((decision IS NULL) OR ((decision)::text = ANY ((ARRAY['approved'::character varying, 'rejected'::character varying, 'request_changes'::character varying])::text[])))),
-    CONSTRAINT workflow_steps_status_check CHECK (((status)::text = ANY ((ARRAY['pending'::character varying, 'active'::character varying, 'completed'::character varying, 'skipped'::character varying])::text[])))
-);
+CREATE INDEX workflow_form_data_changes_tenant_idx ON public.workflow_form_data_changes USING btree (tenant_id);
 
 --
--- Name: TABLE workflow_steps; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_instances_created_at_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON TABLE public.workflow_steps IS 'ワークフローステップ（承認タスク）';
+CREATE INDEX workflow_instances_created_at_idx ON public.workflow_instances USING btree (tenant_id, created_at DESC);
 
 --
--- Name: COLUMN workflow_steps.id; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_instances_draft_updated_at_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.id IS '主キー';
+CREATE INDEX workflow_instances_draft_updated_at_idx ON public.workflow_instances USING btree (updated_at, id) WHERE ((status)::text = 'draft'::text);
 
 --
--- Name: COLUMN workflow_steps.instance_id; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_instances_initiated_by_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.instance_id IS 'インスタンスID（FK）';
+CREATE INDEX workflow_instances_initiated_by_idx ON public.workflow_instances USING btree (initiated_by);
 
 --
--- Name: COLUMN workflow_steps.step_id; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_instances_initiated_by_keyset_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.step_id IS '定義上のステップID';
+CREATE INDEX workflow_instances_initiated_by_keyset_idx ON public.workflow_instances USING btree (tenant_id, initiated_by, created_at DESC, id DESC);
 
 --
--- Name: COLUMN workflow_steps.step_name; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_instances_proxy_submitted_by_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.step_name IS 'ステップ名';
+CREATE INDEX workflow_instances_proxy_submitted_by_idx ON public.workflow_instances USING btree (tenant_id, proxy_submitted_by) WHERE (proxy_submitted_by IS NOT NULL);
 
 --
--- Name: COLUMN workflow_steps.step_type; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_instances_search_vector_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.step_type IS 'ステップ種別（approval/notification/...）';
+CREATE INDEX workflow_instances_search_vector_idx ON public.workflow_instances USING gin (search_vector);
 
 --
--- Name: COLUMN workflow_steps.status; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_instances_tenant_status_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.status IS '状態（pending/active/completed/skipped）';
+CREATE INDEX workflow_instances_tenant_status_idx ON public.workflow_instances USING btree (tenant_id, status);
 
 --
--- Name: COLUMN workflow_steps.assigned_to; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_instances_title_trgm_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.assigned_to IS '担当者（FK）';
+CREATE INDEX workflow_instances_title_trgm_idx ON public.workflow_instances USING gin (title public.gin_trgm_ops);
 
 --
--- Name: COLUMN workflow_steps.decision; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_proxy_grants_proxy_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.decision IS '判断（approved/rejected/request_changes）';
+CREATE INDEX workflow_proxy_grants_proxy_idx ON public.workflow_proxy_grants USING btree (proxy_id);
 
 --
--- Name: COLUMN workflow_steps.comment; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_proxy_grants_tenant_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.comment IS 'コメント';
+CREATE INDEX workflow_proxy_grants_tenant_idx ON public.workflow_proxy_grants USING btree (tenant_id);
 
 --
--- Name: COLUMN workflow_steps.due_date; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_schedules_definition_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.due_date IS '期限';
+CREATE INDEX workflow_schedules_definition_idx ON public.workflow_schedules USING btree (definition_id);
 
 --
--- Name: COLUMN workflow_steps.started_at; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_schedules_due_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.started_at IS '開始日時';
+CREATE INDEX workflow_schedules_due_idx ON public.workflow_schedules USING btree (next_run_at) WHERE is_active;
 
 --
--- Name: COLUMN workflow_steps.completed_at; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_schedules_owner_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.completed_at IS '完了日時';
+CREATE INDEX workflow_schedules_owner_idx ON public.workflow_schedules USING btree (tenant_id, owner_id);
 
 --
--- Name: COLUMN workflow_steps.version; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_steps_assigned_to_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.version IS '楽観的ロック用バージョン番号';
+CREATE INDEX workflow_steps_assigned_to_idx ON public.workflow_steps USING btree (assigned_to) WHERE ((status)::text = 'active'::text);
 
 --
--- Name: COLUMN workflow_steps.display_number; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_steps_assigned_to_keyset_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.display_number IS '表示用連番（インスタンス内で一意）';
+CREATE INDEX workflow_steps_assigned_to_keyset_idx ON public.workflow_steps USING btree (tenant_id, assigned_to, created_at DESC, id DESC) WHERE ((status)::text = 'active'::text);
 
 --
--- Name: COLUMN workflow_steps.tenant_id; Type: COMMENT; Schema: public; Owner: -
+-- Name: workflow_steps_instance_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-COMMENT ON COLUMN public.workflow_steps.tenant_id IS 'テナントID（FK、RLS 二重防御用）';
+CREATE INDEX workflow_steps_instance_idx ON public.workflow_steps USING btree (instance_id);
 
 --
--- Name: credentials credentials_pkey; Type: CONSTRAINT; Schema: auth; Owner: -
+-- Name: workflow_steps_tenant_id_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY auth.credentials
-    ADD CONSTRAINT credentials_pkey PRIMARY KEY (id);
+CREATE INDEX workflow_steps_tenant_id_idx ON public.workflow_steps USING btree (tenant_id);
 
 --
--- Name: credentials uq_credentials_user_type; Type: CONSTRAINT; Schema: auth; Owner: -
+-- Name: workflow_submissions_tenant_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY auth.credentials
-    ADD CONSTRAINT uq_credentials_user_type UNIQUE (user_id, credential_type);
+CREATE INDEX workflow_submissions_tenant_idx ON public.workflow_submissions USING btree (tenant_id);
 
 --
--- Name: display_id_counters display_id_counters_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_watchers_tenant_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.display_id_counters
-    ADD CONSTRAINT display_id_counters_pkey PRIMARY KEY (tenant_id, entity_type);
+CREATE INDEX workflow_watchers_tenant_idx ON public.workflow_watchers USING btree (tenant_id);
 
 --
--- Name: documents documents_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_watchers_user_idx; Type: INDEX; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.documents
-    ADD CONSTRAINT documents_pkey PRIMARY KEY (id);
+CREATE INDEX workflow_watchers_user_idx ON public.workflow_watchers USING btree (user_id);
 
 --
--- Name: folders folders_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: credentials credentials_updated_at; Type: TRIGGER; Schema: auth; Owner: -
 --
 
-ALTER TABLE ONLY public.folders
-    ADD CONSTRAINT folders_pkey PRIMARY KEY (id);
+CREATE TRIGGER credentials_updated_at BEFORE UPDATE ON auth.credentials FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
 
 --
--- Name: folders folders_tenant_id_parent_id_name_key; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: roles roles_updated_at; Type: TRIGGER; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.folders
-    ADD CONSTRAINT folders_tenant_id_parent_id_name_key UNIQUE NULLS NOT DISTINCT (tenant_id, parent_id, name);
+CREATE TRIGGER roles_updated_at BEFORE UPDATE ON public.roles FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
 
 --
--- Name: notification_logs notification_logs_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: tenants tenants_updated_at; Type: TRIGGER; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.notification_logs
-    ADD CONSTRAINT notification_logs_pkey PRIMARY KEY (id);
+CREATE TRIGGER tenants_updated_at BEFORE UPDATE ON public.tenants FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
 
 --
--- Name: roles roles_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: users users_updated_at; Type: TRIGGER; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.roles
-    ADD CONSTRAINT roles_pkey PRIMARY KEY (id);
+CREATE TRIGGER users_updated_at BEFORE UPDATE ON public.users FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
 
 --
--- Name: roles roles_tenant_name_key; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_comments workflow_comments_updated_at; Type: TRIGGER; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.roles
-    ADD CONSTRAINT roles_tenant_name_key UNIQUE (tenant_id, name);
+CREATE TRIGGER workflow_comments_updated_at BEFORE UPDATE ON public.workflow_comments FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
 
 --
--- Name: tenants tenants_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_definitions workflow_definitions_updated_at; Type: TRIGGER; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.tenants
-    ADD CONSTRAINT tenants_pkey PRIMARY KEY (id);
+CREATE TRIGGER workflow_definitions_updated_at BEFORE UPDATE ON public.workflow_definitions FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
 
 --
--- Name: tenants tenants_subdomain_key; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_instances workflow_instances_updated_at; Type: TRIGGER; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.tenants
-    ADD CONSTRAINT tenants_subdomain_key UNIQUE (subdomain);
+CREATE TRIGGER workflow_instances_updated_at BEFORE UPDATE ON public.workflow_instances FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
 
 --
--- Name: user_roles user_roles_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_steps workflow_steps_updated_at; Type: TRIGGER; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.user_roles
-    ADD CONSTRAINT user_roles_pkey PRIMARY KEY (id);
+CREATE TRIGGER workflow_steps_updated_at BEFORE UPDATE ON public.workflow_steps FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
 
 --
--- Name: user_roles user_roles_user_role_key; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: display_id_counters display_id_counters_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.user_roles
-    ADD CONSTRAINT user_roles_user_role_key UNIQUE (user_id, role_id);
+ALTER TABLE ONLY public.display_id_counters
+    ADD CONSTRAINT display_id_counters_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: users users_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: documents documents_comment_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.users
-    ADD CONSTRAINT users_pkey PRIMARY KEY (id);
+ALTER TABLE ONLY public.documents
+    ADD CONSTRAINT documents_comment_id_fkey FOREIGN KEY (comment_id) REFERENCES public.workflow_comments(id) ON DELETE CASCADE;
 
 --
--- Name: users users_tenant_email_key; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: documents documents_folder_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.users
-    ADD CONSTRAINT users_tenant_email_key UNIQUE (tenant_id, email);
+ALTER TABLE ONLY public.documents
+    ADD CONSTRAINT documents_folder_id_fkey FOREIGN KEY (folder_id) REFERENCES public.folders(id) ON DELETE RESTRICT;
 
 --
--- Name: workflow_comments workflow_comments_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: documents documents_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_comments
-    ADD CONSTRAINT workflow_comments_pkey PRIMARY KEY (id);
+ALTER TABLE ONLY public.documents
+    ADD CONSTRAINT documents_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_definitions workflow_definitions_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: documents documents_uploaded_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_definitions
-    ADD CONSTRAINT workflow_definitions_pkey PRIMARY KEY (id);
+ALTER TABLE ONLY public.documents
+    ADD CONSTRAINT documents_uploaded_by_fkey FOREIGN KEY (uploaded_by) REFERENCES public.users(id) ON DELETE SET NULL;
 
 --
--- Name: workflow_instances workflow_instances_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: documents documents_workflow_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_instances
-    ADD CONSTRAINT workflow_instances_pkey PRIMARY KEY (id);
+ALTER TABLE ONLY public.documents
+    ADD CONSTRAINT documents_workflow_instance_id_fkey FOREIGN KEY (workflow_instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_steps workflow_steps_pkey; Type: CONSTRAINT; Schema: public; Owner: -
+-- Name: folders folders_created_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_steps
-    ADD CONSTRAINT workflow_steps_pkey PRIMARY KEY (id);
+ALTER TABLE ONLY public.folders
+    ADD CONSTRAINT folders_created_by_fkey FOREIGN KEY (created_by) REFERENCES public.users(id) ON DELETE SET NULL;
 
 --
--- Name: idx_credentials_tenant_id; Type: INDEX; Schema: auth; Owner: -
+-- Name: folders folders_parent_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_credentials_tenant_id ON auth.credentials USING btree (tenant_id);
+ALTER TABLE ONLY public.folders
+    ADD CONSTRAINT folders_parent_id_fkey FOREIGN KEY (parent_id) REFERENCES public.folders(id) ON DELETE RESTRICT;
 
 --
--- Name: idx_credentials_user_id; Type: INDEX; Schema: auth; Owner: -
+-- Name: folders folders_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_credentials_user_id ON auth.credentials USING btree (user_id);
+ALTER TABLE ONLY public.folders
+    ADD CONSTRAINT folders_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: idx_documents_folder_id; Type: INDEX; Schema: public; Owner: -
+-- Name: inbox_notifications inbox_notifications_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_documents_folder_id ON public.documents USING btree (folder_id) WHERE (folder_id IS NOT NULL);
+ALTER TABLE ONLY public.inbox_notifications
+    ADD CONSTRAINT inbox_notifications_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: idx_documents_status; Type: INDEX; Schema: public; Owner: -
+-- Name: inbox_notifications inbox_notifications_user_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_documents_status ON public.documents USING btree (status) WHERE ((status)::text <> 'deleted'::text);
+ALTER TABLE ONLY public.inbox_notifications
+    ADD CONSTRAINT inbox_notifications_user_id_fkey FOREIGN KEY (user_id) REFERENCES public.users(id) ON DELETE CASCADE;
 
 --
--- Name: idx_documents_tenant_id; Type: INDEX; Schema: public; Owner: -
+-- Name: inbox_notifications inbox_notifications_workflow_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_documents_tenant_id ON public.documents USING btree (tenant_id);
+ALTER TABLE ONLY public.inbox_notifications
+    ADD CONSTRAINT inbox_notifications_workflow_instance_id_fkey FOREIGN KEY (workflow_instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: idx_documents_workflow_instance_id; Type: INDEX; Schema: public; Owner: -
+-- Name: notification_digest_items notification_digest_items_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_documents_workflow_instance_id ON public.documents USING btree (workflow_instance_id) WHERE (workflow_instance_id IS NOT NULL);
+ALTER TABLE ONLY public.notification_digest_items
+    ADD CONSTRAINT notification_digest_items_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: idx_folders_parent_id; Type: INDEX; Schema: public; Owner: -
+-- Name: notification_digest_items notification_digest_items_user_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_folders_parent_id ON public.folders USING btree (parent_id);
+ALTER TABLE ONLY public.notification_digest_items
+    ADD CONSTRAINT notification_digest_items_user_id_fkey FOREIGN KEY (user_id) REFERENCES public.users(id) ON DELETE CASCADE;
 
 --
--- Name: idx_folders_path; Type: INDEX; Schema: public; Owner: -
+-- Name: notification_digest_items notification_digest_items_workflow_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_folders_path ON public.folders USING btree (path);
+ALTER TABLE ONLY public.notification_digest_items
+    ADD CONSTRAINT notification_digest_items_workflow_instance_id_fkey FOREIGN KEY (workflow_instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: idx_folders_tenant_id; Type: INDEX; Schema: public; Owner: -
+-- Name: notification_logs notification_logs_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_folders_tenant_id ON public.folders USING btree (tenant_id);
+ALTER TABLE ONLY public.notification_logs
+    ADD CONSTRAINT notification_logs_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: idx_notification_logs_recipient_user_id; Type: INDEX; Schema: public; Owner: -
+-- Name: notification_logs notification_logs_workflow_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_notification_logs_recipient_user_id ON public.notification_logs USING btree (recipient_user_id);
+ALTER TABLE ONLY public.notification_logs
+    ADD CONSTRAINT notification_logs_workflow_instance_id_fkey FOREIGN KEY (workflow_instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: idx_notification_logs_sent_at; Type: INDEX; Schema: public; Owner: -
+-- Name: notification_preferences notification_preferences_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_notification_logs_sent_at ON public.notification_logs USING btree (sent_at DESC);
+ALTER TABLE ONLY public.notification_preferences
+    ADD CONSTRAINT notification_preferences_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: idx_notification_logs_tenant_id; Type: INDEX; Schema: public; Owner: -
+-- Name: notification_preferences notification_preferences_user_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_notification_logs_tenant_id ON public.notification_logs USING btree (tenant_id);
+ALTER TABLE ONLY public.notification_preferences
+    ADD CONSTRAINT notification_preferences_user_id_fkey FOREIGN KEY (user_id) REFERENCES public.users(id) ON DELETE CASCADE;
 
 --
--- Name: idx_notification_logs_workflow_instance_id; Type: INDEX; Schema: public; Owner: -
+-- Name: notification_templates notification_templates_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX idx_notification_logs_workflow_instance_id ON public.notification_logs USING btree (workflow_instance_id);
+ALTER TABLE ONLY public.notification_templates
+    ADD CONSTRAINT notification_templates_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: idx_users_display_number; Type: INDEX; Schema: public; Owner: -
+-- Name: notification_templates notification_templates_updated_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE UNIQUE INDEX idx_users_display_number ON public.users USING btree (tenant_id, display_number) WHERE (display_number IS NOT NULL);
+ALTER TABLE ONLY public.notification_templates
+    ADD CONSTRAINT notification_templates_updated_by_fkey FOREIGN KEY (updated_by) REFERENCES public.users(id) ON DELETE RESTRICT;
 
 --
--- Name: idx_workflow_instances_display_number; Type: INDEX; Schema: public; Owner: -
+-- Name: roles roles_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE UNIQUE INDEX idx_workflow_instances_display_number ON public.workflow_instances USING btree (tenant_id, display_number) WHERE (display_number IS NOT NULL);
+ALTER TABLE ONLY public.roles
+    ADD CONSTRAINT roles_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: idx_workflow_steps_display_number; Type: INDEX; Schema: public; Owner: -
+-- Name: user_roles user_roles_role_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE UNIQUE INDEX idx_workflow_steps_display_number ON public.workflow_steps USING btree (instance_id, display_number) WHERE (display_number IS NOT NULL);
+ALTER TABLE ONLY public.user_roles
+    ADD CONSTRAINT user_roles_role_id_fkey FOREIGN KEY (role_id) REFERENCES public.roles(id) ON DELETE CASCADE;
 
 --
--- Name: user_roles_role_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: user_roles user_roles_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX user_roles_role_idx ON public.user_roles USING btree (role_id);
+ALTER TABLE ONLY public.user_roles
+    ADD CONSTRAINT user_roles_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: user_roles_tenant_id_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: user_roles user_roles_user_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX user_roles_tenant_id_idx ON public.user_roles USING btree (tenant_id);
+ALTER TABLE ONLY public.user_roles
+    ADD CONSTRAINT user_roles_user_id_fkey FOREIGN KEY (user_id) REFERENCES public.users(id) ON DELETE CASCADE;
 
 --
--- Name: user_roles_user_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: users users_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX user_roles_user_idx ON public.user_roles USING btree (user_id);
+ALTER TABLE ONLY public.users
+    ADD CONSTRAINT users_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: users_tenant_status_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: webhook_deliveries webhook_deliveries_subscription_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX users_tenant_status_idx ON public.users USING btree (tenant_id, status);
+ALTER TABLE ONLY public.webhook_deliveries
+    ADD CONSTRAINT webhook_deliveries_subscription_id_fkey FOREIGN KEY (subscription_id) REFERENCES public.webhook_subscriptions(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_comments_instance_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: webhook_deliveries webhook_deliveries_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX workflow_comments_instance_idx ON public.workflow_comments USING btree (instance_id);
+ALTER TABLE ONLY public.webhook_deliveries
+    ADD CONSTRAINT webhook_deliveries_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_comments_tenant_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: webhook_subscriptions webhook_subscriptions_created_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX workflow_comments_tenant_idx ON public.workflow_comments USING btree (tenant_id);
+ALTER TABLE ONLY public.webhook_subscriptions
+    ADD CONSTRAINT webhook_subscriptions_created_by_fkey FOREIGN KEY (created_by) REFERENCES public.users(id) ON DELETE RESTRICT;
 
 --
--- Name: workflow_definitions_tenant_status_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: webhook_subscriptions webhook_subscriptions_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX workflow_definitions_tenant_status_idx ON public.workflow_definitions USING btree (tenant_id, status);
+ALTER TABLE ONLY public.webhook_subscriptions
+    ADD CONSTRAINT webhook_subscriptions_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_instances_created_at_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: workflow_activities workflow_activities_actor_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX workflow_instances_created_at_idx ON public.workflow_instances USING btree (tenant_id, created_at DESC);
+ALTER TABLE ONLY public.workflow_activities
+    ADD CONSTRAINT workflow_activities_actor_id_fkey FOREIGN KEY (actor_id) REFERENCES public.users(id) ON DELETE RESTRICT;
 
 --
--- Name: workflow_instances_initiated_by_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: workflow_activities workflow_activities_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX workflow_instances_initiated_by_idx ON public.workflow_instances USING btree (initiated_by);
+ALTER TABLE ONLY public.workflow_activities
+    ADD CONSTRAINT workflow_activities_instance_id_fkey FOREIGN KEY (instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_instances_tenant_status_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: workflow_activities workflow_activities_step_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX workflow_instances_tenant_status_idx ON public.workflow_instances USING btree (tenant_id, status);
+ALTER TABLE ONLY public.workflow_activities
+    ADD CONSTRAINT workflow_activities_step_id_fkey FOREIGN KEY (step_id) REFERENCES public.workflow_steps(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_steps_assigned_to_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: workflow_activities workflow_activities_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX workflow_steps_assigned_to_idx ON public.workflow_steps USING btree (assigned_to) WHERE ((status)::text = 'active'::text);
+ALTER TABLE ONLY public.workflow_activities
+    ADD CONSTRAINT workflow_activities_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_steps_instance_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: workflow_comment_revisions workflow_comment_revisions_comment_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX workflow_steps_instance_idx ON public.workflow_steps USING btree (instance_id);
+ALTER TABLE ONLY public.workflow_comment_revisions
+    ADD CONSTRAINT workflow_comment_revisions_comment_id_fkey FOREIGN KEY (comment_id) REFERENCES public.workflow_comments(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_steps_tenant_id_idx; Type: INDEX; Schema: public; Owner: -
+-- Name: workflow_comment_revisions workflow_comment_revisions_edited_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE INDEX workflow_steps_tenant_id_idx ON public.workflow_steps USING btree (tenant_id);
+ALTER TABLE ONLY public.workflow_comment_revisions
+    ADD CONSTRAINT workflow_comment_revisions_edited_by_fkey FOREIGN KEY (edited_by) REFERENCES public.users(id) ON DELETE RESTRICT;
 
 --
--- Name: credentials credentials_updated_at; Type: TRIGGER; Schema: auth; Owner: -
+-- Name: workflow_comment_revisions workflow_comment_revisions_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE TRIGGER credentials_updated_at BEFORE UPDATE ON auth.credentials FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
+ALTER TABLE ONLY public.workflow_comment_revisions
+    ADD CONSTRAINT workflow_comment_revisions_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: roles roles_updated_at; Type: TRIGGER; Schema: public; Owner: -
+-- Name: workflow_comments workflow_comments_deleted_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE TRIGGER roles_updated_at BEFORE UPDATE ON public.roles FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
+ALTER TABLE ONLY public.workflow_comments
+    ADD CONSTRAINT workflow_comments_deleted_by_fkey FOREIGN KEY (deleted_by) REFERENCES public.users(id) ON DELETE SET NULL;
 
 --
--- Name: tenants tenants_updated_at; Type: TRIGGER; Schema: public; Owner: -
+-- Name: workflow_comments workflow_comments_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE TRIGGER tenants_updated_at BEFORE UPDATE ON public.tenants FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
+ALTER TABLE ONLY public.workflow_comments
+    ADD CONSTRAINT workflow_comments_instance_id_fkey FOREIGN KEY (instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: users users_updated_at; Type: TRIGGER; Schema: public; Owner: -
+-- Name: workflow_comments workflow_comments_parent_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE TRIGGER users_updated_at BEFORE UPDATE ON public.users FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
+ALTER TABLE ONLY public.workflow_comments
+    ADD CONSTRAINT workflow_comments_parent_id_fkey FOREIGN KEY (parent_id) REFERENCES public.workflow_comments(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_comments workflow_comments_updated_at; Type: TRIGGER; Schema: public; Owner: -
+-- Name: workflow_comments workflow_comments_posted_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE TRIGGER workflow_comments_updated_at BEFORE UPDATE ON public.workflow_comments FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
+ALTER TABLE ONLY public.workflow_comments
+    ADD CONSTRAINT workflow_comments_posted_by_fkey FOREIGN KEY (posted_by) REFERENCES public.users(id) ON DELETE RESTRICT;
 
 --
--- Name: workflow_definitions workflow_definitions_updated_at; Type: TRIGGER; Schema: public; Owner: -
+-- Name: workflow_comments workflow_comments_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE TRIGGER workflow_definitions_updated_at BEFORE UPDATE ON public.workflow_definitions FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
+ALTER TABLE ONLY public.workflow_comments
+    ADD CONSTRAINT workflow_comments_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_instances workflow_instances_updated_at; Type: TRIGGER; Schema: public; Owner: -
+-- Name: workflow_definitions workflow_definitions_created_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE TRIGGER workflow_instances_updated_at BEFORE UPDATE ON public.workflow_instances FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
+ALTER TABLE ONLY public.workflow_definitions
+    ADD CONSTRAINT workflow_definitions_created_by_fkey FOREIGN KEY (created_by) REFERENCES public.users(id);
 
 --
--- Name: workflow_steps workflow_steps_updated_at; Type: TRIGGER; Schema: public; Owner: -
+-- Name: workflow_definitions workflow_definitions_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-CREATE TRIGGER workflow_steps_updated_at BEFORE UPDATE ON public.workflow_steps FOR EACH ROW EXECUTE FUNCTION public.update_updated_at();
+ALTER TABLE ONLY public.workflow_definitions
+    ADD CONSTRAINT workflow_definitions_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: display_id_counters display_id_counters_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_event_outbox workflow_event_outbox_actor_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.display_id_counters
-    ADD CONSTRAINT display_id_counters_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_event_outbox
+    ADD CONSTRAINT workflow_event_outbox_actor_id_fkey FOREIGN KEY (actor_id) REFERENCES public.users(id) ON DELETE RESTRICT;
 
 --
--- Name: documents documents_folder_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_event_outbox workflow_event_outbox_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.documents
-    ADD CONSTRAINT documents_folder_id_fkey FOREIGN KEY (folder_id) REFERENCES public.folders(id) ON DELETE RESTRICT;
+ALTER TABLE ONLY public.workflow_event_outbox
+    ADD CONSTRAINT workflow_event_outbox_instance_id_fkey FOREIGN KEY (instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: documents documents_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_event_outbox workflow_event_outbox_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.documents
-    ADD CONSTRAINT documents_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_event_outbox
+    ADD CONSTRAINT workflow_event_outbox_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: documents documents_uploaded_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_form_data_changes workflow_form_data_changes_changed_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.documents
-    ADD CONSTRAINT documents_uploaded_by_fkey FOREIGN KEY (uploaded_by) REFERENCES public.users(id) ON DELETE SET NULL;
+ALTER TABLE ONLY public.workflow_form_data_changes
+    ADD CONSTRAINT workflow_form_data_changes_changed_by_fkey FOREIGN KEY (changed_by) REFERENCES public.users(id) ON DELETE RESTRICT;
 
 --
--- Name: documents documents_workflow_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_form_data_changes workflow_form_data_changes_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.documents
-    ADD CONSTRAINT documents_workflow_instance_id_fkey FOREIGN KEY (workflow_instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_form_data_changes
+    ADD CONSTRAINT workflow_form_data_changes_instance_id_fkey FOREIGN KEY (instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: folders folders_created_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_form_data_changes workflow_form_data_changes_step_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.folders
-    ADD CONSTRAINT folders_created_by_fkey FOREIGN KEY (created_by) REFERENCES public.users(id) ON DELETE SET NULL;
+ALTER TABLE ONLY public.workflow_form_data_changes
+    ADD CONSTRAINT workflow_form_data_changes_step_id_fkey FOREIGN KEY (step_id) REFERENCES public.workflow_steps(id) ON DELETE CASCADE;
 
 --
--- Name: folders folders_parent_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_form_data_changes workflow_form_data_changes_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.folders
-    ADD CONSTRAINT folders_parent_id_fkey FOREIGN KEY (parent_id) REFERENCES public.folders(id) ON DELETE RESTRICT;
+ALTER TABLE ONLY public.workflow_form_data_changes
+    ADD CONSTRAINT workflow_form_data_changes_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: folders folders_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_instances workflow_instances_definition_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.folders
-    ADD CONSTRAINT folders_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_instances
+    ADD CONSTRAINT workflow_instances_definition_id_fkey FOREIGN KEY (definition_id) REFERENCES public.workflow_definitions(id);
 
 --
--- Name: notification_logs notification_logs_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_instances workflow_instances_initiated_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.notification_logs
-    ADD CONSTRAINT notification_logs_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_instances
+    ADD CONSTRAINT workflow_instances_initiated_by_fkey FOREIGN KEY (initiated_by) REFERENCES public.users(id);
 
 --
--- Name: notification_logs notification_logs_workflow_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_instances workflow_instances_proxy_submitted_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.notification_logs
-    ADD CONSTRAINT notification_logs_workflow_instance_id_fkey FOREIGN KEY (workflow_instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_instances
+    ADD CONSTRAINT workflow_instances_proxy_submitted_by_fkey FOREIGN KEY (proxy_submitted_by) REFERENCES public.users(id);
 
 --
--- Name: roles roles_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_instances workflow_instances_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.roles
-    ADD CONSTRAINT roles_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_instances
+    ADD CONSTRAINT workflow_instances_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: user_roles user_roles_role_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_proxy_grants workflow_proxy_grants_principal_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.user_roles
-    ADD CONSTRAINT user_roles_role_id_fkey FOREIGN KEY (role_id) REFERENCES public.roles(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_proxy_grants
+    ADD CONSTRAINT workflow_proxy_grants_principal_id_fkey FOREIGN KEY (principal_id) REFERENCES public.users(id) ON DELETE CASCADE;
 
 --
--- Name: user_roles user_roles_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_proxy_grants workflow_proxy_grants_proxy_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.user_roles
-    ADD CONSTRAINT user_roles_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_proxy_grants
+    ADD CONSTRAINT workflow_proxy_grants_proxy_id_fkey FOREIGN KEY (proxy_id) REFERENCES public.users(id) ON DELETE CASCADE;
 
 --
--- Name: user_roles user_roles_user_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_proxy_grants workflow_proxy_grants_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.user_roles
-    ADD CONSTRAINT user_roles_user_id_fkey FOREIGN KEY (user_id) REFERENCES public.users(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_proxy_grants
+    ADD CONSTRAINT workflow_proxy_grants_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: users users_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_schedules workflow_schedules_definition_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.users
-    ADD CONSTRAINT users_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_schedules
+    ADD CONSTRAINT workflow_schedules_definition_id_fkey FOREIGN KEY (definition_id) REFERENCES public.workflow_definitions(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_comments workflow_comments_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_schedules workflow_schedules_owner_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_comments
-    ADD CONSTRAINT workflow_comments_instance_id_fkey FOREIGN KEY (instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_schedules
+    ADD CONSTRAINT workflow_schedules_owner_id_fkey FOREIGN KEY (owner_id) REFERENCES public.users(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_comments workflow_comments_posted_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_schedules workflow_schedules_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_comments
-    ADD CONSTRAINT workflow_comments_posted_by_fkey FOREIGN KEY (posted_by) REFERENCES public.users(id) ON DELETE RESTRICT;
+ALTER TABLE ONLY public.workflow_schedules
+    ADD CONSTRAINT workflow_schedules_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_comments workflow_comments_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_steps workflow_steps_assigned_to_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_comments
-    ADD CONSTRAINT workflow_comments_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_steps
+    ADD CONSTRAINT workflow_steps_assigned_to_fkey FOREIGN KEY (assigned_to) REFERENCES public.users(id) ON DELETE SET NULL;
 
 --
--- Name: workflow_definitions workflow_definitions_created_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_steps workflow_steps_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_definitions
-    ADD CONSTRAINT workflow_definitions_created_by_fkey FOREIGN KEY (created_by) REFERENCES public.users(id);
+ALTER TABLE ONLY public.workflow_steps
+    ADD CONSTRAINT workflow_steps_instance_id_fkey FOREIGN KEY (instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_definitions workflow_definitions_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_steps workflow_steps_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_definitions
-    ADD CONSTRAINT workflow_definitions_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_steps
+    ADD CONSTRAINT workflow_steps_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_instances workflow_instances_definition_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_submissions workflow_submissions_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_instances
-    ADD CONSTRAINT workflow_instances_definition_id_fkey FOREIGN KEY (definition_id) REFERENCES public.workflow_definitions(id);
+ALTER TABLE ONLY public.workflow_submissions
+    ADD CONSTRAINT workflow_submissions_instance_id_fkey FOREIGN KEY (instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_instances workflow_instances_initiated_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_submissions workflow_submissions_submitted_by_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_instances
-    ADD CONSTRAINT workflow_instances_initiated_by_fkey FOREIGN KEY (initiated_by) REFERENCES public.users(id);
+ALTER TABLE ONLY public.workflow_submissions
+    ADD CONSTRAINT workflow_submissions_submitted_by_fkey FOREIGN KEY (submitted_by) REFERENCES public.users(id) ON DELETE RESTRICT;
 
 --
--- Name: workflow_instances workflow_instances_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_submissions workflow_submissions_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_instances
-    ADD CONSTRAINT workflow_instances_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_submissions
+    ADD CONSTRAINT workflow_submissions_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_steps workflow_steps_assigned_to_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_watchers workflow_watchers_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_steps
-    ADD CONSTRAINT workflow_steps_assigned_to_fkey FOREIGN KEY (assigned_to) REFERENCES public.users(id) ON DELETE SET NULL;
+ALTER TABLE ONLY public.workflow_watchers
+    ADD CONSTRAINT workflow_watchers_instance_id_fkey FOREIGN KEY (instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_steps workflow_steps_instance_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_watchers workflow_watchers_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_steps
-    ADD CONSTRAINT workflow_steps_instance_id_fkey FOREIGN KEY (instance_id) REFERENCES public.workflow_instances(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_watchers
+    ADD CONSTRAINT workflow_watchers_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
 
 --
--- Name: workflow_steps workflow_steps_tenant_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
+-- Name: workflow_watchers workflow_watchers_user_id_fkey; Type: FK CONSTRAINT; Schema: public; Owner: -
 --
 
-ALTER TABLE ONLY public.workflow_steps
-    ADD CONSTRAINT workflow_steps_tenant_id_fkey FOREIGN KEY (tenant_id) REFERENCES public.tenants(id) ON DELETE CASCADE;
+ALTER TABLE ONLY public.workflow_watchers
+    ADD CONSTRAINT workflow_watchers_user_id_fkey FOREIGN KEY (user_id) REFERENCES public.users(id) ON DELETE CASCADE;
 
 --
 -- Name: credentials; Type: ROW SECURITY; Schema: auth; Owner: -
@@ -1352,6 +3234,18 @@ ALTER TABLE public.documents ENABLE ROW LEVEL SECURITY;
 
 ALTER TABLE public.folders ENABLE ROW LEVEL SECURITY;
 
+--
+-- Name: inbox_notifications; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.inbox_notifications ENABLE ROW LEVEL SECURITY;
+
+--
+-- Name: notification_digest_items; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.notification_digest_items ENABLE ROW LEVEL SECURITY;
+
 --
 -- Name: notification_logs; Type: ROW SECURITY; Schema: public; Owner: -
 --
@@ -1364,6 +3258,18 @@ ALTER TABLE public.notification_logs ENABLE ROW LEVEL SECURITY;
 
 CREATE POLICY notification_logs_tenant_isolation ON public.notification_logs USING ((tenant_id = (current_setting('app.current_tenant_id'::text))::uuid));
 
+--
+-- Name: notification_preferences; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.notification_preferences ENABLE ROW LEVEL SECURITY;
+
+--
+-- Name: notification_templates; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.notification_templates ENABLE ROW LEVEL SECURITY;
+
 --
 -- Name: roles; Type: ROW SECURITY; Schema: public; Owner: -
 --
@@ -1388,6 +3294,30 @@ CREATE POLICY tenant_isolation ON public.documents TO ringiflow_app USING ((tena
 
 CREATE POLICY tenant_isolation ON public.folders TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
 
+--
+-- Name: inbox_notifications tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.inbox_notifications TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
+--
+-- Name: notification_digest_items tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.notification_digest_items TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
+--
+-- Name: notification_preferences tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.notification_preferences TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
+--
+-- Name: notification_templates tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.notification_templates TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
 --
 -- Name: roles tenant_isolation; Type: POLICY; Schema: public; Owner: -
 --
@@ -1412,6 +3342,30 @@ CREATE POLICY tenant_isolation ON public.user_roles TO ringiflow_app USING ((ten
 
 CREATE POLICY tenant_isolation ON public.users TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
 
+--
+-- Name: webhook_deliveries tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.webhook_deliveries TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
+--
+-- Name: webhook_subscriptions tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.webhook_subscriptions TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
+--
+-- Name: workflow_activities tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.workflow_activities TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
+--
+-- Name: workflow_comment_revisions tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.workflow_comment_revisions TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
 --
 -- Name: workflow_comments tenant_isolation; Type: POLICY; Schema: public; Owner: -
 --
@@ -1424,18 +3378,54 @@ CREATE POLICY tenant_isolation ON public.workflow_comments TO ringiflow_app USIN
 
 CREATE POLICY tenant_isolation ON public.workflow_definitions TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
 
+--
+-- Name: workflow_event_outbox tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.workflow_event_outbox TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
+--
+-- Name: workflow_form_data_changes tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.workflow_form_data_changes TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
 --
 -- Name: workflow_instances tenant_isolation; Type: POLICY; Schema: public; Owner: -
 --
 
 CREATE POLICY tenant_isolation ON public.workflow_instances TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
 
+--
+-- Name: workflow_proxy_grants tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.workflow_proxy_grants TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
+--
+-- Name: workflow_schedules tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.workflow_schedules TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
 --
 -- Name: workflow_steps tenant_isolation; Type: POLICY; Schema: public; Owner: -
 --
 
 CREATE POLICY tenant_isolation ON public.workflow_steps TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
 
+--
+-- Name: workflow_submissions tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.workflow_submissions TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
+--
+-- Name: workflow_watchers tenant_isolation; Type: POLICY; Schema: public; Owner: -
+--
+
+CREATE POLICY tenant_isolation ON public.workflow_watchers TO ringiflow_app USING ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid)) WITH CHECK ((tenant_id = (NULLIF(current_setting('app.tenant_id'::text, true), ''::text))::uuid));
+
 --
 -- Name: tenants; Type: ROW SECURITY; Schema: public; Owner: -
 --
@@ -1454,6 +3444,30 @@ ALTER TABLE public.user_roles ENABLE ROW LEVEL SECURITY;
 
 ALTER TABLE public.users ENABLE ROW LEVEL SECURITY;
 
+--
+-- Name: webhook_deliveries; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.webhook_deliveries ENABLE ROW LEVEL SECURITY;
+
+--
+-- Name: webhook_subscriptions; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.webhook_subscriptions ENABLE ROW LEVEL SECURITY;
+
+--
+-- Name: workflow_activities; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.workflow_activities ENABLE ROW LEVEL SECURITY;
+
+--
+-- Name: workflow_comment_revisions; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.workflow_comment_revisions ENABLE ROW LEVEL SECURITY;
+
 --
 -- Name: workflow_comments; Type: ROW SECURITY; Schema: public; Owner: -
 --
@@ -1466,18 +3480,54 @@ ALTER TABLE public.workflow_comments ENABLE ROW LEVEL SECURITY;
 
 ALTER TABLE public.workflow_definitions ENABLE ROW LEVEL SECURITY;
 
+--
+-- Name: workflow_event_outbox; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.workflow_event_outbox ENABLE ROW LEVEL SECURITY;
+
+--
+-- Name: workflow_form_data_changes; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.workflow_form_data_changes ENABLE ROW LEVEL SECURITY;
+
 --
 -- Name: workflow_instances; Type: ROW SECURITY; Schema: public; Owner: -
 --
 
 ALTER TABLE public.workflow_instances ENABLE ROW LEVEL SECURITY;
 
+--
+-- Name: workflow_proxy_grants; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.workflow_proxy_grants ENABLE ROW LEVEL SECURITY;
+
+--
+-- Name: workflow_schedules; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.workflow_schedules ENABLE ROW LEVEL SECURITY;
+
 --
 -- Name: workflow_steps; Type: ROW SECURITY; Schema: public; Owner: -
 --
 
 ALTER TABLE public.workflow_steps ENABLE ROW LEVEL SECURITY;
 
+--
+-- Name: workflow_submissions; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.workflow_submissions ENABLE ROW LEVEL SECURITY;
+
+--
+-- Name: workflow_watchers; Type: ROW SECURITY; Schema: public; Owner: -
+--
+
+ALTER TABLE public.workflow_watchers ENABLE ROW LEVEL SECURITY;
+
 --
 -- PostgreSQL database dump complete
 --
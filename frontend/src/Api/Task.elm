@@ -12,6 +12,7 @@ BFF の `/api/v1/tasks` エンドポイントへのアクセスを提供。
     -- 自分のタスク一覧を取得
     TaskApi.listMyTasks
         { config = requestConfig
+        , cursor = Nothing
         , toMsg = GotTasks
         }
 
@@ -26,26 +27,28 @@ BFF の `/api/v1/tasks` エンドポイントへのアクセスを提供。
 -}
 
 import Api exposing (ApiError, RequestConfig)
-import Data.Task as Task exposing (TaskDetail, TaskItem)
+import Data.Task as Task exposing (TaskDetail, TaskItemList)
+import Url.Builder as Builder
 
 
 {-| 自分のタスク一覧を取得
 
 `GET /api/v1/tasks/my`
 
-ログインユーザーにアサインされたアクティブなタスクの一覧を返す。
-タスク一覧画面で使用。
+ログインユーザーにアサインされたアクティブなタスクの一覧を新しい順に返す。
+タスク一覧画面で使用。`cursor` に前ページの `nextCursor` を渡すと続きを取得する。
 
 -}
 listMyTasks :
     { config : RequestConfig
-    , toMsg : Result ApiError (List TaskItem) -> msg
+    , cursor : Maybe String
+    , toMsg : Result ApiError TaskItemList -> msg
     }
     -> Cmd msg
-listMyTasks { config, toMsg } =
+listMyTasks { config, cursor, toMsg } =
     Api.get
         { config = config
-        , url = "/api/v1/tasks/my"
+        , url = "/api/v1/tasks/my" ++ Builder.toQuery (List.filterMap identity [ Maybe.map (Builder.string "cursor") cursor ])
         , decoder = Task.listDecoder
         , toMsg = toMsg
         }
@@ -34,6 +34,7 @@ BFF の `/api/v1/workflows` エンドポイントへのアクセスを提供。
     -- 自分の申請一覧を取得
     WorkflowApi.listMyWorkflows
         { config = requestConfig
+        , cursor = Nothing
         , toMsg = GotWorkflows
         }
 
@@ -48,28 +49,30 @@ BFF の `/api/v1/workflows` エンドポイントへのアクセスを提供。
 
 import Api exposing (ApiError, RequestConfig)
 import Data.WorkflowComment as WorkflowComment exposing (WorkflowComment)
-import Data.WorkflowInstance as WorkflowInstance exposing (WorkflowInstance)
+import Data.WorkflowInstance as WorkflowInstance exposing (WorkflowInstance, WorkflowInstanceList)
 import Http
 import Json.Encode as Encode
+import Url.Builder as Builder
 
 
 {-| 自分のワークフロー一覧を取得
 
 `GET /api/v1/workflows`
 
-ログインユーザーが作成したワークフローインスタンスの一覧を返す。
-申請一覧画面で使用。
+ログインユーザーが作成したワークフローインスタンスの一覧を新しい順に返す。
+申請一覧画面で使用。`cursor` に前ページの `nextCursor` を渡すと続きを取得する。
 
 -}
 listMyWorkflows :
     { config : RequestConfig
-    , toMsg : Result ApiError (List WorkflowInstance) -> msg
+    , cursor : Maybe String
+    , toMsg : Result ApiError WorkflowInstanceList -> msg
     }
     -> Cmd msg
-listMyWorkflows { config, toMsg } =
+listMyWorkflows { config, cursor, toMsg } =
     Api.get
         { config = config
-        , url = "/api/v1/workflows"
+        , url = "/api/v1/workflows" ++ Builder.toQuery (List.filterMap identity [ Maybe.map (Builder.string "cursor") cursor ])
         , decoder = WorkflowInstance.listDecoder
         , toMsg = toMsg
         }
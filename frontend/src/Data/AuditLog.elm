@@ -109,6 +109,36 @@ actionToJapanese action =
         "role.delete" ->
             "ロール削除"
 
+        "webhook.create" ->
+            "Webhook 作成"
+
+        "webhook.update" ->
+            "Webhook 更新"
+
+        "webhook.delete" ->
+            "Webhook 削除"
+
+        "notification_template.update" ->
+            "通知テンプレート更新"
+
+        "notification_template.delete" ->
+            "通知テンプレート削除"
+
+        "notification_log.resend" ->
+            "通知再送"
+
+        "tenant_settings.update" ->
+            "テナント設定更新"
+
+        "workflow.cancel" ->
+            "ワークフロー強制取消"
+
+        "workflow.reassign" ->
+            "担当者変更"
+
+        "workflow.force_complete" ->
+            "ワークフロー強制完了"
+
         _ ->
             action
 
@@ -1,6 +1,7 @@
 module Data.Task exposing
     ( TaskDetail
     , TaskItem
+    , TaskItemList
     , WorkflowSummary
     , detailDecoder
     , listDecoder
@@ -17,6 +18,7 @@ module Data.Task exposing
 ## 型の構成
 
   - `TaskItem`: タスク一覧用（軽量なワークフロー概要を含む）
+  - `TaskItemList`: タスク一覧の 1 ページ（カーソルページネーション付き）
   - `TaskDetail`: タスク詳細用（完全な WorkflowStep + WorkflowInstance）
   - `WorkflowSummary`: タスク一覧に表示するワークフロー概要
 
@@ -81,6 +83,14 @@ type alias TaskDetail =
     }
 
 
+{-| タスク一覧（カーソルページネーション付き）
+-}
+type alias TaskItemList =
+    { items : List TaskItem
+    , nextCursor : Maybe String
+    }
+
+
 
 -- DECODERS
 
@@ -118,9 +128,11 @@ taskItemDecoder =
 
 {-| タスク一覧をデコード
 -}
-listDecoder : Decoder (List TaskItem)
+listDecoder : Decoder TaskItemList
 listDecoder =
-    Decode.list taskItemDecoder
+    Decode.succeed TaskItemList
+        |> required "items" (Decode.list taskItemDecoder)
+        |> optional "next_cursor" (Decode.nullable Decode.string) Nothing
 
 
 {-| タスク詳細をデコード
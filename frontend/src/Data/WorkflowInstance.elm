@@ -4,6 +4,7 @@ module Data.WorkflowInstance exposing
     , StepStatus(..)
     , WorkflowInstance
     , WorkflowInstanceId
+    , WorkflowInstanceList
     , WorkflowStep
     , decisionFromString
     , decisionToJapanese
@@ -127,6 +128,14 @@ type alias WorkflowInstance =
     }
 
 
+{-| ワークフローインスタンス一覧（カーソルページネーション付き）
+-}
+type alias WorkflowInstanceList =
+    { items : List WorkflowInstance
+    , nextCursor : Maybe String
+    }
+
+
 
 -- STATUS HELPERS
 
@@ -433,6 +442,8 @@ detailDecoder =
 
 {-| ワークフローインスタンス一覧をデコード
 -}
-listDecoder : Decoder (List WorkflowInstance)
+listDecoder : Decoder WorkflowInstanceList
 listDecoder =
-    Decode.list decoder
+    Decode.succeed WorkflowInstanceList
+        |> required "items" (Decode.list decoder)
+        |> optional "next_cursor" (Decode.nullable Decode.string) Nothing
@@ -17,16 +17,18 @@ module Page.Task.List exposing
   - タスク一覧の表示（テーブル形式）
   - 各タスクの申請タイトル、ステップ名、ステータス、期限を表示
   - 詳細ページへの遷移
+  - 「さらに読み込む」による続きの取得（カーソルページネーション）
 
 -}
 
 import Api exposing (ApiError)
 import Api.Task as TaskApi
 import Component.Badge as Badge
+import Component.Button as Button
 import Component.EmptyState as EmptyState
 import Component.ErrorState as ErrorState
 import Component.LoadingSpinner as LoadingSpinner
-import Data.Task exposing (TaskItem)
+import Data.Task exposing (TaskItem, TaskItemList)
 import Data.WorkflowInstance as WorkflowInstance
 import Html exposing (..)
 import Html.Attributes exposing (..)
@@ -45,7 +47,8 @@ import Util.DateFormat as DateFormat
 -}
 type alias Model =
     { shared : Shared
-    , tasks : RemoteData ApiError (List TaskItem)
+    , tasks : RemoteData ApiError TaskItemList
+    , loadingMore : Bool
     }
 
 
@@ -55,9 +58,11 @@ init : Shared -> ( Model, Cmd Msg )
 init shared =
     ( { shared = shared
       , tasks = Loading
+      , loadingMore = False
       }
     , TaskApi.listMyTasks
         { config = Shared.toRequestConfig shared
+        , cursor = Nothing
         , toMsg = GotTasks
         }
     )
@@ -77,7 +82,9 @@ updateShared shared model =
 {-| メッセージ
 -}
 type Msg
-    = GotTasks (Result ApiError (List TaskItem))
+    = GotTasks (Result ApiError TaskItemList)
+    | LoadMore String
+    | GotMoreTasks (Result ApiError TaskItemList)
     | Refresh
 
 
@@ -98,10 +105,38 @@ update msg model =
                     , Cmd.none
                     )
 
+        LoadMore cursor ->
+            ( { model | loadingMore = True }
+            , TaskApi.listMyTasks
+                { config = Shared.toRequestConfig model.shared
+                , cursor = Just cursor
+                , toMsg = GotMoreTasks
+                }
+            )
+
+        GotMoreTasks result ->
+            case result of
+                Ok page ->
+                    ( { model
+                        | tasks =
+                            RemoteData.map
+                                (\current -> { items = current.items ++ page.items, nextCursor = page.nextCursor })
+                                model.tasks
+                        , loadingMore = False
+                      }
+                    , Cmd.none
+                    )
+
+                Err err ->
+                    ( { model | tasks = Failure err, loadingMore = False }
+                    , Cmd.none
+                    )
+
         Refresh ->
-            ( { model | tasks = Loading }
+            ( { model | tasks = Loading, loadingMore = False }
             , TaskApi.listMyTasks
                 { config = Shared.toRequestConfig model.shared
+                , cursor = Nothing
                 , toMsg = GotTasks
                 }
             )
@@ -140,8 +175,8 @@ viewContent model =
         Failure _ ->
             viewError
 
-        Success tasks ->
-            viewTaskList (Shared.zone model.shared) tasks
+        Success taskList ->
+            viewTaskList (Shared.zone model.shared) model.loadingMore taskList
 
 
 viewError : Html Msg
@@ -152,9 +187,9 @@ viewError =
         }
 
 
-viewTaskList : Time.Zone -> List TaskItem -> Html Msg
-viewTaskList zone tasks =
-    if List.isEmpty tasks then
+viewTaskList : Time.Zone -> Bool -> TaskItemList -> Html Msg
+viewTaskList zone loadingMore taskList =
+    if List.isEmpty taskList.items then
         EmptyState.view
             { message = "承認待ちのタスクはありません"
             , description = Just "新しいタスクが割り当てられるとここに表示されます"
@@ -162,8 +197,9 @@ viewTaskList zone tasks =
 
     else
         div []
-            [ div [ class "overflow-x-auto rounded-lg border border-secondary-200" ] [ viewTaskTable zone tasks ]
-            , viewCount (List.length tasks)
+            [ div [ class "overflow-x-auto rounded-lg border border-secondary-200" ] [ viewTaskTable zone taskList.items ]
+            , viewCount (List.length taskList.items) taskList.nextCursor
+            , viewLoadMore loadingMore taskList.nextCursor
             ]
 
 
@@ -203,7 +239,45 @@ viewTaskRow zone task =
         ]
 
 
-viewCount : Int -> Html Msg
-viewCount count =
+{-| 件数表示
+
+続きのページがある場合は総件数が分からないため、表示中の件数を示す。
+
+-}
+viewCount : Int -> Maybe String -> Html Msg
+viewCount count nextCursor =
+    let
+        label =
+            case nextCursor of
+                Just _ ->
+                    String.fromInt count ++ " 件を表示中"
+
+                Nothing ->
+                    "全 " ++ String.fromInt count ++ " 件"
+    in
     div [ class "mt-4 text-sm text-secondary-500" ]
-        [ text ("全 " ++ String.fromInt count ++ " 件") ]
+        [ text label ]
+
+
+viewLoadMore : Bool -> Maybe String -> Html Msg
+viewLoadMore loadingMore nextCursor =
+    case nextCursor of
+        Just cursor ->
+            div [ class "mt-4 flex justify-center" ]
+                [ Button.view
+                    { variant = Button.Outline
+                    , disabled = loadingMore
+                    , onClick = LoadMore cursor
+                    }
+                    [ text
+                        (if loadingMore then
+                            "読み込み中..."
+
+                         else
+                            "さらに読み込む"
+                        )
+                    ]
+                ]
+
+        Nothing ->
+            text ""
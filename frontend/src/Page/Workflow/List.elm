@@ -20,6 +20,9 @@ module Page.Workflow.List exposing
   - 申請一覧の表示
   - ステータスによるフィルタリング
   - 詳細ページへの遷移
+  - 「さらに読み込む」による続きの取得（カーソルページネーション）
+
+ステータスフィルタは読み込み済みの申請に対して適用する。
 
 
 ## 設計
@@ -35,7 +38,7 @@ import Component.Badge as Badge
 import Component.Button as Button
 import Component.ErrorState as ErrorState
 import Component.LoadingSpinner as LoadingSpinner
-import Data.WorkflowInstance as WorkflowInstance exposing (Status, WorkflowInstance)
+import Data.WorkflowInstance as WorkflowInstance exposing (Status, WorkflowInstance, WorkflowInstanceList)
 import Html exposing (..)
 import Html.Attributes exposing (..)
 import Html.Events exposing (onClick, onInput)
@@ -62,7 +65,8 @@ type alias Model =
     , key : Nav.Key
 
     -- API データ
-    , workflows : RemoteData ApiError (List WorkflowInstance)
+    , workflows : RemoteData ApiError WorkflowInstanceList
+    , loadingMore : Bool
 
     -- フィルタ状態
     , statusFilter : Maybe Status
@@ -92,6 +96,7 @@ init shared key filter =
     ( { shared = shared
       , key = key
       , workflows = Loading
+      , loadingMore = False
       , statusFilter = filter.status
       , completedToday = filter.completedToday
       , now = Nothing
@@ -99,6 +104,7 @@ init shared key filter =
     , Cmd.batch
         [ WorkflowApi.listMyWorkflows
             { config = Shared.toRequestConfig shared
+            , cursor = Nothing
             , toMsg = GotWorkflows
             }
         , timeCmd
@@ -145,7 +151,9 @@ applyFilter filter model =
 {-| メッセージ
 -}
 type Msg
-    = GotWorkflows (Result ApiError (List WorkflowInstance))
+    = GotWorkflows (Result ApiError WorkflowInstanceList)
+    | LoadMore String
+    | GotMoreWorkflows (Result ApiError WorkflowInstanceList)
     | SetStatusFilter (Maybe Status)
     | ClearCompletedToday
     | GotCurrentTime Time.Posix
@@ -169,6 +177,33 @@ update msg model =
                     , Cmd.none
                     )
 
+        LoadMore cursor ->
+            ( { model | loadingMore = True }
+            , WorkflowApi.listMyWorkflows
+                { config = Shared.toRequestConfig model.shared
+                , cursor = Just cursor
+                , toMsg = GotMoreWorkflows
+                }
+            )
+
+        GotMoreWorkflows result ->
+            case result of
+                Ok page ->
+                    ( { model
+                        | workflows =
+                            RemoteData.map
+                                (\current -> { items = current.items ++ page.items, nextCursor = page.nextCursor })
+                                model.workflows
+                        , loadingMore = False
+                      }
+                    , Cmd.none
+                    )
+
+                Err err ->
+                    ( { model | workflows = Failure err, loadingMore = False }
+                    , Cmd.none
+                    )
+
         SetStatusFilter maybeStatus ->
             ( model
             , Nav.replaceUrl model.key
@@ -187,9 +222,10 @@ update msg model =
             )
 
         Refresh ->
-            ( { model | workflows = Loading }
+            ( { model | workflows = Loading, loadingMore = False }
             , WorkflowApi.listMyWorkflows
                 { config = Shared.toRequestConfig model.shared
+                , cursor = Nothing
                 , toMsg = GotWorkflows
                 }
             )
@@ -233,7 +269,7 @@ viewContent model =
         Failure _ ->
             viewError
 
-        Success workflows ->
+        Success workflowList ->
             let
                 zone =
                     Shared.zone model.shared
@@ -241,7 +277,7 @@ viewContent model =
                 filter =
                     { status = model.statusFilter, completedToday = model.completedToday }
             in
-            viewWorkflowList zone model.now filter workflows
+            viewWorkflowList zone model.now filter model.loadingMore workflowList
 
 
 viewError : Html Msg
@@ -252,11 +288,11 @@ viewError =
         }
 
 
-viewWorkflowList : Time.Zone -> Maybe Time.Posix -> WorkflowFilter -> List WorkflowInstance -> Html Msg
-viewWorkflowList zone maybeNow filter workflows =
+viewWorkflowList : Time.Zone -> Maybe Time.Posix -> WorkflowFilter -> Bool -> WorkflowInstanceList -> Html Msg
+viewWorkflowList zone maybeNow filter loadingMore workflowList =
     let
         filteredWorkflows =
-            filterWorkflows zone maybeNow filter workflows
+            filterWorkflows zone maybeNow filter workflowList.items
     in
     div []
         [ viewFilterBar filter
@@ -275,8 +311,9 @@ viewWorkflowList zone maybeNow filter workflows =
           else
             div []
                 [ div [ class "overflow-x-auto rounded-lg border border-secondary-200" ] [ viewWorkflowTable zone filteredWorkflows ]
-                , viewCount (List.length filteredWorkflows)
+                , viewCount (List.length filteredWorkflows) workflowList.nextCursor
                 ]
+        , viewLoadMore loadingMore workflowList.nextCursor
         ]
 
 
@@ -404,10 +441,53 @@ viewWorkflowRow zone workflow =
         ]
 
 
-viewCount : Int -> Html Msg
-viewCount count =
+{-| 件数表示
+
+続きのページがある場合は総件数が分からないため、表示中の件数を示す。
+
+-}
+viewCount : Int -> Maybe String -> Html Msg
+viewCount count nextCursor =
+    let
+        label =
+            case nextCursor of
+                Just _ ->
+                    String.fromInt count ++ " 件を表示中"
+
+                Nothing ->
+                    "全 " ++ String.fromInt count ++ " 件"
+    in
     div [ class "mt-4 text-sm text-secondary-500" ]
-        [ text ("全 " ++ String.fromInt count ++ " 件") ]
+        [ text label ]
+
+
+{-| 「さらに読み込む」ボタン
+
+フィルタで絞り込んだ結果が空でも、未読み込みの申請に一致するものがありうるため常に表示する。
+
+-}
+viewLoadMore : Bool -> Maybe String -> Html Msg
+viewLoadMore loadingMore nextCursor =
+    case nextCursor of
+        Just cursor ->
+            div [ class "mt-4 flex justify-center" ]
+                [ Button.view
+                    { variant = Button.Outline
+                    , disabled = loadingMore
+                    , onClick = LoadMore cursor
+                    }
+                    [ text
+                        (if loadingMore then
+                            "読み込み中..."
+
+                         else
+                            "さらに読み込む"
+                        )
+                    ]
+                ]
+
+        Nothing ->
+            text ""
 
 
 
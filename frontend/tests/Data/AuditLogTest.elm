@@ -131,6 +131,14 @@ actionToJapaneseTests =
             \_ ->
                 AuditLog.actionToJapanese "role.delete"
                     |> Expect.equal "ロール削除"
+        , test "webhook.create → Webhook 作成" <|
+            \_ ->
+                AuditLog.actionToJapanese "webhook.create"
+                    |> Expect.equal "Webhook 作成"
+        , test "notification_template.update → 通知テンプレート更新" <|
+            \_ ->
+                AuditLog.actionToJapanese "notification_template.update"
+                    |> Expect.equal "通知テンプレート更新"
         , test "不明なアクション → そのまま返す" <|
             \_ ->
                 AuditLog.actionToJapanese "unknown.action"
@@ -265,7 +265,8 @@ listDecoderTests =
                 let
                     json =
                         """
-                        [
+                        {
+                            "items": [
                             {
                                 "id": "step-001",
                                 "display_number": 1,
@@ -285,17 +286,19 @@ listDecoderTests =
                                     }
                                 }
                             }
-                        ]
+                            ],
+                            "next_cursor": "cursor-1"
+                        }
                         """
                 in
                 Decode.decodeString Task.listDecoder json
-                    |> Result.map List.length
-                    |> Expect.equal (Ok 1)
-        , test "空の一覧をデコード" <|
+                    |> Result.map (\list -> ( List.length list.items, list.nextCursor ))
+                    |> Expect.equal (Ok ( 1, Just "cursor-1" ))
+        , test "最後のページは nextCursor が Nothing" <|
             \_ ->
-                Decode.decodeString Task.listDecoder "[]"
-                    |> Result.map List.length
-                    |> Expect.equal (Ok 0)
+                Decode.decodeString Task.listDecoder """{"items": [], "next_cursor": null}"""
+                    |> Result.map (\list -> ( List.length list.items, list.nextCursor ))
+                    |> Expect.equal (Ok ( 0, Nothing ))
         ]
 
 
@@ -516,7 +516,8 @@ listDecoderTests =
                 let
                     json =
                         """
-                        [
+                        {
+                            "items": [
                             {
                                 "id": "inst-001",
                                 "display_id": "WF-1",
@@ -541,14 +542,16 @@ listDecoderTests =
                                 "created_at": "2026-01-01T00:00:00Z",
                                 "updated_at": "2026-01-01T00:00:00Z"
                             }
-                        ]
+                            ],
+                            "next_cursor": "cursor-1"
+                        }
                         """
                 in
                 Decode.decodeString WorkflowInstance.listDecoder json
-                    |> Result.map List.length
-                    |> Expect.equal (Ok 2)
-        , test "空の一覧をデコード" <|
+                    |> Result.map (\list -> ( List.length list.items, list.nextCursor ))
+                    |> Expect.equal (Ok ( 2, Just "cursor-1" ))
+        , test "最後のページは nextCursor が Nothing" <|
             \_ ->
-                Decode.decodeString WorkflowInstance.listDecoder "[]"
-                    |> Expect.equal (Ok [])
+                Decode.decodeString WorkflowInstance.listDecoder """{"items": [], "next_cursor": null}"""
+                    |> Expect.equal (Ok { items = [], nextCursor = Nothing })
         ]